@@ -92,7 +92,10 @@ BEGIN { inside = 0; marker = "" }
 # A session counts as inside a worktree when either:
 #   (a) the current working directory is inside `.worktrees/<stage>/`, or
 #   (b) LOOM_WORKTREE_PATH points into `.worktrees/` AND that directory still
-#       exists on disk (the on-disk check rejects a stale, leaked value).
+#       exists on disk (the on-disk check rejects a stale, leaked value), or
+#   (c) for worktrees under a configured `[worktrees] root` outside the repo,
+#       the current directory is inside LOOM_WORKTREE_PATH and that directory
+#       is a git worktree (`.git` file) with a `.work` symlink.
 #
 # Returns the worktree root on stdout.
 loom_current_worktree() {
@@ -109,5 +112,12 @@ loom_current_worktree() {
         return 0
     fi
 
+    wt="${wt%/}"
+    if [[ -n "$wt" && -f "$wt/.git" && -L "$wt/.work" ]] \
+        && [[ "$dir" == "$wt" || "$dir" == "$wt"/* ]]; then
+        printf '%s' "$wt"
+        return 0
+    fi
+
     return 1
 }
@@ -24,7 +24,7 @@ fi
 
 # Configuration
 readonly WORKTREE_MARKER=".worktrees/"
-readonly LOOM_BRANCH_PREFIX="loom/"
+readonly LOOM_BRANCH_PREFIX="${LOOM_BRANCH_PREFIX:-loom/}"
 readonly WORK_DIR=".work"
 readonly STAGES_DIR="$WORK_DIR/stages"
 # Debug logging - enabled when LOOM_HOOK_DEBUG=1
@@ -0,0 +1,143 @@
+#!/usr/bin/env bash
+# PreToolUse hook: Keep a stage's reads inside its declared context
+#
+# A stage may declare `sandbox.readable_paths` and `sandbox.forbidden_paths`
+# (globs relative to the worktree root). When the stage is spawned loom
+# records them in .work/context-scope/<stage-id>.json; this hook blocks
+# Read/Glob/Grep calls outside that scope so the agent does not wander into
+# unrelated subsystems and fill its context with them.
+#
+# Inert outside a loom worktree and when no scope file exists for the stage
+# (the worktree directory name is the stage id).
+#
+# Checked path:
+#   - Read: file_path
+#   - Glob/Grep: path (the worktree root when omitted)
+#
+# Always allowed: .work/ and anything outside the worktree (the worktree
+# boundary itself is enforced by worktree-file-guard.sh).
+#
+# Exit codes:
+#   0 - Allow the operation
+#   2 - Block with guidance message
+
+set -euo pipefail
+source "$(dirname "$0")/_common.sh"
+
+WORKTREE_PATH=$(loom_current_worktree) || exit 0
+WORKTREE_PATH="${WORKTREE_PATH%/}"
+STAGE_ID="${WORKTREE_PATH##*/}"
+SCOPE_FILE="$WORKTREE_PATH/.work/context-scope/$STAGE_ID.json"
+if [[ ! -f "$SCOPE_FILE" ]]; then
+    exit 0
+fi
+
+# Read stdin JSON (Claude Code provides tool input)
+# Cross-platform timeout: gtimeout (macOS+coreutils), timeout (Linux), or plain cat
+if command -v gtimeout &>/dev/null; then
+    INPUT_JSON=$(gtimeout 1 cat 2>/dev/null || true)
+elif command -v timeout &>/dev/null; then
+    INPUT_JSON=$(timeout 1 cat 2>/dev/null || true)
+else
+    INPUT_JSON=$(cat 2>/dev/null || true)
+fi
+
+TOOL_NAME=$(echo "$INPUT_JSON" | jq -r '.tool_name // empty' 2>/dev/null || true)
+case "$TOOL_NAME" in
+    Read)
+        TARGET=$(echo "$INPUT_JSON" | jq -r '.tool_input.file_path // empty' 2>/dev/null || true)
+        [[ -z "$TARGET" ]] && exit 0
+        ;;
+    Glob|Grep)
+        TARGET=$(echo "$INPUT_JSON" | jq -r '.tool_input.path // empty' 2>/dev/null || true)
+        ;;
+    *)
+        exit 0
+        ;;
+esac
+
+# Make the target relative to the worktree root
+if [[ -d "$WORKTREE_PATH" ]]; then
+    WORKTREE_REAL=$(cd "$WORKTREE_PATH" && pwd -P)
+else
+    WORKTREE_REAL="$WORKTREE_PATH"
+fi
+if [[ -z "$TARGET" ]]; then
+    REL_PATH=""
+elif [[ "$TARGET" == "$WORKTREE_PATH" || "$TARGET" == "$WORKTREE_REAL" ]]; then
+    REL_PATH=""
+elif [[ "$TARGET" == "$WORKTREE_PATH/"* ]]; then
+    REL_PATH="${TARGET#"$WORKTREE_PATH"/}"
+elif [[ "$TARGET" == "$WORKTREE_REAL/"* ]]; then
+    REL_PATH="${TARGET#"$WORKTREE_REAL"/}"
+elif [[ "$TARGET" == /* || "$TARGET" == ~* ]]; then
+    # Outside the worktree: not this hook's concern
+    exit 0
+else
+    CWD=$(pwd -P 2>/dev/null || pwd)
+    if [[ "$CWD" == "$WORKTREE_REAL" ]]; then
+        REL_PATH="$TARGET"
+    elif [[ "$CWD" == "$WORKTREE_REAL/"* ]]; then
+        REL_PATH="${CWD#"$WORKTREE_REAL"/}/$TARGET"
+    else
+        exit 0
+    fi
+fi
+while [[ "$REL_PATH" == ./* ]]; do
+    REL_PATH="${REL_PATH#./}"
+done
+REL_PATH="${REL_PATH%/}"
+[[ "$REL_PATH" == "." ]] && REL_PATH=""
+
+if [[ "$REL_PATH" == .work || "$REL_PATH" == .work/* ]]; then
+    exit 0
+fi
+
+# A directory matches a glob when "<dir>/" does, so `src/**` admits `src`
+matches_any() {
+    local key="$1"
+    local glob
+    while IFS= read -r glob; do
+        [[ -z "$glob" ]] && continue
+        # shellcheck disable=SC2053 # glob is intentionally unquoted
+        if [[ "$REL_PATH" == $glob || "$REL_PATH/" == $glob ]]; then
+            return 0
+        fi
+    done < <(jq -r --arg key "$key" '.[$key] // [] | .[]' "$SCOPE_FILE" 2>/dev/null || true)
+    return 1
+}
+
+SHOWN_PATH="${REL_PATH:-. (worktree root)}"
+if matches_any forbidden_paths; then
+    REASON="It matches the stage's forbidden_paths."
+elif [[ $(jq -r '.readable_paths // [] | length' "$SCOPE_FILE" 2>/dev/null || echo 0) -eq 0 ]] ||
+    matches_any readable_paths; then
+    exit 0
+else
+    REASON="It is outside the stage's readable_paths."
+fi
+
+READABLE=$(jq -r '.readable_paths // [] | .[] | "  - " + .' "$SCOPE_FILE" 2>/dev/null || true)
+FORBIDDEN=$(jq -r '.forbidden_paths // [] | .[] | "  - " + .' "$SCOPE_FILE" 2>/dev/null || true)
+cat >&2 <<EOF
+
+============================================================
+  LOOM: BLOCKED - $TOOL_NAME outside the stage's context scope
+============================================================
+
+Blocked: $SHOWN_PATH
+$REASON
+
+Readable paths:
+${READABLE:-  (whole worktree)}
+Forbidden paths:
+${FORBIDDEN:-  (none)}
+
+Point $TOOL_NAME at a path inside the readable scope. If this
+stage genuinely needs the blocked code, say so in your handoff
+instead of reading it.
+
+============================================================
+
+EOF
+exit 2
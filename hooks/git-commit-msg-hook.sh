@@ -0,0 +1,53 @@
+#!/usr/bin/env bash
+# Git commit-msg hook: Require a [<stage-id>] prefix on commits in loom stage worktrees
+#
+# Installed by loom when `[git_hooks] commit_msg = true` in .work/config.toml.
+# The hook only acts in worktrees whose git dir holds a `loom-hooks` file
+# (written when the stage's session starts) with `commit_msg=1`; commits
+# elsewhere, including the main repository, are never checked.
+#
+# Merge, revert, fixup!, squash! and amend! messages generated by git are
+# allowed as-is.
+#
+# Exit codes:
+#   0 - Allow commit
+#   1 - Block commit (message lacks the [<stage-id>] prefix)
+
+set -euo pipefail
+
+# Marker for loom hook section (for idempotent installation)
+# LOOM_COMMIT_MSG_HOOK_START
+
+loom_check_commit_msg() {
+    local conf
+    conf="$(git rev-parse --git-dir 2>/dev/null)/loom-hooks"
+    [[ -f "$conf" ]] || return 0
+    grep -qx "commit_msg=1" "$conf" || return 0
+
+    local stage_id
+    stage_id=$(sed -n 's/^stage_id=//p' "$conf")
+    [[ -n "$stage_id" ]] || return 0
+
+    local subject
+    subject=$(grep -v '^#' "$1" | sed '/^[[:space:]]*$/d' | head -n 1 || true)
+    case "$subject" in
+        "[$stage_id]"*) return 0 ;;
+        "Merge "* | "Revert \""* | "fixup! "* | "squash! "* | "amend! "*) return 0 ;;
+    esac
+
+    echo ""
+    echo "============================================================"
+    echo "  LOOM: BLOCKED COMMIT - Missing stage prefix"
+    echo "============================================================"
+    echo ""
+    echo "Commit messages in this worktree must start with [$stage_id]:"
+    echo ""
+    echo "  [$stage_id] ${subject:-<summary>}"
+    echo ""
+    echo "============================================================"
+    return 1
+}
+
+loom_check_commit_msg "$1"
+
+# LOOM_COMMIT_MSG_HOOK_END
@@ -21,6 +21,7 @@ check_forbidden_paths() {
     local forbidden_patterns=(
         "^\.work/"
         "^\.work$"
+        "^\.work\.init/"
         "^\.worktrees/"
         "^\.worktrees$"
     )
@@ -0,0 +1,43 @@
+#!/usr/bin/env bash
+# Git pre-push hook: Block pushes from loom stage worktrees
+#
+# Installed by loom when `[git_hooks] pre_push = true` in .work/config.toml.
+# Stage branches reach the target branch through loom's progressive merge;
+# an agent pushing one directly bypasses verification. The hook only acts in
+# worktrees whose git dir holds a `loom-hooks` file (written when the stage's
+# session starts) with `pre_push=1`; pushes from the main repository are never
+# blocked.
+#
+# Exit codes:
+#   0 - Allow push
+#   1 - Block push (running in a stage worktree)
+
+set -euo pipefail
+
+# Marker for loom hook section (for idempotent installation)
+# LOOM_PRE_PUSH_HOOK_START
+
+loom_check_pre_push() {
+    local conf
+    conf="$(git rev-parse --git-dir 2>/dev/null)/loom-hooks"
+    [[ -f "$conf" ]] || return 0
+    grep -qx "pre_push=1" "$conf" || return 0
+
+    local stage_id
+    stage_id=$(sed -n 's/^stage_id=//p' "$conf")
+
+    echo ""
+    echo "============================================================"
+    echo "  LOOM: BLOCKED PUSH - Stage worktree"
+    echo "============================================================"
+    echo ""
+    echo "Pushes from stage worktrees are blocked (stage: ${stage_id:-unknown})."
+    echo "Finish the stage with 'loom stage complete'; loom merges the branch."
+    echo ""
+    echo "============================================================"
+    return 1
+}
+
+loom_check_pre_push
+
+# LOOM_PRE_PUSH_HOOK_END
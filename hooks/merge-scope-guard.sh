@@ -0,0 +1,100 @@
+#!/usr/bin/env bash
+# PreToolUse hook: Keep merge conflict resolution sessions to their conflicts
+#
+# When the daemon spawns a merge resolution session for a stage with known
+# conflicting files, it records them in .work/merge-scope/<stage-id>.json.
+# This hook blocks Write/Edit calls from that session to any other file in
+# the repository except test files, so the resolver cannot make unrelated
+# changes while it has the main repository checked out.
+#
+# Inert unless LOOM_MERGE_SESSION=1 and a scope file exists for the stage
+# (LOOM_STAGE_ID is "merge-<stage-id>" in merge sessions).
+#
+# Allowed:
+#   - Files listed in the scope
+#   - Test files (tests/, test/, __tests__/, spec/ dirs; *_test.*, *.test.*,
+#     *_spec.*, *.spec.*, test_* names)
+#   - Anything under .work/ and anything outside the repository
+#
+# Exit codes:
+#   0 - Allow the operation
+#   2 - Block with guidance message
+
+set -euo pipefail
+
+if [[ "${LOOM_MERGE_SESSION:-}" != "1" ]] || [[ -z "${LOOM_WORK_DIR:-}" ]]; then
+    exit 0
+fi
+
+STAGE_ID="${LOOM_STAGE_ID:-}"
+STAGE_ID="${STAGE_ID#merge-}"
+SCOPE_FILE="$LOOM_WORK_DIR/merge-scope/$STAGE_ID.json"
+if [[ -z "$STAGE_ID" ]] || [[ ! -f "$SCOPE_FILE" ]]; then
+    exit 0
+fi
+
+# Read stdin JSON (Claude Code provides tool input)
+# Cross-platform timeout: gtimeout (macOS+coreutils), timeout (Linux), or plain cat
+if command -v gtimeout &>/dev/null; then
+    INPUT_JSON=$(gtimeout 1 cat 2>/dev/null || true)
+elif command -v timeout &>/dev/null; then
+    INPUT_JSON=$(timeout 1 cat 2>/dev/null || true)
+else
+    INPUT_JSON=$(cat 2>/dev/null || true)
+fi
+
+TOOL_NAME=$(echo "$INPUT_JSON" | jq -r '.tool_name // empty' 2>/dev/null || true)
+FILE_PATH=$(echo "$INPUT_JSON" | jq -r '.tool_input.file_path // empty' 2>/dev/null || true)
+
+if [[ "$TOOL_NAME" != "Write" && "$TOOL_NAME" != "Edit" ]] || [[ -z "$FILE_PATH" ]]; then
+    exit 0
+fi
+
+REPO_ROOT=$(git rev-parse --show-toplevel 2>/dev/null || pwd)
+if [[ "$FILE_PATH" == "$REPO_ROOT/"* ]]; then
+    REL_PATH="${FILE_PATH#"$REPO_ROOT"/}"
+elif [[ "$FILE_PATH" == /* ]]; then
+    # Outside the repository: not part of the merge
+    exit 0
+else
+    REL_PATH="${FILE_PATH#./}"
+fi
+
+if [[ "$REL_PATH" == .work/* ]]; then
+    exit 0
+fi
+
+if jq -e --arg path "$REL_PATH" '.files | index($path)' "$SCOPE_FILE" >/dev/null 2>&1; then
+    exit 0
+fi
+
+NAME="${REL_PATH##*/}"
+STEM="${NAME%%.*}"
+if [[ "$REL_PATH" =~ (^|/)(test|tests|__tests__|spec|specs|testdata|fixtures)/ ]] ||
+    [[ "$STEM" == test_* || "$STEM" == *_test || "$STEM" == *_tests || "$STEM" == *_spec ]] ||
+    [[ "$NAME" == *.test.* || "$NAME" == *.spec.* ]]; then
+    exit 0
+fi
+
+FILES=$(jq -r '.files[] | "  - " + .' "$SCOPE_FILE" 2>/dev/null || true)
+cat >&2 <<EOF
+
+============================================================
+  LOOM: BLOCKED - Edit outside the merge conflict scope
+============================================================
+
+This merge resolution session may only edit the conflicting
+files and test files. Blocked: $REL_PATH
+
+Conflicting files:
+$FILES
+
+Resolve the conflicts in the files above. If the merge truly
+needs other changes, stop and ask the user; \`loom stage merge
+$STAGE_ID --resolved\` refuses a merge commit that changes
+files outside this scope.
+
+============================================================
+
+EOF
+exit 2
@@ -0,0 +1,109 @@
+#!/usr/bin/env bash
+# notification.sh - Claude Code Notification hook for loom
+#
+# Called when Claude Code raises a notification: a permission prompt, or the
+# session sitting idle waiting for input.
+#
+# Input: JSON from stdin (Claude Code passes notification info via stdin)
+#   {"message": "Claude needs your permission to use Bash",
+#    "notification_type": "permission_prompt", "transcript_path": "...", ...}
+#
+# Environment variables (set by loom worktree settings):
+#   LOOM_STAGE_ID    - The stage being executed
+#   LOOM_SESSION_ID  - The session ID
+#   LOOM_WORK_DIR    - Path to the .work directory
+#
+# Actions:
+#   1. Rewrites .work/heartbeat/<stage-id>.json with `waiting_prompt` set to an
+#      excerpt of the prompt (for permission prompts, the pending tool call
+#      from the session transcript). The loom daemon flips the stage to
+#      WaitingForInput; the next PostToolUse heartbeat clears the prompt and
+#      the daemon flips the stage back to Executing.
+
+set -euo pipefail
+
+# Read JSON input from stdin
+# Cross-platform timeout: gtimeout (macOS+coreutils), timeout (Linux), or plain cat
+if command -v gtimeout &>/dev/null; then
+	INPUT_JSON=$(gtimeout 1 cat 2>/dev/null || true)
+elif command -v timeout &>/dev/null; then
+	INPUT_JSON=$(timeout 1 cat 2>/dev/null || true)
+else
+	INPUT_JSON=$(cat 2>/dev/null || true)
+fi
+
+# Validate required environment variables
+# Silently exit if not in loom context (hook runs on ALL sessions)
+if [[ -z "${LOOM_STAGE_ID:-}" ]] || [[ -z "${LOOM_SESSION_ID:-}" ]] || [[ -z "${LOOM_WORK_DIR:-}" ]]; then
+	exit 0
+fi
+
+if [[ ! -d "${LOOM_WORK_DIR}" ]]; then
+	exit 0
+fi
+
+# The heartbeat is JSON built with jq; without it there is nothing safe to write
+if ! command -v jq &>/dev/null; then
+	exit 0
+fi
+
+MESSAGE=$(echo "$INPUT_JSON" | jq -r '.message // empty' 2>/dev/null || true)
+NOTIFICATION_TYPE=$(echo "$INPUT_JSON" | jq -r '.notification_type // empty' 2>/dev/null || true)
+TRANSCRIPT_PATH=$(echo "$INPUT_JSON" | jq -r '.transcript_path // empty' 2>/dev/null || true)
+
+# Only prompts that block the session mark it as waiting. Older Claude Code
+# versions send no notification_type, so fall back to the message text.
+case "$NOTIFICATION_TYPE" in
+permission_prompt | idle_prompt | elicitation_dialog) ;;
+"")
+	case "$MESSAGE" in
+	*"permission"* | *"waiting for your input"*) ;;
+	*) exit 0 ;;
+	esac
+	;;
+*) exit 0 ;;
+esac
+
+PROMPT="${MESSAGE:-Waiting for input}"
+
+# For permission prompts, name the pending tool call: the last tool_use in the
+# transcript is the one awaiting approval.
+if [[ "$PROMPT" == *"permission"* ]] && [[ -n "$TRANSCRIPT_PATH" ]] && [[ -f "$TRANSCRIPT_PATH" ]]; then
+	DETAIL=$(tail -n 50 "$TRANSCRIPT_PATH" 2>/dev/null |
+		jq -r 'select(.type == "assistant") | .message.content[]? | select(.type == "tool_use")
+			| (.input.command // .input.file_path // .input.url // .input.pattern // empty)' 2>/dev/null |
+		tail -n 1 || true)
+	if [[ -n "$DETAIL" ]]; then
+		PROMPT="${PROMPT}: ${DETAIL}"
+	fi
+fi
+
+# One line, bounded length: this is shown inline in `loom status`
+PROMPT=$(printf '%s' "$PROMPT" | tr '\n\t' '  ')
+if [[ ${#PROMPT} -gt 200 ]]; then
+	PROMPT="${PROMPT:0:197}..."
+fi
+
+HEARTBEAT_DIR="${LOOM_WORK_DIR}/heartbeat"
+mkdir -p "$HEARTBEAT_DIR" 2>/dev/null || exit 0
+HEARTBEAT_FILE="${HEARTBEAT_DIR}/${LOOM_STAGE_ID}.json"
+TIMESTAMP=$(date -u +"%Y-%m-%dT%H:%M:%S.000Z")
+
+# Keep the last tool and context reading from the existing heartbeat
+BASE=$(jq -c . "$HEARTBEAT_FILE" 2>/dev/null || true)
+if [[ -z "$BASE" ]]; then
+	BASE='{}'
+fi
+HEARTBEAT_JSON=$(printf '%s' "$BASE" | jq \
+	--arg stage_id "$LOOM_STAGE_ID" \
+	--arg session_id "$LOOM_SESSION_ID" \
+	--arg timestamp "$TIMESTAMP" \
+	--arg prompt "$PROMPT" \
+	'. + {stage_id: $stage_id, session_id: $session_id, timestamp: $timestamp, waiting_prompt: $prompt, activity: ("Waiting: " + $prompt)}' \
+	2>/dev/null || true)
+
+if [[ -n "$HEARTBEAT_JSON" ]]; then
+	printf '%s\n' "$HEARTBEAT_JSON" >"$HEARTBEAT_FILE"
+fi
+
+exit 0
@@ -13,8 +13,10 @@
 #   LOOM_WORK_DIR    - Path to the .work directory
 #
 # Actions:
-#   1. Updates heartbeat in .work/heartbeat/<stage-id>.json
+#   1. Updates heartbeat in .work/heartbeat/<stage-id>.json and appends it to
+#      .work/heartbeat/<stage-id>.history.jsonl (last 50 kept)
 #   2. After git commits in loom stages, reminds Claude to update knowledge/memory
+#   3. After `loom signal refresh`, tells Claude to re-read its signal
 
 set -euo pipefail
 
@@ -90,6 +92,18 @@ else
 EOF
 fi
 
+# Append to the per-stage history ring buffer used by `loom status`.
+# Keep the last 50 entries (mirrors HEARTBEAT_HISTORY_LEN in heartbeat.rs).
+# Only written when jq produced the heartbeat, so each entry is one line.
+if [[ -n "$HEARTBEAT_JSON" ]]; then
+	HISTORY_FILE="${HEARTBEAT_DIR}/${LOOM_STAGE_ID}.history.jsonl"
+	{
+		printf '%s\n' "$HEARTBEAT_JSON" | jq -c . >>"$HISTORY_FILE" &&
+			tail -n 50 "$HISTORY_FILE" >"${HISTORY_FILE}.tmp" &&
+			mv "${HISTORY_FILE}.tmp" "$HISTORY_FILE"
+	} 2>/dev/null || true
+fi
+
 # === TOOL EVENT LOGGING ===
 # Append a structured row to tool-events.jsonl for observability.
 # Entire section guarded by jq availability — heartbeat must never be broken.
@@ -125,6 +139,18 @@ if command -v jq &>/dev/null; then
 	fi
 fi
 
+# === SIGNAL REFRESH NOTIFICATION ===
+# `loom signal refresh` rewrites the signal and leaves a .refreshed marker
+# listing the changed sections. Consume it once and inject a re-read prompt.
+REFRESH_MARKER="${LOOM_WORK_DIR}/signals/${LOOM_SESSION_ID}.refreshed"
+if [[ -f "$REFRESH_MARKER" ]] && command -v jq &>/dev/null; then
+	CHANGED=$(paste -sd ',' "$REFRESH_MARKER" 2>/dev/null | sed 's/,/, /g' || true)
+	rm -f "$REFRESH_MARKER"
+	REFRESH_MSG="Your loom signal was refreshed (changed: ${CHANGED:-unknown}). Re-read ${LOOM_WORK_DIR}/signals/${LOOM_SESSION_ID}.md before continuing: dependency outputs, facts and memory may have changed."
+	jq -nc --arg ctx "$REFRESH_MSG" \
+		'{hookSpecificOutput: {hookEventName: "PostToolUse", additionalContext: $ctx}}'
+fi
+
 # === POST-COMMIT KNOWLEDGE/MEMORY REMINDER ===
 # After a git commit in a loom stage, remind Claude to update knowledge/memory
 # This is non-blocking - just a prompt to help capture lessons learned
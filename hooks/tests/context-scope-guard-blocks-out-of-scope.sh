@@ -0,0 +1,54 @@
+#!/usr/bin/env bash
+set -euo pipefail
+HOOK="$(cd "$(dirname "$0")/.." && pwd)/context-scope-guard.sh"
+TMP=$(cd "$(mktemp -d)" && pwd -P)
+trap 'rm -rf "$TMP"' EXIT
+WT="$TMP/.worktrees/api"
+mkdir -p "$TMP/.work/context-scope" "$WT/src/api" "$WT/src/billing"
+ln -s ../../.work "$WT/.work"
+echo '{"stage_id":"api","readable_paths":["src/api/**","Cargo.toml"],"forbidden_paths":["src/api/generated/**"]}' \
+    >"$TMP/.work/context-scope/api.json"
+
+run() {
+    local tool="$1" key="$2" path="$3"
+    set +e
+    (cd "$WT" && echo "{\"tool_name\":\"$tool\",\"tool_input\":{\"$key\":\"$path\"}}" |
+        bash "$HOOK" 2>/dev/null)
+    local code=$?
+    set -e
+    echo "$code"
+}
+
+expect() {
+    local want="$1" tool="$2" key="$3" path="$4"
+    local code
+    code=$(run "$tool" "$key" "$path")
+    if [[ $code -ne $want ]]; then
+        echo "FAIL: expected exit $want for $tool $path, got exit $code"
+        exit 1
+    fi
+}
+
+expect 0 Read file_path "$WT/src/api/handlers.rs"
+expect 0 Read file_path "Cargo.toml"
+expect 0 Read file_path "$WT/.work/signals/api.md"
+expect 0 Grep path "src/api"
+expect 2 Read file_path "$WT/src/billing/invoice.rs"
+expect 2 Read file_path "$WT/src/api/generated/schema.rs"
+expect 2 Glob path "src/billing"
+expect 2 Grep path ""
+
+expect 0 Read file_path "./Cargo.toml"
+expect 0 Glob path "$WT/src/api"
+
+# forbidden_paths only: everything else stays readable
+echo '{"stage_id":"api","forbidden_paths":["vendor/**"]}' >"$TMP/.work/context-scope/api.json"
+expect 0 Read file_path "$WT/src/lib.rs"
+expect 0 Grep path ""
+expect 2 Read file_path "$WT/vendor/openssl/lib.c"
+expect 2 Glob path "vendor"
+
+# No scope file: inert
+rm "$TMP/.work/context-scope/api.json"
+expect 0 Read file_path "$WT/src/billing/invoice.rs"
+echo "PASS"
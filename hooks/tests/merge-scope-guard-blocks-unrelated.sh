@@ -0,0 +1,35 @@
+#!/usr/bin/env bash
+set -euo pipefail
+HOOK="$(cd "$(dirname "$0")/.." && pwd)/merge-scope-guard.sh"
+TMP=$(cd "$(mktemp -d)" && pwd -P)
+trap 'rm -rf "$TMP"' EXIT
+git -C "$TMP" init -q
+mkdir -p "$TMP/.work/merge-scope"
+echo '{"stage_id":"api","session_id":"s","created_at":"2026-01-01T00:00:00Z","files":["src/lib.rs"]}' \
+    >"$TMP/.work/merge-scope/api.json"
+
+run() {
+    local path="$1"
+    set +e
+    (cd "$TMP" && echo "{\"tool_name\":\"Edit\",\"tool_input\":{\"file_path\":\"$TMP/$path\"}}" |
+        LOOM_MERGE_SESSION=1 LOOM_STAGE_ID=merge-api LOOM_WORK_DIR="$TMP/.work" bash "$HOOK" 2>/dev/null)
+    local code=$?
+    set -e
+    echo "$code"
+}
+
+for path in src/lib.rs tests/api.rs src/lib_test.go; do
+    CODE=$(run "$path")
+    if [[ $CODE -ne 0 ]]; then
+        echo "FAIL: expected exit 0 for in-scope $path, got exit $CODE"
+        exit 1
+    fi
+done
+
+CODE=$(run src/main.rs)
+if [[ $CODE -eq 2 ]]; then
+    echo "PASS"
+else
+    echo "FAIL: expected exit 2 for out-of-scope src/main.rs, got exit $CODE"
+    exit 1
+fi
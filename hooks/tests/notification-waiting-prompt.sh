@@ -0,0 +1,30 @@
+#!/usr/bin/env bash
+set -euo pipefail
+HOOK="$(dirname "$0")/../notification.sh"
+TMPDIR_TEST=$(mktemp -d)
+trap 'rm -rf "$TMPDIR_TEST"' EXIT
+
+export LOOM_STAGE_ID="test-stage"
+export LOOM_SESSION_ID="test-session"
+export LOOM_WORK_DIR="$TMPDIR_TEST"
+
+HEARTBEAT="$TMPDIR_TEST/heartbeat/test-stage.json"
+TRANSCRIPT="$TMPDIR_TEST/transcript.jsonl"
+mkdir -p "$TMPDIR_TEST/heartbeat"
+echo '{"stage_id":"test-stage","session_id":"test-session","timestamp":"2026-01-01T00:00:00.000Z","last_tool":"Edit"}' > "$HEARTBEAT"
+echo '{"type":"assistant","message":{"content":[{"type":"tool_use","name":"Bash","input":{"command":"cargo publish"}}]}}' > "$TRANSCRIPT"
+
+# Informational notifications leave the heartbeat alone
+bash "$HOOK" <<< '{"message":"Authenticated","notification_type":"auth_success"}' > /dev/null 2>&1
+if jq -e 'has("waiting_prompt")' "$HEARTBEAT" > /dev/null 2>&1; then
+    echo "FAIL: auth_success marked the stage as waiting"
+    exit 1
+fi
+
+bash "$HOOK" <<< "{\"message\":\"Claude needs your permission to use Bash\",\"notification_type\":\"permission_prompt\",\"transcript_path\":\"$TRANSCRIPT\"}" > /dev/null 2>&1
+if ! jq -e '.waiting_prompt == "Claude needs your permission to use Bash: cargo publish" and .last_tool == "Edit"' "$HEARTBEAT" > /dev/null 2>&1; then
+    echo "FAIL: permission prompt not recorded: $(cat "$HEARTBEAT")"
+    exit 1
+fi
+
+echo "PASS"
@@ -0,0 +1,33 @@
+#!/usr/bin/env bash
+set -euo pipefail
+HOOK="$(dirname "$0")/../post-tool-use.sh"
+TMPDIR_TEST=$(mktemp -d)
+trap 'rm -rf "$TMPDIR_TEST"' EXIT
+
+export LOOM_STAGE_ID="test-stage"
+export LOOM_SESSION_ID="test-session"
+export LOOM_WORK_DIR="$TMPDIR_TEST"
+
+HISTORY="$TMPDIR_TEST/heartbeat/test-stage.history.jsonl"
+
+for _ in $(seq 1 55); do
+    bash "$HOOK" <<< '{"tool_name":"Edit","tool_input":{"file_path":"x"},"tool_result":{"output":"ok"}}' > /dev/null 2>&1
+done
+
+if [[ ! -f "$HISTORY" ]]; then
+    echo "FAIL: history file not written"
+    exit 1
+fi
+
+LINES=$(wc -l < "$HISTORY" | tr -d ' ')
+if [[ "$LINES" != "50" ]]; then
+    echo "FAIL: expected 50 history lines, got $LINES"
+    exit 1
+fi
+
+if ! tail -n 1 "$HISTORY" | jq -e '.last_tool == "Edit" and .session_id == "test-session"' > /dev/null 2>&1; then
+    echo "FAIL: last history entry malformed: $(tail -n 1 "$HISTORY")"
+    exit 1
+fi
+
+echo "PASS"
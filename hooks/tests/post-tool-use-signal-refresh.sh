@@ -0,0 +1,40 @@
+#!/usr/bin/env bash
+set -euo pipefail
+HOOK="$(dirname "$0")/../post-tool-use.sh"
+TMPDIR_TEST=$(mktemp -d)
+trap 'rm -rf "$TMPDIR_TEST"' EXIT
+
+export LOOM_STAGE_ID="test-stage"
+export LOOM_SESSION_ID="test-session"
+export LOOM_WORK_DIR="$TMPDIR_TEST"
+
+mkdir -p "$TMPDIR_TEST/signals"
+printf 'Dependency Outputs\nStage Memory' > "$TMPDIR_TEST/signals/test-session.refreshed"
+
+INPUT='{"tool_name":"Read","tool_input":{"file_path":"x"},"tool_result":{"output":"x"}}'
+
+OUTPUT=$(bash "$HOOK" <<< "$INPUT" 2>/dev/null)
+
+if ! echo "$OUTPUT" | jq -e '.hookSpecificOutput.hookEventName == "PostToolUse"' > /dev/null 2>&1; then
+    echo "FAIL: no PostToolUse context emitted: $OUTPUT"
+    exit 1
+fi
+
+if ! echo "$OUTPUT" | jq -r '.hookSpecificOutput.additionalContext' | grep -q "Dependency Outputs, Stage Memory"; then
+    echo "FAIL: changed sections missing from context: $OUTPUT"
+    exit 1
+fi
+
+if [[ -f "$TMPDIR_TEST/signals/test-session.refreshed" ]]; then
+    echo "FAIL: refresh marker not consumed"
+    exit 1
+fi
+
+# Second call must stay silent
+OUTPUT=$(bash "$HOOK" <<< "$INPUT" 2>/dev/null)
+if [[ -n "$OUTPUT" ]]; then
+    echo "FAIL: notification repeated: $OUTPUT"
+    exit 1
+fi
+
+echo "PASS"
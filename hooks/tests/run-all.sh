@@ -26,10 +26,15 @@ run_test "prefer-modern-tools: rg has no warning" "$SCRIPT_DIR/prefer-modern-too
 run_test "prefer-modern-tools: quoted grep no warning" "$SCRIPT_DIR/prefer-modern-tools-quoted.sh"
 run_test "post-tool-use: tool event written" "$SCRIPT_DIR/post-tool-use-tool-event.sh"
 run_test "post-tool-use: empty output records output_bytes=0" "$SCRIPT_DIR/post-tool-use-empty-output.sh"
+run_test "post-tool-use: signal refresh marker emits re-read context" "$SCRIPT_DIR/post-tool-use-signal-refresh.sh"
+run_test "post-tool-use: heartbeat history capped at 50" "$SCRIPT_DIR/post-tool-use-heartbeat-history.sh"
+run_test "notification: permission prompt recorded in heartbeat" "$SCRIPT_DIR/notification-waiting-prompt.sh"
 run_test "session-start: compact source emits re-anchor" "$SCRIPT_DIR/session-start-compact.sh"
 run_test "plans-path-guard: blocks ~/.claude/plans write" "$SCRIPT_DIR/plans-path-guard-blocks-claude-plans.sh"
 run_test "plans-path-guard: blocks ~/.claude/projects/*/plans edit" "$SCRIPT_DIR/plans-path-guard-blocks-project-plans.sh"
 run_test "plans-path-guard: allows doc/plans and other .claude paths" "$SCRIPT_DIR/plans-path-guard-allows-doc-plans.sh"
+run_test "merge-scope-guard: blocks edits outside conflicting files" "$SCRIPT_DIR/merge-scope-guard-blocks-unrelated.sh"
+run_test "context-scope-guard: blocks reads outside the stage scope" "$SCRIPT_DIR/context-scope-guard-blocks-out-of-scope.sh"
 
 echo ""
 echo "Results: $PASS passed, $FAIL failed"
@@ -1,50 +1,192 @@
 use anyhow::Result;
+use loom::commands::resume::ResumeAction;
+use loom::commands::status::render::GroupBy;
 use loom::commands::{
-    clean, diagnose, graph, handoff, init, knowledge, map, memory, plan, pressure, repair, resume,
-    review, run, self_update, sessions, skill_index, stage, status, stop, verify, worktree_cmd,
+    ci_check, clean, diagnose, doctor, fact, graph, handoff, history, init, integrity, knowledge,
+    learn, logs, map, mcp, memory, merge, pause, plan, pressure, repair, report, resume, review,
+    run, self_update, sessions, signal, skill_index, stage, status, stop, tmux_status, verify,
+    worktree_cmd,
 };
 use loom::completions::{complete_dynamic, generate_completions, CompletionContext, Shell};
+use loom::fs::facts::FactConfidence;
+use loom::verbosity::{is_quiet, is_verbose};
 use std::path::PathBuf;
 use std::str::FromStr;
 
 use super::types::{
-    Commands, KnowledgeCommands, MemoryCommands, OutputCommands, PlanCommands, SessionsCommands,
-    StageCommands, WorktreeCommands,
+    Commands, FactCommands, HandoffCommands, HistoryCommands, KnowledgeCommands, LearnCommands,
+    LogsCommands, McpCommands, MemoryCommands, MergeCommands, OutputCommands, PlanCommands,
+    ReportCommands, SessionsCommands, SignalCommands, StageCommands, VerifyCommands,
+    WorktreeCommands,
 };
 
 pub fn dispatch(command: Commands) -> Result<()> {
     match command {
-        Commands::Init { plan_path, clean } => init::execute(Some(PathBuf::from(plan_path)), clean),
+        Commands::Init {
+            plan_path,
+            clean,
+            switch_plan,
+            interactive,
+        } => {
+            let plan_path = plan_path.map(PathBuf::from);
+            if interactive {
+                init::execute_interactive(plan_path, clean, switch_plan)
+            } else {
+                init::execute(plan_path, clean, switch_plan)
+            }
+        }
         Commands::Run {
             manual,
             max_parallel,
             foreground,
             watch,
             no_merge,
+            until,
+            allow_dirty,
+            keep_terminal,
+            supervised,
+            max_runtime,
+            handoff_on_timeout,
+            param,
+            simulate,
         } => {
             let auto_merge = !no_merge;
+            run::resolve_params(&param)?;
+            if keep_terminal {
+                loom::orchestrator::terminal::keep_open::enable_for_process();
+            }
+            let deadline = max_runtime
+                .map(|limit| loom::orchestrator::RunDeadline::after(limit, handoff_on_timeout));
+            let simulation = simulate
+                .map(|path| loom::orchestrator::SimulationSchedule::load(path.as_deref()))
+                .transpose()?;
             if foreground {
-                run::execute(manual, max_parallel, watch, auto_merge)
+                run::execute(
+                    manual,
+                    max_parallel,
+                    watch,
+                    auto_merge,
+                    until,
+                    allow_dirty,
+                    deadline,
+                    simulation,
+                )
             } else {
-                run::execute_background(manual, max_parallel, watch, auto_merge)
+                run::execute_background(
+                    manual,
+                    max_parallel,
+                    watch,
+                    auto_merge,
+                    until,
+                    allow_dirty,
+                    supervised,
+                    deadline,
+                    simulation,
+                )
             }
         }
         Commands::Status {
             live,
             compact,
-            verbose,
-        } => status::execute(live, compact, verbose),
-        Commands::Resume { stage_id } => resume::execute(stage_id),
+            explain,
+            decisions,
+            group_by,
+        } => match (explain, decisions) {
+            (Some(stage_id), Some(limit)) => {
+                status::explain(&stage_id)?;
+                println!();
+                status::decisions(limit, Some(&stage_id))
+            }
+            (Some(stage_id), None) => status::explain(&stage_id),
+            (None, Some(limit)) => status::decisions(limit, None),
+            (None, None) => {
+                let group_by = group_by.as_deref().map(GroupBy::from_str).transpose()?;
+                status::execute(live, compact, is_verbose(), group_by)
+            }
+        },
+        Commands::TmuxStatus { json } => tmux_status::execute(json),
+        Commands::Resume { stage_id, action } => match stage_id {
+            Some(stage_id) => resume::execute(
+                stage_id,
+                action.as_deref().map(ResumeAction::from_str).transpose()?,
+            ),
+            None => pause::resume(),
+        },
+        Commands::Pause { sessions, reason } => pause::pause(sessions, reason),
         Commands::Sessions { command } => match command {
             SessionsCommands::List => sessions::list(),
-            SessionsCommands::Kill { session_ids, stage } => sessions::kill(session_ids, stage),
+            SessionsCommands::Kill {
+                session_ids,
+                stage,
+                all,
+                status,
+            } => {
+                let status = status.map(|s| s.parse()).transpose()?;
+                sessions::kill(session_ids, stage, all, status)
+            }
+            SessionsCommands::Focus { session_id, stage } => sessions::focus(session_id, stage),
+        },
+        Commands::Merge { command } => match command {
+            MergeCommands::Queue => merge::queue(),
+            MergeCommands::Pin { stage_id } => merge::pin(stage_id),
+            MergeCommands::Unpin => merge::unpin(),
+            MergeCommands::Move { stage_id, position } => merge::move_to(stage_id, position),
+            MergeCommands::LockStatus => merge::lock_status(),
+        },
+        Commands::Fact { command } => match command {
+            FactCommands::Set {
+                key,
+                value,
+                description,
+                confidence,
+                stage,
+            } => fact::set(
+                key,
+                value,
+                description,
+                confidence
+                    .as_deref()
+                    .map(FactConfidence::from_str)
+                    .transpose()?,
+                stage,
+            ),
+            FactCommands::List => fact::list(),
+            FactCommands::Export { output, outputs } => fact::export(output, outputs),
+            FactCommands::Import { path } => fact::import(path),
+        },
+        Commands::Signal { command } => match command {
+            SignalCommands::Refresh { session_id } => signal::refresh(session_id),
+            SignalCommands::Preview { stage_id, content } => signal::preview(stage_id, content),
         },
         Commands::Worktree { command } => match command {
             WorktreeCommands::List => worktree_cmd::list(),
             WorktreeCommands::Remove { stage_id } => worktree_cmd::remove(stage_id),
+            WorktreeCommands::Shell { stage_id, command } => worktree_cmd::shell(stage_id, command),
         },
-        Commands::Graph => graph::show(),
+        Commands::Graph { group_by } => {
+            graph::show(group_by.as_deref().map(GroupBy::from_str).transpose()?)
+        }
+        Commands::Handoff {
+            command:
+                Some(HandoffCommands::Create {
+                    stage,
+                    session,
+                    summary,
+                    remaining_tasks,
+                    key_decisions,
+                    yaml,
+                }),
+            ..
+        } => handoff::create::execute_create(
+            stage,
+            session,
+            summary,
+            remaining_tasks,
+            key_decisions,
+            yaml,
+        ),
         Commands::Handoff {
+            command: None,
             stage,
             session,
             trigger,
@@ -66,9 +208,21 @@ pub fn dispatch(command: Commands) -> Result<()> {
             } => stage::reset(stage_id, hard, kill_session),
             StageCommands::Waiting { stage_id } => stage::waiting(stage_id),
             StageCommands::Resume { stage_id } => stage::resume_from_waiting(stage_id),
+            StageCommands::Claim { stage_id, operator } => stage::claim(stage_id, operator),
+            StageCommands::Assign {
+                stage_id,
+                person,
+                clear: _,
+            } => stage::assign(stage_id, person),
             StageCommands::Hold { stage_id } => stage::hold(stage_id),
             StageCommands::Release { stage_id } => stage::release(stage_id),
+            StageCommands::Watch { stage_id, interval } => stage::watch(stage_id, interval),
             StageCommands::Skip { stage_id, reason } => stage::skip(stage_id, reason),
+            StageCommands::Cancel {
+                stage_id,
+                delete_worktree,
+                cascade,
+            } => stage::cancel(stage_id, delete_worktree, cascade),
             StageCommands::Retry {
                 stage_id,
                 force,
@@ -79,9 +233,10 @@ pub fn dispatch(command: Commands) -> Result<()> {
                 stage_id,
                 no_reload,
                 dry_run,
+                json,
             } => {
                 if dry_run {
-                    stage::check_acceptance(stage_id)
+                    stage::check_acceptance(stage_id, json)
                 } else {
                     stage::verify(stage_id, no_reload)
                 }
@@ -92,6 +247,12 @@ pub fn dispatch(command: Commands) -> Result<()> {
                 force_complete,
                 reject,
             } => stage::human_review(stage_id, approve, force_complete, reject),
+            StageCommands::PairReview {
+                stage_id,
+                approve,
+                reject,
+                notes,
+            } => stage::pair_review(stage_id, approve, reject, notes),
             StageCommands::DisputeCriteria {
                 stage_id,
                 criterion_index,
@@ -111,27 +272,50 @@ pub fn dispatch(command: Commands) -> Result<()> {
                     key,
                     value,
                     description,
-                } => stage::output_set(stage_id, key, value, description),
+                    from_json,
+                } => match (from_json, key, value) {
+                    (Some(source), _, _) => stage::output_set_from_json(stage_id, source),
+                    (None, Some(key), Some(value)) => {
+                        stage::output_set(stage_id, key, value, description)
+                    }
+                    _ => anyhow::bail!("<KEY> and <VALUE> are required without --from-json"),
+                },
                 OutputCommands::Get { stage_id, key } => stage::output_get(stage_id, key),
                 OutputCommands::List { stage_id } => stage::output_list(stage_id),
                 OutputCommands::Remove { stage_id, key } => stage::output_remove(stage_id, key),
             },
         },
+        Commands::Learn { command } => match command {
+            LearnCommands::Export {
+                to,
+                files,
+                dry_run,
+                push,
+            } => learn::export(to, files, dry_run, push),
+            LearnCommands::Resolve { id } => learn::resolve(id),
+        },
         Commands::Knowledge { command } => match command {
-            KnowledgeCommands::Show { file } => knowledge::show(file),
-            KnowledgeCommands::Update { file, content } => knowledge::update(file, content),
+            KnowledgeCommands::Show { file, tag, json } => knowledge::show(file, tag, json),
+            KnowledgeCommands::Update {
+                file,
+                content,
+                tags,
+                severity,
+            } => {
+                let severity = severity.map(|s| s.parse()).transpose()?;
+                knowledge::update(file, content, tags, severity)
+            }
             KnowledgeCommands::Init => knowledge::init(),
             KnowledgeCommands::List => knowledge::list(),
             KnowledgeCommands::Check {
                 min_coverage,
                 src_path,
-                quiet,
-            } => knowledge::check::check(min_coverage, src_path, quiet),
+            } => knowledge::check::check(min_coverage, src_path, is_quiet()),
             KnowledgeCommands::Audit {
                 max_file_lines,
                 max_total_lines,
-                quiet,
-            } => knowledge::audit::audit(max_file_lines, max_total_lines, quiet),
+            } => knowledge::audit::audit(max_file_lines, max_total_lines, is_quiet()),
+            KnowledgeCommands::Diff { base, json } => knowledge::diff::diff(base, json),
             KnowledgeCommands::Gc {
                 model,
                 dry_run,
@@ -159,8 +343,34 @@ pub fn dispatch(command: Commands) -> Result<()> {
             MemoryCommands::Question { text, stage } => memory::question(text, stage),
             MemoryCommands::Query { search, stage } => memory::query(search, stage),
             MemoryCommands::List { stage, entry_type } => memory::list(stage, entry_type),
+            MemoryCommands::Delete {
+                index,
+                entry_type,
+                all,
+                stage,
+            } => memory::delete(index, entry_type, all, stage),
+            MemoryCommands::Edit {
+                index,
+                text,
+                context,
+                stage,
+            } => memory::edit(index, text, context, stage),
             MemoryCommands::Show { stage, all } => memory::show(stage, all),
         },
+        Commands::History { command } => match command {
+            HistoryCommands::List { json } => history::list(json),
+            HistoryCommands::Show { run_id, json } => history::show(run_id, json),
+        },
+        Commands::Logs { command } => match command {
+            LogsCommands::Prune { dry_run, keep } => logs::prune(dry_run, keep),
+        },
+        Commands::Report { command } => match command {
+            ReportCommands::Conflicts { json, top } => report::conflicts::conflicts(json, top),
+            ReportCommands::Html { output } => report::html::html(output),
+        },
+        Commands::Verify { command } => match command {
+            VerifyCommands::All { json, accept } => integrity::all(json, accept),
+        },
         Commands::Review { ai_summary } => review::execute(ai_summary),
         Commands::SelfUpdate => self_update::execute(),
         Commands::Clean {
@@ -168,8 +378,10 @@ pub fn dispatch(command: Commands) -> Result<()> {
             worktrees,
             sessions,
             state,
-        } => clean::execute(all, worktrees, sessions, state),
+            stage,
+        } => clean::execute(all, worktrees, sessions, state, stage),
         Commands::Repair { fix } => repair::execute(fix),
+        Commands::Doctor => doctor::execute(),
         Commands::Map {
             deep,
             focus,
@@ -180,6 +392,9 @@ pub fn dispatch(command: Commands) -> Result<()> {
             rounds,
             dry_run,
         } => pressure::execute(plan, rounds, dry_run),
+        Commands::Mcp { command } => match command {
+            McpCommands::Serve => mcp::serve(),
+        },
         Commands::Stop => stop::execute(),
         Commands::Diagnose { stage_id } => diagnose::execute(&stage_id),
         Commands::Plan { command } => match command {
@@ -189,7 +404,34 @@ pub fn dispatch(command: Commands) -> Result<()> {
                 json,
                 no_color,
             } => plan::verify::execute(&path, strict, json, no_color),
+            PlanCommands::Diff {
+                path,
+                json,
+                no_color,
+            } => plan::diff::execute(path, json, no_color),
+            PlanCommands::Suggest {
+                paths,
+                commits,
+                depth,
+                max_stages,
+                title,
+                output,
+                force,
+            } => plan::suggest::execute(plan::suggest::SuggestOptions {
+                paths,
+                commits,
+                depth,
+                max_stages,
+                title,
+                output,
+                force,
+            }),
         },
+        Commands::CiCheck {
+            paths,
+            strict,
+            json,
+        } => ci_check::execute(&paths, strict, json),
         Commands::Check { stage_id, suggest } => verify::execute(&stage_id, suggest),
         Commands::SkillIndex => skill_index::execute(),
         Commands::Completions {
@@ -210,7 +452,7 @@ pub fn dispatch(command: Commands) -> Result<()> {
             }
 
             let shell = shell.ok_or_else(|| {
-                anyhow::anyhow!("Shell argument required. Usage: loom completions <bash|zsh|fish>")
+                anyhow::anyhow!("Shell argument required. Usage: loom completions <bash|zsh|fish|nushell|powershell>")
             })?;
             let shell = Shell::from_str(&shell)?;
             generate_completions(shell);
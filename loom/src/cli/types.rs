@@ -1,7 +1,7 @@
 use clap::{Parser, Subcommand};
-use loom::validation::clap_id_validator;
+use loom::validation::{clap_duration_validator, clap_id_validator};
 
-pub use super::types_memory::{KnowledgeCommands, MemoryCommands};
+pub use super::types_memory::{KnowledgeCommands, LearnCommands, MemoryCommands};
 pub use super::types_stage::{OutputCommands, StageCommands};
 
 const HELP_TEMPLATE: &str = "
@@ -22,6 +22,24 @@ const HELP_TEMPLATE: &str = "
 #[command(help_template = HELP_TEMPLATE)]
 #[command(subcommand_help_heading = "Commands")]
 pub struct Cli {
+    /// Disable self-update and other network access; network paths fail fast
+    #[arg(long, global = true)]
+    pub offline: bool,
+
+    /// Only print results, warnings and errors (no progress output)
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    pub quiet: bool,
+
+    /// Also log debug detail: git commands run and scheduler decisions
+    #[arg(short, long, global = true)]
+    pub verbose: bool,
+
+    /// Operate on this workspace (its .work directory or project root)
+    /// instead of discovering it from the current directory; falls back to
+    /// LOOM_WORK_DIR when the current directory is not in a workspace
+    #[arg(long, global = true, value_name = "PATH")]
+    pub work_dir: Option<std::path::PathBuf>,
+
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -30,13 +48,23 @@ pub struct Cli {
 pub enum Commands {
     /// Initialize .work/ directory from a plan
     Init {
-        /// Path to the plan file
-        plan_path: String,
+        /// Path to the plan file (with --interactive: where to write the new plan)
+        #[arg(required_unless_present = "interactive")]
+        plan_path: Option<String>,
 
         /// Clean up stale resources before initialization
         /// (removes old .work/, prunes worktrees, kills orphaned sessions)
         #[arg(long)]
         clean: bool,
+
+        /// Archive the current plan's run into .work/archive/plans/ instead
+        /// of refusing to replace it
+        #[arg(long, conflicts_with = "clean")]
+        switch_plan: bool,
+
+        /// Build the plan by answering prompts, then optionally initialize with it
+        #[arg(short = 'i', long)]
+        interactive: bool,
     },
 
     /// Run stages from a plan (starts orchestrator in background)
@@ -60,6 +88,44 @@ pub enum Commands {
         /// Disable auto-merge of completed stages (merge is enabled by default)
         #[arg(long)]
         no_merge: bool,
+
+        /// Run only this stage and the stages it depends on, then stop
+        #[arg(long, value_name = "STAGE_ID", value_parser = clap_id_validator)]
+        until: Option<String>,
+
+        /// Start despite uncommitted changes; merges stash and restore them
+        #[arg(long)]
+        allow_dirty: bool,
+
+        /// Keep stage terminal windows open after their agents exit
+        #[arg(long)]
+        keep_terminal: bool,
+
+        /// Restart the daemon if it crashes or stops responding, resuming
+        /// from .work/ state
+        #[arg(long, conflicts_with = "foreground")]
+        supervised: bool,
+
+        /// Stop starting new stages after this long (e.g. 4h, 90m, 1h30m),
+        /// then end the run once running sessions exit
+        #[arg(long, value_name = "DURATION", value_parser = clap_duration_validator)]
+        max_runtime: Option<std::time::Duration>,
+
+        /// At --max-runtime, hand off running stage sessions instead of
+        /// letting them finish
+        #[arg(long, requires = "max_runtime")]
+        handoff_on_timeout: bool,
+
+        /// Set a stage parameter declared in the plan (repeatable); missing
+        /// parameters are asked for, and values are kept for later runs
+        #[arg(long = "param", value_name = "NAME=VALUE")]
+        param: Vec<String>,
+
+        /// Simulate sessions instead of starting agents: each stage
+        /// completes, fails or crashes as the schedule file says (default:
+        /// every stage completes after 2s)
+        #[arg(long, value_name = "SCHEDULE", num_args = 0..=1, conflicts_with = "manual")]
+        simulate: Option<Option<std::path::PathBuf>>,
     },
 
     /// Show dashboard with context health
@@ -72,16 +138,54 @@ pub enum Commands {
         #[arg(short, long)]
         compact: bool,
 
-        /// Verbose mode: show detailed failure information
-        #[arg(short, long)]
-        verbose: bool,
+        /// Explain why a stage isn't running: unmet dependencies, holds,
+        /// gates, parallel limit, retry backoff, merge contention
+        #[arg(long, value_name = "STAGE_ID", value_parser = clap_id_validator)]
+        explain: Option<String>,
+
+        /// Show the last N scheduler decisions from .work/logs/scheduler.jsonl
+        /// (default 20); with --explain, only that stage's
+        #[arg(long, value_name = "N", num_args = 0..=1, default_missing_value = "20")]
+        decisions: Option<usize>,
+
+        /// Group stages with a progress bar per group: by parallel_group
+        /// (parallel) or by dependency level (phase)
+        #[arg(long, value_name = "GROUPING")]
+        group_by: Option<String>,
     },
 
-    /// Resume work on a stage
+    /// Print a one-line summary for a tmux status bar
+    #[command(name = "tmux-status")]
+    TmuxStatus {
+        /// Machine-readable JSON output
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Resume work on a stage, or resume a paused plan when no stage is given
     Resume {
-        /// Stage ID to resume (alphanumeric, dash, underscore only; max 128 characters)
+        /// Stage ID to resume (alphanumeric, dash, underscore only; max 128 characters).
+        /// Omit to resume a plan paused with `loom pause`.
         #[arg(value_parser = clap_id_validator)]
-        stage_id: String,
+        stage_id: Option<String>,
+
+        /// What to do with the stage's handoff: `existing` (keep working in
+        /// the running session), `handoff` (spawn a continuation session
+        /// with the latest handoff) or `discard` (set the handoffs aside and
+        /// spawn a fresh session). Asks when omitted.
+        #[arg(long, value_name = "ACTION", requires = "stage_id")]
+        action: Option<String>,
+    },
+
+    /// Pause the plan: the daemon starts no new stages until `loom resume`
+    Pause {
+        /// Also suspend running sessions (SIGSTOP), continued on resume
+        #[arg(long)]
+        sessions: bool,
+
+        /// Why the plan is paused (shown in status)
+        #[arg(long)]
+        reason: Option<String>,
     },
 
     /// Manage active sessions
@@ -90,6 +194,18 @@ pub enum Commands {
         command: SessionsCommands,
     },
 
+    /// Inspect and reorder the progressive merge queue
+    Merge {
+        #[command(subcommand)]
+        command: MergeCommands,
+    },
+
+    /// Manage session signals
+    Signal {
+        #[command(subcommand)]
+        command: SignalCommands,
+    },
+
     /// Manage git worktrees
     Worktree {
         #[command(subcommand)]
@@ -97,7 +213,12 @@ pub enum Commands {
     },
 
     /// Show the execution graph
-    Graph,
+    Graph {
+        /// Group stages with a progress bar per group: by parallel_group
+        /// (parallel) or by dependency level (phase)
+        #[arg(long, value_name = "GROUPING")]
+        group_by: Option<String>,
+    },
 
     /// Manage individual stages
     Stage {
@@ -106,7 +227,11 @@ pub enum Commands {
     },
 
     /// Create a handoff file capturing current session state
+    #[command(args_conflicts_with_subcommands = true)]
     Handoff {
+        #[command(subcommand)]
+        command: Option<HandoffCommands>,
+
         /// Stage ID (auto-detected from LOOM_STAGE_ID env var if not provided)
         #[arg(long, value_parser = clap_id_validator)]
         stage: Option<String>,
@@ -130,12 +255,48 @@ pub enum Commands {
         command: KnowledgeCommands,
     },
 
+    /// Share curated knowledge with other projects
+    Learn {
+        #[command(subcommand)]
+        command: LearnCommands,
+    },
+
     /// Manage session memory journal (notes, decisions, questions)
     Memory {
         #[command(subcommand)]
         command: MemoryCommands,
     },
 
+    /// Manage plan-wide facts (ports, service names, schema versions)
+    Fact {
+        #[command(subcommand)]
+        command: FactCommands,
+    },
+
+    /// Compare archived runs: durations, retry and conflict rates, token usage
+    History {
+        #[command(subcommand)]
+        command: HistoryCommands,
+    },
+
+    /// Maintain the daemon and session logs under .work/
+    Logs {
+        #[command(subcommand)]
+        command: LogsCommands,
+    },
+
+    /// Check loom's records for tampering or corruption
+    Verify {
+        #[command(subcommand)]
+        command: VerifyCommands,
+    },
+
+    /// Reports aggregated from the plan's recorded state
+    Report {
+        #[command(subcommand)]
+        command: ReportCommands,
+    },
+
     /// Generate code review documents from stage memories
     Review {
         /// Summarize the plan with a headless Claude Haiku call (`claude -p`).
@@ -165,6 +326,16 @@ pub enum Commands {
         /// Remove only .work/ state directory
         #[arg(long)]
         state: bool,
+
+        /// Remove only this stage's worktree, branches, sessions, signals,
+        /// heartbeats and logs, and reset the stage so it runs again
+        #[arg(
+            long,
+            value_name = "STAGE_ID",
+            value_parser = clap_id_validator,
+            conflicts_with_all = ["all", "worktrees", "sessions", "state"]
+        )]
+        stage: Option<String>,
     },
 
     /// Repair loom workspace issues (corrupted .work, missing hooks, sandbox settings, etc.)
@@ -177,6 +348,11 @@ pub enum Commands {
         fix: bool,
     },
 
+    /// Check that git, the terminal, claude and other dependencies are usable
+    ///
+    /// Exits non-zero if orchestration cannot work in this environment.
+    Doctor,
+
     /// Map codebase structure to knowledge files
     Map {
         /// Deep analysis (more thorough, slower)
@@ -207,6 +383,12 @@ pub enum Commands {
         dry_run: bool,
     },
 
+    /// Run a Model Context Protocol server exposing loom state and actions
+    Mcp {
+        #[command(subcommand)]
+        command: McpCommands,
+    },
+
     /// Stop the running daemon
     Stop,
 
@@ -223,6 +405,26 @@ pub enum Commands {
         command: PlanCommands,
     },
 
+    /// Validate every plan in the repository for CI, without side effects
+    ///
+    /// Checks schemas, dependency cycles, referenced working directories and
+    /// globs, and shell syntax of acceptance and setup commands. Reports
+    /// `file:line` errors (GitHub annotations under GitHub Actions) and exits
+    /// non-zero on any error.
+    CiCheck {
+        /// Plan files or directories to check (default: doc/plans/, skipping
+        /// DONE- plans)
+        paths: Vec<std::path::PathBuf>,
+
+        /// Promote warnings to errors
+        #[arg(long)]
+        strict: bool,
+
+        /// Machine-readable JSON output
+        #[arg(long)]
+        json: bool,
+    },
+
     /// Run goal-backward verification for a stage
     ///
     /// Validates OUTCOMES beyond acceptance criteria:
@@ -244,7 +446,7 @@ pub enum Commands {
 
     /// Generate shell completion script
     Completions {
-        /// Shell to generate completions for (bash, zsh, fish).
+        /// Shell to generate completions for (bash, zsh, fish, nushell, powershell).
         /// Auto-detected from $SHELL when using --install without specifying a shell.
         shell: Option<String>,
 
@@ -287,6 +489,167 @@ pub enum PlanCommands {
         #[arg(long)]
         no_color: bool,
     },
+
+    /// Compare a modified plan with the stages in .work/ and report what it
+    /// adds, removes and changes, and which completed stages it invalidates
+    Diff {
+        /// Plan file to compare (default: the active plan)
+        path: Option<std::path::PathBuf>,
+
+        /// Machine-readable JSON output
+        #[arg(long)]
+        json: bool,
+
+        /// Disable ANSI color codes
+        #[arg(long)]
+        no_color: bool,
+    },
+    /// Draft a plan skeleton from the git history of the given paths
+    ///
+    /// Each path becomes a stage covering it, described by the recent commits
+    /// and issues touching it. Paths that change together are chained with a
+    /// dependency.
+    Suggest {
+        /// Paths (relative to the repository root) to draft stages for
+        /// (default: the most frequently changed directories)
+        paths: Vec<String>,
+
+        /// Number of recent commits to analyze
+        #[arg(long, default_value_t = 200)]
+        commits: usize,
+
+        /// Directory depth used to group changes when no paths are given
+        #[arg(long, default_value_t = 2)]
+        depth: usize,
+
+        /// Maximum number of stages drafted when no paths are given
+        #[arg(long, default_value_t = 5)]
+        max_stages: usize,
+
+        /// Plan title
+        #[arg(long)]
+        title: Option<String>,
+
+        /// Output file (default: doc/plans/PLAN-<title>.md)
+        #[arg(short, long)]
+        output: Option<std::path::PathBuf>,
+
+        /// Overwrite the output file if it exists
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum HistoryCommands {
+    /// List archived runs with their efficiency statistics, oldest first
+    List {
+        /// Machine-readable JSON output
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Show one archived run's final stage graph and per-stage statistics
+    Show {
+        /// Run ID (or unique prefix) from `loom history list`
+        run_id: String,
+
+        /// Machine-readable JSON output
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum LogsCommands {
+    /// Rotate oversized logs and delete old rotated copies now
+    Prune {
+        /// Show what would be rotated and removed without changing anything
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Rotated copies to keep per log (default: [logs] keep, or 3)
+        #[arg(long)]
+        keep: Option<usize>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum ReportCommands {
+    /// Files and stage pairs that hit merge conflicts most often
+    Conflicts {
+        /// Machine-readable JSON output
+        #[arg(long)]
+        json: bool,
+
+        /// Number of files and stage pairs to show
+        #[arg(long, default_value_t = 10)]
+        top: usize,
+    },
+
+    /// Standalone HTML report of the run (graph, timeline, diffs,
+    /// verification, conflicts, memory) to share with stakeholders
+    Html {
+        /// Where to write the report (default: .work/reports/<plan-id>-report.html)
+        #[arg(long, value_name = "PATH")]
+        output: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum VerifyCommands {
+    /// Check memory journals, knowledge files and facts against the
+    /// integrity ledger
+    All {
+        /// Machine-readable JSON output
+        #[arg(long)]
+        json: bool,
+
+        /// Trust the current content of every file and record it
+        #[arg(long)]
+        accept: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum HandoffCommands {
+    /// Hand the stage off to a fresh session and end this one
+    ///
+    /// Writes the next numbered handoff from the given summary, remaining
+    /// tasks and key decisions, marks the stage NeedsHandoff, and lets the
+    /// daemon close this session and resume the stage from the handoff.
+    Create {
+        /// Stage ID (auto-detected from LOOM_STAGE_ID env var if not provided)
+        #[arg(long, value_parser = clap_id_validator)]
+        stage: Option<String>,
+
+        /// Session ID (auto-detected from LOOM_SESSION_ID env var if not provided)
+        #[arg(long, value_parser = clap_id_validator)]
+        session: Option<String>,
+
+        /// What this session accomplished
+        #[arg(long)]
+        summary: Option<String>,
+
+        /// A task the next session should pick up (repeatable, in priority order)
+        #[arg(long = "remaining", value_name = "TASK")]
+        remaining_tasks: Vec<String>,
+
+        /// A key decision, optionally with its rationale as "decision: rationale" (repeatable)
+        #[arg(long = "decision", value_name = "DECISION")]
+        key_decisions: Vec<String>,
+
+        /// Read summary, remaining_tasks and key_decisions from a YAML file ("-" for stdin)
+        #[arg(long, value_name = "FILE")]
+        yaml: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum McpCommands {
+    /// Serve MCP over stdio (tools: get_stage, list_stages, list_facts,
+    /// set_output, record_memory, create_checkpoint)
+    Serve,
 }
 
 #[derive(Subcommand)]
@@ -295,14 +658,153 @@ pub enum SessionsCommands {
     List,
 
     /// Kill one or more sessions
+    ///
+    /// Killing a session closes its window, removes its session and signal
+    /// files, and cleans up its PID file, wrapper script and heartbeat.
     Kill {
         /// Session IDs to kill (alphanumeric, dash, underscore only; max 128 characters)
-        #[arg(num_args = 1.., required_unless_present = "stage", value_parser = clap_id_validator)]
+        #[arg(
+            num_args = 1..,
+            required_unless_present_any = ["stage", "all", "status"],
+            value_parser = clap_id_validator
+        )]
         session_ids: Vec<String>,
 
         /// Kill all sessions for a stage
         #[arg(long, conflicts_with = "session_ids", value_parser = clap_id_validator)]
         stage: Option<String>,
+
+        /// Kill every session
+        #[arg(long, conflicts_with_all = ["session_ids", "stage"])]
+        all: bool,
+
+        /// Only kill sessions in this state: crashed (marked crashed, or
+        /// its process is gone) or stale (no heartbeat for 5 minutes)
+        #[arg(long, value_name = "STATUS", conflicts_with = "session_ids")]
+        status: Option<String>,
+    },
+
+    /// Bring a session's terminal window or tab to the front
+    ///
+    /// The target may be a session ID or prefix, a stage ID, or a partial
+    /// match of either; several matches (or no target) open a numbered picker.
+    Focus {
+        /// Session ID, prefix, stage ID or fuzzy pattern to focus
+        #[arg(value_parser = clap_id_validator)]
+        session_id: Option<String>,
+
+        /// Focus the most recent session of a stage
+        #[arg(long, conflicts_with = "session_id", value_parser = clap_id_validator)]
+        stage: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum MergeCommands {
+    /// Show stages awaiting merge, their conflict predictions and overlaps
+    Queue,
+
+    /// Merge a stage first once it has completed
+    ///
+    /// While the pinned stage waits to merge, other stages wait for it, up to
+    /// a timeout. While it is still executing, they merge in completion order.
+    Pin {
+        /// Stage ID to merge next
+        #[arg(value_parser = clap_id_validator)]
+        stage_id: String,
+    },
+
+    /// Clear the pinned stage
+    Unpin,
+
+    /// Move a stage to a position in the merge queue
+    Move {
+        /// Stage ID to move
+        #[arg(value_parser = clap_id_validator)]
+        stage_id: String,
+
+        /// New position (1 = next)
+        position: usize,
+    },
+
+    /// Show who holds the merge lock, since when, and whether it is stale
+    LockStatus,
+}
+
+#[derive(Subcommand)]
+pub enum FactCommands {
+    /// Set a fact embedded in every stage's signal
+    Set {
+        /// Fact key (alphanumeric, underscores, dashes; max 64 characters)
+        key: String,
+
+        /// Fact value (JSON if it parses as JSON, otherwise a string)
+        value: String,
+
+        /// What the fact means
+        #[arg(short, long)]
+        description: Option<String>,
+
+        /// How far the fact can be relied on: low, medium or high
+        /// (default: `[facts] default_confidence`, else medium)
+        #[arg(long, value_name = "LEVEL")]
+        confidence: Option<String>,
+
+        /// Stage the fact comes from (default: the session's stage)
+        #[arg(long, value_name = "STAGE_ID", value_parser = clap_id_validator)]
+        stage: Option<String>,
+    },
+
+    /// List all facts
+    List,
+
+    /// Export facts as portable JSON for a follow-up plan
+    Export {
+        /// File to write (default: stdout)
+        #[arg(short, long)]
+        output: Option<std::path::PathBuf>,
+
+        /// Also export the outputs of completed stages as facts
+        #[arg(long)]
+        outputs: bool,
+    },
+
+    /// Import facts exported from another plan into this plan's .work/
+    ///
+    /// Imported facts replace facts with the same key.
+    Import {
+        /// Export file written by `loom fact export`
+        path: std::path::PathBuf,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum SignalCommands {
+    /// Regenerate the dynamic sections of a running session's signal
+    ///
+    /// Picks up dependency outputs, facts, handoffs and stage memory recorded
+    /// since the session started, keeps the cached prefix unchanged, and
+    /// notifies the session on its next tool call.
+    Refresh {
+        /// Session ID (or unique prefix) whose signal to refresh
+        #[arg(value_parser = clap_id_validator)]
+        session_id: String,
+    },
+
+    /// Preview the signal a stage would get if it started now
+    ///
+    /// Generates the signal without writing it and reports the estimated
+    /// tokens of each section, plus warnings for weak spots in the stage
+    /// definition (no acceptance criteria, empty knowledge, unmet
+    /// dependencies).
+    Preview {
+        /// Stage ID to preview
+        #[arg(value_parser = clap_id_validator)]
+        stage_id: String,
+
+        /// Print the full signal content after the report
+        #[arg(long)]
+        content: bool,
     },
 }
 
@@ -320,4 +822,19 @@ pub enum WorktreeCommands {
         #[arg(value_parser = clap_id_validator)]
         stage_id: String,
     },
+
+    /// Open a shell in a stage's worktree with loom env vars set
+    ///
+    /// Sets LOOM_STAGE_ID, LOOM_SESSION_ID (when the stage has a session),
+    /// LOOM_WORK_DIR and LOOM_WORKTREE_PATH. Pass a command after `--` to run
+    /// it instead of an interactive shell.
+    Shell {
+        /// Stage ID (prefix matching supported)
+        #[arg(value_parser = clap_id_validator)]
+        stage_id: String,
+
+        /// Command to run instead of an interactive shell
+        #[arg(last = true)]
+        command: Vec<String>,
+    },
 }
@@ -11,6 +11,14 @@ pub enum KnowledgeCommands {
         /// File to show (entry-points, patterns, conventions)
         #[arg(value_name = "FILE")]
         file: Option<String>,
+
+        /// Only show entries carrying this tag
+        #[arg(long, value_parser = clap_id_validator)]
+        tag: Option<String>,
+
+        /// Print entries as JSON
+        #[arg(long)]
+        json: bool,
     },
 
     /// Update (append to) a knowledge file
@@ -21,6 +29,15 @@ pub enum KnowledgeCommands {
         /// Content to append (markdown format). Omit or use "-" to read from stdin.
         #[arg(value_parser = clap_knowledge_content_validator)]
         content: Option<String>,
+
+        /// Tag the appended entries (repeatable)
+        #[arg(long = "tag", value_name = "TAG", value_parser = clap_id_validator)]
+        tags: Vec<String>,
+
+        /// Severity of the appended entries: info, warn, critical.
+        /// Unresolved warn and critical mistakes are listed in signals.
+        #[arg(long, value_name = "LEVEL")]
+        severity: Option<String>,
     },
 
     /// Replace a section in a knowledge file by heading
@@ -51,10 +68,6 @@ pub enum KnowledgeCommands {
         /// Path to src/ directory to check (default: auto-detect)
         #[arg(long)]
         src_path: Option<String>,
-
-        /// Quiet mode - only output errors
-        #[arg(short, long)]
-        quiet: bool,
     },
 
     /// Analyze knowledge files for size, duplicates, and curated blocks
@@ -66,10 +79,18 @@ pub enum KnowledgeCommands {
         /// Max total lines before compaction is recommended
         #[arg(long, default_value_t = DEFAULT_MAX_TOTAL_LINES)]
         max_total_lines: usize,
+    },
 
-        /// Only show metrics, skip compaction instructions
-        #[arg(short, long)]
-        quiet: bool,
+    /// Show knowledge entries added during the current plan, by file and stage
+    Diff {
+        /// Compare against the knowledge files at this git ref instead of
+        /// the plan's init time (e.g. main)
+        #[arg(long, value_name = "REF")]
+        base: Option<String>,
+
+        /// Print added entries as JSON
+        #[arg(long)]
+        json: bool,
     },
 
     /// Spawn Claude session to compact knowledge files (dedupe, summarize, drop stale)
@@ -103,6 +124,38 @@ pub enum KnowledgeCommands {
     },
 }
 
+#[derive(Subcommand)]
+pub enum LearnCommands {
+    /// Export knowledge entries to a shared knowledge repo or directory
+    ///
+    /// Entries go to `<path>/<project>/` with a provenance.json; entries
+    /// already exported are skipped. Inside a git repository the export is
+    /// committed.
+    Export {
+        /// Export location (default: [learn] export_path in .work/config.toml)
+        #[arg(long, value_name = "PATH")]
+        to: Option<String>,
+
+        /// Only export this knowledge file (repeatable; default: all)
+        #[arg(long = "file", value_name = "FILE")]
+        files: Vec<String>,
+
+        /// Show what would be exported without writing
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Push the shared repository after committing the export
+        #[arg(long)]
+        push: bool,
+    },
+
+    /// Mark a learning as addressed so signals stop listing it
+    Resolve {
+        /// Entry id, as shown by `loom knowledge show --tag` or `--json`
+        id: String,
+    },
+}
+
 #[derive(Subcommand)]
 pub enum MemoryCommands {
     /// Record a note in the stage memory
@@ -170,6 +223,45 @@ pub enum MemoryCommands {
         entry_type: Option<String>,
     },
 
+    /// Delete entries from a memory journal
+    ///
+    /// Deletes entry #INDEX (as numbered by `loom memory show`), or every
+    /// entry of --type. Pruned entries are no longer recited into signals.
+    Delete {
+        /// Entry number from `loom memory show`
+        #[arg(required_unless_present_any = ["entry_type", "all"], conflicts_with_all = ["entry_type", "all"])]
+        index: Option<usize>,
+
+        /// Delete every entry of this type (note, decision, question, change)
+        #[arg(short = 't', long = "type", conflicts_with = "all")]
+        entry_type: Option<String>,
+
+        /// Delete every entry in the journal
+        #[arg(long)]
+        all: bool,
+
+        /// Stage ID (auto-detected from LOOM_STAGE_ID if not provided)
+        #[arg(short = 'S', long, alias = "session", value_parser = clap_id_validator)]
+        stage: Option<String>,
+    },
+
+    /// Replace the text of a memory entry
+    Edit {
+        /// Entry number from `loom memory show`
+        index: usize,
+
+        /// New entry text
+        text: String,
+
+        /// New context or rationale
+        #[arg(short, long)]
+        context: Option<String>,
+
+        /// Stage ID (auto-detected from LOOM_STAGE_ID if not provided)
+        #[arg(short = 'S', long, alias = "session", value_parser = clap_id_validator)]
+        stage: Option<String>,
+    },
+
     /// Show full memory journal
     Show {
         /// Stage ID (auto-detected if not provided)
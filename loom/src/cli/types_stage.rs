@@ -1,7 +1,7 @@
 //! Stage-related CLI command types
 
 use clap::Subcommand;
-use loom::validation::{clap_description_validator, clap_id_validator};
+use loom::validation::{clap_assignee_validator, clap_description_validator, clap_id_validator};
 
 #[derive(Subcommand)]
 pub enum StageCommands {
@@ -71,6 +71,36 @@ pub enum StageCommands {
         stage_id: String,
     },
 
+    /// Claim a ready stage for human-driven execution (no agent spawned)
+    Claim {
+        /// Stage ID (alphanumeric, dash, underscore only; max 128 characters)
+        #[arg(value_parser = clap_id_validator)]
+        stage_id: String,
+
+        /// Person claiming the stage (default: $LOOM_OPERATOR, then $USER);
+        /// recorded as its assignee
+        #[arg(long = "as", value_name = "PERSON", value_parser = clap_assignee_validator)]
+        operator: Option<String>,
+    },
+
+    /// Assign a stage to a person (shown in status; routes its notifications)
+    Assign {
+        /// Stage ID (alphanumeric, dash, underscore only; max 128 characters)
+        #[arg(value_parser = clap_id_validator)]
+        stage_id: String,
+
+        /// Person responsible for the stage
+        #[arg(
+            required_unless_present = "clear",
+            value_parser = clap_assignee_validator
+        )]
+        person: Option<String>,
+
+        /// Remove the stage's assignee
+        #[arg(long, conflicts_with = "person")]
+        clear: bool,
+    },
+
     /// Hold a stage (prevent auto-execution even when ready)
     Hold {
         /// Stage ID (alphanumeric, dash, underscore only; max 128 characters)
@@ -85,6 +115,22 @@ pub enum StageCommands {
         stage_id: String,
     },
 
+    /// Follow one stage in the foreground until it finishes
+    ///
+    /// Prints status transitions, heartbeats, checkpoints and verification
+    /// results, and tails the session log of headless sessions. Exits 0 when
+    /// the stage completes or is skipped, non-zero when it stops needing
+    /// attention (blocked, merge conflict, human review).
+    Watch {
+        /// Stage ID (alphanumeric, dash, underscore only; max 128 characters)
+        #[arg(value_parser = clap_id_validator)]
+        stage_id: String,
+
+        /// Seconds between polls
+        #[arg(long, default_value_t = 2)]
+        interval: u64,
+    },
+
     /// Skip a stage (dependents will remain blocked)
     Skip {
         /// Stage ID (alphanumeric, dash, underscore only; max 128 characters)
@@ -96,6 +142,23 @@ pub enum StageCommands {
         reason: Option<String>,
     },
 
+    /// Cancel a stage: kill its session and mark it skipped as "cancelled"
+    ///
+    /// Dependents can no longer run; --cascade cancels them too.
+    Cancel {
+        /// Stage ID (alphanumeric, dash, underscore only; max 128 characters)
+        #[arg(value_parser = clap_id_validator)]
+        stage_id: String,
+
+        /// Also remove the stage's worktree and delete its branch
+        #[arg(long)]
+        delete_worktree: bool,
+
+        /// Cancel every unfinished dependent as well
+        #[arg(long)]
+        cascade: bool,
+    },
+
     /// Retry a failed, crashed, or hung stage
     ///
     /// Generates a recovery signal with context when the stage was crashed or
@@ -149,6 +212,11 @@ pub enum StageCommands {
         /// Check criteria without changing stage status (shows detailed results)
         #[arg(long)]
         dry_run: bool,
+
+        /// With --dry-run: print the results as JSON (name, category, outcome
+        /// and output of each criterion, counts per category)
+        #[arg(long, requires = "dry_run")]
+        json: bool,
     },
 
     /// Respond to a stage flagged for human review
@@ -173,6 +241,28 @@ pub enum StageCommands {
         reject: Option<String>,
     },
 
+    /// Record the pair reviewer's verdict on a `pair: true` stage (experimental).
+    ///
+    /// The stage can only complete once its latest commit is approved.
+    /// Without a verdict, shows the current review state.
+    PairReview {
+        /// Stage ID (alphanumeric, dash, underscore only; max 128 characters)
+        #[arg(value_parser = clap_id_validator)]
+        stage_id: String,
+
+        /// Approve the work at the worktree's current commit
+        #[arg(long, conflicts_with = "reject")]
+        approve: bool,
+
+        /// Request changes (requires --notes)
+        #[arg(long)]
+        reject: bool,
+
+        /// What the implementer should change, or review remarks (max 500 characters)
+        #[arg(long, value_parser = clap_description_validator)]
+        notes: Option<String>,
+    },
+
     /// Dispute an acceptance criterion. Files a structured dispute via the
     /// daemon RPC; the daemon writes request.md and transitions the stage
     /// to NeedsAdjudication. Verdict.md is written by the adjudicator
@@ -208,22 +298,32 @@ pub enum StageCommands {
 
 #[derive(Subcommand)]
 pub enum OutputCommands {
-    /// Set an output value for a stage
+    /// Set an output value for a stage, or many at once with --from-json
     Set {
         /// Stage ID (alphanumeric, dash, underscore only; max 128 characters)
         #[arg(value_parser = clap_id_validator)]
         stage_id: String,
 
         /// Output key (alphanumeric, dash, underscore only; max 64 characters)
-        #[arg(value_parser = clap_id_validator)]
-        key: String,
+        #[arg(value_parser = clap_id_validator, required_unless_present = "from_json")]
+        key: Option<String>,
 
         /// Output value (JSON or plain string)
-        value: String,
+        #[arg(required_unless_present = "from_json")]
+        value: Option<String>,
 
         /// Description of the output
         #[arg(short, long, value_parser = clap_description_validator)]
         description: Option<String>,
+
+        /// Set every key of a JSON object read from a file, or `-` for stdin.
+        /// All values are checked against the declared outputs before any is set.
+        #[arg(
+            long,
+            value_name = "PATH",
+            conflicts_with_all = ["key", "value", "description"]
+        )]
+        from_json: Option<String>,
     },
 
     /// Get a specific output value
@@ -0,0 +1,631 @@
+//! loom ci-check — validate every plan in a repository, for CI.
+//!
+//! Read-only: nothing is written and no `.work/` or daemon is needed. Each
+//! plan is parsed and schema-validated, its dependency graph is checked for
+//! cycles, the working directories and `files`/`artifacts` globs it
+//! references are checked against the tree, and every shell command it
+//! declares is syntax-checked with `sh -n` (parsed, never run).
+//!
+//! Problems are printed as `file:line: error: message`, or as workflow
+//! commands (`::error file=...,line=...::`) when `GITHUB_ACTIONS` is set,
+//! so they show up as annotations on the pull request.
+
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+use serde::Serialize;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use crate::fs::plan_lifecycle::DONE_PREFIX;
+use crate::plan::graph::ExecutionGraph;
+use crate::plan::parser::extract_yaml_metadata_with_ranges;
+use crate::plan::schema::{
+    check_sandbox_recommendations, check_working_dirs, validate, validate_structural_preflight,
+    LoomMetadata, StageDefinition,
+};
+
+const METADATA_MARKER: &str = "<!-- loom METADATA";
+const PLANS_DIR: &str = "doc/plans";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+/// One problem found in a plan
+#[derive(Debug, Clone, Serialize)]
+pub struct Diagnostic {
+    /// Plan path, relative to the repository root where possible
+    pub file: String,
+    /// 1-based line in the plan file, when the problem can be located
+    pub line: Option<usize>,
+    pub severity: Severity,
+    pub stage_id: Option<String>,
+    pub message: String,
+}
+
+#[derive(Serialize)]
+struct JsonOutput<'a> {
+    plans: Vec<String>,
+    valid: bool,
+    errors: usize,
+    warnings: usize,
+    diagnostics: &'a [Diagnostic],
+}
+
+/// Line lookup within a plan's YAML block
+struct PlanLines<'a> {
+    /// Lines of the YAML block
+    yaml: Vec<&'a str>,
+    /// File line number of the first YAML line, minus one
+    offset: usize,
+    /// YAML line index where each stage definition starts, in plan order
+    stages: Vec<(String, usize)>,
+}
+
+impl<'a> PlanLines<'a> {
+    fn new(content: &'a str, yaml: &'a str, fence_start: usize) -> Self {
+        let yaml_start = content[fence_start..]
+            .find(yaml)
+            .map_or(fence_start, |i| fence_start + i);
+        let offset = content[..yaml_start].matches('\n').count();
+        let yaml: Vec<&str> = yaml.lines().collect();
+        let stages = yaml
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, line)| {
+                let value = line.trim_start().strip_prefix("- id:")?;
+                let id = value.trim().trim_matches(|c| c == '"' || c == '\'');
+                Some((id.to_string(), idx))
+            })
+            .collect();
+        Self {
+            yaml,
+            offset,
+            stages,
+        }
+    }
+
+    /// File line of a YAML line index
+    fn file_line(&self, yaml_idx: usize) -> usize {
+        self.offset + yaml_idx + 1
+    }
+
+    /// File line where `stage_id` is defined
+    fn stage(&self, stage_id: &str) -> Option<usize> {
+        self.stages
+            .iter()
+            .find(|(id, _)| id == stage_id)
+            .map(|(_, idx)| self.file_line(*idx))
+    }
+
+    /// File line of the first line mentioning `needle` inside the stage's
+    /// definition, falling back to the stage's own line.
+    fn within_stage(&self, stage_id: &str, needle: &str) -> Option<usize> {
+        let pos = self.stages.iter().position(|(id, _)| id == stage_id)?;
+        let start = self.stages[pos].1;
+        let end = self
+            .stages
+            .get(pos + 1)
+            .map_or(self.yaml.len(), |(_, idx)| *idx);
+        let needle = needle.lines().next().unwrap_or(needle).trim();
+        let found = (start..end).find(|&idx| !needle.is_empty() && self.yaml[idx].contains(needle));
+        Some(self.file_line(found.unwrap_or(start)))
+    }
+}
+
+/// Collects the diagnostics of one plan
+struct PlanReport<'a> {
+    file: &'a str,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl PlanReport<'_> {
+    fn push(
+        &mut self,
+        severity: Severity,
+        line: Option<usize>,
+        stage_id: Option<&str>,
+        message: impl Into<String>,
+    ) {
+        self.diagnostics.push(Diagnostic {
+            file: self.file.to_string(),
+            line,
+            severity,
+            stage_id: stage_id.map(String::from),
+            message: message.into(),
+        });
+    }
+
+    /// Push a warning produced by the plan validators, which name their
+    /// stage as a `Stage '<id>': ` prefix.
+    fn push_warning(&mut self, lines: &PlanLines<'_>, warning: String) {
+        let stage_id = warning
+            .strip_prefix("Stage '")
+            .and_then(|rest| rest.split_once('\''))
+            .map(|(id, _)| id.to_string());
+        let line = stage_id.as_deref().and_then(|id| lines.stage(id));
+        self.push(Severity::Warning, line, stage_id.as_deref(), warning);
+    }
+}
+
+/// Find the plans to check.
+///
+/// With no paths, every plan under `doc/plans/` is checked except `DONE-`
+/// plans, which will not run again. Directories are searched recursively for
+/// markdown files with a loom METADATA block; files named explicitly are
+/// always checked.
+pub fn discover_plans(repo_root: &Path, paths: &[PathBuf]) -> Result<Vec<PathBuf>> {
+    let mut plans = Vec::new();
+    if paths.is_empty() {
+        let dir = repo_root.join(PLANS_DIR);
+        if dir.is_dir() {
+            collect_plans(&dir, &mut plans)?;
+        }
+    } else {
+        for path in paths {
+            if path.is_dir() {
+                collect_plans(path, &mut plans)?;
+            } else {
+                plans.push(path.clone());
+            }
+        }
+    }
+    plans.sort();
+    plans.dedup();
+    Ok(plans)
+}
+
+fn collect_plans(dir: &Path, plans: &mut Vec<PathBuf>) -> Result<()> {
+    for entry in fs::read_dir(dir).with_context(|| format!("Failed to read {}", dir.display()))? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_plans(&path, plans)?;
+            continue;
+        }
+        let is_markdown = path.extension().and_then(|e| e.to_str()) == Some("md");
+        let is_done = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .is_some_and(|n| n.starts_with(DONE_PREFIX));
+        if !is_markdown || is_done {
+            continue;
+        }
+        if fs::read_to_string(&path).is_ok_and(|c| c.contains(METADATA_MARKER)) {
+            plans.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Check one plan. `file` is the path used in diagnostics.
+pub fn check_plan(path: &Path, file: &str, repo_root: &Path) -> Vec<Diagnostic> {
+    let mut report = PlanReport {
+        file,
+        diagnostics: Vec::new(),
+    };
+
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) => {
+            report.push(Severity::Error, None, None, format!("Failed to read: {e}"));
+            return report.diagnostics;
+        }
+    };
+    let extracted = match extract_yaml_metadata_with_ranges(&content) {
+        Ok(extracted) => extracted,
+        Err(e) => {
+            report.push(Severity::Error, Some(1), None, e.to_string());
+            return report.diagnostics;
+        }
+    };
+    let lines = PlanLines::new(&content, &extracted.yaml, extracted.yaml_fence_range.start);
+
+    let metadata: LoomMetadata = match serde_yaml::from_str(&extracted.yaml) {
+        Ok(metadata) => metadata,
+        Err(e) => {
+            let line = e
+                .location()
+                .map(|l| lines.offset + l.line())
+                .or(Some(lines.file_line(0)));
+            report.push(
+                Severity::Error,
+                line,
+                None,
+                format!("YAML parse error: {e}"),
+            );
+            return report.diagnostics;
+        }
+    };
+    let stages = &metadata.loom.stages;
+
+    if let Err(errors) = validate(&metadata) {
+        for e in errors {
+            let line = e.stage_id.as_deref().and_then(|id| lines.stage(id));
+            report.push(Severity::Error, line, e.stage_id.as_deref(), e.message);
+        }
+        return report.diagnostics;
+    }
+    if let Err(e) = ExecutionGraph::build(stages.clone()) {
+        report.push(Severity::Error, None, None, e.to_string());
+    }
+
+    match check_working_dirs(stages, repo_root) {
+        Ok(warnings) => {
+            for warning in warnings {
+                report.push_warning(&lines, warning);
+            }
+        }
+        Err(errors) => {
+            for e in errors {
+                let line = e
+                    .stage_id
+                    .as_deref()
+                    .and_then(|id| lines.within_stage(id, "working_dir"));
+                report.push(Severity::Error, line, e.stage_id.as_deref(), e.message);
+            }
+        }
+    }
+
+    for stage in stages {
+        check_references(stage, repo_root, &lines, &mut report);
+        check_commands(stage, &lines, &mut report);
+    }
+
+    for warning in validate_structural_preflight(stages, Some(repo_root)) {
+        report.push_warning(&lines, warning);
+    }
+    for warning in check_sandbox_recommendations(&metadata) {
+        report.push_warning(&lines, warning);
+    }
+
+    report.diagnostics
+}
+
+/// Check the `files` and `artifacts` globs of a stage. A malformed pattern is
+/// an error; a `files` pattern matching nothing is a warning, since the stage
+/// may create the file (none for entries listed in `new_files`). Artifacts
+/// are produced by the stage, so only their syntax is checked.
+fn check_references(
+    stage: &StageDefinition,
+    repo_root: &Path,
+    lines: &PlanLines<'_>,
+    report: &mut PlanReport<'_>,
+) {
+    let id = stage.id.as_str();
+    for pattern in stage.files.iter().chain(&stage.artifacts) {
+        if let Err(e) = glob::Pattern::new(pattern) {
+            report.push(
+                Severity::Error,
+                lines.within_stage(id, pattern),
+                Some(id),
+                format!("invalid glob '{pattern}': {e}"),
+            );
+        }
+    }
+    for pattern in stage.files.iter().filter(|p| !stage.new_files.contains(p)) {
+        let full = repo_root.join(pattern).to_string_lossy().to_string();
+        let Ok(mut matches) = glob::glob(&full) else {
+            continue;
+        };
+        if matches.next().is_none() {
+            report.push(
+                Severity::Warning,
+                lines.within_stage(id, pattern),
+                Some(id),
+                format!("files entry '{pattern}' matches nothing in the repository"),
+            );
+        }
+    }
+}
+
+/// Syntax-check every shell command a stage declares.
+fn check_commands(stage: &StageDefinition, lines: &PlanLines<'_>, report: &mut PlanReport<'_>) {
+    let mut commands: Vec<(&str, &str)> = Vec::new();
+    commands.extend(stage.acceptance.iter().map(|c| ("acceptance", c.command())));
+    commands.extend(stage.setup.iter().map(|c| ("setup", c.as_str())));
+    commands.extend(
+        stage
+            .worktree_setup
+            .iter()
+            .map(|c| ("worktree_setup", c.as_str())),
+    );
+    commands.extend(
+        stage
+            .before_stage
+            .iter()
+            .map(|c| ("before_stage", c.command.as_str())),
+    );
+    commands.extend(
+        stage
+            .after_stage
+            .iter()
+            .map(|c| ("after_stage", c.command.as_str())),
+    );
+    commands.extend(
+        stage
+            .wiring_tests
+            .iter()
+            .map(|t| ("wiring_tests", t.command.as_str())),
+    );
+    if let Some(check) = &stage.dead_code_check {
+        commands.push(("dead_code_check", check.command.as_str()));
+    }
+
+    for (field, command) in commands {
+        if let Some(error) = shell_syntax_error(command) {
+            report.push(
+                Severity::Error,
+                lines.within_stage(&stage.id, command),
+                Some(&stage.id),
+                format!("{field} command '{command}' is not valid shell: {error}"),
+            );
+        }
+    }
+}
+
+/// Parse `command` with `sh -n` without running it; returns the shell's
+/// complaint if it does not parse.
+pub fn shell_syntax_error(command: &str) -> Option<String> {
+    let output = Command::new("sh")
+        .args(["-n", "-c", command])
+        .stdin(Stdio::null())
+        .output()
+        .ok()?;
+    if output.status.success() {
+        return None;
+    }
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let message = stderr
+        .lines()
+        .rfind(|l| !l.trim().is_empty())
+        .unwrap_or("syntax error")
+        .trim();
+    // Drop the `sh: 1: ` / `sh: -c: line 1: ` prefix the shell adds
+    let message = message
+        .strip_prefix("sh: ")
+        .map(|rest| rest.trim_start_matches(|c: char| c != ' ' && c != ':'))
+        .map_or(message, |rest| rest.trim_start_matches([':', ' ']));
+    Some(message.to_string())
+}
+
+/// Escape a workflow-command message (`%`, `\r`, `\n`).
+fn escape_workflow(message: &str) -> String {
+    message
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+fn format_diagnostic(d: &Diagnostic, github: bool) -> String {
+    let message = match &d.stage_id {
+        Some(id) if !d.message.contains(&format!("'{id}'")) => format!("[{id}] {}", d.message),
+        _ => d.message.clone(),
+    };
+    let level = match d.severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+    };
+    if github {
+        let line = d.line.map(|l| format!(",line={l}")).unwrap_or_default();
+        return format!(
+            "::{level} file={}{line},title=loom ci-check::{}",
+            d.file,
+            escape_workflow(&message)
+        );
+    }
+    let location = match d.line {
+        Some(line) => format!("{}:{line}", d.file),
+        None => d.file.clone(),
+    };
+    let level = match d.severity {
+        Severity::Error => level.red().bold(),
+        Severity::Warning => level.yellow().bold(),
+    };
+    format!("{}: {level}: {message}", location.bold())
+}
+
+/// Walk up from `dir` to the directory containing `.git`.
+fn find_repo_root(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors()
+        .find(|d| d.join(".git").exists())
+        .map(Path::to_path_buf)
+}
+
+pub fn execute(paths: &[PathBuf], strict: bool, json: bool) -> Result<()> {
+    let cwd = std::env::current_dir()?;
+    let repo_root = find_repo_root(&cwd).unwrap_or_else(|| cwd.clone());
+    let plans = discover_plans(&repo_root, paths)?;
+
+    let mut diagnostics = Vec::new();
+    let mut files = Vec::new();
+    for plan in &plans {
+        let absolute = cwd.join(plan);
+        let file = absolute
+            .strip_prefix(&repo_root)
+            .unwrap_or(plan)
+            .to_string_lossy()
+            .to_string();
+        diagnostics.extend(check_plan(plan, &file, &repo_root));
+        files.push(file);
+    }
+
+    let errors = diagnostics
+        .iter()
+        .filter(|d| d.severity == Severity::Error)
+        .count();
+    let warnings = diagnostics.len() - errors;
+    let failed = errors > 0 || (strict && warnings > 0);
+
+    if json {
+        let output = JsonOutput {
+            plans: files,
+            valid: errors == 0,
+            errors,
+            warnings,
+            diagnostics: &diagnostics,
+        };
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        let _ = std::io::stdout().flush();
+        std::process::exit(if failed { 1 } else { 0 });
+    }
+
+    if plans.is_empty() {
+        println!("No plans found under {PLANS_DIR}/");
+        return Ok(());
+    }
+    let github = std::env::var("GITHUB_ACTIONS").is_ok_and(|v| v == "true");
+    for d in &diagnostics {
+        println!("{}", format_diagnostic(d, github));
+    }
+
+    let summary = format!(
+        "Checked {} plan(s): {errors} error(s), {warnings} warning(s)",
+        plans.len()
+    );
+    if failed {
+        println!("{}", summary.red());
+        bail!("Plan check failed ({errors} error(s), {warnings} warning(s))");
+    } else if warnings > 0 {
+        println!("{}", summary.yellow());
+    } else {
+        println!("{}", summary.green());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn plan(stages: &str) -> String {
+        format!(
+            "# Plan\n\nIntro.\n\n<!-- loom METADATA -->\n\n```yaml\nloom:\n  version: 1\n  stages:\n{stages}```\n\n<!-- END loom METADATA -->\n"
+        )
+    }
+
+    #[test]
+    fn test_check_plan_locates_problems() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(root.join("src/lib.rs"), "").unwrap();
+        let content = plan(concat!(
+            "    - id: api\n",
+            "      name: API\n",
+            "      working_dir: \".\"\n",
+            "      files:\n",
+            "        - \"src/*.rs\"\n",
+            "        - \"web/**/*.ts\"\n",
+            "      acceptance:\n",
+            "        - \"cargo test\"\n",
+            "        - \"if true; then echo\"\n",
+            "    - id: web\n",
+            "      name: Web\n",
+            "      working_dir: missing\n",
+            "      acceptance: [\"true\"]\n",
+        ));
+        fs::write(root.join("plan.md"), &content).unwrap();
+
+        let diagnostics = check_plan(&root.join("plan.md"), "plan.md", root);
+        let find = |needle: &str| {
+            diagnostics
+                .iter()
+                .find(|d| d.message.contains(needle))
+                .unwrap_or_else(|| panic!("no '{needle}' in {diagnostics:#?}"))
+        };
+
+        let shell = find("is not valid shell");
+        assert_eq!(shell.severity, Severity::Error);
+        assert_eq!(shell.line, Some(19));
+        assert_eq!(shell.stage_id.as_deref(), Some("api"));
+
+        let files = find("'web/**/*.ts' matches nothing");
+        assert_eq!(files.severity, Severity::Warning);
+        assert_eq!(files.line, Some(16));
+        assert!(!diagnostics.iter().any(|d| d.message.contains("src/*.rs")));
+
+        let working_dir = find("working_dir 'missing' does not exist");
+        assert_eq!(working_dir.severity, Severity::Error);
+        assert_eq!(working_dir.line, Some(22));
+    }
+
+    #[test]
+    fn test_check_plan_reports_yaml_and_schema_errors() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+
+        fs::write(root.join("bad.md"), plan("    - id: [unbalanced\n")).unwrap();
+        let diagnostics = check_plan(&root.join("bad.md"), "bad.md", root);
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].message.starts_with("YAML parse error"));
+        assert_eq!(diagnostics[0].line, Some(11));
+
+        let content = plan(concat!(
+            "    - id: api\n",
+            "      name: API\n",
+            "      working_dir: \".\"\n",
+            "      dependencies: [nope]\n",
+        ));
+        fs::write(root.join("deps.md"), content).unwrap();
+        let diagnostics = check_plan(&root.join("deps.md"), "deps.md", root);
+        assert!(diagnostics
+            .iter()
+            .any(|d| d.severity == Severity::Error && d.line == Some(11)));
+    }
+
+    #[test]
+    fn test_discover_plans() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        let plans = root.join(PLANS_DIR);
+        fs::create_dir_all(plans.join("nested")).unwrap();
+        let content = plan("    - id: a\n      name: A\n      working_dir: \".\"\n");
+        fs::write(plans.join("PLAN-a.md"), &content).unwrap();
+        fs::write(plans.join("nested/PLAN-b.md"), &content).unwrap();
+        fs::write(plans.join("DONE-PLAN-c.md"), &content).unwrap();
+        fs::write(plans.join("README.md"), "# Plans\n").unwrap();
+
+        let found = discover_plans(root, &[]).unwrap();
+        assert_eq!(
+            found,
+            vec![plans.join("PLAN-a.md"), plans.join("nested/PLAN-b.md")]
+        );
+
+        let done = plans.join("DONE-PLAN-c.md");
+        assert_eq!(
+            discover_plans(root, std::slice::from_ref(&done)).unwrap(),
+            vec![done]
+        );
+    }
+
+    #[test]
+    fn test_shell_syntax_error() {
+        assert_eq!(
+            shell_syntax_error("cargo test && echo {{deps.api.port}}"),
+            None
+        );
+        assert!(shell_syntax_error("if true; then echo").is_some());
+        assert!(shell_syntax_error("echo 'unterminated").is_some());
+    }
+
+    #[test]
+    fn test_format_diagnostic_for_github() {
+        let d = Diagnostic {
+            file: "doc/plans/PLAN-x.md".to_string(),
+            line: Some(12),
+            severity: Severity::Error,
+            stage_id: Some("api".to_string()),
+            message: "100% broken\nsecond line".to_string(),
+        };
+        assert_eq!(
+            format_diagnostic(&d, true),
+            "::error file=doc/plans/PLAN-x.md,line=12,title=loom ci-check::[api] 100%25 broken%0Asecond line"
+        );
+    }
+}
@@ -1,5 +1,5 @@
 //! Clean command for loom resource cleanup
-//! Usage: loom clean [--all] [--worktrees] [--sessions] [--state]
+//! Usage: loom clean [--all] [--worktrees] [--sessions] [--state] [--stage <id>]
 
 use anyhow::{Context, Result};
 use colored::Colorize;
@@ -7,10 +7,24 @@ use std::fs;
 use std::io::{IsTerminal, Write};
 use std::path::Path;
 
-use crate::git::branch::{commits_ahead_of, list_loom_branches, resolve_target_branch};
+use crate::commands::stage::kill_stage_session;
+use crate::daemon::DaemonServer;
+use crate::fs::run_history::{stash_run_history, RUN_HISTORY_STASH_DIR};
+use crate::fs::session_files::find_sessions_for_stage;
+use crate::git::branch::{
+    base_branch_name_for_stage, branch_name_for_stage, commits_ahead_of, list_loom_branches,
+    resolve_target_branch,
+};
 use crate::git::cleanup::{
-    cleanup_all_base_branches, cleanup_multiple_stages, prune_worktrees, CleanupConfig,
+    cleanup_after_merge, cleanup_all_base_branches, cleanup_multiple_stages, prune_worktrees,
+    CleanupConfig,
 };
+use crate::git::worktree::{uses_default_worktrees_root, worktrees_root};
+use crate::models::stage::{Stage, StageStatus};
+use crate::orchestrator::monitor::heartbeat::{heartbeat_history_path, heartbeat_path};
+use crate::orchestrator::terminal::native::{pids_dir, wrappers_dir};
+use crate::verify::transitions::{load_stage, save_stage};
+use crate::verify::worktree_setup::{worktree_setup_log_path, worktree_setup_marker_path};
 
 /// Statistics for cleanup operations
 #[derive(Default)]
@@ -28,6 +42,7 @@ struct CleanStats {
 /// * `worktrees` - Remove only worktrees
 /// * `sessions` - Kill only sessions
 /// * `state` - Remove only .work/ state directory
+/// * `stage` - Remove only this stage's resources and reset it
 ///
 /// Bare `loom clean` (no flags) is intentionally NON-destructive: it only
 /// prunes stale git worktree references and prints help. The destructive path
@@ -35,12 +50,22 @@ struct CleanStats {
 /// flag — `--all`, `--worktrees`, or `--state`. Before any `loom/*` branch with
 /// unmerged commits is deleted, the user is shown the commits-ahead counts and
 /// asked to confirm (skip the prompt with `LOOM_CLEAN_YES=1`).
-pub fn execute(all: bool, worktrees: bool, sessions: bool, state: bool) -> Result<()> {
+pub fn execute(
+    all: bool,
+    worktrees: bool,
+    sessions: bool,
+    state: bool,
+    stage: Option<String>,
+) -> Result<()> {
     let repo_root = std::env::current_dir()?;
 
     // Print header
     print_header();
 
+    if let Some(stage_id) = stage {
+        return clean_stage(&repo_root, &stage_id);
+    }
+
     // Bare invocation with no flags: do NOT treat as --all. Prune-only + help.
     if !all && !worktrees && !sessions && !state {
         return run_bare_clean(&repo_root);
@@ -51,7 +76,7 @@ pub fn execute(all: bool, worktrees: bool, sessions: bool, state: bool) -> Resul
     // If we are about to delete worktree branches, surface any unmerged work and
     // require confirmation. This guards a user who typed `loom clean --all`
     // mid-plan from silently losing committed-but-unmerged branches.
-    if (clean_all || worktrees) && !confirm_branch_deletion(&repo_root)? {
+    if (clean_all || worktrees) && !confirm_branch_deletion(&repo_root, None)? {
         println!();
         println!("{} Aborted — nothing was deleted.", "✗".red().bold());
         return Ok(());
@@ -143,7 +168,9 @@ fn run_bare_clean(repo_root: &Path) -> Result<()> {
 ///
 /// Returns `Ok(false)` to abort (user declined, or non-interactive stdin with
 /// unmerged work and no `LOOM_CLEAN_YES`).
-fn confirm_branch_deletion(repo_root: &Path) -> Result<bool> {
+///
+/// With `only_stage`, only that stage's branch is considered.
+fn confirm_branch_deletion(repo_root: &Path, only_stage: Option<&str>) -> Result<bool> {
     // Resolve the merge target so commits-ahead is measured against the right base.
     let work_dir = repo_root.join(".work");
     let config_branch = crate::fs::work_dir::load_config(&work_dir)
@@ -153,7 +180,11 @@ fn confirm_branch_deletion(repo_root: &Path) -> Result<bool> {
     let target_branch = resolve_target_branch(&config_branch, repo_root);
 
     // Collect loom/* branches with unmerged commits (commits ahead of target).
-    let branches = list_loom_branches(repo_root).unwrap_or_default();
+    let mut branches = list_loom_branches(repo_root).unwrap_or_default();
+    if let Some(stage_id) = only_stage {
+        let stage_branch = branch_name_for_stage(stage_id);
+        branches.retain(|branch| *branch == stage_branch);
+    }
     let mut unmerged: Vec<(String, usize)> = Vec::new();
     for branch in &branches {
         // Fail closed: a git error here counts as "has unmerged work" so we warn
@@ -275,7 +306,13 @@ fn print_summary(stats: &CleanStats) {
 ///
 /// Returns (worktrees_removed, branches_removed) counts
 fn clean_worktrees(repo_root: &Path) -> Result<(usize, usize)> {
-    let worktrees_dir = repo_root.join(".worktrees");
+    let worktrees_dir = worktrees_root(repo_root);
+    let in_repo = uses_default_worktrees_root(repo_root);
+    let label = if in_repo {
+        ".worktrees/".to_string()
+    } else {
+        format!("{}/", worktrees_dir.display())
+    };
 
     // First, always prune stale git worktrees
     match prune_worktrees(repo_root) {
@@ -292,11 +329,11 @@ fn clean_worktrees(repo_root: &Path) -> Result<(usize, usize)> {
     }
 
     if !worktrees_dir.exists() {
-        println!("  {} No {} directory", "─".dimmed(), ".worktrees/".dimmed());
+        println!("  {} No {} directory", "─".dimmed(), label.dimmed());
         return Ok((0, 0));
     }
 
-    // Collect all stage IDs from .worktrees/ directory
+    // Collect all stage IDs from the worktrees directory
     let mut stage_ids = Vec::new();
     if let Ok(entries) = fs::read_dir(&worktrees_dir) {
         for entry in entries.flatten() {
@@ -331,7 +368,7 @@ fn clean_worktrees(repo_root: &Path) -> Result<(usize, usize)> {
             println!(
                 "  {} Deleted branch: {}",
                 "✓".green().bold(),
-                format!("loom/{stage_id}").dimmed()
+                branch_name_for_stage(&stage_id).dimmed()
             );
             branches_removed += 1;
         }
@@ -367,19 +404,22 @@ fn clean_worktrees(repo_root: &Path) -> Result<(usize, usize)> {
         }
     }
 
-    // Remove the .worktrees directory itself if it still exists
+    // Remove the worktrees directory itself if it still exists. A configured
+    // root outside the repo may be shared, so it is only removed when empty.
     if worktrees_dir.exists() {
-        fs::remove_dir_all(&worktrees_dir).with_context(|| {
+        let removed = if in_repo {
+            fs::remove_dir_all(&worktrees_dir).map(|_| true)
+        } else {
+            Ok(fs::remove_dir(&worktrees_dir).is_ok())
+        };
+        if removed.with_context(|| {
             format!(
-                "Failed to remove .worktrees/ directory at {}",
+                "Failed to remove worktrees directory at {}",
                 worktrees_dir.display()
             )
-        })?;
-        println!(
-            "  {} Removed {}",
-            "✓".green().bold(),
-            ".worktrees/".dimmed()
-        );
+        })? {
+            println!("  {} Removed {}", "✓".green().bold(), label.dimmed());
+        }
     }
 
     Ok((worktrees_removed, branches_removed))
@@ -397,7 +437,8 @@ fn clean_sessions() -> Result<usize> {
     Ok(0)
 }
 
-/// Remove the .work/ state directory
+/// Remove the .work/ state directory, keeping the run history for the
+/// next `loom init`
 ///
 /// Returns true if the directory was removed
 fn clean_state_directory(repo_root: &Path) -> Result<bool> {
@@ -408,6 +449,7 @@ fn clean_state_directory(repo_root: &Path) -> Result<bool> {
         return Ok(false);
     }
 
+    let stashed = stash_run_history(repo_root)?;
     fs::remove_dir_all(&work_dir).with_context(|| {
         format!(
             "Failed to remove .work/ directory at {}",
@@ -415,10 +457,183 @@ fn clean_state_directory(repo_root: &Path) -> Result<bool> {
         )
     })?;
     println!("  {} Removed {}", "✓".green().bold(), ".work/".dimmed());
+    if stashed > 0 {
+        println!(
+            "  {} Kept {} archived run(s) in {}",
+            "✓".green().bold(),
+            stashed,
+            format!("{RUN_HISTORY_STASH_DIR}/").dimmed()
+        );
+    }
 
     Ok(true)
 }
 
+/// Remove one stage's resources and reset the stage so it runs again from
+/// scratch.
+///
+/// Kills the stage's live sessions and removes its worktree, branch, base
+/// branch, session files, signals, PID and wrapper files, heartbeats,
+/// worktree setup log and crash reports. The stage file stays, reset to
+/// Queued when its dependencies are done and WaitingForDeps otherwise.
+fn clean_stage(repo_root: &Path, stage_id: &str) -> Result<()> {
+    let work_dir = repo_root.join(".work");
+    let mut stage = load_stage(stage_id, &work_dir)?;
+
+    if !confirm_branch_deletion(repo_root, Some(stage_id))? {
+        println!();
+        println!("{} Aborted — nothing was deleted.", "✗".red().bold());
+        return Ok(());
+    }
+
+    println!("\n{}", format!("Stage {stage_id}").bold());
+    println!("{}", "─".repeat(40).dimmed());
+
+    let session_ids = find_sessions_for_stage(stage_id, &work_dir)?;
+    for session_id in &session_ids {
+        kill_stage_session(&work_dir, session_id);
+    }
+
+    let config = CleanupConfig {
+        verbose: false,
+        ..CleanupConfig::forced()
+    };
+    let result = cleanup_after_merge(stage_id, repo_root, &config)?;
+    if result.worktree_removed {
+        println!(
+            "  {} Removed worktree: {}",
+            "✓".green().bold(),
+            stage_id.dimmed()
+        );
+    }
+    if result.branch_deleted {
+        println!(
+            "  {} Deleted branch: {}",
+            "✓".green().bold(),
+            branch_name_for_stage(stage_id).dimmed()
+        );
+    }
+    if result.base_branch_deleted {
+        println!(
+            "  {} Deleted base branch: {}",
+            "✓".green().bold(),
+            base_branch_name_for_stage(stage_id).dimmed()
+        );
+    }
+    for warning in &result.warnings {
+        println!("  {} {}", "⚠".yellow().bold(), warning.dimmed());
+    }
+
+    let files_removed = remove_stage_files(&work_dir, stage_id, &session_ids);
+    println!(
+        "  {} Removed {} session{} and {} state file{}",
+        "✓".green().bold(),
+        session_ids.len(),
+        if session_ids.len() == 1 { "" } else { "s" },
+        files_removed,
+        if files_removed == 1 { "" } else { "s" }
+    );
+
+    let status = reset_stage(&mut stage, &work_dir);
+    save_stage(&stage, &work_dir)?;
+    println!(
+        "  {} Stage reset to {}",
+        "✓".green().bold(),
+        format!("{status:?}").dimmed()
+    );
+
+    if DaemonServer::is_running(&work_dir) {
+        println!();
+        println!(
+            "{} The daemon is running and still holds the old stage state; \
+             restart it ('loom stop', then 'loom run') to pick up the reset.",
+            "⚠".yellow().bold()
+        );
+    }
+    println!();
+    Ok(())
+}
+
+/// Remove the `.work/` files belonging to a stage and its sessions.
+///
+/// Returns the number of files removed. Missing files are skipped.
+fn remove_stage_files(work_dir: &Path, stage_id: &str, session_ids: &[String]) -> usize {
+    let mut paths = vec![
+        heartbeat_path(work_dir, stage_id),
+        heartbeat_history_path(work_dir, stage_id),
+        worktree_setup_log_path(work_dir, stage_id),
+        worktree_setup_marker_path(work_dir, stage_id),
+    ];
+    for session_id in session_ids {
+        paths.push(work_dir.join("sessions").join(format!("{session_id}.md")));
+        paths.push(work_dir.join("signals").join(format!("{session_id}.md")));
+        paths.push(
+            work_dir
+                .join("signals")
+                .join(format!("{session_id}.refreshed")),
+        );
+    }
+
+    // PID files and wrapper scripts are keyed by `<tracking-key>-<session-id>`.
+    // Crash reports are named `<YYYYmmdd-HHMMSS>-<stage-or-session-id>.md`.
+    let in_dir = |dir: &Path, matches: &dyn Fn(&str) -> bool| -> Vec<std::path::PathBuf> {
+        fs::read_dir(dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter(|entry| matches(&entry.file_name().to_string_lossy()))
+            .map(|entry| entry.path())
+            .collect()
+    };
+    let owned_by_session = |name: &str| session_ids.iter().any(|id| name.contains(id.as_str()));
+    paths.extend(in_dir(&pids_dir(work_dir), &owned_by_session));
+    paths.extend(in_dir(&wrappers_dir(work_dir), &owned_by_session));
+    paths.extend(in_dir(&work_dir.join("crashes"), &|name: &str| {
+        name.get(16..)
+            .and_then(|rest| rest.strip_suffix(".md"))
+            .is_some_and(|id| id == stage_id || session_ids.iter().any(|s| s == id))
+    }));
+
+    paths
+        .iter()
+        .filter(|path| fs::remove_file(path).is_ok())
+        .count()
+}
+
+/// Reset `stage` to its initial state, dropping everything recorded by
+/// earlier attempts. Returns the new status: Queued when every dependency is
+/// done, WaitingForDeps otherwise.
+///
+/// Like `loom stage reset`, this bypasses the state machine: WaitingForDeps
+/// has no incoming transitions.
+fn reset_stage(stage: &mut Stage, work_dir: &Path) -> StageStatus {
+    let deps_done = stage.dependencies.iter().all(|dep_id| {
+        load_stage(dep_id, work_dir).is_ok_and(|dep| {
+            dep.status == StageStatus::Skipped
+                || (dep.status == StageStatus::Completed && dep.merged)
+        })
+    });
+    stage.status = if deps_done {
+        StageStatus::Queued
+    } else {
+        StageStatus::WaitingForDeps
+    };
+
+    stage.session = None;
+    stage.worktree = None;
+    stage.merged = false;
+    stage.completed_at = None;
+    stage.close_reason = None;
+    stage.started_at = None;
+    stage.duration_secs = None;
+    stage.retry_count = 0;
+    stage.fix_attempts = 0;
+    stage.last_failure_at = None;
+    stage.failure_info = None;
+    stage.updated_at = chrono::Utc::now();
+    stage.status.clone()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -464,4 +679,67 @@ mod tests {
         // Returns (0, 0) when no .worktrees directory exists
         assert_eq!(result.unwrap(), (0, 0));
     }
+
+    #[test]
+    fn test_remove_stage_files_only_touches_the_stage() {
+        let temp_dir = TempDir::new().unwrap();
+        let work_dir = temp_dir.path();
+        let session = "session-abc-1";
+        let files = [
+            "heartbeat/db.json",
+            "heartbeat/db.history.jsonl",
+            "logs/worktree-setup/db.log",
+            "sessions/session-abc-1.md",
+            "signals/session-abc-1.md",
+            "signals/session-abc-1.refreshed",
+            "pids/loom-db-session-abc-1.pid",
+            "wrappers/loom-db-session-abc-1-wrapper.sh",
+            "crashes/20261014-182844-db.md",
+        ];
+        let others = [
+            "heartbeat/api-db.json",
+            "sessions/session-xyz-2.md",
+            "pids/loom-api-db-session-xyz-2.pid",
+            "crashes/20261014-182844-api-db.md",
+        ];
+        for file in files.iter().chain(&others) {
+            let path = work_dir.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "x").unwrap();
+        }
+
+        let removed = remove_stage_files(work_dir, "db", &[session.to_string()]);
+        assert_eq!(removed, files.len());
+        assert!(files.iter().all(|f| !work_dir.join(f).exists()));
+        assert!(others.iter().all(|f| work_dir.join(f).exists()));
+    }
+
+    #[test]
+    fn test_reset_stage_queues_when_dependencies_are_done() {
+        let temp_dir = TempDir::new().unwrap();
+        let work_dir = temp_dir.path();
+        let mut dep = Stage::new("api".to_string(), None);
+        dep.id = "api".to_string();
+        dep.status = StageStatus::Completed;
+        save_stage(&dep, work_dir).unwrap();
+
+        let mut stage = Stage::new("web".to_string(), None);
+        stage.id = "web".to_string();
+        stage.dependencies = vec!["api".to_string()];
+        stage.status = StageStatus::Blocked;
+        stage.session = Some("session-1".to_string());
+        stage.retry_count = 2;
+
+        // Completed but not merged yet
+        assert_eq!(
+            reset_stage(&mut stage, work_dir),
+            StageStatus::WaitingForDeps
+        );
+        assert_eq!(stage.session, None);
+        assert_eq!(stage.retry_count, 0);
+
+        dep.merged = true;
+        save_stage(&dep, work_dir).unwrap();
+        assert_eq!(reset_stage(&mut stage, work_dir), StageStatus::Queued);
+    }
 }
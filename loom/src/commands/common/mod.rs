@@ -1,15 +1,17 @@
 //! Common utility functions shared across command implementations.
 //!
 //! This module provides utilities for:
-//! - Work directory discovery
+//! - Work directory discovery and the `--work-dir` override
 //! - Stage ID detection from worktree branch
 //! - String truncation for display
 //! - Shared tree-rendering helpers (see [`tree`])
+//! - Fuzzy ID matching and a numbered picker (see [`picker`])
 
+pub mod picker;
 pub mod tree;
 
-use anyhow::{bail, Result};
-use std::path::PathBuf;
+use anyhow::{bail, Context, Result};
+use std::path::{Path, PathBuf};
 
 use crate::git::branch::stage_id_from_branch;
 
@@ -34,6 +36,49 @@ pub fn find_work_dir() -> Result<PathBuf> {
     }
 }
 
+/// Resolve the workspace to operate on from `--work-dir` or `LOOM_WORK_DIR`.
+///
+/// The flag always wins. The environment variable is only a fallback for
+/// when the current directory is not inside a workspace: agent sessions
+/// export it, and commands they run from their worktree must keep resolving
+/// the worktree (branch, stage id) rather than jumping to the main repo.
+pub fn work_dir_override(flag: Option<PathBuf>) -> Option<PathBuf> {
+    flag.or_else(|| {
+        let env = std::env::var_os("LOOM_WORK_DIR").filter(|v| !v.is_empty())?;
+        find_work_dir().is_err().then(|| PathBuf::from(env))
+    })
+}
+
+/// Project root for a `--work-dir` value.
+///
+/// Accepts either the `.work` directory itself or the project root that
+/// contains it (which need not have a `.work` yet, so `loom init` works).
+pub fn workspace_root(path: &Path) -> Result<PathBuf> {
+    let path = path
+        .canonicalize()
+        .with_context(|| format!("Work directory {} does not exist", path.display()))?;
+    if !path.is_dir() {
+        bail!("Work directory {} is not a directory", path.display());
+    }
+    if path.file_name().is_some_and(|name| name == ".work") {
+        if let Some(root) = path.parent() {
+            return Ok(root.to_path_buf());
+        }
+    }
+    Ok(path)
+}
+
+/// Switch the process into the workspace named by `--work-dir`.
+///
+/// Commands discover `.work` and the repository from the current directory,
+/// so changing into the project root makes every one of them honor the
+/// override without threading a path through each command.
+pub fn enter_workspace(path: &Path) -> Result<()> {
+    let root = workspace_root(path)?;
+    std::env::set_current_dir(&root)
+        .with_context(|| format!("Failed to change into {}", root.display()))
+}
+
 /// Detect stage ID from current worktree branch.
 ///
 /// Checks if the current git branch follows the loom worktree naming pattern
@@ -67,6 +112,29 @@ pub use crate::utils::{truncate, truncate_for_display};
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_workspace_root_accepts_work_dir_or_project_root() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path().canonicalize().unwrap();
+        std::fs::create_dir(root.join(".work")).unwrap();
+
+        assert_eq!(workspace_root(&root.join(".work")).unwrap(), root);
+        assert_eq!(workspace_root(&root).unwrap(), root);
+        assert!(workspace_root(&root.join("missing")).is_err());
+
+        std::fs::write(root.join("file"), "").unwrap();
+        assert!(workspace_root(&root.join("file")).is_err());
+    }
+
+    #[test]
+    fn test_work_dir_override_prefers_flag() {
+        let flag = PathBuf::from("/somewhere/.work");
+        assert_eq!(work_dir_override(Some(flag.clone())), Some(flag));
+    }
+
     #[test]
     fn test_detect_stage_id_format() {
         let parse_branch = |branch: &str| -> Option<String> {
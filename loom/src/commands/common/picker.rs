@@ -0,0 +1,154 @@
+//! Fuzzy matching and a numbered stdin picker for ID arguments.
+//!
+//! Session and stage IDs are long and generated, so commands that take one
+//! can fall back to ranking the known IDs against what was typed and letting
+//! the user choose from a short numbered list.
+
+use anyhow::Result;
+use std::io::{IsTerminal, Write};
+
+/// Most candidates shown by [`pick`] and in "did you mean" hints.
+pub const MAX_CHOICES: usize = 10;
+
+/// How well `query` matches `candidate`, case-insensitively; lower is better.
+///
+/// A prefix beats a substring, which beats the query's characters appearing
+/// in order (a subsequence). `None` when the query does not match at all.
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<usize> {
+    let query = query.to_lowercase();
+    let candidate = candidate.to_lowercase();
+    if query.is_empty() {
+        return Some(0);
+    }
+    if candidate.starts_with(&query) {
+        return Some(candidate.len() - query.len());
+    }
+    if let Some(pos) = candidate.find(&query) {
+        return Some(1_000 + pos);
+    }
+
+    // Subsequence: penalise the characters skipped between matches
+    let mut chars = candidate.chars().enumerate();
+    let mut first = None;
+    let mut last = 0;
+    for q in query.chars() {
+        let (idx, _) = chars.by_ref().find(|(_, c)| *c == q)?;
+        first.get_or_insert(idx);
+        last = idx;
+    }
+    let span = last - first.unwrap_or(0) + 1;
+    Some(10_000 + span - query.chars().count())
+}
+
+/// Indices of `candidates` matching `query`, best first.
+///
+/// Each candidate may carry several keys (e.g. a session ID and its stage
+/// ID); it scores by its best key. Ties keep the input order.
+pub fn fuzzy_rank<S: AsRef<str>>(query: &str, candidates: &[Vec<S>]) -> Vec<usize> {
+    let mut scored: Vec<(usize, usize)> = candidates
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, keys)| {
+            keys.iter()
+                .filter_map(|key| fuzzy_score(query, key.as_ref()))
+                .min()
+                .map(|score| (score, idx))
+        })
+        .collect();
+    scored.sort_by_key(|(score, idx)| (*score, *idx));
+    scored.into_iter().map(|(_, idx)| idx).collect()
+}
+
+/// Whether an interactive picker can be shown.
+pub fn can_prompt() -> bool {
+    std::io::stdin().is_terminal()
+}
+
+/// Show a numbered list of `labels` and read a choice from stdin.
+///
+/// Returns the chosen index, or `None` when the user cancels with an empty
+/// line or `q`. Invalid input re-prompts.
+pub fn pick(prompt: &str, labels: &[String]) -> Result<Option<usize>> {
+    println!("{prompt}");
+    for (i, label) in labels.iter().enumerate() {
+        println!("  {:>2}) {label}", i + 1);
+    }
+    loop {
+        print!("Select [1-{}] (Enter to cancel): ", labels.len());
+        std::io::stdout().flush()?;
+        let mut response = String::new();
+        if std::io::stdin().read_line(&mut response)? == 0 {
+            return Ok(None);
+        }
+        match parse_choice(&response, labels.len()) {
+            Choice::Cancel => return Ok(None),
+            Choice::Index(idx) => return Ok(Some(idx)),
+            Choice::Invalid => println!("Enter a number between 1 and {}.", labels.len()),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Choice {
+    Cancel,
+    Index(usize),
+    Invalid,
+}
+
+fn parse_choice(input: &str, len: usize) -> Choice {
+    let input = input.trim();
+    if input.is_empty() || input.eq_ignore_ascii_case("q") {
+        return Choice::Cancel;
+    }
+    match input.parse::<usize>() {
+        Ok(n) if (1..=len).contains(&n) => Choice::Index(n - 1),
+        _ => Choice::Invalid,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzzy_score_ordering() {
+        let prefix = fuzzy_score("sess", "session-abc").unwrap();
+        let substring = fuzzy_score("abc", "session-abc").unwrap();
+        let subsequence = fuzzy_score("sab", "session-abc").unwrap();
+        assert!(prefix < substring);
+        assert!(substring < subsequence);
+        assert_eq!(fuzzy_score("SESS", "session-abc"), Some(prefix));
+        assert!(fuzzy_score("xyz", "session-abc").is_none());
+        assert!(fuzzy_score("cba", "session-abc").is_none());
+    }
+
+    #[test]
+    fn test_fuzzy_score_prefers_tighter_subsequence() {
+        let tight = fuzzy_score("api", "x-a-p-i").unwrap();
+        let loose = fuzzy_score("api", "a-----p-----i").unwrap();
+        assert!(tight < loose);
+    }
+
+    #[test]
+    fn test_fuzzy_rank_uses_best_key() {
+        let candidates = vec![
+            vec!["session-111", "frontend"],
+            vec!["session-222", "api-auth"],
+            vec!["session-333", "database"],
+        ];
+        assert_eq!(fuzzy_rank("auth", &candidates), vec![1]);
+        assert_eq!(fuzzy_rank("session-2", &candidates), vec![1]);
+        assert_eq!(fuzzy_rank("session", &candidates), vec![0, 1, 2]);
+        assert!(fuzzy_rank("zzz", &candidates).is_empty());
+    }
+
+    #[test]
+    fn test_parse_choice() {
+        assert_eq!(parse_choice("2\n", 3), Choice::Index(1));
+        assert_eq!(parse_choice("\n", 3), Choice::Cancel);
+        assert_eq!(parse_choice("q", 3), Choice::Cancel);
+        assert_eq!(parse_choice("4", 3), Choice::Invalid);
+        assert_eq!(parse_choice("0", 3), Choice::Invalid);
+        assert_eq!(parse_choice("two", 3), Choice::Invalid);
+    }
+}
@@ -6,6 +6,7 @@ use std::path::{Path, PathBuf};
 use uuid::Uuid;
 
 use crate::git::runner::run_git;
+use crate::git::worktree::get_worktree_path;
 use crate::models::stage::StageStatus;
 use crate::verify::transitions::load_stage;
 
@@ -54,7 +55,7 @@ pub fn execute(stage_id: &str) -> Result<()> {
         .worktree
         .as_ref()
         .and_then(|wt| {
-            let path = work_dir.parent()?.join(".worktrees").join(wt);
+            let path = get_worktree_path(wt, work_dir.parent()?);
             if path.exists() {
                 Some(path)
             } else {
@@ -102,7 +103,7 @@ fn load_crash_report(stage_id: &str, work_dir: &Path) -> Option<String> {
 /// Get git status from stage's worktree if it exists
 fn get_worktree_git_status(stage: &crate::models::stage::Stage, work_dir: &Path) -> Option<String> {
     let wt = stage.worktree.as_ref()?;
-    let worktree_path = work_dir.parent()?.join(".worktrees").join(wt);
+    let worktree_path = get_worktree_path(wt, work_dir.parent()?);
 
     if !worktree_path.exists() {
         return None;
@@ -116,7 +117,7 @@ fn get_worktree_git_status(stage: &crate::models::stage::Stage, work_dir: &Path)
 /// Get git diff from stage's worktree if it exists
 fn get_worktree_git_diff(stage: &crate::models::stage::Stage, work_dir: &Path) -> Option<String> {
     let wt = stage.worktree.as_ref()?;
-    let worktree_path = work_dir.parent()?.join(".worktrees").join(wt);
+    let worktree_path = get_worktree_path(wt, work_dir.parent()?);
 
     if !worktree_path.exists() {
         return None;
@@ -0,0 +1,384 @@
+//! Doctor command for checking the host environment
+//!
+//! Verifies the external tools and OS facilities orchestration depends on
+//! (git with worktree support, a terminal emulator, the claude binary, window
+//! management tools, docker for containerized stages, disk space, process
+//! signalling) and prints an actionable fix for each problem. Exits non-zero
+//! when a blocking problem is found, so it can gate CI or setup scripts.
+
+use anyhow::{bail, Result};
+use colored::Colorize;
+use std::path::Path;
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+use crate::claude::find_claude_path;
+use crate::git::worktree::find_repo_root_from_cwd;
+use crate::orchestrator::terminal::native::detect_terminal;
+use crate::process::is_process_alive;
+
+/// Oldest git with `git worktree` subcommands loom relies on.
+const MIN_GIT_VERSION: (u64, u64, u64) = (2, 15, 0);
+
+/// Free space below which orchestration is considered unable to work.
+const MIN_FREE_BYTES: u64 = 512 * 1024 * 1024;
+
+/// Free space below which a warning is shown (each stage gets a worktree).
+const LOW_FREE_BYTES: u64 = 2 * 1024 * 1024 * 1024;
+
+/// Outcome of a single check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckStatus {
+    /// Requirement met
+    Ok,
+    /// Optional component, reported for information only
+    Info,
+    /// Orchestration works but something is degraded
+    Warn,
+    /// Orchestration cannot work until this is fixed
+    Fail,
+}
+
+/// Result of one environment check
+#[derive(Debug, Clone)]
+pub struct DoctorCheck {
+    pub name: &'static str,
+    pub status: CheckStatus,
+    pub detail: String,
+    pub fix: Option<String>,
+}
+
+impl DoctorCheck {
+    fn new(name: &'static str, status: CheckStatus, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status,
+            detail: detail.into(),
+            fix: None,
+        }
+    }
+
+    fn with_fix(mut self, fix: impl Into<String>) -> Self {
+        self.fix = Some(fix.into());
+        self
+    }
+}
+
+/// Execute the doctor command
+pub fn execute() -> Result<()> {
+    let cwd = std::env::current_dir()?;
+
+    crate::utils::print_logo_header("Environment Check");
+
+    let checks = run_checks(&cwd);
+    for check in &checks {
+        let icon = match check.status {
+            CheckStatus::Ok => "✓".green().bold(),
+            CheckStatus::Info => "i".blue().bold(),
+            CheckStatus::Warn => "!".yellow().bold(),
+            CheckStatus::Fail => "✗".red().bold(),
+        };
+        println!("{icon} {:<18} {}", check.name.bold(), check.detail);
+        if let Some(ref fix) = check.fix {
+            println!("  {:<18} {} {}", "", "Fix:".dimmed(), fix.dimmed());
+        }
+    }
+    println!();
+
+    let failed = count(&checks, CheckStatus::Fail);
+    let warned = count(&checks, CheckStatus::Warn);
+    if failed > 0 {
+        bail!("{failed} blocking problem(s) found; orchestration cannot run in this environment");
+    }
+    if warned > 0 {
+        println!(
+            "{} Ready to orchestrate, with {warned} warning(s)",
+            "!".yellow().bold()
+        );
+    } else {
+        println!("{} Environment is ready for loom", "✓".green().bold());
+    }
+
+    Ok(())
+}
+
+fn count(checks: &[DoctorCheck], status: CheckStatus) -> usize {
+    checks.iter().filter(|c| c.status == status).count()
+}
+
+/// Run every check from `cwd`.
+pub fn run_checks(cwd: &Path) -> Vec<DoctorCheck> {
+    let repo_root = find_repo_root_from_cwd(cwd);
+
+    let mut checks = vec![check_git()];
+    checks.push(check_worktree_support(repo_root.as_deref()));
+    checks.push(check_terminal());
+    checks.push(check_claude());
+    if let Some(check) = check_window_tools() {
+        checks.push(check);
+    }
+    checks.push(check_tmux());
+    checks.push(check_docker());
+    checks.push(check_disk_space(repo_root.as_deref().unwrap_or(cwd)));
+    checks.push(check_signals());
+    checks
+}
+
+/// Parse `git version 2.43.0` (or `2.39.3 (Apple Git-145)`) into a triple.
+pub fn parse_git_version(output: &str) -> Option<(u64, u64, u64)> {
+    let version = output
+        .split_whitespace()
+        .find(|word| word.starts_with(|c: char| c.is_ascii_digit()))?;
+    let mut parts = version.split('.').map(|p| p.parse::<u64>().ok());
+    let major = parts.next()??;
+    let minor = parts.next()??;
+    let patch = parts.next().flatten().unwrap_or(0);
+    Some((major, minor, patch))
+}
+
+fn check_git() -> DoctorCheck {
+    let output = match Command::new("git").arg("--version").output() {
+        Ok(o) if o.status.success() => o,
+        _ => {
+            return DoctorCheck::new("git", CheckStatus::Fail, "not found in PATH")
+                .with_fix("Install git 2.15 or newer")
+        }
+    };
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    match parse_git_version(&stdout) {
+        Some(version) if version >= MIN_GIT_VERSION => DoctorCheck::new(
+            "git",
+            CheckStatus::Ok,
+            format!("{}.{}.{}", version.0, version.1, version.2),
+        ),
+        Some(version) => DoctorCheck::new(
+            "git",
+            CheckStatus::Fail,
+            format!(
+                "{}.{}.{} is too old for worktree support",
+                version.0, version.1, version.2
+            ),
+        )
+        .with_fix("Upgrade git to 2.15 or newer"),
+        None => DoctorCheck::new(
+            "git",
+            CheckStatus::Warn,
+            format!("unrecognized version output: {}", stdout.trim()),
+        ),
+    }
+}
+
+fn check_worktree_support(repo_root: Option<&Path>) -> DoctorCheck {
+    let Some(repo_root) = repo_root else {
+        return DoctorCheck::new(
+            "git worktree",
+            CheckStatus::Warn,
+            "not inside a git repository",
+        )
+        .with_fix("Run loom from the root of the project you want to orchestrate");
+    };
+    let ok = Command::new("git")
+        .args(["worktree", "list"])
+        .current_dir(repo_root)
+        .output()
+        .is_ok_and(|o| o.status.success());
+    if ok {
+        DoctorCheck::new(
+            "git worktree",
+            CheckStatus::Ok,
+            format!("supported in {}", repo_root.display()),
+        )
+    } else {
+        DoctorCheck::new(
+            "git worktree",
+            CheckStatus::Fail,
+            "`git worktree list` failed",
+        )
+        .with_fix("Upgrade git, and check the repository is not a bare or broken checkout")
+    }
+}
+
+fn check_terminal() -> DoctorCheck {
+    match detect_terminal() {
+        Ok(emulator) => DoctorCheck::new("terminal", CheckStatus::Ok, emulator.to_string()),
+        Err(e) => DoctorCheck::new("terminal", CheckStatus::Fail, format!("{e}")).with_fix(
+            "Install a supported terminal emulator or set LOOM_TERMINAL / TERMINAL to one",
+        ),
+    }
+}
+
+fn check_claude() -> DoctorCheck {
+    match find_claude_path() {
+        Ok(path) => DoctorCheck::new("claude", CheckStatus::Ok, path.display().to_string()),
+        Err(_) => DoctorCheck::new("claude", CheckStatus::Fail, "binary not found")
+            .with_fix("Install Claude Code and make sure `claude` is on PATH"),
+    }
+}
+
+/// wmctrl/xdotool are only used to find and close stage windows on X11.
+#[cfg(target_os = "linux")]
+fn check_window_tools() -> Option<DoctorCheck> {
+    let x11 = std::env::var_os("DISPLAY").is_some();
+    let wayland = std::env::var_os("WAYLAND_DISPLAY").is_some();
+    if !x11 {
+        let detail = if wayland {
+            "not needed on Wayland"
+        } else {
+            "no display detected"
+        };
+        return Some(DoctorCheck::new(
+            "wmctrl/xdotool",
+            CheckStatus::Info,
+            detail,
+        ));
+    }
+
+    let found: Vec<&str> = ["wmctrl", "xdotool"]
+        .into_iter()
+        .filter(|tool| which::which(tool).is_ok())
+        .collect();
+    Some(if found.is_empty() {
+        DoctorCheck::new(
+            "wmctrl/xdotool",
+            CheckStatus::Warn,
+            "neither found; stage windows cannot be focused or closed by title",
+        )
+        .with_fix("Install wmctrl (preferred) or xdotool")
+    } else {
+        DoctorCheck::new("wmctrl/xdotool", CheckStatus::Ok, found.join(", "))
+    })
+}
+
+#[cfg(not(target_os = "linux"))]
+fn check_window_tools() -> Option<DoctorCheck> {
+    None
+}
+
+fn check_tmux() -> DoctorCheck {
+    let detail = match which::which("tmux") {
+        Ok(path) => format!("{} (optional)", path.display()),
+        Err(_) => "not installed (optional; sessions run in native terminal windows)".to_string(),
+    };
+    DoctorCheck::new("tmux", CheckStatus::Info, detail)
+}
+
+fn check_docker() -> DoctorCheck {
+    let detail = match which::which("docker") {
+        Ok(path) => format!("{} (optional)", path.display()),
+        Err(_) => "not installed (optional; needed only by stages with `container:`)".to_string(),
+    };
+    DoctorCheck::new("docker", CheckStatus::Info, detail)
+}
+
+/// Classify free space against the worktree thresholds.
+pub fn disk_space_status(available: u64) -> CheckStatus {
+    if available < MIN_FREE_BYTES {
+        CheckStatus::Fail
+    } else if available < LOW_FREE_BYTES {
+        CheckStatus::Warn
+    } else {
+        CheckStatus::Ok
+    }
+}
+
+fn check_disk_space(path: &Path) -> DoctorCheck {
+    let available = match fs2::available_space(path) {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            return DoctorCheck::new(
+                "disk space",
+                CheckStatus::Warn,
+                format!("could not query free space: {e}"),
+            )
+        }
+    };
+    let detail = format!(
+        "{:.1} GiB free at {}",
+        available as f64 / (1024.0 * 1024.0 * 1024.0),
+        path.display()
+    );
+    let check = DoctorCheck::new("disk space", disk_space_status(available), detail);
+    match check.status {
+        CheckStatus::Ok | CheckStatus::Info => check,
+        _ => check.with_fix("Free up space; every running stage gets its own worktree checkout"),
+    }
+}
+
+/// Spawn a child, verify liveness probing sees it, then stop it with SIGTERM
+/// the way `loom stop` and `loom sessions kill` stop sessions.
+fn check_signals() -> DoctorCheck {
+    let fail = |detail: String| {
+        DoctorCheck::new("signal handling", CheckStatus::Fail, detail)
+            .with_fix("Run loom outside sandboxes that block kill(2) between processes")
+    };
+
+    let mut child = match Command::new("sleep").arg("30").spawn() {
+        Ok(child) => child,
+        Err(e) => return fail(format!("could not spawn a probe process: {e}")),
+    };
+    let pid = child.id();
+    if !is_process_alive(pid) {
+        let _ = child.kill();
+        return fail("liveness probe cannot see child processes".to_string());
+    }
+
+    let sent = i32::try_from(pid).ok().and_then(|pid| {
+        nix::sys::signal::kill(
+            nix::unistd::Pid::from_raw(pid),
+            nix::sys::signal::Signal::SIGTERM,
+        )
+        .ok()
+    });
+    if sent.is_none() {
+        let _ = child.kill();
+        let _ = child.wait();
+        return fail("SIGTERM could not be delivered".to_string());
+    }
+
+    let deadline = Instant::now() + Duration::from_secs(2);
+    while Instant::now() < deadline {
+        if let Ok(Some(_)) = child.try_wait() {
+            return DoctorCheck::new(
+                "signal handling",
+                CheckStatus::Ok,
+                "SIGTERM and liveness probes work",
+            );
+        }
+        std::thread::sleep(Duration::from_millis(20));
+    }
+    let _ = child.kill();
+    let _ = child.wait();
+    fail("probe process ignored SIGTERM".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_git_version() {
+        assert_eq!(parse_git_version("git version 2.43.0\n"), Some((2, 43, 0)));
+        assert_eq!(
+            parse_git_version("git version 2.39.3 (Apple Git-145)"),
+            Some((2, 39, 3))
+        );
+        assert_eq!(
+            parse_git_version("git version 2.45.windows.1"),
+            Some((2, 45, 0))
+        );
+        assert_eq!(parse_git_version("not git"), None);
+    }
+
+    #[test]
+    fn test_disk_space_status_thresholds() {
+        assert_eq!(disk_space_status(100 * 1024 * 1024), CheckStatus::Fail);
+        assert_eq!(disk_space_status(MIN_FREE_BYTES), CheckStatus::Warn);
+        assert_eq!(disk_space_status(LOW_FREE_BYTES), CheckStatus::Ok);
+    }
+
+    #[test]
+    fn test_git_and_signal_checks_pass_on_test_host() {
+        assert_eq!(check_git().status, CheckStatus::Ok);
+        assert_eq!(check_signals().status, CheckStatus::Ok);
+    }
+}
@@ -0,0 +1,153 @@
+//! Plan-wide fact commands
+//! Usage: loom fact [set <key> <value> [--confidence <level>] [--stage <id>]|list|export|import <file>]
+
+use anyhow::{bail, Context, Result};
+use std::path::PathBuf;
+
+use crate::commands::common::find_work_dir;
+use crate::commands::stage::{format_value, parse_value};
+use crate::fs::facts::{
+    load_facts, save_facts, validate_key, Fact, FactConfidence, FactExport, FactProvenance,
+    FactsConfig,
+};
+use crate::fs::load_config;
+use crate::verify::transitions::list_all_stages;
+
+/// Set a fact, replacing the fact with the same key.
+///
+/// The value is parsed like a stage output value: JSON if it looks like
+/// JSON, otherwise a string. The fact records who set it; inside a loom
+/// session that is the session, and `stage` defaults to its stage.
+pub fn set(
+    key: String,
+    value: String,
+    description: Option<String>,
+    confidence: Option<FactConfidence>,
+    stage: Option<String>,
+) -> Result<()> {
+    validate_key(&key)?;
+    let work_dir = find_work_dir()?;
+    let stages = list_all_stages(&work_dir)?;
+    if let Some(stage) = &stage {
+        if !stages.iter().any(|s| &s.id == stage) {
+            bail!("Stage '{stage}' not found");
+        }
+    }
+    let config = FactsConfig::load(&work_dir);
+    let mut store = load_facts(&work_dir)?;
+
+    let value = parse_value(&value);
+    let fact = Fact {
+        key: key.clone(),
+        description: description.unwrap_or_else(|| format!("Fact: {key}")),
+        value: value.clone(),
+        source: None,
+        confidence: Some(confidence.unwrap_or(config.default_confidence())),
+        provenance: Some(FactProvenance::current("fact set", stage)),
+    };
+    config.check(&fact, &stages)?;
+    let was_new = store.set(fact);
+    save_facts(&work_dir, &store)?;
+
+    let action = if was_new { "added" } else { "updated" };
+    println!("Fact '{key}' {action}");
+    println!("  Value: {}", format_value(&value));
+    Ok(())
+}
+
+/// List all facts.
+pub fn list() -> Result<()> {
+    let work_dir = find_work_dir()?;
+    let store = load_facts(&work_dir)?;
+
+    if store.facts.is_empty() {
+        println!("No facts (set one with 'loom fact set' or 'loom fact import')");
+        return Ok(());
+    }
+
+    println!("Facts:");
+    println!();
+    for fact in &store.facts {
+        println!("  {}:", fact.key);
+        println!("    Value: {}", format_value(&fact.value));
+        println!("    Description: {}", fact.description);
+        if let Some(confidence) = fact.confidence {
+            println!("    Confidence: {confidence}");
+        }
+        if let Some(provenance) = &fact.provenance {
+            println!("    Set by: {}", provenance.describe());
+        }
+        if let Some(source) = &fact.source {
+            println!("    Source: {source}");
+        }
+        println!();
+    }
+    Ok(())
+}
+
+/// Write the facts, and with `outputs` the outputs of completed stages, as
+/// portable JSON to `output` (default: stdout).
+pub fn export(output: Option<PathBuf>, outputs: bool) -> Result<()> {
+    let work_dir = find_work_dir()?;
+    let store = load_facts(&work_dir)?;
+    let stages = if outputs {
+        Some(list_all_stages(&work_dir)?)
+    } else {
+        None
+    };
+    let plan = load_config(&work_dir)?.and_then(|config| config.plan_id().map(str::to_string));
+
+    let export = FactExport::build(&store, stages.as_deref(), plan.as_deref());
+    let json = serde_json::to_string_pretty(&export)?;
+    match output {
+        Some(path) => {
+            std::fs::write(&path, format!("{json}\n"))
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+            println!(
+                "Exported {} fact(s) to {}",
+                export.facts.len(),
+                path.display()
+            );
+        }
+        None => println!("{json}"),
+    }
+    Ok(())
+}
+
+/// Merge the facts of an export file into this plan's facts. Imported facts
+/// replace facts with the same key, keep their confidence and are recorded
+/// as set by whoever imports them.
+pub fn import(path: PathBuf) -> Result<()> {
+    let work_dir = find_work_dir()?;
+    let json = std::fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    let export =
+        FactExport::parse(&json).with_context(|| format!("Failed to import {}", path.display()))?;
+    if export.facts.is_empty() {
+        bail!("{} contains no facts", path.display());
+    }
+
+    let config = FactsConfig::load(&work_dir);
+    let stages = list_all_stages(&work_dir)?;
+    let mut store = load_facts(&work_dir)?;
+    let (mut added, mut updated) = (0, 0);
+    for mut fact in export.facts {
+        // The stage an exported fact came from belongs to the other plan
+        fact.provenance = Some(FactProvenance::current("fact import", None));
+        fact.confidence = Some(fact.confidence.unwrap_or(config.default_confidence()));
+        config.check(&fact, &stages)?;
+        if store.set(fact) {
+            added += 1;
+        } else {
+            updated += 1;
+        }
+    }
+    save_facts(&work_dir, &store)?;
+
+    let from = export
+        .plan
+        .map(|plan| format!(" from {plan}"))
+        .unwrap_or_default();
+    println!("Imported facts{from}: {added} added, {updated} updated");
+    Ok(())
+}
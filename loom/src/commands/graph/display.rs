@@ -78,8 +78,13 @@ pub fn build_graph_display(stages: &[Stage]) -> Result<String> {
             let indicator = status_indicator(&stage.status);
             let deps = format_dependencies(stage, &stage_map);
             let colored_name = stage.name.color(stage_color(&stage.id));
+            let assignee = stage
+                .assignee
+                .as_deref()
+                .map(|a| format!(" {}", format!("@{a}").cyan()))
+                .unwrap_or_default();
             output.push_str(&format!(
-                "  {indicator} {colored_name} ({}){deps}\n",
+                "  {indicator} {colored_name} ({}){deps}{assignee}\n",
                 stage.id
             ));
         }
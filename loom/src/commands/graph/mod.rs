@@ -1,6 +1,6 @@
 //! Execution graph display
 //!
-//! Usage: loom graph
+//! Usage: loom graph [--group-by parallel|phase]
 //!
 //! ## Module Organization
 //!
@@ -21,7 +21,9 @@ mod tests;
 use anyhow::Result;
 
 use crate::commands::common::find_work_dir;
+use crate::commands::status::render::{group_stages, render_conflict_risk, render_groups, GroupBy};
 use crate::models::stage::StageStatus;
+use crate::plan::graph::conflict_risk::unfinished_stage_risks;
 use crate::verify::transitions::list_all_stages;
 
 // Re-export the public API
@@ -50,15 +52,20 @@ const ALL_STATUSES: &[StageStatus] = &[
     StageStatus::NeedsAdjudication,
 ];
 
-/// Show the execution graph
-pub fn show() -> Result<()> {
+/// Show the execution graph, or grouped progress bars when `group_by` is set
+pub fn show(group_by: Option<GroupBy>) -> Result<()> {
     crate::utils::print_logo_header("Execution Graph");
 
     let work_dir = find_work_dir()?;
 
     let stages = list_all_stages(&work_dir)?;
-    let tree_display = build_tree_display(&stages);
-    println!("{tree_display}");
+    match group_by {
+        Some(by) => {
+            let grouped: Vec<_> = stages.iter().map(Into::into).collect();
+            render_groups(&mut std::io::stdout(), &group_stages(&grouped, by))?;
+        }
+        None => println!("{}", build_tree_display(&stages)),
+    }
 
     // Print legend generated from the canonical StageStatus methods so no
     // variant is ever omitted and icons/colors stay in sync automatically.
@@ -75,6 +82,11 @@ pub fn show() -> Result<()> {
         );
     }
     println!();
+
+    if let Some(repo_root) = work_dir.parent() {
+        let risks = unfinished_stage_risks(&stages, repo_root);
+        render_conflict_risk(&mut std::io::stdout(), &risks)?;
+    }
     println!();
 
     Ok(())
@@ -8,10 +8,12 @@
 
 use std::collections::HashMap;
 
+use chrono::Utc;
 use colored::{Color, Colorize};
 
 use crate::commands::common::tree::{compute_connector, format_dep_annotation};
 use crate::models::stage::{Stage, StageStatus};
+use crate::utils::format_elapsed;
 
 use super::colors::color_by_index;
 use super::indicators::status_indicator;
@@ -93,6 +95,7 @@ pub fn build_tree_display(stages: &[Stage]) -> String {
         *level_counts.entry(level).or_insert(0) += 1;
     }
 
+    let now = Utc::now();
     let mut output = String::new();
 
     for (global_index, stage) in sorted_stages.iter().enumerate() {
@@ -105,7 +108,29 @@ pub fn build_tree_display(stages: &[Stage]) -> String {
         let deps = format_dep_annotation(&stage.dependencies, &color_map);
         let color = color_by_index(global_index);
         let colored_id = stage.id.color(color);
-        output.push_str(&format!("{connector}{indicator}  {colored_id}{deps}\n"));
+        let assignee = stage
+            .assignee
+            .as_deref()
+            .map(|a| format!("  {}", format!("@{a}").cyan()))
+            .unwrap_or_default();
+        let overdue = stage
+            .overdue_secs(now)
+            .zip(stage.expected_duration_secs)
+            .map(|(ran, expected)| {
+                format!(
+                    "  {}",
+                    format!(
+                        "overdue {} / ~{} expected",
+                        format_elapsed(ran),
+                        format_elapsed(expected as i64)
+                    )
+                    .red()
+                )
+            })
+            .unwrap_or_default();
+        output.push_str(&format!(
+            "{connector}{indicator}  {colored_id}{deps}{assignee}{overdue}\n"
+        ));
 
         // Increment index for this level
         *level_indices.get_mut(&level).unwrap() += 1;
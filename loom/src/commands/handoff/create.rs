@@ -1,15 +1,162 @@
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use chrono::Utc;
+use serde::Deserialize;
 use std::env;
 use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
 use crate::commands::common::find_work_dir;
 use crate::git::branch::current_branch;
 use crate::handoff::generator::{generate_handoff, HandoffContent};
 use crate::models::session::{Session, SessionStatus};
-use crate::models::stage::Stage;
-use crate::verify::transitions::load_stage;
+use crate::models::stage::{Stage, StageStatus};
+use crate::verify::transitions::{load_stage, save_stage};
+
+/// What an agent hands to the next session (`loom handoff create`).
+///
+/// Read from `--yaml` and/or the `--summary`, `--remaining` and `--decision`
+/// flags; flags are appended to what the YAML provides.
+#[derive(Debug, Default, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct HandoffNotes {
+    /// What this session accomplished
+    #[serde(default)]
+    pub summary: Option<String>,
+    /// Tasks left for the next session, in priority order
+    #[serde(default)]
+    pub remaining_tasks: Vec<String>,
+    /// Decisions the next session should not revisit
+    #[serde(default)]
+    pub key_decisions: Vec<DecisionNote>,
+}
+
+/// A key decision, either `"decision: rationale"` or a mapping.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum DecisionNote {
+    Text(String),
+    Full {
+        decision: String,
+        #[serde(default)]
+        rationale: String,
+    },
+}
+
+impl DecisionNote {
+    /// `(decision, rationale)`, splitting text at its first `": "`.
+    fn into_pair(self) -> (String, String) {
+        match self {
+            Self::Text(text) => match text.split_once(": ") {
+                Some((decision, rationale)) => {
+                    (decision.trim().to_string(), rationale.trim().to_string())
+                }
+                None => (text.trim().to_string(), String::new()),
+            },
+            Self::Full {
+                decision,
+                rationale,
+            } => (decision, rationale),
+        }
+    }
+}
+
+impl HandoffNotes {
+    fn is_empty(&self) -> bool {
+        self.summary.as_deref().is_none_or(|s| s.trim().is_empty())
+            && self.remaining_tasks.is_empty()
+            && self.key_decisions.is_empty()
+    }
+}
+
+/// Execute the `loom handoff create` command
+///
+/// Writes the next numbered handoff from the agent's notes and marks the
+/// stage `NeedsHandoff`. The daemon then closes the session and re-queues the
+/// stage, so the next session resumes from this handoff.
+pub fn execute_create(
+    stage_arg: Option<String>,
+    session_arg: Option<String>,
+    summary: Option<String>,
+    remaining_tasks: Vec<String>,
+    key_decisions: Vec<String>,
+    yaml: Option<String>,
+) -> Result<()> {
+    let stage_id = resolve_stage_id(&stage_arg)?;
+    let session_id = resolve_session_id(&session_arg)?;
+    let work_dir = find_work_dir()?;
+
+    let mut notes = match yaml.as_deref() {
+        Some(source) => read_notes(source)?,
+        None => HandoffNotes::default(),
+    };
+    if summary.is_some() {
+        notes.summary = summary;
+    }
+    notes.remaining_tasks.extend(remaining_tasks);
+    notes
+        .key_decisions
+        .extend(key_decisions.into_iter().map(DecisionNote::Text));
+    if notes.is_empty() {
+        bail!("Nothing to hand off: pass --summary, --remaining or --decision, or --yaml <FILE>");
+    }
+
+    let mut stage = load_stage(&stage_id, &work_dir)?;
+    if !stage.status.can_transition_to(&StageStatus::NeedsHandoff) {
+        bail!(
+            "Stage '{stage_id}' is {}; only an executing stage can be handed off",
+            stage.status
+        );
+    }
+
+    let handoff_path = write_handoff(
+        &work_dir,
+        &stage_id,
+        &session_id,
+        "agent",
+        "loom handoff create",
+        notes,
+    )?;
+
+    stage.try_mark_needs_handoff()?;
+    // The daemon ends the session recorded on the stage
+    if stage.session.is_none() {
+        stage.session = Some(session_id);
+    }
+    save_stage(&stage, &work_dir)?;
+
+    println!("Handoff written to {}", handoff_path.display());
+    println!(
+        "Stage '{stage_id}' marked NeedsHandoff; loom will close this session and resume the \
+         stage from the handoff in a fresh one."
+    );
+    println!("Stop here: make no further changes in this session.");
+    Ok(())
+}
+
+/// Parse handoff notes from a YAML file, or stdin for `-`.
+fn read_notes(source: &str) -> Result<HandoffNotes> {
+    let yaml = if source == "-" {
+        let mut buffer = String::new();
+        std::io::stdin()
+            .read_to_string(&mut buffer)
+            .context("Failed to read handoff YAML from stdin")?;
+        buffer
+    } else {
+        fs::read_to_string(source)
+            .with_context(|| format!("Failed to read handoff YAML from {source}"))?
+    };
+    parse_notes(&yaml)
+}
+
+fn parse_notes(yaml: &str) -> Result<HandoffNotes> {
+    if yaml.trim().is_empty() {
+        return Ok(HandoffNotes::default());
+    }
+    serde_yaml::from_str(yaml)
+        .context("Invalid handoff YAML: expected `summary`, `remaining_tasks` and `key_decisions`")
+}
 
 /// Execute the `loom handoff` command
 ///
@@ -33,8 +180,67 @@ pub fn execute(
     // Determine work directory (look for .work in current dir or as symlink)
     let work_dir = find_work_dir()?;
 
+    let origin = if stage_arg.is_some() || session_arg.is_some() {
+        "manual CLI"
+    } else {
+        "environment"
+    };
+    let handoff_path = create_handoff(
+        &work_dir,
+        &stage_id,
+        &session_id,
+        &trigger,
+        message.as_deref(),
+        origin,
+    )?;
+
+    // Print the handoff file path (hooks parse this output)
+    println!("{}", handoff_path.display());
+
+    Ok(())
+}
+
+/// Write a handoff file for a stage/session and return its path.
+///
+/// Shared by the `loom handoff` CLI and the MCP `create_checkpoint` tool. Does
+/// not print anything, so it is safe to call from a stdio protocol server.
+///
+/// # Arguments
+/// * `work_dir` - The `.work` directory
+/// * `stage_id` - Stage the handoff belongs to
+/// * `session_id` - Session creating the handoff
+/// * `trigger` - Trigger type (e.g., "manual", "precompact", "session_end")
+/// * `message` - Optional message recorded as a next step
+/// * `origin` - How the handoff was requested, recorded alongside the trigger
+pub fn create_handoff(
+    work_dir: &Path,
+    stage_id: &str,
+    session_id: &str,
+    trigger: &str,
+    message: Option<&str>,
+    origin: &str,
+) -> Result<PathBuf> {
+    let notes = HandoffNotes {
+        remaining_tasks: message.map(str::to_string).into_iter().collect(),
+        ..Default::default()
+    };
+    write_handoff(work_dir, stage_id, session_id, trigger, origin, notes)
+}
+
+/// Write a handoff file recording `notes` and return its path.
+fn write_handoff(
+    work_dir: &Path,
+    stage_id: &str,
+    session_id: &str,
+    trigger: &str,
+    origin: &str,
+    notes: HandoffNotes,
+) -> Result<PathBuf> {
+    let stage_id = stage_id.to_string();
+    let session_id = session_id.to_string();
+
     // Load stage (gracefully handle missing stage)
-    let stage = load_stage(&stage_id, &work_dir).unwrap_or_else(|_| {
+    let stage = load_stage(&stage_id, work_dir).unwrap_or_else(|_| {
         // Create minimal stage if loading fails
         Stage {
             id: stage_id.clone(),
@@ -76,25 +282,34 @@ pub fn execute(
         }
     }
 
-    // Add message as a next step if provided
-    if let Some(msg) = &message {
-        content = content.with_next_steps(vec![msg.clone()]);
+    // Record what the session reports: summary lines as completed work,
+    // remaining tasks as next steps
+    if let Some(summary) = &notes.summary {
+        let completed = summary
+            .lines()
+            .map(|line| line.trim().trim_start_matches("- ").to_string())
+            .filter(|line| !line.is_empty())
+            .collect();
+        content = content.with_completed_work(completed);
+    }
+    if !notes.remaining_tasks.is_empty() {
+        content = content.with_next_steps(notes.remaining_tasks);
+    }
+    if !notes.key_decisions.is_empty() {
+        let decisions = notes
+            .key_decisions
+            .into_iter()
+            .map(DecisionNote::into_pair)
+            .collect();
+        content = content.with_decisions(decisions);
     }
 
     // Add trigger information to goals
-    let trigger_note = format!(
-        "\n\nHandoff created via: {} (trigger: {})",
-        if stage_arg.is_some() || session_arg.is_some() {
-            "manual CLI"
-        } else {
-            "environment"
-        },
-        trigger
-    );
+    let trigger_note = format!("\n\nHandoff created via: {origin} (trigger: {trigger})");
     let goals_with_trigger = format!("{}{}", content.goals, trigger_note);
     content = content.with_goals(goals_with_trigger);
 
-    // Create a minimal Session for generate_handoff (the _session parameter is unused)
+    // Create a minimal Session for generate_handoff (its worktree is resolved from the stage)
     let session = Session {
         id: session_id.clone(),
         stage_id: Some(stage_id.clone()),
@@ -109,15 +324,12 @@ pub fn execute(
         merge_source_branch: None,
         merge_target_branch: None,
         tracking_key: String::new(),
+        container: None,
+        role: Default::default(),
     };
 
     // Generate the handoff file
-    let handoff_path = generate_handoff(&session, &stage, content, &work_dir)?;
-
-    // Print the handoff file path (hooks parse this output)
-    println!("{}", handoff_path.display());
-
-    Ok(())
+    generate_handoff(&session, &stage, content, work_dir)
 }
 
 /// Resolve stage ID from argument or LOOM_STAGE_ID environment variable
@@ -225,6 +437,61 @@ mod tests {
         assert_eq!(result.unwrap(), "test-session");
     }
 
+    #[test]
+    fn test_parse_notes() {
+        let notes = parse_notes(
+            "summary: Added the parser\n\
+             remaining_tasks:\n  - Wire the CLI\n\
+             key_decisions:\n  - \"Use serde_yaml: already a dependency\"\n  \
+             - decision: Keep v1 format\n    rationale: hooks parse it\n",
+        )
+        .unwrap();
+        assert_eq!(notes.summary.as_deref(), Some("Added the parser"));
+        assert_eq!(notes.remaining_tasks, vec!["Wire the CLI".to_string()]);
+        let decisions: Vec<_> = notes
+            .key_decisions
+            .into_iter()
+            .map(DecisionNote::into_pair)
+            .collect();
+        assert_eq!(
+            decisions,
+            vec![
+                (
+                    "Use serde_yaml".to_string(),
+                    "already a dependency".to_string()
+                ),
+                ("Keep v1 format".to_string(), "hooks parse it".to_string()),
+            ]
+        );
+
+        assert!(parse_notes("").unwrap().is_empty());
+        assert!(parse_notes("summry: typo\n").is_err());
+    }
+
+    #[test]
+    fn test_write_handoff_records_notes() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let notes = HandoffNotes {
+            summary: Some("- Added the parser\n- Fixed tests".to_string()),
+            remaining_tasks: vec!["Wire the CLI".to_string()],
+            key_decisions: vec![DecisionNote::Text("No new deps".to_string())],
+        };
+        let path = write_handoff(
+            temp.path(),
+            "api",
+            "session-1",
+            "agent",
+            "loom handoff create",
+            notes,
+        )
+        .unwrap();
+        assert!(path.ends_with("handoffs/api-handoff-001.md"));
+        let markdown = fs::read_to_string(path).unwrap();
+        assert!(markdown.contains("- Added the parser\n- Fixed tests\n"));
+        assert!(markdown.contains("1. Wire the CLI"));
+        assert!(markdown.contains("| No new deps |  |"));
+    }
+
     #[test]
     fn test_build_handoff_content() {
         let session_id = "test-session".to_string();
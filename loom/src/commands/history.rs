@@ -0,0 +1,144 @@
+//! Run history commands
+//! Usage: loom history list | show <run-id>
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::commands::common::find_work_dir;
+use crate::fs::run_history::{find_run, list_runs, RunRecord};
+use crate::utils::format_elapsed;
+
+/// List archived runs with their efficiency statistics, oldest first.
+pub fn list(json: bool) -> Result<()> {
+    let work_dir = find_work_dir()?;
+    let runs = list_runs(&work_dir)?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&runs)?);
+        return Ok(());
+    }
+
+    if runs.is_empty() {
+        println!("No archived runs (runs are archived when every stage completes)");
+        return Ok(());
+    }
+
+    println!(
+        "{:<40} {:>7} {:>9} {:>9} {:>7} {:>9} {:>9}",
+        "RUN".bold(),
+        "STAGES".bold(),
+        "DURATION".bold(),
+        "AVG STAGE".bold(),
+        "RETRY".bold(),
+        "CONFLICT".bold(),
+        "TOKENS".bold(),
+    );
+    for run in &runs {
+        println!(
+            "{:<40} {:>7} {:>9} {:>9} {:>7} {:>9} {:>9}",
+            run.run_id,
+            format!("{}/{}", run.completed_count(), run.stages.len()),
+            duration(run.total_duration_secs()),
+            duration(run.avg_stage_duration_secs()),
+            percent(run.retry_rate()),
+            percent(run.conflict_rate()),
+            run.total_tokens(),
+        );
+    }
+
+    if runs.len() > 1 {
+        let n = runs.len() as f64;
+        let avg_stage: Vec<i64> = runs
+            .iter()
+            .filter_map(RunRecord::avg_stage_duration_secs)
+            .collect();
+        let avg_stage =
+            (!avg_stage.is_empty()).then(|| avg_stage.iter().sum::<i64>() / avg_stage.len() as i64);
+        println!();
+        println!(
+            "{} runs: avg stage {}, retry rate {}, conflict rate {}",
+            runs.len(),
+            duration(avg_stage),
+            percent(runs.iter().map(RunRecord::retry_rate).sum::<f64>() / n),
+            percent(runs.iter().map(RunRecord::conflict_rate).sum::<f64>() / n),
+        );
+    }
+    Ok(())
+}
+
+/// Show one archived run's final graph state and per-stage statistics.
+pub fn show(run_id: String, json: bool) -> Result<()> {
+    let work_dir = find_work_dir()?;
+    let run = find_run(&work_dir, &run_id)?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&run)?);
+        return Ok(());
+    }
+
+    println!("{} {}", "Run".bold(), run.run_id.cyan());
+    if let Some(plan_id) = &run.plan_id {
+        println!("  Plan:        {plan_id}");
+    }
+    if let Some(source) = &run.plan_source {
+        println!("  Source:      {source}");
+    }
+    println!("  Merged into: {}", run.merge_point);
+    if let Some(finished_at) = run.finished_at {
+        println!(
+            "  Finished:    {}",
+            finished_at.format("%Y-%m-%d %H:%M:%S UTC")
+        );
+    }
+    println!("  Duration:    {}", duration(run.total_duration_secs()));
+    println!(
+        "  Stages:      {}/{} completed, avg {}",
+        run.completed_count(),
+        run.stages.len(),
+        duration(run.avg_stage_duration_secs())
+    );
+    println!(
+        "  Retries:     {} ({} of stages)",
+        run.total_retries(),
+        percent(run.retry_rate())
+    );
+    println!("  Conflicts:   {} of stages", percent(run.conflict_rate()));
+    println!("  Tokens:      {}", run.total_tokens());
+
+    println!();
+    println!(
+        "{:<28} {:<12} {:>9} {:>7} {:>8} {:>9}  {}",
+        "STAGE".bold(),
+        "STATUS".bold(),
+        "DURATION".bold(),
+        "RETRIES".bold(),
+        "CONFLICT".bold(),
+        "TOKENS".bold(),
+        "DEPENDS ON".bold(),
+    );
+    for stage in &run.stages {
+        println!(
+            "{:<28} {:<12} {:>9} {:>7} {:>8} {:>9}  {}",
+            stage.id,
+            stage.status.to_string(),
+            duration(stage.duration_secs),
+            stage.retries,
+            if stage.merge_conflict { "yes" } else { "-" },
+            stage.tokens,
+            if stage.dependencies.is_empty() {
+                "-".to_string()
+            } else {
+                stage.dependencies.join(", ")
+            },
+        );
+    }
+    Ok(())
+}
+
+fn duration(secs: Option<i64>) -> String {
+    secs.map(format_elapsed).unwrap_or_else(|| "-".to_string())
+}
+
+fn percent(rate: f64) -> String {
+    format!("{:.0}%", rate * 100.0)
+}
@@ -5,8 +5,17 @@ use colored::Colorize;
 use std::fs;
 use std::path::Path;
 
+use crate::fs::run_history::{stash_run_history, RUN_HISTORY_STASH_DIR};
 use crate::git::branch::branch_name_for_stage;
 use crate::git::runner::run_git;
+use crate::git::worktree::{uses_default_worktrees_root, worktrees_root};
+use crate::say;
+
+/// Directory `loom init` builds in before moving it into place as `.work/`.
+///
+/// A crash mid-init leaves this directory (never a half-built `.work/`), so
+/// the next `loom init` can detect and discard it.
+pub const INIT_STAGING_DIR: &str = ".work.init";
 
 /// Prune stale git worktrees that have been deleted but are still registered
 pub fn prune_stale_worktrees(repo_root: &Path) -> Result<()> {
@@ -14,7 +23,7 @@ pub fn prune_stale_worktrees(repo_root: &Path) -> Result<()> {
 
     match result {
         Ok(output) if output.status.success() => {
-            println!("  {} Stale worktrees pruned", "✓".green().bold());
+            say!("  {} Stale worktrees pruned", "✓".green().bold());
         }
         Ok(output) => {
             let stderr = String::from_utf8_lossy(&output.stderr);
@@ -38,11 +47,11 @@ pub fn prune_stale_worktrees(repo_root: &Path) -> Result<()> {
 
 /// Kill any orphaned loom sessions from previous runs
 pub fn cleanup_orphaned_sessions() -> Result<()> {
-    println!("  {} No orphaned sessions to clean", "✓".green().bold());
+    say!("  {} No orphaned sessions to clean", "✓".green().bold());
     Ok(())
 }
 
-/// Remove the existing .work/ directory
+/// Remove the existing .work/ directory, stashing its run history
 pub fn cleanup_work_directory(repo_root: &Path) -> Result<()> {
     let work_dir = repo_root.join(".work");
 
@@ -50,29 +59,94 @@ pub fn cleanup_work_directory(repo_root: &Path) -> Result<()> {
         return Ok(());
     }
 
+    let stashed = stash_run_history(repo_root)?;
     fs::remove_dir_all(&work_dir).with_context(|| {
         format!(
             "Failed to remove .work/ directory at {}",
             work_dir.display()
         )
     })?;
-    println!("  {} Removed old {}", "✓".green().bold(), ".work/".dimmed());
+    say!("  {} Removed old {}", "✓".green().bold(), ".work/".dimmed());
+    if stashed > 0 {
+        say!(
+            "  {} Kept {} archived run(s) in {}",
+            "✓".green().bold(),
+            stashed,
+            format!("{RUN_HISTORY_STASH_DIR}/").dimmed()
+        );
+    }
 
     Ok(())
 }
 
-/// Remove the .work/ directory silently (used for cleanup on initialization failure)
-pub fn remove_work_directory_on_failure(repo_root: &Path) {
-    let work_dir = repo_root.join(".work");
+/// Remove the init staging directory silently (used for cleanup on
+/// initialization failure)
+pub fn remove_staging_directory_on_failure(repo_root: &Path) {
+    let staging = repo_root.join(INIT_STAGING_DIR);
 
-    if work_dir.exists() {
-        let _ = fs::remove_dir_all(&work_dir);
+    if staging.exists() {
+        let _ = fs::remove_dir_all(&staging);
     }
 }
 
+/// Discard the staging directory left behind by an interrupted `loom init`.
+///
+/// Init is deterministic from the plan file, so rebuilding from scratch
+/// continues exactly where the interrupted run stopped.
+///
+/// Returns `true` if partial state was found and removed.
+pub fn discard_interrupted_init(repo_root: &Path) -> Result<bool> {
+    let staging = repo_root.join(INIT_STAGING_DIR);
+    if !staging.exists() {
+        return Ok(false);
+    }
+
+    let stage_files = fs::read_dir(staging.join("stages"))
+        .map(|entries| entries.count())
+        .unwrap_or(0);
+    let has_config = staging.join("config.toml").exists();
+    say!(
+        "  {} Found interrupted init {} ({} stage file{}, config {})",
+        "!".yellow().bold(),
+        format!("{INIT_STAGING_DIR}/").dimmed(),
+        stage_files,
+        if stage_files == 1 { "" } else { "s" },
+        if has_config { "written" } else { "missing" }
+    );
+
+    fs::remove_dir_all(&staging).with_context(|| {
+        format!(
+            "Failed to remove interrupted init state at {}",
+            staging.display()
+        )
+    })?;
+    say!(
+        "  {} Discarded partial state; initializing from scratch",
+        "✓".green().bold()
+    );
+    Ok(true)
+}
+
+/// Atomically move the fully built staging directory into place as `.work/`.
+pub fn commit_staging_directory(repo_root: &Path) -> Result<()> {
+    let staging = repo_root.join(INIT_STAGING_DIR);
+    let work_dir = repo_root.join(".work");
+    fs::rename(&staging, &work_dir).with_context(|| {
+        format!(
+            "Failed to move {} into place at {}",
+            staging.display(),
+            work_dir.display()
+        )
+    })
+}
+
 /// Remove existing loom worktrees and the .worktrees/ directory
+///
+/// A configured `[worktrees] root` outside the repo may be shared, so it is
+/// only removed when empty.
 pub fn cleanup_worktrees_directory(repo_root: &Path) -> Result<()> {
-    let worktrees_dir = repo_root.join(".worktrees");
+    let worktrees_dir = worktrees_root(repo_root);
+    let in_repo = uses_default_worktrees_root(repo_root);
 
     if !worktrees_dir.exists() {
         return Ok(());
@@ -96,19 +170,24 @@ pub fn cleanup_worktrees_directory(repo_root: &Path) -> Result<()> {
     let _ = run_git(&["worktree", "prune"], repo_root);
 
     if worktrees_dir.exists() {
-        fs::remove_dir_all(&worktrees_dir).with_context(|| {
-            format!(
-                "Failed to remove .worktrees/ directory at {}",
-                worktrees_dir.display()
-            )
-        })?;
+        if in_repo {
+            fs::remove_dir_all(&worktrees_dir).with_context(|| {
+                format!(
+                    "Failed to remove .worktrees/ directory at {}",
+                    worktrees_dir.display()
+                )
+            })?;
+        } else {
+            let _ = fs::remove_dir(&worktrees_dir);
+        }
     }
 
-    println!(
-        "  {} Removed old {}",
-        "✓".green().bold(),
-        ".worktrees/".dimmed()
-    );
+    let label = if in_repo {
+        ".worktrees/".to_string()
+    } else {
+        format!("worktrees in {}", worktrees_dir.display())
+    };
+    say!("  {} Removed old {}", "✓".green().bold(), label.dimmed());
 
     Ok(())
 }
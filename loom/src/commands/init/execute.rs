@@ -1,21 +1,27 @@
 //! Main execution entry point for loom init command.
 
 use crate::fs::permissions::{ensure_loom_permissions, migrate_legacy_trust};
-use crate::fs::work_dir::WorkDir;
+use crate::fs::run_history::restore_run_history;
+use crate::fs::work_dir::{read_worktrees_config, write_worktrees_config, WorkDir};
 use crate::fs::work_integrity::validate_work_dir_state;
 use crate::git::install_pre_commit_hook;
+use crate::git::worktree::WorktreesConfig;
+use crate::say;
 use anyhow::{bail, Result};
 use colored::Colorize;
 use std::path::{Path, PathBuf};
 
 use super::cleanup::{
     cleanup_orphaned_sessions, cleanup_work_directory, cleanup_worktrees_directory,
-    prune_stale_worktrees, remove_work_directory_on_failure,
+    commit_staging_directory, discard_interrupted_init, prune_stale_worktrees,
+    remove_staging_directory_on_failure, INIT_STAGING_DIR,
 };
 use super::plan_setup::initialize_with_plan;
+use super::switch::{resolve_previous_run, restore_previous_run, stash_previous_run, PreviousRun};
 
-/// RAII guard that cleans up .work directory on drop unless disarmed.
+/// RAII guard that cleans up the staging directory on drop unless disarmed.
 /// This ensures cleanup happens on ANY failure path, not just plan parsing.
+/// (A hard crash skips the guard; the next init discards the leftovers.)
 struct InitGuard {
     repo_root: PathBuf,
     work_created: bool,
@@ -43,50 +49,69 @@ impl InitGuard {
 impl Drop for InitGuard {
     fn drop(&mut self) {
         if self.work_created && !self.disarmed {
-            println!(
+            say!(
                 "  {} Cleaning up {} due to initialization failure",
                 "→".yellow().bold(),
-                ".work/".dimmed()
+                format!("{INIT_STAGING_DIR}/").dimmed()
             );
-            remove_work_directory_on_failure(&self.repo_root);
+            remove_staging_directory_on_failure(&self.repo_root);
         }
     }
 }
 
 /// Initialize the .work/ directory structure
 ///
+/// Everything is built in [`INIT_STAGING_DIR`] and renamed to `.work/` as the
+/// last step, so `.work/` is either absent or complete. Partial state from an
+/// interrupted init is reported and discarded before starting over.
+///
 /// # Arguments
 /// * `plan_path` - Optional path to a plan file to initialize with
 /// * `clean` - If true, clean up stale resources before initialization
-pub fn execute(plan_path: Option<PathBuf>, clean: bool) -> Result<()> {
+/// * `switch_plan` - If true, archive an existing `.work/` into the new
+///   `.work/archive/plans/` instead of refusing or removing it
+pub fn execute(plan_path: Option<PathBuf>, clean: bool, switch_plan: bool) -> Result<()> {
     let repo_root = std::env::current_dir()?;
     let repo_bootstrap = crate::git::ensure_repo_ready_for_worktrees(&repo_root)?;
 
     // Validate .work directory state before proceeding
     validate_work_dir_state(&repo_root)?;
 
+    // Decide about an existing .work/ before touching anything: replacing
+    // another plan's state needs a confirmation or --switch-plan.
+    let previous = resolve_previous_run(&repo_root, plan_path.as_deref(), clean, switch_plan)?;
+
     print_header();
 
     print_repo_bootstrap(repo_bootstrap);
 
-    println!("\n{}", "Cleanup".bold());
-    println!("{}", "─".repeat(40).dimmed());
+    say!("\n{}", "Cleanup".bold());
+    say!("{}", "─".repeat(40).dimmed());
 
     prune_stale_worktrees(&repo_root)?;
     cleanup_orphaned_sessions()?;
 
-    if clean {
-        cleanup_work_directory(&repo_root)?;
-        cleanup_worktrees_directory(&repo_root)?;
+    // The worktree root is configured in .work/config.toml: read it before
+    // --clean removes .work/, and carry it into the new config.
+    let worktrees_config = read_worktrees_config(&repo_root.join(".work")).unwrap_or_default();
+
+    match previous {
+        Some(PreviousRun::Discard) => {
+            cleanup_worktrees_directory(&repo_root)?;
+            cleanup_work_directory(&repo_root)?;
+        }
+        Some(PreviousRun::Archive) => stash_previous_run(&repo_root)?,
+        None if clean => cleanup_worktrees_directory(&repo_root)?,
+        None => {}
     }
 
-    println!("\n{}", "Initialize".bold());
-    println!("{}", "─".repeat(40).dimmed());
+    say!("\n{}", "Initialize".bold());
+    say!("{}", "─".repeat(40).dimmed());
 
-    // `loom init` is one-shot: if .work/ already exists, refuse. Pass --clean
-    // to wipe and start over. Reusing an existing .work/ would silently
-    // overlay the new plan's stages on top of the previous plan's files,
-    // producing duplicate ids and an unrecoverable graph.
+    // `loom init` is one-shot: resolve_previous_run refused or moved an
+    // existing .work/. Reusing one would silently overlay the new plan's
+    // stages on top of the previous plan's files, producing duplicate ids
+    // and an unrecoverable graph.
     if repo_root.join(".work").exists() {
         bail!(
             ".work/ already initialized.\n\
@@ -95,11 +120,16 @@ pub fn execute(plan_path: Option<PathBuf>, clean: bool) -> Result<()> {
         );
     }
 
+    discard_interrupted_init(&repo_root)?;
+
     let mut guard = InitGuard::new(repo_root.clone());
-    let work_dir = WorkDir::new(".")?;
+    let work_dir = WorkDir::at(repo_root.join(INIT_STAGING_DIR));
     work_dir.initialize()?;
     guard.mark_work_created();
-    println!(
+    if worktrees_config != WorktreesConfig::default() {
+        write_worktrees_config(work_dir.root(), &worktrees_config)?;
+    }
+    say!(
         "  {} Directory structure created {}",
         "✓".green().bold(),
         ".work/".dimmed()
@@ -108,10 +138,10 @@ pub fn execute(plan_path: Option<PathBuf>, clean: bool) -> Result<()> {
     // Install git pre-commit hook to prevent .work commits
     match install_pre_commit_hook(&repo_root) {
         Ok(true) => {
-            println!("  {} Git pre-commit hook installed", "✓".green().bold());
+            say!("  {} Git pre-commit hook installed", "✓".green().bold());
         }
         Ok(false) => {
-            println!(
+            say!(
                 "  {} Git pre-commit hook {} up to date",
                 "✓".green().bold(),
                 "already".dimmed()
@@ -128,7 +158,7 @@ pub fn execute(plan_path: Option<PathBuf>, clean: bool) -> Result<()> {
     }
 
     ensure_loom_permissions(&repo_root)?;
-    println!("  {} Permissions configured", "✓".green().bold());
+    say!("  {} Permissions configured", "✓".green().bold());
 
     // Check for CLAUDE.md
     if let Some(home) = dirs::home_dir() {
@@ -147,16 +177,37 @@ pub fn execute(plan_path: Option<PathBuf>, clean: bool) -> Result<()> {
         eprintln!("  {} Legacy trust migration: {}", "!".yellow().bold(), e);
     }
 
-    if let Some(path) = plan_path {
-        let stage_count = initialize_with_plan(&work_dir, &path)?;
-        print_summary(Some(&path), stage_count);
-    } else {
-        print_summary(None, 0);
+    let stage_count = match &plan_path {
+        Some(path) => initialize_with_plan(&work_dir, path)?,
+        None => 0,
+    };
+
+    // Restore the run history last, so a failed init leaves it stashed
+    let restored = restore_run_history(&repo_root, work_dir.root())?;
+    if restored > 0 {
+        say!(
+            "  {} Restored {} archived run(s) {}",
+            "✓".green().bold(),
+            restored,
+            ".work/archive/runs/".dimmed()
+        );
     }
 
-    // Success - disarm the guard to prevent cleanup
+    if let Some(archived) = restore_previous_run(&repo_root, work_dir.root())? {
+        let archived = archived.strip_prefix(work_dir.root()).unwrap_or(&archived);
+        say!(
+            "  {} Archived the previous run {}",
+            "✓".green().bold(),
+            format!(".work/{}/", archived.display()).dimmed()
+        );
+    }
+
+    // Success - move the staged directory into place and disarm the guard
+    commit_staging_directory(&repo_root)?;
     guard.disarm();
 
+    print_summary(plan_path.as_deref(), stage_count);
+
     Ok(())
 }
 
@@ -165,15 +216,15 @@ fn print_repo_bootstrap(repo_bootstrap: crate::git::RepoBootstrapResult) {
         return;
     }
 
-    println!("\n{}", "Git".bold());
-    println!("{}", "─".repeat(40).dimmed());
+    say!("\n{}", "Git".bold());
+    say!("{}", "─".repeat(40).dimmed());
 
     if repo_bootstrap.initialized_repo {
-        println!("  {} Initialized git repository", "✓".green().bold());
+        say!("  {} Initialized git repository", "✓".green().bold());
     }
 
     if repo_bootstrap.created_initial_commit {
-        println!(
+        say!(
             "  {} Created bootstrap commit for worktree support",
             "✓".green().bold()
         );
@@ -187,27 +238,27 @@ fn print_header() {
 
 /// Print the final summary
 fn print_summary(plan_path: Option<&Path>, stage_count: usize) {
-    println!();
-    println!("{}", "═".repeat(40).dimmed());
+    say!();
+    say!("{}", "═".repeat(40).dimmed());
 
     if let Some(path) = plan_path {
-        println!(
+        say!(
             "{} Initialized from {}",
             "✓".green().bold(),
             path.display().to_string().cyan()
         );
-        println!(
+        say!(
             "  {} stage{} ready for execution",
             stage_count.to_string().bold(),
             if stage_count == 1 { "" } else { "s" }
         );
     } else {
-        println!("{} Empty workspace initialized", "✓".green().bold());
+        say!("{} Empty workspace initialized", "✓".green().bold());
     }
 
-    println!();
-    println!("{}", "Next steps:".bold());
-    println!("  {}  Start execution", "loom run".cyan());
-    println!("  {}  View dashboard", "loom status".cyan());
-    println!();
+    say!();
+    say!("{}", "Next steps:".bold());
+    say!("  {}  Start execution", "loom run".cyan());
+    say!("  {}  View dashboard", "loom status".cyan());
+    say!();
 }
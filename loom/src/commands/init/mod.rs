@@ -1,13 +1,18 @@
 //! Initialize the .work/ directory structure for loom orchestration.
 //!
 //! This module provides the `loom init` command which sets up the workspace,
-//! optionally initializes from a plan file, and creates stage files.
+//! optionally initializes from a plan file, and creates stage files. With
+//! `--interactive` it first builds the plan from prompts. `--switch-plan`
+//! archives the run of a previous plan instead of refusing to replace it.
 
 mod cleanup;
 mod execute;
 mod plan_setup;
+mod switch;
+mod wizard;
 
 #[cfg(test)]
 mod tests;
 
 pub use execute::execute;
+pub use wizard::execute_interactive;
@@ -7,17 +7,19 @@ use crate::models::stage::{Stage, StageStatus};
 use crate::plan::graph::levels::compute_all_levels;
 use crate::plan::parser::parse_plan;
 use crate::plan::schema::{
-    check_knowledge_recommendations, check_sandbox_recommendations, detect_stage_type,
-    validate_structural_preflight, StageDefinition,
+    check_knowledge_recommendations, check_sandbox_recommendations, check_working_dirs,
+    detect_stage_type, validate_structural_preflight, ConflictAvoidance, StageDefinition,
 };
 use crate::sandbox::{merge_config as merge_sandbox_config, validate_config as validate_sandbox};
+use crate::say;
+use crate::validation::parse_duration;
 use crate::verify::serialize_stage_to_markdown;
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use chrono::Utc;
 use colored::Colorize;
 use std::fs;
 use std::path::Path;
-use toml_edit::{value, Item, Table};
+use toml_edit::{value, Array, Item, Table};
 
 // Plan / config writes go through the centralized `fs::work_dir` API using
 // `toml_edit`, which preserves comments and unknown keys across edits.
@@ -37,7 +39,7 @@ pub fn initialize_with_plan(work_dir: &WorkDir, plan_path: &Path) -> Result<usiz
     let parsed_plan = parse_plan(&canonical_path)
         .with_context(|| format!("Failed to parse plan file: {}", canonical_path.display()))?;
 
-    println!(
+    say!(
         "  {} Plan parsed: {}",
         "✓".green().bold(),
         parsed_plan.name.bold()
@@ -86,13 +88,13 @@ pub fn initialize_with_plan(work_dir: &WorkDir, plan_path: &Path) -> Result<usiz
                     }
                 }
             }
-            println!(
+            say!(
                 "  {} {}",
                 "💡".blue(),
                 "No sandbox network domains configured. Suggested domains for your project:".blue()
             );
             for d in &domains {
-                println!("      - \"{}\"", d);
+                say!("      - \"{}\"", d);
             }
         }
     }
@@ -106,6 +108,22 @@ pub fn initialize_with_plan(work_dir: &WorkDir, plan_path: &Path) -> Result<usiz
         println!("  {} {}", "⚠".yellow().bold(), warning.yellow());
     }
 
+    // Sessions start in (and acceptance runs from) each stage's working_dir,
+    // so it must exist before the stage is spawned.
+    if let Some(root) = repo_root.as_deref() {
+        match check_working_dirs(&stages, root) {
+            Ok(warnings) => {
+                for warning in &warnings {
+                    println!("  {} {}", "⚠".yellow().bold(), warning.yellow());
+                }
+            }
+            Err(errors) => {
+                let messages: Vec<_> = errors.iter().map(|e| e.to_string()).collect();
+                bail!("Invalid working_dir:\n  - {}", messages.join("\n  - "));
+            }
+        }
+    }
+
     // Validate every stage's resolved sandbox configuration at init time.
     // This catches incompatible combinations (e.g. bypass-permissions) before
     // the daemon ever tries to spawn a session.
@@ -152,6 +170,35 @@ pub fn initialize_with_plan(work_dir: &WorkDir, plan_path: &Path) -> Result<usiz
     plan_table["plan_id"] = value(parsed_plan.id.clone());
     plan_table["plan_name"] = value(parsed_plan.name.clone());
     plan_table["base_branch"] = value(base_branch.clone());
+    plan_table["namespace"] = value(crate::namespace::derive(&project_root, &parsed_plan.id));
+    if parsed_plan.metadata.loom.conflict_avoidance == ConflictAvoidance::Strict {
+        plan_table["conflict_avoidance"] = value("strict");
+    }
+    if parsed_plan.metadata.loom.auto_code_review {
+        plan_table["auto_code_review"] = value(true);
+    }
+    if let Some(template) = &parsed_plan.metadata.loom.branch_template {
+        plan_table["branch_template"] = value(template.clone());
+    }
+    if parsed_plan.metadata.loom.push_branches {
+        plan_table["push_branches"] = value(true);
+        if let Some(remote) = &parsed_plan.metadata.loom.push_remote {
+            plan_table["push_remote"] = value(remote.clone());
+        }
+    }
+    if parsed_plan.metadata.loom.sparse_checkout {
+        plan_table["sparse_checkout"] = value(true);
+        let include = &parsed_plan.metadata.loom.sparse_include;
+        if !include.is_empty() {
+            plan_table["sparse_include"] = value(Array::from_iter(include.iter().cloned()));
+        }
+    }
+    if parsed_plan.metadata.loom.knowledge_first {
+        plan_table["knowledge_first"] = value(true);
+    }
+    if let Some(every) = parsed_plan.metadata.loom.knowledge_refresh_every {
+        plan_table["knowledge_refresh_every"] = value(i64::from(every));
+    }
     doc.insert("plan", Item::Table(plan_table));
 
     work_dir::write_config(work_dir.root(), &doc).context("Failed to write .work/config.toml")?;
@@ -169,7 +216,7 @@ pub fn initialize_with_plan(work_dir: &WorkDir, plan_path: &Path) -> Result<usiz
     )
     .context("Failed to persist remote control config")?;
 
-    println!(
+    say!(
         "  {} Config saved {}",
         "✓".green().bold(),
         "config.toml".dimmed()
@@ -183,12 +230,12 @@ pub fn initialize_with_plan(work_dir: &WorkDir, plan_path: &Path) -> Result<usiz
     }
 
     let stage_count = stages.len();
-    println!(
+    say!(
         "\n{} {}",
         "Stages".bold(),
         format!("({stage_count})").dimmed()
     );
-    println!("{}", "─".repeat(40).dimmed());
+    say!("{}", "─".repeat(40).dimmed());
 
     let max_id_len = stages.iter().map(|s| s.id.len()).max().unwrap_or(0);
 
@@ -209,7 +256,7 @@ pub fn initialize_with_plan(work_dir: &WorkDir, plan_path: &Path) -> Result<usiz
             "○".yellow()
         };
 
-        println!(
+        say!(
             "  {}  {:width$}  {}",
             status_indicator,
             stage.id.dimmed(),
@@ -233,6 +280,19 @@ pub(crate) fn create_stage_from_definition(stage_def: &StageDefinition, plan_id:
 
     let stage_type = detect_stage_type(stage_def);
 
+    let (setup, before_stage, after_stage) = match &stage_def.package {
+        Some(package) => (
+            stage_def.setup.iter().map(|c| package.render(c)).collect(),
+            package.scope_checks(&stage_def.before_stage),
+            package.scope_checks(&stage_def.after_stage),
+        ),
+        None => (
+            stage_def.setup.clone(),
+            stage_def.before_stage.clone(),
+            stage_def.after_stage.clone(),
+        ),
+    };
+
     Stage {
         id: stage_def.id.clone(),
         name: stage_def.name.clone(),
@@ -240,14 +300,16 @@ pub(crate) fn create_stage_from_definition(stage_def: &StageDefinition, plan_id:
         status,
         dependencies: stage_def.dependencies.clone(),
         parallel_group: stage_def.parallel_group.clone(),
-        acceptance: stage_def.acceptance.clone(),
-        setup: stage_def.setup.clone(),
-        files: stage_def.files.clone(),
+        acceptance: stage_def.scoped_acceptance(),
+        setup,
+        files: stage_def.scoped_files(),
         stage_type,
         plan_id: Some(plan_id.to_string()),
         worktree: None,
         session: None,
         held: false,
+        held_by: None,
+        assignee: None,
         parent_stage: None,
         child_stages: Vec::new(),
         created_at: now,
@@ -277,8 +339,8 @@ pub(crate) fn create_stage_from_definition(stage_def: &StageDefinition, plan_id:
         wiring: stage_def.wiring.clone(),
         wiring_tests: stage_def.wiring_tests.clone(),
         dead_code_check: stage_def.dead_code_check.clone(),
-        before_stage: stage_def.before_stage.clone(),
-        after_stage: stage_def.after_stage.clone(),
+        before_stage,
+        after_stage,
         fix_attempts: 0,
         dispute_count: 0,
         evidence_rounds: 0,
@@ -287,11 +349,31 @@ pub(crate) fn create_stage_from_definition(stage_def: &StageDefinition, plan_id:
         execution_mode: stage_def.execution_mode,
         max_fix_attempts: None,
         review_reason: None,
+        waiting_prompt: None,
+        waiting_since: None,
         bug_fix: stage_def.bug_fix,
         regression_test: stage_def.regression_test.clone(),
         model: stage_def.model.clone(),
         reasoning_effort: stage_def.reasoning_effort.clone(),
         is_possibly_stuck: false,
         ultracode: stage_def.ultracode,
+        pair: stage_def.pair,
+        pair_review: None,
+        worktree_setup: stage_def.worktree_setup.clone(),
+        instructions: stage_def.instructions.clone(),
+        prompt_prefix: stage_def.prompt_prefix.clone(),
+        container: stage_def.container.clone(),
+        keep_terminal: stage_def.keep_terminal,
+        expected_outputs: stage_def.outputs.clone(),
+        params: stage_def.params.clone(),
+        requires_outputs: stage_def.requires_outputs.clone(),
+        new_files: stage_def.new_files.clone(),
+        expected_duration_secs: stage_def
+            .expected_duration
+            .as_deref()
+            .and_then(|d| parse_duration(d).ok())
+            .map(|d| d.as_secs()),
+        acceptance_isolation: stage_def.acceptance_isolation.clone(),
+        skip_if_satisfied: stage_def.skip_if_satisfied,
     }
 }
@@ -0,0 +1,292 @@
+//! Switching an initialized `.work/` to another plan.
+//!
+//! `loom init <plan>` on top of a `.work/` that belongs to a different plan
+//! lists what the switch would lose (running sessions, stages whose work is
+//! not merged, facts) and only proceeds after an explicit confirmation.
+//! `--switch-plan` keeps the old run instead: `.work/` is set aside as
+//! [`PREVIOUS_RUN_STASH_DIR`] and moved into the new
+//! `.work/archive/plans/<plan-id>-<time>/` once init succeeds.
+
+use anyhow::{bail, Context, Result};
+use chrono::Utc;
+use colored::Colorize;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::commands::common::picker::can_prompt;
+use crate::daemon::DaemonServer;
+use crate::fs::facts::load_facts;
+use crate::fs::run_history::{archive_run, stash_run_history};
+use crate::models::session::{Session, SessionStatus};
+use crate::models::stage::{StageStatus, StatusBucket};
+use crate::parser::frontmatter::parse_from_markdown;
+use crate::say;
+use crate::verify::transitions::list_all_stages;
+
+/// Directory, relative to the repository root, that holds the previous
+/// `.work/` while `loom init --switch-plan` builds the new one.
+pub const PREVIOUS_RUN_STASH_DIR: &str = ".work.previous";
+
+/// What happens to an existing `.work/` before init
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreviousRun {
+    /// Remove it, with the stage worktrees (`--clean` or a confirmed switch)
+    Discard,
+    /// Keep it under `.work/archive/plans/` (`--switch-plan`)
+    Archive,
+}
+
+/// What replacing an existing `.work/` would lose
+#[derive(Debug, Clone, Default)]
+pub struct SwitchReport {
+    /// Plan source recorded in `config.toml`
+    pub current_plan: Option<String>,
+    /// `(session id, stage id)` of sessions still spawning or running
+    pub running_sessions: Vec<(String, Option<String>)>,
+    /// Stages with started work that is not merged, with their status
+    pub unmerged_stages: Vec<(String, StageStatus)>,
+    /// Keys of the plan-wide facts
+    pub facts: Vec<String>,
+}
+
+impl SwitchReport {
+    /// Collect the report from `work_dir`.
+    pub fn inspect(work_dir: &Path) -> Result<Self> {
+        let current_plan =
+            crate::fs::get_source_path(work_dir)?.map(|path| path.display().to_string());
+        let running_sessions = load_sessions(work_dir)
+            .into_iter()
+            .filter(|s| matches!(s.status, SessionStatus::Spawning | SessionStatus::Running))
+            .map(|s| (s.id, s.stage_id))
+            .collect();
+        let unmerged_stages = list_all_stages(work_dir)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|s| {
+                !s.merged
+                    && s.status != StageStatus::Skipped
+                    && s.status.bucket() != StatusBucket::Pending
+            })
+            .map(|s| (s.id, s.status))
+            .collect();
+        let facts = load_facts(work_dir)
+            .map(|store| store.facts.into_iter().map(|f| f.key).collect())
+            .unwrap_or_default();
+        Ok(Self {
+            current_plan,
+            running_sessions,
+            unmerged_stages,
+            facts,
+        })
+    }
+
+    /// Whether nothing would be lost.
+    pub fn is_empty(&self) -> bool {
+        self.running_sessions.is_empty() && self.unmerged_stages.is_empty() && self.facts.is_empty()
+    }
+
+    /// The report as diff-style lines, one per kind of loss.
+    pub fn lines(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        if !self.running_sessions.is_empty() {
+            let sessions: Vec<String> = self
+                .running_sessions
+                .iter()
+                .map(|(id, stage)| match stage {
+                    Some(stage) => format!("{id} ({stage})"),
+                    None => id.clone(),
+                })
+                .collect();
+            lines.push(format!(
+                "- {} running session(s): {}",
+                sessions.len(),
+                sessions.join(", ")
+            ));
+        }
+        if !self.unmerged_stages.is_empty() {
+            let stages: Vec<String> = self
+                .unmerged_stages
+                .iter()
+                .map(|(id, status)| format!("{id} ({status})"))
+                .collect();
+            lines.push(format!(
+                "- {} unmerged stage(s): {}",
+                stages.len(),
+                stages.join(", ")
+            ));
+        }
+        if !self.facts.is_empty() {
+            lines.push(format!(
+                "- {} fact(s): {}",
+                self.facts.len(),
+                self.facts.join(", ")
+            ));
+        }
+        lines
+    }
+
+    fn print(&self, new_plan: Option<&Path>) {
+        println!(
+            "{} .work/ belongs to {}{}",
+            "!".yellow().bold(),
+            self.current_plan
+                .as_deref()
+                .unwrap_or("another plan")
+                .cyan(),
+            new_plan
+                .map(|p| format!(", not {}", p.display()))
+                .unwrap_or_default()
+        );
+        if self.is_empty() {
+            println!("  Nothing unmerged or running would be lost.");
+        }
+        for line in self.lines() {
+            println!("  {}", line.red());
+        }
+    }
+}
+
+/// Decide what to do with an existing `.work/` before initializing
+/// `new_plan`.
+///
+/// Returns `None` when there is no `.work/`. Without `--clean` or
+/// `--switch-plan`, replacing the `.work/` of another plan needs a
+/// confirmation on the terminal; re-initializing the same plan is refused.
+pub fn resolve_previous_run(
+    repo_root: &Path,
+    new_plan: Option<&Path>,
+    clean: bool,
+    switch_plan: bool,
+) -> Result<Option<PreviousRun>> {
+    let work_dir = repo_root.join(".work");
+    if !work_dir.is_dir() {
+        return Ok(None);
+    }
+    if clean {
+        return Ok(Some(PreviousRun::Discard));
+    }
+    if !switch_plan && !is_other_plan(&work_dir, new_plan) {
+        bail!(
+            ".work/ already initialized.\n\
+             Run `loom init <plan> --clean` to wipe existing state and start over,\n\
+             or `loom clean` followed by `loom init <plan>`."
+        );
+    }
+    if DaemonServer::is_running(&work_dir) {
+        bail!("The daemon is running for the current plan; stop it with `loom stop` first");
+    }
+
+    if switch_plan {
+        return Ok(Some(PreviousRun::Archive));
+    }
+
+    SwitchReport::inspect(&work_dir)?.print(new_plan);
+    if can_prompt() && confirm("Discard this state and initialize the new plan?")? {
+        return Ok(Some(PreviousRun::Discard));
+    }
+    bail!(
+        "Not replacing .work/.\n\
+         Run `loom init <plan> --switch-plan` to archive the current run into .work/archive/,\n\
+         or `loom init <plan> --clean` to discard it."
+    )
+}
+
+/// Whether `work_dir` was initialized from a plan other than `new_plan`.
+fn is_other_plan(work_dir: &Path, new_plan: Option<&Path>) -> bool {
+    let (Some(new_plan), Ok(Some(current))) = (new_plan, crate::fs::resolve_source_path(work_dir))
+    else {
+        return false;
+    };
+    let canonical = |p: &Path| p.canonicalize().unwrap_or_else(|_| p.to_path_buf());
+    canonical(&current) != canonical(new_plan)
+}
+
+/// Set `.work/` aside for [`restore_previous_run`], recording its run in the
+/// run history first.
+pub fn stash_previous_run(repo_root: &Path) -> Result<()> {
+    let work_dir = repo_root.join(".work");
+    let stash = repo_root.join(PREVIOUS_RUN_STASH_DIR);
+    if stash.exists() {
+        bail!(
+            "{PREVIOUS_RUN_STASH_DIR}/ already exists from an interrupted `loom init --switch-plan`; \
+             run `loom init` again without --switch-plan to archive it, or remove it"
+        );
+    }
+
+    if let Err(e) = archive_run(&work_dir) {
+        eprintln!("Warning: failed to record the current run in the run history: {e:#}");
+    }
+    stash_run_history(repo_root)?;
+    fs::rename(&work_dir, &stash)
+        .with_context(|| format!("Failed to move .work/ to {PREVIOUS_RUN_STASH_DIR}/"))?;
+    say!(
+        "  {} Set the current run aside {}",
+        "✓".green().bold(),
+        format!("{PREVIOUS_RUN_STASH_DIR}/").dimmed()
+    );
+    Ok(())
+}
+
+/// Move a stashed `.work/` into `work_dir`'s `archive/plans/`, carrying
+/// over the plans it had archived itself.
+///
+/// Returns the archive path, or `None` when nothing was stashed.
+pub fn restore_previous_run(repo_root: &Path, work_dir: &Path) -> Result<Option<PathBuf>> {
+    let stash = repo_root.join(PREVIOUS_RUN_STASH_DIR);
+    if !stash.is_dir() {
+        return Ok(None);
+    }
+
+    let plans = work_dir.join("archive").join("plans");
+    fs::create_dir_all(&plans).context("Failed to create the plan archive directory")?;
+
+    let nested = stash.join("archive").join("plans");
+    if nested.is_dir() {
+        for entry in fs::read_dir(&nested)?.flatten() {
+            let target = plans.join(entry.file_name());
+            if !target.exists() {
+                fs::rename(entry.path(), &target)?;
+            }
+        }
+        fs::remove_dir_all(&nested)?;
+    }
+
+    let label = crate::fs::load_config(&stash)
+        .ok()
+        .flatten()
+        .and_then(|config| config.plan_id().map(String::from))
+        .unwrap_or_else(|| "plan".to_string());
+    let target = plans.join(format!("{label}-{}", Utc::now().format("%Y%m%d-%H%M%S")));
+    fs::rename(&stash, &target).with_context(|| {
+        format!(
+            "Failed to move {PREVIOUS_RUN_STASH_DIR}/ to {}",
+            target.display()
+        )
+    })?;
+    Ok(Some(target))
+}
+
+fn confirm(prompt: &str) -> Result<bool> {
+    print!("{prompt} [y/N] ");
+    std::io::stdout().flush()?;
+    let mut response = String::new();
+    std::io::stdin().read_line(&mut response)?;
+    Ok(matches!(
+        response.trim().to_lowercase().as_str(),
+        "y" | "yes"
+    ))
+}
+
+fn load_sessions(work_dir: &Path) -> Vec<Session> {
+    let Ok(entries) = fs::read_dir(work_dir.join("sessions")) else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().and_then(|e| e.to_str()) == Some("md"))
+        .filter_map(|path| fs::read_to_string(path).ok())
+        .filter_map(|content| parse_from_markdown::<Session>(&content, "Session").ok())
+        .collect()
+}
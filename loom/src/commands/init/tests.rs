@@ -5,8 +5,8 @@ use super::plan_setup::{create_stage_from_definition, initialize_with_plan};
 use crate::fs::work_dir::WorkDir;
 use crate::models::stage::{Stage, StageStatus, StageType as ModelStageType};
 use crate::plan::schema::{
-    AcceptanceCriterion, LoomConfig, LoomMetadata, SandboxConfig, StageDefinition,
-    StageSandboxConfig, StageType,
+    AcceptanceCriterion, LoomConfig, LoomMetadata, PackageKind, PackageTarget, SandboxConfig,
+    StageDefinition, StageSandboxConfig, StageType,
 };
 use crate::verify::serialize_stage_to_markdown;
 use chrono::Utc;
@@ -24,6 +24,15 @@ fn create_test_plan(dir: &Path, stages: Vec<StageDefinition>) -> PathBuf {
             sandbox: SandboxConfig::default(),
             change_impact: None,
             adjudication: None,
+            conflict_avoidance: Default::default(),
+            auto_code_review: false,
+            branch_template: None,
+            push_branches: false,
+            push_remote: None,
+            sparse_checkout: false,
+            sparse_include: Vec::new(),
+            knowledge_first: false,
+            knowledge_refresh_every: None,
             stages,
         },
     };
@@ -67,6 +76,20 @@ fn test_create_stage_from_definition_no_dependencies() {
         reasoning_effort: None,
         code_review: None,
         ultracode: false,
+        pair: false,
+        worktree_setup: vec![],
+        instructions: None,
+        prompt_prefix: None,
+        container: None,
+        keep_terminal: None,
+        outputs: Vec::new(),
+        params: Vec::new(),
+        requires_outputs: Vec::new(),
+        new_files: Vec::new(),
+        expected_duration: None,
+        acceptance_isolation: None,
+        skip_if_satisfied: false,
+        package: None,
     };
 
     let stage = create_stage_from_definition(&stage_def, "plan-001");
@@ -82,10 +105,46 @@ fn test_create_stage_from_definition_no_dependencies() {
     // The ultracode license propagates from the definition to the stage model
     let ultracode_def = StageDefinition {
         ultracode: true,
+        pair: false,
         ..stage_def
     };
     let ultracode_stage = create_stage_from_definition(&ultracode_def, "plan-001");
     assert!(ultracode_stage.ultracode);
+
+    // Worktree setup commands propagate so the orchestrator can run them at spawn
+    let setup_def = StageDefinition {
+        worktree_setup: vec!["cargo fetch".to_string()],
+        ..ultracode_def
+    };
+    let setup_stage = create_stage_from_definition(&setup_def, "plan-001");
+    assert_eq!(setup_stage.worktree_setup, vec!["cargo fetch".to_string()]);
+
+    // A package scopes commands and files; empty acceptance gets its test command
+    let package_def = StageDefinition {
+        acceptance: vec![],
+        setup: vec!["cargo build {{package}}".to_string()],
+        package: Some(PackageTarget {
+            kind: PackageKind::Cargo,
+            path: "crates/auth".to_string(),
+            name: Some("loom-auth".to_string()),
+        }),
+        ..setup_def
+    };
+    let package_stage = create_stage_from_definition(&package_def, "plan-001");
+    assert_eq!(
+        package_stage.acceptance,
+        vec![AcceptanceCriterion::Simple(
+            "cargo test -p loom-auth".to_string()
+        )]
+    );
+    assert_eq!(
+        package_stage.setup,
+        vec!["cargo build -p loom-auth".to_string()]
+    );
+    assert_eq!(
+        package_stage.files,
+        vec!["src/*.rs".to_string(), "crates/auth/**".to_string()]
+    );
 }
 
 #[test]
@@ -117,6 +176,20 @@ fn test_create_stage_from_definition_with_dependencies() {
         reasoning_effort: None,
         code_review: None,
         ultracode: false,
+        pair: false,
+        worktree_setup: vec![],
+        instructions: None,
+        prompt_prefix: None,
+        container: None,
+        keep_terminal: None,
+        outputs: Vec::new(),
+        params: Vec::new(),
+        requires_outputs: Vec::new(),
+        new_files: Vec::new(),
+        expected_duration: None,
+        acceptance_isolation: None,
+        skip_if_satisfied: false,
+        package: None,
     };
 
     let stage = create_stage_from_definition(&stage_def, "plan-002");
@@ -145,6 +218,8 @@ fn test_serialize_stage_to_markdown_minimal() {
         worktree: None,
         session: None,
         held: false,
+        held_by: None,
+        assignee: None,
         parent_stage: None,
         child_stages: vec![],
         created_at: Utc::now(),
@@ -183,12 +258,28 @@ fn test_serialize_stage_to_markdown_minimal() {
         execution_mode: None,
         max_fix_attempts: None,
         review_reason: None,
+        waiting_prompt: None,
+        waiting_since: None,
         bug_fix: None,
         regression_test: None,
         model: None,
         reasoning_effort: None,
         is_possibly_stuck: false,
         ultracode: false,
+        pair: false,
+        pair_review: None,
+        worktree_setup: Vec::new(),
+        instructions: None,
+        prompt_prefix: None,
+        container: None,
+        keep_terminal: None,
+        expected_outputs: Vec::new(),
+        params: Vec::new(),
+        requires_outputs: Vec::new(),
+        new_files: Vec::new(),
+        expected_duration_secs: None,
+        acceptance_isolation: None,
+        skip_if_satisfied: false,
     };
 
     let content = serialize_stage_to_markdown(&stage).unwrap();
@@ -219,6 +310,8 @@ fn test_serialize_stage_to_markdown_with_all_fields() {
         worktree: None,
         session: None,
         held: false,
+        held_by: None,
+        assignee: None,
         parent_stage: None,
         child_stages: vec![],
         created_at: Utc::now(),
@@ -257,12 +350,28 @@ fn test_serialize_stage_to_markdown_with_all_fields() {
         execution_mode: None,
         max_fix_attempts: None,
         review_reason: None,
+        waiting_prompt: None,
+        waiting_since: None,
         bug_fix: None,
         regression_test: None,
         model: None,
         reasoning_effort: None,
         is_possibly_stuck: false,
         ultracode: false,
+        pair: false,
+        pair_review: None,
+        worktree_setup: Vec::new(),
+        instructions: None,
+        prompt_prefix: None,
+        container: None,
+        keep_terminal: None,
+        expected_outputs: Vec::new(),
+        params: Vec::new(),
+        requires_outputs: Vec::new(),
+        new_files: Vec::new(),
+        expected_duration_secs: None,
+        acceptance_isolation: None,
+        skip_if_satisfied: false,
     };
 
     let content = serialize_stage_to_markdown(&stage).unwrap();
@@ -326,6 +435,20 @@ fn test_initialize_with_plan_creates_config() {
         reasoning_effort: None,
         code_review: None,
         ultracode: false,
+        pair: false,
+        worktree_setup: vec![],
+        instructions: None,
+        prompt_prefix: None,
+        container: None,
+        keep_terminal: None,
+        outputs: Vec::new(),
+        params: Vec::new(),
+        requires_outputs: Vec::new(),
+        new_files: Vec::new(),
+        expected_duration: None,
+        acceptance_isolation: None,
+        skip_if_satisfied: false,
+        package: None,
     };
 
     let plan_path = create_test_plan(temp_dir.path(), vec![stage_def]);
@@ -377,6 +500,20 @@ fn test_initialize_with_plan_creates_stage_files() {
             reasoning_effort: None,
             code_review: None,
             ultracode: false,
+            pair: false,
+            worktree_setup: vec![],
+            instructions: None,
+            prompt_prefix: None,
+            container: None,
+            keep_terminal: None,
+            outputs: Vec::new(),
+            params: Vec::new(),
+            requires_outputs: Vec::new(),
+            new_files: Vec::new(),
+            expected_duration: None,
+            acceptance_isolation: None,
+            skip_if_satisfied: false,
+            package: None,
         },
         StageDefinition {
             id: "stage-2".to_string(),
@@ -405,6 +542,20 @@ fn test_initialize_with_plan_creates_stage_files() {
             reasoning_effort: None,
             code_review: None,
             ultracode: false,
+            pair: false,
+            worktree_setup: vec![],
+            instructions: None,
+            prompt_prefix: None,
+            container: None,
+            keep_terminal: None,
+            outputs: Vec::new(),
+            params: Vec::new(),
+            requires_outputs: Vec::new(),
+            new_files: Vec::new(),
+            expected_duration: None,
+            acceptance_isolation: None,
+            skip_if_satisfied: false,
+            package: None,
         },
     ];
 
@@ -492,32 +643,120 @@ fn test_cleanup_orphaned_sessions_does_not_fail() {
 }
 
 #[test]
-fn test_remove_work_directory_on_failure_removes_directory() {
-    use super::cleanup::remove_work_directory_on_failure;
+fn test_remove_staging_directory_on_failure_removes_directory() {
+    use super::cleanup::{remove_staging_directory_on_failure, INIT_STAGING_DIR};
 
     let temp_dir = TempDir::new().unwrap();
-    let work_dir = temp_dir.path().join(".work");
+    let staging = temp_dir.path().join(INIT_STAGING_DIR);
 
-    fs::create_dir_all(&work_dir).unwrap();
-    fs::write(work_dir.join("test.txt"), "content").unwrap();
+    fs::create_dir_all(&staging).unwrap();
+    fs::write(staging.join("test.txt"), "content").unwrap();
 
-    assert!(work_dir.exists());
+    assert!(staging.exists());
 
-    remove_work_directory_on_failure(temp_dir.path());
+    remove_staging_directory_on_failure(temp_dir.path());
 
-    assert!(!work_dir.exists());
+    assert!(!staging.exists());
 }
 
 #[test]
-fn test_remove_work_directory_on_failure_nonexistent_ok() {
-    use super::cleanup::remove_work_directory_on_failure;
+fn test_remove_staging_directory_on_failure_nonexistent_ok() {
+    use super::cleanup::{remove_staging_directory_on_failure, INIT_STAGING_DIR};
 
     let temp_dir = TempDir::new().unwrap();
-    let work_dir = temp_dir.path().join(".work");
+    let staging = temp_dir.path().join(INIT_STAGING_DIR);
 
-    assert!(!work_dir.exists());
+    assert!(!staging.exists());
 
-    remove_work_directory_on_failure(temp_dir.path());
+    remove_staging_directory_on_failure(temp_dir.path());
 
-    assert!(!work_dir.exists());
+    assert!(!staging.exists());
+}
+
+#[test]
+fn test_interrupted_init_is_discarded_and_rebuilt() {
+    use super::cleanup::{commit_staging_directory, discard_interrupted_init, INIT_STAGING_DIR};
+
+    let temp_dir = TempDir::new().unwrap();
+    let repo_root = temp_dir.path();
+    assert!(!discard_interrupted_init(repo_root).unwrap());
+
+    // A crash after writing some stage files leaves only the staging dir
+    let staging = repo_root.join(INIT_STAGING_DIR);
+    fs::create_dir_all(staging.join("stages")).unwrap();
+    fs::write(staging.join("stages/0-partial.md"), "partial").unwrap();
+    assert!(!repo_root.join(".work").exists());
+
+    assert!(discard_interrupted_init(repo_root).unwrap());
+    assert!(!staging.exists());
+
+    let work_dir = WorkDir::at(&staging);
+    work_dir.initialize().unwrap();
+    commit_staging_directory(repo_root).unwrap();
+    assert!(!staging.exists());
+    assert!(repo_root.join(".work/stages").is_dir());
+    assert!(!repo_root.join(".work/stages/0-partial.md").exists());
+}
+
+#[test]
+fn test_switch_plan_requires_confirmation_and_archives_previous_run() {
+    use super::switch::{
+        resolve_previous_run, restore_previous_run, stash_previous_run, PreviousRun, SwitchReport,
+    };
+    use crate::fs::work_dir::update_config;
+
+    let temp_dir = TempDir::new().unwrap();
+    let repo_root = temp_dir.path();
+    let old_plan = repo_root.join("PLAN-old.md");
+    let new_plan = repo_root.join("PLAN-new.md");
+    fs::write(&old_plan, "old").unwrap();
+    fs::write(&new_plan, "new").unwrap();
+
+    let work_dir = WorkDir::at(repo_root.join(".work"));
+    work_dir.initialize().unwrap();
+    update_config(work_dir.root(), |doc| {
+        doc["plan"]["source_path"] = toml_edit::value(old_plan.display().to_string());
+        doc["plan"]["plan_id"] = toml_edit::value("PLAN-old");
+        Ok(())
+    })
+    .unwrap();
+    let mut stage = Stage::new("API".to_string(), None);
+    stage.id = "api".to_string();
+    stage.status = StageStatus::Completed;
+    crate::verify::transitions::save_stage(&stage, work_dir.root()).unwrap();
+
+    let report = SwitchReport::inspect(work_dir.root()).unwrap();
+    assert_eq!(
+        report.lines(),
+        vec!["- 1 unmerged stage(s): api (Completed)"]
+    );
+
+    // Re-initializing the same plan is refused outright; another plan needs
+    // a confirmation, which tests cannot give
+    let err = resolve_previous_run(repo_root, Some(&old_plan), false, false).unwrap_err();
+    assert!(err.to_string().contains("already initialized"), "{err}");
+    let err = resolve_previous_run(repo_root, Some(&new_plan), false, false).unwrap_err();
+    assert!(err.to_string().contains("--switch-plan"), "{err}");
+    assert_eq!(
+        resolve_previous_run(repo_root, Some(&new_plan), false, true).unwrap(),
+        Some(PreviousRun::Archive)
+    );
+
+    stash_previous_run(repo_root).unwrap();
+    assert!(!repo_root.join(".work").exists());
+    let new_work = WorkDir::at(repo_root.join(".work"));
+    new_work.initialize().unwrap();
+    let archived = restore_previous_run(repo_root, new_work.root())
+        .unwrap()
+        .unwrap();
+    assert!(archived
+        .file_name()
+        .unwrap()
+        .to_string_lossy()
+        .starts_with("PLAN-old-"));
+    assert!(archived.join("stages").is_dir());
+    assert!(archived.starts_with(new_work.root().join("archive/plans")));
+    assert!(restore_previous_run(repo_root, new_work.root())
+        .unwrap()
+        .is_none());
 }
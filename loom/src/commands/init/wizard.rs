@@ -0,0 +1,538 @@
+//! loom init --interactive — build a plan by answering prompts.
+//!
+//! Asks for a title and then one stage at a time: name, description,
+//! dependencies, files and acceptance commands. Dependencies are checked as
+//! they are entered, so a cycle is rejected at the prompt that would create
+//! it. The answers are rendered as a plan with the usual knowledge-bootstrap
+//! and integration-verify bookends, validated, written under `doc/plans/`
+//! and optionally handed straight to `loom init`.
+
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+use std::collections::BTreeSet;
+use std::fmt::Write as _;
+use std::fs;
+use std::io::{BufRead, Write};
+use std::path::{Path, PathBuf};
+
+use crate::commands::common::picker::can_prompt;
+use crate::commands::plan::suggest::{default_checks, quote, slug, write_acceptance};
+use crate::language::detect_project_languages;
+use crate::plan::parser::parse_plan_content;
+
+/// Stage IDs the wizard adds itself.
+const RESERVED_IDS: &[&str] = &["knowledge-bootstrap", "integration-verify"];
+
+/// A stage as entered at the prompts.
+#[derive(Debug, Clone, PartialEq)]
+struct WizardStage {
+    id: String,
+    name: String,
+    description: Option<String>,
+    dependencies: Vec<String>,
+    files: Vec<String>,
+    acceptance: Vec<String>,
+}
+
+/// The answers collected by the wizard.
+#[derive(Debug)]
+struct WizardPlan {
+    title: String,
+    stages: Vec<WizardStage>,
+}
+
+/// Line-oriented prompts over any input and output.
+struct Prompter<R, W> {
+    input: R,
+    output: W,
+}
+
+impl<R: BufRead, W: Write> Prompter<R, W> {
+    /// Print `prompt` and read one trimmed line.
+    fn ask(&mut self, prompt: &str) -> Result<String> {
+        write!(self.output, "{prompt}")?;
+        self.output.flush()?;
+        let mut line = String::new();
+        if self.input.read_line(&mut line)? == 0 {
+            bail!("Input ended before the plan was complete");
+        }
+        Ok(line.trim().to_string())
+    }
+
+    /// Ask a yes/no question; an empty answer takes `default`.
+    fn confirm(&mut self, prompt: &str, default: bool) -> Result<bool> {
+        let hint = if default { "[Y/n]" } else { "[y/N]" };
+        loop {
+            match self
+                .ask(&format!("{prompt} {hint} "))?
+                .to_lowercase()
+                .as_str()
+            {
+                "" => return Ok(default),
+                "y" | "yes" => return Ok(true),
+                "n" | "no" => return Ok(false),
+                _ => writeln!(self.output, "Answer y or n.")?,
+            }
+        }
+    }
+
+    fn say(&mut self, message: impl std::fmt::Display) -> Result<()> {
+        writeln!(self.output, "{message}")?;
+        Ok(())
+    }
+}
+
+/// Run the wizard on the terminal, write the plan and optionally initialize.
+///
+/// # Arguments
+/// * `plan_path` - Where to write the plan; defaults to `doc/plans/PLAN-<title>.md`
+/// * `clean` - Passed on to `loom init` when initializing with the new plan
+pub fn execute_interactive(
+    plan_path: Option<PathBuf>,
+    clean: bool,
+    switch_plan: bool,
+) -> Result<()> {
+    if !can_prompt() {
+        bail!("loom init --interactive needs a terminal; pass a plan file instead");
+    }
+    let repo_root = std::env::current_dir().context("Failed to get current directory")?;
+    let checks = default_checks(&detect_project_languages(&repo_root));
+
+    let stdin = std::io::stdin();
+    let mut prompter = Prompter {
+        input: stdin.lock(),
+        output: std::io::stdout(),
+    };
+
+    println!("{}", "Plan wizard".bold());
+    println!("{}", "─".repeat(40).dimmed());
+    let plan = collect_plan(&mut prompter, &checks)?;
+
+    let path = plan_path.unwrap_or_else(|| {
+        repo_root
+            .join("doc/plans")
+            .join(format!("PLAN-{}.md", slug(&plan.title)))
+    });
+    let content = render_plan(&plan);
+    parse_plan_content(&content, &path).context("Generated plan failed validation")?;
+
+    if path.exists()
+        && !prompter.confirm(&format!("{} exists. Overwrite?", path.display()), false)?
+    {
+        bail!("Not overwriting {}", path.display());
+    }
+    write_plan(&path, &content)?;
+    println!(
+        "{} Wrote {} stage{}: {}",
+        "✓".green().bold(),
+        plan.stages.len(),
+        if plan.stages.len() == 1 { "" } else { "s" },
+        path.display()
+    );
+
+    if prompter.confirm("Initialize loom with this plan now?", true)? {
+        super::execute(Some(path), clean, switch_plan)
+    } else {
+        println!(
+            "  Run {} when ready",
+            format!("loom init {}", path.display()).cyan()
+        );
+        Ok(())
+    }
+}
+
+fn write_plan(path: &Path, content: &str) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    fs::write(path, content).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// Prompt for the title and stages until an empty stage name.
+fn collect_plan<R: BufRead, W: Write>(
+    prompter: &mut Prompter<R, W>,
+    checks: &[&str],
+) -> Result<WizardPlan> {
+    let title = loop {
+        let title = prompter.ask("Plan title: ")?;
+        if !slug(&title).is_empty() {
+            break title;
+        }
+        prompter.say("The title needs at least one letter or digit.")?;
+    };
+
+    let mut stages: Vec<WizardStage> = Vec::new();
+    loop {
+        prompter.say("")?;
+        let prompt = if stages.is_empty() {
+            "Stage name: ".to_string()
+        } else {
+            "Stage name (Enter to finish): ".to_string()
+        };
+        let name = prompter.ask(&prompt)?;
+        if name.is_empty() {
+            if stages.is_empty() {
+                prompter.say("A plan needs at least one stage.")?;
+                continue;
+            }
+            break;
+        }
+        let id = unique_id(&name, &stages);
+        if id.is_empty() {
+            prompter.say("The name needs at least one letter or digit.")?;
+            continue;
+        }
+        prompter.say(format!("  id: {id}"))?;
+
+        let description = prompter.ask("  Description (optional): ")?;
+        let dependencies = ask_dependencies(prompter, &stages)?;
+        let required_by = ask_required_by(prompter, &id, &dependencies, &stages)?;
+        let files = split_list(&prompter.ask("  Files (comma-separated globs, optional): ")?);
+        let acceptance = ask_acceptance(prompter, checks)?;
+
+        for stage in &mut stages {
+            if required_by.contains(&stage.id) {
+                stage.dependencies.push(id.clone());
+            }
+        }
+        stages.push(WizardStage {
+            id,
+            name,
+            description: (!description.is_empty()).then_some(description),
+            dependencies,
+            files,
+            acceptance,
+        });
+    }
+
+    Ok(WizardPlan { title, stages })
+}
+
+/// Stage ID for `name`, suffixed when it collides with an earlier stage or
+/// a reserved ID.
+fn unique_id(name: &str, stages: &[WizardStage]) -> String {
+    let base = slug(name);
+    if base.is_empty() {
+        return base;
+    }
+    let taken = |id: &str| RESERVED_IDS.contains(&id) || stages.iter().any(|s| s.id == id);
+    let mut id = base.clone();
+    let mut n = 2;
+    while taken(&id) {
+        id = format!("{base}-{n}");
+        n += 1;
+    }
+    id
+}
+
+fn ask_dependencies<R: BufRead, W: Write>(
+    prompter: &mut Prompter<R, W>,
+    stages: &[WizardStage],
+) -> Result<Vec<String>> {
+    if stages.is_empty() {
+        return Ok(Vec::new());
+    }
+    let known: Vec<&str> = stages.iter().map(|s| s.id.as_str()).collect();
+    loop {
+        let answer = prompter.ask(&format!("  Depends on ({}; optional): ", known.join(", ")))?;
+        let deps = split_list(&answer);
+        match deps.iter().find(|dep| !known.contains(&dep.as_str())) {
+            Some(unknown) => prompter.say(format!("  Unknown stage '{unknown}'."))?,
+            None => return Ok(deps),
+        }
+    }
+}
+
+/// Existing stages that should wait for the new stage `id`.
+///
+/// This is the only way to create a cycle, so each answer is checked
+/// against the dependencies entered so far.
+fn ask_required_by<R: BufRead, W: Write>(
+    prompter: &mut Prompter<R, W>,
+    id: &str,
+    dependencies: &[String],
+    stages: &[WizardStage],
+) -> Result<Vec<String>> {
+    let candidates: Vec<&str> = stages
+        .iter()
+        .map(|s| s.id.as_str())
+        .filter(|s| !dependencies.iter().any(|d| d == s))
+        .collect();
+    if candidates.is_empty() {
+        return Ok(Vec::new());
+    }
+    'prompt: loop {
+        let answer = prompter.ask(&format!(
+            "  Required by ({}; optional): ",
+            candidates.join(", ")
+        ))?;
+        let dependents = split_list(&answer);
+        for dependent in &dependents {
+            if !stages.iter().any(|s| &s.id == dependent) {
+                prompter.say(format!("  Unknown stage '{dependent}'."))?;
+                continue 'prompt;
+            }
+            if let Some(path) = cycle_path(stages, id, dependencies, dependent) {
+                prompter.say(format!("  That would create a cycle: {}", path.join(" → ")))?;
+                continue 'prompt;
+            }
+        }
+        return Ok(dependents);
+    }
+}
+
+/// The cycle formed if `dependent` waits for the new stage `id`, which
+/// depends on `dependencies`: `dependent → id → … → dependent`.
+fn cycle_path(
+    stages: &[WizardStage],
+    id: &str,
+    dependencies: &[String],
+    dependent: &str,
+) -> Option<Vec<String>> {
+    fn walk(
+        stages: &[WizardStage],
+        from: &str,
+        target: &str,
+        visited: &mut BTreeSet<String>,
+    ) -> Option<Vec<String>> {
+        if from == target {
+            return Some(vec![from.to_string()]);
+        }
+        if !visited.insert(from.to_string()) {
+            return None;
+        }
+        let stage = stages.iter().find(|s| s.id == from)?;
+        stage.dependencies.iter().find_map(|dep| {
+            walk(stages, dep, target, visited).map(|mut path| {
+                path.insert(0, from.to_string());
+                path
+            })
+        })
+    }
+
+    let mut visited = BTreeSet::new();
+    dependencies.iter().find_map(|dep| {
+        walk(stages, dep, dependent, &mut visited).map(|path| {
+            let mut cycle = vec![dependent.to_string(), id.to_string()];
+            cycle.extend(path);
+            cycle
+        })
+    })
+}
+
+fn ask_acceptance<R: BufRead, W: Write>(
+    prompter: &mut Prompter<R, W>,
+    checks: &[&str],
+) -> Result<Vec<String>> {
+    if checks.is_empty() {
+        prompter.say("  Acceptance commands, one per line (empty line to finish):")?;
+    } else {
+        prompter.say(format!(
+            "  Acceptance commands, one per line (empty line to finish; none uses {}):",
+            checks.join(", ")
+        ))?;
+    }
+    let mut commands = Vec::new();
+    loop {
+        let command = prompter.ask("    $ ")?;
+        if !command.is_empty() {
+            commands.push(command);
+            continue;
+        }
+        if !commands.is_empty() {
+            return Ok(commands);
+        }
+        if !checks.is_empty() {
+            return Ok(checks.iter().map(|c| c.to_string()).collect());
+        }
+        prompter.say("  Enter at least one command.")?;
+    }
+}
+
+fn split_list(answer: &str) -> Vec<String> {
+    let mut items: Vec<String> = Vec::new();
+    for item in answer.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+        if !items.iter().any(|i| i == item) {
+            items.push(item.to_string());
+        }
+    }
+    items
+}
+
+fn render_plan(plan: &WizardPlan) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "# PLAN: {}", plan.title);
+    let _ = writeln!(out);
+    let _ = writeln!(out, "Written with `loom init --interactive`.");
+    let _ = writeln!(out);
+    let _ = writeln!(out, "## Stages");
+    for stage in &plan.stages {
+        let _ = writeln!(out);
+        let _ = writeln!(out, "### {} (`{}`)", stage.name, stage.id);
+        if let Some(description) = &stage.description {
+            let _ = writeln!(out);
+            let _ = writeln!(out, "{description}");
+        }
+    }
+    let _ = writeln!(out);
+    let _ = writeln!(out, "---");
+    let _ = writeln!(out);
+    let _ = writeln!(out, "<!-- loom METADATA -->");
+    let _ = writeln!(out);
+    let _ = writeln!(out, "```yaml");
+    let _ = writeln!(out, "loom:");
+    let _ = writeln!(out, "  version: 1");
+    let _ = writeln!(out, "  stages:");
+    let _ = writeln!(out, "    - id: knowledge-bootstrap");
+    let _ = writeln!(out, "      name: \"Knowledge Bootstrap\"");
+    let _ = writeln!(out, "      stage_type: knowledge");
+    let _ = writeln!(out, "      working_dir: \".\"");
+    let _ = writeln!(out, "      dependencies: []");
+
+    let mut depended_on: BTreeSet<&str> = BTreeSet::new();
+    let mut all_checks: Vec<&str> = Vec::new();
+    for stage in &plan.stages {
+        let _ = writeln!(out);
+        let _ = writeln!(out, "    - id: {}", stage.id);
+        let _ = writeln!(out, "      name: {}", quote(&stage.name));
+        if let Some(description) = &stage.description {
+            let _ = writeln!(out, "      description: {}", quote(description));
+        }
+        let _ = writeln!(out, "      working_dir: \".\"");
+        let deps: Vec<String> = if stage.dependencies.is_empty() {
+            vec![quote("knowledge-bootstrap")]
+        } else {
+            stage.dependencies.iter().map(|d| quote(d)).collect()
+        };
+        let _ = writeln!(out, "      dependencies: [{}]", deps.join(", "));
+        depended_on.extend(stage.dependencies.iter().map(String::as_str));
+        if !stage.files.is_empty() {
+            let files: Vec<String> = stage.files.iter().map(|f| quote(f)).collect();
+            let _ = writeln!(out, "      files: [{}]", files.join(", "));
+        }
+        let checks: Vec<&str> = stage.acceptance.iter().map(String::as_str).collect();
+        write_acceptance(&mut out, &checks);
+        for check in checks {
+            if !all_checks.contains(&check) {
+                all_checks.push(check);
+            }
+        }
+    }
+
+    let leaves: Vec<String> = plan
+        .stages
+        .iter()
+        .filter(|stage| !depended_on.contains(stage.id.as_str()))
+        .map(|stage| quote(&stage.id))
+        .collect();
+    let _ = writeln!(out);
+    let _ = writeln!(out, "    - id: integration-verify");
+    let _ = writeln!(out, "      name: \"Integration Verify\"");
+    let _ = writeln!(out, "      stage_type: integration-verify");
+    let _ = writeln!(out, "      working_dir: \".\"");
+    let _ = writeln!(out, "      dependencies: [{}]", leaves.join(", "));
+    write_acceptance(&mut out, &all_checks);
+    let _ = writeln!(out, "```");
+    let _ = writeln!(out);
+    let _ = writeln!(out, "<!-- END loom METADATA -->");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn run(script: &str) -> (Result<WizardPlan>, String) {
+        let mut prompter = Prompter {
+            input: Cursor::new(script.to_string()),
+            output: Vec::new(),
+        };
+        let plan = collect_plan(&mut prompter, &["cargo test"]);
+        (plan, String::from_utf8(prompter.output).unwrap())
+    }
+
+    #[test]
+    fn test_wizard_rejects_cycles_and_renders_valid_plan() {
+        let script = "\
+Auth rework
+Models
+User and session models
+src/models/**
+cargo test --lib
+
+API
+Endpoints
+skip
+models
+src/api/**
+
+Schema
+
+
+frontend
+models
+db/**
+
+Docs
+
+api
+models
+
+
+
+
+";
+        let (plan, transcript) = run(script);
+        let plan = plan.unwrap();
+
+        assert!(transcript.contains("Unknown stage 'skip'"));
+        assert!(transcript.contains("Unknown stage 'frontend'"));
+        // Docs needs api, which needs models, so models cannot wait for docs
+        assert!(transcript.contains("cycle: models → docs → api → models"));
+
+        let ids: Vec<&str> = plan.stages.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, vec!["models", "api", "schema", "docs"]);
+        assert_eq!(plan.stages[0].dependencies, vec!["schema"]);
+        assert_eq!(plan.stages[0].acceptance, vec!["cargo test --lib"]);
+        assert_eq!(plan.stages[1].acceptance, vec!["cargo test"]);
+        assert_eq!(plan.stages[2].description, None);
+        assert_eq!(plan.stages[2].files, vec!["db/**"]);
+        assert_eq!(plan.stages[3].dependencies, vec!["api"]);
+
+        let content = render_plan(&plan);
+        let parsed = parse_plan_content(&content, Path::new("PLAN-auth-rework.md")).unwrap();
+        let stages = &parsed.stages;
+        let dependencies = |id: &str| {
+            stages
+                .iter()
+                .find(|s| s.id == id)
+                .map(|s| s.dependencies.clone())
+                .unwrap()
+        };
+        assert_eq!(dependencies("schema"), vec!["knowledge-bootstrap"]);
+        assert_eq!(dependencies("integration-verify"), vec!["docs"]);
+    }
+
+    #[test]
+    fn test_wizard_ids_and_eof() {
+        let stages = vec![WizardStage {
+            id: "api".to_string(),
+            name: "API".to_string(),
+            description: None,
+            dependencies: Vec::new(),
+            files: Vec::new(),
+            acceptance: Vec::new(),
+        }];
+        assert_eq!(unique_id("API", &stages), "api-2");
+        assert_eq!(unique_id("Integration Verify", &[]), "integration-verify-2");
+        assert_eq!(unique_id("!!", &[]), "");
+
+        let (plan, _) = run("Title\nOnly stage\n");
+        assert!(plan
+            .unwrap_err()
+            .to_string()
+            .contains("Input ended before the plan was complete"));
+    }
+}
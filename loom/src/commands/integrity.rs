@@ -0,0 +1,53 @@
+//! Integrity report for memory journals, knowledge files and facts
+//! Usage: loom verify all [--json] [--accept]
+
+use anyhow::{bail, Result};
+use colored::Colorize;
+
+use crate::commands::common::find_work_dir;
+use crate::fs::integrity::{seal_all, verify_all, FindingKind, LEDGER_PATH};
+
+/// Check every recorded file against the integrity ledger.
+///
+/// Exits with an error when something was modified, truncated or deleted
+/// after loom wrote it, or the ledger itself was edited. `accept` records
+/// the current content of every file as trusted instead.
+pub fn all(json: bool, accept: bool) -> Result<()> {
+    let work_dir = find_work_dir()?;
+
+    if accept {
+        let sealed = seal_all(&work_dir)?;
+        println!("Recorded {sealed} file(s) in .work/{LEDGER_PATH}");
+        return Ok(());
+    }
+
+    let report = verify_all(&work_dir)?;
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        println!(
+            "{} ledger record(s), {} file(s) checked",
+            report.records, report.files_checked
+        );
+        if report.is_clean() {
+            println!("{} No tampering or corruption found", "✓".green());
+        }
+        for finding in &report.findings {
+            let kind = match finding.kind {
+                FindingKind::Unsealed => finding.kind.to_string().dimmed(),
+                _ => finding.kind.to_string().red().bold(),
+            };
+            println!(
+                "  {:<14} {}  {}",
+                kind,
+                finding.path,
+                finding.detail.dimmed()
+            );
+        }
+    }
+
+    if !report.is_clean() {
+        bail!("Integrity check failed; review the files above, then run 'loom verify all --accept' to trust their current content");
+    }
+    Ok(())
+}
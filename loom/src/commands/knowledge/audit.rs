@@ -100,6 +100,8 @@ mod tests {
         crate::commands::knowledge::update(
             "architecture".to_string(),
             Some("## Overview\n\nSmall content".to_string()),
+            Vec::new(),
+            None,
         )
         .expect("Failed to update");
 
@@ -122,8 +124,13 @@ mod tests {
         for i in 0..250 {
             big_content.push_str(&format!("- Line {}\n", i));
         }
-        crate::commands::knowledge::update("architecture".to_string(), Some(big_content))
-            .expect("Failed to update");
+        crate::commands::knowledge::update(
+            "architecture".to_string(),
+            Some(big_content),
+            Vec::new(),
+            None,
+        )
+        .expect("Failed to update");
 
         let result = audit(200, 800, true);
         assert!(result.is_ok());
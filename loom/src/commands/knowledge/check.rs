@@ -460,6 +460,8 @@ More description
         crate::commands::knowledge::update(
             "architecture".to_string(),
             Some("## Overview\n\nProject architecture here".to_string()),
+            Vec::new(),
+            None,
         )
         .expect("Failed to update architecture");
 
@@ -488,6 +490,8 @@ More description
         crate::commands::knowledge::update(
             "architecture".to_string(),
             Some("## Overview\n\n- commands/ - CLI\n- models/ - Data".to_string()),
+            Vec::new(),
+            None,
         )
         .expect("Failed to update architecture");
 
@@ -530,6 +534,8 @@ More description
         crate::commands::knowledge::update(
             "architecture".to_string(),
             Some("## Overview\n\nProject architecture here".to_string()),
+            Vec::new(),
+            None,
         )
         .expect("Failed to update architecture");
 
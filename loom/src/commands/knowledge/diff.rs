@@ -0,0 +1,225 @@
+//! Knowledge diff command - show what knowledge the current plan added.
+//!
+//! By default an entry counts as added when it was created after the plan
+//! was initialized (the earliest stage creation time). With `--base <ref>`
+//! the knowledge files are compared against their content at that git ref
+//! instead, which also catches entries written without metadata.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
+use colored::Colorize;
+
+use super::FileEntry;
+use crate::fs::knowledge::{parse_entries, KnowledgeDir, KnowledgeEntry, KnowledgeFile};
+use crate::fs::work_dir::WorkDir;
+use crate::git::runner::run_git;
+use crate::verify::transitions::list_all_stages;
+
+/// Label for entries that carry no source stage
+const NO_STAGE: &str = "(no stage)";
+
+/// What knowledge is compared against
+enum Baseline {
+    /// Entries created at or after this time are new
+    Since(DateTime<Utc>),
+    /// Entries missing from the files at this git ref are new
+    Ref(String),
+}
+
+pub fn diff(base: Option<String>, json: bool) -> Result<()> {
+    let work_dir = WorkDir::new(".")?;
+    let project_root = work_dir
+        .project_root()
+        .context("Could not determine project root")?;
+    let knowledge = KnowledgeDir::new(project_root);
+
+    let baseline = match base {
+        Some(git_ref) => Baseline::Ref(git_ref),
+        None => {
+            let initialized = list_all_stages(work_dir.root())?
+                .iter()
+                .map(|s| s.created_at)
+                .min();
+            match initialized {
+                Some(at) => Baseline::Since(at),
+                None => bail!(
+                    "No initialized plan to diff against. \
+                     Pass --base <ref> to compare against a branch or commit."
+                ),
+            }
+        }
+    };
+
+    let mut added: Vec<(KnowledgeFile, KnowledgeEntry)> = Vec::new();
+    for file_type in KnowledgeFile::all() {
+        let current = knowledge.entries(*file_type)?;
+        let new = match &baseline {
+            Baseline::Since(at) => added_since(current, *at),
+            Baseline::Ref(git_ref) => {
+                let base_entries = entries_at_ref(project_root, git_ref, *file_type)?;
+                added_relative_to(current, &base_entries)
+            }
+        };
+        added.extend(new.into_iter().map(|entry| (*file_type, entry)));
+    }
+
+    if json {
+        let rows: Vec<FileEntry> = added
+            .iter()
+            .map(|(file_type, entry)| FileEntry {
+                file: file_type.filename(),
+                entry,
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&rows)?);
+        return Ok(());
+    }
+
+    let since = match &baseline {
+        Baseline::Since(at) => format!("since plan init ({})", at.format("%Y-%m-%d %H:%M UTC")),
+        Baseline::Ref(git_ref) => format!("relative to {git_ref}"),
+    };
+    if added.is_empty() {
+        println!("{} No knowledge added {since}.", "─".dimmed());
+        return Ok(());
+    }
+
+    println!("{}", format!("Knowledge added {since}").bold());
+    let mut files = 0;
+    for file_type in KnowledgeFile::all() {
+        let entries: Vec<&KnowledgeEntry> = added
+            .iter()
+            .filter(|(f, _)| f == file_type)
+            .map(|(_, entry)| entry)
+            .collect();
+        if entries.is_empty() {
+            continue;
+        }
+        files += 1;
+        println!();
+        println!("{}", file_type.filename().cyan().bold());
+        for (stage, entries) in group_by_stage(entries) {
+            println!("  {} ({})", stage.bold(), entries.len());
+            for entry in entries {
+                println!("    {} {} {}", "+".green(), entry.title, entry.id.dimmed());
+            }
+        }
+    }
+    println!();
+    println!(
+        "{} entr{} in {files} file{}",
+        added.len(),
+        if added.len() == 1 { "y" } else { "ies" },
+        if files == 1 { "" } else { "s" }
+    );
+
+    Ok(())
+}
+
+/// Entries created at or after `since`. Entries without a creation time
+/// (hand-written sections) cannot be dated and are left out.
+fn added_since(entries: Vec<KnowledgeEntry>, since: DateTime<Utc>) -> Vec<KnowledgeEntry> {
+    entries
+        .into_iter()
+        .filter(|entry| entry.created_at.is_some_and(|at| at >= since))
+        .collect()
+}
+
+/// Entries whose content does not appear among `base`.
+fn added_relative_to(entries: Vec<KnowledgeEntry>, base: &[KnowledgeEntry]) -> Vec<KnowledgeEntry> {
+    entries
+        .into_iter()
+        .filter(|entry| !base.iter().any(|b| b.same_content(entry)))
+        .collect()
+}
+
+/// Entries of `file_type` as committed at `git_ref`; none when the file did
+/// not exist there.
+fn entries_at_ref(
+    project_root: &Path,
+    git_ref: &str,
+    file_type: KnowledgeFile,
+) -> Result<Vec<KnowledgeEntry>> {
+    let verify = format!("{git_ref}^{{commit}}");
+    let resolved = run_git(&["rev-parse", "--verify", "--quiet", &verify], project_root)?;
+    if !resolved.status.success() {
+        bail!("Unknown git ref '{git_ref}'");
+    }
+
+    let spec = format!("{git_ref}:doc/loom/knowledge/{}", file_type.filename());
+    let output = run_git(&["show", &spec], project_root)?;
+    if !output.status.success() {
+        return Ok(Vec::new());
+    }
+    let content = String::from_utf8_lossy(&output.stdout);
+    Ok(parse_entries(&content).1)
+}
+
+/// Group entries by the stage that recorded them, stages in name order.
+fn group_by_stage(entries: Vec<&KnowledgeEntry>) -> BTreeMap<String, Vec<&KnowledgeEntry>> {
+    let mut groups: BTreeMap<String, Vec<&KnowledgeEntry>> = BTreeMap::new();
+    for entry in entries {
+        let stage = entry.stage.clone().unwrap_or_else(|| NO_STAGE.to_string());
+        groups.entry(stage).or_default().push(entry);
+    }
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn entry(
+        title: &str,
+        stage: Option<&str>,
+        created_at: Option<DateTime<Utc>>,
+    ) -> KnowledgeEntry {
+        let mut entry = KnowledgeEntry::new(title, format!("- about {title}"))
+            .with_source(stage.map(String::from), None);
+        entry.created_at = created_at;
+        entry
+    }
+
+    #[test]
+    fn test_added_since_skips_older_and_undated_entries() {
+        let init = Utc::now();
+        let entries = vec![
+            entry("old", Some("a"), Some(init - Duration::hours(1))),
+            entry("new", Some("a"), Some(init + Duration::minutes(5))),
+            entry("hand-written", None, None),
+        ];
+        let added = added_since(entries, init);
+        assert_eq!(added.len(), 1);
+        assert_eq!(added[0].title, "new");
+    }
+
+    #[test]
+    fn test_added_relative_to_compares_content() {
+        let base = vec![entry("kept", None, None)];
+        let entries = vec![
+            entry("kept", Some("a"), Some(Utc::now())),
+            entry("learned", Some("a"), Some(Utc::now())),
+        ];
+        let added = added_relative_to(entries, &base);
+        assert_eq!(added.len(), 1);
+        assert_eq!(added[0].title, "learned");
+    }
+
+    #[test]
+    fn test_group_by_stage() {
+        let entries = [
+            entry("one", Some("web"), None),
+            entry("two", None, None),
+            entry("three", Some("api"), None),
+            entry("four", Some("web"), None),
+        ];
+        let groups = group_by_stage(entries.iter().collect());
+        let keys: Vec<&str> = groups.keys().map(String::as_str).collect();
+        assert_eq!(keys, vec![NO_STAGE, "api", "web"]);
+        assert_eq!(groups["web"].len(), 2);
+    }
+}
@@ -2,21 +2,31 @@
 pub mod audit;
 pub mod bootstrap;
 pub mod check;
+pub mod diff;
 pub mod gc;
 pub mod spawn;
 
-use crate::fs::knowledge::{KnowledgeDir, KnowledgeFile};
+use crate::fs::knowledge::{parse_entries, KnowledgeDir, KnowledgeEntry, KnowledgeFile, Severity};
 use crate::fs::work_dir::WorkDir;
 use anyhow::{bail, Context, Result};
 use colored::Colorize;
+use serde::Serialize;
 
-pub fn show(file: Option<String>) -> Result<()> {
+pub fn show(file: Option<String>, tag: Option<String>, json: bool) -> Result<()> {
     let work_dir = WorkDir::new(".")?;
     let project_root = work_dir
         .project_root()
         .context("Could not determine project root")?;
     let knowledge = KnowledgeDir::new(project_root);
 
+    if tag.is_some() || json {
+        let files = match &file {
+            Some(file_name) => vec![parse_file_type(file_name)?],
+            None => KnowledgeFile::all().to_vec(),
+        };
+        return show_entries(&knowledge, &files, tag.as_deref(), json);
+    }
+
     if !knowledge.exists() {
         println!(
             "{} Knowledge directory not found. Run 'loom knowledge init' to create it.",
@@ -44,6 +54,77 @@ pub fn show(file: Option<String>) -> Result<()> {
     Ok(())
 }
 
+/// An entry tagged with the file it came from, for `show --json`
+#[derive(Serialize)]
+struct FileEntry<'a> {
+    file: &'static str,
+    #[serde(flatten)]
+    entry: &'a KnowledgeEntry,
+}
+
+fn show_entries(
+    knowledge: &KnowledgeDir,
+    files: &[KnowledgeFile],
+    tag: Option<&str>,
+    json: bool,
+) -> Result<()> {
+    let mut entries = Vec::new();
+    for file_type in files {
+        for entry in knowledge.entries(*file_type)? {
+            if tag.is_none_or(|t| entry.has_tag(t)) {
+                entries.push((*file_type, entry));
+            }
+        }
+    }
+
+    if json {
+        let rows: Vec<FileEntry> = entries
+            .iter()
+            .map(|(file_type, entry)| FileEntry {
+                file: file_type.filename(),
+                entry,
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&rows)?);
+        return Ok(());
+    }
+
+    if entries.is_empty() {
+        match tag {
+            Some(t) => println!("{} No knowledge entries tagged '{t}'.", "─".dimmed()),
+            None => println!("{} No knowledge entries found.", "─".dimmed()),
+        }
+        return Ok(());
+    }
+
+    for (file_type, entry) in &entries {
+        println!("{}", format!("## {}", entry.title).bold());
+        let mut meta = vec![entry.id.clone(), file_type.filename().to_string()];
+        if !entry.tags.is_empty() {
+            meta.push(format!("tags: {}", entry.tags.join(", ")));
+        }
+        if let Some(stage) = &entry.stage {
+            meta.push(format!("stage: {stage}"));
+        }
+        if let Some(severity) = entry.severity {
+            meta.push(severity.to_string());
+        }
+        if let Some(resolved_at) = entry.resolved_at {
+            meta.push(format!("resolved {}", resolved_at.format("%Y-%m-%d")));
+        }
+        if let Some(created_at) = entry.created_at {
+            meta.push(created_at.format("%Y-%m-%d").to_string());
+        }
+        println!("{}", meta.join(" · ").dimmed());
+        if !entry.body.is_empty() {
+            println!("\n{}", entry.body);
+        }
+        println!();
+    }
+
+    Ok(())
+}
+
 fn read_content_from_stdin() -> Result<String> {
     use std::io::Read;
     let limit = (crate::validation::MAX_KNOWLEDGE_CONTENT_LENGTH + 1) as u64;
@@ -60,7 +141,12 @@ fn read_content_from_stdin() -> Result<String> {
     Ok(trimmed)
 }
 
-pub fn update(file: String, content: Option<String>) -> Result<()> {
+pub fn update(
+    file: String,
+    content: Option<String>,
+    tags: Vec<String>,
+    severity: Option<Severity>,
+) -> Result<()> {
     let content = match content {
         Some(c) if c == "-" => read_content_from_stdin()?,
         Some(c) => c,
@@ -69,6 +155,14 @@ pub fn update(file: String, content: Option<String>) -> Result<()> {
 
     crate::validation::validate_knowledge_content(&content)?;
 
+    let (preamble, sections) = parse_entries(&content);
+    if sections.is_empty() && !tags.is_empty() {
+        bail!("--tag needs at least one '## Title' section in the content to attach to");
+    }
+    if sections.is_empty() && severity.is_some() {
+        bail!("--severity needs at least one '## Title' section in the content to attach to");
+    }
+
     let work_dir = WorkDir::new(".")?;
     let project_root = work_dir
         .project_root()
@@ -82,13 +176,49 @@ pub fn update(file: String, content: Option<String>) -> Result<()> {
     }
 
     let file_type = parse_file_type(&file)?;
-    knowledge.append(file_type, &content)?;
+    if !preamble.is_empty() {
+        knowledge.append(file_type, &preamble)?;
+    }
+
+    let env_var = |name: &str| std::env::var(name).ok().filter(|v| !v.is_empty());
+    let stage = env_var("LOOM_STAGE_ID");
+    let session = env_var("LOOM_SESSION_ID");
+    let entries: Vec<KnowledgeEntry> = sections
+        .into_iter()
+        .map(|section| {
+            KnowledgeEntry::new(section.title, section.body)
+                .with_tags(&tags)
+                .with_severity(severity)
+                .with_source(stage.clone(), session.clone())
+        })
+        .collect();
+    let appended = knowledge.append_entries(file_type, &entries)?;
+    let skipped = entries.len() - appended;
+
+    if preamble.is_empty() && appended == 0 {
+        println!(
+            "{} Nothing appended to {}: all entries are already present",
+            "─".dimmed(),
+            file_type.filename()
+        );
+        return Ok(());
+    }
 
     println!(
         "{} Appended to {}",
         "✓".green().bold(),
         file_type.filename()
     );
+    if skipped > 0 {
+        println!(
+            "  {}",
+            format!(
+                "Skipped {skipped} duplicate entr{}",
+                if skipped == 1 { "y" } else { "ies" }
+            )
+            .dimmed()
+        );
+    }
 
     Ok(())
 }
@@ -194,7 +324,7 @@ pub fn list() -> Result<()> {
     Ok(())
 }
 
-fn parse_file_type(file: &str) -> Result<KnowledgeFile> {
+pub(crate) fn parse_file_type(file: &str) -> Result<KnowledgeFile> {
     if let Some(file_type) = KnowledgeFile::from_filename(file) {
         return Ok(file_type);
     }
@@ -323,6 +453,8 @@ mod tests {
         let result = update(
             "entry-points".to_string(),
             Some("## New Section\n\n- New entry".to_string()),
+            Vec::new(),
+            None,
         );
         assert!(result.is_ok());
 
@@ -389,8 +521,10 @@ mod tests {
         let result = update(
             "entry-points".to_string(),
             Some("## Test Entry\n\n- test/file.rs - Test description".to_string()),
+            Vec::new(),
+            None,
         );
-        assert!(result.is_ok(), "update() failed: {result:?}");
+        assert!(result.is_ok(), "update(, Vec::new()) failed: {result:?}");
 
         let content = fs::read_to_string(worktree_knowledge_dir.join("entry-points.md")).unwrap();
         assert!(
@@ -406,6 +540,68 @@ mod tests {
         std::env::set_current_dir(original_dir).expect("Failed to restore dir");
     }
 
+    #[test]
+    #[serial]
+    fn test_update_tags_entries_and_skips_duplicates() {
+        let (_temp_dir, test_dir) = setup_test_env();
+        let original_dir = std::env::current_dir().expect("Failed to get current dir");
+        std::env::set_current_dir(&test_dir).expect("Failed to change dir");
+
+        init().expect("Failed to init knowledge");
+        let content = "## Token refresh\n\n- src/auth.rs - refresh".to_string();
+        update(
+            "patterns".to_string(),
+            Some(content.clone()),
+            vec!["auth".to_string()],
+            None,
+        )
+        .unwrap();
+        update("patterns".to_string(), Some(content), Vec::new(), None).unwrap();
+
+        let knowledge = KnowledgeDir::new(&test_dir);
+        let entries = knowledge.entries(KnowledgeFile::Patterns).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert!(entries[0].has_tag("auth"));
+        assert!(update(
+            "patterns".to_string(),
+            Some("- no heading".to_string()),
+            vec!["auth".to_string()],
+            None,
+        )
+        .is_err());
+
+        std::env::set_current_dir(original_dir).expect("Failed to restore dir");
+    }
+
+    #[test]
+    #[serial]
+    fn test_update_severity_and_learn_resolve() {
+        let (_temp_dir, test_dir) = setup_test_env();
+        let original_dir = std::env::current_dir().expect("Failed to get current dir");
+        std::env::set_current_dir(&test_dir).expect("Failed to change dir");
+
+        init().expect("Failed to init knowledge");
+        update(
+            "mistakes".to_string(),
+            Some("## Forgot the lock\n\n- take it first".to_string()),
+            Vec::new(),
+            Some(Severity::Critical),
+        )
+        .unwrap();
+
+        let knowledge = KnowledgeDir::new(&test_dir);
+        let open = knowledge.unresolved_mistakes(5).unwrap();
+        assert_eq!(open.len(), 1);
+        assert_eq!(open[0].severity, Some(Severity::Critical));
+
+        crate::commands::learn::resolve(open[0].id.clone()).unwrap();
+        assert!(knowledge.unresolved_mistakes(5).unwrap().is_empty());
+        assert!(knowledge.entries(KnowledgeFile::Mistakes).unwrap()[0].is_resolved());
+        assert!(crate::commands::learn::resolve("k-missing".to_string()).is_err());
+
+        std::env::set_current_dir(original_dir).expect("Failed to restore dir");
+    }
+
     #[test]
     #[serial]
     fn test_update_with_explicit_content() {
@@ -418,6 +614,8 @@ mod tests {
         let result = update(
             "patterns".to_string(),
             Some("## Test Pattern\n\nExplicit content".to_string()),
+            Vec::new(),
+            None,
         );
         assert!(result.is_ok());
 
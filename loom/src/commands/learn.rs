@@ -0,0 +1,176 @@
+//! Learn command - share curated knowledge across projects.
+//!
+//! Usage: loom learn export [--to <path>] [--file <file>]... [--dry-run] [--push]
+//!        loom learn resolve <id>
+
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+use std::path::{Path, PathBuf};
+
+use crate::commands::knowledge::parse_file_type;
+use crate::fs::knowledge::export::{export_knowledge, ExportProvenance, ExportReport};
+use crate::fs::knowledge::{KnowledgeDir, KnowledgeFile};
+use crate::fs::work_dir::{load_config, read_learn_config, WorkDir};
+use crate::git::runner::{run_git, run_git_checked};
+
+/// Export knowledge entries to the shared knowledge location
+pub fn export(to: Option<String>, files: Vec<String>, dry_run: bool, push: bool) -> Result<()> {
+    let work_dir = WorkDir::new(".")?;
+    let project_root = work_dir
+        .project_root()
+        .context("Could not determine project root")?
+        .to_path_buf();
+    let knowledge = KnowledgeDir::new(&project_root);
+    if !knowledge.exists() {
+        bail!("Knowledge directory not found. Run 'loom knowledge init' to create it.");
+    }
+
+    let config = read_learn_config(work_dir.root())?;
+    let export_root = match (to, &config.export_path) {
+        (Some(to), _) => std::env::current_dir()?.join(to),
+        (None, Some(path)) => project_root.join(path),
+        (None, None) => bail!(
+            "No export path: pass --to <path> or set [learn] export_path in .work/config.toml"
+        ),
+    };
+
+    let files = if files.is_empty() {
+        KnowledgeFile::all().to_vec()
+    } else {
+        files
+            .iter()
+            .map(|file| parse_file_type(file))
+            .collect::<Result<Vec<_>>>()?
+    };
+
+    let project = match &config.project {
+        Some(project) => project.clone(),
+        None => project_name(&project_root)?,
+    };
+    let plan_id = load_config(work_dir.root())
+        .ok()
+        .flatten()
+        .and_then(|c| c.plan_id().map(String::from));
+    let provenance = ExportProvenance::collect(&project_root, &project, plan_id.as_deref());
+
+    let report = export_knowledge(&knowledge, &export_root, &files, provenance, dry_run)?;
+    print_report(&report, dry_run);
+    if dry_run {
+        return Ok(());
+    }
+
+    if !is_git_work_tree(&export_root) {
+        if push {
+            bail!(
+                "--push needs {} to be inside a git repository",
+                export_root.display()
+            );
+        }
+        return Ok(());
+    }
+    commit_export(&export_root, &report, &project)?;
+    if push {
+        crate::offline::ensure_online(Some(work_dir.root()), "loom learn export --push")?;
+        run_git_checked(&["push"], &export_root).context("Failed to push knowledge export")?;
+        println!("{} Pushed knowledge export", "✓".green().bold());
+    }
+
+    Ok(())
+}
+
+/// Mark the knowledge entry `id` as addressed
+pub fn resolve(id: String) -> Result<()> {
+    let work_dir = WorkDir::new(".")?;
+    let project_root = work_dir
+        .project_root()
+        .context("Could not determine project root")?;
+    let knowledge = KnowledgeDir::new(project_root);
+    if !knowledge.exists() {
+        bail!("Knowledge directory not found. Run 'loom knowledge init' to create it.");
+    }
+
+    let Some((file_type, entry)) = knowledge.find_entry(&id)? else {
+        bail!("No knowledge entry with id '{id}'. List ids with 'loom knowledge show --json'.");
+    };
+    if entry.is_resolved() {
+        println!(
+            "{} {} is already resolved",
+            "─".dimmed(),
+            entry.title.bold()
+        );
+        return Ok(());
+    }
+
+    knowledge.update_entry(file_type, &id, |entry| entry.resolve())?;
+    println!(
+        "{} Resolved {} in {}",
+        "✓".green().bold(),
+        entry.title.bold(),
+        file_type.filename()
+    );
+    Ok(())
+}
+
+/// Default project name: the project root's directory name.
+fn project_name(project_root: &Path) -> Result<String> {
+    let root = project_root
+        .canonicalize()
+        .unwrap_or_else(|_| project_root.to_path_buf());
+    root.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .context("Could not derive a project name; set [learn] project in .work/config.toml")
+}
+
+fn is_git_work_tree(path: &Path) -> bool {
+    path.is_dir()
+        && run_git(&["rev-parse", "--is-inside-work-tree"], path)
+            .is_ok_and(|out| out.status.success())
+}
+
+/// Commit the exported project directory in the shared repository.
+///
+/// Commits whatever is uncommitted under the project directory, so entries
+/// left over from an export whose commit failed go out with the next one.
+fn commit_export(export_root: &Path, report: &ExportReport, project: &str) -> Result<()> {
+    let target: PathBuf = report
+        .target
+        .strip_prefix(export_root)
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|_| report.target.clone());
+    let target = target.to_string_lossy();
+    let status = run_git_checked(&["status", "--porcelain", "--", &target], export_root)?;
+    if status.trim().is_empty() {
+        return Ok(());
+    }
+    run_git_checked(&["add", "--", &target], export_root)
+        .context("Failed to stage knowledge export")?;
+    let message = match report.total_added() {
+        0 => format!("loom: export knowledge from {project}"),
+        count => format!("loom: export {count} knowledge entries from {project}"),
+    };
+    run_git_checked(&["commit", "-m", &message, "--", &target], export_root)
+        .context("Failed to commit knowledge export")?;
+    println!("{} Committed: {message}", "✓".green().bold());
+    Ok(())
+}
+
+fn print_report(report: &ExportReport, dry_run: bool) {
+    if report.added.is_empty() {
+        println!("{} {} is up to date", "─".dimmed(), report.target.display());
+        return;
+    }
+    let verb = if dry_run { "Would export" } else { "Exported" };
+    println!(
+        "{} {verb} {} entries to {}",
+        "✓".green().bold(),
+        report.total_added(),
+        report.target.display()
+    );
+    for (file_type, count) in &report.added {
+        println!(
+            "  {} {} (+{count})",
+            "─".dimmed(),
+            file_type.filename().cyan()
+        );
+    }
+}
@@ -0,0 +1,62 @@
+//! Log maintenance
+//! Usage: loom logs prune [--dry-run] [--keep <n>]
+
+use anyhow::Result;
+use colored::Colorize;
+use std::path::Path;
+
+use crate::commands::common::find_work_dir;
+use crate::fs::logs::{rotate_logs, LogsConfig};
+
+/// Rotate oversized logs under `.work/` and delete rotated generations
+/// beyond the retention count (`--keep`, else `[logs] keep`).
+pub fn prune(dry_run: bool, keep: Option<usize>) -> Result<()> {
+    let work_dir = find_work_dir()?;
+    let mut config = LogsConfig::load(&work_dir);
+    if keep.is_some() {
+        config.keep = keep;
+    }
+
+    let report = rotate_logs(&work_dir, &config, dry_run)?;
+    let project_root = work_dir.parent().unwrap_or(&work_dir);
+    let (rotate_verb, remove_verb) = if dry_run {
+        ("Would rotate", "Would remove")
+    } else {
+        ("Rotated", "Removed")
+    };
+
+    if report.rotated.is_empty() && report.removed.is_empty() {
+        println!(
+            "{} Logs are within the size and retention limits.",
+            "─".dimmed()
+        );
+        return Ok(());
+    }
+    for path in &report.rotated {
+        println!("{rotate_verb} {}", display(path, project_root).cyan());
+    }
+    for path in &report.removed {
+        println!("{remove_verb} {}", display(path, project_root).dimmed());
+    }
+    println!(
+        "{} {} log{} rotated, {} old generation{} removed ({:.1} MiB)",
+        if dry_run {
+            "─".dimmed()
+        } else {
+            "✓".green()
+        },
+        report.rotated.len(),
+        if report.rotated.len() == 1 { "" } else { "s" },
+        report.removed.len(),
+        if report.removed.len() == 1 { "" } else { "s" },
+        report.freed_bytes as f64 / (1024.0 * 1024.0)
+    );
+    Ok(())
+}
+
+fn display(path: &Path, project_root: &Path) -> String {
+    path.strip_prefix(project_root)
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
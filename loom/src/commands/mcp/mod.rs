@@ -0,0 +1,124 @@
+//! `loom mcp serve` — Model Context Protocol server over stdio.
+//!
+//! Lets agents read and update loom state through MCP tools instead of
+//! shelling out to the CLI from their worktrees. The server speaks
+//! newline-delimited JSON-RPC 2.0 on stdin/stdout; stdout carries protocol
+//! messages only, so diagnostics go to stderr.
+//!
+//! Register it with an MCP client, e.g. in a project `.mcp.json`:
+//!
+//! ```json
+//! { "mcpServers": { "loom": { "command": "loom", "args": ["mcp", "serve"] } } }
+//! ```
+
+pub mod protocol;
+pub mod tools;
+
+#[cfg(test)]
+mod tests;
+
+use anyhow::{Context, Result};
+use serde_json::{json, Value};
+use std::io::{BufRead, Write};
+use std::path::Path;
+
+use crate::commands::common::find_work_dir;
+use protocol::{
+    tool_text_result, RpcRequest, RpcResponse, INVALID_PARAMS, INVALID_REQUEST, METHOD_NOT_FOUND,
+    PARSE_ERROR, PROTOCOL_VERSION,
+};
+
+/// Run the MCP server until stdin closes.
+pub fn serve() -> Result<()> {
+    let work_dir = find_work_dir()?;
+    eprintln!("loom MCP server ready (work dir: {})", work_dir.display());
+
+    let stdin = std::io::stdin();
+    let mut stdout = std::io::stdout().lock();
+    run(&work_dir, stdin.lock(), &mut stdout)
+}
+
+/// Serve requests read line-by-line from `input`, writing responses to `output`.
+pub fn run(work_dir: &Path, input: impl BufRead, output: &mut impl Write) -> Result<()> {
+    for line in input.lines() {
+        let line = line.context("Failed to read from stdin")?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        if let Some(response) = handle_message(work_dir, &line) {
+            serde_json::to_writer(&mut *output, &response)?;
+            output.write_all(b"\n")?;
+            output.flush()?;
+        }
+    }
+    Ok(())
+}
+
+/// Handle one JSON-RPC message. Returns `None` for notifications.
+pub fn handle_message(work_dir: &Path, line: &str) -> Option<RpcResponse> {
+    let value: Value = match serde_json::from_str(line) {
+        Ok(value) => value,
+        Err(e) => {
+            return Some(RpcResponse::error(
+                Value::Null,
+                PARSE_ERROR,
+                format!("Parse error: {e}"),
+            ))
+        }
+    };
+    let request: RpcRequest = match serde_json::from_value(value) {
+        Ok(request) => request,
+        Err(e) => {
+            return Some(RpcResponse::error(
+                Value::Null,
+                INVALID_REQUEST,
+                format!("Invalid request: {e}"),
+            ))
+        }
+    };
+
+    if request.is_notification() {
+        // notifications/initialized, notifications/cancelled, ... need no reply
+        return None;
+    }
+    let id = request.id.clone().unwrap_or(Value::Null);
+
+    let response = match request.method.as_str() {
+        "initialize" => RpcResponse::success(
+            id,
+            json!({
+                "protocolVersion": PROTOCOL_VERSION,
+                "capabilities": { "tools": {} },
+                "serverInfo": {
+                    "name": "loom",
+                    "version": env!("CARGO_PKG_VERSION"),
+                },
+            }),
+        ),
+        "ping" => RpcResponse::success(id, json!({})),
+        "tools/list" => RpcResponse::success(id, json!({ "tools": tools::tool_definitions() })),
+        "tools/call" => {
+            let Some(name) = request.params.get("name").and_then(Value::as_str) else {
+                return Some(RpcResponse::error(
+                    id,
+                    INVALID_PARAMS,
+                    "tools/call requires a 'name' parameter",
+                ));
+            };
+            let args = request
+                .params
+                .get("arguments")
+                .cloned()
+                .unwrap_or_else(|| json!({}));
+            match tools::call_tool(work_dir, name, &args) {
+                Ok(text) => RpcResponse::success(id, tool_text_result(text, false)),
+                Err(e) => match e.downcast_ref::<tools::InvalidParams>() {
+                    Some(invalid) => RpcResponse::error(id, INVALID_PARAMS, invalid.to_string()),
+                    None => RpcResponse::success(id, tool_text_result(format!("{e:#}"), true)),
+                },
+            }
+        }
+        other => RpcResponse::error(id, METHOD_NOT_FOUND, format!("Method not found: {other}")),
+    };
+    Some(response)
+}
@@ -0,0 +1,88 @@
+//! JSON-RPC 2.0 framing for the MCP stdio transport.
+//!
+//! MCP over stdio exchanges one JSON-RPC message per line. Requests carry an
+//! `id` and expect a response; notifications (no `id`) never get one.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+/// MCP protocol revision this server implements.
+pub const PROTOCOL_VERSION: &str = "2024-11-05";
+
+/// JSON-RPC error: invalid JSON was received.
+pub const PARSE_ERROR: i64 = -32700;
+/// JSON-RPC error: the JSON sent is not a valid request object.
+pub const INVALID_REQUEST: i64 = -32600;
+/// JSON-RPC error: the method does not exist.
+pub const METHOD_NOT_FOUND: i64 = -32601;
+/// JSON-RPC error: invalid method parameters.
+pub const INVALID_PARAMS: i64 = -32602;
+
+/// Incoming JSON-RPC message (request or notification).
+#[derive(Debug, Clone, Deserialize)]
+pub struct RpcRequest {
+    #[serde(default)]
+    pub jsonrpc: Option<String>,
+    /// Absent for notifications.
+    #[serde(default)]
+    pub id: Option<Value>,
+    pub method: String,
+    #[serde(default)]
+    pub params: Value,
+}
+
+impl RpcRequest {
+    /// Whether this message is a notification (no response expected).
+    pub fn is_notification(&self) -> bool {
+        self.id.is_none()
+    }
+}
+
+/// JSON-RPC error object.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct RpcError {
+    pub code: i64,
+    pub message: String,
+}
+
+/// Outgoing JSON-RPC response.
+#[derive(Debug, Clone, Serialize)]
+pub struct RpcResponse {
+    pub jsonrpc: &'static str,
+    pub id: Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<RpcError>,
+}
+
+impl RpcResponse {
+    pub fn success(id: Value, result: Value) -> Self {
+        Self {
+            jsonrpc: "2.0",
+            id,
+            result: Some(result),
+            error: None,
+        }
+    }
+
+    pub fn error(id: Value, code: i64, message: impl Into<String>) -> Self {
+        Self {
+            jsonrpc: "2.0",
+            id,
+            result: None,
+            error: Some(RpcError {
+                code,
+                message: message.into(),
+            }),
+        }
+    }
+}
+
+/// Build a `tools/call` result carrying a single text block.
+pub fn tool_text_result(text: String, is_error: bool) -> Value {
+    json!({
+        "content": [{ "type": "text", "text": text }],
+        "isError": is_error,
+    })
+}
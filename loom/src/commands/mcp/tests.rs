@@ -0,0 +1,236 @@
+use super::*;
+use crate::models::stage::Stage;
+use crate::verify::transitions::{load_stage, save_stage};
+use tempfile::TempDir;
+
+fn work_dir_with_stages() -> TempDir {
+    let temp = TempDir::new().unwrap();
+    let upstream = Stage {
+        id: "upstream".to_string(),
+        name: "Upstream".to_string(),
+        ..Default::default()
+    };
+    let downstream = Stage {
+        id: "downstream".to_string(),
+        name: "Downstream".to_string(),
+        dependencies: vec!["upstream".to_string()],
+        ..Default::default()
+    };
+    save_stage(&upstream, temp.path()).unwrap();
+    save_stage(&downstream, temp.path()).unwrap();
+    temp
+}
+
+fn call(work_dir: &Path, name: &str, arguments: Value) -> Value {
+    let request = json!({
+        "jsonrpc": "2.0",
+        "id": 7,
+        "method": "tools/call",
+        "params": { "name": name, "arguments": arguments },
+    });
+    let response = handle_message(work_dir, &request.to_string()).unwrap();
+    assert!(
+        response.error.is_none(),
+        "unexpected error: {:?}",
+        response.error
+    );
+    response.result.unwrap()
+}
+
+fn result_text(result: &Value) -> &str {
+    result["content"][0]["text"].as_str().unwrap()
+}
+
+#[test]
+fn test_initialize_reports_tools_capability() {
+    let temp = TempDir::new().unwrap();
+    let line = r#"{"jsonrpc":"2.0","id":1,"method":"initialize","params":{}}"#;
+
+    let response = handle_message(temp.path(), line).unwrap();
+
+    assert_eq!(response.id, json!(1));
+    let result = response.result.unwrap();
+    assert_eq!(result["protocolVersion"], PROTOCOL_VERSION);
+    assert_eq!(result["serverInfo"]["name"], "loom");
+    assert!(result["capabilities"]["tools"].is_object());
+}
+
+#[test]
+fn test_notifications_get_no_response() {
+    let temp = TempDir::new().unwrap();
+    let line = r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#;
+
+    assert!(handle_message(temp.path(), line).is_none());
+}
+
+#[test]
+fn test_protocol_errors() {
+    let temp = TempDir::new().unwrap();
+
+    let parse = handle_message(temp.path(), "{not json").unwrap();
+    assert_eq!(parse.error.unwrap().code, PARSE_ERROR);
+
+    let unknown = handle_message(temp.path(), r#"{"jsonrpc":"2.0","id":2,"method":"nope"}"#);
+    assert_eq!(unknown.unwrap().error.unwrap().code, METHOD_NOT_FOUND);
+
+    let no_name = handle_message(
+        temp.path(),
+        r#"{"jsonrpc":"2.0","id":3,"method":"tools/call","params":{}}"#,
+    );
+    assert_eq!(no_name.unwrap().error.unwrap().code, INVALID_PARAMS);
+}
+
+#[test]
+fn test_tools_list_matches_tool_names() {
+    let temp = TempDir::new().unwrap();
+    let line = r#"{"jsonrpc":"2.0","id":1,"method":"tools/list"}"#;
+
+    let result = handle_message(temp.path(), line).unwrap().result.unwrap();
+    let names: Vec<&str> = result["tools"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|tool| tool["name"].as_str().unwrap())
+        .collect();
+
+    assert_eq!(names, tools::TOOL_NAMES);
+}
+
+#[test]
+fn test_set_output_then_list_facts_includes_dependencies() {
+    let temp = work_dir_with_stages();
+
+    let set = call(
+        temp.path(),
+        "set_output",
+        json!({ "stage": "upstream", "key": "port", "value": "8080" }),
+    );
+    assert_eq!(set["isError"], false);
+    assert_eq!(
+        load_stage("upstream", temp.path()).unwrap().outputs[0].value,
+        json!(8080)
+    );
+
+    let facts = call(temp.path(), "list_facts", json!({ "stage": "downstream" }));
+    let facts: Value = serde_json::from_str(result_text(&facts)).unwrap();
+    assert_eq!(facts["outputs"]["upstream"][0]["key"], "port");
+    assert_eq!(facts["outputs"]["downstream"], json!([]));
+    assert_eq!(facts["facts"], json!([]));
+}
+
+#[test]
+fn test_list_facts_reports_fact_store() {
+    use crate::fs::facts::{save_facts, Fact, FactConfidence, FactProvenance, FactStore};
+
+    let temp = work_dir_with_stages();
+    let store = FactStore {
+        facts: vec![Fact {
+            key: "db_engine".to_string(),
+            value: json!("postgres"),
+            description: "Primary database".to_string(),
+            source: None,
+            confidence: Some(FactConfidence::High),
+            provenance: Some(FactProvenance {
+                session: None,
+                stage: Some("upstream".to_string()),
+                via: "fact set".to_string(),
+                at: chrono::Utc::now(),
+            }),
+        }],
+    };
+    save_facts(temp.path(), &store).unwrap();
+
+    let facts = call(temp.path(), "list_facts", json!({ "stage": "downstream" }));
+    let facts: Value = serde_json::from_str(result_text(&facts)).unwrap();
+    let fact = &facts["facts"][0];
+    assert_eq!(fact["key"], "db_engine");
+    assert_eq!(fact["value"], "postgres");
+    assert_eq!(fact["confidence"], "high");
+    assert_eq!(fact["provenance"]["via"], "fact set");
+}
+
+#[test]
+fn test_get_stage_and_list_stages() {
+    let temp = work_dir_with_stages();
+
+    let stage = call(temp.path(), "get_stage", json!({ "stage": "downstream" }));
+    let stage: Value = serde_json::from_str(result_text(&stage)).unwrap();
+    assert_eq!(stage["id"], "downstream");
+    assert_eq!(stage["dependencies"], json!(["upstream"]));
+
+    let stages = call(temp.path(), "list_stages", json!({}));
+    let stages: Value = serde_json::from_str(result_text(&stages)).unwrap();
+    assert_eq!(stages.as_array().unwrap().len(), 2);
+}
+
+#[test]
+fn test_record_memory_appends_journal_entry() {
+    let temp = work_dir_with_stages();
+
+    let result = call(
+        temp.path(),
+        "record_memory",
+        json!({ "stage": "upstream", "type": "decision", "text": "Use sqlite", "context": "No server needed" }),
+    );
+
+    assert_eq!(result["isError"], false);
+    let journal = crate::fs::memory::read_journal(temp.path(), "upstream").unwrap();
+    assert_eq!(journal.entries.len(), 1);
+    assert_eq!(journal.entries[0].content, "Use sqlite");
+    assert_eq!(
+        journal.entries[0].context.as_deref(),
+        Some("No server needed")
+    );
+}
+
+#[test]
+fn test_tool_failures_are_reported_as_tool_errors() {
+    let temp = work_dir_with_stages();
+
+    let missing_arg = call(temp.path(), "set_output", json!({ "stage": "upstream" }));
+    assert_eq!(missing_arg["isError"], true);
+    assert!(result_text(&missing_arg).contains("'key'"));
+
+    let unknown = call(temp.path(), "delete_everything", json!({}));
+    assert_eq!(unknown["isError"], true);
+}
+
+#[test]
+fn test_invalid_ids_are_invalid_params_errors() {
+    let temp = work_dir_with_stages();
+
+    for stage in ["../etc", "a b", ""] {
+        let request = json!({
+            "jsonrpc": "2.0",
+            "id": 9,
+            "method": "tools/call",
+            "params": { "name": "get_stage", "arguments": { "stage": stage } },
+        });
+        let response = handle_message(temp.path(), &request.to_string()).unwrap();
+        let error = response.error.expect("invalid ID must be a JSON-RPC error");
+        assert_eq!(error.code, INVALID_PARAMS, "{stage:?}");
+        assert!(error.message.contains("Invalid ID"));
+    }
+}
+
+#[test]
+fn test_run_writes_one_response_per_request_line() {
+    let temp = TempDir::new().unwrap();
+    let input = concat!(
+        r#"{"jsonrpc":"2.0","id":1,"method":"ping"}"#,
+        "\n\n",
+        r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#,
+        "\n",
+        r#"{"jsonrpc":"2.0","id":2,"method":"ping"}"#,
+        "\n",
+    );
+    let mut output = Vec::new();
+
+    run(temp.path(), input.as_bytes(), &mut output).unwrap();
+
+    let lines: Vec<&str> = std::str::from_utf8(&output).unwrap().lines().collect();
+    assert_eq!(lines.len(), 2);
+    let second: Value = serde_json::from_str(lines[1]).unwrap();
+    assert_eq!(second["id"], 2);
+    assert_eq!(second["result"], json!({}));
+}
@@ -0,0 +1,256 @@
+//! MCP tool definitions and handlers.
+//!
+//! Each tool wraps an existing loom operation with a non-printing code path so
+//! the stdio transport stays clean. Stage and session arguments default to the
+//! `LOOM_STAGE_ID` / `LOOM_SESSION_ID` environment variables that loom sets for
+//! every agent session, so agents rarely need to pass them explicitly.
+//!
+//! `list_facts` reports the plan-wide facts established with `loom fact`
+//! together with the outputs of the stage and its dependencies, the
+//! structured values stages share with their dependents. `create_checkpoint`
+//! writes a handoff file, loom's resumable snapshot of a session.
+
+use anyhow::{anyhow, bail, Context, Result};
+use serde_json::{json, Map, Value};
+use std::path::Path;
+
+use crate::commands::handoff::create::create_handoff;
+use crate::commands::stage::write_output;
+use crate::fs::facts::load_facts;
+use crate::fs::memory::{append_entry, validate_content, MemoryEntry, MemoryEntryType};
+use crate::verify::transitions::{list_all_stages, load_stage};
+
+/// Names of all tools exposed by the server, in `tools/list` order.
+pub const TOOL_NAMES: &[&str] = &[
+    "get_stage",
+    "list_stages",
+    "list_facts",
+    "set_output",
+    "record_memory",
+    "create_checkpoint",
+];
+
+/// Tool descriptors returned by `tools/list`.
+pub fn tool_definitions() -> Value {
+    let stage_prop = json!({
+        "type": "string",
+        "description": "Stage ID (defaults to LOOM_STAGE_ID)"
+    });
+
+    json!([
+        {
+            "name": "get_stage",
+            "description": "Get the full state of a stage: status, dependencies, acceptance criteria, outputs.",
+            "inputSchema": {
+                "type": "object",
+                "properties": { "stage": stage_prop },
+            }
+        },
+        {
+            "name": "list_stages",
+            "description": "List all stages in the current plan with their status.",
+            "inputSchema": { "type": "object", "properties": {} }
+        },
+        {
+            "name": "list_facts",
+            "description": "List established plan-wide facts (key, value, confidence, provenance) and the structured outputs published by a stage and its dependencies.",
+            "inputSchema": {
+                "type": "object",
+                "properties": { "stage": stage_prop },
+            }
+        },
+        {
+            "name": "set_output",
+            "description": "Set a structured output on a stage for dependent stages to consume. Values are parsed as JSON when possible.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "stage": stage_prop,
+                    "key": { "type": "string", "description": "Output key (alphanumeric, '_' or '-', 1-64 chars)" },
+                    "value": { "type": "string", "description": "Output value (JSON or plain string)" },
+                    "description": { "type": "string", "description": "What the output represents" },
+                },
+                "required": ["key", "value"],
+            }
+        },
+        {
+            "name": "record_memory",
+            "description": "Record a note, decision, question, or change in the stage memory journal.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "stage": stage_prop,
+                    "type": {
+                        "type": "string",
+                        "enum": ["note", "decision", "question", "change"],
+                    },
+                    "text": { "type": "string" },
+                    "context": { "type": "string", "description": "Rationale (decisions only)" },
+                },
+                "required": ["type", "text"],
+            }
+        },
+        {
+            "name": "create_checkpoint",
+            "description": "Write a handoff file capturing the current session so work can resume in a fresh session.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "stage": stage_prop,
+                    "session": { "type": "string", "description": "Session ID (defaults to LOOM_SESSION_ID)" },
+                    "message": { "type": "string", "description": "Next step to record in the handoff" },
+                },
+            }
+        },
+    ])
+}
+
+/// Dispatch a `tools/call` invocation.
+///
+/// Returns the tool's text output. Unknown tools and invalid arguments are
+/// reported as errors, which the server surfaces as `isError` results.
+pub fn call_tool(work_dir: &Path, name: &str, args: &Value) -> Result<String> {
+    match name {
+        "get_stage" => get_stage(work_dir, args),
+        "list_stages" => list_stages(work_dir),
+        "list_facts" => list_facts(work_dir, args),
+        "set_output" => set_output(work_dir, args),
+        "record_memory" => record_memory(work_dir, args),
+        "create_checkpoint" => create_checkpoint(work_dir, args),
+        _ => bail!("Unknown tool: {name}"),
+    }
+}
+
+fn get_stage(work_dir: &Path, args: &Value) -> Result<String> {
+    let stage_id = resolve_stage(args)?;
+    let stage = load_stage(&stage_id, work_dir)?;
+    Ok(serde_json::to_string_pretty(&stage)?)
+}
+
+fn list_stages(work_dir: &Path) -> Result<String> {
+    let stages: Vec<Value> = list_all_stages(work_dir)?
+        .into_iter()
+        .map(|stage| {
+            json!({
+                "id": stage.id,
+                "name": stage.name,
+                "status": stage.status,
+                "dependencies": stage.dependencies,
+            })
+        })
+        .collect();
+    Ok(serde_json::to_string_pretty(&stages)?)
+}
+
+fn list_facts(work_dir: &Path, args: &Value) -> Result<String> {
+    let stage_id = resolve_stage(args)?;
+    let stage = load_stage(&stage_id, work_dir)?;
+
+    let mut outputs = Map::new();
+    for dep_id in &stage.dependencies {
+        // A missing dependency file is not fatal; report what is available
+        if let Ok(dep) = load_stage(dep_id, work_dir) {
+            outputs.insert(dep_id.clone(), serde_json::to_value(&dep.outputs)?);
+        }
+    }
+    outputs.insert(stage_id, serde_json::to_value(&stage.outputs)?);
+
+    let facts = load_facts(work_dir)?.facts;
+    Ok(serde_json::to_string_pretty(&json!({
+        "facts": facts,
+        "outputs": outputs,
+    }))?)
+}
+
+fn set_output(work_dir: &Path, args: &Value) -> Result<String> {
+    let stage_id = resolve_stage(args)?;
+    let key = required_str(args, "key")?;
+    let value = required_str(args, "value")?;
+    let description = optional_str(args, "description");
+
+    let (was_new, json_value) = write_output(work_dir, &stage_id, key, value, description)?;
+
+    let action = if was_new { "added" } else { "updated" };
+    Ok(format!(
+        "Output '{key}' {action} for stage '{stage_id}': {json_value}"
+    ))
+}
+
+fn record_memory(work_dir: &Path, args: &Value) -> Result<String> {
+    let stage_id = resolve_stage(args)?;
+    let entry_type: MemoryEntryType = required_str(args, "type")?.parse()?;
+    let text = required_str(args, "text")?;
+    validate_content(text)?;
+
+    let entry = match (optional_str(args, "context"), entry_type) {
+        (Some(ctx), MemoryEntryType::Decision) => {
+            validate_content(ctx)?;
+            MemoryEntry::with_context(entry_type, text.to_string(), ctx.to_string())
+        }
+        _ => MemoryEntry::new(entry_type, text.to_string()),
+    };
+    append_entry(work_dir, &stage_id, &entry)?;
+
+    Ok(format!(
+        "Recorded {} for stage '{stage_id}'",
+        entry.entry_type.display_name().to_lowercase()
+    ))
+}
+
+fn create_checkpoint(work_dir: &Path, args: &Value) -> Result<String> {
+    let stage_id = resolve_stage(args)?;
+    let session_id = match optional_str(args, "session") {
+        Some(id) => id.to_string(),
+        None => std::env::var("LOOM_SESSION_ID")
+            .context("No session provided and LOOM_SESSION_ID is not set")?,
+    };
+    validate_id(&session_id)?;
+
+    let path = create_handoff(
+        work_dir,
+        &stage_id,
+        &session_id,
+        "manual",
+        optional_str(args, "message"),
+        "MCP",
+    )?;
+    Ok(path.display().to_string())
+}
+
+/// Resolve the stage argument, falling back to `LOOM_STAGE_ID`.
+fn resolve_stage(args: &Value) -> Result<String> {
+    let stage_id = match optional_str(args, "stage") {
+        Some(id) => id.to_string(),
+        None => std::env::var("LOOM_STAGE_ID")
+            .map_err(|_| anyhow!("No stage provided and LOOM_STAGE_ID is not set"))?,
+    };
+    validate_id(&stage_id)?;
+    Ok(stage_id)
+}
+
+/// A tool argument the server rejects outright; reported as a JSON-RPC
+/// invalid-params error rather than a failed tool result.
+#[derive(Debug)]
+pub struct InvalidParams(pub String);
+
+impl std::fmt::Display for InvalidParams {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for InvalidParams {}
+
+/// Check a stage or session ID with the same rules as the CLI.
+fn validate_id(id: &str) -> Result<()> {
+    crate::validation::validate_id(id)
+        .map_err(|e| InvalidParams(format!("Invalid ID '{id}': {e}")).into())
+}
+
+fn optional_str<'a>(args: &'a Value, name: &str) -> Option<&'a str> {
+    args.get(name).and_then(Value::as_str)
+}
+
+fn required_str<'a>(args: &'a Value, name: &str) -> Result<&'a str> {
+    optional_str(args, name).ok_or_else(|| anyhow!("Missing required string argument '{name}'"))
+}
@@ -35,8 +35,9 @@ pub fn format_entry_compact(entry: &MemoryEntry) -> String {
     }
 }
 
-/// Format a single entry for show display (full format)
-pub fn format_entry_full(entry: &MemoryEntry) -> String {
+/// Format a single entry for show display (full format), numbered for
+/// `loom memory edit` and `loom memory delete`
+pub fn format_entry_full(index: usize, entry: &MemoryEntry) -> String {
     let time = entry.timestamp.format("%Y-%m-%d %H:%M:%S").to_string();
     let type_emoji = match entry.entry_type {
         MemoryEntryType::Note => "📝",
@@ -46,7 +47,8 @@ pub fn format_entry_full(entry: &MemoryEntry) -> String {
     };
 
     let mut output = format!(
-        "\n{} {} {}\n{}\n{}",
+        "\n{} {} {} {}\n{}\n{}",
+        format!("#{index}").dimmed(),
         type_emoji,
         entry.entry_type.display_name().bold(),
         time.dimmed(),
@@ -78,3 +80,15 @@ pub fn format_record_success(entry_type: &MemoryEntryType, stage_id: &str, text:
         truncate_for_display(text, 60)
     )
 }
+
+/// Format a success message for deleting an entry
+pub fn format_removed_entry(index: usize, stage_id: &str, entry: &MemoryEntry) -> String {
+    format!(
+        "{} Deleted {} #{} from stage '{}'\n  {}",
+        "✓".green(),
+        entry.entry_type,
+        index,
+        stage_id.cyan(),
+        truncate_for_display(&entry.content, 60)
+    )
+}
@@ -5,12 +5,14 @@ use colored::Colorize;
 use std::env;
 
 use crate::fs::memory::{
-    append_entry, list_journals, query_entries, read_journal, validate_content, MemoryEntry,
-    MemoryEntryType,
+    append_entry, delete_entries_by_type, delete_entry, edit_entry, list_journals, query_entries,
+    read_journal, validate_content, MemoryEntry, MemoryEntryType,
 };
 use crate::git::worktree::{find_repo_root_from_cwd, find_worktree_root_from_cwd};
 
-use super::formatters::{format_entry_compact, format_entry_full, format_record_success};
+use super::formatters::{
+    format_entry_compact, format_entry_full, format_record_success, format_removed_entry,
+};
 
 /// Get the .work directory, handling worktree symlinks
 ///
@@ -218,7 +220,8 @@ fn print_journal_entries(
     let entries: Vec<_> = journal
         .entries
         .iter()
-        .filter(|e| type_filter.is_none_or(|t| e.entry_type == t))
+        .enumerate()
+        .filter(|(_, e)| type_filter.is_none_or(|t| e.entry_type == t))
         .collect();
 
     if entries.is_empty() {
@@ -237,8 +240,12 @@ fn print_journal_entries(
     );
     println!("{}", "─".repeat(60));
 
-    for entry in entries.iter().rev().take(limit) {
-        println!("{}", format_entry_compact(entry));
+    for (i, entry) in entries.iter().rev().take(limit) {
+        println!(
+            "{} {}",
+            format!("#{}", i + 1).dimmed(),
+            format_entry_compact(entry)
+        );
     }
 
     if entries.len() > limit {
@@ -348,8 +355,8 @@ pub fn show(stage_id: Option<String>, all: bool) -> Result<()> {
             println!("{}", format!("Memory Journal: {stage_name}").bold());
             println!("{} entries", journal.entries.len());
             println!("{}", "═".repeat(60));
-            for entry in &journal.entries {
-                println!("{}", format_entry_full(entry));
+            for (i, entry) in journal.entries.iter().enumerate() {
+                println!("{}", format_entry_full(i + 1, entry));
             }
             println!();
         }
@@ -379,11 +386,86 @@ pub fn show(stage_id: Option<String>, all: bool) -> Result<()> {
     println!("{} entries", journal.entries.len());
     println!("{}", "═".repeat(60));
 
-    for entry in &journal.entries {
-        println!("{}", format_entry_full(entry));
+    for (i, entry) in journal.entries.iter().enumerate() {
+        println!("{}", format_entry_full(i + 1, entry));
     }
 
     println!("\n{}", "═".repeat(60));
 
     Ok(())
 }
+
+/// Delete memory entries, by number or by type
+pub fn delete(
+    index: Option<usize>,
+    entry_type: Option<String>,
+    all: bool,
+    stage_id: Option<String>,
+) -> Result<()> {
+    if let Some(ref id) = stage_id {
+        validate_stage_id(id)?;
+    }
+
+    let work_dir = get_work_dir()?;
+    let stage = stage_id
+        .or_else(|| std::env::var("LOOM_STAGE_ID").ok())
+        .ok_or_else(|| anyhow::anyhow!("No stage ID provided or detected. Use --stage <id>"))?;
+
+    if let Some(index) = index {
+        let removed = delete_entry(&work_dir, &stage, index)?;
+        println!("{}", format_removed_entry(index, &stage, &removed));
+        return Ok(());
+    }
+
+    let type_filter: Option<MemoryEntryType> = entry_type.map(|t| t.parse()).transpose()?;
+    debug_assert!(type_filter.is_some() || all);
+    let removed = delete_entries_by_type(&work_dir, &stage, type_filter)?;
+    let kind = type_filter.map(|t| format!("{t} ")).unwrap_or_default();
+    if removed == 0 {
+        println!(
+            "{} No {kind}entries in memory journal for stage '{stage}'",
+            "ℹ".blue()
+        );
+    } else {
+        println!(
+            "{} Deleted {removed} {kind}entr{} from stage '{}'",
+            "✓".green(),
+            if removed == 1 { "y" } else { "ies" },
+            stage.cyan()
+        );
+    }
+
+    Ok(())
+}
+
+/// Replace the text of a memory entry, keeping its type and timestamp
+pub fn edit(
+    index: usize,
+    text: String,
+    context: Option<String>,
+    stage_id: Option<String>,
+) -> Result<()> {
+    validate_content(&text)?;
+    if let Some(ref ctx) = context {
+        validate_content(ctx)?;
+    }
+    if let Some(ref id) = stage_id {
+        validate_stage_id(id)?;
+    }
+
+    let work_dir = get_work_dir()?;
+    let stage = stage_id
+        .or_else(|| std::env::var("LOOM_STAGE_ID").ok())
+        .ok_or_else(|| anyhow::anyhow!("No stage ID provided or detected. Use --stage <id>"))?;
+
+    let entry = edit_entry(&work_dir, &stage, index, text, context)?;
+    println!(
+        "{} Updated {} #{index} in stage '{}'\n  {}",
+        "✓".green(),
+        entry.entry_type,
+        stage.cyan(),
+        format_entry_compact(&entry)
+    );
+
+    Ok(())
+}
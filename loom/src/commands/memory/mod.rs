@@ -7,6 +7,8 @@
 //! - `loom memory query <search>` - Search memory entries
 //! - `loom memory list [--stage <id>]` - List memory entries
 //! - `loom memory show [--stage <id>] [--all]` - Show full memory journal
+//! - `loom memory delete [<index>] [--type <type>] [--all] [--stage <id>]` - Prune entries
+//! - `loom memory edit <index> <text> [--context <ctx>] [--stage <id>]` - Rewrite an entry
 
 mod formatters;
 mod handlers;
@@ -14,6 +16,8 @@ mod handlers;
 // Re-export all public command handlers
 pub use handlers::change;
 pub use handlers::decision;
+pub use handlers::delete;
+pub use handlers::edit;
 pub use handlers::list;
 pub use handlers::note;
 pub use handlers::query;
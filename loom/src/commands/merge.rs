@@ -0,0 +1,320 @@
+//! Merge queue commands
+//! Usage: loom merge queue | pin <stage-id> | unpin | move <stage-id> <position> | lock-status
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
+use colored::Colorize;
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use crate::commands::common::find_work_dir;
+use crate::fs::get_merge_point;
+use crate::git::branch::branch_name_for_stage;
+use crate::git::merge::lock::{HolderState, LockInfo, MergeConfig};
+use crate::git::merge::{branch_changed_files, predict_merge_conflicts, ConflictPrediction};
+use crate::git::worktree::find_repo_root_from_cwd;
+use crate::models::stage::{StageStatus, StageType};
+use crate::orchestrator::progressive_merge::queue::{
+    load_merge_queue, move_stage, pin_stage, unpin_stage, MergeQueue, MERGE_QUEUE_TIMEOUT,
+};
+use crate::utils::format_elapsed;
+use crate::verify::transitions::{list_all_stages, load_stage};
+
+/// A stage whose work has not reached the merge point yet
+#[derive(Debug, Clone, PartialEq)]
+pub struct PendingMerge {
+    pub stage_id: String,
+    /// Set while the stage waits in the queue for its turn
+    pub waiting_since: Option<DateTime<Utc>>,
+    pub pinned: bool,
+}
+
+/// Stages awaiting merge, in the order they will merge: stages waiting in the
+/// queue, then stages still executing (which join the queue on completion).
+pub fn pending_merges(work_dir: &Path, queue: &MergeQueue) -> Result<Vec<PendingMerge>> {
+    let mut candidates: Vec<(String, Option<DateTime<Utc>>)> = queue
+        .waiting
+        .iter()
+        .map(|e| (e.stage_id.clone(), Some(e.enqueued_at)))
+        .collect();
+    for stage in list_all_stages(work_dir)? {
+        let executing = stage.status == StageStatus::Executing
+            && !stage.merged
+            && stage.stage_type != StageType::Knowledge;
+        if executing && !candidates.iter().any(|(id, _)| *id == stage.id) {
+            candidates.push((stage.id, None));
+        }
+    }
+
+    let ranked = queue.rank(
+        candidates
+            .iter()
+            .map(|(id, since)| (id.as_str(), *since))
+            .collect(),
+    );
+    Ok(ranked
+        .into_iter()
+        .map(|id| PendingMerge {
+            stage_id: id.to_string(),
+            waiting_since: candidates
+                .iter()
+                .find(|(c, _)| c == id)
+                .and_then(|(_, since)| *since),
+            pinned: queue.pinned.as_deref() == Some(id),
+        })
+        .collect())
+}
+
+/// Show stages awaiting merge with their conflict predictions
+pub fn queue() -> Result<()> {
+    let work_dir = find_work_dir()?;
+    let cwd = std::env::current_dir().context("Failed to get current directory")?;
+    let repo_root = find_repo_root_from_cwd(&cwd).unwrap_or(cwd);
+    let merge_point = get_merge_point(&work_dir)?;
+    let queue = load_merge_queue(&work_dir)?;
+    let pending = pending_merges(&work_dir, &queue)?;
+
+    println!("{} {}", "Merge queue →".bold(), merge_point.cyan());
+    if let Some(pinned) = &queue.pinned {
+        if !pending.iter().any(|p| &p.stage_id == pinned) {
+            println!(
+                "  {} '{pinned}' is pinned but not awaiting merge",
+                "!".yellow()
+            );
+        }
+    }
+    if pending.is_empty() {
+        println!("  {} No stages awaiting merge", "─".dimmed());
+        return Ok(());
+    }
+    println!();
+
+    let changed: Vec<BTreeSet<String>> = pending
+        .iter()
+        .map(|p| {
+            branch_changed_files(
+                &branch_name_for_stage(&p.stage_id),
+                &merge_point,
+                &repo_root,
+            )
+            .into_iter()
+            .collect()
+        })
+        .collect();
+
+    let now = Utc::now();
+    for (i, item) in pending.iter().enumerate() {
+        let state = match item.waiting_since {
+            Some(since) => format!(
+                "waiting {}",
+                format_elapsed(now.signed_duration_since(since).num_seconds().max(0))
+            )
+            .green(),
+            None => "executing".normal(),
+        };
+        let pin = if item.pinned { " (pinned)" } else { "" };
+        println!(
+            "  {}. {}{}  {}",
+            i + 1,
+            item.stage_id.bold(),
+            pin.yellow(),
+            state
+        );
+
+        let branch = branch_name_for_stage(&item.stage_id);
+        match predict_merge_conflicts(&branch, &merge_point, &repo_root) {
+            ConflictPrediction::Clean => {
+                println!("     {} merges cleanly", "✓".green());
+            }
+            ConflictPrediction::Conflicts(files) => {
+                println!(
+                    "     {} conflicts with {merge_point}: {}",
+                    "✗".red(),
+                    files.join(", ")
+                );
+            }
+            ConflictPrediction::Unknown => {
+                println!("     {} conflict prediction unavailable", "─".dimmed());
+            }
+        }
+
+        for (j, other) in pending.iter().enumerate() {
+            if i == j {
+                continue;
+            }
+            let shared: Vec<&str> = changed[i]
+                .intersection(&changed[j])
+                .map(String::as_str)
+                .collect();
+            if !shared.is_empty() {
+                println!(
+                    "     {} also touched by {}: {}",
+                    "⚠".yellow(),
+                    other.stage_id,
+                    shared.join(", ")
+                );
+            }
+        }
+    }
+
+    println!();
+    println!(
+        "{}",
+        "Reorder with 'loom merge move <stage-id> <position>' or 'loom merge pin <stage-id>'"
+            .dimmed()
+    );
+    Ok(())
+}
+
+/// Pin a stage so it merges next
+pub fn pin(stage_id: String) -> Result<()> {
+    let work_dir = find_work_dir()?;
+    let stage = load_stage(&stage_id, &work_dir)?;
+    if stage.merged {
+        bail!("Stage '{stage_id}' is already merged");
+    }
+    pin_stage(&work_dir, &stage_id)?;
+    println!("{} Pinned '{stage_id}' to merge next", "✓".green().bold());
+    if stage.status == StageStatus::Executing {
+        println!(
+            "{}",
+            format!(
+                "  Stages that finish first will wait for it (up to {} minutes).",
+                MERGE_QUEUE_TIMEOUT.as_secs() / 60
+            )
+            .dimmed()
+        );
+    }
+    Ok(())
+}
+
+/// Clear the pinned stage
+pub fn unpin() -> Result<()> {
+    let work_dir = find_work_dir()?;
+    match unpin_stage(&work_dir)? {
+        Some(stage_id) => println!("{} Unpinned '{stage_id}'", "✓".green().bold()),
+        None => println!("{} No stage is pinned", "─".dimmed()),
+    }
+    Ok(())
+}
+
+/// Move a stage to a position in the merge queue (1 = next)
+pub fn move_to(stage_id: String, position: usize) -> Result<()> {
+    let work_dir = find_work_dir()?;
+    let queue = load_merge_queue(&work_dir)?;
+    let current: Vec<String> = pending_merges(&work_dir, &queue)?
+        .into_iter()
+        .map(|p| p.stage_id)
+        .collect();
+    move_stage(&work_dir, &current, &stage_id, position)?;
+
+    println!(
+        "{} Moved '{stage_id}' to position {position}",
+        "✓".green().bold()
+    );
+    if let Some(pinned) = queue.pinned.as_deref().filter(|p| *p != stage_id) {
+        println!(
+            "{}",
+            format!("  '{pinned}' is pinned and still merges first").dimmed()
+        );
+    }
+    Ok(())
+}
+
+/// Show who holds the merge lock and whether it will be taken over
+pub fn lock_status() -> Result<()> {
+    let work_dir = find_work_dir()?;
+    let timeout = MergeConfig::load(&work_dir).lock_timeout().as_secs();
+    let Some(info) = LockInfo::read(&work_dir) else {
+        println!(
+            "{} Merge lock is free (timeout {timeout}s)",
+            "✓".green().bold()
+        );
+        return Ok(());
+    };
+
+    println!("{} Merge lock is held", "●".yellow().bold());
+    if let Some(pid) = info.pid {
+        println!("  Holder:    PID {pid}");
+    }
+    if let Some(operation) = &info.operation {
+        println!("  Operation: {operation}");
+    }
+    if let Some(host) = &info.host {
+        println!("  Host:      {host}");
+    }
+    if let Some(at) = info.acquired_at {
+        println!(
+            "  Since:     {} ({} ago)",
+            at.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M:%S"),
+            format_elapsed((Utc::now() - at).num_seconds())
+        );
+    }
+    println!("  Timeout:   {timeout}s (`[merge] lock_timeout_secs`)");
+    match info.holder_state() {
+        HolderState::Alive => println!("  State:     {}", "holder is running".green()),
+        HolderState::Dead => println!(
+            "  State:     {}",
+            "holder is dead; the next merge takes the lock over".yellow()
+        ),
+        HolderState::Unknown => println!(
+            "  State:     {}",
+            "holder cannot be checked; the lock is taken over 5 minutes after it was last written"
+                .yellow()
+        ),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::stage::Stage;
+    use crate::orchestrator::progressive_merge::queue::QueueEntry;
+    use crate::verify::transitions::save_stage;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_pending_merges_ranks_waiting_before_executing() {
+        let temp = TempDir::new().unwrap();
+        let work_dir = temp.path();
+        for (id, status) in [
+            ("api", StageStatus::Executing),
+            ("ui", StageStatus::Executing),
+            ("db", StageStatus::Completed),
+        ] {
+            let stage = Stage {
+                id: id.to_string(),
+                name: id.to_string(),
+                status,
+                merged: id == "db",
+                ..Default::default()
+            };
+            save_stage(&stage, work_dir).unwrap();
+        }
+        let mut queue = MergeQueue {
+            waiting: vec![QueueEntry {
+                stage_id: "ui".to_string(),
+                pid: std::process::id(),
+                enqueued_at: Utc::now(),
+            }],
+            ..Default::default()
+        };
+
+        let ids = |q: &MergeQueue| -> Vec<String> {
+            pending_merges(work_dir, q)
+                .unwrap()
+                .into_iter()
+                .map(|p| p.stage_id)
+                .collect()
+        };
+        assert_eq!(ids(&queue), vec!["ui", "api"]);
+
+        queue.pinned = Some("api".to_string());
+        let pending = pending_merges(work_dir, &queue).unwrap();
+        assert_eq!(pending[0].stage_id, "api");
+        assert!(pending[0].pinned);
+        assert!(pending[0].waiting_since.is_none());
+        assert!(pending[1].waiting_since.is_some());
+    }
+}
@@ -1,23 +1,36 @@
+pub mod ci_check;
 pub mod clean;
 pub mod common;
 pub mod diagnose;
+pub mod doctor;
+pub mod fact;
 pub mod graph;
 pub mod handoff;
+pub mod history;
 pub mod init;
+pub mod integrity;
 pub mod knowledge;
+pub mod learn;
+pub mod logs;
 pub mod map;
+pub mod mcp;
 pub mod memory;
+pub mod merge;
+pub mod pause;
 pub mod plan;
 pub mod pressure;
 pub mod repair;
+pub mod report;
 pub mod resume;
 pub mod review;
 pub mod run;
 pub mod self_update;
 pub mod sessions;
+pub mod signal;
 pub mod skill_index;
 pub mod stage;
 pub mod status;
 pub mod stop;
+pub mod tmux_status;
 pub mod verify;
 pub mod worktree_cmd;
@@ -0,0 +1,106 @@
+//! Whole-plan pause and resume
+//! Usage: loom pause [--sessions] [--reason <text>] | loom resume
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::commands::status::data::load_all_sessions;
+use crate::fs::work_dir::WorkDir;
+use crate::models::session::{SessionStatus, SessionType};
+use crate::orchestrator::pause::{
+    clear_pause_state, continue_processes, read_pause_state, suspend_process_tree,
+    write_pause_state, PauseState, SuspendedSession,
+};
+
+/// Stop scheduling new stages; with `sessions`, also suspend running sessions.
+pub fn pause(sessions: bool, reason: Option<String>) -> Result<()> {
+    let work_dir = WorkDir::new(".")?;
+    work_dir.load()?;
+
+    if let Some(existing) = read_pause_state(work_dir.root()) {
+        println!(
+            "{} Plan already paused since {}",
+            "─".dimmed(),
+            existing.paused_at.format("%Y-%m-%d %H:%M:%S UTC")
+        );
+        println!(
+            "  Run {} first to change how it is paused",
+            "loom resume".cyan()
+        );
+        return Ok(());
+    }
+
+    let mut state = PauseState::new(reason);
+    if sessions {
+        for session in load_all_sessions(&work_dir)? {
+            let live = matches!(
+                session.status,
+                SessionStatus::Spawning | SessionStatus::Running
+            );
+            let Some(pid) = session.pid.filter(|_| live) else {
+                continue;
+            };
+            if session.session_type == SessionType::Human {
+                continue;
+            }
+            let pids = suspend_process_tree(pid);
+            if pids.is_empty() {
+                continue;
+            }
+            println!(
+                "  {} Suspended session {} ({} process{})",
+                "⏸".yellow(),
+                session.id,
+                pids.len(),
+                if pids.len() == 1 { "" } else { "es" }
+            );
+            state.suspended.push(SuspendedSession {
+                session_id: session.id,
+                stage_id: session.stage_id,
+                pids,
+            });
+        }
+    }
+    write_pause_state(work_dir.root(), &state)?;
+
+    println!(
+        "{} Plan paused; the daemon starts no new stages",
+        "✓".green().bold()
+    );
+    if !sessions {
+        println!(
+            "  Running sessions keep working (use {} to suspend them)",
+            "--sessions".cyan()
+        );
+    }
+    println!("  Resume with {}", "loom resume".cyan());
+    Ok(())
+}
+
+/// Continue suspended sessions and let the daemon schedule stages again.
+pub fn resume() -> Result<()> {
+    let work_dir = WorkDir::new(".")?;
+    work_dir.load()?;
+
+    let Some(state) = clear_pause_state(work_dir.root())? else {
+        println!("{} Plan is not paused", "─".dimmed());
+        return Ok(());
+    };
+
+    for session in &state.suspended {
+        let continued = continue_processes(&session.pids);
+        println!(
+            "  {} Continued session {} ({continued}/{} process{})",
+            "▶".green(),
+            session.session_id,
+            session.pids.len(),
+            if session.pids.len() == 1 { "" } else { "es" }
+        );
+    }
+
+    println!(
+        "{} Plan resumed; the daemon re-evaluates ready stages on its next poll",
+        "✓".green().bold()
+    );
+    Ok(())
+}
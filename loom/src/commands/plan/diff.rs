@@ -0,0 +1,130 @@
+//! loom plan diff — change impact of a modified plan on the running one.
+
+use anyhow::{Context, Result};
+use colored::Colorize;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+use crate::commands::common::find_work_dir;
+use crate::plan::diff::{diff_plan, ChangedField, InvalidationCause, PlanDiff};
+use crate::plan::parse_plan;
+use crate::verify::transitions::list_all_stages;
+
+#[derive(Serialize)]
+struct JsonOutput<'a> {
+    source: String,
+    #[serde(flatten)]
+    diff: &'a PlanDiff,
+}
+
+/// Compare `path` (default: the active plan file) with the stages in `.work/`.
+pub fn execute(path: Option<PathBuf>, json: bool, no_color: bool) -> Result<()> {
+    if no_color {
+        colored::control::set_override(false);
+    }
+
+    let work_dir = find_work_dir()?;
+    let path = match path {
+        Some(path) => path,
+        None => crate::fs::resolve_source_path(&work_dir)?.context(
+            "No active plan to compare against. Pass the plan file: loom plan diff <path>",
+        )?,
+    };
+    let plan =
+        parse_plan(&path).with_context(|| format!("Failed to parse plan: {}", path.display()))?;
+    let stages = list_all_stages(&work_dir)?;
+    let diff = diff_plan(&plan.stages, &stages);
+
+    if json {
+        let output = JsonOutput {
+            source: path.to_string_lossy().to_string(),
+            diff: &diff,
+        };
+        println!("{}", serde_json::to_string_pretty(&output)?);
+    } else {
+        print_human(&path, &diff);
+    }
+    Ok(())
+}
+
+fn print_human(path: &Path, diff: &PlanDiff) {
+    println!(
+        "{}",
+        format!("── Plan diff: {} ──", path.display()).cyan().bold()
+    );
+    println!("   {}", "compared with the stages in .work/".dimmed());
+    println!();
+
+    if diff.is_empty() {
+        println!("{}", "No stage changes".green());
+        return;
+    }
+
+    if !diff.added.is_empty() {
+        println!("{}", format!("Added ({})", diff.added.len()).bold());
+        for id in &diff.added {
+            println!("  {} {id}", "+".green().bold());
+        }
+        println!();
+    }
+
+    if !diff.removed.is_empty() {
+        println!("{}", format!("Removed ({})", diff.removed.len()).bold());
+        for id in &diff.removed {
+            println!("  {} {id}", "-".red().bold());
+        }
+        println!();
+    }
+
+    if !diff.changed.is_empty() {
+        println!("{}", format!("Changed ({})", diff.changed.len()).bold());
+        for change in &diff.changed {
+            let mut fields: Vec<String> = Vec::new();
+            for field in &change.fields {
+                let mut label = field.label().to_string();
+                if *field == ChangedField::Dependencies {
+                    let deps: Vec<String> = change
+                        .added_dependencies
+                        .iter()
+                        .map(|d| format!("+{d}"))
+                        .chain(change.removed_dependencies.iter().map(|d| format!("-{d}")))
+                        .collect();
+                    label = format!("{label} ({})", deps.join(", "));
+                }
+                fields.push(label);
+            }
+            println!(
+                "  {} {}  {}",
+                "~".yellow().bold(),
+                change.stage_id,
+                fields.join(", ").dimmed()
+            );
+        }
+        println!();
+    }
+
+    if diff.invalidated.is_empty() {
+        println!("{}", "No completed stage is invalidated".green());
+        return;
+    }
+    println!(
+        "{}",
+        format!("Invalidated completed stages ({})", diff.invalidated.len())
+            .red()
+            .bold()
+    );
+    for invalidation in &diff.invalidated {
+        let cause = match &invalidation.cause {
+            InvalidationCause::Changed => "its definition changed".to_string(),
+            InvalidationCause::Upstream { dependency } => {
+                format!("depends on '{dependency}', which changes")
+            }
+        };
+        println!(
+            "  {} {}  {}",
+            "✗".red(),
+            invalidation.stage_id,
+            cause.dimmed()
+        );
+    }
+}
@@ -1 +1,3 @@
+pub mod diff;
+pub mod suggest;
 pub mod verify;
@@ -0,0 +1,597 @@
+//! loom plan suggest — draft a plan skeleton from git history.
+//!
+//! Each analyzed area (a path given on the command line, or one of the most
+//! frequently changed directories) becomes a stage whose `files` cover it and
+//! whose description lists the recent commits and referenced issues touching
+//! it. Areas that keep changing in the same commits are chained with a
+//! dependency, the area first seen in history going first. The draft gets the
+//! usual knowledge-bootstrap and integration-verify bookends and is meant to
+//! be edited before `loom init`.
+
+use anyhow::{bail, Context, Result};
+use chrono::Utc;
+use colored::Colorize;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::git::runner::run_git_checked;
+use crate::language::{detect_project_languages, DetectedLanguage};
+
+/// Commits listed in a stage's description.
+const MAX_LISTED_COMMITS: usize = 5;
+
+/// Minimum shared commits before two areas are chained.
+const MIN_SHARED_COMMITS: usize = 2;
+
+/// Minimum share of the less active area's commits that must also touch the
+/// other area before they are chained.
+const MIN_SHARED_RATIO: f64 = 0.25;
+
+/// Options for [`execute`].
+pub struct SuggestOptions {
+    pub paths: Vec<String>,
+    pub commits: usize,
+    pub depth: usize,
+    pub max_stages: usize,
+    pub title: Option<String>,
+    pub output: Option<PathBuf>,
+    pub force: bool,
+}
+
+/// A commit from `git log`, oldest last.
+#[derive(Debug, Clone, PartialEq)]
+struct Commit {
+    hash: String,
+    date: String,
+    subject: String,
+    issues: Vec<String>,
+    files: Vec<String>,
+}
+
+/// A drafted stage: one analyzed area and the commits touching it.
+#[derive(Debug)]
+struct Area {
+    path: String,
+    id: String,
+    is_file: bool,
+    /// Indices into the commit list, newest first
+    commits: Vec<usize>,
+    /// Co-changed areas this one depends on, with the shared commit count
+    dependencies: Vec<(String, usize)>,
+}
+
+pub fn execute(options: SuggestOptions) -> Result<()> {
+    let root = repo_root()?;
+    let commits = load_history(&root, &options.paths, options.commits)?;
+    if commits.is_empty() {
+        bail!("No commits found to analyze. Pass paths with history: loom plan suggest <path>...");
+    }
+
+    let paths = if options.paths.is_empty() {
+        busiest_dirs(&commits, options.depth, options.max_stages)
+    } else {
+        options.paths.iter().map(|p| normalize_path(p)).collect()
+    };
+    let areas = build_areas(&root, &paths, &commits);
+    if areas.is_empty() {
+        bail!("None of the given paths were changed in the analyzed commits");
+    }
+
+    let title = options
+        .title
+        .unwrap_or_else(|| format!("Work on {}", paths.join(", ")));
+    let checks = default_checks(&detect_project_languages(&root));
+    let content = render_plan(&title, &areas, &commits, &checks);
+
+    let output = options.output.unwrap_or_else(|| {
+        root.join("doc/plans")
+            .join(format!("PLAN-{}.md", slug(&title)))
+    });
+    if output.exists() && !options.force {
+        bail!(
+            "{} already exists (use --force to overwrite)",
+            output.display()
+        );
+    }
+    if let Some(parent) = output.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    fs::write(&output, content).with_context(|| format!("Failed to write {}", output.display()))?;
+
+    println!(
+        "{} Drafted {} stage{} from {} commit{}: {}",
+        "✓".green().bold(),
+        areas.len(),
+        if areas.len() == 1 { "" } else { "s" },
+        commits.len(),
+        if commits.len() == 1 { "" } else { "s" },
+        output.display()
+    );
+    for area in &areas {
+        let deps: Vec<&str> = area.dependencies.iter().map(|(d, _)| d.as_str()).collect();
+        let after = if deps.is_empty() {
+            String::new()
+        } else {
+            format!(" (after {})", deps.join(", "))
+        };
+        println!(
+            "  {} {} — {} commit{}{after}",
+            "•".dimmed(),
+            area.id,
+            area.commits.len(),
+            if area.commits.len() == 1 { "" } else { "s" }
+        );
+    }
+    println!(
+        "  Review descriptions and acceptance criteria, then run {}",
+        format!("loom plan verify {}", output.display()).cyan()
+    );
+    Ok(())
+}
+
+fn repo_root() -> Result<PathBuf> {
+    let cwd = std::env::current_dir().context("Failed to get current directory")?;
+    let root = run_git_checked(&["rev-parse", "--show-toplevel"], &cwd)
+        .context("loom plan suggest must run inside a git repository")?;
+    Ok(PathBuf::from(root.trim()))
+}
+
+/// Recent commits touching `paths` (all commits when empty), newest first.
+fn load_history(root: &Path, paths: &[String], max: usize) -> Result<Vec<Commit>> {
+    let max_count = format!("--max-count={max}");
+    let mut args = vec![
+        "log",
+        "--no-merges",
+        "--date=short",
+        "--name-only",
+        "--format=%x00%h%x1f%ad%x1f%s%x1f%b%x1e",
+        max_count.as_str(),
+    ];
+    let normalized: Vec<String> = paths.iter().map(|p| normalize_path(p)).collect();
+    if !normalized.is_empty() {
+        args.push("--");
+        args.extend(normalized.iter().map(String::as_str));
+    }
+    let out = run_git_checked(&args, root)?;
+    Ok(parse_log(&out))
+}
+
+/// Parse `git log --name-only --format=%x00%h%x1f%ad%x1f%s%x1f%b%x1e`.
+fn parse_log(out: &str) -> Vec<Commit> {
+    out.split('\0')
+        .filter_map(|record| {
+            let (header, files) = record.split_once('\x1e')?;
+            let mut fields = header.splitn(4, '\x1f');
+            let hash = fields.next()?.trim().to_string();
+            let date = fields.next()?.trim().to_string();
+            let subject = fields.next()?.trim().to_string();
+            let body = fields.next().unwrap_or_default();
+            let mut issues = issue_refs(&subject);
+            for issue in issue_refs(body) {
+                if !issues.contains(&issue) {
+                    issues.push(issue);
+                }
+            }
+            Some(Commit {
+                hash,
+                date,
+                subject,
+                issues,
+                files: files
+                    .lines()
+                    .map(str::trim)
+                    .filter(|line| !line.is_empty())
+                    .map(String::from)
+                    .collect(),
+            })
+        })
+        .collect()
+}
+
+/// Issue references (`#123`) in commit text, in order.
+fn issue_refs(text: &str) -> Vec<String> {
+    let mut refs = Vec::new();
+    let bytes = text.as_bytes();
+    for (start, _) in text.match_indices('#') {
+        let preceded_by_word = start > 0 && bytes[start - 1].is_ascii_alphanumeric();
+        let digits: String = text[start + 1..]
+            .chars()
+            .take_while(char::is_ascii_digit)
+            .collect();
+        if !preceded_by_word && !digits.is_empty() {
+            let issue = format!("#{digits}");
+            if !refs.contains(&issue) {
+                refs.push(issue);
+            }
+        }
+    }
+    refs
+}
+
+fn normalize_path(path: &str) -> String {
+    let trimmed = path.trim().trim_start_matches("./").trim_end_matches('/');
+    if trimmed.is_empty() {
+        ".".to_string()
+    } else {
+        trimmed.to_string()
+    }
+}
+
+/// The `max` directories (first `depth` components) changed by the most
+/// commits. Files at the repository root are not grouped.
+fn busiest_dirs(commits: &[Commit], depth: usize, max: usize) -> Vec<String> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for commit in commits {
+        let dirs: BTreeSet<String> = commit
+            .files
+            .iter()
+            .filter_map(|file| {
+                let parts: Vec<&str> = file.split('/').collect();
+                let dir_parts = parts.len().saturating_sub(1).min(depth.max(1));
+                (dir_parts > 0).then(|| parts[..dir_parts].join("/"))
+            })
+            .collect();
+        for dir in dirs {
+            *counts.entry(dir).or_default() += 1;
+        }
+    }
+    let mut ranked: Vec<(String, usize)> = counts.into_iter().collect();
+    ranked.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    ranked.into_iter().take(max).map(|(dir, _)| dir).collect()
+}
+
+fn touches(file: &str, area: &str) -> bool {
+    area == "." || file == area || file.starts_with(&format!("{area}/"))
+}
+
+/// One area per path that some commit touched, in first-seen order (oldest
+/// first), with dependencies between areas that change together.
+fn build_areas(root: &Path, paths: &[String], commits: &[Commit]) -> Vec<Area> {
+    let mut used_ids = BTreeSet::from([
+        "knowledge-bootstrap".to_string(),
+        "integration-verify".to_string(),
+    ]);
+    let mut areas: Vec<Area> = Vec::new();
+    for path in paths {
+        let touching: Vec<usize> = commits
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| c.files.iter().any(|f| touches(f, path)))
+            .map(|(i, _)| i)
+            .collect();
+        if touching.is_empty() {
+            continue;
+        }
+        let base = match slug(path) {
+            s if s.is_empty() => "root".to_string(),
+            s => s,
+        };
+        let mut id = base.clone();
+        let mut n = 2;
+        while !used_ids.insert(id.clone()) {
+            id = format!("{base}-{n}");
+            n += 1;
+        }
+        areas.push(Area {
+            path: path.clone(),
+            id,
+            is_file: root.join(path).is_file(),
+            commits: touching,
+            dependencies: Vec::new(),
+        });
+    }
+
+    // Oldest first: the highest index of a touching commit is its first
+    // appearance in the (newest-first) history.
+    areas.sort_by_key(|area| std::cmp::Reverse(area.commits.last().copied()));
+
+    for later in 1..areas.len() {
+        for earlier in 0..later {
+            let shared = areas[later]
+                .commits
+                .iter()
+                .filter(|c| areas[earlier].commits.contains(c))
+                .count();
+            let smaller = areas[later].commits.len().min(areas[earlier].commits.len());
+            if shared >= MIN_SHARED_COMMITS && shared as f64 >= smaller as f64 * MIN_SHARED_RATIO {
+                let dep = areas[earlier].id.clone();
+                areas[later].dependencies.push((dep, shared));
+            }
+        }
+    }
+
+    // Drop dependencies already implied through another one. Areas are in
+    // dependency order, so each area's ancestors are known before it.
+    let mut ancestors: HashMap<String, BTreeSet<String>> = HashMap::new();
+    for area in &mut areas {
+        let implied: BTreeSet<String> = area
+            .dependencies
+            .iter()
+            .flat_map(|(dep, _)| ancestors.get(dep).into_iter().flatten().cloned())
+            .collect();
+        area.dependencies.retain(|(dep, _)| !implied.contains(dep));
+        let mut own = implied;
+        own.extend(area.dependencies.iter().map(|(dep, _)| dep.clone()));
+        ancestors.insert(area.id.clone(), own);
+    }
+    areas
+}
+
+/// Acceptance criteria suggested for the detected project languages.
+pub(crate) fn default_checks(languages: &[DetectedLanguage]) -> Vec<&'static str> {
+    languages
+        .iter()
+        .map(|language| match language {
+            DetectedLanguage::Rust => "cargo test",
+            DetectedLanguage::TypeScript => "npm test",
+            DetectedLanguage::Python => "pytest",
+            DetectedLanguage::Go => "go test ./...",
+        })
+        .collect()
+}
+
+/// Lowercase, dash-separated form of `text` usable as a stage ID or file name.
+pub(crate) fn slug(text: &str) -> String {
+    let mut out = String::new();
+    for c in text.chars() {
+        if c.is_ascii_alphanumeric() {
+            out.push(c.to_ascii_lowercase());
+        } else if !out.ends_with('-') {
+            out.push('-');
+        }
+    }
+    out.trim_matches('-').chars().take(64).collect::<String>()
+}
+
+/// Double-quoted YAML scalar.
+pub(crate) fn quote(text: &str) -> String {
+    serde_json::to_string(text).unwrap_or_else(|_| "\"\"".to_string())
+}
+
+fn render_plan(title: &str, areas: &[Area], commits: &[Commit], checks: &[&str]) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "# PLAN: {title}");
+    let _ = writeln!(out);
+    let _ = writeln!(
+        out,
+        "Drafted by `loom plan suggest` on {} from {} commit{}. Stage scopes and \
+         dependencies come from history; rewrite the descriptions and acceptance \
+         criteria before running `loom init`.",
+        Utc::now().format("%Y-%m-%d"),
+        commits.len(),
+        if commits.len() == 1 { "" } else { "s" }
+    );
+    let _ = writeln!(out);
+    let _ = writeln!(out, "## Stages");
+    for area in areas {
+        let _ = writeln!(out);
+        let _ = writeln!(out, "### {} (`{}`)", area.id, area.path);
+        let _ = writeln!(out);
+        for &i in area.commits.iter().take(MAX_LISTED_COMMITS) {
+            let c = &commits[i];
+            let _ = writeln!(out, "- {} `{}` {}", c.date, c.hash, c.subject);
+        }
+        let issues = area_issues(area, commits);
+        if !issues.is_empty() {
+            let _ = writeln!(out);
+            let _ = writeln!(out, "Referenced issues: {}", issues.join(", "));
+        }
+    }
+    let _ = writeln!(out);
+    let _ = writeln!(out, "---");
+    let _ = writeln!(out);
+    let _ = writeln!(out, "<!-- loom METADATA -->");
+    let _ = writeln!(out);
+    let _ = writeln!(out, "```yaml");
+    let _ = writeln!(out, "loom:");
+    let _ = writeln!(out, "  version: 1");
+    let _ = writeln!(out, "  stages:");
+    let _ = writeln!(out, "    - id: knowledge-bootstrap");
+    let _ = writeln!(out, "      name: \"Knowledge Bootstrap\"");
+    let _ = writeln!(out, "      stage_type: knowledge");
+    let _ = writeln!(out, "      working_dir: \".\"");
+    let _ = writeln!(out, "      dependencies: []");
+
+    let mut depended_on: BTreeSet<&str> = BTreeSet::new();
+    for area in areas {
+        let _ = writeln!(out);
+        let _ = writeln!(out, "    - id: {}", area.id);
+        let _ = writeln!(out, "      name: {}", quote(&area.path));
+        let _ = writeln!(out, "      description: |");
+        let _ = writeln!(out, "        TODO: describe the change to `{}`.", area.path);
+        let _ = writeln!(out, "        Recent history:");
+        for &i in area.commits.iter().take(MAX_LISTED_COMMITS) {
+            let _ = writeln!(out, "        - {}", commits[i].subject);
+        }
+        let _ = writeln!(out, "      working_dir: \".\"");
+        for (dep, shared) in &area.dependencies {
+            let _ = writeln!(
+                out,
+                "      # {dep}: changed together in {shared} of the last {} commits",
+                commits.len()
+            );
+            depended_on.insert(dep);
+        }
+        let deps: Vec<String> = std::iter::once("knowledge-bootstrap".to_string())
+            .chain(area.dependencies.iter().map(|(dep, _)| dep.clone()))
+            .map(|dep| quote(&dep))
+            .collect();
+        let _ = writeln!(out, "      dependencies: [{}]", deps.join(", "));
+        let files = if area.is_file || area.path == "." {
+            if area.path == "." {
+                "**".to_string()
+            } else {
+                area.path.clone()
+            }
+        } else {
+            format!("{}/**", area.path)
+        };
+        let _ = writeln!(out, "      files: [{}]", quote(&files));
+        write_acceptance(&mut out, checks);
+    }
+
+    let leaves: Vec<String> = areas
+        .iter()
+        .filter(|area| !depended_on.contains(area.id.as_str()))
+        .map(|area| quote(&area.id))
+        .collect();
+    let _ = writeln!(out);
+    let _ = writeln!(out, "    - id: integration-verify");
+    let _ = writeln!(out, "      name: \"Integration Verify\"");
+    let _ = writeln!(out, "      stage_type: integration-verify");
+    let _ = writeln!(out, "      working_dir: \".\"");
+    let _ = writeln!(out, "      dependencies: [{}]", leaves.join(", "));
+    write_acceptance(&mut out, checks);
+    let _ = writeln!(out, "```");
+    let _ = writeln!(out);
+    let _ = writeln!(out, "<!-- END loom METADATA -->");
+    out
+}
+
+pub(crate) fn write_acceptance(out: &mut String, checks: &[&str]) {
+    let _ = writeln!(out, "      acceptance:");
+    if checks.is_empty() {
+        let _ = writeln!(out, "        # TODO: replace with a real check");
+        let _ = writeln!(out, "        - \"true\"");
+    }
+    for check in checks {
+        let _ = writeln!(out, "        - {}", quote(check));
+    }
+}
+
+/// Issues referenced by the commits touching `area`, most recent first.
+fn area_issues(area: &Area, commits: &[Commit]) -> Vec<String> {
+    let mut issues = Vec::new();
+    for &i in &area.commits {
+        for issue in &commits[i].issues {
+            if !issues.contains(issue) {
+                issues.push(issue.clone());
+            }
+        }
+    }
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plan::parser::parse_plan_content;
+
+    fn commit(hash: &str, subject: &str, files: &[&str]) -> Commit {
+        Commit {
+            hash: hash.to_string(),
+            date: "2026-01-01".to_string(),
+            subject: subject.to_string(),
+            issues: issue_refs(subject),
+            files: files.iter().map(|f| f.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_parse_log() {
+        let out = "\0abc1234\x1f2026-01-02\x1fFix parser (#12)\x1fCloses #40\n\x1e\n\nsrc/parser.rs\nREADME.md\n\
+                   \0def5678\x1f2026-01-01\x1fInitial\x1f\x1e\n\nsrc/lib.rs\n";
+        let commits = parse_log(out);
+        assert_eq!(commits.len(), 2);
+        assert_eq!(commits[0].hash, "abc1234");
+        assert_eq!(commits[0].subject, "Fix parser (#12)");
+        assert_eq!(commits[0].issues, vec!["#12", "#40"]);
+        assert_eq!(commits[0].files, vec!["src/parser.rs", "README.md"]);
+        assert_eq!(commits[1].files, vec!["src/lib.rs"]);
+    }
+
+    #[test]
+    fn test_issue_refs_skip_anchors_and_words() {
+        assert_eq!(issue_refs("Fix #3 and #3, see a#4 and #x"), vec!["#3"]);
+    }
+
+    #[test]
+    fn test_busiest_dirs_by_commit_count() {
+        let commits = vec![
+            commit("a", "one", &["src/api/a.rs", "src/api/b.rs"]),
+            commit("b", "two", &["src/api/c.rs", "src/db/x.rs"]),
+            commit("c", "three", &["src/db/y.rs", "Cargo.toml"]),
+            commit("d", "four", &["docs/guide.md"]),
+        ];
+        assert_eq!(
+            busiest_dirs(&commits, 2, 2),
+            vec!["src/api".to_string(), "src/db".to_string()]
+        );
+        assert_eq!(busiest_dirs(&commits, 1, 5), vec!["src", "docs"]);
+    }
+
+    #[test]
+    fn test_build_areas_chains_co_changed_areas_oldest_first() {
+        // Newest first: db appears in the oldest commit, so api follows it.
+        let commits = vec![
+            commit("a", "api and db", &["src/api/a.rs", "src/db/x.rs"]),
+            commit("b", "api and db again", &["src/api/b.rs", "src/db/y.rs"]),
+            commit("c", "docs", &["docs/guide.md"]),
+            commit("d", "db only", &["src/db/z.rs"]),
+        ];
+        let paths = vec![
+            "src/api".to_string(),
+            "docs".to_string(),
+            "src/db".to_string(),
+            "missing".to_string(),
+        ];
+        let areas = build_areas(Path::new("/nonexistent"), &paths, &commits);
+        let ids: Vec<&str> = areas.iter().map(|a| a.id.as_str()).collect();
+        assert_eq!(ids, vec!["src-db", "docs", "src-api"]);
+        assert_eq!(areas[2].dependencies, vec![("src-db".to_string(), 2)]);
+        assert!(areas[1].dependencies.is_empty());
+    }
+
+    #[test]
+    fn test_build_areas_drops_implied_dependencies() {
+        let commits = vec![
+            commit("a", "all", &["c/1", "b/1", "a/1"]),
+            commit("b", "all", &["c/2", "b/2", "a/2"]),
+            commit("c", "first", &["a/3"]),
+        ];
+        let paths = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let areas = build_areas(Path::new("/nonexistent"), &paths, &commits);
+        let ids: Vec<&str> = areas.iter().map(|a| a.id.as_str()).collect();
+        assert_eq!(ids, vec!["a", "b", "c"]);
+        assert_eq!(areas[1].dependencies, vec![("a".to_string(), 2)]);
+        // c co-changed with both, but b already follows a
+        assert_eq!(areas[2].dependencies, vec![("b".to_string(), 2)]);
+    }
+
+    #[test]
+    fn test_rendered_plan_parses() {
+        let commits = vec![
+            commit("a", "Add endpoint (#7)", &["src/api/a.rs", "src/db/x.rs"]),
+            commit("b", "Add table", &["src/api/b.rs", "src/db/y.rs"]),
+        ];
+        let paths = vec!["src/db".to_string(), "src/api".to_string()];
+        let areas = build_areas(Path::new("/nonexistent"), &paths, &commits);
+        let content = render_plan("Api work", &areas, &commits, &["cargo test"]);
+        assert!(content.contains("Referenced issues: #7"));
+
+        let plan = parse_plan_content(&content, Path::new("PLAN-api-work.md")).unwrap();
+        let ids: Vec<&str> = plan.stages.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(
+            ids,
+            vec![
+                "knowledge-bootstrap",
+                "src-db",
+                "src-api",
+                "integration-verify"
+            ]
+        );
+        let api = &plan.stages[2];
+        assert_eq!(api.dependencies, vec!["knowledge-bootstrap", "src-db"]);
+        assert_eq!(api.files, vec!["src/api/**"]);
+        assert_eq!(plan.stages[3].dependencies, vec!["src-api"]);
+    }
+
+    #[test]
+    fn test_slug() {
+        assert_eq!(slug("src/api"), "src-api");
+        assert_eq!(slug("Work on ./Foo Bar!"), "work-on-foo-bar");
+    }
+}
@@ -81,6 +81,7 @@ fn stage_type_label(st: StageType) -> &'static str {
         StageType::Knowledge => "knowledge",
         StageType::IntegrationVerify => "integration-verify",
         StageType::KnowledgeDistill => "knowledge-distill",
+        StageType::CodeReview => "code-review",
     }
 }
 
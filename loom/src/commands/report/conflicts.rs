@@ -0,0 +1,76 @@
+//! Merge conflict report - hotspot files and conflicting stage pairs.
+//!
+//! Reads the records in `.work/merge-stats.json` (see
+//! [`crate::git::merge::stats`]). Files that conflict again and again, and
+//! stage pairs that keep colliding, are the places to split or sequence
+//! differently when partitioning `files` in the next plan.
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::commands::common::find_work_dir;
+use crate::git::merge::stats::load_merge_stats;
+use crate::utils::format_elapsed;
+
+pub fn conflicts(json: bool, top: usize) -> Result<()> {
+    let work_dir = find_work_dir()?;
+    let summary = load_merge_stats(&work_dir)?.summarize();
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&summary)?);
+        return Ok(());
+    }
+
+    if summary.total == 0 {
+        println!("{} No merge conflicts recorded.", "─".dimmed());
+        return Ok(());
+    }
+
+    println!(
+        "{} conflict{}, {} resolved, mean resolution time {}",
+        summary.total,
+        if summary.total == 1 { "" } else { "s" },
+        summary.resolved,
+        summary
+            .mean_resolution_secs
+            .map(format_elapsed)
+            .unwrap_or_else(|| "-".to_string())
+    );
+
+    println!();
+    println!("{}", "Hotspot files".bold());
+    for hotspot in summary.hotspots.iter().take(top) {
+        println!(
+            "  {:>3}  {}  {}",
+            hotspot.conflicts,
+            hotspot.file.cyan(),
+            hotspot.stages.join(", ").dimmed()
+        );
+    }
+
+    println!();
+    println!("{}", "Conflicting stage pairs".bold());
+    if summary.pairs.is_empty() {
+        println!("  {}", "(no counterpart stages identified)".dimmed());
+    }
+    for pair in summary.pairs.iter().take(top) {
+        println!(
+            "  {:>3}  {} ↔ {}  {}",
+            pair.conflicts,
+            pair.stages[0].cyan(),
+            pair.stages[1].cyan(),
+            pair.files.join(", ").dimmed()
+        );
+    }
+
+    if let Some(pair) = summary.pairs.first() {
+        println!();
+        println!(
+            "{} Give {} and {} disjoint files, or make one depend on the other.",
+            "Hint:".bold(),
+            pair.stages[0],
+            pair.stages[1]
+        );
+    }
+    Ok(())
+}
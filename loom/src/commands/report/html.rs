@@ -0,0 +1,645 @@
+//! Standalone HTML report of a run.
+//!
+//! One self-contained file (inline CSS and SVG, no scripts or external
+//! assets) with the dependency graph, a timeline of stage execution, the
+//! diff each stage merged, verification results, merge conflicts and
+//! highlights from the memory journals — for people who will never run the
+//! CLI. Written to `.work/reports/<plan-id>-report.html` unless `--output`
+//! says otherwise.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use colored::Colorize;
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::commands::common::find_work_dir;
+use crate::commands::graph::compute_stage_levels;
+use crate::fs::memory::MemoryEntry;
+use crate::fs::verifications::{list_verifications, VerificationRecord};
+use crate::git::merge::stats::{load_merge_stats, MergeStats};
+use crate::models::stage::{Stage, StatusBucket, VerificationStatus};
+use crate::orchestrator::plan_report::{build_plan_report, PlanReport};
+use crate::utils::format_elapsed_verbose;
+use crate::verify::transitions::list_all_stages;
+
+/// Graph node size and spacing, in pixels
+const NODE_WIDTH: usize = 180;
+const NODE_HEIGHT: usize = 44;
+const NODE_GAP_X: usize = 60;
+const NODE_GAP_Y: usize = 20;
+
+/// Timeline geometry, in pixels
+const TIMELINE_LABEL_WIDTH: usize = 180;
+const TIMELINE_WIDTH: usize = 720;
+const TIMELINE_ROW_HEIGHT: usize = 24;
+
+/// Longest stage name drawn inside a graph node
+const MAX_NODE_LABEL: usize = 24;
+
+const STYLE: &str = "\
+body { font-family: -apple-system, 'Segoe UI', Helvetica, Arial, sans-serif; color: #1f2328; max-width: 1100px; margin: 2rem auto; padding: 0 1rem; }
+h1 { margin-bottom: 0.2rem; }
+h2 { border-bottom: 1px solid #d0d7de; padding-bottom: 0.3rem; margin-top: 2rem; }
+.meta { color: #59636e; }
+table { border-collapse: collapse; width: 100%; }
+th, td { text-align: left; padding: 0.35rem 0.6rem; border-bottom: 1px solid #d0d7de; vertical-align: top; }
+code { background: #f6f8fa; padding: 0.1rem 0.3rem; border-radius: 4px; }
+.status { display: inline-block; padding: 0.05rem 0.5rem; border-radius: 1rem; color: #fff; font-size: 0.85em; }
+.empty { color: #59636e; font-style: italic; }
+svg text { font-family: inherit; font-size: 12px; }
+";
+
+/// Write the HTML report of the current run
+pub fn html(output: Option<String>) -> Result<()> {
+    let work_dir = find_work_dir()?;
+    let repo_root = work_dir
+        .parent()
+        .context("Could not determine repository root")?
+        .to_path_buf();
+    let report = HtmlReport::build(&work_dir, &repo_root)?;
+
+    let path = match output {
+        Some(path) => std::env::current_dir()?.join(path),
+        None => {
+            let name = report.summary.plan_id.as_deref().unwrap_or("plan");
+            work_dir.join("reports").join(format!("{name}-report.html"))
+        }
+    };
+    report.write(&path)?;
+    println!("{} Wrote report to {}", "✓".green().bold(), path.display());
+    Ok(())
+}
+
+/// Everything the HTML report shows
+#[derive(Debug, Clone)]
+pub struct HtmlReport {
+    /// Stage summaries, diffs and memory highlights
+    pub summary: PlanReport,
+    /// Full stage state, in the order of `summary.stages`
+    pub stages: Vec<Stage>,
+    /// Latest verification record of each stage that has one
+    pub verifications: HashMap<String, VerificationRecord>,
+    pub conflicts: MergeStats,
+    pub generated_at: DateTime<Utc>,
+}
+
+impl HtmlReport {
+    /// Gather the report from `.work/` and git history.
+    pub fn build(work_dir: &Path, repo_root: &Path) -> Result<Self> {
+        let summary = build_plan_report(work_dir, repo_root)?;
+        let mut by_id: HashMap<String, Stage> = list_all_stages(work_dir)?
+            .into_iter()
+            .map(|stage| (stage.id.clone(), stage))
+            .collect();
+        let stages = summary
+            .stages
+            .iter()
+            .filter_map(|s| by_id.remove(&s.id))
+            .collect();
+        let verifications = list_verifications(work_dir)?
+            .into_iter()
+            .map(|record| (record.stage_id.clone(), record))
+            .collect();
+        Ok(Self {
+            summary,
+            stages,
+            verifications,
+            conflicts: load_merge_stats(work_dir)?,
+            generated_at: Utc::now(),
+        })
+    }
+
+    /// Render and write the report to `path`, creating its directory.
+    pub fn write(&self, path: &Path) -> Result<PathBuf> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        fs::write(path, self.render())
+            .with_context(|| format!("Failed to write report: {}", path.display()))?;
+        Ok(path.to_path_buf())
+    }
+
+    /// Render the report as a standalone HTML document.
+    pub fn render(&self) -> String {
+        let title = format!(
+            "Loom report: {}",
+            self.summary.plan_id.as_deref().unwrap_or("plan")
+        );
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+             <title>{}</title>\n<style>\n{STYLE}</style>\n</head>\n<body>",
+            escape(&title)
+        );
+        let _ = writeln!(out, "<h1>{}</h1>", escape(&title));
+        self.render_overview(&mut out);
+
+        out.push_str("<h2>Dependency graph</h2>\n");
+        out.push_str(&render_graph(&self.stages));
+        out.push_str("<h2>Timeline</h2>\n");
+        out.push_str(&render_timeline(&self.stages, self.generated_at));
+        self.render_stages(&mut out);
+        self.render_verification(&mut out);
+        self.render_conflicts(&mut out);
+
+        out.push_str("<h2>Memory highlights</h2>\n<h3>Decisions and notes</h3>\n");
+        render_entries(&mut out, &self.summary.learnings, "No learnings recorded.");
+        out.push_str("<h3>Open questions</h3>\n");
+        render_entries(&mut out, &self.summary.open_questions, "No open questions.");
+
+        out.push_str("</body>\n</html>\n");
+        out
+    }
+
+    fn render_overview(&self, out: &mut String) {
+        let completed = self
+            .stages
+            .iter()
+            .filter(|s| s.status.bucket() == StatusBucket::Completed)
+            .count();
+        let mut facts = vec![
+            format!(
+                "Generated {}",
+                self.generated_at.format("%Y-%m-%d %H:%M UTC")
+            ),
+            format!("{completed}/{} stages done", self.stages.len()),
+            format!(
+                "merged into <code>{}</code>",
+                escape(&self.summary.merge_point)
+            ),
+        ];
+        if let Some(secs) = self.summary.total_duration_secs() {
+            facts.push(format!("total duration {}", format_elapsed_verbose(secs)));
+        }
+        if !self.conflicts.conflicts.is_empty() {
+            facts.push(format!(
+                "{} merge conflict(s)",
+                self.conflicts.conflicts.len()
+            ));
+        }
+        let _ = writeln!(out, "<p class=\"meta\">{}</p>", facts.join(" · "));
+    }
+
+    fn render_stages(&self, out: &mut String) {
+        out.push_str("<h2>Stages</h2>\n");
+        if self.stages.is_empty() {
+            out.push_str("<p class=\"empty\">No stages.</p>\n");
+            return;
+        }
+        out.push_str(
+            "<table>\n<tr><th>Stage</th><th>Status</th><th>Duration</th>\
+             <th>Retries</th><th>Changes</th></tr>\n",
+        );
+        for (stage, summary) in self.stages.iter().zip(&self.summary.stages) {
+            let _ = writeln!(
+                out,
+                "<tr><td>{} <code>{}</code></td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                escape(&stage.name),
+                escape(&stage.id),
+                status_badge(stage),
+                stage
+                    .duration_secs
+                    .map(format_elapsed_verbose)
+                    .unwrap_or_else(|| "-".to_string()),
+                stage.retry_count,
+                escape(summary.shortstat.as_deref().unwrap_or("-")),
+            );
+        }
+        out.push_str("</table>\n");
+    }
+
+    fn render_verification(&self, out: &mut String) {
+        out.push_str("<h2>Verification</h2>\n");
+        if self.stages.is_empty() {
+            out.push_str("<p class=\"empty\">No stages.</p>\n");
+            return;
+        }
+        out.push_str(
+            "<table>\n<tr><th>Stage</th><th>Acceptance criteria</th>\
+             <th>Goal-backward verification</th><th>Failure</th></tr>\n",
+        );
+        for stage in &self.stages {
+            let criteria = if stage.acceptance.is_empty() {
+                "-".to_string()
+            } else {
+                let items: String = stage
+                    .acceptance
+                    .iter()
+                    .map(|c| {
+                        let label = c.name().unwrap_or(c.command());
+                        format!("<li><code>{}</code></li>", escape(label))
+                    })
+                    .collect();
+                format!("<ul>{items}</ul>")
+            };
+
+            let mut verification = match &stage.verification_status {
+                VerificationStatus::NotRun => "not run".to_string(),
+                VerificationStatus::Passed => "passed".to_string(),
+                VerificationStatus::GapsFound { gap_count } => format!("{gap_count} gap(s)"),
+                VerificationStatus::HumanNeeded => "needs human judgment".to_string(),
+            };
+            if let Some(record) = self.verifications.get(&stage.id) {
+                let gaps: String = record
+                    .gaps
+                    .iter()
+                    .map(|gap| {
+                        format!(
+                            "<li>{}: {}</li>",
+                            escape(&gap.gap_type),
+                            escape(&gap.description)
+                        )
+                    })
+                    .collect();
+                if !gaps.is_empty() {
+                    verification.push_str(&format!("<ul>{gaps}</ul>"));
+                }
+            }
+
+            let failure = stage
+                .failure_info
+                .as_ref()
+                .map(|info| match info.evidence.first() {
+                    Some(evidence) => format!("{}: {evidence}", info.failure_type),
+                    None => info.failure_type.to_string(),
+                })
+                .or_else(|| {
+                    (stage.status.bucket() == StatusBucket::Blocked)
+                        .then(|| stage.close_reason.clone())
+                        .flatten()
+                })
+                .map(|text| escape(text.trim()))
+                .unwrap_or_else(|| "-".to_string());
+
+            let _ = writeln!(
+                out,
+                "<tr><td><code>{}</code></td><td>{criteria}</td><td>{verification}</td><td>{failure}</td></tr>",
+                escape(&stage.id)
+            );
+        }
+        out.push_str("</table>\n");
+    }
+
+    fn render_conflicts(&self, out: &mut String) {
+        out.push_str("<h2>Merge conflicts</h2>\n");
+        if self.conflicts.conflicts.is_empty() {
+            out.push_str("<p class=\"empty\">No merge conflicts recorded.</p>\n");
+            return;
+        }
+        out.push_str(
+            "<table>\n<tr><th>Stage</th><th>Files</th><th>Conflicting with</th>\
+             <th>Detected</th><th>Resolved in</th></tr>\n",
+        );
+        for record in &self.conflicts.conflicts {
+            let files: Vec<String> = record
+                .files
+                .iter()
+                .map(|f| format!("<code>{}</code>", escape(f)))
+                .collect();
+            let _ = writeln!(
+                out,
+                "<tr><td><code>{}</code></td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                escape(&record.stage_id),
+                files.join(", "),
+                escape(&record.counterparts.join(", ")),
+                record.detected_at.format("%Y-%m-%d %H:%M"),
+                record
+                    .resolution_secs()
+                    .map(format_elapsed_verbose)
+                    .unwrap_or_else(|| "unresolved".to_string()),
+            );
+        }
+        out.push_str("</table>\n");
+
+        let summary = self.conflicts.summarize();
+        if let Some(hotspot) = summary.hotspots.first() {
+            let _ = writeln!(
+                out,
+                "<p>Most conflicted file: <code>{}</code> ({} conflict(s)).</p>",
+                escape(&hotspot.file),
+                hotspot.conflicts
+            );
+        }
+    }
+}
+
+/// Layered SVG drawing of the stage graph: one column per dependency level,
+/// with an arrow from each dependency to its dependent.
+fn render_graph(stages: &[Stage]) -> String {
+    if stages.is_empty() {
+        return "<p class=\"empty\">No stages.</p>\n".to_string();
+    }
+    let levels = compute_stage_levels(stages);
+    let mut columns: Vec<Vec<&Stage>> = Vec::new();
+    for stage in stages {
+        let level = levels.get(&stage.id).copied().unwrap_or(0);
+        if columns.len() <= level {
+            columns.resize_with(level + 1, Vec::new);
+        }
+        columns[level].push(stage);
+    }
+
+    let mut positions: HashMap<&str, (usize, usize)> = HashMap::new();
+    for (level, column) in columns.iter().enumerate() {
+        for (row, stage) in column.iter().enumerate() {
+            positions.insert(
+                &stage.id,
+                (
+                    level * (NODE_WIDTH + NODE_GAP_X),
+                    row * (NODE_HEIGHT + NODE_GAP_Y),
+                ),
+            );
+        }
+    }
+    let width = columns.len() * (NODE_WIDTH + NODE_GAP_X) - NODE_GAP_X;
+    let rows = columns.iter().map(Vec::len).max().unwrap_or(1);
+    let height = rows * (NODE_HEIGHT + NODE_GAP_Y) - NODE_GAP_Y;
+
+    let mut svg = format!(
+        "<svg class=\"graph\" xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+         viewBox=\"0 0 {width} {height}\">\n\
+         <defs><marker id=\"arrow\" viewBox=\"0 0 10 10\" refX=\"10\" refY=\"5\" markerWidth=\"6\" \
+         markerHeight=\"6\" orient=\"auto\"><path d=\"M0,0 L10,5 L0,10 z\" fill=\"#8c959f\"/></marker></defs>\n"
+    );
+    for stage in stages {
+        let (x, y) = positions[stage.id.as_str()];
+        for dep in &stage.dependencies {
+            if let Some(&(dx, dy)) = positions.get(dep.as_str()) {
+                let _ = writeln!(
+                    svg,
+                    "<line class=\"edge\" x1=\"{}\" y1=\"{}\" x2=\"{x}\" y2=\"{}\" stroke=\"#8c959f\" \
+                     stroke-width=\"1.5\" marker-end=\"url(#arrow)\"/>",
+                    dx + NODE_WIDTH,
+                    dy + NODE_HEIGHT / 2,
+                    y + NODE_HEIGHT / 2
+                );
+            }
+        }
+    }
+    for stage in stages {
+        let (x, y) = positions[stage.id.as_str()];
+        let _ = writeln!(
+            svg,
+            "<g class=\"node\"><title>{} ({})</title>\
+             <rect x=\"{x}\" y=\"{y}\" width=\"{NODE_WIDTH}\" height=\"{NODE_HEIGHT}\" rx=\"6\" \
+             fill=\"#fff\" stroke=\"{color}\" stroke-width=\"2\"/>\
+             <text x=\"{}\" y=\"{}\">{}</text>\
+             <text x=\"{}\" y=\"{}\" fill=\"{color}\">{}</text></g>",
+            escape(&stage.name),
+            stage.status,
+            x + 8,
+            y + 18,
+            escape(&truncate(&stage.name, MAX_NODE_LABEL)),
+            x + 8,
+            y + 35,
+            stage.status,
+            color = status_color(stage),
+        );
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// SVG bars from each stage's start to its completion (or to `now` for
+/// stages still running), on a shared time axis.
+fn render_timeline(stages: &[Stage], now: DateTime<Utc>) -> String {
+    let started: Vec<&Stage> = stages.iter().filter(|s| s.started_at.is_some()).collect();
+    let Some(start) = started.iter().filter_map(|s| s.started_at).min() else {
+        return "<p class=\"empty\">No stage has started yet.</p>\n".to_string();
+    };
+    let end_of = |stage: &Stage| stage.completed_at.unwrap_or(now);
+    let end = started.iter().map(|s| end_of(s)).max().unwrap_or(now);
+    let span = (end - start).num_seconds().max(1) as f64;
+    let scale = |at: DateTime<Utc>| (at - start).num_seconds().max(0) as f64 / span;
+
+    let width = TIMELINE_LABEL_WIDTH + TIMELINE_WIDTH;
+    let height = (started.len() + 1) * TIMELINE_ROW_HEIGHT;
+    let mut svg = format!(
+        "<svg class=\"timeline\" xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+         viewBox=\"0 0 {width} {height}\">\n"
+    );
+    for (row, stage) in started.iter().enumerate() {
+        let y = row * TIMELINE_ROW_HEIGHT;
+        let stage_start = stage.started_at.unwrap_or(start);
+        let x = TIMELINE_LABEL_WIDTH as f64 + scale(stage_start) * TIMELINE_WIDTH as f64;
+        let bar = ((scale(end_of(stage)) - scale(stage_start)) * TIMELINE_WIDTH as f64).max(2.0);
+        let _ = writeln!(
+            svg,
+            "<text x=\"0\" y=\"{}\">{}</text>\
+             <rect class=\"bar\" x=\"{x:.1}\" y=\"{}\" width=\"{bar:.1}\" height=\"{}\" rx=\"3\" fill=\"{}\">\
+             <title>{}: {}</title></rect>",
+            y + 16,
+            escape(&truncate(&stage.id, MAX_NODE_LABEL)),
+            y + 4,
+            TIMELINE_ROW_HEIGHT - 8,
+            status_color(stage),
+            escape(&stage.id),
+            format_elapsed_verbose((end_of(stage) - stage_start).num_seconds()),
+        );
+    }
+    let axis_y = started.len() * TIMELINE_ROW_HEIGHT + 16;
+    let _ = writeln!(
+        svg,
+        "<text x=\"{TIMELINE_LABEL_WIDTH}\" y=\"{axis_y}\" fill=\"#59636e\">{}</text>\
+         <text x=\"{width}\" y=\"{axis_y}\" text-anchor=\"end\" fill=\"#59636e\">+{}</text>",
+        start.format("%Y-%m-%d %H:%M"),
+        format_elapsed_verbose(span as i64),
+    );
+    svg.push_str("</svg>\n");
+    svg
+}
+
+fn render_entries(out: &mut String, entries: &[(String, MemoryEntry)], empty: &str) {
+    if entries.is_empty() {
+        let _ = writeln!(out, "<p class=\"empty\">{empty}</p>");
+        return;
+    }
+    out.push_str("<ul>\n");
+    for (stage_id, entry) in entries {
+        let _ = write!(
+            out,
+            "<li><code>{}</code> {}",
+            escape(stage_id),
+            escape(&entry.content)
+        );
+        if let Some(context) = &entry.context {
+            let _ = write!(out, " — {}", escape(context));
+        }
+        out.push_str("</li>\n");
+    }
+    out.push_str("</ul>\n");
+}
+
+fn status_badge(stage: &Stage) -> String {
+    format!(
+        "<span class=\"status\" style=\"background: {}\">{}</span>",
+        status_color(stage),
+        stage.status
+    )
+}
+
+fn status_color(stage: &Stage) -> &'static str {
+    match stage.status.bucket() {
+        StatusBucket::Completed => "#1a7f37",
+        StatusBucket::Executing => "#0969da",
+        StatusBucket::Pending => "#8c959f",
+        StatusBucket::Blocked => "#cf222e",
+    }
+}
+
+fn truncate(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        return text.to_string();
+    }
+    let mut short: String = text.chars().take(max - 1).collect();
+    short.push('…');
+    short
+}
+
+/// Escape text for HTML element content and attribute values.
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fs::memory::{append_entry, MemoryEntryType};
+    use crate::fs::verifications::{store_verification, GapRecord};
+    use crate::git::merge::stats::record_conflict;
+    use crate::models::stage::{AcceptanceCriterion, StageStatus};
+    use crate::verify::transitions::save_stage;
+    use chrono::Duration;
+    use tempfile::TempDir;
+
+    fn stage(id: &str, deps: &[&str], status: StageStatus, start: Option<DateTime<Utc>>) -> Stage {
+        let mut stage = Stage::new(format!("Stage {id}"), None);
+        stage.id = id.to_string();
+        stage.status = status;
+        stage.dependencies = deps.iter().map(|d| d.to_string()).collect();
+        stage.started_at = start;
+        stage.completed_at = start.map(|s| s + Duration::seconds(300));
+        stage.duration_secs = start.map(|_| 300);
+        stage
+    }
+
+    #[test]
+    fn test_report_renders_every_section() {
+        let temp = TempDir::new().unwrap();
+        let work_dir = temp.path().join(".work");
+        let start = Utc::now() - Duration::hours(1);
+
+        let mut api = stage("api", &[], StageStatus::Completed, Some(start));
+        api.name = "API <v2> & auth".to_string();
+        api.acceptance = vec![AcceptanceCriterion::Simple("cargo test".to_string())];
+        save_stage(&api, &work_dir).unwrap();
+        let mut ui = stage(
+            "ui",
+            &["api"],
+            StageStatus::Blocked,
+            Some(start + Duration::seconds(300)),
+        );
+        ui.close_reason = Some("Tests failed".to_string());
+        save_stage(&ui, &work_dir).unwrap();
+        save_stage(
+            &stage("docs", &["ui"], StageStatus::WaitingForDeps, None),
+            &work_dir,
+        )
+        .unwrap();
+
+        store_verification(
+            "api",
+            &VerificationRecord::new(
+                "api",
+                false,
+                vec![GapRecord {
+                    gap_type: "ArtifactMissing".to_string(),
+                    description: "src/auth.rs is a stub".to_string(),
+                    suggestion: String::new(),
+                }],
+            ),
+            &work_dir,
+        )
+        .unwrap();
+        record_conflict(
+            &work_dir,
+            "ui",
+            &["src/lib.rs".to_string()],
+            &["api".to_string()],
+        )
+        .unwrap();
+        append_entry(
+            &work_dir,
+            "api",
+            &MemoryEntry::new(MemoryEntryType::Decision, "Use JWT".into()),
+        )
+        .unwrap();
+
+        let report = HtmlReport::build(&work_dir, temp.path()).unwrap();
+        let path = report
+            .write(&work_dir.join("reports").join("plan-report.html"))
+            .unwrap();
+        let html = fs::read_to_string(path).unwrap();
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("API &lt;v2&gt; &amp; auth"));
+        assert!(!html.contains("API <v2>"));
+        assert_eq!(html.matches("class=\"node\"").count(), 3);
+        assert_eq!(html.matches("class=\"edge\"").count(), 2);
+        assert_eq!(html.matches("class=\"bar\"").count(), 2);
+        assert!(html.contains("<code>cargo test</code>"));
+        assert!(html.contains("ArtifactMissing: src/auth.rs is a stub"));
+        assert!(html.contains("Tests failed"));
+        assert!(html.contains("<code>src/lib.rs</code>"));
+        assert!(html.contains("unresolved"));
+        assert!(html.contains("Use JWT"));
+        assert!(html.contains("No open questions."));
+        assert!(html.contains("1/3 stages done"));
+    }
+
+    #[test]
+    fn test_empty_run_renders_placeholders() {
+        let temp = TempDir::new().unwrap();
+        let work_dir = temp.path().join(".work");
+        fs::create_dir_all(work_dir.join("stages")).unwrap();
+
+        let html = HtmlReport::build(&work_dir, temp.path()).unwrap().render();
+
+        assert!(html.contains("No stage has started yet."));
+        assert!(html.contains("No merge conflicts recorded."));
+        assert!(!html.contains("<svg"));
+    }
+
+    #[test]
+    fn test_graph_places_dependents_in_later_columns() {
+        let stages = vec![
+            stage("a", &[], StageStatus::Completed, None),
+            stage("b", &["a"], StageStatus::Queued, None),
+            stage("c", &["a"], StageStatus::Queued, None),
+        ];
+        let svg = render_graph(&stages);
+
+        let width = 2 * NODE_WIDTH + NODE_GAP_X;
+        let height = 2 * NODE_HEIGHT + NODE_GAP_Y;
+        assert!(svg.contains(&format!("width=\"{width}\" height=\"{height}\"")));
+        assert!(svg.contains(&format!("<rect x=\"{}\" y=\"0\"", NODE_WIDTH + NODE_GAP_X)));
+        assert_eq!(truncate("abcdef", 4), "abc…");
+        assert_eq!(escape("\"x\" & 'y'"), "&quot;x&quot; &amp; &#39;y&#39;");
+    }
+}
@@ -0,0 +1,5 @@
+//! Reports aggregated from a plan's recorded state
+//! Usage: loom report conflicts|html
+
+pub mod conflicts;
+pub mod html;
@@ -1,17 +1,69 @@
+//! Resume failed/blocked stages with handoff context
+//! Usage: loom resume <stage_id> [--action existing|handoff|discard]
+
+use crate::commands::common::picker::{can_prompt, pick};
+use crate::commands::sessions::latest_session_for_stage;
 use crate::fs::work_dir::WorkDir;
 use crate::handoff::{
-    continue_session, find_latest_handoff, load_handoff_content, prepare_continuation,
-    ContinuationConfig,
+    continue_session, discard_stage_handoffs, find_latest_handoff, load_handoff_content,
+    prepare_continuation, ContinuationConfig, ParsedHandoff,
 };
-use crate::models::stage::StageStatus;
+use crate::models::session::Session;
+use crate::models::stage::{Stage, StageStatus};
 use crate::models::worktree::Worktree;
+use crate::orchestrator::terminal::native::NativeBackend;
 use crate::verify::transitions::{load_stage, save_stage};
-use anyhow::{bail, Context, Result};
-use std::io::{stdin, stdout, Write};
+use anyhow::{anyhow, bail, Context, Result};
+use std::path::Path;
+use std::str::FromStr;
+
+/// Lines of a prose handoff shown when it has no "Next Steps" section.
+const SUMMARY_LINES: usize = 20;
+
+/// What `loom resume` does with the stage's session and latest handoff
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResumeAction {
+    /// Keep working in the stage's session, which is still running
+    Existing,
+    /// Spawn a continuation session that reads the latest handoff
+    Handoff,
+    /// Set the stage's handoffs aside and spawn a session without them
+    Discard,
+}
+
+impl FromStr for ResumeAction {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "existing" | "continue" | "session" => Ok(ResumeAction::Existing),
+            "handoff" | "continuation" => Ok(ResumeAction::Handoff),
+            "discard" | "fresh" => Ok(ResumeAction::Discard),
+            _ => Err(anyhow!(
+                "Unsupported resume action: {s}. Use existing, handoff or discard"
+            )),
+        }
+    }
+}
+
+impl ResumeAction {
+    fn label(self, has_handoff: bool) -> &'static str {
+        match self {
+            ResumeAction::Existing => "Continue with the existing session",
+            ResumeAction::Handoff if has_handoff => {
+                "Spawn a continuation session with the latest handoff"
+            }
+            ResumeAction::Handoff => "Spawn a new session",
+            ResumeAction::Discard => "Discard the handoff and spawn a fresh session",
+        }
+    }
+}
 
 /// Resume failed/blocked stages with handoff context
-/// Usage: loom resume <stage_id>
-pub fn execute(stage_id: String) -> Result<()> {
+///
+/// Prints a summary of the latest handoff, then continues the stage the way
+/// `action` says, or the way the user picks when no action is given.
+pub fn execute(stage_id: String, action: Option<ResumeAction>) -> Result<()> {
     let work_dir = WorkDir::new(".")?;
     work_dir.load()?;
 
@@ -35,10 +87,8 @@ pub fn execute(stage_id: String) -> Result<()> {
     if let Some(ref path) = handoff_path {
         println!("\nLatest handoff: {}", path.display());
         let content = load_handoff_content(path)?;
-        let lines: Vec<&str> = content.lines().take(20).collect();
-        println!("\nHandoff summary (first 20 lines):");
         println!("---");
-        for line in lines {
+        for line in handoff_summary(&content) {
             println!("{line}");
         }
         println!("---");
@@ -46,66 +96,298 @@ pub fn execute(stage_id: String) -> Result<()> {
         println!("\nNo handoff found for this stage.");
     }
 
-    print!("\nResume this stage? (y/n): ");
-    stdout().flush()?;
+    let session = running_session(&stage_id, work_dir.root())?;
+    if let Some(ref session) = session {
+        println!("\nSession {} is still running.", session.id);
+    }
+
+    let available = available_actions(session.is_some(), handoff_path.is_some());
+    let action = match action {
+        Some(action) if available.contains(&action) => action,
+        Some(ResumeAction::Existing) => bail!(
+            "Stage '{stage_id}' has no running session to continue; use --action handoff or --action discard"
+        ),
+        Some(_) => bail!("Stage '{stage_id}' has no handoff to discard; use --action handoff"),
+        None => match choose_action(&available, handoff_path.is_some())? {
+            Some(action) => action,
+            None => {
+                println!("Resume cancelled.");
+                return Ok(());
+            }
+        },
+    };
 
-    let mut response = String::new();
-    stdin().read_line(&mut response)?;
+    match action {
+        ResumeAction::Existing => {
+            let session = session.context("No running session")?;
+            resume_existing(&mut stage, &session, work_dir.root())
+        }
+        ResumeAction::Handoff => spawn_continuation(&mut stage, handoff_path.as_deref(), &work_dir),
+        ResumeAction::Discard => {
+            for path in discard_stage_handoffs(&stage_id, work_dir.root())? {
+                println!("✓ Discarded handoff, kept at {}", path.display());
+            }
+            spawn_continuation(&mut stage, None, &work_dir)
+        }
+    }
+}
+
+/// The actions that make sense for a stage, in the order they are offered.
+fn available_actions(session_running: bool, has_handoff: bool) -> Vec<ResumeAction> {
+    let mut actions = Vec::new();
+    if session_running {
+        actions.push(ResumeAction::Existing);
+    }
+    actions.push(ResumeAction::Handoff);
+    if has_handoff {
+        actions.push(ResumeAction::Discard);
+    }
+    actions
+}
 
-    if !response.trim().eq_ignore_ascii_case("y") {
-        println!("Resume cancelled.");
-        return Ok(());
+/// Let the user pick one of `actions`; `None` when they cancel.
+fn choose_action(actions: &[ResumeAction], has_handoff: bool) -> Result<Option<ResumeAction>> {
+    if !can_prompt() {
+        bail!(
+            "No terminal to ask on; pass --action {}",
+            actions
+                .iter()
+                .map(|a| match a {
+                    ResumeAction::Existing => "existing",
+                    ResumeAction::Handoff => "handoff",
+                    ResumeAction::Discard => "discard",
+                })
+                .collect::<Vec<_>>()
+                .join("|")
+        );
     }
+    let labels: Vec<String> = actions
+        .iter()
+        .map(|a| a.label(has_handoff).to_string())
+        .collect();
+    println!();
+    Ok(pick("How should the stage resume?", &labels)?.map(|idx| actions[idx]))
+}
+
+/// The stage's most recent session, if it is still running.
+fn running_session(stage_id: &str, work_dir: &Path) -> Result<Option<Session>> {
+    let Some(session) = latest_session_for_stage(work_dir, stage_id)? else {
+        return Ok(None);
+    };
+    let native = NativeBackend::new(work_dir.to_path_buf())
+        .context("Failed to construct native backend for the liveness check")?;
+    Ok(native.is_session_alive(&session)?.then_some(session))
+}
 
-    // Prepare continuation context
-    let context = prepare_continuation(&stage_id, work_dir.root())
-        .context("Failed to prepare continuation context")?;
+/// Hand the stage back to its running session and bring its window forward.
+fn resume_existing(stage: &mut Stage, session: &Session, work_dir: &Path) -> Result<()> {
+    stage.try_mark_queued()?;
+    stage.try_mark_executing()?;
+    stage.assign_session(session.id.clone());
+    save_stage(stage, work_dir)?;
 
-    // Create continuation configuration with auto_spawn enabled
-    let config = ContinuationConfig { auto_spawn: true };
+    println!("\n✓ Stage status updated to Executing");
+    println!("✓ Continuing in session: {}", session.id);
+
+    let focused = NativeBackend::new(work_dir.to_path_buf())
+        .map(|native| native.focus_session(session))
+        .unwrap_or(false);
+    if !focused {
+        println!("Attach with: loom sessions focus {}", session.id);
+    }
+    Ok(())
+}
+
+/// Spawn a new session for the stage, pointing it at `handoff_path`.
+fn spawn_continuation(
+    stage: &mut Stage,
+    handoff_path: Option<&Path>,
+    work_dir: &WorkDir,
+) -> Result<()> {
+    // Continuations start from Queued; Blocked and NeedsHandoff both lead there
+    stage.try_mark_queued()?;
 
     // Check if we have a worktree to spawn the session in
-    if let Some(worktree_id) = &stage.worktree {
+    if let Some(worktree_id) = stage.worktree.clone() {
+        // Prepare continuation context
+        let context = prepare_continuation(&stage.id, work_dir.root())
+            .context("Failed to prepare continuation context")?;
+
+        // Create continuation configuration with auto_spawn enabled
+        let config = ContinuationConfig { auto_spawn: true };
+
         // Create worktree object for the continuation
         let worktree = Worktree::new(
-            worktree_id.clone(),
+            worktree_id,
             context.worktree_path.clone(),
             context.branch.clone(),
         );
 
         // Spawn the continuation session with handoff context
-        let session = continue_session(
-            &context.stage,
-            context.handoff_path.as_deref(),
-            &worktree,
-            &config,
-            work_dir.root(),
-        )
-        .context("Failed to spawn continuation session")?;
+        let session = continue_session(stage, handoff_path, &worktree, &config, work_dir.root())
+            .context("Failed to spawn continuation session")?;
 
         // Update stage status to Executing
         stage.try_mark_executing()?;
-        save_stage(&stage, work_dir.root())?;
+        stage.assign_session(session.id.clone());
+        save_stage(stage, work_dir.root())?;
 
         println!("\n✓ Stage status updated to Executing");
         println!("✓ Spawned session: {}", session.id);
 
-        if let Some(ref path) = handoff_path {
-            println!("\nHandoff context loaded from: {}", path.display());
+        match handoff_path {
+            Some(path) => println!("\nHandoff context loaded from: {}", path.display()),
+            None => println!("\nThe session starts without a handoff."),
         }
     } else {
         // No worktree assigned - just update the status
         stage.try_mark_executing()?;
-        save_stage(&stage, work_dir.root())?;
+        save_stage(stage, work_dir.root())?;
 
         println!("\n✓ Stage status updated to Executing");
         println!("\n⚠️  No worktree assigned to this stage.");
         println!("Work can be resumed manually in the main directory.");
 
-        if let Some(ref path) = handoff_path {
+        if let Some(path) = handoff_path {
             println!("\nHandoff available at: {}", path.display());
         }
     }
 
     Ok(())
 }
+
+/// A short summary of a handoff: for structured handoffs what was done,
+/// what is open and what comes next; for prose handoffs the next steps.
+pub fn handoff_summary(content: &str) -> Vec<String> {
+    let mut lines = Vec::new();
+    match ParsedHandoff::parse(content) {
+        ParsedHandoff::V2(handoff) => {
+            lines.push(format!(
+                "From session {} at {:.0}% context",
+                handoff.session_id, handoff.context_percent
+            ));
+            if let Some(branch) = &handoff.branch {
+                lines.push(format!(
+                    "Branch {branch}, {} commit(s), {} uncommitted file(s)",
+                    handoff.commits.len(),
+                    handoff.uncommitted_files.len()
+                ));
+            }
+            let mut section = |title: &str, items: Vec<String>| {
+                if !items.is_empty() {
+                    lines.push(format!("{title}:"));
+                    lines.extend(items);
+                }
+            };
+            section(
+                "Completed",
+                handoff
+                    .completed_tasks
+                    .iter()
+                    .map(|t| format!("  - {}", t.description))
+                    .collect(),
+            );
+            section(
+                "Open questions",
+                handoff
+                    .open_questions
+                    .iter()
+                    .map(|q| format!("  - {q}"))
+                    .collect(),
+            );
+            section(
+                "Next",
+                handoff
+                    .next_actions
+                    .iter()
+                    .enumerate()
+                    .map(|(i, a)| format!("  {}. {a}", i + 1))
+                    .collect(),
+            );
+        }
+        ParsedHandoff::V1Fallback(text) => {
+            let mut body = text.lines().skip_while(|l| !l.starts_with("## Next Steps"));
+            let next_steps: Vec<String> = body
+                .next()
+                .into_iter()
+                .chain(body.take_while(|l| !l.starts_with("## ")))
+                .filter(|l| !l.trim().is_empty())
+                .map(String::from)
+                .collect();
+            if next_steps.is_empty() {
+                lines.extend(
+                    text.lines()
+                        .filter(|l| !l.trim().is_empty())
+                        .take(SUMMARY_LINES)
+                        .map(String::from),
+                );
+            } else {
+                lines.extend(next_steps);
+            }
+        }
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resume_action_from_str_and_availability() {
+        assert_eq!(
+            "existing".parse::<ResumeAction>().unwrap(),
+            ResumeAction::Existing
+        );
+        assert_eq!(
+            "Handoff".parse::<ResumeAction>().unwrap(),
+            ResumeAction::Handoff
+        );
+        assert_eq!(
+            "fresh".parse::<ResumeAction>().unwrap(),
+            ResumeAction::Discard
+        );
+        assert!("later".parse::<ResumeAction>().is_err());
+
+        assert_eq!(
+            available_actions(true, true),
+            vec![
+                ResumeAction::Existing,
+                ResumeAction::Handoff,
+                ResumeAction::Discard
+            ]
+        );
+        assert_eq!(available_actions(false, false), vec![ResumeAction::Handoff]);
+    }
+
+    #[test]
+    fn test_handoff_summary() {
+        let v2 = "---\nversion: 2\nsession_id: session-7\nstage_id: api\n\
+                  context_percent: 76.4\nbranch: loom/api\n\
+                  completed_tasks:\n- description: Added the login route\n\
+                  next_actions:\n- Write the token refresh\n- Add tests\n---\n\n\
+                  # Handoff: api\n";
+        assert_eq!(
+            handoff_summary(v2),
+            vec![
+                "From session session-7 at 76% context",
+                "Branch loom/api, 0 commit(s), 0 uncommitted file(s)",
+                "Completed:",
+                "  - Added the login route",
+                "Next:",
+                "  1. Write the token refresh",
+                "  2. Add tests",
+            ]
+        );
+
+        let v1 = "# Handoff\n\n## Completed Work\n\n- Routes\n\n\
+                  ## Next Steps (Prioritized)\n\n1. Tests\n2. Docs\n\n## Notes\n\nMisc\n";
+        assert_eq!(
+            handoff_summary(v1),
+            vec!["## Next Steps (Prioritized)", "1. Tests", "2. Docs"]
+        );
+
+        let prose = "# Handoff\n\nSome notes\n";
+        assert_eq!(handoff_summary(prose), vec!["# Handoff", "Some notes"]);
+    }
+}
@@ -6,10 +6,17 @@ use anyhow::{bail, Result};
 use colored::Colorize;
 use std::path::Path;
 
-use crate::git::{get_uncommitted_changes_summary, has_uncommitted_changes};
+use super::graph_loader::build_execution_graph;
+use crate::fs::work_dir::WorkDir;
+use crate::git::dirty::{dirty_paths, DirtyTreeConfig};
+use crate::orchestrator::SimulationSchedule;
+use crate::say;
+use crate::verify::transitions::list_all_stages;
 
 /// Ensure the repository is ready for Loom's git worktree operations.
-pub fn prepare_repo_for_run(repo_root: &Path) -> Result<()> {
+///
+/// `allow_dirty` is the `--allow-dirty` flag of `loom run`.
+pub fn prepare_repo_for_run(repo_root: &Path, allow_dirty: bool) -> Result<()> {
     let repo_bootstrap = crate::git::ensure_repo_ready_for_worktrees(repo_root)?;
     print_repo_bootstrap(repo_bootstrap);
 
@@ -18,14 +25,39 @@ pub fn prepare_repo_for_run(repo_root: &Path) -> Result<()> {
     // applies main-repo settings env to worktree sessions too) — heal before
     // spawning anything.
     for path in crate::fs::permissions::scrub_main_repo_settings_identity(repo_root) {
-        println!(
+        say!(
             "{} Removed stale session identity env from {}",
             "✓".green().bold(),
             path.display()
         );
     }
 
-    check_for_uncommitted_changes(repo_root)
+    let policy = DirtyTreeConfig::load(&repo_root.join(".work"));
+    check_for_uncommitted_changes(repo_root, &policy, allow_dirty)
+}
+
+/// Check that the `--until` target exists, so a bad ID fails here instead of
+/// inside the daemon.
+pub fn check_until_target(work_dir: &WorkDir, target: &str) -> Result<()> {
+    let (graph, _) = build_execution_graph(work_dir)?;
+    graph.dependency_closure(target)?;
+    Ok(())
+}
+
+/// Warn about stages a `--simulate` schedule names that the plan lacks
+/// (usually a typo, which would leave the stage on the default schedule).
+pub fn check_simulation_schedule(work_dir: &WorkDir, schedule: &SimulationSchedule) -> Result<()> {
+    let known: Vec<String> = list_all_stages(work_dir.root())?
+        .into_iter()
+        .map(|stage| stage.id)
+        .collect();
+    for id in schedule.unknown_stages(&known) {
+        say!(
+            "{} Simulation schedule names unknown stage '{id}'",
+            "!".yellow().bold()
+        );
+    }
+    Ok(())
 }
 
 fn print_repo_bootstrap(result: crate::git::RepoBootstrapResult) {
@@ -34,11 +66,11 @@ fn print_repo_bootstrap(result: crate::git::RepoBootstrapResult) {
     }
 
     if result.initialized_repo {
-        println!("{} Initialized git repository", "✓".green().bold());
+        say!("{} Initialized git repository", "✓".green().bold());
     }
 
     if result.created_initial_commit {
-        println!(
+        say!(
             "{} Created bootstrap commit for worktree support",
             "✓".green().bold()
         );
@@ -48,28 +80,54 @@ fn print_repo_bootstrap(result: crate::git::RepoBootstrapResult) {
 /// Check for uncommitted changes and bail if found
 ///
 /// This prevents starting orchestration with a dirty repository, which could
-/// cause issues with worktree creation and branch management.
-pub fn check_for_uncommitted_changes(repo_root: &Path) -> Result<()> {
-    if has_uncommitted_changes(repo_root)? {
-        let summary = get_uncommitted_changes_summary(repo_root)?;
-        eprintln!(
-            "{} Cannot start loom run with uncommitted changes",
-            "✗".red().bold()
-        );
-        eprintln!();
-        if !summary.is_empty() {
-            for line in summary.lines() {
-                eprintln!("  {}", line.dimmed());
-            }
-            eprintln!();
-        }
-        eprintln!("  {} Commit or stash your changes first:", "→".dimmed());
-        eprintln!(
-            "    {}  Commit changes",
-            "git commit -am \"message\"".cyan()
+/// cause issues with worktree creation and branch management. Paths matching
+/// the `[dirty_tree]` ignore globs never count; with `--allow-dirty` (or
+/// `allow_dirty_main = true`) the run starts anyway and merges stash the
+/// changes around each merge.
+pub fn check_for_uncommitted_changes(
+    repo_root: &Path,
+    policy: &DirtyTreeConfig,
+    allow_dirty: bool,
+) -> Result<()> {
+    let dirty = dirty_paths(repo_root, policy)?;
+    if dirty.is_empty() {
+        return Ok(());
+    }
+
+    if allow_dirty || policy.allow_dirty_main {
+        println!(
+            "{} Starting with {} uncommitted change{} (stashed around each merge): {}",
+            "⚠".yellow().bold(),
+            dirty.len(),
+            if dirty.len() == 1 { "" } else { "s" },
+            dirty.join(", ").dimmed()
         );
-        eprintln!("    {}  Or stash them", "git stash".cyan());
-        bail!("Uncommitted changes in repository - commit or stash before running loom");
+        return Ok(());
     }
-    Ok(())
+
+    eprintln!(
+        "{} Cannot start loom run with uncommitted changes",
+        "✗".red().bold()
+    );
+    eprintln!();
+    for path in &dirty {
+        eprintln!("  {}", path.dimmed());
+    }
+    eprintln!();
+    eprintln!("  {} Commit or stash your changes first:", "→".dimmed());
+    eprintln!(
+        "    {}  Commit changes",
+        "git commit -am \"message\"".cyan()
+    );
+    eprintln!("    {}  Or stash them", "git stash".cyan());
+    eprintln!(
+        "    {}  Or run anyway, stashing them around merges",
+        "loom run --allow-dirty".cyan()
+    );
+    eprintln!(
+        "    {}",
+        "Local-only paths can be ignored via [dirty_tree] ignore = [...] in .work/config.toml"
+            .dimmed()
+    );
+    bail!("Uncommitted changes in repository - commit or stash before running loom");
 }
@@ -4,43 +4,68 @@ use anyhow::{bail, Context, Result};
 use colored::Colorize;
 use std::time::Duration;
 
-use super::checks::prepare_repo_for_run;
+use super::checks::{check_simulation_schedule, prepare_repo_for_run};
 use super::graph_loader::build_execution_graph;
 use crate::commands::status::render::print_completion_summary;
 use crate::daemon::collect_completion_summary;
 use crate::fs::plan_lifecycle;
 use crate::fs::work_dir::WorkDir;
-use crate::orchestrator::{Orchestrator, OrchestratorConfig, OrchestratorResult};
+use crate::orchestrator::terminal::mock::SIMULATED_POLL_INTERVAL;
+use crate::orchestrator::{
+    Orchestrator, OrchestratorConfig, OrchestratorResult, RunDeadline, SimulationSchedule,
+};
+use crate::say;
 
 /// Execute plan stages in foreground (for --foreground flag)
-/// Usage: loom run --foreground [--manual] [--max-parallel <n>] [--watch] [--no-merge]
+/// Usage: loom run --foreground [--manual] [--max-parallel <n>] [--watch] [--no-merge] [--until <stage-id>] [--simulate [<schedule>]]
+#[allow(clippy::too_many_arguments)]
 pub fn execute(
     manual: bool,
     max_parallel: Option<usize>,
     watch: bool,
     auto_merge: bool,
+    until: Option<String>,
+    allow_dirty: bool,
+    deadline: Option<RunDeadline>,
+    simulation: Option<SimulationSchedule>,
 ) -> Result<()> {
     // Ensure git worktree prerequisites are met before starting.
     let repo_root = std::env::current_dir()?;
-    prepare_repo_for_run(&repo_root)?;
+    prepare_repo_for_run(&repo_root, allow_dirty)?;
 
     let work_dir = WorkDir::new(".")?;
     work_dir.load()?;
+    if let Some(schedule) = &simulation {
+        check_simulation_schedule(&work_dir, schedule)?;
+    }
 
     // Mark plan as in-progress when starting execution
     plan_lifecycle::mark_plan_in_progress(&work_dir)?;
 
     crate::utils::print_logo_header("Run (foreground)");
 
-    execute_foreground(manual, max_parallel, watch, auto_merge, &work_dir)
+    execute_foreground(
+        manual,
+        max_parallel,
+        watch,
+        auto_merge,
+        until,
+        deadline,
+        simulation,
+        &work_dir,
+    )
 }
 
 /// Execute orchestrator in foreground mode (for debugging)
+#[allow(clippy::too_many_arguments)]
 fn execute_foreground(
     manual: bool,
     max_parallel: Option<usize>,
     watch: bool,
     auto_merge: bool,
+    until: Option<String>,
+    deadline: Option<RunDeadline>,
+    simulation: Option<SimulationSchedule>,
     work_dir: &WorkDir,
 ) -> Result<()> {
     // Advisory Remote Control preflight — never aborts startup.
@@ -53,9 +78,13 @@ fn execute_foreground(
     // Parse config.toml to extract base_branch
     let base_branch = crate::fs::parse_base_branch_from_config(work_dir.root())?;
 
+    let simulated = simulation.is_some();
     let config = OrchestratorConfig {
         max_parallel_sessions: max_parallel.unwrap_or(4),
-        poll_interval: Duration::from_secs(5),
+        poll_interval: match simulation {
+            Some(_) => SIMULATED_POLL_INTERVAL,
+            None => Duration::from_secs(5),
+        },
         manual_mode: manual,
         watch_mode: watch,
         work_dir: work_dir.root().to_path_buf(),
@@ -68,20 +97,32 @@ fn execute_foreground(
         max_skill_recommendations: 5,
         sandbox_config: plan_sandbox,
         shutdown_flag: None,
+        until,
+        deadline,
+        event_sink: None,
+        simulation,
     };
 
     let mut orchestrator =
         Orchestrator::new(config, graph).context("Failed to create orchestrator")?;
 
+    if simulated {
+        say!(
+            "{} Simulating sessions {}",
+            "→".cyan().bold(),
+            "(no agents are started)".dimmed()
+        );
+    }
+
     if watch {
-        println!(
+        say!(
             "{} Running in watch mode {}",
             "→".cyan().bold(),
             "(continuous execution)".dimmed()
         );
-        println!("  {} Press {} to stop\n", "→".dimmed(), "Ctrl+C".bold());
+        say!("  {} Press {} to stop\n", "→".dimmed(), "Ctrl+C".bold());
     } else {
-        println!("{} Running all ready stages...", "→".cyan().bold());
+        say!("{} Running all ready stages...", "→".cyan().bold());
     }
     let result = orchestrator.run()?;
 
@@ -114,14 +155,14 @@ fn print_result(result: &OrchestratorResult) {
     crate::utils::print_logo_header("Orchestration Complete");
 
     if !result.completed_stages.is_empty() {
-        println!(
+        say!(
             "\n{} {}",
             "Completed".green().bold(),
             format!("({})", result.completed_stages.len()).dimmed()
         );
-        println!("{}", "─".repeat(40).dimmed());
+        say!("{}", "─".repeat(40).dimmed());
         for stage in &result.completed_stages {
-            println!("  {} {}", "✓".green().bold(), stage);
+            say!("  {} {}", "✓".green().bold(), stage);
         }
     }
 
@@ -131,38 +172,38 @@ fn print_result(result: &OrchestratorResult) {
             "Failed".red().bold(),
             format!("({})", result.failed_stages.len()).dimmed()
         );
-        println!("{}", "─".repeat(40).dimmed());
+        say!("{}", "─".repeat(40).dimmed());
         for stage in &result.failed_stages {
             println!("  {} {}", "✗".red().bold(), stage);
         }
     }
 
     if !result.needs_handoff.is_empty() {
-        println!(
+        say!(
             "\n{} {}",
             "Needs Handoff".yellow().bold(),
             format!("({})", result.needs_handoff.len()).dimmed()
         );
-        println!("{}", "─".repeat(40).dimmed());
+        say!("{}", "─".repeat(40).dimmed());
         for stage in &result.needs_handoff {
             println!("  {} {}", "⚠".yellow().bold(), stage);
         }
-        println!(
+        say!(
             "\n  {} Run {} to continue",
             "→".dimmed(),
             "loom resume <stage-id>".cyan()
         );
     }
 
-    println!();
-    println!("{}", "═".repeat(40).dimmed());
-    println!(
+    say!();
+    say!("{}", "═".repeat(40).dimmed());
+    say!(
         "Sessions spawned: {}",
         result.total_sessions_spawned.to_string().bold()
     );
 
     if result.is_success() {
-        println!(
+        say!(
             "\n{} All stages completed successfully!",
             "✓".green().bold()
         );
@@ -174,20 +215,20 @@ fn print_result(result: &OrchestratorResult) {
 /// This supplements the completion summary with actionable information.
 fn print_needs_attention(result: &OrchestratorResult) {
     if !result.needs_handoff.is_empty() {
-        println!(
+        say!(
             "{} {}",
             "Needs Handoff".yellow().bold(),
             format!("({})", result.needs_handoff.len()).dimmed()
         );
-        println!("{}", "─".repeat(40).dimmed());
+        say!("{}", "─".repeat(40).dimmed());
         for stage in &result.needs_handoff {
             println!("  {} {}", "⚠".yellow().bold(), stage);
         }
-        println!(
+        say!(
             "\n  {} Run {} to continue",
             "→".dimmed(),
             "loom resume <stage-id>".cyan()
         );
-        println!();
+        say!();
     }
 }
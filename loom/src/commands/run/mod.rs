@@ -7,6 +7,7 @@ mod checks;
 mod foreground;
 mod frontmatter;
 mod graph_loader;
+mod params;
 
 #[cfg(test)]
 mod tests;
@@ -17,29 +18,44 @@ use colored::Colorize;
 use crate::daemon::{DaemonConfig, DaemonServer};
 use crate::fs::plan_lifecycle;
 use crate::fs::work_dir::WorkDir;
+use crate::orchestrator::{RunDeadline, SimulationSchedule};
+use crate::say;
 
-use checks::prepare_repo_for_run;
+use checks::{check_simulation_schedule, check_until_target, prepare_repo_for_run};
 
 // Re-export the main entry point for foreground mode
 pub use foreground::execute;
+pub use params::resolve_params;
 
 // Re-export plan lifecycle functions for daemon use (now from fs module)
 pub use crate::fs::plan_lifecycle::mark_plan_done_if_all_merged;
 
 /// Execute orchestrator in background (daemon mode)
-/// Usage: loom run [--manual] [--max-parallel <n>] [--watch] [--no-merge]
+/// Usage: loom run [--manual] [--max-parallel <n>] [--watch] [--no-merge] [--until <stage-id>] [--supervised] [--max-runtime <duration>] [--param <name=value>]... [--simulate [<schedule>]]
+#[allow(clippy::too_many_arguments)]
 pub fn execute_background(
     manual: bool,
     max_parallel: Option<usize>,
     _watch: bool, // Daemon always runs in watch mode; CLI flag is accepted but ignored
     auto_merge: bool,
+    until: Option<String>,
+    allow_dirty: bool,
+    supervised: bool,
+    deadline: Option<RunDeadline>,
+    simulation: Option<SimulationSchedule>,
 ) -> Result<()> {
     // Ensure git worktree prerequisites are met before starting.
     let repo_root = std::env::current_dir()?;
-    prepare_repo_for_run(&repo_root)?;
+    prepare_repo_for_run(&repo_root, allow_dirty)?;
 
     let work_dir = WorkDir::new(".")?;
     work_dir.load()?;
+    if let Some(target) = &until {
+        check_until_target(&work_dir, target)?;
+    }
+    if let Some(schedule) = &simulation {
+        check_simulation_schedule(&work_dir, schedule)?;
+    }
 
     // Advisory Remote Control preflight — never aborts startup.
     if let Ok(claude_path) = crate::claude::find_claude_path() {
@@ -52,10 +68,10 @@ pub fn execute_background(
     crate::utils::print_logo_header("Run");
 
     if DaemonServer::is_running(work_dir.root()) {
-        println!("{} Daemon is already running", "─".dimmed());
-        println!();
-        println!("  {}  Check status", "loom status".cyan());
-        println!("  {}  Stop daemon", "loom stop".cyan());
+        say!("{} Daemon is already running", "─".dimmed());
+        say!();
+        say!("  {}  Check status", "loom status".cyan());
+        say!("  {}  Stop daemon", "loom stop".cyan());
         return Ok(());
     }
 
@@ -72,18 +88,57 @@ pub fn execute_background(
         max_parallel,
         watch_mode: true, // Daemon always runs in watch mode (ignores CLI flag)
         auto_merge,
+        until: until.clone(),
+        supervised,
+        deadline,
+        simulation: simulation.clone(),
     };
 
     let daemon = DaemonServer::with_config(work_dir.root(), daemon_config);
-    daemon.start()?;
+    if supervised {
+        daemon.start_supervised()?;
+    } else {
+        daemon.start()?;
+    }
 
-    println!("{} Daemon started", "✓".green().bold());
+    say!("{} Daemon started", "✓".green().bold());
+    if supervised {
+        say!(
+            "  {} Supervised: restarted automatically if it dies",
+            "→".dimmed()
+        );
+    }
+    if simulation.is_some() {
+        say!(
+            "  {} Simulated: sessions follow the schedule, no agents are started",
+            "→".dimmed()
+        );
+    }
     if !auto_merge {
-        println!("  {} Auto-merge disabled", "→".dimmed());
+        say!("  {} Auto-merge disabled", "→".dimmed());
+    }
+    if let Some(target) = &until {
+        say!(
+            "  {} Running until '{target}' and its dependencies complete",
+            "→".dimmed()
+        );
+    }
+    if let Some(deadline) = &deadline {
+        let local = deadline.at.with_timezone(&chrono::Local);
+        say!(
+            "  {} No new stages after {}{}",
+            "→".dimmed(),
+            local.format("%Y-%m-%d %H:%M"),
+            if deadline.handoff {
+                "; running sessions are then handed off"
+            } else {
+                ""
+            }
+        );
     }
-    println!();
-    println!("  {}  Monitor progress", "loom status".cyan());
-    println!("  {}  Stop daemon", "loom stop".cyan());
+    say!();
+    say!("  {}  Monitor progress", "loom status".cyan());
+    say!("  {}  Stop daemon", "loom stop".cyan());
 
     Ok(())
 }
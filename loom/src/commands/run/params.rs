@@ -0,0 +1,150 @@
+//! Run-time stage parameters for `loom run`.
+//!
+//! Collects the `params` the plan's stages declare before the orchestrator
+//! starts: from `--param NAME=VALUE` first, then values stored by an earlier
+//! run, then an interactive prompt, then the declared default.
+
+use anyhow::{anyhow, bail, Context, Result};
+use colored::Colorize;
+use std::io::Write;
+use std::path::Path;
+
+use crate::commands::common::picker::{can_prompt, pick};
+use crate::fs::params::{declared_params, load_params, save_params, ParamValues};
+use crate::models::stage::StageParam;
+use crate::say;
+use crate::verify::transitions::list_all_stages;
+
+/// Resolve and store every parameter the plan declares.
+///
+/// `assignments` are the `--param NAME=VALUE` arguments; they replace stored
+/// values. Without a terminal to prompt on, a missing parameter without a
+/// default is an error.
+pub fn resolve_params(assignments: &[String]) -> Result<()> {
+    let work_dir = Path::new(".work");
+    if !work_dir.join("stages").is_dir() {
+        if !assignments.is_empty() {
+            bail!("--param needs an initialized plan (run 'loom init' first)");
+        }
+        return Ok(());
+    }
+
+    let declared = declared_params(&list_all_stages(work_dir)?);
+    let given = parse_assignments(assignments)?;
+    if let Some(name) = given
+        .keys()
+        .find(|n| !declared.iter().any(|p| &p.name == *n))
+    {
+        bail!("No stage declares a parameter named '{name}'");
+    }
+
+    let mut stored = load_params(work_dir)?;
+    let mut changed = false;
+    for param in &declared {
+        let value = match given.get(&param.name) {
+            Some(value) => value.clone(),
+            None if stored.contains_key(&param.name) => continue,
+            None => ask(param)?,
+        };
+        if !param.accepts(&value) {
+            bail!(
+                "'{value}' is not a valid value for parameter '{}' (choose from: {})",
+                param.name,
+                param.choices.join(", ")
+            );
+        }
+        if stored.get(&param.name) != Some(&value) {
+            stored.insert(param.name.clone(), value);
+            changed = true;
+        }
+    }
+
+    if changed {
+        save_params(work_dir, &stored)?;
+    }
+    if !declared.is_empty() {
+        say!("{}", "Parameters:".bold());
+        for param in &declared {
+            if let Some(value) = stored.get(&param.name) {
+                say!("  {} = {}", param.name.cyan(), value);
+            }
+        }
+        say!();
+    }
+    Ok(())
+}
+
+/// Parse `NAME=VALUE` arguments; a later one wins.
+fn parse_assignments(assignments: &[String]) -> Result<ParamValues> {
+    assignments
+        .iter()
+        .map(|assignment| {
+            let (name, value) = assignment
+                .split_once('=')
+                .with_context(|| format!("Invalid --param '{assignment}': expected NAME=VALUE"))?;
+            let name = name.trim();
+            if name.is_empty() {
+                bail!("Invalid --param '{assignment}': the name is empty");
+            }
+            Ok((name.to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+/// Ask for `param`, or fall back to its default when no one can answer.
+fn ask(param: &StageParam) -> Result<String> {
+    let missing = || {
+        anyhow!(
+            "Parameter '{}' is not set; pass --param {}=<value>",
+            param.name,
+            param.name
+        )
+    };
+    if !can_prompt() {
+        return param.default.clone().ok_or_else(missing);
+    }
+
+    let mut prompt = format!("Parameter '{}'", param.name.bold());
+    if let Some(description) = &param.description {
+        prompt.push_str(&format!(" - {description}"));
+    }
+    if let Some(default) = &param.default {
+        prompt.push_str(&format!(" (Enter for {default})"));
+    }
+
+    if !param.choices.is_empty() {
+        return match pick(&prompt, &param.choices)? {
+            Some(idx) => Ok(param.choices[idx].clone()),
+            None => param.default.clone().ok_or_else(missing),
+        };
+    }
+
+    print!("{prompt}: ");
+    std::io::stdout().flush()?;
+    let mut response = String::new();
+    std::io::stdin().read_line(&mut response)?;
+    match response.trim() {
+        "" => param.default.clone().ok_or_else(missing),
+        value => Ok(value.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_assignments() {
+        let values = parse_assignments(&[
+            "target_env=staging".to_string(),
+            "query=a=b".to_string(),
+            "target_env=prod".to_string(),
+        ])
+        .unwrap();
+        assert_eq!(values.get("target_env").map(String::as_str), Some("prod"));
+        assert_eq!(values.get("query").map(String::as_str), Some("a=b"));
+
+        assert!(parse_assignments(&["target_env".to_string()]).is_err());
+        assert!(parse_assignments(&["=prod".to_string()]).is_err());
+    }
+}
@@ -19,6 +19,15 @@ fn create_test_plan(dir: &Path, stages: Vec<StageDefinition>) -> PathBuf {
             sandbox: SandboxConfig::default(),
             change_impact: None,
             adjudication: None,
+            conflict_avoidance: Default::default(),
+            auto_code_review: false,
+            branch_template: None,
+            push_branches: false,
+            push_remote: None,
+            sparse_checkout: false,
+            sparse_include: Vec::new(),
+            knowledge_first: false,
+            knowledge_refresh_every: None,
             stages,
         },
     };
@@ -66,6 +75,20 @@ fn setup_work_dir_with_plan(temp_dir: &TempDir) -> (PathBuf, WorkDir) {
         reasoning_effort: None,
         code_review: None,
         ultracode: false,
+        pair: false,
+        worktree_setup: vec![],
+        instructions: None,
+        prompt_prefix: None,
+        container: None,
+        keep_terminal: None,
+        outputs: Vec::new(),
+        params: Vec::new(),
+        requires_outputs: Vec::new(),
+        new_files: Vec::new(),
+        expected_duration: None,
+        acceptance_isolation: None,
+        skip_if_satisfied: false,
+        package: None,
     };
 
     let plan_path = create_test_plan(temp_dir.path(), vec![stage_def]);
@@ -185,6 +208,33 @@ sandbox:
     );
 }
 
+/// Stage files store produced output values under `outputs`, the key plans
+/// use for the declared output schema; those must still load.
+#[test]
+fn test_extract_stage_definition_accepts_stage_output_values() {
+    let content = r#"---
+id: api
+name: API
+working_dir: "."
+outputs:
+  - key: api_port
+    value: 8080
+    description: Port the API listens on
+expected_outputs:
+  - key: api_port
+    type: int
+    required: true
+---
+
+# Stage
+"#;
+
+    let def = extract_stage_definition(content).expect("should deserialize");
+
+    assert_eq!(def.outputs.len(), 1);
+    assert_eq!(def.outputs[0].key, "api_port");
+}
+
 #[test]
 fn test_extract_stage_definition_no_delimiter() {
     let content = "No frontmatter here";
@@ -376,3 +426,44 @@ fn test_orchestrator_result_with_handoffs() {
 
     assert!(!result.is_success());
 }
+
+#[test]
+fn test_check_for_uncommitted_changes_policy() {
+    use super::checks::check_for_uncommitted_changes;
+    use crate::git::dirty::DirtyTreeConfig;
+    use std::process::Command;
+
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    let git = |args: &[&str]| {
+        let out = Command::new("git")
+            .args(args)
+            .current_dir(root)
+            .output()
+            .unwrap();
+        assert!(out.status.success(), "git {args:?} failed");
+    };
+    git(&["init", "-b", "main"]);
+    git(&["config", "user.email", "t@t.com"]);
+    git(&["config", "user.name", "t"]);
+    fs::write(root.join(".env"), "A=1\n").unwrap();
+    git(&["add", "."]);
+    git(&["commit", "-m", "init"]);
+    fs::write(root.join(".env"), "A=2\n").unwrap();
+
+    let strict = DirtyTreeConfig::default();
+    assert!(check_for_uncommitted_changes(root, &strict, false).is_err());
+    assert!(check_for_uncommitted_changes(root, &strict, true).is_ok());
+
+    let ignoring = DirtyTreeConfig {
+        allow_dirty_main: false,
+        ignore: vec![".env".to_string()],
+    };
+    assert!(check_for_uncommitted_changes(root, &ignoring, false).is_ok());
+
+    let allowing = DirtyTreeConfig {
+        allow_dirty_main: true,
+        ignore: Vec::new(),
+    };
+    assert!(check_for_uncommitted_changes(root, &allowing, false).is_ok());
+}
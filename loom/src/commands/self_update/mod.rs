@@ -60,6 +60,9 @@ struct Asset {
 
 /// Execute self-update command.
 pub fn execute() -> Result<()> {
+    let work_dir = crate::commands::common::find_work_dir().ok();
+    crate::offline::ensure_online(work_dir.as_deref(), "loom self-update")?;
+
     crate::utils::print_logo_header("Self Update");
     println!("{}", "Checking for updates...".blue());
 
@@ -1,15 +1,78 @@
 //! Session management commands
-//! Usage: loom sessions [list|kill <id>...]
+//! Usage: loom sessions [list|kill <id>...|kill --all|kill --status crashed|stale|focus [<id>]]
 
 use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
+use std::path::{Path, PathBuf};
 
 use crate::commands::common::find_work_dir;
+use crate::commands::common::picker::{can_prompt, fuzzy_rank, pick, MAX_CHOICES};
 use crate::fs::session_files::find_session_file;
 use crate::fs::worktree_files::find_sessions_for_stage;
-use crate::models::session::Session;
-use crate::orchestrator::terminal::native::NativeBackend;
+use crate::models::constants::STALENESS_THRESHOLD_SECS;
+use crate::models::session::{Session, SessionStatus};
+use crate::orchestrator::monitor::heartbeat::{heartbeat_path, read_heartbeat};
+use crate::orchestrator::terminal::native::{pids_dir, wrappers_dir, NativeBackend};
 use crate::parser::frontmatter::parse_from_markdown;
 
+/// Which sessions `loom sessions kill --status` selects
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionFilter {
+    /// Marked crashed, or recorded as running while its process is gone
+    Crashed,
+    /// Running, but silent for longer than the heartbeat staleness threshold
+    Stale,
+}
+
+impl std::fmt::Display for SessionFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SessionFilter::Crashed => write!(f, "crashed"),
+            SessionFilter::Stale => write!(f, "stale"),
+        }
+    }
+}
+
+impl std::str::FromStr for SessionFilter {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "crashed" => Ok(SessionFilter::Crashed),
+            "stale" => Ok(SessionFilter::Stale),
+            _ => bail!("Invalid session status: {s}. Use: crashed, stale"),
+        }
+    }
+}
+
+impl SessionFilter {
+    /// Whether `session` matches. `alive` reports whether its process is
+    /// still running; `last_seen` is its latest heartbeat, or its last
+    /// recorded activity.
+    fn matches(
+        self,
+        session: &Session,
+        alive: impl FnOnce(&Session) -> bool,
+        last_seen: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> bool {
+        let running = matches!(
+            session.status,
+            SessionStatus::Spawning | SessionStatus::Running
+        );
+        match self {
+            SessionFilter::Crashed => {
+                session.status == SessionStatus::Crashed || (running && !alive(session))
+            }
+            SessionFilter::Stale => {
+                running
+                    && !session.is_human_session()
+                    && (now - last_seen).num_seconds() > STALENESS_THRESHOLD_SECS as i64
+            }
+        }
+    }
+}
+
 /// List all sessions
 pub fn list() -> Result<()> {
     println!("Active sessions:");
@@ -47,15 +110,56 @@ pub fn list() -> Result<()> {
     Ok(())
 }
 
-/// Kill one or more sessions by ID/prefix, or all sessions for a stage
-pub fn kill(session_ids: Vec<String>, stage: Option<String>) -> Result<()> {
+/// Kill one or more sessions by ID/prefix, all sessions for a stage, or
+/// every session (`--all`), optionally only those matching `status`
+pub fn kill(
+    session_ids: Vec<String>,
+    stage: Option<String>,
+    all: bool,
+    status: Option<SessionFilter>,
+) -> Result<()> {
     let work_dir = find_work_dir()?;
+    let native = NativeBackend::new(work_dir.to_path_buf())
+        .with_context(|| "Failed to construct native backend for session kill")?;
 
     // Collect all session IDs to kill
     let mut ids_to_kill = session_ids;
 
-    // If --stage is provided, find all sessions for that stage
-    if let Some(stage_id) = &stage {
+    if all || status.is_some() {
+        let now = Utc::now();
+        let selected: Vec<String> = load_all_sessions(&work_dir)
+            .into_iter()
+            .filter(|session| {
+                stage
+                    .as_deref()
+                    .is_none_or(|id| session.stage_id.as_deref() == Some(id))
+            })
+            .filter(|session| {
+                status.is_none_or(|filter| {
+                    filter.matches(
+                        session,
+                        |s| native.is_session_alive(s).unwrap_or(false),
+                        last_seen(&work_dir, session),
+                        now,
+                    )
+                })
+            })
+            .map(|session| session.id)
+            .collect();
+
+        let kind = status.map(|s| format!("{s} ")).unwrap_or_default();
+        let scope = stage
+            .as_deref()
+            .map(|id| format!(" for stage '{id}'"))
+            .unwrap_or_default();
+        if selected.is_empty() {
+            println!("No {kind}sessions found{scope}");
+            return Ok(());
+        }
+        println!("Found {} {kind}session(s){scope}", selected.len());
+        ids_to_kill.extend(selected);
+    } else if let Some(stage_id) = &stage {
+        // If --stage is provided, find all sessions for that stage
         let stage_sessions = find_sessions_for_stage(stage_id, &work_dir)
             .with_context(|| format!("Failed to find sessions for stage '{stage_id}'"))?;
 
@@ -79,7 +183,7 @@ pub fn kill(session_ids: Vec<String>, stage: Option<String>) -> Result<()> {
     let mut failure_count = 0;
 
     for session_id in &ids_to_kill {
-        match kill_single_session(&work_dir, session_id) {
+        match kill_single_session(&work_dir, &native, session_id) {
             Ok(()) => success_count += 1,
             Err(e) => {
                 eprintln!("Failed to kill session '{session_id}': {e}");
@@ -103,8 +207,153 @@ pub fn kill(session_ids: Vec<String>, stage: Option<String>) -> Result<()> {
     Ok(())
 }
 
+/// Bring a session's window or tab to the front.
+///
+/// `target` may be a session ID or prefix, a stage ID (its most recent
+/// session), or anything close to either: fuzzy matches are offered in a
+/// numbered picker. Without a target, the picker lists every session.
+pub fn focus(target: Option<String>, stage: Option<String>) -> Result<()> {
+    let work_dir = find_work_dir()?;
+
+    let session = match (target, stage) {
+        (_, Some(stage_id)) => latest_session_for_stage(&work_dir, &stage_id)?
+            .with_context(|| format!("No sessions found for stage '{stage_id}'"))?,
+        (Some(target), None) => resolve_focus_target(&work_dir, &target)?,
+        (None, None) => {
+            let sessions = load_all_sessions(&work_dir);
+            if sessions.is_empty() {
+                bail!("No sessions found");
+            }
+            choose_session(sessions, "Sessions:")?
+        }
+    };
+
+    let native = NativeBackend::new(work_dir.to_path_buf())
+        .with_context(|| "Failed to construct native backend for session focus")?;
+    if !native.focus_session(&session) {
+        bail!(
+            "No window or tab found for session '{}'. It may have exited, or the terminal \
+             offers no way to focus it",
+            session.id
+        );
+    }
+    println!("Focused session {}", session.id);
+    Ok(())
+}
+
+/// Resolve a focus target: an exact or unique-prefix session ID, then an
+/// exact stage ID, then fuzzy matches over session and stage IDs.
+fn resolve_focus_target(work_dir: &Path, target: &str) -> Result<Session> {
+    // An ambiguous prefix is not an error here; the picker handles it
+    if let Ok(Some(_)) = find_session_file(work_dir, target) {
+        return load_session(work_dir, target);
+    }
+    if let Some(session) = latest_session_for_stage(work_dir, target)? {
+        return Ok(session);
+    }
+
+    let sessions = load_all_sessions(work_dir);
+    let mut matches = fuzzy_match_sessions(target, sessions);
+    match matches.len() {
+        0 => bail!("No session or stage matches '{target}'"),
+        1 => {
+            let session = matches.remove(0);
+            println!("Matched session {}", session_label(&session));
+            Ok(session)
+        }
+        _ => choose_session(matches, &format!("Sessions matching '{target}':")),
+    }
+}
+
+/// Sessions whose ID or stage ID fuzzy-matches `query`, best first.
+fn fuzzy_match_sessions(query: &str, sessions: Vec<Session>) -> Vec<Session> {
+    let keys: Vec<Vec<&str>> = sessions
+        .iter()
+        .map(|s| {
+            std::iter::once(s.id.as_str())
+                .chain(s.stage_id.as_deref())
+                .collect()
+        })
+        .collect();
+    let order = fuzzy_rank(query, &keys);
+    let mut slots: Vec<Option<Session>> = sessions.into_iter().map(Some).collect();
+    order
+        .into_iter()
+        .filter_map(|idx| slots[idx].take())
+        .collect()
+}
+
+/// Let the user pick one of `sessions` from a numbered list, or list them
+/// and fail when stdin is not a terminal.
+fn choose_session(mut sessions: Vec<Session>, heading: &str) -> Result<Session> {
+    sessions.truncate(MAX_CHOICES);
+    let labels: Vec<String> = sessions.iter().map(session_label).collect();
+    if !can_prompt() {
+        bail!(
+            "Several sessions match; specify one of:\n  {}",
+            labels.join("\n  ")
+        );
+    }
+    match pick(heading, &labels)? {
+        Some(idx) => Ok(sessions.swap_remove(idx)),
+        None => bail!("No session selected"),
+    }
+}
+
+fn session_label(session: &Session) -> String {
+    match &session.stage_id {
+        Some(stage_id) => format!("{} (stage: {stage_id})", session.id),
+        None => session.id.clone(),
+    }
+}
+
+/// The most recent session of a stage, if it has any.
+pub fn latest_session_for_stage(work_dir: &Path, stage_id: &str) -> Result<Option<Session>> {
+    Ok(find_sessions_for_stage(stage_id, work_dir)
+        .with_context(|| format!("Failed to find sessions for stage '{stage_id}'"))?
+        .iter()
+        .filter_map(|id| load_session(work_dir, id).ok())
+        .max_by_key(|session| session.created_at))
+}
+
+/// All readable sessions, most recent first.
+fn load_all_sessions(work_dir: &Path) -> Vec<Session> {
+    let Ok(entries) = std::fs::read_dir(work_dir.join("sessions")) else {
+        return Vec::new();
+    };
+    let mut sessions: Vec<Session> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "md"))
+        .filter_map(|path| std::fs::read_to_string(path).ok())
+        .filter_map(|content| parse_from_markdown(&content, "Session").ok())
+        .collect();
+    sessions.sort_by_key(|s: &Session| std::cmp::Reverse(s.created_at));
+    sessions
+}
+
+fn load_session(work_dir: &Path, session_id: &str) -> Result<Session> {
+    let Some(session_file) = find_session_file(work_dir, session_id)? else {
+        bail!("Session '{session_id}' not found");
+    };
+    let content = std::fs::read_to_string(&session_file)
+        .with_context(|| format!("Failed to read session file: {}", session_file.display()))?;
+    parse_from_markdown(&content, "Session").context("Failed to parse session from markdown")
+}
+
+/// When `session` was last heard from: its stage heartbeat if the session
+/// wrote it, otherwise its last recorded activity.
+fn last_seen(work_dir: &Path, session: &Session) -> DateTime<Utc> {
+    session
+        .stage_id
+        .as_deref()
+        .and_then(|stage_id| read_heartbeat(&heartbeat_path(work_dir, stage_id)).ok())
+        .filter(|heartbeat| heartbeat.session_id == session.id)
+        .map_or(session.last_active, |heartbeat| heartbeat.timestamp)
+}
+
 /// Kill a single session by ID or prefix
-fn kill_single_session(work_dir: &std::path::Path, session_id: &str) -> Result<()> {
+fn kill_single_session(work_dir: &Path, native: &NativeBackend, session_id: &str) -> Result<()> {
     let session_file = match find_session_file(work_dir, session_id)? {
         Some(path) => path,
         None => bail!("Session '{session_id}' not found"),
@@ -127,9 +376,6 @@ fn kill_single_session(work_dir: &std::path::Path, session_id: &str) -> Result<(
         .context("Failed to parse session from markdown")?;
 
     // Use the native backend for kill/liveness — all sessions are native.
-    let native = NativeBackend::new(work_dir.to_path_buf())
-        .with_context(|| "Failed to construct native backend for session kill")?;
-
     if native.is_session_alive(&session)? {
         println!("  Killing session...");
         native.kill_session(&session)?;
@@ -153,12 +399,48 @@ fn kill_single_session(work_dir: &std::path::Path, session_id: &str) -> Result<(
         println!("  Signal file removed");
     }
 
+    // A session that died on its own leaves its tracking files behind
+    let removed = session_tracking_files(work_dir, &session)
+        .iter()
+        .filter(|path| std::fs::remove_file(path).is_ok())
+        .count();
+    if removed > 0 {
+        println!("  {removed} PID, wrapper and heartbeat file(s) removed");
+    }
+
     Ok(())
 }
 
+/// Files that track a session's process: its PID file and wrapper script
+/// (keyed by `<tracking-key>-<session-id>`), the `.refreshed` marker of its
+/// signal, and its stage heartbeat when this session wrote it last.
+fn session_tracking_files(work_dir: &Path, session: &Session) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = [pids_dir(work_dir), wrappers_dir(work_dir)]
+        .iter()
+        .filter_map(|dir| std::fs::read_dir(dir).ok())
+        .flatten()
+        .flatten()
+        .filter(|entry| entry.file_name().to_string_lossy().contains(&session.id))
+        .map(|entry| entry.path())
+        .collect();
+    paths.push(
+        work_dir
+            .join("signals")
+            .join(format!("{}.refreshed", session.id)),
+    );
+    if let Some(stage_id) = &session.stage_id {
+        let heartbeat = heartbeat_path(work_dir, stage_id);
+        if read_heartbeat(&heartbeat).is_ok_and(|h| h.session_id == session.id) {
+            paths.push(heartbeat);
+        }
+    }
+    paths.into_iter().filter(|path| path.exists()).collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::orchestrator::monitor::heartbeat::{write_heartbeat, Heartbeat};
 
     #[test]
     fn test_parse_session_from_markdown_valid() {
@@ -182,6 +464,74 @@ last_active: 2024-01-01T00:00:00Z
         assert_eq!(session.pid, Some(12345));
     }
 
+    #[test]
+    fn test_session_filter_matches() {
+        let now = Utc::now();
+        let mut session = Session::new();
+        session.status = SessionStatus::Running;
+        let recent = now - chrono::Duration::seconds(10);
+        let silent = now - chrono::Duration::seconds(STALENESS_THRESHOLD_SECS as i64 + 1);
+
+        assert!(SessionFilter::Crashed.matches(&session, |_| false, recent, now));
+        assert!(!SessionFilter::Crashed.matches(&session, |_| true, recent, now));
+        assert!(SessionFilter::Stale.matches(&session, |_| true, silent, now));
+        assert!(!SessionFilter::Stale.matches(&session, |_| true, recent, now));
+
+        session.status = SessionStatus::Crashed;
+        assert!(SessionFilter::Crashed.matches(&session, |_| true, recent, now));
+        assert!(!SessionFilter::Stale.matches(&session, |_| true, silent, now));
+
+        session.status = SessionStatus::Completed;
+        assert!(!SessionFilter::Crashed.matches(&session, |_| false, recent, now));
+
+        assert_eq!(
+            "Stale".parse::<SessionFilter>().unwrap(),
+            SessionFilter::Stale
+        );
+        assert!("running".parse::<SessionFilter>().is_err());
+    }
+
+    #[test]
+    fn test_session_tracking_files_only_match_the_session() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let work_dir = temp.path();
+        let mut session = Session::new();
+        session.assign_to_stage("api".to_string());
+        let mut other = Session::new();
+        other.assign_to_stage("api".to_string());
+
+        for dir in ["pids", "wrappers", "signals", "heartbeat"] {
+            std::fs::create_dir_all(work_dir.join(dir)).unwrap();
+        }
+        let own = [
+            format!("pids/loom-api-{}.pid", session.id),
+            format!("wrappers/loom-api-{}.sh", session.id),
+            format!("signals/{}.refreshed", session.id),
+        ];
+        for file in own
+            .iter()
+            .chain([&format!("pids/loom-api-{}.pid", other.id)])
+        {
+            std::fs::write(work_dir.join(file), "").unwrap();
+        }
+        let heartbeat = |owner: &Session| {
+            let beat = Heartbeat::new("api".to_string(), owner.id.clone());
+            write_heartbeat(work_dir, &beat).unwrap();
+        };
+
+        heartbeat(&other);
+        let mut files = session_tracking_files(work_dir, &session);
+        files.sort();
+        let mut expected: Vec<PathBuf> = own.iter().map(|f| work_dir.join(f)).collect();
+        expected.sort();
+        assert_eq!(files, expected);
+
+        heartbeat(&session);
+        assert!(
+            session_tracking_files(work_dir, &session).contains(&heartbeat_path(work_dir, "api"))
+        );
+    }
+
     #[test]
     fn test_parse_session_from_markdown_invalid() {
         let content = "Invalid content without frontmatter";
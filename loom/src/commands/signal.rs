@@ -0,0 +1,131 @@
+//! Signal commands
+//! Usage: loom signal [refresh <session-id>|preview <stage-id>]
+
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+use std::fs;
+
+use crate::commands::common::find_work_dir;
+use crate::fs::session_files::find_session_file;
+use crate::git::worktree::find_repo_root_from_cwd;
+use crate::language::detect_project_languages;
+use crate::models::session::Session;
+use crate::orchestrator::signals::{load_dependency_status, preview_signal, refresh_signal};
+use crate::parser::frontmatter::parse_from_markdown;
+use crate::skills::load_skill_index;
+use crate::verify::transitions::load_stage;
+
+/// Refresh the signal of a running session from current plan state
+pub fn refresh(session_id: String) -> Result<()> {
+    let work_dir = find_work_dir()?;
+    let Some(session_file) = find_session_file(&work_dir, &session_id)? else {
+        bail!("Session '{session_id}' not found");
+    };
+    let content = fs::read_to_string(&session_file)
+        .with_context(|| format!("Failed to read session file: {}", session_file.display()))?;
+    let session: Session = parse_from_markdown(&content, "Session")
+        .context("Failed to parse session from markdown")?;
+
+    let Some(stage_id) = session.stage_id.clone() else {
+        bail!("Session '{}' is not assigned to a stage", session.id);
+    };
+    let stage = load_stage(&stage_id, &work_dir)?;
+    let deps = load_dependency_status(&stage, &work_dir);
+
+    let cwd = std::env::current_dir().context("Failed to get current directory")?;
+    let repo_root = find_repo_root_from_cwd(&cwd).unwrap_or(cwd);
+
+    let refresh = refresh_signal(&session, &stage, &deps, &repo_root, &work_dir)?;
+
+    if refresh.changed_sections.is_empty() {
+        println!(
+            "{} Signal for session '{}' is already up to date",
+            "✓".green().bold(),
+            session.id
+        );
+        return Ok(());
+    }
+
+    println!(
+        "{} Refreshed signal: {}",
+        "✓".green().bold(),
+        refresh.path.display()
+    );
+    println!("  Changed sections:");
+    for section in &refresh.changed_sections {
+        println!("    - {section}");
+    }
+    println!(
+        "{}",
+        "  The session will be told to re-read its signal on its next tool call.".dimmed()
+    );
+
+    Ok(())
+}
+
+/// Show the signal a stage would get if it started now, with per-section
+/// token estimates and lint warnings. Nothing is written.
+pub fn preview(stage_id: String, show_content: bool) -> Result<()> {
+    let work_dir = find_work_dir()?;
+    let stage = load_stage(&stage_id, &work_dir)?;
+    let deps = load_dependency_status(&stage, &work_dir);
+
+    let cwd = std::env::current_dir().context("Failed to get current directory")?;
+    let repo_root = find_repo_root_from_cwd(&cwd).unwrap_or(cwd);
+    let skill_index = load_skill_index(None);
+    let languages = detect_project_languages(&repo_root);
+
+    let preview = preview_signal(
+        &stage,
+        &deps,
+        &repo_root,
+        &work_dir,
+        skill_index.as_ref(),
+        &languages,
+    );
+
+    println!(
+        "{} {} (~{} tokens, {} bytes)",
+        "Signal preview:".bold(),
+        stage.id,
+        preview.estimated_tokens(),
+        preview.content.len()
+    );
+    println!();
+    let width = preview
+        .sections
+        .iter()
+        .map(|s| s.heading.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("Section".len());
+    println!("  {:<width$}  {:>7}", "Section".dimmed(), "Tokens".dimmed());
+    for section in &preview.sections {
+        println!(
+            "  {:<width$}  {:>7}",
+            section.heading, section.estimated_tokens
+        );
+    }
+    println!();
+
+    if preview.warnings.is_empty() {
+        println!("{} No warnings", "✓".green().bold());
+    } else {
+        println!(
+            "{} {} warning(s):",
+            "⚠".yellow().bold(),
+            preview.warnings.len()
+        );
+        for warning in &preview.warnings {
+            println!("  - {warning}");
+        }
+    }
+
+    if show_content {
+        println!();
+        println!("{}", "─".repeat(60).dimmed());
+        print!("{}", preview.content);
+    }
+
+    Ok(())
+}
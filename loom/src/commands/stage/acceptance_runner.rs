@@ -6,9 +6,15 @@
 use anyhow::{Context, Result};
 use std::path::{Path, PathBuf};
 
-use crate::git::worktree::{find_repo_root_from_cwd, find_worktree_root_from_cwd};
+use crate::fs::flaky::record_flaky_results;
+use crate::git::worktree::{
+    find_repo_root_from_cwd, find_worktree_root_from_cwd, get_worktree_path,
+};
+use crate::models::failure::{FailureInfo, FailureType};
 use crate::models::stage::Stage;
-use crate::verify::criteria::run_acceptance;
+use crate::orchestrator::retry::classify_output;
+use crate::verify::criteria::{run_acceptance, AcceptanceResult};
+use crate::verify::transitions::update_stage;
 
 /// Resolved execution paths for a standard stage.
 #[derive(Debug, Clone)]
@@ -110,7 +116,7 @@ pub(crate) fn resolve_stage_execution_paths(stage: &Stage) -> Result<StageExecut
         .map(|w| {
             repo_root
                 .as_ref()
-                .map(|root| root.join(".worktrees").join(w))
+                .map(|root| get_worktree_path(w, root))
                 .unwrap_or_else(|| PathBuf::from(".worktrees").join(w))
         })
         .filter(|p| p.exists());
@@ -173,15 +179,27 @@ pub(crate) fn run_acceptance_with_display(
     let result =
         run_acceptance(stage, acceptance_dir).context("Failed to run acceptance criteria")?;
 
+    record_flaky_stats(Path::new(".work"), stage, &result);
+    record_acceptance_failure(Path::new(".work"), stage_id, &result);
+
     for criterion_result in result.results() {
-        if criterion_result.success {
-            println!("  ✓ passed: {}", criterion_result.command);
+        let label = criterion_result.label();
+        if criterion_result.success && criterion_result.attempts > 1 {
+            println!(
+                "  ✓ passed after {} attempts (flaky): {label}",
+                criterion_result.attempts
+            );
+        } else if criterion_result.success {
+            println!("  ✓ passed: {label}");
         } else if criterion_result.timed_out {
-            println!("  ✗ TIMEOUT: {}", criterion_result.command);
+            println!("  ✗ TIMEOUT: {label}");
         } else {
-            println!("  ✗ FAILED: {}", criterion_result.command);
+            println!("  ✗ FAILED: {label}");
         }
     }
+    if let Some(line) = category_summary_line(&result) {
+        println!("  {line}");
+    }
 
     if result.all_passed() {
         println!("All acceptance criteria passed!");
@@ -190,6 +208,107 @@ pub(crate) fn run_acceptance_with_display(
     Ok(result.all_passed())
 }
 
+/// `By category: test 3/3, lint 0/1`, or `None` when no criterion has a
+/// category.
+pub(crate) fn category_summary_line(result: &AcceptanceResult) -> Option<String> {
+    let summaries = result.category_summaries();
+    if summaries.is_empty() {
+        return None;
+    }
+    let parts: Vec<String> = summaries
+        .iter()
+        .map(|s| format!("{} {}/{}", s.category, s.passed, s.total))
+        .collect();
+    Some(format!("By category: {}", parts.join(", ")))
+}
+
+/// Update `.work/verifications/flaky.json` for retried criteria and warn
+/// about chronic flakes.
+///
+/// Stats are best-effort: failures are reported as warnings and never affect
+/// the acceptance outcome. Nothing is recorded if `work_dir` does not exist.
+pub(crate) fn record_flaky_stats(work_dir: &Path, stage: &Stage, result: &AcceptanceResult) {
+    if !work_dir.is_dir() {
+        return;
+    }
+    match record_flaky_results(work_dir, &stage.id, &stage.acceptance, result.results()) {
+        Ok(chronic) => {
+            for stats in chronic {
+                eprintln!(
+                    "warning: chronic flaky criterion '{}' has needed retries in {} of {} runs",
+                    stats.command, stats.flaky_passes, stats.runs
+                );
+            }
+        }
+        Err(e) => eprintln!("warning: failed to record flaky criteria stats: {e:#}"),
+    }
+}
+
+/// Classify a failed acceptance run.
+///
+/// The class comes from the first failed criterion: a timeout is a Timeout,
+/// otherwise its stderr then stdout are matched against known tool output
+/// (compile errors, test runner summaries, ...). A criterion that failed
+/// without a recognizable signature counts as a TestFailure.
+///
+/// # Returns
+///
+/// `None` if every criterion passed.
+pub(crate) fn acceptance_failure_info(result: &AcceptanceResult) -> Option<FailureInfo> {
+    let mut failure_type = None;
+    let mut evidence = Vec::new();
+
+    for criterion in result.results().iter().filter(|r| !r.success) {
+        let (class, line) = if criterion.timed_out {
+            (FailureType::Timeout, None)
+        } else {
+            match classify_output(&criterion.stderr).or_else(|| classify_output(&criterion.stdout))
+            {
+                Some((class, line)) => (class, Some(line)),
+                None => (FailureType::TestFailure, None),
+            }
+        };
+        let status = if criterion.timed_out {
+            "TIMEOUT"
+        } else {
+            "FAILED"
+        };
+        evidence.push(format!("{status}: {}", criterion.label()));
+        evidence.extend(line);
+        failure_type.get_or_insert(class);
+    }
+
+    failure_type.map(|failure_type| FailureInfo {
+        failure_type,
+        detected_at: chrono::Utc::now(),
+        evidence,
+    })
+}
+
+/// Store the classified acceptance failure on the stage so a later recovery
+/// signal can tailor its instructions.
+///
+/// Best-effort like the flaky stats: errors are reported as warnings.
+/// Nothing is recorded if the run passed or `work_dir` does not exist.
+pub(crate) fn record_acceptance_failure(
+    work_dir: &Path,
+    stage_id: &str,
+    result: &AcceptanceResult,
+) {
+    if !work_dir.is_dir() {
+        return;
+    }
+    let Some(info) = acceptance_failure_info(result) else {
+        return;
+    };
+    if let Err(e) = update_stage(stage_id, work_dir, |s| {
+        s.failure_info = Some(info);
+        Ok(())
+    }) {
+        eprintln!("warning: failed to record acceptance failure: {e:#}");
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -323,4 +442,60 @@ mod tests {
             expected_root.canonicalize().unwrap()
         );
     }
+
+    #[test]
+    fn test_acceptance_failure_info_classifies_first_failure() {
+        use crate::verify::criteria::CriterionResult;
+        use std::time::Duration;
+
+        let criterion = |command: &str, success: bool, stderr: &str, timed_out: bool| {
+            CriterionResult::new(
+                command.to_string(),
+                success,
+                String::new(),
+                stderr.to_string(),
+                Some(if success { 0 } else { 101 }),
+                Duration::from_millis(1),
+                timed_out,
+            )
+        };
+
+        let passed = AcceptanceResult::AllPassed {
+            results: vec![criterion("true", true, "", false)],
+        };
+        assert!(acceptance_failure_info(&passed).is_none());
+
+        let failed = AcceptanceResult::Failed {
+            results: vec![
+                criterion("true", true, "", false),
+                criterion(
+                    "cargo build",
+                    false,
+                    "error: could not compile `app`",
+                    false,
+                ),
+                criterion("cargo test -- e2e", false, "", true),
+            ],
+            failures: vec![],
+        };
+        let info = acceptance_failure_info(&failed).unwrap();
+        assert_eq!(info.failure_type, FailureType::BuildFailure);
+        assert_eq!(
+            info.evidence,
+            vec![
+                "FAILED: cargo build",
+                "error: could not compile `app`",
+                "TIMEOUT: cargo test -- e2e",
+            ]
+        );
+
+        let unrecognized = AcceptanceResult::Failed {
+            results: vec![criterion("./check.sh", false, "bad", false)],
+            failures: vec![],
+        };
+        assert_eq!(
+            acceptance_failure_info(&unrecognized).unwrap().failure_type,
+            FailureType::TestFailure
+        );
+    }
 }
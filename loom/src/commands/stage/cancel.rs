@@ -0,0 +1,70 @@
+//! Cancel command: stop a stage for good and clean up after it
+
+use anyhow::Result;
+use colored::Colorize;
+use std::path::Path;
+
+use crate::git::cleanup::{cleanup_after_merge, CleanupConfig};
+use crate::models::stage::StageType;
+use crate::orchestrator::skip::cancel_stage;
+use crate::verify::transitions::load_stage;
+
+use super::state::kill_stage_session;
+
+/// Cancel a stage
+///
+/// Marks the stage Skipped (reason "cancelled") from any unfinished status,
+/// kills its session, optionally deletes its worktree and branch, and
+/// recomputes its dependents (cancelling them too with `cascade`).
+pub fn cancel(stage_id: String, delete_worktree: bool, cascade: bool) -> Result<()> {
+    let work_dir = Path::new(".work");
+    let stage_type = load_stage(&stage_id, work_dir)?.stage_type;
+
+    let outcome = cancel_stage(&stage_id, cascade, work_dir)?;
+    if let Some(status) = &outcome.previous_status {
+        println!("Stage '{stage_id}' cancelled (was: {status})");
+    }
+
+    if let Some(session_id) = &outcome.session {
+        kill_stage_session(work_dir, session_id);
+    }
+
+    if delete_worktree && stage_type != StageType::Knowledge {
+        let repo_root = std::env::current_dir()?;
+        let config = CleanupConfig {
+            force_worktree_removal: true,
+            force_branch_deletion: true, // Cancelled work is never merged
+            prune_worktrees: true,
+            verbose: false,
+        };
+        let result = cleanup_after_merge(&stage_id, &repo_root, &config)?;
+        if result.worktree_removed {
+            println!("  Removed worktree: .worktrees/{stage_id}");
+        }
+        if result.branch_deleted {
+            println!(
+                "  Deleted branch: {}",
+                crate::git::branch::branch_name_for_stage(&stage_id)
+            );
+        }
+        for warning in &result.warnings {
+            eprintln!("  Warning: {warning}");
+        }
+    }
+
+    if !outcome.cancelled_dependents.is_empty() {
+        println!(
+            "Also cancelled dependents: {}",
+            outcome.cancelled_dependents.join(", ")
+        );
+    } else if !outcome.dependents.is_empty() {
+        println!(
+            "{} Dependents can no longer run: {}",
+            "Note:".yellow(),
+            outcome.dependents.join(", ")
+        );
+        println!("  Cancel them too with --cascade, or re-plan them with a new dependency.");
+    }
+
+    Ok(())
+}
@@ -8,20 +8,24 @@ use anyhow::{bail, Context, Result};
 use std::path::Path;
 
 use crate::models::stage::{StageStatus, StageType};
-use crate::verify::criteria::run_acceptance;
+use crate::verify::criteria::{run_acceptance, AcceptanceResult};
 use crate::verify::transitions::{load_stage, update_stage};
 
-use super::acceptance_runner::resolve_stage_execution_paths;
+use super::acceptance_runner::{
+    acceptance_failure_info, category_summary_line, record_flaky_stats,
+    resolve_stage_execution_paths,
+};
 
 /// Run acceptance criteria for a stage and display detailed results.
 ///
 /// This command:
 /// 1. Loads the stage and validates its status (Executing or CompletedWithFailures)
 /// 2. Runs all acceptance criteria
-/// 3. Prints full stdout/stderr for each criterion
+/// 3. Prints full stdout/stderr for each criterion (or, with `json`, one
+///    JSON object with every result and the per-category counts)
 /// 4. Increments fix_attempts if any failed
 /// 5. Does NOT change stage status
-pub fn check_acceptance(stage_id: String) -> Result<()> {
+pub fn check_acceptance(stage_id: String, json: bool) -> Result<()> {
     let work_dir = Path::new(".work");
     let mut stage = load_stage(&stage_id, work_dir)?;
 
@@ -37,7 +41,15 @@ pub fn check_acceptance(stage_id: String) -> Result<()> {
     }
 
     if stage.acceptance.is_empty() {
-        println!("No acceptance criteria defined for stage '{stage_id}'.");
+        if json {
+            print_json(
+                &stage_id,
+                &AcceptanceResult::AllPassed { results: vec![] },
+                stage.fix_attempts,
+            )?;
+        } else {
+            println!("No acceptance criteria defined for stage '{stage_id}'.");
+        }
         return Ok(());
     }
 
@@ -49,24 +61,80 @@ pub fn check_acceptance(stage_id: String) -> Result<()> {
         bail!("Worktree not found for stage '{stage_id}'. Cannot run acceptance criteria.");
     }
 
-    println!("Checking acceptance criteria for stage '{stage_id}'...");
-    if let Some(ref dir) = acceptance_dir {
-        println!("  (working directory: {})", dir.display());
+    if !json {
+        println!("Checking acceptance criteria for stage '{stage_id}'...");
+        if let Some(ref dir) = acceptance_dir {
+            println!("  (working directory: {})", dir.display());
+        }
+        println!();
     }
-    println!();
 
-    // Run acceptance criteria
-    let result = run_acceptance(&stage, acceptance_dir.as_deref())
+    // Run acceptance criteria with dependency output references resolved
+    let resolved = crate::verify::dependency_outputs::resolved_stage(&stage, work_dir)?;
+    let result = run_acceptance(&resolved, acceptance_dir.as_deref())
         .context("Failed to run acceptance criteria")?;
+    record_flaky_stats(work_dir, &resolved, &result);
+
+    if !result.all_passed() {
+        // Re-read under the stages-dir lock and increment fix_attempts from the
+        // FRESH on-disk value (this command runs acceptance, which can take
+        // minutes; the in-memory stage is stale). Only fix_attempts and
+        // failure_info are owned here — status is deliberately left
+        // unchanged (A-5).
+        let failure_info = acceptance_failure_info(&result);
+        let updated = update_stage(&stage_id, work_dir, |s| {
+            s.fix_attempts += 1;
+            s.failure_info = failure_info;
+            Ok(())
+        })?;
+        stage.fix_attempts = updated.fix_attempts;
+    }
 
-    // Print detailed results for each criterion
+    let total = result.results().len();
+    if json {
+        print_json(&stage_id, &result, stage.fix_attempts)?;
+    } else {
+        print_results(&result);
+    }
+
+    if !result.all_passed() {
+        if !json {
+            let max = stage.get_effective_max_fix_attempts();
+            println!("Fix attempts: {}/{max}", stage.fix_attempts);
+
+            if stage.fix_attempts >= max {
+                println!();
+                println!("Warning: Fix attempt limit reached ({max}/{max}).");
+                println!(
+                    "  If the acceptance criteria are incorrect, use \
+                     'loom stage dispute-criteria {stage_id}' to challenge them."
+                );
+            } else {
+                println!("Hint: Fix the issues and run 'loom stage verify {stage_id}' again.");
+            }
+        }
+
+        let failed = result.failed_count();
+        bail!("Acceptance check failed for stage '{stage_id}': {failed}/{total} criteria failed");
+    }
+
+    Ok(())
+}
+
+/// Print detailed results for each criterion, then the summary.
+fn print_results(result: &AcceptanceResult) {
     let total = result.results().len();
     for (i, cr) in result.results().iter().enumerate() {
         let num = i + 1;
-        println!("Criterion {num}: {}", cr.command);
+        println!("Criterion {num}: {}", cr.label());
+        if cr.name.is_some() {
+            println!("Command: {}", cr.command);
+        }
 
         if cr.timed_out {
             println!("Result: TIMEOUT");
+        } else if cr.success && cr.attempts > 1 {
+            println!("Result: PASSED after {} attempts (flaky)", cr.attempts);
         } else if cr.success {
             println!("Result: PASSED");
         } else {
@@ -102,36 +170,24 @@ pub fn check_acceptance(stage_id: String) -> Result<()> {
     println!();
     let passed = result.passed_count();
     println!("Summary: {passed}/{total} passed");
-
-    if !result.all_passed() {
-        // Re-read under the stages-dir lock and increment fix_attempts from the
-        // FRESH on-disk value (this command runs acceptance, which can take
-        // minutes; the in-memory stage is stale). Only fix_attempts is owned
-        // here — status is deliberately left unchanged (A-5).
-        let updated = update_stage(&stage_id, work_dir, |s| {
-            s.fix_attempts += 1;
-            Ok(())
-        })?;
-        stage.fix_attempts = updated.fix_attempts;
-
-        let max = stage.get_effective_max_fix_attempts();
-        println!("Fix attempts: {}/{max}", stage.fix_attempts);
-
-        if stage.fix_attempts >= max {
-            println!();
-            println!("Warning: Fix attempt limit reached ({max}/{max}).");
-            println!(
-                "  If the acceptance criteria are incorrect, use \
-                 'loom stage dispute-criteria {stage_id}' to challenge them."
-            );
-        } else {
-            println!("Hint: Fix the issues and run 'loom stage verify {stage_id}' again.");
-        }
-
-        let failed = result.failed_count();
-        bail!("Acceptance check failed for stage '{stage_id}': {failed}/{total} criteria failed");
+    if let Some(line) = category_summary_line(result) {
+        println!("{line}");
     }
+}
 
+/// Print the results as one JSON object.
+fn print_json(stage_id: &str, result: &AcceptanceResult, fix_attempts: u32) -> Result<()> {
+    let output = serde_json::json!({
+        "stage_id": stage_id,
+        "passed": result.all_passed(),
+        "passed_count": result.passed_count(),
+        "total": result.results().len(),
+        "fix_attempts": fix_attempts,
+        "categories": result.category_summaries(),
+        "criteria": result.results(),
+        "failures": result.failures(),
+    });
+    println!("{}", serde_json::to_string_pretty(&output)?);
     Ok(())
 }
 
@@ -168,7 +224,7 @@ mod tests {
         let original_dir = std::env::current_dir().unwrap();
         std::env::set_current_dir(temp_dir.path()).unwrap();
 
-        let result = check_acceptance("test-stage".to_string());
+        let result = check_acceptance("test-stage".to_string(), false);
 
         std::env::set_current_dir(original_dir).unwrap();
 
@@ -191,7 +247,7 @@ mod tests {
         let original_dir = std::env::current_dir().unwrap();
         std::env::set_current_dir(temp_dir.path()).unwrap();
 
-        let result = check_acceptance("test-stage".to_string());
+        let result = check_acceptance("test-stage".to_string(), false);
 
         std::env::set_current_dir(original_dir).unwrap();
 
@@ -214,7 +270,7 @@ mod tests {
         let original_dir = std::env::current_dir().unwrap();
         std::env::set_current_dir(temp_dir.path()).unwrap();
 
-        let result = check_acceptance("test-stage".to_string());
+        let result = check_acceptance("test-stage".to_string(), false);
 
         std::env::set_current_dir(original_dir).unwrap();
 
@@ -248,7 +304,7 @@ mod tests {
         let original_dir = std::env::current_dir().unwrap();
         std::env::set_current_dir(temp_dir.path()).unwrap();
 
-        let result = check_acceptance("test-stage".to_string());
+        let result = check_acceptance("test-stage".to_string(), false);
 
         std::env::set_current_dir(original_dir).unwrap();
 
@@ -273,7 +329,7 @@ mod tests {
         let original_dir = std::env::current_dir().unwrap();
         std::env::set_current_dir(temp_dir.path()).unwrap();
 
-        let result = check_acceptance("test-stage".to_string());
+        let result = check_acceptance("test-stage".to_string(), false);
 
         std::env::set_current_dir(original_dir).unwrap();
 
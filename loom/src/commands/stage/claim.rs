@@ -0,0 +1,157 @@
+//! Claim a ready stage for human-driven execution
+//! Usage: loom stage claim <id>
+//!
+//! The stage goes through the same Queued -> Executing transition the daemon
+//! performs, but instead of spawning an agent loom records a `Human` session.
+//! The daemon treats that session as live until the stage is completed (via
+//! `loom stage complete`, which also runs the progressive merge) or the
+//! session is killed, so graph scheduling of dependents works exactly as for
+//! agent-run stages.
+//!
+//! The claiming operator (`--as`, else `$LOOM_OPERATOR`, else `$USER`) is
+//! recorded as the stage's assignee, and a stage assigned to someone else
+//! cannot be claimed, so several people supervising one run do not pick up
+//! the same stage.
+
+use anyhow::{bail, Context, Result};
+use chrono::Utc;
+use std::path::Path;
+
+use crate::fs::{parse_base_branch_from_config, save_session};
+use crate::git::branch::resolve_target_branch;
+use crate::git::worktree::find_repo_root_from_cwd;
+use crate::models::session::Session;
+use crate::models::stage::{StageStatus, StageType};
+use crate::orchestrator::signals::{generate_signal, load_dependency_status};
+use crate::validation::validate_assignee;
+use crate::verify::transitions::{load_stage, update_stage};
+
+/// The person running loom: `$LOOM_OPERATOR`, else `$USER`.
+fn current_operator() -> Option<String> {
+    ["LOOM_OPERATOR", "USER"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|name| validate_assignee(name).is_ok())
+}
+
+/// Refuse a claim by `operator` of a stage assigned to someone else.
+fn check_assignee(stage_id: &str, assignee: Option<&str>, operator: Option<&str>) -> Result<()> {
+    match (assignee, operator) {
+        (Some(assignee), Some(operator)) if assignee != operator => bail!(
+            "Stage '{stage_id}' is assigned to {assignee}. Claim it with \
+             `--as {assignee}`, or reassign it first with `loom stage assign {stage_id} <person>`."
+        ),
+        _ => Ok(()),
+    }
+}
+
+/// Claim a queued stage: create its worktree, write its signal, and mark it
+/// Executing under a human session assigned to the claiming operator.
+pub fn claim(stage_id: String, operator: Option<String>) -> Result<()> {
+    let work_dir = Path::new(".work");
+    let stage = load_stage(&stage_id, work_dir)?;
+    let operator = operator.or_else(current_operator);
+    check_assignee(&stage_id, stage.assignee.as_deref(), operator.as_deref())?;
+
+    if stage.stage_type == StageType::Knowledge {
+        bail!(
+            "Stage '{stage_id}' is a knowledge stage; knowledge stages run in the main \
+             repository and cannot be claimed."
+        );
+    }
+    if stage.status != StageStatus::Queued {
+        bail!(
+            "Cannot claim stage in status: {}. Only queued (ready) stages can be claimed.",
+            stage.status
+        );
+    }
+
+    let cwd = std::env::current_dir().context("Failed to get current directory")?;
+    let repo_root = find_repo_root_from_cwd(&cwd)
+        .ok_or_else(|| anyhow::anyhow!("Not inside a git repository"))?;
+
+    // A queued stage has all dependencies completed and merged, so the
+    // merge point already contains their work.
+    let base_branch = resolve_target_branch(&parse_base_branch_from_config(work_dir)?, &repo_root);
+    let sparse = crate::git::worktree::sparse_profile(&stage, work_dir);
+    let worktree_created = !crate::git::worktree::get_worktree_path(&stage_id, &repo_root).exists();
+    let worktree = crate::git::vcs::for_repo(&repo_root)
+        .get_or_create_workspace(&stage_id, &repo_root, Some(&base_branch), sparse.as_deref())
+        .with_context(|| format!("Failed to create worktree for stage '{stage_id}'"))?;
+
+    if !stage.worktree_setup.is_empty()
+        && crate::verify::worktree_setup_pending(work_dir, &stage_id, worktree_created)?
+    {
+        println!("Running worktree setup for '{stage_id}'...");
+        let log_path = crate::verify::worktree_setup_log_path(work_dir, &stage_id);
+        if let Some(failure) =
+            crate::verify::run_worktree_setup(&stage.worktree_setup, &worktree.path, &log_path)?
+        {
+            bail!("{}", failure.describe());
+        }
+        crate::verify::mark_worktree_setup_done(work_dir, &stage_id)?;
+    }
+
+    let deps = load_dependency_status(&stage, work_dir);
+
+    let mut session = Session::new_human(&stage_id);
+    session.set_worktree_path(worktree.path.clone());
+    let signal_path = generate_signal(&session, &stage, &worktree, &deps, None, None, work_dir)
+        .context("Failed to generate signal file")?;
+
+    // Re-check the status under the stage lock so a daemon that picked the
+    // stage up in the meantime wins and the claim is refused.
+    update_stage(&stage_id, work_dir, |stage| {
+        if stage.status != StageStatus::Queued {
+            bail!(
+                "Stage '{stage_id}' changed to {} while claiming; not claimed",
+                stage.status
+            );
+        }
+        check_assignee(&stage_id, stage.assignee.as_deref(), operator.as_deref())?;
+        stage.try_mark_executing()?;
+        stage.begin_attempt(Utc::now());
+        if stage.assignee.is_none() {
+            stage.assignee = operator.clone();
+        }
+        stage.assign_session(session.id.clone());
+        stage.set_worktree(Some(worktree.id.clone()));
+        stage.set_resolved_base(Some(base_branch.clone()));
+        Ok(())
+    })?;
+    save_session(&session, work_dir)?;
+
+    match &operator {
+        Some(operator) => println!(
+            "Stage '{stage_id}' claimed by {operator} (session {}).",
+            session.id
+        ),
+        None => println!("Stage '{stage_id}' claimed (session {}).", session.id),
+    }
+    println!("  Worktree: {}", worktree.path.display());
+    println!("  Signal: {}", signal_path.display());
+    println!();
+    println!(
+        "Work in the worktree, then run `loom stage complete {stage_id}` from it to verify and merge."
+    );
+    println!(
+        "To release the claim: loom sessions kill {} && loom stage reset {stage_id}",
+        session.id
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_assignee() {
+        assert!(check_assignee("api", None, Some("alice")).is_ok());
+        assert!(check_assignee("api", Some("alice"), Some("alice")).is_ok());
+        assert!(check_assignee("api", Some("alice"), None).is_ok());
+        let err = check_assignee("api", Some("alice"), Some("bob")).unwrap_err();
+        assert!(err.to_string().contains("assigned to alice"), "{err}");
+    }
+}
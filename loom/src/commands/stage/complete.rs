@@ -10,12 +10,15 @@ use crate::fs::work_dir::load_config;
 use crate::git::merge::{
     detect_in_progress_merge_at_worktree, ActiveMergeState, InProgressMerge, MergeLocation,
 };
-use crate::git::worktree::find_repo_root_from_cwd;
+use crate::git::worktree::{find_repo_root_from_cwd, get_worktree_path};
 use crate::models::session::Session;
 use crate::models::stage::{Stage, StageStatus, StageType};
+use crate::orchestrator::code_review;
 use crate::orchestrator::merge_attribution::{attribute_main_repo_merge, MergeAttribution};
+use crate::orchestrator::pair;
 use crate::plan::parser::{load_stage_definition_from_plan, parse_plan, ParsedPlan};
 use crate::plan::schema::{ChangeImpactConfig, ChangeImpactPolicy};
+use crate::say;
 use crate::verify::baseline::compare_to_baseline;
 use crate::verify::duplicate_detection::detect_duplicate_symbols;
 use crate::verify::transitions::{list_all_stages, load_stage, trigger_dependents, update_stage};
@@ -27,7 +30,7 @@ use super::acceptance_runner::{
 use super::knowledge_complete::complete_knowledge_stage;
 use super::merge_resolver::{spawn_merge_resolver, MergeResolverResult};
 use super::merge_verify::verify_or_derive_completed_commit;
-use super::progressive_complete::complete_with_merge;
+use super::progressive_complete::{complete_with_merge, push_branch_if_enabled};
 use super::session::cleanup_session_resources;
 
 /// Verify the admin token is readable before allowing verification-bypass
@@ -139,7 +142,7 @@ pub fn route_complete_for_conflicts(
     }
 
     // Rule 2: Worktree active merge — refuse with location, never spawn.
-    let worktree = repo_root.join(".worktrees").join(&stage.id);
+    let worktree = get_worktree_path(&stage.id, repo_root);
     if worktree.exists() {
         if let Ok(Some(merge)) = detect_in_progress_merge_at_worktree(&worktree) {
             let location = match &merge.location {
@@ -311,16 +314,16 @@ fn spawn_resolver_for_route(
         work_dir,
     )? {
         MergeResolverResult::DaemonManaged => {
-            println!(
+            say!(
                 "Daemon is handling merge resolution for stage '{}'.",
                 stage.id
             );
         }
         MergeResolverResult::Spawned(id) => {
-            println!("Spawned merge resolver session: {id}");
+            say!("Spawned merge resolver session: {id}");
         }
         MergeResolverResult::AlreadyRunning { session_id } => {
-            println!(
+            say!(
                 "A merge resolver session is already running for stage '{}': {session_id}. \
                  Wait for it to complete, or run `loom sessions kill {session_id}` to abort.",
                 stage.id
@@ -399,7 +402,7 @@ pub fn complete(
         CompleteConflictRoute::DaemonManaged {
             stage_id: managed_id,
         } => {
-            println!(
+            say!(
                 "Daemon is handling merge resolution for stage '{managed_id}'. \
                  Run `loom status` to monitor."
             );
@@ -472,12 +475,26 @@ pub fn complete(
     let working_dir: Option<PathBuf> = execution_paths.worktree_root;
     let acceptance_dir: Option<PathBuf> = execution_paths.acceptance_dir;
 
+    // Pair mode: the reviewer must have approved the work as it is now
+    if !no_verify {
+        let head = working_dir.as_deref().and_then(pair::worktree_head);
+        if let Some(reason) = pair::pair_gate(&stage, head.as_deref()).reason(&stage_id) {
+            eprintln!("  Wait for the reviewer to approve the latest commit");
+            bail!("{reason}");
+        }
+    }
+
     // Sync worktree permissions before running acceptance criteria
     sync_worktree_permissions(&working_dir, &acceptance_dir);
 
     // Run acceptance criteria phase
-    let acceptance_result =
-        run_acceptance_phase(&stage, &stage_id, no_verify, acceptance_dir.as_deref())?;
+    let acceptance_result = run_acceptance_phase(
+        &stage,
+        &stage_id,
+        no_verify,
+        acceptance_dir.as_deref(),
+        work_dir,
+    )?;
 
     // Handle acceptance failure - keep stage in Executing, agent can fix and retry
     // Do NOT transition state - stage stays Executing so agent can fix and re-run
@@ -502,6 +519,31 @@ pub fn complete(
     Ok(())
 }
 
+/// Complete a code review stage. Its branch carries no work, so it is marked
+/// merged right away; the daemon then merges or blocks the reviewed stage.
+fn complete_code_review(stage_id: &str, work_dir: &Path) -> Result<()> {
+    let review = update_stage(stage_id, work_dir, |s| {
+        s.merged = true;
+        s.try_complete(None)
+    })?;
+    let approved = review
+        .outputs
+        .iter()
+        .any(|o| o.key == code_review::APPROVED_OUTPUT && o.value.as_bool() == Some(true));
+    let verdict = if approved { "approved" } else { "rejected" };
+    say!("Code review '{}' completed: {verdict}.", review.id);
+    if let Some(reviewed) = &review.parent_stage {
+        if approved {
+            say!(
+                "The orchestrator will merge '{reviewed}' (or run `loom stage merge {reviewed}`)."
+            );
+        } else {
+            say!("The orchestrator will block '{reviewed}' for a retry.");
+        }
+    }
+    Ok(())
+}
+
 /// Best-effort load of all sessions for the router. Routing must not fail on
 /// transient FS errors — fall back to an empty list (attribution then uses
 /// commit-based matching).
@@ -564,9 +606,10 @@ fn handle_force_unsafe_completion(
         );
     }
 
-    println!(
+    say!(
         "Force-completing stage '{}' (was: {:?})",
-        stage_id, stage.status
+        stage_id,
+        stage.status
     );
 
     // Forced status assignment: --force-unsafe is an explicit administrative
@@ -580,7 +623,7 @@ fn handle_force_unsafe_completion(
     // Only set merged=true if explicitly requested via --assume-merged
     if assume_merged {
         stage.merged = true;
-        println!("  → Stage marked as merged (manual merge assumed)");
+        say!("  → Stage marked as merged (manual merge assumed)");
     } else {
         stage.merged = false;
         eprintln!();
@@ -606,7 +649,7 @@ fn handle_force_unsafe_completion(
         s.completed_commit = forced_commit.clone();
         Ok(())
     })?;
-    println!("Stage '{stage_id}' force-completed!");
+    say!("Stage '{stage_id}' force-completed!");
 
     // Only trigger dependent stages if merged=true (i.e., --assume-merged was used)
     if stage.merged {
@@ -619,9 +662,9 @@ fn handle_force_unsafe_completion(
             .context("Failed to trigger dependent stages")?;
 
         if !triggered.is_empty() {
-            println!("Triggered {} dependent stage(s):", triggered.len());
+            say!("Triggered {} dependent stage(s):", triggered.len());
             for dep_id in &triggered {
-                println!("  → {dep_id}");
+                say!("  → {dep_id}");
             }
         }
     }
@@ -657,7 +700,7 @@ fn sync_worktree_permissions(working_dir: &Option<PathBuf>, acceptance_dir: &Opt
                                 }
                             ));
                         }
-                        println!("{}", msg);
+                        say!("{}", msg);
                     }
                 }
                 Err(e) => {
@@ -750,7 +793,7 @@ fn run_aggregated_wiring_reverification(
             // stage's working_dir, NOT the integration-verify stage's
             // working_dir — resolve against `worktree_root + working_dir`.
             if !stage_def.wiring.is_empty() {
-                println!("  Re-verifying wiring from stage '{}'...", stage.id);
+                say!("  Re-verifying wiring from stage '{}'...", stage.id);
                 let stage_working_dir = if stage_def.working_dir == "." {
                     worktree_root.to_path_buf()
                 } else {
@@ -771,7 +814,7 @@ fn run_aggregated_wiring_reverification(
     }
 
     if all_gaps.is_empty() {
-        println!("Aggregated wiring re-verification passed!");
+        say!("Aggregated wiring re-verification passed!");
     } else {
         eprintln!();
         eprintln!(
@@ -793,14 +836,17 @@ fn run_acceptance_phase(
     stage_id: &str,
     no_verify: bool,
     acceptance_dir: Option<&Path>,
+    work_dir: &Path,
 ) -> Result<Option<bool>> {
     // Track whether acceptance criteria passed (None = skipped via --no-verify)
     let acceptance_result: Option<bool> = if no_verify {
         // --no-verify means we skip criteria entirely (deliberate skip)
         None
     } else {
+        // Criteria may reference dependency outputs ({{deps.<stage>.<key>}})
+        let stage = crate::verify::dependency_outputs::resolved_stage(stage, work_dir)?;
         Some(run_acceptance_with_display(
-            stage,
+            &stage,
             stage_id,
             acceptance_dir,
             AcceptanceDisplayOptions {
@@ -835,7 +881,7 @@ fn run_verification_phase(
         // Run goal-backward verification (artifacts, wiring, wiring_tests, dead_code)
         if let Some(ref stage_def) = stage_def {
             if stage_def.has_any_goal_checks() {
-                println!("Running goal-backward verification...");
+                say!("Running goal-backward verification...");
                 let verification_dir = acceptance_dir.as_deref().unwrap_or(Path::new("."));
 
                 // Use shared helper for verification
@@ -857,17 +903,22 @@ fn run_verification_phase(
                     eprintln!("  Fix the issues and run 'loom stage complete {stage_id}' again");
                     anyhow::bail!("Goal-backward verification failed for stage '{stage_id}'");
                 }
-                println!("Goal-backward verification passed!");
+                say!("Goal-backward verification passed!");
             }
         }
 
         // Run after-stage verification (post-condition checks)
         if let Some(ref stage_def) = stage_def {
             if !stage_def.after_stage.is_empty() {
-                println!("Running after-stage verification...");
+                say!("Running after-stage verification...");
                 let verification_dir = acceptance_dir.as_deref().unwrap_or(Path::new("."));
-                let after_gaps = crate::verify::before_after::run_after_stage_checks(
+                let after_stage = crate::verify::dependency_outputs::resolved_checks(
+                    stage,
                     &stage_def.after_stage,
+                    work_dir,
+                )?;
+                let after_gaps = crate::verify::before_after::run_after_stage_checks(
+                    &after_stage,
                     verification_dir,
                 )?;
 
@@ -882,8 +933,22 @@ fn run_verification_phase(
                     eprintln!("  Fix the issues and run 'loom stage complete {stage_id}' again");
                     anyhow::bail!("After-stage verification failed for stage '{stage_id}'");
                 }
-                println!("After-stage verification passed!");
+                say!("After-stage verification passed!");
+            }
+        }
+
+        // Output schema: required outputs set with their declared types
+        let violations = stage.output_schema_violations();
+        if !violations.is_empty() {
+            for violation in &violations {
+                eprintln!("  ✗ Output: {violation}");
             }
+            eprintln!("    → Set outputs with 'loom stage output set {stage_id} <key> <value>'");
+
+            eprintln!();
+            eprintln!("Output schema verification FAILED for stage '{stage_id}'");
+            eprintln!("  Fix the issues and run 'loom stage complete {stage_id}' again");
+            anyhow::bail!("Output schema verification failed for stage '{stage_id}'");
         }
 
         // Unwired file detection (2a + 2b)
@@ -931,7 +996,7 @@ fn run_verification_phase(
                                      Record memory notes explaining the wiring plan."
                                 );
                             }
-                            println!("Unwired files found but memory notes cover downstream wiring plan.");
+                            say!("Unwired files found but memory notes cover downstream wiring plan.");
                         } else {
                             // No downstream dependents = leaf stage = ERROR (blocking)
                             eprintln!(
@@ -991,7 +1056,7 @@ fn run_verification_phase(
         // Aggregated wiring re-verification for integration-verify stages (3d)
         if stage.stage_type == StageType::IntegrationVerify {
             if let Some(ref root) = *worktree_root {
-                println!("Running aggregated wiring re-verification...");
+                say!("Running aggregated wiring re-verification...");
                 run_aggregated_wiring_reverification(stage_id, root, work_dir)?;
             }
         }
@@ -999,7 +1064,7 @@ fn run_verification_phase(
         // Run change impact comparison if configured
         if let Some(change_impact_config) = load_change_impact_config(work_dir)? {
             if change_impact_config.policy != ChangeImpactPolicy::Skip {
-                println!("Running change impact comparison...");
+                say!("Running change impact comparison...");
                 let comparison_dir = acceptance_dir.as_deref();
 
                 match compare_to_baseline(stage_id, &change_impact_config, comparison_dir, work_dir)
@@ -1011,7 +1076,7 @@ fn run_verification_phase(
                             );
                         } else {
                             // Print summary
-                            println!("  {}", impact.summary());
+                            say!("  {}", impact.summary());
 
                             // Print details if there are new failures
                             if impact.has_new_failures() {
@@ -1063,6 +1128,19 @@ fn run_verification_phase(
         let cwd = std::env::current_dir().context("Failed to get current directory")?;
         let repo_root = find_repo_root_from_cwd(&cwd).unwrap_or_else(|| cwd.clone());
 
+        // Code reviews merge nothing; with `auto_code_review`, the stage's
+        // own merge waits on one.
+        if stage.stage_type == StageType::CodeReview {
+            return complete_code_review(stage_id, work_dir);
+        }
+        push_branch_if_enabled(stage_id, &repo_root, work_dir);
+        if code_review::review_required(work_dir, stage) {
+            let completed = update_stage(stage_id, work_dir, |s| s.try_complete(None))?;
+            let review_id = code_review::request_review(work_dir, &completed)?;
+            say!("Stage '{stage_id}' completed; its merge waits on code review '{review_id}'.");
+            return Ok(());
+        }
+
         complete_with_merge(stage, &repo_root, work_dir)?;
     } else {
         // --no-verify: Skip verifications, just mark as completed.
@@ -1111,14 +1189,18 @@ fn run_verification_phase(
             }
         }
 
+        if branch_exists {
+            push_branch_if_enabled(stage_id, &repo_root, work_dir);
+        }
+
         // The orchestrator daemon will auto-merge and trigger dependents.
         // Re-apply only the Completed transition onto the FRESH on-disk stage so
         // a concurrent daemon/dispute write is not reverted (A-5). merged stays
         // whatever it was on disk (normally false here; daemon auto-merges).
         stage.try_complete(None)?;
         update_stage(stage_id, work_dir, |s| s.try_complete(None))?;
-        println!("Stage '{stage_id}' completed (skipped verification).");
-        println!("The orchestrator will handle merge and dependent triggering.");
+        say!("Stage '{stage_id}' completed (skipped verification).");
+        say!("The orchestrator will handle merge and dependent triggering.");
     }
 
     Ok(())
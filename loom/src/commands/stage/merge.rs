@@ -9,9 +9,16 @@ use std::path::Path;
 
 use crate::commands::common::detect_stage_id;
 use crate::git::branch::{branch_name_for_stage, resolve_target_branch};
+use crate::git::merge::changelog::record_merge;
+use crate::git::merge::lock::MergeLock;
 use crate::git::merge::merge_head_exists;
-use crate::git::{get_conflicting_files, merge_stage, MergeResult};
+use crate::git::merge::scope::{clear_merge_scope, out_of_scope_changes};
+use crate::git::merge::stats::record_resolution;
+use crate::git::worktree::find_linked_worktree;
+use crate::git::{vcs, MergeResult};
+use crate::handoff::record_forward_handoff;
 use crate::models::stage::StageStatus;
+use crate::say;
 use crate::verify::transitions::{load_stage, trigger_dependents, update_stage};
 
 /// Unified merge command entry point.
@@ -63,7 +70,10 @@ fn merge_resolved(stage_id: Option<String>) -> Result<()> {
 
     // Check git status for unmerged files
     let repo_root = std::env::current_dir().context("Failed to get current directory")?;
-    if !get_conflicting_files(&repo_root)?.is_empty() {
+    if !vcs::for_repo(&repo_root)
+        .conflicting_files(&repo_root)?
+        .is_empty()
+    {
         bail!(
             "There are still unmerged files in the repository. \
              Please resolve all conflicts before running this command.\n\
@@ -93,6 +103,23 @@ fn merge_resolved(stage_id: Option<String>) -> Result<()> {
         &repo_root,
     )?;
 
+    // A sandboxed resolution may only change the conflicting files and tests.
+    let out_of_scope = out_of_scope_changes(
+        work_dir,
+        &stage_id,
+        &verified.commit,
+        &target_branch,
+        &repo_root,
+    )?;
+    if !out_of_scope.is_empty() {
+        bail!(
+            "The merge commit for stage '{stage_id}' changes files outside the conflict \
+             resolution scope:\n  {}\n\
+             Amend the merge commit so it changes only the conflicted files and tests.",
+            out_of_scope.join("\n  ")
+        );
+    }
+
     // Apply the derived commit (if any) and the merge-completion transition in a
     // single locked read-modify-write on the FRESH on-disk stage (A-5). The
     // ancestry verification above already established that `completed_commit` is
@@ -108,8 +135,20 @@ fn merge_resolved(stage_id: Option<String>) -> Result<()> {
         s.try_complete_merge()
     })?;
 
+    if let Err(e) = clear_merge_scope(work_dir, &stage_id) {
+        eprintln!("Warning: Failed to clear merge scope: {e}");
+    }
+    if let Err(e) = record_resolution(work_dir, &stage_id) {
+        eprintln!("Warning: Failed to record merge resolution: {e}");
+    }
+    let entry = MergeLock::acquire_for(work_dir, &format!("changelog entry for {stage_id}"))
+        .and_then(|_lock| record_merge(&stage_id, &target_branch, &repo_root, work_dir));
+    if let Err(e) = entry {
+        eprintln!("Warning: failed to add the changelog entry for '{stage_id}': {e:#}");
+    }
+
     println!("Stage '{stage_id}' merge conflict resolution complete!");
-    println!("  Status: Completed (merged: true)");
+    say!("  Status: Completed (merged: true)");
 
     // Trigger dependent stages
     let base_branch = crate::fs::parse_base_branch_from_config(work_dir).unwrap_or(None);
@@ -118,16 +157,16 @@ fn merge_resolved(stage_id: Option<String>) -> Result<()> {
         .context("Failed to trigger dependent stages")?;
 
     if !triggered.is_empty() {
-        println!("Triggered {} dependent stage(s):", triggered.len());
+        say!("Triggered {} dependent stage(s):", triggered.len());
         for dep_id in &triggered {
-            println!("  -> {dep_id}");
+            say!("  -> {dep_id}");
         }
     }
 
     // Suggest cleanup
-    println!();
-    println!("Consider cleaning up the worktree:");
-    println!("  loom worktree remove {stage_id}");
+    say!();
+    say!("Consider cleaning up the worktree:");
+    say!("  loom worktree remove {stage_id}");
 
     Ok(())
 }
@@ -179,10 +218,12 @@ fn merge_retry(stage_id: Option<String>) -> Result<()> {
         );
     }
 
-    // Verify we're in a worktree (cwd should contain .worktrees in its path)
+    // Verify we're in a worktree (cwd should contain .worktrees in its path,
+    // or sit in a worktree under the configured root)
     let cwd = std::env::current_dir().context("Failed to get current directory")?;
     let cwd_str = cwd.to_string_lossy();
-    if !cwd_str.contains(".worktrees") {
+    let linked = find_linked_worktree(&cwd);
+    if !cwd_str.contains(".worktrees") && linked.is_none() {
         bail!(
             "merge must be run from within a worktree.\n\
              \n\
@@ -195,7 +236,10 @@ fn merge_retry(stage_id: Option<String>) -> Result<()> {
     }
 
     // Find repo root (parent of .worktrees)
-    let repo_root = find_repo_root(&cwd)?;
+    let repo_root = match linked {
+        Some((_, repo_root)) => repo_root,
+        None => find_repo_root(&cwd)?,
+    };
 
     // Resolve worktree root from cwd. `git rev-parse --show-toplevel` returns
     // the top of the working tree, which for a worktree is its root.
@@ -249,17 +293,20 @@ fn merge_retry(stage_id: Option<String>) -> Result<()> {
     .fix_attempts;
     stage.fix_attempts = attempts;
 
-    println!("Retrying merge for stage '{stage_id}' (attempt {attempts}/{max_attempts})");
+    say!("Retrying merge for stage '{stage_id}' (attempt {attempts}/{max_attempts})");
 
     // Determine target branch, respecting configured base_branch over repo default
     let base_branch = crate::fs::parse_base_branch_from_config(work_dir).unwrap_or(None);
     let target_branch = resolve_target_branch(&base_branch, &repo_root);
 
     let branch_name = branch_name_for_stage(&stage_id);
-    println!("Merging {branch_name} into {target_branch}...");
+    say!("Merging {branch_name} into {target_branch}...");
+
+    record_forward_handoff(&stage, &repo_root, &target_branch, work_dir);
 
     // Attempt the merge
-    let merge_result = merge_stage(&stage_id, &target_branch, &repo_root, work_dir);
+    let merge_result =
+        vcs::for_repo(&repo_root).merge_stage(&stage_id, &target_branch, &repo_root, work_dir);
 
     match merge_result {
         Ok(MergeResult::Success {
@@ -267,8 +314,8 @@ fn merge_retry(stage_id: Option<String>) -> Result<()> {
             insertions,
             deletions,
         }) => {
-            println!("Merge successful!");
-            println!("  {files_changed} files changed, +{insertions} -{deletions}");
+            say!("Merge successful!");
+            say!("  {files_changed} files changed, +{insertions} -{deletions}");
 
             // Clear merge conflict flag and mark as completed+merged on the fresh
             // on-disk stage (A-5). The real merge above already landed the commit
@@ -279,63 +326,63 @@ fn merge_retry(stage_id: Option<String>) -> Result<()> {
                 s.try_complete_merge()
             })?;
 
-            println!();
-            println!("Stage '{stage_id}' merge complete! (Completed, merged: true)");
+            say!();
+            say!("Stage '{stage_id}' merge complete! (Completed, merged: true)");
 
             // Trigger dependent stages
             let triggered = trigger_dependents(&stage_id, work_dir, &repo_root, &target_branch)
                 .context("Failed to trigger dependent stages")?;
             if !triggered.is_empty() {
-                println!("Triggered {} dependent stage(s):", triggered.len());
+                say!("Triggered {} dependent stage(s):", triggered.len());
                 for dep_id in &triggered {
-                    println!("  -> {dep_id}");
+                    say!("  -> {dep_id}");
                 }
             }
 
-            println!();
-            println!("Next: run 'loom stage complete {stage_id}' if not already done,");
-            println!("or clean up the worktree: loom worktree remove {stage_id}");
+            say!();
+            say!("Next: run 'loom stage complete {stage_id}' if not already done,");
+            say!("or clean up the worktree: loom worktree remove {stage_id}");
         }
 
         Ok(MergeResult::FastForward) => {
-            println!("Fast-forward merge completed!");
+            say!("Fast-forward merge completed!");
 
             update_stage(&stage_id, work_dir, |s| {
                 s.merge_conflict = false;
                 s.try_complete_merge()
             })?;
 
-            println!("Stage '{stage_id}' merge complete! (Completed, merged: true)");
+            say!("Stage '{stage_id}' merge complete! (Completed, merged: true)");
 
             let triggered = trigger_dependents(&stage_id, work_dir, &repo_root, &target_branch)
                 .context("Failed to trigger dependent stages")?;
             if !triggered.is_empty() {
-                println!("Triggered {} dependent stage(s):", triggered.len());
+                say!("Triggered {} dependent stage(s):", triggered.len());
                 for dep_id in &triggered {
-                    println!("  -> {dep_id}");
+                    say!("  -> {dep_id}");
                 }
             }
 
-            println!();
-            println!("Consider cleaning up: loom worktree remove {stage_id}");
+            say!();
+            say!("Consider cleaning up: loom worktree remove {stage_id}");
         }
 
         Ok(MergeResult::AlreadyUpToDate) => {
-            println!("Branch is already up to date with {target_branch}.");
+            say!("Branch is already up to date with {target_branch}.");
 
             update_stage(&stage_id, work_dir, |s| {
                 s.merge_conflict = false;
                 s.try_complete_merge()
             })?;
 
-            println!("Stage '{stage_id}' marked as merged.");
+            say!("Stage '{stage_id}' marked as merged.");
 
             let triggered = trigger_dependents(&stage_id, work_dir, &repo_root, &target_branch)
                 .context("Failed to trigger dependent stages")?;
             if !triggered.is_empty() {
-                println!("Triggered {} dependent stage(s):", triggered.len());
+                say!("Triggered {} dependent stage(s):", triggered.len());
                 for dep_id in &triggered {
-                    println!("  -> {dep_id}");
+                    say!("  -> {dep_id}");
                 }
             }
         }
@@ -431,6 +478,7 @@ fn find_repo_root(cwd: &Path) -> Result<std::path::PathBuf> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::git::get_conflicting_files;
     use crate::models::stage::Stage;
     use std::process::Command;
     use tempfile::TempDir;
@@ -1,8 +1,10 @@
 //! Stage state manipulation
-//! Usage: loom stage <id> [complete|block|reset|merge|retry|verify|...]
+//! Usage: loom stage <id> [complete|block|reset|merge|retry|verify|watch|...]
 
 pub(crate) mod acceptance_runner;
+mod cancel;
 mod check_acceptance;
+mod claim;
 pub mod complete;
 mod criteria_runner;
 mod dispute_criteria;
@@ -12,25 +14,34 @@ mod merge;
 mod merge_resolver;
 pub mod merge_verify;
 mod output;
+mod pair_review;
 mod progressive_complete;
 pub(crate) mod recover;
 pub(crate) mod session;
 mod skip_retry;
 mod state;
 mod verify;
+mod watch;
 
 #[cfg(test)]
 mod tests;
 
 // Re-export public API
+pub use cancel::cancel;
 pub use check_acceptance::check_acceptance;
+pub use claim::claim;
 pub use complete::complete;
 pub use dispute_criteria::dispute_criteria;
 pub use human_review::human_review;
 pub use merge::merge;
+pub(crate) use output::{format_value, parse_value, write_output};
 pub use output::{
     get as output_get, list as output_list, remove as output_remove, set as output_set,
+    set_from_json as output_set_from_json,
 };
+pub use pair_review::pair_review;
 pub use skip_retry::{retry, skip};
-pub use state::{block, hold, release, reset, resume_from_waiting, waiting};
+pub(crate) use state::kill_stage_session;
+pub use state::{assign, block, hold, release, reset, resume_from_waiting, waiting};
 pub use verify::verify;
+pub use watch::watch;
@@ -2,11 +2,12 @@
 //!
 //! Allows stages to emit structured outputs that can be consumed by dependent stages.
 
-use anyhow::{bail, Result};
-use serde_json::Value;
+use anyhow::{bail, Context, Result};
+use serde_json::{Map, Value};
+use std::io::Read;
 use std::path::Path;
 
-use crate::models::stage::StageOutput;
+use crate::models::stage::{OutputType, Stage, StageOutput};
 use crate::verify::transitions::{load_stage, save_stage};
 
 /// Set an output for a stage.
@@ -28,6 +29,118 @@ pub fn set(
 ) -> Result<()> {
     let work_dir = Path::new(".work");
 
+    let (was_new, json_value) =
+        write_output(work_dir, &stage_id, &key, &value, description.as_deref())?;
+
+    let action = if was_new { "added" } else { "updated" };
+    println!("Output '{key}' {action} for stage '{stage_id}'");
+    println!("  Value: {}", format_value(&json_value));
+
+    Ok(())
+}
+
+/// Validate and store an output on a stage without printing anything.
+///
+/// Shared by `loom stage output set` and the MCP `set_output` tool.
+///
+/// # Returns
+///
+/// Whether the key was newly added, and the parsed value that was stored.
+pub(crate) fn write_output(
+    work_dir: &Path,
+    stage_id: &str,
+    key: &str,
+    value: &str,
+    description: Option<&str>,
+) -> Result<(bool, Value)> {
+    validate_key(key)?;
+
+    let mut stage = load_stage(stage_id, work_dir)?;
+
+    let spec = stage.expected_output(key);
+
+    // Parse value as JSON if possible, otherwise use as string. A value
+    // declared as a string is stored verbatim, so "8080" stays a string.
+    let json_value = match spec.and_then(|spec| spec.output_type) {
+        Some(OutputType::String) => Value::String(value.to_string()),
+        _ => parse_value(value),
+    };
+    let output = build_output(&stage, key, json_value.clone(), description)?;
+
+    let was_new = stage.set_output(output);
+    save_stage(&stage, work_dir)?;
+
+    Ok((was_new, json_value))
+}
+
+/// Set every key of a JSON object as an output, from a file or `-` for stdin.
+///
+/// All keys are validated against the stage's declared outputs before any is
+/// stored, so a bad value leaves the stage's outputs untouched.
+pub fn set_from_json(stage_id: String, source: String) -> Result<()> {
+    let work_dir = Path::new(".work");
+
+    let content = if source == "-" {
+        let mut content = String::new();
+        std::io::stdin()
+            .read_to_string(&mut content)
+            .context("Failed to read outputs from stdin")?;
+        content
+    } else {
+        std::fs::read_to_string(&source)
+            .with_context(|| format!("Failed to read outputs from {source}"))?
+    };
+    let outputs: Map<String, Value> = serde_json::from_str(&content)
+        .context("Outputs must be a JSON object mapping output keys to values")?;
+
+    let written = write_outputs(work_dir, &stage_id, outputs)?;
+    if written.is_empty() {
+        println!("No outputs given for stage '{stage_id}'");
+    }
+    for (key, was_new, value) in &written {
+        let action = if *was_new { "added" } else { "updated" };
+        println!("Output '{key}' {action}: {}", format_value(value));
+    }
+    Ok(())
+}
+
+/// Validate and store several outputs on a stage in one save.
+///
+/// Values are taken as given: unlike [`write_output`], a string is never
+/// re-parsed, so `"8080"` is a string and `8080` an int.
+///
+/// # Returns
+///
+/// Each key with whether it was newly added and the value stored.
+pub(crate) fn write_outputs(
+    work_dir: &Path,
+    stage_id: &str,
+    outputs: Map<String, Value>,
+) -> Result<Vec<(String, bool, Value)>> {
+    for key in outputs.keys() {
+        validate_key(key)?;
+    }
+
+    let mut stage = load_stage(stage_id, work_dir)?;
+    let built = outputs
+        .into_iter()
+        .map(|(key, value)| build_output(&stage, &key, value, None))
+        .collect::<Result<Vec<_>>>()?;
+    if built.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut written = Vec::with_capacity(built.len());
+    for output in built {
+        let (key, value) = (output.key.clone(), output.value.clone());
+        let was_new = stage.set_output(output);
+        written.push((key, was_new, value));
+    }
+    save_stage(&stage, work_dir)?;
+    Ok(written)
+}
+
+fn validate_key(key: &str) -> Result<()> {
     // Validate key format (alphanumeric, underscores, dashes)
     if !key
         .chars()
@@ -39,26 +152,36 @@ pub fn set(
     if key.is_empty() || key.len() > 64 {
         bail!("Output key must be 1-64 characters");
     }
+    Ok(())
+}
 
-    let mut stage = load_stage(&stage_id, work_dir)?;
-
-    // Parse value as JSON if possible, otherwise use as string
-    let json_value = parse_value(&value);
-
-    let output = StageOutput {
-        key: key.clone(),
-        value: json_value.clone(),
-        description: description.unwrap_or_else(|| format!("Output: {key}")),
-    };
-
-    let was_new = stage.set_output(output);
-    save_stage(&stage, work_dir)?;
-
-    let action = if was_new { "added" } else { "updated" };
-    println!("Output '{key}' {action} for stage '{stage_id}'");
-    println!("  Value: {}", format_value(&json_value));
+/// Check `value` against the stage's declared type for `key` and fill in the
+/// description (explicit, then declared, then a default).
+fn build_output(
+    stage: &Stage,
+    key: &str,
+    value: Value,
+    description: Option<&str>,
+) -> Result<StageOutput> {
+    let spec = stage.expected_output(key);
+    if let Some(expected) = spec.and_then(|spec| spec.output_type) {
+        if !expected.matches(&value) {
+            bail!(
+                "Output '{key}' is declared as {expected} in the plan, got {}",
+                format_value(&value)
+            );
+        }
+    }
 
-    Ok(())
+    let description = description
+        .map(str::to_string)
+        .or_else(|| spec.and_then(|spec| spec.description.clone()))
+        .unwrap_or_else(|| format!("Output: {key}"));
+    Ok(StageOutput {
+        key: key.to_string(),
+        value,
+        description,
+    })
 }
 
 /// List all outputs for a stage.
@@ -121,7 +244,7 @@ pub fn remove(stage_id: String, key: String) -> Result<()> {
 /// Parse a string value into JSON Value.
 ///
 /// Tries to parse as JSON first. If that fails, returns the string as a JSON string value.
-fn parse_value(value: &str) -> Value {
+pub(crate) fn parse_value(value: &str) -> Value {
     // Try to parse as JSON
     if let Ok(json_value) = serde_json::from_str(value) {
         return json_value;
@@ -154,7 +277,7 @@ fn parse_value(value: &str) -> Value {
 }
 
 /// Format a JSON value for display.
-fn format_value(value: &Value) -> String {
+pub(crate) fn format_value(value: &Value) -> String {
     match value {
         Value::String(s) => format!("\"{s}\""),
         Value::Null => "null".to_string(),
@@ -223,4 +346,80 @@ mod tests {
     fn test_format_value_bool() {
         assert_eq!(format_value(&Value::Bool(true)), "true");
     }
+
+    #[test]
+    fn test_write_output_follows_declared_type() {
+        use crate::models::stage::{OutputSpec, Stage};
+        use tempfile::TempDir;
+
+        let temp = TempDir::new().unwrap();
+        let work_dir = temp.path();
+        let mut stage = Stage::new("api".to_string(), None);
+        stage.id = "api".to_string();
+        stage.expected_outputs = vec![
+            OutputSpec {
+                key: "api_port".to_string(),
+                output_type: Some(OutputType::Int),
+                required: true,
+                description: Some("Port the API listens on".to_string()),
+            },
+            OutputSpec {
+                key: "version".to_string(),
+                output_type: Some(OutputType::String),
+                required: false,
+                description: None,
+            },
+        ];
+        save_stage(&stage, work_dir).unwrap();
+
+        let err = write_output(work_dir, "api", "api_port", "eighty", None).unwrap_err();
+        assert!(err.to_string().contains("declared as int"), "{err}");
+
+        write_output(work_dir, "api", "api_port", "8080", None).unwrap();
+        let (_, version) = write_output(work_dir, "api", "version", "2", None).unwrap();
+        assert_eq!(version, Value::String("2".to_string()));
+
+        let stage = load_stage("api", work_dir).unwrap();
+        let port = stage.get_output("api_port").unwrap();
+        assert_eq!(port.value, Value::Number(8080.into()));
+        assert_eq!(port.description, "Port the API listens on");
+    }
+
+    #[test]
+    fn test_write_outputs_is_all_or_nothing() {
+        use crate::models::stage::{OutputSpec, Stage};
+        use serde_json::json;
+        use tempfile::TempDir;
+
+        let temp = TempDir::new().unwrap();
+        let work_dir = temp.path();
+        let mut stage = Stage::new("api".to_string(), None);
+        stage.id = "api".to_string();
+        stage.expected_outputs = vec![OutputSpec {
+            key: "api_port".to_string(),
+            output_type: Some(OutputType::Int),
+            required: true,
+            description: Some("Port the API listens on".to_string()),
+        }];
+        save_stage(&stage, work_dir).unwrap();
+
+        let bad = json!({"version": "2", "api_port": "8080"});
+        let err = write_outputs(work_dir, "api", bad.as_object().unwrap().clone()).unwrap_err();
+        assert!(err.to_string().contains("declared as int"), "{err}");
+        assert!(load_stage("api", work_dir).unwrap().outputs.is_empty());
+
+        let bad_key = json!({"not valid": 1});
+        assert!(write_outputs(work_dir, "api", bad_key.as_object().unwrap().clone()).is_err());
+
+        let good = json!({"version": "2", "api_port": 8080});
+        let written = write_outputs(work_dir, "api", good.as_object().unwrap().clone()).unwrap();
+        assert_eq!(written.len(), 2);
+        assert!(written.iter().all(|(_, was_new, _)| *was_new));
+
+        let stage = load_stage("api", work_dir).unwrap();
+        assert_eq!(stage.get_output("version").unwrap().value, json!("2"));
+        let port = stage.get_output("api_port").unwrap();
+        assert_eq!(port.value, json!(8080));
+        assert_eq!(port.description, "Port the API listens on");
+    }
 }
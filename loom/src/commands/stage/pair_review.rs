@@ -0,0 +1,136 @@
+//! Pair-mode review verdicts
+//!
+//! The reviewer of a `pair: true` stage approves or rejects the implementer's
+//! work. The verdict is stored on the stage with the worktree HEAD it covers;
+//! `loom stage complete` refuses the stage until the latest commit is approved.
+//!
+//! Only the stage's reviewer session, or a human operator (no
+//! `LOOM_SESSION_ID` in the environment), may record a verdict.
+
+use anyhow::{bail, Context, Result};
+use std::path::Path;
+
+use crate::fs::session_files::find_session_file;
+use crate::models::session::Session;
+use crate::models::stage::Stage;
+use crate::orchestrator::pair::{self, PairGate};
+use crate::parser::frontmatter::parse_from_markdown;
+use crate::verify::transitions::{load_stage, update_stage};
+
+use super::acceptance_runner::resolve_stage_execution_paths;
+
+/// Record (or, without a verdict, show) the pair review of a stage.
+pub fn pair_review(
+    stage_id: String,
+    approve: bool,
+    reject: bool,
+    notes: Option<String>,
+) -> Result<()> {
+    let work_dir = Path::new(".work");
+    let stage = load_stage(&stage_id, work_dir)?;
+    if !stage.pair {
+        bail!("Stage '{stage_id}' is not in pair mode (set `pair: true` in the plan)");
+    }
+
+    let head = resolve_stage_execution_paths(&stage)
+        .ok()
+        .and_then(|paths| paths.worktree_root)
+        .and_then(|root| pair::worktree_head(&root));
+
+    if !approve && !reject {
+        return show_review(&stage, head.as_deref());
+    }
+    if reject && notes.is_none() {
+        bail!("--reject needs --notes describing what must change");
+    }
+    check_reviewer(
+        &stage_id,
+        std::env::var("LOOM_SESSION_ID").ok().as_deref(),
+        work_dir,
+    )?;
+
+    let stage = update_stage(&stage_id, work_dir, |s| {
+        pair::record_review(s, approve, notes.clone(), head.clone());
+        Ok(())
+    })?;
+    let commit = head.as_deref().map(short).unwrap_or("unknown commit");
+    if approve {
+        println!("Approved stage '{stage_id}' at {commit}");
+        println!("  The implementer can now run 'loom stage complete {stage_id}'");
+    } else {
+        println!("Requested changes on stage '{stage_id}' at {commit}");
+        if let Some(notes) = stage.pair_review.as_ref().and_then(|r| r.notes.as_deref()) {
+            println!("  {notes}");
+        }
+    }
+    Ok(())
+}
+
+/// Refuse a verdict from any session other than the stage's pair reviewer.
+///
+/// `session_id` is the caller's `LOOM_SESSION_ID`; `None` is a human operator.
+pub(crate) fn check_reviewer(
+    stage_id: &str,
+    session_id: Option<&str>,
+    work_dir: &Path,
+) -> Result<()> {
+    let Some(session_id) = session_id.filter(|id| !id.is_empty()) else {
+        return Ok(());
+    };
+    let reviewer = find_session_file(work_dir, session_id)?
+        .map(|path| {
+            let content = std::fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read session file: {}", path.display()))?;
+            parse_from_markdown::<Session>(&content, "Session")
+        })
+        .transpose()?
+        .is_some_and(|session| {
+            session.is_pair_reviewer() && session.stage_id.as_deref() == Some(stage_id)
+        });
+    if !reviewer {
+        bail!(
+            "Session '{session_id}' is not the pair reviewer of stage '{stage_id}'; only the reviewer can record a verdict"
+        );
+    }
+    Ok(())
+}
+
+fn show_review(stage: &Stage, head: Option<&str>) -> Result<()> {
+    let state = match pair::pair_gate(stage, head) {
+        PairGate::NotRequired => "not in pair mode",
+        PairGate::Missing => "awaiting review",
+        PairGate::Approved => "approved",
+        PairGate::Stale => "approved, but new commits need review",
+        PairGate::Rejected { .. } => "changes requested",
+    };
+    println!("Stage '{}': {state}", stage.id);
+    if let Some(review) = &stage.pair_review {
+        println!(
+            "  Last verdict: {} at {} ({})",
+            if review.approved { "approve" } else { "reject" },
+            review
+                .commit
+                .as_deref()
+                .map(short)
+                .unwrap_or("unknown commit"),
+            review.reviewed_at.format("%Y-%m-%d %H:%M:%S UTC")
+        );
+        if let Some(notes) = &review.notes {
+            println!("  Notes: {notes}");
+        }
+    }
+    println!();
+    println!(
+        "  loom stage pair-review {} --approve [--notes <text>]",
+        stage.id
+    );
+    println!(
+        "  loom stage pair-review {} --reject --notes <text>",
+        stage.id
+    );
+    Ok(())
+}
+
+fn short(commit: &str) -> &str {
+    &commit[..commit.len().min(8)]
+}
@@ -6,11 +6,15 @@
 use anyhow::{bail, Context, Result};
 use std::path::Path;
 
-use crate::git::branch::branch_name_for_stage;
+use crate::fs::work_dir::load_config;
+use crate::git::branch::{branch_name_for_stage, push_stage_branch};
 use crate::git::cleanup::{cleanup_after_merge, CleanupConfig};
 use crate::git::get_branch_head;
+use crate::git::worktree::get_worktree_path;
 use crate::models::stage::Stage;
+use crate::orchestrator::progressive_merge::queue::wait_for_merge_turn;
 use crate::orchestrator::{get_merge_point, merge_completed_stage, ProgressiveMergeResult};
+use crate::say;
 use crate::verify::transitions::update_stage;
 
 /// Result of attempting to merge a completed stage
@@ -26,7 +30,8 @@ pub enum MergeOutcome {
 /// Attempt to progressively merge a completed stage into the merge point.
 ///
 /// This function handles the git merge operations and updates the stage's
-/// merge-related fields (merged, completed_commit).
+/// merge-related fields (merged, completed_commit). The merge waits for the
+/// stage's turn in the merge queue first.
 ///
 /// # Concurrency (A-5)
 ///
@@ -62,20 +67,32 @@ pub fn attempt_progressive_merge(
     // Snapshot the commit to re-apply onto the fresh on-disk stage below.
     let completed_commit = stage.completed_commit.clone();
 
-    println!("Attempting progressive merge into '{merge_point}'...");
-    match merge_completed_stage(stage, repo_root, &merge_point) {
+    say!("Attempting progressive merge into '{merge_point}'...");
+    let merge_result = {
+        // Take this stage's turn in the merge queue (`loom merge queue`). A
+        // broken queue file must never block a merge, so errors only warn.
+        let _turn = match wait_for_merge_turn(work_dir, &stage.id) {
+            Ok(ticket) => Some(ticket),
+            Err(e) => {
+                tracing::warn!(stage_id = %stage.id, "Merge queue unavailable: {e}");
+                None
+            }
+        };
+        merge_completed_stage(stage, repo_root, &merge_point)
+    };
+    match merge_result {
         Ok(ProgressiveMergeResult::Success { files_changed }) => {
-            println!("  ✓ Merged {files_changed} file(s) into '{merge_point}'");
+            say!("  ✓ Merged {files_changed} file(s) into '{merge_point}'");
             stage.merged = true;
             Ok(MergeOutcome::Success)
         }
         Ok(ProgressiveMergeResult::FastForward) => {
-            println!("  ✓ Fast-forward merge into '{merge_point}'");
+            say!("  ✓ Fast-forward merge into '{merge_point}'");
             stage.merged = true;
             Ok(MergeOutcome::Success)
         }
         Ok(ProgressiveMergeResult::AlreadyMerged) => {
-            println!("  ✓ Already up to date with '{merge_point}'");
+            say!("  ✓ Already up to date with '{merge_point}'");
             stage.merged = true;
             Ok(MergeOutcome::Success)
         }
@@ -122,15 +139,13 @@ pub fn attempt_progressive_merge(
                 work_dir,
             ) {
                 Ok(MergeResolverResult::DaemonManaged) => {
-                    println!(
-                        "    Daemon is running - merge resolution will be handled automatically."
-                    );
+                    say!("    Daemon is running - merge resolution will be handled automatically.");
                 }
                 Ok(MergeResolverResult::Spawned(id)) => {
-                    println!("    Spawned merge resolver session: {id}");
+                    say!("    Spawned merge resolver session: {id}");
                 }
                 Ok(MergeResolverResult::AlreadyRunning { session_id }) => {
-                    println!("    Merge resolver session '{session_id}' is already running.");
+                    say!("    Merge resolver session '{session_id}' is already running.");
                 }
                 Err(e) => {
                     eprintln!("    Failed to spawn merge resolver: {e}");
@@ -159,6 +174,28 @@ pub fn attempt_progressive_merge(
     }
 }
 
+/// Push the stage branch to the plan's `push_remote` when the plan sets
+/// `push_branches`, so the work can be inspected from other machines.
+///
+/// Runs before the merge, which deletes the local branch. Best-effort: a
+/// failed push (or offline mode) only warns and never blocks completion.
+pub(super) fn push_branch_if_enabled(stage_id: &str, repo_root: &Path, work_dir: &Path) {
+    let Some(config) = load_config(work_dir).ok().flatten() else {
+        return;
+    };
+    let Some(remote) = config.push_remote() else {
+        return;
+    };
+    if crate::offline::is_offline(Some(work_dir)) {
+        eprintln!("  Warning: offline mode - not pushing stage branch to '{remote}'");
+        return;
+    }
+    match push_stage_branch(stage_id, remote, repo_root) {
+        Ok(branch) => say!("  ✓ Pushed {branch} to '{remote}'"),
+        Err(e) => eprintln!("  Warning: {e:#}"),
+    }
+}
+
 /// Whether worktree cleanup for `stage_id` must be deferred rather than run
 /// now.
 ///
@@ -169,7 +206,7 @@ pub fn attempt_progressive_merge(
 /// case, the caller must skip immediate cleanup and leave it for the
 /// orchestrator (which cleans up after killing the session).
 pub(super) fn should_defer_cleanup(cwd: &Path, repo_root: &Path, stage_id: &str) -> bool {
-    let expected = repo_root.join(".worktrees").join(stage_id);
+    let expected = get_worktree_path(stage_id, repo_root);
     let expected = match expected.canonicalize() {
         Ok(p) => p,
         // Worktree doesn't exist on disk - cleanup would be a no-op anyway.
@@ -205,7 +242,7 @@ pub fn complete_with_merge(stage: &mut Stage, repo_root: &Path, work_dir: &Path)
                 s.try_complete(None)
             })?;
 
-            println!("Stage '{}' completed!", stage.id);
+            say!("Stage '{}' completed!", stage.id);
 
             // Trigger dependent stages
             let target_branch = crate::fs::work_dir::load_config(work_dir)
@@ -223,9 +260,9 @@ pub fn complete_with_merge(stage: &mut Stage, repo_root: &Path, work_dir: &Path)
             .context("Failed to trigger dependent stages")?;
 
             if !triggered.is_empty() {
-                println!("Triggered {} dependent stage(s):", triggered.len());
+                say!("Triggered {} dependent stage(s):", triggered.len());
                 for dep_id in &triggered {
-                    println!("  → {dep_id}");
+                    say!("  → {dep_id}");
                 }
             }
 
@@ -238,10 +275,10 @@ pub fn complete_with_merge(stage: &mut Stage, repo_root: &Path, work_dir: &Path)
             };
 
             if defer_cleanup {
-                println!(
+                say!(
                     "  Worktree cleanup deferred to the orchestrator (session is running inside the worktree)"
                 );
-                println!(
+                say!(
                     "  If no daemon is running, clean up manually with: loom worktree remove {}",
                     stage.id
                 );
@@ -256,10 +293,10 @@ pub fn complete_with_merge(stage: &mut Stage, repo_root: &Path, work_dir: &Path)
                 match cleanup_after_merge(&stage.id, repo_root, &cleanup_config) {
                     Ok(result) => {
                         if result.worktree_removed {
-                            println!("  Removed worktree: .worktrees/{}", stage.id);
+                            say!("  Removed worktree: .worktrees/{}", stage.id);
                         }
                         if result.branch_deleted {
-                            println!("  Deleted branch: {}", branch_name_for_stage(&stage.id));
+                            say!("  Deleted branch: {}", branch_name_for_stage(&stage.id));
                         }
                         if !result.warnings.is_empty() {
                             for warning in &result.warnings {
@@ -144,6 +144,12 @@ pub fn recover(stage_id: String, force: bool) -> Result<()> {
         ),
     };
 
+    // Tailor the recovery actions to how the previous attempt failed
+    let signal_content = match stage.failure_info {
+        Some(ref info) => signal_content.with_failure_info(info),
+        None => signal_content,
+    };
+
     // Generate the recovery signal file
     generate_recovery_signal(&signal_content, &stage, work_dir)
         .context("Failed to generate recovery signal")?;
@@ -281,6 +287,8 @@ mod tests {
             worktree: None,
             session: None,
             held: false,
+            held_by: None,
+            assignee: None,
             parent_stage: None,
             child_stages: vec![],
             created_at: Utc::now(),
@@ -320,12 +328,28 @@ mod tests {
             execution_mode: None,
             max_fix_attempts: None,
             review_reason: None,
+            waiting_prompt: None,
+            waiting_since: None,
             bug_fix: None,
             regression_test: None,
             model: None,
             reasoning_effort: None,
             is_possibly_stuck: false,
             ultracode: false,
+            pair: false,
+            pair_review: None,
+            worktree_setup: Vec::new(),
+            instructions: None,
+            prompt_prefix: None,
+            container: None,
+            keep_terminal: None,
+            expected_outputs: Vec::new(),
+            params: Vec::new(),
+            requires_outputs: Vec::new(),
+            new_files: Vec::new(),
+            expected_duration_secs: None,
+            acceptance_isolation: None,
+            skip_if_satisfied: false,
         };
 
         // No reason - should be Manual
@@ -113,6 +113,10 @@ pub fn retry(stage_id: String, force: bool, context: Option<String>) -> Result<(
         .clone()
         .unwrap_or_else(|| "unknown".to_string());
 
+    // Keep the failure classification for the recovery signal even when
+    // --force clears it from the stage
+    let failure_info = stage.failure_info.clone();
+
     // Reset or increment for retry
     if force {
         stage.retry_count = 0;
@@ -188,6 +192,11 @@ pub fn retry(stage_id: String, force: bool, context: Option<String>) -> Result<(
             ),
         };
 
+        let signal_content = match failure_info {
+            Some(ref info) => signal_content.with_failure_info(info),
+            None => signal_content,
+        };
+
         generate_recovery_signal(&signal_content, &stage, work_dir)
             .context("Failed to generate recovery signal")?;
 
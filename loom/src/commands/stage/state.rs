@@ -4,11 +4,12 @@ use anyhow::{Context, Result};
 use std::path::Path;
 
 use crate::fs::session_files::find_session_file;
+use crate::models::failure::{FailureInfo, FailureType};
 use crate::models::session::Session;
 use crate::models::stage::StageStatus;
 use crate::orchestrator::terminal::native::NativeBackend;
 use crate::parser::frontmatter::parse_from_markdown;
-use crate::verify::transitions::{load_stage, save_stage};
+use crate::verify::transitions::{load_stage, save_stage, update_stage};
 
 /// Block a stage with a reason
 pub fn block(stage_id: String, reason: String) -> Result<()> {
@@ -17,6 +18,14 @@ pub fn block(stage_id: String, reason: String) -> Result<()> {
     let mut stage = load_stage(&stage_id, work_dir)?;
     stage.try_mark_blocked()?;
     stage.close_reason = Some(reason.clone());
+    // Record the block as UserBlocked so a stale retryable failure (crash,
+    // timeout) from an earlier attempt cannot auto-retry a stage the user
+    // stopped deliberately.
+    stage.failure_info = Some(FailureInfo {
+        failure_type: FailureType::UserBlocked,
+        detected_at: chrono::Utc::now(),
+        evidence: vec![reason.clone()],
+    });
     stage.updated_at = chrono::Utc::now();
     save_stage(&stage, work_dir)?;
 
@@ -40,36 +49,7 @@ pub fn reset(stage_id: String, hard: bool, kill_session: bool) -> Result<()> {
     // respawned stage starts a new one.
     if kill_session {
         if let Some(ref session_id) = stage.session.clone() {
-            let kill_result = find_session_file(work_dir, session_id)
-                .context("Failed to locate session file")
-                .and_then(|maybe_path| match maybe_path {
-                    None => {
-                        eprintln!("Note: No session file found for '{session_id}', skipping kill");
-                        Ok(())
-                    }
-                    Some(session_file) => std::fs::read_to_string(&session_file)
-                        .context("Failed to read session file")
-                        .and_then(|content| {
-                            parse_from_markdown::<Session>(&content, "Session")
-                                .context("Failed to parse session")
-                        })
-                        .and_then(|session| {
-                            NativeBackend::new(work_dir.to_path_buf())
-                                .context("Failed to construct native backend")
-                                .and_then(|native| {
-                                    if native.is_session_alive(&session)? {
-                                        native.kill_session(&session)?;
-                                        println!("  Killed session '{session_id}'");
-                                    } else {
-                                        println!("  Session '{session_id}' already terminated");
-                                    }
-                                    Ok(())
-                                })
-                        }),
-                });
-            if let Err(e) = kill_result {
-                eprintln!("Warning: Failed to kill session '{session_id}': {e}");
-            }
+            kill_stage_session(work_dir, session_id);
         } else {
             eprintln!("Note: Stage '{stage_id}' has no associated session to kill");
         }
@@ -114,6 +94,43 @@ pub fn reset(stage_id: String, hard: bool, kill_session: bool) -> Result<()> {
     Ok(())
 }
 
+/// Kill a stage's session if it is still alive.
+///
+/// Failures are reported as warnings: the caller's state change goes ahead
+/// either way.
+pub(crate) fn kill_stage_session(work_dir: &Path, session_id: &str) {
+    let kill_result = find_session_file(work_dir, session_id)
+        .context("Failed to locate session file")
+        .and_then(|maybe_path| match maybe_path {
+            None => {
+                eprintln!("Note: No session file found for '{session_id}', skipping kill");
+                Ok(())
+            }
+            Some(session_file) => std::fs::read_to_string(&session_file)
+                .context("Failed to read session file")
+                .and_then(|content| {
+                    parse_from_markdown::<Session>(&content, "Session")
+                        .context("Failed to parse session")
+                })
+                .and_then(|session| {
+                    NativeBackend::new(work_dir.to_path_buf())
+                        .context("Failed to construct native backend")
+                        .and_then(|native| {
+                            if native.is_session_alive(&session)? {
+                                native.kill_session(&session)?;
+                                println!("  Killed session '{session_id}'");
+                            } else {
+                                println!("  Session '{session_id}' already terminated");
+                            }
+                            Ok(())
+                        })
+                }),
+        });
+    if let Err(e) = kill_result {
+        eprintln!("Warning: Failed to kill session '{session_id}': {e}");
+    }
+}
+
 /// Mark a stage as ready for execution
 ///
 /// Note: This is an internal function not exposed via CLI. The orchestrator
@@ -195,6 +212,22 @@ pub fn hold(stage_id: String) -> Result<()> {
     Ok(())
 }
 
+/// Assign a stage to a person, or clear its assignee when `person` is `None`
+pub fn assign(stage_id: String, person: Option<String>) -> Result<()> {
+    let work_dir = Path::new(".work");
+
+    let stage = update_stage(&stage_id, work_dir, |stage| {
+        stage.assignee = person.clone();
+        Ok(())
+    })?;
+
+    match &stage.assignee {
+        Some(person) => println!("Stage '{stage_id}' assigned to {person}"),
+        None => println!("Stage '{stage_id}' unassigned"),
+    }
+    Ok(())
+}
+
 /// Release a held stage (allow auto-execution)
 pub fn release(stage_id: String) -> Result<()> {
     let work_dir = Path::new(".work");
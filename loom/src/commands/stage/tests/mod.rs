@@ -15,6 +15,9 @@ mod complete_router;
 #[cfg(test)]
 mod progressive_complete;
 
+#[cfg(test)]
+mod pair_review;
+
 // Shared test utilities
 use crate::models::stage::{Stage, StageStatus};
 use std::fs;
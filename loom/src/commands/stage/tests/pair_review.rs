@@ -0,0 +1,35 @@
+//! Tests for pair review verdict authorization
+
+use super::super::pair_review::check_reviewer;
+use super::setup_work_dir;
+use crate::fs::save_session;
+use crate::models::session::Session;
+
+#[test]
+fn test_implementer_session_cannot_record_verdict() {
+    let temp = setup_work_dir();
+    let work_dir = temp.path().join(".work");
+
+    let mut implementer = Session::new();
+    implementer.assign_to_stage("stage-1".to_string());
+    save_session(&implementer, &work_dir).unwrap();
+
+    let err = check_reviewer("stage-1", Some(&implementer.id), &work_dir).unwrap_err();
+    assert!(err.to_string().contains("not the pair reviewer"));
+}
+
+#[test]
+fn test_reviewer_session_and_operator_can_record_verdict() {
+    let temp = setup_work_dir();
+    let work_dir = temp.path().join(".work");
+
+    let mut reviewer = Session::new_pair_reviewer();
+    reviewer.assign_to_stage("stage-1".to_string());
+    save_session(&reviewer, &work_dir).unwrap();
+
+    assert!(check_reviewer("stage-1", None, &work_dir).is_ok());
+    assert!(check_reviewer("stage-1", Some(&reviewer.id), &work_dir).is_ok());
+    // The reviewer of one stage cannot approve another, nor can unknown sessions.
+    assert!(check_reviewer("stage-2", Some(&reviewer.id), &work_dir).is_err());
+    assert!(check_reviewer("stage-1", Some("session-unknown"), &work_dir).is_err());
+}
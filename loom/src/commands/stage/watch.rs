@@ -0,0 +1,318 @@
+//! Watch command: follow one stage in the foreground until it settles
+//!
+//! Polls the stage file, its heartbeat and its handoff checkpoints, prints
+//! what changed since the previous poll, and tails the session's log when it
+//! has one (headless sessions write `.work/logs/sessions/<session-id>.log`).
+//! Exits once the stage completes, is skipped, or stops needing attention.
+
+use anyhow::{bail, Result};
+use chrono::Local;
+use colored::Colorize;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+use crate::handoff::generator::list_stage_handoffs;
+use crate::models::stage::{Stage, StageStatus, StatusBucket, VerificationStatus};
+use crate::orchestrator::monitor::heartbeat::read_heartbeat;
+use crate::orchestrator::terminal::headless::headless_log_path;
+use crate::verify::transitions::load_stage;
+
+/// What `loom stage watch` reports on, captured once per poll.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Snapshot {
+    status: Option<StageStatus>,
+    session: Option<String>,
+    retry_count: u32,
+    fix_attempts: u32,
+    verification: Option<VerificationStatus>,
+    failure: Option<String>,
+    merged: bool,
+    /// Heartbeat timestamp, context percent and activity
+    heartbeat: Option<(String, Option<f32>, String)>,
+    waiting_prompt: Option<String>,
+    checkpoints: Vec<String>,
+}
+
+impl Snapshot {
+    fn capture(stage: &Stage, work_dir: &Path) -> Self {
+        let heartbeat_path = work_dir
+            .join("heartbeat")
+            .join(format!("{}.json", stage.id));
+        let heartbeat = read_heartbeat(&heartbeat_path).ok();
+        let checkpoints = list_stage_handoffs(&stage.id, work_dir)
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|path| path.file_name())
+            .map(|name| name.to_string_lossy().into_owned())
+            .collect();
+        Self {
+            status: Some(stage.status.clone()),
+            session: stage.session.clone(),
+            retry_count: stage.retry_count,
+            fix_attempts: stage.fix_attempts,
+            verification: Some(stage.verification_status.clone()),
+            failure: stage.failure_info.as_ref().map(|info| {
+                let evidence = info.evidence.first().map(String::as_str).unwrap_or("");
+                format!("{}: {}", info.failure_type, first_line(evidence))
+            }),
+            merged: stage.merged,
+            heartbeat: heartbeat.as_ref().map(|hb| {
+                let activity = hb
+                    .activity
+                    .clone()
+                    .or_else(|| hb.last_tool.clone())
+                    .unwrap_or_default();
+                (hb.timestamp.to_rfc3339(), hb.context_percent, activity)
+            }),
+            waiting_prompt: heartbeat.and_then(|hb| hb.waiting_prompt),
+            checkpoints,
+        }
+    }
+}
+
+/// Follow a stage until it reaches a terminal state
+///
+/// Fails when the stage ends up needing attention (blocked, merge trouble,
+/// review), so scripts can tell the outcomes apart.
+pub fn watch(stage_id: String, interval_secs: u64) -> Result<()> {
+    let work_dir = Path::new(".work");
+    let interval = Duration::from_secs(interval_secs.max(1));
+    let mut previous = Snapshot::default();
+    let mut log = LogTail::default();
+
+    println!(
+        "{} Watching stage '{}' (Ctrl+C to stop)",
+        "→".cyan().bold(),
+        stage_id.bold()
+    );
+    loop {
+        let stage = load_stage(&stage_id, work_dir)?;
+        let current = Snapshot::capture(&stage, work_dir);
+        for change in changes(&previous, &current) {
+            println!(
+                "{} {change}",
+                Local::now().format("%H:%M:%S").to_string().dimmed()
+            );
+        }
+
+        if let Some(session) = &current.session {
+            if current.session != previous.session {
+                log = LogTail::new(headless_log_path(work_dir, session));
+            }
+            for line in log.read_new_lines() {
+                println!("  {} {line}", "│".dimmed());
+            }
+        }
+
+        match stage.status.bucket() {
+            StatusBucket::Completed => {
+                println!(
+                    "{} Stage '{stage_id}' finished: {}",
+                    "✓".green().bold(),
+                    stage.status
+                );
+                return Ok(());
+            }
+            StatusBucket::Blocked => {
+                bail!("Stage '{stage_id}' stopped: {}", stage.status);
+            }
+            StatusBucket::Executing | StatusBucket::Pending => {}
+        }
+        previous = current;
+        thread::sleep(interval);
+    }
+}
+
+/// Human-readable lines for what changed between two polls.
+fn changes(previous: &Snapshot, current: &Snapshot) -> Vec<String> {
+    let mut lines = Vec::new();
+    if current.status != previous.status {
+        if let Some(status) = &current.status {
+            let from = previous
+                .status
+                .as_ref()
+                .map(|s| format!("{s} → "))
+                .unwrap_or_default();
+            lines.push(format!("status {from}{}", status.to_string().bold()));
+        }
+    }
+    if current.session != previous.session {
+        match &current.session {
+            Some(session) => lines.push(format!("session {session}")),
+            None => lines.push("session ended".to_string()),
+        }
+    }
+    if current.retry_count > previous.retry_count {
+        lines.push(format!("retry #{}", current.retry_count));
+    }
+    if current.fix_attempts > previous.fix_attempts {
+        lines.push(format!("fix attempt #{}", current.fix_attempts));
+    }
+    if current.heartbeat != previous.heartbeat {
+        if let Some((_, context, activity)) = &current.heartbeat {
+            let context = context
+                .map(|pct| format!(" (context {pct:.0}%)"))
+                .unwrap_or_default();
+            let activity = if activity.is_empty() {
+                "alive".to_string()
+            } else {
+                first_line(activity).to_string()
+            };
+            lines.push(format!("heartbeat: {activity}{context}"));
+        }
+    }
+    if current.waiting_prompt != previous.waiting_prompt {
+        if let Some(prompt) = &current.waiting_prompt {
+            lines.push(format!(
+                "{} {}",
+                "waiting for input:".yellow(),
+                first_line(prompt)
+            ));
+        }
+    }
+    for checkpoint in &current.checkpoints {
+        if !previous.checkpoints.contains(checkpoint) {
+            lines.push(format!("checkpoint {checkpoint}"));
+        }
+    }
+    if current.verification != previous.verification {
+        if let Some(verification) = current
+            .verification
+            .as_ref()
+            .filter(|v| **v != VerificationStatus::NotRun)
+        {
+            lines.push(format!("verification {verification}"));
+        }
+    }
+    if current.failure != previous.failure {
+        if let Some(failure) = &current.failure {
+            lines.push(format!("{} {failure}", "failure".red()));
+        }
+    }
+    if current.merged && !previous.merged {
+        lines.push("merged".green().to_string());
+    }
+    lines
+}
+
+fn first_line(text: &str) -> &str {
+    text.lines().next().unwrap_or("").trim()
+}
+
+/// Incremental reader for a session log that may not exist yet.
+#[derive(Debug, Default)]
+struct LogTail {
+    path: Option<PathBuf>,
+    offset: u64,
+    partial: String,
+}
+
+impl LogTail {
+    fn new(path: PathBuf) -> Self {
+        Self {
+            path: Some(path),
+            offset: 0,
+            partial: String::new(),
+        }
+    }
+
+    /// Complete lines appended since the previous call.
+    ///
+    /// A log that shrank was rotated or truncated, so it is read again from
+    /// the start.
+    fn read_new_lines(&mut self) -> Vec<String> {
+        let Some(path) = &self.path else {
+            return Vec::new();
+        };
+        let Ok(mut file) = File::open(path) else {
+            return Vec::new();
+        };
+        let len = file.metadata().map(|m| m.len()).unwrap_or(0);
+        if len < self.offset {
+            self.offset = 0;
+            self.partial.clear();
+        }
+        if len == self.offset || file.seek(SeekFrom::Start(self.offset)).is_err() {
+            return Vec::new();
+        }
+        let mut bytes = Vec::new();
+        if file.read_to_end(&mut bytes).is_err() {
+            return Vec::new();
+        }
+        self.offset += bytes.len() as u64;
+        self.partial.push_str(&String::from_utf8_lossy(&bytes));
+
+        let mut lines: Vec<String> = self.partial.split('\n').map(String::from).collect();
+        self.partial = lines.pop().unwrap_or_default();
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_changes_reports_transitions_once() {
+        colored::control::set_override(false);
+        let before = Snapshot {
+            status: Some(StageStatus::Queued),
+            ..Snapshot::default()
+        };
+        let after = Snapshot {
+            status: Some(StageStatus::Executing),
+            session: Some("session-1".to_string()),
+            heartbeat: Some(("t1".to_string(), Some(42.0), "Edit".to_string())),
+            checkpoints: vec!["stage-a-handoff-001.md".to_string()],
+            verification: Some(VerificationStatus::NotRun),
+            ..Snapshot::default()
+        };
+        assert_eq!(
+            changes(&before, &after),
+            vec![
+                "status Queued → Executing",
+                "session session-1",
+                "heartbeat: Edit (context 42%)",
+                "checkpoint stage-a-handoff-001.md",
+            ]
+        );
+        assert!(changes(&after, &after).is_empty());
+
+        let done = Snapshot {
+            status: Some(StageStatus::Completed),
+            merged: true,
+            verification: Some(VerificationStatus::Passed),
+            ..after.clone()
+        };
+        assert_eq!(
+            changes(&after, &done),
+            vec![
+                "status Executing → Completed",
+                "verification Passed",
+                "merged"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_log_tail_reads_complete_lines_and_handles_truncation() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("session.log");
+        let mut tail = LogTail::new(path.clone());
+        assert!(tail.read_new_lines().is_empty());
+
+        let mut file = File::create(&path).unwrap();
+        write!(file, "one\ntw").unwrap();
+        assert_eq!(tail.read_new_lines(), vec!["one"]);
+        write!(file, "o\nthree\n").unwrap();
+        assert_eq!(tail.read_new_lines(), vec!["two", "three"]);
+
+        std::fs::write(&path, "fresh\n").unwrap();
+        assert_eq!(tail.read_new_lines(), vec!["fresh"]);
+    }
+}
@@ -6,17 +6,22 @@ pub mod render;
 pub mod ui;
 mod validation;
 
-use crate::daemon::{DaemonServer, DaemonStatus};
-use crate::fs::work_dir::WorkDir;
+use crate::daemon::{read_daemon_health, read_supervisor_state, DaemonServer, DaemonStatus};
+use crate::fs::work_dir::{read_escalation_config, WorkDir};
+use crate::orchestrator::pause::read_pause_state;
+use crate::orchestrator::throttle::read_throttle_state;
 use anyhow::Result;
 use colored::Colorize;
 
 use diagnostics::{check_directory_structure, check_parsing_errors};
 use display::count_files;
+use render::GroupBy;
 use validation::{validate_markdown_files, validate_references};
 
 /// Show the status dashboard with context health
-pub fn execute(live: bool, compact: bool, verbose: bool) -> Result<()> {
+///
+/// `group_by` replaces the stage tree with grouped progress bars.
+pub fn execute(live: bool, compact: bool, verbose: bool, group_by: Option<GroupBy>) -> Result<()> {
     let work_dir = WorkDir::new(".")?;
     work_dir.load()?;
 
@@ -42,7 +47,27 @@ pub fn execute(live: bool, compact: bool, verbose: bool) -> Result<()> {
     }
 
     // Static mode (default): show snapshot of current state
-    execute_static(&work_dir, verbose)
+    execute_static(&work_dir, verbose, group_by)
+}
+
+/// Explain why a stage isn't running
+pub fn explain(stage_id: &str) -> Result<()> {
+    let work_dir = WorkDir::new(".")?;
+    work_dir.load()?;
+
+    let explanation = data::explain::collect_explanation(&work_dir, stage_id)?;
+    render::render_explanation(&mut std::io::stdout(), &explanation)?;
+    Ok(())
+}
+
+/// Show the most recent scheduler decisions, optionally for one stage
+pub fn decisions(limit: usize, stage_id: Option<&str>) -> Result<()> {
+    let work_dir = WorkDir::new(".")?;
+    work_dir.load()?;
+
+    let decisions = crate::orchestrator::decisions::read_recent(work_dir.root(), limit, stage_id);
+    render::render_decisions(&mut std::io::stdout(), &decisions)?;
+    Ok(())
 }
 
 /// Execute compact mode - single line output for scripting
@@ -56,7 +81,7 @@ fn execute_compact(work_dir: &WorkDir) -> Result<()> {
 }
 
 /// Show static status dashboard
-fn execute_static(work_dir: &WorkDir, verbose: bool) -> Result<()> {
+fn execute_static(work_dir: &WorkDir, verbose: bool, group_by: Option<GroupBy>) -> Result<()> {
     use data::collect_status_data;
     use std::io::stdout;
 
@@ -104,15 +129,40 @@ fn execute_static(work_dir: &WorkDir, verbose: bool) -> Result<()> {
             );
         }
     }
+    if let Some(state) = read_pause_state(work_dir.root()) {
+        render::render_pause(&mut out, &state)?;
+    }
+    if daemon_status != DaemonStatus::NotRunning {
+        if let Some(state) = read_throttle_state(work_dir.root()) {
+            render::render_throttle(&mut out, &state)?;
+        }
+    }
+    render::render_daemon_supervision(
+        &mut out,
+        daemon_status != DaemonStatus::NotRunning,
+        read_daemon_health(work_dir.root()).as_ref(),
+        read_supervisor_state(work_dir.root()).as_ref(),
+        chrono::Utc::now(),
+    )?;
+    if let Ok(config) = read_escalation_config(work_dir.root()) {
+        render::render_escalations(&mut out, &status_data.stages, &config, chrono::Utc::now())?;
+    }
     println!();
 
     // Progress bar with stage counts.
     render::render_progress(&mut out, &status_data.progress)?;
 
-    // Unified stage graph (replaces separate Active Stages, Worktrees, Merge sections).
+    // Unified stage graph (replaces separate Active Stages, Worktrees, Merge sections),
+    // or one progress bar per group for large plans.
     if stage_count > 0 {
         println!();
-        render::render_graph(&mut out, &status_data)?;
+        match group_by {
+            Some(by) => {
+                let stages: Vec<_> = status_data.stages.iter().map(Into::into).collect();
+                render::render_groups(&mut out, &render::group_stages(&stages, by))?;
+            }
+            None => render::render_graph(&mut out, &status_data)?,
+        }
     }
 
     // Merge status: only show if there are pending merges or conflicts.
@@ -120,6 +170,9 @@ fn execute_static(work_dir: &WorkDir, verbose: bool) -> Result<()> {
         render::render_merge_status(&mut out, &status_data.merge)?;
     }
 
+    // Conflict risk between unfinished stages that may run in parallel.
+    render::render_conflict_risk(&mut out, &data::collect_conflict_risks(work_dir))?;
+
     // Verbose mode: show detailed failure information.
     if verbose {
         render::render_attention(&mut out, &status_data.stages)?;
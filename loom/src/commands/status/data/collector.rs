@@ -1,5 +1,5 @@
 use anyhow::{Context, Result};
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use std::collections::HashSet;
 use std::fs;
 
@@ -9,9 +9,10 @@ use crate::models::constants::STALENESS_THRESHOLD_SECS;
 use crate::models::session::{Session, SessionStatus};
 use crate::models::stage::{Stage, StageStatus, StatusBucket};
 use crate::orchestrator::get_merge_point;
-use crate::orchestrator::monitor::heartbeat::{read_heartbeat, Heartbeat};
+use crate::orchestrator::monitor::heartbeat::{read_heartbeat, read_heartbeat_history, Heartbeat};
 use crate::orchestrator::monitor::soft_signals::{read_active, SoftSignal};
 use crate::parser::frontmatter::parse_from_markdown;
+use crate::plan::graph::conflict_risk::{unfinished_stage_risks, ConflictRisk};
 use crate::plan::parser::extract_plan_name;
 use crate::verify::transitions::list_all_stages;
 
@@ -35,6 +36,27 @@ fn read_heartbeat_for_stage(stage_id: &str, work_dir: &WorkDir) -> Option<Heartb
     }
 }
 
+/// Number of one-minute buckets in the activity sparkline
+const ACTIVITY_BUCKETS: usize = 10;
+
+/// Number of recent tools shown next to the sparkline
+const RECENT_TOOLS: usize = 5;
+
+/// Count heartbeats per minute over the last [`ACTIVITY_BUCKETS`] minutes.
+///
+/// Each heartbeat is one tool call, so the counts show how busy the agent
+/// has been; the last bucket is the current minute.
+fn activity_buckets(history: &[Heartbeat], now: DateTime<Utc>) -> Vec<u32> {
+    let mut buckets = vec![0; ACTIVITY_BUCKETS];
+    for hb in history {
+        let age_mins = now.signed_duration_since(hb.timestamp).num_minutes();
+        if (0..ACTIVITY_BUCKETS as i64).contains(&age_mins) {
+            buckets[ACTIVITY_BUCKETS - 1 - age_mins as usize] += 1;
+        }
+    }
+    buckets
+}
+
 /// Calculate activity status from session state and heartbeat staleness
 fn determine_activity_status(
     session: Option<&Session>,
@@ -103,6 +125,7 @@ fn load_session_from_file(path: &std::path::Path) -> Result<Session> {
 /// passes the set here so we avoid an O(N) scan per stage (P-5).
 fn build_stage_summary(
     stage: &Stage,
+    stages: &[Stage],
     sessions: &[Session],
     work_dir: &WorkDir,
     stuck_session_ids: &HashSet<String>,
@@ -140,18 +163,53 @@ fn build_stage_summary(
     let last_tool = heartbeat.as_ref().and_then(|hb| hb.last_tool.clone());
     let last_activity = heartbeat.as_ref().and_then(|hb| hb.activity.clone());
 
+    // Recent activity from the heartbeat history, for the current session only
+    let history: Vec<Heartbeat> = match session {
+        Some(s) if stage.status == StageStatus::Executing => {
+            read_heartbeat_history(work_dir.root(), &stage.id)
+                .into_iter()
+                .filter(|hb| hb.session_id == s.id)
+                .collect()
+        }
+        _ => Vec::new(),
+    };
+    let (activity_buckets, recent_tools) = if history.is_empty() {
+        (Vec::new(), Vec::new())
+    } else {
+        let tools: Vec<String> = history
+            .iter()
+            .filter_map(|hb| hb.last_tool.clone())
+            .collect();
+        let skip = tools.len().saturating_sub(RECENT_TOOLS);
+        (
+            activity_buckets(&history, Utc::now()),
+            tools.into_iter().skip(skip).collect(),
+        )
+    };
+
     // Look up in the pre-built stuck-session index (O(1)) instead of
     // re-reading the soft-signals file once per stage.
     let is_possibly_stuck = session
         .map(|s| stuck_session_ids.contains(&s.id))
         .unwrap_or(false);
 
+    // Required outputs only gate stages that have not started yet
+    let waiting_for_outputs = if matches!(
+        stage.status,
+        StageStatus::WaitingForDeps | StageStatus::Queued
+    ) {
+        stage.missing_required_outputs(stages)
+    } else {
+        Vec::new()
+    };
+
     StageSummary {
         id: stage.id.clone(),
         name: stage.name.clone(),
         status: stage.status.clone(),
         stage_type: stage.stage_type,
         dependencies: stage.dependencies.clone(),
+        parallel_group: stage.parallel_group.clone(),
         context_pct,
         elapsed_secs: Some(elapsed_secs),
         execution_secs: stage.execution_secs,
@@ -162,16 +220,25 @@ fn build_stage_summary(
         last_tool,
         last_activity,
         staleness_secs,
+        activity_buckets,
+        recent_tools,
         context_budget_pct: None, // TODO: Read from plan if needed
         review_reason: stage.review_reason.clone(),
+        waiting_prompt: stage.waiting_prompt.clone(),
+        waiting_since: stage.waiting_since,
         merged: stage.merged,
         held: stage.held,
+        held_by: stage.held_by.clone(),
+        assignee: stage.assignee.clone(),
         retry_count: stage.retry_count,
         max_retries: stage.max_retries,
         pid,
         session_alive,
         model: stage.effective_model().to_string(),
         is_possibly_stuck,
+        waiting_for_outputs,
+        expected_duration_secs: stage.expected_duration_secs,
+        overdue_secs: stage.overdue_secs(Utc::now()),
     }
 }
 
@@ -236,6 +303,15 @@ fn build_merge_summary_from_report(
     }
 }
 
+/// Predicted conflict risks between unfinished stages (best-effort: empty
+/// when the stages cannot be read).
+pub fn collect_conflict_risks(work_dir: &WorkDir) -> Vec<ConflictRisk> {
+    match (list_all_stages(work_dir.root()), work_dir.project_root()) {
+        (Ok(stages), Some(project_root)) => unfinished_stage_risks(&stages, project_root),
+        _ => Vec::new(),
+    }
+}
+
 /// Load the plan name from config.toml and the plan file (best-effort).
 fn load_plan_name(work_dir: &WorkDir) -> Option<String> {
     let config = load_config(work_dir.root()).ok()??;
@@ -268,7 +344,7 @@ pub fn collect_status_data(work_dir: &WorkDir) -> Result<StatusData> {
     // Build stage summaries
     let stage_summaries: Vec<StageSummary> = stages
         .iter()
-        .map(|stage| build_stage_summary(stage, &sessions, work_dir, &stuck_session_ids))
+        .map(|stage| build_stage_summary(stage, &stages, &sessions, work_dir, &stuck_session_ids))
         .collect();
 
     // Get merge point for merge report
@@ -323,6 +399,8 @@ mod tests {
             worktree: None,
             session: None,
             held: false,
+            held_by: None,
+            assignee: None,
             parent_stage: None,
             child_stages: vec![],
             created_at: Utc::now(),
@@ -362,12 +440,28 @@ mod tests {
             execution_mode: None,
             max_fix_attempts: None,
             review_reason: None,
+            waiting_prompt: None,
+            waiting_since: None,
             bug_fix: None,
             regression_test: None,
             model: None,
             reasoning_effort: None,
             is_possibly_stuck: false,
             ultracode: false,
+            pair: false,
+            pair_review: None,
+            worktree_setup: Vec::new(),
+            instructions: None,
+            prompt_prefix: None,
+            container: None,
+            keep_terminal: None,
+            expected_outputs: Vec::new(),
+            params: Vec::new(),
+            requires_outputs: Vec::new(),
+            new_files: Vec::new(),
+            expected_duration_secs: None,
+            acceptance_isolation: None,
+            skip_if_satisfied: false,
         }
     }
 
@@ -431,7 +525,7 @@ mod tests {
         session.context_limit = 200000;
 
         let empty_stuck: HashSet<String> = HashSet::new();
-        let summary = build_stage_summary(&stage, &[session], &work_dir, &empty_stuck);
+        let summary = build_stage_summary(&stage, &[], &[session], &work_dir, &empty_stuck);
 
         assert_eq!(summary.id, "test-stage");
         assert_eq!(summary.status, StageStatus::Executing);
@@ -453,7 +547,7 @@ mod tests {
         let stage = make_test_stage("test-stage", StageStatus::WaitingForDeps);
 
         let empty_stuck: HashSet<String> = HashSet::new();
-        let summary = build_stage_summary(&stage, &[], &work_dir, &empty_stuck);
+        let summary = build_stage_summary(&stage, &[], &[], &work_dir, &empty_stuck);
 
         assert_eq!(summary.id, "test-stage");
         assert_eq!(summary.status, StageStatus::WaitingForDeps);
@@ -571,7 +665,7 @@ status: executing"#;
             .collect();
 
         // Build a stage summary and verify the flag
-        let summary = build_stage_summary(&stage, &[session], &work_dir, &stuck_session_ids);
+        let summary = build_stage_summary(&stage, &[], &[session], &work_dir, &stuck_session_ids);
         assert!(
             summary.is_possibly_stuck,
             "StageSummary should report is_possibly_stuck"
@@ -0,0 +1,613 @@
+//! Scheduler explanation for `loom status --explain <stage-id>`.
+//!
+//! Re-derives, from `.work/` state, every condition that keeps the
+//! orchestrator from running (or finishing) one stage: unmet or unmerged
+//! dependencies, holds, review and dispute gates, the parallel session
+//! limit, strict conflict avoidance, crash-retry backoff, and merge queue or
+//! merge lock contention.
+
+use anyhow::{Context, Result};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::daemon::{read_daemon_config, DaemonConfig, DaemonServer, DaemonStatus};
+use crate::fs::work_dir::{load_config, WorkDir};
+use crate::git::branch::{is_ancestor_of, resolve_target_branch};
+use crate::git::merge::lock::MergeLock;
+use crate::models::failure::FailureType;
+use crate::models::stage::{Stage, StageStatus, StageType};
+use crate::orchestrator::code_review::latest_review;
+use crate::orchestrator::pause::{read_pause_state, PauseState};
+use crate::orchestrator::progressive_merge::queue::{blocking_stage, load_merge_queue};
+use crate::orchestrator::retry::{
+    backoff_remaining, calculate_backoff, should_auto_retry, RETRY_BACKOFF_BASE_SECS,
+    RETRY_BACKOFF_MAX_SECS,
+};
+use crate::orchestrator::throttle::{read_throttle_state, ThrottleState};
+use crate::plan::graph::conflict_risk::{assess_pair, RepoHistory, RiskCandidate, RiskLevel};
+use crate::plan::schema::ConflictAvoidance;
+use crate::verify::transitions::list_all_stages;
+
+/// Sessions the daemon runs in parallel when `--max-parallel` is not given.
+const DEFAULT_MAX_PARALLEL: usize = 4;
+
+/// Retries allowed when a stage sets no `max_retries`.
+const DEFAULT_MAX_RETRIES: u32 = 3;
+
+/// One condition keeping a stage from running or finishing.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Reason {
+    /// No daemon is running to schedule stages
+    DaemonStopped,
+    /// The daemon runs with `--manual` and never starts stages itself
+    ManualMode,
+    /// `loom pause` holds back every new stage
+    PlanPaused {
+        reason: Option<String>,
+    },
+    /// The daemon runs with `--until` and the stage is outside its scope
+    OutsideRunScope {
+        until: String,
+    },
+    /// A dependency has no stage file
+    DependencyMissing {
+        id: String,
+    },
+    /// A dependency has not completed
+    DependencyUnfinished {
+        id: String,
+        status: StageStatus,
+    },
+    /// A dependency completed but is not merged yet
+    DependencyUnmerged {
+        id: String,
+    },
+    /// A dependency is marked merged but its commit is not on the target
+    /// branch (or it recorded no commit)
+    DependencyPhantomMerge {
+        id: String,
+        target: String,
+    },
+    /// A merged dependency has not set an output listed in `requires_outputs`
+    OutputMissing {
+        stage: String,
+        key: String,
+    },
+    /// `loom stage hold`
+    Held,
+    /// Every parallel session slot is taken
+    ParallelLimit {
+        running: usize,
+        max: usize,
+        /// `--max-parallel` when load throttling lowered the limit
+        throttled_from: Option<usize>,
+    },
+    /// `conflict_avoidance: strict` defers the stage behind a running one
+    ConflictDeferral {
+        with: String,
+        score: u8,
+    },
+    /// A crash auto-retry fires once the backoff elapses
+    RetryBackoff {
+        remaining: Duration,
+        attempt: u32,
+        max: u32,
+    },
+    /// A crash auto-retry is due on the daemon's next poll
+    RetryDue,
+    /// The crash retry budget is spent
+    RetriesExhausted {
+        attempts: u32,
+    },
+    /// Blocked by a failure that is never retried automatically
+    Blocked {
+        failure: Option<FailureType>,
+        reason: Option<String>,
+    },
+    AwaitingHumanReview,
+    AwaitingAdjudication,
+    AwaitingInput,
+    /// Context ran out; a continuation session picks the stage up
+    NeedsHandoff,
+    AcceptanceFailed,
+    MergeConflict,
+    MergeBlocked,
+    /// Completed, but the merge into the merge point has not happened
+    NotMerged,
+    /// Completed; the merge waits on this code review stage
+    AwaitingCodeReview {
+        review: String,
+    },
+    /// Waiting in the merge queue behind another stage
+    MergeQueue {
+        behind: String,
+    },
+    /// Another process holds `.work/merge.lock`
+    MergeLock {
+        pid: u32,
+    },
+    Skipped {
+        reason: Option<String>,
+    },
+}
+
+/// Why one stage is (or is not) running.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Explanation {
+    pub stage_id: String,
+    pub status: StageStatus,
+    pub session: Option<String>,
+    /// Empty when nothing keeps the stage from running or finishing
+    pub reasons: Vec<Reason>,
+}
+
+/// Scheduler state outside the stage files.
+#[derive(Debug, Default)]
+pub struct SchedulerContext {
+    pub daemon_running: bool,
+    /// Configuration of the running daemon
+    pub daemon_config: Option<DaemonConfig>,
+    /// Load-based session limit of the running daemon
+    pub throttle: Option<ThrottleState>,
+    /// `loom pause` state, when the plan is paused
+    pub pause: Option<PauseState>,
+    /// Repository history for strict conflict avoidance (`None` = off)
+    pub conflict_history: Option<RepoHistory>,
+    /// Repository and branch dependencies must be merged into
+    pub target: Option<(PathBuf, String)>,
+    /// Stage that must merge before the explained one
+    pub merge_queue_blocker: Option<String>,
+    /// PID of the process holding the merge lock
+    pub merge_lock_holder: Option<u32>,
+}
+
+/// Explain the scheduling state of `stage_id`.
+pub fn collect_explanation(work_dir: &WorkDir, stage_id: &str) -> Result<Explanation> {
+    let root = work_dir.root();
+    let stages = list_all_stages(root)?;
+    let stage = stages
+        .iter()
+        .find(|s| s.id == stage_id)
+        .with_context(|| format!("Stage '{stage_id}' not found"))?;
+
+    let ctx = scheduler_context(work_dir, stage_id);
+    Ok(explain(stage, &stages, &ctx))
+}
+
+fn scheduler_context(work_dir: &WorkDir, stage_id: &str) -> SchedulerContext {
+    let root = work_dir.root();
+    let daemon_running = DaemonServer::check_status(root) != DaemonStatus::NotRunning;
+    let config = load_config(root).ok().flatten();
+    let project_root = work_dir.project_root().map(Path::to_path_buf);
+
+    let conflict_history = config
+        .as_ref()
+        .filter(|c| c.conflict_avoidance() == ConflictAvoidance::Strict)
+        .zip(project_root.as_deref())
+        .map(|(_, repo)| RepoHistory::load(repo));
+    let target = project_root.map(|repo| {
+        let base = config.as_ref().and_then(|c| c.base_branch());
+        let branch = resolve_target_branch(&base, &repo);
+        (repo, branch)
+    });
+    let merge_queue_blocker = load_merge_queue(root)
+        .ok()
+        .filter(|queue| queue.waiting.iter().any(|e| e.stage_id == stage_id))
+        .and_then(|queue| blocking_stage(&queue, stage_id, root));
+
+    SchedulerContext {
+        daemon_running,
+        daemon_config: daemon_running.then(|| read_daemon_config(root)).flatten(),
+        throttle: daemon_running.then(|| read_throttle_state(root)).flatten(),
+        pause: read_pause_state(root),
+        conflict_history,
+        target,
+        merge_queue_blocker,
+        merge_lock_holder: MergeLock::holder(root),
+    }
+}
+
+/// Derive the reasons `stage` is not running from the stage files and `ctx`.
+pub fn explain(stage: &Stage, stages: &[Stage], ctx: &SchedulerContext) -> Explanation {
+    let reasons = match stage.status {
+        StageStatus::WaitingForDeps | StageStatus::Queued => start_reasons(stage, stages, ctx),
+        StageStatus::Executing => merge_reasons(ctx),
+        StageStatus::Blocked => retry_reasons(stage, ctx),
+        StageStatus::Completed if stage.merged => Vec::new(),
+        StageStatus::Completed => match latest_review(stage, stages)
+            .filter(|review| review.status != StageStatus::Completed)
+        {
+            Some(review) => vec![Reason::AwaitingCodeReview {
+                review: review.id.clone(),
+            }],
+            None => {
+                let mut reasons = vec![Reason::NotMerged];
+                reasons.extend(merge_reasons(ctx));
+                reasons
+            }
+        },
+        StageStatus::NeedsHumanReview => vec![Reason::AwaitingHumanReview],
+        StageStatus::NeedsAdjudication => vec![Reason::AwaitingAdjudication],
+        StageStatus::WaitingForInput => vec![Reason::AwaitingInput],
+        StageStatus::NeedsHandoff => vec![Reason::NeedsHandoff],
+        StageStatus::CompletedWithFailures => vec![Reason::AcceptanceFailed],
+        StageStatus::MergeConflict => vec![Reason::MergeConflict],
+        StageStatus::MergeBlocked => vec![Reason::MergeBlocked],
+        StageStatus::Skipped => vec![Reason::Skipped {
+            reason: stage.close_reason.clone(),
+        }],
+    };
+
+    Explanation {
+        stage_id: stage.id.clone(),
+        status: stage.status.clone(),
+        session: stage.session.clone(),
+        reasons,
+    }
+}
+
+/// Reasons a waiting or queued stage has not been started.
+fn start_reasons(stage: &Stage, stages: &[Stage], ctx: &SchedulerContext) -> Vec<Reason> {
+    let by_id: HashMap<&str, &Stage> = stages.iter().map(|s| (s.id.as_str(), s)).collect();
+    let mut reasons = Vec::new();
+
+    if !ctx.daemon_running {
+        reasons.push(Reason::DaemonStopped);
+    }
+    if let Some(pause) = &ctx.pause {
+        reasons.push(Reason::PlanPaused {
+            reason: pause.reason.clone(),
+        });
+    }
+    if let Some(config) = &ctx.daemon_config {
+        if config.manual_mode {
+            reasons.push(Reason::ManualMode);
+        }
+        if let Some(until) = &config.until {
+            if !run_scope(until, &by_id).contains(stage.id.as_str()) {
+                reasons.push(Reason::OutsideRunScope {
+                    until: until.clone(),
+                });
+            }
+        }
+    }
+
+    let mut unmet = HashSet::new();
+    for dep_id in &stage.dependencies {
+        if let Some(reason) = dependency_reason(dep_id, &by_id, ctx) {
+            reasons.push(reason);
+            unmet.insert(dep_id.as_str());
+        }
+    }
+    for (producer, key) in stage.missing_required_outputs(stages) {
+        if !unmet.contains(producer.as_str()) {
+            reasons.push(Reason::OutputMissing {
+                stage: producer,
+                key,
+            });
+        }
+    }
+
+    if stage.held {
+        reasons.push(Reason::Held);
+    }
+
+    let running: Vec<&Stage> = stages
+        .iter()
+        .filter(|s| s.status == StageStatus::Executing)
+        .collect();
+    if stage.status == StageStatus::Queued {
+        let configured = ctx
+            .daemon_config
+            .as_ref()
+            .and_then(|c| c.max_parallel)
+            .unwrap_or(DEFAULT_MAX_PARALLEL);
+        let throttled = ctx.throttle.as_ref().filter(|t| t.is_throttled());
+        let max = throttled.map_or(configured, |t| t.effective_max);
+        if running.len() >= max {
+            reasons.push(Reason::ParallelLimit {
+                running: running.len(),
+                max,
+                throttled_from: throttled.map(|t| t.configured_max),
+            });
+        }
+        if let Some(history) = &ctx.conflict_history {
+            let candidate = RiskCandidate::from(stage);
+            let deferral = running
+                .iter()
+                .filter_map(|other| assess_pair(&candidate, &RiskCandidate::from(*other), history))
+                .find(|risk| risk.level() == RiskLevel::High);
+            if let Some(risk) = deferral {
+                reasons.push(Reason::ConflictDeferral {
+                    with: risk.second,
+                    score: risk.score,
+                });
+            }
+        }
+    }
+
+    reasons
+}
+
+/// Why `dep_id` does not satisfy its dependents yet, if it doesn't.
+fn dependency_reason(
+    dep_id: &str,
+    by_id: &HashMap<&str, &Stage>,
+    ctx: &SchedulerContext,
+) -> Option<Reason> {
+    let id = dep_id.to_string();
+    let Some(dep) = by_id.get(dep_id) else {
+        return Some(Reason::DependencyMissing { id });
+    };
+    if dep.status != StageStatus::Completed {
+        return Some(Reason::DependencyUnfinished {
+            id,
+            status: dep.status.clone(),
+        });
+    }
+    if !dep.merged {
+        return Some(Reason::DependencyUnmerged { id });
+    }
+    // Knowledge stages have no branch; status and merged flag suffice.
+    if dep.stage_type == StageType::Knowledge {
+        return None;
+    }
+    let (repo_root, target) = ctx.target.as_ref()?;
+    let on_target = match &dep.completed_commit {
+        Some(commit) => is_ancestor_of(commit, target, repo_root).unwrap_or(true),
+        None => false,
+    };
+    (!on_target).then(|| Reason::DependencyPhantomMerge {
+        id,
+        target: target.clone(),
+    })
+}
+
+/// The `--until` target and its transitive dependencies.
+fn run_scope<'a>(until: &'a str, by_id: &HashMap<&str, &'a Stage>) -> HashSet<&'a str> {
+    let mut scope = HashSet::new();
+    let mut pending = vec![until];
+    while let Some(id) = pending.pop() {
+        if !scope.insert(id) {
+            continue;
+        }
+        if let Some(stage) = by_id.get(id) {
+            pending.extend(stage.dependencies.iter().map(String::as_str));
+        }
+    }
+    scope
+}
+
+/// Reasons a Blocked stage stays blocked.
+fn retry_reasons(stage: &Stage, ctx: &SchedulerContext) -> Vec<Reason> {
+    let max = stage.max_retries.unwrap_or(DEFAULT_MAX_RETRIES);
+    let failure = stage
+        .failure_info
+        .as_ref()
+        .map(|info| info.failure_type.clone());
+    let retryable = matches!(
+        failure,
+        Some(FailureType::SessionCrash | FailureType::Timeout)
+    );
+
+    let mut reasons = Vec::new();
+    match failure {
+        Some(ref failure_type) if should_auto_retry(failure_type, stage.retry_count, max) => {
+            if !ctx.daemon_running {
+                reasons.push(Reason::DaemonStopped);
+            }
+            let backoff = calculate_backoff(
+                stage.retry_count,
+                RETRY_BACKOFF_BASE_SECS,
+                RETRY_BACKOFF_MAX_SECS,
+            );
+            let remaining = backoff_remaining(stage.last_failure_at, backoff);
+            reasons.push(if remaining.is_zero() {
+                Reason::RetryDue
+            } else {
+                Reason::RetryBackoff {
+                    remaining,
+                    attempt: stage.retry_count + 1,
+                    max,
+                }
+            });
+        }
+        _ if retryable => reasons.push(Reason::RetriesExhausted {
+            attempts: stage.retry_count,
+        }),
+        _ => reasons.push(Reason::Blocked {
+            failure,
+            reason: stage.close_reason.clone(),
+        }),
+    }
+    reasons
+}
+
+/// Reasons a stage that is done executing has not merged yet.
+fn merge_reasons(ctx: &SchedulerContext) -> Vec<Reason> {
+    if let Some(behind) = &ctx.merge_queue_blocker {
+        return vec![Reason::MergeQueue {
+            behind: behind.clone(),
+        }];
+    }
+    ctx.merge_lock_holder
+        .map(|pid| vec![Reason::MergeLock { pid }])
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::failure::FailureInfo;
+    use chrono::Utc;
+
+    fn stage(id: &str, status: StageStatus, deps: &[&str]) -> Stage {
+        let mut stage = Stage::new(id.to_string(), None);
+        stage.id = id.to_string();
+        stage.status = status;
+        stage.dependencies = deps.iter().map(|d| d.to_string()).collect();
+        stage
+    }
+
+    fn running_daemon(max_parallel: Option<usize>) -> SchedulerContext {
+        SchedulerContext {
+            daemon_running: true,
+            daemon_config: Some(DaemonConfig {
+                max_parallel,
+                ..DaemonConfig::default()
+            }),
+            ..SchedulerContext::default()
+        }
+    }
+
+    #[test]
+    fn test_explain_waiting_stage_lists_unmet_dependencies() {
+        let mut done = stage("done", StageStatus::Completed, &[]);
+        done.merged = true;
+        let unmerged = stage("unmerged", StageStatus::Completed, &[]);
+        let running = stage("running", StageStatus::Executing, &[]);
+        let mut waiting = stage(
+            "waiting",
+            StageStatus::WaitingForDeps,
+            &["done", "unmerged", "running", "gone"],
+        );
+        waiting.held = true;
+        let stages = vec![done, unmerged, running, waiting.clone()];
+
+        let explanation = explain(&waiting, &stages, &SchedulerContext::default());
+        assert_eq!(
+            explanation.reasons,
+            vec![
+                Reason::DaemonStopped,
+                Reason::DependencyUnmerged {
+                    id: "unmerged".to_string()
+                },
+                Reason::DependencyUnfinished {
+                    id: "running".to_string(),
+                    status: StageStatus::Executing
+                },
+                Reason::DependencyMissing {
+                    id: "gone".to_string()
+                },
+                Reason::Held,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_explain_waiting_for_required_output() {
+        let mut producer = stage("stage-x", StageStatus::Completed, &[]);
+        producer.merged = true;
+        let unfinished = stage("stage-z", StageStatus::Executing, &[]);
+        let mut consumer = stage("consumer", StageStatus::Queued, &["stage-x", "stage-z"]);
+        consumer.requires_outputs = vec![
+            "stage-x.schema_version".to_string(),
+            "stage-z.api_port".to_string(),
+        ];
+        let stages = vec![producer, unfinished, consumer.clone()];
+
+        // stage-z's missing output is implied by it not having finished
+        let explanation = explain(&consumer, &stages, &running_daemon(None));
+        assert_eq!(
+            explanation.reasons,
+            vec![
+                Reason::DependencyUnfinished {
+                    id: "stage-z".to_string(),
+                    status: StageStatus::Executing
+                },
+                Reason::OutputMissing {
+                    stage: "stage-x".to_string(),
+                    key: "schema_version".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_explain_queued_stage_at_parallel_limit() {
+        let stages = vec![
+            stage("a", StageStatus::Executing, &[]),
+            stage("b", StageStatus::Executing, &[]),
+            stage("c", StageStatus::Queued, &[]),
+        ];
+
+        let saturated = explain(&stages[2], &stages, &running_daemon(Some(2)));
+        assert_eq!(
+            saturated.reasons,
+            vec![Reason::ParallelLimit {
+                running: 2,
+                max: 2,
+                throttled_from: None
+            }]
+        );
+        let free = explain(&stages[2], &stages, &running_daemon(None));
+        assert!(free.reasons.is_empty());
+
+        let mut ctx = running_daemon(None);
+        ctx.daemon_config.as_mut().unwrap().until = Some("a".to_string());
+        assert_eq!(
+            explain(&stages[2], &stages, &ctx).reasons,
+            vec![Reason::OutsideRunScope {
+                until: "a".to_string()
+            }]
+        );
+
+        let mut ctx = running_daemon(None);
+        ctx.pause = Some(PauseState::new(Some("CI is down".to_string())));
+        assert_eq!(
+            explain(&stages[2], &stages, &ctx).reasons,
+            vec![Reason::PlanPaused {
+                reason: Some("CI is down".to_string())
+            }]
+        );
+    }
+
+    #[test]
+    fn test_explain_blocked_stage_retry_state() {
+        let mut crashed = stage("crashed", StageStatus::Blocked, &[]);
+        crashed.retry_count = 1;
+        crashed.last_failure_at = Some(Utc::now());
+        crashed.failure_info = Some(FailureInfo {
+            failure_type: FailureType::SessionCrash,
+            detected_at: Utc::now(),
+            evidence: Vec::new(),
+        });
+        let ctx = running_daemon(None);
+
+        let reasons = explain(&crashed, &[], &ctx).reasons;
+        assert!(
+            matches!(
+                reasons.as_slice(),
+                [Reason::RetryBackoff {
+                    attempt: 2,
+                    max: 3,
+                    remaining
+                }] if *remaining > Duration::from_secs(25)
+            ),
+            "{reasons:?}"
+        );
+
+        crashed.retry_count = 3;
+        assert_eq!(
+            explain(&crashed, &[], &ctx).reasons,
+            vec![Reason::RetriesExhausted { attempts: 3 }]
+        );
+    }
+
+    #[test]
+    fn test_explain_completed_stage_awaiting_code_review() {
+        let api = stage("api", StageStatus::Completed, &[]);
+        let mut review = stage("api-review", StageStatus::Executing, &[]);
+        review.stage_type = StageType::CodeReview;
+        review.parent_stage = Some("api".to_string());
+        let stages = vec![api.clone(), review];
+
+        assert_eq!(
+            explain(&api, &stages, &running_daemon(None)).reasons,
+            vec![Reason::AwaitingCodeReview {
+                review: "api-review".to_string()
+            }]
+        );
+    }
+}
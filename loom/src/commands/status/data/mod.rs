@@ -1,7 +1,9 @@
 mod collector;
+pub mod explain;
 
-pub use collector::{collect_status_data, load_all_sessions};
+pub use collector::{collect_conflict_risks, collect_status_data, load_all_sessions};
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 // Re-export types that consumers will need
@@ -64,6 +66,9 @@ pub struct StageSummary {
     #[serde(default)]
     pub stage_type: StageType,
     pub dependencies: Vec<String>,
+    /// Plan `parallel_group`, for the grouped view
+    #[serde(default)]
+    pub parallel_group: Option<String>,
     pub context_pct: Option<f32>,
     pub elapsed_secs: Option<i64>,
     /// Accumulated execution time (excludes wait/backoff time)
@@ -79,14 +84,32 @@ pub struct StageSummary {
     pub last_activity: Option<String>,
     /// Seconds since last heartbeat (for staleness detection)
     pub staleness_secs: Option<u64>,
+    /// Tool calls per minute over the recent activity window, oldest first
+    #[serde(default)]
+    pub activity_buckets: Vec<u32>,
+    /// Most recent tools used (from heartbeat history), oldest first
+    #[serde(default)]
+    pub recent_tools: Vec<String>,
     /// Stage-specific context budget percentage (if set in plan)
     pub context_budget_pct: Option<f32>,
     /// Reason the stage was flagged for human review
     pub review_reason: Option<String>,
+    /// Prompt the session is blocked on (auto-detected waiting)
+    #[serde(default)]
+    pub waiting_prompt: Option<String>,
+    /// When the stage entered WaitingForInput
+    #[serde(default)]
+    pub waiting_since: Option<DateTime<Utc>>,
     /// Whether stage changes have been merged to the merge point
     pub merged: bool,
     /// Whether the stage is held
     pub held: bool,
+    /// Waiting stage whose escalation held this stage
+    #[serde(default)]
+    pub held_by: Option<String>,
+    /// Person the stage is assigned to
+    #[serde(default)]
+    pub assignee: Option<String>,
     /// Current retry count
     pub retry_count: u32,
     /// Maximum retries allowed
@@ -99,6 +122,15 @@ pub struct StageSummary {
     pub model: String,
     /// Whether the monitor has flagged this stage's session as possibly stuck
     pub is_possibly_stuck: bool,
+    /// Required dependency outputs not set yet, as `(stage id, output key)`
+    #[serde(default)]
+    pub waiting_for_outputs: Vec<(String, String)>,
+    /// Expected execution time from the plan's `expected_duration`
+    #[serde(default)]
+    pub expected_duration_secs: Option<u64>,
+    /// Execution time, set while the stage runs well past its expected duration
+    #[serde(default)]
+    pub overdue_secs: Option<i64>,
 }
 
 /// Session display data (test-only)
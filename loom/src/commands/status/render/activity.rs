@@ -27,3 +27,34 @@ pub fn render_staleness_warning(secs: u64) -> Option<String> {
         None
     }
 }
+
+/// Render per-minute tool-call counts as a sparkline, one character per
+/// minute. Minutes without any tool call show as `·` so a spinning agent
+/// (steady bars) is easy to tell from an idle one.
+pub fn render_sparkline(buckets: &[u32]) -> String {
+    const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let max = buckets.iter().copied().max().unwrap_or(0);
+    buckets
+        .iter()
+        .map(|&count| {
+            if count == 0 {
+                '·'
+            } else {
+                BARS[(count as usize * BARS.len() - 1) / max as usize]
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_sparkline_scales_to_busiest_minute() {
+        assert_eq!(render_sparkline(&[0, 1, 4, 8]), "·▁▄█");
+        assert_eq!(render_sparkline(&[0, 0]), "··");
+        assert_eq!(render_sparkline(&[3, 3]), "██");
+        assert_eq!(render_sparkline(&[]), "");
+    }
+}
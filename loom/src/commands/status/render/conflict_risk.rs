@@ -0,0 +1,88 @@
+//! Conflict risk widget
+
+use colored::Colorize;
+use std::io::Write;
+
+use crate::plan::graph::conflict_risk::{ConflictRisk, RiskLevel};
+
+/// Shared files listed per pair before the rest are summarized.
+const MAX_SHARED_FILES: usize = 3;
+
+/// Render predicted conflict risks between stages that may run in parallel
+pub fn render_conflict_risk<W: Write>(w: &mut W, risks: &[ConflictRisk]) -> std::io::Result<()> {
+    if risks.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(w)?;
+    writeln!(w, "{}", "Conflict Risk".bold())?;
+
+    for risk in risks {
+        let level = match risk.level() {
+            RiskLevel::High => risk.level().to_string().red(),
+            RiskLevel::Medium => risk.level().to_string().yellow(),
+            RiskLevel::Low => risk.level().to_string().dimmed(),
+        };
+        writeln!(
+            w,
+            "  {} {} ↔ {}  {:>3} {}",
+            "⚠".yellow(),
+            risk.first,
+            risk.second,
+            risk.score,
+            level
+        )?;
+        if !risk.shared_files.is_empty() {
+            let mut files = risk
+                .shared_files
+                .iter()
+                .take(MAX_SHARED_FILES)
+                .cloned()
+                .collect::<Vec<_>>()
+                .join(", ");
+            let rest = risk.shared_files.len().saturating_sub(MAX_SHARED_FILES);
+            if rest > 0 {
+                files.push_str(&format!(" (+{rest} more)"));
+            }
+            writeln!(w, "      {}", files.dimmed())?;
+        }
+    }
+
+    writeln!(
+        w,
+        "  {}",
+        "Add a dependency, or set conflict_avoidance: strict to serialize high-risk pairs".dimmed()
+    )?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_conflict_risk() {
+        colored::control::set_override(false);
+        let risks = vec![ConflictRisk {
+            first: "api".to_string(),
+            second: "db".to_string(),
+            score: 62,
+            shared_files: ["a.rs", "b.rs", "c.rs", "d.rs"]
+                .iter()
+                .map(|f| f.to_string())
+                .collect(),
+            co_changes: 2,
+        }];
+        let mut out = Vec::new();
+        render_conflict_risk(&mut out, &risks).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("Conflict Risk"));
+        assert!(out.contains("api ↔ db   62 high"), "{out}");
+        assert!(out.contains("a.rs, b.rs, c.rs (+1 more)"), "{out}");
+
+        let mut empty = Vec::new();
+        render_conflict_risk(&mut empty, &[]).unwrap();
+        assert!(empty.is_empty());
+    }
+}
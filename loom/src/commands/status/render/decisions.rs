@@ -0,0 +1,81 @@
+//! Recent scheduler decisions (`loom status --decisions`)
+
+use chrono::Local;
+use colored::Colorize;
+use std::io::Write;
+
+use crate::orchestrator::decisions::{Decision, DecisionKind};
+
+/// Render scheduler decisions, oldest first, one per line
+pub fn render_decisions<W: Write>(w: &mut W, decisions: &[Decision]) -> std::io::Result<()> {
+    if decisions.is_empty() {
+        writeln!(
+            w,
+            "{}",
+            "No scheduler decisions recorded yet (written by `loom run`)".dimmed()
+        )?;
+        return Ok(());
+    }
+    let width = decisions
+        .iter()
+        .map(|d| d.stage_id.len())
+        .max()
+        .unwrap_or(0);
+    for decision in decisions {
+        let kind = format!("{:<8}", decision.kind.to_string());
+        let kind = match decision.kind {
+            DecisionKind::Ready => kind.cyan(),
+            DecisionKind::Deferred => kind.yellow(),
+            DecisionKind::Started => kind.green(),
+            DecisionKind::RetryScheduled => kind.red(),
+        };
+        let until = decision
+            .until
+            .map(|t| format!(" until {}", t.with_timezone(&Local).format("%H:%M:%S")))
+            .unwrap_or_default();
+        writeln!(
+            w,
+            "{} {:<width$}  {kind} {}{until}",
+            decision
+                .at
+                .with_timezone(&Local)
+                .format("%m-%d %H:%M:%S")
+                .to_string()
+                .dimmed(),
+            decision.stage_id,
+            decision.detail,
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_decisions() {
+        colored::control::set_override(false);
+        let decisions = vec![
+            Decision::new(
+                "api",
+                DecisionKind::Deferred,
+                "parallel limit reached (max 2)",
+            ),
+            Decision::new("ui", DecisionKind::RetryScheduled, "crash (attempt 1/3)")
+                .until(chrono::Utc::now()),
+        ];
+        let mut out = Vec::new();
+        render_decisions(&mut out, &decisions).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert!(lines[0].ends_with("api  deferred parallel limit reached (max 2)"));
+        assert!(lines[1].contains("ui   retry    crash (attempt 1/3) until "));
+
+        let mut out = Vec::new();
+        render_decisions(&mut out, &[]).unwrap();
+        assert!(String::from_utf8(out)
+            .unwrap()
+            .contains("No scheduler decisions"));
+    }
+}
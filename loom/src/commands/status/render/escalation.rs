@@ -0,0 +1,123 @@
+//! Escalated waiting-for-input indicator
+
+use chrono::{DateTime, Utc};
+use colored::Colorize;
+use std::io::Write;
+
+use crate::commands::status::data::StageSummary;
+use crate::models::stage::StageStatus;
+use crate::orchestrator::escalation::EscalationConfig;
+use crate::utils::format_elapsed;
+
+/// Render one line per stage that has waited for input past the
+/// `[escalation]` threshold, with the dependents held on it.
+pub fn render_escalations<W: Write>(
+    w: &mut W,
+    stages: &[StageSummary],
+    config: &EscalationConfig,
+    now: DateTime<Utc>,
+) -> std::io::Result<()> {
+    for stage in stages {
+        let Some(since) = stage.waiting_since else {
+            continue;
+        };
+        if stage.status != StageStatus::WaitingForInput || !config.is_escalated(since, now) {
+            continue;
+        }
+        let held = stages
+            .iter()
+            .filter(|s| s.held && s.held_by.as_deref() == Some(stage.id.as_str()))
+            .count();
+        let held = match held {
+            0 => String::new(),
+            1 => ", 1 dependent held".to_string(),
+            n => format!(", {n} dependents held"),
+        };
+        writeln!(
+            w,
+            "   {} {}        {}",
+            "⏰".magenta(),
+            format!(
+                "'{}' waiting for input for {}{held}",
+                stage.id,
+                format_elapsed((now - since).num_seconds().max(0))
+            )
+            .magenta(),
+            format!("loom sessions focus --stage {}", stage.id).dimmed()
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::status::data::{ActivityStatus, StageType};
+
+    fn summary(id: &str, status: StageStatus) -> StageSummary {
+        StageSummary {
+            id: id.to_string(),
+            name: id.to_string(),
+            status,
+            stage_type: StageType::Standard,
+            dependencies: vec![],
+            parallel_group: None,
+            context_pct: None,
+            elapsed_secs: None,
+            execution_secs: None,
+            base_branch: None,
+            base_merged_from: vec![],
+            failure_info: None,
+            activity_status: ActivityStatus::default(),
+            last_tool: None,
+            last_activity: None,
+            staleness_secs: None,
+            activity_buckets: Vec::new(),
+            recent_tools: Vec::new(),
+            context_budget_pct: None,
+            review_reason: None,
+            waiting_prompt: None,
+            waiting_since: None,
+            merged: false,
+            held: false,
+            held_by: None,
+            assignee: None,
+            retry_count: 0,
+            max_retries: None,
+            pid: None,
+            session_alive: false,
+            model: "opus".to_string(),
+            is_possibly_stuck: false,
+            waiting_for_outputs: Vec::new(),
+            expected_duration_secs: None,
+            overdue_secs: None,
+        }
+    }
+
+    #[test]
+    fn test_render_escalations() {
+        colored::control::set_override(false);
+        let now = Utc::now();
+        let config = EscalationConfig {
+            after_minutes: Some(30),
+            ..Default::default()
+        };
+        let mut api = summary("api", StageStatus::WaitingForInput);
+        api.waiting_since = Some(now - chrono::Duration::minutes(45));
+        let mut cli = summary("cli", StageStatus::WaitingForInput);
+        cli.waiting_since = Some(now - chrono::Duration::minutes(5));
+        let mut web = summary("web", StageStatus::WaitingForDeps);
+        web.held = true;
+        web.held_by = Some("api".to_string());
+
+        let mut out = Vec::new();
+        render_escalations(&mut out, &[api, cli, web], &config, now).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(
+            out.contains("'api' waiting for input for 45m0s, 1 dependent held"),
+            "{out}"
+        );
+        assert!(out.contains("loom sessions focus --stage api"));
+        assert!(!out.contains("'cli'"));
+    }
+}
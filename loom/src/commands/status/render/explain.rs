@@ -0,0 +1,198 @@
+//! Scheduler explanation for a single stage
+
+use colored::Colorize;
+use std::io::Write;
+
+use crate::commands::status::data::explain::{Explanation, Reason};
+use crate::models::stage::StageStatus;
+
+/// Render why a stage is not running, one line per reason with a hint below
+pub fn render_explanation<W: Write>(w: &mut W, explanation: &Explanation) -> std::io::Result<()> {
+    let id = &explanation.stage_id;
+    let status = &explanation.status;
+
+    if explanation.reasons.is_empty() {
+        let summary = match status {
+            StageStatus::Executing => match &explanation.session {
+                Some(session) => format!("is running in session {session}"),
+                None => "is running".to_string(),
+            },
+            StageStatus::Completed => "is complete and merged".to_string(),
+            _ => "has nothing blocking it; the daemon starts it on its next poll".to_string(),
+        };
+        writeln!(w, "{} Stage '{id}' ({status}) {summary}", "✓".green())?;
+        return Ok(());
+    }
+
+    writeln!(w, "Stage '{}' ({status}) is waiting on:", id.bold())?;
+    for reason in &explanation.reasons {
+        writeln!(w, "  {} {}", "✗".red(), describe(reason))?;
+        if let Some(hint) = hint(reason, id) {
+            writeln!(w, "    {}", format!("→ {hint}").dimmed())?;
+        }
+    }
+    Ok(())
+}
+
+fn describe(reason: &Reason) -> String {
+    match reason {
+        Reason::DaemonStopped => "the daemon is not running".to_string(),
+        Reason::ManualMode => "the daemon runs in manual mode (--manual)".to_string(),
+        Reason::PlanPaused { reason } => match reason {
+            Some(reason) => format!("the plan is paused: {reason}"),
+            None => "the plan is paused".to_string(),
+        },
+        Reason::OutsideRunScope { until } => {
+            format!("outside the run scope of --until {until}")
+        }
+        Reason::DependencyMissing { id } => format!("dependency '{id}' has no stage file"),
+        Reason::DependencyUnfinished { id, status } => {
+            format!("dependency '{id}' is {status}")
+        }
+        Reason::DependencyUnmerged { id } => {
+            format!("dependency '{id}' completed but is not merged")
+        }
+        Reason::DependencyPhantomMerge { id, target } => {
+            format!("dependency '{id}' is marked merged but its commit is not on {target}")
+        }
+        Reason::OutputMissing { stage, key } => {
+            format!("waiting for output {key} from {stage}")
+        }
+        Reason::Held => "the stage is held".to_string(),
+        Reason::ParallelLimit {
+            running,
+            max,
+            throttled_from,
+        } => match throttled_from {
+            Some(configured) => format!(
+                "all parallel slots are taken ({running}/{max} stages executing; \
+                 throttled from {configured} by system load)"
+            ),
+            None => format!("all parallel slots are taken ({running}/{max} stages executing)"),
+        },
+        Reason::ConflictDeferral { with, score } => format!(
+            "deferred behind running stage '{with}': high conflict risk ({score}, \
+             conflict_avoidance: strict)"
+        ),
+        Reason::RetryBackoff {
+            remaining,
+            attempt,
+            max,
+        } => format!(
+            "crash retry {attempt}/{max} starts in {}s (backoff)",
+            remaining.as_secs()
+        ),
+        Reason::RetryDue => "crash retry is due on the daemon's next poll".to_string(),
+        Reason::RetriesExhausted { attempts } => {
+            format!("crash retries exhausted after {attempts} attempts")
+        }
+        Reason::Blocked { failure, reason } => {
+            let cause = reason
+                .clone()
+                .or_else(|| failure.as_ref().map(ToString::to_string))
+                .unwrap_or_else(|| "no reason recorded".to_string());
+            format!("blocked: {cause}")
+        }
+        Reason::AwaitingHumanReview => "awaiting human review".to_string(),
+        Reason::AwaitingAdjudication => "a disputed criterion awaits a verdict".to_string(),
+        Reason::AwaitingInput => "the session is waiting for input".to_string(),
+        Reason::NeedsHandoff => "context exhausted; awaiting a continuation session".to_string(),
+        Reason::AcceptanceFailed => "acceptance criteria failed".to_string(),
+        Reason::MergeConflict => "merge conflicts need resolving".to_string(),
+        Reason::MergeBlocked => "the merge failed".to_string(),
+        Reason::NotMerged => "completed but not merged".to_string(),
+        Reason::AwaitingCodeReview { review } => {
+            format!("completed; the merge waits on code review '{review}'")
+        }
+        Reason::MergeQueue { behind } => format!("waiting in the merge queue behind '{behind}'"),
+        Reason::MergeLock { pid } => format!("the merge lock is held by process {pid}"),
+        Reason::Skipped { reason } => match reason {
+            Some(reason) => format!("skipped: {reason}"),
+            None => "skipped".to_string(),
+        },
+    }
+}
+
+fn hint(reason: &Reason, id: &str) -> Option<String> {
+    let hint = match reason {
+        Reason::DaemonStopped => "loom run".to_string(),
+        Reason::ManualMode => format!("loom stage claim {id}, or restart without --manual"),
+        Reason::PlanPaused { .. } => "loom resume".to_string(),
+        Reason::OutsideRunScope { .. } => "loom run without --until after this run".to_string(),
+        Reason::DependencyMissing { .. } => "loom repair".to_string(),
+        Reason::DependencyUnfinished { id: dep, .. } | Reason::DependencyUnmerged { id: dep } => {
+            format!("loom status --explain {dep}")
+        }
+        Reason::DependencyPhantomMerge { .. } => "loom repair".to_string(),
+        Reason::OutputMissing { stage, key } => {
+            format!("loom stage output set {stage} {key} <value>")
+        }
+        Reason::Held => format!("loom stage release {id}"),
+        Reason::ParallelLimit {
+            throttled_from: Some(_),
+            ..
+        } => "starts once system load drops below the [throttle] thresholds".to_string(),
+        Reason::ParallelLimit { .. } => "restart with a higher --max-parallel".to_string(),
+        Reason::ConflictDeferral { .. } => {
+            "starts once that stage finishes; or set conflict_avoidance: warn".to_string()
+        }
+        Reason::RetriesExhausted { .. } | Reason::Blocked { .. } => {
+            format!("loom stage retry {id}")
+        }
+        Reason::AwaitingHumanReview => format!("loom stage human-review {id} --approve"),
+        Reason::AcceptanceFailed => format!("loom stage retry {id}"),
+        Reason::MergeConflict => format!("resolve, then loom stage merge {id} --resolved"),
+        Reason::MergeBlocked | Reason::NotMerged => format!("loom stage merge {id}"),
+        Reason::MergeQueue { .. } => "loom merge queue".to_string(),
+        Reason::AwaitingCodeReview { review } => format!("loom status --explain {review}"),
+        Reason::AwaitingInput => format!("answer in the session, then loom stage resume {id}"),
+        Reason::RetryBackoff { .. }
+        | Reason::RetryDue
+        | Reason::AwaitingAdjudication
+        | Reason::NeedsHandoff
+        | Reason::MergeLock { .. }
+        | Reason::Skipped { .. } => return None,
+    };
+    Some(hint)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_explanation() {
+        colored::control::set_override(false);
+        let explanation = Explanation {
+            stage_id: "api".to_string(),
+            status: StageStatus::Queued,
+            session: None,
+            reasons: vec![
+                Reason::Held,
+                Reason::ParallelLimit {
+                    running: 4,
+                    max: 4,
+                    throttled_from: None,
+                },
+            ],
+        };
+        let mut out = Vec::new();
+        render_explanation(&mut out, &explanation).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("Stage 'api' (Queued) is waiting on:"), "{out}");
+        assert!(out.contains("✗ the stage is held\n    → loom stage release api"));
+        assert!(out.contains("all parallel slots are taken (4/4 stages executing)"));
+
+        let running = Explanation {
+            status: StageStatus::Executing,
+            session: Some("session-1".to_string()),
+            reasons: Vec::new(),
+            ..explanation
+        };
+        let mut out = Vec::new();
+        render_explanation(&mut out, &running).unwrap();
+        assert!(String::from_utf8(out)
+            .unwrap()
+            .contains("is running in session session-1"));
+    }
+}
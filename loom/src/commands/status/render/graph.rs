@@ -9,9 +9,11 @@ use std::io::Write;
 use colored::{Color, Colorize};
 
 use crate::commands::common::tree::{compute_connector, format_dep_annotation};
+use crate::commands::common::truncate;
 use crate::commands::graph::colors::color_by_index;
 use crate::commands::graph::indicators::status_indicator;
 use crate::commands::status::data::{StageSummary, StatusData};
+use crate::commands::status::render::render_sparkline;
 use crate::models::failure::FailureType;
 use crate::models::stage::{StageStatus, StageType};
 use crate::plan::graph::levels;
@@ -58,10 +60,36 @@ fn format_stage_annotations(stage: &StageSummary) -> String {
             parts.push(format!("{}", format_elapsed(secs).dimmed()));
         }
 
+        // Running well past the plan's expected_duration
+        if let (Some(ran), Some(expected)) = (stage.overdue_secs, stage.expected_duration_secs) {
+            parts.push(format!(
+                "{}",
+                format!(
+                    "overdue {} / ~{} expected",
+                    format_elapsed(ran),
+                    format_elapsed(expected as i64)
+                )
+                .red()
+                .bold()
+            ));
+        }
+
         // Activity icon
         let activity_icon = stage.activity_status.icon();
         parts.push(activity_icon.to_string());
 
+        // Tool calls per minute and the last few tools, to tell iterating
+        // from spinning at a glance
+        if !stage.activity_buckets.is_empty() {
+            parts.push(format!(
+                "{}",
+                render_sparkline(&stage.activity_buckets).cyan()
+            ));
+        }
+        if !stage.recent_tools.is_empty() {
+            parts.push(format!("{}", stage.recent_tools.join(" › ").dimmed()));
+        }
+
         // Staleness warning
         if let Some(staleness) = stage.staleness_secs {
             if staleness > 300 {
@@ -79,9 +107,24 @@ fn format_stage_annotations(stage: &StageSummary) -> String {
         }
     }
 
+    if let Some(assignee) = &stage.assignee {
+        parts.push(format!("{}", format!("@{assignee}").cyan()));
+    }
+
     // Held indicator
     if stage.held {
-        parts.push(format!("{}", "HELD".yellow()));
+        match &stage.held_by {
+            Some(by) => parts.push(format!("{}", format!("HELD (waiting on {by})").yellow())),
+            None => parts.push(format!("{}", "HELD".yellow())),
+        }
+    }
+
+    // Data dependencies the stage is waiting on
+    for (producer, key) in &stage.waiting_for_outputs {
+        parts.push(format!(
+            "{}",
+            format!("waiting for output {key} from {producer}").yellow()
+        ));
     }
 
     // Stuck warning
@@ -105,6 +148,7 @@ fn format_stage_annotations(stage: &StageSummary) -> String {
                 FailureType::UserBlocked => "user",
                 FailureType::MergeConflict => "merge",
                 FailureType::InfrastructureError => "infra",
+                FailureType::PreflightFailed => "preflight",
                 FailureType::Unknown => "error",
             })
             .unwrap_or("error");
@@ -114,6 +158,20 @@ fn format_stage_annotations(stage: &StageSummary) -> String {
         ));
     }
 
+    // Prompt the session is blocked on (auto-detected from its heartbeat)
+    if stage.status == StageStatus::WaitingForInput {
+        if let Some(since) = stage.waiting_since {
+            let waited = (chrono::Utc::now() - since).num_seconds().max(0);
+            parts.push(format!(
+                "{}",
+                format!("waiting {}", format_elapsed(waited)).yellow()
+            ));
+        }
+        if let Some(ref prompt) = stage.waiting_prompt {
+            parts.push(format!("{}", truncate(prompt, 80).yellow()));
+        }
+    }
+
     // Review reason for NeedsHumanReview
     if stage.status == StageStatus::NeedsHumanReview {
         if let Some(ref reason) = stage.review_reason {
@@ -8,6 +8,7 @@ fn make_stage_summary(id: &str, deps: Vec<&str>, status: StageStatus) -> StageSu
         status,
         stage_type: StageType::Standard,
         dependencies: deps.into_iter().map(String::from).collect(),
+        parallel_group: None,
         context_pct: None,
         elapsed_secs: None,
         execution_secs: None,
@@ -18,16 +19,25 @@ fn make_stage_summary(id: &str, deps: Vec<&str>, status: StageStatus) -> StageSu
         last_tool: None,
         last_activity: None,
         staleness_secs: None,
+        activity_buckets: Vec::new(),
+        recent_tools: Vec::new(),
         context_budget_pct: None,
         review_reason: None,
+        waiting_prompt: None,
+        waiting_since: None,
         merged: false,
         held: false,
+        held_by: None,
+        assignee: None,
         retry_count: 0,
         max_retries: None,
         pid: None,
         session_alive: false,
         model: "opus".to_string(),
         is_possibly_stuck: false,
+        waiting_for_outputs: Vec::new(),
+        expected_duration_secs: None,
+        overdue_secs: None,
     }
 }
 
@@ -125,6 +135,19 @@ fn test_render_graph_with_context() {
     assert!(output_str.contains("2m0s"));
 }
 
+#[test]
+fn test_render_graph_flags_overdue_stage() {
+    let mut stage = make_stage_summary("slow", vec![], StageStatus::Executing);
+    stage.expected_duration_secs = Some(1800);
+    stage.overdue_secs = Some(3000);
+
+    let data = make_status_data(vec![stage]);
+    let mut output = Vec::new();
+    render_graph(&mut output, &data).unwrap();
+    let output_str = String::from_utf8(output).unwrap();
+    assert!(output_str.contains("overdue 50m0s / ~30m0s expected"));
+}
+
 #[test]
 fn test_status_indicators() {
     // Just verify they don't panic
@@ -221,3 +244,51 @@ fn test_executing_stuck_shows_annotation() {
         "Expected 'stuck?' annotation in output for possibly stuck stage"
     );
 }
+
+#[test]
+fn test_waiting_stage_shows_detected_prompt() {
+    let mut stage = make_stage_summary("waiting-stage", vec![], StageStatus::WaitingForInput);
+    stage.waiting_prompt = Some("Claude needs your permission to use Bash".to_string());
+
+    let data = make_status_data(vec![stage]);
+    let mut output = Vec::new();
+    render_graph(&mut output, &data).unwrap();
+    let output_str = String::from_utf8(output).unwrap();
+
+    assert!(
+        output_str.contains("needs your permission to use Bash"),
+        "Expected the detected prompt in output for a waiting stage"
+    );
+}
+
+#[test]
+fn test_assigned_stage_shows_assignee() {
+    let mut stage = make_stage_summary("api", vec![], StageStatus::Queued);
+    stage.assignee = Some("alice".to_string());
+
+    let data = make_status_data(vec![stage]);
+    let mut output = Vec::new();
+    render_graph(&mut output, &data).unwrap();
+    let output_str = String::from_utf8(output).unwrap();
+
+    assert!(
+        output_str.contains("@alice"),
+        "Expected the assignee in output for an assigned stage"
+    );
+}
+
+#[test]
+fn test_queued_stage_shows_missing_output() {
+    let mut stage = make_stage_summary("consumer", vec!["stage-x"], StageStatus::Queued);
+    stage.waiting_for_outputs = vec![("stage-x".to_string(), "schema_version".to_string())];
+
+    let data = make_status_data(vec![stage]);
+    let mut output = Vec::new();
+    render_graph(&mut output, &data).unwrap();
+    let output_str = String::from_utf8(output).unwrap();
+
+    assert!(
+        output_str.contains("waiting for output schema_version from stage-x"),
+        "Expected missing output annotation in: {output_str}"
+    );
+}
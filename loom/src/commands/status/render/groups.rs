@@ -0,0 +1,262 @@
+//! Grouped stage view with per-group progress bars
+//!
+//! Large plans are hard to read as one tree. `--group-by parallel` groups
+//! stages by their `parallel_group`, `--group-by phase` by topological level
+//! (phase 1 holds the stages without dependencies). Each group gets a
+//! progress bar and a compact list of its stages.
+
+use anyhow::{anyhow, Result};
+use colored::Colorize;
+use std::collections::HashMap;
+use std::io::Write;
+use std::str::FromStr;
+
+use crate::commands::common::truncate;
+use crate::commands::graph::indicators::status_indicator;
+use crate::commands::status::data::StageSummary;
+use crate::models::stage::{Stage, StageStatus, StatusBucket};
+use crate::plan::graph::levels;
+
+/// Width of each group's progress bar.
+const BAR_WIDTH: usize = 20;
+
+/// Width of the group label column.
+const LABEL_WIDTH: usize = 24;
+
+/// How to group stages
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupBy {
+    /// By `parallel_group`; stages without one are listed last
+    Parallel,
+    /// By topological level
+    Phase,
+}
+
+impl FromStr for GroupBy {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "parallel" | "parallel-group" | "group" => Ok(GroupBy::Parallel),
+            "phase" | "level" => Ok(GroupBy::Phase),
+            _ => Err(anyhow!("Unsupported grouping: {s}. Use parallel or phase")),
+        }
+    }
+}
+
+/// The fields of a stage the grouped view needs
+#[derive(Debug, Clone)]
+pub struct GroupedStage {
+    pub id: String,
+    pub status: StageStatus,
+    pub dependencies: Vec<String>,
+    pub parallel_group: Option<String>,
+}
+
+impl From<&Stage> for GroupedStage {
+    fn from(stage: &Stage) -> Self {
+        Self {
+            id: stage.id.clone(),
+            status: stage.status.clone(),
+            dependencies: stage.dependencies.clone(),
+            parallel_group: stage.parallel_group.clone(),
+        }
+    }
+}
+
+impl From<&StageSummary> for GroupedStage {
+    fn from(stage: &StageSummary) -> Self {
+        Self {
+            id: stage.id.clone(),
+            status: stage.status.clone(),
+            dependencies: stage.dependencies.clone(),
+            parallel_group: stage.parallel_group.clone(),
+        }
+    }
+}
+
+/// A group of stages in display order
+#[derive(Debug, Clone)]
+pub struct StageGroup {
+    pub label: String,
+    pub stages: Vec<GroupedStage>,
+}
+
+impl StageGroup {
+    /// Stages finished (completed or skipped)
+    pub fn done(&self) -> usize {
+        self.count(StatusBucket::Completed)
+    }
+
+    fn count(&self, bucket: StatusBucket) -> usize {
+        self.stages
+            .iter()
+            .filter(|s| s.status.bucket() == bucket)
+            .count()
+    }
+}
+
+/// Group `stages`, ordering groups by the earliest phase they contain and
+/// stages within a group by phase, then id.
+pub fn group_stages(stages: &[GroupedStage], by: GroupBy) -> Vec<StageGroup> {
+    let level = levels::compute_all_levels(stages, |s| s.id.as_str(), |s| &s.dependencies);
+    let level_of = |s: &GroupedStage| level.get(&s.id).copied().unwrap_or(0);
+
+    let mut sorted: Vec<&GroupedStage> = stages.iter().collect();
+    sorted.sort_by(|a, b| level_of(a).cmp(&level_of(b)).then(a.id.cmp(&b.id)));
+
+    // Groups in first-seen order with their sort key; ungrouped stages last
+    let mut groups: Vec<((usize, usize), StageGroup)> = Vec::new();
+    let mut index: HashMap<String, usize> = HashMap::new();
+    for stage in sorted {
+        let (label, key) = match by {
+            GroupBy::Phase => (
+                format!("Phase {}", level_of(stage) + 1),
+                (0, level_of(stage)),
+            ),
+            GroupBy::Parallel => match &stage.parallel_group {
+                Some(group) => (group.clone(), (0, level_of(stage))),
+                None => ("(ungrouped)".to_string(), (1, 0)),
+            },
+        };
+        match index.get(&label) {
+            Some(&i) => groups[i].1.stages.push(stage.clone()),
+            None => {
+                index.insert(label.clone(), groups.len());
+                groups.push((
+                    key,
+                    StageGroup {
+                        label,
+                        stages: vec![stage.clone()],
+                    },
+                ));
+            }
+        }
+    }
+    groups.sort_by_key(|(key, _)| *key);
+    groups.into_iter().map(|(_, group)| group).collect()
+}
+
+/// Render each group as a progress bar followed by its stages
+pub fn render_groups<W: Write>(w: &mut W, groups: &[StageGroup]) -> std::io::Result<()> {
+    for group in groups {
+        let total = group.stages.len();
+        let done = group.done();
+        let executing = group.count(StatusBucket::Executing);
+        let blocked = group.count(StatusBucket::Blocked);
+
+        let filled = if total > 0 {
+            (done as f32 / total as f32 * BAR_WIDTH as f32).round() as usize
+        } else {
+            0
+        };
+        let bar = format!("{}{}", "█".repeat(filled), "░".repeat(BAR_WIDTH - filled));
+        let bar = if blocked > 0 {
+            bar.yellow()
+        } else if done == total {
+            bar.green()
+        } else {
+            bar.blue()
+        };
+
+        write!(
+            w,
+            "   {:<width$} [{}] {:>3} / {:<3}",
+            truncate(&group.label, LABEL_WIDTH).bold(),
+            bar,
+            done,
+            total,
+            width = LABEL_WIDTH
+        )?;
+        if executing > 0 {
+            write!(w, "  {} {}", executing, "executing".blue())?;
+        }
+        if blocked > 0 {
+            write!(w, "  {} {}", blocked, "blocked".red().bold())?;
+        }
+        writeln!(w)?;
+
+        let members: Vec<String> = group
+            .stages
+            .iter()
+            .map(|s| format!("{} {}", status_indicator(&s.status), s.id))
+            .collect();
+        writeln!(w, "      {}", members.join("  "))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stage(id: &str, deps: &[&str], group: Option<&str>, status: StageStatus) -> GroupedStage {
+        GroupedStage {
+            id: id.to_string(),
+            status,
+            dependencies: deps.iter().map(|d| d.to_string()).collect(),
+            parallel_group: group.map(String::from),
+        }
+    }
+
+    fn plan() -> Vec<GroupedStage> {
+        vec![
+            stage("docs", &[], None, StageStatus::Queued),
+            stage("api", &["setup"], Some("build"), StageStatus::Executing),
+            stage("setup", &[], None, StageStatus::Completed),
+            stage("ui", &["setup"], Some("build"), StageStatus::Completed),
+            stage(
+                "e2e",
+                &["api", "ui"],
+                Some("verify"),
+                StageStatus::WaitingForDeps,
+            ),
+        ]
+    }
+
+    fn labels(groups: &[StageGroup]) -> Vec<(&str, Vec<&str>)> {
+        groups
+            .iter()
+            .map(|g| {
+                (
+                    g.label.as_str(),
+                    g.stages.iter().map(|s| s.id.as_str()).collect(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_group_by_phase_and_parallel_group() {
+        let stages = plan();
+        assert_eq!(
+            labels(&group_stages(&stages, GroupBy::Phase)),
+            vec![
+                ("Phase 1", vec!["docs", "setup"]),
+                ("Phase 2", vec!["api", "ui"]),
+                ("Phase 3", vec!["e2e"]),
+            ]
+        );
+
+        let groups = group_stages(&stages, GroupBy::Parallel);
+        assert_eq!(
+            labels(&groups),
+            vec![
+                ("build", vec!["api", "ui"]),
+                ("verify", vec!["e2e"]),
+                ("(ungrouped)", vec!["docs", "setup"]),
+            ]
+        );
+        assert_eq!(groups[0].done(), 1);
+
+        let mut out = Vec::new();
+        render_groups(&mut out, &groups[..1]).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("  1 / 2"), "{out}");
+        assert!(out.contains("1 executing"));
+        assert!(out.contains("api"));
+
+        assert_eq!("phase".parse::<GroupBy>().unwrap(), GroupBy::Phase);
+        assert!("nope".parse::<GroupBy>().is_err());
+    }
+}
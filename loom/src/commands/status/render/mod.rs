@@ -2,16 +2,32 @@ pub mod activity;
 pub mod attention;
 pub mod compact;
 pub mod completion;
+pub mod conflict_risk;
+pub mod decisions;
+pub mod escalation;
+pub mod explain;
 pub mod graph;
+pub mod groups;
 pub mod merge;
+pub mod pause;
 pub mod progress;
 pub mod summary;
+pub mod supervisor;
+pub mod throttle;
 
-pub use activity::{render_activity_status, render_staleness_warning};
+pub use activity::{render_activity_status, render_sparkline, render_staleness_warning};
 pub use attention::render_attention;
 pub use compact::render_compact;
 pub use completion::{render_completion_lines, render_completion_screen};
+pub use conflict_risk::render_conflict_risk;
+pub use decisions::render_decisions;
+pub use escalation::render_escalations;
+pub use explain::render_explanation;
 pub use graph::render_graph;
+pub use groups::{group_stages, render_groups, GroupBy, GroupedStage};
 pub use merge::render_merge_status;
+pub use pause::render_pause;
 pub use progress::{render_context_bar, render_progress};
 pub use summary::print_completion_summary;
+pub use supervisor::render_daemon_supervision;
+pub use throttle::render_throttle;
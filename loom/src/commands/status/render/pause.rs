@@ -0,0 +1,57 @@
+//! Plan pause indicator
+
+use colored::Colorize;
+use std::io::Write;
+
+use crate::orchestrator::pause::PauseState;
+
+/// Render the `loom pause` state: since when, why, and suspended sessions
+pub fn render_pause<W: Write>(w: &mut W, state: &PauseState) -> std::io::Result<()> {
+    let mut detail = format!("since {}", state.paused_at.format("%H:%M:%S UTC"));
+    if let Some(reason) = &state.reason {
+        detail.push_str(&format!(" — {reason}"));
+    }
+    let what = if state.sessions_suspended() {
+        format!(
+            "plan paused, {} session(s) suspended",
+            state.suspended.len()
+        )
+    } else {
+        "plan paused, no new stages start".to_string()
+    };
+    writeln!(
+        w,
+        "   {} {}        {}",
+        "⏸".yellow(),
+        what.yellow(),
+        format!("{detail}; `loom resume` to continue").dimmed()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::orchestrator::pause::SuspendedSession;
+
+    #[test]
+    fn test_render_pause() {
+        colored::control::set_override(false);
+        let mut state = PauseState::new(Some("CI is down".to_string()));
+        let mut out = Vec::new();
+        render_pause(&mut out, &state).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("plan paused, no new stages start"), "{out}");
+        assert!(out.contains("— CI is down; `loom resume` to continue"));
+
+        state.suspended.push(SuspendedSession {
+            session_id: "session-1".to_string(),
+            stage_id: None,
+            pids: vec![42],
+        });
+        let mut out = Vec::new();
+        render_pause(&mut out, &state).unwrap();
+        assert!(String::from_utf8(out)
+            .unwrap()
+            .contains("plan paused, 1 session(s) suspended"));
+    }
+}
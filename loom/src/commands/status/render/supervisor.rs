@@ -0,0 +1,142 @@
+//! Daemon restart and crash indicator
+
+use chrono::{DateTime, Utc};
+use colored::Colorize;
+use std::io::Write;
+
+use crate::daemon::{DaemonCrash, DaemonHealth, SupervisorState};
+use crate::utils::format_elapsed;
+
+/// Render daemon restarts by the supervisor, a supervisor that gave up, or
+/// a daemon that died without shutting down (its heartbeat file is left
+/// behind).
+pub fn render_daemon_supervision<W: Write>(
+    w: &mut W,
+    daemon_running: bool,
+    health: Option<&DaemonHealth>,
+    supervisor: Option<&SupervisorState>,
+    now: DateTime<Utc>,
+) -> std::io::Result<()> {
+    let ago =
+        |at: DateTime<Utc>| format!("{} ago", format_elapsed((now - at).num_seconds().max(0)));
+    let last_crash = |crash: &DaemonCrash| {
+        let log = crash
+            .log
+            .as_ref()
+            .map(|log| format!("; log: {}", log.display()))
+            .unwrap_or_default();
+        format!("last: {} {}{log}", crash.reason, ago(crash.at))
+    };
+
+    if let Some(state) = supervisor.filter(|s| daemon_running && s.ended_at.is_none()) {
+        if let Some(crash) = state.crashes.last() {
+            writeln!(
+                w,
+                "   {} {}        {}",
+                "↻".yellow(),
+                format!("supervisor restarted the daemon {}x", state.crashes.len()).yellow(),
+                last_crash(crash).dimmed()
+            )?;
+        }
+        return Ok(());
+    }
+    if daemon_running {
+        return Ok(());
+    }
+
+    if let Some(state) = supervisor.filter(|s| s.gave_up) {
+        if let Some(crash) = state.crashes.last() {
+            writeln!(
+                w,
+                "   {} {}        {}",
+                "✗".red(),
+                format!(
+                    "supervisor gave up after {} daemon crash(es)",
+                    state.crashes.len()
+                )
+                .red(),
+                last_crash(crash).dimmed()
+            )?;
+            return Ok(());
+        }
+    }
+    if let Some(health) = health {
+        writeln!(
+            w,
+            "   {} {}        {}",
+            "✗".red(),
+            format!(
+                "daemon (PID {}) died without shutting down, last heartbeat {}",
+                health.pid,
+                ago(health.heartbeat_at)
+            )
+            .red(),
+            "`loom run --supervised` restarts it automatically".dimmed()
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn render(
+        running: bool,
+        health: Option<&DaemonHealth>,
+        supervisor: Option<&SupervisorState>,
+        now: DateTime<Utc>,
+    ) -> String {
+        colored::control::set_override(false);
+        let mut out = Vec::new();
+        render_daemon_supervision(&mut out, running, health, supervisor, now).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_render_daemon_supervision() {
+        let now = Utc::now();
+        let crash = DaemonCrash {
+            at: now - chrono::Duration::minutes(5),
+            daemon_pid: 41,
+            reason: "killed by SIGSEGV".to_string(),
+            log: Some(PathBuf::from(".work/crashes/daemon-1.log")),
+        };
+        let mut state = SupervisorState {
+            pid: 40,
+            started_at: now,
+            crashes: vec![crash],
+            gave_up: false,
+            ended_at: None,
+        };
+        let health = DaemonHealth {
+            pid: 42,
+            started_at: now,
+            heartbeat_at: now - chrono::Duration::seconds(90),
+            supervised: true,
+        };
+
+        let out = render(true, Some(&health), Some(&state), now);
+        assert!(out.contains("supervisor restarted the daemon 1x"), "{out}");
+        assert!(out.contains("last: killed by SIGSEGV 5m0s ago; log: .work/crashes/daemon-1.log"));
+
+        // A daemon that died unsupervised left its heartbeat behind
+        let out = render(false, Some(&health), None, now);
+        assert!(
+            out.contains("daemon (PID 42) died without shutting down, last heartbeat 1m30s ago")
+        );
+
+        state.gave_up = true;
+        state.ended_at = Some(now);
+        let out = render(false, Some(&health), Some(&state), now);
+        assert!(
+            out.contains("supervisor gave up after 1 daemon crash(es)"),
+            "{out}"
+        );
+
+        // Nothing to report for a clean, unsupervised run
+        assert!(render(true, None, None, now).is_empty());
+        assert!(render(false, None, None, now).is_empty());
+    }
+}
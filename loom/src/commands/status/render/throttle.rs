@@ -0,0 +1,57 @@
+//! Parallelism throttle indicator
+
+use colored::Colorize;
+use std::io::Write;
+
+use crate::orchestrator::throttle::ThrottleState;
+
+/// Render the daemon's load-based session limit while it is below
+/// `--max-parallel`
+pub fn render_throttle<W: Write>(w: &mut W, state: &ThrottleState) -> std::io::Result<()> {
+    if !state.is_throttled() {
+        return Ok(());
+    }
+    let reason = if state.exceeded.is_empty() {
+        "recovering".to_string()
+    } else {
+        state.exceeded.join(", ")
+    };
+    writeln!(
+        w,
+        "   {} {}        {}",
+        "◐".yellow(),
+        format!(
+            "throttled to {}/{} parallel sessions",
+            state.effective_max, state.configured_max
+        )
+        .yellow(),
+        reason.dimmed()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    #[test]
+    fn test_render_throttle() {
+        colored::control::set_override(false);
+        let mut state = ThrottleState {
+            configured_max: 4,
+            effective_max: 2,
+            exceeded: vec!["memory 93.0% > 90.0%".to_string()],
+            updated_at: Utc::now(),
+        };
+        let mut out = Vec::new();
+        render_throttle(&mut out, &state).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("throttled to 2/4 parallel sessions"), "{out}");
+        assert!(out.contains("memory 93.0% > 90.0%"));
+
+        state.effective_max = 4;
+        let mut out = Vec::new();
+        render_throttle(&mut out, &state).unwrap();
+        assert!(out.is_empty());
+    }
+}
@@ -178,13 +178,18 @@ impl<'a> TreeWidget<'a> {
             };
 
             let model_text = format!(" [{}]", stage.effective_model());
+            let assignee_text = stage
+                .assignee
+                .as_deref()
+                .map(|a| format!(" @{a}"))
+                .unwrap_or_default();
 
             // Width accounting
             let connector_w = connector.chars().count();
             let icon_w = 2; // icon char + space
             let elapsed_w = elapsed_text.chars().count();
             let deps_w = deps_prefix.len() + deps_joined.len();
-            let model_w = model_text.chars().count();
+            let model_w = model_text.chars().count() + assignee_text.chars().count();
 
             // Budget for stage ID (runtime always visible, deps can shrink)
             let fixed_w = connector_w + icon_w + elapsed_w + deps_w + model_w;
@@ -203,6 +208,7 @@ impl<'a> TreeWidget<'a> {
                 Span::raw(" "),
                 Span::styled(id_display, Style::default().fg(stage_color)),
                 Span::styled(model_text, Theme::dimmed()),
+                Span::styled(assignee_text, Style::default().fg(Color::Cyan)),
             ];
 
             // Elapsed time (for executing and completed stages)
@@ -19,6 +19,8 @@ fn make_stage(id: &str, deps: Vec<&str>, status: StageStatus) -> Stage {
         worktree: None,
         session: None,
         held: false,
+        held_by: None,
+        assignee: None,
         parent_stage: None,
         child_stages: vec![],
         created_at: Utc::now(),
@@ -58,12 +60,28 @@ fn make_stage(id: &str, deps: Vec<&str>, status: StageStatus) -> Stage {
         execution_mode: None,
         max_fix_attempts: None,
         review_reason: None,
+        waiting_prompt: None,
+        waiting_since: None,
         bug_fix: None,
         regression_test: None,
         model: None,
         reasoning_effort: None,
         is_possibly_stuck: false,
         ultracode: false,
+        pair: false,
+        pair_review: None,
+        worktree_setup: Vec::new(),
+        instructions: None,
+        prompt_prefix: None,
+        container: None,
+        keep_terminal: None,
+        expected_outputs: Vec::new(),
+        params: Vec::new(),
+        requires_outputs: Vec::new(),
+        new_files: Vec::new(),
+        expected_duration_secs: None,
+        acceptance_isolation: None,
+        skip_if_satisfied: false,
     }
 }
 
@@ -81,6 +81,8 @@ pub fn stage_info_to_stage(info: &StageInfo) -> Stage {
         worktree: None,
         session: None,
         held: false,
+        held_by: None,
+        assignee: info.assignee.clone(),
         parent_stage: None,
         child_stages: vec![],
         created_at: info.started_at,
@@ -122,6 +124,8 @@ pub fn stage_info_to_stage(info: &StageInfo) -> Stage {
         execution_mode: None,
         max_fix_attempts: None,
         review_reason: None,
+        waiting_prompt: None,
+        waiting_since: None,
         bug_fix: None,
         regression_test: None,
         model: if info.model.is_empty() {
@@ -132,6 +136,20 @@ pub fn stage_info_to_stage(info: &StageInfo) -> Stage {
         reasoning_effort: None,
         is_possibly_stuck: info.is_possibly_stuck,
         ultracode: false,
+        pair: false,
+        pair_review: None,
+        worktree_setup: Vec::new(),
+        instructions: None,
+        prompt_prefix: None,
+        container: None,
+        keep_terminal: None,
+        expected_outputs: Vec::new(),
+        params: Vec::new(),
+        requires_outputs: Vec::new(),
+        new_files: Vec::new(),
+        expected_duration_secs: None,
+        acceptance_isolation: None,
+        skip_if_satisfied: false,
     }
 }
 
@@ -361,6 +379,7 @@ mod tests {
             dependencies: vec!["dep1".to_string(), "dep2".to_string()],
             model: "opus".to_string(),
             is_possibly_stuck: false,
+            assignee: None,
         };
 
         let stage = stage_info_to_stage(&info);
@@ -294,6 +294,7 @@ mod tests {
             dependencies: vec![],
             model: String::new(),
             is_possibly_stuck: false,
+            assignee: None,
         }];
         status.completed = vec![StageInfo {
             id: "b".to_string(),
@@ -307,6 +308,7 @@ mod tests {
             dependencies: vec![],
             model: String::new(),
             is_possibly_stuck: false,
+            assignee: None,
         }];
 
         assert_eq!(status.total(), 2);
@@ -330,6 +332,7 @@ mod tests {
                     dependencies: vec![],
                     model: String::new(),
                     is_possibly_stuck: false,
+                    assignee: None,
                 },
                 StageInfo {
                     id: "b".to_string(),
@@ -343,6 +346,7 @@ mod tests {
                     dependencies: vec!["a".to_string()],
                     model: String::new(),
                     is_possibly_stuck: false,
+                    assignee: None,
                 },
                 StageInfo {
                     id: "c".to_string(),
@@ -356,6 +360,7 @@ mod tests {
                     dependencies: vec!["a".to_string(), "b".to_string()],
                     model: String::new(),
                     is_possibly_stuck: false,
+                    assignee: None,
                 },
             ],
             completed: vec![],
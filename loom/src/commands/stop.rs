@@ -9,7 +9,7 @@
 //! PID fallback (SIGTERM/SIGKILL to the daemon process) is reserved for the
 //! socket-hang case and requires `--force` to opt in.
 
-use crate::daemon::{read_admin_token, DaemonServer};
+use crate::daemon::{read_admin_token, stop_supervisor, DaemonServer};
 use crate::fs::work_dir::WorkDir;
 use anyhow::{bail, Context, Result};
 use colored::Colorize;
@@ -36,6 +36,14 @@ pub fn execute() -> Result<()> {
 pub fn execute_with_force(force: bool) -> Result<()> {
     let work_dir = WorkDir::new(".")?;
 
+    // A supervisor would restart the daemon we are about to stop
+    if let Some(pid) = stop_supervisor(work_dir.root()) {
+        println!(
+            "{} Stopped daemon supervisor (PID {pid})",
+            "✓".green().bold()
+        );
+    }
+
     if !DaemonServer::is_running(work_dir.root()) {
         println!("{} Daemon is not running", "─".dimmed());
         return Ok(());
@@ -0,0 +1,32 @@
+//! One-line plan summary for a tmux status bar
+//! Usage: loom tmux-status [--json]
+//!
+//! Embed in `~/.tmux.conf` with
+//! `set -g status-right '#(cd #{pane_current_path} && loom tmux-status)'`.
+//! Outside a loom workspace it prints nothing, so the segment disappears.
+
+use anyhow::Result;
+use chrono::Utc;
+
+use crate::commands::common::find_work_dir;
+use crate::daemon::{compute_status_line, read_status_line};
+
+pub fn execute(json: bool) -> Result<()> {
+    let Ok(work_dir) = find_work_dir() else {
+        return Ok(());
+    };
+
+    // The daemon keeps a fresh summary cached; compute one only without it
+    let now = Utc::now();
+    let line = match read_status_line(&work_dir) {
+        Some(line) if !line.is_stale(now) => line,
+        _ => compute_status_line(&work_dir, now)?,
+    };
+
+    if json {
+        println!("{}", serde_json::to_string(&line)?);
+    } else {
+        println!("{}", line.render());
+    }
+    Ok(())
+}
@@ -1,16 +1,17 @@
 //! Worktree management commands
-//! Usage: loom worktree [list|remove <stage-id>]
+//! Usage: loom worktree [list|remove <stage-id>|shell <stage-id> [-- <cmd>...]]
 
 use anyhow::{bail, Context, Result};
 use colored::Colorize;
 use std::path::{Path, PathBuf};
 
+use crate::commands::common::find_work_dir;
 use crate::fs::stage_files::find_stage_file;
 use crate::git::branch::{
     branch_name_for_stage, commits_ahead_of, is_ancestor_of, resolve_target_branch,
 };
 use crate::git::cleanup::{cleanup_after_merge, prune_worktrees, CleanupConfig};
-use crate::git::worktree::find_worktree_by_prefix;
+use crate::git::worktree::{find_worktree_by_prefix, get_worktree_path, worktrees_root};
 use crate::models::stage::{Stage, StageStatus};
 use crate::verify::transitions::{load_stage, parse_stage_from_markdown, save_stage};
 
@@ -19,9 +20,9 @@ pub fn list() -> Result<()> {
     println!("Git worktrees:");
     println!("─────────────────────────────────────────────────────────");
 
-    let worktrees_dir = std::env::current_dir()?.join(".worktrees");
+    let worktrees_dir = worktrees_root(&std::env::current_dir()?);
     if !worktrees_dir.exists() {
-        println!("(no .worktrees/ directory)");
+        println!("(no {} directory)", worktrees_dir.display());
         return Ok(());
     }
 
@@ -65,12 +66,12 @@ pub fn clean() -> Result<()> {
     println!("{}", "─".repeat(50).dimmed());
 
     let repo_root = std::env::current_dir()?;
-    let worktrees_dir = repo_root.join(".worktrees");
+    let worktrees_dir = worktrees_root(&repo_root);
     let work_dir = repo_root.join(".work");
     let stages_dir = work_dir.join("stages");
 
     if !worktrees_dir.exists() {
-        println!("No .worktrees/ directory to clean");
+        println!("No {} directory to clean", worktrees_dir.display());
         return Ok(());
     }
 
@@ -328,9 +329,7 @@ fn mark_stage_merged(stage_id: &str, work_dir: &std::path::Path) -> Result<()> {
 
 /// Get the base worktrees directory
 pub fn worktrees_dir() -> PathBuf {
-    std::env::current_dir()
-        .unwrap_or_default()
-        .join(".worktrees")
+    worktrees_root(&std::env::current_dir().unwrap_or_default())
 }
 
 /// Remove a specific worktree and its branch after merge conflict resolution
@@ -368,10 +367,7 @@ pub fn remove(stage_id: String) -> Result<()> {
         None => {
             // No worktree found, but branch might still exist
             // Fall back to the provided stage_id
-            (
-                repo_root.join(".worktrees").join(&stage_id),
-                stage_id.clone(),
-            )
+            (get_worktree_path(&stage_id, &repo_root), stage_id.clone())
         }
     };
 
@@ -463,3 +459,122 @@ pub fn remove(stage_id: String) -> Result<()> {
 
     Ok(())
 }
+
+/// Open an interactive shell, or run `command`, inside a stage's worktree
+///
+/// The process runs with the environment a stage session sees:
+/// `LOOM_STAGE_ID`, `LOOM_WORK_DIR`, `LOOM_WORKTREE_PATH` and, when the stage
+/// has a session, `LOOM_SESSION_ID`, so `loom memory`, `loom stage` and the
+/// hooks behave as they would for the agent. The shell is `$SHELL`, falling
+/// back to `/bin/sh`. Supports the same prefix matching as `remove`.
+pub fn shell(stage_id: String, command: Vec<String>) -> Result<()> {
+    use std::os::unix::process::CommandExt;
+
+    let work_dir = find_work_dir()?
+        .canonicalize()
+        .context("Failed to resolve .work directory")?;
+    let repo_root = work_dir
+        .parent()
+        .context(".work directory has no parent")?
+        .to_path_buf();
+
+    let Some(worktree_path) = find_worktree_by_prefix(&repo_root, &stage_id)? else {
+        bail!(
+            "No worktree found for stage '{stage_id}' in {}",
+            worktrees_root(&repo_root).display()
+        );
+    };
+    let actual_stage_id = worktree_path
+        .file_name()
+        .and_then(|s| s.to_str())
+        .unwrap_or(&stage_id)
+        .to_string();
+    let session_id = load_stage(&actual_stage_id, &work_dir)
+        .ok()
+        .and_then(|stage| stage.session);
+
+    let mut process = match command.split_first() {
+        Some((program, args)) => {
+            let mut process = std::process::Command::new(program);
+            process.args(args);
+            process
+        }
+        None => {
+            println!(
+                "{} {} {}",
+                "Opening shell in".cyan().bold(),
+                worktree_path.display(),
+                "(exit to return)".dimmed()
+            );
+            std::process::Command::new(login_shell())
+        }
+    };
+    process.current_dir(&worktree_path).envs(shell_env(
+        &actual_stage_id,
+        session_id.as_deref(),
+        &work_dir,
+        &worktree_path,
+    ));
+
+    // exec only returns on failure
+    let err = process.exec();
+    Err(err).with_context(|| format!("Failed to start a shell in {}", worktree_path.display()))
+}
+
+/// The user's shell, or `/bin/sh` when `$SHELL` is unset
+fn login_shell() -> String {
+    std::env::var("SHELL")
+        .ok()
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| "/bin/sh".to_string())
+}
+
+/// Loom environment for a process running in a stage's worktree
+fn shell_env(
+    stage_id: &str,
+    session_id: Option<&str>,
+    work_dir: &Path,
+    worktree_path: &Path,
+) -> Vec<(&'static str, String)> {
+    let mut env = vec![
+        ("LOOM_STAGE_ID", stage_id.to_string()),
+        ("LOOM_WORK_DIR", work_dir.display().to_string()),
+        ("LOOM_WORKTREE_PATH", worktree_path.display().to_string()),
+    ];
+    if let Some(session_id) = session_id {
+        env.push(("LOOM_SESSION_ID", session_id.to_string()));
+    }
+    env
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shell_env() {
+        let env = shell_env(
+            "api",
+            Some("session-1"),
+            Path::new("/repo/.work"),
+            Path::new("/repo/.worktrees/api"),
+        );
+        assert_eq!(
+            env,
+            vec![
+                ("LOOM_STAGE_ID", "api".to_string()),
+                ("LOOM_WORK_DIR", "/repo/.work".to_string()),
+                ("LOOM_WORKTREE_PATH", "/repo/.worktrees/api".to_string()),
+                ("LOOM_SESSION_ID", "session-1".to_string()),
+            ]
+        );
+
+        let env = shell_env(
+            "api",
+            None,
+            Path::new("/repo/.work"),
+            Path::new("/repo/.worktrees/api"),
+        );
+        assert!(env.iter().all(|(name, _)| *name != "LOOM_SESSION_ID"));
+    }
+}
@@ -5,27 +5,38 @@ use anyhow::Result;
 /// All top-level loom commands.
 const TOP_LEVEL_COMMANDS: &[&str] = &[
     "check",
+    "ci-check",
     "clean",
     "completions",
     "diagnose",
+    "doctor",
     "graph",
     "handoff",
     "init",
     "knowledge",
+    "learn",
+    "logs",
     "map",
+    "mcp",
     "memory",
+    "merge",
+    "pause",
     "plan",
     "pressure",
     "repair",
+    "report",
     "resume",
     "review",
     "run",
     "self-update",
     "sessions",
+    "signal",
     "skill-index",
     "stage",
     "status",
     "stop",
+    "tmux-status",
+    "verify",
     "worktree",
 ];
 
@@ -39,12 +50,15 @@ pub fn complete_subcommands(parent: &str, prefix: &str) -> Result<Vec<String>> {
     let subs: &[&str] = match parent {
         "stage" => &[
             "block",
+            "cancel",
+            "claim",
             "complete",
             "dispute-criteria",
             "hold",
             "human-review",
             "merge",
             "output",
+            "pair-review",
             "release",
             "reset",
             "resume",
@@ -52,24 +66,34 @@ pub fn complete_subcommands(parent: &str, prefix: &str) -> Result<Vec<String>> {
             "skip",
             "verify",
             "waiting",
+            "watch",
         ],
-        "sessions" => &["kill", "list"],
-        "worktree" => &["list", "remove"],
+        "sessions" => &["focus", "kill", "list"],
+        "merge" => &["lock-status", "move", "pin", "queue", "unpin"],
+        "signal" => &["refresh"],
+        "worktree" => &["list", "remove", "shell"],
         "knowledge" => &[
             "audit",
             "bootstrap",
             "check",
+            "diff",
             "gc",
             "init",
             "list",
             "show",
             "update",
         ],
+        "learn" => &["export", "resolve"],
         "memory" => &[
-            "change", "decision", "list", "note", "query", "question", "show",
+            "change", "decision", "delete", "edit", "list", "note", "query", "question", "show",
         ],
         "output" => &["get", "list", "remove", "set"],
-        "plan" => &["verify"],
+        "plan" => &["diff", "suggest", "verify"],
+        "handoff" => &["create"],
+        "logs" => &["prune"],
+        "report" => &["conflicts", "html"],
+        "verify" => &["all"],
+        "mcp" => &["serve"],
         _ => return Ok(Vec::new()),
     };
     Ok(filter_prefix(subs, prefix))
@@ -81,20 +105,47 @@ pub fn complete_subcommands(parent: &str, prefix: &str) -> Result<Vec<String>> {
 pub fn complete_flags(command_path: &[&str], prefix: &str) -> Result<Vec<String>> {
     let flags: &[&str] = match command_path {
         ["run"] => &[
+            "--allow-dirty",
             "--foreground",
+            "--handoff-on-timeout",
+            "--keep-terminal",
             "--manual",
+            "--max-runtime",
             "--max-parallel",
             "--no-merge",
+            "--param",
+            "--simulate",
+            "--until",
             "--watch",
         ],
-        ["status"] => &["--compact", "--live", "--verbose"],
-        ["init"] => &["--clean"],
+        ["status"] => &[
+            "--compact",
+            "--decisions",
+            "--explain",
+            "--group-by",
+            "--live",
+            "--verbose",
+        ],
+        ["graph"] => &["--group-by"],
+        ["init"] => &["--clean", "--interactive", "--switch-plan"],
         ["clean"] => &["--all", "--sessions", "--state", "--worktrees"],
         ["repair"] => &["--fix"],
         ["map"] => &["--deep", "--focus", "--overwrite"],
         ["pressure"] => &["--dry-run", "--rounds"],
         ["check"] => &["--suggest"],
+        ["ci-check"] => &["--json", "--strict"],
+        ["fact", "set"] => &["--confidence", "--description", "--stage"],
+        ["pause"] => &["--reason", "--sessions"],
+        ["resume"] => &["--action"],
         ["handoff"] => &["--message", "--session", "--stage", "--trigger"],
+        ["handoff", "create"] => &[
+            "--decision",
+            "--remaining",
+            "--session",
+            "--stage",
+            "--summary",
+            "--yaml",
+        ],
         ["stage", "complete"] => &[
             "--assume-merged",
             "--force-unsafe",
@@ -103,15 +154,27 @@ pub fn complete_flags(command_path: &[&str], prefix: &str) -> Result<Vec<String>
         ],
         ["stage", "reset"] => &["--hard", "--kill-session"],
         ["stage", "skip"] => &["--reason"],
+        ["stage", "cancel"] => &["--cascade", "--delete-worktree"],
         ["stage", "retry"] => &["--context", "--force"],
         ["stage", "merge"] => &["--resolved"],
         ["stage", "verify"] => &["--dry-run", "--no-reload"],
+        ["stage", "watch"] => &["--interval"],
         ["stage", "human-review"] => &["--approve", "--force-complete", "--reject"],
-        ["sessions", "kill"] => &["--stage"],
+        ["stage", "pair-review"] => &["--approve", "--notes", "--reject"],
+        ["logs", "prune"] => &["--dry-run", "--keep"],
+        ["report", "conflicts"] => &["--json", "--top"],
+        ["report", "html"] => &["--output"],
+        ["verify", "all"] => &["--accept", "--json"],
+        ["tmux-status"] => &["--json"],
+        ["sessions", "focus"] => &["--stage"],
+        ["sessions", "kill"] => &["--all", "--stage", "--status"],
+        ["knowledge", "show"] => &["--json", "--tag"],
+        ["knowledge", "update"] => &["--severity", "--tag"],
         ["knowledge", "check"] => &["--min-coverage", "--quiet", "--src-path"],
         ["knowledge", "audit"] => &["--max-file-lines", "--max-total-lines", "--quiet"],
         ["knowledge", "gc"] => &["--dry-run", "--model", "--quick"],
         ["knowledge", "bootstrap"] => &["--model", "--quick", "--skip-map"],
+        ["learn", "export"] => &["--dry-run", "--file", "--push", "--to"],
         ["memory", "note"]
         | ["memory", "decision"]
         | ["memory", "question"]
@@ -119,15 +182,20 @@ pub fn complete_flags(command_path: &[&str], prefix: &str) -> Result<Vec<String>
         | ["memory", "query"] => &["--stage"],
         ["memory", "list"] => &["--entry-type", "--stage"],
         ["memory", "show"] => &["--all", "--stage"],
+        ["memory", "delete"] => &["--all", "--stage", "--type"],
+        ["memory", "edit"] => &["--context", "--stage"],
         ["plan", "verify"] => &["--json", "--no-color", "--strict"],
         _ => return Ok(Vec::new()),
     };
     Ok(filter_prefix(flags, prefix))
 }
 
-/// Complete shell type names (bash, zsh, fish).
+/// Complete shell type names (bash, zsh, fish, nushell, powershell).
 pub fn complete_shell_types(prefix: &str) -> Result<Vec<String>> {
-    Ok(filter_prefix(&["bash", "fish", "zsh"], prefix))
+    Ok(filter_prefix(
+        &["bash", "fish", "nushell", "powershell", "zsh"],
+        prefix,
+    ))
 }
 
 /// Complete model names for --model flag.
@@ -143,11 +211,25 @@ pub fn complete_trigger_types(prefix: &str) -> Result<Vec<String>> {
     ))
 }
 
+/// Complete session states for `sessions kill --status`.
+pub fn complete_session_filters(prefix: &str) -> Result<Vec<String>> {
+    Ok(filter_prefix(&["crashed", "stale"], prefix))
+}
+
 /// Commands that have subcommands (and thus should not get value completions).
 pub fn has_subcommands(command: &str) -> bool {
     matches!(
         command,
-        "stage" | "sessions" | "worktree" | "knowledge" | "memory" | "plan"
+        "stage"
+            | "sessions"
+            | "merge"
+            | "signal"
+            | "worktree"
+            | "knowledge"
+            | "learn"
+            | "memory"
+            | "plan"
+            | "mcp"
     )
 }
 
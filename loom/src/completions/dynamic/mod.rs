@@ -18,8 +18,8 @@ use anyhow::Result;
 use std::path::Path;
 
 pub use commands::{
-    complete_commands, complete_flags, complete_model_names, complete_shell_types,
-    complete_subcommands, complete_trigger_types,
+    complete_commands, complete_flags, complete_model_names, complete_session_filters,
+    complete_shell_types, complete_subcommands, complete_trigger_types,
 };
 pub use knowledge::complete_knowledge_files;
 pub use memory::complete_memory_entry_types;
@@ -40,15 +40,32 @@ pub struct CompletionContext {
 impl CompletionContext {
     /// Parse completion context from shell-provided arguments
     ///
+    /// The first argument is always the working directory. The rest depend
+    /// on the shell:
+    ///
+    /// * bash, zsh, fish: `<cmdline> <current-word> <prev-word>`
+    /// * nushell: the command-line spans, one argument each (the last span
+    ///   is the word being completed, empty after a trailing space)
+    /// * powershell: `<cursor-offset> <cmdline>` (Windows PowerShell drops
+    ///   empty arguments to native commands, so the words are derived from
+    ///   the text before the cursor)
+    ///
     /// # Arguments
     ///
-    /// * `shell` - Shell type (bash, zsh, fish)
+    /// * `shell` - Shell type (bash, zsh, fish, nushell, powershell)
     /// * `args` - Arguments passed from shell completion system
     pub fn from_args(shell: &str, args: &[String]) -> Self {
         let cwd = args.first().cloned().unwrap_or_else(|| ".".to_string());
-        let cmdline = args.get(1).cloned().unwrap_or_default();
-        let current_word = args.get(2).cloned().unwrap_or_default();
-        let prev_word = args.get(3).cloned().unwrap_or_default();
+        let rest = args.get(1..).unwrap_or_default();
+        let (cmdline, current_word, prev_word) = match shell {
+            "nushell" | "nu" => words_from_spans(rest),
+            "powershell" | "pwsh" => words_from_cursor(rest),
+            _ => (
+                rest.first().cloned().unwrap_or_default(),
+                rest.get(1).cloned().unwrap_or_default(),
+                rest.get(2).cloned().unwrap_or_default(),
+            ),
+        };
 
         Self {
             cwd,
@@ -60,6 +77,46 @@ impl CompletionContext {
     }
 }
 
+/// Split nushell completer spans into (cmdline, current word, previous word).
+fn words_from_spans(spans: &[String]) -> (String, String, String) {
+    let current_word = spans.last().cloned().unwrap_or_default();
+    let prev_word = spans
+        .len()
+        .checked_sub(2)
+        .and_then(|i| spans.get(i))
+        .cloned()
+        .unwrap_or_default();
+    (spans.join(" "), current_word, prev_word)
+}
+
+/// Split a PowerShell `<cursor-offset> <cmdline>` pair into
+/// (cmdline up to the cursor, current word, previous word).
+///
+/// The command text PowerShell reports excludes trailing whitespace, so a
+/// cursor past its end means a new, empty word is being completed.
+fn words_from_cursor(args: &[String]) -> (String, String, String) {
+    let line = args.get(1).map(String::as_str).unwrap_or_default();
+    let line_len = line.chars().count();
+    let cursor = args
+        .first()
+        .and_then(|c| c.parse::<usize>().ok())
+        .unwrap_or(line_len);
+
+    let mut before: String = line.chars().take(cursor).collect();
+    if cursor > line_len {
+        before.push(' ');
+    }
+
+    let mut words: Vec<&str> = before.split_whitespace().collect();
+    let current_word = if before.ends_with(char::is_whitespace) {
+        String::new()
+    } else {
+        words.pop().unwrap_or_default().to_string()
+    };
+    let prev_word = words.last().copied().unwrap_or_default().to_string();
+    (before, current_word, prev_word)
+}
+
 /// Parse the command line into words, stripping the leading "loom" if present.
 fn parse_cmdline_words(cmdline: &str) -> Vec<String> {
     let words: Vec<String> = cmdline.split_whitespace().map(String::from).collect();
@@ -136,7 +193,7 @@ fn complete_flag_value(
     cmdline: &str,
 ) -> Result<Option<Vec<String>>> {
     match prev_word {
-        "--stage" => {
+        "--stage" | "--until" => {
             let results = complete_stage_ids(cwd, prefix)?;
             Ok(Some(results))
         }
@@ -156,6 +213,10 @@ fn complete_flag_value(
             let results = complete_session_ids(cwd, prefix)?;
             Ok(Some(results))
         }
+        "--status" if cmdline.contains("sessions") => {
+            let results = complete_session_filters(prefix)?;
+            Ok(Some(results))
+        }
         // --tail expects a number; suppress stage-ID suggestions
         "--tail" => Ok(Some(Vec::new())),
         _ => Ok(None),
@@ -214,8 +275,10 @@ fn complete_after_subcommand(
         ("stage", "verify") => {
             complete_stage_ids_filtered(cwd, prefix, &["completed-with-failures", "executing"])
         }
-        ("stage", "human-review" | "dispute-criteria") => complete_stage_ids(cwd, prefix),
-        ("stage", "block" | "hold" | "release" | "skip" | "waiting" | "resume") => {
+        ("stage", "human-review" | "pair-review" | "dispute-criteria") => {
+            complete_stage_ids(cwd, prefix)
+        }
+        ("stage", "block" | "hold" | "release" | "skip" | "waiting" | "resume" | "watch") => {
             complete_stage_ids(cwd, prefix)
         }
         ("stage", "output") => complete_subcommands("output", prefix),
@@ -225,9 +288,14 @@ fn complete_after_subcommand(
 
         // Session subcommands
         ("sessions", "kill") => complete_session_ids(cwd, prefix),
+        ("sessions", "focus") => complete_stage_or_session_ids(cwd, prefix),
+        ("signal", "refresh") => complete_session_ids(cwd, prefix),
+
+        // Merge queue subcommands
+        ("merge", "pin") | ("merge", "move") => complete_stage_ids(cwd, prefix),
 
         // Worktree subcommands
-        ("worktree", "remove") => complete_stage_ids(cwd, prefix),
+        ("worktree", "remove" | "shell") => complete_stage_ids(cwd, prefix),
 
         // Knowledge subcommands
         ("knowledge", "show" | "update") => complete_knowledge_files(prefix),
@@ -207,6 +207,60 @@ fn test_completion_context_from_args_empty() {
     assert_eq!(ctx.prev_word, "");
 }
 
+#[test]
+fn test_completion_context_from_args_nushell_spans() {
+    let args: Vec<String> = ["/home/user/project", "loom", "stage", "complete", "st"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    let ctx = CompletionContext::from_args("nushell", &args);
+
+    assert_eq!(ctx.cwd, "/home/user/project");
+    assert_eq!(ctx.cmdline, "loom stage complete st");
+    assert_eq!(ctx.current_word, "st");
+    assert_eq!(ctx.prev_word, "complete");
+
+    // A trailing space yields an empty final span
+    let args: Vec<String> = ["/p", "loom", "stage", ""]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    let ctx = CompletionContext::from_args("nu", &args);
+    assert_eq!(ctx.current_word, "");
+    assert_eq!(ctx.prev_word, "stage");
+}
+
+#[test]
+fn test_completion_context_from_args_powershell_cursor() {
+    let args: Vec<String> = ["/p", "17", "loom stage retry"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    let ctx = CompletionContext::from_args("powershell", &args);
+    assert_eq!(ctx.cmdline, "loom stage retry ");
+    assert_eq!(ctx.current_word, "");
+    assert_eq!(ctx.prev_word, "retry");
+
+    // Cursor in the middle of a word completes only the text before it
+    let args: Vec<String> = ["/p", "12", "loom stage retry"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    let ctx = CompletionContext::from_args("pwsh", &args);
+    assert_eq!(ctx.cmdline, "loom stage r");
+    assert_eq!(ctx.current_word, "r");
+    assert_eq!(ctx.prev_word, "stage");
+
+    // Missing or unparsable cursor completes at the end of the line
+    let args: Vec<String> = ["/p", "x", "loom ini"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    let ctx = CompletionContext::from_args("powershell", &args);
+    assert_eq!(ctx.current_word, "ini");
+    assert_eq!(ctx.prev_word, "loom");
+}
+
 #[test]
 fn test_complete_dynamic_init() {
     let temp_dir = setup_test_workspace();
@@ -11,6 +11,9 @@ fn test_complete_commands_all() {
     assert!(results.contains(&"stage".to_string()));
     assert!(results.contains(&"status".to_string()));
     assert!(results.contains(&"completions".to_string()));
+    assert!(results.contains(&"doctor".to_string()));
+    assert!(results.contains(&"signal".to_string()));
+    assert!(results.contains(&"merge".to_string()));
 }
 
 #[test]
@@ -37,6 +40,7 @@ fn test_complete_subcommands_stage() {
     assert!(results.contains(&"human-review".to_string()));
     assert!(results.contains(&"dispute-criteria".to_string()));
     assert!(results.contains(&"output".to_string()));
+    assert!(results.contains(&"claim".to_string()));
 }
 
 #[test]
@@ -70,6 +74,22 @@ fn test_complete_subcommands_sessions() {
     let results = complete_subcommands("sessions", "").unwrap();
     assert!(results.contains(&"list".to_string()));
     assert!(results.contains(&"kill".to_string()));
+    assert!(results.contains(&"focus".to_string()));
+}
+
+#[test]
+fn test_complete_subcommands_merge() {
+    let results = complete_subcommands("merge", "").unwrap();
+    assert_eq!(
+        results,
+        vec!["lock-status", "move", "pin", "queue", "unpin"]
+    );
+}
+
+#[test]
+fn test_complete_subcommands_signal() {
+    let results = complete_subcommands("signal", "").unwrap();
+    assert_eq!(results, vec!["refresh".to_string()]);
 }
 
 #[test]
@@ -102,6 +122,9 @@ fn test_complete_flags_run() {
     assert!(results.contains(&"--foreground".to_string()));
     assert!(results.contains(&"--max-parallel".to_string()));
     assert!(results.contains(&"--no-merge".to_string()));
+    assert!(results.contains(&"--until".to_string()));
+    assert!(results.contains(&"--allow-dirty".to_string()));
+    assert!(results.contains(&"--keep-terminal".to_string()));
 }
 
 #[test]
@@ -144,10 +167,12 @@ fn test_complete_flags_unknown_command() {
 #[test]
 fn test_complete_shell_types_all() {
     let results = complete_shell_types("").unwrap();
-    assert_eq!(results.len(), 3);
+    assert_eq!(results.len(), 5);
     assert!(results.contains(&"bash".to_string()));
     assert!(results.contains(&"zsh".to_string()));
     assert!(results.contains(&"fish".to_string()));
+    assert!(results.contains(&"nushell".to_string()));
+    assert!(results.contains(&"powershell".to_string()));
 }
 
 #[test]
@@ -179,6 +204,8 @@ fn test_complete_trigger_types_all() {
 fn test_has_subcommands() {
     assert!(has_subcommands("stage"));
     assert!(has_subcommands("sessions"));
+    assert!(has_subcommands("signal"));
+    assert!(has_subcommands("merge"));
     assert!(has_subcommands("worktree"));
     assert!(has_subcommands("knowledge"));
     assert!(has_subcommands("memory"));
@@ -197,7 +224,7 @@ fn test_complete_commands_includes_plan() {
 #[test]
 fn test_complete_subcommands_plan() {
     let results = complete_subcommands("plan", "").unwrap();
-    assert_eq!(results, vec!["verify".to_string()]);
+    assert_eq!(results, vec!["diff", "suggest", "verify"]);
 }
 
 #[test]
@@ -208,6 +235,12 @@ fn test_complete_flags_plan_verify() {
     assert!(results.contains(&"--strict".to_string()));
 }
 
+#[test]
+fn test_complete_flags_knowledge_show() {
+    let results = complete_flags(&["knowledge", "show"], "--").unwrap();
+    assert_eq!(results, vec!["--json".to_string(), "--tag".to_string()]);
+}
+
 #[test]
 fn test_complete_commands_includes_pressure() {
     let results = complete_commands("").unwrap();
@@ -232,6 +265,14 @@ fn test_pressure_has_no_subcommands() {
     assert!(!has_subcommands("pressure"));
 }
 
+#[test]
+fn test_complete_subcommands_mcp() {
+    assert!(complete_commands("").unwrap().contains(&"mcp".to_string()));
+    assert!(has_subcommands("mcp"));
+    let results = complete_subcommands("mcp", "").unwrap();
+    assert_eq!(results, vec!["serve".to_string()]);
+}
+
 #[test]
 fn test_plan_verify_positional_completes_plan_files() {
     use crate::completions::dynamic::complete_plan_files;
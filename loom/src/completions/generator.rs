@@ -9,6 +9,8 @@ pub enum Shell {
     Bash,
     Zsh,
     Fish,
+    Nushell,
+    PowerShell,
 }
 
 impl FromStr for Shell {
@@ -19,8 +21,10 @@ impl FromStr for Shell {
             "bash" => Ok(Shell::Bash),
             "zsh" => Ok(Shell::Zsh),
             "fish" => Ok(Shell::Fish),
+            "nu" | "nushell" => Ok(Shell::Nushell),
+            "powershell" | "pwsh" => Ok(Shell::PowerShell),
             _ => Err(anyhow!(
-                "Unsupported shell: {s}. Supported shells: bash, zsh, fish"
+                "Unsupported shell: {s}. Supported shells: bash, zsh, fish, nushell, powershell"
             )),
         }
     }
@@ -32,6 +36,8 @@ pub fn generate_completions(shell: Shell) {
         Shell::Bash => print!("{}", scripts::BASH_COMPLETION),
         Shell::Zsh => print!("{}", scripts::ZSH_COMPLETION),
         Shell::Fish => print!("{}", scripts::FISH_COMPLETION),
+        Shell::Nushell => print!("{}", scripts::NUSHELL_COMPLETION),
+        Shell::PowerShell => print!("{}", scripts::POWERSHELL_COMPLETION),
     }
 }
 
@@ -52,24 +58,31 @@ mod tests {
         assert_eq!(Shell::from_str("fish").unwrap(), Shell::Fish);
         assert_eq!(Shell::from_str("Fish").unwrap(), Shell::Fish);
         assert_eq!(Shell::from_str("FISH").unwrap(), Shell::Fish);
+
+        assert_eq!(Shell::from_str("nushell").unwrap(), Shell::Nushell);
+        assert_eq!(Shell::from_str("nu").unwrap(), Shell::Nushell);
+
+        assert_eq!(Shell::from_str("powershell").unwrap(), Shell::PowerShell);
+        assert_eq!(Shell::from_str("PowerShell").unwrap(), Shell::PowerShell);
+        assert_eq!(Shell::from_str("pwsh").unwrap(), Shell::PowerShell);
     }
 
     #[test]
     fn test_shell_from_str_invalid() {
-        assert!(Shell::from_str("powershell").is_err());
         assert!(Shell::from_str("cmd").is_err());
+        assert!(Shell::from_str("elvish").is_err());
         assert!(Shell::from_str("").is_err());
         assert!(Shell::from_str("invalid").is_err());
     }
 
     #[test]
     fn test_shell_from_str_error_message() {
-        let result = Shell::from_str("powershell");
+        let result = Shell::from_str("elvish");
         assert!(result.is_err());
         let err = result.unwrap_err();
         let err_msg = err.to_string();
         assert!(err_msg.contains("Unsupported shell"));
-        assert!(err_msg.contains("powershell"));
-        assert!(err_msg.contains("bash, zsh, fish"));
+        assert!(err_msg.contains("elvish"));
+        assert!(err_msg.contains("bash, zsh, fish, nushell, powershell"));
     }
 }
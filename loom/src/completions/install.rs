@@ -1,8 +1,16 @@
 use anyhow::{anyhow, Context, Result};
 use std::path::{Path, PathBuf};
 
-/// Detect the user's shell from $SHELL environment variable
+/// Detect the user's shell: the shell loom was started from, falling back
+/// to the login shell in $SHELL.
+///
+/// $SHELL alone names the login shell, which is wrong for a nushell or
+/// PowerShell session started from a bash login.
 pub fn detect_shell() -> Result<super::generator::Shell> {
+    if let Some(shell) = parent_shell() {
+        return Ok(shell);
+    }
+
     let shell_path = std::env::var("SHELL")
         .context("$SHELL not set — specify shell explicitly: loom completions --install bash")?;
 
@@ -14,6 +22,28 @@ pub fn detect_shell() -> Result<super::generator::Shell> {
     shell_name.parse()
 }
 
+/// The shell that started this process, from `ps`, if it is one loom supports.
+fn parent_shell() -> Option<super::generator::Shell> {
+    let ppid = std::os::unix::process::parent_id().to_string();
+    let output = std::process::Command::new("ps")
+        .args(["-o", "comm=", "-p", &ppid])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    shell_from_process_name(String::from_utf8_lossy(&output.stdout).trim())
+}
+
+/// Map a process name (`-zsh`, `/usr/bin/fish`, `pwsh.exe`) to a shell.
+fn shell_from_process_name(name: &str) -> Option<super::generator::Shell> {
+    let name = Path::new(name.trim_start_matches('-'))
+        .file_name()?
+        .to_str()?
+        .trim_end_matches(".exe");
+    name.parse().ok()
+}
+
 fn home_dir() -> Result<PathBuf> {
     std::env::var("HOME")
         .map(PathBuf::from)
@@ -38,6 +68,15 @@ pub fn install_path(shell: super::generator::Shell) -> Result<PathBuf> {
                 .unwrap_or_else(|_| home_dir().unwrap_or_default().join(".config"));
             Ok(config_dir.join("fish/completions/loom.fish"))
         }
+        Shell::Nushell => Ok(config_dir()?.join("nushell/completions/loom.nu")),
+        Shell::PowerShell => Ok(config_dir()?.join("powershell/loom-completions.ps1")),
+    }
+}
+
+fn config_dir() -> Result<PathBuf> {
+    match std::env::var("XDG_CONFIG_HOME") {
+        Ok(dir) => Ok(PathBuf::from(dir)),
+        Err(_) => Ok(home_dir()?.join(".config")),
     }
 }
 
@@ -83,18 +122,26 @@ pub fn install(shell: super::generator::Shell) -> Result<()> {
 
     let content = completion_content(shell);
 
-    if path.exists() {
-        eprintln!("Updating existing completions at: {}", path.display());
-    }
+    if std::fs::read_to_string(&path).is_ok_and(|existing| existing == content) {
+        eprintln!(
+            "{} completions already up to date at: {}",
+            shell_name(shell),
+            path.display()
+        );
+    } else {
+        if path.exists() {
+            eprintln!("Updating existing completions at: {}", path.display());
+        }
 
-    std::fs::write(&path, content)
-        .with_context(|| format!("Failed to write completions to: {}", path.display()))?;
+        std::fs::write(&path, content)
+            .with_context(|| format!("Failed to write completions to: {}", path.display()))?;
 
-    eprintln!(
-        "Installed {} completions to: {}",
-        shell_name(shell),
-        path.display()
-    );
+        eprintln!(
+            "Installed {} completions to: {}",
+            shell_name(shell),
+            path.display()
+        );
+    }
 
     let home = home_dir()?;
     match shell {
@@ -107,6 +154,14 @@ pub fn install(shell: super::generator::Shell) -> Result<()> {
             ensure_bashrc_completion(&home, &path)?;
         }
         Shell::Fish => {}
+        Shell::Nushell => {
+            let config = config_dir()?.join("nushell/config.nu");
+            ensure_rc_sources(&config, &format!("source {}", path.display()))?;
+        }
+        Shell::PowerShell => {
+            let profile = path.with_file_name("Microsoft.PowerShell_profile.ps1");
+            ensure_rc_sources(&profile, &format!(". '{}'", path.display()))?;
+        }
     }
 
     print_post_install(shell, &path);
@@ -150,6 +205,35 @@ fn ensure_bashrc_completion(home: &Path, completion_path: &Path) -> Result<()> {
     Ok(())
 }
 
+/// Ensure a shell startup file runs `line` to load the completion file.
+///
+/// Used for nushell's `config.nu` and the PowerShell profile, which have no
+/// auto-loaded completion directory.
+fn ensure_rc_sources(rc_file: &Path, line: &str) -> Result<()> {
+    let existing = if rc_file.exists() {
+        std::fs::read_to_string(rc_file)
+            .with_context(|| format!("Failed to read {}", rc_file.display()))?
+    } else {
+        String::new()
+    };
+
+    if existing.contains(RC_MARKER) || existing.contains(line) {
+        return Ok(());
+    }
+
+    if let Some(parent) = rc_file.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+    }
+
+    let updated = format!("{existing}\n{RC_MARKER}\n{line}\n");
+    std::fs::write(rc_file, updated)
+        .with_context(|| format!("Failed to write {}", rc_file.display()))?;
+
+    eprintln!("Configured {} to load loom completions.", rc_file.display());
+    Ok(())
+}
+
 /// Ensure ~/.zshrc has fpath and compinit configured for ~/.zfunc.
 fn ensure_zshrc_fpath(home: &Path) -> Result<()> {
     let zshrc = home.join(".zshrc");
@@ -199,6 +283,8 @@ fn completion_content(shell: super::generator::Shell) -> &'static str {
         Shell::Bash => super::scripts::BASH_COMPLETION,
         Shell::Zsh => super::scripts::ZSH_COMPLETION,
         Shell::Fish => super::scripts::FISH_COMPLETION,
+        Shell::Nushell => super::scripts::NUSHELL_COMPLETION,
+        Shell::PowerShell => super::scripts::POWERSHELL_COMPLETION,
     }
 }
 
@@ -209,6 +295,8 @@ fn shell_name(shell: super::generator::Shell) -> &'static str {
         Shell::Bash => "bash",
         Shell::Zsh => "zsh",
         Shell::Fish => "fish",
+        Shell::Nushell => "nushell",
+        Shell::PowerShell => "PowerShell",
     }
 }
 
@@ -333,5 +421,50 @@ fn completion_file_paths(home: &Path) -> Vec<PathBuf> {
         home.join(".local/share/bash-completion/completions/loom"),
         home.join(".zfunc/_loom"),
         home.join(".config/fish/completions/loom.fish"),
+        home.join(".config/nushell/completions/loom.nu"),
+        home.join(".config/powershell/loom-completions.ps1"),
     ]
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::completions::Shell;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_shell_from_process_name() {
+        assert_eq!(shell_from_process_name("-zsh"), Some(Shell::Zsh));
+        assert_eq!(shell_from_process_name("/usr/bin/fish"), Some(Shell::Fish));
+        assert_eq!(shell_from_process_name("nu"), Some(Shell::Nushell));
+        assert_eq!(shell_from_process_name("pwsh.exe"), Some(Shell::PowerShell));
+        assert_eq!(shell_from_process_name("cargo"), None);
+        assert_eq!(shell_from_process_name(""), None);
+    }
+
+    #[test]
+    fn test_rc_updates_are_idempotent() {
+        let temp = TempDir::new().unwrap();
+        let home = temp.path();
+        let completion = home.join(".local/share/bash-completion/completions/loom");
+
+        ensure_bashrc_completion(home, &completion).unwrap();
+        ensure_bashrc_completion(home, &completion).unwrap();
+        let bashrc = std::fs::read_to_string(home.join(".bashrc")).unwrap();
+        assert_eq!(bashrc.matches(RC_MARKER).count(), 1);
+        assert!(bashrc.contains(&format!("source {}", completion.display())));
+
+        std::fs::write(home.join(".zshrc"), "autoload -Uz compinit && compinit\n").unwrap();
+        ensure_zshrc_fpath(home).unwrap();
+        ensure_zshrc_fpath(home).unwrap();
+        let zshrc = std::fs::read_to_string(home.join(".zshrc")).unwrap();
+        assert_eq!(zshrc.matches("fpath=(~/.zfunc $fpath)").count(), 1);
+        assert!(zshrc.find("fpath=").unwrap() < zshrc.find("compinit").unwrap());
+
+        let config = home.join(".config/nushell/config.nu");
+        ensure_rc_sources(&config, "source loom.nu").unwrap();
+        ensure_rc_sources(&config, "source loom.nu").unwrap();
+        let config = std::fs::read_to_string(config).unwrap();
+        assert_eq!(config.matches("source loom.nu").count(), 1);
+    }
+}
@@ -1,7 +1,11 @@
 mod bash;
 mod fish;
+mod nushell;
+mod powershell;
 mod zsh;
 
 pub use bash::BASH_COMPLETION;
 pub use fish::FISH_COMPLETION;
+pub use nushell::NUSHELL_COMPLETION;
+pub use powershell::POWERSHELL_COMPLETION;
 pub use zsh::ZSH_COMPLETION;
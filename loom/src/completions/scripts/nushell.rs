@@ -0,0 +1,31 @@
+pub const NUSHELL_COMPLETION: &str = r##"# loom shell completions for nushell
+# Generated by: loom completions nushell
+# Install: loom completions --install nushell
+
+# nushell has a single external completer for all commands; keep whatever
+# completer was configured before and only handle `loom` here.
+let loom_previous_completer = $env.config?.completions?.external?.completer?
+
+let loom_completer = {|spans: list<string>|
+    let completions = (^loom complete nushell $env.PWD ...$spans | complete)
+    if $completions.exit_code != 0 {
+        return null
+    }
+    let values = ($completions.stdout | lines | where {|line| $line != "" })
+    if ($values | is-empty) {
+        # Fall back to nushell's file completion (e.g. `loom init <plan>`)
+        null
+    } else {
+        $values | each {|value| { value: $value } }
+    }
+}
+
+$env.config.completions.external.enable = true
+$env.config.completions.external.completer = {|spans: list<string>|
+    if ($spans | first) == "loom" {
+        do $loom_completer $spans
+    } else if $loom_previous_completer != null {
+        do $loom_previous_completer $spans
+    }
+}
+"##;
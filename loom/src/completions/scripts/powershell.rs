@@ -0,0 +1,22 @@
+pub const POWERSHELL_COMPLETION: &str = r##"# loom shell completions for PowerShell
+# Generated by: loom completions powershell
+# Install: loom completions --install powershell
+
+Register-ArgumentCompleter -Native -CommandName loom -ScriptBlock {
+    param($wordToComplete, $commandAst, $cursorPosition)
+
+    # Windows PowerShell drops empty arguments to native commands, so pass the
+    # cursor offset into the command text instead of the (possibly empty)
+    # current and previous words; loom derives them from the two.
+    $line = $commandAst.Extent.Text
+    $cursor = $cursorPosition - $commandAst.Extent.StartOffset
+
+    $completions = @(& loom complete powershell "$PWD" "$cursor" "$line" 2>$null)
+    foreach ($completion in $completions) {
+        if ($completion) {
+            [System.Management.Automation.CompletionResult]::new(
+                $completion, $completion, 'ParameterValue', $completion)
+        }
+    }
+}
+"##;
@@ -6,6 +6,9 @@ pub use protocol::{
     StageCompletionInfo, StageInfo,
 };
 pub use server::{
-    admin_token_path, collect_completion_summary, handle_dispute_criteria, read_admin_token,
-    read_auth_token, read_user_token, DaemonServer, DaemonStatus,
+    admin_token_path, collect_completion_summary, compute_status_line, handle_dispute_criteria,
+    read_admin_token, read_auth_token, read_daemon_config, read_daemon_health, read_events_url,
+    read_status_line, read_supervisor_state, read_user_token, stop_supervisor, DaemonCrash,
+    DaemonHealth, DaemonServer, DaemonStatus, EventsConfig, StaleSession, StatusLine,
+    SupervisorState,
 };
@@ -5,6 +5,7 @@ use std::io::{Read, Write};
 
 use crate::models::stage::StageStatus;
 use crate::models::worktree::WorktreeStatus;
+use crate::orchestrator::{RunDeadline, SimulationSchedule};
 
 /// Information about a single stage's completion status.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -67,6 +68,19 @@ pub struct DaemonConfig {
     pub watch_mode: bool,
     /// Auto-merge completed stages (default: true, disable with --no-merge)
     pub auto_merge: bool,
+    /// Stop once this stage and its dependencies complete (maps to --until)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub until: Option<String>,
+    /// Restarted by a supervisor when it dies (maps to --supervised)
+    #[serde(default)]
+    pub supervised: bool,
+    /// Wall-clock limit of the run (maps to --max-runtime and
+    /// --handoff-on-timeout)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deadline: Option<RunDeadline>,
+    /// Schedule of a simulated run (maps to --simulate)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub simulation: Option<SimulationSchedule>,
 }
 
 impl Default for DaemonConfig {
@@ -76,6 +90,10 @@ impl Default for DaemonConfig {
             max_parallel: None,
             watch_mode: true,
             auto_merge: true,
+            until: None,
+            supervised: false,
+            deadline: None,
+            simulation: None,
         }
     }
 }
@@ -187,6 +205,9 @@ pub struct StageInfo {
     /// Whether the monitor has flagged this session as possibly stuck
     #[serde(default)]
     pub is_possibly_stuck: bool,
+    /// Person the stage is assigned to (`loom stage assign`)
+    #[serde(default)]
+    pub assignee: Option<String>,
 }
 
 /// Write a length-prefixed JSON message to a stream.
@@ -304,6 +325,7 @@ mod tests {
                 dependencies: vec!["stage-0".to_string()],
                 model: "opus".to_string(),
                 is_possibly_stuck: false,
+                assignee: None,
             }],
             stages_pending: vec![StageInfo {
                 id: "stage-2".to_string(),
@@ -317,6 +339,7 @@ mod tests {
                 dependencies: vec!["stage-1".to_string()],
                 model: "sonnet".to_string(),
                 is_possibly_stuck: false,
+                assignee: None,
             }],
             stages_completed: vec![StageInfo {
                 id: "stage-0".to_string(),
@@ -330,6 +353,7 @@ mod tests {
                 dependencies: vec![],
                 model: "opus".to_string(),
                 is_possibly_stuck: false,
+                assignee: None,
             }],
             stages_blocked: vec![],
         };
@@ -372,6 +396,9 @@ mod tests {
         assert!(config.max_parallel.is_none());
         assert!(config.watch_mode);
         assert!(config.auto_merge);
+        assert!(config.until.is_none());
+        assert!(config.deadline.is_none());
+        assert!(config.simulation.is_none());
     }
 
     #[test]
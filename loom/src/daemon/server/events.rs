@@ -0,0 +1,313 @@
+//! Server-sent event stream of monitor events.
+//!
+//! The daemon serves `GET /events` over HTTP on a loopback port so editor
+//! plugins and dashboards can react to stage transitions, heartbeats, merge
+//! sessions and crashes as they happen instead of polling `loom status`.
+//!
+//! * The listener binds `127.0.0.1` only. The port comes from `[events] port`
+//!   in `.work/config.toml` (default `0`: any free port) and the resulting URL
+//!   is written to `.work/orchestrator.events`.
+//! * Clients authenticate with the user token (`.work/user.token`), either as
+//!   `Authorization: Bearer <token>` or as `?token=<token>` for clients such
+//!   as browser `EventSource` that cannot set headers.
+//! * Each monitor event is one SSE message: `event:` is the event kind and
+//!   `data:` the JSON-serialized [`MonitorEvent`]. A comment line is sent
+//!   every [`KEEP_ALIVE_INTERVAL`] so dead clients are noticed and dropped.
+//!
+//! ```toml
+//! [events]
+//! enabled = true   # false = no HTTP listener
+//! port = 0         # fixed port, or 0 for any free port
+//! ```
+
+use super::super::protocol::Capability;
+use super::client::verify_for_capability;
+use super::core::{DaemonServer, MAX_CONNECTIONS};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::{ErrorKind, Read, Write};
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{Receiver, RecvTimeoutError};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use crate::fs::work_dir::read_events_config;
+use crate::orchestrator::monitor::MonitorEvent;
+
+/// File under `.work/` holding the URL of the event stream.
+pub(super) const EVENTS_URL_FILE: &str = "orchestrator.events";
+
+/// Interval between keep-alive comments on idle streams.
+const KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(15);
+
+/// How long a client may take to send its request head.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How long a write to a subscriber may block before it is dropped.
+const WRITE_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Largest request head accepted, in bytes.
+const MAX_REQUEST_BYTES: usize = 8 * 1024;
+
+/// Persisted `[events]` section of `.work/config.toml`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct EventsConfig {
+    /// Serve the `/events` stream from the daemon.
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// Loopback port to listen on; `0` picks any free port.
+    #[serde(default)]
+    pub port: u16,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+impl Default for EventsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            port: 0,
+        }
+    }
+}
+
+/// URL of the running daemon's event stream, if it serves one.
+pub fn read_events_url(work_dir: &Path) -> Option<String> {
+    fs::read_to_string(work_dir.join(EVENTS_URL_FILE))
+        .ok()
+        .map(|url| url.trim().to_string())
+        .filter(|url| !url.is_empty())
+}
+
+/// Bind the event stream listener and spawn the thread serving it.
+///
+/// Returns `None` when the stream is disabled or the listener cannot be bound;
+/// the daemon runs normally either way, and the orchestrator's event sink just
+/// has no receiver.
+pub fn spawn_event_server(
+    server: &DaemonServer,
+    events: Receiver<MonitorEvent>,
+) -> Option<JoinHandle<()>> {
+    let config = read_events_config(&server.work_dir).unwrap_or_default();
+    if !config.enabled {
+        return None;
+    }
+
+    let listener = match bind_listener(&server.work_dir, config.port) {
+        Ok(listener) => listener,
+        Err(e) => {
+            eprintln!("Event stream disabled: {e:#}");
+            return None;
+        }
+    };
+
+    let work_dir = server.work_dir.clone();
+    let shutdown_flag = Arc::clone(&server.shutdown_flag);
+
+    Some(thread::spawn(move || {
+        run_event_server(listener, events, &work_dir, shutdown_flag);
+    }))
+}
+
+/// Bind `127.0.0.1:<port>` and publish the stream URL.
+fn bind_listener(work_dir: &Path, port: u16) -> Result<TcpListener> {
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port))
+        .with_context(|| format!("Failed to bind 127.0.0.1:{port}"))?;
+    listener
+        .set_nonblocking(true)
+        .context("Failed to set event listener to non-blocking")?;
+    let addr = listener
+        .local_addr()
+        .context("Failed to read event listener address")?;
+    let url = format!("http://{addr}/events");
+    fs::write(work_dir.join(EVENTS_URL_FILE), format!("{url}\n"))
+        .context("Failed to write event stream URL")?;
+    println!("Event stream listening on {url}");
+    Ok(listener)
+}
+
+/// Accept clients and fan monitor events out to them until shutdown.
+pub(super) fn run_event_server(
+    listener: TcpListener,
+    events: Receiver<MonitorEvent>,
+    work_dir: &Path,
+    shutdown_flag: Arc<AtomicBool>,
+) {
+    let subscribers: Arc<Mutex<Vec<TcpStream>>> = Arc::new(Mutex::new(Vec::new()));
+    let mut last_write = Instant::now();
+
+    while !shutdown_flag.load(Ordering::Relaxed) {
+        loop {
+            match listener.accept() {
+                Ok((stream, _addr)) => {
+                    let subscribers = Arc::clone(&subscribers);
+                    let work_dir = work_dir.to_path_buf();
+                    thread::spawn(move || accept_subscriber(stream, &work_dir, &subscribers));
+                }
+                Err(ref e) if e.kind() == ErrorKind::WouldBlock => break,
+                Err(e) => {
+                    eprintln!("Event stream accept error: {e}");
+                    break;
+                }
+            }
+        }
+
+        match events.recv_timeout(Duration::from_millis(100)) {
+            Ok(event) => {
+                broadcast(&subscribers, &sse_frame(&event));
+                last_write = Instant::now();
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                if last_write.elapsed() >= KEEP_ALIVE_INTERVAL {
+                    broadcast(&subscribers, ": keep-alive\n\n");
+                    last_write = Instant::now();
+                }
+            }
+            // The orchestrator finished; keep serving keep-alives until the
+            // daemon shuts down.
+            Err(RecvTimeoutError::Disconnected) => thread::sleep(Duration::from_millis(100)),
+        }
+    }
+}
+
+/// Format one monitor event as an SSE message.
+pub(super) fn sse_frame(event: &MonitorEvent) -> String {
+    let data = serde_json::to_value(event).unwrap_or_default();
+    let kind = data
+        .get("event")
+        .and_then(|kind| kind.as_str())
+        .unwrap_or("message")
+        .to_string();
+    format!("event: {kind}\ndata: {data}\n\n")
+}
+
+/// Write `frame` to every subscriber, dropping those that fail.
+fn broadcast(subscribers: &Mutex<Vec<TcpStream>>, frame: &str) {
+    let mut subscribers = subscribers.lock().unwrap_or_else(PoisonError::into_inner);
+    subscribers.retain_mut(|stream| {
+        stream
+            .write_all(frame.as_bytes())
+            .and_then(|()| stream.flush())
+            .is_ok()
+    });
+}
+
+/// Stream headers sent to an accepted subscriber.
+const STREAM_HEADERS: &[u8] = b"HTTP/1.1 200 OK\r\n\
+    Content-Type: text/event-stream\r\n\
+    Cache-Control: no-cache\r\n\
+    Connection: keep-alive\r\n\r\n\
+    : connected\n\n";
+
+/// Read the request, authenticate it, and register the stream on success.
+///
+/// The stream headers are written while holding the subscriber lock, so a
+/// client that has seen them receives every event broadcast afterwards.
+fn accept_subscriber(mut stream: TcpStream, work_dir: &Path, subscribers: &Mutex<Vec<TcpStream>>) {
+    let status = match authenticate(&mut stream, work_dir) {
+        Ok(()) => {
+            let mut subscribers = subscribers.lock().unwrap_or_else(PoisonError::into_inner);
+            if subscribers.len() < MAX_CONNECTIONS {
+                if stream.write_all(STREAM_HEADERS).is_ok() {
+                    subscribers.push(stream);
+                }
+                return;
+            }
+            eprintln!("Event stream subscriber limit reached ({MAX_CONNECTIONS}), rejecting");
+            "503 Service Unavailable"
+        }
+        Err(status) => status,
+    };
+    let _ = write!(
+        stream,
+        "HTTP/1.1 {status}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+    );
+}
+
+/// Validate a `GET /events` request and its token.
+///
+/// Returns the HTTP status line to reply with when the request is refused.
+fn authenticate(stream: &mut TcpStream, work_dir: &Path) -> std::result::Result<(), &'static str> {
+    const BAD_REQUEST: &str = "400 Bad Request";
+
+    stream.set_nonblocking(false).map_err(|_| BAD_REQUEST)?;
+    stream
+        .set_read_timeout(Some(REQUEST_TIMEOUT))
+        .map_err(|_| BAD_REQUEST)?;
+    stream
+        .set_write_timeout(Some(WRITE_TIMEOUT))
+        .map_err(|_| BAD_REQUEST)?;
+
+    let head = read_request_head(stream).ok_or(BAD_REQUEST)?;
+    let request = parse_request(&head).ok_or(BAD_REQUEST)?;
+    if request.method != "GET" {
+        return Err("405 Method Not Allowed");
+    }
+    if request.path != "/events" {
+        return Err("404 Not Found");
+    }
+    let authorized = request
+        .token
+        .is_some_and(|token| verify_for_capability(work_dir, token, Capability::User));
+    if !authorized {
+        return Err("401 Unauthorized");
+    }
+    Ok(())
+}
+
+/// Read up to the blank line ending the request head.
+fn read_request_head(stream: &mut TcpStream) -> Option<String> {
+    let mut head = Vec::new();
+    let mut buf = [0u8; 1024];
+    while !head.windows(4).any(|w| w == b"\r\n\r\n") {
+        if head.len() > MAX_REQUEST_BYTES {
+            return None;
+        }
+        let n = stream.read(&mut buf).ok()?;
+        if n == 0 {
+            return None;
+        }
+        head.extend_from_slice(&buf[..n]);
+    }
+    String::from_utf8(head).ok()
+}
+
+/// The parts of an HTTP request the event stream cares about.
+#[derive(Debug, PartialEq)]
+pub(super) struct EventsRequest<'a> {
+    pub method: &'a str,
+    pub path: &'a str,
+    /// Bearer token from the `Authorization` header, else the `token` query
+    /// parameter.
+    pub token: Option<&'a str>,
+}
+
+/// Parse a request head into method, path and auth token.
+pub(super) fn parse_request(head: &str) -> Option<EventsRequest<'_>> {
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next()?.split_whitespace();
+    let method = request_line.next()?;
+    let target = request_line.next()?;
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+
+    let header_token = lines
+        .filter_map(|line| line.split_once(':'))
+        .find(|(name, _)| name.trim().eq_ignore_ascii_case("authorization"))
+        .and_then(|(_, value)| value.trim().strip_prefix("Bearer "))
+        .map(str::trim);
+    let query_token = query
+        .split('&')
+        .find_map(|pair| pair.strip_prefix("token="));
+
+    Some(EventsRequest {
+        method,
+        path,
+        token: header_token.or(query_token),
+    })
+}
@@ -0,0 +1,86 @@
+//! Daemon health heartbeat.
+//!
+//! The live daemon rewrites `.work/daemon.health.json` every
+//! [`HEARTBEAT_INTERVAL`] and removes it on a clean exit. A supervisor treats
+//! a stale heartbeat as a hung daemon, and a heartbeat left behind by a dead
+//! process tells `loom status` the daemon died without shutting down.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+/// File under `.work/` holding the daemon's [`DaemonHealth`].
+pub(super) const HEALTH_FILE: &str = "daemon.health.json";
+
+/// How often the daemon refreshes its heartbeat.
+pub(super) const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Heartbeat age after which the daemon is considered hung.
+const HEARTBEAT_STALE_AFTER: Duration = Duration::from_secs(60);
+
+/// Last heartbeat of a daemon process.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DaemonHealth {
+    pub pid: u32,
+    pub started_at: DateTime<Utc>,
+    pub heartbeat_at: DateTime<Utc>,
+    /// Whether the daemon runs under `loom run --supervised`
+    #[serde(default)]
+    pub supervised: bool,
+}
+
+impl DaemonHealth {
+    /// Whether the heartbeat is older than [`HEARTBEAT_STALE_AFTER`].
+    pub fn is_stale(&self, now: DateTime<Utc>) -> bool {
+        (now - self.heartbeat_at)
+            .to_std()
+            .is_ok_and(|age| age > HEARTBEAT_STALE_AFTER)
+    }
+}
+
+/// Heartbeat of the current (or last, if it died) daemon.
+pub fn read_daemon_health(work_dir: &Path) -> Option<DaemonHealth> {
+    let content = fs::read_to_string(work_dir.join(HEALTH_FILE)).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Record a heartbeat. Best-effort: a failed write only shows up as a stale
+/// heartbeat.
+pub(super) fn write_daemon_health(work_dir: &Path, health: &DaemonHealth) {
+    let Ok(json) = serde_json::to_string_pretty(health) else {
+        return;
+    };
+    // Write-then-rename so readers never see a partial file
+    let tmp = work_dir.join(format!("{HEALTH_FILE}.tmp"));
+    if fs::write(&tmp, json).is_ok() {
+        let _ = fs::rename(&tmp, work_dir.join(HEALTH_FILE));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_daemon_health_round_trip_and_staleness() {
+        let temp = TempDir::new().unwrap();
+        let now = Utc::now();
+        let health = DaemonHealth {
+            pid: 42,
+            started_at: now,
+            heartbeat_at: now,
+            supervised: true,
+        };
+        assert_eq!(read_daemon_health(temp.path()), None);
+        write_daemon_health(temp.path(), &health);
+        assert_eq!(read_daemon_health(temp.path()), Some(health.clone()));
+
+        assert!(!health.is_stale(now + chrono::Duration::seconds(30)));
+        assert!(health.is_stale(now + chrono::Duration::seconds(61)));
+        // A heartbeat from the future (clock skew) is not stale
+        assert!(!health.is_stale(now - chrono::Duration::seconds(120)));
+    }
+}
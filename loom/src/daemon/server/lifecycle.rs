@@ -4,8 +4,12 @@ use super::super::protocol::{read_message, write_message, Request, Response};
 use super::broadcast::{spawn_log_tailer, spawn_status_broadcaster};
 use super::client::{admin_token_path, handle_client_connection, USER_TOKEN_FILE};
 use super::core::{DaemonServer, MAX_CONNECTIONS};
-use super::orchestrator::spawn_orchestrator;
+use super::events::{spawn_event_server, EVENTS_URL_FILE};
+use super::health::{write_daemon_health, DaemonHealth, HEALTH_FILE, HEARTBEAT_INTERVAL};
+use super::orchestrator::{spawn_orchestrator, DAEMON_CONFIG_FILE};
+use super::status_line::{write_status_line, STATUS_LINE_FILE, STATUS_LINE_INTERVAL};
 use anyhow::{bail, Context, Result};
+use chrono::Utc;
 use nix::unistd::{close, fork, pipe, setsid, ForkResult};
 use std::fs::{self, File, Permissions};
 use std::os::unix::fs::PermissionsExt;
@@ -13,9 +17,12 @@ use std::os::unix::io::AsRawFd;
 use std::os::unix::net::{UnixListener, UnixStream};
 use std::path::Path;
 use std::sync::atomic::Ordering;
-use std::sync::Arc;
+use std::sync::{mpsc, Arc};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
+
+use crate::fs::logs::{create_log, rotate_logs, LogsConfig, ROTATION_INTERVAL};
+use crate::orchestrator::throttle::THROTTLE_STATE_FILE;
 
 /// Generate a 64-character hex token from 32 cryptographically-strong bytes.
 ///
@@ -104,6 +111,14 @@ impl DaemonServer {
     /// # Returns
     /// `Ok(())` on success, error if daemon fails to start
     pub fn start(&self) -> Result<()> {
+        let success_pipe = self.detach()?;
+        self.serve_detached(Some(success_pipe))
+    }
+
+    /// Double-fork away from the terminal. Returns in the detached
+    /// grandchild with the write end of the start pipe; the calling process
+    /// exits once a byte (success) or EOF (failure) arrives on it.
+    pub(super) fn detach(&self) -> Result<std::os::fd::OwnedFd> {
         // Create pipe for error propagation from grandchild to original parent.
         // The success byte is written by `run_server` only AFTER the socket is
         // bound (see A-1/O-7), so the parent's `loom run` exits 0 only when the
@@ -153,6 +168,13 @@ impl DaemonServer {
             }
         }
 
+        Ok(write_fd)
+    }
+
+    /// Become the daemon in a detached process: take the singleton lock,
+    /// write the control-plane files, redirect output to the log and serve.
+    /// `success_pipe` is signalled once the socket is bound.
+    pub(super) fn serve_detached(&self, success_pipe: Option<std::os::fd::OwnedFd>) -> Result<()> {
         // CRITICAL (A-1/O-7): Acquire the singleton flock BEFORE any destructive
         // op (socket unlink, PID overwrite, token regeneration, log truncation).
         // A losing race or a corrupt lock must NOT delete the live daemon's
@@ -217,7 +239,7 @@ impl DaemonServer {
             .context("Failed to set user token file permissions")?;
 
         // Redirect stdout and stderr to log file
-        let log_file = File::create(&self.log_path).context("Failed to create log file")?;
+        let log_file = create_log(&self.log_path).context("Failed to create log file")?;
 
         // Close stdin and redirect stdout/stderr to log file
         close(0).ok();
@@ -230,7 +252,7 @@ impl DaemonServer {
 
         // Run the server. The success byte is signaled to the original parent
         // from inside `run_server`, immediately after the socket bind succeeds.
-        self.run_server(lock_guard, Some(write_fd))
+        self.run_server(lock_guard, success_pipe)
     }
 
     /// Run the daemon in foreground (for testing).
@@ -383,8 +405,12 @@ impl DaemonServer {
             .set_nonblocking(true)
             .context("Failed to set socket to non-blocking")?;
 
-        // Spawn the orchestrator thread to actually run stages
-        let orchestrator_handle = spawn_orchestrator(self);
+        // Spawn the event stream server, then the orchestrator thread that
+        // feeds it and actually runs stages
+        let (event_sink, events) = mpsc::channel();
+        let event_server_handle = spawn_event_server(self, events);
+        let event_sink = event_server_handle.is_some().then_some(event_sink);
+        let orchestrator_handle = spawn_orchestrator(self, event_sink);
 
         // Spawn log tailing thread
         let log_tail_handle = spawn_log_tailer(self);
@@ -392,7 +418,50 @@ impl DaemonServer {
         // Spawn status broadcasting thread
         let status_broadcast_handle = spawn_status_broadcaster(self);
 
+        let mut health = DaemonHealth {
+            pid: std::process::id(),
+            started_at: Utc::now(),
+            heartbeat_at: Utc::now(),
+            supervised: self.config.supervised,
+        };
+        write_daemon_health(&self.work_dir, &health);
+        let mut last_heartbeat = Instant::now();
+        write_status_line(&self.work_dir);
+        let mut last_status_line = Instant::now();
+        let mut last_log_rotation = Instant::now();
+        let mut orchestrator_died = false;
+
         while !self.shutdown_flag.load(Ordering::SeqCst) {
+            if last_heartbeat.elapsed() >= HEARTBEAT_INTERVAL {
+                health.heartbeat_at = Utc::now();
+                write_daemon_health(&self.work_dir, &health);
+                last_heartbeat = Instant::now();
+            }
+            if last_status_line.elapsed() >= STATUS_LINE_INTERVAL {
+                write_status_line(&self.work_dir);
+                last_status_line = Instant::now();
+            }
+            if last_log_rotation.elapsed() >= ROTATION_INTERVAL {
+                let config = LogsConfig::load(&self.work_dir);
+                if let Err(e) = rotate_logs(&self.work_dir, &config, false) {
+                    eprintln!("Failed to rotate logs: {e:#}");
+                }
+                last_log_rotation = Instant::now();
+            }
+            // Unsupervised, a daemon whose orchestrator died keeps serving
+            // status; supervised, it exits so the supervisor restarts it
+            if self.config.supervised
+                && orchestrator_handle
+                    .as_ref()
+                    .is_some_and(|handle| handle.is_finished())
+                && !self.shutdown_flag.load(Ordering::SeqCst)
+            {
+                eprintln!("Orchestrator thread exited unexpectedly, shutting down");
+                orchestrator_died = true;
+                self.shutdown_flag.store(true, Ordering::SeqCst);
+                break;
+            }
+
             match listener.accept() {
                 Ok((stream, _addr)) => {
                     // Atomically increment connection count and check limit
@@ -462,8 +531,14 @@ impl DaemonServer {
             wait_with_timeout(handle, "log_tail");
         }
         wait_with_timeout(status_broadcast_handle, "status_broadcast");
+        if let Some(handle) = event_server_handle {
+            wait_with_timeout(handle, "event_server");
+        }
 
         self.cleanup()?;
+        if orchestrator_died {
+            bail!("Orchestrator thread exited unexpectedly");
+        }
         Ok(())
     }
 
@@ -510,6 +585,31 @@ impl DaemonServer {
                 return Err(e).context("Failed to remove user.token file");
             }
         }
+        if let Err(e) = fs::remove_file(self.work_dir.join(EVENTS_URL_FILE)) {
+            if e.kind() != std::io::ErrorKind::NotFound {
+                return Err(e).context("Failed to remove event stream URL file");
+            }
+        }
+        if let Err(e) = fs::remove_file(self.work_dir.join(DAEMON_CONFIG_FILE)) {
+            if e.kind() != std::io::ErrorKind::NotFound {
+                return Err(e).context("Failed to remove daemon config file");
+            }
+        }
+        if let Err(e) = fs::remove_file(self.work_dir.join(HEALTH_FILE)) {
+            if e.kind() != std::io::ErrorKind::NotFound {
+                return Err(e).context("Failed to remove daemon health file");
+            }
+        }
+        if let Err(e) = fs::remove_file(self.work_dir.join(STATUS_LINE_FILE)) {
+            if e.kind() != std::io::ErrorKind::NotFound {
+                return Err(e).context("Failed to remove status line cache");
+            }
+        }
+        if let Err(e) = fs::remove_file(self.work_dir.join(THROTTLE_STATE_FILE)) {
+            if e.kind() != std::io::ErrorKind::NotFound {
+                return Err(e).context("Failed to remove throttle state file");
+            }
+        }
         let admin_path = admin_token_path(&self.work_dir);
         if let Err(e) = fs::remove_file(&admin_path) {
             if e.kind() != std::io::ErrorKind::NotFound {
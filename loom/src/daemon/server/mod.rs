@@ -4,9 +4,13 @@ mod broadcast;
 mod client;
 mod core;
 mod dispute;
+mod events;
+mod health;
 mod lifecycle;
 mod orchestrator;
 mod status;
+mod status_line;
+mod supervisor;
 
 #[cfg(test)]
 mod tests;
@@ -14,4 +18,9 @@ mod tests;
 pub use client::{admin_token_path, read_admin_token, read_auth_token, read_user_token};
 pub use core::{DaemonServer, DaemonStatus};
 pub use dispute::handle_dispute_criteria;
+pub use events::{read_events_url, EventsConfig};
+pub use health::{read_daemon_health, DaemonHealth};
+pub use orchestrator::read_daemon_config;
 pub use status::collect_completion_summary;
+pub use status_line::{compute_status_line, read_status_line, StaleSession, StatusLine};
+pub use supervisor::{read_supervisor_state, stop_supervisor, DaemonCrash, SupervisorState};
@@ -6,6 +6,7 @@ use anyhow::{Context, Result};
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::Sender;
 use std::sync::Arc;
 use std::thread::{self, JoinHandle};
 use std::time::Duration;
@@ -13,20 +14,42 @@ use std::time::Duration;
 use crate::fs::mark_plan_done_if_all_merged;
 use crate::fs::parse_base_branch_from_config;
 use crate::fs::work_dir::WorkDir;
+use crate::orchestrator::monitor::MonitorEvent;
+use crate::orchestrator::terminal::mock::SIMULATED_POLL_INTERVAL;
 use crate::orchestrator::{Orchestrator, OrchestratorConfig};
 use crate::plan::graph::ExecutionGraph;
 use crate::plan::schema::SandboxConfig;
 
+/// File under `.work/` recording the running daemon's [`DaemonConfig`], so
+/// other commands can tell how it schedules stages.
+pub(super) const DAEMON_CONFIG_FILE: &str = "orchestrator.config.json";
+
+/// Configuration of the running daemon, if it recorded one.
+pub fn read_daemon_config(work_dir: &Path) -> Option<DaemonConfig> {
+    let content = fs::read_to_string(work_dir.join(DAEMON_CONFIG_FILE)).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
 /// Spawn the orchestrator thread to execute stages.
 ///
 /// Returns a join handle for the orchestrator thread.
-pub fn spawn_orchestrator(server: &DaemonServer) -> Option<JoinHandle<()>> {
+pub fn spawn_orchestrator(
+    server: &DaemonServer,
+    event_sink: Option<Sender<MonitorEvent>>,
+) -> Option<JoinHandle<()>> {
     let work_dir = server.work_dir.clone();
     let daemon_config = server.config.clone();
     let shutdown_flag = Arc::clone(&server.shutdown_flag);
 
+    // Best-effort: only `loom status --explain` reads it.
+    if let Ok(json) = serde_json::to_string_pretty(&daemon_config) {
+        if let Err(e) = fs::write(work_dir.join(DAEMON_CONFIG_FILE), json) {
+            eprintln!("Warning: Failed to record daemon config: {e}");
+        }
+    }
+
     Some(thread::spawn(move || {
-        if let Err(e) = run_orchestrator(&work_dir, &daemon_config, shutdown_flag) {
+        if let Err(e) = run_orchestrator(&work_dir, &daemon_config, shutdown_flag, event_sink) {
             eprintln!("Orchestrator error: {e}");
         }
     }))
@@ -37,6 +60,7 @@ fn run_orchestrator(
     work_dir: &Path,
     daemon_config: &DaemonConfig,
     shutdown_flag: Arc<AtomicBool>,
+    event_sink: Option<Sender<MonitorEvent>>,
 ) -> Result<()> {
     // Build execution graph from stage files
     let (graph, plan_sandbox) = build_execution_graph(work_dir)?;
@@ -68,7 +92,10 @@ fn run_orchestrator(
     // Configure orchestrator using daemon config
     let config = OrchestratorConfig {
         max_parallel_sessions: daemon_config.max_parallel.unwrap_or(4),
-        poll_interval: Duration::from_secs(5),
+        poll_interval: match daemon_config.simulation {
+            Some(_) => SIMULATED_POLL_INTERVAL,
+            None => Duration::from_secs(5),
+        },
         manual_mode: daemon_config.manual_mode,
         watch_mode: daemon_config.watch_mode,
         work_dir: work_dir.to_path_buf(),
@@ -81,6 +108,10 @@ fn run_orchestrator(
         max_skill_recommendations: 5,
         sandbox_config: plan_sandbox,
         shutdown_flag: Some(shutdown_flag.clone()),
+        until: daemon_config.until.clone(),
+        deadline: daemon_config.deadline,
+        event_sink,
+        simulation: daemon_config.simulation.clone(),
     };
 
     // Create and run orchestrator
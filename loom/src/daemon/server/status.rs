@@ -9,6 +9,7 @@ use std::path::{Path, PathBuf};
 use std::process::Command;
 
 use crate::git::branch::{branch_name_for_stage, resolve_target_branch};
+use crate::git::worktree::get_worktree_path;
 use crate::models::stage::{Stage, StatusBucket};
 use crate::models::worktree::WorktreeStatus;
 use crate::parser::frontmatter::{extract_yaml_frontmatter, parse_from_markdown};
@@ -77,6 +78,7 @@ pub fn collect_status(work_dir: &Path) -> Result<Response> {
                                 dependencies: stage.dependencies,
                                 model,
                                 is_possibly_stuck,
+                                assignee: stage.assignee,
                             };
 
                             // D-5: categorize via the canonical StageStatus::bucket()
@@ -155,7 +157,7 @@ fn detect_worktree_status_with_target(
     repo_root: &Path,
     target_branch: &str,
 ) -> Option<WorktreeStatus> {
-    let worktree_path = repo_root.join(".worktrees").join(stage_id);
+    let worktree_path = get_worktree_path(stage_id, repo_root);
 
     if !worktree_path.exists() {
         return None;
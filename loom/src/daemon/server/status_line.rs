@@ -0,0 +1,229 @@
+//! Cached one-line status summary for terminal status bars.
+//!
+//! The live daemon rewrites `.work/status-line.json` every
+//! [`STATUS_LINE_INTERVAL`] with stage counts and the oldest stale session,
+//! read from stage and heartbeat files only (no git, no process checks).
+//! `loom tmux-status` prints it from the cache, so a tmux `status-right`
+//! polling every few seconds costs one small file read. Without a live daemon
+//! the summary is computed on the spot.
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::models::constants::STALENESS_THRESHOLD_SECS;
+use crate::models::stage::StatusBucket;
+use crate::orchestrator::monitor::heartbeat::read_heartbeat;
+use crate::verify::transitions::list_all_stages;
+
+/// File under `.work/` holding the cached [`StatusLine`].
+pub(super) const STATUS_LINE_FILE: &str = "status-line.json";
+
+/// How often the daemon refreshes the cached status line.
+pub(super) const STATUS_LINE_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Cache age after which readers compute the summary themselves.
+const STATUS_LINE_STALE_AFTER: Duration = Duration::from_secs(30);
+
+/// Executing session whose heartbeat has gone quiet.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StaleSession {
+    pub stage_id: String,
+    /// Seconds since the session's last heartbeat
+    pub silent_secs: u64,
+}
+
+/// Stage counts and the oldest stale session.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatusLine {
+    pub updated_at: DateTime<Utc>,
+    pub total: usize,
+    pub running: usize,
+    pub pending: usize,
+    pub completed: usize,
+    /// Blocked, conflicted and review-waiting stages
+    pub failed: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oldest_stale: Option<StaleSession>,
+}
+
+impl StatusLine {
+    /// Whether the cache is older than [`STATUS_LINE_STALE_AFTER`].
+    pub fn is_stale(&self, now: DateTime<Utc>) -> bool {
+        (now - self.updated_at)
+            .to_std()
+            .is_ok_and(|age| age > STATUS_LINE_STALE_AFTER)
+    }
+
+    /// Render as plain text, e.g. `loom 3/8 ●2 ✗1 stale:api-auth 12m`.
+    pub fn render(&self) -> String {
+        let mut line = format!("loom {}/{}", self.completed, self.total);
+        if self.running > 0 {
+            line.push_str(&format!(" ●{}", self.running));
+        }
+        if self.failed > 0 {
+            line.push_str(&format!(" ✗{}", self.failed));
+        }
+        if let Some(stale) = &self.oldest_stale {
+            line.push_str(&format!(
+                " stale:{} {}m",
+                stale.stage_id,
+                stale.silent_secs / 60
+            ));
+        }
+        line
+    }
+}
+
+/// Summarize the stage and heartbeat files under `work_dir`.
+pub fn compute_status_line(work_dir: &Path, now: DateTime<Utc>) -> Result<StatusLine> {
+    let stages = list_all_stages(work_dir)?;
+    let mut line = StatusLine {
+        updated_at: now,
+        total: stages.len(),
+        running: 0,
+        pending: 0,
+        completed: 0,
+        failed: 0,
+        oldest_stale: None,
+    };
+
+    for stage in &stages {
+        match stage.status.bucket() {
+            StatusBucket::Executing => line.running += 1,
+            StatusBucket::Pending => line.pending += 1,
+            StatusBucket::Completed => line.completed += 1,
+            StatusBucket::Blocked => line.failed += 1,
+        }
+        if stage.status.bucket() != StatusBucket::Executing {
+            continue;
+        }
+        let heartbeat_path = work_dir
+            .join("heartbeat")
+            .join(format!("{}.json", stage.id));
+        let Ok(heartbeat) = read_heartbeat(&heartbeat_path) else {
+            continue;
+        };
+        if stage
+            .session
+            .as_deref()
+            .is_some_and(|sid| sid != heartbeat.session_id)
+        {
+            continue;
+        }
+        let silent_secs = (now - heartbeat.timestamp).num_seconds().max(0) as u64;
+        if silent_secs > STALENESS_THRESHOLD_SECS
+            && line
+                .oldest_stale
+                .as_ref()
+                .is_none_or(|oldest| silent_secs > oldest.silent_secs)
+        {
+            line.oldest_stale = Some(StaleSession {
+                stage_id: stage.id.clone(),
+                silent_secs,
+            });
+        }
+    }
+    Ok(line)
+}
+
+/// Cached status line, if the daemon wrote one.
+pub fn read_status_line(work_dir: &Path) -> Option<StatusLine> {
+    let content = fs::read_to_string(work_dir.join(STATUS_LINE_FILE)).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Refresh the cached status line. Best-effort: readers fall back to
+/// computing it when the cache is missing or stale.
+pub(super) fn write_status_line(work_dir: &Path) {
+    let Ok(line) = compute_status_line(work_dir, Utc::now()) else {
+        return;
+    };
+    let Ok(json) = serde_json::to_string_pretty(&line) else {
+        return;
+    };
+    // Write-then-rename so readers never see a partial file
+    let tmp = work_dir.join(format!("{STATUS_LINE_FILE}.tmp"));
+    if fs::write(&tmp, json).is_ok() {
+        let _ = fs::rename(&tmp, work_dir.join(STATUS_LINE_FILE));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::stage::{Stage, StageStatus};
+    use crate::orchestrator::monitor::heartbeat::Heartbeat;
+    use crate::verify::transitions::save_stage;
+    use tempfile::TempDir;
+
+    fn stage(id: &str, status: StageStatus, session: Option<&str>) -> Stage {
+        let mut stage = Stage::new(id.to_string(), None);
+        stage.id = id.to_string();
+        stage.status = status;
+        stage.session = session.map(str::to_string);
+        stage
+    }
+
+    #[test]
+    fn test_compute_status_line_counts_and_oldest_stale() {
+        let temp = TempDir::new().unwrap();
+        let work_dir = temp.path();
+        let now = Utc::now();
+        for s in [
+            stage("done", StageStatus::Completed, None),
+            stage("broken", StageStatus::Blocked, None),
+            stage("waiting", StageStatus::WaitingForDeps, None),
+            stage("quiet", StageStatus::Executing, Some("s-quiet")),
+            stage("quieter", StageStatus::Executing, Some("s-quieter")),
+            stage("busy", StageStatus::Executing, Some("s-busy")),
+        ] {
+            save_stage(&s, work_dir).unwrap();
+        }
+        let heartbeat_dir = work_dir.join("heartbeat");
+        fs::create_dir_all(&heartbeat_dir).unwrap();
+        for (stage_id, session_id, age_secs) in [
+            ("quiet", "s-quiet", 600),
+            ("quieter", "s-quieter", 1200),
+            ("busy", "s-busy", 10),
+        ] {
+            let mut hb = Heartbeat::new(stage_id.to_string(), session_id.to_string());
+            hb.timestamp = now - chrono::Duration::seconds(age_secs);
+            fs::write(
+                heartbeat_dir.join(format!("{stage_id}.json")),
+                serde_json::to_string(&hb).unwrap(),
+            )
+            .unwrap();
+        }
+
+        let line = compute_status_line(work_dir, now).unwrap();
+        assert_eq!(line.total, 6);
+        assert_eq!(line.running, 3);
+        assert_eq!(line.pending, 1);
+        assert_eq!(line.completed, 1);
+        assert_eq!(line.failed, 1);
+        assert_eq!(
+            line.oldest_stale,
+            Some(StaleSession {
+                stage_id: "quieter".to_string(),
+                silent_secs: 1200,
+            })
+        );
+        assert_eq!(line.render(), "loom 1/6 ●3 ✗1 stale:quieter 20m");
+    }
+
+    #[test]
+    fn test_status_line_cache_round_trip_and_staleness() {
+        let temp = TempDir::new().unwrap();
+        assert_eq!(read_status_line(temp.path()), None);
+        write_status_line(temp.path());
+        let line = read_status_line(temp.path()).unwrap();
+        assert_eq!(line.total, 0);
+        assert_eq!(line.render(), "loom 0/0");
+        assert!(!line.is_stale(line.updated_at + chrono::Duration::seconds(10)));
+        assert!(line.is_stale(line.updated_at + chrono::Duration::seconds(31)));
+    }
+}
@@ -0,0 +1,339 @@
+//! Daemon supervisor for `loom run --supervised`.
+//!
+//! The supervisor is the detached process that would otherwise become the
+//! daemon. It forks the daemon, waits on it, and restarts it when it exits
+//! with an error, dies from a signal, or stops refreshing its heartbeat. A
+//! restarted daemon resumes from the stage and session files in `.work/`,
+//! like any `loom run` after a crash.
+//!
+//! Each crash is recorded in `.work/daemon.supervisor.json` with the tail of
+//! the dead daemon's log saved under `.work/crashes/`, so `loom status` can
+//! surface it. Restarts back off exponentially; after [`MAX_RESTARTS`]
+//! crashes within [`RESTART_WINDOW`] the supervisor gives up.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use nix::sys::signal::{kill, Signal};
+use nix::sys::wait::{waitpid, WaitPidFlag, WaitStatus};
+use nix::unistd::{fork, ForkResult, Pid};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File, OpenOptions};
+use std::os::unix::io::AsRawFd;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+use super::core::DaemonServer;
+use super::health::read_daemon_health;
+use crate::secrets::redact;
+
+/// File under `.work/` holding the [`SupervisorState`].
+pub(super) const SUPERVISOR_FILE: &str = "daemon.supervisor.json";
+
+/// Log of the supervisor itself (the daemon logs to `orchestrator.log`).
+const SUPERVISOR_LOG: &str = "supervisor.log";
+
+/// Crashes within [`RESTART_WINDOW`] after which the supervisor gives up.
+const MAX_RESTARTS: usize = 5;
+
+/// Window over which crashes count against [`MAX_RESTARTS`].
+const RESTART_WINDOW: Duration = Duration::from_secs(10 * 60);
+
+const RESTART_BACKOFF_BASE: Duration = Duration::from_secs(2);
+const RESTART_BACKOFF_MAX: Duration = Duration::from_secs(60);
+
+/// How often the supervisor checks on the daemon.
+const WATCH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Lines of the dead daemon's log kept in its crash log.
+const CRASH_LOG_LINES: usize = 200;
+
+/// One unexpected daemon exit.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DaemonCrash {
+    pub at: DateTime<Utc>,
+    pub daemon_pid: u32,
+    /// e.g. "exited with status 1", "killed by SIGSEGV", "heartbeat stale"
+    pub reason: String,
+    /// Tail of the daemon's log at the time of the crash
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub log: Option<PathBuf>,
+}
+
+/// State of the supervisor of the current (or last) supervised run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SupervisorState {
+    pub pid: u32,
+    pub started_at: DateTime<Utc>,
+    /// Crashes the supervisor restarted (or gave up on), oldest first
+    #[serde(default)]
+    pub crashes: Vec<DaemonCrash>,
+    /// Set once the supervisor stops restarting the daemon
+    #[serde(default)]
+    pub gave_up: bool,
+    /// When the supervisor exited
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ended_at: Option<DateTime<Utc>>,
+}
+
+impl SupervisorState {
+    fn new(pid: u32) -> Self {
+        Self {
+            pid,
+            started_at: Utc::now(),
+            crashes: Vec::new(),
+            gave_up: false,
+            ended_at: None,
+        }
+    }
+
+    /// Whether the supervisor is (as far as its state says) still running.
+    pub fn is_active(&self) -> bool {
+        self.ended_at.is_none() && crate::process::is_process_alive(self.pid)
+    }
+
+    /// Delay before restarting after the latest crash, or `None` once more
+    /// than [`MAX_RESTARTS`] crashes happened within [`RESTART_WINDOW`].
+    pub fn restart_delay(&self, now: DateTime<Utc>) -> Option<Duration> {
+        let recent = self
+            .crashes
+            .iter()
+            .filter(|crash| {
+                (now - crash.at)
+                    .to_std()
+                    .map_or(true, |age| age < RESTART_WINDOW)
+            })
+            .count();
+        if recent > MAX_RESTARTS {
+            return None;
+        }
+        let exponent = recent.saturating_sub(1).min(16) as u32;
+        Some((RESTART_BACKOFF_BASE * 2u32.pow(exponent)).min(RESTART_BACKOFF_MAX))
+    }
+}
+
+/// State of the current (or last) supervisor, if a supervised run happened.
+pub fn read_supervisor_state(work_dir: &Path) -> Option<SupervisorState> {
+    let content = fs::read_to_string(work_dir.join(SUPERVISOR_FILE)).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+fn write_supervisor_state(work_dir: &Path, state: &SupervisorState) {
+    match serde_json::to_string_pretty(state) {
+        Ok(json) => {
+            if let Err(e) = fs::write(work_dir.join(SUPERVISOR_FILE), json) {
+                eprintln!("Warning: Failed to record supervisor state: {e}");
+            }
+        }
+        Err(e) => eprintln!("Warning: Failed to serialize supervisor state: {e}"),
+    }
+}
+
+/// Terminate a running supervisor so it does not restart the daemon that is
+/// about to be stopped. Returns the supervisor's PID if one was signalled.
+pub fn stop_supervisor(work_dir: &Path) -> Option<u32> {
+    let mut state = read_supervisor_state(work_dir)?;
+    if !state.is_active() || state.pid == std::process::id() {
+        return None;
+    }
+    kill(Pid::from_raw(state.pid as i32), Signal::SIGTERM).ok()?;
+    state.ended_at = Some(Utc::now());
+    write_supervisor_state(work_dir, &state);
+    Some(state.pid)
+}
+
+/// How a watched daemon process ended.
+enum DaemonExit {
+    /// Exit status 0: stopped by `loom stop` or finished orchestrating
+    Clean,
+    Crashed(String),
+}
+
+impl DaemonServer {
+    /// Start the daemon under a supervisor that restarts it when it dies.
+    ///
+    /// Like [`DaemonServer::start`], the calling `loom run` process exits once
+    /// the first daemon is listening (or failed to start). Only returns on
+    /// errors before detaching; the supervisor exits when it stops.
+    pub fn start_supervised(&self) -> Result<()> {
+        let success_pipe = self.detach()?;
+
+        // Detached from the terminal: log to .work/supervisor.log
+        let log = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.work_dir.join(SUPERVISOR_LOG))
+            .context("Failed to open supervisor log")?;
+        // stdin points at /dev/null rather than being closed: the daemon
+        // closes fd 0 itself, which must not be a file it opened by then
+        let null = File::open("/dev/null").context("Failed to open /dev/null")?;
+        // SAFETY: fds 0, 1 and 2 are valid open descriptors in this detached
+        // process; see `start`.
+        unsafe {
+            libc::dup2(null.as_raw_fd(), 0);
+            libc::dup2(log.as_raw_fd(), 1);
+            libc::dup2(log.as_raw_fd(), 2);
+        }
+
+        let mut state = SupervisorState::new(std::process::id());
+        write_supervisor_state(&self.work_dir, &state);
+        eprintln!("[{}] Supervisor {} started", Utc::now(), state.pid);
+
+        let mut success_pipe = Some(success_pipe);
+        loop {
+            let child = match unsafe { fork() }.context("Failed to fork daemon")? {
+                ForkResult::Child => {
+                    let code = match self.serve_detached(success_pipe.take()) {
+                        Ok(()) => 0,
+                        Err(e) => {
+                            eprintln!("Daemon error: {e:#}");
+                            1
+                        }
+                    };
+                    let _ = self.cleanup();
+                    std::process::exit(code);
+                }
+                ForkResult::Parent { child } => child,
+            };
+            // Only the first daemon reports startup to `loom run`; drop our
+            // copy so its read sees EOF if that daemon dies before listening
+            let first_start = success_pipe.take().is_some();
+
+            let reason = match self.watch_daemon(child) {
+                DaemonExit::Clean => {
+                    eprintln!("[{}] Daemon {child} exited cleanly", Utc::now());
+                    break;
+                }
+                DaemonExit::Crashed(reason) => reason,
+            };
+            let started = read_daemon_health(&self.work_dir)
+                .is_some_and(|health| health.pid == child.as_raw() as u32);
+            eprintln!("[{}] Daemon {child} {reason}", Utc::now());
+
+            state.crashes.push(DaemonCrash {
+                at: Utc::now(),
+                daemon_pid: child.as_raw() as u32,
+                reason,
+                log: self.save_crash_log(child),
+            });
+            // A first daemon that never came up was already reported as a
+            // failed start (e.g. another daemon holds the lock)
+            if first_start && !started {
+                state.gave_up = true;
+                write_supervisor_state(&self.work_dir, &state);
+                break;
+            }
+            match state.restart_delay(Utc::now()) {
+                Some(delay) => {
+                    write_supervisor_state(&self.work_dir, &state);
+                    eprintln!("[{}] Restarting daemon in {}s", Utc::now(), delay.as_secs());
+                    thread::sleep(delay);
+                }
+                None => {
+                    eprintln!(
+                        "[{}] Daemon crashed more than {MAX_RESTARTS} times in {} minutes, \
+                         giving up",
+                        Utc::now(),
+                        RESTART_WINDOW.as_secs() / 60
+                    );
+                    state.gave_up = true;
+                    write_supervisor_state(&self.work_dir, &state);
+                    break;
+                }
+            }
+        }
+
+        state.ended_at = Some(Utc::now());
+        write_supervisor_state(&self.work_dir, &state);
+        // The supervisor is not the `loom run` that reports the start
+        std::process::exit(0);
+    }
+
+    /// Wait for the daemon `child` to exit, killing it if its heartbeat goes
+    /// stale.
+    fn watch_daemon(&self, child: Pid) -> DaemonExit {
+        loop {
+            thread::sleep(WATCH_INTERVAL);
+            match waitpid(child, Some(WaitPidFlag::WNOHANG)) {
+                Ok(WaitStatus::StillAlive) => {}
+                Ok(WaitStatus::Exited(_, 0)) => return DaemonExit::Clean,
+                Ok(WaitStatus::Exited(_, code)) => {
+                    return DaemonExit::Crashed(format!("exited with status {code}"));
+                }
+                Ok(WaitStatus::Signaled(_, signal, _)) => {
+                    return DaemonExit::Crashed(format!("killed by {}", signal.as_str()));
+                }
+                Ok(_) => continue,
+                Err(e) => return DaemonExit::Crashed(format!("could not be waited on: {e}")),
+            }
+
+            let stale = read_daemon_health(&self.work_dir)
+                .filter(|health| health.pid == child.as_raw() as u32)
+                .filter(|health| health.is_stale(Utc::now()));
+            if let Some(health) = stale {
+                let _ = kill(child, Signal::SIGKILL);
+                let _ = waitpid(child, None);
+                return DaemonExit::Crashed(format!(
+                    "stopped responding (last heartbeat {}), killed",
+                    health.heartbeat_at.format("%Y-%m-%d %H:%M:%S UTC")
+                ));
+            }
+        }
+    }
+
+    /// Keep the tail of the dead daemon's log, which the next daemon
+    /// truncates, under `.work/crashes/`.
+    fn save_crash_log(&self, child: Pid) -> Option<PathBuf> {
+        let log = fs::read_to_string(&self.log_path).ok()?;
+        let lines: Vec<&str> = log.lines().collect();
+        let tail = lines[lines.len().saturating_sub(CRASH_LOG_LINES)..].join("\n");
+
+        let crashes_dir = self.work_dir.join("crashes");
+        fs::create_dir_all(&crashes_dir).ok()?;
+        let path = crashes_dir.join(format!(
+            "daemon-{}-{child}.log",
+            Utc::now().format("%Y%m%d-%H%M%S")
+        ));
+        fs::write(&path, format!("{}\n", redact(&tail))).ok()?;
+        Some(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn crash(minutes_ago: i64, now: DateTime<Utc>) -> DaemonCrash {
+        DaemonCrash {
+            at: now - chrono::Duration::minutes(minutes_ago),
+            daemon_pid: 1,
+            reason: "killed by SIGKILL".to_string(),
+            log: None,
+        }
+    }
+
+    #[test]
+    fn test_restart_delay_backs_off_and_gives_up() {
+        let now = Utc::now();
+        let mut state = SupervisorState::new(1);
+
+        state.crashes.push(crash(0, now));
+        assert_eq!(state.restart_delay(now), Some(Duration::from_secs(2)));
+        state.crashes.push(crash(0, now));
+        assert_eq!(state.restart_delay(now), Some(Duration::from_secs(4)));
+        for _ in 0..3 {
+            state.crashes.push(crash(0, now));
+        }
+        assert_eq!(state.restart_delay(now), Some(Duration::from_secs(32)));
+        state.crashes.push(crash(0, now));
+        assert_eq!(state.restart_delay(now), None);
+
+        // Crashes outside the window no longer count
+        let mut state = SupervisorState::new(1);
+        for _ in 0..10 {
+            state.crashes.push(crash(30, now));
+        }
+        state.crashes.push(crash(0, now));
+        assert_eq!(state.restart_delay(now), Some(Duration::from_secs(2)));
+    }
+}
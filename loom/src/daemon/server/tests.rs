@@ -2,10 +2,17 @@
 
 use super::super::protocol::Response;
 use super::core::DaemonServer;
+use super::events::{parse_request, run_event_server, sse_frame, EventsRequest};
 use super::status::{collect_status, detect_worktree_status, is_manually_merged};
 use crate::models::worktree::WorktreeStatus;
+use crate::orchestrator::monitor::MonitorEvent;
 use std::fs;
-use std::sync::atomic::Ordering;
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::Duration;
 use tempfile::TempDir;
 
 #[test]
@@ -221,3 +228,92 @@ fn test_is_manually_merged_no_git_repo() {
 // 1. Gets the default branch (main/master)
 // 2. Checks if loom/{stage_id} is in `git branch --merged {target}`
 // 3. Returns true if the branch has been merged, false otherwise
+
+#[test]
+fn test_parse_events_request() {
+    let head = "GET /events?since=1&token=abc HTTP/1.1\r\nHost: localhost\r\n\r\n";
+    assert_eq!(
+        parse_request(head),
+        Some(EventsRequest {
+            method: "GET",
+            path: "/events",
+            token: Some("abc"),
+        })
+    );
+
+    let head = "GET /events HTTP/1.1\r\nauthorization: Bearer  xyz \r\n\r\n";
+    assert_eq!(parse_request(head).unwrap().token, Some("xyz"));
+    assert_eq!(
+        parse_request("GET /other HTTP/1.1\r\n\r\n").unwrap().token,
+        None
+    );
+    assert_eq!(parse_request(""), None);
+}
+
+#[test]
+fn test_sse_frame() {
+    let event = MonitorEvent::StageCompleted {
+        stage_id: "stage-1".to_string(),
+    };
+    assert_eq!(
+        sse_frame(&event),
+        "event: stage_completed\ndata: {\"event\":\"stage_completed\",\"stage_id\":\"stage-1\"}\n\n"
+    );
+}
+
+#[test]
+fn test_event_server_streams_to_authenticated_clients() {
+    let temp = TempDir::new().unwrap();
+    let work_dir = temp.path().to_path_buf();
+    fs::write(work_dir.join("user.token"), "secret").unwrap();
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    listener.set_nonblocking(true).unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (sink, events) = mpsc::channel();
+    let shutdown_flag = Arc::new(AtomicBool::new(false));
+    let flag = Arc::clone(&shutdown_flag);
+    let server_dir = work_dir.clone();
+    let handle = thread::spawn(move || run_event_server(listener, events, &server_dir, flag));
+
+    let request = |head: &str| {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        stream
+            .set_read_timeout(Some(Duration::from_secs(5)))
+            .unwrap();
+        stream.write_all(head.as_bytes()).unwrap();
+        stream
+    };
+    let read_until = |stream: &mut TcpStream, needle: &str| {
+        let mut received = String::new();
+        let mut buf = [0u8; 1024];
+        while !received.contains(needle) {
+            let n = stream.read(&mut buf).unwrap();
+            assert!(n > 0, "stream closed before {needle:?}: {received}");
+            received.push_str(&String::from_utf8_lossy(&buf[..n]));
+        }
+        received
+    };
+
+    let mut denied = request("GET /events?token=wrong HTTP/1.1\r\n\r\n");
+    assert!(read_until(&mut denied, "\r\n").starts_with("HTTP/1.1 401"));
+    let mut missing = request("GET /status HTTP/1.1\r\n\r\n");
+    assert!(read_until(&mut missing, "\r\n").starts_with("HTTP/1.1 404"));
+
+    let mut client = request("GET /events HTTP/1.1\r\nAuthorization: Bearer secret\r\n\r\n");
+    let headers = read_until(&mut client, ": connected\n\n");
+    assert!(headers.starts_with("HTTP/1.1 200 OK"));
+    assert!(headers.contains("Content-Type: text/event-stream"));
+
+    sink.send(MonitorEvent::StageBlocked {
+        stage_id: "stage-1".to_string(),
+        reason: "merge conflict".to_string(),
+    })
+    .unwrap();
+    let frame = read_until(&mut client, "\n\n");
+    assert!(frame.starts_with("event: stage_blocked\ndata: "), "{frame}");
+    assert!(frame.contains("\"reason\":\"merge conflict\""), "{frame}");
+
+    shutdown_flag.store(true, Ordering::SeqCst);
+    handle.join().unwrap();
+}
@@ -0,0 +1,458 @@
+//! Plan-wide facts
+//!
+//! Facts are established values every stage of a plan can rely on: ports,
+//! service names, schema versions. Unlike stage outputs, which only reach a
+//! stage's dependents, facts are embedded in every signal. They live in
+//! `.work/facts.json` and are set with `loom fact set` or carried over from
+//! an earlier plan with `loom fact export` / `loom fact import`.
+//!
+//! Every fact set this way records its provenance (a human or the agent
+//! session that set it, and with which command) and a confidence. The
+//! `[facts]` section of `.work/config.toml` picks the default confidence and
+//! can reserve high confidence for facts a human set or a completed stage
+//! produced:
+//!
+//! ```toml
+//! [facts]
+//! default_confidence = "medium"
+//! require_verified = "high"
+//! ```
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::fs::work_dir::read_facts_config;
+use crate::models::stage::{Stage, StageStatus};
+
+/// Version of the portable export format.
+pub const FACT_EXPORT_VERSION: u32 = 1;
+
+/// How far a fact can be relied on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FactConfidence {
+    Low,
+    Medium,
+    High,
+}
+
+impl std::fmt::Display for FactConfidence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FactConfidence::Low => write!(f, "low"),
+            FactConfidence::Medium => write!(f, "medium"),
+            FactConfidence::High => write!(f, "high"),
+        }
+    }
+}
+
+impl std::str::FromStr for FactConfidence {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "low" => Ok(FactConfidence::Low),
+            "medium" | "med" => Ok(FactConfidence::Medium),
+            "high" => Ok(FactConfidence::High),
+            _ => bail!("Invalid confidence: {s}. Use: low, medium, high"),
+        }
+    }
+}
+
+/// Who set a fact, and how.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FactProvenance {
+    /// Agent session that set the fact; `None` when a human did
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session: Option<String>,
+    /// Stage the fact comes from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stage: Option<String>,
+    /// The loom command that set it, e.g. `fact set`
+    pub via: String,
+    pub at: DateTime<Utc>,
+}
+
+impl FactProvenance {
+    /// Provenance of a fact `via` sets now. Inside a loom session
+    /// (`LOOM_SESSION_ID`) the setter is that session, and `stage` defaults
+    /// to the session's `LOOM_STAGE_ID`.
+    pub fn current(via: &str, stage: Option<String>) -> Self {
+        let env = |name| std::env::var(name).ok().filter(|v: &String| !v.is_empty());
+        let session = env("LOOM_SESSION_ID");
+        let stage = stage.or_else(|| session.as_ref().and_then(|_| env("LOOM_STAGE_ID")));
+        Self {
+            session,
+            stage,
+            via: via.to_string(),
+            at: Utc::now(),
+        }
+    }
+
+    /// Whether a human set the fact.
+    pub fn is_human(&self) -> bool {
+        self.session.is_none()
+    }
+
+    /// One-line description, e.g. `session-1 (stage api) via loom fact set`.
+    pub fn describe(&self) -> String {
+        let who = match &self.session {
+            Some(session) => format!("agent session {session}"),
+            None => "human".to_string(),
+        };
+        let stage = self
+            .stage
+            .as_ref()
+            .map(|stage| format!(" (stage {stage})"))
+            .unwrap_or_default();
+        format!(
+            "{who}{stage} via loom {}, {}",
+            self.via,
+            self.at.format("%Y-%m-%d %H:%M UTC")
+        )
+    }
+}
+
+/// One established fact.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Fact {
+    /// Fact key, unique within the store (same rules as output keys)
+    pub key: String,
+    /// The fact value (string, number, boolean, array, or object)
+    pub value: Value,
+    /// What the fact means
+    #[serde(default)]
+    pub description: String,
+    /// Where the fact was established, e.g. `PLAN-auth/api` for an output
+    /// of stage `api` in plan `PLAN-auth`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    /// How far the fact can be relied on; facts from before confidence was
+    /// recorded have none
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<FactConfidence>,
+    /// Who set the fact, and how
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provenance: Option<FactProvenance>,
+}
+
+/// Contents of `facts.json`, in the order facts were established.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FactStore {
+    #[serde(default)]
+    pub facts: Vec<Fact>,
+}
+
+impl FactStore {
+    /// Add `fact`, replacing the fact with the same key. Returns `true` if
+    /// the key was new.
+    pub fn set(&mut self, fact: Fact) -> bool {
+        match self.facts.iter_mut().find(|f| f.key == fact.key) {
+            Some(existing) => {
+                *existing = fact;
+                false
+            }
+            None => {
+                self.facts.push(fact);
+                true
+            }
+        }
+    }
+}
+
+/// Portable file written by `loom fact export`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FactExport {
+    pub version: u32,
+    /// Plan the facts were exported from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plan: Option<String>,
+    pub exported_at: DateTime<Utc>,
+    pub facts: Vec<Fact>,
+}
+
+impl FactExport {
+    /// Export `store`, plus the outputs of completed `stages` when given.
+    ///
+    /// Outputs become facts sourced from their stage; an output replaces a
+    /// stored fact or an earlier stage's output with the same key.
+    pub fn build(store: &FactStore, stages: Option<&[Stage]>, plan: Option<&str>) -> Self {
+        let mut facts = store.clone();
+        for stage in stages.unwrap_or_default() {
+            if stage.status != StageStatus::Completed {
+                continue;
+            }
+            let source = match plan {
+                Some(plan) => format!("{plan}/{}", stage.id),
+                None => stage.id.clone(),
+            };
+            for output in &stage.outputs {
+                facts.set(Fact {
+                    key: output.key.clone(),
+                    value: output.value.clone(),
+                    description: output.description.clone(),
+                    source: Some(source.clone()),
+                    confidence: None,
+                    provenance: None,
+                });
+            }
+        }
+        Self {
+            version: FACT_EXPORT_VERSION,
+            plan: plan.map(str::to_string),
+            exported_at: Utc::now(),
+            facts: facts.facts,
+        }
+    }
+
+    /// Parse an export file, rejecting unknown versions and invalid keys.
+    pub fn parse(json: &str) -> Result<Self> {
+        let export: Self = serde_json::from_str(json).context("Invalid fact export file")?;
+        if export.version != FACT_EXPORT_VERSION {
+            bail!(
+                "Unsupported fact export version {} (expected {FACT_EXPORT_VERSION})",
+                export.version
+            );
+        }
+        for fact in &export.facts {
+            validate_key(&fact.key)?;
+        }
+        Ok(export)
+    }
+}
+
+/// Fact settings (`[facts]`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FactsConfig {
+    /// Confidence of facts set without `--confidence` (default medium)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_confidence: Option<FactConfidence>,
+    /// Facts at or above this confidence must be set by a human or come
+    /// from a completed stage
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub require_verified: Option<FactConfidence>,
+}
+
+impl FactsConfig {
+    /// Load the `[facts]` config, falling back to the defaults when the
+    /// section is invalid.
+    pub fn load(work_dir: &Path) -> Self {
+        read_facts_config(work_dir).unwrap_or_else(|e| {
+            eprintln!("Warning: ignoring invalid [facts] config: {e:#}");
+            Self::default()
+        })
+    }
+
+    pub fn default_confidence(&self) -> FactConfidence {
+        self.default_confidence.unwrap_or(FactConfidence::Medium)
+    }
+
+    /// Check `fact` against `require_verified`. A stage counts as verified
+    /// once it completed, i.e. passed its acceptance criteria.
+    pub fn check(&self, fact: &Fact, stages: &[Stage]) -> Result<()> {
+        let Some(required) = self.require_verified else {
+            return Ok(());
+        };
+        let confidence = fact.confidence.unwrap_or(self.default_confidence());
+        if confidence < required {
+            return Ok(());
+        }
+        let Some(provenance) = &fact.provenance else {
+            return Ok(());
+        };
+        if provenance.is_human() {
+            return Ok(());
+        }
+        let verified = provenance.stage.as_ref().is_some_and(|id| {
+            stages
+                .iter()
+                .any(|s| &s.id == id && s.status == StageStatus::Completed)
+        });
+        if !verified {
+            bail!(
+                "Fact '{}' has {confidence} confidence, which [facts] require_verified = \"{required}\" \
+                 reserves for facts set by a human or taken from a completed stage; \
+                 set it with a lower --confidence, or --stage <completed stage>",
+                fact.key
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Check that `key` is a valid fact key: 1-64 alphanumeric characters,
+/// underscores or dashes.
+pub fn validate_key(key: &str) -> Result<()> {
+    if !key
+        .chars()
+        .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+    {
+        bail!(
+            "Fact key '{key}' must contain only alphanumeric characters, underscores, and dashes"
+        );
+    }
+    if key.is_empty() || key.len() > 64 {
+        bail!("Fact key must be 1-64 characters");
+    }
+    Ok(())
+}
+
+/// Path of the fact store.
+pub fn facts_path(work_dir: &Path) -> PathBuf {
+    work_dir.join("facts.json")
+}
+
+/// Load the fact store, returning an empty store if the file does not exist.
+pub fn load_facts(work_dir: &Path) -> Result<FactStore> {
+    let path = facts_path(work_dir);
+    if !path.exists() {
+        return Ok(FactStore::default());
+    }
+
+    let json = fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read facts: {}", path.display()))?;
+    serde_json::from_str(&json)
+        .with_context(|| format!("Failed to parse facts: {}", path.display()))
+}
+
+/// Write the fact store.
+pub fn save_facts(work_dir: &Path, store: &FactStore) -> Result<()> {
+    let path = facts_path(work_dir);
+    let json = serde_json::to_string_pretty(store).context("Failed to serialize facts")?;
+    fs::write(&path, json).with_context(|| format!("Failed to write facts: {}", path.display()))?;
+    crate::fs::integrity::seal_or_warn(work_dir, &path, crate::fs::integrity::SealMode::Exact);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::stage::StageOutput;
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    fn fact(key: &str, value: Value) -> Fact {
+        Fact {
+            key: key.to_string(),
+            value,
+            description: String::new(),
+            source: None,
+            confidence: None,
+            provenance: None,
+        }
+    }
+
+    fn stage_with_output(id: &str, status: StageStatus, key: &str, value: Value) -> Stage {
+        let mut stage = Stage::new(id.to_string(), None);
+        stage.id = id.to_string();
+        stage.status = status;
+        stage.outputs.push(StageOutput {
+            key: key.to_string(),
+            value,
+            description: format!("{key} of {id}"),
+        });
+        stage
+    }
+
+    #[test]
+    fn test_export_import_round_trip() {
+        let temp = TempDir::new().unwrap();
+        let mut store = FactStore::default();
+        store.set(fact("db_port", json!(5432)));
+        store.set(fact("schema_version", json!(3)));
+        let stages = vec![
+            stage_with_output("api", StageStatus::Completed, "api_port", json!(8080)),
+            stage_with_output("db", StageStatus::Completed, "schema_version", json!(4)),
+            stage_with_output("web", StageStatus::Executing, "web_port", json!(3000)),
+        ];
+
+        let export = FactExport::build(&store, Some(&stages), Some("PLAN-auth"));
+        let keys: Vec<_> = export.facts.iter().map(|f| f.key.as_str()).collect();
+        assert_eq!(keys, ["db_port", "schema_version", "api_port"]);
+        let schema = &export.facts[1];
+        assert_eq!(schema.value, json!(4));
+        assert_eq!(schema.source.as_deref(), Some("PLAN-auth/db"));
+
+        let parsed = FactExport::parse(&serde_json::to_string(&export).unwrap()).unwrap();
+        assert_eq!(parsed, export);
+        let mut imported = FactStore::default();
+        for fact in parsed.facts {
+            imported.set(fact);
+        }
+        save_facts(temp.path(), &imported).unwrap();
+        assert_eq!(load_facts(temp.path()).unwrap(), imported);
+
+        // Without stages only the store is exported
+        let export = FactExport::build(&store, None, None);
+        assert_eq!(export.facts, store.facts);
+    }
+
+    #[test]
+    fn test_require_verified() {
+        let agent = |stage: &str| FactProvenance {
+            session: Some("session-1".to_string()),
+            stage: Some(stage.to_string()),
+            via: "fact set".to_string(),
+            at: Utc::now(),
+        };
+        let stages = vec![
+            stage_with_output("api", StageStatus::Completed, "api_port", json!(8080)),
+            stage_with_output("web", StageStatus::Executing, "web_port", json!(3000)),
+        ];
+        let mut high = fact("db_port", json!(5432));
+        high.confidence = Some(FactConfidence::High);
+        high.provenance = Some(agent("web"));
+
+        // No rule: anything goes
+        assert!(FactsConfig::default().check(&high, &stages).is_ok());
+
+        let config = FactsConfig {
+            default_confidence: Some(FactConfidence::Low),
+            require_verified: Some(FactConfidence::High),
+        };
+        let err = config.check(&high, &stages).unwrap_err().to_string();
+        assert!(err.contains("require_verified"), "{err}");
+
+        high.provenance = Some(agent("api"));
+        assert!(config.check(&high, &stages).is_ok());
+        high.provenance.as_mut().unwrap().session = None;
+        high.provenance.as_mut().unwrap().stage = None;
+        assert!(config.check(&high, &stages).is_ok());
+
+        // Facts without a confidence take the configured default
+        let mut unrated = fact("web_port", json!(3000));
+        unrated.provenance = Some(agent("web"));
+        assert!(config.check(&unrated, &stages).is_ok());
+        assert_eq!(config.default_confidence(), FactConfidence::Low);
+        assert_eq!(
+            FactsConfig::default().default_confidence(),
+            FactConfidence::Medium
+        );
+
+        assert_eq!(
+            "HIGH".parse::<FactConfidence>().unwrap(),
+            FactConfidence::High
+        );
+        assert!("certain".parse::<FactConfidence>().is_err());
+        assert!(agent("api")
+            .describe()
+            .starts_with("agent session session-1 (stage api) via loom fact set, "));
+    }
+
+    #[test]
+    fn test_parse_rejects_bad_exports() {
+        let bad_version = r#"{"version": 2, "exported_at": "2026-01-01T00:00:00Z", "facts": []}"#;
+        assert!(FactExport::parse(bad_version).is_err());
+        let bad_key = r#"{"version": 1, "exported_at": "2026-01-01T00:00:00Z",
+            "facts": [{"key": "db port", "value": 5432}]}"#;
+        assert!(FactExport::parse(bad_key).is_err());
+        assert_eq!(
+            load_facts(Path::new("/nonexistent")).unwrap(),
+            FactStore::default()
+        );
+    }
+}
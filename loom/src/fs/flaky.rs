@@ -0,0 +1,221 @@
+//! Flaky acceptance criteria statistics
+//!
+//! Criteria annotated with `flaky: true` or `retries: N` are tracked in
+//! `.work/verifications/flaky.json`, keyed by stage and command. A criterion
+//! that only passes after retrying counts as a flaky pass; repeated flaky
+//! passes mark it as a chronic flake worth fixing at the source.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::models::stage::AcceptanceCriterion;
+use crate::verify::criteria::CriterionResult;
+
+/// Flaky passes after which a criterion is reported as a chronic flake.
+pub const CHRONIC_FLAKE_THRESHOLD: u32 = 3;
+
+/// Run history for one tracked criterion.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FlakyCriterionStats {
+    /// Stage the criterion belongs to
+    pub stage_id: String,
+    /// Criterion command (unexpanded, as written in the plan)
+    pub command: String,
+    /// Total acceptance runs that executed this criterion
+    pub runs: u32,
+    /// Runs that passed on the first attempt
+    pub first_try_passes: u32,
+    /// Runs that passed only after one or more retries
+    pub flaky_passes: u32,
+    /// Runs that failed every attempt
+    pub failures: u32,
+    /// Sum of attempts across all runs
+    pub total_attempts: u32,
+    /// When the criterion last ran
+    pub last_run: Option<DateTime<Utc>>,
+}
+
+impl FlakyCriterionStats {
+    /// Whether this criterion has needed retries often enough to be surfaced.
+    pub fn is_chronic(&self) -> bool {
+        self.flaky_passes >= CHRONIC_FLAKE_THRESHOLD
+    }
+}
+
+/// Contents of `flaky.json`, keyed by `<stage_id>::<command>`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FlakyStats {
+    #[serde(default)]
+    pub criteria: BTreeMap<String, FlakyCriterionStats>,
+}
+
+impl FlakyStats {
+    /// Chronic flakes, most flaky passes first.
+    pub fn chronic(&self) -> Vec<&FlakyCriterionStats> {
+        let mut chronic: Vec<_> = self.criteria.values().filter(|s| s.is_chronic()).collect();
+        chronic.sort_by_key(|s| std::cmp::Reverse(s.flaky_passes));
+        chronic
+    }
+}
+
+/// Path of the flaky statistics file.
+pub fn flaky_stats_path(work_dir: &Path) -> PathBuf {
+    work_dir.join("verifications").join("flaky.json")
+}
+
+/// Load flaky statistics, returning empty stats if the file does not exist.
+pub fn load_flaky_stats(work_dir: &Path) -> Result<FlakyStats> {
+    let path = flaky_stats_path(work_dir);
+    if !path.exists() {
+        return Ok(FlakyStats::default());
+    }
+
+    let json = fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read flaky stats: {}", path.display()))?;
+    serde_json::from_str(&json)
+        .with_context(|| format!("Failed to parse flaky stats: {}", path.display()))
+}
+
+/// Record the outcome of tracked criteria from one acceptance run.
+///
+/// `results` must line up index-for-index with `criteria`, as produced by the
+/// acceptance runner. Untracked criteria are ignored.
+///
+/// # Returns
+///
+/// The updated stats of every tracked criterion that is now a chronic flake.
+pub fn record_flaky_results(
+    work_dir: &Path,
+    stage_id: &str,
+    criteria: &[AcceptanceCriterion],
+    results: &[CriterionResult],
+) -> Result<Vec<FlakyCriterionStats>> {
+    let tracked: Vec<_> = criteria
+        .iter()
+        .zip(results)
+        .filter(|(criterion, _)| criterion.is_flaky_tracked())
+        .collect();
+    if tracked.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut stats = load_flaky_stats(work_dir)?;
+    let now = Utc::now();
+    let mut chronic = Vec::new();
+
+    for (criterion, result) in tracked {
+        let command = criterion.command();
+        let entry = stats
+            .criteria
+            .entry(format!("{stage_id}::{command}"))
+            .or_insert_with(|| FlakyCriterionStats {
+                stage_id: stage_id.to_string(),
+                command: command.to_string(),
+                ..Default::default()
+            });
+
+        entry.runs += 1;
+        entry.total_attempts += result.attempts;
+        entry.last_run = Some(now);
+        match (result.success, result.attempts) {
+            (true, 1) => entry.first_try_passes += 1,
+            (true, _) => entry.flaky_passes += 1,
+            (false, _) => entry.failures += 1,
+        }
+
+        if entry.is_chronic() {
+            chronic.push(entry.clone());
+        }
+    }
+
+    let path = flaky_stats_path(work_dir);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).context("Failed to create verifications directory")?;
+    }
+    let json = serde_json::to_string_pretty(&stats).context("Failed to serialize flaky stats")?;
+    fs::write(&path, json)
+        .with_context(|| format!("Failed to write flaky stats: {}", path.display()))?;
+
+    Ok(chronic)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::stage::TruthCheck;
+    use std::time::Duration;
+    use tempfile::TempDir;
+
+    fn flaky(command: &str) -> AcceptanceCriterion {
+        AcceptanceCriterion::Extended(TruthCheck {
+            command: command.to_string(),
+            stdout_contains: vec![],
+            stdout_not_contains: vec![],
+            stderr_empty: None,
+            exit_code: None,
+            description: None,
+            name: None,
+            category: None,
+            timeout: None,
+            retries: None,
+            flaky: true,
+            isolation: None,
+        })
+    }
+
+    fn result(command: &str, success: bool, attempts: u32) -> CriterionResult {
+        let mut result = CriterionResult::new(
+            command.to_string(),
+            success,
+            String::new(),
+            String::new(),
+            Some(if success { 0 } else { 1 }),
+            Duration::from_millis(1),
+            false,
+        );
+        result.attempts = attempts;
+        result
+    }
+
+    #[test]
+    fn test_record_flaky_results_counts_outcomes() {
+        let temp = TempDir::new().unwrap();
+        let criteria = vec![AcceptanceCriterion::Simple("true".into()), flaky("e2e")];
+
+        for (success, attempts) in [(true, 1), (true, 3), (false, 3)] {
+            let results = vec![result("true", true, 1), result("e2e", success, attempts)];
+            record_flaky_results(temp.path(), "s1", &criteria, &results).unwrap();
+        }
+
+        let stats = load_flaky_stats(temp.path()).unwrap();
+        assert_eq!(stats.criteria.len(), 1, "simple criteria are not tracked");
+        let e2e = &stats.criteria["s1::e2e"];
+        assert_eq!(e2e.runs, 3);
+        assert_eq!(e2e.first_try_passes, 1);
+        assert_eq!(e2e.flaky_passes, 1);
+        assert_eq!(e2e.failures, 1);
+        assert_eq!(e2e.total_attempts, 7);
+        assert!(!e2e.is_chronic());
+    }
+
+    #[test]
+    fn test_record_flaky_results_reports_chronic_flakes() {
+        let temp = TempDir::new().unwrap();
+        let criteria = vec![flaky("e2e")];
+        let results = vec![result("e2e", true, 2)];
+
+        for _ in 1..CHRONIC_FLAKE_THRESHOLD {
+            let chronic = record_flaky_results(temp.path(), "s1", &criteria, &results).unwrap();
+            assert!(chronic.is_empty());
+        }
+        let chronic = record_flaky_results(temp.path(), "s1", &criteria, &results).unwrap();
+
+        assert_eq!(chronic.len(), 1);
+        assert_eq!(chronic[0].flaky_passes, CHRONIC_FLAKE_THRESHOLD);
+        assert_eq!(load_flaky_stats(temp.path()).unwrap().chronic().len(), 1);
+    }
+}
@@ -0,0 +1,438 @@
+//! Tamper-evident ledger for memory journals, knowledge files and facts
+//!
+//! Whenever loom writes one of these files it appends a record to
+//! `.work/integrity/ledger.jsonl`: the file's path (relative to the project
+//! root), its length and the SHA-256 of its content. Each record also
+//! carries the hash of the record before it, so the ledger forms a hash
+//! chain and an edited or deleted record breaks every record after it.
+//!
+//! `loom verify all` walks the chain and compares each file with its latest
+//! record. Memory journals and knowledge files are append-only, so only the
+//! sealed prefix has to be unchanged: entries appended later (for example
+//! by a merged stage branch) are fine, while edits to earlier content are
+//! reported. The fact store is rewritten as a whole and must match exactly.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::fs::locking::locked_dir_update;
+
+/// Ledger location inside `.work/`
+pub const LEDGER_PATH: &str = "integrity/ledger.jsonl";
+
+/// `prev` of the first record
+const GENESIS: &str = "genesis";
+
+/// How a file's current content is compared with its record
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SealMode {
+    /// The sealed content must still be a prefix of the file
+    Append,
+    /// The file must be unchanged
+    Exact,
+}
+
+/// One ledger line
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LedgerRecord {
+    pub seq: u64,
+    pub path: String,
+    pub mode: SealMode,
+    pub len: u64,
+    pub sha256: String,
+    pub at: DateTime<Utc>,
+    pub prev: String,
+    pub hash: String,
+}
+
+impl LedgerRecord {
+    fn compute_hash(&self) -> String {
+        sha256_hex(
+            format!(
+                "{}\n{}\n{}\n{:?}\n{}\n{}\n{}",
+                self.seq,
+                self.prev,
+                self.path,
+                self.mode,
+                self.len,
+                self.sha256,
+                self.at.to_rfc3339()
+            )
+            .as_bytes(),
+        )
+    }
+}
+
+/// What `verify_all` found wrong with a file or the ledger
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum FindingKind {
+    /// A ledger record was edited, removed or reordered
+    ChainBroken,
+    /// Content loom wrote was changed afterwards
+    Modified,
+    /// The file is shorter than the content loom wrote
+    Truncated,
+    /// The file was deleted
+    Missing,
+    /// A tracked file loom never recorded
+    Unsealed,
+}
+
+impl std::fmt::Display for FindingKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            Self::ChainBroken => "chain broken",
+            Self::Modified => "modified",
+            Self::Truncated => "truncated",
+            Self::Missing => "missing",
+            Self::Unsealed => "unsealed",
+        };
+        write!(f, "{label}")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Finding {
+    pub path: String,
+    pub kind: FindingKind,
+    pub detail: String,
+}
+
+/// Result of `verify_all`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct IntegrityReport {
+    /// Ledger records read
+    pub records: usize,
+    /// Files compared with their latest record
+    pub files_checked: usize,
+    pub findings: Vec<Finding>,
+}
+
+impl IntegrityReport {
+    /// Whether anything points at tampering or corruption. Unsealed files
+    /// alone (e.g. written before the ledger existed) do not count.
+    pub fn is_clean(&self) -> bool {
+        self.findings
+            .iter()
+            .all(|f| f.kind == FindingKind::Unsealed)
+    }
+}
+
+/// Record the current content of `path` in the ledger of `work_dir`.
+pub fn seal(work_dir: &Path, path: &Path, mode: SealMode) -> Result<()> {
+    let root = project_root(work_dir)?;
+    let path = path
+        .canonicalize()
+        .with_context(|| format!("Failed to resolve {}", path.display()))?;
+    let relative = path
+        .strip_prefix(&root)
+        .with_context(|| format!("{} is outside the project", path.display()))?
+        .to_string_lossy()
+        .to_string();
+    let content = fs::read(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+
+    let ledger = work_dir.join(LEDGER_PATH);
+    let dir = ledger.parent().expect("ledger path has a parent");
+    fs::create_dir_all(dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+    locked_dir_update(dir, || {
+        let last = read_ledger(work_dir)?.pop();
+        let mut record = LedgerRecord {
+            seq: last.as_ref().map_or(0, |r| r.seq + 1),
+            path: relative,
+            mode,
+            len: content.len() as u64,
+            sha256: sha256_hex(&content),
+            at: Utc::now(),
+            prev: last.map_or_else(|| GENESIS.to_string(), |r| r.hash),
+            hash: String::new(),
+        };
+        record.hash = record.compute_hash();
+
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&ledger)
+            .with_context(|| format!("Failed to open {}", ledger.display()))?;
+        writeln!(file, "{}", serde_json::to_string(&record)?)
+            .with_context(|| format!("Failed to append to {}", ledger.display()))
+    })
+}
+
+/// [`seal`], warning instead of failing: a ledger problem must never lose
+/// the write it records.
+pub fn seal_or_warn(work_dir: &Path, path: &Path, mode: SealMode) {
+    if let Err(e) = seal(work_dir, path, mode) {
+        tracing::warn!(path = %path.display(), error = %e, "Failed to record file in integrity ledger");
+    }
+}
+
+/// Read every ledger record in order. A missing ledger has none.
+pub fn read_ledger(work_dir: &Path) -> Result<Vec<LedgerRecord>> {
+    let path = work_dir.join(LEDGER_PATH);
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content =
+        fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .enumerate()
+        .map(|(i, line)| {
+            serde_json::from_str(line)
+                .with_context(|| format!("Invalid ledger record on line {}", i + 1))
+        })
+        .collect()
+}
+
+/// Check the ledger chain and every memory journal, knowledge file and the
+/// fact store against it.
+pub fn verify_all(work_dir: &Path) -> Result<IntegrityReport> {
+    let root = project_root(work_dir)?;
+    let mut report = IntegrityReport::default();
+
+    let records = match read_ledger(work_dir) {
+        Ok(records) => records,
+        Err(e) => {
+            report.findings.push(Finding {
+                path: format!(".work/{LEDGER_PATH}"),
+                kind: FindingKind::ChainBroken,
+                detail: format!("{e:#}"),
+            });
+            Vec::new()
+        }
+    };
+    report.records = records.len();
+    report.findings.extend(check_chain(&records));
+
+    let mut latest: BTreeMap<&str, &LedgerRecord> = BTreeMap::new();
+    for record in &records {
+        latest.insert(record.path.as_str(), record);
+    }
+    for (path, record) in &latest {
+        report.files_checked += 1;
+        if let Some(finding) = check_file(&root.join(path), record) {
+            report.findings.push(finding);
+        }
+    }
+
+    for path in tracked_files(&root) {
+        let relative = path
+            .strip_prefix(&root)
+            .unwrap_or(&path)
+            .to_string_lossy()
+            .to_string();
+        if !latest.contains_key(relative.as_str()) {
+            report.findings.push(Finding {
+                path: relative,
+                kind: FindingKind::Unsealed,
+                detail: "not written through loom since the ledger was started".to_string(),
+            });
+        }
+    }
+    Ok(report)
+}
+
+/// Seal the current content of every tracked file, accepting it as trusted.
+///
+/// Returns the number of files sealed.
+pub fn seal_all(work_dir: &Path) -> Result<usize> {
+    let root = project_root(work_dir)?;
+    let files = tracked_files(&root);
+    for path in &files {
+        seal(work_dir, path, mode_for(&root, path))?;
+    }
+    Ok(files.len())
+}
+
+fn check_chain(records: &[LedgerRecord]) -> Vec<Finding> {
+    let mut prev = GENESIS.to_string();
+    for (i, record) in records.iter().enumerate() {
+        let detail = if record.seq != i as u64 {
+            Some(format!("record {i} has sequence number {}", record.seq))
+        } else if record.prev != prev {
+            Some(format!(
+                "record {i} does not follow record {}",
+                i.saturating_sub(1)
+            ))
+        } else if record.hash != record.compute_hash() {
+            Some(format!("record {i} was edited"))
+        } else {
+            None
+        };
+        if let Some(detail) = detail {
+            return vec![Finding {
+                path: record.path.clone(),
+                kind: FindingKind::ChainBroken,
+                detail,
+            }];
+        }
+        prev = record.hash.clone();
+    }
+    Vec::new()
+}
+
+fn check_file(path: &Path, record: &LedgerRecord) -> Option<Finding> {
+    let finding = |kind, detail: String| {
+        Some(Finding {
+            path: record.path.clone(),
+            kind,
+            detail,
+        })
+    };
+    let Ok(content) = fs::read(path) else {
+        return finding(
+            FindingKind::Missing,
+            format!("last recorded {}", record.at.format("%Y-%m-%d %H:%M UTC")),
+        );
+    };
+    let len = record.len as usize;
+    if content.len() < len {
+        return finding(
+            FindingKind::Truncated,
+            format!("{} bytes, {} recorded", content.len(), len),
+        );
+    }
+    let compared = match record.mode {
+        SealMode::Append => &content[..len],
+        SealMode::Exact => &content[..],
+    };
+    if sha256_hex(compared) != record.sha256 {
+        let detail = match record.mode {
+            SealMode::Append => "content recorded by loom was changed".to_string(),
+            SealMode::Exact => "content differs from what loom wrote".to_string(),
+        };
+        return finding(FindingKind::Modified, detail);
+    }
+    None
+}
+
+/// The files the ledger covers, as they exist under `root`.
+fn tracked_files(root: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    for dir in [".work/memory", "doc/loom/knowledge"] {
+        if let Ok(entries) = fs::read_dir(root.join(dir)) {
+            let mut md: Vec<PathBuf> = entries
+                .flatten()
+                .map(|e| e.path())
+                .filter(|p| p.is_file() && p.extension().and_then(|e| e.to_str()) == Some("md"))
+                .collect();
+            md.sort();
+            files.extend(md);
+        }
+    }
+    let facts = root.join(".work/facts.json");
+    if facts.is_file() {
+        files.push(facts);
+    }
+    files
+}
+
+fn mode_for(root: &Path, path: &Path) -> SealMode {
+    if path == root.join(".work/facts.json") {
+        SealMode::Exact
+    } else {
+        SealMode::Append
+    }
+}
+
+/// Project root of `work_dir`, following the `.work` link of a worktree.
+fn project_root(work_dir: &Path) -> Result<PathBuf> {
+    let work_dir = work_dir
+        .canonicalize()
+        .with_context(|| format!("Failed to resolve {}", work_dir.display()))?;
+    work_dir
+        .parent()
+        .map(Path::to_path_buf)
+        .context(".work directory has no parent")
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(bytes);
+    hex::encode(hasher.finalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn setup() -> (TempDir, PathBuf) {
+        let temp = TempDir::new().unwrap();
+        let work_dir = temp.path().join(".work");
+        fs::create_dir_all(work_dir.join("memory")).unwrap();
+        (temp, work_dir)
+    }
+
+    #[test]
+    fn test_append_only_files_may_grow_but_not_change() {
+        let (_temp, work_dir) = setup();
+        let journal = work_dir.join("memory/api.md");
+        fs::write(&journal, "# Memory\n\n### Note\nfirst\n").unwrap();
+        seal(&work_dir, &journal, SealMode::Append).unwrap();
+        let facts = work_dir.join("facts.json");
+        fs::write(&facts, "{\"facts\":[]}").unwrap();
+        seal(&work_dir, &facts, SealMode::Exact).unwrap();
+
+        let report = verify_all(&work_dir).unwrap();
+        assert_eq!((report.records, report.files_checked), (2, 2));
+        assert!(report.findings.is_empty(), "{:?}", report.findings);
+
+        fs::write(&journal, "# Memory\n\n### Note\nfirst\n### Note\nmore\n").unwrap();
+        assert!(verify_all(&work_dir).unwrap().findings.is_empty());
+
+        fs::write(&journal, "# Memory\n\n### Note\nforged\n### Note\nmore\n").unwrap();
+        fs::write(&facts, "{\"facts\":[1]}").unwrap();
+        fs::write(work_dir.join("memory/ui.md"), "# Memory\n").unwrap();
+        let report = verify_all(&work_dir).unwrap();
+        let kinds: Vec<_> = report
+            .findings
+            .iter()
+            .map(|f| (f.path.as_str(), f.kind))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (".work/facts.json", FindingKind::Modified),
+                (".work/memory/api.md", FindingKind::Modified),
+                (".work/memory/ui.md", FindingKind::Unsealed),
+            ]
+        );
+        assert!(!report.is_clean());
+
+        assert_eq!(seal_all(&work_dir).unwrap(), 3);
+        assert!(verify_all(&work_dir).unwrap().findings.is_empty());
+
+        fs::remove_file(&journal).unwrap();
+        let report = verify_all(&work_dir).unwrap();
+        assert_eq!(report.findings[0].kind, FindingKind::Missing);
+    }
+
+    #[test]
+    fn test_edited_ledger_breaks_the_chain() {
+        let (_temp, work_dir) = setup();
+        let journal = work_dir.join("memory/api.md");
+        for text in ["one\n", "one\ntwo\n", "one\ntwo\nthree\n"] {
+            fs::write(&journal, text).unwrap();
+            seal(&work_dir, &journal, SealMode::Append).unwrap();
+        }
+        assert!(verify_all(&work_dir).unwrap().is_clean());
+
+        let ledger = work_dir.join(LEDGER_PATH);
+        let content = fs::read_to_string(&ledger).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        fs::write(&ledger, format!("{}\n{}\n", lines[0], lines[2])).unwrap();
+        let report = verify_all(&work_dir).unwrap();
+        assert_eq!(report.findings[0].kind, FindingKind::ChainBroken);
+        assert!(report.findings[0].detail.contains("record 1"));
+    }
+}
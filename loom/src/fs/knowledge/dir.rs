@@ -1,7 +1,9 @@
 //! Knowledge directory manager.
 
+use super::entry::{parse_entries, KnowledgeEntry, Severity, ENTRY_META_END, ENTRY_META_START};
 use super::gc::{analyze_gc_metrics, GcMetrics};
 use super::types::KnowledgeFile;
+use crate::fs::integrity::SealMode;
 use anyhow::{Context, Result};
 use std::fs::{self, OpenOptions};
 use std::io::Write;
@@ -10,6 +12,8 @@ use std::path::{Path, PathBuf};
 /// Manager for the doc/loom/knowledge/ directory
 pub struct KnowledgeDir {
     root: PathBuf,
+    /// `.work` of the project, whose integrity ledger records each write
+    work_dir: Option<PathBuf>,
 }
 
 impl KnowledgeDir {
@@ -17,6 +21,15 @@ impl KnowledgeDir {
     pub fn new<P: AsRef<Path>>(project_root: P) -> Self {
         Self {
             root: project_root.as_ref().join("doc/loom/knowledge"),
+            work_dir: Some(project_root.as_ref().join(".work")),
+        }
+    }
+
+    /// Use `root` itself as the knowledge directory (e.g. an export target)
+    pub fn at<P: AsRef<Path>>(root: P) -> Self {
+        Self {
+            root: root.as_ref().to_path_buf(),
+            work_dir: None,
         }
     }
 
@@ -73,6 +86,7 @@ impl KnowledgeDir {
                 Ok(mut file) => {
                     file.write_all(content.as_bytes())
                         .with_context(|| format!("Failed to write {}", file_type.filename()))?;
+                    self.seal(*file_type);
                 }
                 Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
                     // File already exists, skip (idempotent)
@@ -131,7 +145,151 @@ impl KnowledgeDir {
                 format!("{base}\n\n{content_owned}\n")
             }
         })
-        .with_context(|| format!("Failed to append to {}", file_type.filename()))
+        .with_context(|| format!("Failed to append to {}", file_type.filename()))?;
+        self.seal(file_type);
+        Ok(())
+    }
+
+    /// Parse the `## ` sections of a knowledge file into entries.
+    ///
+    /// A missing file has no entries.
+    pub fn entries(&self, file_type: KnowledgeFile) -> Result<Vec<KnowledgeEntry>> {
+        let path = self.file_path(file_type);
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+        Ok(parse_entries(&self.read(file_type)?).1)
+    }
+
+    /// Append structured entries to a knowledge file, skipping any whose
+    /// title and body already appear in the file (or earlier in `entries`).
+    ///
+    /// # Returns
+    ///
+    /// The number of entries actually appended.
+    pub fn append_entries(
+        &self,
+        file_type: KnowledgeFile,
+        entries: &[KnowledgeEntry],
+    ) -> Result<usize> {
+        let path = self.file_path(file_type);
+        let default = self.default_content(file_type);
+        let mut appended = 0;
+
+        crate::fs::locking::locked_read_modify_write(&path, |existing| {
+            let mut result = if existing.is_empty() {
+                default
+            } else {
+                existing
+            };
+            let mut known = parse_entries(&result).1;
+            for entry in entries {
+                if known.iter().any(|k| k.same_content(entry)) {
+                    continue;
+                }
+                if !result.ends_with('\n') {
+                    result.push('\n');
+                }
+                result.push('\n');
+                result.push_str(&entry.to_markdown());
+                known.push(entry.clone());
+                appended += 1;
+            }
+            result
+        })
+        .with_context(|| format!("Failed to append to {}", file_type.filename()))?;
+        self.seal(file_type);
+
+        Ok(appended)
+    }
+
+    /// Find the entry with `id` in any knowledge file.
+    pub fn find_entry(&self, id: &str) -> Result<Option<(KnowledgeFile, KnowledgeEntry)>> {
+        for file_type in KnowledgeFile::all() {
+            if let Some(entry) = self.entries(*file_type)?.into_iter().find(|e| e.id == id) {
+                return Ok(Some((*file_type, entry)));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Apply `update` to the entry with `id` and rewrite its section in
+    /// place; the rest of the file is left untouched.
+    ///
+    /// # Returns
+    ///
+    /// The updated entry, or `None` when the file has no entry with `id`.
+    pub fn update_entry(
+        &self,
+        file_type: KnowledgeFile,
+        id: &str,
+        update: impl FnOnce(&mut KnowledgeEntry),
+    ) -> Result<Option<KnowledgeEntry>> {
+        let path = self.file_path(file_type);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let mut updated = None;
+
+        crate::fs::locking::locked_read_modify_write(&path, |existing| {
+            let lines: Vec<&str> = existing.lines().collect();
+            let mut headings = Vec::new();
+            let mut in_fence = false;
+            for (i, line) in lines.iter().enumerate() {
+                if line.trim_start().starts_with("```") {
+                    in_fence = !in_fence;
+                }
+                if !in_fence && line.starts_with("## ") {
+                    headings.push(i);
+                }
+            }
+
+            for (n, &start) in headings.iter().enumerate() {
+                let end = headings.get(n + 1).copied().unwrap_or(lines.len());
+                let section = lines[start..end].join("\n");
+                let Some(mut entry) = parse_entries(&section).1.pop() else {
+                    continue;
+                };
+                if entry.id != id {
+                    continue;
+                }
+                update(&mut entry);
+
+                let mut result = String::new();
+                for line in &lines[..start] {
+                    result.push_str(line);
+                    result.push('\n');
+                }
+                result.push_str(&entry.to_markdown());
+                if end < lines.len() {
+                    result.push('\n');
+                    result.push_str(&lines[end..].join("\n"));
+                    result.push('\n');
+                }
+                updated = Some(entry);
+                return result;
+            }
+            existing
+        })
+        .with_context(|| format!("Failed to update entry {id} in {}", file_type.filename()))?;
+        if updated.is_some() {
+            self.seal(file_type);
+        }
+
+        Ok(updated)
+    }
+
+    /// Unresolved warn and critical entries of `mistakes.md`, critical
+    /// first and otherwise in file order, at most `limit` of them.
+    pub fn unresolved_mistakes(&self, limit: usize) -> Result<Vec<KnowledgeEntry>> {
+        let mut entries: Vec<KnowledgeEntry> = self
+            .entries(KnowledgeFile::Mistakes)?
+            .into_iter()
+            .filter(|e| !e.is_resolved() && e.severity.is_some_and(|s| s >= Severity::Warn))
+            .collect();
+        entries.sort_by_key(|e| std::cmp::Reverse(e.severity));
+        entries.truncate(limit);
+        Ok(entries)
     }
 
     /// Replace a section in a knowledge file identified by its ## heading.
@@ -180,8 +338,23 @@ impl KnowledgeDir {
                         result.push_str(line);
                         result.push('\n');
                     }
-                    // Replacement section
-                    result.push_str(&format!("## {heading_owned}\n\n{content_owned}\n"));
+                    // Replacement section, keeping the entry's metadata comment
+                    result.push_str(&format!("## {heading_owned}\n"));
+                    let section = &lines[start + 1..end];
+                    if let Some(first) = section.iter().position(|l| !l.trim().is_empty()) {
+                        if section[first].trim() == ENTRY_META_START {
+                            if let Some(len) = section[first..]
+                                .iter()
+                                .position(|l| l.trim() == ENTRY_META_END)
+                            {
+                                for line in &section[first..=first + len] {
+                                    result.push_str(line);
+                                    result.push('\n');
+                                }
+                            }
+                        }
+                    }
+                    result.push_str(&format!("\n{content_owned}\n"));
                     // Lines after the replaced section
                     if end < lines.len() {
                         result.push('\n');
@@ -215,7 +388,24 @@ impl KnowledgeDir {
                 heading,
                 file_type.filename()
             )
-        })
+        })?;
+        self.seal(file_type);
+        Ok(())
+    }
+
+    /// Record a write in the integrity ledger. Only writes in the main
+    /// repository count: a worktree's knowledge reaches it through a merge,
+    /// which appends to what was recorded there.
+    fn seal(&self, file_type: KnowledgeFile) {
+        if let Some(work_dir) = &self.work_dir {
+            if work_dir.is_dir() && !work_dir.is_symlink() {
+                crate::fs::integrity::seal_or_warn(
+                    work_dir,
+                    &self.file_path(file_type),
+                    SealMode::Append,
+                );
+            }
+        }
     }
 
     /// Generate a compact summary of all knowledge for embedding in signals
@@ -441,6 +631,88 @@ mod tests {
         assert!(summary.contains("CLI Entry Point"));
     }
 
+    #[test]
+    fn test_update_entry_rewrites_only_its_section() {
+        let temp = TempDir::new().unwrap();
+        let knowledge = KnowledgeDir::new(temp.path());
+        knowledge.initialize().unwrap();
+        knowledge
+            .append(KnowledgeFile::Mistakes, "## Hand written\n\n- keep me")
+            .unwrap();
+        let tracked = KnowledgeEntry::new("Forgot the lock", "- take the merge lock")
+            .with_severity(Some(Severity::Critical));
+        knowledge
+            .append_entries(KnowledgeFile::Mistakes, std::slice::from_ref(&tracked))
+            .unwrap();
+        let before = knowledge.read(KnowledgeFile::Mistakes).unwrap();
+
+        let hand_written = knowledge.entries(KnowledgeFile::Mistakes).unwrap()[0].clone();
+        let resolved = knowledge
+            .update_entry(KnowledgeFile::Mistakes, &hand_written.id, |e| e.resolve())
+            .unwrap()
+            .unwrap();
+        assert_eq!(resolved.id, hand_written.id);
+
+        let after = knowledge.read(KnowledgeFile::Mistakes).unwrap();
+        let (_, entries) = parse_entries(&after);
+        assert_eq!(entries.len(), 2);
+        assert!(entries[0].is_resolved());
+        assert_eq!(entries[0].id, hand_written.id);
+        assert_eq!(entries[0].body, "- keep me");
+        assert_eq!(entries[1], tracked);
+        assert!(after.ends_with(&tracked.to_markdown()));
+        assert_eq!(
+            before.split("## Hand written").next(),
+            after.split("## Hand written").next()
+        );
+
+        assert!(knowledge
+            .update_entry(KnowledgeFile::Mistakes, "k-missing", |e| e.resolve())
+            .unwrap()
+            .is_none());
+        assert_eq!(
+            knowledge.find_entry(&tracked.id).unwrap(),
+            Some((KnowledgeFile::Mistakes, tracked))
+        );
+    }
+
+    #[test]
+    fn test_unresolved_mistakes_puts_critical_first() {
+        let temp = TempDir::new().unwrap();
+        let knowledge = KnowledgeDir::new(temp.path());
+        knowledge.initialize().unwrap();
+        let entry = |title: &str, severity| {
+            KnowledgeEntry::new(title, format!("- {title}")).with_severity(severity)
+        };
+        knowledge
+            .append_entries(
+                KnowledgeFile::Mistakes,
+                &[
+                    entry("Untriaged", None),
+                    entry("Minor", Some(Severity::Info)),
+                    entry("Flaky test", Some(Severity::Warn)),
+                    entry("Lost data", Some(Severity::Critical)),
+                    entry("Fixed already", Some(Severity::Critical)),
+                ],
+            )
+            .unwrap();
+        let fixed = knowledge.entries(KnowledgeFile::Mistakes).unwrap()[4].clone();
+        knowledge
+            .update_entry(KnowledgeFile::Mistakes, &fixed.id, |e| e.resolve())
+            .unwrap();
+
+        let titles = |limit| -> Vec<String> {
+            knowledge
+                .unresolved_mistakes(limit)
+                .unwrap()
+                .into_iter()
+                .map(|e| e.title)
+                .collect()
+        };
+        assert_eq!(titles(5), vec!["Lost data", "Flaky test"]);
+        assert_eq!(titles(1), vec!["Lost data"]);
+    }
+
     #[test]
     fn test_initialize_idempotent() {
         let temp = TempDir::new().unwrap();
@@ -507,6 +779,59 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_append_entries_skips_duplicates() {
+        let temp = TempDir::new().unwrap();
+        let knowledge = KnowledgeDir::new(temp.path());
+        knowledge.initialize().unwrap();
+        knowledge
+            .append(
+                KnowledgeFile::Patterns,
+                "## Retry loop\n\n- backoff in net.rs",
+            )
+            .unwrap();
+
+        let entries = vec![
+            KnowledgeEntry::new("Retry loop", "- backoff  in net.rs"),
+            KnowledgeEntry::new("Auth tokens", "- see auth.rs").with_tags(&["auth".to_string()]),
+            KnowledgeEntry::new("Auth tokens", "- see auth.rs"),
+        ];
+        let appended = knowledge
+            .append_entries(KnowledgeFile::Patterns, &entries)
+            .unwrap();
+
+        assert_eq!(appended, 1);
+        let parsed = knowledge.entries(KnowledgeFile::Patterns).unwrap();
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[1].title, "Auth tokens");
+        assert!(parsed[1].has_tag("AUTH"));
+        assert!(knowledge
+            .generate_summary()
+            .unwrap()
+            .contains("- see auth.rs"));
+    }
+
+    #[test]
+    fn test_replace_section_keeps_entry_metadata() {
+        let temp = TempDir::new().unwrap();
+        let knowledge = KnowledgeDir::new(temp.path());
+        knowledge.initialize().unwrap();
+        let entry = KnowledgeEntry::new("Auth tokens", "- old").with_tags(&["auth".to_string()]);
+        knowledge
+            .append_entries(KnowledgeFile::Patterns, std::slice::from_ref(&entry))
+            .unwrap();
+
+        knowledge
+            .replace_section(KnowledgeFile::Patterns, "Auth tokens", "- new")
+            .unwrap();
+
+        let parsed = knowledge.entries(KnowledgeFile::Patterns).unwrap();
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed[0].id, entry.id);
+        assert_eq!(parsed[0].tags, vec!["auth"]);
+        assert_eq!(parsed[0].body, "- new");
+    }
+
     #[test]
     fn test_replace_section_not_found_appends() {
         let temp = TempDir::new().unwrap();
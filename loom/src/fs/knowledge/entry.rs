@@ -0,0 +1,347 @@
+//! Structured knowledge entries.
+//!
+//! Each `## ` section of a knowledge file is an entry. Entries written
+//! through the CLI carry their metadata in an HTML comment directly under
+//! the heading, so files stay readable and hand-editable:
+//!
+//! ```markdown
+//! ## Token refresh flow
+//! <!-- loom-knowledge
+//! id: k-3f2a9c1b
+//! tags: [auth]
+//! stage: api-auth
+//! created_at: 2026-01-05T10:00:00Z
+//! -->
+//!
+//! - src/auth/refresh.rs - refreshes tokens before expiry
+//! ```
+//!
+//! Sections without the comment (written by hand or before entries carried
+//! metadata) still parse; their id is derived from their content.
+//!
+//! Lessons can also carry a `severity` (info, warn, critical) and, once
+//! addressed with `loom learn resolve`, a `resolved_at` time. Signals list
+//! unresolved critical and warn entries of `mistakes.md` up front and leave
+//! resolved ones out.
+
+use anyhow::bail;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Opening line of an entry's metadata comment
+pub const ENTRY_META_START: &str = "<!-- loom-knowledge";
+
+/// Closing line of an entry's metadata comment
+pub const ENTRY_META_END: &str = "-->";
+
+/// How much a lesson matters to the agents that come after it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Info,
+    Warn,
+    Critical,
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Info => write!(f, "info"),
+            Severity::Warn => write!(f, "warn"),
+            Severity::Critical => write!(f, "critical"),
+        }
+    }
+}
+
+impl std::str::FromStr for Severity {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s.to_lowercase().as_str() {
+            "info" => Ok(Severity::Info),
+            "warn" | "warning" => Ok(Severity::Warn),
+            "critical" | "crit" => Ok(Severity::Critical),
+            _ => bail!("Invalid severity: {s}. Use: info, warn, critical"),
+        }
+    }
+}
+
+/// One `## ` section of a knowledge file
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct KnowledgeEntry {
+    pub id: String,
+    pub title: String,
+    pub tags: Vec<String>,
+    /// Stage that recorded the entry
+    pub stage: Option<String>,
+    /// Session that recorded the entry
+    pub session: Option<String>,
+    pub created_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<Severity>,
+    /// When the lesson was marked addressed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolved_at: Option<DateTime<Utc>>,
+    pub body: String,
+    /// Whether the section carried a metadata comment
+    #[serde(skip)]
+    pub has_meta: bool,
+}
+
+/// Metadata as serialized into the entry's comment
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct EntryMeta {
+    #[serde(default)]
+    id: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    stage: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    session: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    created_at: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    severity: Option<Severity>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    resolved_at: Option<DateTime<Utc>>,
+}
+
+impl KnowledgeEntry {
+    /// Create a new entry stamped with the current time.
+    pub fn new(title: impl Into<String>, body: impl Into<String>) -> Self {
+        let title = title.into().trim().to_string();
+        let body = body.into().trim().to_string();
+        Self {
+            id: content_id(&title, &body),
+            title,
+            tags: Vec::new(),
+            stage: None,
+            session: None,
+            created_at: Some(Utc::now()),
+            severity: None,
+            resolved_at: None,
+            body,
+            has_meta: true,
+        }
+    }
+
+    /// Attach tags, lowercased and without duplicates.
+    pub fn with_tags(mut self, tags: &[String]) -> Self {
+        for tag in tags {
+            let tag = tag.trim().to_lowercase();
+            if !tag.is_empty() && !self.tags.contains(&tag) {
+                self.tags.push(tag);
+            }
+        }
+        self
+    }
+
+    /// Record the stage and session the entry came from.
+    pub fn with_source(mut self, stage: Option<String>, session: Option<String>) -> Self {
+        self.stage = stage;
+        self.session = session;
+        self
+    }
+
+    /// Set the lesson's severity.
+    pub fn with_severity(mut self, severity: Option<Severity>) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Mark the lesson addressed now. Entries without a metadata comment
+    /// gain one, keeping their content-derived id.
+    pub fn resolve(&mut self) {
+        self.resolved_at = Some(Utc::now());
+        self.has_meta = true;
+    }
+
+    /// Whether the lesson has been marked addressed.
+    pub fn is_resolved(&self) -> bool {
+        self.resolved_at.is_some()
+    }
+
+    /// Whether the entry carries `tag` (case-insensitive).
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t.eq_ignore_ascii_case(tag))
+    }
+
+    /// Whether two entries say the same thing, ignoring metadata and
+    /// whitespace differences.
+    pub fn same_content(&self, other: &KnowledgeEntry) -> bool {
+        content_id(&self.title, &self.body) == content_id(&other.title, &other.body)
+    }
+
+    /// Render the entry as a markdown section.
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!("## {}\n", self.title);
+        if self.has_meta {
+            let meta = EntryMeta {
+                id: Some(self.id.clone()),
+                tags: self.tags.clone(),
+                stage: self.stage.clone(),
+                session: self.session.clone(),
+                created_at: self.created_at,
+                severity: self.severity,
+                resolved_at: self.resolved_at,
+            };
+            let yaml = serde_yaml::to_string(&meta).unwrap_or_default();
+            out.push_str(ENTRY_META_START);
+            out.push('\n');
+            out.push_str(&yaml);
+            if !yaml.ends_with('\n') {
+                out.push('\n');
+            }
+            out.push_str(ENTRY_META_END);
+            out.push('\n');
+        }
+        if !self.body.is_empty() {
+            out.push('\n');
+            out.push_str(&self.body);
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// Stable id for an entry's content: `k-` plus a short hash of its
+/// whitespace-normalized title and body.
+pub fn content_id(title: &str, body: &str) -> String {
+    let normalize = |s: &str| s.split_whitespace().collect::<Vec<_>>().join(" ");
+    let mut hasher = Sha256::new();
+    hasher.update(format!("{}\n{}", normalize(title), normalize(body)).as_bytes());
+    format!("k-{}", hex::encode(&hasher.finalize()[..4]))
+}
+
+/// Split markdown into the text before the first `## ` heading and the
+/// entries that follow. Headings inside fenced code blocks are ignored.
+pub fn parse_entries(content: &str) -> (String, Vec<KnowledgeEntry>) {
+    let mut preamble = String::new();
+    let mut sections: Vec<(String, Vec<&str>)> = Vec::new();
+    let mut in_fence = false;
+
+    for line in content.lines() {
+        if line.trim_start().starts_with("```") {
+            in_fence = !in_fence;
+        }
+        if !in_fence {
+            if let Some(title) = line.strip_prefix("## ") {
+                sections.push((title.trim().to_string(), Vec::new()));
+                continue;
+            }
+        }
+        match sections.last_mut() {
+            Some((_, lines)) => lines.push(line),
+            None => {
+                preamble.push_str(line);
+                preamble.push('\n');
+            }
+        }
+    }
+
+    let entries = sections
+        .into_iter()
+        .map(|(title, lines)| parse_section(title, &lines))
+        .collect();
+    (preamble.trim().to_string(), entries)
+}
+
+fn parse_section(title: String, lines: &[&str]) -> KnowledgeEntry {
+    let mut rest = lines;
+    let mut meta = None;
+
+    let first = lines.iter().position(|line| !line.trim().is_empty());
+    if let Some(start) = first.filter(|&i| lines[i].trim() == ENTRY_META_START) {
+        if let Some(len) = lines[start + 1..]
+            .iter()
+            .position(|line| line.trim() == ENTRY_META_END)
+        {
+            let yaml = lines[start + 1..start + 1 + len].join("\n");
+            meta = serde_yaml::from_str::<EntryMeta>(&yaml).ok();
+            rest = &lines[start + len + 2..];
+        }
+    }
+
+    let body = rest.join("\n").trim().to_string();
+    let has_meta = meta.is_some();
+    let meta = meta.unwrap_or_default();
+    KnowledgeEntry {
+        id: meta.id.unwrap_or_else(|| content_id(&title, &body)),
+        title,
+        tags: meta.tags,
+        stage: meta.stage,
+        session: meta.session,
+        created_at: meta.created_at,
+        severity: meta.severity,
+        resolved_at: meta.resolved_at,
+        body,
+        has_meta,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entry_round_trips_through_markdown() {
+        let entry = KnowledgeEntry::new("Token refresh", "- src/auth.rs - refresh")
+            .with_tags(&["Auth".to_string(), "auth".to_string(), "api".to_string()])
+            .with_source(Some("api-auth".to_string()), Some("s1".to_string()));
+
+        let markdown = format!("# Patterns\n\n> intro\n\n{}", entry.to_markdown());
+        let (preamble, entries) = parse_entries(&markdown);
+
+        assert_eq!(preamble, "# Patterns\n\n> intro");
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0], entry);
+        assert_eq!(entries[0].tags, vec!["auth", "api"]);
+        assert!(entries[0].id.starts_with("k-"));
+    }
+
+    #[test]
+    fn test_parse_entries_accepts_plain_sections_and_fenced_headings() {
+        let content =
+            "# Mistakes\n\n## Hand written\n\nBody\n```\n## not a heading\n```\n\n## Second\n- x\n";
+        let (_, entries) = parse_entries(content);
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].title, "Hand written");
+        assert!(entries[0].body.contains("## not a heading"));
+        assert!(!entries[0].has_meta);
+        assert!(entries[0].tags.is_empty());
+        assert_eq!(entries[0].id, content_id("Hand written", &entries[0].body));
+        assert_eq!(entries[1].body, "- x");
+    }
+
+    #[test]
+    fn test_severity_and_resolution_round_trip() {
+        let mut entry = KnowledgeEntry::new("Skipped migrations", "- run them first")
+            .with_severity(Some(Severity::Critical));
+        assert!(!entry.is_resolved());
+        entry.resolve();
+
+        let markdown = entry.to_markdown();
+        assert!(markdown.contains("severity: critical"));
+        assert!(markdown.contains("resolved_at:"));
+        let (_, entries) = parse_entries(&markdown);
+        assert_eq!(entries[0], entry);
+        assert!(entries[0].is_resolved());
+
+        assert_eq!("WARNING".parse::<Severity>().unwrap(), Severity::Warn);
+        assert!("fatal".parse::<Severity>().is_err());
+        assert!(Severity::Critical > Severity::Warn && Severity::Warn > Severity::Info);
+    }
+
+    #[test]
+    fn test_same_content_ignores_whitespace_and_metadata() {
+        let a = KnowledgeEntry::new("Title", "- one\n- two").with_tags(&["x".to_string()]);
+        let b = KnowledgeEntry::new(" Title ", "- one\n\n- two  ");
+        let c = KnowledgeEntry::new("Title", "- three");
+
+        assert!(a.same_content(&b));
+        assert!(!a.same_content(&c));
+    }
+}
@@ -0,0 +1,254 @@
+//! Export of curated knowledge to a location shared across projects.
+//!
+//! `loom learn export` copies the entries of `doc/loom/knowledge/` into a
+//! directory shared by every project a team orchestrates with loom, usually
+//! a checkout of a dedicated git repository:
+//!
+//! ```text
+//! <export_path>/
+//!   <project>/
+//!     conventions.md     # same layout as doc/loom/knowledge/
+//!     mistakes.md
+//!     ...
+//!     provenance.json    # where and when the entries came from
+//! ```
+//!
+//! Entries keep their metadata (id, tags, stage, session). Entries already in
+//! the exported file are skipped, so repeated exports only add what is new.
+//!
+//! ```toml
+//! [learn]
+//! export_path = "../team-knowledge"  # relative to the project root
+//! project = "billing-api"            # default: project directory name
+//! ```
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::{KnowledgeDir, KnowledgeEntry, KnowledgeFile};
+use crate::git::runner::run_git_checked;
+
+/// Provenance file written next to the exported knowledge files.
+pub const PROVENANCE_FILE: &str = "provenance.json";
+
+/// Persisted `[learn]` section of `.work/config.toml`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LearnConfig {
+    /// Shared knowledge location; relative paths resolve against the
+    /// project root.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub export_path: Option<PathBuf>,
+    /// Directory name for this project under the export path.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+}
+
+/// Where exported entries came from.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ExportProvenance {
+    pub project: String,
+    /// URL of the source repository's `origin` remote
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote: Option<String>,
+    /// Source repository `HEAD` at export time
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plan_id: Option<String>,
+    pub loom_version: String,
+    pub exported_at: DateTime<Utc>,
+    /// Entries each exported file held in the source, by filename
+    pub entries: BTreeMap<String, usize>,
+}
+
+impl ExportProvenance {
+    /// Describe an export of `project` from the repository at `project_root`.
+    pub fn collect(project_root: &Path, project: &str, plan_id: Option<&str>) -> Self {
+        let git = |args: &[&str]| {
+            run_git_checked(args, project_root)
+                .ok()
+                .map(|out| out.trim().to_string())
+                .filter(|out| !out.is_empty())
+        };
+        Self {
+            project: project.to_string(),
+            remote: git(&["remote", "get-url", "origin"]),
+            commit: git(&["rev-parse", "HEAD"]),
+            plan_id: plan_id.map(String::from),
+            loom_version: env!("CARGO_PKG_VERSION").to_string(),
+            exported_at: Utc::now(),
+            entries: BTreeMap::new(),
+        }
+    }
+}
+
+/// Outcome of an export.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportReport {
+    /// `<export_path>/<project>`
+    pub target: PathBuf,
+    /// New entries per file (files with nothing new are omitted)
+    pub added: Vec<(KnowledgeFile, usize)>,
+}
+
+impl ExportReport {
+    pub fn total_added(&self) -> usize {
+        self.added.iter().map(|(_, count)| count).sum()
+    }
+}
+
+/// Directory name used for a project under the export path.
+///
+/// Rejects names that would escape the export path or nest directories.
+pub fn validate_project_name(project: &str) -> Result<()> {
+    if project.is_empty() || project == "." || project == ".." || project.contains(['/', '\\']) {
+        bail!("Invalid knowledge export project name '{project}': must be a single directory name");
+    }
+    Ok(())
+}
+
+/// Export the entries of `files` from `source` to `<export_root>/<project>`.
+///
+/// With `dry_run`, nothing is written and the report lists what would be
+/// added. The provenance file is rewritten whenever entries are added (or
+/// when it is missing), so it always describes the latest export.
+pub fn export_knowledge(
+    source: &KnowledgeDir,
+    export_root: &Path,
+    files: &[KnowledgeFile],
+    mut provenance: ExportProvenance,
+    dry_run: bool,
+) -> Result<ExportReport> {
+    validate_project_name(&provenance.project)?;
+    let target_root = export_root.join(&provenance.project);
+    let target = KnowledgeDir::at(&target_root);
+    if !dry_run {
+        fs::create_dir_all(&target_root).with_context(|| {
+            format!(
+                "Failed to create export directory {}",
+                target_root.display()
+            )
+        })?;
+    }
+
+    let mut added = Vec::new();
+    for file_type in files {
+        let entries = source.entries(*file_type)?;
+        if entries.is_empty() {
+            continue;
+        }
+        provenance
+            .entries
+            .insert(file_type.filename().to_string(), entries.len());
+
+        let count = if dry_run {
+            new_entries(&target.entries(*file_type)?, &entries)
+        } else {
+            target.append_entries(*file_type, &entries)?
+        };
+        if count > 0 {
+            added.push((*file_type, count));
+        }
+    }
+
+    let provenance_path = target_root.join(PROVENANCE_FILE);
+    if !dry_run && (!added.is_empty() || !provenance_path.exists()) {
+        let json = serde_json::to_string_pretty(&provenance)?;
+        fs::write(&provenance_path, format!("{json}\n"))
+            .with_context(|| format!("Failed to write {}", provenance_path.display()))?;
+    }
+
+    Ok(ExportReport {
+        target: target_root,
+        added,
+    })
+}
+
+/// How many of `entries` are not already in `existing` (mirrors the
+/// deduplication of [`KnowledgeDir::append_entries`]).
+fn new_entries(existing: &[KnowledgeEntry], entries: &[KnowledgeEntry]) -> usize {
+    let mut known: Vec<&KnowledgeEntry> = existing.iter().collect();
+    let mut count = 0;
+    for entry in entries {
+        if known.iter().any(|k| k.same_content(entry)) {
+            continue;
+        }
+        known.push(entry);
+        count += 1;
+    }
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn provenance(project: &str) -> ExportProvenance {
+        ExportProvenance {
+            project: project.to_string(),
+            remote: None,
+            commit: None,
+            plan_id: Some("PLAN-0001".to_string()),
+            loom_version: "0.0.0".to_string(),
+            exported_at: Utc::now(),
+            entries: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_export_knowledge_is_idempotent() {
+        let temp = TempDir::new().unwrap();
+        let source = KnowledgeDir::new(temp.path().join("project"));
+        source.initialize().unwrap();
+        source
+            .append_entries(
+                KnowledgeFile::Mistakes,
+                &[
+                    KnowledgeEntry::new("Forgot migrations", "Run them first")
+                        .with_source(Some("db".to_string()), None),
+                    KnowledgeEntry::new("Stale cache", "Clear it"),
+                ],
+            )
+            .unwrap();
+        let export_root = temp.path().join("shared");
+        let files = KnowledgeFile::all();
+
+        let report =
+            export_knowledge(&source, &export_root, files, provenance("api"), true).unwrap();
+        assert_eq!(report.added, vec![(KnowledgeFile::Mistakes, 2)]);
+        assert!(!export_root.exists());
+
+        let report =
+            export_knowledge(&source, &export_root, files, provenance("api"), false).unwrap();
+        assert_eq!(report.total_added(), 2);
+        let exported = KnowledgeDir::at(export_root.join("api"));
+        let entries = exported.entries(KnowledgeFile::Mistakes).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].stage.as_deref(), Some("db"));
+
+        let written: ExportProvenance = serde_json::from_str(
+            &fs::read_to_string(export_root.join("api").join(PROVENANCE_FILE)).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(written.plan_id.as_deref(), Some("PLAN-0001"));
+        assert_eq!(written.entries.get("mistakes.md"), Some(&2));
+
+        let report =
+            export_knowledge(&source, &export_root, files, provenance("api"), false).unwrap();
+        assert!(report.added.is_empty());
+        assert_eq!(exported.entries(KnowledgeFile::Mistakes).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_validate_project_name() {
+        assert!(validate_project_name("billing-api").is_ok());
+        for name in ["", ".", "..", "a/b", "a\\b"] {
+            assert!(validate_project_name(name).is_err(), "{name:?}");
+        }
+    }
+}
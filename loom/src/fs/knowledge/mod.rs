@@ -5,11 +5,14 @@
 //! not raw indexing.
 
 pub mod dir;
+pub mod entry;
+pub mod export;
 pub mod gc;
 pub mod types;
 
 // Re-export commonly used types
 pub use dir::KnowledgeDir;
+pub use entry::{parse_entries, KnowledgeEntry, Severity};
 pub use gc::{
     analyze_gc_metrics, FileGcMetrics, GcMetrics, DEFAULT_MAX_FILE_LINES,
     DEFAULT_MAX_PROMOTED_BLOCKS, DEFAULT_MAX_TOTAL_LINES,
@@ -0,0 +1,328 @@
+//! Size-based rotation of loom's log files.
+//!
+//! The daemon, the supervisor and every session append to logs under
+//! `.work/` for as long as they run: `orchestrator.log`, `supervisor.log`
+//! and everything below `.work/logs/` (headless session output, worktree
+//! setup logs, the scheduler's decision log). Without a cap a long plan can
+//! fill the disk, so the daemon rotates any of them that outgrows the
+//! configured size:
+//!
+//! ```toml
+//! [logs]
+//! max_size_mb = 10   # 0 disables rotation
+//! keep = 3           # rotated generations kept per log
+//! ```
+//!
+//! A log is rotated by copying it to `<name>.1` (shifting older generations
+//! up to `<name>.<keep>`) and truncating it in place. Writers keep their open
+//! descriptor, so every loom log is opened in append mode (see
+//! [`create_log`]); their next write lands at the start of the emptied file.
+//! `loom logs prune` applies the same policy on demand.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File, OpenOptions};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::fs::work_dir::read_logs_config;
+
+/// How often the daemon checks log sizes.
+pub const ROTATION_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Size a log may reach before rotation when `max_size_mb` is unset.
+pub const DEFAULT_MAX_SIZE_MB: u64 = 10;
+
+/// Rotated generations kept per log when `keep` is unset.
+pub const DEFAULT_KEEP: usize = 3;
+
+/// Extensions of the files below `.work/logs/` that are rotated
+/// (`scheduler.jsonl` is the scheduler's decision log).
+const LOG_EXTENSIONS: &[&str] = &["log", "jsonl"];
+
+/// Logs directly in `.work/` that are rotated along with `.work/logs/`.
+const TOP_LEVEL_LOGS: &[&str] = &["orchestrator.log", "supervisor.log"];
+
+/// Persisted `[logs]` section of `.work/config.toml`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct LogsConfig {
+    /// Size in MiB above which a log is rotated; 0 disables rotation
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_size_mb: Option<u64>,
+    /// Rotated generations kept per log
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keep: Option<usize>,
+}
+
+impl LogsConfig {
+    /// Load the `[logs]` config, falling back to the defaults when the
+    /// section is invalid.
+    pub fn load(work_dir: &Path) -> Self {
+        read_logs_config(work_dir).unwrap_or_else(|e| {
+            eprintln!("Warning: ignoring invalid [logs] config: {e:#}");
+            Self::default()
+        })
+    }
+
+    /// Rotation threshold in bytes, or `None` when rotation is disabled.
+    pub fn max_bytes(&self) -> Option<u64> {
+        match self.max_size_mb.unwrap_or(DEFAULT_MAX_SIZE_MB) {
+            0 => None,
+            mb => Some(mb.saturating_mul(1024 * 1024)),
+        }
+    }
+
+    pub fn keep(&self) -> usize {
+        self.keep.unwrap_or(DEFAULT_KEEP)
+    }
+}
+
+/// Result of a rotation pass.
+#[derive(Debug, Default)]
+pub struct PruneReport {
+    /// Logs that were over the size cap and got rotated
+    pub rotated: Vec<PathBuf>,
+    /// Rotated generations deleted for exceeding the retention count
+    pub removed: Vec<PathBuf>,
+    /// Bytes freed by deleting generations
+    pub freed_bytes: u64,
+}
+
+/// Create (or truncate) a log file and open it for appending.
+///
+/// Append mode makes in-place truncation by [`rotate_logs`] safe for the
+/// writer; a plain `File::create` would keep writing at its old offset.
+pub fn create_log(path: &Path) -> Result<File> {
+    File::create(path).with_context(|| format!("Failed to create {}", path.display()))?;
+    OpenOptions::new()
+        .append(true)
+        .open(path)
+        .with_context(|| format!("Failed to open {}", path.display()))
+}
+
+/// Path of the `generation`th rotated copy of `log`.
+pub fn rotated_path(log: &Path, generation: usize) -> PathBuf {
+    let mut name = log.as_os_str().to_os_string();
+    name.push(format!(".{generation}"));
+    PathBuf::from(name)
+}
+
+/// Active log files loom writes under `work_dir`.
+pub fn log_files(work_dir: &Path) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = TOP_LEVEL_LOGS
+        .iter()
+        .map(|name| work_dir.join(name))
+        .filter(|path| path.is_file())
+        .collect();
+    collect_logs(&work_dir.join("logs"), &mut files);
+    files.sort();
+    files
+}
+
+fn collect_logs(dir: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Ok(file_type) = entry.file_type() else {
+            continue;
+        };
+        if file_type.is_dir() {
+            collect_logs(&path, files);
+        } else if file_type.is_file()
+            && path
+                .extension()
+                .is_some_and(|ext| LOG_EXTENSIONS.iter().any(|e| ext == *e))
+        {
+            files.push(path);
+        }
+    }
+}
+
+/// Rotate every log over the size cap and delete generations beyond `keep`.
+///
+/// With `dry_run`, only reports what would happen. Generations beyond
+/// `keep` are deleted even when rotation is disabled, or their log is gone.
+pub fn rotate_logs(work_dir: &Path, config: &LogsConfig, dry_run: bool) -> Result<PruneReport> {
+    let keep = config.keep();
+    let mut report = PruneReport::default();
+
+    if let Some(max) = config.max_bytes() {
+        for log in log_files(work_dir) {
+            if fs::metadata(&log).is_ok_and(|m| m.len() > max) {
+                rotate_file(&log, keep, dry_run, &mut report)?;
+            }
+        }
+    }
+
+    for (path, generation) in rotated_files(work_dir) {
+        if generation > keep {
+            remove_generation(path, dry_run, &mut report)?;
+        }
+    }
+
+    Ok(report)
+}
+
+/// Copy `log` to `<log>.1` after shifting older generations up (dropping
+/// `<log>.<keep>`), then truncate `log` in place.
+fn rotate_file(log: &Path, keep: usize, dry_run: bool, report: &mut PruneReport) -> Result<()> {
+    report.rotated.push(log.to_path_buf());
+    if keep > 0 {
+        let oldest = rotated_path(log, keep);
+        if oldest.exists() {
+            remove_generation(oldest, dry_run, report)?;
+        }
+    }
+    if dry_run {
+        return Ok(());
+    }
+
+    if keep > 0 {
+        for generation in (1..keep).rev() {
+            let from = rotated_path(log, generation);
+            if from.exists() {
+                fs::rename(&from, rotated_path(log, generation + 1))
+                    .with_context(|| format!("Failed to rotate {}", from.display()))?;
+            }
+        }
+        fs::copy(log, rotated_path(log, 1))
+            .with_context(|| format!("Failed to rotate {}", log.display()))?;
+    }
+    OpenOptions::new()
+        .write(true)
+        .open(log)
+        .and_then(|file| file.set_len(0))
+        .with_context(|| format!("Failed to truncate {}", log.display()))
+}
+
+fn remove_generation(path: PathBuf, dry_run: bool, report: &mut PruneReport) -> Result<()> {
+    report.freed_bytes += fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+    if !dry_run {
+        fs::remove_file(&path).with_context(|| format!("Failed to remove {}", path.display()))?;
+    }
+    report.removed.push(path);
+    Ok(())
+}
+
+/// Rotated generations (`<name>.log.<n>`, `<name>.jsonl.<n>`) in `work_dir` and below
+/// `work_dir/logs`, with their `n`.
+fn rotated_files(work_dir: &Path) -> Vec<(PathBuf, usize)> {
+    let mut found = Vec::new();
+    collect_generations(work_dir, false, &mut found);
+    collect_generations(&work_dir.join("logs"), true, &mut found);
+    found.sort();
+    found
+}
+
+fn collect_generations(dir: &Path, recursive: bool, found: &mut Vec<(PathBuf, usize)>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Ok(file_type) = entry.file_type() else {
+            continue;
+        };
+        if file_type.is_dir() {
+            if recursive {
+                collect_generations(&path, true, found);
+            }
+        } else if let Some(generation) = file_type.is_file().then(|| generation_of(&path)).flatten()
+        {
+            found.push((path, generation));
+        }
+    }
+}
+
+fn generation_of(path: &Path) -> Option<usize> {
+    let name = path.file_name()?.to_str()?;
+    let (stem, generation) = name.rsplit_once('.')?;
+    if !LOG_EXTENSIONS
+        .iter()
+        .any(|ext| stem.ends_with(&format!(".{ext}")))
+    {
+        return None;
+    }
+    generation.parse().ok().filter(|n| *n > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::TempDir;
+
+    fn config(max_size_mb: u64, keep: usize) -> LogsConfig {
+        LogsConfig {
+            max_size_mb: Some(max_size_mb),
+            keep: Some(keep),
+        }
+    }
+
+    #[test]
+    fn test_rotate_logs_shifts_generations_and_caps_retention() {
+        let temp = TempDir::new().unwrap();
+        let work_dir = temp.path();
+        let log = work_dir.join("logs").join("sessions").join("s1.log");
+        fs::create_dir_all(log.parent().unwrap()).unwrap();
+
+        let mut writer = create_log(&log).unwrap();
+        for round in 1..=3u8 {
+            writer
+                .write_all(&vec![b'0' + round; 1024 * 1024 + 1])
+                .unwrap();
+            let report = rotate_logs(work_dir, &config(1, 2), false).unwrap();
+            assert_eq!(report.rotated, vec![log.clone()]);
+            assert_eq!(report.removed.len(), usize::from(round == 3));
+        }
+
+        // The writer keeps appending at the start of the truncated file
+        writer.write_all(b"tail").unwrap();
+        assert_eq!(fs::read(&log).unwrap(), b"tail");
+        assert_eq!(fs::read(rotated_path(&log, 1)).unwrap()[0], b'3');
+        assert_eq!(fs::read(rotated_path(&log, 2)).unwrap()[0], b'2');
+        assert!(!rotated_path(&log, 3).exists());
+    }
+
+    #[test]
+    fn test_rotate_logs_dry_run_and_reduced_retention() {
+        let temp = TempDir::new().unwrap();
+        let work_dir = temp.path();
+        let log = work_dir.join("orchestrator.log");
+        fs::write(&log, "small").unwrap();
+        for generation in 1..=3 {
+            fs::write(rotated_path(&log, generation), "old").unwrap();
+        }
+
+        let report = rotate_logs(work_dir, &config(1, 1), true).unwrap();
+        assert!(report.rotated.is_empty());
+        assert_eq!(
+            report.removed,
+            vec![rotated_path(&log, 2), rotated_path(&log, 3)]
+        );
+        assert_eq!(report.freed_bytes, 6);
+        assert!(rotated_path(&log, 3).exists());
+
+        rotate_logs(work_dir, &config(0, 1), false).unwrap();
+        assert!(rotated_path(&log, 1).exists());
+        assert!(!rotated_path(&log, 2).exists());
+        assert_eq!(fs::read_to_string(&log).unwrap(), "small");
+    }
+
+    #[test]
+    fn test_logs_config_defaults() {
+        let config = LogsConfig::default();
+        assert_eq!(config.max_bytes(), Some(10 * 1024 * 1024));
+        assert_eq!(config.keep(), 3);
+        assert_eq!(
+            LogsConfig {
+                max_size_mb: Some(0),
+                keep: None
+            }
+            .max_bytes(),
+            None
+        );
+    }
+}
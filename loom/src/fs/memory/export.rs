@@ -1,15 +1,19 @@
 //! Export and formatting functions for memory journal content.
 
-use super::query::get_recent_entries;
+use super::query::rank_entries_for_recitation;
 use super::storage::read_journal;
 use super::types::MemoryEntryType;
 use crate::utils::truncate_for_display;
 use std::path::Path;
 
 /// Format memory entries for embedding in a signal
+///
+/// Recites the `max_entries` most relevant entries to the stage, ranked
+/// against `keywords` by [`rank_entries_for_recitation`].
 pub fn format_memory_for_signal(
     work_dir: &Path,
     stage_id: &str,
+    keywords: &[String],
     max_entries: usize,
 ) -> Option<String> {
     let journal = read_journal(work_dir, stage_id).ok()?;
@@ -18,7 +22,7 @@ pub fn format_memory_for_signal(
         return None;
     }
 
-    let recent = get_recent_entries(&journal, max_entries);
+    let recent = rank_entries_for_recitation(&journal, keywords, max_entries);
     if recent.is_empty() {
         return None;
     }
@@ -3,7 +3,7 @@
 //! Memory journals allow agents to continuously record notes, decisions, and questions
 //! during a session. This implements the Manus todo.md recitation pattern:
 //! - Agent constantly writes to memory
-//! - Signal generation recites recent memory at end
+//! - Signal generation recites the most relevant memory at end
 //! - Keeps important context in attention window
 //!
 //! Memory files are stored in .work/memory/{session-id}.md
@@ -27,17 +27,23 @@ pub use types::{MemoryEntry, MemoryEntryType, MemoryJournal};
 // Re-export storage functions
 pub use storage::{
     append_entry, create_journal, init_memory_dir, memory_dir, memory_file_path, read_journal,
-    write_summary,
+    rewrite_entries, write_summary,
 };
 
 // Re-export query functions
-pub use query::{generate_summary, get_recent_entries, query_entries};
+pub use query::{
+    generate_summary, get_recent_entries, query_entries, rank_entries_for_recitation,
+    recitation_keywords,
+};
 
 // Re-export export functions
 pub use export::{format_memory_for_handoff, format_memory_for_signal};
 
 // Re-export persistence functions
-pub use persistence::{extract_key_notes, list_journals, preserve_for_crash, validate_content};
+pub use persistence::{
+    delete_entries_by_type, delete_entry, edit_entry, extract_key_notes, list_journals,
+    preserve_for_crash, validate_content,
+};
 
 #[cfg(test)]
 mod tests {
@@ -133,7 +139,7 @@ mod tests {
         append_entry(work_dir, stage_id, &entry2).unwrap();
         append_entry(work_dir, stage_id, &entry3).unwrap();
 
-        let signal = format_memory_for_signal(work_dir, stage_id, 10).unwrap();
+        let signal = format_memory_for_signal(work_dir, stage_id, &[], 10).unwrap();
         assert!(signal.contains("### Notes"));
         assert!(signal.contains("Note 1"));
         assert!(signal.contains("### Decisions"));
@@ -290,6 +296,59 @@ mod tests {
         assert!(validate_content(&"a".repeat(2001)).is_err());
     }
 
+    #[test]
+    fn test_delete_and_edit_entries() {
+        let temp_dir = TempDir::new().unwrap();
+        let work_dir = temp_dir.path();
+
+        let stage_id = "test-stage";
+        for (entry_type, text) in [
+            (MemoryEntryType::Note, "Noisy note 1"),
+            (MemoryEntryType::Decision, "Use JWT"),
+            (MemoryEntryType::Note, "Noisy note 2"),
+            (MemoryEntryType::Question, "Cache results?"),
+        ] {
+            append_entry(
+                work_dir,
+                stage_id,
+                &MemoryEntry::new(entry_type, text.to_string()),
+            )
+            .unwrap();
+        }
+        write_summary(work_dir, stage_id, "\n## Summary\n\nStale\n").unwrap();
+
+        assert_eq!(
+            delete_entries_by_type(work_dir, stage_id, Some(MemoryEntryType::Note)).unwrap(),
+            2
+        );
+        let content = std::fs::read_to_string(memory_file_path(work_dir, stage_id)).unwrap();
+        assert!(content.starts_with("<!-- loom-memory-journal -->"));
+        assert!(content.contains("**Stage**: test-stage"));
+        assert!(!content.contains("Stale"));
+
+        let edited = edit_entry(
+            work_dir,
+            stage_id,
+            1,
+            "Use JWT with rotation".to_string(),
+            Some("Short-lived tokens".to_string()),
+        )
+        .unwrap();
+        assert_eq!(edited.entry_type, MemoryEntryType::Decision);
+
+        let removed = delete_entry(work_dir, stage_id, 2).unwrap();
+        assert_eq!(removed.content, "Cache results?");
+        assert!(delete_entry(work_dir, stage_id, 2).is_err());
+
+        let journal = read_journal(work_dir, stage_id).unwrap();
+        assert_eq!(journal.entries.len(), 1);
+        assert_eq!(journal.entries[0].content, "Use JWT with rotation");
+        assert_eq!(
+            journal.entries[0].context.as_deref(),
+            Some("Short-lived tokens")
+        );
+    }
+
     #[test]
     fn test_list_journals() {
         let temp_dir = TempDir::new().unwrap();
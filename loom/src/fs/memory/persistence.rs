@@ -1,7 +1,7 @@
-//! Persistence operations for memory journals (listing, archiving).
+//! Persistence operations for memory journals (listing, archiving, pruning).
 
-use super::storage::{memory_dir, memory_file_path};
-use super::types::MemoryEntryType;
+use super::storage::{memory_dir, memory_file_path, read_journal, rewrite_entries};
+use super::types::{MemoryEntry, MemoryEntryType};
 use anyhow::{bail, Context, Result};
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -50,6 +50,69 @@ pub fn preserve_for_crash(work_dir: &Path, stage_id: &str) -> Result<Option<Path
     Ok(Some(dest))
 }
 
+/// Delete entries from a stage's memory journal.
+///
+/// Removes every entry of `entry_type` (all entries when `None`) and returns
+/// how many were removed. The journal is left untouched when nothing matches.
+pub fn delete_entries_by_type(
+    work_dir: &Path,
+    stage_id: &str,
+    entry_type: Option<MemoryEntryType>,
+) -> Result<usize> {
+    let mut journal = read_journal(work_dir, stage_id)?;
+    let before = journal.entries.len();
+    journal
+        .entries
+        .retain(|e| entry_type.is_some_and(|t| e.entry_type != t));
+    let removed = before - journal.entries.len();
+    if removed > 0 {
+        rewrite_entries(work_dir, stage_id, &journal.entries)?;
+    }
+    Ok(removed)
+}
+
+/// Delete the entry at a 1-based `index` (as numbered by `loom memory show`)
+/// and return it.
+pub fn delete_entry(work_dir: &Path, stage_id: &str, index: usize) -> Result<MemoryEntry> {
+    let mut journal = read_journal(work_dir, stage_id)?;
+    let position = entry_position(&journal, index)?;
+    let removed = journal.entries.remove(position);
+    rewrite_entries(work_dir, stage_id, &journal.entries)?;
+    Ok(removed)
+}
+
+/// Replace the content (and optionally the context) of the entry at a
+/// 1-based `index`, keeping its type and timestamp. Returns the updated entry.
+pub fn edit_entry(
+    work_dir: &Path,
+    stage_id: &str,
+    index: usize,
+    content: String,
+    context: Option<String>,
+) -> Result<MemoryEntry> {
+    let mut journal = read_journal(work_dir, stage_id)?;
+    let position = entry_position(&journal, index)?;
+    let entry = &mut journal.entries[position];
+    entry.content = content;
+    if context.is_some() {
+        entry.context = context;
+    }
+    let updated = entry.clone();
+    rewrite_entries(work_dir, stage_id, &journal.entries)?;
+    Ok(updated)
+}
+
+fn entry_position(journal: &super::types::MemoryJournal, index: usize) -> Result<usize> {
+    let count = journal.entries.len();
+    if index == 0 || index > count {
+        bail!(
+            "No memory entry #{index} in stage '{}' ({count} entries)",
+            journal.stage_id
+        );
+    }
+    Ok(index - 1)
+}
+
 /// Extract key notes from memory for review on completion
 pub fn extract_key_notes(journal: &super::types::MemoryJournal) -> Vec<String> {
     let mut key_notes = Vec::new();
@@ -2,6 +2,16 @@
 
 use super::types::{MemoryEntry, MemoryEntryType, MemoryJournal};
 use crate::utils::truncate_for_display;
+use std::collections::HashSet;
+
+/// Word overlap (Jaccard) above which two entries count as near-identical
+const NEAR_DUPLICATE_SIMILARITY: f64 = 0.8;
+
+/// Keyword matches counted per entry when ranking for recitation
+const MAX_KEYWORD_HITS: usize = 3;
+
+/// Keywords shorter than this are too generic to rank by
+const MIN_KEYWORD_LEN: usize = 4;
 
 /// Get recent entries from a journal (for recitation in signals)
 pub fn get_recent_entries(journal: &MemoryJournal, max_entries: usize) -> Vec<&MemoryEntry> {
@@ -13,6 +23,109 @@ pub fn get_recent_entries(journal: &MemoryJournal, max_entries: usize) -> Vec<&M
     }
 }
 
+/// Pick the entries worth reciting in a stage signal, best `max_entries` first
+/// by relevance, returned in journal order.
+///
+/// Decisions and open questions outrank changes, which outrank notes. Each
+/// keyword (see [`recitation_keywords`]) found in an entry's content or
+/// rationale adds to its score, and newer entries break ties. Of several
+/// near-identical entries only the latest is kept.
+pub fn rank_entries_for_recitation<'a>(
+    journal: &'a MemoryJournal,
+    keywords: &[String],
+    max_entries: usize,
+) -> Vec<&'a MemoryEntry> {
+    let len = journal.entries.len();
+    let mut kept: Vec<(usize, HashSet<String>)> = Vec::new();
+    for (idx, entry) in journal.entries.iter().enumerate().rev() {
+        let words = word_set(&entry.content);
+        let duplicate = kept.iter().any(|(kept_idx, kept_words)| {
+            journal.entries[*kept_idx].entry_type == entry.entry_type
+                && similarity(&words, kept_words) >= NEAR_DUPLICATE_SIMILARITY
+        });
+        if !duplicate {
+            kept.push((idx, words));
+        }
+    }
+
+    let mut scored: Vec<(f64, usize)> = kept
+        .into_iter()
+        .map(|(idx, _)| {
+            let entry = &journal.entries[idx];
+            let recency = (idx + 1) as f64 / len as f64;
+            (
+                type_weight(entry.entry_type) + keyword_hits(entry, keywords) as f64 + recency,
+                idx,
+            )
+        })
+        .collect();
+    scored.sort_by(|a, b| b.0.total_cmp(&a.0));
+    scored.truncate(max_entries);
+
+    let mut selected: Vec<usize> = scored.into_iter().map(|(_, idx)| idx).collect();
+    selected.sort_unstable();
+    selected
+        .into_iter()
+        .map(|idx| &journal.entries[idx])
+        .collect()
+}
+
+/// Lowercased keywords from free text and file paths for
+/// [`rank_entries_for_recitation`].
+///
+/// Text is split on anything that is not alphanumeric; glob wildcards and
+/// directory separators in paths split the same way, so `src/auth/**/*.rs`
+/// yields `auth`. Short words and duplicates are dropped.
+pub fn recitation_keywords<S: AsRef<str>>(sources: &[S]) -> Vec<String> {
+    let mut seen = HashSet::new();
+    sources
+        .iter()
+        .flat_map(|source| words(source.as_ref()))
+        .filter(|word| word.chars().count() >= MIN_KEYWORD_LEN)
+        .filter(|word| seen.insert(word.clone()))
+        .collect()
+}
+
+fn type_weight(entry_type: MemoryEntryType) -> f64 {
+    match entry_type {
+        MemoryEntryType::Decision => 3.0,
+        MemoryEntryType::Question => 2.5,
+        MemoryEntryType::Change => 1.5,
+        MemoryEntryType::Note => 1.0,
+    }
+}
+
+fn keyword_hits(entry: &MemoryEntry, keywords: &[String]) -> usize {
+    let mut text = entry.content.to_lowercase();
+    if let Some(ctx) = &entry.context {
+        text.push(' ');
+        text.push_str(&ctx.to_lowercase());
+    }
+    keywords
+        .iter()
+        .filter(|keyword| text.contains(keyword.as_str()))
+        .take(MAX_KEYWORD_HITS)
+        .count()
+}
+
+fn words(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+}
+
+fn word_set(text: &str) -> HashSet<String> {
+    words(text).collect()
+}
+
+fn similarity(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 1.0;
+    }
+    a.intersection(b).count() as f64 / union as f64
+}
+
 /// Query memory entries by search term
 pub fn query_entries<'a>(journal: &'a MemoryJournal, search: &str) -> Vec<&'a MemoryEntry> {
     let search_lower = search.to_lowercase();
@@ -81,3 +194,72 @@ pub fn generate_summary(journal: &MemoryJournal, max_entries: usize) -> String {
 
     summary
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn journal(entries: &[(MemoryEntryType, &str)]) -> MemoryJournal {
+        MemoryJournal {
+            stage_id: "test-stage".to_string(),
+            entries: entries
+                .iter()
+                .map(|(entry_type, content)| MemoryEntry::new(*entry_type, content.to_string()))
+                .collect(),
+            summary: None,
+        }
+    }
+
+    #[test]
+    fn test_rank_entries_prefers_decisions_and_keywords() {
+        let journal = journal(&[
+            (MemoryEntryType::Decision, "Use JWT for sessions"),
+            (
+                MemoryEntryType::Note,
+                "Token refresh lives in src/auth/refresh.rs",
+            ),
+            (MemoryEntryType::Note, "Lint config is strict"),
+            (MemoryEntryType::Note, "Docs build is slow"),
+        ]);
+        let keywords = recitation_keywords(&["Auth token refresh", "src/auth/**/*.rs"]);
+
+        let ranked = rank_entries_for_recitation(&journal, &keywords, 2);
+        let contents: Vec<_> = ranked.iter().map(|e| e.content.as_str()).collect();
+        assert_eq!(
+            contents,
+            vec![
+                "Use JWT for sessions",
+                "Token refresh lives in src/auth/refresh.rs"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rank_entries_dedupes_near_identical_notes() {
+        let journal = journal(&[
+            (
+                MemoryEntryType::Note,
+                "The build cache lives in target/cache",
+            ),
+            (
+                MemoryEntryType::Note,
+                "the build cache lives in target/cache!",
+            ),
+            (
+                MemoryEntryType::Decision,
+                "The build cache lives in target/cache",
+            ),
+        ]);
+
+        let ranked = rank_entries_for_recitation(&journal, &[], 10);
+        assert_eq!(ranked.len(), 2);
+        assert_eq!(ranked[0].content, "the build cache lives in target/cache!");
+        assert_eq!(ranked[1].entry_type, MemoryEntryType::Decision);
+    }
+
+    #[test]
+    fn test_recitation_keywords() {
+        let keywords = recitation_keywords(&["Add API auth", "src/auth/**/*.rs"]);
+        assert_eq!(keywords, vec!["auth".to_string()]);
+    }
+}
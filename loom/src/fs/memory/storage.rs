@@ -3,6 +3,8 @@
 use super::constants::MEMORY_HEADER;
 use super::parser::{format_entry, parse_journal};
 use super::types::{MemoryEntry, MemoryJournal};
+use crate::fs::integrity::{seal_or_warn, SealMode};
+use crate::secrets::redact;
 use anyhow::{Context, Result};
 use chrono::Utc;
 use std::fs;
@@ -48,6 +50,7 @@ pub fn create_journal(work_dir: &Path, stage_id: &str) -> Result<PathBuf> {
 
     fs::write(&file_path, header)
         .with_context(|| format!("Failed to create memory journal: {}", file_path.display()))?;
+    seal_or_warn(work_dir, &file_path, SealMode::Append);
 
     Ok(file_path)
 }
@@ -68,12 +71,14 @@ pub fn append_entry(work_dir: &Path, stage_id: &str, entry: &MemoryEntry) -> Res
         .open(&file_path)
         .with_context(|| format!("Failed to open memory journal: {}", file_path.display()))?;
 
-    file.write_all(formatted.as_bytes()).with_context(|| {
-        format!(
-            "Failed to append to memory journal: {}",
-            file_path.display()
-        )
-    })?;
+    file.write_all(redact(&formatted).as_bytes())
+        .with_context(|| {
+            format!(
+                "Failed to append to memory journal: {}",
+                file_path.display()
+            )
+        })?;
+    seal_or_warn(work_dir, &file_path, SealMode::Append);
 
     Ok(())
 }
@@ -95,6 +100,33 @@ pub fn read_journal(work_dir: &Path, stage_id: &str) -> Result<MemoryJournal> {
     parse_journal(&content, stage_id)
 }
 
+/// Replace every entry in a stage's memory journal.
+///
+/// Keeps the journal header and drops any summary, which no longer
+/// describes the remaining entries.
+pub fn rewrite_entries(work_dir: &Path, stage_id: &str, entries: &[MemoryEntry]) -> Result<()> {
+    let file_path = memory_file_path(work_dir, stage_id);
+    let content = fs::read_to_string(&file_path)
+        .with_context(|| format!("Failed to read memory journal: {}", file_path.display()))?;
+
+    let header_end = content
+        .find("\n### ")
+        .into_iter()
+        .chain(content.find("\n## Summary"))
+        .min()
+        .map_or(content.len(), |pos| pos + 1);
+    let mut rewritten = content[..header_end].to_string();
+    for entry in entries {
+        rewritten.push_str(&format_entry(entry));
+    }
+
+    fs::write(&file_path, redact(&rewritten).as_bytes())
+        .with_context(|| format!("Failed to write memory journal: {}", file_path.display()))?;
+    seal_or_warn(work_dir, &file_path, SealMode::Append);
+
+    Ok(())
+}
+
 /// Write summary to the journal file
 pub fn write_summary(work_dir: &Path, stage_id: &str, summary: &str) -> Result<()> {
     let file_path = memory_file_path(work_dir, stage_id);
@@ -104,12 +136,14 @@ pub fn write_summary(work_dir: &Path, stage_id: &str, summary: &str) -> Result<(
         .open(&file_path)
         .with_context(|| format!("Failed to open memory journal: {}", file_path.display()))?;
 
-    file.write_all(summary.as_bytes()).with_context(|| {
-        format!(
-            "Failed to write summary to memory journal: {}",
-            file_path.display()
-        )
-    })?;
+    file.write_all(redact(summary).as_bytes())
+        .with_context(|| {
+            format!(
+                "Failed to write summary to memory journal: {}",
+                file_path.display()
+            )
+        })?;
+    seal_or_warn(work_dir, &file_path, SealMode::Append);
 
     Ok(())
 }
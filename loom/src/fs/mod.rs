@@ -1,8 +1,14 @@
+pub mod facts;
+pub mod flaky;
+pub mod integrity;
 pub mod knowledge;
 pub mod locking;
+pub mod logs;
 pub mod memory;
+pub mod params;
 pub mod permissions;
 pub mod plan_lifecycle;
+pub mod run_history;
 pub mod safe_fs;
 pub mod safe_write;
 pub mod session_files;
@@ -0,0 +1,112 @@
+//! Run-time stage parameters
+//!
+//! Stages declare the values they need from whoever starts the run
+//! (`params: [{name: target_env, choices: [staging, prod]}]`). `loom run`
+//! collects them once per plan and stores them in `.work/params.json`, so a
+//! resumed run or a restarted daemon uses the same answers. Commands and
+//! signals read them as `{{params.<name>}}`.
+
+use anyhow::{Context, Result};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::models::stage::{Stage, StageParam};
+
+/// Stored parameter values by name.
+pub type ParamValues = BTreeMap<String, String>;
+
+/// Path to `params.json` in a work directory.
+pub fn params_path(work_dir: &Path) -> PathBuf {
+    work_dir.join("params.json")
+}
+
+/// Load the stored values, returning none if the file does not exist.
+pub fn load_params(work_dir: &Path) -> Result<ParamValues> {
+    let path = params_path(work_dir);
+    if !path.exists() {
+        return Ok(ParamValues::new());
+    }
+
+    let json = fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read parameters: {}", path.display()))?;
+    serde_json::from_str(&json)
+        .with_context(|| format!("Failed to parse parameters: {}", path.display()))
+}
+
+/// Write the stored values.
+pub fn save_params(work_dir: &Path, values: &ParamValues) -> Result<()> {
+    let path = params_path(work_dir);
+    let json = serde_json::to_string_pretty(values).context("Failed to serialize parameters")?;
+    fs::write(&path, json)
+        .with_context(|| format!("Failed to write parameters: {}", path.display()))
+}
+
+/// The parameters `stages` declare, once per name in the order they are
+/// first declared. Stages sharing a parameter share its value.
+pub fn declared_params(stages: &[Stage]) -> Vec<StageParam> {
+    let mut params: Vec<StageParam> = Vec::new();
+    for param in stages.iter().flat_map(|stage| &stage.params) {
+        if !params.iter().any(|p| p.name == param.name) {
+            params.push(param.clone());
+        }
+    }
+    params
+}
+
+/// The stored values of the parameters `stage` declares.
+pub fn stage_values(stage: &Stage, values: &ParamValues) -> ParamValues {
+    stage
+        .params
+        .iter()
+        .filter_map(|param| {
+            values
+                .get(&param.name)
+                .map(|value| (param.name.clone(), value.clone()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn param(name: &str, choices: &[&str]) -> StageParam {
+        StageParam {
+            name: name.to_string(),
+            description: None,
+            choices: choices.iter().map(|c| c.to_string()).collect(),
+            default: None,
+        }
+    }
+
+    #[test]
+    fn test_declared_params_and_stage_values() {
+        let mut deploy = Stage::new("Deploy".to_string(), None);
+        deploy.params = vec![param("target_env", &["staging", "prod"])];
+        let mut smoke = Stage::new("Smoke".to_string(), None);
+        smoke.params = vec![param("target_env", &[]), param("region", &[])];
+
+        let declared = declared_params(&[deploy.clone(), smoke]);
+        let names: Vec<_> = declared.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["target_env", "region"]);
+        assert!(declared[0].accepts("prod"));
+        assert!(!declared[0].accepts("dev"));
+        assert!(declared[1].accepts("anything"));
+
+        let temp = TempDir::new().unwrap();
+        assert!(load_params(temp.path()).unwrap().is_empty());
+        let values = ParamValues::from([
+            ("target_env".to_string(), "prod".to_string()),
+            ("region".to_string(), "eu".to_string()),
+        ]);
+        save_params(temp.path(), &values).unwrap();
+        let loaded = load_params(temp.path()).unwrap();
+        assert_eq!(loaded, values);
+        assert_eq!(
+            stage_values(&deploy, &loaded),
+            ParamValues::from([("target_env".to_string(), "prod".to_string())])
+        );
+    }
+}
@@ -22,6 +22,9 @@ pub const HOOK_PRE_COMPACT: &str = include_str!("../../../../hooks/pre-compact.s
 /// SessionEnd hook - handles session completion
 pub const HOOK_SESSION_END: &str = include_str!("../../../../hooks/session-end.sh");
 
+/// Notification hook - records permission/input prompts in the heartbeat
+pub const HOOK_NOTIFICATION: &str = include_str!("../../../../hooks/notification.sh");
+
 /// AskUserQuestion pre hook - marks stage as waiting for input
 pub const HOOK_ASK_USER_PRE: &str = include_str!("../../../../hooks/ask-user-pre.sh");
 
@@ -54,6 +57,14 @@ pub const HOOK_WORKTREE_FILE_GUARD: &str = include_str!("../../../../hooks/workt
 /// Fires in ALL sessions (plan mode runs interactively); redirects to doc/plans/
 pub const HOOK_PLANS_PATH_GUARD: &str = include_str!("../../../../hooks/plans-path-guard.sh");
 
+/// MergeScopeGuard hook - limits merge resolution sessions to conflicting files
+/// Reads .work/merge-scope/<stage-id>.json; allows listed files and tests only
+pub const HOOK_MERGE_SCOPE_GUARD: &str = include_str!("../../../../hooks/merge-scope-guard.sh");
+
+/// ContextScopeGuard hook - limits stage Read/Glob/Grep calls to the stage's scope
+/// Reads .work/context-scope/<stage-id>.json (sandbox readable_paths/forbidden_paths)
+pub const HOOK_CONTEXT_SCOPE_GUARD: &str = include_str!("../../../../hooks/context-scope-guard.sh");
+
 /// All loom hook scripts with their filenames (installed to ~/.claude/hooks/loom/)
 /// All hooks are installed to the loom/ subdirectory to keep them separate from user hooks.
 pub const LOOM_HOOKS: &[(&str, &str)] = &[
@@ -65,6 +76,7 @@ pub const LOOM_HOOKS: &[(&str, &str)] = &[
     ("pre-compact.sh", HOOK_PRE_COMPACT),
     ("session-end.sh", HOOK_SESSION_END),
     ("learning-validator.sh", HOOK_LEARNING_VALIDATOR),
+    ("notification.sh", HOOK_NOTIFICATION),
     // Global hooks (commit enforcement, user question handling, tool guidance)
     ("commit-guard.sh", HOOK_COMMIT_GUARD),
     ("ask-user-pre.sh", HOOK_ASK_USER_PRE),
@@ -75,6 +87,8 @@ pub const LOOM_HOOKS: &[(&str, &str)] = &[
     ("worktree-isolation.sh", HOOK_WORKTREE_ISOLATION),
     ("worktree-file-guard.sh", HOOK_WORKTREE_FILE_GUARD),
     ("plans-path-guard.sh", HOOK_PLANS_PATH_GUARD),
+    ("merge-scope-guard.sh", HOOK_MERGE_SCOPE_GUARD),
+    ("context-scope-guard.sh", HOOK_CONTEXT_SCOPE_GUARD),
     // Skill suggestion hooks
     ("skill-trigger.sh", HOOK_SKILL_TRIGGER),
 ];
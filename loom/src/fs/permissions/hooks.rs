@@ -50,6 +50,14 @@ fn loom_hooks_config_for_dir(hooks_dir: &str) -> Value {
                 "matcher": "Write",
                 "hooks": [{"type": "command", "command": format!("{hooks_dir}/plans-path-guard.sh")}]
             },
+            {
+                "matcher": "Edit",
+                "hooks": [{"type": "command", "command": format!("{hooks_dir}/merge-scope-guard.sh")}]
+            },
+            {
+                "matcher": "Write",
+                "hooks": [{"type": "command", "command": format!("{hooks_dir}/merge-scope-guard.sh")}]
+            },
             {
                 "matcher": "Read",
                 "hooks": [{"type": "command", "command": format!("{hooks_dir}/worktree-file-guard.sh")}]
@@ -61,6 +69,18 @@ fn loom_hooks_config_for_dir(hooks_dir: &str) -> Value {
             {
                 "matcher": "Grep",
                 "hooks": [{"type": "command", "command": format!("{hooks_dir}/worktree-file-guard.sh")}]
+            },
+            {
+                "matcher": "Read",
+                "hooks": [{"type": "command", "command": format!("{hooks_dir}/context-scope-guard.sh")}]
+            },
+            {
+                "matcher": "Glob",
+                "hooks": [{"type": "command", "command": format!("{hooks_dir}/context-scope-guard.sh")}]
+            },
+            {
+                "matcher": "Grep",
+                "hooks": [{"type": "command", "command": format!("{hooks_dir}/context-scope-guard.sh")}]
             }
         ],
         "PostToolUse": [
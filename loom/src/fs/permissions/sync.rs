@@ -13,7 +13,7 @@ use std::fs::{self, OpenOptions};
 use std::io::{Read, Seek, Write};
 use std::path::{Path, PathBuf};
 
-use crate::git::worktree::refresh_worktree_settings_local;
+use crate::git::worktree::{refresh_worktree_settings_local, worktrees_root};
 
 /// Patterns that indicate a worktree-specific permission that should not be synced.
 ///
@@ -414,11 +414,11 @@ fn merge_permission_array(
     Ok(added)
 }
 
-/// List all worktree directories in .worktrees/
+/// List all worktree directories in .worktrees/ (or the configured root)
 ///
 /// Returns paths to worktree directories, excluding the optional source worktree.
 fn list_worktree_paths(main_repo_path: &Path, exclude: Option<&Path>) -> Result<Vec<PathBuf>> {
-    let worktrees_dir = main_repo_path.join(".worktrees");
+    let worktrees_dir = worktrees_root(main_repo_path);
 
     if !worktrees_dir.exists() {
         return Ok(Vec::new());
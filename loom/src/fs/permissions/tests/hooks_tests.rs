@@ -17,11 +17,16 @@ fn test_hooks_config_structure() {
     // 7. Write: worktree-isolation.sh
     // 8. Edit: plans-path-guard.sh
     // 9. Write: plans-path-guard.sh
-    // 10. Read: worktree-file-guard.sh
-    // 11. Glob: worktree-file-guard.sh
-    // 12. Grep: worktree-file-guard.sh
+    // 10. Edit: merge-scope-guard.sh
+    // 11. Write: merge-scope-guard.sh
+    // 12. Read: worktree-file-guard.sh
+    // 13. Glob: worktree-file-guard.sh
+    // 14. Grep: worktree-file-guard.sh
+    // 15. Read: context-scope-guard.sh
+    // 16. Glob: context-scope-guard.sh
+    // 17. Grep: context-scope-guard.sh
     let pre_tool = hooks_obj.get("PreToolUse").unwrap().as_array().unwrap();
-    assert_eq!(pre_tool.len(), 12);
+    assert_eq!(pre_tool.len(), 17);
     // First hook: AskUserQuestion matcher with ask-user-pre.sh
     assert_eq!(pre_tool[0]["matcher"], "AskUserQuestion");
     assert!(pre_tool[0]["hooks"][0]["command"]
@@ -76,24 +81,44 @@ fn test_hooks_config_structure() {
         .as_str()
         .unwrap()
         .contains("plans-path-guard.sh"));
-    // Tenth hook: Read matcher with worktree-file-guard.sh
-    assert_eq!(pre_tool[9]["matcher"], "Read");
+    // Tenth hook: Edit matcher with merge-scope-guard.sh
+    assert_eq!(pre_tool[9]["matcher"], "Edit");
     assert!(pre_tool[9]["hooks"][0]["command"]
         .as_str()
         .unwrap()
-        .contains("worktree-file-guard.sh"));
-    // Eleventh hook: Glob matcher with worktree-file-guard.sh
-    assert_eq!(pre_tool[10]["matcher"], "Glob");
+        .contains("merge-scope-guard.sh"));
+    // Eleventh hook: Write matcher with merge-scope-guard.sh
+    assert_eq!(pre_tool[10]["matcher"], "Write");
     assert!(pre_tool[10]["hooks"][0]["command"]
         .as_str()
         .unwrap()
-        .contains("worktree-file-guard.sh"));
-    // Twelfth hook: Grep matcher with worktree-file-guard.sh
-    assert_eq!(pre_tool[11]["matcher"], "Grep");
+        .contains("merge-scope-guard.sh"));
+    // Twelfth hook: Read matcher with worktree-file-guard.sh
+    assert_eq!(pre_tool[11]["matcher"], "Read");
     assert!(pre_tool[11]["hooks"][0]["command"]
         .as_str()
         .unwrap()
         .contains("worktree-file-guard.sh"));
+    // Thirteenth hook: Glob matcher with worktree-file-guard.sh
+    assert_eq!(pre_tool[12]["matcher"], "Glob");
+    assert!(pre_tool[12]["hooks"][0]["command"]
+        .as_str()
+        .unwrap()
+        .contains("worktree-file-guard.sh"));
+    // Fourteenth hook: Grep matcher with worktree-file-guard.sh
+    assert_eq!(pre_tool[13]["matcher"], "Grep");
+    assert!(pre_tool[13]["hooks"][0]["command"]
+        .as_str()
+        .unwrap()
+        .contains("worktree-file-guard.sh"));
+    // Last three hooks: Read/Glob/Grep matchers with context-scope-guard.sh
+    for (i, matcher) in ["Read", "Glob", "Grep"].iter().enumerate() {
+        assert_eq!(pre_tool[14 + i]["matcher"], *matcher);
+        assert!(pre_tool[14 + i]["hooks"][0]["command"]
+            .as_str()
+            .unwrap()
+            .contains("context-scope-guard.sh"));
+    }
 
     // Check PostToolUse hooks (only AskUserQuestion for resume in global config)
     // Session-specific post-tool-use.sh (Bash) is merged at worktree creation
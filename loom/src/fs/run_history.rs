@@ -0,0 +1,447 @@
+//! Run history archive
+//!
+//! When a plan's execution graph completes, the orchestrator archives the
+//! run — final graph state, per-stage durations, retries, merge conflicts and
+//! token usage — as `.work/archive/runs/<run-id>.json`. `loom history` reads
+//! these records to compare plan efficiency across runs.
+//!
+//! The archive outlives the plan that produced it: `loom init --clean` and
+//! `loom clean --state` stash `archive/runs/` next to `.work/` as
+//! [`RUN_HISTORY_STASH_DIR`] before removing `.work/`, and the next
+//! `loom init` moves it back.
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::models::session::{Session, SessionType};
+use crate::models::stage::{Stage, StageStatus};
+use crate::parser::frontmatter::parse_from_markdown;
+use crate::verify::transitions::list_all_stages;
+
+/// Version of the run record format.
+pub const RUN_RECORD_VERSION: u32 = 1;
+
+/// Directory, relative to the repository root, that holds the run history
+/// while `.work/` is removed and recreated.
+pub const RUN_HISTORY_STASH_DIR: &str = ".work.runs";
+
+/// Final state of one stage in an archived run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StageRun {
+    pub id: String,
+    pub name: String,
+    pub status: StageStatus,
+    #[serde(default)]
+    pub dependencies: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_secs: Option<i64>,
+    /// Time spent executing, excluding backoff and waiting
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub execution_secs: Option<i64>,
+    #[serde(default)]
+    pub retries: u32,
+    /// Whether merging the stage hit a conflict
+    #[serde(default)]
+    pub merge_conflict: bool,
+    /// Context tokens used by the stage's sessions still on record
+    #[serde(default)]
+    pub tokens: u64,
+}
+
+/// One archived run of a plan.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunRecord {
+    pub version: u32,
+    pub run_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plan_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plan_source: Option<String>,
+    pub merge_point: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub finished_at: Option<DateTime<Utc>>,
+    pub archived_at: DateTime<Utc>,
+    pub stages: Vec<StageRun>,
+}
+
+impl RunRecord {
+    /// Build a record from the stages and sessions of a finished run.
+    pub fn build(
+        plan_id: Option<String>,
+        plan_source: Option<String>,
+        merge_point: String,
+        stages: &[Stage],
+        sessions: &[Session],
+    ) -> Self {
+        let started_at = stages.iter().filter_map(|s| s.started_at).min();
+        let finished_at = stages.iter().filter_map(|s| s.completed_at).max();
+        let archived_at = Utc::now();
+
+        let mut stage_runs: Vec<StageRun> = stages
+            .iter()
+            .map(|stage| {
+                let stage_sessions = sessions
+                    .iter()
+                    .filter(|s| s.stage_id.as_deref() == Some(stage.id.as_str()));
+                let mut tokens = 0u64;
+                let mut merge_session = false;
+                for session in stage_sessions {
+                    tokens += u64::from(session.context_tokens);
+                    merge_session |= session.session_type == SessionType::Merge;
+                }
+                StageRun {
+                    id: stage.id.clone(),
+                    name: stage.name.clone(),
+                    status: stage.status.clone(),
+                    dependencies: stage.dependencies.clone(),
+                    duration_secs: stage.duration_secs,
+                    execution_secs: stage.execution_secs,
+                    retries: stage.retry_count,
+                    merge_conflict: stage.merge_conflict || merge_session,
+                    tokens,
+                }
+            })
+            .collect();
+        stage_runs.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let stamp = finished_at.unwrap_or(archived_at);
+        let run_id = format!(
+            "{}-{}",
+            stamp.format("%Y%m%d-%H%M%S"),
+            plan_id.as_deref().unwrap_or("plan")
+        );
+
+        Self {
+            version: RUN_RECORD_VERSION,
+            run_id,
+            plan_id,
+            plan_source,
+            merge_point,
+            started_at,
+            finished_at,
+            archived_at,
+            stages: stage_runs,
+        }
+    }
+
+    /// Wall-clock time from the first stage starting to the last finishing.
+    pub fn total_duration_secs(&self) -> Option<i64> {
+        match (self.started_at, self.finished_at) {
+            (Some(start), Some(end)) => Some(end.signed_duration_since(start).num_seconds()),
+            _ => None,
+        }
+    }
+
+    /// Number of completed stages.
+    pub fn completed_count(&self) -> usize {
+        self.stages
+            .iter()
+            .filter(|s| s.status == StageStatus::Completed)
+            .count()
+    }
+
+    /// Mean duration of the stages that recorded one.
+    pub fn avg_stage_duration_secs(&self) -> Option<i64> {
+        let durations: Vec<i64> = self.stages.iter().filter_map(|s| s.duration_secs).collect();
+        if durations.is_empty() {
+            return None;
+        }
+        Some(durations.iter().sum::<i64>() / durations.len() as i64)
+    }
+
+    /// Total retries across all stages.
+    pub fn total_retries(&self) -> u32 {
+        self.stages.iter().map(|s| s.retries).sum()
+    }
+
+    /// Fraction of stages that needed at least one retry.
+    pub fn retry_rate(&self) -> f64 {
+        self.rate(|s| s.retries > 0)
+    }
+
+    /// Fraction of stages whose merge hit a conflict.
+    pub fn conflict_rate(&self) -> f64 {
+        self.rate(|s| s.merge_conflict)
+    }
+
+    /// Context tokens used across all stages.
+    pub fn total_tokens(&self) -> u64 {
+        self.stages.iter().map(|s| s.tokens).sum()
+    }
+
+    fn rate(&self, pred: impl Fn(&StageRun) -> bool) -> f64 {
+        if self.stages.is_empty() {
+            return 0.0;
+        }
+        self.stages.iter().filter(|s| pred(s)).count() as f64 / self.stages.len() as f64
+    }
+}
+
+/// Directory holding archived runs.
+pub fn runs_dir(work_dir: &Path) -> PathBuf {
+    work_dir.join("archive").join("runs")
+}
+
+/// Archive the current run from the stage and session files in `work_dir`.
+///
+/// Archiving the same finished run twice (e.g. `loom run` on an already
+/// complete plan) leaves the existing record in place.
+///
+/// # Returns
+///
+/// The path of the run record.
+pub fn archive_run(work_dir: &Path) -> Result<PathBuf> {
+    let config = crate::fs::load_config(work_dir)?;
+    let plan_id = config.as_ref().and_then(|c| c.plan_id().map(String::from));
+    let plan_source = config
+        .as_ref()
+        .and_then(|c| c.get_plan_str("source_path").map(String::from));
+    let merge_point = crate::fs::get_merge_point(work_dir)?;
+
+    let stages = list_all_stages(work_dir)?;
+    let sessions = load_sessions(work_dir);
+    let record = RunRecord::build(plan_id, plan_source, merge_point, &stages, &sessions);
+
+    let dir = runs_dir(work_dir);
+    fs::create_dir_all(&dir).context("Failed to create run archive directory")?;
+    let path = dir.join(format!("{}.json", record.run_id));
+    if path.exists() {
+        return Ok(path);
+    }
+
+    let json = serde_json::to_string_pretty(&record).context("Failed to serialize run record")?;
+    fs::write(&path, json)
+        .with_context(|| format!("Failed to write run record: {}", path.display()))?;
+    Ok(path)
+}
+
+/// Load every archived run, oldest first. Unreadable records are skipped
+/// with a warning.
+pub fn list_runs(work_dir: &Path) -> Result<Vec<RunRecord>> {
+    let dir = runs_dir(work_dir);
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut runs = Vec::new();
+    let entries = fs::read_dir(&dir)
+        .with_context(|| format!("Failed to read run archive: {}", dir.display()))?;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("json") {
+            continue;
+        }
+        match load_run_file(&path) {
+            Ok(run) => runs.push(run),
+            Err(e) => eprintln!("Warning: Skipping run record {}: {e:#}", path.display()),
+        }
+    }
+
+    runs.sort_by(|a, b| {
+        (a.finished_at.unwrap_or(a.archived_at), &a.run_id)
+            .cmp(&(b.finished_at.unwrap_or(b.archived_at), &b.run_id))
+    });
+    Ok(runs)
+}
+
+/// Find an archived run by id or unique id prefix.
+pub fn find_run(work_dir: &Path, id: &str) -> Result<RunRecord> {
+    let runs = list_runs(work_dir)?;
+    if let Some(run) = runs.iter().find(|r| r.run_id == id) {
+        return Ok(run.clone());
+    }
+
+    let mut matches: Vec<RunRecord> = runs
+        .into_iter()
+        .filter(|r| r.run_id.starts_with(id))
+        .collect();
+    match matches.len() {
+        0 => bail!("No archived run matches '{id}' (see 'loom history list')"),
+        1 => Ok(matches.remove(0)),
+        n => bail!("'{id}' matches {n} archived runs; use a longer prefix"),
+    }
+}
+
+fn load_run_file(path: &Path) -> Result<RunRecord> {
+    let json = fs::read_to_string(path)?;
+    let run: RunRecord = serde_json::from_str(&json)?;
+    if run.version != RUN_RECORD_VERSION {
+        bail!(
+            "unsupported run record version {} (expected {RUN_RECORD_VERSION})",
+            run.version
+        );
+    }
+    Ok(run)
+}
+
+/// Load the session files still in `.work/sessions/`, skipping any that
+/// fail to parse.
+fn load_sessions(work_dir: &Path) -> Vec<Session> {
+    let Ok(entries) = fs::read_dir(work_dir.join("sessions")) else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.extension().and_then(|e| e.to_str()) == Some("md"))
+        .filter_map(|p| fs::read_to_string(p).ok())
+        .filter_map(|content| parse_from_markdown::<Session>(&content, "Session").ok())
+        .collect()
+}
+
+/// Move `.work/archive/runs/` to [`RUN_HISTORY_STASH_DIR`] so it survives
+/// removal of `.work/`.
+///
+/// Returns the number of run records stashed.
+pub fn stash_run_history(repo_root: &Path) -> Result<usize> {
+    let runs = runs_dir(&repo_root.join(".work"));
+    move_run_records(&runs, &repo_root.join(RUN_HISTORY_STASH_DIR))
+}
+
+/// Move stashed run records into `work_dir`'s archive and remove the stash.
+///
+/// Returns the number of run records restored.
+pub fn restore_run_history(repo_root: &Path, work_dir: &Path) -> Result<usize> {
+    move_run_records(&repo_root.join(RUN_HISTORY_STASH_DIR), &runs_dir(work_dir))
+}
+
+/// Move every run record from `from` into `to`, then remove `from`.
+/// A record already present in `to` is kept.
+fn move_run_records(from: &Path, to: &Path) -> Result<usize> {
+    if !from.exists() {
+        return Ok(0);
+    }
+
+    fs::create_dir_all(to)
+        .with_context(|| format!("Failed to create run archive: {}", to.display()))?;
+    let mut moved = 0;
+    let entries =
+        fs::read_dir(from).with_context(|| format!("Failed to read {}", from.display()))?;
+    for entry in entries.flatten() {
+        let target = to.join(entry.file_name());
+        if target.exists() {
+            continue;
+        }
+        fs::rename(entry.path(), &target).with_context(|| {
+            format!(
+                "Failed to move run record {} to {}",
+                entry.path().display(),
+                target.display()
+            )
+        })?;
+        moved += 1;
+    }
+    fs::remove_dir_all(from).with_context(|| format!("Failed to remove {}", from.display()))?;
+    Ok(moved)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::verify::transitions::save_stage;
+    use chrono::Duration;
+    use tempfile::TempDir;
+
+    fn stage(id: &str, start: DateTime<Utc>, secs: i64, retries: u32, conflict: bool) -> Stage {
+        Stage {
+            id: id.to_string(),
+            name: format!("Stage {id}"),
+            status: StageStatus::Completed,
+            merged: true,
+            started_at: Some(start),
+            completed_at: Some(start + Duration::seconds(secs)),
+            duration_secs: Some(secs),
+            retry_count: retries,
+            merge_conflict: conflict,
+            ..Default::default()
+        }
+    }
+
+    fn session(stage_id: &str, tokens: u32, session_type: SessionType) -> Session {
+        let mut session = Session::new();
+        session.stage_id = Some(stage_id.to_string());
+        session.context_tokens = tokens;
+        session.session_type = session_type;
+        session
+    }
+
+    #[test]
+    fn test_build_computes_run_statistics() {
+        let start = Utc::now() - Duration::hours(1);
+        let stages = vec![
+            stage("api", start, 600, 2, false),
+            stage("db", start, 300, 0, true),
+            stage("ui", start + Duration::seconds(600), 900, 0, false),
+            stage("docs", start, 0, 0, false),
+        ];
+        let sessions = vec![
+            session("api", 40_000, SessionType::Stage),
+            session("api", 10_000, SessionType::Stage),
+            session("ui", 5_000, SessionType::Merge),
+        ];
+
+        let run = RunRecord::build(
+            Some("PLAN-auth".into()),
+            None,
+            "main".into(),
+            &stages,
+            &sessions,
+        );
+
+        assert!(run.run_id.ends_with("-PLAN-auth"));
+        assert_eq!(run.total_duration_secs(), Some(1500));
+        assert_eq!(run.completed_count(), 4);
+        assert_eq!(run.avg_stage_duration_secs(), Some(450));
+        assert_eq!(run.total_retries(), 2);
+        assert_eq!(run.retry_rate(), 0.25);
+        // db flagged in its stage file, ui through its merge session
+        assert_eq!(run.conflict_rate(), 0.5);
+        assert_eq!(run.total_tokens(), 55_000);
+        let api = run.stages.iter().find(|s| s.id == "api").unwrap();
+        assert_eq!(api.tokens, 50_000);
+    }
+
+    #[test]
+    fn test_archive_list_and_find() {
+        let temp = TempDir::new().unwrap();
+        let work_dir = temp.path().join(".work");
+        let start = Utc::now() - Duration::hours(1);
+        save_stage(&stage("api", start, 60, 0, false), &work_dir).unwrap();
+
+        let path = archive_run(&work_dir).unwrap();
+        assert!(path.starts_with(runs_dir(&work_dir)));
+        // Archiving the same finished run again keeps a single record
+        assert_eq!(archive_run(&work_dir).unwrap(), path);
+
+        let runs = list_runs(&work_dir).unwrap();
+        assert_eq!(runs.len(), 1);
+        let run_id = runs[0].run_id.clone();
+        assert_eq!(find_run(&work_dir, &run_id[..8]).unwrap().run_id, run_id);
+        assert!(find_run(&work_dir, "nope").is_err());
+    }
+
+    #[test]
+    fn test_stash_and_restore_survive_work_dir_removal() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        let work_dir = root.join(".work");
+        save_stage(&stage("api", Utc::now(), 60, 0, false), &work_dir).unwrap();
+        archive_run(&work_dir).unwrap();
+
+        assert_eq!(stash_run_history(root).unwrap(), 1);
+        fs::remove_dir_all(&work_dir).unwrap();
+
+        let new_work_dir = root.join(".work.init");
+        assert_eq!(restore_run_history(root, &new_work_dir).unwrap(), 1);
+        assert!(!root.join(RUN_HISTORY_STASH_DIR).exists());
+        assert_eq!(list_runs(&new_work_dir).unwrap().len(), 1);
+
+        // Nothing to move when there is no archive
+        assert_eq!(stash_run_history(root).unwrap(), 0);
+    }
+}
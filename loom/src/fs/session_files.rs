@@ -174,7 +174,10 @@ pub fn find_session_for_stage(stage_id: &str, work_dir: &Path) -> Option<String>
         // Try to read and parse session file using canonical parser
         if let Ok(content) = fs::read_to_string(&path) {
             if let Ok(doc) = MarkdownDocument::parse(&content) {
-                if doc.get_frontmatter("stage_id").map(|s| s.as_str()) == Some(stage_id) {
+                // A pair reviewer is never the stage's own session
+                if doc.get_frontmatter("stage_id").map(|s| s.as_str()) == Some(stage_id)
+                    && doc.get_frontmatter("role").map(|s| s.as_str()) != Some("reviewer")
+                {
                     if let Some(session_id) = doc.get_frontmatter("id") {
                         return Some(session_id.clone());
                     }
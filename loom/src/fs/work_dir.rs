@@ -3,9 +3,25 @@ use std::fs;
 use std::path::{Path, PathBuf};
 use toml_edit::DocumentMut;
 
+use crate::daemon::EventsConfig;
+use crate::fs::facts::FactsConfig;
+use crate::fs::knowledge::export::LearnConfig;
 use crate::fs::knowledge::KnowledgeDir;
-use crate::plan::schema::SandboxConfig;
+use crate::fs::logs::LogsConfig;
+use crate::git::dirty::DirtyTreeConfig;
+use crate::git::hooks::GitHooksConfig;
+use crate::git::merge::lock::MergeConfig;
+use crate::git::vcs::VcsConfig;
+use crate::git::worktree::WorktreesConfig;
+use crate::offline::OfflineConfig;
+use crate::orchestrator::escalation::EscalationConfig;
+use crate::orchestrator::notify::NotifyConfig;
+use crate::orchestrator::spawn_rate::SpawnRateConfig;
+use crate::orchestrator::terminal::keep_open::TerminalConfig;
+use crate::orchestrator::throttle::ThrottleConfig;
+use crate::plan::schema::{ConflictAvoidance, SandboxConfig};
 use crate::remote_control::RemoteControlConfig;
+use crate::secrets::SecretsConfig;
 
 /// Parsed config.toml structure
 #[derive(Debug, Clone)]
@@ -37,6 +53,82 @@ impl Config {
         self.get_plan_str("plan_id")
     }
 
+    /// Get the plan namespace for branch and window names
+    pub fn namespace(&self) -> Option<&str> {
+        self.get_plan_str("namespace")
+    }
+
+    /// Get the stage branch name template, if the plan sets one
+    pub fn branch_template(&self) -> Option<&str> {
+        self.get_plan_str("branch_template")
+    }
+
+    /// Remote to push stage branches to, when the plan sets `push_branches`
+    pub fn push_remote(&self) -> Option<&str> {
+        let push = self
+            .inner
+            .get("plan")
+            .and_then(|p| p.get("push_branches"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        push.then(|| self.get_plan_str("push_remote").unwrap_or("origin"))
+    }
+
+    /// Extra sparse-checkout paths, when the plan sets `sparse_checkout`
+    pub fn sparse_checkout(&self) -> Option<Vec<String>> {
+        let plan = self.inner.get("plan")?;
+        if !plan.get("sparse_checkout")?.as_bool()? {
+            return None;
+        }
+        let include = plan
+            .get("sparse_include")
+            .and_then(|v| v.as_array())
+            .map(|paths| {
+                paths
+                    .iter()
+                    .filter_map(|p| p.as_str().map(String::from))
+                    .collect()
+            })
+            .unwrap_or_default();
+        Some(include)
+    }
+
+    /// Get the plan's conflict avoidance policy (default: warn)
+    pub fn conflict_avoidance(&self) -> ConflictAvoidance {
+        match self.get_plan_str("conflict_avoidance") {
+            Some("strict") => ConflictAvoidance::Strict,
+            _ => ConflictAvoidance::Warn,
+        }
+    }
+
+    /// Whether completed standard stages get a code-review stage before merging
+    pub fn auto_code_review(&self) -> bool {
+        self.inner
+            .get("plan")
+            .and_then(|p| p.get("auto_code_review"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false)
+    }
+
+    /// Whether other stages wait for the plan's knowledge stages
+    pub fn knowledge_first(&self) -> bool {
+        self.inner
+            .get("plan")
+            .and_then(|p| p.get("knowledge_first"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false)
+    }
+
+    /// Merged standard stages between knowledge refreshes, if enabled
+    pub fn knowledge_refresh_every(&self) -> Option<u32> {
+        self.inner
+            .get("plan")
+            .and_then(|p| p.get("knowledge_refresh_every"))
+            .and_then(|v| v.as_integer())
+            .and_then(|n| u32::try_from(n).ok())
+            .filter(|n| *n > 0)
+    }
+
     /// Get mutable access to the underlying TOML value for updates
     pub fn as_toml_mut(&mut self) -> &mut toml::Value {
         &mut self.inner
@@ -112,6 +204,14 @@ impl WorkDir {
         Ok(Self { root: candidate })
     }
 
+    /// Use `root` as the work directory as-is, without searching for `.work`.
+    ///
+    /// `loom init` builds in a staging directory next to `.work/` and moves
+    /// it into place once complete.
+    pub fn at<P: Into<PathBuf>>(root: P) -> Self {
+        Self { root: root.into() }
+    }
+
     /// Open an existing `.work/` directory or initialise it if missing.
     ///
     /// Used by `loom init` reconfigure paths so a second invocation (with
@@ -334,7 +434,7 @@ Do not manually edit these files unless you know what you're doing.
 // Section layout in `.work/config.toml`:
 //
 //   [plan]
-//   source_path / plan_id / plan_name / base_branch
+//   source_path / plan_id / plan_name / base_branch / namespace
 //
 //   [plan_sandbox]   # persisted snapshot of plan-level sandbox at init time
 //
@@ -343,6 +443,22 @@ Do not manually edit these files unless you know what you're doing.
 
 const PLAN_SANDBOX_SECTION: &str = "plan_sandbox";
 const REMOTE_CONTROL_SECTION: &str = "remote_control";
+const OFFLINE_SECTION: &str = "offline";
+const DIRTY_TREE_SECTION: &str = "dirty_tree";
+const TERMINAL_SECTION: &str = "terminal";
+const EVENTS_SECTION: &str = "events";
+const LEARN_SECTION: &str = "learn";
+const GIT_HOOKS_SECTION: &str = "git_hooks";
+const THROTTLE_SECTION: &str = "throttle";
+const ESCALATION_SECTION: &str = "escalation";
+const NOTIFY_SECTION: &str = "notify";
+const SPAWN_RATE_SECTION: &str = "spawn_rate";
+const WORKTREES_SECTION: &str = "worktrees";
+const LOGS_SECTION: &str = "logs";
+const SECRETS_SECTION: &str = "secrets";
+const VCS_SECTION: &str = "vcs";
+const MERGE_SECTION: &str = "merge";
+const FACTS_SECTION: &str = "facts";
 
 fn config_path(work_dir: &Path) -> PathBuf {
     work_dir.join("config.toml")
@@ -500,6 +616,181 @@ pub fn write_remote_control_config(work_dir: &Path, config: &RemoteControlConfig
     write_section(work_dir, REMOTE_CONTROL_SECTION, config)
 }
 
+/// Read the persisted offline-mode config (`[offline]`).
+///
+/// A missing section yields `OfflineConfig::default()` (online).
+pub fn read_offline_config(work_dir: &Path) -> Result<OfflineConfig> {
+    Ok(read_section(work_dir, OFFLINE_SECTION)?.unwrap_or_default())
+}
+
+/// Persist the offline-mode config (`[offline]`).
+pub fn write_offline_config(work_dir: &Path, config: &OfflineConfig) -> Result<()> {
+    write_section(work_dir, OFFLINE_SECTION, config)
+}
+
+/// Read the persisted dirty-tree policy (`[dirty_tree]`).
+///
+/// A missing section yields `DirtyTreeConfig::default()` (strict, nothing
+/// ignored).
+pub fn read_dirty_tree_config(work_dir: &Path) -> Result<DirtyTreeConfig> {
+    Ok(read_section(work_dir, DIRTY_TREE_SECTION)?.unwrap_or_default())
+}
+
+/// Persist the dirty-tree policy (`[dirty_tree]`).
+pub fn write_dirty_tree_config(work_dir: &Path, config: &DirtyTreeConfig) -> Result<()> {
+    write_section(work_dir, DIRTY_TREE_SECTION, config)
+}
+
+/// Read the persisted terminal window policy (`[terminal]`).
+///
+/// A missing section yields `TerminalConfig::default()` (auto-close).
+pub fn read_terminal_config(work_dir: &Path) -> Result<TerminalConfig> {
+    Ok(read_section(work_dir, TERMINAL_SECTION)?.unwrap_or_default())
+}
+
+/// Persist the terminal window policy (`[terminal]`).
+pub fn write_terminal_config(work_dir: &Path, config: &TerminalConfig) -> Result<()> {
+    write_section(work_dir, TERMINAL_SECTION, config)
+}
+
+/// Read the persisted daemon event stream settings (`[events]`).
+///
+/// A missing section yields `EventsConfig::default()` (enabled, any port).
+pub fn read_events_config(work_dir: &Path) -> Result<EventsConfig> {
+    Ok(read_section(work_dir, EVENTS_SECTION)?.unwrap_or_default())
+}
+
+/// Persist the daemon event stream settings (`[events]`).
+pub fn write_events_config(work_dir: &Path, config: &EventsConfig) -> Result<()> {
+    write_section(work_dir, EVENTS_SECTION, config)
+}
+
+/// Read the persisted knowledge export settings (`[learn]`).
+///
+/// A missing section yields `LearnConfig::default()` (no export path).
+pub fn read_learn_config(work_dir: &Path) -> Result<LearnConfig> {
+    Ok(read_section(work_dir, LEARN_SECTION)?.unwrap_or_default())
+}
+
+/// Persist the knowledge export settings (`[learn]`).
+pub fn write_learn_config(work_dir: &Path, config: &LearnConfig) -> Result<()> {
+    write_section(work_dir, LEARN_SECTION, config)
+}
+
+/// Read the persisted worktree git hook set (`[git_hooks]`).
+///
+/// A missing section yields `GitHooksConfig::default()` (no optional hooks).
+pub fn read_git_hooks_config(work_dir: &Path) -> Result<GitHooksConfig> {
+    Ok(read_section(work_dir, GIT_HOOKS_SECTION)?.unwrap_or_default())
+}
+
+/// Persist the worktree git hook set (`[git_hooks]`).
+pub fn write_git_hooks_config(work_dir: &Path, config: &GitHooksConfig) -> Result<()> {
+    write_section(work_dir, GIT_HOOKS_SECTION, config)
+}
+
+/// Read the persisted parallelism throttle thresholds (`[throttle]`).
+///
+/// A missing section yields `ThrottleConfig::default()` (no throttling).
+pub fn read_throttle_config(work_dir: &Path) -> Result<ThrottleConfig> {
+    Ok(read_section(work_dir, THROTTLE_SECTION)?.unwrap_or_default())
+}
+
+/// Persist the parallelism throttle thresholds (`[throttle]`).
+pub fn write_throttle_config(work_dir: &Path, config: &ThrottleConfig) -> Result<()> {
+    write_section(work_dir, THROTTLE_SECTION, config)
+}
+
+/// Read the waiting-stage escalation settings (`[escalation]`).
+pub fn read_escalation_config(work_dir: &Path) -> Result<EscalationConfig> {
+    Ok(read_section(work_dir, ESCALATION_SECTION)?.unwrap_or_default())
+}
+
+/// Persist the waiting-stage escalation settings (`[escalation]`).
+pub fn write_escalation_config(work_dir: &Path, config: &EscalationConfig) -> Result<()> {
+    write_section(work_dir, ESCALATION_SECTION, config)
+}
+
+/// Read the per-assignee notification routes (`[notify]`).
+pub fn read_notify_config(work_dir: &Path) -> Result<NotifyConfig> {
+    Ok(read_section(work_dir, NOTIFY_SECTION)?.unwrap_or_default())
+}
+
+/// Persist the per-assignee notification routes (`[notify]`).
+pub fn write_notify_config(work_dir: &Path, config: &NotifyConfig) -> Result<()> {
+    write_section(work_dir, NOTIFY_SECTION, config)
+}
+
+/// Read the session spawn rate limits (`[spawn_rate]`).
+pub fn read_spawn_rate_config(work_dir: &Path) -> Result<SpawnRateConfig> {
+    Ok(read_section(work_dir, SPAWN_RATE_SECTION)?.unwrap_or_default())
+}
+
+/// Persist the session spawn rate limits (`[spawn_rate]`).
+pub fn write_spawn_rate_config(work_dir: &Path, config: &SpawnRateConfig) -> Result<()> {
+    write_section(work_dir, SPAWN_RATE_SECTION, config)
+}
+
+/// Read the worktree placement (`[worktrees]`).
+pub fn read_worktrees_config(work_dir: &Path) -> Result<WorktreesConfig> {
+    Ok(read_section(work_dir, WORKTREES_SECTION)?.unwrap_or_default())
+}
+
+/// Persist the worktree placement (`[worktrees]`).
+pub fn write_worktrees_config(work_dir: &Path, config: &WorktreesConfig) -> Result<()> {
+    write_section(work_dir, WORKTREES_SECTION, config)
+}
+
+/// Read the log rotation policy (`[logs]`).
+pub fn read_logs_config(work_dir: &Path) -> Result<LogsConfig> {
+    Ok(read_section(work_dir, LOGS_SECTION)?.unwrap_or_default())
+}
+
+/// Persist the log rotation policy (`[logs]`).
+pub fn write_logs_config(work_dir: &Path, config: &LogsConfig) -> Result<()> {
+    write_section(work_dir, LOGS_SECTION, config)
+}
+
+/// Read the secret redaction rules (`[secrets]`).
+pub fn read_secrets_config(work_dir: &Path) -> Result<SecretsConfig> {
+    Ok(read_section(work_dir, SECRETS_SECTION)?.unwrap_or_default())
+}
+
+/// Persist the secret redaction rules (`[secrets]`).
+pub fn write_secrets_config(work_dir: &Path, config: &SecretsConfig) -> Result<()> {
+    write_section(work_dir, SECRETS_SECTION, config)
+}
+
+/// Read the version control backend selection (`[vcs]`).
+pub fn read_vcs_config(work_dir: &Path) -> Result<VcsConfig> {
+    Ok(read_section(work_dir, VCS_SECTION)?.unwrap_or_default())
+}
+
+/// Persist the version control backend selection (`[vcs]`).
+pub fn write_vcs_config(work_dir: &Path, config: &VcsConfig) -> Result<()> {
+    write_section(work_dir, VCS_SECTION, config)
+}
+
+/// Read the merge settings (`[merge]`).
+pub fn read_merge_config(work_dir: &Path) -> Result<MergeConfig> {
+    Ok(read_section(work_dir, MERGE_SECTION)?.unwrap_or_default())
+}
+
+/// Persist the merge settings (`[merge]`).
+pub fn write_merge_config(work_dir: &Path, config: &MergeConfig) -> Result<()> {
+    write_section(work_dir, MERGE_SECTION, config)
+}
+
+/// Read the fact confidence settings (`[facts]`).
+pub fn read_facts_config(work_dir: &Path) -> Result<FactsConfig> {
+    Ok(read_section(work_dir, FACTS_SECTION)?.unwrap_or_default())
+}
+
+/// Persist the fact confidence settings (`[facts]`).
+pub fn write_facts_config(work_dir: &Path, config: &FactsConfig) -> Result<()> {
+    write_section(work_dir, FACTS_SECTION, config)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -6,6 +6,8 @@
 use anyhow::{bail, Result};
 use std::path::Path;
 
+use crate::git::worktree::find_linked_worktree;
+
 /// State of the .work directory
 #[derive(Debug, Clone, PartialEq)]
 pub enum WorkDirState {
@@ -67,7 +69,7 @@ pub fn check_work_dir_state(repo_root: &Path) -> WorkDirState {
 /// true if in a worktree, false if in main repo
 pub fn is_in_worktree(current_dir: &Path) -> bool {
     let path_str = current_dir.to_string_lossy();
-    path_str.contains(".worktrees/")
+    path_str.contains(".worktrees/") || find_linked_worktree(current_dir).is_some()
 }
 
 /// Validate that the .work directory is in the expected state
@@ -16,10 +16,11 @@ pub fn cleanup_merged_branches(target_branch: &str, repo_root: &Path) -> Result<
         .with_context(|| "Failed to get merged branches")?;
 
     let stdout = String::from_utf8_lossy(&output.stdout);
+    let prefix = crate::namespace::branch_prefix();
     let merged_loom_branches: Vec<String> = stdout
         .lines()
         .map(|s| s.trim().trim_start_matches('*').trim().to_string())
-        .filter(|s| s.starts_with("loom/"))
+        .filter(|s| s.starts_with(&prefix))
         .collect();
 
     let mut deleted = Vec::new();
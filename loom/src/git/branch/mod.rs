@@ -8,21 +8,26 @@
 //! - `ancestry`: Branch ancestry and merge detection
 //! - `cleanup`: Branch cleanup after merges
 //! - `naming`: Loom branch naming conventions
+//! - `push`: Publishing stage branches to a remote
 
 mod ancestry;
 mod cleanup;
 mod info;
 mod naming;
 mod operations;
+mod push;
 mod status;
 
 // Re-export all public items
 pub use ancestry::{commits_ahead_of, get_branch_head, is_ancestor_of, is_branch_merged};
 pub use cleanup::cleanup_merged_branches;
 pub use info::BranchInfo;
-pub use naming::{branch_name_for_stage, stage_id_from_branch};
+pub use naming::{
+    base_branch_name_for_stage, branch_name_for_stage, is_base_branch, stage_id_from_branch,
+};
 pub use operations::{
     branch_exists, create_branch, current_branch, default_branch, delete_branch, list_branches,
     list_loom_branches, resolve_target_branch,
 };
+pub use push::push_stage_branch;
 pub use status::{get_uncommitted_changes_summary, has_uncommitted_changes};
@@ -1,13 +1,28 @@
 //! Branch naming conventions for loom stages
+//!
+//! Names carry the plan namespace when one is active (see
+//! [`crate::namespace`]): `loom/<ns>/<stage>` instead of `loom/<stage>`.
+
+use crate::namespace::branch_prefix;
 
 /// Generate loom branch name from stage ID
 pub fn branch_name_for_stage(stage_id: &str) -> String {
-    format!("loom/{stage_id}")
+    format!("{}{stage_id}", branch_prefix())
+}
+
+/// Generate the temporary multi-dependency base branch name for a stage
+pub fn base_branch_name_for_stage(stage_id: &str) -> String {
+    format!("{}_base/{stage_id}", branch_prefix())
 }
 
 /// Get the stage ID from a loom branch name
 pub fn stage_id_from_branch(branch_name: &str) -> Option<String> {
-    branch_name.strip_prefix("loom/").map(String::from)
+    branch_name.strip_prefix(&branch_prefix()).map(String::from)
+}
+
+/// Whether a branch is a base branch (`loom/[<ns>/]_base/<stage>`)
+pub fn is_base_branch(branch_name: &str) -> bool {
+    stage_id_from_branch(branch_name).is_some_and(|rest| rest.starts_with("_base/"))
 }
 
 #[cfg(test)]
@@ -28,4 +43,12 @@ mod tests {
         assert_eq!(branch_name_for_stage("stage-1"), "loom/stage-1");
         assert_eq!(branch_name_for_stage("my-feature"), "loom/my-feature");
     }
+
+    #[test]
+    fn test_base_branch_name_for_stage() {
+        assert_eq!(base_branch_name_for_stage("stage-1"), "loom/_base/stage-1");
+        assert!(is_base_branch("loom/_base/stage-1"));
+        assert!(!is_base_branch("loom/stage-1"));
+        assert!(!is_base_branch("main"));
+    }
 }
@@ -69,9 +69,11 @@ pub fn default_branch(repo_root: &Path) -> Result<String> {
     anyhow::bail!("Could not determine default branch")
 }
 
-/// List loom branches (branches starting with loom/)
+/// List loom branches (branches starting with loom/, within the active plan
+/// namespace)
 pub fn list_loom_branches(repo_root: &Path) -> Result<Vec<String>> {
-    let stdout = run_git_checked(&["branch", "--list", "loom/*"], repo_root)?;
+    let pattern = format!("{}*", crate::namespace::branch_prefix());
+    let stdout = run_git_checked(&["branch", "--list", &pattern], repo_root)?;
     let branches: Vec<String> = stdout
         .lines()
         .map(|s| s.trim().trim_start_matches('*').trim().to_string())
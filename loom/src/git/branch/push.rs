@@ -0,0 +1,82 @@
+//! Publishing stage branches to a remote
+
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use super::naming::branch_name_for_stage;
+use crate::git::runner::run_git_checked;
+
+/// Push a stage's branch to `remote` under the same name.
+///
+/// Retries and resets rewrite stage branches, so the push is forced, but
+/// with a lease: it is refused if someone else moved the remote branch since
+/// loom last pushed it.
+pub fn push_stage_branch(stage_id: &str, remote: &str, repo_root: &Path) -> Result<String> {
+    let branch = branch_name_for_stage(stage_id);
+    let refspec = format!("refs/heads/{branch}:refs/heads/{branch}");
+    run_git_checked(
+        &["push", "--force-with-lease", "--quiet", remote, &refspec],
+        repo_root,
+    )
+    .with_context(|| format!("Failed to push {branch} to {remote}"))?;
+    Ok(branch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    fn git(dir: &Path, args: &[&str]) -> String {
+        let out = Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap();
+        assert!(
+            out.status.success(),
+            "git {args:?} failed: {}",
+            String::from_utf8_lossy(&out.stderr)
+        );
+        String::from_utf8_lossy(&out.stdout).trim().to_string()
+    }
+
+    #[test]
+    fn test_push_stage_branch_creates_and_updates_remote_branch() {
+        let temp = TempDir::new().unwrap();
+        let remote = temp.path().join("remote.git");
+        let root = temp.path().join("repo");
+        fs::create_dir_all(&root).unwrap();
+        git(temp.path(), &["init", "--bare", "-b", "main", "remote.git"]);
+        git(&root, &["init", "-b", "main"]);
+        git(&root, &["config", "user.email", "t@t.com"]);
+        git(&root, &["config", "user.name", "t"]);
+        git(
+            &root,
+            &["remote", "add", "origin", remote.to_str().unwrap()],
+        );
+        fs::write(root.join("README.md"), "v1\n").unwrap();
+        git(&root, &["add", "."]);
+        git(&root, &["commit", "-m", "init"]);
+
+        let branch = branch_name_for_stage("api");
+        git(&root, &["checkout", "-b", &branch]);
+        fs::write(root.join("api.rs"), "v1\n").unwrap();
+        git(&root, &["add", "."]);
+        git(&root, &["commit", "-m", "api"]);
+
+        assert_eq!(push_stage_branch("api", "origin", &root).unwrap(), branch);
+        let head = git(&root, &["rev-parse", "HEAD"]);
+        assert_eq!(git(&remote, &["rev-parse", &branch]), head);
+
+        // A retry rewrites the branch; the next push replaces it
+        git(&root, &["commit", "--amend", "-m", "api (retry)"]);
+        push_stage_branch("api", "origin", &root).unwrap();
+        let head = git(&root, &["rev-parse", "HEAD"]);
+        assert_eq!(git(&remote, &["rev-parse", &branch]), head);
+
+        assert!(push_stage_branch("api", "nowhere", &root).is_err());
+    }
+}
@@ -7,8 +7,9 @@
 use anyhow::Result;
 use std::path::Path;
 
-use crate::git::branch::delete_branch;
+use crate::git::branch::{base_branch_name_for_stage, delete_branch};
 use crate::git::runner::{run_git_bool, run_git_checked};
+use crate::namespace::branch_prefix;
 
 /// Clean up the base branch for a stage
 ///
@@ -22,7 +23,7 @@ use crate::git::runner::{run_git_bool, run_git_checked};
 /// # Returns
 /// `true` if the branch was deleted, `false` if it didn't exist
 pub fn cleanup_base_branch(stage_id: &str, repo_root: &Path) -> Result<bool> {
-    let branch_name = format!("loom/_base/{stage_id}");
+    let branch_name = base_branch_name_for_stage(stage_id);
 
     // Check if branch exists first
     let ref_path = format!("refs/heads/{branch_name}");
@@ -38,7 +39,8 @@ pub fn cleanup_base_branch(stage_id: &str, repo_root: &Path) -> Result<bool> {
 
 /// Clean up all base branches in the repository
 ///
-/// This deletes all branches matching the pattern `loom/_base/*`.
+/// This deletes all branches matching the pattern `loom/_base/*` (within the
+/// active plan namespace).
 /// Useful for cleaning up after all stages are complete or when
 /// resetting the orchestration state.
 ///
@@ -49,7 +51,8 @@ pub fn cleanup_base_branch(stage_id: &str, repo_root: &Path) -> Result<bool> {
 /// A vector of branch names that were deleted
 pub fn cleanup_all_base_branches(repo_root: &Path) -> Result<Vec<String>> {
     // List all branches matching the pattern
-    let stdout = run_git_checked(&["branch", "--list", "loom/_base/*"], repo_root)?;
+    let pattern = format!("{}_base/*", branch_prefix());
+    let stdout = run_git_checked(&["branch", "--list", &pattern], repo_root)?;
 
     let branches: Vec<String> = stdout
         .lines()
@@ -76,7 +79,7 @@ pub fn cleanup_all_base_branches(repo_root: &Path) -> Result<Vec<String>> {
 /// # Returns
 /// `true` if the base branch exists, `false` otherwise
 pub fn base_branch_exists(stage_id: &str, repo_root: &Path) -> Result<bool> {
-    let branch_name = format!("loom/_base/{stage_id}");
+    let branch_name = base_branch_name_for_stage(stage_id);
     let ref_path = format!("refs/heads/{branch_name}");
     Ok(run_git_bool(
         &["rev-parse", "--verify", &ref_path],
@@ -8,7 +8,8 @@ use super::base::cleanup_base_branch;
 use super::branch::cleanup_branch;
 use super::config::{CleanupConfig, CleanupResult};
 use super::worktree::cleanup_worktree;
-use crate::git::branch::branch_name_for_stage;
+use crate::git::branch::{base_branch_name_for_stage, branch_name_for_stage};
+use crate::git::worktree::get_worktree_path;
 
 /// Perform full cleanup after a successful merge
 ///
@@ -74,7 +75,7 @@ pub fn cleanup_after_merge(
     }
 
     // Phase 3: Delete the base branch (if it exists)
-    let base_branch_name = format!("loom/_base/{stage_id}");
+    let base_branch_name = base_branch_name_for_stage(stage_id);
     match cleanup_base_branch(stage_id, repo_root) {
         Ok(deleted) => {
             result.base_branch_deleted = deleted;
@@ -162,7 +163,7 @@ pub fn cleanup_multiple_stages(
 ///
 /// Returns true if the stage has a worktree or branch that exists.
 pub fn needs_cleanup(stage_id: &str, repo_root: &Path) -> bool {
-    let worktree_path = repo_root.join(".worktrees").join(stage_id);
+    let worktree_path = get_worktree_path(stage_id, repo_root);
     let branch_name = branch_name_for_stage(stage_id);
 
     // Check worktree exists
@@ -4,7 +4,8 @@ use anyhow::{Context, Result};
 use std::path::Path;
 use std::process::Command;
 
-use crate::git::branch::{branch_name_for_stage, delete_branch};
+use crate::git::branch::branch_name_for_stage;
+use crate::git::vcs;
 
 /// Clean up the branch for a stage
 ///
@@ -35,6 +36,6 @@ pub fn cleanup_branch(stage_id: &str, repo_root: &Path, force: bool) -> Result<b
     }
 
     // Delete the branch
-    delete_branch(&branch_name, force, repo_root)?;
+    vcs::for_repo(repo_root).delete_branch(&branch_name, force, repo_root)?;
     Ok(true)
 }
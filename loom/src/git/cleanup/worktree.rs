@@ -3,7 +3,8 @@
 use anyhow::{Context, Result};
 use std::path::Path;
 
-use crate::git::worktree::remove_worktree;
+use crate::git::vcs;
+use crate::git::worktree::get_worktree_path;
 
 /// Clean up a single worktree for a stage
 ///
@@ -15,7 +16,7 @@ use crate::git::worktree::remove_worktree;
 /// # Returns
 /// `true` if the worktree was removed, `false` if it didn't exist
 pub fn cleanup_worktree(stage_id: &str, repo_root: &Path, force: bool) -> Result<bool> {
-    let worktree_path = repo_root.join(".worktrees").join(stage_id);
+    let worktree_path = get_worktree_path(stage_id, repo_root);
 
     if !worktree_path.exists() {
         return Ok(false);
@@ -24,8 +25,8 @@ pub fn cleanup_worktree(stage_id: &str, repo_root: &Path, force: bool) -> Result
     // Remove symlinks first to avoid issues with git worktree remove
     remove_worktree_symlinks(&worktree_path)?;
 
-    // Try to remove via git worktree
-    match remove_worktree(stage_id, repo_root, force) {
+    // Try to remove via git worktree (or the repository's VCS backend)
+    match vcs::for_repo(repo_root).remove_workspace(stage_id, repo_root, force) {
         Ok(()) => Ok(true),
         Err(e) => {
             // `git worktree remove` (non-force) refuses when the worktree has
@@ -0,0 +1,260 @@
+//! Dirty main-tree policy shared by `loom run` and the merge path.
+//!
+//! `loom run` refuses to start while the main repository has uncommitted
+//! changes to tracked files, unless the changes are covered by the
+//! `[dirty_tree]` section of `.work/config.toml` or the run was started with
+//! `--allow-dirty`:
+//!
+//! ```toml
+//! [dirty_tree]
+//! allow_dirty_main = false        # true = behave as if --allow-dirty was passed
+//! ignore = [".env", "scratch/**"] # paths that never count as dirty
+//! ```
+//!
+//! Merges into the main repository stash the same (non-ignored) dirty paths
+//! before checking out the merge target and restore them afterwards, so local
+//! edits made while a run is in flight never block or get swept into a merge.
+
+use anyhow::{bail, Result};
+use glob::{MatchOptions, Pattern};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use crate::fs::work_dir::read_dirty_tree_config;
+use crate::git::runner::{run_git, run_git_checked};
+
+/// Persisted `[dirty_tree]` section of `.work/config.toml`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DirtyTreeConfig {
+    /// Start runs even when the main repository has uncommitted changes.
+    #[serde(default)]
+    pub allow_dirty_main: bool,
+    /// Glob patterns (relative to the repository root) for paths whose
+    /// uncommitted changes are ignored. A trailing `/` matches a directory.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ignore: Vec<String>,
+}
+
+impl DirtyTreeConfig {
+    /// Load the policy for a work directory, falling back to the default
+    /// (strict, nothing ignored) when the section is missing or unreadable.
+    pub fn load(work_dir: &Path) -> Self {
+        read_dirty_tree_config(work_dir).unwrap_or_default()
+    }
+
+    /// Whether changes to `path` are ignored by this policy.
+    pub fn is_ignored(&self, path: &str) -> bool {
+        let options = MatchOptions {
+            require_literal_separator: true,
+            ..MatchOptions::default()
+        };
+        self.ignore.iter().any(|pattern| {
+            if let Some(dir) = pattern.strip_suffix('/') {
+                return path == dir || path.starts_with(pattern.as_str());
+            }
+            Pattern::new(pattern).is_ok_and(|p| p.matches_with(path, options))
+        })
+    }
+}
+
+/// Tracked paths with staged or unstaged changes, minus ignored paths.
+///
+/// Untracked files are not considered: they never affect worktree creation
+/// or block a checkout.
+pub fn dirty_paths(repo_root: &Path, config: &DirtyTreeConfig) -> Result<Vec<String>> {
+    let output = run_git(&["status", "--porcelain", "-z"], repo_root)?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!("git status failed: {stderr}");
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    Ok(parse_porcelain_z(&stdout)
+        .into_iter()
+        .filter(|path| !config.is_ignored(path))
+        .collect())
+}
+
+/// Parse `git status --porcelain -z`, returning tracked changed paths.
+fn parse_porcelain_z(stdout: &str) -> Vec<String> {
+    let mut paths = Vec::new();
+    let mut entries = stdout.split('\0');
+    while let Some(entry) = entries.next() {
+        if entry.len() < 4 || entry.starts_with("??") || entry.starts_with("!!") {
+            continue;
+        }
+        let (status, path) = entry.split_at(3);
+        paths.push(path.to_string());
+        // Renames and copies carry the original path as the next entry.
+        if status.starts_with('R') || status.starts_with('C') {
+            entries.next();
+        }
+    }
+    paths
+}
+
+/// Local changes stashed for the duration of a merge.
+#[derive(Debug)]
+pub struct StashedChanges {
+    paths: Vec<String>,
+    message: String,
+}
+
+impl StashedChanges {
+    /// Stash `paths` (staged and unstaged changes) under `message`.
+    ///
+    /// Returns `None` when there is nothing to stash.
+    pub fn push(repo_root: &Path, paths: &[String], message: &str) -> Result<Option<Self>> {
+        if paths.is_empty() {
+            return Ok(None);
+        }
+        let mut args = vec!["stash", "push", "--message", message, "--"];
+        args.extend(paths.iter().map(String::as_str));
+        run_git_checked(&args, repo_root)?;
+        Ok(Some(Self {
+            paths: paths.to_vec(),
+            message: message.to_string(),
+        }))
+    }
+
+    /// Paths that were stashed.
+    pub fn paths(&self) -> &[String] {
+        &self.paths
+    }
+
+    /// Restore the stash, unless the merge changed one of the stashed paths.
+    ///
+    /// `changed` lists files the merge touched. Applying a stash over them
+    /// would leave conflict markers in the main repository, so in that case
+    /// the stash is kept and an error explains how to restore it by hand.
+    pub fn restore(self, repo_root: &Path, changed: &[String]) -> Result<()> {
+        let changed: BTreeSet<&str> = changed.iter().map(String::as_str).collect();
+        let overlap: Vec<&str> = self
+            .paths
+            .iter()
+            .map(String::as_str)
+            .filter(|p| changed.contains(p))
+            .collect();
+        if !overlap.is_empty() {
+            bail!(
+                "local changes to {} were stashed ('{}') and the merge changed the same files; \
+                 restore them with `git stash pop` and resolve by hand",
+                overlap.join(", "),
+                self.message
+            );
+        }
+        if let Err(e) = run_git_checked(&["stash", "pop", "--index"], repo_root) {
+            bail!(
+                "failed to restore stashed local changes ('{}'): {e}; \
+                 they are still in `git stash list`",
+                self.message
+            );
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    fn git(root: &Path, args: &[&str]) {
+        let out = Command::new("git")
+            .args(args)
+            .current_dir(root)
+            .output()
+            .unwrap();
+        assert!(
+            out.status.success(),
+            "git {args:?} failed: {}",
+            String::from_utf8_lossy(&out.stderr)
+        );
+    }
+
+    fn init_repo() -> TempDir {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        git(root, &["init", "-b", "main"]);
+        git(root, &["config", "user.email", "t@t.com"]);
+        git(root, &["config", "user.name", "t"]);
+        fs::create_dir_all(root.join("scratch")).unwrap();
+        for path in ["README.md", ".env", "scratch/notes.md"] {
+            fs::write(root.join(path), "v1\n").unwrap();
+        }
+        git(root, &["add", "."]);
+        git(root, &["commit", "-m", "init"]);
+        temp
+    }
+
+    #[test]
+    fn test_is_ignored() {
+        let config = DirtyTreeConfig {
+            allow_dirty_main: false,
+            ignore: vec![".env".into(), "scratch/".into(), "logs/**/*.log".into()],
+        };
+        assert!(config.is_ignored(".env"));
+        assert!(config.is_ignored("scratch/notes.md"));
+        assert!(config.is_ignored("logs/a/b.log"));
+        assert!(!config.is_ignored("src/.env"));
+        assert!(!config.is_ignored("README.md"));
+        assert!(!DirtyTreeConfig::default().is_ignored(".env"));
+    }
+
+    #[test]
+    fn test_parse_porcelain_z() {
+        let out = " M README.md\0A  new file.rs\0R  renamed.rs\0old.rs\0?? untracked\0";
+        assert_eq!(
+            parse_porcelain_z(out),
+            vec!["README.md", "new file.rs", "renamed.rs"]
+        );
+    }
+
+    #[test]
+    fn test_dirty_paths_honors_ignore_globs() {
+        let temp = init_repo();
+        let root = temp.path();
+        for path in ["README.md", ".env", "scratch/notes.md"] {
+            fs::write(root.join(path), "v2\n").unwrap();
+        }
+        fs::write(root.join("untracked.txt"), "x\n").unwrap();
+
+        let strict = dirty_paths(root, &DirtyTreeConfig::default()).unwrap();
+        assert_eq!(strict, vec![".env", "README.md", "scratch/notes.md"]);
+
+        let config = DirtyTreeConfig {
+            allow_dirty_main: false,
+            ignore: vec![".env".into(), "scratch/**".into()],
+        };
+        assert_eq!(dirty_paths(root, &config).unwrap(), vec!["README.md"]);
+    }
+
+    #[test]
+    fn test_stash_round_trip_and_overlap() {
+        let temp = init_repo();
+        let root = temp.path();
+        fs::write(root.join("README.md"), "local edit\n").unwrap();
+        let paths = vec!["README.md".to_string()];
+
+        let stash = StashedChanges::push(root, &paths, "loom: test")
+            .unwrap()
+            .unwrap();
+        assert_eq!(fs::read_to_string(root.join("README.md")).unwrap(), "v1\n");
+        stash.restore(root, &[]).unwrap();
+        assert_eq!(
+            fs::read_to_string(root.join("README.md")).unwrap(),
+            "local edit\n"
+        );
+
+        let stash = StashedChanges::push(root, &paths, "loom: test")
+            .unwrap()
+            .unwrap();
+        let err = stash.restore(root, &paths).unwrap_err().to_string();
+        assert!(err.contains("git stash pop"), "{err}");
+        assert!(StashedChanges::push(root, &[], "loom: test")
+            .unwrap()
+            .is_none());
+    }
+}
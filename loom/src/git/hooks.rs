@@ -1,11 +1,26 @@
 //! Git hook installation for loom
 //!
-//! Installs git hooks to prevent accidental commits of .work/ and .worktrees/
+//! Installs git hooks to prevent accidental commits of .work/ and .worktrees/,
+//! plus an optional per-worktree hook set configured in `.work/config.toml`:
+//!
+//! ```toml
+//! [git_hooks]
+//! commit_msg = true   # require a "[<stage-id>]" prefix on stage commits
+//! pre_push = true     # block pushes from stage worktrees
+//! ```
+//!
+//! Git shares one hooks directory between a repository and its worktrees, so
+//! the optional hooks are installed there and stay inert unless the running
+//! worktree's git dir holds a `loom-hooks` file enabling them. That file is
+//! written for each stage worktree when its session starts.
 
 use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
 use std::fs;
 use std::os::unix::fs::PermissionsExt;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+use crate::git::runner::run_git_checked;
 
 /// Marker for loom's pre-commit hook section (for idempotent installation)
 const LOOM_HOOK_START_MARKER: &str = "# LOOM_PRE_COMMIT_HOOK_START";
@@ -14,6 +29,62 @@ const LOOM_HOOK_END_MARKER: &str = "# LOOM_PRE_COMMIT_HOOK_END";
 /// The pre-commit hook script content (embedded from hooks/git-pre-commit-hook.sh)
 const PRE_COMMIT_HOOK_CONTENT: &str = include_str!("../../../hooks/git-pre-commit-hook.sh");
 
+/// The commit-msg hook script content (embedded from hooks/git-commit-msg-hook.sh)
+const COMMIT_MSG_HOOK_CONTENT: &str = include_str!("../../../hooks/git-commit-msg-hook.sh");
+
+/// The pre-push hook script content (embedded from hooks/git-pre-push-hook.sh)
+const PRE_PUSH_HOOK_CONTENT: &str = include_str!("../../../hooks/git-pre-push-hook.sh");
+
+/// Per-worktree file, in the worktree's git dir, enabling the optional hooks
+pub const WORKTREE_HOOKS_FILE: &str = "loom-hooks";
+
+/// A git hook loom installs as a marked section of `.git/hooks/<name>`
+struct HookSection {
+    name: &'static str,
+    content: &'static str,
+    start_marker: &'static str,
+    end_marker: &'static str,
+}
+
+const PRE_COMMIT: HookSection = HookSection {
+    name: "pre-commit",
+    content: PRE_COMMIT_HOOK_CONTENT,
+    start_marker: LOOM_HOOK_START_MARKER,
+    end_marker: LOOM_HOOK_END_MARKER,
+};
+
+const COMMIT_MSG: HookSection = HookSection {
+    name: "commit-msg",
+    content: COMMIT_MSG_HOOK_CONTENT,
+    start_marker: "# LOOM_COMMIT_MSG_HOOK_START",
+    end_marker: "# LOOM_COMMIT_MSG_HOOK_END",
+};
+
+const PRE_PUSH: HookSection = HookSection {
+    name: "pre-push",
+    content: PRE_PUSH_HOOK_CONTENT,
+    start_marker: "# LOOM_PRE_PUSH_HOOK_START",
+    end_marker: "# LOOM_PRE_PUSH_HOOK_END",
+};
+
+/// Persisted `[git_hooks]` section of `.work/config.toml`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct GitHooksConfig {
+    /// Reject commits in stage worktrees whose subject lacks a
+    /// `[<stage-id>]` prefix.
+    #[serde(default)]
+    pub commit_msg: bool,
+    /// Reject every push from a stage worktree.
+    #[serde(default)]
+    pub pre_push: bool,
+}
+
+impl GitHooksConfig {
+    fn any_enabled(&self) -> bool {
+        self.commit_msg || self.pre_push
+    }
+}
+
 /// Install the pre-commit hook to the repository's .git/hooks directory
 ///
 /// This function:
@@ -30,12 +101,17 @@ const PRE_COMMIT_HOOK_CONTENT: &str = include_str!("../../../hooks/git-pre-commi
 /// * `Ok(false)` - Hook was already up to date
 /// * `Err` - Installation failed
 pub fn install_pre_commit_hook(repo_root: &Path) -> Result<bool> {
-    let git_hooks_dir = repo_root.join(".git/hooks");
-    let hook_path = git_hooks_dir.join("pre-commit");
+    install_hook_section(&repo_root.join(".git/hooks"), &PRE_COMMIT)
+}
+
+/// Install `hook` into `git_hooks_dir`, keeping any non-loom content of an
+/// existing hook. Returns whether the hook file changed.
+fn install_hook_section(git_hooks_dir: &Path, hook: &HookSection) -> Result<bool> {
+    let hook_path = git_hooks_dir.join(hook.name);
 
     // Ensure hooks directory exists
     if !git_hooks_dir.exists() {
-        fs::create_dir_all(&git_hooks_dir).with_context(|| {
+        fs::create_dir_all(git_hooks_dir).with_context(|| {
             format!(
                 "Failed to create hooks directory: {}",
                 git_hooks_dir.display()
@@ -44,7 +120,7 @@ pub fn install_pre_commit_hook(repo_root: &Path) -> Result<bool> {
     }
 
     // Extract only the loom section from the full hook file
-    let loom_section = extract_loom_section(PRE_COMMIT_HOOK_CONTENT);
+    let loom_section = extract_loom_section(hook.content, hook);
 
     // Check if hook already exists
     if hook_path.exists() {
@@ -52,15 +128,15 @@ pub fn install_pre_commit_hook(repo_root: &Path) -> Result<bool> {
             .with_context(|| format!("Failed to read existing hook: {}", hook_path.display()))?;
 
         // Check if loom hook is already installed
-        if existing_content.contains(LOOM_HOOK_START_MARKER) {
+        if existing_content.contains(hook.start_marker) {
             // Check if content is the same
-            let existing_section = extract_existing_loom_section(&existing_content);
+            let existing_section = extract_existing_loom_section(&existing_content, hook);
             if existing_section.trim() == loom_section.trim() {
                 return Ok(false); // Already up to date
             }
 
             // Replace existing loom section
-            let new_content = replace_loom_section(&existing_content, &loom_section);
+            let new_content = replace_loom_section(&existing_content, &loom_section, hook);
             fs::write(&hook_path, new_content)
                 .with_context(|| format!("Failed to update hook: {}", hook_path.display()))?;
         } else {
@@ -72,8 +148,8 @@ pub fn install_pre_commit_hook(repo_root: &Path) -> Result<bool> {
     } else {
         // Create new hook with shebang and loom section
         let content = format!(
-            "#!/usr/bin/env bash\n# Git pre-commit hook\n\n{}",
-            loom_section
+            "#!/usr/bin/env bash\n# Git {} hook\n\n{}",
+            hook.name, loom_section
         );
         fs::write(&hook_path, content)
             .with_context(|| format!("Failed to create hook: {}", hook_path.display()))?;
@@ -90,13 +166,71 @@ pub fn install_pre_commit_hook(repo_root: &Path) -> Result<bool> {
     Ok(true)
 }
 
+/// Enable the configured optional hooks for the stage worktree at
+/// `worktree_path`.
+///
+/// Installs the enabled hooks into the repository's shared hooks directory
+/// and writes the worktree's [`WORKTREE_HOOKS_FILE`]. With every hook
+/// disabled, an existing file is removed so the worktree is unaffected.
+pub fn setup_worktree_git_hooks(
+    worktree_path: &Path,
+    stage_id: &str,
+    config: &GitHooksConfig,
+) -> Result<()> {
+    if !config.any_enabled() {
+        // Nothing to disable outside a git worktree.
+        let Ok(git_dir) = git_path(worktree_path, "--absolute-git-dir") else {
+            return Ok(());
+        };
+        let hooks_file = git_dir.join(WORKTREE_HOOKS_FILE);
+        if let Err(e) = fs::remove_file(&hooks_file) {
+            if e.kind() != std::io::ErrorKind::NotFound {
+                return Err(e)
+                    .with_context(|| format!("Failed to remove {}", hooks_file.display()));
+            }
+        }
+        return Ok(());
+    }
+
+    let hooks_file = git_path(worktree_path, "--absolute-git-dir")?.join(WORKTREE_HOOKS_FILE);
+
+    let common_dir = git_path(worktree_path, "--git-common-dir")?;
+    let hooks_dir = common_dir.join("hooks");
+    if config.commit_msg {
+        install_hook_section(&hooks_dir, &COMMIT_MSG)?;
+    }
+    if config.pre_push {
+        install_hook_section(&hooks_dir, &PRE_PUSH)?;
+    }
+
+    let content = format!(
+        "stage_id={stage_id}\ncommit_msg={}\npre_push={}\n",
+        u8::from(config.commit_msg),
+        u8::from(config.pre_push)
+    );
+    fs::write(&hooks_file, content)
+        .with_context(|| format!("Failed to write {}", hooks_file.display()))
+}
+
+/// Absolute path printed by `git rev-parse <flag>` in `dir`.
+fn git_path(dir: &Path, flag: &str) -> Result<PathBuf> {
+    let out = run_git_checked(&["rev-parse", flag], dir)
+        .with_context(|| format!("Failed to resolve git dir of {}", dir.display()))?;
+    let path = PathBuf::from(out.trim());
+    Ok(if path.is_absolute() {
+        path
+    } else {
+        dir.join(path)
+    })
+}
+
 /// Extract the loom section from the full hook content
-fn extract_loom_section(content: &str) -> String {
-    let start = content.find(LOOM_HOOK_START_MARKER);
-    let end = content.find(LOOM_HOOK_END_MARKER);
+fn extract_loom_section(content: &str, hook: &HookSection) -> String {
+    let start = content.find(hook.start_marker);
+    let end = content.find(hook.end_marker);
 
     match (start, end) {
-        (Some(s), Some(e)) => content[s..e + LOOM_HOOK_END_MARKER.len()].to_string(),
+        (Some(s), Some(e)) => content[s..e + hook.end_marker.len()].to_string(),
         _ => {
             // Fallback: use the whole content (shouldn't happen with proper hook file)
             content.to_string()
@@ -105,19 +239,19 @@ fn extract_loom_section(content: &str) -> String {
 }
 
 /// Extract existing loom section from a hook file
-fn extract_existing_loom_section(content: &str) -> String {
-    extract_loom_section(content)
+fn extract_existing_loom_section(content: &str, hook: &HookSection) -> String {
+    extract_loom_section(content, hook)
 }
 
 /// Replace the loom section in an existing hook file
-fn replace_loom_section(existing: &str, new_section: &str) -> String {
-    let start = existing.find(LOOM_HOOK_START_MARKER);
-    let end = existing.find(LOOM_HOOK_END_MARKER);
+fn replace_loom_section(existing: &str, new_section: &str, hook: &HookSection) -> String {
+    let start = existing.find(hook.start_marker);
+    let end = existing.find(hook.end_marker);
 
     match (start, end) {
         (Some(s), Some(e)) => {
             let before = &existing[..s];
-            let after = &existing[e + LOOM_HOOK_END_MARKER.len()..];
+            let after = &existing[e + hook.end_marker.len()..];
             format!("{}{}{}", before, new_section, after)
         }
         _ => {
@@ -149,7 +283,7 @@ mod tests {
     fn test_extract_loom_section() {
         let content =
             "#!/bin/bash\n# LOOM_PRE_COMMIT_HOOK_START\necho test\n# LOOM_PRE_COMMIT_HOOK_END\n";
-        let section = extract_loom_section(content);
+        let section = extract_loom_section(content, &PRE_COMMIT);
         assert!(section.contains("LOOM_PRE_COMMIT_HOOK_START"));
         assert!(section.contains("echo test"));
         assert!(section.contains("LOOM_PRE_COMMIT_HOOK_END"));
@@ -189,6 +323,56 @@ mod tests {
         assert!(!result2.unwrap());
     }
 
+    fn git(dir: &Path, args: &[&str]) -> std::process::Output {
+        std::process::Command::new("git")
+            .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_setup_worktree_git_hooks_enforces_stage_prefix() {
+        let temp = TempDir::new().unwrap();
+        let repo = temp.path().join("repo");
+        fs::create_dir_all(&repo).unwrap();
+        git(&repo, &["init", "-q"]);
+        git(&repo, &["commit", "-q", "--allow-empty", "-m", "init"]);
+        assert!(git(
+            &repo,
+            &["worktree", "add", "-q", "-b", "loom/api", "../api"]
+        )
+        .status
+        .success());
+        let worktree = temp.path().join("api");
+
+        let config = GitHooksConfig {
+            commit_msg: true,
+            pre_push: true,
+        };
+        setup_worktree_git_hooks(&worktree, "api", &config).unwrap();
+        assert!(repo.join(".git/hooks/commit-msg").exists());
+        assert!(repo.join(".git/hooks/pre-push").exists());
+
+        let commit = |dir: &Path, msg: &str| {
+            git(dir, &["commit", "-q", "--allow-empty", "-m", msg])
+                .status
+                .success()
+        };
+        assert!(!commit(&worktree, "add endpoint"));
+        assert!(commit(&worktree, "[api] add endpoint"));
+        // The main repository is never checked.
+        assert!(commit(&repo, "unprefixed"));
+
+        setup_worktree_git_hooks(&worktree, "api", &GitHooksConfig::default()).unwrap();
+        assert!(!repo
+            .join(".git/worktrees/api")
+            .join(WORKTREE_HOOKS_FILE)
+            .exists());
+        assert!(commit(&worktree, "unprefixed again"));
+    }
+
     #[test]
     fn test_is_pre_commit_hook_installed() {
         let temp = TempDir::new().unwrap();
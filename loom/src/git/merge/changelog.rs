@@ -0,0 +1,248 @@
+//! Changelog entries for merged stages
+//!
+//! With `[merge] changelog = "CHANGELOG.md"` in `.work/config.toml`, every
+//! stage merge appends an entry for the stage to that file and amends it into
+//! the merge commit, so the repository history says which stage produced a
+//! merge and what it left behind. Merges finished by resolving a conflict
+//! get their entry when `loom stage merge --resolved` confirms them:
+//!
+//! ```markdown
+//! ## Add login endpoint (`api-auth`) - 2026-01-01
+//!
+//! Implement POST /login with JWT sessions.
+//!
+//! Outputs:
+//! - `api_port`: 8080
+//! ```
+
+use anyhow::{bail, Context, Result};
+use chrono::{NaiveDate, Utc};
+use serde_json::Value;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Component, Path};
+
+use crate::git::branch::{branch_exists, branch_name_for_stage, current_branch, is_ancestor_of};
+use crate::git::runner::{run_git_bool, run_git_checked};
+use crate::git::vcs::{self, VcsKind};
+use crate::models::stage::Stage;
+use crate::verify::transitions::load_stage;
+
+use super::lock::MergeConfig;
+
+/// Heading written to a changelog file loom creates.
+const HEADER: &str = "# Changelog\n";
+
+/// Append the entry for `stage_id` to the configured changelog in the merge
+/// commit that landed the stage on `target_branch`.
+///
+/// This is the one post-merge step for every path that finishes a stage
+/// merge: a clean merge by either backend, or a conflict resolution
+/// confirmed with `loom stage merge --resolved`. The caller has just
+/// created (or confirmed) the merge commit.
+///
+/// Returns whether an entry was written; without a configured changelog, or
+/// when the merge commit already has the stage's entry, nothing happens.
+pub fn record_merge(
+    stage_id: &str,
+    target_branch: &str,
+    repo_root: &Path,
+    work_dir: &Path,
+) -> Result<bool> {
+    let Some(path) = MergeConfig::load(work_dir).changelog else {
+        return Ok(false);
+    };
+    let relative = Path::new(&path);
+    if relative.is_absolute()
+        || relative
+            .components()
+            .any(|c| !matches!(c, Component::Normal(_) | Component::CurDir))
+    {
+        bail!("[merge] changelog must be a path inside the repository, got '{path}'");
+    }
+
+    let stage = load_stage(stage_id, work_dir)?;
+    let entry = format_entry(&stage, Utc::now().date_naive());
+    match vcs::detect(repo_root) {
+        VcsKind::Git => amend_merge_commit(&stage, target_branch, &path, &entry, repo_root),
+        VcsKind::Jujutsu => vcs::append_to_merge(stage_id, target_branch, &path, &entry, repo_root),
+    }
+}
+
+/// Heading fragment that identifies the entry of `stage_id`.
+pub(crate) fn entry_marker(stage_id: &str) -> String {
+    format!("(`{stage_id}`)")
+}
+
+/// Append `entry` to the changelog at `file`, creating it with a heading.
+pub(crate) fn append_entry(file: &Path, entry: &str) -> Result<()> {
+    let mut text = String::new();
+    if !file.exists() {
+        text.push_str(HEADER);
+    }
+    text.push('\n');
+    text.push_str(entry);
+
+    if let Some(parent) = file.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(file)
+        .and_then(|mut f| f.write_all(text.as_bytes()))
+        .with_context(|| format!("Failed to write changelog {}", file.display()))
+}
+
+/// Amend `entry` into the git merge commit at HEAD, which must be the merge
+/// of `stage`'s work into the checked-out `target_branch`.
+///
+/// A failed amend restores the changelog, so the main repository is not
+/// left with an edit that trips the next merge's dirty-tree check.
+fn amend_merge_commit(
+    stage: &Stage,
+    target_branch: &str,
+    path: &str,
+    entry: &str,
+    repo_root: &Path,
+) -> Result<bool> {
+    let branch = current_branch(repo_root)?;
+    if branch != target_branch {
+        bail!(
+            "Cannot amend the merge commit: '{target_branch}' is not checked out (on '{branch}')"
+        );
+    }
+    let stage_branch = branch_name_for_stage(&stage.id);
+    let stage_rev = match (
+        &stage.completed_commit,
+        branch_exists(&stage_branch, repo_root)?,
+    ) {
+        (_, true) => stage_branch,
+        (Some(commit), false) => commit.clone(),
+        (None, false) => bail!("Cannot find the merged work of stage '{}'", stage.id),
+    };
+    let is_stage_merge = run_git_bool(&["rev-parse", "--verify", "-q", "HEAD^2"], repo_root)
+        && is_ancestor_of(&stage_rev, "HEAD", repo_root)?
+        && !is_ancestor_of(&stage_rev, "HEAD^1", repo_root)?;
+    if !is_stage_merge {
+        bail!("HEAD is not the merge commit of stage '{}'", stage.id);
+    }
+    let merged = run_git_checked(&["diff", "HEAD^1", "HEAD", "--", path], repo_root)?;
+    if merged.contains(&entry_marker(&stage.id)) {
+        return Ok(false);
+    }
+    if !run_git_checked(&["status", "--porcelain", "--", path], repo_root)?.is_empty() {
+        bail!(
+            "{path} has local changes; not adding the entry for '{}'",
+            stage.id
+        );
+    }
+
+    let tracked = run_git_bool(&["cat-file", "-e", &format!("HEAD:{path}")], repo_root);
+    let file = repo_root.join(path);
+    let amended = append_entry(&file, entry)
+        .and_then(|()| run_git_checked(&["add", "--", path], repo_root))
+        .and_then(|_| {
+            run_git_checked(
+                &["commit", "--amend", "--no-edit", "--no-verify"],
+                repo_root,
+            )
+        });
+    if let Err(e) = amended {
+        let restored = if tracked {
+            run_git_checked(&["checkout", "HEAD", "--", path], repo_root).map(|_| ())
+        } else {
+            run_git_checked(
+                &["rm", "-q", "--cached", "--ignore-unmatch", "--", path],
+                repo_root,
+            )
+            .map(|_| ())
+            .and_then(|()| std::fs::remove_file(&file).map_err(Into::into))
+        };
+        if let Err(restore) = restored {
+            eprintln!("Warning: failed to restore {path}: {restore:#}");
+        }
+        return Err(e);
+    }
+    Ok(true)
+}
+
+/// The changelog entry for `stage`: name and id, the first paragraph of its
+/// description, and the outputs it set.
+pub fn format_entry(stage: &Stage, date: NaiveDate) -> String {
+    let mut entry = format!("## {} (`{}`) - {date}\n", stage.name, stage.id);
+
+    let summary = stage
+        .description
+        .as_deref()
+        .and_then(|d| d.trim().split("\n\n").next())
+        .map(|p| p.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|p| !p.is_empty());
+    if let Some(summary) = summary {
+        entry.push_str(&format!("\n{summary}\n"));
+    }
+
+    if !stage.outputs.is_empty() {
+        entry.push_str("\nOutputs:\n");
+        for output in &stage.outputs {
+            let value = match &output.value {
+                Value::String(text) => text.clone(),
+                other => other.to_string(),
+            };
+            entry.push_str(&format!("- `{}`: {value}", output.key));
+            if !output.description.is_empty() {
+                entry.push_str(&format!(" ({})", output.description));
+            }
+            entry.push('\n');
+        }
+    }
+    entry
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::stage::StageOutput;
+    use serde_json::json;
+
+    #[test]
+    fn test_format_entry() {
+        let mut stage = Stage::new("Add login endpoint".to_string(), None);
+        stage.id = "api-auth".to_string();
+        stage.description = Some(
+            "Implement POST /login\nwith JWT sessions.\n\nDetails the entry leaves out."
+                .to_string(),
+        );
+        stage.outputs = vec![
+            StageOutput {
+                key: "api_port".to_string(),
+                value: json!(8080),
+                description: String::new(),
+            },
+            StageOutput {
+                key: "base_url".to_string(),
+                value: json!("http://localhost"),
+                description: "Where the API is served".to_string(),
+            },
+        ];
+
+        let date = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+        assert_eq!(
+            format_entry(&stage, date),
+            "## Add login endpoint (`api-auth`) - 2026-01-01\n\
+             \n\
+             Implement POST /login with JWT sessions.\n\
+             \n\
+             Outputs:\n\
+             - `api_port`: 8080\n\
+             - `base_url`: http://localhost (Where the API is served)\n"
+        );
+
+        stage.description = None;
+        stage.outputs.clear();
+        assert_eq!(
+            format_entry(&stage, date),
+            "## Add login endpoint (`api-auth`) - 2026-01-01\n"
+        );
+    }
+}
@@ -8,6 +8,7 @@ use anyhow::{Context, Result};
 use std::path::{Path, PathBuf};
 
 use crate::git::runner::run_git_checked;
+use crate::git::worktree::get_worktree_path;
 
 /// Where an in-progress merge was found.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -173,7 +174,7 @@ pub fn detect_in_progress_merges(stage_id: &str, repo_root: &Path) -> Vec<InProg
         merges.push(m);
     }
 
-    let worktree = repo_root.join(".worktrees").join(stage_id);
+    let worktree = get_worktree_path(stage_id, repo_root);
     if worktree.exists() {
         if let Ok(Some(m)) = detect_in_progress_merge_at_worktree(&worktree) {
             merges.push(m);
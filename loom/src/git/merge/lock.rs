@@ -1,17 +1,159 @@
 //! File-based locking for merge operations
+//!
+//! Every merge into the merge point holds `.work/merge.lock`. The lock file
+//! records who holds it, one `key=value` per line:
+//!
+//! ```text
+//! pid=4242
+//! timestamp=2026-01-01T12:00:00+00:00
+//! host=build-01
+//! operation=merge api-auth into main
+//! ```
+//!
+//! A lock whose holder process has died (on this host) is stale and taken
+//! over by the next process that wants it, so a crashed merge never stalls
+//! the plan. Acquisition waits up to `[merge] lock_timeout_secs` (default
+//! 30 seconds) and then fails with the holder's details. `loom merge
+//! lock-status` shows the same details.
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use std::fs::{self, OpenOptions};
 use std::io::{self, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 
+use crate::fs::work_dir::read_merge_config;
+use crate::utils::format_elapsed;
+
 // Merge lock timeout for detecting stale locks (5 minutes)
 const MERGE_LOCK_STALE_TIMEOUT_SECS: u64 = 300;
 
+/// How long to wait for the merge lock when `lock_timeout_secs` is unset.
+pub const DEFAULT_LOCK_TIMEOUT_SECS: u64 = 30;
+
+/// Persisted `[merge]` section of `.work/config.toml`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MergeConfig {
+    /// Seconds to wait for the merge lock before giving up
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lock_timeout_secs: Option<u64>,
+    /// File, relative to the repository root, that each stage merge appends
+    /// an entry to as part of its merge commit
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub changelog: Option<String>,
+}
+
+impl MergeConfig {
+    /// Load the `[merge]` config, falling back to the defaults when the
+    /// section is invalid.
+    pub fn load(work_dir: &Path) -> Self {
+        read_merge_config(work_dir).unwrap_or_else(|e| {
+            eprintln!("Warning: ignoring invalid [merge] config: {e:#}");
+            Self::default()
+        })
+    }
+
+    pub fn lock_timeout(&self) -> Duration {
+        Duration::from_secs(self.lock_timeout_secs.unwrap_or(DEFAULT_LOCK_TIMEOUT_SECS))
+    }
+}
+
+/// Who holds the merge lock, as recorded in the lock file.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LockInfo {
+    pub pid: Option<u32>,
+    pub acquired_at: Option<DateTime<Utc>>,
+    pub host: Option<String>,
+    /// What the holder is doing, e.g. `merge api-auth into main`
+    pub operation: Option<String>,
+}
+
+/// Whether the lock's holder is still running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HolderState {
+    Alive,
+    /// The holder process is gone; the next acquirer takes the lock over
+    Dead,
+    /// Liveness cannot be checked (another host, or no PID recorded)
+    Unknown,
+}
+
+impl LockInfo {
+    fn parse(contents: &str) -> Self {
+        let mut info = Self::default();
+        for line in contents.lines() {
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            let value = value.trim();
+            match key.trim() {
+                "pid" => info.pid = value.parse().ok(),
+                "timestamp" => {
+                    info.acquired_at = DateTime::parse_from_rfc3339(value)
+                        .ok()
+                        .map(|t| t.with_timezone(&Utc))
+                }
+                "host" => info.host = Some(value.to_string()),
+                "operation" => info.operation = Some(value.to_string()),
+                _ => {}
+            }
+        }
+        info
+    }
+
+    /// Read the lock file in `work_dir`; `None` when the lock is free.
+    pub fn read(work_dir: &Path) -> Option<Self> {
+        fs::read_to_string(work_dir.join("merge.lock"))
+            .ok()
+            .map(|contents| Self::parse(&contents))
+    }
+
+    pub fn holder_state(&self) -> HolderState {
+        let same_host = match (&self.host, current_host()) {
+            (Some(host), Some(current)) => *host == current,
+            _ => true,
+        };
+        match self.pid {
+            Some(_) if !same_host => HolderState::Unknown,
+            Some(pid) if crate::process::is_process_alive(pid) => HolderState::Alive,
+            Some(_) => HolderState::Dead,
+            None => HolderState::Unknown,
+        }
+    }
+
+    /// One-line description: `PID 4242 (merge a into main) on build-01, held 3m`
+    pub fn describe(&self) -> String {
+        let mut out = match self.pid {
+            Some(pid) => format!("PID {pid}"),
+            None => "an unknown process".to_string(),
+        };
+        if let Some(operation) = &self.operation {
+            out.push_str(&format!(" ({operation})"));
+        }
+        if let Some(host) = &self.host {
+            out.push_str(&format!(" on {host}"));
+        }
+        if let Some(at) = self.acquired_at {
+            out.push_str(&format!(
+                ", held {}",
+                format_elapsed((Utc::now() - at).num_seconds())
+            ));
+        }
+        out
+    }
+}
+
+fn current_host() -> Option<String> {
+    nix::unistd::gethostname()
+        .ok()
+        .and_then(|h| h.into_string().ok())
+}
+
 /// File-based merge lock to prevent concurrent merges
 pub struct MergeLock {
-    lock_path: std::path::PathBuf,
+    lock_path: PathBuf,
     held: bool,
 }
 
@@ -22,23 +164,47 @@ impl MergeLock {
     /// If another process holds the lock, this will wait up to `timeout`
     /// before failing.
     pub fn acquire(work_dir: &Path, timeout: Duration) -> Result<Self> {
+        Self::acquire_inner(work_dir, timeout, None)
+    }
+
+    /// Acquire the merge lock for `operation`, waiting up to the configured
+    /// `[merge] lock_timeout_secs`.
+    ///
+    /// On timeout the error names the holder and how to inspect it.
+    pub fn acquire_for(work_dir: &Path, operation: &str) -> Result<Self> {
+        let timeout = MergeConfig::load(work_dir).lock_timeout();
+        Self::acquire_inner(work_dir, timeout, Some(operation))
+    }
+
+    fn acquire_inner(work_dir: &Path, timeout: Duration, operation: Option<&str>) -> Result<Self> {
         let lock_path = work_dir.join("merge.lock");
         let start = std::time::Instant::now();
         let poll_interval = Duration::from_millis(100);
 
         loop {
-            match Self::try_acquire(&lock_path) {
+            match Self::try_acquire(&lock_path, operation) {
                 Ok(lock) => return Ok(lock),
                 Err(_) if start.elapsed() < timeout => {
                     std::thread::sleep(poll_interval);
                 }
-                Err(e) => return Err(e),
+                Err(e) => {
+                    let Some(info) = LockInfo::read(work_dir) else {
+                        return Err(e);
+                    };
+                    bail!(
+                        "Timed out after {}s waiting for the merge lock, held by {}. \
+                         Run `loom merge lock-status` for details; raise \
+                         `[merge] lock_timeout_secs` if merges legitimately take longer.",
+                        timeout.as_secs(),
+                        info.describe()
+                    );
+                }
             }
         }
     }
 
     /// Try to acquire the lock without waiting
-    fn try_acquire(lock_path: &Path) -> Result<Self> {
+    fn try_acquire(lock_path: &Path, operation: Option<&str>) -> Result<Self> {
         // Try to create the lock file exclusively
         match OpenOptions::new()
             .write(true)
@@ -49,8 +215,14 @@ impl MergeLock {
                 // Write our PID and timestamp to the lock file
                 let pid = std::process::id();
                 let timestamp = chrono::Utc::now().to_rfc3339();
-                writeln!(file, "pid={pid}")?;
-                writeln!(file, "timestamp={timestamp}")?;
+                let mut contents = format!("pid={pid}\ntimestamp={timestamp}\n");
+                if let Some(host) = current_host() {
+                    contents.push_str(&format!("host={host}\n"));
+                }
+                if let Some(operation) = operation {
+                    contents.push_str(&format!("operation={}\n", operation.replace('\n', " ")));
+                }
+                file.write_all(contents.as_bytes())?;
                 file.sync_all()?;
                 Ok(Self {
                     lock_path: lock_path.to_path_buf(),
@@ -65,8 +237,14 @@ impl MergeLock {
                     match fs::rename(lock_path, &claimed_path) {
                         Ok(_) => {
                             // We successfully claimed the stale lock
+                            if let Ok(contents) = fs::read_to_string(&claimed_path) {
+                                eprintln!(
+                                    "Taking over stale merge lock from {}",
+                                    LockInfo::parse(&contents).describe()
+                                );
+                            }
                             fs::remove_file(&claimed_path).ok();
-                            Self::try_acquire(lock_path)
+                            Self::try_acquire(lock_path, operation)
                         }
                         Err(_) => {
                             // Another process already claimed it, lock is not stale anymore
@@ -81,6 +259,13 @@ impl MergeLock {
         }
     }
 
+    /// PID of the live process holding the merge lock in `work_dir`, if any.
+    pub fn holder(work_dir: &Path) -> Option<u32> {
+        LockInfo::read(work_dir)
+            .filter(|info| info.holder_state() != HolderState::Dead)
+            .and_then(|info| info.pid)
+    }
+
     /// Check if an existing lock is stale.
     ///
     /// Staleness is decided by the holder's liveness first, NOT by mtime alone.
@@ -93,18 +278,19 @@ impl MergeLock {
     /// - If no PID can be read from the file (truncated/legacy/partial write),
     ///   fall back to the mtime ceiling so a genuinely abandoned lock still
     ///   eventually clears.
+    ///
+    /// A holder on another host (a `.work/` on a shared filesystem) cannot be
+    /// checked for liveness and is treated like a lock without a PID.
     fn is_lock_stale(lock_path: &Path) -> Result<bool> {
-        match Self::read_holder_pid(lock_path) {
-            Some(pid) => {
-                if crate::process::is_process_alive(pid) {
-                    // Holder is still running — the lock is valid no matter how old.
-                    Ok(false)
-                } else {
-                    // Holder is gone — reclaim the lock.
-                    Ok(true)
-                }
-            }
-            None => {
+        let info = fs::read_to_string(lock_path)
+            .map(|contents| LockInfo::parse(&contents))
+            .unwrap_or_default();
+        match info.holder_state() {
+            // Holder is still running — the lock is valid no matter how old.
+            HolderState::Alive => Ok(false),
+            // Holder is gone — reclaim the lock.
+            HolderState::Dead => Ok(true),
+            HolderState::Unknown => {
                 // Couldn't read the holder PID; use the mtime backstop.
                 let metadata = fs::metadata(lock_path)?;
                 let modified = metadata.modified()?;
@@ -116,19 +302,6 @@ impl MergeLock {
         }
     }
 
-    /// Parse the `pid=<n>` line written by [`Self::try_acquire`].
-    ///
-    /// Returns `None` if the file can't be read or has no parseable `pid=` line
-    /// (e.g. a partially-written lock), signalling the caller to fall back to
-    /// the mtime heuristic.
-    fn read_holder_pid(lock_path: &Path) -> Option<u32> {
-        let contents = fs::read_to_string(lock_path).ok()?;
-        contents
-            .lines()
-            .find_map(|line| line.strip_prefix("pid="))
-            .and_then(|v| v.trim().parse::<u32>().ok())
-    }
-
     /// Release the lock
     pub fn release(mut self) -> Result<()> {
         self.release_inner()
@@ -235,4 +408,36 @@ mod tests {
             "a lock whose holder is dead must be stale"
         );
     }
+
+    #[test]
+    fn test_lock_records_holder_and_times_out_with_details() {
+        let temp_dir = TempDir::new().unwrap();
+        let work_dir = temp_dir.path();
+
+        let _lock = MergeLock::acquire_for(work_dir, "merge api into main").unwrap();
+        let info = LockInfo::read(work_dir).unwrap();
+        assert_eq!(info.pid, Some(std::process::id()));
+        assert_eq!(info.operation.as_deref(), Some("merge api into main"));
+        assert!(info.acquired_at.is_some());
+        assert_eq!(info.holder_state(), HolderState::Alive);
+
+        let err = MergeLock::acquire_inner(work_dir, Duration::from_millis(100), None)
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(err.contains("Timed out after 0s"), "{err}");
+        assert!(err.contains("(merge api into main)"), "{err}");
+    }
+
+    #[test]
+    fn test_dead_holder_is_taken_over_and_other_hosts_are_unknown() {
+        let temp_dir = TempDir::new().unwrap();
+        let work_dir = temp_dir.path();
+        fs::write(work_dir.join("merge.lock"), "pid=4294967294\n").unwrap();
+        assert_eq!(MergeLock::holder(work_dir), None);
+        let _lock = MergeLock::acquire_for(work_dir, "merge b into main").unwrap();
+
+        let remote = LockInfo::parse("pid=4294967294\nhost=some-other-host-xyz\n");
+        assert_eq!(remote.holder_state(), HolderState::Unknown);
+    }
 }
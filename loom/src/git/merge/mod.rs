@@ -1,14 +1,20 @@
 //! Git merge operations for integrating worktree branches
 
+pub mod changelog;
 pub mod in_progress;
 pub mod lock;
+mod predict;
+pub mod scope;
+pub mod stats;
 mod status;
 
 use anyhow::{bail, Result};
 use std::path::Path;
-use std::time::Duration;
 
-use super::branch::{branch_exists, branch_name_for_stage, current_branch, is_ancestor_of};
+use super::branch::{
+    branch_exists, branch_name_for_stage, current_branch, get_branch_head, is_ancestor_of,
+};
+use crate::git::dirty::{dirty_paths, DirtyTreeConfig, StashedChanges};
 use crate::git::runner::{run_git, run_git_checked};
 use lock::MergeLock;
 
@@ -17,6 +23,7 @@ pub use in_progress::{
     detect_in_progress_merge_at, detect_in_progress_merge_at_worktree, detect_in_progress_merges,
     git_dir_for_repo_path, merge_head_exists, ActiveMergeState, InProgressMerge, MergeLocation,
 };
+pub use predict::{branch_changed_files, predict_merge_conflicts, ConflictPrediction};
 pub use status::{build_merge_report, check_merge_state, MergeState, MergeStatusReport};
 
 /// Result of a merge operation
@@ -65,9 +72,12 @@ fn require_no_active_merge(repo_root: &Path) -> Result<()> {
 ///
 /// Steps:
 /// 1. Acquire merge lock to prevent concurrent merges
-/// 2. Checkout target branch
-/// 3. Merge stage branch (loom/{stage_id})
-/// 4. Return merge result
+/// 2. Stash uncommitted changes to tracked files (minus `[dirty_tree]`
+///    ignores, see [`crate::git::dirty`])
+/// 3. Checkout target branch
+/// 4. Merge stage branch (loom/{stage_id}), amending the stage's entry in
+///    the `[merge] changelog` file into the merge commit
+/// 5. Restore the stash and return merge result
 ///
 /// The merge lock is held for the duration of the operation and automatically
 /// released when the function returns.
@@ -78,17 +88,54 @@ pub fn merge_stage(
     work_dir: &Path,
 ) -> Result<MergeResult> {
     // Acquire merge lock to prevent concurrent merges
-    let _lock = MergeLock::acquire(work_dir, Duration::from_secs(30)).map_err(|e| {
-        anyhow::anyhow!(
-            "Could not acquire merge lock: {}. Another merge may be in progress.",
-            e
-        )
-    })?;
+    let _lock =
+        MergeLock::acquire_for(work_dir, &format!("merge {stage_id} into {target_branch}"))?;
 
     // Refuse if MERGE_HEAD already exists — running here would `git merge
     // --abort` an active resolution and lose work.
     require_no_active_merge(repo_root)?;
 
+    // Local edits in the main repository would block the checkout or be
+    // swept into the merge commit; park them for the duration.
+    let dirty = dirty_paths(repo_root, &DirtyTreeConfig::load(work_dir))?;
+    let stash = StashedChanges::push(
+        repo_root,
+        &dirty,
+        &format!("loom: local changes before merging {stage_id}"),
+    )?;
+    let target_before = get_branch_head(target_branch, repo_root).ok();
+
+    let result = merge_stage_branch(stage_id, target_branch, repo_root);
+    if let Ok(MergeResult::Success { .. }) = &result {
+        if let Err(e) = changelog::record_merge(stage_id, target_branch, repo_root, work_dir) {
+            eprintln!("Warning: failed to add the changelog entry for '{stage_id}': {e:#}");
+        }
+    }
+
+    if let Some(stash) = stash {
+        let changed = match (&result, &target_before) {
+            (Ok(MergeResult::Success { .. } | MergeResult::FastForward), Some(before)) => {
+                run_git_checked(&["diff", "--name-only", before, target_branch], repo_root)
+                    .map(|out| out.lines().map(String::from).collect())
+                    .unwrap_or_else(|_| stash.paths().to_vec())
+            }
+            _ => Vec::new(),
+        };
+        if let Err(e) = stash.restore(repo_root, &changed) {
+            eprintln!("Warning: {e}");
+        }
+    }
+
+    result
+}
+
+/// Checkout `target_branch` and merge the stage branch into it. Caller holds
+/// the merge lock.
+fn merge_stage_branch(
+    stage_id: &str,
+    target_branch: &str,
+    repo_root: &Path,
+) -> Result<MergeResult> {
     let branch_name = branch_name_for_stage(stage_id);
 
     // First, check that the branch exists
@@ -185,7 +232,7 @@ pub fn merge_stage(
 }
 
 /// Parse merge statistics from git output
-fn parse_merge_stats(output: &str) -> (u32, u32, u32) {
+pub(crate) fn parse_merge_stats(output: &str) -> (u32, u32, u32) {
     let mut files_changed = 0u32;
     let mut insertions = 0u32;
     let mut deletions = 0u32;
@@ -237,12 +284,10 @@ pub fn get_conflicting_files_from_status(
     work_dir: &Path,
 ) -> Result<Vec<String>> {
     // Acquire merge lock to prevent concurrent merge operations
-    let _lock = MergeLock::acquire(work_dir, Duration::from_secs(30)).map_err(|e| {
-        anyhow::anyhow!(
-            "Could not acquire merge lock: {}. Another merge may be in progress.",
-            e
-        )
-    })?;
+    let _lock = MergeLock::acquire_for(
+        work_dir,
+        &format!("conflict probe of {source_branch} into {target_branch}"),
+    )?;
 
     // Refuse if MERGE_HEAD already exists — running a probe merge here would
     // `git merge --abort` the active resolution and corrupt state.
@@ -500,6 +545,199 @@ mod tests {
         );
     }
 
+    #[test]
+    fn merge_stage_stashes_and_restores_local_changes() {
+        use tempfile::TempDir;
+
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path();
+
+        git_ok(root, &["init", "-b", "main"]);
+        git_ok(root, &["config", "user.email", "t@t.com"]);
+        git_ok(root, &["config", "user.name", "t"]);
+        std::fs::write(root.join("a.txt"), "seed").unwrap();
+        std::fs::write(root.join("notes.md"), "seed").unwrap();
+        git_ok(root, &["add", "."]);
+        git_ok(root, &["commit", "-m", "seed"]);
+
+        git_ok(root, &["checkout", "-b", "loom/feature"]);
+        std::fs::write(root.join("a.txt"), "feature").unwrap();
+        git_ok(root, &["commit", "-am", "feature"]);
+        git_ok(root, &["checkout", "main"]);
+
+        // An edit the merge does not touch, made while the run is in flight
+        std::fs::write(root.join("notes.md"), "local edit").unwrap();
+
+        let work_dir = root.join(".work");
+        std::fs::create_dir_all(&work_dir).unwrap();
+        let result = merge_stage("feature", "main", root, &work_dir).unwrap();
+        assert!(matches!(
+            result,
+            MergeResult::Success { .. } | MergeResult::FastForward
+        ));
+        assert_eq!(
+            std::fs::read_to_string(root.join("a.txt")).unwrap(),
+            "feature"
+        );
+        assert_eq!(
+            std::fs::read_to_string(root.join("notes.md")).unwrap(),
+            "local edit"
+        );
+        let stashes = run_git_checked(&["stash", "list"], root).unwrap();
+        assert!(stashes.trim().is_empty(), "stash must be popped: {stashes}");
+    }
+
+    #[test]
+    fn merge_stage_amends_changelog_entry_into_merge_commit() {
+        use crate::fs::work_dir::write_merge_config;
+        use crate::models::stage::Stage;
+        use tempfile::TempDir;
+
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path();
+
+        git_ok(root, &["init", "-b", "main"]);
+        git_ok(root, &["config", "user.email", "t@t.com"]);
+        git_ok(root, &["config", "user.name", "t"]);
+        std::fs::write(root.join(".gitignore"), ".work/\n").unwrap();
+        std::fs::write(root.join("a.txt"), "seed").unwrap();
+        git_ok(root, &["add", "."]);
+        git_ok(root, &["commit", "-m", "seed"]);
+
+        git_ok(root, &["checkout", "-b", "loom/feature"]);
+        std::fs::write(root.join("a.txt"), "feature").unwrap();
+        git_ok(root, &["commit", "-am", "feature"]);
+        git_ok(root, &["checkout", "main"]);
+        std::fs::write(root.join("b.txt"), "main").unwrap();
+        git_ok(root, &["add", "b.txt"]);
+        git_ok(root, &["commit", "-m", "main"]);
+
+        let work_dir = root.join(".work");
+        std::fs::create_dir_all(&work_dir).unwrap();
+        write_merge_config(
+            &work_dir,
+            &lock::MergeConfig {
+                changelog: Some("docs/CHANGELOG.md".to_string()),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let mut stage = Stage::new("Feature".to_string(), Some("Ship it".to_string()));
+        stage.id = "feature".to_string();
+        crate::verify::transitions::save_stage(&stage, &work_dir).unwrap();
+
+        let result = merge_stage("feature", "main", root, &work_dir).unwrap();
+        assert!(matches!(result, MergeResult::Success { .. }));
+
+        let changelog = std::fs::read_to_string(root.join("docs/CHANGELOG.md")).unwrap();
+        assert!(changelog.starts_with("# Changelog\n"), "{changelog}");
+        assert!(changelog.contains("## Feature (`feature`)"), "{changelog}");
+        assert!(changelog.contains("Ship it"), "{changelog}");
+
+        let head = run_git_checked(&["log", "-1", "--format=%P%n%s"], root).unwrap();
+        assert_eq!(head.lines().next().unwrap().split(' ').count(), 2, "{head}");
+        assert!(head.contains("Merge loom/feature into main"), "{head}");
+        let changed = run_git_checked(&["diff", "--name-only", "HEAD^1", "HEAD"], root).unwrap();
+        assert!(changed.contains("docs/CHANGELOG.md"), "{changed}");
+        let status = run_git_checked(&["status", "--porcelain"], root).unwrap();
+        assert!(status.trim().is_empty(), "{status}");
+    }
+
+    /// Repo with `loom/feature` and `main` both editing `a.txt`, a tracked
+    /// `CHANGELOG.md`, and `[merge] changelog` configured.
+    fn conflicting_changelog_repo(root: &Path) -> std::path::PathBuf {
+        use crate::fs::work_dir::write_merge_config;
+        use crate::models::stage::Stage;
+
+        git_ok(root, &["init", "-b", "main"]);
+        git_ok(root, &["config", "user.email", "t@t.com"]);
+        git_ok(root, &["config", "user.name", "t"]);
+        std::fs::write(root.join(".gitignore"), ".work/\n").unwrap();
+        std::fs::write(root.join("a.txt"), "seed").unwrap();
+        std::fs::write(root.join("CHANGELOG.md"), "# Changelog\n").unwrap();
+        git_ok(root, &["add", "."]);
+        git_ok(root, &["commit", "-m", "seed"]);
+
+        git_ok(root, &["checkout", "-b", "loom/feature"]);
+        std::fs::write(root.join("a.txt"), "feature").unwrap();
+        git_ok(root, &["commit", "-am", "feature"]);
+        git_ok(root, &["checkout", "main"]);
+        std::fs::write(root.join("a.txt"), "main").unwrap();
+        git_ok(root, &["commit", "-am", "main"]);
+
+        let work_dir = root.join(".work");
+        std::fs::create_dir_all(&work_dir).unwrap();
+        write_merge_config(
+            &work_dir,
+            &lock::MergeConfig {
+                changelog: Some("CHANGELOG.md".to_string()),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let mut stage = Stage::new("Feature".to_string(), None);
+        stage.id = "feature".to_string();
+        crate::verify::transitions::save_stage(&stage, &work_dir).unwrap();
+        work_dir
+    }
+
+    #[test]
+    fn changelog_entry_added_to_resolved_merge_commit() {
+        use tempfile::TempDir;
+
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path();
+        let work_dir = conflicting_changelog_repo(root);
+
+        let result = merge_stage("feature", "main", root, &work_dir).unwrap();
+        assert!(matches!(result, MergeResult::Conflict { .. }));
+
+        // Resolve by hand, as a resolver session does before `--resolved`
+        let _ = isolated_git(root, &["merge", "--no-ff", "loom/feature"]);
+        std::fs::write(root.join("a.txt"), "resolved").unwrap();
+        git_ok(root, &["commit", "-am", "Merge loom/feature into main"]);
+
+        assert!(changelog::record_merge("feature", "main", root, &work_dir).unwrap());
+        let changelog = std::fs::read_to_string(root.join("CHANGELOG.md")).unwrap();
+        assert!(changelog.contains("## Feature (`feature`)"), "{changelog}");
+        let parents = run_git_checked(&["log", "-1", "--format=%P"], root).unwrap();
+        assert_eq!(parents.split(' ').count(), 2, "{parents}");
+        let changed = run_git_checked(&["diff", "--name-only", "HEAD^1", "HEAD"], root).unwrap();
+        assert!(changed.contains("CHANGELOG.md"), "{changed}");
+        let status = run_git_checked(&["status", "--porcelain"], root).unwrap();
+        assert!(status.trim().is_empty(), "{status}");
+
+        // Confirming the same merge again does not add a second entry
+        assert!(!changelog::record_merge("feature", "main", root, &work_dir).unwrap());
+    }
+
+    #[test]
+    fn changelog_restored_when_amend_fails() {
+        use std::os::unix::fs::PermissionsExt;
+        use tempfile::TempDir;
+
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path();
+        let work_dir = conflicting_changelog_repo(root);
+
+        let _ = isolated_git(root, &["merge", "--no-ff", "loom/feature"]);
+        std::fs::write(root.join("a.txt"), "resolved").unwrap();
+        git_ok(root, &["commit", "-am", "Merge loom/feature into main"]);
+
+        // `--no-verify` does not skip prepare-commit-msg, so this fails the amend
+        let hook = root.join(".git/hooks/prepare-commit-msg");
+        std::fs::write(&hook, "#!/bin/sh\nexit 1\n").unwrap();
+        std::fs::set_permissions(&hook, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        assert!(changelog::record_merge("feature", "main", root, &work_dir).is_err());
+        assert_eq!(
+            std::fs::read_to_string(root.join("CHANGELOG.md")).unwrap(),
+            "# Changelog\n"
+        );
+        let status = run_git_checked(&["status", "--porcelain"], root).unwrap();
+        assert!(status.trim().is_empty(), "{status}");
+    }
+
     #[test]
     fn get_conflicting_files_from_status_refuses_when_merge_head_set() {
         use tempfile::TempDir;
@@ -0,0 +1,138 @@
+//! Merge conflict prediction without touching the working tree.
+//!
+//! Unlike [`super::get_conflicting_files_from_status`], which performs a test
+//! merge in the main repository under the merge lock, these helpers use
+//! `git merge-tree --write-tree` (git 2.38+) to compute the merge entirely in
+//! the object database. They are safe to run while another stage is merging.
+
+use std::path::Path;
+
+use crate::git::runner::{run_git, run_git_checked};
+
+/// Predicted outcome of merging a branch into a target
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConflictPrediction {
+    /// The branch merges cleanly
+    Clean,
+    /// The merge would conflict in these files
+    Conflicts(Vec<String>),
+    /// The prediction could not be made (old git, missing ref, ...)
+    Unknown,
+}
+
+/// Predict whether merging `branch` into `target` would conflict.
+pub fn predict_merge_conflicts(branch: &str, target: &str, repo_root: &Path) -> ConflictPrediction {
+    let output = match run_git(
+        &[
+            "merge-tree",
+            "--write-tree",
+            "--name-only",
+            "--no-messages",
+            target,
+            branch,
+        ],
+        repo_root,
+    ) {
+        Ok(output) => output,
+        Err(_) => return ConflictPrediction::Unknown,
+    };
+
+    match output.status.code() {
+        Some(0) => ConflictPrediction::Clean,
+        Some(1) => {
+            // First line is the tree OID; the conflicted paths follow.
+            // An unresolvable ref also exits 1, but without printing a tree.
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            let mut lines = stdout.lines();
+            let is_tree = lines
+                .next()
+                .is_some_and(|oid| !oid.is_empty() && oid.chars().all(|c| c.is_ascii_hexdigit()));
+            if !is_tree {
+                return ConflictPrediction::Unknown;
+            }
+            let mut files: Vec<String> = lines
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(String::from)
+                .collect();
+            files.dedup();
+            ConflictPrediction::Conflicts(files)
+        }
+        _ => ConflictPrediction::Unknown,
+    }
+}
+
+/// Files `branch` changed since it diverged from `target`.
+pub fn branch_changed_files(branch: &str, target: &str, repo_root: &Path) -> Vec<String> {
+    run_git_checked(
+        &["diff", "--name-only", &format!("{target}...{branch}")],
+        repo_root,
+    )
+    .map(|stdout| stdout.lines().map(String::from).collect())
+    .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    fn git(root: &Path, args: &[&str]) {
+        let out = Command::new("git")
+            .args(args)
+            .current_dir(root)
+            .env("GIT_CONFIG_GLOBAL", root.join(".loom-test-no-global"))
+            .env("GIT_CONFIG_NOSYSTEM", "1")
+            .output()
+            .unwrap();
+        assert!(
+            out.status.success(),
+            "git {args:?} failed: {}",
+            String::from_utf8_lossy(&out.stderr)
+        );
+    }
+
+    fn commit_file(root: &Path, path: &str, content: &str) {
+        fs::write(root.join(path), content).unwrap();
+        git(root, &["add", path]);
+        git(root, &["commit", "-m", path]);
+    }
+
+    #[test]
+    fn test_predict_merge_conflicts_and_changed_files() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        git(root, &["init", "-b", "main"]);
+        git(root, &["config", "user.email", "t@t.com"]);
+        git(root, &["config", "user.name", "t"]);
+        commit_file(root, "shared.rs", "fn a() {}\n");
+
+        git(root, &["checkout", "-b", "loom/clean"]);
+        commit_file(root, "clean.rs", "fn c() {}\n");
+        git(root, &["checkout", "main"]);
+        git(root, &["checkout", "-b", "loom/clash"]);
+        commit_file(root, "shared.rs", "fn b() {}\n");
+        git(root, &["checkout", "main"]);
+        commit_file(root, "shared.rs", "fn main_side() {}\n");
+
+        assert_eq!(
+            predict_merge_conflicts("loom/clean", "main", root),
+            ConflictPrediction::Clean
+        );
+        assert_eq!(
+            predict_merge_conflicts("loom/clash", "main", root),
+            ConflictPrediction::Conflicts(vec!["shared.rs".to_string()])
+        );
+        assert_eq!(
+            predict_merge_conflicts("loom/missing", "main", root),
+            ConflictPrediction::Unknown
+        );
+        assert_eq!(
+            branch_changed_files("loom/clash", "main", root),
+            vec!["shared.rs"]
+        );
+        assert!(branch_changed_files("loom/missing", "main", root).is_empty());
+    }
+}
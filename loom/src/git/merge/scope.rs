@@ -0,0 +1,276 @@
+//! Edit scope for merge conflict resolution sessions.
+//!
+//! When the daemon spawns a merge resolution session for a stage with known
+//! conflicting files, it records them in `.work/merge-scope/<stage-id>.json`.
+//! The `merge-scope-guard.sh` hook blocks Edit/Write calls from that session
+//! to any other file except tests, and merge completion refuses a resolution
+//! commit that changed files outside the scope. This keeps a resolver from
+//! "improving" unrelated code while it has the main repository checked out.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::git::runner::run_git_checked;
+
+/// Directory under `.work/` holding one scope file per stage.
+pub const MERGE_SCOPE_DIR: &str = "merge-scope";
+
+/// Files a merge resolution session may edit.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MergeScope {
+    pub stage_id: String,
+    pub session_id: String,
+    pub created_at: DateTime<Utc>,
+    /// Conflicting files, relative to the repository root
+    pub files: Vec<String>,
+}
+
+impl MergeScope {
+    pub fn new(stage_id: &str, session_id: &str, files: &[String]) -> Self {
+        Self {
+            stage_id: stage_id.to_string(),
+            session_id: session_id.to_string(),
+            created_at: Utc::now(),
+            files: files.to_vec(),
+        }
+    }
+
+    /// Whether the resolution may change `path`: a conflicting file or a test.
+    pub fn allows(&self, path: &str) -> bool {
+        self.files.iter().any(|f| f == path) || is_test_path(path)
+    }
+}
+
+/// Whether `path` looks like a test file or lives in a test directory.
+pub fn is_test_path(path: &str) -> bool {
+    let in_test_dir = path.split('/').rev().skip(1).any(|dir| {
+        matches!(
+            dir,
+            "test" | "tests" | "__tests__" | "spec" | "specs" | "testdata" | "fixtures"
+        )
+    });
+    if in_test_dir {
+        return true;
+    }
+    let name = path.rsplit('/').next().unwrap_or(path);
+    let stem = name.split('.').next().unwrap_or(name);
+    stem.starts_with("test_")
+        || stem.ends_with("_test")
+        || stem.ends_with("_tests")
+        || stem.ends_with("_spec")
+        || name.contains(".test.")
+        || name.contains(".spec.")
+}
+
+fn scope_path(work_dir: &Path, stage_id: &str) -> PathBuf {
+    work_dir
+        .join(MERGE_SCOPE_DIR)
+        .join(format!("{stage_id}.json"))
+}
+
+/// Record the edit scope for a stage's merge resolution session.
+pub fn write_merge_scope(work_dir: &Path, scope: &MergeScope) -> Result<()> {
+    let path = scope_path(work_dir, &scope.stage_id);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    let json = serde_json::to_string_pretty(scope).context("Failed to serialize merge scope")?;
+    fs::write(&path, json).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// Read a stage's merge scope, or `None` when its resolution is unrestricted.
+pub fn read_merge_scope(work_dir: &Path, stage_id: &str) -> Option<MergeScope> {
+    let content = fs::read_to_string(scope_path(work_dir, stage_id)).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Remove a stage's merge scope.
+pub fn clear_merge_scope(work_dir: &Path, stage_id: &str) -> Result<()> {
+    let path = scope_path(work_dir, stage_id);
+    if path.exists() {
+        fs::remove_file(&path).with_context(|| format!("Failed to remove {}", path.display()))?;
+    }
+    Ok(())
+}
+
+/// The merge commit on `target` that brought `stage_commit` in, if any.
+///
+/// The oldest merge on the ancestry path from the stage commit to the target
+/// is the one that merged it; a fast-forward leaves none.
+pub fn find_resolution_commit(
+    stage_commit: &str,
+    target: &str,
+    repo_root: &Path,
+) -> Result<Option<String>> {
+    let range = format!("{stage_commit}..{target}");
+    let output = run_git_checked(
+        &[
+            "rev-list",
+            "--merges",
+            "--ancestry-path",
+            "--reverse",
+            &range,
+        ],
+        repo_root,
+    )?;
+    Ok(output.lines().next().map(|line| line.trim().to_string()))
+}
+
+/// Files the resolution of `merge_commit` changed relative to every parent.
+///
+/// These are the paths where the merge result is not simply one side's
+/// version: the resolved conflicts plus any other edit made in the merge.
+pub fn resolution_changed_files(merge_commit: &str, repo_root: &Path) -> Result<Vec<String>> {
+    let output = run_git_checked(
+        &[
+            "diff-tree",
+            "-c",
+            "--name-only",
+            "--no-commit-id",
+            "-r",
+            merge_commit,
+        ],
+        repo_root,
+    )?;
+    Ok(output
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(String::from)
+        .collect())
+}
+
+/// Files the stage's resolution commit changed outside its merge scope.
+///
+/// Empty when the stage has no scope or was merged without a merge commit.
+pub fn out_of_scope_changes(
+    work_dir: &Path,
+    stage_id: &str,
+    stage_commit: &str,
+    target: &str,
+    repo_root: &Path,
+) -> Result<Vec<String>> {
+    let Some(scope) = read_merge_scope(work_dir, stage_id) else {
+        return Ok(Vec::new());
+    };
+    let Some(merge_commit) = find_resolution_commit(stage_commit, target, repo_root)? else {
+        return Ok(Vec::new());
+    };
+    Ok(resolution_changed_files(&merge_commit, repo_root)?
+        .into_iter()
+        .filter(|path| !scope.allows(path))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    fn git(root: &Path, args: &[&str]) {
+        let out = Command::new("git")
+            .args(args)
+            .current_dir(root)
+            .env("GIT_CONFIG_GLOBAL", root.join(".loom-test-no-global"))
+            .env("GIT_CONFIG_NOSYSTEM", "1")
+            .output()
+            .unwrap();
+        assert!(
+            out.status.success(),
+            "git {args:?} failed: {}",
+            String::from_utf8_lossy(&out.stderr)
+        );
+    }
+
+    fn head(root: &Path, rev: &str) -> String {
+        run_git_checked(&["rev-parse", rev], root)
+            .unwrap()
+            .trim()
+            .to_string()
+    }
+
+    #[test]
+    fn test_is_test_path() {
+        assert!(is_test_path("tests/integration.rs"));
+        assert!(is_test_path("src/__tests__/app.js"));
+        assert!(is_test_path("pkg/parser_test.go"));
+        assert!(is_test_path("src/app.test.ts"));
+        assert!(is_test_path("test_utils.py"));
+        assert!(!is_test_path("src/lib.rs"));
+        assert!(!is_test_path("src/testing.rs"));
+        assert!(!is_test_path("tests"));
+    }
+
+    #[test]
+    fn test_scope_round_trip_and_allows() {
+        let temp = TempDir::new().unwrap();
+        assert!(read_merge_scope(temp.path(), "api").is_none());
+
+        let scope = MergeScope::new("api", "session-1", &["src/lib.rs".to_string()]);
+        write_merge_scope(temp.path(), &scope).unwrap();
+        let read = read_merge_scope(temp.path(), "api").unwrap();
+        assert_eq!(read, scope);
+        assert!(read.allows("src/lib.rs"));
+        assert!(read.allows("tests/lib.rs"));
+        assert!(!read.allows("src/main.rs"));
+
+        clear_merge_scope(temp.path(), "api").unwrap();
+        assert!(read_merge_scope(temp.path(), "api").is_none());
+    }
+
+    #[test]
+    fn test_out_of_scope_changes_in_resolution_commit() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        git(root, &["init", "-b", "main"]);
+        git(root, &["config", "user.email", "t@t.com"]);
+        git(root, &["config", "user.name", "t"]);
+        fs::write(root.join("a.txt"), "seed\n").unwrap();
+        fs::write(root.join("b.txt"), "seed\n").unwrap();
+        git(root, &["add", "."]);
+        git(root, &["commit", "-m", "seed"]);
+
+        git(root, &["checkout", "-b", "loom/api"]);
+        fs::write(root.join("a.txt"), "branch\n").unwrap();
+        git(root, &["commit", "-am", "branch"]);
+        let stage_commit = head(root, "HEAD");
+
+        git(root, &["checkout", "main"]);
+        fs::write(root.join("a.txt"), "main\n").unwrap();
+        git(root, &["commit", "-am", "main"]);
+
+        // Resolve the conflict in a.txt and sneak an edit into b.txt
+        let _ = Command::new("git")
+            .args(["merge", "loom/api"])
+            .current_dir(root)
+            .output()
+            .unwrap();
+        fs::write(root.join("a.txt"), "resolved\n").unwrap();
+        fs::write(root.join("b.txt"), "drive-by\n").unwrap();
+        git(root, &["add", "a.txt", "b.txt"]);
+        git(root, &["commit", "--no-edit"]);
+
+        let work_dir = root.join(".work");
+        assert!(
+            out_of_scope_changes(&work_dir, "api", &stage_commit, "main", root)
+                .unwrap()
+                .is_empty(),
+            "no scope recorded means no restriction"
+        );
+
+        write_merge_scope(
+            &work_dir,
+            &MergeScope::new("api", "session-1", &["a.txt".to_string()]),
+        )
+        .unwrap();
+        assert_eq!(
+            out_of_scope_changes(&work_dir, "api", &stage_commit, "main", root).unwrap(),
+            vec!["b.txt".to_string()]
+        );
+    }
+}
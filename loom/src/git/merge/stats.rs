@@ -0,0 +1,383 @@
+//! Merge conflict statistics.
+//!
+//! Every merge conflict the daemon hands to a resolver is recorded in
+//! `.work/merge-stats.json`: the conflicting files, the stages whose merged
+//! work the branch collided with, and when the conflict was detected and
+//! resolved. `loom report conflicts` aggregates the records into hotspot
+//! files and conflicting stage pairs, to guide `files` partitioning in
+//! future plans.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+use crate::fs::locking::locked_update;
+use crate::git::branch::{is_base_branch, stage_id_from_branch};
+use crate::git::runner::{run_git, run_git_checked};
+
+/// File under `.work/` holding the conflict records.
+pub const MERGE_STATS_FILE: &str = "merge-stats.json";
+
+/// One merge conflict of a stage branch with the merge target.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConflictRecord {
+    pub stage_id: String,
+    /// Conflicting files, relative to the repository root
+    pub files: Vec<String>,
+    /// Stages whose merged work changed the conflicting files
+    #[serde(default)]
+    pub counterparts: Vec<String>,
+    pub detected_at: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolved_at: Option<DateTime<Utc>>,
+}
+
+impl ConflictRecord {
+    /// Seconds from detection to resolution, once resolved.
+    pub fn resolution_secs(&self) -> Option<i64> {
+        self.resolved_at
+            .map(|resolved| (resolved - self.detected_at).num_seconds())
+    }
+}
+
+/// All recorded conflicts, oldest first.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MergeStats {
+    #[serde(default)]
+    pub conflicts: Vec<ConflictRecord>,
+}
+
+fn stats_path(work_dir: &Path) -> PathBuf {
+    work_dir.join(MERGE_STATS_FILE)
+}
+
+/// Read the conflict records; none when nothing was recorded yet.
+pub fn load_merge_stats(work_dir: &Path) -> Result<MergeStats> {
+    let path = stats_path(work_dir);
+    if !path.exists() {
+        return Ok(MergeStats::default());
+    }
+    let content = std::fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    serde_json::from_str(&content).with_context(|| format!("Failed to parse {}", path.display()))
+}
+
+fn update_merge_stats(work_dir: &Path, modify: impl FnOnce(&mut MergeStats)) -> Result<()> {
+    let path = stats_path(work_dir);
+    locked_update(&path, |existing| {
+        let mut stats: MergeStats = if existing.trim().is_empty() {
+            MergeStats::default()
+        } else {
+            serde_json::from_str(&existing)
+                .with_context(|| format!("Failed to parse {}", path.display()))?
+        };
+        modify(&mut stats);
+        serde_json::to_string_pretty(&stats).context("Failed to serialize merge stats")
+    })
+}
+
+/// Record a conflict of `stage_id`. A conflict already open for the stage
+/// (a resolver being respawned) is updated instead of counted again.
+pub fn record_conflict(
+    work_dir: &Path,
+    stage_id: &str,
+    files: &[String],
+    counterparts: &[String],
+) -> Result<()> {
+    update_merge_stats(work_dir, |stats| {
+        let open = stats
+            .conflicts
+            .iter_mut()
+            .rev()
+            .find(|c| c.stage_id == stage_id && c.resolved_at.is_none());
+        match open {
+            Some(record) => {
+                if !files.is_empty() {
+                    record.files = files.to_vec();
+                }
+                if !counterparts.is_empty() {
+                    record.counterparts = counterparts.to_vec();
+                }
+            }
+            None => stats.conflicts.push(ConflictRecord {
+                stage_id: stage_id.to_string(),
+                files: files.to_vec(),
+                counterparts: counterparts.to_vec(),
+                detected_at: Utc::now(),
+                resolved_at: None,
+            }),
+        }
+    })
+}
+
+/// Mark the open conflict of `stage_id` resolved, if there is one.
+pub fn record_resolution(work_dir: &Path, stage_id: &str) -> Result<()> {
+    if !stats_path(work_dir).exists() {
+        return Ok(());
+    }
+    update_merge_stats(work_dir, |stats| {
+        if let Some(record) = stats
+            .conflicts
+            .iter_mut()
+            .rev()
+            .find(|c| c.stage_id == stage_id && c.resolved_at.is_none())
+        {
+            record.resolved_at = Some(Utc::now());
+        }
+    })
+}
+
+/// Stages whose merges into `target` since `source` branched off changed any
+/// of `files`, found from the subjects of loom's merge commits.
+pub fn conflict_counterparts(
+    source: &str,
+    target: &str,
+    files: &[String],
+    repo_root: &Path,
+) -> Result<Vec<String>> {
+    if files.is_empty() {
+        return Ok(Vec::new());
+    }
+    let base = run_git_checked(&["merge-base", source, target], repo_root)?;
+    let range = format!("{}..{target}", base.trim());
+    let mut args = vec![
+        "log",
+        "--first-parent",
+        "--merges",
+        "--format=%s",
+        range.as_str(),
+        "--",
+    ];
+    args.extend(files.iter().map(String::as_str));
+    let output = run_git(&args, repo_root)?;
+    if !output.status.success() {
+        return Ok(Vec::new());
+    }
+
+    let source_stage = stage_id_from_branch(source);
+    let stages: BTreeSet<String> = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(merged_branch)
+        .filter(|branch| !is_base_branch(branch))
+        .filter_map(|branch| stage_id_from_branch(&branch))
+        .filter(|stage| Some(stage) != source_stage.as_ref())
+        .collect();
+    Ok(stages.into_iter().collect())
+}
+
+/// Branch named by a merge commit subject: loom's `Merge <branch> into
+/// <target>` or git's default `Merge branch '<branch>' [into <target>]`.
+fn merged_branch(subject: &str) -> Option<String> {
+    let rest = subject.strip_prefix("Merge ")?;
+    let branch = match rest.strip_prefix("branch '") {
+        Some(quoted) => quoted.split('\'').next()?,
+        None => rest.split_whitespace().next()?,
+    };
+    Some(branch.to_string())
+}
+
+/// How often a file conflicted, and in which stages.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FileHotspot {
+    pub file: String,
+    pub conflicts: usize,
+    pub stages: Vec<String>,
+}
+
+/// How often two stages conflicted with each other.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StagePair {
+    pub stages: [String; 2],
+    pub conflicts: usize,
+    pub files: Vec<String>,
+}
+
+/// Aggregated view of the conflict records.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ConflictSummary {
+    pub total: usize,
+    pub resolved: usize,
+    /// Mean detection-to-resolution time of resolved conflicts
+    pub mean_resolution_secs: Option<i64>,
+    /// Files by conflict count, most first
+    pub hotspots: Vec<FileHotspot>,
+    /// Stage pairs by conflict count, most first
+    pub pairs: Vec<StagePair>,
+}
+
+impl MergeStats {
+    pub fn summarize(&self) -> ConflictSummary {
+        let mut files: BTreeMap<&str, (usize, BTreeSet<&str>)> = BTreeMap::new();
+        let mut pairs: BTreeMap<[&str; 2], (usize, BTreeSet<&str>)> = BTreeMap::new();
+        for record in &self.conflicts {
+            for file in &record.files {
+                let entry = files.entry(file).or_default();
+                entry.0 += 1;
+                entry.1.insert(&record.stage_id);
+                entry
+                    .1
+                    .extend(record.counterparts.iter().map(String::as_str));
+            }
+            for other in &record.counterparts {
+                let mut pair = [record.stage_id.as_str(), other.as_str()];
+                pair.sort();
+                let entry = pairs.entry(pair).or_default();
+                entry.0 += 1;
+                entry.1.extend(record.files.iter().map(String::as_str));
+            }
+        }
+
+        let mut hotspots: Vec<FileHotspot> = files
+            .into_iter()
+            .map(|(file, (conflicts, stages))| FileHotspot {
+                file: file.to_string(),
+                conflicts,
+                stages: stages.into_iter().map(String::from).collect(),
+            })
+            .collect();
+        hotspots.sort_by_key(|h| std::cmp::Reverse(h.conflicts));
+
+        let mut pairs: Vec<StagePair> = pairs
+            .into_iter()
+            .map(|([a, b], (conflicts, files))| StagePair {
+                stages: [a.to_string(), b.to_string()],
+                conflicts,
+                files: files.into_iter().map(String::from).collect(),
+            })
+            .collect();
+        pairs.sort_by_key(|p| std::cmp::Reverse(p.conflicts));
+
+        let durations: Vec<i64> = self
+            .conflicts
+            .iter()
+            .filter_map(ConflictRecord::resolution_secs)
+            .collect();
+        ConflictSummary {
+            total: self.conflicts.len(),
+            resolved: durations.len(),
+            mean_resolution_secs: (!durations.is_empty())
+                .then(|| durations.iter().sum::<i64>() / durations.len() as i64),
+            hotspots,
+            pairs,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    fn git(root: &Path, args: &[&str]) {
+        let out = Command::new("git")
+            .args(args)
+            .current_dir(root)
+            .env("GIT_CONFIG_GLOBAL", root.join(".loom-test-no-global"))
+            .env("GIT_CONFIG_NOSYSTEM", "1")
+            .output()
+            .unwrap();
+        assert!(
+            out.status.success(),
+            "git {args:?} failed: {}",
+            String::from_utf8_lossy(&out.stderr)
+        );
+    }
+
+    fn record(stage: &str, files: &[&str], counterparts: &[&str]) -> ConflictRecord {
+        ConflictRecord {
+            stage_id: stage.to_string(),
+            files: files.iter().map(|f| f.to_string()).collect(),
+            counterparts: counterparts.iter().map(|s| s.to_string()).collect(),
+            detected_at: Utc::now(),
+            resolved_at: None,
+        }
+    }
+
+    #[test]
+    fn test_record_conflict_and_resolution() {
+        let temp = TempDir::new().unwrap();
+        let files = vec!["src/lib.rs".to_string()];
+        record_conflict(temp.path(), "api", &files, &[]).unwrap();
+        // A respawned resolver updates the open record
+        record_conflict(temp.path(), "api", &files, &["db".to_string()]).unwrap();
+
+        let stats = load_merge_stats(temp.path()).unwrap();
+        assert_eq!(stats.conflicts.len(), 1);
+        assert_eq!(stats.conflicts[0].counterparts, vec!["db"]);
+        assert!(stats.conflicts[0].resolution_secs().is_none());
+
+        record_resolution(temp.path(), "api").unwrap();
+        record_conflict(temp.path(), "api", &files, &[]).unwrap();
+        let stats = load_merge_stats(temp.path()).unwrap();
+        assert_eq!(stats.conflicts.len(), 2);
+        assert!(stats.conflicts[0].resolution_secs().is_some());
+        assert!(stats.conflicts[1].resolved_at.is_none());
+    }
+
+    #[test]
+    fn test_summarize_ranks_hotspots_and_pairs() {
+        let stats = MergeStats {
+            conflicts: vec![
+                record("api", &["src/routes.rs", "Cargo.toml"], &["db"]),
+                record("web", &["Cargo.toml"], &["api"]),
+                record("db", &["Cargo.toml"], &["api"]),
+            ],
+        };
+        let summary = stats.summarize();
+        assert_eq!(summary.total, 3);
+        assert_eq!(summary.resolved, 0);
+        assert_eq!(summary.hotspots[0].file, "Cargo.toml");
+        assert_eq!(summary.hotspots[0].conflicts, 3);
+        assert_eq!(summary.hotspots[0].stages, vec!["api", "db", "web"]);
+        assert_eq!(
+            summary.pairs[0].stages,
+            ["api".to_string(), "db".to_string()]
+        );
+        assert_eq!(summary.pairs[0].conflicts, 2);
+    }
+
+    #[test]
+    fn test_merged_branch() {
+        assert_eq!(
+            merged_branch("Merge loom/api into main").as_deref(),
+            Some("loom/api")
+        );
+        assert_eq!(
+            merged_branch("Merge branch 'loom/db' into main").as_deref(),
+            Some("loom/db")
+        );
+        assert_eq!(merged_branch("Fix typo"), None);
+    }
+
+    #[test]
+    fn test_conflict_counterparts_from_merge_commits() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        git(root, &["init", "-b", "main"]);
+        git(root, &["config", "user.email", "t@t.com"]);
+        git(root, &["config", "user.name", "t"]);
+        fs::write(root.join("shared.txt"), "seed\n").unwrap();
+        fs::write(root.join("other.txt"), "seed\n").unwrap();
+        git(root, &["add", "."]);
+        git(root, &["commit", "-m", "seed"]);
+        git(root, &["branch", "loom/api"]);
+
+        for (stage, file) in [("db", "shared.txt"), ("docs", "other.txt")] {
+            let branch = format!("loom/{stage}");
+            git(root, &["checkout", "-b", &branch]);
+            fs::write(root.join(file), format!("{stage}\n")).unwrap();
+            git(root, &["commit", "-am", stage]);
+            git(root, &["checkout", "main"]);
+            let msg = format!("Merge {branch} into main");
+            git(root, &["merge", "--no-ff", "-m", &msg, &branch]);
+        }
+
+        let counterparts =
+            conflict_counterparts("loom/api", "main", &["shared.txt".to_string()], root).unwrap();
+        assert_eq!(counterparts, vec!["db"]);
+    }
+}
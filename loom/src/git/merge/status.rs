@@ -222,6 +222,8 @@ mod tests {
             worktree: None,
             session: None,
             held: false,
+            held_by: None,
+            assignee: None,
             parent_stage: None,
             child_stages: vec![],
             created_at: Utc::now(),
@@ -261,12 +263,28 @@ mod tests {
             execution_mode: None,
             max_fix_attempts: None,
             review_reason: None,
+            waiting_prompt: None,
+            waiting_since: None,
             bug_fix: None,
             regression_test: None,
             model: None,
             reasoning_effort: None,
             is_possibly_stuck: false,
             ultracode: false,
+            pair: false,
+            pair_review: None,
+            worktree_setup: Vec::new(),
+            instructions: None,
+            prompt_prefix: None,
+            container: None,
+            keep_terminal: None,
+            expected_outputs: Vec::new(),
+            params: Vec::new(),
+            requires_outputs: Vec::new(),
+            new_files: Vec::new(),
+            expected_duration_secs: None,
+            acceptance_isolation: None,
+            skip_if_satisfied: false,
         }
     }
 
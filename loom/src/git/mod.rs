@@ -6,13 +6,17 @@
 //! - Merge operations for integrating completed work
 //! - Cleanup utilities for successful merges
 //! - Git hook installation for .work protection
+//! - A [`vcs::Vcs`] backend trait, so Jujutsu repositories can be driven
+//!   with jj instead of git
 
 pub mod branch;
 pub mod cleanup;
+pub mod dirty;
 pub mod hooks;
 pub mod merge;
 pub mod repository;
 pub mod runner;
+pub mod vcs;
 pub mod worktree;
 
 // Re-export commonly used types and functions
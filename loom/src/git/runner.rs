@@ -20,6 +20,7 @@ use std::process::{Command, Output};
 /// * `args` - Git command arguments (e.g., `&["branch", "-v"]`)
 /// * `repo_root` - Working directory for the git command
 pub fn run_git(args: &[&str], repo_root: &Path) -> Result<Output> {
+    tracing::debug!(dir = %repo_root.display(), "git {}", args.join(" "));
     Command::new("git")
         .args(args)
         .env("LC_ALL", "C")
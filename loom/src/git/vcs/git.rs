@@ -0,0 +1,59 @@
+//! The git backend: the worktree, branch and merge operations of
+//! [`crate::git`].
+
+use anyhow::Result;
+use std::path::Path;
+
+use super::{Vcs, VcsKind};
+use crate::git::merge::MergeResult;
+use crate::models::worktree::Worktree;
+
+/// Stage work in `git worktree`s on `loom/<stage-id>` branches.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GitBackend;
+
+impl Vcs for GitBackend {
+    fn kind(&self) -> VcsKind {
+        VcsKind::Git
+    }
+
+    fn get_or_create_workspace(
+        &self,
+        stage_id: &str,
+        repo_root: &Path,
+        base_branch: Option<&str>,
+        sparse: Option<&[String]>,
+    ) -> Result<Worktree> {
+        crate::git::get_or_create_worktree(stage_id, repo_root, base_branch, sparse)
+    }
+
+    fn remove_workspace(&self, stage_id: &str, repo_root: &Path, force: bool) -> Result<()> {
+        crate::git::remove_worktree(stage_id, repo_root, force)
+    }
+
+    fn branch_exists(&self, name: &str, repo_root: &Path) -> Result<bool> {
+        crate::git::branch_exists(name, repo_root)
+    }
+
+    fn create_branch(&self, name: &str, base: Option<&str>, repo_root: &Path) -> Result<()> {
+        crate::git::create_branch(name, base, repo_root)
+    }
+
+    fn delete_branch(&self, name: &str, force: bool, repo_root: &Path) -> Result<()> {
+        crate::git::delete_branch(name, force, repo_root)
+    }
+
+    fn merge_stage(
+        &self,
+        stage_id: &str,
+        target_branch: &str,
+        repo_root: &Path,
+        work_dir: &Path,
+    ) -> Result<MergeResult> {
+        crate::git::merge_stage(stage_id, target_branch, repo_root, work_dir)
+    }
+
+    fn conflicting_files(&self, repo_root: &Path) -> Result<Vec<String>> {
+        crate::git::get_conflicting_files(repo_root)
+    }
+}
@@ -0,0 +1,371 @@
+//! The Jujutsu backend, for jj repositories colocated with git.
+//!
+//! - Each stage gets a `jj workspace` named after its window title
+//!   (`loom-<stage-id>`) at the usual worktree path, and a bookmark named
+//!   like the git stage branch (`loom/<stage-id>`), so the git-facing parts
+//!   of loom find the stage branch as usual.
+//! - jj snapshots a workspace whenever a jj command runs in it, so the
+//!   stage bookmark is moved to the workspace's latest change just before a
+//!   merge, whether or not the agent committed.
+//! - A merge creates a merge commit of the target bookmark and the stage
+//!   bookmark with `jj new --no-edit` and moves the target bookmark to it.
+//!   The main workspace's working copy is left where it is; run
+//!   `jj new <target>` there to continue on top of the merged work.
+//!   A conflicted merge commit is abandoned and reported as a conflict.
+//!   A `[merge] changelog` entry is written in the stage's workspace on top
+//!   of the merge and squashed into it.
+//! - Removing a workspace forgets it and deletes the directory. The
+//!   workspace's changes stay in the repository as commits, so nothing is
+//!   lost even when removal is forced.
+//! - Sparse checkout profiles are not applied to jj workspaces.
+
+use anyhow::{bail, Context, Result};
+use std::path::Path;
+use std::process::{Command, Output};
+
+use super::{Vcs, VcsKind};
+use crate::fs::permissions::{trust_worktree, untrust_worktree};
+use crate::git::branch::branch_name_for_stage;
+use crate::git::merge::changelog;
+use crate::git::merge::lock::MergeLock;
+use crate::git::merge::{parse_merge_stats, MergeResult};
+use crate::git::worktree::{
+    cleanup_worktree_settings, ensure_work_symlink, get_worktree_path, setup_claude_directory,
+    setup_root_claude_md,
+};
+use crate::models::worktree::Worktree;
+use crate::validation::validate_id;
+
+/// Run a jj command in `dir` and return the raw output.
+///
+/// Color and pagination are off so output can be parsed.
+pub fn run_jj(args: &[&str], dir: &Path) -> Result<Output> {
+    tracing::debug!(dir = %dir.display(), "jj {}", args.join(" "));
+    Command::new("jj")
+        .args(args)
+        .args(["--color", "never", "--no-pager"])
+        .current_dir(dir)
+        .output()
+        .with_context(|| format!("Failed to execute: jj {}", args.join(" ")))
+}
+
+/// Run a jj command, check for success, and return stdout trimmed.
+pub fn run_jj_checked(args: &[&str], dir: &Path) -> Result<String> {
+    let output = run_jj(args, dir)?;
+    if !output.status.success() {
+        let exit_code = output
+            .status
+            .code()
+            .map(|c| c.to_string())
+            .unwrap_or_else(|| "signal".to_string());
+        bail!(
+            "jj {} failed (exit code {exit_code}):\n\
+             Command: jj {}\n\
+             Directory: {}\n\
+             Stderr: {}",
+            args.first().unwrap_or(&""),
+            args.join(" "),
+            dir.display(),
+            String::from_utf8_lossy(&output.stderr).trim(),
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Stage work in jj workspaces with one bookmark per stage.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JujutsuBackend;
+
+impl Vcs for JujutsuBackend {
+    fn kind(&self) -> VcsKind {
+        VcsKind::Jujutsu
+    }
+
+    fn get_or_create_workspace(
+        &self,
+        stage_id: &str,
+        repo_root: &Path,
+        base_branch: Option<&str>,
+        sparse: Option<&[String]>,
+    ) -> Result<Worktree> {
+        validate_id(stage_id).context("Invalid stage ID for workspace")?;
+
+        let path = get_worktree_path(stage_id, repo_root);
+        let branch_name = branch_name_for_stage(stage_id);
+        if path.join(".jj").exists() {
+            let mut worktree = Worktree::new(stage_id.to_string(), path, branch_name);
+            worktree.mark_active();
+            return Ok(worktree);
+        }
+        if path.exists() {
+            std::fs::remove_dir_all(&path).with_context(|| {
+                format!("Failed to remove stale workspace dir: {}", path.display())
+            })?;
+        }
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).with_context(|| {
+                format!(
+                    "Failed to create workspaces directory: {}",
+                    parent.display()
+                )
+            })?;
+        }
+        if sparse.is_some() {
+            eprintln!("Note: sparse checkout is not applied to jj workspace for '{stage_id}'");
+        }
+
+        // A surviving stage bookmark holds earlier work; continue on top of it
+        let existing = self.branch_exists(&branch_name, repo_root)?;
+        let base = if existing {
+            quote(&branch_name)
+        } else {
+            base_branch.map_or_else(|| "@-".to_string(), quote)
+        };
+        let name = workspace_name(stage_id);
+        let path_str = path.to_string_lossy().to_string();
+        run_jj_checked(
+            &["workspace", "add", "--name", &name, "-r", &base, &path_str],
+            repo_root,
+        )?;
+        if !existing {
+            let parent = format!("{}@-", quote(&name));
+            self.create_branch(&branch_name, Some(&parent), repo_root)?;
+        }
+
+        ensure_work_symlink(&path, repo_root)?;
+        setup_claude_directory(&path, repo_root)?;
+        setup_root_claude_md(&path, repo_root)?;
+        if let Err(e) = trust_worktree(&path) {
+            eprintln!("Warning: Failed to register worktree trust: {e}");
+        }
+
+        let mut worktree = Worktree::new(stage_id.to_string(), path, branch_name);
+        worktree.mark_active();
+        Ok(worktree)
+    }
+
+    fn remove_workspace(&self, stage_id: &str, repo_root: &Path, _force: bool) -> Result<()> {
+        validate_id(stage_id).context("Invalid stage ID for workspace removal")?;
+
+        let path = get_worktree_path(stage_id, repo_root);
+        if !path.exists() {
+            bail!("Workspace does not exist: {}", path.display());
+        }
+        // Snapshot the working copy so its last edits survive as a commit
+        let _ = run_jj(&["status"], &path);
+        cleanup_worktree_settings(&path);
+        if let Err(e) = untrust_worktree(&path) {
+            eprintln!("Warning: Failed to remove worktree trust: {e}");
+        }
+        run_jj_checked(
+            &["workspace", "forget", &workspace_name(stage_id)],
+            repo_root,
+        )?;
+        std::fs::remove_dir_all(&path)
+            .with_context(|| format!("Failed to remove workspace: {}", path.display()))
+    }
+
+    fn branch_exists(&self, name: &str, repo_root: &Path) -> Result<bool> {
+        let revset = format!("bookmarks(exact:{})", quote(name));
+        let out = run_jj_checked(
+            &[
+                "log",
+                "--no-graph",
+                "-r",
+                &revset,
+                "-T",
+                "commit_id ++ \"\\n\"",
+            ],
+            repo_root,
+        )?;
+        Ok(!out.is_empty())
+    }
+
+    fn create_branch(&self, name: &str, base: Option<&str>, repo_root: &Path) -> Result<()> {
+        let base = base.unwrap_or("@-");
+        run_jj_checked(&["bookmark", "create", name, "-r", base], repo_root)?;
+        Ok(())
+    }
+
+    fn delete_branch(&self, name: &str, _force: bool, repo_root: &Path) -> Result<()> {
+        run_jj_checked(&["bookmark", "delete", name], repo_root)?;
+        Ok(())
+    }
+
+    fn merge_stage(
+        &self,
+        stage_id: &str,
+        target_branch: &str,
+        repo_root: &Path,
+        work_dir: &Path,
+    ) -> Result<MergeResult> {
+        let _lock =
+            MergeLock::acquire_for(work_dir, &format!("merge {stage_id} into {target_branch}"))?;
+
+        let branch_name = branch_name_for_stage(stage_id);
+        let workspace = get_worktree_path(stage_id, repo_root);
+        if workspace.join(".jj").exists() {
+            sync_stage_bookmark(&branch_name, &workspace, repo_root)?;
+        }
+        if !self.branch_exists(&branch_name, repo_root)? {
+            bail!("Bookmark '{branch_name}' does not exist");
+        }
+
+        let (target, branch) = (quote(target_branch), quote(&branch_name));
+        if !query(&format!("{branch} & ::{target}"), "commit_id", repo_root)?.is_empty() {
+            return Ok(MergeResult::AlreadyUpToDate);
+        }
+        let target_before = query(&target, "commit_id", repo_root)?;
+
+        let msg = format!("Merge {branch_name} into {target_branch}");
+        run_jj_checked(
+            &["new", "--no-edit", &target, &branch, "-m", &msg],
+            repo_root,
+        )?;
+        let merge = query(
+            &format!("latest(children({target}) & children({branch}))"),
+            "commit_id",
+            repo_root,
+        )?;
+        if merge.is_empty() {
+            bail!("jj new did not create a merge of {branch_name} and {target_branch}");
+        }
+
+        if query(&merge, "if(conflict, \"conflict\")", repo_root)? == "conflict" {
+            let files = run_jj(&["resolve", "--list", "-r", &merge], repo_root)
+                .map(|out| parse_resolve_list(&String::from_utf8_lossy(&out.stdout)))
+                .unwrap_or_default();
+            run_jj_checked(&["abandon", &merge], repo_root)?;
+            return Ok(MergeResult::Conflict {
+                conflicting_files: files,
+            });
+        }
+
+        run_jj_checked(&["bookmark", "set", target_branch, "-r", &merge], repo_root)?;
+        let stat = run_jj_checked(
+            &["diff", "--stat", "--from", &target_before, "--to", &merge],
+            repo_root,
+        )
+        .unwrap_or_default();
+        let (files_changed, insertions, deletions) = parse_merge_stats(&stat);
+        if let Err(e) = changelog::record_merge(stage_id, target_branch, repo_root, work_dir) {
+            eprintln!("Warning: failed to add the changelog entry for '{stage_id}': {e:#}");
+        }
+        Ok(MergeResult::Success {
+            files_changed,
+            insertions,
+            deletions,
+        })
+    }
+
+    fn conflicting_files(&self, repo_root: &Path) -> Result<Vec<String>> {
+        let output = run_jj(&["resolve", "--list"], repo_root)?;
+        if output.status.success() {
+            return Ok(parse_resolve_list(&String::from_utf8_lossy(&output.stdout)));
+        }
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if stderr.contains("No conflicts") {
+            return Ok(Vec::new());
+        }
+        bail!("jj resolve --list failed: {}", stderr.trim());
+    }
+}
+
+/// Name of the jj workspace for a stage (its window title).
+fn workspace_name(stage_id: &str) -> String {
+    format!("{}{stage_id}", crate::namespace::title_prefix())
+}
+
+/// A name as a jj revset string literal (bookmark names contain `/`).
+fn quote(name: &str) -> String {
+    format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Append a changelog `entry` to `path` in the merge commit at
+/// `target_branch`.
+///
+/// The main workspace's working copy is never touched: the file is written
+/// in the stage's workspace on top of the merge and squashed into it, and
+/// the target bookmark follows the rewritten merge.
+pub fn append_to_merge(
+    stage_id: &str,
+    target_branch: &str,
+    path: &str,
+    entry: &str,
+    repo_root: &Path,
+) -> Result<bool> {
+    let workspace = get_worktree_path(stage_id, repo_root);
+    if !workspace.join(".jj").exists() {
+        bail!("Workspace of stage '{stage_id}' is gone; cannot add its changelog entry");
+    }
+    let target = quote(target_branch);
+    let fileset = format!("root-file:{}", quote(path));
+    let merged = run_jj_checked(&["diff", "--git", "-r", &target, &fileset], repo_root)?;
+    if merged.contains(&changelog::entry_marker(stage_id)) {
+        return Ok(false);
+    }
+
+    run_jj_checked(&["new", &target], &workspace)?;
+    changelog::append_entry(&workspace.join(path), entry)?;
+    run_jj_checked(&["squash"], &workspace)?;
+    Ok(true)
+}
+
+/// Evaluate `template` for the single revision `revset`.
+fn query(revset: &str, template: &str, dir: &Path) -> Result<String> {
+    run_jj_checked(&["log", "--no-graph", "-r", revset, "-T", template], dir)
+}
+
+/// Point the stage bookmark at the workspace's latest change: its working
+/// copy when that has edits, else the commit below it.
+fn sync_stage_bookmark(branch_name: &str, workspace: &Path, repo_root: &Path) -> Result<()> {
+    let head = query("@", "if(empty, \"\", commit_id)", workspace)?;
+    let head = if head.is_empty() {
+        query("@-", "commit_id", workspace)?
+    } else {
+        head
+    };
+    run_jj_checked(
+        &[
+            "bookmark",
+            "set",
+            branch_name,
+            "-r",
+            &head,
+            "--allow-backwards",
+        ],
+        repo_root,
+    )?;
+    Ok(())
+}
+
+/// Paths from `jj resolve --list` (`<path>    2-sided conflict`).
+fn parse_resolve_list(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim_end();
+            let end = line.find("  ").unwrap_or(line.len());
+            let path = line[..end].trim();
+            (!path.is_empty()).then(|| path.to_string())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_resolve_list_and_quote() {
+        let output = "src/lib.rs    2-sided conflict\n\
+                      docs/a b.md    2-sided conflict including 1 deletion\n";
+        assert_eq!(
+            parse_resolve_list(output),
+            vec!["src/lib.rs".to_string(), "docs/a b.md".to_string()]
+        );
+        assert!(parse_resolve_list("").is_empty());
+        assert_eq!(quote("loom/api"), "\"loom/api\"");
+        assert_eq!(quote("a\"b"), "\"a\\\"b\"");
+    }
+}
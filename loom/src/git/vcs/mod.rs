@@ -0,0 +1,175 @@
+//! Version control backends for stage workspaces.
+//!
+//! loom drives plain git by default: one `git worktree` per stage on branch
+//! `loom/<stage-id>`, merged with `git merge --no-ff`. In a Jujutsu
+//! repository colocated with git (`jj git init --colocate`), the git
+//! commands that move `HEAD` or create worktrees fight jj's working-copy
+//! commit, so the operations that create, merge and remove stage work go
+//! through the [`Vcs`] trait instead, with a backend per tool:
+//!
+//! - [`GitBackend`]: the existing git implementation
+//! - [`JujutsuBackend`]: `jj workspace` per stage, a bookmark per stage
+//!   branch, and merges as jj merge commits that never touch the main
+//!   workspace's working copy
+//!
+//! The backend is detected (a `.jj` directory at the repository root and
+//! `jj` on `PATH` select Jujutsu) unless `[vcs]` in `.work/config.toml`
+//! names one:
+//!
+//! ```toml
+//! [vcs]
+//! backend = "jj"   # "auto" (default), "git" or "jj"
+//! ```
+//!
+//! Read-only git queries (diffs, ancestry, branch listings) keep using git
+//! directly: a colocated repository exports every jj bookmark as a git
+//! branch, so they see the same history.
+
+mod git;
+mod jj;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use crate::fs::work_dir::read_vcs_config;
+use crate::git::merge::MergeResult;
+use crate::models::worktree::Worktree;
+
+pub use git::GitBackend;
+pub use jj::{append_to_merge, run_jj, run_jj_checked, JujutsuBackend};
+
+/// A version control tool loom can drive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VcsKind {
+    Git,
+    #[serde(rename = "jj")]
+    Jujutsu,
+}
+
+impl std::fmt::Display for VcsKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Git => write!(f, "git"),
+            Self::Jujutsu => write!(f, "jj"),
+        }
+    }
+}
+
+/// `[vcs] backend` setting.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VcsBackendSetting {
+    /// Detect from the repository
+    #[default]
+    Auto,
+    Git,
+    #[serde(rename = "jj")]
+    Jujutsu,
+}
+
+/// Persisted `[vcs]` section of `.work/config.toml`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct VcsConfig {
+    #[serde(default)]
+    pub backend: VcsBackendSetting,
+}
+
+/// Operations that create, merge and remove a stage's work.
+pub trait Vcs {
+    fn kind(&self) -> VcsKind;
+
+    /// The stage's workspace, created from `base_branch` (or the current
+    /// revision) when it does not exist yet. `sparse` limits the checkout
+    /// to the given patterns where the backend supports it.
+    fn get_or_create_workspace(
+        &self,
+        stage_id: &str,
+        repo_root: &Path,
+        base_branch: Option<&str>,
+        sparse: Option<&[String]>,
+    ) -> Result<Worktree>;
+
+    /// Remove the stage's workspace; `force` discards uncommitted changes.
+    fn remove_workspace(&self, stage_id: &str, repo_root: &Path, force: bool) -> Result<()>;
+
+    fn branch_exists(&self, name: &str, repo_root: &Path) -> Result<bool>;
+
+    fn create_branch(&self, name: &str, base: Option<&str>, repo_root: &Path) -> Result<()>;
+
+    fn delete_branch(&self, name: &str, force: bool, repo_root: &Path) -> Result<()>;
+
+    /// Merge the stage's branch into `target_branch`, holding the merge
+    /// lock in `work_dir`. Conflicts are reported, not left behind.
+    fn merge_stage(
+        &self,
+        stage_id: &str,
+        target_branch: &str,
+        repo_root: &Path,
+        work_dir: &Path,
+    ) -> Result<MergeResult>;
+
+    /// Files with unresolved conflicts in the repository's working copy.
+    fn conflicting_files(&self, repo_root: &Path) -> Result<Vec<String>>;
+}
+
+/// The backend configured or detected for the repository at `repo_root`.
+pub fn detect(repo_root: &Path) -> VcsKind {
+    let setting = read_vcs_config(&repo_root.join(".work"))
+        .map(|config| config.backend)
+        .unwrap_or_default();
+    match setting {
+        VcsBackendSetting::Git => VcsKind::Git,
+        VcsBackendSetting::Jujutsu => VcsKind::Jujutsu,
+        VcsBackendSetting::Auto => {
+            if repo_root.join(".jj").is_dir() && which::which("jj").is_ok() {
+                VcsKind::Jujutsu
+            } else {
+                VcsKind::Git
+            }
+        }
+    }
+}
+
+/// The backend for the repository at `repo_root`.
+pub fn for_repo(repo_root: &Path) -> Box<dyn Vcs> {
+    match detect(repo_root) {
+        VcsKind::Git => Box::new(GitBackend),
+        VcsKind::Jujutsu => Box::new(JujutsuBackend),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fs::work_dir::write_vcs_config;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_detect_honors_config() {
+        let temp = TempDir::new().unwrap();
+        std::fs::create_dir(temp.path().join(".work")).unwrap();
+        assert_eq!(detect(temp.path()), VcsKind::Git);
+
+        std::fs::create_dir(temp.path().join(".jj")).unwrap();
+        write_vcs_config(
+            &temp.path().join(".work"),
+            &VcsConfig {
+                backend: VcsBackendSetting::Git,
+            },
+        )
+        .unwrap();
+        assert_eq!(detect(temp.path()), VcsKind::Git);
+
+        write_vcs_config(
+            &temp.path().join(".work"),
+            &VcsConfig {
+                backend: VcsBackendSetting::Jujutsu,
+            },
+        )
+        .unwrap();
+        assert_eq!(detect(temp.path()), VcsKind::Jujutsu);
+        assert_eq!(for_repo(temp.path()).kind(), VcsKind::Jujutsu);
+    }
+}
@@ -132,7 +132,9 @@ pub fn resolve_base_branch(
             .get_node(dep)
             .ok_or_else(|| anyhow::anyhow!("Dependency '{dep}' not found in graph"))?;
 
-        let is_completed = dep_node.status == StageStatus::Completed;
+        // A stage skipped as already satisfied counts as completed and merged
+        let is_completed =
+            dep_node.status == StageStatus::Completed || dep_node.satisfies_dependents();
         let is_merged = dep_node.merged;
 
         tracing::debug!(dep, status = ?dep_node.status, is_completed, is_merged, "checking dependency");
@@ -304,6 +306,20 @@ mod tests {
                 reasoning_effort: None,
                 code_review: None,
                 ultracode: false,
+                pair: false,
+                worktree_setup: vec![],
+                instructions: None,
+                prompt_prefix: None,
+                container: None,
+                keep_terminal: None,
+                outputs: Vec::new(),
+                params: Vec::new(),
+                requires_outputs: Vec::new(),
+                new_files: Vec::new(),
+                expected_duration: None,
+                acceptance_isolation: None,
+                skip_if_satisfied: false,
+                package: None,
             })
             .collect();
 
@@ -37,7 +37,7 @@ pub fn check_worktree_support() -> Result<()> {
 
 /// Check if a worktree exists for a stage
 pub fn worktree_exists(stage_id: &str, repo_root: &Path) -> bool {
-    let worktree_path = repo_root.join(".worktrees").join(stage_id);
+    let worktree_path = get_worktree_path(stage_id, repo_root);
     worktree_path.exists()
 }
 
@@ -67,7 +67,7 @@ pub fn is_valid_git_worktree(worktree_path: &Path, repo_root: &Path) -> Result<b
 
 /// Get the path to a worktree
 pub fn get_worktree_path(stage_id: &str, repo_root: &Path) -> std::path::PathBuf {
-    repo_root.join(".worktrees").join(stage_id)
+    super::worktrees_root(repo_root).join(stage_id)
 }
 
 #[cfg(test)]
@@ -4,10 +4,13 @@ use anyhow::{bail, Context, Result};
 use std::fs;
 use std::path::{Path, PathBuf};
 
+use super::paths::find_linked_worktree;
+use super::root::worktrees_root;
+
 /// Find a worktree by ID or prefix.
 ///
-/// First attempts an exact match: `.worktrees/{id}`
-/// If not found, scans the .worktrees directory for directories starting with the given prefix.
+/// First attempts an exact match: `.worktrees/{id}` (or the configured root)
+/// If not found, scans the worktrees directory for directories starting with the given prefix.
 ///
 /// # Arguments
 /// * `repo_root` - Path to the repository root
@@ -18,7 +21,7 @@ use std::path::{Path, PathBuf};
 /// * `Ok(None)` - No matches found
 /// * `Err` - Multiple matches found (ambiguous prefix) or filesystem error
 pub fn find_worktree_by_prefix(repo_root: &Path, id: &str) -> Result<Option<PathBuf>> {
-    let worktrees_dir = repo_root.join(".worktrees");
+    let worktrees_dir = worktrees_root(repo_root);
 
     if !worktrees_dir.exists() {
         return Ok(None);
@@ -91,11 +94,14 @@ pub fn extract_worktree_stage_id(path: &Path) -> Option<String> {
 /// - `/home/user/project/.worktrees/my-stage/src/main.rs` -> Some("my-stage")
 /// - `/regular/path/without/worktree` -> None
 ///
+/// Existing paths inside a worktree under a configured `[worktrees] root` are
+/// recognized by their git metadata instead.
+///
 /// # Arguments
 /// * `path` - Any path that may be inside a worktree
 ///
 /// # Returns
-/// The stage ID if the path is within a `.worktrees/<stage-id>` directory, None otherwise.
+/// The stage ID if the path is within a stage worktree, None otherwise.
 pub fn extract_stage_id_from_path(path: &Path) -> Option<String> {
     let path_str = path.to_string_lossy();
 
@@ -110,7 +116,8 @@ pub fn extract_stage_id_from_path(path: &Path) -> Option<String> {
             .filter(|s| !s.is_empty())?;
         return Some(stage_id.to_string());
     }
-    None
+    find_linked_worktree(path)
+        .and_then(|(worktree_root, _)| extract_worktree_stage_id(&worktree_root))
 }
 
 #[cfg(test)]
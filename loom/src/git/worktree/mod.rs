@@ -1,7 +1,8 @@
 //! Git worktree management for parallel stage isolation
 //!
 //! Each parallel stage gets its own worktree to prevent file conflicts.
-//! Worktrees are created in .worktrees/{stage_id}/ directories.
+//! Worktrees are created in .worktrees/{stage_id}/ directories, or under the
+//! root configured in `[worktrees]` (see `root`).
 //!
 //! ## Module structure
 //!
@@ -11,6 +12,9 @@
 //! - `operations`: Core CRUD operations (create, remove, list, get_or_create)
 //! - `parser`: Git worktree output parsing
 //! - `paths`: Path resolution utilities for worktrees
+//! - `root`: Configurable worktree root directory
+//! - `sparse`: Sparse-checkout profiles derived from stage `files`
+//! - `stage_context`: Stage-specific `CLAUDE.md` written with each signal
 //! - `settings`: Settings management (.claude/, CLAUDE.md, symlinks)
 
 mod base;
@@ -19,7 +23,10 @@ mod discovery;
 mod operations;
 mod parser;
 mod paths;
+mod root;
 mod settings;
+mod sparse;
+mod stage_context;
 
 // Re-export all public items for backwards compatibility
 pub use base::{resolve_base_branch, BaseBranchError, ResolvedBase};
@@ -31,8 +38,18 @@ pub use operations::{
     clean_worktrees, create_worktree, get_or_create_worktree, list_worktrees, remove_worktree,
 };
 pub use parser::WorktreeInfo;
-pub use paths::{find_repo_root_from_cwd, find_worktree_root_from_cwd};
+pub use paths::{find_linked_worktree, find_repo_root_from_cwd, find_worktree_root_from_cwd};
+pub use root::{
+    resolve_worktrees_root, uses_default_worktrees_root, worktrees_root, WorktreesConfig,
+    DEFAULT_WORKTREES_DIR,
+};
 pub use settings::{
     add_settings_local_to_main_gitignore, add_settings_local_to_worktree_gitignore,
     ensure_work_symlink, refresh_worktree_settings_local, setup_worktree_hooks,
 };
+pub use sparse::{sparse_patterns, sparse_profile};
+pub use stage_context::{render_stage_claude_md, write_stage_claude_md, STAGE_CONTEXT_MARKER};
+
+pub(crate) use settings::{
+    cleanup_worktree_settings, setup_claude_directory, setup_root_claude_md,
+};
@@ -11,11 +11,13 @@ use crate::git::runner::{run_git, run_git_checked};
 use crate::models::worktree::Worktree;
 use crate::validation::validate_id;
 
-use super::checks::is_valid_git_worktree;
+use super::checks::{get_worktree_path, is_valid_git_worktree};
 use super::parser::{parse_worktree_list, WorktreeInfo};
+use super::root::worktrees_root;
 use super::settings::{
     cleanup_worktree_settings, ensure_work_symlink, setup_claude_directory, setup_root_claude_md,
 };
+use super::sparse::checkout_sparse;
 
 /// Create a new worktree for a stage
 ///
@@ -25,22 +27,30 @@ use super::settings::{
 /// If `base_branch` is Some(branch), the new branch is created from that branch:
 ///   git worktree add -b loom/{stage_id} .worktrees/{stage_id} {branch}
 /// If `base_branch` is None, the new branch is created from HEAD (current behavior).
+///
+/// If `sparse` is Some(patterns), the worktree is created with `--no-checkout`
+/// and populated through a sparse checkout of `patterns` (see [`super::sparse`]).
 pub fn create_worktree(
     stage_id: &str,
     repo_root: &Path,
     base_branch: Option<&str>,
+    sparse: Option<&[String]>,
 ) -> Result<Worktree> {
     // Validate stage_id before using in paths
     validate_id(stage_id).context("Invalid stage ID for worktree")?;
 
-    let worktree_path = repo_root.join(".worktrees").join(stage_id);
+    let worktrees_dir = worktrees_root(repo_root);
+    let worktree_path = worktrees_dir.join(stage_id);
     let branch_name = branch_name_for_stage(stage_id);
 
-    // Ensure .worktrees directory exists
-    let worktrees_dir = repo_root.join(".worktrees");
+    // Ensure the worktrees directory exists
     if !worktrees_dir.exists() {
-        std::fs::create_dir_all(&worktrees_dir)
-            .with_context(|| "Failed to create .worktrees directory")?;
+        std::fs::create_dir_all(&worktrees_dir).with_context(|| {
+            format!(
+                "Failed to create worktrees directory: {}",
+                worktrees_dir.display()
+            )
+        })?;
     }
 
     // Check if worktree already exists
@@ -52,7 +62,11 @@ pub fn create_worktree(
     // If base_branch is Some: git worktree add -b loom/{stage_id} .worktrees/{stage_id} {base_branch}
     // If base_branch is None: git worktree add -b loom/{stage_id} .worktrees/{stage_id} (from HEAD)
     let worktree_path_str = worktree_path.to_string_lossy().to_string();
-    let mut args: Vec<&str> = vec!["worktree", "add", "-b", &branch_name];
+    let mut args: Vec<&str> = vec!["worktree", "add"];
+    if sparse.is_some() {
+        args.push("--no-checkout");
+    }
+    args.extend(["-b", &branch_name]);
     args.push(&worktree_path_str);
     if let Some(base) = base_branch {
         args.push(base);
@@ -97,8 +111,11 @@ pub fn create_worktree(
                 // Branch holds unmerged commits — reuse it rather than destroy it.
                 // `git worktree add <path> <branch>` (no -b) checks the existing
                 // branch out into the new worktree.
-                let reuse_args: Vec<&str> =
-                    vec!["worktree", "add", &worktree_path_str, &branch_name];
+                let mut reuse_args: Vec<&str> = vec!["worktree", "add"];
+                if sparse.is_some() {
+                    reuse_args.push("--no-checkout");
+                }
+                reuse_args.extend([worktree_path_str.as_str(), &branch_name]);
                 let reuse_output = run_git(&reuse_args, repo_root)?;
                 if !reuse_output.status.success() {
                     let reuse_stderr = String::from_utf8_lossy(&reuse_output.stderr);
@@ -115,6 +132,10 @@ pub fn create_worktree(
         }
     }
 
+    if let Some(patterns) = sparse {
+        checkout_sparse(&worktree_path, patterns)?;
+    }
+
     // Create symlink to main .work/ directory
     ensure_work_symlink(&worktree_path, repo_root)?;
 
@@ -150,7 +171,7 @@ pub fn remove_worktree(stage_id: &str, repo_root: &Path, force: bool) -> Result<
     // Validate stage_id before using in paths
     validate_id(stage_id).context("Invalid stage ID for worktree removal")?;
 
-    let worktree_path = repo_root.join(".worktrees").join(stage_id);
+    let worktree_path = get_worktree_path(stage_id, repo_root);
 
     if !worktree_path.exists() {
         bail!("Worktree does not exist: {}", worktree_path.display());
@@ -201,17 +222,19 @@ pub fn clean_worktrees(repo_root: &Path) -> Result<()> {
 ///
 /// If `base_branch` is Some(branch), new worktrees will branch from that branch.
 /// If `base_branch` is None, new worktrees will branch from HEAD.
+/// `sparse` is passed to [`create_worktree`]; a reused worktree keeps its checkout.
 ///
 /// This function is idempotent and safe to call multiple times for the same stage.
 pub fn get_or_create_worktree(
     stage_id: &str,
     repo_root: &Path,
     base_branch: Option<&str>,
+    sparse: Option<&[String]>,
 ) -> Result<Worktree> {
     // Validate stage_id before using in paths
     validate_id(stage_id).context("Invalid stage ID for worktree")?;
 
-    let worktree_path = repo_root.join(".worktrees").join(stage_id);
+    let worktree_path = get_worktree_path(stage_id, repo_root);
     let branch_name = branch_name_for_stage(stage_id);
 
     if worktree_path.exists() {
@@ -242,5 +265,5 @@ pub fn get_or_create_worktree(
     }
 
     // Create new worktree
-    create_worktree(stage_id, repo_root, base_branch)
+    create_worktree(stage_id, repo_root, base_branch, sparse)
 }
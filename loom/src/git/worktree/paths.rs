@@ -2,6 +2,51 @@
 
 use std::path::{Path, PathBuf};
 
+use super::root::worktrees_root;
+
+/// Find the loom worktree containing `path` by its git metadata.
+///
+/// Walks up from `path` to a directory whose `.git` is a file pointing at
+/// `<repo>/.git/worktrees/<name>`, and accepts it when it sits directly in the
+/// repository's worktree root. This finds worktrees under a configured
+/// `[worktrees] root` outside the repository, where the `.worktrees/` path
+/// marker does not apply.
+///
+/// # Returns
+/// * `Some((worktree_root, repo_root))` - The worktree directory and the main repository root
+/// * `None` - If `path` is not inside a loom worktree
+pub fn find_linked_worktree(path: &Path) -> Option<(PathBuf, PathBuf)> {
+    let start = path.canonicalize().ok()?;
+    for dir in start.ancestors() {
+        let Ok(content) = std::fs::read_to_string(dir.join(".git")) else {
+            continue;
+        };
+        let gitdir = PathBuf::from(content.trim().strip_prefix("gitdir:")?.trim());
+        let gitdir = if gitdir.is_absolute() {
+            gitdir
+        } else {
+            dir.join(gitdir)
+        };
+        // <repo>/.git/worktrees/<name>
+        let worktrees_meta = gitdir.parent()?;
+        let dot_git = worktrees_meta.parent()?;
+        if worktrees_meta.file_name()? != "worktrees" || dot_git.file_name()? != ".git" {
+            return None;
+        }
+        let repo_root = dot_git.parent()?;
+        let repo_root = repo_root
+            .canonicalize()
+            .unwrap_or_else(|_| repo_root.to_path_buf());
+        let root = worktrees_root(&repo_root);
+        let root = root.canonicalize().unwrap_or(root);
+        if dir.parent() != Some(root.as_path()) {
+            return None;
+        }
+        return Some((dir.to_path_buf(), repo_root));
+    }
+    None
+}
+
 /// Find the main repository root from any path within a worktree or the main repo.
 ///
 /// Given a path like `/home/user/project/.worktrees/my-stage/src/lib/module.rs`,
@@ -62,6 +107,11 @@ pub fn find_repo_root_from_cwd(cwd: &Path) -> Option<PathBuf> {
         }
     }
 
+    // A worktree under a configured root outside the repository
+    if let Some((_, repo_root)) = find_linked_worktree(cwd) {
+        return Some(repo_root);
+    }
+
     // Not in a worktree - try to find git repo root by looking for .git directory
     // Walk up the directory tree looking for .git
     let mut current = if cwd.is_absolute() {
@@ -95,7 +145,8 @@ pub fn find_repo_root_from_cwd(cwd: &Path) -> Option<PathBuf> {
 ///
 /// # Returns
 /// * `Some(PathBuf)` - Absolute path to the worktree root if `cwd` is inside a worktree
-/// * `None` - If `cwd` is not inside a `.worktrees/<stage-id>` directory
+/// * `None` - If `cwd` is not inside a `.worktrees/<stage-id>` directory or a
+///   worktree under the configured `[worktrees] root`
 ///
 /// # Examples
 ///
@@ -111,7 +162,9 @@ pub fn find_worktree_root_from_cwd(cwd: &Path) -> Option<PathBuf> {
 
     // Look for ".worktrees/" pattern in the path
     let worktrees_marker = ".worktrees/";
-    let idx = path_str.find(worktrees_marker)?;
+    let Some(idx) = path_str.find(worktrees_marker) else {
+        return find_linked_worktree(cwd).map(|(worktree_root, _)| worktree_root);
+    };
 
     let after_worktrees = &path_str[idx + worktrees_marker.len()..];
 
@@ -219,6 +272,63 @@ mod tests {
         assert_eq!(result, Some(PathBuf::from(".")));
     }
 
+    #[test]
+    fn test_find_linked_worktree_under_configured_root() {
+        use crate::fs::work_dir::write_worktrees_config;
+        use crate::git::worktree::{extract_stage_id_from_path, WorktreesConfig};
+        use std::process::Command;
+
+        let git = |args: &[&str], dir: &Path| {
+            let out = Command::new("git")
+                .args(args)
+                .current_dir(dir)
+                .output()
+                .unwrap();
+            assert!(out.status.success(), "git {args:?} failed");
+        };
+        let repo = tempfile::TempDir::new().unwrap();
+        let scratch = tempfile::TempDir::new().unwrap();
+        let root = repo.path().canonicalize().unwrap();
+        git(&["init", "-b", "main"], &root);
+        git(&["config", "user.email", "t@t.com"], &root);
+        git(&["config", "user.name", "t"], &root);
+        std::fs::write(root.join("README.md"), "seed").unwrap();
+        git(&["add", "README.md"], &root);
+        git(&["commit", "-m", "seed"], &root);
+
+        let work_dir = root.join(".work");
+        std::fs::create_dir_all(&work_dir).unwrap();
+        let worktrees = scratch.path().canonicalize().unwrap();
+        write_worktrees_config(
+            &work_dir,
+            &WorktreesConfig {
+                root: Some(worktrees.to_string_lossy().into_owned()),
+            },
+        )
+        .unwrap();
+
+        let worktree = worktrees.join("my-stage");
+        let worktree_str = worktree.to_string_lossy().into_owned();
+        git(
+            &["worktree", "add", "-b", "loom/my-stage", &worktree_str],
+            &root,
+        );
+        let subdir = worktree.join("src");
+        std::fs::create_dir_all(&subdir).unwrap();
+
+        assert_eq!(
+            find_linked_worktree(&subdir),
+            Some((worktree.clone(), root.clone()))
+        );
+        assert_eq!(find_worktree_root_from_cwd(&subdir), Some(worktree));
+        assert_eq!(find_repo_root_from_cwd(&subdir), Some(root.clone()));
+        assert_eq!(
+            extract_stage_id_from_path(&subdir),
+            Some("my-stage".to_string())
+        );
+        assert_eq!(find_linked_worktree(&root), None);
+    }
+
     #[test]
     fn test_find_repo_root_from_cwd_with_trailing_slash() {
         let path = PathBuf::from("/home/user/project/.worktrees/my-stage/");
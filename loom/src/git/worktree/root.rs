@@ -0,0 +1,179 @@
+//! Configurable location of stage worktrees.
+//!
+//! Worktrees default to `<repo>/.worktrees/<stage-id>`. The `[worktrees]`
+//! section of `.work/config.toml` can move them elsewhere, e.g. to a fast
+//! scratch disk or a cache directory outside the repository:
+//!
+//! ```toml
+//! [worktrees]
+//! root = "$XDG_CACHE_HOME/loom/worktrees/{repo}"
+//! ```
+//!
+//! `~` and `$VAR` / `${VAR}` are expanded (`XDG_CACHE_HOME` falls back to
+//! `~/.cache`), `{repo}` is replaced with the repository directory name, and
+//! relative roots are resolved against the repository root.
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use crate::fs::work_dir::read_worktrees_config;
+
+/// Default worktree directory, relative to the repository root.
+pub const DEFAULT_WORKTREES_DIR: &str = ".worktrees";
+
+/// Persisted worktree placement (`[worktrees]` in `.work/config.toml`).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WorktreesConfig {
+    /// Directory holding one worktree per stage; unset keeps `.worktrees/`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub root: Option<String>,
+}
+
+/// Directory holding the stage worktrees of the repository at `repo_root`.
+///
+/// Reads `[worktrees] root` from `<repo_root>/.work/config.toml`; a missing or
+/// unreadable config yields `<repo_root>/.worktrees`.
+pub fn worktrees_root(repo_root: &Path) -> PathBuf {
+    let configured = read_worktrees_config(&repo_root.join(".work"))
+        .ok()
+        .and_then(|config| config.root)
+        .filter(|root| !root.trim().is_empty());
+    match configured {
+        Some(root) => resolve_worktrees_root(&root, repo_root),
+        None => repo_root.join(DEFAULT_WORKTREES_DIR),
+    }
+}
+
+/// Whether worktrees live in the default in-repo `.worktrees/` directory.
+pub fn uses_default_worktrees_root(repo_root: &Path) -> bool {
+    worktrees_root(repo_root) == repo_root.join(DEFAULT_WORKTREES_DIR)
+}
+
+/// Expand a configured `root` value into an absolute directory.
+pub fn resolve_worktrees_root(raw: &str, repo_root: &Path) -> PathBuf {
+    let repo_name = repo_root
+        .canonicalize()
+        .unwrap_or_else(|_| repo_root.to_path_buf())
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "repo".to_string());
+    let expanded =
+        expand_vars(raw.trim(), &|name| std::env::var(name).ok()).replace("{repo}", &repo_name);
+    let path = expand_home(&expanded);
+    if path.is_absolute() {
+        path
+    } else {
+        repo_root.join(path)
+    }
+}
+
+fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix('~'), dirs::home_dir()) {
+        (Some(rest), Some(home)) if rest.is_empty() || rest.starts_with('/') => {
+            home.join(rest.trim_start_matches('/'))
+        }
+        _ => PathBuf::from(path),
+    }
+}
+
+/// Replace `$VAR` and `${VAR}` with `lookup(VAR)`. Unset variables expand to
+/// nothing, except `XDG_CACHE_HOME`, which falls back to `~/.cache`.
+fn expand_vars(input: &str, lookup: &dyn Fn(&str) -> Option<String>) -> String {
+    let mut out = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(idx) = rest.find('$') {
+        out.push_str(&rest[..idx]);
+        let after = &rest[idx + 1..];
+        let (name, consumed) = if let Some(braced) = after.strip_prefix('{') {
+            match braced.find('}') {
+                Some(end) => (&braced[..end], end + 2),
+                None => ("", 0),
+            }
+        } else {
+            let end = after
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(after.len());
+            (&after[..end], end)
+        };
+        if name.is_empty() {
+            out.push('$');
+            rest = after;
+            continue;
+        }
+        match lookup(name) {
+            Some(value) if !value.is_empty() => out.push_str(&value),
+            _ if name == "XDG_CACHE_HOME" => out.push_str("~/.cache"),
+            _ => {}
+        }
+        rest = &after[consumed..];
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fs::work_dir::write_worktrees_config;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_worktrees_root_defaults_to_dot_worktrees() {
+        let temp = TempDir::new().unwrap();
+        assert_eq!(worktrees_root(temp.path()), temp.path().join(".worktrees"));
+        assert!(uses_default_worktrees_root(temp.path()));
+    }
+
+    #[test]
+    fn test_worktrees_root_reads_config() {
+        let temp = TempDir::new().unwrap();
+        let work_dir = temp.path().join(".work");
+        std::fs::create_dir_all(&work_dir).unwrap();
+        write_worktrees_config(
+            &work_dir,
+            &WorktreesConfig {
+                root: Some("/scratch/loom/{repo}".to_string()),
+            },
+        )
+        .unwrap();
+
+        let repo_name = temp
+            .path()
+            .canonicalize()
+            .unwrap()
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .into_owned();
+        assert_eq!(
+            worktrees_root(temp.path()),
+            PathBuf::from("/scratch/loom").join(repo_name)
+        );
+        assert!(!uses_default_worktrees_root(temp.path()));
+    }
+
+    #[test]
+    fn test_relative_root_resolves_against_repo() {
+        let repo = Path::new("/home/user/project");
+        assert_eq!(
+            resolve_worktrees_root("../wt", repo),
+            PathBuf::from("/home/user/project/../wt")
+        );
+    }
+
+    #[test]
+    fn test_expand_vars() {
+        let lookup = |name: &str| match name {
+            "SCRATCH" => Some("/mnt/fast".to_string()),
+            _ => None,
+        };
+        assert_eq!(expand_vars("$SCRATCH/loom", &lookup), "/mnt/fast/loom");
+        assert_eq!(expand_vars("${SCRATCH}x", &lookup), "/mnt/fastx");
+        assert_eq!(
+            expand_vars("$XDG_CACHE_HOME/loom", &lookup),
+            "~/.cache/loom"
+        );
+        assert_eq!(expand_vars("a$UNSET/b", &lookup), "a/b");
+        assert_eq!(expand_vars("cost$", &lookup), "cost$");
+    }
+}
@@ -10,19 +10,25 @@ use serde_json::{json, Value};
 use std::collections::HashSet;
 use std::fs::File;
 use std::io::{Read, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
+use crate::fs::work_dir::read_git_hooks_config;
+use crate::git::hooks::setup_worktree_git_hooks;
 use crate::hooks::{setup_hooks_for_worktree, HooksConfig};
 use crate::plan::schema::PermissionMode;
 
+use super::root::DEFAULT_WORKTREES_DIR;
+
 /// Creates or restores the .work symlink in a worktree.
 ///
 /// Used during worktree creation and merge failure recovery.
-/// The symlink points from .worktrees/{stage_id}/.work to ../../.work (the main repo's .work/).
+/// The symlink points from .worktrees/{stage_id}/.work to ../../.work (the main repo's .work/),
+/// or to the absolute main `.work/` when worktrees live under a configured root.
 pub fn ensure_work_symlink(worktree_path: &Path, repo_root: &Path) -> Result<()> {
     let main_work_dir = repo_root.join(".work");
     let worktree_work_link = worktree_path.join(".work");
-    let relative_work_path = Path::new("../../.work");
+    let relative_work_path =
+        &main_repo_link_target(worktree_path, repo_root, &main_work_dir, "../../.work");
 
     if main_work_dir.exists() && !worktree_work_link.exists() {
         #[cfg(unix)]
@@ -36,6 +42,26 @@ pub fn ensure_work_symlink(worktree_path: &Path, repo_root: &Path) -> Result<()>
     Ok(())
 }
 
+/// Target for a symlink from a worktree to `main_path` in the main repo.
+///
+/// Worktrees in the default `<repo>/.worktrees/` use `in_repo_relative`, which
+/// keeps the link valid if the repository moves; worktrees under a configured
+/// root elsewhere link to the absolute path.
+fn main_repo_link_target(
+    worktree_path: &Path,
+    repo_root: &Path,
+    main_path: &Path,
+    in_repo_relative: &str,
+) -> PathBuf {
+    if worktree_path.parent() == Some(repo_root.join(DEFAULT_WORKTREES_DIR).as_path()) {
+        PathBuf::from(in_repo_relative)
+    } else {
+        main_path
+            .canonicalize()
+            .unwrap_or_else(|_| main_path.to_path_buf())
+    }
+}
+
 /// Set up .claude/ directory for worktree
 ///
 /// We create a real directory and symlink CLAUDE.md from main repo.
@@ -56,7 +82,12 @@ pub fn setup_claude_directory(worktree_path: &Path, repo_root: &Path) -> Result<
         let main_claude_md = main_claude_dir.join("CLAUDE.md");
         if main_claude_md.exists() {
             let worktree_claude_md = worktree_claude_dir.join("CLAUDE.md");
-            let relative_claude_md = Path::new("../../../.claude/CLAUDE.md");
+            let relative_claude_md = &main_repo_link_target(
+                worktree_path,
+                repo_root,
+                &main_claude_md,
+                "../../../.claude/CLAUDE.md",
+            );
 
             #[cfg(unix)]
             std::os::unix::fs::symlink(relative_claude_md, &worktree_claude_md)
@@ -99,7 +130,12 @@ pub fn setup_root_claude_md(worktree_path: &Path, repo_root: &Path) -> Result<()
 
     if main_root_claude_md.exists() && !worktree_root_claude_md.exists() {
         // Relative path from .worktrees/{stage_id}/CLAUDE.md to ../../CLAUDE.md
-        let relative_root_claude_md = Path::new("../../CLAUDE.md");
+        let relative_root_claude_md = &main_repo_link_target(
+            worktree_path,
+            repo_root,
+            &main_root_claude_md,
+            "../../CLAUDE.md",
+        );
 
         #[cfg(unix)]
         std::os::unix::fs::symlink(relative_root_claude_md, &worktree_root_claude_md)
@@ -446,6 +482,8 @@ fn create_worktree_settings(
 ///
 /// Session identity (stage/session IDs) is NOT written here: hooks read it
 /// from the process environment exported by the session wrapper script.
+///
+/// Also enables the git hooks configured in `[git_hooks]` for the worktree.
 pub fn setup_worktree_hooks(
     worktree_path: &Path,
     work_dir: &Path,
@@ -466,13 +504,26 @@ pub fn setup_worktree_hooks(
             "Failed to setup hooks for worktree: {}",
             worktree_path.display()
         )
+    })?;
+
+    // Worktrees live at .worktrees/<stage-id>
+    let stage_id = worktree_path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let git_hooks = read_git_hooks_config(work_dir)?;
+    setup_worktree_git_hooks(worktree_path, &stage_id, &git_hooks).with_context(|| {
+        format!(
+            "Failed to setup git hooks for worktree: {}",
+            worktree_path.display()
+        )
     })
 }
 
 /// Append a pattern to a git `info/exclude` file, creating it if absent.
 ///
 /// Idempotent: skips the write when the pattern is already present.
-fn add_to_gitignore_exclude(git_dir: &Path, pattern: &str) -> Result<()> {
+pub(super) fn add_to_gitignore_exclude(git_dir: &Path, pattern: &str) -> Result<()> {
     let info_dir = git_dir.join("info");
     std::fs::create_dir_all(&info_dir)
         .with_context(|| format!("Failed to create {}", info_dir.display()))?;
@@ -0,0 +1,112 @@
+//! Sparse checkouts for stage worktrees
+//!
+//! Worktrees already share the main repository's object store, so on a large
+//! repository the cost of creating one is the checkout itself. When the plan
+//! sets `sparse_checkout: true`, a stage with `files` gets a worktree created
+//! with `--no-checkout` and a non-cone sparse-checkout profile holding:
+//!
+//! - the top-level files of the repository (manifests, CLAUDE.md, ...)
+//! - the top-level files of the stage's `working_dir`
+//! - the plan's `sparse_include` paths
+//! - the stage's `files` globs
+//!
+//! The profile is per worktree (`extensions.worktreeConfig`); the main
+//! repository and other stages keep their full checkouts. Stages without
+//! `files` are checked out in full.
+
+use anyhow::Result;
+use std::path::Path;
+
+use crate::fs::work_dir::load_config;
+use crate::git::runner::{run_git, run_git_checked};
+use crate::models::stage::Stage;
+
+/// Sparse-checkout patterns for `stage`, or `None` for a full checkout.
+///
+/// Reads the plan's `sparse_checkout` and `sparse_include` settings from
+/// `work_dir/config.toml`.
+pub fn sparse_profile(stage: &Stage, work_dir: &Path) -> Option<Vec<String>> {
+    let include = load_config(work_dir).ok().flatten()?.sparse_checkout()?;
+    let patterns = sparse_patterns(&stage.files, stage.working_dir.as_deref(), &include);
+    (!patterns.is_empty()).then_some(patterns)
+}
+
+/// Non-cone sparse-checkout patterns covering `files`.
+///
+/// Empty when `files` is empty, since there is nothing to scope to.
+pub fn sparse_patterns(
+    files: &[String],
+    working_dir: Option<&str>,
+    include: &[String],
+) -> Vec<String> {
+    if files.iter().all(|f| f.trim().is_empty()) {
+        return Vec::new();
+    }
+    let mut patterns = vec!["/*".to_string(), "!/*/".to_string()];
+    if let Some(dir) = working_dir.map(|d| d.trim().trim_matches('/')) {
+        if !dir.is_empty() && dir != "." {
+            let dir = dir.trim_start_matches("./");
+            patterns.push(format!("/{dir}/*"));
+            patterns.push(format!("!/{dir}/*/"));
+        }
+    }
+    for path in include.iter().chain(files) {
+        let path = path.trim().trim_start_matches("./").trim_start_matches('/');
+        if path.is_empty() {
+            continue;
+        }
+        let pattern = format!("/{path}");
+        if !patterns.contains(&pattern) {
+            patterns.push(pattern);
+        }
+    }
+    patterns
+}
+
+/// Populate a worktree created with `--no-checkout` using `patterns`.
+///
+/// If git cannot set up the sparse checkout (e.g. it predates
+/// `git sparse-checkout`), the worktree is checked out in full instead.
+pub(super) fn checkout_sparse(worktree_path: &Path, patterns: &[String]) -> Result<()> {
+    let mut args = vec!["sparse-checkout", "set", "--no-cone"];
+    args.extend(patterns.iter().map(String::as_str));
+    let output = run_git(&args, worktree_path)?;
+    if !output.status.success() {
+        eprintln!(
+            "Warning: sparse checkout failed, checking out {} in full: {}",
+            worktree_path.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+        let _ = run_git(&["sparse-checkout", "disable"], worktree_path);
+    }
+    run_git_checked(&["checkout"], worktree_path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sparse_patterns() {
+        let files = vec!["src/auth/**".to_string(), "./tests/auth.rs".to_string()];
+        let include = vec!["/crates/common".to_string(), "src/auth/**".to_string()];
+        assert_eq!(
+            sparse_patterns(&files, Some("loom/"), &include),
+            vec![
+                "/*",
+                "!/*/",
+                "/loom/*",
+                "!/loom/*/",
+                "/crates/common",
+                "/src/auth/**",
+                "/tests/auth.rs",
+            ]
+        );
+        assert_eq!(
+            sparse_patterns(&files, Some("."), &[]),
+            vec!["/*", "!/*/", "/src/auth/**", "/tests/auth.rs"]
+        );
+        assert!(sparse_patterns(&[], None, &include).is_empty());
+    }
+}
@@ -0,0 +1,206 @@
+//! Stage-specific `CLAUDE.md` for each worktree
+//!
+//! The signal file is the agent's full assignment, but an agent that skims
+//! or never opens it loses the stage's goal and acceptance criteria. Claude
+//! Code always loads `.claude/CLAUDE.md`, so whenever loom writes a stage
+//! signal it also writes that file into the stage's worktree: the stage
+//! goal, constraints, files to touch and acceptance commands, followed by
+//! the project's own `.claude/CLAUDE.md` (which the worktree would
+//! otherwise link to). The project's root `CLAUDE.md` is loaded alongside
+//! it as before.
+//!
+//! The file is only written when loom owns it: when it is missing, is the
+//! link loom created, or was generated by loom earlier. A project that
+//! tracks its own `.claude/CLAUDE.md` gets `CLAUDE.local.md` instead.
+
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+
+use crate::models::stage::Stage;
+
+use super::settings::add_to_gitignore_exclude;
+
+/// First line of a generated file; marks it as safe to overwrite.
+pub const STAGE_CONTEXT_MARKER: &str = "<!-- loom:stage-context -->";
+
+/// Write the stage context for `stage` into the worktree at `worktree_path`.
+///
+/// Returns the file written, or `None` when the worktree is the main
+/// repository (knowledge stages) or every candidate file belongs to the
+/// project.
+pub fn write_stage_claude_md(
+    worktree_path: &Path,
+    repo_root: &Path,
+    stage: &Stage,
+    signal_path: &Path,
+) -> Result<Option<PathBuf>> {
+    if !worktree_path.is_dir() || same_dir(worktree_path, repo_root) {
+        return Ok(None);
+    }
+    let Some(relative) = [".claude/CLAUDE.md", "CLAUDE.local.md"]
+        .into_iter()
+        .find(|p| is_loom_owned(&worktree_path.join(p)))
+    else {
+        return Ok(None);
+    };
+
+    let project = std::fs::read_to_string(repo_root.join(".claude/CLAUDE.md")).ok();
+    let content = render_stage_claude_md(stage, signal_path, project.as_deref());
+
+    let path = worktree_path.join(relative);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    if path.is_symlink() {
+        std::fs::remove_file(&path)
+            .with_context(|| format!("Failed to replace {}", path.display()))?;
+    }
+    std::fs::write(&path, content)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+
+    let gitdir = repo_root.join(".git/worktrees").join(&stage.id);
+    if gitdir.is_dir() {
+        if let Err(e) = add_to_gitignore_exclude(&gitdir, relative) {
+            eprintln!("Warning: Failed to exclude {relative} from git: {e}");
+        }
+    }
+    Ok(Some(path))
+}
+
+/// Render the stage context, followed by the project's `.claude/CLAUDE.md`.
+pub fn render_stage_claude_md(stage: &Stage, signal_path: &Path, project: Option<&str>) -> String {
+    let mut out = format!(
+        "{STAGE_CONTEXT_MARKER}\n# Stage: {} (`{}`)\n\n\
+         Generated by loom for this worktree and rewritten with each signal. \
+         Your full assignment is in `{}`; run `loom stage complete {}` when done.\n\n",
+        stage.name,
+        stage.id,
+        signal_path.display(),
+        stage.id
+    );
+
+    out.push_str("## Goal\n\n");
+    match stage.description.as_deref().map(str::trim) {
+        Some(description) if !description.is_empty() => {
+            out.push_str(description);
+            out.push_str("\n\n");
+        }
+        _ => out.push_str(&format!("{}\n\n", stage.name)),
+    }
+
+    out.push_str("## Constraints\n\n");
+    let working_dir = stage.working_dir.as_deref().unwrap_or(".");
+    out.push_str(&format!(
+        "- Acceptance commands run from `{working_dir}` in this worktree\n"
+    ));
+    if !stage.files.is_empty() {
+        out.push_str("- Keep changes within the files listed below\n");
+    }
+    out.push_str("- Never edit or commit `.work/`; commit your work on this branch\n");
+    if stage.pair {
+        out.push_str(
+            "- Pair mode: a reviewer session checks your commits; the stage completes only \
+             once the reviewer approves the latest one\n",
+        );
+    }
+    if let Some(instructions) = stage.instructions.as_deref().map(str::trim) {
+        if !instructions.is_empty() {
+            out.push_str(&format!("\n{instructions}\n"));
+        }
+    }
+    out.push('\n');
+
+    if !stage.files.is_empty() {
+        out.push_str("## Files to Touch\n\n");
+        for file in &stage.files {
+            out.push_str(&format!("- `{file}`\n"));
+        }
+        out.push('\n');
+    }
+
+    out.push_str("## Acceptance\n\n");
+    if stage.acceptance.is_empty() {
+        out.push_str("No acceptance commands.\n\n");
+    } else {
+        for criterion in &stage.acceptance {
+            match criterion.name() {
+                Some(name) => out.push_str(&format!("- {name}: `{}`\n", criterion.command())),
+                None => out.push_str(&format!("- `{}`\n", criterion.command())),
+            }
+        }
+        out.push('\n');
+    }
+
+    if let Some(project) = project.map(str::trim).filter(|p| !p.is_empty()) {
+        out.push_str("---\n\n");
+        out.push_str(project);
+        out.push('\n');
+    }
+    out
+}
+
+/// Whether loom may overwrite `path`.
+fn is_loom_owned(path: &Path) -> bool {
+    if path.is_symlink() || !path.exists() {
+        return true;
+    }
+    std::fs::read_to_string(path)
+        .map(|content| content.starts_with(STAGE_CONTEXT_MARKER))
+        .unwrap_or(false)
+}
+
+fn same_dir(a: &Path, b: &Path) -> bool {
+    match (a.canonicalize(), b.canonicalize()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn stage() -> Stage {
+        let mut stage = Stage::new("API auth".to_string(), Some("Add token auth".to_string()));
+        stage.id = "api-auth".to_string();
+        stage.files = vec!["src/auth/**".to_string()];
+        stage.acceptance = vec![serde_yaml::from_str("cargo test auth").unwrap()];
+        stage
+    }
+
+    #[test]
+    fn test_writes_merged_context_only_where_owned() {
+        let temp = TempDir::new().unwrap();
+        let repo = temp.path();
+        std::fs::create_dir_all(repo.join(".claude")).unwrap();
+        std::fs::write(repo.join(".claude/CLAUDE.md"), "Use tabs.\n").unwrap();
+        let worktree = repo.join(".worktrees/api-auth");
+        std::fs::create_dir_all(&worktree).unwrap();
+        let signal = Path::new(".work/signals/s1.md");
+
+        let path = write_stage_claude_md(&worktree, repo, &stage(), signal)
+            .unwrap()
+            .unwrap();
+        assert_eq!(path, worktree.join(".claude/CLAUDE.md"));
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(content.starts_with(STAGE_CONTEXT_MARKER));
+        assert!(content.contains("Add token auth"));
+        assert!(content.contains("- `src/auth/**`"));
+        assert!(content.contains("- `cargo test auth`"));
+        assert!(content.ends_with("---\n\nUse tabs.\n"));
+
+        // A project-owned file is left alone
+        std::fs::write(&path, "Tracked by the project\n").unwrap();
+        let path = write_stage_claude_md(&worktree, repo, &stage(), signal)
+            .unwrap()
+            .unwrap();
+        assert_eq!(path, worktree.join("CLAUDE.local.md"));
+
+        assert_eq!(
+            write_stage_claude_md(repo, repo, &stage(), signal).unwrap(),
+            None
+        );
+    }
+}
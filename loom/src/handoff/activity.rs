@@ -0,0 +1,311 @@
+//! Artifact-based activity summary for handoffs.
+//!
+//! A session that hits its context limit is often in no state to write a
+//! useful handoff itself. The summary is assembled without a model, from what
+//! the session left behind:
+//!   * recent checkpoints (earlier handoff files for the stage)
+//!   * decisions recorded in the stage memory journal
+//!   * commits on the worktree branch since its branch point
+//!   * files modified since the branch point, committed or not
+//!
+//! Every source is optional: a missing journal, worktree or branch point just
+//! leaves that part of the summary empty.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::fs::get_merge_point;
+use crate::fs::memory::{read_journal, MemoryEntryType};
+use crate::git::runner::run_git_checked;
+use crate::git::worktree::get_worktree_path;
+use crate::handoff::generator::list_stage_handoffs;
+use crate::handoff::git_handoff::{CommitInfo, GitHistory};
+use crate::handoff::schema::ParsedHandoff;
+use crate::models::stage::Stage;
+
+/// Earlier checkpoints summarized in a new handoff.
+const MAX_CHECKPOINTS: usize = 3;
+
+/// Commits listed in the git history section.
+const MAX_COMMITS: usize = 20;
+
+/// An earlier handoff for the same stage.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CheckpointSummary {
+    /// Handoff file name (e.g. `stage-1-handoff-002.md`)
+    pub file: String,
+    /// Session that wrote the checkpoint
+    pub session_id: Option<String>,
+    /// Context usage when the checkpoint was written
+    pub context_percent: Option<f32>,
+    /// Next actions the checkpoint recorded
+    pub next_actions: Vec<String>,
+}
+
+/// Structured summary of a stage's activity, gathered from artifacts.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ActivitySummary {
+    /// Most recent checkpoints, oldest first
+    pub checkpoints: Vec<CheckpointSummary>,
+    /// (decision, rationale) pairs from the memory journal
+    pub decisions: Vec<(String, String)>,
+    /// Worktree branch history since the branch point
+    pub git_history: Option<GitHistory>,
+    /// Files changed since the branch point, including uncommitted changes
+    pub files_modified: Vec<String>,
+}
+
+/// Collect the activity summary for `stage`.
+///
+/// `worktree` is the stage's worktree; git sources are skipped when it is
+/// `None` or does not exist.
+pub fn collect_activity_summary(
+    stage: &Stage,
+    work_dir: &Path,
+    worktree: Option<&Path>,
+) -> ActivitySummary {
+    let mut summary = ActivitySummary {
+        checkpoints: recent_checkpoints(&stage.id, work_dir),
+        decisions: journal_decisions(&stage.id, work_dir),
+        ..Default::default()
+    };
+
+    if let Some(worktree) = worktree.filter(|path| path.is_dir()) {
+        let base = stage
+            .base_branch
+            .clone()
+            .or_else(|| stage.resolved_base.clone())
+            .unwrap_or_else(|| get_merge_point(work_dir).unwrap_or_else(|_| "main".to_string()));
+        if let Some((history, files)) = worktree_activity(worktree, &base) {
+            summary.git_history = Some(history);
+            summary.files_modified = files;
+        }
+    }
+
+    summary
+}
+
+/// Resolve a stage's worktree: the session's recorded path, else
+/// `.worktrees/<worktree-id>` next to the work directory.
+pub fn resolve_worktree(
+    session_worktree: Option<&Path>,
+    stage: &Stage,
+    work_dir: &Path,
+) -> Option<PathBuf> {
+    if let Some(path) = session_worktree.filter(|path| path.is_dir()) {
+        return Some(path.to_path_buf());
+    }
+    let worktree_id = stage.worktree.as_deref().unwrap_or(&stage.id);
+    let path = get_worktree_path(worktree_id, work_dir.parent()?);
+    path.is_dir().then_some(path)
+}
+
+fn recent_checkpoints(stage_id: &str, work_dir: &Path) -> Vec<CheckpointSummary> {
+    let handoffs = list_stage_handoffs(stage_id, work_dir).unwrap_or_default();
+    let skip = handoffs.len().saturating_sub(MAX_CHECKPOINTS);
+    handoffs
+        .into_iter()
+        .skip(skip)
+        .map(|path| {
+            let file = path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            let v2 = fs::read_to_string(&path)
+                .ok()
+                .and_then(|content| ParsedHandoff::parse(&content).as_v2().cloned());
+            CheckpointSummary {
+                file,
+                session_id: v2.as_ref().map(|h| h.session_id.clone()),
+                context_percent: v2.as_ref().map(|h| h.context_percent),
+                next_actions: v2.map(|h| h.next_actions).unwrap_or_default(),
+            }
+        })
+        .collect()
+}
+
+fn journal_decisions(stage_id: &str, work_dir: &Path) -> Vec<(String, String)> {
+    let Ok(journal) = read_journal(work_dir, stage_id) else {
+        return Vec::new();
+    };
+    journal
+        .entries
+        .into_iter()
+        .filter(|entry| entry.entry_type == MemoryEntryType::Decision)
+        .map(|entry| (entry.content, entry.context.unwrap_or_default()))
+        .collect()
+}
+
+/// Branch history and modified files of `worktree` since it left `base`.
+fn worktree_activity(worktree: &Path, base: &str) -> Option<(GitHistory, Vec<String>)> {
+    let branch = run_git_checked(&["rev-parse", "--abbrev-ref", "HEAD"], worktree).ok()?;
+    let branch_point = run_git_checked(&["merge-base", "HEAD", base], worktree).ok()?;
+    let branch_point = branch_point.trim();
+
+    let range = format!("{branch_point}..HEAD");
+    let max_count = format!("--max-count={MAX_COMMITS}");
+    let log = run_git_checked(&["log", "--format=%h%x09%s", &max_count, &range], worktree)
+        .unwrap_or_default();
+    let commits = log
+        .lines()
+        .filter_map(|line| line.split_once('\t'))
+        .map(|(hash, message)| CommitInfo {
+            hash: hash.to_string(),
+            message: message.to_string(),
+        })
+        .collect();
+
+    let status = run_git_checked(&["status", "--porcelain"], worktree).unwrap_or_default();
+    let uncommitted_changes: Vec<String> = status
+        .lines()
+        .map(|line| line.trim_start().to_string())
+        .filter(|line| !line.is_empty())
+        .collect();
+
+    let diff =
+        run_git_checked(&["diff", "--name-only", branch_point], worktree).unwrap_or_default();
+    let mut files: Vec<String> = diff.lines().map(String::from).collect();
+    for line in status.lines().filter(|line| line.starts_with("??")) {
+        let path = line[2..].trim().to_string();
+        if !files.contains(&path) {
+            files.push(path);
+        }
+    }
+
+    let history = GitHistory {
+        branch: branch.trim().to_string(),
+        base_branch: base.to_string(),
+        commits,
+        uncommitted_changes,
+    };
+    Some((history, files))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fs::memory::{append_entry, MemoryEntry};
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let out = Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap();
+        assert!(
+            out.status.success(),
+            "git {args:?} failed: {}",
+            String::from_utf8_lossy(&out.stderr)
+        );
+    }
+
+    #[test]
+    fn test_collect_activity_summary_from_artifacts() {
+        let temp = TempDir::new().unwrap();
+        let work_dir = temp.path().join(".work");
+        let handoffs = work_dir.join("handoffs");
+        fs::create_dir_all(&handoffs).unwrap();
+        for n in 1..=4 {
+            fs::write(
+                handoffs.join(format!("stage-1-handoff-{n:03}.md")),
+                format!(
+                    "---\nversion: 2\nsession_id: session-{n}\nstage_id: stage-1\n\
+                     context_percent: 75.0\nnext_actions:\n- step {n}\n---\n\n# Handoff\n"
+                ),
+            )
+            .unwrap();
+        }
+        append_entry(
+            &work_dir,
+            "stage-1",
+            &MemoryEntry::with_context(
+                MemoryEntryType::Decision,
+                "Use sqlite".to_string(),
+                "No server needed".to_string(),
+            ),
+        )
+        .unwrap();
+        append_entry(
+            &work_dir,
+            "stage-1",
+            &MemoryEntry::new(MemoryEntryType::Note, "Noted".to_string()),
+        )
+        .unwrap();
+
+        let worktree = temp.path().join(".worktrees/stage-1");
+        fs::create_dir_all(&worktree).unwrap();
+        git(&worktree, &["init", "-b", "main"]);
+        git(&worktree, &["config", "user.email", "t@t.com"]);
+        git(&worktree, &["config", "user.name", "t"]);
+        fs::write(worktree.join("base.rs"), "base\n").unwrap();
+        git(&worktree, &["add", "."]);
+        git(&worktree, &["commit", "-m", "base"]);
+        git(&worktree, &["checkout", "-b", "loom/stage-1"]);
+        fs::write(worktree.join("feature.rs"), "feature\n").unwrap();
+        git(&worktree, &["add", "."]);
+        git(&worktree, &["commit", "-m", "Add feature"]);
+        fs::write(worktree.join("base.rs"), "edited\n").unwrap();
+        fs::write(worktree.join("new.rs"), "new\n").unwrap();
+
+        let stage = Stage {
+            id: "stage-1".to_string(),
+            base_branch: Some("main".to_string()),
+            ..Default::default()
+        };
+        let resolved = resolve_worktree(None, &stage, &work_dir).unwrap();
+        assert_eq!(resolved, worktree);
+
+        let summary = collect_activity_summary(&stage, &work_dir, Some(&resolved));
+
+        let files: Vec<&str> = summary
+            .checkpoints
+            .iter()
+            .map(|c| c.file.as_str())
+            .collect();
+        assert_eq!(
+            files,
+            vec![
+                "stage-1-handoff-002.md",
+                "stage-1-handoff-003.md",
+                "stage-1-handoff-004.md"
+            ]
+        );
+        assert_eq!(
+            summary.checkpoints[2].session_id.as_deref(),
+            Some("session-4")
+        );
+        assert_eq!(summary.checkpoints[2].next_actions, vec!["step 4"]);
+
+        assert_eq!(
+            summary.decisions,
+            vec![("Use sqlite".to_string(), "No server needed".to_string())]
+        );
+
+        let history = summary.git_history.unwrap();
+        assert_eq!(history.branch, "loom/stage-1");
+        assert_eq!(history.base_branch, "main");
+        assert_eq!(history.commits.len(), 1);
+        assert_eq!(history.commits[0].message, "Add feature");
+        assert!(history
+            .uncommitted_changes
+            .contains(&"M base.rs".to_string()));
+        assert_eq!(
+            summary.files_modified,
+            vec!["base.rs", "feature.rs", "new.rs"]
+        );
+    }
+
+    #[test]
+    fn test_collect_activity_summary_without_artifacts() {
+        let temp = TempDir::new().unwrap();
+        let stage = Stage {
+            id: "stage-1".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(resolve_worktree(None, &stage, temp.path()), None);
+        let summary = collect_activity_summary(&stage, temp.path(), None);
+        assert_eq!(summary, ActivitySummary::default());
+    }
+}
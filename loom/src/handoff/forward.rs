@@ -0,0 +1,364 @@
+//! Forward handoffs: what a merged stage actually did, for its dependents.
+//!
+//! When a stage's branch is merged, loom records a distilled summary of its
+//! work in `.work/handoffs/forward/<stage-id>.md`: the commit messages, the
+//! files it changed, its outputs and the decisions and notes from its memory
+//! journal. Dependent stages get these summaries embedded in their signals,
+//! so they start from what the dependency did rather than from its plan
+//! description alone.
+
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::fs::memory::{read_journal, MemoryEntryType};
+use crate::git::branch::branch_name_for_stage;
+use crate::git::runner::run_git_checked;
+use crate::handoff::git_handoff::CommitInfo;
+use crate::models::stage::{Stage, StageOutput};
+
+/// Directory under `.work/handoffs/` holding one forward handoff per stage.
+pub const FORWARD_HANDOFF_DIR: &str = "forward";
+
+/// Most commits listed in a forward handoff
+const MAX_COMMITS: usize = 20;
+
+/// Most changed files listed in a forward handoff
+const MAX_FILES: usize = 30;
+
+/// Most memory entries of each kind listed in a forward handoff
+const MAX_MEMORY_ENTRIES: usize = 8;
+
+/// A file the stage changed, with its `git diff --name-status` letter
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangedFile {
+    pub status: char,
+    pub path: String,
+}
+
+/// Distilled summary of a stage's work, collected when it is merged.
+#[derive(Debug, Clone, Default)]
+pub struct ForwardHandoff {
+    pub stage_id: String,
+    pub stage_name: String,
+    pub branch: String,
+    pub target: String,
+    /// Commits on the stage branch, oldest first
+    pub commits: Vec<CommitInfo>,
+    pub files: Vec<ChangedFile>,
+    pub outputs: Vec<StageOutput>,
+    /// Decisions from the memory journal, as (decision, rationale)
+    pub decisions: Vec<(String, Option<String>)>,
+    /// Most recent notes from the memory journal
+    pub notes: Vec<String>,
+}
+
+impl ForwardHandoff {
+    /// Collect the summary for `stage` before its branch is merged into `target`.
+    ///
+    /// Commits and files are taken from the branch since it left `target`, so
+    /// this must run while the branch is still unmerged. Missing git history
+    /// or memory leaves the corresponding lists empty.
+    pub fn collect(stage: &Stage, repo_root: &Path, target: &str, work_dir: &Path) -> Self {
+        let branch = branch_name_for_stage(&stage.id);
+        let (commits, files) = branch_changes(&branch, target, repo_root).unwrap_or_default();
+
+        let mut decisions = Vec::new();
+        let mut notes = Vec::new();
+        if let Ok(journal) = read_journal(work_dir, &stage.id) {
+            for entry in journal.entries {
+                match entry.entry_type {
+                    MemoryEntryType::Decision => decisions.push((entry.content, entry.context)),
+                    MemoryEntryType::Note => notes.push(entry.content),
+                    _ => {}
+                }
+            }
+        }
+        let skip = notes.len().saturating_sub(MAX_MEMORY_ENTRIES);
+        notes.drain(..skip);
+
+        Self {
+            stage_id: stage.id.clone(),
+            stage_name: stage.name.clone(),
+            branch,
+            target: target.to_string(),
+            commits,
+            files,
+            outputs: stage.outputs.clone(),
+            decisions,
+            notes,
+        }
+    }
+
+    /// Render as the markdown stored in `.work/handoffs/forward/`.
+    pub fn render(&self) -> String {
+        let mut out = format!("# Forward Handoff: {}\n\n", self.stage_name);
+        out.push_str(&format!(
+            "Stage `{}` merged `{}` into `{}`.\n\n",
+            self.stage_id, self.branch, self.target
+        ));
+
+        if !self.commits.is_empty() {
+            out.push_str("## Commits\n\n");
+            for commit in self.commits.iter().take(MAX_COMMITS) {
+                out.push_str(&format!("- {} {}\n", commit.hash, commit.message));
+            }
+            push_more(&mut out, self.commits.len(), MAX_COMMITS, "commits");
+            out.push('\n');
+        }
+
+        if !self.files.is_empty() {
+            out.push_str("## Files Changed\n\n");
+            for file in self.files.iter().take(MAX_FILES) {
+                out.push_str(&format!(
+                    "- `{}` ({})\n",
+                    file.path,
+                    status_label(file.status)
+                ));
+            }
+            push_more(&mut out, self.files.len(), MAX_FILES, "files");
+            out.push('\n');
+        }
+
+        if !self.outputs.is_empty() {
+            out.push_str("## Outputs\n\n");
+            for output in &self.outputs {
+                out.push_str(&format!("- `{}` = `{}`", output.key, output.value));
+                if !output.description.is_empty() {
+                    out.push_str(&format!(" - {}", output.description));
+                }
+                out.push('\n');
+            }
+            out.push('\n');
+        }
+
+        if !self.decisions.is_empty() || !self.notes.is_empty() {
+            out.push_str("## Memory\n\n");
+            for (decision, rationale) in self.decisions.iter().take(MAX_MEMORY_ENTRIES) {
+                out.push_str(&format!("- Decision: {decision}"));
+                if let Some(rationale) = rationale {
+                    out.push_str(&format!(" ({rationale})"));
+                }
+                out.push('\n');
+            }
+            push_more(
+                &mut out,
+                self.decisions.len(),
+                MAX_MEMORY_ENTRIES,
+                "decisions",
+            );
+            for note in &self.notes {
+                out.push_str(&format!("- Note: {note}\n"));
+            }
+            out.push('\n');
+        }
+
+        if self.commits.is_empty()
+            && self.files.is_empty()
+            && self.outputs.is_empty()
+            && self.decisions.is_empty()
+            && self.notes.is_empty()
+        {
+            out.push_str("No commits, outputs or memory were recorded.\n");
+        }
+
+        out
+    }
+
+    /// Write to `.work/handoffs/forward/<stage-id>.md`.
+    pub fn write(&self, work_dir: &Path) -> Result<PathBuf> {
+        let path = forward_handoff_path(work_dir, &self.stage_id);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        fs::write(&path, self.render())
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        Ok(path)
+    }
+}
+
+/// Path of a stage's forward handoff
+pub fn forward_handoff_path(work_dir: &Path, stage_id: &str) -> PathBuf {
+    work_dir
+        .join("handoffs")
+        .join(FORWARD_HANDOFF_DIR)
+        .join(format!("{stage_id}.md"))
+}
+
+/// Read a stage's forward handoff, if one was recorded
+pub fn read_forward_handoff(work_dir: &Path, stage_id: &str) -> Option<String> {
+    fs::read_to_string(forward_handoff_path(work_dir, stage_id)).ok()
+}
+
+/// Collect and write the forward handoff for a stage about to be merged.
+///
+/// Every merge path calls this just before merging: the stage's own changes
+/// can only be told apart from the target's while its branch is unmerged.
+///
+/// Failures are reported as warnings: a missing forward handoff only costs
+/// dependents context, so it never blocks the merge.
+pub fn record_forward_handoff(stage: &Stage, repo_root: &Path, target: &str, work_dir: &Path) {
+    let handoff = ForwardHandoff::collect(stage, repo_root, target, work_dir);
+    if let Err(e) = handoff.write(work_dir) {
+        tracing::warn!(stage_id = %stage.id, error = %e, "Failed to write forward handoff");
+    }
+}
+
+/// Commits and changed files on `branch` since it left `target`
+fn branch_changes(
+    branch: &str,
+    target: &str,
+    repo_root: &Path,
+) -> Result<(Vec<CommitInfo>, Vec<ChangedFile>)> {
+    let base = run_git_checked(&["merge-base", target, branch], repo_root)?;
+    let base = base.trim();
+
+    let range = format!("{base}..{branch}");
+    let log = run_git_checked(
+        &["log", "--reverse", "--format=%h%x09%s", &range],
+        repo_root,
+    )?;
+    let commits = log
+        .lines()
+        .filter_map(|line| line.split_once('\t'))
+        .map(|(hash, message)| CommitInfo {
+            hash: hash.to_string(),
+            message: message.to_string(),
+        })
+        .collect();
+
+    let diff = run_git_checked(&["diff", "--name-status", base, branch], repo_root)?;
+    let files = diff
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.split('\t');
+            let status = parts.next()?.chars().next()?;
+            // Renames and copies list the old path first
+            let path = parts.next_back()?;
+            Some(ChangedFile {
+                status,
+                path: path.to_string(),
+            })
+        })
+        .collect();
+
+    Ok((commits, files))
+}
+
+fn status_label(status: char) -> &'static str {
+    match status {
+        'A' => "added",
+        'D' => "deleted",
+        'R' => "renamed",
+        'C' => "copied",
+        _ => "modified",
+    }
+}
+
+fn push_more(out: &mut String, total: usize, shown: usize, what: &str) {
+    if total > shown {
+        out.push_str(&format!("- ... and {} more {what}\n", total - shown));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fs::memory::{append_entry, MemoryEntry};
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let out = Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap();
+        assert!(
+            out.status.success(),
+            "git {args:?} failed: {}",
+            String::from_utf8_lossy(&out.stderr)
+        );
+    }
+
+    #[test]
+    fn test_collect_and_write_forward_handoff() {
+        let temp = TempDir::new().unwrap();
+        let repo = temp.path();
+        let work_dir = repo.join(".work");
+        git(repo, &["init", "-b", "main"]);
+        git(repo, &["config", "user.email", "t@t.com"]);
+        git(repo, &["config", "user.name", "t"]);
+        fs::write(repo.join("old.rs"), "old\n").unwrap();
+        fs::write(repo.join("lib.rs"), "lib\n").unwrap();
+        git(repo, &["add", "."]);
+        git(repo, &["commit", "-m", "base"]);
+        git(repo, &["checkout", "-b", "loom/api"]);
+        fs::write(repo.join("api.rs"), "api\n").unwrap();
+        git(repo, &["add", "."]);
+        git(repo, &["commit", "-m", "Add api module"]);
+        fs::write(repo.join("lib.rs"), "lib\nmod api;\n").unwrap();
+        git(repo, &["rm", "-q", "old.rs"]);
+        git(repo, &["commit", "-am", "Wire api into lib"]);
+        git(repo, &["checkout", "main"]);
+
+        append_entry(
+            &work_dir,
+            "api",
+            &MemoryEntry::with_context(
+                MemoryEntryType::Decision,
+                "Use axum".to_string(),
+                "Already a dependency".to_string(),
+            ),
+        )
+        .unwrap();
+        append_entry(
+            &work_dir,
+            "api",
+            &MemoryEntry::new(MemoryEntryType::Note, "Routes live in api.rs".to_string()),
+        )
+        .unwrap();
+
+        let stage = Stage {
+            id: "api".to_string(),
+            name: "API".to_string(),
+            outputs: vec![StageOutput {
+                key: "port".to_string(),
+                value: serde_json::json!(8080),
+                description: "Listening port".to_string(),
+            }],
+            ..Default::default()
+        };
+
+        let handoff = ForwardHandoff::collect(&stage, repo, "main", &work_dir);
+        let messages: Vec<&str> = handoff.commits.iter().map(|c| c.message.as_str()).collect();
+        assert_eq!(messages, vec!["Add api module", "Wire api into lib"]);
+
+        let path = handoff.write(&work_dir).unwrap();
+        assert_eq!(path, forward_handoff_path(&work_dir, "api"));
+        let content = read_forward_handoff(&work_dir, "api").unwrap();
+        assert!(content.contains("merged `loom/api` into `main`"));
+        assert!(content.contains("`api.rs` (added)"));
+        assert!(content.contains("`lib.rs` (modified)"));
+        assert!(content.contains("`old.rs` (deleted)"));
+        assert!(content.contains("`port` = `8080` - Listening port"));
+        assert!(content.contains("- Decision: Use axum (Already a dependency)"));
+        assert!(content.contains("- Note: Routes live in api.rs"));
+    }
+
+    #[test]
+    fn test_render_without_history() {
+        let handoff = ForwardHandoff {
+            stage_id: "docs".to_string(),
+            stage_name: "Docs".to_string(),
+            branch: "loom/docs".to_string(),
+            target: "main".to_string(),
+            ..Default::default()
+        };
+        let content = handoff.render();
+        assert!(content.starts_with("# Forward Handoff: Docs\n"));
+        assert!(content.contains("No commits, outputs or memory were recorded."));
+        assert!(!content.contains("## Commits"));
+    }
+}
@@ -1,5 +1,6 @@
 //! Handoff content data structures and builder methods.
 
+use crate::handoff::activity::{ActivitySummary, CheckpointSummary};
 use crate::handoff::git_handoff::GitHistory;
 use crate::handoff::schema::{CommitRef, CompletedTask, HandoffV2, KeyDecision};
 
@@ -20,6 +21,8 @@ pub struct HandoffContent {
     pub git_history: Option<GitHistory>,
     /// Memory content from session journal (for handoff)
     pub memory_content: Option<String>,
+    /// Earlier checkpoints for the stage (from the activity summary)
+    pub checkpoints: Vec<CheckpointSummary>,
 }
 
 impl HandoffContent {
@@ -39,6 +42,7 @@ impl HandoffContent {
             next_steps: Vec::new(),
             git_history: None,
             memory_content: None,
+            checkpoints: Vec::new(),
         }
     }
 
@@ -102,6 +106,30 @@ impl HandoffContent {
         self
     }
 
+    /// Fill in what the session did not provide from an artifact-based
+    /// activity summary.
+    ///
+    /// Decisions and git history are taken from the summary only when the
+    /// content has none; modified files are merged.
+    pub fn with_activity_summary(mut self, summary: ActivitySummary) -> Self {
+        if self.decisions.is_empty() {
+            self.decisions = summary.decisions;
+        }
+        if self.current_branch.is_none() {
+            self.current_branch = summary.git_history.as_ref().map(|h| h.branch.clone());
+        }
+        if self.git_history.is_none() {
+            self.git_history = summary.git_history;
+        }
+        for file in summary.files_modified {
+            if !self.files_modified.contains(&file) {
+                self.files_modified.push(file);
+            }
+        }
+        self.checkpoints = summary.checkpoints;
+        self
+    }
+
     /// Convert to HandoffV2 structured format
     pub fn to_v2(&self) -> HandoffV2 {
         // Convert completed_work strings to CompletedTask structs
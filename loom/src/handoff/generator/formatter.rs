@@ -105,6 +105,25 @@ pub fn format_handoff_markdown(content: &HandoffContent) -> Result<String> {
         md.push('\n');
     }
 
+    // Recent Checkpoints (if any)
+    if !content.checkpoints.is_empty() {
+        md.push_str("## Recent Checkpoints\n\n");
+        for checkpoint in &content.checkpoints {
+            md.push_str(&format!("- **{}**", checkpoint.file));
+            if let Some(session_id) = &checkpoint.session_id {
+                md.push_str(&format!(" from {session_id}"));
+            }
+            if let Some(percent) = checkpoint.context_percent {
+                md.push_str(&format!(" at {percent:.1}% context"));
+            }
+            md.push('\n');
+            for action in &checkpoint.next_actions {
+                md.push_str(&format!("  - Next: {action}\n"));
+            }
+        }
+        md.push('\n');
+    }
+
     // Next Steps
     md.push_str("## Next Steps (Prioritized)\n\n");
     if content.next_steps.is_empty() {
@@ -11,17 +11,22 @@ use anyhow::{Context, Result};
 use std::fs;
 use std::path::{Path, PathBuf};
 
+use crate::handoff::activity::{collect_activity_summary, resolve_worktree};
 use crate::models::session::Session;
 use crate::models::stage::Stage;
 
 pub use content::HandoffContent;
-pub use numbering::find_latest_handoff;
+pub use numbering::{discard_stage_handoffs, find_latest_handoff, list_stage_handoffs};
 
 use formatter::format_handoff_markdown;
 use numbering::get_next_handoff_number;
 
 /// Generate a handoff file for a session transitioning due to context exhaustion
 ///
+/// The content is completed from an artifact-based activity summary (earlier
+/// checkpoints, memory decisions, worktree git history and modified files),
+/// so the handoff is useful even when the session could not write much.
+///
 /// # Arguments
 /// * `session` - The session being handed off
 /// * `stage` - The stage being worked on
@@ -31,11 +36,16 @@ use numbering::get_next_handoff_number;
 /// # Returns
 /// Path to the created handoff file
 pub fn generate_handoff(
-    _session: &Session,
+    session: &Session,
     stage: &Stage,
     content: HandoffContent,
     work_dir: &Path,
 ) -> Result<PathBuf> {
+    // Gather the activity summary before this handoff joins the checkpoints
+    let worktree = resolve_worktree(session.worktree_path.as_deref(), stage, work_dir);
+    let summary = collect_activity_summary(stage, work_dir, worktree.as_deref());
+    let content = content.with_activity_summary(summary);
+
     // Ensure handoffs directory exists
     let handoffs_dir = work_dir.join("handoffs");
     if !handoffs_dir.exists() {
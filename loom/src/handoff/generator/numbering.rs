@@ -51,11 +51,16 @@ pub fn get_next_handoff_number(stage_id: &str, work_dir: &Path) -> Result<u32> {
 ///
 /// Returns the path to the latest handoff file, or None if no handoffs exist.
 pub fn find_latest_handoff(stage_id: &str, work_dir: &Path) -> Result<Option<PathBuf>> {
+    Ok(list_stage_handoffs(stage_id, work_dir)?.pop())
+}
+
+/// List a stage's handoff files, oldest (lowest number) first
+pub fn list_stage_handoffs(stage_id: &str, work_dir: &Path) -> Result<Vec<PathBuf>> {
     let handoffs_dir = work_dir.join("handoffs");
 
     // If directory doesn't exist, no handoffs exist
     if !handoffs_dir.exists() {
-        return Ok(None);
+        return Ok(Vec::new());
     }
 
     // Read directory entries
@@ -66,8 +71,7 @@ pub fn find_latest_handoff(stage_id: &str, work_dir: &Path) -> Result<Option<Pat
         )
     })?;
 
-    let mut max_number = 0u32;
-    let mut latest_path: Option<PathBuf> = None;
+    let mut handoffs: Vec<(u32, PathBuf)> = Vec::new();
     let prefix = format!("{stage_id}-handoff-");
 
     for entry in entries {
@@ -81,14 +85,51 @@ pub fn find_latest_handoff(stage_id: &str, work_dir: &Path) -> Result<Option<Pat
             // Extract number from "NNN.md"
             if let Some(num_str) = rest.strip_suffix(".md") {
                 if let Ok(num) = num_str.parse::<u32>() {
-                    if num > max_number {
-                        max_number = num;
-                        latest_path = Some(path);
-                    }
+                    handoffs.push((num, path));
                 }
             }
         }
     }
 
-    Ok(latest_path)
+    handoffs.sort_by_key(|(num, _)| *num);
+    Ok(handoffs.into_iter().map(|(_, path)| path).collect())
+}
+
+/// Move a stage's handoff files to `handoffs/discarded/`
+///
+/// Continuation sessions and `loom resume` no longer see them; the files are
+/// kept for reference. Returns the new paths.
+pub fn discard_stage_handoffs(stage_id: &str, work_dir: &Path) -> Result<Vec<PathBuf>> {
+    let handoffs = list_stage_handoffs(stage_id, work_dir)?;
+    if handoffs.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let discarded_dir = work_dir.join("handoffs").join("discarded");
+    fs::create_dir_all(&discarded_dir).with_context(|| {
+        format!(
+            "Failed to create discarded handoffs directory: {}",
+            discarded_dir.display()
+        )
+    })?;
+
+    let mut moved = Vec::with_capacity(handoffs.len());
+    for path in handoffs {
+        let stem = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or(stage_id)
+            .to_string();
+        // Numbering restarts after a discard, so an older discard may hold the name
+        let mut target = discarded_dir.join(format!("{stem}.md"));
+        let mut n = 1;
+        while target.exists() {
+            n += 1;
+            target = discarded_dir.join(format!("{stem}-{n}.md"));
+        }
+        fs::rename(&path, &target)
+            .with_context(|| format!("Failed to discard handoff: {}", path.display()))?;
+        moved.push(target);
+    }
+    Ok(moved)
 }
@@ -6,7 +6,7 @@ use tempfile::TempDir;
 use super::content::HandoffContent;
 use super::formatter::format_handoff_markdown;
 use super::generate_handoff;
-use super::numbering::{find_latest_handoff, get_next_handoff_number};
+use super::numbering::{discard_stage_handoffs, find_latest_handoff, get_next_handoff_number};
 use crate::models::session::Session;
 use crate::models::stage::Stage;
 
@@ -154,6 +154,38 @@ fn test_find_latest_handoff_none_exist() {
     assert!(latest.is_none());
 }
 
+#[test]
+fn test_discard_stage_handoffs() {
+    let temp = TempDir::new().unwrap();
+    let work_dir = temp.path();
+    let handoffs_dir = work_dir.join("handoffs");
+    fs::create_dir_all(handoffs_dir.join("discarded")).unwrap();
+
+    fs::write(handoffs_dir.join("stage-1-handoff-001.md"), "first").unwrap();
+    fs::write(handoffs_dir.join("stage-1-handoff-002.md"), "second").unwrap();
+    fs::write(handoffs_dir.join("stage-2-handoff-001.md"), "other").unwrap();
+    fs::write(
+        handoffs_dir
+            .join("discarded")
+            .join("stage-1-handoff-001.md"),
+        "older discard",
+    )
+    .unwrap();
+
+    let moved = discard_stage_handoffs("stage-1", work_dir).unwrap();
+    assert_eq!(moved.len(), 2);
+    assert!(moved[0].ends_with("discarded/stage-1-handoff-001-2.md"));
+    assert!(moved[1].ends_with("discarded/stage-1-handoff-002.md"));
+    assert_eq!(fs::read_to_string(&moved[0]).unwrap(), "first");
+
+    assert!(find_latest_handoff("stage-1", work_dir).unwrap().is_none());
+    assert!(find_latest_handoff("stage-2", work_dir).unwrap().is_some());
+    assert_eq!(get_next_handoff_number("stage-1", work_dir).unwrap(), 1);
+    assert!(discard_stage_handoffs("stage-1", work_dir)
+        .unwrap()
+        .is_empty());
+}
+
 #[test]
 fn test_generate_handoff() {
     let temp = TempDir::new().unwrap();
@@ -195,3 +227,39 @@ fn test_generate_multiple_handoffs() {
     assert!(path1.to_string_lossy().contains("handoff-001.md"));
     assert!(path2.to_string_lossy().contains("handoff-002.md"));
 }
+
+#[test]
+fn test_activity_summary_fills_gaps_and_lists_checkpoints() {
+    use crate::handoff::activity::{ActivitySummary, CheckpointSummary};
+    use crate::handoff::git_handoff::GitHistory;
+
+    let summary = ActivitySummary {
+        checkpoints: vec![CheckpointSummary {
+            file: "stage-1-handoff-001.md".to_string(),
+            session_id: Some("session-0".to_string()),
+            context_percent: Some(72.0),
+            next_actions: vec!["Wire the parser".to_string()],
+        }],
+        decisions: vec![("Use sqlite".to_string(), "No server".to_string())],
+        git_history: Some(GitHistory {
+            branch: "loom/stage-1".to_string(),
+            base_branch: "main".to_string(),
+            ..Default::default()
+        }),
+        files_modified: vec!["src/a.rs".to_string(), "src/b.rs".to_string()],
+    };
+
+    let content = HandoffContent::new("session-1".to_string(), "stage-1".to_string())
+        .with_files_modified(vec!["src/a.rs".to_string()])
+        .with_activity_summary(summary);
+
+    assert_eq!(content.current_branch.as_deref(), Some("loom/stage-1"));
+    assert_eq!(content.files_modified, vec!["src/a.rs", "src/b.rs"]);
+    assert_eq!(content.decisions.len(), 1);
+
+    let markdown = format_handoff_markdown(&content).unwrap();
+    assert!(markdown.contains("## Recent Checkpoints"));
+    assert!(markdown.contains("- **stage-1-handoff-001.md** from session-0 at 72.0% context"));
+    assert!(markdown.contains("  - Next: Wire the parser"));
+    assert!(markdown.contains("| Use sqlite | No server |"));
+}
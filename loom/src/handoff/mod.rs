@@ -1,10 +1,16 @@
+pub mod activity;
 pub mod detector;
+pub mod forward;
 pub mod generator;
 pub mod git_handoff;
 pub mod schema;
 
+pub use activity::{collect_activity_summary, ActivitySummary, CheckpointSummary};
 pub use detector::{check_context_threshold, ContextLevel, ThresholdConfig};
-pub use generator::{find_latest_handoff, generate_handoff, HandoffContent};
+pub use forward::{read_forward_handoff, record_forward_handoff, ForwardHandoff};
+pub use generator::{
+    discard_stage_handoffs, find_latest_handoff, generate_handoff, HandoffContent,
+};
 pub use git_handoff::{format_git_history_markdown, CommitInfo, GitHistory};
 pub use schema::{
     CommitRef, CompletedTask, FileRef, HandoffV2, KeyDecision, ParsedHandoff,
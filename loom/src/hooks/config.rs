@@ -23,6 +23,8 @@ pub enum HookEvent {
     SessionEnd,
     /// Called when session is stopping
     Stop,
+    /// Called when Claude Code asks for permission or waits for input
+    Notification,
     /// Called before Bash tool use to suggest modern CLI tools (fd/rg)
     PreferModernTools,
 }
@@ -35,6 +37,7 @@ impl fmt::Display for HookEvent {
             HookEvent::PreCompact => write!(f, "PreCompact"),
             HookEvent::SessionEnd => write!(f, "SessionEnd"),
             HookEvent::Stop => write!(f, "Stop"),
+            HookEvent::Notification => write!(f, "Notification"),
             HookEvent::PreferModernTools => write!(f, "PreferModernTools"),
         }
     }
@@ -49,6 +52,7 @@ impl HookEvent {
             HookEvent::PreCompact => "pre-compact.sh",
             HookEvent::SessionEnd => "session-end.sh",
             HookEvent::Stop => "learning-validator.sh",
+            HookEvent::Notification => "notification.sh",
             HookEvent::PreferModernTools => "prefer-modern-tools.sh",
         }
     }
@@ -61,6 +65,7 @@ impl HookEvent {
             HookEvent::PreCompact,
             HookEvent::SessionEnd,
             HookEvent::Stop,
+            HookEvent::Notification,
             HookEvent::PreferModernTools,
         ]
     }
@@ -151,6 +156,7 @@ impl HooksConfig {
     /// - PreCompact (handoff trigger)
     /// - SessionEnd (cleanup)
     /// - Stop (learning-validator)
+    /// - Notification (waiting detection)
     ///
     /// Returns a map of event type to hook rules.
     pub fn to_settings_hooks(&self) -> std::collections::HashMap<String, Vec<HookRule>> {
@@ -219,6 +225,19 @@ impl HooksConfig {
                 }],
             });
 
+        // Notification hook - records permission/input prompts in the heartbeat
+        // so the daemon can flip the stage to WaitingForInput
+        hooks_map
+            .entry("Notification".to_string())
+            .or_default()
+            .push(HookRule {
+                matcher: "*".to_string(),
+                hooks: vec![HookCommand {
+                    hook_type: "command".to_string(),
+                    command: self.build_command(HookEvent::Notification),
+                }],
+            });
+
         hooks_map
     }
 }
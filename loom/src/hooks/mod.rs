@@ -14,6 +14,7 @@
 //! - `PreCompact`: Called before context compaction (triggers handoff)
 //! - `SessionEnd`: Called when a session ends normally
 //! - `Stop`: Called when session is stopping (learning-validator)
+//! - `Notification`: Called on permission/input prompts (waiting detection)
 //! - `PreferModernTools`: Called before Bash to suggest modern CLI tools
 //! - `WorktreeIsolation`: Called before Bash/Edit/Write to enforce boundaries
 //!
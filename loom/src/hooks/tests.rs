@@ -21,6 +21,7 @@ mod config_tests {
         assert_eq!(HookEvent::PreCompact.to_string(), "PreCompact");
         assert_eq!(HookEvent::SessionEnd.to_string(), "SessionEnd");
         assert_eq!(HookEvent::Stop.to_string(), "Stop");
+        assert_eq!(HookEvent::Notification.to_string(), "Notification");
         assert_eq!(
             HookEvent::PreferModernTools.to_string(),
             "PreferModernTools"
@@ -34,6 +35,7 @@ mod config_tests {
         assert_eq!(HookEvent::PreCompact.script_name(), "pre-compact.sh");
         assert_eq!(HookEvent::SessionEnd.script_name(), "session-end.sh");
         assert_eq!(HookEvent::Stop.script_name(), "learning-validator.sh");
+        assert_eq!(HookEvent::Notification.script_name(), "notification.sh");
         assert_eq!(
             HookEvent::PreferModernTools.script_name(),
             "prefer-modern-tools.sh"
@@ -43,12 +45,13 @@ mod config_tests {
     #[test]
     fn test_hook_event_all() {
         let all = HookEvent::all();
-        assert_eq!(all.len(), 6);
+        assert_eq!(all.len(), 7);
         assert!(all.contains(&HookEvent::SessionStart));
         assert!(all.contains(&HookEvent::PostToolUse));
         assert!(all.contains(&HookEvent::PreCompact));
         assert!(all.contains(&HookEvent::SessionEnd));
         assert!(all.contains(&HookEvent::Stop));
+        assert!(all.contains(&HookEvent::Notification));
         assert!(all.contains(&HookEvent::PreferModernTools));
     }
 
@@ -110,6 +113,9 @@ mod config_tests {
         let stop_rules = &hooks["Stop"];
         assert!(!stop_rules.is_empty());
 
+        // Check Notification hook exists
+        assert!(hooks.contains_key("Notification"));
+
         // Check PostToolUse has * matcher
         assert!(hooks.contains_key("PostToolUse"));
         let post_tool_rules = &hooks["PostToolUse"];
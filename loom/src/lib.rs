@@ -11,15 +11,19 @@ pub mod hooks;
 pub mod language;
 pub mod map;
 pub mod models;
+pub mod namespace;
+pub mod offline;
 pub mod orchestrator;
 pub mod parser;
 pub mod plan;
 pub mod process;
 pub mod remote_control;
 pub mod sandbox;
+pub mod secrets;
 pub mod skills;
 pub mod utils;
 pub mod validation;
+pub mod verbosity;
 pub mod verify;
 
 /// ASCII art logo for loom CLI
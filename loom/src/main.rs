@@ -3,24 +3,42 @@ mod cli;
 use anyhow::Result;
 use clap::Parser;
 use cli::{dispatch, Cli};
+use loom::secrets::RedactingWriter;
+use loom::verbosity::{self, Verbosity};
 use tracing_subscriber::{fmt, EnvFilter};
 
 fn main() -> Result<()> {
     // Recover terminal state if a previous TUI was killed without cleanup
     loom::utils::recover_terminal_if_needed();
 
+    let cli = Cli::parse();
+    if let Some(path) = loom::commands::common::work_dir_override(cli.work_dir) {
+        loom::commands::common::enter_workspace(&path)?;
+    }
+    if cli.offline {
+        loom::offline::enable_for_process();
+    }
+    if cli.quiet {
+        verbosity::set_for_process(Verbosity::Quiet);
+    } else if cli.verbose {
+        verbosity::set_for_process(Verbosity::Verbose);
+    }
+
     // Initialize tracing subscriber
-    // Default level: warn, loom modules at info
+    // Default level: warn, loom modules at info (error only with --quiet,
+    // loom modules at debug with --verbose)
     // Configurable via RUST_LOG env var
-    let filter =
-        EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("warn,loom=info"));
+    let filter = EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| EnvFilter::new(verbosity::current().log_filter()));
 
     fmt()
         .with_env_filter(filter)
-        .with_writer(std::io::stderr)
+        .with_writer(|| RedactingWriter(std::io::stderr()))
         .try_init()
         .ok();
-
-    let cli = Cli::parse();
+    if let Ok(work_dir) = loom::commands::common::find_work_dir() {
+        loom::namespace::activate(&work_dir);
+        loom::secrets::activate(&work_dir);
+    }
     dispatch(cli.command)
 }
@@ -37,10 +37,32 @@ pub enum FailureType {
     /// Infrastructure error (git, worktree, filesystem issues)
     InfrastructureError,
 
+    /// Stage preconditions not met before spawning (missing setup binaries,
+    /// `files` matching nothing, missing acceptance scripts)
+    PreflightFailed,
+
     /// Unknown or unclassified failure
     Unknown,
 }
 
+impl std::fmt::Display for FailureType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FailureType::SessionCrash => write!(f, "Agent crash"),
+            FailureType::ContextExhausted => write!(f, "Context exhaustion"),
+            FailureType::TestFailure => write!(f, "Test failure"),
+            FailureType::BuildFailure => write!(f, "Compile error"),
+            FailureType::CodeError => write!(f, "Code error"),
+            FailureType::Timeout => write!(f, "Timeout"),
+            FailureType::UserBlocked => write!(f, "Blocked by user"),
+            FailureType::MergeConflict => write!(f, "Merge conflict"),
+            FailureType::InfrastructureError => write!(f, "Infrastructure error"),
+            FailureType::PreflightFailed => write!(f, "Pre-flight check failed"),
+            FailureType::Unknown => write!(f, "Unknown"),
+        }
+    }
+}
+
 /// Information about a failure that occurred during stage execution.
 ///
 /// This struct captures the type of failure, when it was detected,
@@ -2,7 +2,7 @@ use anyhow::Result;
 use chrono::Utc;
 use std::path::PathBuf;
 
-use super::types::{Session, SessionStatus, SessionType};
+use super::types::{Session, SessionRole, SessionStatus, SessionType};
 use crate::models::constants::{CONTEXT_CRITICAL_THRESHOLD, DEFAULT_CONTEXT_LIMIT};
 
 impl Session {
@@ -24,6 +24,8 @@ impl Session {
             merge_source_branch: None,
             merge_target_branch: None,
             tracking_key: String::new(),
+            container: None,
+            role: SessionRole::default(),
         }
     }
 
@@ -61,20 +63,42 @@ impl Session {
         session
     }
 
+    /// Create the reviewer session of a pair-mode stage.
+    pub fn new_pair_reviewer() -> Self {
+        let mut session = Self::new();
+        session.role = SessionRole::Reviewer;
+        session
+    }
+
+    /// Create a session representing a human working a claimed stage.
+    ///
+    /// The session is `Running` from the start: there is nothing to spawn,
+    /// and the monitor only tracks running sessions.
+    pub fn new_human(stage_id: &str) -> Self {
+        let mut session = Self::new();
+        session.session_type = SessionType::Human;
+        session.status = SessionStatus::Running;
+        session.assign_to_stage(stage_id.to_string());
+        session
+    }
+
     /// Derive the canonical tracking key for a session.
     ///
     /// The tracking key is used to find OS-level resources owned by this
     /// session (terminal window titles, etc.) without having to thread the
     /// session ID through every spawn/kill code path.
     ///
-    /// Format: `loom-[<kind>-]<stage_id>` where `<kind>` is omitted for
+    /// Format: `loom-[<ns>-][<kind>-]<stage_id>` where `<ns>` is the active
+    /// plan namespace (see [`crate::namespace`]) and `<kind>` is omitted for
     /// regular stage sessions.
     pub fn derive_tracking_key(stage_id: &str, kind: SessionType) -> String {
+        let prefix = crate::namespace::title_prefix();
         match kind {
-            SessionType::Stage => format!("loom-{stage_id}"),
-            SessionType::Merge => format!("loom-merge-{stage_id}"),
-            SessionType::BaseConflict => format!("loom-base-conflict-{stage_id}"),
-            SessionType::Knowledge => format!("loom-knowledge-{stage_id}"),
+            SessionType::Stage => format!("{prefix}{stage_id}"),
+            SessionType::Merge => format!("{prefix}merge-{stage_id}"),
+            SessionType::BaseConflict => format!("{prefix}base-conflict-{stage_id}"),
+            SessionType::Knowledge => format!("{prefix}knowledge-{stage_id}"),
+            SessionType::Human => format!("{prefix}human-{stage_id}"),
         }
     }
 
@@ -88,6 +112,16 @@ impl Session {
         self.session_type == SessionType::BaseConflict
     }
 
+    /// Check if this is the reviewer session of a pair-mode stage
+    pub fn is_pair_reviewer(&self) -> bool {
+        self.role == SessionRole::Reviewer
+    }
+
+    /// Check if this session is a human claim rather than an agent process
+    pub fn is_human_session(&self) -> bool {
+        self.session_type == SessionType::Human
+    }
+
     fn generate_id() -> String {
         let timestamp = Utc::now().timestamp();
         let uuid_short = uuid::Uuid::new_v4()
@@ -103,7 +137,12 @@ impl Session {
         // Derive the tracking key from the (stage_id, session_type) pair so
         // OS-level resource lookups (terminal titles) have a stable handle
         // even before the session has a PID.
-        self.tracking_key = Self::derive_tracking_key(&stage_id, self.session_type);
+        self.tracking_key = match self.role {
+            SessionRole::Implementer => Self::derive_tracking_key(&stage_id, self.session_type),
+            SessionRole::Reviewer => {
+                format!("{}reviewer-{stage_id}", crate::namespace::title_prefix())
+            }
+        };
         self.stage_id = Some(stage_id);
         self.last_active = Utc::now();
     }
@@ -2,7 +2,7 @@ mod methods;
 mod transitions;
 mod types;
 
-pub use types::{Session, SessionStatus, SessionType};
+pub use types::{Session, SessionRole, SessionStatus, SessionType};
 
 #[cfg(test)]
 mod tests;
@@ -22,6 +22,10 @@ fn tracking_key_matrix() {
         Session::derive_tracking_key("auth", SessionType::Knowledge),
         "loom-knowledge-auth"
     );
+    assert_eq!(
+        Session::derive_tracking_key("auth", SessionType::Human),
+        "loom-human-auth"
+    );
 }
 
 #[test]
@@ -47,6 +51,17 @@ fn knowledge_constructor_derives_tracking_key() {
     assert_eq!(session.tracking_key, "loom-knowledge-knowledge-bootstrap");
 }
 
+#[test]
+fn human_constructor_is_running_and_assigned() {
+    let session = Session::new_human("api");
+    assert_eq!(session.session_type, SessionType::Human);
+    assert_eq!(session.status, SessionStatus::Running);
+    assert_eq!(session.stage_id.as_deref(), Some("api"));
+    assert_eq!(session.tracking_key, "loom-human-api");
+    assert!(session.is_human_session());
+    assert!(session.pid.is_none());
+}
+
 #[test]
 fn legacy_session_without_new_fields_deserializes() {
     // Sessions written before runtime-identity fields existed must still parse.
@@ -16,6 +16,11 @@ pub enum SessionType {
     /// Knowledge-gathering session (runs in main repo, no worktree).
     /// Tracking key: `loom-knowledge-{stage_id}`.
     Knowledge,
+    /// Human-driven stage execution claimed via `loom stage claim`. No agent
+    /// process exists, so liveness probes always report it alive and kills
+    /// are no-ops; the claim ends when the stage is completed or reset.
+    /// Tracking key: `loom-human-{stage_id}`.
+    Human,
 }
 
 impl std::fmt::Display for SessionType {
@@ -25,6 +30,35 @@ impl std::fmt::Display for SessionType {
             SessionType::Merge => write!(f, "merge"),
             SessionType::BaseConflict => write!(f, "base_conflict"),
             SessionType::Knowledge => write!(f, "knowledge"),
+            SessionType::Human => write!(f, "human"),
+        }
+    }
+}
+
+/// A stage session's part in the stage's work
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SessionRole {
+    /// Does the stage's work (every session outside pair mode)
+    #[default]
+    Implementer,
+    /// Pair mode: reviews and tests the implementer's work without editing
+    /// files, and approves it with `loom stage pair-review`.
+    /// Tracking key: `loom-reviewer-{stage_id}`.
+    Reviewer,
+}
+
+impl SessionRole {
+    pub fn is_implementer(&self) -> bool {
+        *self == SessionRole::Implementer
+    }
+}
+
+impl std::fmt::Display for SessionRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SessionRole::Implementer => write!(f, "implementer"),
+            SessionRole::Reviewer => write!(f, "reviewer"),
         }
     }
 }
@@ -83,4 +117,11 @@ pub struct Session {
     /// May be empty for legacy sessions or sessions created before assignment.
     #[serde(default)]
     pub tracking_key: String,
+    /// Name of the docker container the agent runs in, for sessions spawned
+    /// by the docker backend. Liveness and kill go through docker when set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub container: Option<String>,
+    /// Implementer, or the reviewer of a pair-mode stage
+    #[serde(default, skip_serializing_if = "SessionRole::is_implementer")]
+    pub role: SessionRole,
 }
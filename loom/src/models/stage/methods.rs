@@ -1,7 +1,11 @@
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 
-use super::types::{AcceptanceCriterion, Stage, StageOutput, StageStatus, StageType};
+use super::types::{AcceptanceCriterion, OutputSpec, Stage, StageOutput, StageStatus, StageType};
+
+/// How far past its `expected_duration` an executing stage may run before
+/// it is flagged as overdue.
+pub const OVERDUE_FACTOR: f64 = 1.5;
 
 /// Maximum disputes a single stage may file before further requests
 /// are refused (escalation goes through `NeedsHumanReview`).
@@ -27,6 +31,8 @@ impl Stage {
             worktree: None,
             session: None,
             held: false,
+            held_by: None,
+            assignee: None,
             parent_stage: None,
             child_stages: Vec::new(),
             created_at: now,
@@ -66,12 +72,28 @@ impl Stage {
             execution_mode: None,
             max_fix_attempts: None,
             review_reason: None,
+            waiting_prompt: None,
+            waiting_since: None,
             bug_fix: None,
             regression_test: None,
             model: None,
             reasoning_effort: None,
             is_possibly_stuck: false,
             ultracode: false,
+            pair: false,
+            pair_review: None,
+            worktree_setup: Vec::new(),
+            instructions: None,
+            prompt_prefix: None,
+            container: None,
+            keep_terminal: None,
+            expected_outputs: Vec::new(),
+            params: Vec::new(),
+            requires_outputs: Vec::new(),
+            new_files: Vec::new(),
+            expected_duration_secs: None,
+            acceptance_isolation: None,
+            skip_if_satisfied: false,
         }
     }
 
@@ -154,8 +176,15 @@ impl Stage {
     /// `Ok(())` if the transition succeeded, `Err` if the transition is invalid
     pub fn try_transition(&mut self, new_status: StageStatus) -> Result<()> {
         let validated_status = self.status.try_transition(new_status)?;
+        let now = Utc::now();
+        if validated_status != StageStatus::WaitingForInput {
+            self.waiting_prompt = None;
+            self.waiting_since = None;
+        } else if self.status != StageStatus::WaitingForInput {
+            self.waiting_since = Some(now);
+        }
         self.status = validated_status;
-        self.updated_at = Utc::now();
+        self.updated_at = now;
         Ok(())
     }
 
@@ -246,6 +275,18 @@ impl Stage {
         self.try_transition(StageStatus::WaitingForInput)
     }
 
+    /// Mark the stage as waiting on a prompt detected from its heartbeat.
+    ///
+    /// The prompt is kept until the stage leaves `WaitingForInput`.
+    ///
+    /// # Returns
+    /// `Ok(())` if the transition succeeded, `Err` if invalid
+    pub fn try_mark_waiting_for_prompt(&mut self, prompt: String) -> Result<()> {
+        self.try_mark_waiting_for_input()?;
+        self.waiting_prompt = Some(prompt);
+        Ok(())
+    }
+
     /// Mark the stage as blocked with validation.
     ///
     /// # Returns
@@ -447,6 +488,7 @@ impl Stage {
     pub fn release(&mut self) {
         if self.held {
             self.held = false;
+            self.held_by = None;
             self.updated_at = Utc::now();
         }
     }
@@ -502,6 +544,60 @@ impl Stage {
         }
     }
 
+    /// Required dependency outputs (`requires_outputs`) that are not set yet.
+    ///
+    /// # Arguments
+    /// * `stages` - Stages to look the producers up in (at least the dependencies)
+    ///
+    /// # Returns
+    /// `(producer stage id, output key)` for each required output whose
+    /// producer is missing from `stages` or has not set the key.
+    pub fn missing_required_outputs(&self, stages: &[Stage]) -> Vec<(String, String)> {
+        self.requires_outputs
+            .iter()
+            .filter_map(|reference| reference.split_once('.'))
+            .filter(|(producer, key)| {
+                !stages
+                    .iter()
+                    .any(|s| s.id == *producer && s.get_output(key).is_some())
+            })
+            .map(|(producer, key)| (producer.to_string(), key.to_string()))
+            .collect()
+    }
+
+    /// Declared output spec for `key`, if the plan declares one.
+    pub fn expected_output(&self, key: &str) -> Option<&OutputSpec> {
+        self.expected_outputs.iter().find(|spec| spec.key == key)
+    }
+
+    /// Check the outputs set so far against the declared output schema.
+    ///
+    /// # Returns
+    /// One message per required output that is unset and per output whose
+    /// value does not match its declared type; empty when the schema holds.
+    pub fn output_schema_violations(&self) -> Vec<String> {
+        let mut violations = Vec::new();
+        for spec in &self.expected_outputs {
+            match self.get_output(&spec.key) {
+                None if spec.required => {
+                    violations.push(format!("required output '{}' is not set", spec.key));
+                }
+                None => {}
+                Some(output) => {
+                    if let Some(expected) = spec.output_type {
+                        if !expected.matches(&output.value) {
+                            violations.push(format!(
+                                "output '{}' should be {expected}, got {}",
+                                spec.key, output.value
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+        violations
+    }
+
     /// Check if an output key already exists in this stage.
     ///
     /// # Arguments
@@ -544,6 +640,27 @@ impl Stage {
             self.execution_secs = Some(current.saturating_add(elapsed));
         }
     }
+
+    /// Execution time so far: accumulated attempts plus the running one.
+    pub fn execution_elapsed_secs(&self, now: DateTime<Utc>) -> i64 {
+        let running = self
+            .attempt_started_at
+            .map(|start| now.signed_duration_since(start).num_seconds().max(0))
+            .unwrap_or(0);
+        self.execution_secs.unwrap_or(0).saturating_add(running)
+    }
+
+    /// Execution time of a stage still executing past [`OVERDUE_FACTOR`]
+    /// times its `expected_duration`, or `None` while it is on schedule (or
+    /// declares no expectation).
+    pub fn overdue_secs(&self, now: DateTime<Utc>) -> Option<i64> {
+        let expected = self.expected_duration_secs?;
+        if self.status != StageStatus::Executing {
+            return None;
+        }
+        let elapsed = self.execution_elapsed_secs(now);
+        (elapsed as f64 > expected as f64 * OVERDUE_FACTOR).then_some(elapsed)
+    }
 }
 
 #[cfg(test)]
@@ -618,4 +735,26 @@ mod tests {
 
         assert_eq!(stage.execution_secs, Some(100)); // Unchanged
     }
+
+    #[test]
+    fn test_overdue_secs() {
+        let now = Utc::now();
+        let mut stage = Stage {
+            status: StageStatus::Executing,
+            execution_secs: Some(600),
+            attempt_started_at: Some(now - Duration::minutes(30)),
+            ..Stage::default()
+        };
+        assert_eq!(stage.execution_elapsed_secs(now), 2400);
+        assert_eq!(stage.overdue_secs(now), None);
+
+        stage.expected_duration_secs = Some(1800);
+        assert_eq!(stage.overdue_secs(now), None);
+
+        stage.expected_duration_secs = Some(1500);
+        assert_eq!(stage.overdue_secs(now), Some(2400));
+
+        stage.status = StageStatus::Completed;
+        assert_eq!(stage.overdue_secs(now), None);
+    }
 }
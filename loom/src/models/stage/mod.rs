@@ -5,9 +5,11 @@ mod types;
 #[cfg(test)]
 mod tests;
 
+pub use methods::OVERDUE_FACTOR;
 pub use types::{
-    AcceptanceCriterion, DeadCodeCheck, ExecutionMode, FilesystemConfig, LinuxConfig,
-    NetworkConfig, PermissionMode, RegressionTest, Stage, StageOutput, StageSandboxConfig,
-    StageStatus, StageType, StatusBucket, SuccessCriteria, TruthCheck, WiringCheck, WiringTest,
-    ALLOWED_REASONING_EFFORTS,
+    AcceptanceCriterion, ContainerConfig, DeadCodeCheck, EnvIsolation, ExecutionMode,
+    FilesystemConfig, IsolationMode, LinuxConfig, NetworkConfig, OutputSpec, OutputType,
+    PairReview, PermissionMode, RegressionTest, Stage, StageOutput, StageParam, StageSandboxConfig,
+    StageStatus, StageType, StatusBucket, SuccessCriteria, TruthCheck, VerificationStatus,
+    WiringCheck, WiringTest, ALLOWED_REASONING_EFFORTS, DEFAULT_FLAKY_RETRIES,
 };
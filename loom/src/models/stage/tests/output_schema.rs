@@ -0,0 +1,84 @@
+use serde_json::json;
+
+use crate::models::stage::{OutputSpec, OutputType, StageOutput, StageStatus};
+
+use super::create_test_stage;
+
+fn spec(key: &str, output_type: Option<OutputType>, required: bool) -> OutputSpec {
+    OutputSpec {
+        key: key.to_string(),
+        output_type,
+        required,
+        description: None,
+    }
+}
+
+fn output(key: &str, value: serde_json::Value) -> StageOutput {
+    StageOutput {
+        key: key.to_string(),
+        value,
+        description: format!("Output: {key}"),
+    }
+}
+
+#[test]
+fn test_output_schema_reports_missing_required_and_mistyped() {
+    let mut stage = create_test_stage(StageStatus::Executing);
+    stage.expected_outputs = vec![
+        spec("api_port", Some(OutputType::Int), true),
+        spec("base_url", Some(OutputType::String), true),
+        spec("notes", None, false),
+        spec("ratio", Some(OutputType::Number), false),
+    ];
+    stage.set_output(output("api_port", json!("8080")));
+    stage.set_output(output("ratio", json!(3)));
+
+    assert_eq!(
+        stage.output_schema_violations(),
+        vec![
+            "output 'api_port' should be int, got \"8080\"".to_string(),
+            "required output 'base_url' is not set".to_string(),
+        ]
+    );
+
+    stage.set_output(output("api_port", json!(8080)));
+    stage.set_output(output("base_url", json!("http://localhost:8080")));
+    assert!(stage.output_schema_violations().is_empty());
+}
+
+#[test]
+fn test_output_type_matches() {
+    assert!(OutputType::Int.matches(&json!(1)));
+    assert!(!OutputType::Int.matches(&json!(1.5)));
+    assert!(OutputType::Number.matches(&json!(1.5)));
+    assert!(OutputType::Bool.matches(&json!(false)));
+    assert!(OutputType::Array.matches(&json!([1])));
+    assert!(OutputType::Object.matches(&json!({"a": 1})));
+    assert!(!OutputType::String.matches(&json!(null)));
+}
+
+#[test]
+fn test_missing_required_outputs() {
+    let mut producer = create_test_stage(StageStatus::Completed);
+    producer.id = "stage-x".to_string();
+    let mut consumer = create_test_stage(StageStatus::Queued);
+    consumer.requires_outputs = vec![
+        "stage-x.schema_version".to_string(),
+        "stage-z.api_port".to_string(),
+    ];
+
+    let stages = vec![producer.clone()];
+    assert_eq!(
+        consumer.missing_required_outputs(&stages),
+        vec![
+            ("stage-x".to_string(), "schema_version".to_string()),
+            ("stage-z".to_string(), "api_port".to_string()),
+        ]
+    );
+
+    producer.set_output(output("schema_version", json!(3)));
+    assert_eq!(
+        consumer.missing_required_outputs(&[producer]),
+        vec![("stage-z".to_string(), "api_port".to_string())]
+    );
+}
@@ -117,3 +117,18 @@ fn test_stage_try_mark_waiting_for_input_valid() {
     assert!(result.is_ok());
     assert_eq!(stage.status, StageStatus::WaitingForInput);
 }
+
+#[test]
+fn test_stage_waiting_since_tracks_wait_for_input() {
+    let mut stage = create_test_stage(StageStatus::Executing);
+    stage.try_mark_waiting_for_input().unwrap();
+    let since = stage
+        .waiting_since
+        .expect("waiting_since set on entering wait");
+
+    stage.try_mark_executing().unwrap();
+    assert_eq!(stage.waiting_since, None);
+
+    stage.try_mark_waiting_for_input().unwrap();
+    assert!(stage.waiting_since.unwrap() >= since);
+}
@@ -25,6 +25,10 @@ pub enum StageType {
     /// This is a WORKTREE stage — NOT a Knowledge stage. It gets a branch
     /// and merge like Standard/IntegrationVerify.
     KnowledgeDistill,
+    /// Code review of one implementation stage's changes (created by loom
+    /// when the plan sets `auto_code_review: true`). A read-only worktree
+    /// stage; its `approved` output gates the reviewed stage's merge.
+    CodeReview,
 }
 
 impl StageType {
@@ -44,6 +48,8 @@ impl StageType {
             StageType::KnowledgeDistill => "opus",
             // Standard and integration-verify stages default to opus
             StageType::Standard | StageType::IntegrationVerify => "opus",
+            // Reviews are judgement calls on someone else's diff
+            StageType::CodeReview => "opus",
         }
     }
 
@@ -60,11 +66,12 @@ impl StageType {
     }
 }
 
-/// Hint for how the stage should be executed.
+/// How the stage should be executed.
 ///
-/// This is an advisory field for orchestration tooling:
 /// - `Single`: Default mode, single agent executes the stage
-/// - `Team`: Stage benefits from coordinated multi-agent work
+/// - `Team`: Stage benefits from coordinated multi-agent work (advisory)
+/// - `Headless`: Agent runs as `claude --print` without a terminal window;
+///   output goes to `.work/logs/sessions/<session-id>.log`
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub enum ExecutionMode {
@@ -73,6 +80,8 @@ pub enum ExecutionMode {
     Single,
     /// Coordinated multi-agent team execution
     Team,
+    /// Non-interactive `claude --print` run with no terminal window
+    Headless,
 }
 
 /// Wiring check to verify component connections.
@@ -123,6 +132,26 @@ impl PermissionMode {
     }
 }
 
+/// Container a stage's agent session runs in (docker backend)
+///
+/// The worktree, the `.work` directory and the user's Claude configuration
+/// are bind-mounted at their host paths, so signal paths and hook settings
+/// resolve unchanged inside the container. The image must provide `claude`,
+/// `bash`, `git` and `jq`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContainerConfig {
+    /// Image to run (e.g. `ghcr.io/acme/rust-toolchain:1.79`)
+    pub image: String,
+
+    /// Extra bind mounts, in `docker run -v` syntax (`host:container[:ro]`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub volumes: Vec<String>,
+
+    /// Extra environment, as `NAME=value` or `NAME` to pass through the host value
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub env: Vec<String>,
+}
+
 /// Per-stage sandbox configuration (overrides plan-level defaults)
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct StageSandboxConfig {
@@ -158,6 +187,16 @@ pub struct StageSandboxConfig {
     /// When unset, the plan-level override (or stage type default) applies.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub permission_mode: Option<PermissionMode>,
+
+    /// Worktree-relative globs the agent's Read/Glob/Grep calls are limited to.
+    /// Empty means the whole worktree is readable.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub readable_paths: Vec<String>,
+
+    /// Worktree-relative globs the agent's Read/Glob/Grep calls may not touch.
+    /// Takes precedence over `readable_paths`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub forbidden_paths: Vec<String>,
 }
 
 /// Filesystem access configuration
@@ -286,7 +325,8 @@ fn default_deny_write() -> Vec<String> {
 /// All extended fields are optional for backward compatibility.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TruthCheck {
-    /// Shell command to execute
+    /// Shell command to execute (`run` is accepted as an alias)
+    #[serde(alias = "run")]
     pub command: String,
     /// Strings that must appear in stdout
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
@@ -303,6 +343,72 @@ pub struct TruthCheck {
     /// Human-readable description of what this truth verifies
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    /// Short name reported instead of the command (acceptance criteria only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Free-form group such as `test`, `lint` or `build` that results are
+    /// summarized by (acceptance criteria only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    /// Seconds the command may run before it is killed (acceptance criteria
+    /// only; defaults to 30s for extended criteria)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<u64>,
+    /// Extra attempts after a failure (acceptance criteria only).
+    /// Defaults to `DEFAULT_FLAKY_RETRIES` when `flaky` is set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retries: Option<u32>,
+    /// Marks the command as known-flaky (acceptance criteria only): it is
+    /// retried on failure and its pass/fail history is tracked in
+    /// `.work/verifications/flaky.json`.
+    #[serde(default)]
+    pub flaky: bool,
+    /// Environment isolation for this command (acceptance criteria only);
+    /// overrides the stage's `acceptance_isolation` mode and adds to its
+    /// `allow` list
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub isolation: Option<EnvIsolation>,
+}
+
+/// Environment an acceptance command runs in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum IsolationMode {
+    /// loom's own environment (default)
+    #[default]
+    Inherit,
+    /// An empty environment (`env -i`) plus a small base set and `allow`
+    Clean,
+    /// A clean environment inside the stage's sandbox profile: read-only
+    /// filesystem outside the working directory, `deny_read` paths hidden,
+    /// no network unless the profile allows domains (Linux, via `bwrap`)
+    Sandbox,
+}
+
+/// Environment isolation for acceptance criteria, set per stage
+/// (`acceptance_isolation`) or per criterion (`isolation`)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EnvIsolation {
+    #[serde(default)]
+    pub mode: IsolationMode,
+    /// Variables passed through from loom's environment besides the base
+    /// set (`PATH`, `HOME`, `USER`, `LANG`, `TERM`, `TMPDIR`, ...)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allow: Vec<String>,
+}
+
+/// Retries granted to a `flaky: true` criterion that does not set `retries`.
+pub const DEFAULT_FLAKY_RETRIES: u32 = 2;
+
+impl TruthCheck {
+    /// Number of extra attempts allowed after a failure.
+    pub fn retry_count(&self) -> u32 {
+        match self.retries {
+            Some(n) => n,
+            None if self.flaky => DEFAULT_FLAKY_RETRIES,
+            None => 0,
+        }
+    }
 }
 
 /// Unified acceptance criterion - either a simple shell command or an extended check.
@@ -314,6 +420,13 @@ pub struct TruthCheck {
 ///   - command: "loom --help"                  # Extended
 ///     stdout_contains: ["Usage:"]
 ///     exit_code: 0
+///   - command: "npm run e2e"                  # Retried up to 3 extra times
+///     flaky: true
+///     retries: 3
+///   - name: "unit tests"                      # Reported by name and category
+///     run: "cargo test"
+///     category: test
+///     timeout: 600
 /// ```
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -337,6 +450,61 @@ impl AcceptanceCriterion {
     pub fn is_extended(&self) -> bool {
         matches!(self, AcceptanceCriterion::Extended(_))
     }
+
+    /// Name given to the criterion, if any
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            AcceptanceCriterion::Simple(_) => None,
+            AcceptanceCriterion::Extended(check) => check.name.as_deref(),
+        }
+    }
+
+    /// Category the criterion is reported under, if any
+    pub fn category(&self) -> Option<&str> {
+        match self {
+            AcceptanceCriterion::Simple(_) => None,
+            AcceptanceCriterion::Extended(check) => check.category.as_deref(),
+        }
+    }
+
+    /// Name if set, otherwise the command
+    pub fn label(&self) -> &str {
+        self.name().unwrap_or_else(|| self.command())
+    }
+
+    /// Explicit per-criterion timeout, if any
+    pub fn timeout(&self) -> Option<std::time::Duration> {
+        match self {
+            AcceptanceCriterion::Simple(_) => None,
+            AcceptanceCriterion::Extended(check) => {
+                check.timeout.map(std::time::Duration::from_secs)
+            }
+        }
+    }
+
+    /// Extra attempts allowed after a failure (0 for simple criteria)
+    pub fn retry_count(&self) -> u32 {
+        match self {
+            AcceptanceCriterion::Simple(_) => 0,
+            AcceptanceCriterion::Extended(check) => check.retry_count(),
+        }
+    }
+
+    /// Environment isolation set on the criterion itself, if any
+    pub fn isolation(&self) -> Option<&EnvIsolation> {
+        match self {
+            AcceptanceCriterion::Simple(_) => None,
+            AcceptanceCriterion::Extended(check) => check.isolation.as_ref(),
+        }
+    }
+
+    /// Whether this criterion's outcomes are tracked in the flaky statistics
+    pub fn is_flaky_tracked(&self) -> bool {
+        match self {
+            AcceptanceCriterion::Simple(_) => false,
+            AcceptanceCriterion::Extended(check) => check.flaky || check.retry_count() > 0,
+        }
+    }
 }
 
 impl std::fmt::Display for AcceptanceCriterion {
@@ -463,6 +631,103 @@ pub struct StageOutput {
     pub description: String,
 }
 
+/// JSON type a declared stage output must have.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputType {
+    String,
+    #[serde(alias = "integer")]
+    Int,
+    #[serde(alias = "float")]
+    Number,
+    #[serde(alias = "boolean")]
+    Bool,
+    Array,
+    Object,
+}
+
+impl OutputType {
+    /// Whether `value` has this type. Integers also satisfy `number`.
+    pub fn matches(&self, value: &Value) -> bool {
+        match self {
+            OutputType::String => value.is_string(),
+            OutputType::Int => value.is_i64() || value.is_u64(),
+            OutputType::Number => value.is_number(),
+            OutputType::Bool => value.is_boolean(),
+            OutputType::Array => value.is_array(),
+            OutputType::Object => value.is_object(),
+        }
+    }
+}
+
+impl std::fmt::Display for OutputType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OutputType::String => write!(f, "string"),
+            OutputType::Int => write!(f, "int"),
+            OutputType::Number => write!(f, "number"),
+            OutputType::Bool => write!(f, "bool"),
+            OutputType::Array => write!(f, "array"),
+            OutputType::Object => write!(f, "object"),
+        }
+    }
+}
+
+/// An output a stage declares in the plan, checked at `loom stage complete`.
+///
+/// Stage files store the produced values under the same `outputs` key, so
+/// every field but `key` is optional to let those parse as well; an output
+/// without a type accepts any value.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OutputSpec {
+    pub key: String,
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub output_type: Option<OutputType>,
+    /// Whether completion fails while the output is unset
+    #[serde(default)]
+    pub required: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// A value a stage needs from whoever starts the run, declared in the plan
+/// (e.g. `{name: target_env, choices: [staging, prod]}`).
+///
+/// `loom run` asks for it, or takes it from `--param`, and stores the answer
+/// in `.work/params.json`; commands use it as `{{params.<name>}}`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StageParam {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Allowed values; any value is accepted when empty
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub choices: Vec<String>,
+    /// Used when the value is neither given nor asked for interactively
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+}
+
+impl StageParam {
+    /// Whether `value` is one of the allowed choices.
+    pub fn accepts(&self, value: &str) -> bool {
+        self.choices.is_empty() || self.choices.iter().any(|choice| choice == value)
+    }
+}
+
+/// A pair reviewer's verdict on a pair-mode stage.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PairReview {
+    pub approved: bool,
+    /// Worktree HEAD the verdict covers; an approval only counts while HEAD
+    /// has not moved
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+    pub reviewed_at: DateTime<Utc>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Stage {
     pub id: String,
@@ -483,6 +748,14 @@ pub struct Stage {
     pub session: Option<String>,
     #[serde(default)]
     pub held: bool,
+    /// Waiting stage whose escalation held this stage. Set only for holds
+    /// loom placed itself, which it releases once that stage resumes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub held_by: Option<String>,
+    /// Person responsible for the stage (`loom stage assign`); status views
+    /// show it and `[notify]` routes the stage's notifications to them
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<String>,
     pub parent_stage: Option<String>,
     pub child_stages: Vec<String>,
     pub created_at: DateTime<Utc>,
@@ -598,7 +871,7 @@ pub struct Stage {
     /// Per-stage sandbox configuration
     #[serde(default)]
     pub sandbox: StageSandboxConfig,
-    /// Hint for execution mode (single agent vs team)
+    /// Execution mode (single agent, team, or headless)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub execution_mode: Option<ExecutionMode>,
     /// Maximum fix attempts allowed (None = use default of 3)
@@ -607,6 +880,14 @@ pub struct Stage {
     /// Reason the stage was flagged for human review
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub review_reason: Option<String>,
+    /// Prompt the session is blocked on (permission approval or input request),
+    /// detected from its heartbeat. Set only while auto-detected as waiting.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub waiting_prompt: Option<String>,
+    /// When the stage entered `WaitingForInput`; cleared when it leaves.
+    /// Drives escalation of stages left waiting (see `orchestrator::escalation`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub waiting_since: Option<DateTime<Utc>>,
     /// Whether this stage is a bug fix that requires a regression test
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub bug_fix: Option<bool>,
@@ -636,6 +917,63 @@ pub struct Stage {
     /// (multi-agent fan-out). Copied from the plan's StageDefinition.
     #[serde(default)]
     pub ultracode: bool,
+    /// Pair mode: a read-only reviewer session runs next to the implementer
+    /// and must approve before the stage can complete. Copied from the plan's
+    /// StageDefinition.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub pair: bool,
+    /// The pair reviewer's latest verdict
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pair_review: Option<PairReview>,
+    /// Commands loom runs in the worktree before spawning a session
+    /// (e.g. `npm ci`, `cargo fetch`). Copied from the plan's StageDefinition.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub worktree_setup: Vec<String>,
+    /// Stage-specific instructions appended to the signal's dynamic section.
+    /// Copied from the plan's StageDefinition.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+    /// Replacement for the generic initial prompt passed to the agent session.
+    /// Copied from the plan's StageDefinition.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prompt_prefix: Option<String>,
+    /// Container the agent session runs in instead of the host.
+    /// Copied from the plan's StageDefinition.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub container: Option<ContainerConfig>,
+    /// Keep this stage's terminal windows open after the agent exits
+    /// (`None` = the `[terminal] keep_open` default).
+    /// Copied from the plan's StageDefinition.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keep_terminal: Option<bool>,
+    /// Outputs the plan declares this stage must produce.
+    /// Copied from the plan's StageDefinition `outputs`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub expected_outputs: Vec<OutputSpec>,
+    /// Run-time parameters the stage's commands use as `{{params.<name>}}`.
+    /// Copied from the plan's StageDefinition.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub params: Vec<StageParam>,
+    /// Outputs of dependencies this stage needs before it can start, as
+    /// `<stage-id>.<key>`. Copied from the plan's StageDefinition.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub requires_outputs: Vec<String>,
+    /// Paths or globs the stage creates, exempt from pre-flight existence
+    /// checks. Copied from the plan's StageDefinition.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub new_files: Vec<String>,
+    /// Expected execution time in seconds, parsed from the plan's
+    /// StageDefinition `expected_duration`. See [`Stage::overdue_secs`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_duration_secs: Option<u64>,
+    /// Environment isolation for every acceptance criterion of the stage.
+    /// Copied from the plan's StageDefinition.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub acceptance_isolation: Option<EnvIsolation>,
+    /// Skip the stage when its checks already pass on the base.
+    /// Copied from the plan's StageDefinition.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub skip_if_satisfied: bool,
 }
 
 /// Status of a stage in the execution lifecycle.
@@ -980,6 +1318,8 @@ impl Default for Stage {
             worktree: None,
             session: None,
             held: false,
+            held_by: None,
+            assignee: None,
             parent_stage: None,
             child_stages: Vec::new(),
             created_at: now,
@@ -1019,12 +1359,28 @@ impl Default for Stage {
             execution_mode: None,
             max_fix_attempts: None,
             review_reason: None,
+            waiting_prompt: None,
+            waiting_since: None,
             bug_fix: None,
             regression_test: None,
             model: None,
             reasoning_effort: None,
             is_possibly_stuck: false,
             ultracode: false,
+            pair: false,
+            pair_review: None,
+            worktree_setup: Vec::new(),
+            instructions: None,
+            prompt_prefix: None,
+            container: None,
+            keep_terminal: None,
+            expected_outputs: Vec::new(),
+            params: Vec::new(),
+            requires_outputs: Vec::new(),
+            new_files: Vec::new(),
+            expected_duration_secs: None,
+            acceptance_isolation: None,
+            skip_if_satisfied: false,
         }
     }
 }
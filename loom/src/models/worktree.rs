@@ -127,9 +127,10 @@ impl Worktree {
     /// * `stage_id` - The stage identifier
     ///
     /// # Returns
-    /// A path in the format "{base}/.worktrees/{stage_id}"
+    /// A path in the format "{base}/.worktrees/{stage_id}", or under the
+    /// configured `[worktrees] root`
     pub fn worktree_path(base: &Path, stage_id: &str) -> PathBuf {
-        base.join(".worktrees").join(stage_id)
+        crate::git::worktree::get_worktree_path(stage_id, base)
     }
 }
 
@@ -0,0 +1,215 @@
+//! Plan-scoped namespacing of branches and terminal windows.
+//!
+//! Stage branches (`loom/<stage>`) and terminal window titles
+//! (`loom-<stage>`) are derived from the stage id alone, so two plans that
+//! reuse a stage id collide: a second plan in the same repository finds the
+//! first plan's branch, and a run in another repository can match (and kill)
+//! the wrong window.
+//!
+//! `loom init` records a short namespace in `[plan] namespace` of
+//! `.work/config.toml`, derived from the repository root and plan id. Every
+//! `loom` process activates it once at startup, after which:
+//!   * stage branches are `loom/<ns>/<stage>` (base branches
+//!     `loom/<ns>/_base/<stage>`)
+//!   * tracking keys and window titles are `loom-<ns>-[<kind>-]<stage>`
+//!
+//! Work directories initialized before namespacing have no `namespace` key
+//! and keep the unprefixed names, so in-flight runs are unaffected.
+//!
+//! Teams whose branch protection reserves the `loom/` prefix can set
+//! `branch_template` in the plan's `loom` metadata (recorded in `[plan]`),
+//! e.g. `ai/{plan}/{stage}`. `{plan}` is the plan id and `{ns}` the
+//! namespace; `{stage}` must come last, so everything before it is the
+//! branch prefix (base branches become `<prefix>_base/<stage>`).
+
+use anyhow::{bail, Result};
+use sha2::{Digest, Sha256};
+use std::path::Path;
+use std::sync::OnceLock;
+
+use crate::fs::work_dir::load_config;
+
+/// Digest bytes kept in a derived namespace (two hex characters each).
+const NAMESPACE_BYTES: usize = 4;
+
+/// Placeholder a branch template must end with.
+pub const STAGE_PLACEHOLDER: &str = "{stage}";
+
+static NAMESPACE: OnceLock<Option<String>> = OnceLock::new();
+
+/// Branch prefix rendered from `[plan] branch_template`, when one is set.
+static TEMPLATE_PREFIX: OnceLock<Option<String>> = OnceLock::new();
+
+/// Derive the namespace for a plan initialized in `repo_root`.
+///
+/// Re-initializing the same plan in the same repository yields the same
+/// namespace, so resumed runs find their existing branches.
+pub fn derive(repo_root: &Path, plan_id: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(repo_root.to_string_lossy().as_bytes());
+    hasher.update([0]);
+    hasher.update(plan_id.as_bytes());
+    hex::encode(&hasher.finalize()[..NAMESPACE_BYTES])
+}
+
+/// Activate the namespace recorded in `work_dir`'s config for this process.
+///
+/// Called once from `main` before dispatch. Later calls are ignored: every
+/// name this process creates must agree with the names it already created.
+pub fn activate(work_dir: &Path) {
+    let config = load_config(work_dir).ok().flatten();
+    let namespace = config
+        .as_ref()
+        .and_then(|config| config.namespace().map(String::from));
+    let template_prefix = config.as_ref().and_then(|config| {
+        let template = config.branch_template()?;
+        match render_branch_prefix(template, config.plan_id(), namespace.as_deref()) {
+            Ok(prefix) => Some(prefix),
+            Err(e) => {
+                eprintln!("Warning: ignoring [plan] branch_template: {e}");
+                None
+            }
+        }
+    });
+    let _ = NAMESPACE.set(namespace);
+    let _ = TEMPLATE_PREFIX.set(template_prefix);
+}
+
+/// The active namespace, if any.
+pub fn current() -> Option<&'static str> {
+    NAMESPACE.get().and_then(|ns| ns.as_deref())
+}
+
+/// Prefix of stage branch names: `loom/`, `loom/<ns>/`, or the prefix of
+/// the configured branch template.
+pub fn branch_prefix() -> String {
+    match TEMPLATE_PREFIX.get().and_then(|prefix| prefix.as_deref()) {
+        Some(prefix) => prefix.to_string(),
+        None => branch_prefix_for(current()),
+    }
+}
+
+/// Prefix of tracking keys and window titles: `loom-` or `loom-<ns>-`.
+pub fn title_prefix() -> String {
+    title_prefix_for(current())
+}
+
+fn branch_prefix_for(namespace: Option<&str>) -> String {
+    match namespace {
+        Some(ns) => format!("loom/{ns}/"),
+        None => "loom/".to_string(),
+    }
+}
+
+/// Check a branch template: `{stage}` exactly once and last, only known
+/// placeholders, and a non-empty prefix usable in a git ref name.
+pub fn validate_branch_template(template: &str) -> Result<()> {
+    render_branch_prefix(template, Some("plan"), Some("ns")).map(|_| ())
+}
+
+/// Render the branch prefix of `template`: the part before `{stage}`, with
+/// `{plan}` and `{ns}` filled in. A placeholder with no value is dropped
+/// along with its trailing `/`.
+fn render_branch_prefix(
+    template: &str,
+    plan_id: Option<&str>,
+    namespace: Option<&str>,
+) -> Result<String> {
+    let Some(raw) = template.strip_suffix(STAGE_PLACEHOLDER) else {
+        bail!("'{template}' must end with {STAGE_PLACEHOLDER}");
+    };
+    if raw.contains(STAGE_PLACEHOLDER) {
+        bail!("'{template}' must contain {STAGE_PLACEHOLDER} only once");
+    }
+
+    let mut prefix = raw.to_string();
+    for (placeholder, value) in [("{plan}", plan_id), ("{ns}", namespace)] {
+        match value {
+            Some(value) => prefix = prefix.replace(placeholder, value),
+            None => {
+                prefix = prefix
+                    .replace(&format!("{placeholder}/"), "")
+                    .replace(placeholder, "")
+            }
+        }
+    }
+
+    if let Some(start) = prefix.find('{') {
+        let end = prefix[start..]
+            .find('}')
+            .map_or(prefix.len(), |i| start + i + 1);
+        bail!(
+            "'{template}' has unknown placeholder {}; use {{plan}}, {{ns}} and {STAGE_PLACEHOLDER}",
+            &prefix[start..end]
+        );
+    }
+    if prefix.is_empty() {
+        bail!("'{template}' needs a prefix before {STAGE_PLACEHOLDER}");
+    }
+    let invalid = prefix.starts_with('/')
+        || prefix.starts_with('-')
+        || prefix.contains("..")
+        || prefix.contains("//")
+        || prefix.contains("@{")
+        || prefix
+            .chars()
+            .any(|c| c.is_whitespace() || c.is_control() || "~^:?*[\\".contains(c));
+    if invalid {
+        bail!("'{template}' does not give a valid git branch name");
+    }
+    Ok(prefix)
+}
+
+fn title_prefix_for(namespace: Option<&str>) -> String {
+    match namespace {
+        Some(ns) => format!("loom-{ns}-"),
+        None => "loom-".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_derive_is_stable_and_plan_scoped() {
+        let repo = Path::new("/repos/app");
+        let ns = derive(repo, "PLAN-0001");
+        assert_eq!(ns.len(), NAMESPACE_BYTES * 2);
+        assert!(ns.chars().all(|c| c.is_ascii_hexdigit()));
+        assert_eq!(ns, derive(repo, "PLAN-0001"));
+        assert_ne!(ns, derive(repo, "PLAN-0002"));
+        assert_ne!(ns, derive(Path::new("/repos/other"), "PLAN-0001"));
+    }
+
+    #[test]
+    fn test_prefixes() {
+        assert_eq!(branch_prefix_for(None), "loom/");
+        assert_eq!(branch_prefix_for(Some("ab12cd34")), "loom/ab12cd34/");
+        assert_eq!(title_prefix_for(None), "loom-");
+        assert_eq!(title_prefix_for(Some("ab12cd34")), "loom-ab12cd34-");
+    }
+
+    #[test]
+    fn test_render_branch_prefix() {
+        let render = |template| render_branch_prefix(template, Some("PLAN-7"), Some("ab12cd34"));
+        assert_eq!(render("ai/{plan}/{stage}").unwrap(), "ai/PLAN-7/");
+        assert_eq!(render("ai/{ns}-{stage}").unwrap(), "ai/ab12cd34-");
+        assert_eq!(render("agents/{stage}").unwrap(), "agents/");
+        assert_eq!(
+            render_branch_prefix("ai/{ns}/{stage}", Some("PLAN-7"), None).unwrap(),
+            "ai/"
+        );
+    }
+
+    #[test]
+    fn test_validate_branch_template() {
+        assert!(validate_branch_template("ai/{plan}/{stage}").is_ok());
+        assert!(validate_branch_template("ai/{stage}/work").is_err());
+        assert!(validate_branch_template("{stage}").is_err());
+        assert!(validate_branch_template("ai/{stage}{stage}").is_err());
+        assert!(validate_branch_template("ai/{team}/{stage}").is_err());
+        assert!(validate_branch_template("ai bots/{stage}").is_err());
+        assert!(validate_branch_template("ai..x/{stage}").is_err());
+    }
+}
@@ -0,0 +1,114 @@
+//! Offline (air-gapped) mode.
+//!
+//! Some repositories live on machines without network access, where a
+//! connection attempt can hang for minutes before timing out. Offline mode
+//! turns every network-touching path into an immediate, explained failure.
+//!
+//! Resolution model:
+//!   * The global `--offline` flag exports `LOOM_OFFLINE=1` for the current
+//!     process, so the daemon and any other `loom` processes it spawns
+//!     inherit the setting.
+//!   * `LOOM_OFFLINE` can also be set directly (e.g. in a shell profile on an
+//!     air-gapped machine). `1`, `true`, `yes` and `on` enable it.
+//!   * `[offline] enabled = true` in `.work/config.toml` enables it for one
+//!     repository.
+//!
+//! Paths gated by offline mode:
+//!   * `loom self-update` (GitHub releases API and downloads)
+//!   * the dispute adjudicator (Anthropic Messages API) — disputes route to
+//!     human review instead
+//!   * Claude Code Remote Control — never enabled
+//!   * pushing stage branches (`push_branches`) — skipped with a warning
+
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use crate::fs::work_dir::read_offline_config;
+
+/// Environment variable that enables offline mode.
+pub const OFFLINE_ENV: &str = "LOOM_OFFLINE";
+
+/// Persisted `[offline]` section of `.work/config.toml`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct OfflineConfig {
+    /// Disable every network-touching code path for this repository.
+    #[serde(default)]
+    pub enabled: bool,
+}
+
+/// Enable offline mode for this process and every process it spawns.
+///
+/// Called once from `main` before dispatch, while the process is still
+/// single-threaded.
+pub fn enable_for_process() {
+    std::env::set_var(OFFLINE_ENV, "1");
+}
+
+/// Whether `LOOM_OFFLINE` enables offline mode.
+fn env_enabled() -> bool {
+    std::env::var(OFFLINE_ENV).is_ok_and(|v| parse_env_flag(&v))
+}
+
+/// Whether an environment value reads as "on".
+pub(crate) fn parse_env_flag(value: &str) -> bool {
+    matches!(
+        value.trim().to_ascii_lowercase().as_str(),
+        "1" | "true" | "yes" | "on"
+    )
+}
+
+/// Whether offline mode is enabled, from the environment or (when a work
+/// directory is known) `.work/config.toml`.
+///
+/// A config file that cannot be read counts as "not offline" so a broken
+/// config never blocks commands that would work anyway.
+pub fn is_offline(work_dir: Option<&Path>) -> bool {
+    env_enabled()
+        || work_dir
+            .and_then(|dir| read_offline_config(dir).ok())
+            .is_some_and(|config| config.enabled)
+}
+
+/// Fail fast if offline mode is enabled. `action` names what needs the
+/// network, e.g. "loom self-update".
+pub fn ensure_online(work_dir: Option<&Path>, action: &str) -> Result<()> {
+    if is_offline(work_dir) {
+        bail!(
+            "{action} needs network access, but loom is in offline mode \
+             (--offline, {OFFLINE_ENV}, or [offline] enabled = true in .work/config.toml)"
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fs::work_dir::write_offline_config;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_config_enables_offline_mode() {
+        let temp = TempDir::new().unwrap();
+        let work_dir = temp.path();
+        assert!(!read_offline_config(work_dir).unwrap().enabled);
+
+        write_offline_config(work_dir, &OfflineConfig { enabled: true }).unwrap();
+        assert!(is_offline(Some(work_dir)));
+        let err = ensure_online(Some(work_dir), "loom self-update").unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("loom self-update needs network access"));
+    }
+
+    #[test]
+    fn test_parse_env_flag() {
+        for value in ["1", "true", "TRUE", " yes ", "on"] {
+            assert!(parse_env_flag(value), "{value:?}");
+        }
+        for value in ["", "0", "false", "off", "no"] {
+            assert!(!parse_env_flag(value), "{value:?}");
+        }
+    }
+}
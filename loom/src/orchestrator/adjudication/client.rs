@@ -76,6 +76,8 @@ pub fn call_anthropic_with(
     model: &str,
     endpoint: &str,
 ) -> Result<String> {
+    crate::offline::ensure_online(None, "The dispute adjudicator")?;
+
     let client = reqwest::blocking::Client::builder()
         .timeout(Duration::from_secs(REQUEST_TIMEOUT_SECS))
         .build()
@@ -15,9 +15,10 @@
 //!   so dropped sessions don't leak threads.
 //!
 //! The registry is owned by the [`Orchestrator`] and lives for the
-//! entire daemon run. When `ANTHROPIC_API_KEY` is unset the registry
-//! goes into "disabled" mode: workers are never spawned and any
-//! pending disputes route directly to `NeedsHumanReview`.
+//! entire daemon run. When `ANTHROPIC_API_KEY` is unset, or loom is in
+//! offline mode (see [`crate::offline`]), the registry goes into
+//! "disabled" mode: workers are never spawned and any pending disputes
+//! route directly to `NeedsHumanReview`.
 
 pub mod client;
 pub mod feedback;
@@ -63,6 +64,9 @@ pub struct AdjudicatorRegistry {
     /// API key. `None` permanently disables the adjudicator for this
     /// daemon run.
     pub api_key: Option<String>,
+    /// Offline mode was on at startup; the key is discarded and the
+    /// adjudicator never connects for this daemon run.
+    pub offline: bool,
     /// Adjudicator endpoint URL. Overridable for tests.
     pub endpoint: String,
     /// Model identifier sent in the JSON body.
@@ -78,13 +82,16 @@ pub struct AdjudicatorRegistry {
 }
 
 impl AdjudicatorRegistry {
-    /// Construct a registry. `api_key.is_none()` puts the registry in
-    /// disabled mode permanently for the lifetime of this daemon run.
+    /// Construct a registry. `api_key.is_none()` or offline mode puts the
+    /// registry in disabled mode permanently for the lifetime of this
+    /// daemon run.
     pub fn new(api_key: Option<String>, work_dir: &Path) -> Self {
         let (tx, rx) = mpsc::channel();
         let model = worker::resolve_model(work_dir);
+        let offline = crate::offline::is_offline(Some(work_dir));
         Self {
-            api_key,
+            api_key: api_key.filter(|_| !offline),
+            offline,
             endpoint: client::ANTHROPIC_MESSAGES_URL.to_string(),
             model,
             handles: HashMap::new(),
@@ -94,7 +101,8 @@ impl AdjudicatorRegistry {
         }
     }
 
-    /// True when the registry has been permanently disabled (no key).
+    /// True when the registry has been permanently disabled (no key or
+    /// offline mode).
     pub fn is_disabled(&self) -> bool {
         self.api_key.is_none()
     }
@@ -141,12 +149,17 @@ impl AdjudicatorRegistry {
             }
 
             if self.is_disabled() {
-                if let Err(e) = escalate_no_api_key(work_dir, &stage_id) {
+                let reason = if self.offline {
+                    "offline mode; adjudicator disabled"
+                } else {
+                    "ANTHROPIC_API_KEY not set; adjudicator disabled"
+                };
+                if let Err(e) = escalate_disabled(work_dir, &stage_id, reason) {
                     tracing::warn!(
                         target: "loom::adjudication",
                         stage = %stage_id,
                         error = %e,
-                        "failed to escalate stage without API key",
+                        "failed to escalate stage with adjudicator disabled",
                     );
                 }
                 continue;
@@ -486,12 +499,11 @@ fn transition_to_queued(stage: &mut Stage) -> Result<()> {
     Ok(())
 }
 
-fn escalate_no_api_key(work_dir: &Path, stage_id: &str) -> Result<()> {
+fn escalate_disabled(work_dir: &Path, stage_id: &str, reason: &str) -> Result<()> {
     // Single locked read-modify-write: re-apply only the human-review
     // transition onto the fresh on-disk stage (A-5).
     update_stage(stage_id, work_dir, |s| {
-        s.try_request_human_review("ANTHROPIC_API_KEY not set; adjudicator disabled".to_string())
-            .ok();
+        s.try_request_human_review(reason.to_string()).ok();
         Ok(())
     })?;
     Ok(())
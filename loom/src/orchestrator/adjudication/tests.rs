@@ -83,6 +83,19 @@ fn registry_disabled_when_no_api_key() {
     assert!(reg.is_disabled());
 }
 
+#[test]
+fn registry_disabled_in_offline_mode() {
+    let tmp = tempfile::tempdir().unwrap();
+    crate::fs::work_dir::write_offline_config(
+        tmp.path(),
+        &crate::offline::OfflineConfig { enabled: true },
+    )
+    .unwrap();
+    let reg = AdjudicatorRegistry::new(Some("test-key".to_string()), tmp.path());
+    assert!(reg.offline);
+    assert!(reg.is_disabled());
+}
+
 #[test]
 fn check_pending_disputes_escalates_when_disabled() {
     let tmp = tempfile::tempdir().unwrap();
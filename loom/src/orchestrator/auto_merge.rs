@@ -9,11 +9,14 @@ use std::path::Path;
 
 use crate::git::branch::branch_name_for_stage;
 use crate::git::cleanup::{cleanup_after_merge, CleanupConfig, CleanupResult};
-use crate::git::merge::{merge_stage, MergeResult};
+use crate::git::merge::MergeResult;
+use crate::git::vcs;
+use crate::git::worktree::get_worktree_path;
+use crate::handoff::record_forward_handoff;
 use crate::models::session::Session;
 use crate::models::stage::Stage;
 use crate::orchestrator::signals::generate_merge_signal;
-use crate::orchestrator::terminal::native::NativeBackend;
+use crate::orchestrator::terminal::TerminalBackend;
 
 /// Result of an auto-merge attempt
 #[derive(Debug)]
@@ -72,17 +75,20 @@ pub fn attempt_auto_merge(
     repo_root: &Path,
     work_dir: &Path,
     target_branch: &str,
-    native: &NativeBackend,
+    backend: &dyn TerminalBackend,
 ) -> Result<AutoMergeResult> {
     // Check if stage has a worktree
-    let worktree_path = repo_root.join(".worktrees").join(&stage.id);
+    let worktree_path = get_worktree_path(&stage.id, repo_root);
     if !worktree_path.exists() {
         return Ok(AutoMergeResult::NoWorktree);
     }
 
+    record_forward_handoff(stage, repo_root, target_branch, work_dir);
+
     // Attempt the merge
-    let merge_result =
-        merge_stage(&stage.id, target_branch, repo_root, work_dir).context("Auto-merge failed")?;
+    let merge_result = vcs::for_repo(repo_root)
+        .merge_stage(&stage.id, target_branch, repo_root, work_dir)
+        .context("Auto-merge failed")?;
 
     match merge_result {
         MergeResult::Success {
@@ -148,7 +154,7 @@ pub fn attempt_auto_merge(
             .context("Failed to generate merge signal")?;
 
             // Spawn the merge resolution session.
-            let spawned_session = native
+            let spawned_session = backend
                 .spawn_merge_session(stage, session, &signal_path, repo_root)
                 .context("Failed to spawn merge resolution session")?;
 
@@ -0,0 +1,295 @@
+//! Code review stages that gate implementation merges.
+//!
+//! With `auto_code_review: true` in the plan's `loom` metadata, every
+//! standard stage that completes gets a [`StageType::CodeReview`] stage
+//! instead of an immediate merge. The review runs in its own read-only
+//! worktree against the reviewed stage's branch and records its verdict as
+//! the `approved` output:
+//!
+//! - approved: the reviewed stage merges as usual
+//! - rejected: the reviewed stage is blocked with the `review_notes` output
+//!   as evidence; once it is retried and completes again, a new review round
+//!   (`<stage>-review-2`, ...) starts
+//!
+//! A review belongs to the stage named by its `parent_stage`. Only the latest
+//! round counts, and only if it finished after the reviewed stage's latest
+//! completion.
+
+use anyhow::{bail, Result};
+use std::path::Path;
+
+use crate::fs::work_dir::load_config;
+use crate::git::branch::branch_name_for_stage;
+use crate::models::stage::{OutputSpec, OutputType, Stage, StageStatus, StageType};
+use crate::verify::transitions::{list_all_stages, load_stage, save_stage};
+
+/// Output holding the review verdict (`true` approves the merge).
+pub const APPROVED_OUTPUT: &str = "approved";
+
+/// Output holding the reviewer's findings.
+pub const NOTES_OUTPUT: &str = "review_notes";
+
+/// Where a stage's merge stands with respect to code review.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReviewGate {
+    /// The plan does not review this stage
+    NotRequired,
+    /// No review covers the stage's latest completion
+    Missing,
+    /// The review has not finished
+    Pending { review_id: String },
+    /// The review approved the merge
+    Approved { review_id: String },
+    /// The review rejected the changes
+    Rejected {
+        review_id: String,
+        notes: Option<String>,
+    },
+}
+
+/// Whether the active plan reviews `stage` before merging it.
+pub fn review_required(work_dir: &Path, stage: &Stage) -> bool {
+    stage.stage_type == StageType::Standard
+        && load_config(work_dir)
+            .ok()
+            .flatten()
+            .is_some_and(|config| config.auto_code_review())
+}
+
+/// Review state of `stage`, read from the stage files.
+pub fn review_gate(work_dir: &Path, stage: &Stage) -> Result<ReviewGate> {
+    if !review_required(work_dir, stage) {
+        return Ok(ReviewGate::NotRequired);
+    }
+    let stages = list_all_stages(work_dir)?;
+    Ok(gate_for(stage, latest_review(stage, &stages)))
+}
+
+/// Latest review round of `stage` among `stages`.
+pub fn latest_review<'a>(stage: &Stage, stages: &'a [Stage]) -> Option<&'a Stage> {
+    stages
+        .iter()
+        .filter(|s| is_review_of(s, &stage.id))
+        .max_by_key(|s| s.created_at)
+}
+
+fn is_review_of(review: &Stage, stage_id: &str) -> bool {
+    review.stage_type == StageType::CodeReview && review.parent_stage.as_deref() == Some(stage_id)
+}
+
+fn gate_for(stage: &Stage, review: Option<&Stage>) -> ReviewGate {
+    let Some(review) = review else {
+        return ReviewGate::Missing;
+    };
+    let review_id = review.id.clone();
+    if review.status != StageStatus::Completed {
+        return ReviewGate::Pending { review_id };
+    }
+    // A verdict on an earlier attempt says nothing about the current changes
+    if let (Some(reviewed), Some(completed)) = (review.completed_at, stage.completed_at) {
+        if reviewed < completed {
+            return ReviewGate::Missing;
+        }
+    }
+    let output = |key: &str| review.outputs.iter().find(|o| o.key == key);
+    if output(APPROVED_OUTPUT).is_some_and(|o| o.value.as_bool() == Some(true)) {
+        return ReviewGate::Approved { review_id };
+    }
+    let notes = output(NOTES_OUTPUT).map(|o| match o.value.as_str() {
+        Some(text) => text.to_string(),
+        None => o.value.to_string(),
+    });
+    ReviewGate::Rejected { review_id, notes }
+}
+
+/// Make sure a review of `stage`'s latest completion exists.
+///
+/// Creates the next review round unless one is still pending, and returns
+/// the id of the review the merge now waits on.
+pub fn request_review(work_dir: &Path, stage: &Stage) -> Result<String> {
+    let stages = list_all_stages(work_dir)?;
+    if let ReviewGate::Pending { review_id } = gate_for(stage, latest_review(stage, &stages)) {
+        return Ok(review_id);
+    }
+
+    let round = stages.iter().filter(|s| is_review_of(s, &stage.id)).count() + 1;
+    let review_id = match round {
+        1 => format!("{}-review", stage.id),
+        n => format!("{}-review-{n}", stage.id),
+    };
+    if stages.iter().any(|s| s.id == review_id) {
+        bail!(
+            "Cannot create code review for stage '{}': stage '{review_id}' already exists",
+            stage.id
+        );
+    }
+
+    let mut review = Stage::new(
+        format!("Review: {}", stage.name),
+        Some(format!(
+            "Code review of stage '{}' (round {round}). Its merge waits on this review.",
+            stage.id
+        )),
+    );
+    review.id = review_id.clone();
+    review.stage_type = StageType::CodeReview;
+    review.status = StageStatus::Queued;
+    review.parent_stage = Some(stage.id.clone());
+    review.plan_id = stage.plan_id.clone();
+    review.expected_outputs = vec![
+        OutputSpec {
+            key: APPROVED_OUTPUT.to_string(),
+            output_type: Some(OutputType::Bool),
+            required: true,
+            description: Some("true to merge the reviewed stage, false to block it".to_string()),
+        },
+        OutputSpec {
+            key: NOTES_OUTPUT.to_string(),
+            output_type: Some(OutputType::String),
+            required: false,
+            description: Some("Findings; required reading when rejecting".to_string()),
+        },
+    ];
+    save_stage(&review, work_dir)?;
+    Ok(review_id)
+}
+
+/// Signal section pointing a review stage at the changes it reviews.
+pub fn render_review_target(work_dir: &Path, review: &Stage) -> Option<String> {
+    let stage_id = review.parent_stage.as_deref()?;
+    let stage = load_stage(stage_id, work_dir).ok()?;
+    let branch = branch_name_for_stage(stage_id);
+    let base = load_config(work_dir)
+        .ok()
+        .flatten()
+        .and_then(|c| c.base_branch())
+        .unwrap_or_else(|| "HEAD".to_string());
+
+    let mut section = format!(
+        "\n## Review Target\n\nReview stage `{stage_id}` ({}).\n\n",
+        stage.name
+    );
+    section.push_str(&format!(
+        "- **Branch:** `{branch}`\n- **Base:** `{base}`\n\n"
+    ));
+    section.push_str("```bash\n");
+    section.push_str(&format!("git log --oneline {base}..{branch}\n"));
+    section.push_str(&format!("git diff --stat {base}...{branch}\n"));
+    section.push_str(&format!("git diff {base}...{branch}\n"));
+    section.push_str("```\n");
+    if let Some(description) = &stage.description {
+        section.push_str(&format!(
+            "\n### What the stage was asked to do\n\n{description}\n"
+        ));
+    }
+    if !stage.acceptance.is_empty() {
+        section.push_str("\n### Its acceptance criteria\n\n");
+        for criterion in &stage.acceptance {
+            section.push_str(&format!("- `{}`\n", criterion.command()));
+        }
+    }
+    section.push_str(&format!(
+        "\nWhen done: `loom stage output set {id} {APPROVED_OUTPUT} true|false`, \
+         `loom stage output set {id} {NOTES_OUTPUT} \"...\"`, then `loom stage complete {id}`.\n",
+        id = review.id
+    ));
+    Some(section)
+}
+
+/// Signal section with the findings of the review that rejected `stage`.
+pub fn render_review_feedback(work_dir: &Path, stage: &Stage) -> Option<String> {
+    if !review_required(work_dir, stage) {
+        return None;
+    }
+    let stages = list_all_stages(work_dir).ok()?;
+    let ReviewGate::Rejected { review_id, notes } = gate_for(stage, latest_review(stage, &stages))
+    else {
+        return None;
+    };
+    let notes = notes.unwrap_or_else(|| "(the reviewer left no notes)".to_string());
+    Some(format!(
+        "\n## Code Review Feedback\n\nReview `{review_id}` rejected the previous attempt of this \
+         stage. Address these findings before completing again:\n\n{notes}\n"
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::stage::StageOutput;
+    use chrono::{Duration, Utc};
+
+    fn review_of(stage: &Stage, approved: Option<bool>) -> Stage {
+        let mut review = Stage::new("Review".to_string(), None);
+        review.id = format!("{}-review", stage.id);
+        review.stage_type = StageType::CodeReview;
+        review.parent_stage = Some(stage.id.clone());
+        review.status = StageStatus::Queued;
+        if let Some(approved) = approved {
+            review.status = StageStatus::Completed;
+            review.completed_at = Some(Utc::now());
+            review.outputs.push(StageOutput {
+                key: APPROVED_OUTPUT.to_string(),
+                value: serde_json::json!(approved),
+                description: String::new(),
+            });
+            review.outputs.push(StageOutput {
+                key: NOTES_OUTPUT.to_string(),
+                value: serde_json::json!("missing error handling"),
+                description: String::new(),
+            });
+        }
+        review
+    }
+
+    #[test]
+    fn test_gate_for_review_rounds() {
+        let mut stage = Stage::new("Api".to_string(), None);
+        stage.id = "api".to_string();
+        stage.completed_at = Some(Utc::now() - Duration::minutes(5));
+
+        assert_eq!(gate_for(&stage, None), ReviewGate::Missing);
+        assert_eq!(
+            gate_for(&stage, Some(&review_of(&stage, None))),
+            ReviewGate::Pending {
+                review_id: "api-review".to_string()
+            }
+        );
+        assert_eq!(
+            gate_for(&stage, Some(&review_of(&stage, Some(true)))),
+            ReviewGate::Approved {
+                review_id: "api-review".to_string()
+            }
+        );
+        let rejected = review_of(&stage, Some(false));
+        assert_eq!(
+            gate_for(&stage, Some(&rejected)),
+            ReviewGate::Rejected {
+                review_id: "api-review".to_string(),
+                notes: Some("missing error handling".to_string())
+            }
+        );
+
+        // Completing again after the rejection needs a new review
+        stage.completed_at = Some(Utc::now() + Duration::minutes(5));
+        assert_eq!(gate_for(&stage, Some(&rejected)), ReviewGate::Missing);
+    }
+
+    #[test]
+    fn test_latest_review_ignores_other_stages() {
+        let mut stage = Stage::new("Api".to_string(), None);
+        stage.id = "api".to_string();
+        let mut first = review_of(&stage, Some(false));
+        first.created_at = Utc::now() - Duration::minutes(10);
+        let mut second = review_of(&stage, None);
+        second.id = "api-review-2".to_string();
+        let mut other = Stage::new("Other".to_string(), None);
+        other.parent_stage = Some("api".to_string());
+
+        let stages = vec![second, first, other];
+        assert_eq!(
+            latest_review(&stage, &stages).map(|s| s.id.as_str()),
+            Some("api-review-2")
+        );
+    }
+}
@@ -23,11 +23,13 @@ pub use session_io::{save_session, session_to_markdown};
 
 use anyhow::{bail, Context, Result};
 use std::path::Path;
+use std::sync::Arc;
 
 use crate::models::session::Session;
 use crate::models::stage::{Stage, StageStatus};
 use crate::models::worktree::Worktree;
 use crate::orchestrator::signals::{generate_signal, DependencyStatus};
+use crate::orchestrator::terminal::backend_for_stage;
 use crate::orchestrator::terminal::native::NativeBackend;
 
 /// Configuration for session continuation
@@ -88,7 +90,7 @@ pub fn continue_session(
     if config.auto_spawn {
         let native = NativeBackend::new(work_dir.to_path_buf())
             .context("Failed to construct native backend for continuation")?;
-        session = native
+        session = backend_for_stage(&Arc::new(native), stage)
             .spawn_session(stage, worktree, session, &signal_path)
             .context("Failed to spawn session for continuation")?;
     }
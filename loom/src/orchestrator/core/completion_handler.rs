@@ -1,12 +1,15 @@
 //! Stage completion handling
 //!
-//! The orchestrator kills the session and runs auto-merge against the
-//! host repo directly.
+//! The orchestrator kills the session (unless its terminal is kept open, see
+//! [`keep_open`]) and runs auto-merge against the host repo directly.
 
 use anyhow::Result;
 use chrono::Utc;
 
+use crate::models::stage::StageType;
+use crate::orchestrator::pair;
 use crate::orchestrator::signals::remove_signal;
+use crate::orchestrator::terminal::keep_open;
 
 use super::persistence::Persistence;
 use super::Orchestrator;
@@ -15,12 +18,17 @@ impl Orchestrator {
     pub(super) fn handle_stage_completed(&mut self, stage_id: &str) -> Result<()> {
         // Accumulate execution time for the final attempt. A-4: a corrupt
         // stage file must be logged (with its path), not silently skipped.
-        match self.load_stage(stage_id) {
+        let mut reviewed_stage = None;
+        let keep_terminal = match self.load_stage(stage_id) {
             Ok(mut stage) => {
                 stage.accumulate_attempt_time(Utc::now());
                 if let Err(e) = self.save_stage(&stage) {
                     eprintln!("Warning: failed to save execution time for stage '{stage_id}': {e}");
                 }
+                if stage.stage_type == StageType::CodeReview {
+                    reviewed_stage = stage.parent_stage.clone();
+                }
+                keep_open::keep_open(Some(&stage), &self.config.work_dir)
             }
             Err(e) => {
                 let path = crate::fs::stage_files::find_stage_file(
@@ -35,13 +43,25 @@ impl Orchestrator {
                     error = %e,
                     "Failed to load stage while recording completion time; continuing (corrupt stage file?)"
                 );
+                keep_open::keep_open(None, &self.config.work_dir)
             }
-        }
+        };
 
         // Clean up session first
         if let Some(session) = self.active_sessions.remove(stage_id) {
             remove_signal(&session.id, &self.config.work_dir)?;
-            let _ = self.native.kill_session(&session);
+            if keep_terminal {
+                eprintln!("Keeping terminal open for completed stage '{stage_id}'");
+            } else {
+                let _ = self.session_backend().kill_session(&session);
+            }
+        }
+
+        // A pair reviewer's work ends with its stage
+        let reviewers =
+            pair::stop_reviewers(stage_id, &self.config.work_dir, self.session_backend());
+        if reviewers > 0 {
+            eprintln!("Stopped {reviewers} reviewer session(s) for stage '{stage_id}'");
         }
 
         self.active_worktrees.remove(stage_id);
@@ -66,6 +86,12 @@ impl Orchestrator {
             }
         }
 
+        // A finished review settles the reviewed stage's merge: approved
+        // merges it, rejected blocks it.
+        if let Some(reviewed) = reviewed_stage {
+            let _ = self.try_auto_merge(&reviewed);
+        }
+
         Ok(())
     }
 }
@@ -4,9 +4,14 @@ use anyhow::Result;
 use chrono::Utc;
 use std::path::PathBuf;
 
+use crate::git::worktree::get_worktree_path;
 use crate::models::failure::FailureInfo;
 use crate::models::stage::StageStatus;
-use crate::orchestrator::retry::{calculate_backoff, classify_failure, should_auto_retry};
+use crate::orchestrator::decisions::{Decision, DecisionKind};
+use crate::orchestrator::retry::{
+    calculate_backoff, classify_failure, should_auto_retry, RETRY_BACKOFF_BASE_SECS,
+    RETRY_BACKOFF_MAX_SECS,
+};
 
 use super::persistence::Persistence;
 use super::{clear_status_line, Orchestrator};
@@ -50,8 +55,9 @@ impl Orchestrator {
             };
 
             // Don't override terminal states - stage may have completed before session died
-            if matches!(stage.status, StageStatus::Completed) {
-                // Stage already completed successfully, just clean up
+            if matches!(stage.status, StageStatus::Completed | StageStatus::Skipped) {
+                // Stage already completed successfully (or was skipped or
+                // cancelled while its session ran), just clean up
                 return Ok(());
             }
 
@@ -109,7 +115,23 @@ impl Orchestrator {
             // Check if auto-retry is eligible (default max_retries = 3)
             let max = stage.max_retries.unwrap_or(3);
             if should_auto_retry(&failure_type, stage.retry_count, max) {
-                let backoff = calculate_backoff(stage.retry_count, 30, 300);
+                let backoff = calculate_backoff(
+                    stage.retry_count,
+                    RETRY_BACKOFF_BASE_SECS,
+                    RETRY_BACKOFF_MAX_SECS,
+                );
+                self.decisions.record(
+                    Decision::new(
+                        &sid,
+                        DecisionKind::RetryScheduled,
+                        format!("{} (attempt {}/{max})", failure_type, stage.retry_count),
+                    )
+                    .until(
+                        Utc::now()
+                            + chrono::Duration::from_std(backoff)
+                                .unwrap_or_else(|_| chrono::Duration::zero()),
+                    ),
+                );
                 clear_status_line();
                 eprintln!(
                     "Stage '{}' crashed (attempt {}/{}). Will retry in {}s...",
@@ -132,7 +154,7 @@ impl Orchestrator {
 
             // Best-effort permission sync before transitioning to Blocked
             // This preserves permissions granted during the crashed session
-            let worktree_path = self.config.repo_root.join(".worktrees").join(&sid);
+            let worktree_path = get_worktree_path(&sid, &self.config.repo_root);
             if worktree_path.exists() {
                 let working_dir_path = stage.working_dir.as_ref().map(|wd| worktree_path.join(wd));
                 match crate::fs::permissions::sync_worktree_permissions_with_working_dir(
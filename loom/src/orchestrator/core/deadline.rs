@@ -0,0 +1,166 @@
+//! Wall-clock limit for a run (`loom run --max-runtime`).
+//!
+//! Once the deadline passes the orchestrator starts no new stage or merge
+//! resolution sessions, sends a desktop notification, and - when the run
+//! was started with `--handoff-on-timeout` - writes a handoff for every
+//! running stage session, stops it and re-queues the stage for the next
+//! `loom run`. Otherwise running sessions finish on their own. The run ends
+//! when no session is left, after writing the plan summary report.
+//!
+//! The deadline is absolute, so a supervised daemon that restarts after it
+//! has passed stops straight away instead of starting a fresh time box.
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::models::session::SessionType;
+use crate::orchestrator::notify::send_desktop_notification;
+use crate::orchestrator::signals::remove_signal;
+use crate::utils::format_elapsed_verbose;
+
+use super::clear_status_line;
+use super::persistence::Persistence;
+use super::Orchestrator;
+
+/// Wall-clock limit of a run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunDeadline {
+    /// When the run stops starting new sessions
+    pub at: DateTime<Utc>,
+    /// Hand off running stage sessions at the deadline instead of letting
+    /// them finish
+    #[serde(default)]
+    pub handoff: bool,
+}
+
+impl RunDeadline {
+    /// Deadline `max_runtime` from now.
+    pub fn after(max_runtime: std::time::Duration, handoff: bool) -> Self {
+        let max_runtime = chrono::Duration::from_std(max_runtime).unwrap_or(chrono::Duration::MAX);
+        Self {
+            at: Utc::now()
+                .checked_add_signed(max_runtime)
+                .unwrap_or(DateTime::<Utc>::MAX_UTC),
+            handoff,
+        }
+    }
+
+    pub fn has_passed(&self, now: DateTime<Utc>) -> bool {
+        now >= self.at
+    }
+}
+
+impl Orchestrator {
+    /// Whether the run's deadline has passed. Acts on the deadline the first
+    /// time it is seen: notifies, and hands off running sessions if asked to.
+    pub(super) fn check_deadline(&mut self) -> bool {
+        if self.deadline_reached {
+            return true;
+        }
+        let Some(deadline) = self.config.deadline else {
+            return false;
+        };
+        if !deadline.has_passed(Utc::now()) {
+            return false;
+        }
+        self.deadline_reached = true;
+
+        let running = self.active_sessions.len();
+        clear_status_line();
+        println!();
+        println!(
+            "Max runtime reached ({}); no new stages will start",
+            deadline.at.format("%Y-%m-%d %H:%M UTC")
+        );
+        let body = if deadline.handoff && running > 0 {
+            format!("Handing off {running} running session(s); the run stops once they exit")
+        } else if running > 0 {
+            format!("Waiting for {running} running session(s) to finish, then stopping")
+        } else {
+            "No sessions running; stopping".to_string()
+        };
+        println!("{body}");
+        send_desktop_notification("loom: max runtime reached", &body);
+
+        if deadline.handoff {
+            self.handoff_running_sessions();
+        }
+        true
+    }
+
+    /// Report why a run that hit its deadline stopped.
+    pub(super) fn report_deadline_stop(&self, started_at: DateTime<Utc>) {
+        let elapsed = (Utc::now() - started_at).num_seconds();
+        println!();
+        println!(
+            "Run stopped at its max runtime after {}. Run `loom run` to continue.",
+            format_elapsed_verbose(elapsed)
+        );
+    }
+
+    /// Hand off every running stage session. Merge and other resolution
+    /// sessions are left to finish their (short) job.
+    fn handoff_running_sessions(&mut self) {
+        let stage_ids: Vec<String> = self
+            .active_sessions
+            .iter()
+            .filter(|(_, session)| session.session_type == SessionType::Stage)
+            .map(|(stage_id, _)| stage_id.clone())
+            .collect();
+        for stage_id in stage_ids {
+            if let Err(e) = self.handoff_session(&stage_id) {
+                eprintln!("Warning: Failed to hand off stage '{stage_id}': {e:#}");
+            }
+        }
+    }
+
+    fn handoff_session(&mut self, stage_id: &str) -> Result<()> {
+        let Some(session) = self.active_sessions.get(stage_id).cloned() else {
+            return Ok(());
+        };
+        let mut stage = self.load_stage(stage_id)?;
+
+        let handoff_path = self
+            .monitor
+            .handlers()
+            .handle_context_critical(&session, &stage)?;
+        if let Err(e) = self.session_backend().kill_session(&session) {
+            eprintln!("Warning: Failed to stop session '{}': {e}", session.id);
+        }
+        if let Err(e) = remove_signal(&session.id, &self.config.work_dir) {
+            eprintln!("Warning: Failed to remove signal for '{}': {e}", session.id);
+        }
+        self.active_sessions.remove(stage_id);
+
+        // Re-queue so the next `loom run` continues from the handoff
+        stage.accumulate_attempt_time(Utc::now());
+        stage.try_mark_needs_handoff()?;
+        self.save_stage(&stage)?;
+        stage.try_mark_queued()?;
+        self.save_stage(&stage)?;
+        self.graph.mark_queued(stage_id)?;
+
+        println!("Handed off stage '{stage_id}' ({})", handoff_path.display());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_run_deadline_after() {
+        let deadline = RunDeadline::after(Duration::from_secs(3600), true);
+        let now = Utc::now();
+        assert!(!deadline.has_passed(now));
+        assert!(deadline.has_passed(now + chrono::Duration::seconds(3601)));
+        assert!(deadline.handoff);
+
+        // Absurd limits saturate instead of overflowing
+        let forever = RunDeadline::after(Duration::from_secs(u64::MAX), false);
+        assert!(!forever.has_passed(now));
+    }
+}
@@ -6,7 +6,9 @@ use std::path::PathBuf;
 
 use crate::models::stage::StageStatus;
 use crate::orchestrator::monitor::MonitorEvent;
+use crate::orchestrator::notify::notify_assignee;
 use crate::orchestrator::signals::remove_signal;
+use crate::utils::truncate;
 
 use super::clear_status_line;
 use super::persistence::Persistence;
@@ -85,7 +87,7 @@ impl EventHandler for Orchestrator {
         // Kill old session if still tracked
         if let Some(session) = self.active_sessions.get(stage_id) {
             let session_clone = session.clone();
-            if let Err(e) = self.native.kill_session(&session_clone) {
+            if let Err(e) = self.session_backend().kill_session(&session_clone) {
                 eprintln!("Warning: Failed to kill session '{session_id}': {e}");
             }
             // Remove old signal file
@@ -123,6 +125,14 @@ impl EventHandler for Orchestrator {
 }
 
 impl Orchestrator {
+    /// Route a notification to the assignee of `stage_id`, if it has one
+    /// with a `[notify.assignees]` command.
+    fn notify_assignee_of(&self, stage_id: &str, title: &str, body: &str) {
+        if let Ok(stage) = self.load_stage(stage_id) {
+            notify_assignee(&self.config.work_dir, &stage, title, body);
+        }
+    }
+
     /// Handle a single monitor event. Errors are isolated per event by the
     /// caller (`handle_events`) so one bad event cannot abort the batch or the
     /// daemon (O-4).
@@ -135,6 +145,11 @@ impl Orchestrator {
                 clear_status_line();
                 eprintln!("Stage '{stage_id}' blocked: {reason}");
                 self.graph.mark_status(&stage_id, StageStatus::Blocked)?;
+                self.notify_assignee_of(
+                    &stage_id,
+                    &format!("loom: Stage '{stage_id}' blocked"),
+                    &truncate(&reason, 200),
+                );
             }
             MonitorEvent::SessionContextWarning {
                 session_id,
@@ -173,11 +188,38 @@ impl Orchestrator {
                 } else {
                     eprintln!("Stage '{stage_id}' is waiting for user input");
                 }
+                self.notify_assignee_of(
+                    &stage_id,
+                    &format!("loom: Stage '{stage_id}' is waiting for input"),
+                    "The session is waiting for you to answer it.",
+                );
             }
             MonitorEvent::StageResumedExecution { stage_id } => {
                 clear_status_line();
                 eprintln!("Stage '{stage_id}' resumed execution after user input");
             }
+            MonitorEvent::StagePromptDetected {
+                stage_id,
+                session_id,
+                prompt,
+            } => {
+                let mut stage = self.load_stage(&stage_id)?;
+                // Re-check against the file: the stage may have moved on
+                // since the monitor loaded it.
+                if stage.status == StageStatus::Executing
+                    && stage.session.as_deref() == Some(session_id.as_str())
+                {
+                    stage.try_mark_waiting_for_prompt(prompt)?;
+                    self.save_stage(&stage)?;
+                }
+            }
+            MonitorEvent::StagePromptAnswered { stage_id } => {
+                let mut stage = self.load_stage(&stage_id)?;
+                if stage.status == StageStatus::WaitingForInput && stage.waiting_prompt.is_some() {
+                    stage.try_mark_executing()?;
+                    self.save_stage(&stage)?;
+                }
+            }
             MonitorEvent::MergeSessionCompleted {
                 session_id,
                 stage_id,
@@ -235,6 +277,11 @@ impl Orchestrator {
                     &stage_id,
                     review_reason.as_deref(),
                 );
+                self.notify_assignee_of(
+                    &stage_id,
+                    &format!("loom: Stage '{stage_id}' needs review"),
+                    &truncate(reason_str, 200),
+                );
             }
             MonitorEvent::PossiblyStuck {
                 session_id,
@@ -365,6 +412,20 @@ mod tests {
             reasoning_effort: None,
             code_review: None,
             ultracode: false,
+            pair: false,
+            worktree_setup: vec![],
+            instructions: None,
+            prompt_prefix: None,
+            container: None,
+            keep_terminal: None,
+            outputs: Vec::new(),
+            params: Vec::new(),
+            requires_outputs: Vec::new(),
+            new_files: Vec::new(),
+            expected_duration: None,
+            acceptance_isolation: None,
+            skip_if_satisfied: false,
+            package: None,
         }];
         ExecutionGraph::build(stages).unwrap()
     }
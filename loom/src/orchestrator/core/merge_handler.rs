@@ -3,14 +3,21 @@
 use std::path::Path;
 
 use anyhow::{Context, Result};
+use chrono::Utc;
 
 use crate::git::branch::branch_name_for_stage;
 use crate::git::cleanup::{cleanup_after_merge, needs_cleanup, CleanupConfig};
+use crate::git::merge::scope::{
+    clear_merge_scope, out_of_scope_changes, write_merge_scope, MergeScope,
+};
+use crate::git::merge::stats::{conflict_counterparts, record_conflict, record_resolution};
 use crate::git::merge::{check_merge_state, MergeState};
 use crate::git::merge::{get_conflicting_files_from_status, verify_merge_succeeded};
+use crate::models::failure::{FailureInfo, FailureType};
 use crate::models::session::{Session, SessionType};
-use crate::models::stage::StageStatus;
+use crate::models::stage::{Stage, StageStatus, StageType};
 use crate::orchestrator::auto_merge::{attempt_auto_merge, is_auto_merge_enabled, AutoMergeResult};
+use crate::orchestrator::code_review::{request_review, review_gate, ReviewGate};
 use crate::orchestrator::signals::{
     find_live_merge_session_for_stage, generate_merge_signal, remove_signal,
 };
@@ -80,6 +87,7 @@ impl Orchestrator {
                 }
                 self.active_sessions.remove(stage_id);
                 self.clear_merge_resolver_attempts(stage_id);
+                self.drop_merge_scope(stage_id);
                 clear_status_line();
                 eprintln!("Stage '{stage_id}' merge completed successfully");
                 return Ok(());
@@ -140,6 +148,17 @@ impl Orchestrator {
 
         match merge_state {
             Ok(MergeState::Merged) => {
+                // A sandboxed resolver must not have touched files beyond the
+                // conflicts and tests; hand such a merge to a human instead.
+                let out_of_scope = self.resolution_out_of_scope(&stage, &merge_point);
+                if !out_of_scope.is_empty() {
+                    if let Err(e) = remove_signal(session_id, &self.config.work_dir) {
+                        eprintln!("Warning: Failed to remove merge signal: {e}");
+                    }
+                    self.escalate_out_of_scope_resolution(stage_id, &out_of_scope);
+                    return Ok(());
+                }
+
                 // `finalize_merge_resolution` re-verifies git ancestry before
                 // writing merged=true (phantom-merge invariant). If verification
                 // unexpectedly fails here, it routes to the surface-to-user arm
@@ -197,6 +216,35 @@ impl Orchestrator {
         Ok(())
     }
 
+    /// Files the stage's resolution commit changed outside its merge scope.
+    ///
+    /// Empty when the stage has no scope, no resolvable commit, or the check
+    /// itself fails (the failure is logged; finalization still ancestry-checks).
+    fn resolution_out_of_scope(&self, stage: &Stage, merge_point: &str) -> Vec<String> {
+        let commit = stage.completed_commit.clone().or_else(|| {
+            crate::git::get_branch_head(&branch_name_for_stage(&stage.id), &self.config.repo_root)
+                .ok()
+        });
+        let Some(commit) = commit else {
+            return Vec::new();
+        };
+        out_of_scope_changes(
+            &self.config.work_dir,
+            &stage.id,
+            &commit,
+            merge_point,
+            &self.config.repo_root,
+        )
+        .unwrap_or_else(|e| {
+            tracing::warn!(
+                stage_id = %stage.id,
+                error = %e,
+                "Failed to check merge resolution scope"
+            );
+            Vec::new()
+        })
+    }
+
     /// Remove active-session tracking for an unresolved merge and print
     /// user-facing recovery instructions.
     ///
@@ -299,6 +347,10 @@ impl Orchestrator {
         }
         self.active_sessions.remove(stage_id);
         self.clear_merge_resolver_attempts(stage_id);
+        self.drop_merge_scope(stage_id);
+        if let Err(e) = record_resolution(&self.config.work_dir, stage_id) {
+            tracing::warn!(stage_id = %stage_id, error = %e, "Failed to record merge resolution");
+        }
 
         clear_status_line();
         eprintln!("Stage '{stage_id}' {log_message}");
@@ -512,6 +564,60 @@ impl Orchestrator {
             return true;
         }
 
+        // A code review's branch carries no work: there is nothing to merge.
+        if stage.stage_type == StageType::CodeReview {
+            stage.merged = true;
+            if let Err(e) = self.save_stage(&stage) {
+                tracing::warn!(
+                    stage_id = %stage_id,
+                    error = %e,
+                    "Failed to save finished code review"
+                );
+            }
+            cleanup_merged_stage_resources(stage_id, &self.config.repo_root);
+            return true;
+        }
+
+        // With `auto_code_review`, the merge waits on an approving review of
+        // this completion (see `orchestrator::code_review`).
+        match review_gate(&self.config.work_dir, &stage) {
+            Ok(ReviewGate::NotRequired | ReviewGate::Approved { .. }) => {}
+            Ok(ReviewGate::Pending { review_id }) => {
+                tracing::info!(
+                    stage_id = %stage_id,
+                    review = %review_id,
+                    "merge waits on code review"
+                );
+                return true;
+            }
+            Ok(ReviewGate::Missing) => {
+                match request_review(&self.config.work_dir, &stage) {
+                    Ok(review_id) => {
+                        clear_status_line();
+                        eprintln!("Stage '{stage_id}' awaits code review '{review_id}'");
+                    }
+                    Err(e) => tracing::error!(
+                        stage_id = %stage_id,
+                        error = %e,
+                        "Failed to create code review stage"
+                    ),
+                }
+                return true;
+            }
+            Ok(ReviewGate::Rejected { review_id, notes }) => {
+                self.block_rejected_stage(&mut stage, &review_id, notes);
+                return false;
+            }
+            Err(e) => {
+                tracing::warn!(
+                    stage_id = %stage_id,
+                    error = %e,
+                    "Failed to read code review state; not merging"
+                );
+                return true;
+            }
+        }
+
         // Load plan-level auto_merge setting from config.
         //
         // O-20: distinguish "no plan-level setting exists" (legitimate None →
@@ -608,7 +714,7 @@ impl Orchestrator {
             &self.config.repo_root,
             &self.config.work_dir,
             &target_branch,
-            &self.native,
+            self.session_backend(),
         ) {
             Ok(AutoMergeResult::Success {
                 files_changed,
@@ -893,7 +999,7 @@ impl Orchestrator {
                 // Kill the original session to prevent zombie processes.
                 // When loom stage complete detected a merge conflict, the Stage session
                 // may still be running -- actively terminate it.
-                let kill_result = self.native.kill_session(&stale_session);
+                let kill_result = self.session_backend().kill_session(&stale_session);
                 if let Err(e) = &kill_result {
                     tracing::debug!(
                         session_id = %stale_session_id,
@@ -1010,35 +1116,111 @@ impl Orchestrator {
         }
     }
 
+    /// Block a stage whose code review rejected it, with the reviewer's
+    /// notes as failure evidence. A retry re-runs the stage; completing it
+    /// again requests a new review round.
+    fn block_rejected_stage(&mut self, stage: &mut Stage, review_id: &str, notes: Option<String>) {
+        let reason = format!("code review '{review_id}' rejected the changes");
+        // Completed is terminal; the rejection is a sanctioned forced revert.
+        stage.force_status_with_reason(StageStatus::Blocked, &reason);
+        stage.close_reason = Some(reason.clone());
+        let mut evidence = vec![reason];
+        evidence.extend(notes);
+        stage.failure_info = Some(FailureInfo {
+            failure_type: FailureType::CodeError,
+            detected_at: Utc::now(),
+            evidence,
+        });
+
+        if let Err(e) = self.save_stage(stage) {
+            tracing::warn!(
+                stage_id = %stage.id,
+                error = %e,
+                "Failed to save stage after rejected code review"
+            );
+        }
+        if let Err(e) = self.graph.mark_status(&stage.id, StageStatus::Blocked) {
+            tracing::warn!(
+                stage_id = %stage.id,
+                error = %e,
+                "Failed to mark stage blocked in graph after rejected code review"
+            );
+        }
+
+        clear_status_line();
+        eprintln!(
+            "Stage '{}' blocked: code review '{review_id}' rejected it. \
+             Run `loom stage retry {}` to address the findings.",
+            stage.id, stage.id
+        );
+    }
+
     /// Route a stage whose merge-resolver budget is exhausted to
     /// `NeedsHumanReview` and persist it.
+    fn escalate_merge_resolver_exhausted(&mut self, stage_id: &str, failed_attempts: u32) {
+        tracing::error!(
+            stage_id = %stage_id,
+            failed_attempts = %failed_attempts,
+            "Merge-resolver attempt cap reached; routing stage to NeedsHumanReview"
+        );
+        let reason = format!(
+            "merge resolution failed after {failed_attempts} resolver attempt(s); \
+             escalating to human review. Resolve manually with `loom stage merge {stage_id}`."
+        );
+        if self.escalate_merge_to_human_review(stage_id, reason) {
+            clear_status_line();
+            eprintln!(
+                "Stage '{stage_id}' needs human review: merge resolution failed after \
+                 {failed_attempts} attempt(s). Run `loom stage merge {stage_id}` manually."
+            );
+        }
+    }
+
+    /// Route a stage whose resolution commit changed files outside its merge
+    /// scope to `NeedsHumanReview` instead of finalizing it.
+    fn escalate_out_of_scope_resolution(&mut self, stage_id: &str, out_of_scope: &[String]) {
+        tracing::error!(
+            stage_id = %stage_id,
+            files = ?out_of_scope,
+            "Merge resolution changed files outside the conflict scope; routing stage \
+             to NeedsHumanReview"
+        );
+        let reason = format!(
+            "merge resolution changed files outside the conflicting files: {}. \
+             Review the merge commit, then run `loom stage merge {stage_id} --resolved`.",
+            out_of_scope.join(", ")
+        );
+        if self.escalate_merge_to_human_review(stage_id, reason) {
+            clear_status_line();
+            eprintln!(
+                "Stage '{stage_id}' needs human review: its merge commit changed {} file(s) \
+                 outside the conflict resolution scope.",
+                out_of_scope.len()
+            );
+        }
+    }
+
+    /// Force a merge-stage to `NeedsHumanReview` with `reason` and persist it.
+    /// Returns `false` when the stage could not be loaded.
     ///
     /// `MergeConflict`/`MergeBlocked -> NeedsHumanReview` is not a legal edge, so
     /// this uses the sanctioned forced-assignment path. After escalation the
     /// stage is no longer in MergeConflict/MergeBlocked, so the spawn loop stops
-    /// considering it and respawning ceases.
-    fn escalate_merge_resolver_exhausted(&mut self, stage_id: &str, failed_attempts: u32) {
+    /// considering it and respawning ceases. The merge scope is dropped so the
+    /// human resolving it is not held to the resolver's sandbox.
+    fn escalate_merge_to_human_review(&mut self, stage_id: &str, reason: String) -> bool {
         let mut stage = match self.load_stage(stage_id) {
             Ok(s) => s,
             Err(e) => {
                 tracing::error!(
                     stage_id = %stage_id,
                     error = %e,
-                    "Merge-resolver budget exhausted but failed to load stage for escalation"
+                    "Failed to load stage for merge escalation to human review"
                 );
-                return;
+                return false;
             }
         };
 
-        let reason = format!(
-            "merge resolution failed after {failed_attempts} resolver attempt(s); \
-             escalating to human review. Resolve manually with `loom stage merge {stage_id}`."
-        );
-        tracing::error!(
-            stage_id = %stage_id,
-            failed_attempts = %failed_attempts,
-            "Merge-resolver attempt cap reached; routing stage to NeedsHumanReview"
-        );
         // Illegal edge from MergeConflict/MergeBlocked — forced assignment is the
         // sanctioned bypass and logs at error level.
         stage.force_status_with_reason(StageStatus::NeedsHumanReview, &reason);
@@ -1048,7 +1230,7 @@ impl Orchestrator {
             tracing::warn!(
                 stage_id = %stage_id,
                 error = %e,
-                "Failed to save stage after merge-resolver escalation"
+                "Failed to save stage after merge escalation"
             );
         }
         if let Err(e) = self
@@ -1066,12 +1248,29 @@ impl Orchestrator {
         // manual re-merge starts fresh.
         self.active_sessions.remove(stage_id);
         self.clear_merge_resolver_attempts(stage_id);
+        self.drop_merge_scope(stage_id);
+        true
+    }
 
-        clear_status_line();
-        eprintln!(
-            "Stage '{stage_id}' needs human review: merge resolution failed after \
-             {failed_attempts} attempt(s). Run `loom stage merge {stage_id}` manually."
-        );
+    /// Drop the merge scope recorded for `stage_id`, if any.
+    fn drop_merge_scope(&self, stage_id: &str) {
+        if let Err(e) = clear_merge_scope(&self.config.work_dir, stage_id) {
+            tracing::warn!(
+                stage_id = %stage_id,
+                error = %e,
+                "Failed to clear merge scope"
+            );
+        }
+    }
+
+    /// Record a conflict in `.work/merge-stats.json`. Statistics are advisory,
+    /// so failures are only logged.
+    fn record_conflict_stats(&self, stage_id: &str, source: &str, target: &str, files: &[String]) {
+        let counterparts = conflict_counterparts(source, target, files, &self.config.repo_root)
+            .unwrap_or_default();
+        if let Err(e) = record_conflict(&self.config.work_dir, stage_id, files, &counterparts) {
+            tracing::warn!(stage_id = %stage_id, error = %e, "Failed to record merge conflict stats");
+        }
     }
 
     /// Spawn a merge resolution session for a stage with merge issues.
@@ -1124,6 +1323,24 @@ impl Orchestrator {
             conflicting_files
         };
 
+        // Sandbox the resolver to the conflicting files (plus tests). Without a
+        // known conflict set there is nothing to restrict it to.
+        if conflicting_files.is_empty() {
+            self.drop_merge_scope(&stage.id);
+        } else {
+            write_merge_scope(
+                &self.config.work_dir,
+                &MergeScope::new(&stage.id, &session.id, &conflicting_files),
+            )
+            .context("Failed to write merge scope")?;
+            self.record_conflict_stats(
+                &stage.id,
+                &source_branch,
+                &target_branch,
+                &conflicting_files,
+            );
+        }
+
         // Generate merge signal
         let signal_path = generate_merge_signal(
             &session,
@@ -1138,7 +1355,7 @@ impl Orchestrator {
 
         // Spawn the merge resolution session.
         let spawned_session = self
-            .native
+            .session_backend()
             .spawn_merge_session(stage, session, &signal_path, &self.config.repo_root)
             .context("Failed to spawn merge resolution session")?;
 
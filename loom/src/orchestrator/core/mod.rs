@@ -11,6 +11,7 @@ use std::io::{self, Write};
 
 mod completion_handler;
 mod crash_handler;
+mod deadline;
 mod event_handler;
 mod merge_handler;
 mod orchestrator;
@@ -18,6 +19,7 @@ mod persistence;
 mod recovery;
 mod stage_executor;
 
+pub use deadline::RunDeadline;
 pub use orchestrator::{Orchestrator, OrchestratorConfig, OrchestratorResult};
 
 /// Clear the current line (status line) before printing a message.
@@ -31,6 +33,7 @@ pub(super) fn clear_status_line() {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::orchestrator::terminal::mock::SimulationSchedule;
     use crate::parser::frontmatter::extract_yaml_frontmatter;
     use crate::plan::schema::{SandboxConfig, StageDefinition, StageSandboxConfig};
     use crate::plan::ExecutionGraph;
@@ -53,6 +56,10 @@ mod tests {
             max_skill_recommendations: 5,
             sandbox_config: SandboxConfig::default(),
             shutdown_flag: None,
+            until: None,
+            deadline: None,
+            event_sink: None,
+            simulation: None,
         }
     }
 
@@ -84,6 +91,20 @@ mod tests {
             reasoning_effort: None,
             code_review: None,
             ultracode: false,
+            pair: false,
+            worktree_setup: vec![],
+            instructions: None,
+            prompt_prefix: None,
+            container: None,
+            keep_terminal: None,
+            outputs: Vec::new(),
+            params: Vec::new(),
+            requires_outputs: Vec::new(),
+            new_files: Vec::new(),
+            expected_duration: None,
+            acceptance_isolation: None,
+            skip_if_satisfied: false,
+            package: None,
         }];
 
         ExecutionGraph::build(stages).unwrap()
@@ -142,9 +163,11 @@ mod tests {
     }
 
     #[test]
-    #[ignore] // Requires a terminal emulator - skipped in CI
     fn test_running_session_count() {
-        let config = create_test_config();
+        let config = OrchestratorConfig {
+            simulation: Some(SimulationSchedule::default()),
+            ..create_test_config()
+        };
         let graph = create_simple_graph();
         let orchestrator = Orchestrator::new(config, graph).expect("Failed to create orchestrator");
 
@@ -5,27 +5,40 @@ use chrono::{DateTime, Utc};
 use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::Sender;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use crate::fs::work_integrity::validate_work_dir_state;
 use crate::language::{detect_project_languages, DetectedLanguage};
 use crate::models::session::Session;
-use crate::models::stage::StageStatus;
+use crate::models::stage::{Stage, StageStatus};
 use crate::models::worktree::Worktree;
 use crate::orchestrator::adjudication::AdjudicatorRegistry;
-use crate::orchestrator::monitor::{Monitor, MonitorConfig};
-use crate::plan::schema::SandboxConfig;
+use crate::orchestrator::decisions::DecisionLog;
+use crate::orchestrator::escalation::Escalation;
+use crate::orchestrator::knowledge_policy::{self, KnowledgePolicy};
+use crate::orchestrator::monitor::{Monitor, MonitorConfig, MonitorEvent};
+use crate::orchestrator::overdue::OverdueWatch;
+use crate::orchestrator::pause::{read_pause_state, PauseState};
+use crate::orchestrator::plan_report;
+use crate::orchestrator::spawn_rate::SpawnLimiter;
+use crate::orchestrator::throttle::Throttle;
+use crate::plan::graph::conflict_risk::RepoHistory;
+use crate::plan::schema::{ConflictAvoidance, SandboxConfig};
 use crate::plan::ExecutionGraph;
 use crate::skills::SkillIndex;
 use crate::utils::{cleanup_terminal, install_terminal_panic_hook};
 
+use super::deadline::RunDeadline;
 use super::event_handler::EventHandler;
 use super::persistence::Persistence;
 use super::recovery::Recovery;
 use super::stage_executor::StageExecutor;
 use crate::orchestrator::liveness::LivenessService;
+use crate::orchestrator::terminal::mock::{MockBackend, SimulationSchedule};
 use crate::orchestrator::terminal::native::NativeBackend;
+use crate::orchestrator::terminal::{backend_for_stage, TerminalBackend};
 
 /// Configuration for the orchestrator
 #[derive(Debug, Clone)]
@@ -53,6 +66,19 @@ pub struct OrchestratorConfig {
     pub sandbox_config: SandboxConfig,
     /// Shutdown flag for graceful termination (used by daemon)
     pub shutdown_flag: Option<Arc<AtomicBool>>,
+    /// Target stage for `loom run --until`: only this stage and the stages
+    /// it transitively depends on are started, and the run stops once it
+    /// completes
+    pub until: Option<String>,
+    /// Wall-clock limit (`loom run --max-runtime`): past it no new sessions
+    /// start and the run ends once running sessions are gone
+    pub deadline: Option<RunDeadline>,
+    /// Receives a copy of every monitor event before it is handled (used by
+    /// the daemon's `/events` stream)
+    pub event_sink: Option<Sender<MonitorEvent>>,
+    /// Run simulated sessions following this schedule instead of agents
+    /// (`loom run --simulate`)
+    pub simulation: Option<SimulationSchedule>,
 }
 
 impl Default for OrchestratorConfig {
@@ -72,6 +98,10 @@ impl Default for OrchestratorConfig {
             max_skill_recommendations: 5,
             sandbox_config: SandboxConfig::default(),
             shutdown_flag: None,
+            until: None,
+            deadline: None,
+            event_sink: None,
+            simulation: None,
         }
     }
 }
@@ -87,6 +117,8 @@ pub struct Orchestrator {
     pub(super) reported_crashes: HashSet<String>,
     /// Native terminal backend — spawns and kills sessions.
     pub(super) native: Arc<NativeBackend>,
+    /// Simulated backend replacing every other one under `--simulate`.
+    pub(super) simulator: Option<Arc<MockBackend>>,
     /// Liveness probe (shared with the monitor thread).
     pub(super) liveness: LivenessService,
     /// Skill index for generating skill recommendations in signals
@@ -118,11 +150,55 @@ pub struct Orchestrator {
     /// Adjudicator registry — owns worker threads + completion channel.
     /// Disabled (workers never spawn) when `ANTHROPIC_API_KEY` is unset.
     pub(super) adjudicators: AdjudicatorRegistry,
+    /// Stages this run may start (`--until` target and its dependency
+    /// closure); `None` means every stage in the graph.
+    pub(super) run_scope: Option<HashSet<String>>,
+    /// Repository history for conflict prediction. Loaded once at startup,
+    /// and only when the plan sets `conflict_avoidance: strict`: high-risk
+    /// stage pairs then never run at the same time.
+    pub(super) conflict_history: Option<RepoHistory>,
+    /// Stage IDs whose start has already been logged as deferred for
+    /// conflict avoidance (same purpose as `spawn_skip_logged`).
+    pub(super) conflict_deferral_logged: HashSet<String>,
+    /// Stage IDs whose start has already been logged as waiting for a
+    /// dependency output (`requires_outputs`; same purpose as
+    /// `spawn_skip_logged`).
+    pub(super) output_wait_logged: HashSet<String>,
+    /// Load-based limit on parallel sessions; `None` when no `[throttle]`
+    /// threshold is configured.
+    pub(super) throttle: Option<Throttle>,
+    /// Spawn rate limit and jitter; `None` when no `[spawn_rate]` limit is
+    /// configured.
+    pub(super) spawn_limiter: Option<SpawnLimiter>,
+    /// Escalation of stages left waiting for input; `None` when no
+    /// `[escalation]` threshold is configured.
+    pub(super) escalation: Option<Escalation>,
+    /// Notifications for stages running past their `expected_duration`
+    pub(super) overdue: OverdueWatch,
+    /// Audit log of scheduling decisions (`.work/logs/scheduler.jsonl`)
+    pub(super) decisions: DecisionLog,
+    /// `knowledge_first` / `knowledge_refresh_every` plan settings
+    pub(super) knowledge_policy: KnowledgePolicy,
+    /// Knowledge stage other stages were last reported waiting on under
+    /// `knowledge_first` (same purpose as `spawn_skip_logged`).
+    pub(super) knowledge_wait_logged: Option<String>,
+    /// Pause state seen on the previous poll (`loom pause`), used to report
+    /// transitions and re-evaluate readiness on resume.
+    pub(super) pause: Option<PauseState>,
+    /// Whether the run's deadline has passed (see [`RunDeadline`])
+    pub(super) deadline_reached: bool,
 }
 
 impl Orchestrator {
     /// Create a new orchestrator from config and execution graph
     pub fn new(config: OrchestratorConfig, graph: ExecutionGraph) -> Result<Self> {
+        let run_scope = config
+            .until
+            .as_deref()
+            .map(|target| graph.dependency_closure(target))
+            .transpose()
+            .context("Invalid --until target")?;
+
         let monitor_config = MonitorConfig {
             poll_interval: config.poll_interval,
             work_dir: config.work_dir.clone(),
@@ -131,8 +207,30 @@ impl Orchestrator {
 
         let mut monitor = Monitor::new(monitor_config);
 
-        let native = Arc::new(NativeBackend::new(config.work_dir.clone())?);
-        let liveness = LivenessService::new(Arc::clone(&native));
+        // Without a display only headless stages can run; windowed stages
+        // fail to spawn and are blocked with the detection error.
+        let native = match config.simulation {
+            Some(_) => {
+                NativeBackend::without_terminal(config.work_dir.clone(), "simulated run".into())
+            }
+            None => match NativeBackend::new(config.work_dir.clone()) {
+                Ok(native) => native,
+                Err(e) => {
+                    eprintln!("{e:#}");
+                    eprintln!("Continuing without a terminal: only headless stages can be spawned");
+                    NativeBackend::without_terminal(config.work_dir.clone(), format!("{e:#}"))
+                }
+            },
+        };
+        let native = Arc::new(native);
+        let simulator = config
+            .simulation
+            .clone()
+            .map(|schedule| Arc::new(MockBackend::new(config.work_dir.clone(), schedule)));
+        let liveness = match &simulator {
+            Some(simulator) => LivenessService::simulated(Arc::clone(simulator)),
+            None => LivenessService::new(Arc::clone(&native)),
+        };
         monitor.set_liveness(liveness.clone());
 
         // Load skill index if skill routing is enabled
@@ -156,7 +254,12 @@ impl Orchestrator {
                 Some(trimmed)
             }
         });
-        if api_key.is_none() {
+        if crate::offline::is_offline(Some(&config.work_dir)) {
+            tracing::warn!(
+                target: "loom::adjudication",
+                "offline mode; adjudicator is disabled for this daemon run",
+            );
+        } else if api_key.is_none() {
             tracing::warn!(
                 target: "loom::adjudication",
                 "ANTHROPIC_API_KEY not set; adjudicator is disabled for this daemon run",
@@ -178,6 +281,18 @@ impl Orchestrator {
             );
         }
 
+        let strict_conflicts = crate::fs::work_dir::load_config(&config.work_dir)
+            .ok()
+            .flatten()
+            .is_some_and(|cfg| cfg.conflict_avoidance() == ConflictAvoidance::Strict);
+        let conflict_history = strict_conflicts.then(|| RepoHistory::load(&config.repo_root));
+        let throttle = Throttle::load(&config.work_dir);
+        let spawn_limiter = SpawnLimiter::load(&config.work_dir);
+        let escalation = Escalation::load(&config.work_dir);
+        let overdue = OverdueWatch::new(&config.work_dir);
+        let knowledge_policy = KnowledgePolicy::load(&config.work_dir);
+        let decisions = DecisionLog::new(&config.work_dir);
+
         Ok(Self {
             config,
             graph,
@@ -186,6 +301,7 @@ impl Orchestrator {
             monitor,
             reported_crashes: HashSet::new(),
             native,
+            simulator,
             liveness,
             skill_index,
             detected_languages,
@@ -193,37 +309,61 @@ impl Orchestrator {
             verified_merged: HashSet::new(),
             spawn_skip_logged: HashSet::new(),
             adjudicators,
+            run_scope,
+            conflict_history,
+            conflict_deferral_logged: HashSet::new(),
+            output_wait_logged: HashSet::new(),
+            throttle,
+            spawn_limiter,
+            escalation,
+            overdue,
+            decisions,
+            knowledge_policy,
+            knowledge_wait_logged: None,
+            pause: None,
+            deadline_reached: false,
         })
     }
 
-    /// Load the skill index from the configured or default directory
-    fn load_skill_index(config: &OrchestratorConfig) -> Option<SkillIndex> {
-        // Determine skills directory: use config or default to ~/.claude/skills/
-        let skills_dir = config.skills_dir.clone().unwrap_or_else(|| {
-            dirs::home_dir()
-                .map(|h| h.join(".claude").join("skills"))
-                .unwrap_or_else(|| PathBuf::from(".claude/skills"))
-        });
-
-        if !skills_dir.exists() {
-            return None;
+    /// Backend that spawns sessions for `stage`.
+    pub(super) fn backend_for(&self, stage: &Stage) -> Arc<dyn TerminalBackend> {
+        match &self.simulator {
+            Some(simulator) => Arc::clone(simulator) as Arc<dyn TerminalBackend>,
+            None => backend_for_stage(&self.native, stage),
         }
+    }
 
-        match SkillIndex::load_from_directory(&skills_dir) {
-            Ok(index) => {
-                if index.is_empty() {
-                    None
-                } else {
-                    Some(index)
-                }
-            }
-            Err(e) => {
-                eprintln!("Warning: Failed to load skill index: {e}");
-                None
-            }
+    /// Backend that kills sessions and spawns merge sessions.
+    pub(super) fn session_backend(&self) -> &dyn TerminalBackend {
+        match &self.simulator {
+            Some(simulator) => simulator.as_ref(),
+            None => self.native.as_ref(),
         }
     }
 
+    /// Whether `stage_id` is part of this run (see `OrchestratorConfig::until`).
+    pub(super) fn in_run_scope(&self, stage_id: &str) -> bool {
+        self.run_scope
+            .as_ref()
+            .is_none_or(|scope| scope.contains(stage_id))
+    }
+
+    /// Whether the `--until` target has finished, so the run can stop.
+    fn until_target_done(&self) -> bool {
+        let Some(target) = self.config.until.as_deref() else {
+            return false;
+        };
+        self.active_sessions.is_empty()
+            && self.graph.get_node(target).is_some_and(|node| {
+                matches!(node.status, StageStatus::Completed | StageStatus::Skipped)
+            })
+    }
+
+    /// Load the skill index from the configured or default directory
+    fn load_skill_index(config: &OrchestratorConfig) -> Option<SkillIndex> {
+        crate::skills::load_skill_index(config.skills_dir.clone())
+    }
+
     /// Main run loop - executes until all stages complete or error
     pub fn run(&mut self) -> Result<OrchestratorResult> {
         // Install panic hook to restore terminal on panic
@@ -311,6 +451,9 @@ impl Orchestrator {
             self.sync_queued_status_to_files()
                 .context("Failed to sync queued status to files")?;
 
+            // Queue a knowledge refresh once enough stages have merged
+            self.request_knowledge_refresh();
+
             // Adjudicator hooks (every tick): scan for new disputes,
             // apply ready verdicts, drain completed workers. The calls
             // are no-ops when there are no pending disputes on disk.
@@ -321,15 +464,23 @@ impl Orchestrator {
             self.drain_completed_adjudicator_workers()
                 .context("Failed to drain completed adjudicator workers")?;
 
-            // Spawn merge resolution sessions for stages stuck in MergeConflict/MergeBlocked
-            let merge_sessions_spawned = self
-                .spawn_merge_resolution_sessions()
-                .context("Failed to spawn merge resolution sessions")?;
-            total_sessions_spawned += merge_sessions_spawned;
+            // `loom pause` holds back every new session until `loom resume`
+            let paused = self.sync_pause_state();
+            // Past `--max-runtime`, likewise, but for good
+            let expired = self.check_deadline();
 
-            let started = self
-                .start_ready_stages()
-                .context("Failed to start ready stages")?;
+            let started = if paused || expired {
+                0
+            } else {
+                // Spawn merge resolution sessions for stages stuck in MergeConflict/MergeBlocked
+                let merge_sessions_spawned = self
+                    .spawn_merge_resolution_sessions()
+                    .context("Failed to spawn merge resolution sessions")?;
+                total_sessions_spawned += merge_sessions_spawned;
+
+                self.start_ready_stages()
+                    .context("Failed to start ready stages")?
+            };
             total_sessions_spawned += started;
 
             // Print instructions on how to view sessions (once, after first batch starts)
@@ -346,14 +497,33 @@ impl Orchestrator {
                 // that get removed from active_sessions during event handling
                 let stage_ids: Vec<String> = self.active_sessions.keys().cloned().collect();
 
-                let events = self
+                let mut events = self
                     .monitor
                     .poll()
                     .context("Failed to poll monitor for events")?;
+                // Suspended sessions cannot heartbeat; they are not hung
+                if self
+                    .pause
+                    .as_ref()
+                    .is_some_and(PauseState::sessions_suspended)
+                {
+                    events.retain(|e| !matches!(e, MonitorEvent::SessionHung { .. }));
+                }
+
+                if let Some(sink) = &self.config.event_sink {
+                    for event in &events {
+                        let _ = sink.send(event.clone());
+                    }
+                }
 
                 self.handle_events(events)
                     .context("Failed to handle monitor events")?;
 
+                if let Some(escalation) = self.escalation.as_mut() {
+                    escalation.check();
+                }
+                self.overdue.check();
+
                 for stage_id in &stage_ids {
                     match self.load_stage(stage_id) {
                         Ok(stage) => match stage.status {
@@ -396,6 +566,20 @@ impl Orchestrator {
             }
 
             // Exit conditions depend on mode
+            if self.deadline_reached && self.active_sessions.is_empty() {
+                self.report_deadline_stop(started_at);
+                break;
+            }
+
+            if self.until_target_done() {
+                println!();
+                println!(
+                    "Target stage '{}' is complete; leaving the remaining stages untouched.",
+                    self.config.until.as_deref().unwrap_or_default()
+                );
+                break;
+            }
+
             if self.config.manual_mode {
                 // Manual mode: exit after first batch
                 break;
@@ -444,6 +628,21 @@ impl Orchestrator {
         // Restore terminal state before returning (clears \r-based status line)
         cleanup_terminal();
 
+        // A run stopped by its max runtime also gets the summary, as a
+        // record of what the time box produced
+        if self.graph.is_complete() || self.deadline_reached {
+            match plan_report::write_plan_report(&self.config.work_dir, &self.config.repo_root) {
+                Ok(path) => println!("Plan summary written to {}", path.display()),
+                Err(e) => eprintln!("Warning: Failed to write plan summary: {e:#}"),
+            }
+        }
+        if self.graph.is_complete() {
+            match crate::fs::run_history::archive_run(&self.config.work_dir) {
+                Ok(path) => println!("Run archived to {}", path.display()),
+                Err(e) => eprintln!("Warning: Failed to archive run: {e:#}"),
+            }
+        }
+
         Ok(OrchestratorResult {
             completed_stages,
             failed_stages,
@@ -454,6 +653,49 @@ impl Orchestrator {
         })
     }
 
+    /// Pick up `loom pause` / `loom resume`, report the transition, and
+    /// re-evaluate readiness on resume. Returns whether the plan is paused.
+    fn sync_pause_state(&mut self) -> bool {
+        let state = read_pause_state(&self.config.work_dir);
+        match (&self.pause, &state) {
+            (None, Some(state)) => {
+                println!();
+                match &state.reason {
+                    Some(reason) => println!("Plan paused ({reason}); no new stages will start"),
+                    None => println!("Plan paused; no new stages will start"),
+                }
+            }
+            (Some(_), None) => {
+                println!();
+                println!("Plan resumed; re-evaluating ready stages");
+                self.graph.refresh_ready_status();
+            }
+            _ => {}
+        }
+        self.pause = state;
+        self.pause.is_some()
+    }
+
+    /// Create the next knowledge refresh stage when the plan's
+    /// `knowledge_refresh_every` says one is due. The stage joins the graph
+    /// on the next sync.
+    fn request_knowledge_refresh(&mut self) {
+        let Some(every) = self.knowledge_policy.refresh_every else {
+            return;
+        };
+        let Some(round) = knowledge_policy::refresh_due(&self.graph.all_nodes(), every) else {
+            return;
+        };
+        match knowledge_policy::create_refresh_stage(&self.config.work_dir, round, every) {
+            Ok(stage_id) => {
+                println!("Queued knowledge refresh '{stage_id}' after {every} more merged stages");
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to create knowledge refresh stage");
+            }
+        }
+    }
+
     /// Count currently running sessions
     pub fn running_session_count(&self) -> usize {
         self.active_sessions.len()
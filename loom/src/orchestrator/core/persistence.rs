@@ -187,6 +187,20 @@ mod persistence_tests {
             reasoning_effort: None,
             code_review: None,
             ultracode: false,
+            pair: false,
+            worktree_setup: vec![],
+            instructions: None,
+            prompt_prefix: None,
+            container: None,
+            keep_terminal: None,
+            outputs: Vec::new(),
+            params: Vec::new(),
+            requires_outputs: Vec::new(),
+            new_files: Vec::new(),
+            expected_duration: None,
+            acceptance_isolation: None,
+            skip_if_satisfied: false,
+            package: None,
         }
     }
 
@@ -5,7 +5,11 @@ use std::io::{self, IsTerminal, Write};
 
 use crate::models::session::Session;
 use crate::models::stage::{Stage, StageStatus};
-use crate::orchestrator::retry::{calculate_backoff, is_backoff_elapsed, should_auto_retry};
+use crate::orchestrator::decisions::{Decision, DecisionKind};
+use crate::orchestrator::retry::{
+    calculate_backoff, is_backoff_elapsed, should_auto_retry, RETRY_BACKOFF_BASE_SECS,
+    RETRY_BACKOFF_MAX_SECS,
+};
 use crate::parser::frontmatter::parse_from_markdown;
 
 use super::clear_status_line;
@@ -87,7 +91,11 @@ fn check_retry_eligibility(stage: &Stage) -> bool {
     }
 
     // Calculate backoff: base 30s, max 300s (5 minutes)
-    let backoff = calculate_backoff(stage.retry_count, 30, 300);
+    let backoff = calculate_backoff(
+        stage.retry_count,
+        RETRY_BACKOFF_BASE_SECS,
+        RETRY_BACKOFF_MAX_SECS,
+    );
     is_backoff_elapsed(stage.last_failure_at, backoff)
 }
 
@@ -287,6 +295,23 @@ impl Recovery for Orchestrator {
                     merged = stage.merged,
                     "[sync_graph_with_stage_files] Loaded stage"
                 );
+                // Stages created while the daemon runs (code reviews) are not
+                // in the graph it was built with.
+                if self.graph.get_node(&stage.id).is_none() {
+                    let added = std::fs::read_to_string(&path)
+                        .map_err(anyhow::Error::from)
+                        .and_then(|content| crate::fs::extract_stage_definition(&content))
+                        .and_then(|definition| self.graph.add_stage(&definition));
+                    if let Err(e) = added {
+                        tracing::warn!(
+                            stage_id = %stage.id,
+                            error = %e,
+                            "Failed to add new stage to the execution graph"
+                        );
+                        continue;
+                    }
+                }
+
                 // Always sync outputs to the graph so they're available for dependent stages
                 if !stage.outputs.is_empty() {
                     self.graph
@@ -305,8 +330,14 @@ impl Recovery for Orchestrator {
                         // already verified merged this daemon session — the
                         // fact cannot change absent a history rewrite, and
                         // reconcile mutations invalidate the memo.
+                        // Knowledge stages have no branch; code reviews never
+                        // merge theirs (merged only marks the review done).
                         if stage.merged
-                            && stage.stage_type != crate::models::stage::StageType::Knowledge
+                            && !matches!(
+                                stage.stage_type,
+                                crate::models::stage::StageType::Knowledge
+                                    | crate::models::stage::StageType::CodeReview
+                            )
                             && !self.verified_merged.contains(&stage.id)
                         {
                             if self.verify_merged_true_or_revert(&mut stage, &target_branch) {
@@ -473,6 +504,14 @@ impl Recovery for Orchestrator {
                                     attempt = stage.retry_count + 1,
                                     "Auto-retrying stage"
                                 );
+                                self.decisions.record(Decision::new(
+                                    &stage.id,
+                                    DecisionKind::Ready,
+                                    format!(
+                                        "retry backoff elapsed (attempt {})",
+                                        stage.retry_count + 1
+                                    ),
+                                ));
 
                                 // ATOMIC UPDATE PATTERN:
                                 // 1. Save original graph state for potential rollback
@@ -570,6 +609,9 @@ impl Recovery for Orchestrator {
                         }
                     }
                     StageStatus::Skipped => {
+                        // Merged only when skipped as already satisfied, which
+                        // unblocks dependents on the next readiness refresh
+                        self.graph.set_node_merged(&stage.id, stage.merged);
                         if let Err(e) = self.graph.mark_status(&stage.id, StageStatus::Skipped) {
                             tracing::warn!(
                                 "Failed to sync graph status for stage {}: {}",
@@ -685,6 +727,16 @@ impl Recovery for Orchestrator {
                 // Use validated transition
                 if stage.try_mark_queued().is_ok() {
                     self.save_stage(&stage)?;
+                    let reason = if stage.dependencies.is_empty() {
+                        "no dependencies".to_string()
+                    } else {
+                        format!(
+                            "dependencies completed and merged: {}",
+                            stage.dependencies.join(", ")
+                        )
+                    };
+                    self.decisions
+                        .record(Decision::new(&stage.id, DecisionKind::Ready, reason));
                 }
             }
         }
@@ -733,6 +785,12 @@ impl Recovery for Orchestrator {
                 }
             };
 
+            // A pair reviewer does not own its stage; losing it is no reason
+            // to reset the stage
+            if session.is_pair_reviewer() {
+                continue;
+            }
+
             // Check if the session is still running. Treat a probe *error* as
             // "unknown" and skip recovery this pass (O-9) — matching the
             // monitor's fail-safe behavior. Failing UNSAFE here (unwrap_or
@@ -958,6 +1016,11 @@ impl Recovery for Orchestrator {
         }
 
         for node in self.graph.all_nodes() {
+            // Stages outside the `--until` scope never start in this run
+            if !self.in_run_scope(&node.id) {
+                continue;
+            }
+
             // Check graph status first
             match node.status {
                 StageStatus::Completed => continue,
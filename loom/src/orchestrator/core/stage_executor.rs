@@ -2,6 +2,8 @@
 
 use anyhow::{Context, Result};
 use chrono::Utc;
+use std::path::Path;
+use std::time::Instant;
 
 use crate::git;
 use crate::git::worktree::setup_worktree_hooks;
@@ -11,9 +13,15 @@ use crate::hooks::{find_hooks_dir, setup_hooks_for_worktree, HooksConfig};
 use crate::models::failure::{FailureInfo, FailureType};
 use crate::models::session::Session;
 use crate::models::stage::{Stage, StageStatus, StageType};
+use crate::models::worktree::Worktree;
+use crate::orchestrator::decisions::{Decision, DecisionKind};
+use crate::orchestrator::knowledge_policy;
 use crate::orchestrator::signals::{
     generate_knowledge_signal, generate_signal_with_skills, DependencyStatus,
 };
+use crate::orchestrator::skip::skip_satisfied_stage;
+use crate::plan::graph::conflict_risk::{assess_pair, RiskCandidate, RiskLevel};
+use crate::verify::transitions::load_stage;
 
 use super::persistence::Persistence;
 use super::Orchestrator;
@@ -28,33 +36,166 @@ pub(super) trait StageExecutor: Persistence {
 
     /// Start a knowledge stage (runs in main repo without worktree)
     fn start_knowledge_stage(&mut self, stage: Stage) -> Result<()>;
+
+    /// Under `conflict_avoidance: strict`, the first of `running` that
+    /// `stage_id` has a high conflict risk with
+    fn conflicting_stage(&self, stage_id: &str, running: &[String]) -> Option<String>;
 }
 
 impl StageExecutor for Orchestrator {
     fn start_ready_stages(&mut self) -> Result<usize> {
-        let ready_stages = self.graph.ready_stages();
-        let available_slots = self
-            .config
-            .max_parallel_sessions
-            .saturating_sub(self.active_sessions.len());
+        let running = self.active_sessions.len();
+        let max_parallel = match self.throttle.as_mut() {
+            Some(throttle) => throttle.effective_max(self.config.max_parallel_sessions, running),
+            None => self.config.max_parallel_sessions,
+        };
+        let mut ready_stages = self.graph.ready_stages();
+        knowledge_policy::prioritize(&mut ready_stages);
+        let mut deferred: Vec<(String, String)> = Vec::new();
+        // Under `knowledge_first`, only knowledge stages start until the
+        // plan's own knowledge stages are done
+        if self.knowledge_policy.knowledge_first {
+            let waiting_on = knowledge_policy::pending_knowledge_stage(&self.graph.all_nodes())
+                .map(String::from);
+            if let Some(knowledge_id) = &waiting_on {
+                let held: Vec<String> = ready_stages
+                    .iter()
+                    .filter(|node| node.stage_type != StageType::Knowledge)
+                    .map(|node| node.id.clone())
+                    .collect();
+                if !held.is_empty() && self.knowledge_wait_logged.as_ref() != Some(knowledge_id) {
+                    println!(
+                        "Holding {} stage(s) until knowledge stage '{knowledge_id}' \
+                         completes (knowledge_first)",
+                        held.len()
+                    );
+                }
+                for id in held {
+                    deferred.push((
+                        id,
+                        format!("waiting for knowledge stage '{knowledge_id}' (knowledge_first)"),
+                    ));
+                }
+                ready_stages.retain(|node| node.stage_type == StageType::Knowledge);
+            }
+            self.knowledge_wait_logged = waiting_on;
+        }
+        let available_slots = max_parallel.saturating_sub(running);
 
-        // Collect stage IDs first to avoid borrow checker issues
-        let stage_ids: Vec<String> = ready_stages
+        // Collect stage IDs first to avoid borrow checker issues.
+        // Stages outside the `--until` scope stay queued for a later run.
+        let (in_scope, out_of_scope): (Vec<_>, Vec<_>) = ready_stages
             .iter()
-            .take(available_slots)
             .map(|node| node.id.clone())
-            .collect();
+            .partition(|id| self.in_run_scope(id));
+        for id in out_of_scope {
+            deferred.push((id, "outside the --until scope".to_string()));
+        }
+        let stage_ids: Vec<String> = in_scope.iter().take(available_slots).cloned().collect();
+        for id in in_scope.into_iter().skip(available_slots) {
+            deferred.push((id, format!("parallel limit reached (max {max_parallel})")));
+        }
+        if !ready_stages.is_empty() {
+            tracing::debug!(
+                ready = ?ready_stages.iter().map(|n| n.id.as_str()).collect::<Vec<_>>(),
+                running,
+                max_parallel,
+                selected = ?stage_ids,
+                "Scheduling pass"
+            );
+        }
+        for (id, reason) in deferred {
+            self.decisions
+                .record(Decision::new(&id, DecisionKind::Deferred, reason));
+        }
 
         let mut started = 0;
-        for stage_id in stage_ids {
-            self.start_stage(&stage_id)
+        let mut running: Vec<String> = self.active_sessions.keys().cloned().collect();
+        for (idx, stage_id) in stage_ids.iter().enumerate() {
+            if let Some(blocker) = self.conflicting_stage(stage_id, &running) {
+                self.decisions.record(Decision::new(
+                    stage_id,
+                    DecisionKind::Deferred,
+                    format!("high conflict risk with running stage '{blocker}'"),
+                ));
+                if self.conflict_deferral_logged.insert(stage_id.clone()) {
+                    println!(
+                        "Deferring stage '{stage_id}': high conflict risk with running stage \
+                         '{blocker}' (conflict_avoidance: strict)"
+                    );
+                }
+                continue;
+            }
+            if let Some(limiter) = self.spawn_limiter.as_mut() {
+                if limiter.allowance(Instant::now()) == 0 {
+                    tracing::debug!(
+                        deferred = stage_ids.len() - idx,
+                        "Spawn rate limit reached; deferring remaining stages"
+                    );
+                    limiter.note_deferred(stage_ids.len() - idx);
+                    for id in &stage_ids[idx..] {
+                        self.decisions.record(Decision::new(
+                            id,
+                            DecisionKind::Deferred,
+                            "spawn rate limit reached",
+                        ));
+                    }
+                    break;
+                }
+            }
+            tracing::debug!(stage_id = %stage_id, "Starting stage");
+            let spawned_before = self.active_sessions.len();
+            self.start_stage(stage_id)
                 .with_context(|| format!("Failed to start stage: {stage_id}"))?;
+            self.conflict_deferral_logged.remove(stage_id);
+            // Skipped as already satisfied: no session, no slot taken
+            if self
+                .graph
+                .get_node(stage_id)
+                .is_some_and(|node| node.status == StageStatus::Skipped)
+            {
+                continue;
+            }
+            if self
+                .graph
+                .get_node(stage_id)
+                .is_some_and(|node| node.status == StageStatus::Executing)
+            {
+                self.decisions.record(Decision::new(
+                    stage_id,
+                    DecisionKind::Started,
+                    format!(
+                        "slot available ({} of max {max_parallel} running)",
+                        running.len()
+                    ),
+                ));
+            }
+            if let Some(limiter) = self.spawn_limiter.as_mut() {
+                if self.active_sessions.len() > spawned_before {
+                    let jitter = limiter.jitter();
+                    limiter.record(Instant::now(), jitter);
+                }
+            }
+            running.push(stage_id.clone());
             started += 1;
         }
 
         Ok(started)
     }
 
+    fn conflicting_stage(&self, stage_id: &str, running: &[String]) -> Option<String> {
+        let history = self.conflict_history.as_ref()?;
+        let candidate = RiskCandidate::from(self.graph.get_node(stage_id)?);
+        running
+            .iter()
+            .filter_map(|other| self.graph.get_node(other))
+            .find(|other| {
+                assess_pair(&candidate, &RiskCandidate::from(*other), history)
+                    .is_some_and(|risk| risk.level() == RiskLevel::High)
+            })
+            .map(|other| other.id.clone())
+    }
+
     fn start_stage(&mut self, stage_id: &str) -> Result<()> {
         let mut stage = self.load_stage(stage_id)?;
 
@@ -68,6 +209,12 @@ impl StageExecutor for Orchestrator {
 
         // Skip if stage is held
         if stage.held {
+            tracing::debug!(stage_id = %stage_id, "Not starting held stage");
+            self.decisions.record(Decision::new(
+                stage_id,
+                DecisionKind::Deferred,
+                "held until `loom stage release`",
+            ));
             return Ok(());
         }
 
@@ -97,6 +244,11 @@ impl StageExecutor for Orchestrator {
         ) {
             Ok(true) => {}
             Ok(false) => {
+                self.decisions.record(Decision::new(
+                    stage_id,
+                    DecisionKind::Deferred,
+                    format!("dependencies not verified merged into {target_branch}"),
+                ));
                 if self.spawn_skip_logged.insert(stage_id.to_string()) {
                     tracing::error!(
                         stage_id = %stage_id,
@@ -117,6 +269,30 @@ impl StageExecutor for Orchestrator {
             }
         }
 
+        // Data dependencies: a merged dependency is not enough when the stage
+        // also requires outputs it must have set. Wait (like a hold) until
+        // `loom stage output set` provides them; the next poll re-checks.
+        if !stage.requires_outputs.is_empty() {
+            let producers: Vec<Stage> = stage
+                .dependencies
+                .iter()
+                .filter_map(|dep| self.load_stage(dep).ok())
+                .collect();
+            let missing = stage.missing_required_outputs(&producers);
+            if let Some((producer, key)) = missing.first() {
+                self.decisions.record(Decision::new(
+                    stage_id,
+                    DecisionKind::Deferred,
+                    format!("waiting for output {key} from {producer}"),
+                ));
+                if self.output_wait_logged.insert(stage_id.to_string()) {
+                    println!("Stage '{stage_id}' waiting for output {key} from {producer}");
+                }
+                return Ok(());
+            }
+            self.output_wait_logged.remove(stage_id);
+        }
+
         // Transition through Queued if currently WaitingForDeps to reduce race window
         if stage.status == StageStatus::WaitingForDeps {
             stage.try_mark_queued()?;
@@ -182,6 +358,11 @@ impl StageExecutor for Orchestrator {
             }
             Err(BaseBranchError::SchedulingNotReady(msg)) => {
                 // Transient — skip this cycle, retry on the next poll.
+                self.decisions.record(Decision::new(
+                    stage_id,
+                    DecisionKind::Deferred,
+                    format!("base branch not ready: {msg}"),
+                ));
                 eprintln!("Stage '{stage_id}' skipped due to scheduling error (will retry): {msg}");
                 return Ok(());
             }
@@ -192,10 +373,14 @@ impl StageExecutor for Orchestrator {
             }
         };
 
-        let worktree = match git::get_or_create_worktree(
+        let sparse = git::worktree::sparse_profile(&stage, &self.config.work_dir);
+        let worktree_created =
+            !git::worktree::get_worktree_path(stage_id, &self.config.repo_root).exists();
+        let worktree = match git::vcs::for_repo(&self.config.repo_root).get_or_create_workspace(
             stage_id,
             &self.config.repo_root,
             Some(resolved.branch_name()),
+            sparse.as_deref(),
         ) {
             Ok(wt) => wt,
             Err(e) => {
@@ -216,6 +401,108 @@ impl StageExecutor for Orchestrator {
             }
         };
 
+        // Run loom-owned worktree setup (dependency install, env files) before
+        // anything else touches the worktree, so a broken environment blocks the
+        // stage instead of spawning a session that cannot build. Retries and
+        // resumes on an existing worktree skip it once it has succeeded.
+        if !stage.worktree_setup.is_empty()
+            && crate::verify::worktree_setup_pending(
+                &self.config.work_dir,
+                stage_id,
+                worktree_created,
+            )?
+        {
+            let log_path = crate::verify::worktree_setup_log_path(&self.config.work_dir, stage_id);
+            println!("  Running worktree setup for '{stage_id}'...");
+            let failure = match crate::verify::run_worktree_setup(
+                &stage.worktree_setup,
+                &worktree.path,
+                &log_path,
+            ) {
+                Ok(None) => None,
+                Ok(Some(failure)) => Some(failure.describe()),
+                Err(e) => Some(format!("Worktree setup errored: {e:#}")),
+            };
+            if let Some(err_msg) = failure {
+                eprintln!("Stage '{stage_id}' blocked: {err_msg}");
+                if stage.try_mark_blocked().is_ok() {
+                    stage.failure_info = Some(FailureInfo {
+                        failure_type: FailureType::InfrastructureError,
+                        detected_at: Utc::now(),
+                        evidence: vec![err_msg],
+                    });
+                    self.save_stage(&stage)?;
+                }
+                return Ok(());
+            }
+            crate::verify::mark_worktree_setup_done(&self.config.work_dir, stage_id)?;
+            println!("  ✓ Worktree setup complete for '{stage_id}'");
+        }
+
+        // `skip_if_satisfied`: on the first attempt the worktree is the
+        // resolved base with nothing of the stage's own, so checks that pass
+        // here mean the work is already done (e.g. by an earlier run of an
+        // amended plan).
+        if stage.skip_if_satisfied && stage.started_at.is_none() {
+            match crate::verify::satisfied::is_already_satisfied(
+                &stage,
+                &worktree.path,
+                &self.config.work_dir,
+            ) {
+                Ok(true) => {
+                    skip_satisfied_stage(&mut stage)?;
+                    self.save_stage(&stage)?;
+                    self.graph.set_node_merged(stage_id, true);
+                    self.graph.mark_status(stage_id, StageStatus::Skipped)?;
+                    let vcs = git::vcs::for_repo(&self.config.repo_root);
+                    if let Err(e) = vcs.remove_workspace(stage_id, &self.config.repo_root, true) {
+                        tracing::warn!(stage_id = %stage_id, error = %e, "Failed to remove worktree of satisfied stage");
+                    }
+                    // The branch holds nothing of the stage's own; left behind
+                    // it would look like unmerged work.
+                    let branch = git::branch_name_for_stage(stage_id);
+                    if let Err(e) = vcs.delete_branch(&branch, true, &self.config.repo_root) {
+                        tracing::warn!(stage_id = %stage_id, error = %e, "Failed to delete branch of satisfied stage");
+                    }
+                    println!(
+                        "  ✓ Stage '{stage_id}' skipped: acceptance already passes on {}",
+                        resolved.branch_name()
+                    );
+                    return Ok(());
+                }
+                Ok(false) => {}
+                Err(e) => {
+                    tracing::warn!(stage_id = %stage_id, error = %e, "Already-satisfied check failed; running the stage");
+                }
+            }
+        }
+
+        // Pre-flight: catch a broken plan entry (missing setup binary, `files`
+        // matching nothing, missing acceptance script) before a session spends
+        // time discovering it.
+        let (blocking, warnings): (Vec<_>, Vec<_>) =
+            crate::verify::run_preflight(&stage, &worktree.path)
+                .into_iter()
+                .partition(|issue| issue.is_blocking());
+        for warning in &warnings {
+            eprintln!("  ⚠ Pre-flight '{stage_id}': {}", warning.describe());
+        }
+        if !blocking.is_empty() {
+            eprintln!("Stage '{stage_id}' blocked: pre-flight checks failed");
+            for issue in &blocking {
+                eprintln!("  ✗ {}", issue.describe());
+            }
+            if stage.try_mark_blocked().is_ok() {
+                stage.failure_info = Some(FailureInfo {
+                    failure_type: FailureType::PreflightFailed,
+                    detected_at: Utc::now(),
+                    evidence: blocking.iter().map(|issue| issue.describe()).collect(),
+                });
+                self.save_stage(&stage)?;
+            }
+            return Ok(());
+        }
+
         // Run before-stage checks if configured (verify pre-conditions in fresh worktree)
         if !stage.before_stage.is_empty() {
             let check_dir = match &stage.working_dir {
@@ -224,10 +511,14 @@ impl StageExecutor for Orchestrator {
             };
 
             println!("  Running before-stage checks for '{stage_id}'...");
-            match crate::verify::before_after::run_before_stage_checks(
+            let checks = crate::verify::dependency_outputs::resolved_checks(
+                &stage,
                 &stage.before_stage,
-                &check_dir,
-            ) {
+                &self.config.work_dir,
+            );
+            match checks.and_then(|checks| {
+                crate::verify::before_after::run_before_stage_checks(&checks, &check_dir)
+            }) {
                 Ok(gaps) if !gaps.is_empty() => {
                     for gap in &gaps {
                         eprintln!("  ✗ Before-stage: {}", gap.description);
@@ -291,6 +582,11 @@ impl StageExecutor for Orchestrator {
             eprintln!("Warning: Failed to write sandbox settings for stage '{stage_id}': {e}");
             // Continue anyway - sandbox is optional enhancement
         }
+        if let Err(e) =
+            crate::sandbox::sync_context_scope(&self.config.work_dir, stage_id, &stage.sandbox)
+        {
+            eprintln!("Warning: Failed to write read scope for stage '{stage_id}': {e}");
+        }
 
         // Honor a pending recovery signal (C-5). `loom stage retry --context`
         // (and crash/hung auto-recovery) writes a `recovery-<...>` signal file
@@ -327,7 +623,7 @@ impl StageExecutor for Orchestrator {
             // Reuse the pre-written recovery signal.
             recovery_path
         } else {
-            let deps = get_dependency_status(&stage, &self.graph);
+            let deps = get_dependency_status(&stage, &self.graph, &self.config.work_dir);
 
             // Check for existing handoff file to include in signal for continuation
             let handoff_file = find_latest_handoff(&stage.id, &self.config.work_dir)
@@ -374,7 +670,7 @@ impl StageExecutor for Orchestrator {
             // stage in Executing on disk; subsequent `loom run` invocations
             // poll forever because Executing stages are never re-spawned.
             match self
-                .native
+                .backend_for(&stage)
                 .spawn_session(&stage, &worktree, session, &signal_path)
             {
                 Ok(spawned) => {
@@ -387,7 +683,11 @@ impl StageExecutor for Orchestrator {
                     eprintln!("{err_msg}");
                     // Remove orphan resources so a retry can start clean.
                     // Worktree — best-effort force-removal; ignore "not found" etc.
-                    let _ = git::remove_worktree(stage_id, &self.config.repo_root, true);
+                    let _ = git::vcs::for_repo(&self.config.repo_root).remove_workspace(
+                        stage_id,
+                        &self.config.repo_root,
+                        true,
+                    );
                     // Branch — force-delete so the next retry can recreate
                     // it from the correct base.
                     let branch = git::branch_name_for_stage(stage_id);
@@ -462,6 +762,10 @@ impl StageExecutor for Orchestrator {
             return Ok(());
         }
 
+        if updated_stage.pair && !self.config.manual_mode {
+            self.spawn_pair_reviewer(&updated_stage, &worktree, &signal_path);
+        }
+
         self.active_sessions
             .insert(stage_id.to_string(), spawned_session);
         self.active_worktrees.insert(stage_id.to_string(), worktree);
@@ -538,7 +842,7 @@ impl StageExecutor for Orchestrator {
             eprintln!("Warning: Failed to add settings.local.json to main repo gitignore: {e}");
         }
 
-        let deps = get_dependency_status(&stage, &self.graph);
+        let deps = get_dependency_status(&stage, &self.graph, &self.config.work_dir);
 
         // Check for existing handoff file to include in signal for continuation
         let handoff_file = find_latest_handoff(&stage.id, &self.config.work_dir)
@@ -566,7 +870,7 @@ impl StageExecutor for Orchestrator {
         let spawned_session = if !self.config.manual_mode {
             // Spawn session in the main repo directory (not a worktree)
             let spawned = self
-                .native
+                .backend_for(&stage)
                 .spawn_knowledge_session(&stage, session, &signal_path, &self.config.repo_root)
                 .with_context(|| {
                     format!("Failed to spawn knowledge session for stage: {stage_id}")
@@ -615,6 +919,29 @@ impl StageExecutor for Orchestrator {
 /// Helpers shared by the worktree spawn path (recovery-signal delivery and
 /// infrastructure-failure containment).
 impl Orchestrator {
+    /// Start the read-only reviewer of a pair-mode stage next to its
+    /// implementer, sharing the implementer's signal. A failure only warns:
+    /// the stage then waits for a manual `loom stage pair-review`.
+    fn spawn_pair_reviewer(&self, stage: &Stage, worktree: &Worktree, signal_path: &Path) {
+        let stage_id = &stage.id;
+        match self.backend_for(stage).spawn_session(
+            stage,
+            worktree,
+            Session::new_pair_reviewer(),
+            signal_path,
+        ) {
+            Ok(reviewer) => {
+                println!("  Started reviewer: {stage_id}");
+                if let Err(e) = self.save_session(&reviewer) {
+                    eprintln!("Warning: Failed to save reviewer session for {stage_id}: {e:#}");
+                }
+            }
+            Err(e) => {
+                eprintln!("Warning: Failed to spawn reviewer for pair stage {stage_id}: {e:#}");
+            }
+        }
+    }
+
     /// Mark a stage Blocked with an `InfrastructureError` after a failure that
     /// occurred *after* it was already marked Executing but *before* a session
     /// was successfully recorded (O-11).
@@ -710,9 +1037,13 @@ fn is_recovery_id_suffix(suffix: &str) -> bool {
 }
 
 /// Get dependency status for signal generation
+///
+/// Status and outputs come from the graph; the declared output schema is
+/// read from the dependency's stage file.
 fn get_dependency_status(
     stage: &Stage,
     graph: &crate::plan::ExecutionGraph,
+    work_dir: &Path,
 ) -> Vec<DependencyStatus> {
     stage
         .dependencies
@@ -723,12 +1054,16 @@ fn get_dependency_status(
             } else {
                 ("Unknown".to_string(), Vec::new())
             };
+            let expected_outputs = load_stage(dep_id, work_dir)
+                .map(|dep| dep.expected_outputs)
+                .unwrap_or_default();
 
             DependencyStatus {
                 stage_id: dep_id.clone(),
                 name: dep_id.clone(),
                 status,
                 outputs,
+                expected_outputs,
             }
         })
         .collect()
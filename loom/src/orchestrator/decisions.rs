@@ -0,0 +1,190 @@
+//! Persistent audit log of scheduler decisions.
+//!
+//! The daemon appends one JSON line to `.work/logs/scheduler.jsonl` for each
+//! decision it makes about a stage: it became ready (and why), was deferred
+//! (parallel limit, conflict avoidance, holds, missing outputs, ...), was
+//! started, or will be retried after a backoff. `loom status --explain`
+//! answers "why is this stage waiting right now"; the log answers "what
+//! happened to it earlier", and `loom status --decisions` shows its tail.
+//!
+//! The scheduler re-evaluates every stage on each poll, so a decision is
+//! only written when it differs from the previous one for the same stage.
+//! The file is rotated with the other logs (see [`crate::fs::logs`]).
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::fs::logs::rotated_path;
+use crate::secrets::redact;
+
+/// What the scheduler decided about a stage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DecisionKind {
+    /// The stage's dependencies are satisfied; it may start
+    Ready,
+    /// The stage is ready but was not started on this pass
+    Deferred,
+    /// A session (or knowledge run) was started for the stage
+    Started,
+    /// The stage failed and will be retried once `until` has passed
+    RetryScheduled,
+}
+
+impl std::fmt::Display for DecisionKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            Self::Ready => "ready",
+            Self::Deferred => "deferred",
+            Self::Started => "started",
+            Self::RetryScheduled => "retry",
+        };
+        f.write_str(label)
+    }
+}
+
+/// One line of the scheduler log.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Decision {
+    pub at: DateTime<Utc>,
+    pub stage_id: String,
+    pub kind: DecisionKind,
+    /// Why, in a few words
+    pub detail: String,
+    /// When a delayed action is due (retries)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub until: Option<DateTime<Utc>>,
+}
+
+impl Decision {
+    pub fn new(stage_id: &str, kind: DecisionKind, detail: impl Into<String>) -> Self {
+        Self {
+            at: Utc::now(),
+            stage_id: stage_id.to_string(),
+            kind,
+            detail: detail.into(),
+            until: None,
+        }
+    }
+
+    pub fn until(mut self, until: DateTime<Utc>) -> Self {
+        self.until = Some(until);
+        self
+    }
+}
+
+/// Path of the scheduler log under `work_dir`.
+pub fn decisions_path(work_dir: &Path) -> PathBuf {
+    work_dir.join("logs").join("scheduler.jsonl")
+}
+
+/// Appends decisions, skipping repeats of a stage's previous decision.
+#[derive(Debug)]
+pub struct DecisionLog {
+    path: PathBuf,
+    last: HashMap<String, (DecisionKind, String)>,
+}
+
+impl DecisionLog {
+    pub fn new(work_dir: &Path) -> Self {
+        Self {
+            path: decisions_path(work_dir),
+            last: HashMap::new(),
+        }
+    }
+
+    /// Record `decision` unless it repeats the stage's previous one.
+    ///
+    /// Logging is best effort: a write failure is reported through
+    /// `tracing` and never stops the scheduler.
+    pub fn record(&mut self, decision: Decision) {
+        let key = (decision.kind, decision.detail.clone());
+        if self.last.get(&decision.stage_id) == Some(&key) {
+            return;
+        }
+        tracing::debug!(
+            stage_id = %decision.stage_id,
+            kind = %decision.kind,
+            detail = %decision.detail,
+            "Scheduler decision"
+        );
+        if let Err(e) = self.append(&decision) {
+            tracing::warn!(error = %e, "Failed to write scheduler decision log");
+        }
+        self.last.insert(decision.stage_id, key);
+    }
+
+    fn append(&self, decision: &Decision) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let line = serde_json::to_string(decision)?;
+        let line = redact(&line);
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .with_context(|| format!("Failed to open {}", self.path.display()))?;
+        writeln!(file, "{line}")?;
+        Ok(())
+    }
+}
+
+/// The last `limit` decisions, oldest first, optionally for one stage.
+///
+/// Reads the most recent rotated generation too, so a rotation does not
+/// empty the view. Malformed lines are skipped.
+pub fn read_recent(work_dir: &Path, limit: usize, stage_id: Option<&str>) -> Vec<Decision> {
+    let path = decisions_path(work_dir);
+    let mut decisions: Vec<Decision> = [rotated_path(&path, 1), path]
+        .iter()
+        .filter_map(|path| fs::read_to_string(path).ok())
+        .flat_map(|content| {
+            content
+                .lines()
+                .filter_map(|line| serde_json::from_str::<Decision>(line).ok())
+                .collect::<Vec<_>>()
+        })
+        .filter(|d| stage_id.is_none_or(|id| d.stage_id == id))
+        .collect();
+    let skip = decisions.len().saturating_sub(limit);
+    decisions.drain(..skip);
+    decisions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_record_skips_repeats_and_reads_back() {
+        let temp = TempDir::new().unwrap();
+        let mut log = DecisionLog::new(temp.path());
+        let deferred = || Decision::new("b", DecisionKind::Deferred, "parallel limit reached");
+        log.record(Decision::new("a", DecisionKind::Ready, "no dependencies"));
+        log.record(deferred());
+        log.record(deferred());
+        log.record(Decision::new("b", DecisionKind::Started, "slot available"));
+        log.record(deferred());
+
+        let all = read_recent(temp.path(), 10, None);
+        let kinds: Vec<_> = all.iter().map(|d| (d.stage_id.as_str(), d.kind)).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("a", DecisionKind::Ready),
+                ("b", DecisionKind::Deferred),
+                ("b", DecisionKind::Started),
+                ("b", DecisionKind::Deferred),
+            ]
+        );
+        assert_eq!(read_recent(temp.path(), 2, None).len(), 2);
+        assert_eq!(read_recent(temp.path(), 10, Some("a")).len(), 1);
+    }
+}
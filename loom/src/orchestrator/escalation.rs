@@ -0,0 +1,310 @@
+//! Escalation of stages left waiting for input.
+//!
+//! A stage in `WaitingForInput` blocks on a human, and nothing downstream
+//! moves until someone answers. When `after_minutes` is configured, the
+//! daemon escalates stages that have waited that long:
+//!
+//! * A desktop notification (repeated every `repeat_minutes`, if set).
+//! * With `hold_dependents`, every dependent that has not started yet is
+//!   held, so `loom status` shows the pipeline is gated on the waiting
+//!   stage. loom releases these holds itself once the stage resumes; holds
+//!   placed with `loom stage hold` are never touched.
+//!
+//! ```toml
+//! [escalation]
+//! after_minutes = 30
+//! repeat_minutes = 60     # optional
+//! hold_dependents = true  # optional, default false
+//! ```
+//!
+//! `loom status` shows how long each stage has been waiting and marks the
+//! ones past `after_minutes` regardless of whether the daemon is running.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use crate::fs::work_dir::read_escalation_config;
+use crate::models::stage::{Stage, StageStatus};
+use crate::orchestrator::notify::{notify_assignee, send_desktop_notification};
+use crate::utils::format_elapsed;
+use crate::verify::transitions::{list_all_stages, save_stage};
+
+/// How often the daemon looks for stages to escalate.
+const CHECK_INTERVAL: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// Persisted `[escalation]` section of `.work/config.toml`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct EscalationConfig {
+    /// Minutes a stage may wait for input before it is escalated
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub after_minutes: Option<u64>,
+    /// Minutes between repeated notifications for a stage still waiting
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repeat_minutes: Option<u64>,
+    /// Hold the dependents of an escalated stage until it resumes
+    #[serde(default)]
+    pub hold_dependents: bool,
+}
+
+impl EscalationConfig {
+    /// Whether a stage that started waiting at `since` is past the threshold.
+    pub fn is_escalated(&self, since: DateTime<Utc>, now: DateTime<Utc>) -> bool {
+        self.after_minutes
+            .is_some_and(|after| now - since >= Duration::minutes(after as i64))
+    }
+}
+
+/// Changes one escalation check makes.
+#[derive(Debug, Default, PartialEq)]
+pub struct EscalationActions {
+    /// Waiting stages to notify about, with how long they have waited
+    pub notify: Vec<(String, Duration)>,
+    /// Not-yet-started stages to hold, with the waiting stage gating them
+    pub hold: Vec<(String, String)>,
+    /// Stages whose escalation hold should be released
+    pub release: Vec<String>,
+}
+
+/// Decide what to escalate, hold and release.
+///
+/// `notified` maps stages to the time of their last notification.
+pub fn plan_escalation(
+    config: &EscalationConfig,
+    stages: &[Stage],
+    notified: &HashMap<String, DateTime<Utc>>,
+    now: DateTime<Utc>,
+) -> EscalationActions {
+    let mut actions = EscalationActions::default();
+    let escalated: Vec<&Stage> = stages
+        .iter()
+        .filter(|s| s.status == StageStatus::WaitingForInput)
+        .filter(|s| s.waiting_since.is_some_and(|t| config.is_escalated(t, now)))
+        .collect();
+
+    for stage in &escalated {
+        let due = match (notified.get(&stage.id), config.repeat_minutes) {
+            (None, _) => true,
+            (Some(last), Some(repeat)) => now - *last >= Duration::minutes(repeat as i64),
+            (Some(_), None) => false,
+        };
+        if due {
+            let waited = now - stage.waiting_since.unwrap_or(now);
+            actions.notify.push((stage.id.clone(), waited));
+        }
+    }
+
+    let gating: HashSet<&str> = escalated.iter().map(|s| s.id.as_str()).collect();
+    if config.hold_dependents {
+        let mut held = HashSet::new();
+        for stage in &escalated {
+            for dependent in dependents_of(&stage.id, stages) {
+                if !dependent.held
+                    && matches!(
+                        dependent.status,
+                        StageStatus::WaitingForDeps | StageStatus::Queued
+                    )
+                    && held.insert(dependent.id.as_str())
+                {
+                    actions.hold.push((dependent.id.clone(), stage.id.clone()));
+                }
+            }
+        }
+    }
+
+    for stage in stages {
+        if let Some(by) = &stage.held_by {
+            if stage.held && (!config.hold_dependents || !gating.contains(by.as_str())) {
+                actions.release.push(stage.id.clone());
+            }
+        }
+    }
+    actions
+}
+
+/// Direct and transitive dependents of `stage_id`.
+fn dependents_of<'a>(stage_id: &str, stages: &'a [Stage]) -> Vec<&'a Stage> {
+    let mut found = Vec::new();
+    let mut seen = HashSet::from([stage_id.to_string()]);
+    let mut queue = VecDeque::from([stage_id.to_string()]);
+    while let Some(id) = queue.pop_front() {
+        for stage in stages.iter().filter(|s| s.dependencies.contains(&id)) {
+            if seen.insert(stage.id.clone()) {
+                found.push(stage);
+                queue.push_back(stage.id.clone());
+            }
+        }
+    }
+    found
+}
+
+/// Daemon-side escalation of waiting stages.
+pub struct Escalation {
+    config: EscalationConfig,
+    work_dir: PathBuf,
+    notified: HashMap<String, DateTime<Utc>>,
+    last_check: Option<Instant>,
+}
+
+impl Escalation {
+    /// Load the `[escalation]` config; `None` when `after_minutes` is unset.
+    pub fn load(work_dir: &Path) -> Option<Self> {
+        let config = match read_escalation_config(work_dir) {
+            Ok(config) => config,
+            Err(e) => {
+                eprintln!("Warning: ignoring invalid [escalation] config: {e:#}");
+                return None;
+            }
+        };
+        config.after_minutes?;
+        Some(Self {
+            config,
+            work_dir: work_dir.to_path_buf(),
+            notified: HashMap::new(),
+            last_check: None,
+        })
+    }
+
+    /// Escalate waiting stages when a check is due.
+    pub fn check(&mut self) {
+        if self
+            .last_check
+            .is_some_and(|last| last.elapsed() < CHECK_INTERVAL)
+        {
+            return;
+        }
+        self.last_check = Some(Instant::now());
+
+        let stages = match list_all_stages(&self.work_dir) {
+            Ok(stages) => stages,
+            Err(e) => {
+                eprintln!("Warning: escalation check failed to list stages: {e:#}");
+                return;
+            }
+        };
+        let now = Utc::now();
+        let actions = plan_escalation(&self.config, &stages, &self.notified, now);
+
+        for (stage_id, waited) in &actions.notify {
+            let waited = format_elapsed(waited.num_seconds());
+            eprintln!("Stage '{stage_id}' has been waiting for input for {waited}");
+            let title = format!("loom: Stage '{stage_id}' is waiting for you");
+            let body = format!("Waiting for input for {waited}; dependent stages are gated on it.");
+            send_desktop_notification(&title, &body);
+            if let Some(stage) = stages.iter().find(|s| s.id == *stage_id) {
+                notify_assignee(&self.work_dir, stage, &title, &body);
+            }
+            self.notified.insert(stage_id.clone(), now);
+        }
+        // Forget stages that stopped waiting so a later wait notifies again
+        self.notified.retain(|id, _| {
+            stages
+                .iter()
+                .any(|s| s.id == *id && s.status == StageStatus::WaitingForInput)
+        });
+
+        for stage in stages {
+            let hold = actions.hold.iter().find(|(id, _)| *id == stage.id);
+            let release = actions.release.contains(&stage.id);
+            if hold.is_none() && !release {
+                continue;
+            }
+            let mut stage = stage;
+            if let Some((_, by)) = hold {
+                stage.hold();
+                stage.held_by = Some(by.clone());
+                println!("Holding stage '{}' until '{by}' gets its input", stage.id);
+            } else {
+                let by = stage.held_by.clone().unwrap_or_default();
+                stage.release();
+                println!(
+                    "Released stage '{}' ('{by}' is no longer waiting)",
+                    stage.id
+                );
+            }
+            if let Err(e) = save_stage(&stage, &self.work_dir) {
+                eprintln!("Warning: failed to save stage '{}': {e:#}", stage.id);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stage(id: &str, status: StageStatus, deps: &[&str]) -> Stage {
+        let mut stage = Stage::new(id.to_string(), None);
+        stage.id = id.to_string();
+        stage.status = status;
+        stage.dependencies = deps.iter().map(|d| d.to_string()).collect();
+        stage
+    }
+
+    #[test]
+    fn test_plan_escalation() {
+        let now = Utc::now();
+        let config = EscalationConfig {
+            after_minutes: Some(30),
+            repeat_minutes: Some(60),
+            hold_dependents: true,
+        };
+        let mut api = stage("api", StageStatus::WaitingForInput, &[]);
+        api.waiting_since = Some(now - Duration::minutes(45));
+        let mut stages = vec![
+            api,
+            stage("web", StageStatus::WaitingForDeps, &["api"]),
+            stage("e2e", StageStatus::WaitingForDeps, &["web"]),
+            stage("docs", StageStatus::Queued, &[]),
+        ];
+
+        let actions = plan_escalation(&config, &stages, &HashMap::new(), now);
+        assert_eq!(
+            actions.notify,
+            vec![("api".to_string(), Duration::minutes(45))]
+        );
+        assert_eq!(
+            actions.hold,
+            vec![
+                ("web".to_string(), "api".to_string()),
+                ("e2e".to_string(), "api".to_string())
+            ]
+        );
+        assert!(actions.release.is_empty());
+
+        // Notified 20 minutes ago: not due again until repeat_minutes pass
+        let notified = HashMap::from([("api".to_string(), now - Duration::minutes(20))]);
+        assert!(plan_escalation(&config, &stages, &notified, now)
+            .notify
+            .is_empty());
+
+        // Once api resumes, only loom's own holds are released
+        stages[0].status = StageStatus::Executing;
+        stages[0].waiting_since = None;
+        stages[1].held = true;
+        stages[1].held_by = Some("api".to_string());
+        stages[3].held = true;
+        let actions = plan_escalation(&config, &stages, &HashMap::new(), now);
+        assert!(actions.notify.is_empty() && actions.hold.is_empty());
+        assert_eq!(actions.release, vec!["web".to_string()]);
+    }
+
+    #[test]
+    fn test_waiting_below_threshold_is_not_escalated() {
+        let now = Utc::now();
+        let config = EscalationConfig {
+            after_minutes: Some(30),
+            ..Default::default()
+        };
+        let mut api = stage("api", StageStatus::WaitingForInput, &[]);
+        api.waiting_since = Some(now - Duration::minutes(10));
+        let stages = vec![api, stage("web", StageStatus::WaitingForDeps, &["api"])];
+
+        assert_eq!(
+            plan_escalation(&config, &stages, &HashMap::new(), now),
+            EscalationActions::default()
+        );
+    }
+}
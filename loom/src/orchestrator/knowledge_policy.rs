@@ -0,0 +1,215 @@
+//! Scheduling policy for knowledge stages.
+//!
+//! Knowledge stages map the codebase for the stages after them, so they get
+//! priority whenever they are ready. Two plan settings in the `loom`
+//! metadata go further:
+//!
+//! - `knowledge_first: true`: no other stage starts until every knowledge
+//!   stage in the plan has completed (or was skipped)
+//! - `knowledge_refresh_every: N`: after every N merged standard stages,
+//!   loom adds a knowledge refresh stage (`knowledge-refresh`,
+//!   `knowledge-refresh-2`, ...) that updates the knowledge files with what
+//!   those merges changed. Only one refresh is outstanding at a time.
+//!
+//! Refresh stages are created while the daemon runs, like code reviews (see
+//! [`crate::orchestrator::code_review`]), and are never waited on by
+//! `knowledge_first`.
+
+use anyhow::{bail, Result};
+use std::path::Path;
+
+use crate::fs::work_dir::load_config;
+use crate::models::stage::{Stage, StageStatus, StageType};
+use crate::plan::graph::StageNode;
+use crate::verify::transitions::{list_all_stages, save_stage};
+
+/// Id of the first knowledge refresh stage; later rounds append `-<n>`.
+pub const REFRESH_STAGE_ID: &str = "knowledge-refresh";
+
+/// The plan's knowledge scheduling settings.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct KnowledgePolicy {
+    /// Hold back other stages until the plan's knowledge stages finish
+    pub knowledge_first: bool,
+    /// Merged standard stages between knowledge refreshes
+    pub refresh_every: Option<u32>,
+}
+
+impl KnowledgePolicy {
+    /// Read the policy from `work_dir/config.toml` (defaults when absent).
+    pub fn load(work_dir: &Path) -> Self {
+        load_config(work_dir)
+            .ok()
+            .flatten()
+            .map(|config| Self {
+                knowledge_first: config.knowledge_first(),
+                refresh_every: config.knowledge_refresh_every(),
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// Whether `stage_id` names a knowledge refresh stage created by loom.
+pub fn is_refresh_stage(stage_id: &str) -> bool {
+    stage_id == REFRESH_STAGE_ID
+        || stage_id
+            .strip_prefix(REFRESH_STAGE_ID)
+            .and_then(|rest| rest.strip_prefix('-'))
+            .is_some_and(|round| round.parse::<u32>().is_ok())
+}
+
+/// Order ready stages so knowledge stages start first.
+///
+/// The sort is stable, so stages keep their relative order otherwise.
+pub fn prioritize(ready: &mut [&StageNode]) {
+    ready.sort_by_key(|node| node.stage_type != StageType::Knowledge);
+}
+
+/// The first unfinished knowledge stage from the plan itself, which every
+/// other stage waits on under `knowledge_first`.
+pub fn pending_knowledge_stage<'a>(nodes: &[&'a StageNode]) -> Option<&'a str> {
+    nodes
+        .iter()
+        .filter(|node| node.stage_type == StageType::Knowledge && !is_refresh_stage(&node.id))
+        .filter(|node| !matches!(node.status, StageStatus::Completed | StageStatus::Skipped))
+        .map(|node| node.id.as_str())
+        .min()
+}
+
+/// The refresh round that is due, if any.
+///
+/// Round `n` is due once `n * every` standard stages have merged and every
+/// earlier round exists and has finished.
+pub fn refresh_due(nodes: &[&StageNode], every: u32) -> Option<usize> {
+    if every == 0 {
+        return None;
+    }
+    let refreshes: Vec<_> = nodes.iter().filter(|n| is_refresh_stage(&n.id)).collect();
+    if refreshes
+        .iter()
+        .any(|n| !matches!(n.status, StageStatus::Completed | StageStatus::Skipped))
+    {
+        return None;
+    }
+    let merged = nodes
+        .iter()
+        .filter(|n| n.stage_type == StageType::Standard && n.merged)
+        .count();
+    let due = merged / every as usize;
+    (due > refreshes.len()).then_some(refreshes.len() + 1)
+}
+
+/// Create refresh round `round`, queued to run in the main repository.
+pub fn create_refresh_stage(work_dir: &Path, round: usize, every: u32) -> Result<String> {
+    let stage_id = match round {
+        1 => REFRESH_STAGE_ID.to_string(),
+        n => format!("{REFRESH_STAGE_ID}-{n}"),
+    };
+    if list_all_stages(work_dir)?.iter().any(|s| s.id == stage_id) {
+        bail!("Cannot create knowledge refresh: stage '{stage_id}' already exists");
+    }
+
+    let mut stage = Stage::new(
+        format!("Knowledge refresh {round}"),
+        Some(format!(
+            "Refresh the project knowledge after the last {every} merged stages. \
+             Review what changed on the main branch since the previous knowledge \
+             stage and update `loom knowledge` (architecture, entry points, \
+             patterns, conventions) wherever it no longer matches the code."
+        )),
+    );
+    stage.id = stage_id.clone();
+    stage.stage_type = StageType::Knowledge;
+    stage.status = StageStatus::Queued;
+    stage.plan_id = load_config(work_dir)
+        .ok()
+        .flatten()
+        .and_then(|config| config.plan_id().map(String::from));
+    save_stage(&stage, work_dir)?;
+    Ok(stage_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plan::schema::StageDefinition;
+
+    fn node(id: &str, stage_type: StageType, status: StageStatus, merged: bool) -> StageNode {
+        let definition: StageDefinition = serde_yaml::from_str(&format!(
+            "id: {id}\nname: {id}\nworking_dir: .\nstage_type: {}\n",
+            serde_yaml::to_string(&stage_type).unwrap().trim()
+        ))
+        .unwrap();
+        let mut node = StageNode::from_definition(&definition);
+        node.status = status;
+        node.merged = merged;
+        node
+    }
+
+    #[test]
+    fn test_prioritize_and_pending_knowledge() {
+        let a = node("a", StageType::Standard, StageStatus::Queued, false);
+        let kb = node(
+            "knowledge-bootstrap",
+            StageType::Knowledge,
+            StageStatus::Queued,
+            false,
+        );
+        let refresh = node(
+            "knowledge-refresh-2",
+            StageType::Knowledge,
+            StageStatus::Queued,
+            false,
+        );
+        let mut ready = vec![&a, &kb];
+        prioritize(&mut ready);
+        assert_eq!(ready[0].id, "knowledge-bootstrap");
+
+        assert_eq!(
+            pending_knowledge_stage(&[&a, &kb, &refresh]),
+            Some("knowledge-bootstrap")
+        );
+        assert_eq!(pending_knowledge_stage(&[&a, &refresh]), None);
+        assert!(is_refresh_stage("knowledge-refresh"));
+        assert!(!is_refresh_stage("knowledge-refresher"));
+    }
+
+    #[test]
+    fn test_refresh_due_after_every_n_merges() {
+        let merged: Vec<StageNode> = (0..5)
+            .map(|i| {
+                node(
+                    &format!("s{i}"),
+                    StageType::Standard,
+                    StageStatus::Completed,
+                    true,
+                )
+            })
+            .collect();
+        let mut nodes: Vec<&StageNode> = merged.iter().take(1).collect();
+        assert_eq!(refresh_due(&nodes, 2), None);
+        nodes = merged.iter().take(2).collect();
+        assert_eq!(refresh_due(&nodes, 2), Some(1));
+
+        let running = node(
+            "knowledge-refresh",
+            StageType::Knowledge,
+            StageStatus::Executing,
+            false,
+        );
+        nodes = merged.iter().collect();
+        nodes.push(&running);
+        assert_eq!(refresh_due(&nodes, 2), None);
+
+        let done = node(
+            "knowledge-refresh",
+            StageType::Knowledge,
+            StageStatus::Completed,
+            true,
+        );
+        nodes.pop();
+        nodes.push(&done);
+        assert_eq!(refresh_due(&nodes, 2), Some(2));
+        assert_eq!(refresh_due(&nodes, 0), None);
+    }
+}
@@ -2,12 +2,15 @@
 //!
 //! Replaces the legacy `kill -0 <session.pid>` checks scattered across
 //! the monitor with a single service that delegates to the native
-//! backend's `is_session_alive`.
+//! backend's `is_session_alive` (or the simulated backend's under
+//! `loom run --simulate`).
 
 use anyhow::Result;
 use std::sync::Arc;
 
+use super::terminal::mock::MockBackend;
 use super::terminal::native::NativeBackend;
+use super::terminal::TerminalBackend;
 use crate::models::session::Session;
 
 /// How a [`LivenessService`] resolves liveness for a given session.
@@ -15,6 +18,8 @@ use crate::models::session::Session;
 enum LivenessSource {
     /// Production: route via the native backend.
     Native(Arc<NativeBackend>),
+    /// `loom run --simulate`: route via the simulated backend.
+    Simulated(Arc<MockBackend>),
     /// Test-only: every probe returns this fixed value.
     Fixed(bool),
 }
@@ -35,6 +40,13 @@ impl LivenessService {
         }
     }
 
+    /// Probe the simulated sessions of `loom run --simulate`.
+    pub fn simulated(backend: Arc<MockBackend>) -> Self {
+        Self {
+            source: LivenessSource::Simulated(backend),
+        }
+    }
+
     /// Test-only constructor that always reports the supplied liveness
     /// value. Lets monitor tests exercise the crash-detection path
     /// without spinning up a backend.
@@ -51,6 +63,7 @@ impl LivenessService {
     pub fn is_alive(&self, session: &Session) -> Result<bool> {
         match &self.source {
             LivenessSource::Native(b) => b.is_session_alive(session),
+            LivenessSource::Simulated(b) => b.is_session_alive(session),
             LivenessSource::Fixed(v) => Ok(*v),
         }
     }
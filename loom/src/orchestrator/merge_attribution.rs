@@ -10,7 +10,9 @@ use anyhow::{Context, Result};
 use std::fs;
 use std::path::Path;
 
-use crate::git::branch::{branch_name_for_stage, current_branch, get_branch_head};
+use crate::git::branch::{
+    branch_name_for_stage, current_branch, get_branch_head, is_base_branch, stage_id_from_branch,
+};
 use crate::git::merge::{
     detect_in_progress_merge_at, merge_head_exists, ActiveMergeState, InProgressMerge,
 };
@@ -130,10 +132,10 @@ pub fn attribute_main_repo_merge(
     // target. Refuse attribution in this case.
     //
     // Detection (heuristic — see knowledge/concerns.md):
-    //   * Current branch starts with `loom/_base/`, OR
+    //   * Current branch is a `loom/[<ns>/]_base/` branch, OR
     //   * Any session has SessionType::BaseConflict matching the current branch.
     let head_branch = current_branch(repo_root).unwrap_or_default();
-    if head_branch.starts_with("loom/_base/") {
+    if is_base_branch(&head_branch) {
         return Ok(MergeAttribution::GlobalUnattributed(merge));
     }
     let head_match_base_conflict = sessions.iter().any(|s| {
@@ -154,7 +156,7 @@ pub fn attribute_main_repo_merge(
             continue;
         };
         // Map the source branch back to a stage id.
-        let Some(stage_id) = source_branch.strip_prefix("loom/") else {
+        let Some(stage_id) = stage_id_from_branch(source_branch) else {
             continue;
         };
         // Match by branch HEAD if we can resolve it. Otherwise accept by
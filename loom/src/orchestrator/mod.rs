@@ -1,24 +1,34 @@
 pub mod adjudication;
 pub mod auto_merge;
+pub mod code_review;
 pub mod continuation;
 pub mod core;
+pub mod decisions;
+pub mod escalation;
+pub mod knowledge_policy;
 pub mod liveness;
 pub mod merge_attribution;
 pub mod monitor;
 pub mod notify;
+pub mod overdue;
+pub mod pair;
+pub mod pause;
+pub mod plan_report;
 pub mod progressive_merge;
 pub mod retry;
 pub mod signals;
 pub mod skip;
+pub mod spawn_rate;
 pub mod spawner;
 pub mod terminal;
+pub mod throttle;
 
 pub use auto_merge::{attempt_auto_merge, is_auto_merge_enabled, AutoMergeResult};
 pub use continuation::{
     continue_session, load_handoff_content, prepare_continuation, ContinuationConfig,
     ContinuationContext,
 };
-pub use core::{Orchestrator, OrchestratorConfig, OrchestratorResult};
+pub use core::{Orchestrator, OrchestratorConfig, OrchestratorResult, RunDeadline};
 pub use merge_attribution::{
     attribute_main_repo_merge, reconcile_main_repo_active_merge, AttributionSource,
     MergeAttribution, ReconciliationOutcome,
@@ -30,7 +40,7 @@ pub use monitor::{
     MonitorEvent, StageFailureState, DEFAULT_HEARTBEAT_POLL_SECS, DEFAULT_HUNG_TIMEOUT_SECS,
     DEFAULT_MAX_FAILURES,
 };
-pub use notify::{notify_needs_human_review, send_desktop_notification};
+pub use notify::{notify_assignee, notify_needs_human_review, send_desktop_notification};
 pub use progressive_merge::{
     get_merge_point, merge_completed_stage, merge_completed_stage_with_timeout, MergeLock,
     ProgressiveMergeResult,
@@ -43,6 +53,7 @@ pub use signals::{
 // Re-export crash reporting from spawner (until migrated to separate module)
 pub use spawner::{generate_crash_report, CrashReport};
 // Re-export terminal functions (replaces legacy spawner exports)
+pub use terminal::mock::{MockBackend, SimulationSchedule};
 pub use terminal::native::NativeBackend;
 // Re-export hooks infrastructure from top-level hooks module
 pub use crate::hooks::{
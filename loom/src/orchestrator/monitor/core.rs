@@ -57,7 +57,13 @@ impl Monitor {
         let mut events = Vec::new();
 
         let stages = self.load_stages()?;
-        let sessions = self.load_sessions()?;
+        // Pair reviewers never own their stage, so their exits are not
+        // stage crashes and their heartbeats are not stage heartbeats
+        let sessions: Vec<Session> = self
+            .load_sessions()?
+            .into_iter()
+            .filter(|s| !s.is_pair_reviewer())
+            .collect();
 
         events.extend(self.detection.detect_stage_changes(&stages));
         events.extend(
@@ -73,6 +79,12 @@ impl Monitor {
             &self.handlers,
         ));
 
+        // Flip stages whose sessions are blocked on (or past) a prompt
+        events.extend(
+            self.detection
+                .detect_waiting_prompts(&stages, &self.heartbeat_watcher),
+        );
+
         Ok(events)
     }
 
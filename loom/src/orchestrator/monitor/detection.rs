@@ -418,6 +418,53 @@ impl Detection {
         events
     }
 
+    /// Detect sessions blocked on a permission or input prompt.
+    ///
+    /// An executing stage whose current session's heartbeat carries a
+    /// `waiting_prompt` is reported as waiting; a stage that was flipped to
+    /// waiting this way is reported as answered once a newer heartbeat
+    /// without a prompt arrives. Stages marked waiting by the AskUserQuestion
+    /// hooks have no `waiting_prompt` and are left to those hooks.
+    pub fn detect_waiting_prompts(
+        &self,
+        stages: &[Stage],
+        heartbeat_watcher: &HeartbeatWatcher,
+    ) -> Vec<MonitorEvent> {
+        let mut events = Vec::new();
+
+        for stage in stages {
+            let Some(session_id) = &stage.session else {
+                continue;
+            };
+            let Some(heartbeat) = heartbeat_watcher
+                .get_heartbeat(&stage.id)
+                .filter(|hb| &hb.session_id == session_id)
+            else {
+                continue;
+            };
+
+            match (&stage.status, &heartbeat.waiting_prompt) {
+                (StageStatus::Executing, Some(prompt)) => {
+                    events.push(MonitorEvent::StagePromptDetected {
+                        stage_id: stage.id.clone(),
+                        session_id: session_id.clone(),
+                        prompt: prompt.clone(),
+                    });
+                }
+                (StageStatus::WaitingForInput, None)
+                    if stage.waiting_prompt.is_some() && heartbeat.timestamp > stage.updated_at =>
+                {
+                    events.push(MonitorEvent::StagePromptAnswered {
+                        stage_id: stage.id.clone(),
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        events
+    }
+
     /// Detect heartbeat-based events (heartbeat updates, hung sessions)
     pub fn detect_heartbeat_events(
         &mut self,
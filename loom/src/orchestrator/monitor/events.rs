@@ -1,9 +1,14 @@
 //! Events detected by the monitor
 
+use serde::Serialize;
 use std::path::PathBuf;
 
 /// Events detected by the monitor
-#[derive(Debug, Clone, PartialEq)]
+///
+/// Serializes as a flat JSON object tagged by `event`, e.g.
+/// `{"event":"stage_completed","stage_id":"stage-1"}`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
 pub enum MonitorEvent {
     StageCompleted {
         stage_id: String,
@@ -47,6 +52,16 @@ pub enum MonitorEvent {
     StageResumedExecution {
         stage_id: String,
     },
+    /// Heartbeat shows the session blocked on a permission or input prompt
+    StagePromptDetected {
+        stage_id: String,
+        session_id: String,
+        prompt: String,
+    },
+    /// Heartbeat shows activity after an auto-detected prompt
+    StagePromptAnswered {
+        stage_id: String,
+    },
     /// Merge session completed (conflict resolution session finished)
     MergeSessionCompleted {
         session_id: String,
@@ -11,6 +11,7 @@ use std::fs;
 use std::path::{Path, PathBuf};
 
 use crate::models::failure::{FailureInfo, FailureType};
+use crate::orchestrator::retry::classify_output;
 
 /// Default maximum consecutive failures before escalation
 pub const DEFAULT_MAX_FAILURES: u32 = 3;
@@ -232,12 +233,23 @@ pub fn failure_state_path(work_dir: &Path, stage_id: &str) -> PathBuf {
 }
 
 /// Build a FailureInfo from a failure record
-pub fn build_failure_info(record: &FailureRecord) -> FailureInfo {
-    FailureInfo {
+///
+/// `outputs` are captured logs or acceptance output for the failed attempt.
+/// When one of them carries a recognizable signature (see
+/// [`classify_output`]) that classification replaces the record's type and
+/// the matching line is added to the evidence; otherwise the record's type is
+/// kept as-is.
+pub fn build_failure_info(record: &FailureRecord, outputs: &[&str]) -> FailureInfo {
+    let mut info = FailureInfo {
         failure_type: record.failure_type.clone(),
         detected_at: record.timestamp,
         evidence: vec![record.description.clone()],
+    };
+    if let Some((failure_type, line)) = outputs.iter().find_map(|o| classify_output(o)) {
+        info.failure_type = failure_type;
+        info.evidence.push(line);
     }
+    info
 }
 
 #[cfg(test)]
@@ -322,4 +334,25 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_build_failure_info_classifies_from_output() {
+        let record = FailureRecord {
+            session_id: "session-1".to_string(),
+            failure_type: FailureType::Unknown,
+            timestamp: Utc::now(),
+            description: "Acceptance failed".to_string(),
+        };
+
+        let info = build_failure_info(&record, &["", "error: could not compile `app`"]);
+        assert_eq!(info.failure_type, FailureType::BuildFailure);
+        assert_eq!(
+            info.evidence,
+            vec!["Acceptance failed", "error: could not compile `app`"]
+        );
+
+        let info = build_failure_info(&record, &["nothing recognizable"]);
+        assert_eq!(info.failure_type, FailureType::Unknown);
+        assert_eq!(info.evidence.len(), 1);
+    }
 }
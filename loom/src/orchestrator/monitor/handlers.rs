@@ -14,6 +14,9 @@ use crate::orchestrator::continuation::save_session;
 use crate::orchestrator::liveness::LivenessService;
 use crate::orchestrator::signals::read_merge_signal;
 use crate::orchestrator::spawner::{generate_crash_report, CrashReport};
+use crate::orchestrator::terminal::headless::{
+    headless_log_path, log_tail, read_headless_exit_code, CRASH_LOG_TAIL_LINES,
+};
 
 use super::config::MonitorConfig;
 use super::context::context_usage_percent;
@@ -127,12 +130,26 @@ impl Handlers {
     /// Called when a session crash is detected.
     /// Creates a CrashReport, generates the crash report file, and preserves stage memory.
     pub fn handle_session_crash(&self, session: &Session, reason: &str) -> Option<PathBuf> {
-        let report = CrashReport::new(
+        let mut report = CrashReport::new(
             session.id.clone(),
             session.stage_id.clone(),
             reason.to_string(),
         );
 
+        // Headless sessions leave their output and exit status in a log
+        let log_path = headless_log_path(&self.config.work_dir, &session.id);
+        if log_path.exists() {
+            if let Some(code) = read_headless_exit_code(&self.config.work_dir, &session.id) {
+                report.reason =
+                    format!("Headless session exited with status {code} without completing");
+                report = report.with_exit_code(code);
+            }
+            if let Some(tail) = log_tail(&log_path, CRASH_LOG_TAIL_LINES) {
+                report = report.with_log_tail(tail);
+            }
+            report = report.with_log_path(log_path);
+        }
+
         // Preserve stage memory for recovery
         let stage_id = session.stage_id.as_deref().unwrap_or(&session.id);
         match preserve_for_crash(&self.config.work_dir, stage_id) {
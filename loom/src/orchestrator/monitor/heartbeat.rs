@@ -9,6 +9,16 @@
 //! The orchestrator polls these files to detect:
 //! - Crashed sessions (PID dead)
 //! - Hung sessions (PID alive but no heartbeat update for threshold duration)
+//!
+//! The Notification hook rewrites the heartbeat with `waiting_prompt` set when
+//! Claude Code stops to ask for a permission approval or for input. The next
+//! tool use overwrites it, so a heartbeat that still carries a prompt means
+//! the session is blocked on a human, not hung.
+//!
+//! The PostToolUse hook also appends each heartbeat to
+//! `.work/heartbeat/<stage-id>.history.jsonl`, trimmed to the last
+//! [`HEARTBEAT_HISTORY_LEN`] entries, so `loom status` can show whether an
+//! agent is iterating or spinning.
 
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
@@ -23,6 +33,9 @@ pub const DEFAULT_HUNG_TIMEOUT_SECS: u64 = 300;
 /// Default polling interval for heartbeat checks (10 seconds)
 pub const DEFAULT_HEARTBEAT_POLL_SECS: u64 = 10;
 
+/// Heartbeats kept per stage in the history file (mirrored in post-tool-use.sh)
+pub const HEARTBEAT_HISTORY_LEN: usize = 50;
+
 /// Heartbeat data written by Claude Code hooks
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Heartbeat {
@@ -41,6 +54,9 @@ pub struct Heartbeat {
     /// Optional message about current activity
     #[serde(default)]
     pub activity: Option<String>,
+    /// Prompt the session is blocked on, written by the Notification hook
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub waiting_prompt: Option<String>,
 }
 
 impl Heartbeat {
@@ -53,6 +69,7 @@ impl Heartbeat {
             context_percent: None,
             last_tool: None,
             activity: None,
+            waiting_prompt: None,
         }
     }
 
@@ -74,6 +91,17 @@ impl Heartbeat {
         self
     }
 
+    /// Create heartbeat for a session blocked on a prompt
+    pub fn with_waiting_prompt(mut self, prompt: String) -> Self {
+        self.waiting_prompt = Some(prompt);
+        self
+    }
+
+    /// Whether the session is blocked on a permission or input prompt
+    pub fn is_waiting(&self) -> bool {
+        self.waiting_prompt.is_some()
+    }
+
     /// Check if heartbeat is stale (older than timeout)
     pub fn is_stale(&self, timeout: Duration) -> bool {
         let age = Utc::now().signed_duration_since(self.timestamp);
@@ -206,7 +234,8 @@ impl HeartbeatWatcher {
                 HeartbeatStatus::NoHeartbeat
             }
             Some(heartbeat) => {
-                if heartbeat.is_stale(self.hung_timeout) {
+                // A session waiting on a prompt is idle by design
+                if heartbeat.is_stale(self.hung_timeout) && !heartbeat.is_waiting() {
                     let age = heartbeat.age();
                     HeartbeatStatus::Hung {
                         stale_duration_secs: age.num_seconds().max(0) as u64,
@@ -295,6 +324,30 @@ pub fn heartbeat_path(work_dir: &Path, stage_id: &str) -> PathBuf {
     work_dir.join("heartbeat").join(format!("{stage_id}.json"))
 }
 
+/// Get heartbeat history path for a stage
+pub fn heartbeat_history_path(work_dir: &Path, stage_id: &str) -> PathBuf {
+    work_dir
+        .join("heartbeat")
+        .join(format!("{stage_id}.history.jsonl"))
+}
+
+/// Read the recent heartbeats of a stage, oldest first.
+///
+/// Unparseable lines (e.g. a write cut short) are skipped; a missing file
+/// yields an empty history.
+pub fn read_heartbeat_history(work_dir: &Path, stage_id: &str) -> Vec<Heartbeat> {
+    let Ok(content) = std::fs::read_to_string(heartbeat_history_path(work_dir, stage_id)) else {
+        return Vec::new();
+    };
+    let mut history: Vec<Heartbeat> = content
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect();
+    let excess = history.len().saturating_sub(HEARTBEAT_HISTORY_LEN);
+    history.drain(..excess);
+    history
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -344,6 +397,40 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_heartbeat_history_skips_bad_lines_and_caps_length() -> Result<()> {
+        let tmp = TempDir::new()?;
+        let work_dir = tmp.path();
+        assert!(read_heartbeat_history(work_dir, "stage-1").is_empty());
+
+        let mut lines = Vec::new();
+        for i in 0..HEARTBEAT_HISTORY_LEN + 5 {
+            let hb = Heartbeat::new("stage-1".to_string(), "session-1".to_string())
+                .with_last_tool(format!("Tool{i}"));
+            lines.push(serde_json::to_string(&hb)?);
+        }
+        lines.insert(3, "{not json".to_string());
+        std::fs::create_dir_all(work_dir.join("heartbeat"))?;
+        std::fs::write(
+            heartbeat_history_path(work_dir, "stage-1"),
+            lines.join("\n"),
+        )?;
+
+        let history = read_heartbeat_history(work_dir, "stage-1");
+        assert_eq!(history.len(), HEARTBEAT_HISTORY_LEN);
+        assert_eq!(history[0].last_tool.as_deref(), Some("Tool5"));
+        assert_eq!(
+            history.last().unwrap().last_tool,
+            Some(format!("Tool{}", HEARTBEAT_HISTORY_LEN + 4))
+        );
+
+        // The history file must not be picked up as a latest heartbeat
+        let mut watcher = HeartbeatWatcher::new();
+        assert!(watcher.poll(work_dir)?.is_empty());
+
+        Ok(())
+    }
+
     #[test]
     fn test_heartbeat_watcher_poll() -> Result<()> {
         let tmp = TempDir::new()?;
@@ -407,4 +494,17 @@ mod tests {
             HeartbeatStatus::NoHeartbeat
         );
     }
+
+    #[test]
+    fn test_waiting_heartbeat_is_never_hung() {
+        let mut watcher = HeartbeatWatcher::with_timeout(Duration::from_secs(0));
+        let hb = Heartbeat::new("stage-1".to_string(), "session-1".to_string())
+            .with_waiting_prompt("Claude needs your permission to use Bash".to_string());
+        watcher.heartbeats.insert("stage-1".to_string(), hb);
+
+        assert_eq!(
+            watcher.check_session_hung("stage-1", "session-1"),
+            HeartbeatStatus::Healthy
+        );
+    }
 }
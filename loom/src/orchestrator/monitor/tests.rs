@@ -136,6 +136,36 @@ fn test_detect_session_crash() {
     }
 }
 
+#[test]
+fn test_headless_crash_report_includes_exit_status_and_log() {
+    use crate::orchestrator::terminal::headless::{headless_exit_path, headless_log_path};
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    let work_dir = temp_dir.path().to_path_buf();
+    let log = headless_log_path(&work_dir, "session-1");
+    std::fs::create_dir_all(log.parent().unwrap()).unwrap();
+    std::fs::write(&log, "working...\nError: tool failed\n").unwrap();
+    std::fs::write(headless_exit_path(&work_dir, "session-1"), "1\n").unwrap();
+
+    let config = MonitorConfig {
+        work_dir,
+        ..Default::default()
+    };
+    let handlers = Handlers::new(config, None);
+    let mut session = Session::new();
+    session.id = "session-1".to_string();
+    session.stage_id = Some("api".to_string());
+
+    let path = handlers
+        .handle_session_crash(&session, "Process no longer running")
+        .unwrap();
+    let report = std::fs::read_to_string(path).unwrap();
+    assert!(report.contains("exit_code: 1"));
+    assert!(report.contains("Headless session exited with status 1"));
+    assert!(report.contains("Error: tool failed"));
+}
+
 #[test]
 fn test_detect_context_warning() {
     use tempfile::TempDir;
@@ -730,3 +760,67 @@ fn test_possibly_stuck_not_emitted_for_only_old_events() {
         "Old isolated events must not satisfy the heuristic"
     );
 }
+
+#[test]
+fn test_waiting_prompt_detected_and_answered() {
+    use crate::orchestrator::monitor::heartbeat::{write_heartbeat, Heartbeat, HeartbeatWatcher};
+    use tempfile::TempDir;
+
+    let temp = TempDir::new().unwrap();
+    let work_dir = temp.path();
+    let detection = Detection::new();
+    let mut watcher = HeartbeatWatcher::new();
+
+    let mut stage = Stage::new("test".to_string(), Some("Test stage".to_string()));
+    stage.id = "stage-1".to_string();
+    stage.status = StageStatus::Executing;
+    stage.session = Some("session-1".to_string());
+
+    let prompt = "Claude needs your permission to use Bash: cargo publish";
+    let waiting = Heartbeat::new("stage-1".to_string(), "session-1".to_string())
+        .with_waiting_prompt(prompt.to_string());
+    write_heartbeat(work_dir, &waiting).unwrap();
+    watcher.poll(work_dir).unwrap();
+
+    let events = detection.detect_waiting_prompts(&[stage.clone()], &watcher);
+    assert_eq!(
+        events,
+        vec![MonitorEvent::StagePromptDetected {
+            stage_id: "stage-1".to_string(),
+            session_id: "session-1".to_string(),
+            prompt: prompt.to_string(),
+        }]
+    );
+
+    // A prompt from a previous session for the stage is ignored
+    stage.session = Some("session-2".to_string());
+    assert!(detection
+        .detect_waiting_prompts(&[stage.clone()], &watcher)
+        .is_empty());
+    stage.session = Some("session-1".to_string());
+
+    stage
+        .try_mark_waiting_for_prompt(prompt.to_string())
+        .unwrap();
+    assert!(detection
+        .detect_waiting_prompts(&[stage.clone()], &watcher)
+        .is_empty());
+
+    std::thread::sleep(Duration::from_millis(10));
+    let active = Heartbeat::new("stage-1".to_string(), "session-1".to_string())
+        .with_last_tool("Bash".to_string());
+    write_heartbeat(work_dir, &active).unwrap();
+    watcher.poll(work_dir).unwrap();
+
+    let events = detection.detect_waiting_prompts(&[stage.clone()], &watcher);
+    assert_eq!(
+        events,
+        vec![MonitorEvent::StagePromptAnswered {
+            stage_id: "stage-1".to_string(),
+        }]
+    );
+
+    // Leaving WaitingForInput clears the prompt
+    stage.try_mark_executing().unwrap();
+    assert!(stage.waiting_prompt.is_none());
+}
@@ -2,9 +2,34 @@
 //!
 //! Sends desktop notifications for events that need human attention,
 //! using notify-send on Linux and osascript on macOS.
+//!
+//! Events about an assigned stage (`loom stage assign`) are also routed to
+//! the assignee through the command configured for them. The command runs
+//! via `sh -c` with `LOOM_NOTIFY_TITLE`, `LOOM_NOTIFY_BODY`, `LOOM_STAGE_ID`
+//! and `LOOM_ASSIGNEE` set:
+//!
+//! ```toml
+//! [notify.assignees]
+//! alice = "ntfy publish loom-alice \"$LOOM_NOTIFY_TITLE: $LOOM_NOTIFY_BODY\""
+//! bob = "notify-send \"$LOOM_NOTIFY_TITLE\" \"$LOOM_NOTIFY_BODY\""
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::process::{Command, Stdio};
 
+use crate::fs::work_dir::read_notify_config;
+use crate::models::stage::Stage;
 use crate::utils::truncate;
-use std::process::Command;
+
+/// Persisted `[notify]` section of `.work/config.toml`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct NotifyConfig {
+    /// Command that delivers a notification to each assignee
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub assignees: BTreeMap<String, String>,
+}
 
 /// Send a desktop notification.
 ///
@@ -74,3 +99,105 @@ pub fn notify_needs_human_review(stage_id: &str, review_reason: Option<&str>) {
 
     send_desktop_notification(&title, &body);
 }
+
+/// Route a notification about `stage` to its assignee's `[notify.assignees]`
+/// command.
+///
+/// Returns `false` when the stage is unassigned or its assignee has no
+/// command. Like desktop notifications this is best-effort: the command runs
+/// in the background and failures are only logged.
+pub fn notify_assignee(work_dir: &Path, stage: &Stage, title: &str, body: &str) -> bool {
+    let Some(assignee) = stage.assignee.as_deref() else {
+        return false;
+    };
+    let config = match read_notify_config(work_dir) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Warning: ignoring invalid [notify] config: {e:#}");
+            return false;
+        }
+    };
+    let Some(command) = config.assignees.get(assignee) else {
+        return false;
+    };
+
+    let spawned = Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .env("LOOM_NOTIFY_TITLE", title)
+        .env("LOOM_NOTIFY_BODY", body)
+        .env("LOOM_STAGE_ID", &stage.id)
+        .env("LOOM_ASSIGNEE", assignee)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn();
+    match spawned {
+        Ok(mut child) => {
+            let assignee = assignee.to_string();
+            // Reap in the background so a slow command never stalls the daemon
+            std::thread::spawn(move || match child.wait() {
+                Ok(status) if !status.success() => {
+                    eprintln!("Notification command for '{assignee}' exited with: {status}");
+                }
+                Err(e) => eprintln!("Notification command for '{assignee}' failed: {e}"),
+                Ok(_) => {}
+            });
+            true
+        }
+        Err(e) => {
+            eprintln!("Notification command for '{assignee}' failed to start: {e}");
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fs::work_dir::write_notify_config;
+    use std::time::{Duration, Instant};
+    use tempfile::TempDir;
+
+    #[cfg(unix)]
+    #[test]
+    fn test_notify_assignee_runs_route_command() {
+        let temp = TempDir::new().unwrap();
+        let out = temp.path().join("notified.txt");
+        let config = NotifyConfig {
+            assignees: BTreeMap::from([(
+                "alice".to_string(),
+                format!(
+                    "printf '%s|%s|%s|%s' \"$LOOM_ASSIGNEE\" \"$LOOM_STAGE_ID\" \
+                     \"$LOOM_NOTIFY_TITLE\" \"$LOOM_NOTIFY_BODY\" > {}",
+                    out.display()
+                ),
+            )]),
+        };
+        write_notify_config(temp.path(), &config).unwrap();
+
+        let mut stage = Stage::new("API".to_string(), None);
+        stage.id = "api".to_string();
+        assert!(!notify_assignee(temp.path(), &stage, "t", "b"));
+        stage.assignee = Some("bob".to_string());
+        assert!(!notify_assignee(temp.path(), &stage, "t", "b"));
+
+        stage.assignee = Some("alice".to_string());
+        assert!(notify_assignee(
+            temp.path(),
+            &stage,
+            "Blocked",
+            "tests fail"
+        ));
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while std::fs::read_to_string(&out).map_or(true, |s| s.is_empty())
+            && Instant::now() < deadline
+        {
+            std::thread::sleep(Duration::from_millis(20));
+        }
+        assert_eq!(
+            std::fs::read_to_string(&out).unwrap(),
+            "alice|api|Blocked|tests fail"
+        );
+    }
+}
@@ -0,0 +1,124 @@
+//! Notifications for stages running well past their expected duration.
+//!
+//! A stage can declare `expected_duration` in the plan. Once an executing
+//! stage has run [`OVERDUE_FACTOR`] times that long (counting all of its
+//! attempts), the daemon sends one desktop notification, and notifies the
+//! stage's assignee when `[notify]` has a route for them. This catches a
+//! runaway stage long before a hard timeout would. `loom status` and
+//! `loom graph` mark overdue stages whether or not the daemon is running.
+
+use chrono::{DateTime, Utc};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::models::stage::{Stage, OVERDUE_FACTOR};
+use crate::orchestrator::notify::{notify_assignee, send_desktop_notification};
+use crate::utils::format_elapsed;
+use crate::verify::transitions::list_all_stages;
+
+/// How often the daemon looks for overdue stages.
+const CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Overdue stages not notified about yet, with their execution time.
+pub fn newly_overdue<'a>(
+    stages: &'a [Stage],
+    notified: &HashSet<String>,
+    now: DateTime<Utc>,
+) -> Vec<(&'a Stage, i64)> {
+    stages
+        .iter()
+        .filter(|s| !notified.contains(&s.id))
+        .filter_map(|s| s.overdue_secs(now).map(|ran| (s, ran)))
+        .collect()
+}
+
+/// Daemon-side watch for overdue stages.
+pub struct OverdueWatch {
+    work_dir: PathBuf,
+    notified: HashSet<String>,
+    last_check: Option<Instant>,
+}
+
+impl OverdueWatch {
+    pub fn new(work_dir: &Path) -> Self {
+        Self {
+            work_dir: work_dir.to_path_buf(),
+            notified: HashSet::new(),
+            last_check: None,
+        }
+    }
+
+    /// Notify about stages that became overdue when a check is due.
+    pub fn check(&mut self) {
+        if self
+            .last_check
+            .is_some_and(|last| last.elapsed() < CHECK_INTERVAL)
+        {
+            return;
+        }
+        self.last_check = Some(Instant::now());
+
+        let stages = match list_all_stages(&self.work_dir) {
+            Ok(stages) => stages,
+            Err(e) => {
+                eprintln!("Warning: overdue check failed to list stages: {e:#}");
+                return;
+            }
+        };
+        let now = Utc::now();
+
+        for (stage, ran) in newly_overdue(&stages, &self.notified, now) {
+            let expected = format_elapsed(stage.expected_duration_secs.unwrap_or(0) as i64);
+            let ran = format_elapsed(ran);
+            eprintln!(
+                "Stage '{}' is overdue: running {ran}, expected ~{expected}",
+                stage.id
+            );
+            let title = format!("loom: Stage '{}' is overdue", stage.id);
+            let body =
+                format!("Running for {ran}, more than {OVERDUE_FACTOR}x its expected {expected}.");
+            send_desktop_notification(&title, &body);
+            notify_assignee(&self.work_dir, stage, &title, &body);
+            self.notified.insert(stage.id.clone());
+        }
+        // Forget stages that are no longer overdue so a later attempt can
+        // notify again
+        self.notified.retain(|id| {
+            stages
+                .iter()
+                .any(|s| s.id == *id && s.overdue_secs(now).is_some())
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::stage::StageStatus;
+
+    #[test]
+    fn test_newly_overdue() {
+        let now = Utc::now();
+        let stage = |id: &str, expected: Option<u64>| Stage {
+            id: id.to_string(),
+            status: StageStatus::Executing,
+            execution_secs: Some(0),
+            attempt_started_at: Some(now - chrono::Duration::minutes(60)),
+            expected_duration_secs: expected,
+            ..Stage::default()
+        };
+        let stages = vec![
+            stage("slow", Some(30 * 60)),
+            stage("fine", Some(50 * 60)),
+            stage("undeclared", None),
+            stage("seen", Some(10 * 60)),
+        ];
+        let notified = HashSet::from(["seen".to_string()]);
+
+        let overdue = newly_overdue(&stages, &notified, now);
+        assert_eq!(overdue.len(), 1);
+        assert_eq!(overdue[0].0.id, "slow");
+        assert_eq!(overdue[0].1, 3600);
+    }
+}
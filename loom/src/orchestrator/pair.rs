@@ -0,0 +1,210 @@
+//! Experimental pair mode: an implementer and a reviewer per stage
+//!
+//! A stage with `pair: true` gets a second session next to the implementer:
+//! a reviewer started in the same worktree with the same signal, whose
+//! edit tools are disabled. The reviewer runs the acceptance criteria and
+//! tests and records a verdict with `loom stage pair-review`, which stores
+//! it on the stage together with the worktree HEAD it covers. The stage can
+//! only complete while the latest verdict approves the current HEAD, so any
+//! commit after an approval needs another review.
+//!
+//! Reviewer sessions are not tracked as the stage's session: the monitor
+//! ignores them and they are stopped when the stage completes.
+
+use anyhow::Result;
+use chrono::Utc;
+use std::path::Path;
+
+use crate::git::run_git_checked;
+use crate::models::session::{Session, SessionStatus};
+use crate::models::stage::{PairReview, Stage};
+use crate::orchestrator::terminal::TerminalBackend;
+use crate::parser::frontmatter::parse_from_markdown;
+
+/// Whether a pair-mode stage's reviewer has approved its current work
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PairGate {
+    /// The stage is not in pair mode
+    NotRequired,
+    /// No verdict yet
+    Missing,
+    /// The latest verdict approves the current HEAD
+    Approved,
+    /// Approved, but commits were added since
+    Stale,
+    /// The reviewer asked for changes
+    Rejected { notes: Option<String> },
+}
+
+impl PairGate {
+    /// Whether the stage may complete
+    pub fn allows_completion(&self) -> bool {
+        matches!(self, PairGate::NotRequired | PairGate::Approved)
+    }
+
+    /// Why the stage may not complete, for error messages
+    pub fn reason(&self, stage_id: &str) -> Option<String> {
+        match self {
+            PairGate::NotRequired | PairGate::Approved => None,
+            PairGate::Missing => Some(format!(
+                "Stage '{stage_id}' is in pair mode and its reviewer has not approved it yet"
+            )),
+            PairGate::Stale => Some(format!(
+                "Stage '{stage_id}' has new commits since the reviewer's approval; \
+                 the reviewer must approve the latest commit"
+            )),
+            PairGate::Rejected { notes } => Some(match notes {
+                Some(notes) => {
+                    format!("The reviewer of stage '{stage_id}' requested changes:\n{notes}")
+                }
+                None => format!("The reviewer of stage '{stage_id}' requested changes"),
+            }),
+        }
+    }
+}
+
+/// Evaluate the gate for `stage`, whose worktree is at `head`.
+///
+/// An approval without a recorded commit, or checked without a known HEAD,
+/// counts as current.
+pub fn pair_gate(stage: &Stage, head: Option<&str>) -> PairGate {
+    if !stage.pair {
+        return PairGate::NotRequired;
+    }
+    match &stage.pair_review {
+        None => PairGate::Missing,
+        Some(review) if !review.approved => PairGate::Rejected {
+            notes: review.notes.clone(),
+        },
+        Some(review) => match (review.commit.as_deref(), head) {
+            (Some(reviewed), Some(head)) if reviewed != head => PairGate::Stale,
+            _ => PairGate::Approved,
+        },
+    }
+}
+
+/// Record the reviewer's verdict on `stage` for the worktree at `head`.
+pub fn record_review(
+    stage: &mut Stage,
+    approved: bool,
+    notes: Option<String>,
+    head: Option<String>,
+) {
+    stage.pair_review = Some(PairReview {
+        approved,
+        commit: head,
+        notes,
+        reviewed_at: Utc::now(),
+    });
+    stage.updated_at = Utc::now();
+}
+
+/// Current HEAD of the worktree at `path`, if it is a git checkout.
+pub fn worktree_head(path: &Path) -> Option<String> {
+    run_git_checked(&["rev-parse", "HEAD"], path).ok()
+}
+
+/// Reviewer sessions recorded for `stage_id`.
+pub fn reviewer_sessions(stage_id: &str, work_dir: &Path) -> Vec<Session> {
+    let Ok(entries) = std::fs::read_dir(work_dir.join("sessions")) else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().and_then(|e| e.to_str()) == Some("md"))
+        .filter_map(|path| std::fs::read_to_string(path).ok())
+        .filter_map(|content| parse_from_markdown::<Session>(&content, "Session").ok())
+        .filter(|session| {
+            session.is_pair_reviewer() && session.stage_id.as_deref() == Some(stage_id)
+        })
+        .collect()
+}
+
+/// Stop the reviewer sessions of `stage_id` and mark them completed.
+///
+/// Returns how many were stopped. Failures are reported, not returned: a
+/// stray reviewer must never hold up the stage it reviewed.
+pub fn stop_reviewers(stage_id: &str, work_dir: &Path, backend: &dyn TerminalBackend) -> usize {
+    let mut stopped = 0;
+    for mut session in reviewer_sessions(stage_id, work_dir) {
+        if session.status == SessionStatus::Completed {
+            continue;
+        }
+        if let Err(e) = backend.kill_session(&session) {
+            eprintln!(
+                "Warning: failed to stop reviewer session '{}': {e}",
+                session.id
+            );
+        }
+        if let Err(e) = finish(&mut session, work_dir) {
+            eprintln!(
+                "Warning: failed to save reviewer session '{}': {e}",
+                session.id
+            );
+        }
+        stopped += 1;
+    }
+    stopped
+}
+
+fn finish(session: &mut Session, work_dir: &Path) -> Result<()> {
+    session.status = SessionStatus::Completed;
+    session.last_active = Utc::now();
+    crate::fs::session_files::save_session(session, work_dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn pair_stage() -> Stage {
+        let mut stage = Stage::new("API".to_string(), None);
+        stage.id = "api".to_string();
+        stage.pair = true;
+        stage
+    }
+
+    #[test]
+    fn test_gate_follows_latest_verdict_and_head() {
+        let mut stage = pair_stage();
+        assert_eq!(pair_gate(&stage, Some("abc")), PairGate::Missing);
+
+        record_review(&mut stage, true, None, Some("abc".to_string()));
+        assert!(pair_gate(&stage, Some("abc")).allows_completion());
+        assert_eq!(pair_gate(&stage, Some("def")), PairGate::Stale);
+        assert_eq!(pair_gate(&stage, None), PairGate::Approved);
+
+        record_review(&mut stage, false, Some("Add tests".to_string()), None);
+        let gate = pair_gate(&stage, Some("abc"));
+        assert!(!gate.allows_completion());
+        assert!(gate.reason("api").unwrap().contains("Add tests"));
+
+        stage.pair = false;
+        assert_eq!(pair_gate(&stage, None), PairGate::NotRequired);
+    }
+
+    #[test]
+    fn test_reviewer_sessions_only_lists_reviewers_of_the_stage() {
+        let temp = TempDir::new().unwrap();
+        let mut implementer = Session::new();
+        implementer.assign_to_stage("api".to_string());
+        let mut reviewer = Session::new_pair_reviewer();
+        reviewer.assign_to_stage("api".to_string());
+        let mut other = Session::new_pair_reviewer();
+        other.assign_to_stage("ui".to_string());
+        for session in [&implementer, &reviewer, &other] {
+            crate::fs::session_files::save_session(session, temp.path()).unwrap();
+        }
+
+        let found = reviewer_sessions("api", temp.path());
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].id, reviewer.id);
+        assert!(found[0].tracking_key.contains("reviewer-api"));
+        assert_eq!(
+            crate::fs::session_files::find_session_for_stage("api", temp.path()),
+            Some(implementer.id)
+        );
+    }
+}
@@ -0,0 +1,204 @@
+//! Whole-plan pause.
+//!
+//! `loom pause` writes `.work/pause.json`; while it exists the daemon starts
+//! no new stages and no merge resolution sessions. Running sessions keep
+//! working unless the pause was taken with `--sessions`, which suspends each
+//! session's process tree with `SIGSTOP` and records the stopped PIDs so
+//! `loom resume` can continue exactly those processes with `SIGCONT`.
+//!
+//! The daemon re-reads the file every poll, so pausing works whether or not
+//! the daemon is running; on resume it re-evaluates stage readiness before
+//! scheduling again. Hung-session detection is suppressed while sessions are
+//! suspended, since a stopped agent cannot write heartbeats.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use nix::sys::signal::{kill, Signal};
+use nix::unistd::Pid;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// File under `.work/` whose presence pauses the plan.
+pub const PAUSE_FILE: &str = "pause.json";
+
+/// A session whose processes `loom pause --sessions` stopped.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SuspendedSession {
+    pub session_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stage_id: Option<String>,
+    /// The session's agent PID followed by its descendants, in stop order
+    pub pids: Vec<u32>,
+}
+
+/// Contents of `.work/pause.json`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PauseState {
+    pub paused_at: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Sessions suspended with the pause; empty when they kept running
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub suspended: Vec<SuspendedSession>,
+}
+
+impl PauseState {
+    pub fn new(reason: Option<String>) -> Self {
+        Self {
+            paused_at: Utc::now(),
+            reason,
+            suspended: Vec::new(),
+        }
+    }
+
+    /// Whether any session was suspended with the pause.
+    pub fn sessions_suspended(&self) -> bool {
+        !self.suspended.is_empty()
+    }
+}
+
+fn pause_path(work_dir: &Path) -> PathBuf {
+    work_dir.join(PAUSE_FILE)
+}
+
+/// Read the pause state, or `None` when the plan is not paused.
+pub fn read_pause_state(work_dir: &Path) -> Option<PauseState> {
+    let content = fs::read_to_string(pause_path(work_dir)).ok()?;
+    match serde_json::from_str(&content) {
+        Ok(state) => Some(state),
+        Err(e) => {
+            // A pause file that cannot be parsed still pauses: resuming by
+            // accident would be worse than an explicit `loom resume`.
+            tracing::warn!(error = %e, "Unreadable {PAUSE_FILE}; treating plan as paused");
+            Some(PauseState::new(None))
+        }
+    }
+}
+
+/// Write the pause state.
+pub fn write_pause_state(work_dir: &Path, state: &PauseState) -> Result<()> {
+    let path = pause_path(work_dir);
+    let json = serde_json::to_string_pretty(state).context("Failed to serialize pause state")?;
+    fs::write(&path, json).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// Remove the pause file, returning the state it held.
+pub fn clear_pause_state(work_dir: &Path) -> Result<Option<PauseState>> {
+    let state = read_pause_state(work_dir);
+    let path = pause_path(work_dir);
+    if path.exists() {
+        fs::remove_file(&path).with_context(|| format!("Failed to remove {}", path.display()))?;
+    }
+    Ok(state)
+}
+
+/// Stop `pid` and its descendants with `SIGSTOP`, parent first so it cannot
+/// spawn new children meanwhile. Returns the PIDs that were stopped.
+pub fn suspend_process_tree(pid: u32) -> Vec<u32> {
+    let mut pids = vec![pid];
+    pids.extend(descendants(pid));
+    pids.into_iter()
+        .filter(|&p| send(p, Signal::SIGSTOP))
+        .collect()
+}
+
+/// Continue PIDs stopped by [`suspend_process_tree`], children first.
+/// Returns how many were signalled.
+pub fn continue_processes(pids: &[u32]) -> usize {
+    pids.iter()
+        .rev()
+        .filter(|&&p| send(p, Signal::SIGCONT))
+        .count()
+}
+
+fn send(pid: u32, signal: Signal) -> bool {
+    i32::try_from(pid)
+        .ok()
+        .is_some_and(|raw| kill(Pid::from_raw(raw), signal).is_ok())
+}
+
+/// Descendants of `pid`, breadth first, from `ps -A -o pid=,ppid=`.
+fn descendants(pid: u32) -> Vec<u32> {
+    let Ok(output) = Command::new("ps").args(["-A", "-o", "pid=,ppid="]).output() else {
+        return Vec::new();
+    };
+    let table = String::from_utf8_lossy(&output.stdout);
+    descendants_from_table(pid, &table)
+}
+
+fn descendants_from_table(pid: u32, table: &str) -> Vec<u32> {
+    let mut children: HashMap<u32, Vec<u32>> = HashMap::new();
+    for line in table.lines() {
+        let mut cols = line.split_whitespace().map(str::parse::<u32>);
+        if let (Some(Ok(child)), Some(Ok(parent))) = (cols.next(), cols.next()) {
+            children.entry(parent).or_default().push(child);
+        }
+    }
+
+    let mut found = Vec::new();
+    let mut frontier = vec![pid];
+    while let Some(parent) = frontier.pop() {
+        for &child in children.get(&parent).into_iter().flatten() {
+            if child != pid && !found.contains(&child) {
+                found.push(child);
+                frontier.push(child);
+            }
+        }
+    }
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_pause_state_round_trip() {
+        let temp = TempDir::new().unwrap();
+        assert!(read_pause_state(temp.path()).is_none());
+
+        let mut state = PauseState::new(Some("CI is down".to_string()));
+        state.suspended.push(SuspendedSession {
+            session_id: "session-1".to_string(),
+            stage_id: Some("api".to_string()),
+            pids: vec![100, 101],
+        });
+        write_pause_state(temp.path(), &state).unwrap();
+        assert_eq!(read_pause_state(temp.path()), Some(state.clone()));
+        assert!(state.sessions_suspended());
+
+        assert_eq!(clear_pause_state(temp.path()).unwrap(), Some(state));
+        assert!(read_pause_state(temp.path()).is_none());
+        assert_eq!(clear_pause_state(temp.path()).unwrap(), None);
+    }
+
+    #[test]
+    fn test_corrupt_pause_file_still_pauses() {
+        let temp = TempDir::new().unwrap();
+        fs::write(temp.path().join(PAUSE_FILE), "not json").unwrap();
+        assert!(read_pause_state(temp.path()).is_some());
+    }
+
+    #[test]
+    fn test_descendants_from_table() {
+        let table = "  1     0\n 10     1\n 11    10\n 12    10\n 13    11\n 20     1\n";
+        let mut found = descendants_from_table(10, table);
+        found.sort();
+        assert_eq!(found, vec![11, 12, 13]);
+        assert!(descendants_from_table(20, table).is_empty());
+    }
+
+    #[test]
+    fn test_suspend_and_continue_child_process() {
+        let mut child = Command::new("sleep").arg("30").spawn().unwrap();
+        let pids = suspend_process_tree(child.id());
+        assert_eq!(pids, vec![child.id()]);
+        assert_eq!(continue_processes(&pids), 1);
+        child.kill().unwrap();
+        child.wait().unwrap();
+    }
+}
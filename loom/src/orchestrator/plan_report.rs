@@ -0,0 +1,357 @@
+//! Plan summary report generated when the execution graph completes.
+//!
+//! Collects what the plan produced — completed stages, the diff each stage
+//! merged, learnings and open questions from the memory journals, and the
+//! total duration — into `.work/reports/<plan-id>-summary.md`, formatted for
+//! pasting into a PR description or release notes.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::fs::memory::{read_journal, MemoryEntry, MemoryEntryType};
+use crate::git::branch::branch_name_for_stage;
+use crate::git::run_git_checked;
+use crate::models::stage::{StageStatus, StageType};
+use crate::utils::format_elapsed_verbose;
+use crate::verify::transitions::list_all_stages;
+
+/// Summary of one finished stage.
+#[derive(Debug, Clone)]
+pub struct StageSummary {
+    pub id: String,
+    pub name: String,
+    pub status: StageStatus,
+    pub duration_secs: Option<i64>,
+    /// `git diff --shortstat` of the stage's merge commit, if one was found
+    pub shortstat: Option<String>,
+}
+
+/// Everything that goes into the plan summary.
+#[derive(Debug, Clone)]
+pub struct PlanReport {
+    pub plan_id: Option<String>,
+    pub merge_point: String,
+    pub stages: Vec<StageSummary>,
+    /// Notes and decisions, tagged with the stage that recorded them
+    pub learnings: Vec<(String, MemoryEntry)>,
+    /// Questions recorded in memory, tagged with their stage
+    pub open_questions: Vec<(String, MemoryEntry)>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+impl PlanReport {
+    /// Wall-clock time from the first stage starting to the last finishing.
+    pub fn total_duration_secs(&self) -> Option<i64> {
+        match (self.started_at, self.finished_at) {
+            (Some(start), Some(end)) => Some(end.signed_duration_since(start).num_seconds()),
+            _ => None,
+        }
+    }
+
+    /// Render the report as markdown.
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::new();
+        let title = self.plan_id.as_deref().unwrap_or("plan");
+        let _ = writeln!(out, "# Plan Summary: {title}\n");
+
+        let completed = self
+            .stages
+            .iter()
+            .filter(|s| s.status == StageStatus::Completed)
+            .count();
+        let _ = writeln!(
+            out,
+            "- **Stages completed**: {completed}/{}",
+            self.stages.len()
+        );
+        let _ = writeln!(out, "- **Merged into**: `{}`", self.merge_point);
+        if let Some(secs) = self.total_duration_secs() {
+            let _ = writeln!(
+                out,
+                "- **Total duration**: {}",
+                format_elapsed_verbose(secs)
+            );
+        }
+
+        out.push_str("\n## Stages\n\n");
+        out.push_str("| Stage | Status | Duration | Changes |\n");
+        out.push_str("|-------|--------|----------|---------|\n");
+        for stage in &self.stages {
+            let _ = writeln!(
+                out,
+                "| {} (`{}`) | {} | {} | {} |",
+                stage.name,
+                stage.id,
+                stage.status,
+                stage
+                    .duration_secs
+                    .map(format_elapsed_verbose)
+                    .unwrap_or_else(|| "-".to_string()),
+                stage.shortstat.as_deref().unwrap_or("-"),
+            );
+        }
+
+        out.push_str("\n## Learnings\n\n");
+        write_entries(&mut out, &self.learnings, "No learnings recorded.");
+
+        out.push_str("\n## Open Questions\n\n");
+        write_entries(&mut out, &self.open_questions, "No open questions.");
+
+        out
+    }
+}
+
+fn write_entries(out: &mut String, entries: &[(String, MemoryEntry)], empty: &str) {
+    if entries.is_empty() {
+        let _ = writeln!(out, "{empty}");
+        return;
+    }
+    for (stage_id, entry) in entries {
+        let _ = write!(out, "- **{stage_id}**: {}", entry.content);
+        if let Some(context) = &entry.context {
+            let _ = write!(out, " — {context}");
+        }
+        out.push('\n');
+    }
+}
+
+/// Gather the report from stage files, memory journals and git history.
+///
+/// Git lookups are best effort: a stage whose merge commit cannot be found
+/// (knowledge stages, skipped stages, `--no-verify` completions) is reported
+/// without a shortstat.
+pub fn build_plan_report(work_dir: &Path, repo_root: &Path) -> Result<PlanReport> {
+    let plan_id = crate::fs::load_config(work_dir)?.and_then(|c| c.plan_id().map(String::from));
+    let merge_point = crate::fs::get_merge_point(work_dir)?;
+
+    let mut stages = list_all_stages(work_dir)?;
+    stages.sort_by_key(|s| (s.started_at.is_none(), s.started_at, s.id.clone()));
+
+    let mut report = PlanReport {
+        plan_id,
+        merge_point,
+        stages: Vec::new(),
+        learnings: Vec::new(),
+        open_questions: Vec::new(),
+        started_at: stages.iter().filter_map(|s| s.started_at).min(),
+        finished_at: stages.iter().filter_map(|s| s.completed_at).max(),
+    };
+
+    for stage in &stages {
+        let shortstat = if stage.merged && stage.stage_type != StageType::Knowledge {
+            merge_shortstat(&stage.id, &report.merge_point, repo_root)
+        } else {
+            None
+        };
+        report.stages.push(StageSummary {
+            id: stage.id.clone(),
+            name: stage.name.clone(),
+            status: stage.status.clone(),
+            duration_secs: stage.duration_secs,
+            shortstat,
+        });
+
+        let journal = read_journal(work_dir, &stage.id)?;
+        for entry in journal.entries {
+            match entry.entry_type {
+                MemoryEntryType::Note | MemoryEntryType::Decision => {
+                    report.learnings.push((stage.id.clone(), entry))
+                }
+                MemoryEntryType::Question => report.open_questions.push((stage.id.clone(), entry)),
+                MemoryEntryType::Change => {}
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+/// Build the report and write it under `.work/reports/`.
+///
+/// # Returns
+///
+/// The path of the written report.
+pub fn write_plan_report(work_dir: &Path, repo_root: &Path) -> Result<PathBuf> {
+    let report = build_plan_report(work_dir, repo_root)?;
+
+    let reports_dir = work_dir.join("reports");
+    fs::create_dir_all(&reports_dir).context("Failed to create reports directory")?;
+    let name = report.plan_id.as_deref().unwrap_or("plan");
+    let path = reports_dir.join(format!("{name}-summary.md"));
+    fs::write(&path, report.to_markdown())
+        .with_context(|| format!("Failed to write plan report: {}", path.display()))?;
+
+    Ok(path)
+}
+
+/// Shortstat of the most recent `Merge loom/<stage> into <merge_point>`
+/// commit on the merge point's first-parent history.
+fn merge_shortstat(stage_id: &str, merge_point: &str, repo_root: &Path) -> Option<String> {
+    let grep = format!(
+        "--grep=Merge {} into {merge_point}",
+        branch_name_for_stage(stage_id)
+    );
+    let merge_commit = run_git_checked(
+        &[
+            "log",
+            merge_point,
+            "--merges",
+            "--first-parent",
+            "--fixed-strings",
+            &grep,
+            "-n1",
+            "--format=%H",
+        ],
+        repo_root,
+    )
+    .ok()?;
+    let merge_commit = merge_commit.trim();
+    if merge_commit.is_empty() {
+        return None;
+    }
+
+    let first_parent = format!("{merge_commit}^1");
+    let stat = run_git_checked(
+        &["diff", "--shortstat", &first_parent, merge_commit],
+        repo_root,
+    )
+    .ok()?;
+    let stat = stat.trim();
+    Some(if stat.is_empty() {
+        "no changes".to_string()
+    } else {
+        stat.to_string()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fs::memory::append_entry;
+    use crate::models::stage::Stage;
+    use crate::verify::transitions::save_stage;
+    use chrono::Duration;
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    fn git(root: &Path, args: &[&str]) {
+        let out = Command::new("git")
+            .args(args)
+            .current_dir(root)
+            .env("GIT_CONFIG_GLOBAL", root.join(".loom-test-no-global"))
+            .env("GIT_CONFIG_NOSYSTEM", "1")
+            .output()
+            .unwrap();
+        assert!(
+            out.status.success(),
+            "git {args:?} failed: {}",
+            String::from_utf8_lossy(&out.stderr)
+        );
+    }
+
+    fn completed_stage(id: &str, start: DateTime<Utc>, secs: i64) -> Stage {
+        Stage {
+            id: id.to_string(),
+            name: format!("Stage {id}"),
+            status: StageStatus::Completed,
+            merged: true,
+            started_at: Some(start),
+            completed_at: Some(start + Duration::seconds(secs)),
+            duration_secs: Some(secs),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_report_collects_stages_memory_and_duration() {
+        let temp = TempDir::new().unwrap();
+        let work_dir = temp.path().join(".work");
+        let start = Utc::now() - Duration::hours(2);
+        save_stage(&completed_stage("api", start, 600), &work_dir).unwrap();
+        save_stage(
+            &completed_stage("ui", start + Duration::seconds(600), 900),
+            &work_dir,
+        )
+        .unwrap();
+        append_entry(
+            &work_dir,
+            "api",
+            &MemoryEntry::with_context(
+                MemoryEntryType::Decision,
+                "Use sqlite".into(),
+                "No server needed".into(),
+            ),
+        )
+        .unwrap();
+        append_entry(
+            &work_dir,
+            "ui",
+            &MemoryEntry::new(MemoryEntryType::Question, "Dark mode?".into()),
+        )
+        .unwrap();
+        append_entry(
+            &work_dir,
+            "ui",
+            &MemoryEntry::new(MemoryEntryType::Change, "src/ui.rs".into()),
+        )
+        .unwrap();
+
+        let report = build_plan_report(&work_dir, temp.path()).unwrap();
+
+        assert_eq!(report.stages.len(), 2);
+        assert_eq!(report.stages[0].id, "api");
+        assert_eq!(report.total_duration_secs(), Some(1500));
+        assert_eq!(report.learnings.len(), 1);
+        assert_eq!(report.open_questions.len(), 1);
+
+        let markdown = report.to_markdown();
+        assert!(markdown.contains("**Stages completed**: 2/2"));
+        assert!(markdown.contains("**Total duration**: 25m 0s"));
+        assert!(markdown.contains("- **api**: Use sqlite — No server needed"));
+        assert!(markdown.contains("- **ui**: Dark mode?"));
+        assert!(!markdown.contains("src/ui.rs"));
+    }
+
+    #[test]
+    fn test_write_plan_report_includes_merge_shortstat() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        git(root, &["init", "-b", "main"]);
+        git(root, &["config", "user.email", "t@t.com"]);
+        git(root, &["config", "user.name", "t"]);
+        fs::write(root.join("README.md"), "seed\n").unwrap();
+        git(root, &["add", "README.md"]);
+        git(root, &["commit", "-m", "seed"]);
+        git(root, &["checkout", "-b", "loom/api"]);
+        fs::write(root.join("api.rs"), "fn a() {}\nfn b() {}\n").unwrap();
+        git(root, &["add", "api.rs"]);
+        git(root, &["commit", "-m", "api"]);
+        git(root, &["checkout", "main"]);
+        git(
+            root,
+            &[
+                "merge",
+                "--no-ff",
+                "-m",
+                "Merge loom/api into main",
+                "loom/api",
+            ],
+        );
+
+        let work_dir = root.join(".work");
+        save_stage(&completed_stage("api", Utc::now(), 60), &work_dir).unwrap();
+
+        let path = write_plan_report(&work_dir, root).unwrap();
+
+        assert_eq!(path, work_dir.join("reports").join("plan-summary.md"));
+        let markdown = fs::read_to_string(path).unwrap();
+        assert!(
+            markdown.contains("1 file changed, 2 insertions(+)"),
+            "{markdown}"
+        );
+    }
+}
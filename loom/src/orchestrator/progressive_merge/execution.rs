@@ -5,7 +5,9 @@ use std::path::Path;
 use std::time::Duration;
 
 use crate::git::branch::{branch_exists, branch_name_for_stage};
-use crate::git::merge::{merge_stage, MergeResult};
+use crate::git::merge::MergeResult;
+use crate::git::vcs;
+use crate::handoff::record_forward_handoff;
 use crate::models::stage::Stage;
 
 use super::ProgressiveMergeResult;
@@ -61,8 +63,11 @@ pub fn merge_completed_stage_with_timeout(
         return Err(anyhow::anyhow!(".work directory not found"));
     }
 
+    record_forward_handoff(stage, repo_root, merge_point, &work_dir);
+
     // Attempt the merge (merge_stage will acquire the lock internally)
-    let result = merge_stage(&stage.id, merge_point, repo_root, &work_dir)
+    let result = vcs::for_repo(repo_root)
+        .merge_stage(&stage.id, merge_point, repo_root, &work_dir)
         .with_context(|| format!("Failed to merge stage {} into {}", stage.id, merge_point))?;
 
     // Convert git::merge::MergeResult to ProgressiveMergeResult
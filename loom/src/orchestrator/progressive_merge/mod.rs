@@ -5,9 +5,11 @@
 //! verified branches as soon as they pass, we minimize the window for conflicts.
 //!
 //! The merge uses file-based locking to prevent concurrent merges from multiple
-//! stages completing simultaneously.
+//! stages completing simultaneously, and a merge queue to decide which waiting
+//! stage goes first.
 
 pub mod execution;
+pub mod queue;
 
 pub use crate::fs::get_merge_point;
 pub use crate::git::merge::lock::MergeLock;
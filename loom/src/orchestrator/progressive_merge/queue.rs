@@ -0,0 +1,402 @@
+//! Merge queue for ordering progressive merges
+//!
+//! Stages that pass verification register in `.work/merge-queue.json` and
+//! wait for their turn before taking the merge lock. By default the queue is
+//! first come, first served (completion order). `loom merge pin` puts a stage
+//! first once it has completed — other stages then hold back until it has
+//! merged — and `loom merge move` reorders the stages behind it.
+//!
+//! Waits are bounded: a stage that waits longer than the queue timeout merges
+//! anyway so a stuck or abandoned pin can never stall the plan. Entries whose
+//! process is gone are pruned on every read.
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::fs::locking::{locked_read, locked_update};
+use crate::models::stage::StageStatus;
+use crate::process::is_process_alive;
+use crate::verify::transitions::load_stage;
+
+/// How long a stage waits for its turn before merging out of order
+pub const MERGE_QUEUE_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// A stage waiting to merge
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QueueEntry {
+    pub stage_id: String,
+    /// Process waiting on the merge (the `loom stage complete` invocation)
+    pub pid: u32,
+    pub enqueued_at: DateTime<Utc>,
+}
+
+/// Persisted merge queue state
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MergeQueue {
+    /// Stage that merges first once it has completed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pinned: Option<String>,
+    /// Manual ordering of stage IDs; stages not listed follow in arrival order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub order: Vec<String>,
+    /// Stages currently waiting to merge
+    #[serde(default)]
+    pub waiting: Vec<QueueEntry>,
+}
+
+impl MergeQueue {
+    /// Rank of a stage in the queue: pinned first, then manual order, then
+    /// arrival time.
+    fn rank_key(&self, stage_id: &str) -> (bool, usize) {
+        let pinned = self.pinned.as_deref() == Some(stage_id);
+        let position = self
+            .order
+            .iter()
+            .position(|id| id == stage_id)
+            .unwrap_or(usize::MAX);
+        (!pinned, position)
+    }
+
+    /// Sort stage IDs into merge order. `arrival` breaks ties between stages
+    /// that have no manual position.
+    pub fn rank<'a>(&self, mut ids: Vec<(&'a str, Option<DateTime<Utc>>)>) -> Vec<&'a str> {
+        ids.sort_by_key(|(id, arrival)| (self.rank_key(id), arrival.is_none(), *arrival));
+        ids.into_iter().map(|(id, _)| id).collect()
+    }
+
+    /// Waiting entries in merge order.
+    pub fn ranked_waiting(&self) -> Vec<&QueueEntry> {
+        let order = self.rank(
+            self.waiting
+                .iter()
+                .map(|e| (e.stage_id.as_str(), Some(e.enqueued_at)))
+                .collect(),
+        );
+        order
+            .into_iter()
+            .filter_map(|id| self.waiting.iter().find(|e| e.stage_id == id))
+            .collect()
+    }
+
+    /// Drop entries whose waiting process has exited.
+    fn prune_dead(&mut self) {
+        self.waiting.retain(|e| is_process_alive(e.pid));
+    }
+}
+
+/// Path of the queue file
+pub fn merge_queue_path(work_dir: &Path) -> PathBuf {
+    work_dir.join("merge-queue.json")
+}
+
+/// Load the queue, pruning entries whose process has exited.
+pub fn load_merge_queue(work_dir: &Path) -> Result<MergeQueue> {
+    let path = merge_queue_path(work_dir);
+    if !path.exists() {
+        return Ok(MergeQueue::default());
+    }
+    let content = locked_read(&path)?;
+    let mut queue = parse_queue(&content, &path)?;
+    queue.prune_dead();
+    Ok(queue)
+}
+
+/// Apply `modify` to the queue under the `.work/` lock.
+pub fn update_merge_queue<F>(work_dir: &Path, modify: F) -> Result<()>
+where
+    F: FnOnce(&mut MergeQueue) -> Result<()>,
+{
+    let path = merge_queue_path(work_dir);
+    locked_update(&path, |content| {
+        let mut queue = parse_queue(&content, &path)?;
+        queue.prune_dead();
+        modify(&mut queue)?;
+        serde_json::to_string_pretty(&queue).context("Failed to serialize merge queue")
+    })
+}
+
+fn parse_queue(content: &str, path: &Path) -> Result<MergeQueue> {
+    if content.trim().is_empty() {
+        return Ok(MergeQueue::default());
+    }
+    serde_json::from_str(content)
+        .with_context(|| format!("Failed to parse merge queue: {}", path.display()))
+}
+
+/// Place in the queue held by a waiting stage. Dropping the ticket leaves the
+/// queue and clears the stage's pin and manual position.
+pub struct QueueTicket {
+    work_dir: PathBuf,
+    stage_id: String,
+    /// Whether the wait timed out and the stage is merging out of order
+    pub timed_out: bool,
+}
+
+impl Drop for QueueTicket {
+    fn drop(&mut self) {
+        let stage_id = self.stage_id.clone();
+        let result = update_merge_queue(&self.work_dir, |queue| {
+            queue.waiting.retain(|e| e.stage_id != stage_id);
+            queue.order.retain(|id| *id != stage_id);
+            if queue.pinned.as_deref() == Some(stage_id.as_str()) {
+                queue.pinned = None;
+            }
+            Ok(())
+        });
+        if let Err(e) = result {
+            tracing::warn!(stage_id = %self.stage_id, "Failed to leave merge queue: {e}");
+        }
+    }
+}
+
+/// Join the queue and block until it is this stage's turn to merge.
+pub fn wait_for_merge_turn(work_dir: &Path, stage_id: &str) -> Result<QueueTicket> {
+    wait_for_merge_turn_with(work_dir, stage_id, MERGE_QUEUE_TIMEOUT, POLL_INTERVAL)
+}
+
+pub(crate) fn wait_for_merge_turn_with(
+    work_dir: &Path,
+    stage_id: &str,
+    timeout: Duration,
+    poll: Duration,
+) -> Result<QueueTicket> {
+    let pid = std::process::id();
+    update_merge_queue(work_dir, |queue| {
+        queue.waiting.retain(|e| e.stage_id != stage_id);
+        queue.waiting.push(QueueEntry {
+            stage_id: stage_id.to_string(),
+            pid,
+            enqueued_at: Utc::now(),
+        });
+        Ok(())
+    })?;
+    let mut ticket = QueueTicket {
+        work_dir: work_dir.to_path_buf(),
+        stage_id: stage_id.to_string(),
+        timed_out: false,
+    };
+
+    let start = Instant::now();
+    let mut announced = false;
+    loop {
+        let queue = load_merge_queue(work_dir)?;
+        let Some(ahead) = blocking_stage(&queue, stage_id, work_dir) else {
+            return Ok(ticket);
+        };
+        if start.elapsed() >= timeout {
+            tracing::warn!(stage_id, ahead = %ahead, "Merge queue wait timed out");
+            println!("  Merge queue wait timed out behind '{ahead}'; merging out of order");
+            ticket.timed_out = true;
+            return Ok(ticket);
+        }
+        if !announced {
+            println!("  Waiting in merge queue behind '{ahead}'...");
+            announced = true;
+        }
+        std::thread::sleep(poll);
+    }
+}
+
+/// The stage that must merge before `stage_id`, if any.
+pub(crate) fn blocking_stage(
+    queue: &MergeQueue,
+    stage_id: &str,
+    work_dir: &Path,
+) -> Option<String> {
+    if let Some(pinned) = queue.pinned.as_deref().filter(|p| *p != stage_id) {
+        // A pinned stage still executing does not hold others back; it only
+        // goes first once it is done and about to merge.
+        let pinned_waiting = queue.waiting.iter().any(|e| e.stage_id == pinned);
+        let pinned_merging = load_stage(pinned, work_dir)
+            .map(|s| {
+                matches!(s.status, StageStatus::Completed | StageStatus::MergeBlocked) && !s.merged
+            })
+            .unwrap_or(false);
+        if pinned_waiting || pinned_merging {
+            return Some(pinned.to_string());
+        }
+    }
+    queue
+        .ranked_waiting()
+        .first()
+        .map(|e| e.stage_id.clone())
+        .filter(|first| first != stage_id)
+}
+
+/// Pin a stage so it merges next.
+pub fn pin_stage(work_dir: &Path, stage_id: &str) -> Result<()> {
+    update_merge_queue(work_dir, |queue| {
+        queue.pinned = Some(stage_id.to_string());
+        Ok(())
+    })
+}
+
+/// Clear the pin, returning the stage that was pinned.
+pub fn unpin_stage(work_dir: &Path) -> Result<Option<String>> {
+    let mut previous = None;
+    update_merge_queue(work_dir, |queue| {
+        previous = queue.pinned.take();
+        Ok(())
+    })?;
+    Ok(previous)
+}
+
+/// Move `stage_id` to 1-based `position` within `current`, the displayed
+/// queue order, and persist the result as the manual order.
+pub fn move_stage(
+    work_dir: &Path,
+    current: &[String],
+    stage_id: &str,
+    position: usize,
+) -> Result<()> {
+    if !current.iter().any(|id| id == stage_id) {
+        bail!("Stage '{stage_id}' is not awaiting merge");
+    }
+    if position == 0 || position > current.len() {
+        bail!("Position must be between 1 and {}", current.len());
+    }
+    let mut order: Vec<String> = current
+        .iter()
+        .filter(|id| *id != stage_id)
+        .cloned()
+        .collect();
+    order.insert(position - 1, stage_id.to_string());
+    update_merge_queue(work_dir, |queue| {
+        queue.order = order;
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration as ChronoDuration;
+    use tempfile::TempDir;
+
+    fn entry(id: &str, pid: u32, secs_ago: i64) -> QueueEntry {
+        QueueEntry {
+            stage_id: id.to_string(),
+            pid,
+            enqueued_at: Utc::now() - ChronoDuration::seconds(secs_ago),
+        }
+    }
+
+    #[test]
+    fn test_ranked_waiting_pins_then_orders_then_arrival() {
+        let me = std::process::id();
+        let mut queue = MergeQueue {
+            waiting: vec![entry("a", me, 30), entry("b", me, 20), entry("c", me, 10)],
+            ..Default::default()
+        };
+        let ids = |q: &MergeQueue| -> Vec<String> {
+            q.ranked_waiting()
+                .iter()
+                .map(|e| e.stage_id.clone())
+                .collect()
+        };
+        assert_eq!(ids(&queue), vec!["a", "b", "c"]);
+
+        queue.order = vec!["c".to_string()];
+        assert_eq!(ids(&queue), vec!["c", "a", "b"]);
+
+        queue.pinned = Some("b".to_string());
+        assert_eq!(ids(&queue), vec!["b", "c", "a"]);
+    }
+
+    #[test]
+    fn test_wait_for_turn_prunes_dead_entries_and_leaves_on_drop() {
+        let temp = TempDir::new().unwrap();
+        let work_dir = temp.path();
+        update_merge_queue(work_dir, |queue| {
+            queue.waiting.push(entry("dead", 999_999_999, 60));
+            Ok(())
+        })
+        .unwrap();
+
+        let ticket =
+            wait_for_merge_turn_with(work_dir, "api", Duration::from_secs(5), POLL_INTERVAL)
+                .unwrap();
+        assert!(!ticket.timed_out);
+        let queue = load_merge_queue(work_dir).unwrap();
+        assert_eq!(queue.waiting.len(), 1);
+        assert_eq!(queue.waiting[0].stage_id, "api");
+
+        drop(ticket);
+        assert!(load_merge_queue(work_dir).unwrap().waiting.is_empty());
+    }
+
+    #[test]
+    fn test_wait_for_turn_times_out_behind_earlier_stage() {
+        let temp = TempDir::new().unwrap();
+        let work_dir = temp.path();
+        update_merge_queue(work_dir, |queue| {
+            queue.waiting.push(entry("first", std::process::id(), 60));
+            Ok(())
+        })
+        .unwrap();
+
+        let ticket = wait_for_merge_turn_with(
+            work_dir,
+            "second",
+            Duration::from_millis(50),
+            Duration::from_millis(10),
+        )
+        .unwrap();
+        assert!(ticket.timed_out);
+    }
+
+    #[test]
+    fn test_move_stage_and_pin() {
+        let temp = TempDir::new().unwrap();
+        let work_dir = temp.path();
+        let current = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+
+        move_stage(work_dir, &current, "c", 1).unwrap();
+        assert_eq!(
+            load_merge_queue(work_dir).unwrap().order,
+            vec!["c", "a", "b"]
+        );
+        assert!(move_stage(work_dir, &current, "x", 1).is_err());
+        assert!(move_stage(work_dir, &current, "a", 4).is_err());
+
+        pin_stage(work_dir, "b").unwrap();
+        assert_eq!(unpin_stage(work_dir).unwrap(), Some("b".to_string()));
+        assert_eq!(unpin_stage(work_dir).unwrap(), None);
+    }
+
+    #[test]
+    fn test_executing_pin_does_not_block_until_completed() {
+        use crate::models::stage::Stage;
+        use crate::verify::transitions::save_stage;
+
+        let temp = TempDir::new().unwrap();
+        let work_dir = temp.path();
+        let mut pinned = Stage::new("Pinned".to_string(), None);
+        pinned.id = "pinned".to_string();
+        pinned.status = StageStatus::Executing;
+        save_stage(&pinned, work_dir).unwrap();
+
+        let queue = MergeQueue {
+            pinned: Some("pinned".to_string()),
+            waiting: vec![entry("done", std::process::id(), 10)],
+            ..Default::default()
+        };
+        assert_eq!(blocking_stage(&queue, "done", work_dir), None);
+
+        pinned.status = StageStatus::Completed;
+        save_stage(&pinned, work_dir).unwrap();
+        assert_eq!(
+            blocking_stage(&queue, "done", work_dir),
+            Some("pinned".to_string())
+        );
+
+        pinned.merged = true;
+        save_stage(&pinned, work_dir).unwrap();
+        assert_eq!(blocking_stage(&queue, "done", work_dir), None);
+    }
+}
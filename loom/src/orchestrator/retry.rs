@@ -20,6 +20,12 @@ pub fn should_auto_retry(failure_type: &FailureType, retry_count: u32, max_retri
     )
 }
 
+/// Base of the crash auto-retry backoff, in seconds.
+pub const RETRY_BACKOFF_BASE_SECS: u64 = 30;
+
+/// Cap of the crash auto-retry backoff, in seconds.
+pub const RETRY_BACKOFF_MAX_SECS: u64 = 300;
+
 /// Calculates exponential backoff duration for retry attempts.
 ///
 /// Formula: base_secs * 2^(retry_count-1), capped at max_secs
@@ -70,6 +76,19 @@ pub fn is_backoff_elapsed(last_failure: Option<DateTime<Utc>>, backoff: Duration
     }
 }
 
+/// Time left until the backoff since `last_failure` elapses (zero once it
+/// has).
+pub fn backoff_remaining(last_failure: Option<DateTime<Utc>>, backoff: Duration) -> Duration {
+    let Some(last_time) = last_failure else {
+        return Duration::ZERO;
+    };
+    let elapsed = Utc::now()
+        .signed_duration_since(last_time)
+        .to_std()
+        .unwrap_or(Duration::ZERO);
+    backoff.saturating_sub(elapsed)
+}
+
 /// Classifies a failure based on the close reason string.
 ///
 /// Analyzes the close_reason text to determine what type of failure occurred.
@@ -162,6 +181,59 @@ pub fn classify_failure(close_reason: &str) -> FailureType {
     FailureType::Unknown
 }
 
+/// Output signatures checked by [`classify_output`], in priority order.
+///
+/// Matching is case-sensitive on purpose: these are literal strings emitted
+/// by git, cargo, test runners and the agent CLI, and lowercasing would let
+/// ordinary prose ("the merge conflict is resolved") trigger a class.
+const OUTPUT_SIGNATURES: &[(&str, FailureType)] = &[
+    ("CONFLICT (", FailureType::MergeConflict),
+    ("Automatic merge failed", FailureType::MergeConflict),
+    ("Segmentation fault", FailureType::SessionCrash),
+    ("core dumped", FailureType::SessionCrash),
+    ("Killed: 9", FailureType::SessionCrash),
+    ("prompt is too long", FailureType::ContextExhausted),
+    ("context_length_exceeded", FailureType::ContextExhausted),
+    ("context window", FailureType::ContextExhausted),
+    ("timed out", FailureType::Timeout),
+    ("error[E", FailureType::BuildFailure),
+    ("could not compile", FailureType::BuildFailure),
+    ("error: aborting due to", FailureType::BuildFailure),
+    ("Compilation failed", FailureType::BuildFailure),
+    ("error TS", FailureType::BuildFailure),
+    ("SyntaxError", FailureType::CodeError),
+    ("test result: FAILED", FailureType::TestFailure),
+    ("panicked at", FailureType::TestFailure),
+    ("AssertionError", FailureType::TestFailure),
+    ("Tests failed", FailureType::TestFailure),
+    ("FAIL ", FailureType::TestFailure),
+];
+
+/// Classifies a failure from captured output (session logs, acceptance
+/// criterion stdout/stderr).
+///
+/// Unlike [`classify_failure`], which keys off loom's own close-reason
+/// wording, this looks for the literal signatures tools print: `error[E0425]`
+/// or `could not compile` for build failures, `test result: FAILED` or
+/// `panicked at` for test failures, `CONFLICT (content)` for merge conflicts,
+/// and so on. Signatures are tried in [`OUTPUT_SIGNATURES`] order so a
+/// compile error that also fails the test run is reported as a build failure.
+///
+/// # Returns
+///
+/// The failure type and the first output line carrying its signature (for
+/// use as evidence), or `None` if no signature matched.
+pub fn classify_output(output: &str) -> Option<(FailureType, String)> {
+    OUTPUT_SIGNATURES
+        .iter()
+        .find_map(|(signature, failure_type)| {
+            output
+                .lines()
+                .find(|line| line.contains(signature))
+                .map(|line| (failure_type.clone(), line.trim().to_string()))
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -347,4 +419,49 @@ mod tests {
             FailureType::SessionCrash
         );
     }
+
+    #[test]
+    fn test_classify_output_signatures() {
+        let build = "   Compiling loom v0.1.0\nerror[E0425]: cannot find value `x`\nerror: could not compile `loom`";
+        assert_eq!(
+            classify_output(build),
+            Some((
+                FailureType::BuildFailure,
+                "error[E0425]: cannot find value `x`".to_string()
+            ))
+        );
+
+        let tests = "running 2 tests\nthread 'a' panicked at src/lib.rs:3:5\ntest result: FAILED. 1 passed; 1 failed";
+        assert_eq!(
+            classify_output(tests).map(|(t, _)| t),
+            Some(FailureType::TestFailure)
+        );
+
+        let merge = "Auto-merging src/lib.rs\nCONFLICT (content): Merge conflict in src/lib.rs";
+        assert_eq!(
+            classify_output(merge).map(|(t, _)| t),
+            Some(FailureType::MergeConflict)
+        );
+
+        assert_eq!(
+            classify_output("API Error: prompt is too long: 201234 tokens").map(|(t, _)| t),
+            Some(FailureType::ContextExhausted)
+        );
+        assert_eq!(
+            classify_output("Command timed out after 300s").map(|(t, _)| t),
+            Some(FailureType::Timeout)
+        );
+    }
+
+    #[test]
+    fn test_classify_output_prefers_build_over_test_and_ignores_prose() {
+        // A compile error aborts the test run; the root cause is the build.
+        let output = "error: could not compile `app`\ntest result: FAILED. 0 passed";
+        assert_eq!(
+            classify_output(output).map(|(t, _)| t),
+            Some(FailureType::BuildFailure)
+        );
+        assert_eq!(classify_output("resolved the merge conflict earlier"), None);
+        assert_eq!(classify_output(""), None);
+    }
 }
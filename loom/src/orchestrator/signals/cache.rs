@@ -767,6 +767,60 @@ pub fn generate_knowledge_stable_prefix() -> String {
     content
 }
 
+/// Stable prefix for code-review stages (read-only worktree, reviews another stage's branch)
+pub fn generate_code_review_stable_prefix() -> String {
+    let mut content = String::new();
+
+    content.push_str("## Code Review Context\n\n");
+    content.push_str(
+        "You are running a **code-review stage**: an independent review of ONE implementation stage's changes.\n",
+    );
+    content.push_str(
+        "The reviewed stage is complete but NOT merged — your `approved` output decides whether it merges.\n\n",
+    );
+
+    content.push_str("**Review Workflow:**\n\n");
+    content.push_str(
+        "1. Read the review target below: branch, base, acceptance criteria, review dimensions\n",
+    );
+    content.push_str("2. Inspect the diff: `git diff <base>...loom/<stage-id>` and `git log <base>..loom/<stage-id>`\n");
+    content.push_str(
+        "3. Read changed files in full where needed: `git show loom/<stage-id>:<path>`\n",
+    );
+    content.push_str(
+        "4. Review ADVERSARIALLY — assume a defect exists: correctness, security, wiring, tests, idioms\n",
+    );
+    content.push_str("5. Record the verdict as outputs, then complete:\n");
+    content.push_str("```bash\n");
+    content.push_str("loom stage output set <review-stage-id> approved true   # or false\n");
+    content.push_str("loom stage output set <review-stage-id> review_notes \"<findings>\"\n");
+    content.push_str("loom stage complete <review-stage-id>\n");
+    content.push_str("```\n\n");
+    content.push_str(
+        "Reject (`approved false`) only for defects that must be fixed before merge, and say what to fix in\n",
+    );
+    content.push_str(
+        "`review_notes` — the notes are handed to whoever fixes the stage. Style nits alone are not a rejection.\n\n",
+    );
+
+    content.push_str("**Read-Only (STRICT):**\n\n");
+    content.push_str("- **NEVER** edit files, commit, or check out the reviewed branch\n");
+    content
+        .push_str("- **NEVER** run `loom stage merge` — the orchestrator merges on approval\n\n");
+
+    append_isolation_boundaries_simple(&mut content);
+    append_path_boundaries(&mut content);
+
+    append_execution_rules_intro(&mut content);
+
+    content.push_str("**Completion:**\n");
+    append_completion_rules(&mut content);
+
+    append_common_footer(&mut content);
+
+    content
+}
+
 /// Select the stable prefix for a stage type.
 ///
 /// Single source of truth shared by the regular signal path (`format/mod.rs`)
@@ -780,6 +834,7 @@ pub(crate) fn stable_prefix_for(stage_type: crate::models::stage::StageType) ->
         StageType::KnowledgeDistill => generate_knowledge_distill_stable_prefix(),
         StageType::Knowledge => generate_knowledge_stable_prefix(),
         StageType::Standard => generate_stable_prefix(),
+        StageType::CodeReview => generate_code_review_stable_prefix(),
     }
 }
 
@@ -1,11 +1,24 @@
 use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
 use std::fs;
 use std::path::Path;
 
 use super::format::format_dependency_table;
 use super::parse::parse_signal_content;
+use super::refresh::refresh_marker_path;
 use super::types::{SignalContent, SignalUpdates};
-
+use crate::secrets::redact;
+
+/// Heading of the change journal that [`update_signal`] appends to
+const UPDATES_SECTION: &str = "## Updates";
+
+/// Record changes to a running session's signal.
+///
+/// Earlier sections are never edited: each call appends a dated entry to
+/// the `## Updates` section at the end of the signal (creating it on first
+/// use). The session's cached prefix stays valid, and the agent can read
+/// just the entries added since it last looked. A refresh marker tells the
+/// PostToolUse hook to point the agent at them.
 pub fn update_signal(session_id: &str, updates: SignalUpdates, work_dir: &Path) -> Result<()> {
     let signal_path = work_dir.join("signals").join(format!("{session_id}.md"));
 
@@ -13,67 +26,79 @@ pub fn update_signal(session_id: &str, updates: SignalUpdates, work_dir: &Path)
         bail!("Signal file does not exist: {}", signal_path.display());
     }
 
-    let content = fs::read_to_string(&signal_path).context("Failed to read signal file")?;
+    let Some(entry) = format_update_entry(&updates, Utc::now()) else {
+        return Ok(());
+    };
 
-    let mut updated_content = content;
-
-    if let Some(tasks) = updates.add_tasks {
-        if !tasks.is_empty() {
-            let task_section = tasks
-                .iter()
-                .enumerate()
-                .map(|(i, task)| format!("{}. {}", i + 1, task))
-                .collect::<Vec<_>>()
-                .join("\n");
-
-            if let Some(pos) = updated_content.find("## Immediate Tasks") {
-                // Find the next section, or append at the end if this is the last section
-                if let Some(next_section) = updated_content[pos..].find("\n\n## ") {
-                    let insert_pos = pos + next_section;
-                    updated_content.insert_str(insert_pos, &format!("\n{task_section}"));
-                } else {
-                    // No next section - append at the end of the file
-                    updated_content.push_str(&format!("\n{task_section}\n"));
-                }
-            }
+    let mut content = fs::read_to_string(&signal_path).context("Failed to read signal file")?;
+    if !content.contains(&format!("\n{UPDATES_SECTION}\n")) {
+        if !content.ends_with('\n') {
+            content.push('\n');
         }
+        content.push_str(&format!(
+            "\n{UPDATES_SECTION}\n\n\
+             Changes made after this signal was written, oldest first. Where an \
+             update disagrees with an earlier section, the update wins.\n"
+        ));
+    }
+    content.push_str(&entry);
+
+    fs::write(&signal_path, redact(&content).as_bytes()).context("Failed to update signal file")?;
+
+    let marker = refresh_marker_path(work_dir, session_id);
+    let mut changed: Vec<String> = fs::read_to_string(&marker)
+        .unwrap_or_default()
+        .lines()
+        .map(String::from)
+        .collect();
+    if !changed.iter().any(|heading| heading == "Updates") {
+        changed.push("Updates".to_string());
     }
+    fs::write(&marker, changed.join("\n"))
+        .with_context(|| format!("Failed to write refresh marker: {}", marker.display()))?;
 
-    if let Some(deps) = updates.update_dependencies {
-        if !deps.is_empty() {
-            let dep_table = format_dependency_table(&deps);
-            if let Some(start) = updated_content.find("## Dependencies Status") {
-                if let Some(table_start) = updated_content[start..].find("| Dependency") {
-                    let abs_table_start = start + table_start;
-                    if let Some(next_section) = updated_content[abs_table_start..].find("\n\n## ") {
-                        let end_pos = abs_table_start + next_section;
-                        updated_content.replace_range(abs_table_start..end_pos, &dep_table);
-                    }
-                }
-            }
+    Ok(())
+}
+
+/// Format one dated `### ` entry of the Updates section, or `None` when
+/// `updates` carries no changes.
+fn format_update_entry(updates: &SignalUpdates, at: DateTime<Utc>) -> Option<String> {
+    let mut body = String::new();
+
+    if let Some(tasks) = updates.add_tasks.as_ref().filter(|t| !t.is_empty()) {
+        body.push_str("**New tasks:**\n\n");
+        for (i, task) in tasks.iter().enumerate() {
+            body.push_str(&format!("{}. {}\n", i + 1, task));
         }
+        body.push('\n');
     }
 
-    if let Some(files) = updates.add_context_files {
-        if !files.is_empty() {
-            let file_list = files
-                .iter()
-                .map(|f| format!("- {f}"))
-                .collect::<Vec<_>>()
-                .join("\n");
-
-            if let Some(pos) = updated_content.find("## Context Restoration") {
-                if let Some(next_section) = updated_content[pos..].find("\n\n## ") {
-                    let insert_pos = pos + next_section;
-                    updated_content.insert_str(insert_pos, &format!("\n{file_list}"));
-                }
-            }
-        }
+    if let Some(deps) = updates
+        .update_dependencies
+        .as_ref()
+        .filter(|d| !d.is_empty())
+    {
+        body.push_str("**Dependency status:**\n\n");
+        body.push_str(&format_dependency_table(deps));
+        body.push('\n');
     }
 
-    fs::write(&signal_path, updated_content).context("Failed to update signal file")?;
+    if let Some(files) = updates.add_context_files.as_ref().filter(|f| !f.is_empty()) {
+        body.push_str("**Additional context files:**\n\n");
+        for file in files {
+            body.push_str(&format!("- `{file}`\n"));
+        }
+        body.push('\n');
+    }
 
-    Ok(())
+    if body.is_empty() {
+        return None;
+    }
+    Some(format!(
+        "\n### {}\n\n{}\n",
+        at.format("%Y-%m-%d %H:%M:%S UTC"),
+        body.trim_end()
+    ))
 }
 
 pub fn remove_signal(session_id: &str, work_dir: &Path) -> Result<()> {
@@ -83,6 +108,8 @@ pub fn remove_signal(session_id: &str, work_dir: &Path) -> Result<()> {
         fs::remove_file(&signal_path)
             .with_context(|| format!("Failed to remove signal file: {}", signal_path.display()))?;
     }
+    // An unconsumed refresh marker is meaningless once the signal is gone
+    let _ = fs::remove_file(super::refresh::refresh_marker_path(work_dir, session_id));
 
     Ok(())
 }
@@ -1,5 +1,7 @@
+use crate::fs::facts::Fact;
+use crate::fs::knowledge::KnowledgeEntry;
 use crate::handoff::schema::HandoffV2;
-use crate::models::stage::Stage;
+use crate::models::stage::{OutputSpec, Stage};
 
 use super::super::types::DependencyStatus;
 
@@ -18,15 +20,94 @@ pub fn format_dependency_table(deps: &[DependencyStatus]) -> String {
     table
 }
 
+/// Format plan-wide facts for inclusion in signals, in the same shape as
+/// dependency outputs:
+/// ```text
+/// - **key**: value
+///   > Description (from PLAN-auth/api, high confidence)
+/// ```
+pub(super) fn format_facts(facts: &[Fact]) -> String {
+    let mut content = String::new();
+
+    for fact in facts {
+        content.push_str(&format!(
+            "- **{}**: {}\n",
+            fact.key,
+            format_signal_value(&fact.value)
+        ));
+        let notes: Vec<String> = fact
+            .source
+            .iter()
+            .map(|source| format!("from {source}"))
+            .chain(fact.confidence.map(|c| format!("{c} confidence")))
+            .collect();
+        let notes = if notes.is_empty() {
+            String::new()
+        } else {
+            format!(" ({})", notes.join(", "))
+        };
+        content.push_str(&format!("  > {}{notes}\n\n", fact.description));
+    }
+
+    content
+}
+
+/// Format unresolved lessons for inclusion in signals, with the first
+/// lines of each body:
+/// ```text
+/// - **[critical]** Title (`k-3f2a9c1b`)
+///   > What went wrong and how to avoid it
+/// ```
+pub(super) fn format_open_mistakes(entries: &[KnowledgeEntry]) -> String {
+    const MAX_BODY_LINES: usize = 3;
+    let mut content = String::new();
+
+    for entry in entries {
+        let severity = entry
+            .severity
+            .map(|s| format!("**[{s}]** "))
+            .unwrap_or_default();
+        content.push_str(&format!("- {severity}{} (`{}`)\n", entry.title, entry.id));
+        for line in entry
+            .body
+            .lines()
+            .filter(|l| !l.trim().is_empty())
+            .take(MAX_BODY_LINES)
+        {
+            content.push_str(&format!("  > {}\n", line.trim()));
+        }
+    }
+
+    content
+}
+
+/// Format a JSON value inline, or as a JSON block for arrays and objects.
+fn format_signal_value(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => format!("`\"{s}\"`"),
+        serde_json::Value::Null => "`null`".to_string(),
+        serde_json::Value::Bool(b) => format!("`{b}`"),
+        serde_json::Value::Number(n) => format!("`{n}`"),
+        serde_json::Value::Array(_) | serde_json::Value::Object(_) => {
+            let json = serde_json::to_string(value).unwrap_or_default();
+            format!("```json\n{json}\n```")
+        }
+    }
+}
+
 /// Format outputs from dependency stages for inclusion in signals.
 ///
 /// This produces a clear, structured format that agents can easily parse:
 /// ```text
 /// ### From stage-name
 ///
-/// - **key**: value
+/// - **key** (`int`): value
 ///   > Description of what this output represents
+/// - **other** (`string`, required): _not set yet_
 /// ```
+///
+/// Types come from the dependency's declared output schema; declared outputs
+/// with no value yet are listed so the agent knows what to expect.
 pub(super) fn format_dependency_outputs(deps: &[&DependencyStatus]) -> String {
     let mut content = String::new();
 
@@ -34,26 +115,84 @@ pub(super) fn format_dependency_outputs(deps: &[&DependencyStatus]) -> String {
         content.push_str(&format!("### From {}\n\n", dep.name));
 
         for output in &dep.outputs {
-            // Format value based on type
-            let value_str = match &output.value {
-                serde_json::Value::String(s) => format!("`\"{s}\"`"),
-                serde_json::Value::Null => "`null`".to_string(),
-                serde_json::Value::Bool(b) => format!("`{b}`"),
-                serde_json::Value::Number(n) => format!("`{n}`"),
-                serde_json::Value::Array(_) | serde_json::Value::Object(_) => {
-                    let json = serde_json::to_string(&output.value).unwrap_or_default();
-                    format!("```json\n{json}\n```")
-                }
-            };
+            let value_str = format_signal_value(&output.value);
 
-            content.push_str(&format!("- **{}**: {}\n", output.key, value_str));
+            let type_str = dep
+                .expected_outputs
+                .iter()
+                .find(|spec| spec.key == output.key)
+                .and_then(|spec| spec.output_type)
+                .map(|t| format!(" (`{t}`)"))
+                .unwrap_or_default();
+
+            content.push_str(&format!("- **{}**{type_str}: {value_str}\n", output.key));
             content.push_str(&format!("  > {}\n\n", output.description));
         }
+
+        for spec in &dep.expected_outputs {
+            if dep.outputs.iter().any(|o| o.key == spec.key) {
+                continue;
+            }
+            content.push_str(&format!(
+                "- **{}** ({}): _not set yet_\n",
+                spec.key,
+                format_output_spec_attrs(spec)
+            ));
+            if let Some(description) = &spec.description {
+                content.push_str(&format!("  > {description}\n"));
+            }
+            content.push('\n');
+        }
+    }
+
+    content
+}
+
+/// Format the outputs a stage's plan declares, for the stage's own signal.
+///
+/// Completion fails while a required output is unset or any output has the
+/// wrong type, so the list ends with the command to set them.
+pub(super) fn format_expected_outputs(stage: &Stage) -> String {
+    let mut content = String::new();
+    content.push_str("Dependent stages read these values. `loom stage complete` fails until\n");
+    content.push_str("every required output is set with its declared type.\n\n");
+
+    for spec in &stage.expected_outputs {
+        content.push_str(&format!(
+            "- **{}** ({})",
+            spec.key,
+            format_output_spec_attrs(spec)
+        ));
+        if let Some(output) = stage.get_output(&spec.key) {
+            content.push_str(&format!(" — set to `{}`", output.value));
+        }
+        content.push('\n');
+        if let Some(description) = &spec.description {
+            content.push_str(&format!("  > {description}\n"));
+        }
     }
 
+    content.push_str(&format!(
+        "\nSet each with: `loom stage output set {id} <key> <value>`, or all at once by \
+         piping a JSON object to `loom stage output set {id} --from-json -`\n",
+        id = stage.id
+    ));
     content
 }
 
+/// `` `type`, required `` annotation for a declared output.
+fn format_output_spec_attrs(spec: &OutputSpec) -> String {
+    let type_str = spec
+        .output_type
+        .map(|t| format!("`{t}`"))
+        .unwrap_or_else(|| "any type".to_string());
+    if spec.required {
+        format!("{type_str}, required")
+    } else {
+        format!("{type_str}, optional")
+    }
+}
+
 /// Extract task list from stage definition
 pub(super) fn extract_tasks_from_stage(stage: &Stage) -> Vec<String> {
     let mut tasks = Vec::new();
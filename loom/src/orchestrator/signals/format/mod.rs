@@ -89,3 +89,31 @@ pub fn format_signal_with_metrics(
 
     FormattedSignal { content, metrics }
 }
+
+/// Heading that opens the dynamic section of a stage signal.
+///
+/// Everything before it (header, stable prefix, semi-stable section) is kept
+/// verbatim when a signal is refreshed.
+pub(super) const DYNAMIC_SECTION_START: &str = "## Target\n\n- **Session**: ";
+
+/// Format only the per-session part of a signal (dynamic + recitation)
+pub(super) fn format_session_sections(
+    session: &Session,
+    stage: &Stage,
+    worktree: &Worktree,
+    dependencies_status: &[DependencyStatus],
+    handoff_file: Option<&str>,
+    embedded_context: &EmbeddedContext,
+) -> String {
+    let dynamic = sections::format_dynamic_section(
+        session,
+        stage,
+        worktree,
+        dependencies_status,
+        handoff_file,
+        None,
+        embedded_context,
+    );
+    let recitation = sections::format_recitation_section(stage, embedded_context);
+    format!("{dynamic}{recitation}")
+}
@@ -7,7 +7,7 @@ use crate::skills::SkillMatch;
 use super::super::types::{DependencyStatus, EmbeddedContext, SandboxSummary};
 use super::helpers::{
     extract_tasks_from_stage, format_dependency_outputs, format_dependency_table,
-    format_structured_handoff,
+    format_expected_outputs, format_facts, format_open_mistakes, format_structured_handoff,
 };
 
 /// SEMI-STABLE section: Changes per stage, not per session
@@ -32,6 +32,16 @@ pub(super) fn format_semi_stable_section(
         content.push_str("```\n\n");
     }
 
+    // Unresolved lessons, so the worst mistakes are not repeated
+    if !embedded_context.open_mistakes.is_empty() {
+        content.push_str("## Known Mistakes\n\n");
+        content.push_str(
+            "Unresolved lessons from `mistakes.md`, most severe first. Do NOT repeat them:\n\n",
+        );
+        content.push_str(&format_open_mistakes(&embedded_context.open_mistakes));
+        content.push('\n');
+    }
+
     // Stage-type-aware reminder boxes
     match stage_type {
         StageType::Knowledge | StageType::IntegrationVerify | StageType::KnowledgeDistill => {
@@ -72,7 +82,7 @@ pub(super) fn format_semi_stable_section(
             );
             content.push_str("```\n\n");
         }
-        StageType::Standard => {
+        StageType::Standard | StageType::CodeReview => {
             // Standard implementation stages: MEMORY ONLY, NO KNOWLEDGE UPDATES
             content.push_str("```text\n");
             content.push_str(
@@ -289,7 +299,7 @@ pub(super) fn format_semi_stable_section(
                 content.push_str("```\n\n");
             }
         }
-        StageType::Standard => {
+        StageType::Standard | StageType::CodeReview => {
             // Standard implementation stages: Show MEMORY guidance instead
             content.push_str("## Stage Memory\n\n");
             content.push_str(
@@ -502,16 +512,29 @@ pub(super) fn format_dynamic_section(
     }
     content.push_str("\n\n");
 
+    // Plan-authored instructions for this stage
+    content.push_str(&super::super::helpers::format_stage_instructions_section(
+        stage,
+    ));
+
+    // Outputs the plan requires this stage to set
+    if !stage.expected_outputs.is_empty() {
+        content.push_str("## Outputs to Set\n\n");
+        content.push_str(&format_expected_outputs(stage));
+        content.push('\n');
+    }
+
     // Dependencies status (dynamic - status changes)
     if !dependencies_status.is_empty() {
         content.push_str("## Dependencies Status\n\n");
         content.push_str(&format_dependency_table(dependencies_status));
         content.push('\n');
 
-        // Include outputs from completed dependencies
+        // Include outputs from completed dependencies, and the outputs they
+        // declare but have not set yet
         let deps_with_outputs: Vec<_> = dependencies_status
             .iter()
-            .filter(|d| !d.outputs.is_empty())
+            .filter(|d| !d.outputs.is_empty() || !d.expected_outputs.is_empty())
             .collect();
 
         if !deps_with_outputs.is_empty() {
@@ -521,6 +544,25 @@ pub(super) fn format_dynamic_section(
         }
     }
 
+    // Forward handoffs: what merged dependencies actually did
+    if !embedded_context.dependency_handoffs.is_empty() {
+        content.push_str("## What Your Dependencies Did\n\n");
+        content.push_str(
+            "Recorded when each dependency merged. Build on this work instead of redoing it:\n\n",
+        );
+        for (stage_id, handoff) in &embedded_context.dependency_handoffs {
+            content.push_str(&format!("<forward-handoff stage=\"{stage_id}\">\n"));
+            content.push_str(handoff.trim_end());
+            content.push_str("\n</forward-handoff>\n\n");
+        }
+    }
+
+    // Plan-wide facts, set directly or imported from an earlier plan
+    if !embedded_context.facts.is_empty() {
+        content.push_str("## Established Facts\n\n");
+        content.push_str(&format_facts(&embedded_context.facts));
+    }
+
     // Embed handoff content if available (previous session context)
     if let Some(parsed) = &embedded_context.parsed_handoff {
         // V2 structured handoff: show structured summary
@@ -673,8 +715,11 @@ pub(super) fn format_recitation_section(
     if let Some(usage) = embedded_context.context_usage {
         if usage >= 75.0 {
             content.push_str("## COMPACTION IMMINENT\n\n");
-            content.push_str("**Context is at critical level.** Run NOW:\n");
-            content.push_str("```\nloom handoff --message \"what I was doing\"\n```\n\n");
+            content.push_str("**Context is at critical level.** Hand off NOW and stop:\n");
+            content.push_str(
+                "```\nloom handoff create --summary \"what I did\" \\\n  \
+                 --remaining \"next task\" --decision \"decision: rationale\"\n```\n\n",
+            );
         } else if usage >= 60.0 {
             content.push_str("## Context Preservation Reminder\n\n");
             content.push_str("Consider creating a handoff to preserve your working state:\n");
@@ -2,17 +2,24 @@ use anyhow::Result;
 use std::fs;
 use std::path::{Path, PathBuf};
 
+use crate::fs::facts::load_facts;
 use crate::fs::knowledge::KnowledgeDir;
-use crate::fs::memory::format_memory_for_signal;
+use crate::fs::memory::{format_memory_for_signal, recitation_keywords};
+use crate::fs::params::{load_params, stage_values};
+use crate::git::branch::branch_name_for_stage;
+use crate::git::worktree::write_stage_claude_md;
 use crate::handoff::git_handoff::GitHistory;
+use crate::handoff::read_forward_handoff;
 use crate::handoff::schema::ParsedHandoff;
 use crate::language::{detect_languages_from_files, DetectedLanguage};
 use crate::models::session::Session;
 use crate::models::stage::{Stage, StageType};
 use crate::models::worktree::Worktree;
+use crate::orchestrator::code_review;
 use crate::plan::schema::CodeReviewConfig;
 use crate::skills::{SkillIndex, SkillMatch, SkillMetadata};
 use crate::verify::transitions::load_stage;
+use crate::verify::DependencyOutputs;
 
 use super::cache::SignalMetrics;
 use super::format::{format_signal_content, format_signal_with_metrics};
@@ -26,6 +33,9 @@ pub const DEFAULT_MAX_SKILL_RECOMMENDATIONS: usize = 5;
 /// language-detected skills appear prominently in recommendations.
 const LANGUAGE_DETECTION_SCORE: f32 = 10.0;
 
+/// Maximum number of unresolved lessons from `mistakes.md` in a signal
+const MAX_SIGNAL_MISTAKES: usize = 5;
+
 pub fn generate_signal(
     session: &Session,
     stage: &Stage,
@@ -61,6 +71,47 @@ pub fn generate_signal_with_skills(
     skill_index: Option<&SkillIndex>,
     detected_languages: &[DetectedLanguage],
 ) -> Result<PathBuf> {
+    let content = render_stage_signal(
+        session,
+        stage,
+        worktree,
+        dependencies_status,
+        handoff_file,
+        git_history,
+        work_dir,
+        skill_index,
+        detected_languages,
+    );
+    let signal_path = super::helpers::write_signal_file(&session.id, &content, work_dir)?;
+
+    // Mirror the essentials into the worktree's CLAUDE.md, which the agent
+    // loads even if it never reads the signal closely.
+    if let Some(repo_root) = work_dir.parent() {
+        if let Err(e) = write_stage_claude_md(&worktree.path, repo_root, stage, &signal_path) {
+            eprintln!(
+                "Warning: Failed to write stage CLAUDE.md for '{}': {e}",
+                stage.id
+            );
+        }
+    }
+    Ok(signal_path)
+}
+
+/// Render the content of a stage signal without writing it
+#[allow(clippy::too_many_arguments)]
+pub(super) fn render_stage_signal(
+    session: &Session,
+    stage: &Stage,
+    worktree: &Worktree,
+    dependencies_status: &[DependencyStatus],
+    handoff_file: Option<&str>,
+    git_history: Option<&GitHistory>,
+    work_dir: &Path,
+    skill_index: Option<&SkillIndex>,
+    detected_languages: &[DetectedLanguage],
+) -> String {
+    let stage = &resolve_output_references(stage, dependencies_status, work_dir);
+
     // Build embedded context with shared setup logic
     let mut embedded_context = build_signal_context(session, stage, work_dir, handoff_file);
 
@@ -111,6 +162,13 @@ pub fn generate_signal_with_skills(
         &embedded_context,
     );
 
+    append_stage_feedback_sections(&mut content, stage, work_dir);
+    content
+}
+
+/// Append the sections that follow the formatted signal body: adjudicator
+/// feedback and integration-verify review dimensions.
+pub(super) fn append_stage_feedback_sections(content: &mut String, stage: &Stage, work_dir: &Path) {
     // Inject adjudicator feedback (if any) for stages that have been
     // disputed. Appended after the formatted sections so it sits near
     // the end where the agent's recitation attention is highest.
@@ -126,14 +184,30 @@ pub fn generate_signal_with_skills(
         }
     }
 
+    // Point code-review stages at the changes they review, and hand a rejected
+    // stage its reviewer's findings (see `orchestrator::code_review`).
+    let review_section = match stage.stage_type {
+        StageType::CodeReview => code_review::render_review_target(work_dir, stage),
+        _ => code_review::render_review_feedback(work_dir, stage),
+    };
+    if let Some(section) = review_section {
+        content.push_str(&section);
+    }
+
     // Surface the plan's structured code-review dimensions to integration-verify
     // agents as an actionable checklist. `code_review` lives only on the plan's
     // `StageDefinition` (not the runtime `Stage`), so we read it back from the
     // plan here — the same source the after-stage checks use at completion time.
     // Gated to integration-verify so the plan is only re-parsed for the (rare)
-    // review-gate spawns, never on every stage spawn.
-    if matches!(stage.stage_type, StageType::IntegrationVerify) {
-        if let Some(section) = load_code_review_for_stage(work_dir, &stage.id)
+    // review-gate spawns, never on every stage spawn. Code-review stages get the
+    // dimensions of the stage they review.
+    let reviewed_stage = match stage.stage_type {
+        StageType::IntegrationVerify => Some(stage.id.as_str()),
+        StageType::CodeReview => stage.parent_stage.as_deref(),
+        _ => None,
+    };
+    if let Some(reviewed_stage) = reviewed_stage {
+        if let Some(section) = load_code_review_for_stage(work_dir, reviewed_stage)
             .and_then(|c| render_review_dimensions(&c))
         {
             content.push_str(&section);
@@ -142,8 +216,32 @@ pub fn generate_signal_with_skills(
             }
         }
     }
+}
 
-    super::helpers::write_signal_file(&session.id, &content, work_dir)
+/// Dependency status read from the stage files, for callers outside the
+/// daemon that have no execution graph (claim, signal refresh).
+pub fn load_dependency_status(stage: &Stage, work_dir: &Path) -> Vec<DependencyStatus> {
+    stage
+        .dependencies
+        .iter()
+        .map(|dep_id| {
+            let (status, outputs, expected_outputs) = match load_stage(dep_id, work_dir) {
+                Ok(dep) => (
+                    format!("{:?}", dep.status),
+                    dep.outputs,
+                    dep.expected_outputs,
+                ),
+                Err(_) => ("Unknown".to_string(), Vec::new(), Vec::new()),
+            };
+            DependencyStatus {
+                stage_id: dep_id.clone(),
+                name: dep_id.clone(),
+                status,
+                outputs,
+                expected_outputs,
+            }
+        })
+        .collect()
 }
 
 /// Load the `code_review` configuration for `stage_id` from the active plan.
@@ -220,6 +318,15 @@ fn build_skill_match_text(stage: &Stage) -> String {
     text
 }
 
+/// Keywords from the stage's name, description and files that memory
+/// recitation ranks entries against
+fn memory_keywords(stage: &Stage) -> Vec<String> {
+    let mut sources = vec![stage.name.as_str()];
+    sources.extend(stage.description.as_deref());
+    sources.extend(stage.files.iter().map(String::as_str));
+    recitation_keywords(&sources)
+}
+
 /// Build embedded context for a stage's memory recitation
 pub(super) fn build_embedded_context_for_stage(
     work_dir: &Path,
@@ -274,13 +381,23 @@ pub fn build_embedded_context_with_stage_and_session(
     let project_root = work_dir.parent().unwrap_or(work_dir);
     let knowledge = KnowledgeDir::new(project_root);
     context.knowledge_has_content = knowledge.has_content();
+    context.open_mistakes = knowledge
+        .unresolved_mistakes(MAX_SIGNAL_MISTAKES)
+        .unwrap_or_default();
 
-    // Read recent memory entries for recitation (Manus pattern - last 10 entries)
+    // Recite the 10 memory entries most relevant to the stage (Manus pattern)
     // This keeps important stage context in the attention window
     if let Some(sid) = stage_id {
-        context.memory_content = format_memory_for_signal(work_dir, sid, 10);
+        let keywords = load_stage(sid, work_dir)
+            .map(|stage| memory_keywords(&stage))
+            .unwrap_or_default();
+        context.memory_content = format_memory_for_signal(work_dir, sid, &keywords, 10);
     }
 
+    context.facts = load_facts(work_dir)
+        .map(|store| store.facts)
+        .unwrap_or_default();
+
     context
 }
 
@@ -385,6 +502,8 @@ pub fn generate_signal_with_metrics(
     git_history: Option<&GitHistory>,
     work_dir: &Path,
 ) -> Result<(PathBuf, SignalMetrics)> {
+    let stage = &resolve_output_references(stage, dependencies_status, work_dir);
+
     // Build embedded context with shared setup logic
     let embedded_context = build_signal_context(session, stage, work_dir, handoff_file);
 
@@ -403,6 +522,28 @@ pub fn generate_signal_with_metrics(
     Ok((signal_path, formatted.metrics))
 }
 
+/// Copy of `stage` whose commands show the dependency outputs
+/// (`{{deps.<stage>.<key>}}`) and run-time parameters (`{{params.<name>}}`)
+/// they reference. References not set yet stay as they are;
+/// `loom stage complete` reports them.
+fn resolve_output_references(
+    stage: &Stage,
+    dependencies_status: &[DependencyStatus],
+    work_dir: &Path,
+) -> Stage {
+    let mut outputs = DependencyOutputs::default();
+    for dep in dependencies_status {
+        outputs.insert(&dep.stage_id, dep.outputs.clone());
+    }
+    outputs.set_params(stage_values(
+        stage,
+        &load_params(work_dir).unwrap_or_default(),
+    ));
+    let mut resolved = stage.clone();
+    outputs.render_stage_lenient(&mut resolved);
+    resolved
+}
+
 /// Build cross-stage change summary for integration-verify stages.
 ///
 /// For each completed dependency, aggregates file assignments and stage metadata
@@ -433,7 +574,7 @@ fn build_cross_stage_summary(work_dir: &Path, stage: &Stage) -> Option<String> {
                     dep_stage.name,
                     format_stage_status(&dep_stage.status)
                 ));
-                summary.push_str(&format!("Branch: loom/{dep_id}\n"));
+                summary.push_str(&format!("Branch: {}\n", branch_name_for_stage(dep_id)));
 
                 if !dep_stage.files.is_empty() {
                     summary.push_str("Files:\n");
@@ -581,7 +722,7 @@ fn build_wiring_checklist(work_dir: &Path, stage: &Stage) -> Option<String> {
 ///
 /// This consolidates the context building, budget, usage, and sandbox setup
 /// that was duplicated between `generate_signal_with_skills` and `generate_signal_with_metrics`.
-fn build_signal_context(
+pub(super) fn build_signal_context(
     session: &Session,
     stage: &Stage,
     work_dir: &Path,
@@ -610,6 +751,15 @@ fn build_signal_context(
     // Propagate the ultracode license so the semi-stable section can gate on it
     embedded_context.ultracode = stage.ultracode;
 
+    // What each merged dependency actually did (written at merge time)
+    embedded_context.dependency_handoffs = stage
+        .dependencies
+        .iter()
+        .filter_map(|dep_id| {
+            read_forward_handoff(work_dir, dep_id).map(|content| (dep_id.clone(), content))
+        })
+        .collect();
+
     // Build integration-verify and knowledge-distill enrichments
     if matches!(
         stage.stage_type,
@@ -9,6 +9,7 @@ use std::fs;
 use std::path::{Path, PathBuf};
 
 use crate::models::stage::Stage;
+use crate::secrets::redact;
 
 /// Write a signal file to the signals directory, creating it if needed.
 ///
@@ -27,7 +28,7 @@ pub(super) fn write_signal_file(
 
     let signal_path = signals_dir.join(format!("{session_id}.md"));
 
-    fs::write(&signal_path, content)
+    fs::write(&signal_path, redact(content).as_bytes())
         .with_context(|| format!("Failed to write signal file: {}", signal_path.display()))?;
 
     Ok(signal_path)
@@ -90,6 +91,19 @@ pub(super) fn format_stage_context_section(stage: &Stage) -> String {
     }
 }
 
+/// Format the "## Stage Instructions" section from the plan's `instructions`.
+///
+/// Returns an empty string if the stage has no (non-blank) instructions.
+/// Shared across standard, knowledge and recovery signal generators.
+pub(super) fn format_stage_instructions_section(stage: &Stage) -> String {
+    match stage.instructions.as_deref().map(str::trim) {
+        Some(instructions) if !instructions.is_empty() => {
+            format!("## Stage Instructions\n\n{instructions}\n\n")
+        }
+        _ => String::new(),
+    }
+}
+
 /// Format the "## Conflicting Files" section as a bullet list of backtick-wrapped paths.
 ///
 /// Shows a fallback message when no files are listed.
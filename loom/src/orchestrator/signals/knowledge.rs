@@ -43,7 +43,7 @@ pub fn generate_knowledge_signal(
 }
 
 /// Format the content for a knowledge stage signal
-fn format_knowledge_signal_content(
+pub(super) fn format_knowledge_signal_content(
     session: &Session,
     stage: &Stage,
     repo_root: &Path,
@@ -83,6 +83,7 @@ fn format_knowledge_signal_content(
         content.push_str("(no description provided)");
     }
     content.push_str("\n\n");
+    content.push_str(&super::helpers::format_stage_instructions_section(stage));
 
     // Dependencies status
     if !dependencies_status.is_empty() {
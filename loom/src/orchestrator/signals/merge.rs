@@ -210,6 +210,12 @@ pub(super) fn format_merge_signal_content(
     // Important notes
     content.push_str("## Important\n\n");
     content.push_str("- Do NOT modify code beyond what's needed for conflict resolution\n");
+    if !conflicting_files.is_empty() {
+        content.push_str(
+            "- Edits are limited to the conflicting files above and test files; \
+             other edits are blocked, and `--resolved` refuses a merge commit that changes them\n",
+        );
+    }
     content.push_str("- Preserve intent from BOTH branches where possible\n");
     content.push_str("- If unclear how to resolve, ask the user for guidance\n");
     content.push_str(&format!(
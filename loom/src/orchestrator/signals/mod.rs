@@ -8,10 +8,12 @@ mod knowledge;
 mod merge;
 mod merge_conflict;
 mod parse;
+mod preview;
 mod recovery;
 mod recovery_format;
 mod recovery_parsing;
 mod recovery_types;
+mod refresh;
 mod types;
 
 #[cfg(test)]
@@ -37,8 +39,10 @@ pub use format::{
 };
 pub use generate::{
     build_embedded_context_with_stage, generate_signal, generate_signal_with_metrics,
-    generate_signal_with_skills, DEFAULT_MAX_SKILL_RECOMMENDATIONS,
+    generate_signal_with_skills, load_dependency_status, DEFAULT_MAX_SKILL_RECOMMENDATIONS,
 };
 pub use knowledge::generate_knowledge_signal;
 pub use merge::{find_live_merge_session_for_stage, generate_merge_signal, read_merge_signal};
 pub use merge_conflict::{generate_merge_conflict_signal, read_merge_conflict_signal};
+pub use preview::{preview_signal, SignalPreview, SignalSectionSize};
+pub use refresh::{refresh_marker_path, refresh_signal, SignalRefresh};
@@ -15,6 +15,7 @@ const EXPECTED_SECTIONS: &[&str] = &[
     "Acceptance Criteria",
     "Files to Modify",
     "Immediate Tasks",
+    "Updates",
 ];
 
 /// Required sections that must be present
@@ -170,6 +171,19 @@ pub fn parse_signal_content(session_id: &str, content: &str) -> Result<SignalCon
                     }
                 }
             }
+            // Tasks and context files added later by `update_signal`
+            "Updates" => {
+                if let Some(task) = trimmed.strip_prefix(|c: char| c.is_ascii_digit()) {
+                    if let Some(t) = task.strip_prefix(". ") {
+                        tasks.push(t.to_string());
+                    }
+                } else if let Some(file) = trimmed
+                    .strip_prefix("- `")
+                    .and_then(|f| f.strip_suffix('`'))
+                {
+                    context_files.push(file.to_string());
+                }
+            }
             "Files to Modify" => {
                 if let Some(file) = trimmed.strip_prefix("- ") {
                     files_to_modify.push(file.to_string());
@@ -0,0 +1,260 @@
+//! Signal preview for tuning plans
+//!
+//! Renders the signal a stage would get if it spawned now, without writing
+//! it, and measures and lints it so plans can be tuned before a session is
+//! spent on them.
+
+use std::path::Path;
+
+use crate::fs::knowledge::KnowledgeDir;
+use crate::fs::params::{load_params, stage_values, ParamValues};
+use crate::git::branch::branch_name_for_stage;
+use crate::git::worktree::get_worktree_path;
+use crate::handoff::find_latest_handoff;
+use crate::language::DetectedLanguage;
+use crate::models::session::Session;
+use crate::models::stage::{Stage, StageType};
+use crate::models::worktree::Worktree;
+use crate::skills::SkillIndex;
+use crate::verify::DependencyOutputs;
+
+use super::generate::{build_embedded_context_for_stage, render_stage_signal};
+use super::knowledge::format_knowledge_signal_content;
+use super::refresh::split_sections;
+use super::types::DependencyStatus;
+
+/// Size of one `##` section of a previewed signal
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignalSectionSize {
+    /// Section heading, or `(header)` for the text before the first heading
+    pub heading: String,
+    pub bytes: usize,
+    /// Estimated token count (approximate: bytes / 4, as in `SignalMetrics`)
+    pub estimated_tokens: usize,
+}
+
+/// A signal rendered for preview, with its section sizes and lint warnings
+#[derive(Debug, Clone)]
+pub struct SignalPreview {
+    /// The signal content, as the session would read it
+    pub content: String,
+    pub sections: Vec<SignalSectionSize>,
+    /// Problems worth fixing in the plan before the stage runs
+    pub warnings: Vec<String>,
+}
+
+impl SignalPreview {
+    /// Estimated token count of the whole signal
+    pub fn estimated_tokens(&self) -> usize {
+        self.content.len() / 4
+    }
+}
+
+/// Render the signal `stage` would get if it spawned now.
+///
+/// Dependency status, outputs, facts, handoffs and memory are read from
+/// `work_dir` exactly as at spawn time; the session ID is a placeholder.
+pub fn preview_signal(
+    stage: &Stage,
+    dependencies_status: &[DependencyStatus],
+    repo_root: &Path,
+    work_dir: &Path,
+    skill_index: Option<&SkillIndex>,
+    detected_languages: &[DetectedLanguage],
+) -> SignalPreview {
+    let handoff_file = find_latest_handoff(&stage.id, work_dir)
+        .ok()
+        .flatten()
+        .and_then(|p| p.file_stem().and_then(|s| s.to_str().map(String::from)));
+
+    let content = if stage.stage_type == StageType::Knowledge {
+        let session = Session::new_knowledge(&stage.id);
+        let embedded_context =
+            build_embedded_context_for_stage(work_dir, handoff_file.as_deref(), &stage.id);
+        format_knowledge_signal_content(
+            &session,
+            stage,
+            repo_root,
+            dependencies_status,
+            &embedded_context,
+        )
+    } else {
+        let mut session = Session::new();
+        session.assign_to_stage(stage.id.clone());
+        let worktree_id = stage.worktree.clone().unwrap_or_else(|| stage.id.clone());
+        let worktree = Worktree::new(
+            worktree_id.clone(),
+            get_worktree_path(&worktree_id, repo_root),
+            branch_name_for_stage(&stage.id),
+        );
+        render_stage_signal(
+            &session,
+            stage,
+            &worktree,
+            dependencies_status,
+            handoff_file.as_deref(),
+            None,
+            work_dir,
+            skill_index,
+            detected_languages,
+        )
+    };
+
+    let sections = section_sizes(&content);
+    let params = stage_values(stage, &load_params(work_dir).unwrap_or_default());
+    let warnings = lint_stage(
+        stage,
+        dependencies_status,
+        &params,
+        KnowledgeDir::new(repo_root).has_content(),
+    );
+    SignalPreview {
+        content,
+        sections,
+        warnings,
+    }
+}
+
+/// Sizes of the header and each `##` section of a signal, in order.
+fn section_sizes(content: &str) -> Vec<SignalSectionSize> {
+    let size = |heading: &str, text: &str| SignalSectionSize {
+        heading: heading.to_string(),
+        bytes: text.len(),
+        estimated_tokens: text.len() / 4,
+    };
+
+    let header_len = if content.starts_with("## ") {
+        0
+    } else {
+        content.find("\n## ").map_or(content.len(), |i| i + 1)
+    };
+
+    let mut sizes = Vec::new();
+    if header_len > 0 {
+        sizes.push(size("(header)", &content[..header_len]));
+    }
+    sizes.extend(
+        split_sections(content)
+            .into_iter()
+            .map(|(heading, body)| size(heading, body)),
+    );
+    sizes
+}
+
+/// Problems in a stage's definition that leave its signal weaker than it
+/// should be.
+fn lint_stage(
+    stage: &Stage,
+    dependencies_status: &[DependencyStatus],
+    params: &ParamValues,
+    knowledge_has_content: bool,
+) -> Vec<String> {
+    let mut warnings = Vec::new();
+
+    if stage.acceptance.is_empty() && stage.stage_type != StageType::CodeReview {
+        warnings.push(
+            "no acceptance criteria: `loom stage complete` has nothing to verify the work with"
+                .to_string(),
+        );
+    }
+    if stage
+        .description
+        .as_deref()
+        .is_none_or(|d| d.trim().is_empty())
+    {
+        warnings.push("no description: the Assignment section only names the stage".to_string());
+    }
+    if !knowledge_has_content && stage.stage_type != StageType::Knowledge {
+        warnings.push(
+            "doc/loom/knowledge/ is empty: the agent starts without project knowledge \
+             (run a knowledge-bootstrap stage first)"
+                .to_string(),
+        );
+    }
+
+    for dep in dependencies_status {
+        if dep.status != "Completed" && dep.status != "Skipped" {
+            warnings.push(format!(
+                "dependency '{}' is {}: the stage cannot start yet and its outputs are missing",
+                dep.stage_id, dep.status
+            ));
+        }
+    }
+    let mut outputs = DependencyOutputs::default();
+    for dep in dependencies_status {
+        outputs.insert(&dep.stage_id, dep.outputs.clone());
+    }
+    outputs.set_params(params.clone());
+    if let Err(e) = outputs.render_stage(&mut stage.clone()) {
+        warnings.push(format!("unresolved reference: {e:#}"));
+    }
+
+    warnings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::stage::{AcceptanceCriterion, StageOutput};
+    use serde_json::json;
+
+    fn dep(status: &str, outputs: Vec<StageOutput>) -> DependencyStatus {
+        DependencyStatus {
+            stage_id: "api".to_string(),
+            name: "api".to_string(),
+            status: status.to_string(),
+            outputs,
+            expected_outputs: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_section_sizes_cover_whole_signal() {
+        let content = "# Signal: s\n\nintro\n\n## Target\n\nabcd\n\n## Assignment\n\nwork\n";
+        let sizes = section_sizes(content);
+        let headings: Vec<_> = sizes.iter().map(|s| s.heading.as_str()).collect();
+        assert_eq!(headings, ["(header)", "Target", "Assignment"]);
+        assert_eq!(sizes.iter().map(|s| s.bytes).sum::<usize>(), content.len());
+        assert_eq!(sizes[0].bytes, "# Signal: s\n\nintro\n\n".len());
+    }
+
+    #[test]
+    fn test_lint_stage() {
+        let mut stage = Stage::new("web".to_string(), None);
+        stage.dependencies = vec!["api".to_string()];
+        stage.add_acceptance_criterion(AcceptanceCriterion::Simple(
+            "curl localhost:{{deps.api.port}}".to_string(),
+        ));
+
+        let warnings = lint_stage(
+            &stage,
+            &[dep("Executing", Vec::new())],
+            &ParamValues::new(),
+            false,
+        );
+        assert_eq!(warnings.len(), 4, "{warnings:?}");
+        assert!(warnings[0].starts_with("no description"));
+        assert!(warnings[1].starts_with("doc/loom/knowledge/ is empty"));
+        assert!(warnings[2].starts_with("dependency 'api' is Executing"));
+        assert!(warnings[3].starts_with("unresolved reference"));
+
+        stage.description = Some("Build the web UI".to_string());
+        let port = StageOutput {
+            key: "port".to_string(),
+            value: json!(8080),
+            description: String::new(),
+        };
+        assert!(lint_stage(
+            &stage,
+            &[dep("Completed", vec![port])],
+            &ParamValues::new(),
+            true
+        )
+        .is_empty());
+
+        stage.acceptance.clear();
+        let warnings = lint_stage(&stage, &[], &ParamValues::new(), true);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].starts_with("no acceptance criteria"));
+    }
+}
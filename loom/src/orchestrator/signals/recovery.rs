@@ -168,4 +168,56 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_recovery_signal_tailors_actions_to_failure_class() -> Result<()> {
+        use crate::models::failure::{FailureInfo, FailureType};
+        use crate::orchestrator::signals::recovery_types::RecoverySignalContent;
+        let tmp = TempDir::new()?;
+        let work_dir = tmp.path();
+        fs::create_dir_all(work_dir.join("signals"))?;
+
+        let failure = FailureInfo {
+            failure_type: FailureType::BuildFailure,
+            detected_at: Utc::now(),
+            evidence: vec![
+                "FAILED: cargo test".to_string(),
+                "error[E0425]: cannot find value `x`".to_string(),
+            ],
+        };
+        let content = RecoverySignalContent::for_manual(
+            "session-recovery".to_string(),
+            "test-stage".to_string(),
+            "session-old".to_string(),
+            1,
+        )
+        .with_failure_info(&failure);
+
+        let path = generate_recovery_signal(&content, &create_test_stage(), work_dir)?;
+        let signal_content = fs::read_to_string(&path)?;
+        assert!(signal_content.contains("- **Failure Class**: Compile error"));
+        assert!(signal_content.contains("### Failure Evidence"));
+        assert!(signal_content.contains("- error[E0425]: cannot find value `x`"));
+        assert!(signal_content.contains("read the FIRST compiler error"));
+        assert!(!signal_content.contains("Continue from where the previous session left off"));
+
+        // Unknown failures keep the reason's generic actions
+        let unknown = FailureInfo {
+            failure_type: FailureType::Unknown,
+            ..failure
+        };
+        let content = RecoverySignalContent::for_manual(
+            "session-recovery".to_string(),
+            "test-stage".to_string(),
+            "session-old".to_string(),
+            1,
+        )
+        .with_failure_info(&unknown);
+        assert!(content
+            .recovery_actions
+            .iter()
+            .any(|a| a.contains("Continue from where the previous session left off")));
+
+        Ok(())
+    }
 }
@@ -1,5 +1,6 @@
 //! Formatting for recovery signal markdown files.
 
+use crate::git::branch::branch_name_for_stage;
 use crate::models::stage::Stage;
 
 use super::cache::stable_prefix_for;
@@ -38,9 +39,22 @@ pub fn format_recovery_signal(
     if let Some(ref crash_path) = content.crash_report_path {
         signal.push_str(&format!("- **Crash Report**: {}\n", crash_path.display()));
     }
+    if let Some(ref failure) = content.failure {
+        signal.push_str(&format!("- **Failure Class**: {}\n", failure.failure_type));
+    }
 
     signal.push('\n');
 
+    if let Some(ref failure) = content.failure {
+        if !failure.evidence.is_empty() {
+            signal.push_str("### Failure Evidence\n\n");
+            for line in &failure.evidence {
+                signal.push_str(&format!("- {line}\n"));
+            }
+            signal.push('\n');
+        }
+    }
+
     // Last heartbeat info
     if let Some(ref hb) = content.last_heartbeat {
         signal.push_str("### Last Known State\n\n");
@@ -84,7 +98,10 @@ pub fn format_recovery_signal(
     if let Some(ref worktree) = stage.worktree {
         signal.push_str(&format!("- **Worktree**: {worktree}\n"));
     }
-    signal.push_str(&format!("- **Branch**: loom/{}\n", content.stage_id));
+    signal.push_str(&format!(
+        "- **Branch**: {}\n",
+        branch_name_for_stage(&content.stage_id)
+    ));
     signal.push('\n');
 
     // Assignment from stage
@@ -93,6 +110,7 @@ pub fn format_recovery_signal(
     if let Some(ref desc) = stage.description {
         signal.push_str(&format!("{desc}\n\n"));
     }
+    signal.push_str(&super::helpers::format_stage_instructions_section(stage));
 
     // Acceptance criteria
     if !stage.acceptance.is_empty() {
@@ -73,6 +73,7 @@ pub fn read_recovery_signal(
         crash_report_path,
         recovery_actions,
         recovery_attempt,
+        failure: None,
     }))
 }
 
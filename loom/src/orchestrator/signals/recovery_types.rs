@@ -4,6 +4,8 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
+use crate::models::failure::{FailureInfo, FailureType};
+
 /// Type of recovery being initiated
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
@@ -50,6 +52,8 @@ pub struct RecoverySignalContent {
     pub recovery_actions: Vec<String>,
     /// How many times this stage has been recovered
     pub recovery_attempt: u32,
+    /// Classified failure of the previous attempt (if known)
+    pub failure: Option<FailureInfo>,
 }
 
 /// Information from the last heartbeat
@@ -88,6 +92,7 @@ impl RecoverySignalContent {
                 "If the issue persists, check for environmental problems".to_string(),
             ],
             recovery_attempt,
+            failure: None,
         }
     }
 
@@ -120,6 +125,7 @@ impl RecoverySignalContent {
             crash_report_path: None,
             recovery_actions,
             recovery_attempt,
+            failure: None,
         }
     }
 
@@ -150,6 +156,7 @@ impl RecoverySignalContent {
                 "Prioritize completing remaining tasks efficiently".to_string(),
             ],
             recovery_attempt,
+            failure: None,
         }
     }
 
@@ -174,7 +181,21 @@ impl RecoverySignalContent {
                 "Continue from where the previous session left off".to_string(),
             ],
             recovery_attempt,
+            failure: None,
+        }
+    }
+
+    /// Attach the previous attempt's classified failure.
+    ///
+    /// Replaces the reason's generic recovery actions with instructions
+    /// tailored to the failure class. Unknown failures keep the generic
+    /// actions.
+    pub fn with_failure_info(mut self, failure: &FailureInfo) -> Self {
+        if let Some(actions) = recovery_actions_for_failure(&failure.failure_type) {
+            self.recovery_actions = actions;
         }
+        self.failure = Some(failure.clone());
+        self
     }
 
     /// Set custom recovery actions
@@ -188,3 +209,56 @@ impl RecoverySignalContent {
         self.recovery_actions.push(action);
     }
 }
+
+/// Recovery instructions for a failure class, or `None` when the class has
+/// no specific guidance and the reason's generic actions should be used.
+pub fn recovery_actions_for_failure(failure_type: &FailureType) -> Option<Vec<String>> {
+    let actions: &[&str] = match failure_type {
+        FailureType::BuildFailure => &[
+            "Re-run the build and read the FIRST compiler error; later errors often cascade from it",
+            "Fix the reported file and line before touching anything else",
+            "Rebuild until it compiles cleanly, then re-run the acceptance criteria",
+        ],
+        FailureType::TestFailure => &[
+            "Re-run only the failing test(s) listed in the evidence to reproduce",
+            "Decide whether the implementation or the test expectation is wrong before editing",
+            "Fix the root cause, then run the full acceptance criteria",
+        ],
+        FailureType::CodeError => &[
+            "Run the linter or type checker named in the evidence and fix each reported issue",
+            "Do not silence warnings with allow attributes or ignore comments",
+            "Re-run the acceptance criteria once the checker is clean",
+        ],
+        FailureType::MergeConflict => &[
+            "Run `git status` to list conflicted files",
+            "Resolve each conflict keeping both sides' intent; do not discard upstream changes",
+            "Build and test after resolving, then commit the resolution",
+        ],
+        FailureType::SessionCrash => &[
+            "Review the crash report for the last command the agent ran",
+            "Check `git status` and `git diff` for partially applied work before continuing",
+            "Avoid repeating the exact operation that crashed; break it into smaller steps",
+        ],
+        FailureType::ContextExhausted => &[
+            "Read the handoff file carefully for context",
+            "Continue from the documented progress without re-reading files already summarized",
+            "Keep tool output small: filter logs and read files in ranges",
+        ],
+        FailureType::Timeout => &[
+            "Identify the command that timed out from the evidence",
+            "Check for hangs: interactive prompts, network access, or watch modes",
+            "Run long commands with narrower scope (single test, single package)",
+        ],
+        FailureType::InfrastructureError => &[
+            "Verify the worktree exists and `git status` works before editing",
+            "Re-run the failed setup step and check its log",
+            "If the environment is broken, block the stage with a clear reason instead of working around it",
+        ],
+        // Pre-flight failures are fixed in the plan or environment before any
+        // session runs, so a recovery session has nothing to act on
+        FailureType::UserBlocked | FailureType::PreflightFailed | FailureType::Unknown => {
+            return None
+        }
+    };
+    Some(actions.iter().map(|a| a.to_string()).collect())
+}
@@ -0,0 +1,204 @@
+//! Signal refresh for long-running sessions
+//!
+//! A signal is written once, when its session spawns. Dependency outputs,
+//! facts, handoffs and stage memory keep changing while the session runs, so
+//! a refresh regenerates the dynamic and recitation sections from current
+//! state. The header, stable prefix and semi-stable sections are kept
+//! byte-for-byte so the session's cached prefix stays valid.
+//!
+//! The `## Updates` change journal appended by `update_signal` is carried
+//! over unchanged; it records changes a refresh cannot regenerate.
+//!
+//! After a refresh that changed anything, a `<session-id>.refreshed` marker
+//! is written next to the signal; the PostToolUse hook picks it up and tells
+//! the running agent to re-read its signal.
+
+use anyhow::{bail, Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::git::branch::branch_name_for_stage;
+use crate::git::worktree::get_worktree_path;
+use crate::handoff::find_latest_handoff;
+use crate::models::session::Session;
+use crate::models::stage::{Stage, StageType};
+use crate::models::worktree::Worktree;
+use crate::secrets::redact;
+
+use super::format::{format_session_sections, DYNAMIC_SECTION_START};
+use super::generate::{
+    append_stage_feedback_sections, build_embedded_context_for_stage, build_signal_context,
+};
+use super::knowledge::format_knowledge_signal_content;
+use super::types::DependencyStatus;
+
+/// Outcome of refreshing a signal
+#[derive(Debug, Clone)]
+pub struct SignalRefresh {
+    /// Path of the rewritten signal file
+    pub path: PathBuf,
+    /// Headings of the `##` sections that were added, changed or removed
+    pub changed_sections: Vec<String>,
+}
+
+/// Path of the marker the PostToolUse hook watches for a session.
+pub fn refresh_marker_path(work_dir: &Path, session_id: &str) -> PathBuf {
+    work_dir
+        .join("signals")
+        .join(format!("{session_id}.refreshed"))
+}
+
+/// Regenerate the per-session sections of an existing stage signal.
+///
+/// Only stage signals (`# Signal: ...`) can be refreshed; recovery, merge and
+/// conflict signals describe a one-off situation and are regenerated by the
+/// orchestrator instead.
+pub fn refresh_signal(
+    session: &Session,
+    stage: &Stage,
+    dependencies_status: &[DependencyStatus],
+    repo_root: &Path,
+    work_dir: &Path,
+) -> Result<SignalRefresh> {
+    let path = work_dir.join("signals").join(format!("{}.md", session.id));
+    let old = fs::read_to_string(&path)
+        .with_context(|| format!("No signal found for session '{}'", session.id))?;
+    if !old.starts_with("# Signal: ") {
+        bail!(
+            "Signal for session '{}' is not a stage signal and cannot be refreshed",
+            session.id
+        );
+    }
+
+    let handoff_file = find_latest_handoff(&stage.id, work_dir)
+        .ok()
+        .flatten()
+        .and_then(|p| p.file_stem().and_then(|s| s.to_str().map(String::from)));
+
+    let mut content = if stage.stage_type == StageType::Knowledge {
+        let embedded_context =
+            build_embedded_context_for_stage(work_dir, handoff_file.as_deref(), &stage.id);
+        format_knowledge_signal_content(
+            session,
+            stage,
+            repo_root,
+            dependencies_status,
+            &embedded_context,
+        )
+    } else {
+        let Some(split) = old.find(DYNAMIC_SECTION_START) else {
+            bail!(
+                "Signal {} has no dynamic section to refresh; restart the stage to regenerate it",
+                path.display()
+            );
+        };
+        let worktree_id = stage.worktree.clone().unwrap_or_else(|| stage.id.clone());
+        let worktree_path = session
+            .worktree_path
+            .clone()
+            .unwrap_or_else(|| get_worktree_path(&worktree_id, repo_root));
+        let worktree = Worktree::new(worktree_id, worktree_path, branch_name_for_stage(&stage.id));
+        let embedded_context =
+            build_signal_context(session, stage, work_dir, handoff_file.as_deref());
+
+        let mut content = old[..split].to_string();
+        content.push_str(&format_session_sections(
+            session,
+            stage,
+            &worktree,
+            dependencies_status,
+            handoff_file.as_deref(),
+            &embedded_context,
+        ));
+        append_stage_feedback_sections(&mut content, stage, work_dir);
+        content
+    };
+
+    if let Some((_, updates)) = split_sections(&old)
+        .into_iter()
+        .find(|(heading, _)| *heading == "Updates")
+    {
+        if !content.ends_with('\n') {
+            content.push('\n');
+        }
+        content.push('\n');
+        content.push_str(updates);
+    }
+
+    // The signal on disk is redacted; compare like with like so sections
+    // holding a secret are not reported as changed on every refresh
+    let content = redact(&content).into_owned();
+    let changed_sections = changed_sections(&old, &content);
+    let path = super::helpers::write_signal_file(&session.id, &content, work_dir)?;
+    if !changed_sections.is_empty() {
+        let marker = refresh_marker_path(work_dir, &session.id);
+        fs::write(&marker, changed_sections.join("\n"))
+            .with_context(|| format!("Failed to write refresh marker: {}", marker.display()))?;
+    }
+
+    Ok(SignalRefresh {
+        path,
+        changed_sections,
+    })
+}
+
+/// Split a signal into `(heading, body)` pairs at each `## ` heading.
+pub(super) fn split_sections(content: &str) -> Vec<(&str, &str)> {
+    let mut starts: Vec<usize> = content.match_indices("\n## ").map(|(i, _)| i + 1).collect();
+    if content.starts_with("## ") {
+        starts.insert(0, 0);
+    }
+
+    starts
+        .iter()
+        .enumerate()
+        .map(|(i, &start)| {
+            let end = starts.get(i + 1).copied().unwrap_or(content.len());
+            let section = &content[start..end];
+            let heading = section.lines().next().unwrap_or_default();
+            (heading.trim_start_matches("## ").trim(), section)
+        })
+        .collect()
+}
+
+/// Headings of sections that differ between two versions of a signal.
+///
+/// Sections present in `new` are listed in order when added or changed;
+/// sections that only exist in `old` follow, suffixed with `(removed)`.
+fn changed_sections(old: &str, new: &str) -> Vec<String> {
+    let old_sections = split_sections(old);
+    let new_sections = split_sections(new);
+
+    let mut changed: Vec<String> = new_sections
+        .iter()
+        .filter(|section| !old_sections.contains(section))
+        .map(|(heading, _)| heading.to_string())
+        .collect();
+    changed.extend(
+        old_sections
+            .iter()
+            .filter(|(heading, _)| !new_sections.iter().any(|(h, _)| h == heading))
+            .map(|(heading, _)| format!("{heading} (removed)")),
+    );
+    changed.dedup();
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_changed_sections_reports_added_changed_and_removed() {
+        let old =
+            "# Signal: s\n\n## Target\n\na\n\n## Dependency Outputs\n\nport=1\n\n## Stale\n\nx\n";
+        let new =
+            "# Signal: s\n\n## Target\n\na\n\n## Dependency Outputs\n\nport=2\n\n## Handoff\n\nh\n";
+
+        assert_eq!(
+            changed_sections(old, new),
+            vec!["Dependency Outputs", "Handoff", "Stale (removed)"]
+        );
+        assert!(changed_sections(new, new).is_empty());
+    }
+}
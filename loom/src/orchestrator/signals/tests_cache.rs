@@ -3,6 +3,7 @@
 use std::fs;
 use tempfile::TempDir;
 
+use crate::fs::knowledge::{KnowledgeEntry, Severity};
 use crate::models::stage::StageType;
 
 use super::super::cache::{compute_hash, generate_stable_prefix, SignalMetrics};
@@ -258,6 +259,48 @@ fn test_signal_contains_knowledge_management_section_for_knowledge_stages() {
     assert!(content.contains("loom knowledge update conventions"));
 }
 
+#[test]
+fn test_signal_lists_open_mistakes_in_semi_stable_section() {
+    let session = create_test_session();
+    let stage = create_test_stage();
+    let worktree = create_test_worktree();
+    let critical = KnowledgeEntry::new("Dropped the merge lock", "- hold it\n- always")
+        .with_severity(Some(Severity::Critical));
+    let embedded_context = EmbeddedContext {
+        knowledge_has_content: true,
+        open_mistakes: vec![critical.clone()],
+        ..Default::default()
+    };
+
+    let content = format_signal_content(
+        &session,
+        &stage,
+        &worktree,
+        &[],
+        None,
+        None,
+        &embedded_context,
+    );
+
+    let section = content.find("## Known Mistakes").expect("mistakes section");
+    assert!(content.find("## Knowledge Base").unwrap() < section);
+    assert!(content.contains(&format!(
+        "- **[critical]** Dropped the merge lock (`{}`)\n  > - hold it\n  > - always\n",
+        critical.id
+    )));
+
+    let without = format_signal_content(
+        &session,
+        &stage,
+        &worktree,
+        &[],
+        None,
+        None,
+        &EmbeddedContext::default(),
+    );
+    assert!(!without.contains("## Known Mistakes"));
+}
+
 #[test]
 fn test_signal_contains_delegation_choices_three_way() {
     let session = create_test_session();
@@ -1,6 +1,8 @@
+use crate::fs::facts::Fact;
+use crate::fs::knowledge::KnowledgeEntry;
 use crate::handoff::git_handoff::GitHistory;
 use crate::handoff::schema::HandoffV2;
-use crate::models::stage::StageOutput;
+use crate::models::stage::{OutputSpec, StageOutput};
 use crate::skills::SkillMatch;
 
 /// Summary of sandbox restrictions for signal display
@@ -47,6 +49,12 @@ pub struct EmbeddedContext {
     pub wiring_checklist: Option<String>,
     /// Whether the stage is licensed for ultracode Workflow orchestration
     pub ultracode: bool,
+    /// Plan-wide facts from `.work/facts.json`
+    pub facts: Vec<Fact>,
+    /// Unresolved warn and critical lessons from `mistakes.md`, critical first
+    pub open_mistakes: Vec<KnowledgeEntry>,
+    /// Forward handoffs of merged dependencies, as (stage id, markdown)
+    pub dependency_handoffs: Vec<(String, String)>,
 }
 
 #[derive(Debug, Clone)]
@@ -56,6 +64,8 @@ pub struct DependencyStatus {
     pub status: String,
     /// Outputs from the completed dependency stage
     pub outputs: Vec<StageOutput>,
+    /// Outputs the plan declares the dependency produces
+    pub expected_outputs: Vec<OutputSpec>,
 }
 
 #[derive(Debug, Clone)]
@@ -2,12 +2,27 @@
 //!
 //! This module provides functionality to skip stages that are blocked or waiting.
 //! Skipped stages are marked as such and do not satisfy dependencies for downstream stages.
+//!
+//! Cancelling is a skip from any unfinished status, with reason
+//! [`CANCEL_REASON`], that also settles the stage's dependents (see
+//! [`cancel_stage`]).
+//!
+//! The one skip that does satisfy dependencies is loom's own, for a
+//! `skip_if_satisfied` stage whose checks already pass on its base (see
+//! [`skip_satisfied_stage`]).
 
 use anyhow::{bail, Result};
+use std::collections::HashSet;
 use std::path::Path;
 
-use crate::models::stage::StageStatus;
-use crate::verify::transitions::{load_stage, save_stage};
+use crate::models::stage::{Stage, StageStatus};
+use crate::verify::transitions::{list_all_stages, load_stage, save_stage};
+
+/// `close_reason` of a cancelled stage.
+pub const CANCEL_REASON: &str = "cancelled";
+
+/// `close_reason` of a stage skipped because its work was already done.
+pub const SATISFIED_REASON: &str = "already satisfied";
 
 /// Skip a stage that is blocked, waiting for dependencies, or queued.
 ///
@@ -59,6 +74,126 @@ pub fn skip_stage(stage_id: &str, reason: Option<String>, work_dir: &Path) -> Re
     Ok(())
 }
 
+/// Skip a queued stage whose acceptance already passes on its base.
+///
+/// Nothing is left to merge, so the stage is recorded as merged: unlike any
+/// other skip, it satisfies its dependents' dependency on it.
+pub fn skip_satisfied_stage(stage: &mut Stage) -> Result<()> {
+    stage.try_skip(Some(SATISFIED_REASON.to_string()))?;
+    stage.merged = true;
+    Ok(())
+}
+
+/// What [`cancel_stage`] changed.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CancelOutcome {
+    /// Session the stage was assigned when cancelled (still to be killed)
+    pub session: Option<String>,
+    /// Status the stage was cancelled from
+    pub previous_status: Option<StageStatus>,
+    /// Unfinished transitive dependents, which can no longer run
+    pub dependents: Vec<String>,
+    /// Dependents cancelled along with the stage (`cascade`)
+    pub cancelled_dependents: Vec<String>,
+}
+
+/// Cancel a stage: mark it Skipped with reason [`CANCEL_REASON`] from any
+/// status except Completed and Skipped, then recompute its dependents.
+///
+/// A skipped stage never satisfies a dependency, so every unfinished
+/// transitive dependent is now unreachable. With `cascade` they are cancelled
+/// too; otherwise any that were queued go back to WaitingForDeps and they are
+/// listed in the outcome for the caller to report.
+///
+/// The stage's session is not touched; the caller kills it after this
+/// returns, so a crash detected by the daemon finds the stage already
+/// Skipped and does not schedule a retry.
+pub fn cancel_stage(stage_id: &str, cascade: bool, work_dir: &Path) -> Result<CancelOutcome> {
+    let mut stage = load_stage(stage_id, work_dir)?;
+    if matches!(stage.status, StageStatus::Completed | StageStatus::Skipped) {
+        bail!("Cannot cancel stage in status: {}", stage.status);
+    }
+
+    let mut outcome = CancelOutcome {
+        session: stage.session.clone(),
+        previous_status: Some(stage.status.clone()),
+        ..Default::default()
+    };
+    mark_cancelled(&mut stage, CANCEL_REASON.to_string())?;
+    save_stage(&stage, work_dir)?;
+
+    let stages = list_all_stages(work_dir)?;
+    for mut dependent in transitive_dependents(stage_id, stages) {
+        if matches!(
+            dependent.status,
+            StageStatus::Completed | StageStatus::Skipped
+        ) {
+            continue;
+        }
+        outcome.dependents.push(dependent.id.clone());
+        if cascade {
+            mark_cancelled(
+                &mut dependent,
+                format!("{CANCEL_REASON}: depends on cancelled stage '{stage_id}'"),
+            )?;
+            save_stage(&dependent, work_dir)?;
+            outcome.cancelled_dependents.push(dependent.id.clone());
+        } else if dependent.status == StageStatus::Queued {
+            // INTENTIONAL STATE MACHINE BYPASS: a queued stage whose
+            // dependency was cancelled is waiting again (Queued has no
+            // transition back to WaitingForDeps).
+            dependent.status = StageStatus::WaitingForDeps;
+            dependent.updated_at = chrono::Utc::now();
+            save_stage(&dependent, work_dir)?;
+        }
+    }
+
+    Ok(outcome)
+}
+
+/// Move `stage` to Skipped with `reason`, through the state machine where a
+/// path exists.
+fn mark_cancelled(stage: &mut Stage, reason: String) -> Result<()> {
+    if stage.status == StageStatus::Executing {
+        stage.accumulate_attempt_time(chrono::Utc::now());
+        stage.try_mark_blocked()?;
+    }
+    if stage.status.can_transition_to(&StageStatus::Skipped) {
+        stage.try_skip(Some(reason))?;
+    } else {
+        // INTENTIONAL STATE MACHINE BYPASS: statuses such as NeedsHandoff,
+        // MergeConflict or NeedsHumanReview have no path to Skipped, but
+        // cancelling is an explicit user decision to stop the stage.
+        stage.status = StageStatus::Skipped;
+        stage.close_reason = Some(reason);
+        stage.updated_at = chrono::Utc::now();
+    }
+    stage.held = false;
+    Ok(())
+}
+
+/// Stages that depend on `stage_id`, directly or transitively, by id.
+fn transitive_dependents(stage_id: &str, stages: Vec<Stage>) -> Vec<Stage> {
+    let mut affected: HashSet<String> = HashSet::from([stage_id.to_string()]);
+    loop {
+        let before = affected.len();
+        for stage in &stages {
+            if stage.dependencies.iter().any(|dep| affected.contains(dep)) {
+                affected.insert(stage.id.clone());
+            }
+        }
+        if affected.len() == before {
+            break;
+        }
+    }
+    let mut dependents: Vec<Stage> = stages
+        .into_iter()
+        .filter(|stage| stage.id != stage_id && affected.contains(&stage.id))
+        .collect();
+    dependents.sort_by(|a, b| a.id.cmp(&b.id));
+    dependents
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -172,4 +307,74 @@ mod tests {
             "updated_at should be newer after skip"
         );
     }
+
+    #[test]
+    fn test_cancel_stage_from_executing_recomputes_dependents() {
+        let temp_dir = TempDir::new().unwrap();
+        let work_dir = temp_dir.path();
+
+        let mut stage = create_test_stage("stage-1", "Stage 1", StageStatus::Executing);
+        stage.session = Some("session-1".to_string());
+        save_stage(&stage, work_dir).unwrap();
+        let mut child = create_test_stage("stage-2", "Stage 2", StageStatus::Queued);
+        child.dependencies = vec!["stage-1".to_string()];
+        save_stage(&child, work_dir).unwrap();
+        let mut grandchild = create_test_stage("stage-3", "Stage 3", StageStatus::WaitingForDeps);
+        grandchild.dependencies = vec!["stage-2".to_string()];
+        save_stage(&grandchild, work_dir).unwrap();
+        save_stage(
+            &create_test_stage("other", "Other", StageStatus::Queued),
+            work_dir,
+        )
+        .unwrap();
+
+        let outcome = cancel_stage("stage-1", false, work_dir).unwrap();
+        assert_eq!(outcome.session.as_deref(), Some("session-1"));
+        assert_eq!(outcome.previous_status, Some(StageStatus::Executing));
+        assert_eq!(outcome.dependents, vec!["stage-2", "stage-3"]);
+        assert!(outcome.cancelled_dependents.is_empty());
+
+        let cancelled = load_stage("stage-1", work_dir).unwrap();
+        assert_eq!(cancelled.status, StageStatus::Skipped);
+        assert_eq!(cancelled.close_reason.as_deref(), Some(CANCEL_REASON));
+        assert_eq!(
+            load_stage("stage-2", work_dir).unwrap().status,
+            StageStatus::WaitingForDeps
+        );
+        assert_eq!(
+            load_stage("other", work_dir).unwrap().status,
+            StageStatus::Queued
+        );
+
+        assert!(cancel_stage("stage-1", false, work_dir).is_err());
+    }
+
+    #[test]
+    fn test_cancel_stage_cascade() {
+        let temp_dir = TempDir::new().unwrap();
+        let work_dir = temp_dir.path();
+
+        save_stage(
+            &create_test_stage("stage-1", "Stage 1", StageStatus::NeedsHumanReview),
+            work_dir,
+        )
+        .unwrap();
+        let mut child = create_test_stage("stage-2", "Stage 2", StageStatus::WaitingForDeps);
+        child.dependencies = vec!["stage-1".to_string()];
+        save_stage(&child, work_dir).unwrap();
+
+        let outcome = cancel_stage("stage-1", true, work_dir).unwrap();
+        assert_eq!(outcome.cancelled_dependents, vec!["stage-2"]);
+
+        assert_eq!(
+            load_stage("stage-1", work_dir).unwrap().status,
+            StageStatus::Skipped
+        );
+        let child = load_stage("stage-2", work_dir).unwrap();
+        assert_eq!(child.status, StageStatus::Skipped);
+        assert_eq!(
+            child.close_reason.as_deref(),
+            Some("cancelled: depends on cancelled stage 'stage-1'")
+        );
+    }
 }
@@ -0,0 +1,179 @@
+//! Session spawn rate limiting.
+//!
+//! When a merge unblocks many stages at once, the daemon would otherwise
+//! start a session for every free slot in the same poll, and the burst of
+//! new Claude sessions can trip API rate limits. When configured, spawns are
+//! spread out:
+//!
+//! * `max_per_minute` caps the sessions started in any 60-second window.
+//! * `jitter_secs` waits a random 0..=`jitter_secs` seconds after each spawn
+//!   before the next one, so sessions do not start in lockstep.
+//!
+//! ```toml
+//! [spawn_rate]
+//! max_per_minute = 4
+//! jitter_secs = 15
+//! ```
+//!
+//! Stages held back stay queued and start on a later poll, so delays are
+//! rounded up to the daemon's poll interval. Merge-resolution sessions are
+//! not limited.
+
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::RandomState;
+use std::collections::VecDeque;
+use std::hash::BuildHasher;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use crate::fs::work_dir::read_spawn_rate_config;
+
+/// Window `max_per_minute` is counted over.
+const WINDOW: Duration = Duration::from_secs(60);
+
+/// Persisted `[spawn_rate]` section of `.work/config.toml`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct SpawnRateConfig {
+    /// Most sessions started in any 60-second window
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_per_minute: Option<u32>,
+    /// Upper bound of the random delay after each spawn, in seconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jitter_secs: Option<u64>,
+}
+
+impl SpawnRateConfig {
+    fn is_limited(&self) -> bool {
+        self.max_per_minute.is_some_and(|max| max > 0) || self.jitter_secs.is_some_and(|j| j > 0)
+    }
+}
+
+/// Daemon-side spawn limiter: tracks recent spawns and the jitter delay.
+pub struct SpawnLimiter {
+    config: SpawnRateConfig,
+    recent: VecDeque<Instant>,
+    next_at: Option<Instant>,
+    deferring: bool,
+}
+
+impl SpawnLimiter {
+    /// Load the `[spawn_rate]` config; `None` when neither limit is set.
+    pub fn load(work_dir: &Path) -> Option<Self> {
+        let config = match read_spawn_rate_config(work_dir) {
+            Ok(config) => config,
+            Err(e) => {
+                eprintln!("Warning: ignoring invalid [spawn_rate] config: {e:#}");
+                return None;
+            }
+        };
+        config.is_limited().then(|| Self::new(config))
+    }
+
+    pub fn new(config: SpawnRateConfig) -> Self {
+        Self {
+            config,
+            recent: VecDeque::new(),
+            next_at: None,
+            deferring: false,
+        }
+    }
+
+    /// Sessions that may be started at `now`.
+    pub fn allowance(&mut self, now: Instant) -> usize {
+        while self
+            .recent
+            .front()
+            .is_some_and(|at| now.duration_since(*at) >= WINDOW)
+        {
+            self.recent.pop_front();
+        }
+        if self.next_at.is_some_and(|at| now < at) {
+            return 0;
+        }
+        let remaining = match self.config.max_per_minute.filter(|max| *max > 0) {
+            Some(max) => (max as usize).saturating_sub(self.recent.len()),
+            None => usize::MAX,
+        };
+        if self.config.jitter_secs.is_some_and(|j| j > 0) {
+            remaining.min(1)
+        } else {
+            remaining
+        }
+    }
+
+    /// Record a session started at `now`, delaying the next by `jitter`.
+    pub fn record(&mut self, now: Instant, jitter: Duration) {
+        self.recent.push_back(now);
+        self.next_at = Some(now + jitter);
+        self.deferring = false;
+    }
+
+    /// Random delay of up to `jitter_secs` before the next spawn.
+    pub fn jitter(&self) -> Duration {
+        let Some(max) = self.config.jitter_secs.filter(|j| *j > 0) else {
+            return Duration::ZERO;
+        };
+        let random = RandomState::new().hash_one(Instant::now());
+        Duration::from_millis(random % (max * 1000 + 1))
+    }
+
+    /// Log once per burst that ready stages are being held back.
+    pub fn note_deferred(&mut self, count: usize) {
+        if self.deferring {
+            return;
+        }
+        self.deferring = true;
+        let limit = match self.config.max_per_minute.filter(|max| *max > 0) {
+            Some(max) => format!("at most {max}/min"),
+            None => "jitter between spawns".to_string(),
+        };
+        println!("Spawn rate limit ({limit}): deferring {count} ready stage(s)");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_max_per_minute() {
+        let mut limiter = SpawnLimiter::new(SpawnRateConfig {
+            max_per_minute: Some(2),
+            jitter_secs: None,
+        });
+        let start = Instant::now();
+        assert_eq!(limiter.allowance(start), 2);
+        limiter.record(start, Duration::ZERO);
+        limiter.record(start + Duration::from_secs(10), Duration::ZERO);
+        assert_eq!(limiter.allowance(start + Duration::from_secs(30)), 0);
+        // The first spawn leaves the window
+        assert_eq!(limiter.allowance(start + Duration::from_secs(60)), 1);
+        assert_eq!(limiter.allowance(start + Duration::from_secs(75)), 2);
+    }
+
+    #[test]
+    fn test_jitter_spaces_spawns() {
+        let mut limiter = SpawnLimiter::new(SpawnRateConfig {
+            max_per_minute: None,
+            jitter_secs: Some(10),
+        });
+        let jitter = limiter.jitter();
+        assert!(jitter <= Duration::from_secs(10));
+
+        let start = Instant::now();
+        assert_eq!(limiter.allowance(start), 1);
+        limiter.record(start, Duration::from_secs(5));
+        assert_eq!(limiter.allowance(start + Duration::from_secs(4)), 0);
+        assert_eq!(limiter.allowance(start + Duration::from_secs(5)), 1);
+    }
+
+    #[test]
+    fn test_unset_limits_load_nothing() {
+        assert!(!SpawnRateConfig::default().is_limited());
+        assert!(!SpawnRateConfig {
+            max_per_minute: Some(0),
+            jitter_secs: Some(0),
+        }
+        .is_limited());
+    }
+}
@@ -12,6 +12,8 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 
+use crate::secrets::redact;
+
 // ============================================================================
 // Crash Reporting (retained functionality)
 // ============================================================================
@@ -167,7 +169,7 @@ pub fn generate_crash_report(report: &CrashReport, crashes_dir: &Path) -> Result
     content.push_str("2. Fix any issues in the codebase or configuration\n");
     content.push_str("3. Run `loom resume <stage-id>` to retry the stage\n");
 
-    std::fs::write(&crash_path, &content)
+    std::fs::write(&crash_path, redact(&content).as_bytes())
         .with_context(|| format!("Failed to write crash report: {}", crash_path.display()))?;
 
     Ok(crash_path)
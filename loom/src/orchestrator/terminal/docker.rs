@@ -0,0 +1,324 @@
+//! Docker terminal backend
+//!
+//! Runs the agent inside a project-defined container image for stages that
+//! need a pinned toolchain. The session still gets a native terminal window
+//! (so it can be watched and typed into), but the window runs
+//! `docker run -it` instead of `claude` directly.
+//!
+//! Paths are mounted at their host locations, so the signal path in the
+//! initial prompt, `LOOM_WORK_DIR`, the worktree's `.git` pointer and the
+//! hook commands in `.claude/settings.local.json` all resolve unchanged
+//! inside the container:
+//!
+//! - the repository root (covers `.work/`, `.worktrees/` and `.git/`)
+//! - the session's working directory, if outside the repository root
+//! - `~/.claude` and `~/.claude.json` (credentials, installed hooks)
+//! - the running `loom` binary at `/usr/local/bin/loom` (read-only)
+//!
+//! Liveness and kill go through the docker daemon by container name, which
+//! is recorded on the session.
+
+use anyhow::{bail, Context, Result};
+use shell_escape::escape;
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::Arc;
+
+use super::native::{Launch, NativeBackend};
+use super::TerminalBackend;
+use crate::models::session::{Session, SessionType};
+use crate::models::stage::{ContainerConfig, Stage};
+use crate::models::worktree::Worktree;
+
+/// Environment the wrapper script exports that must reach the agent.
+///
+/// `LOOM_MAIN_AGENT_PID` is deliberately absent: host PIDs mean nothing in
+/// the container's PID namespace, and hooks ignore the gate when unset.
+const FORWARDED_ENV: &[&str] = &[
+    "LOOM_SESSION_ID",
+    "LOOM_STAGE_ID",
+    "LOOM_WORK_DIR",
+    "LOOM_WORKTREE_PATH",
+    "LOOM_BRANCH_PREFIX",
+    "LOOM_MERGE_SESSION",
+    "CLAUDE_CODE_EXPERIMENTAL_AGENT_TEAMS",
+    "CLAUDE_REMOTE_CONTROL_SESSION_NAME_PREFIX",
+    "ANTHROPIC_API_KEY",
+];
+
+/// Docker backend - runs sessions in a container shown in a native terminal
+pub struct DockerBackend {
+    native: Arc<NativeBackend>,
+    config: ContainerConfig,
+}
+
+impl DockerBackend {
+    pub fn new(native: Arc<NativeBackend>, config: ContainerConfig) -> Self {
+        Self { native, config }
+    }
+}
+
+impl TerminalBackend for DockerBackend {
+    fn spawn_session(
+        &self,
+        stage: &Stage,
+        worktree: &Worktree,
+        session: Session,
+        signal_path: &Path,
+    ) -> Result<Session> {
+        self.native.spawn(
+            SessionType::Stage,
+            stage,
+            session,
+            signal_path,
+            &worktree.path,
+            true,
+            Launch::Container(&self.config),
+        )
+    }
+
+    fn spawn_merge_session(
+        &self,
+        stage: &Stage,
+        session: Session,
+        signal_path: &Path,
+        repo_root: &Path,
+    ) -> Result<Session> {
+        self.native.spawn(
+            SessionType::Merge,
+            stage,
+            session,
+            signal_path,
+            repo_root,
+            false,
+            Launch::Container(&self.config),
+        )
+    }
+
+    fn spawn_base_conflict_session(
+        &self,
+        stage: &Stage,
+        session: Session,
+        signal_path: &Path,
+        repo_root: &Path,
+    ) -> Result<Session> {
+        self.native.spawn(
+            SessionType::BaseConflict,
+            stage,
+            session,
+            signal_path,
+            repo_root,
+            false,
+            Launch::Container(&self.config),
+        )
+    }
+
+    fn spawn_knowledge_session(
+        &self,
+        stage: &Stage,
+        session: Session,
+        signal_path: &Path,
+        repo_root: &Path,
+    ) -> Result<Session> {
+        self.native.spawn(
+            SessionType::Knowledge,
+            stage,
+            session,
+            signal_path,
+            repo_root,
+            false,
+            Launch::Container(&self.config),
+        )
+    }
+
+    fn kill_session(&self, session: &Session) -> Result<()> {
+        self.native.kill_session(session)
+    }
+
+    fn is_session_alive(&self, session: &Session) -> Result<bool> {
+        self.native.is_session_alive(session)
+    }
+}
+
+/// Fail early, before a terminal window opens, when docker is missing.
+pub(super) fn ensure_docker(config: &ContainerConfig) -> Result<()> {
+    if which::which("docker").is_err() {
+        bail!(
+            "docker not found in PATH; the stage requires container image '{}'",
+            config.image
+        );
+    }
+    Ok(())
+}
+
+/// Wrap a `claude` invocation in `docker run` for `config`.
+///
+/// `claude_cmd` is the already shell-escaped command line; every value added
+/// here is escaped too, because the wrapper script runs the result through
+/// bash.
+pub(super) fn build_docker_run_command(
+    config: &ContainerConfig,
+    container_name: &str,
+    cwd: &Path,
+    work_dir: &Path,
+    claude_cmd: &str,
+) -> String {
+    let esc = |s: &str| escape(Cow::Owned(s.to_string())).into_owned();
+    let cwd = cwd.canonicalize().unwrap_or_else(|_| cwd.to_path_buf());
+    let work_dir = work_dir
+        .canonicalize()
+        .unwrap_or_else(|_| work_dir.to_path_buf());
+    let repo_root = work_dir.parent().map(Path::to_path_buf);
+
+    let mut args: Vec<String> = vec![
+        "docker".into(),
+        "run".into(),
+        "--rm".into(),
+        "-it".into(),
+        "--init".into(),
+        "--name".into(),
+        esc(container_name),
+    ];
+
+    // Run as the owner of the working directory so files the agent writes
+    // are not root-owned on the host.
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+        if let Ok(meta) = std::fs::metadata(&cwd) {
+            args.push("--user".into());
+            args.push(format!("{}:{}", meta.uid(), meta.gid()));
+        }
+    }
+
+    let mut mounts: Vec<PathBuf> = Vec::new();
+    if let Some(root) = &repo_root {
+        mounts.push(root.clone());
+    }
+    if !repo_root.as_ref().is_some_and(|root| cwd.starts_with(root)) {
+        mounts.push(cwd.clone());
+    }
+    let home = std::env::var_os("HOME").map(PathBuf::from);
+    if let Some(home) = &home {
+        for path in [home.join(".claude"), home.join(".claude.json")] {
+            if path.exists() {
+                mounts.push(path);
+            }
+        }
+    }
+    for mount in &mounts {
+        let mount = mount.display().to_string();
+        args.push("-v".into());
+        args.push(esc(&format!("{mount}:{mount}")));
+    }
+    if let Ok(exe) = std::env::current_exe() {
+        args.push("-v".into());
+        args.push(esc(&format!("{}:/usr/local/bin/loom:ro", exe.display())));
+    }
+    for volume in &config.volumes {
+        args.push("-v".into());
+        args.push(esc(volume));
+    }
+
+    if let Some(home) = &home {
+        args.push("-e".into());
+        args.push(esc(&format!("HOME={}", home.display())));
+    }
+    for name in FORWARDED_ENV {
+        args.push("-e".into());
+        args.push((*name).to_string());
+    }
+    for var in &config.env {
+        args.push("-e".into());
+        args.push(esc(var));
+    }
+
+    args.push("-w".into());
+    args.push(esc(&cwd.display().to_string()));
+    args.push(esc(&config.image));
+    args.push(claude_cmd.to_string());
+
+    args.join(" ")
+}
+
+/// Whether the session's container is running.
+pub fn is_container_running(name: &str) -> Result<bool> {
+    let output = Command::new("docker")
+        .args(["inspect", "--format", "{{.State.Running}}", name])
+        .output()
+        .context("Failed to run docker inspect")?;
+    if !output.status.success() {
+        // `--rm` removes the container when the agent exits
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if stderr.contains("No such object") || stderr.contains("No such container") {
+            return Ok(false);
+        }
+        bail!("docker inspect {name} failed: {}", stderr.trim());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim() == "true")
+}
+
+/// Stop and remove the session's container; the caller closes its window.
+pub(super) fn kill_container(name: &str) -> Result<()> {
+    let output = Command::new("docker")
+        .args(["rm", "--force", name])
+        .output()
+        .context("Failed to run docker rm")?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if !stderr.contains("No such container") {
+            bail!("Failed to remove container {name}: {}", stderr.trim());
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_docker_run_command_mounts_paths_and_forwards_env() {
+        let temp = TempDir::new().unwrap();
+        let work_dir = temp.path().join(".work");
+        let worktree = temp.path().join(".worktrees").join("api");
+        std::fs::create_dir_all(&work_dir).unwrap();
+        std::fs::create_dir_all(&worktree).unwrap();
+        let root = temp.path().canonicalize().unwrap();
+        let config = ContainerConfig {
+            image: "rust:1.79".to_string(),
+            volumes: vec!["/opt/cache:/cache".to_string()],
+            env: vec!["CARGO_HOME=/cache/cargo".to_string()],
+        };
+
+        let cmd = build_docker_run_command(
+            &config,
+            "loom-api-s1",
+            &worktree,
+            &work_dir,
+            "claude --model opus 'Read the signal'",
+        );
+
+        let esc = |s: String| escape(Cow::Owned(s)).into_owned();
+        let root = root.display().to_string();
+        assert!(cmd.starts_with("docker run --rm -it --init --name loom-api-s1 "));
+        assert!(
+            cmd.contains(&format!("-v {}", esc(format!("{root}:{root}")))),
+            "{cmd}"
+        );
+        assert!(cmd.contains(&format!("-v {}", esc("/opt/cache:/cache".to_string()))));
+        assert!(cmd.contains("-e LOOM_WORK_DIR "));
+        assert!(cmd.contains(&format!(
+            "-e {}",
+            esc("CARGO_HOME=/cache/cargo".to_string())
+        )));
+        assert!(cmd.contains(&format!(
+            "-w {} {} claude",
+            esc(format!("{root}/.worktrees/api")),
+            esc("rust:1.79".to_string())
+        )));
+        assert!(cmd.ends_with("'Read the signal'"));
+    }
+}
@@ -29,6 +29,14 @@ fn escape_shell_single_quote(s: &str) -> String {
     s.replace('\'', "'\\''")
 }
 
+/// Remote-control address for the kitty instance hosting window `title`.
+///
+/// An abstract Unix socket (Linux only), so nothing is left on disk when the
+/// window closes.
+pub fn kitty_listen_address(title: &str) -> String {
+    format!("unix:@{title}")
+}
+
 /// Supported terminal emulators
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TerminalEmulator {
@@ -47,6 +55,9 @@ pub enum TerminalEmulator {
     TerminalApp,
     ITerm2,
     Ghostty,
+    /// A terminal defined in `[terminal.emulators.<name>]`, launched from its
+    /// spawn template (see [`super::overrides`])
+    Custom,
 }
 
 impl TerminalEmulator {
@@ -68,6 +79,7 @@ impl TerminalEmulator {
             Self::TerminalApp => "osascript",
             Self::ITerm2 => "osascript",
             Self::Ghostty => "ghostty",
+            Self::Custom => "bash",
         }
     }
 
@@ -124,6 +136,14 @@ impl TerminalEmulator {
                     .arg(cmd);
             }
             Self::Kitty => {
+                // Listen for remote control so the window can be closed by
+                // title on Wayland, where wmctrl/xdotool cannot see it
+                #[cfg(target_os = "linux")]
+                command
+                    .arg("-o")
+                    .arg("allow_remote_control=socket-only")
+                    .arg("--listen-on")
+                    .arg(kitty_listen_address(title));
                 command
                     .arg("--title")
                     .arg(title)
@@ -258,13 +278,20 @@ end tell"#
                 // with shell startup, but since the wrapper script has the cd,
                 // even if there's a delay the directory change will happen.
                 //
-                // SECURITY: Escape cmd to prevent AppleScript injection
+                // The title is recorded as a session variable (and name) so
+                // the session can be found again when closing: iTerm2 window
+                // names track the running job, not a title we set.
+                //
+                // SECURITY: Escape cmd and title to prevent AppleScript injection
                 let escaped_cmd = escape_applescript_string(cmd);
+                let escaped_title = escape_applescript_string(title);
                 let script = format!(
                     r#"tell application "iTerm"
     activate
     create window with default profile
     tell current session of current window
+        set variable named "user.loom_title" to "{escaped_title}"
+        set name to "{escaped_title}"
         write text "{escaped_cmd}"
     end tell
 end tell"#
@@ -304,6 +331,11 @@ end tell"#
                         .arg(cmd);
                 }
             }
+            Self::Custom => {
+                // Only reached without an override template; run the
+                // command directly rather than guessing a terminal's flags
+                command.current_dir(workdir).arg("-c").arg(cmd);
+            }
         }
 
         command
@@ -326,6 +358,7 @@ end tell"#
             Self::TerminalApp => "Terminal.app",
             Self::ITerm2 => "iTerm2",
             Self::Ghostty => "Ghostty",
+            Self::Custom => "custom",
         }
     }
 }
@@ -469,10 +502,30 @@ mod tests {
         let script = args[1].to_str().unwrap();
         assert!(script.contains("tell application \"iTerm\""));
         assert!(script.contains("create window with default profile"));
+        assert!(script.contains("set variable named \"user.loom_title\" to \"Test Title\""));
         // Note: workdir is no longer in the AppleScript - it's handled by the wrapper script
         assert!(script.contains("echo hello"));
     }
 
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_kitty_build_command_listens_for_remote_control() {
+        let emulator = TerminalEmulator::Kitty;
+        let cmd = emulator.build_command("loom-api", Path::new("/tmp/test"), "echo hello");
+
+        let args: Vec<_> = cmd.get_args().map(|a| a.to_str().unwrap()).collect();
+        assert_eq!(
+            &args[..4],
+            [
+                "-o",
+                "allow_remote_control=socket-only",
+                "--listen-on",
+                "unix:@loom-api"
+            ]
+        );
+        assert_eq!(&args[4..6], ["--title", "loom-api"]);
+    }
+
     #[test]
     fn test_escape_applescript_string() {
         use super::escape_applescript_string;
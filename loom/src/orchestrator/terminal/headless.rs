@@ -0,0 +1,228 @@
+//! Headless terminal backend
+//!
+//! Runs the agent as `claude --print` in the background for stages with
+//! `execution_mode: headless`, so simple stages and CI hosts without a
+//! display need no terminal window. The session goes through the same
+//! wrapper script as a windowed one (PID file, `LOOM_*` environment, hooks),
+//! but the wrapper is started by `sh` with its output redirected to
+//! `.work/logs/sessions/<session-id>.log`. When the agent exits, `sh` writes
+//! its exit status next to the log as `<session-id>.exit`.
+//!
+//! Completion works as for windowed sessions: the agent finishes the stage
+//! with `loom stage complete`, and the monitor treats an exit without it as
+//! a crash. Crash reports for headless sessions carry the exit status and
+//! the tail of the log.
+//!
+//! Liveness and kill go through the native backend's PID tracking; there is
+//! no window to close.
+
+use anyhow::{Context, Result};
+use shell_escape::escape;
+use std::fs;
+use std::os::unix::process::CommandExt;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::Arc;
+
+use super::native::{Launch, NativeBackend};
+use super::TerminalBackend;
+use crate::models::session::{Session, SessionType};
+use crate::models::stage::Stage;
+use crate::models::worktree::Worktree;
+
+/// Log lines included in a headless session's crash report.
+pub const CRASH_LOG_TAIL_LINES: usize = 40;
+
+/// Directory holding headless session logs and exit statuses.
+pub fn sessions_log_dir(work_dir: &Path) -> PathBuf {
+    work_dir.join("logs").join("sessions")
+}
+
+/// Output log of a headless session.
+pub fn headless_log_path(work_dir: &Path, session_id: &str) -> PathBuf {
+    sessions_log_dir(work_dir).join(format!("{session_id}.log"))
+}
+
+/// Exit status file of a headless session, written when the agent exits.
+pub fn headless_exit_path(work_dir: &Path, session_id: &str) -> PathBuf {
+    sessions_log_dir(work_dir).join(format!("{session_id}.exit"))
+}
+
+/// Exit status of a finished headless session, or `None` while it runs (or
+/// when the session was not headless).
+pub fn read_headless_exit_code(work_dir: &Path, session_id: &str) -> Option<i32> {
+    fs::read_to_string(headless_exit_path(work_dir, session_id))
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// Last `lines` lines of a session log.
+pub fn log_tail(path: &Path, lines: usize) -> Option<String> {
+    let content = fs::read_to_string(path).ok()?;
+    let all: Vec<&str> = content.lines().collect();
+    let start = all.len().saturating_sub(lines);
+    Some(all[start..].join("\n"))
+}
+
+/// Command that runs `wrapper` in the background with its output captured.
+///
+/// The wrapper execs `claude`, so the PID it records is the agent's; the
+/// outer `sh` only waits for it and records the exit status. It runs in its
+/// own process group so signals aimed at the daemon do not reach it. The log
+/// is appended to so log rotation can truncate it under the running agent.
+pub fn build_command(wrapper: &Path, work_dir: &Path, session_id: &str) -> Result<Command> {
+    let log_dir = sessions_log_dir(work_dir);
+    fs::create_dir_all(&log_dir)
+        .with_context(|| format!("Failed to create {}", log_dir.display()))?;
+
+    let log = headless_log_path(work_dir, session_id);
+    let exit = headless_exit_path(work_dir, session_id);
+    let script = format!(
+        "{wrapper} >> {log} 2>&1 < /dev/null; echo $? > {exit}",
+        wrapper = escape(wrapper.display().to_string().into()),
+        log = escape(log.display().to_string().into()),
+        exit = escape(exit.display().to_string().into()),
+    );
+
+    let mut command = Command::new("sh");
+    command
+        .arg("-c")
+        .arg(script)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .process_group(0);
+    Ok(command)
+}
+
+/// Headless backend - runs sessions as background `claude --print` processes
+pub struct HeadlessBackend {
+    native: Arc<NativeBackend>,
+}
+
+impl HeadlessBackend {
+    pub fn new(native: Arc<NativeBackend>) -> Self {
+        Self { native }
+    }
+}
+
+impl TerminalBackend for HeadlessBackend {
+    fn spawn_session(
+        &self,
+        stage: &Stage,
+        worktree: &Worktree,
+        session: Session,
+        signal_path: &Path,
+    ) -> Result<Session> {
+        self.native.spawn(
+            SessionType::Stage,
+            stage,
+            session,
+            signal_path,
+            &worktree.path,
+            true,
+            Launch::Headless,
+        )
+    }
+
+    fn spawn_merge_session(
+        &self,
+        stage: &Stage,
+        session: Session,
+        signal_path: &Path,
+        repo_root: &Path,
+    ) -> Result<Session> {
+        self.native.spawn(
+            SessionType::Merge,
+            stage,
+            session,
+            signal_path,
+            repo_root,
+            false,
+            Launch::Headless,
+        )
+    }
+
+    fn spawn_base_conflict_session(
+        &self,
+        stage: &Stage,
+        session: Session,
+        signal_path: &Path,
+        repo_root: &Path,
+    ) -> Result<Session> {
+        self.native.spawn(
+            SessionType::BaseConflict,
+            stage,
+            session,
+            signal_path,
+            repo_root,
+            false,
+            Launch::Headless,
+        )
+    }
+
+    fn spawn_knowledge_session(
+        &self,
+        stage: &Stage,
+        session: Session,
+        signal_path: &Path,
+        repo_root: &Path,
+    ) -> Result<Session> {
+        self.native.spawn(
+            SessionType::Knowledge,
+            stage,
+            session,
+            signal_path,
+            repo_root,
+            false,
+            Launch::Headless,
+        )
+    }
+
+    fn kill_session(&self, session: &Session) -> Result<()> {
+        self.native.kill_session(session)
+    }
+
+    fn is_session_alive(&self, session: &Session) -> Result<bool> {
+        self.native.is_session_alive(session)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::PermissionsExt;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_build_command_captures_output_and_exit_status() {
+        let temp = TempDir::new().unwrap();
+        let work_dir = temp.path().join(".work");
+        let wrapper = temp.path().join("wrapper.sh");
+        fs::write(&wrapper, "#!/bin/sh\necho 'agent output'\nexit 3\n").unwrap();
+        fs::set_permissions(&wrapper, fs::Permissions::from_mode(0o700)).unwrap();
+
+        assert_eq!(read_headless_exit_code(&work_dir, "session-1"), None);
+        let status = build_command(&wrapper, &work_dir, "session-1")
+            .unwrap()
+            .status()
+            .unwrap();
+        assert!(status.success());
+
+        assert_eq!(read_headless_exit_code(&work_dir, "session-1"), Some(3));
+        let log = headless_log_path(&work_dir, "session-1");
+        assert_eq!(log_tail(&log, 5).as_deref(), Some("agent output"));
+    }
+
+    #[test]
+    fn test_log_tail_keeps_last_lines() {
+        let temp = TempDir::new().unwrap();
+        let log = temp.path().join("s.log");
+        fs::write(&log, "one\ntwo\nthree\nfour\n").unwrap();
+        assert_eq!(log_tail(&log, 2).as_deref(), Some("three\nfour"));
+        assert_eq!(log_tail(&log, 10).as_deref(), Some("one\ntwo\nthree\nfour"));
+        assert!(log_tail(&temp.path().join("missing.log"), 2).is_none());
+    }
+}
@@ -0,0 +1,143 @@
+//! Whether a session's terminal window stays open after its agent exits.
+//!
+//! Without an explicit policy this used to depend on the terminal: `bash -c`
+//! terminals (kitty, gnome-terminal, ...) closed the window when claude
+//! exited, while Terminal.app and iTerm2 ran the wrapper in an interactive
+//! shell that stayed at a prompt. Now both behaviors are explicit:
+//!   * auto-close (default): the launch command `exec`s the wrapper, so the
+//!     window ends with the agent on every terminal, and the orchestrator
+//!     closes it when the stage completes.
+//!   * keep open: the launch command drops into an interactive shell in the
+//!     session's directory once the agent exits, and completion leaves the
+//!     window alone for inspection.
+//!
+//! Resolution, first match wins:
+//!   * `loom run --keep-terminal` (exported as `LOOM_KEEP_TERMINAL=1`, which
+//!     the daemon inherits)
+//!   * the stage's `keep_terminal: true|false`
+//!   * `[terminal] keep_open` in `.work/config.toml` (default `false`)
+
+use serde::{Deserialize, Serialize};
+use shell_escape::escape;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::fs::work_dir::read_terminal_config;
+use crate::models::stage::Stage;
+
+use super::overrides::EmulatorOverride;
+
+/// Environment variable set by `loom run --keep-terminal`.
+pub const KEEP_TERMINAL_ENV: &str = "LOOM_KEEP_TERMINAL";
+
+/// Persisted `[terminal]` section of `.work/config.toml`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TerminalConfig {
+    /// Keep session windows open after the agent exits, unless a stage
+    /// says otherwise.
+    #[serde(default)]
+    pub keep_open: bool,
+    /// Open sessions as tabs of the window `loom run` was started in
+    /// (kitty, wezterm, iTerm2) instead of new windows.
+    #[serde(default)]
+    pub tabs: bool,
+    /// Terminal to use instead of detecting one: a built-in name or a key
+    /// of `emulators`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emulator: Option<String>,
+    /// Per-emulator spawn and close overrides (`[terminal.emulators.<name>]`)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub emulators: BTreeMap<String, EmulatorOverride>,
+}
+
+/// Keep terminals open for this run (the daemon inherits the setting).
+///
+/// Called from CLI dispatch before the daemon forks, while the process is
+/// still single-threaded.
+pub fn enable_for_process() {
+    std::env::set_var(KEEP_TERMINAL_ENV, "1");
+}
+
+/// Whether the session windows of `stage` stay open after the agent exits.
+///
+/// `stage` is `None` when the stage file could not be loaded; the run flag
+/// and config default still apply.
+pub fn keep_open(stage: Option<&Stage>, work_dir: &Path) -> bool {
+    resolve(
+        std::env::var(KEEP_TERMINAL_ENV).is_ok_and(|v| crate::offline::parse_env_flag(&v)),
+        stage.and_then(|s| s.keep_terminal),
+        || read_terminal_config(work_dir).unwrap_or_default().keep_open,
+    )
+}
+
+fn resolve(run_flag: bool, stage: Option<bool>, config_default: impl FnOnce() -> bool) -> bool {
+    run_flag || stage.unwrap_or_else(config_default)
+}
+
+/// Command a terminal runs to start a session's wrapper script.
+///
+/// Terminals run it either via `bash -c` or typed into an interactive shell
+/// (Terminal.app, iTerm2), so the close behavior is spelled out here rather
+/// than left to whichever shell ends up hosting it.
+pub fn launch_command(wrapper: &Path, keep_open: bool) -> String {
+    let wrapper = escape(Cow::Owned(wrapper.to_string_lossy().into_owned()));
+    if keep_open {
+        format!(
+            "{wrapper}; echo; echo '[loom] Session ended. Window kept open for inspection; \
+             close it when done.'; exec bash -i"
+        )
+    } else {
+        format!("exec {wrapper}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_precedence() {
+        assert!(!resolve(false, None, || false));
+        assert!(resolve(false, None, || true));
+        assert!(!resolve(false, Some(false), || true));
+        assert!(resolve(false, Some(true), || false));
+        assert!(resolve(true, Some(false), || false));
+    }
+
+    #[test]
+    fn test_stage_and_config_resolution() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let work_dir = temp.path();
+        let mut stage = Stage::default();
+        assert!(!keep_open(Some(&stage), work_dir));
+
+        crate::fs::work_dir::write_terminal_config(
+            work_dir,
+            &TerminalConfig {
+                keep_open: true,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert!(keep_open(None, work_dir));
+        stage.keep_terminal = Some(false);
+        assert!(!keep_open(Some(&stage), work_dir));
+    }
+
+    #[test]
+    fn test_launch_command() {
+        let wrapper = Path::new("/repo/.work/wrappers/loom-stage-1.sh");
+        assert_eq!(
+            launch_command(wrapper, false),
+            "exec /repo/.work/wrappers/loom-stage-1.sh"
+        );
+        let kept = launch_command(wrapper, true);
+        assert!(kept.starts_with("/repo/.work/wrappers/loom-stage-1.sh; "));
+        assert!(kept.ends_with("exec bash -i"));
+        assert_eq!(
+            launch_command(Path::new("/my repo/w.sh"), false),
+            "exec '/my repo/w.sh'"
+        );
+    }
+}
@@ -0,0 +1,487 @@
+//! Simulated terminal backend
+//!
+//! `loom run --simulate` drives the orchestrator with [`MockBackend`]
+//! instead of real agents, so the scheduling, merging and failure paths of
+//! a large plan can be exercised in minutes. A simulated stage session is a
+//! thread that waits for its duration and then does what an agent would
+//! have done: completes the stage, blocks it, or exits without either (a
+//! crash, which goes through the usual crash handling and retries).
+//! Simulated sessions make no commits, so merges have nothing to bring in.
+//!
+//! What happens to each stage comes from a TOML schedule. Stages it does not
+//! name follow `[default]`; without a schedule every stage completes after
+//! [`DEFAULT_DURATION`]:
+//!
+//! ```toml
+//! [default]
+//! duration = "3s"
+//!
+//! [stages.api-auth]
+//! outcome = "fail"      # complete | fail | crash
+//! attempts = 1          # only the first session fails, retries complete
+//! duration = "10s"
+//! ```
+//!
+//! Merge and base-conflict sessions end after the default duration; pair
+//! reviewers stay idle until the orchestrator stops them.
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use super::TerminalBackend;
+use crate::fs::session_files::save_session;
+use crate::models::failure::{FailureInfo, FailureType};
+use crate::models::session::{Session, SessionType};
+use crate::models::stage::{Stage, StageStatus};
+use crate::models::worktree::Worktree;
+use crate::validation::parse_duration;
+use crate::verify::transitions::{load_stage, update_stage};
+
+/// How long a simulated session runs when the schedule gives no duration.
+pub const DEFAULT_DURATION: Duration = Duration::from_secs(2);
+
+/// Poll interval of a simulated run; sessions are seconds long, so the
+/// usual five seconds would dominate the run time.
+pub const SIMULATED_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// How long a simulated session waits for the orchestrator to record it on
+/// its stage before giving up.
+const ASSIGN_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Granularity of a simulated session's waits.
+const TICK: Duration = Duration::from_millis(100);
+
+/// What a simulated stage session does when its time is up
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SimulatedOutcome {
+    /// Complete the stage, as `loom stage complete` would
+    #[default]
+    Complete,
+    /// Block the stage with a test failure
+    Fail,
+    /// Exit without completing or blocking the stage
+    Crash,
+}
+
+/// Schedule entry for one stage (or `[default]`)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SimulatedStage {
+    #[serde(default)]
+    pub outcome: SimulatedOutcome,
+    /// Session length such as `10s` or `2m`; falls back to `[default]`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration: Option<String>,
+    /// Number of sessions that get `outcome`; later sessions complete.
+    /// Unset means every session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attempts: Option<u32>,
+}
+
+/// What `loom run --simulate` does with each stage
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SimulationSchedule {
+    #[serde(default)]
+    pub default: SimulatedStage,
+    #[serde(default)]
+    pub stages: BTreeMap<String, SimulatedStage>,
+}
+
+impl SimulationSchedule {
+    /// Load the schedule at `path`, or the default schedule without one.
+    pub fn load(path: Option<&Path>) -> Result<Self> {
+        let Some(path) = path else {
+            return Ok(Self::default());
+        };
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read simulation schedule {}", path.display()))?;
+        Self::parse(&text)
+            .with_context(|| format!("Invalid simulation schedule {}", path.display()))
+    }
+
+    /// Parse a schedule, checking its durations.
+    pub fn parse(text: &str) -> Result<Self> {
+        let schedule: Self = toml::from_str(text)?;
+        let entries = std::iter::once(("default", &schedule.default))
+            .chain(schedule.stages.iter().map(|(id, s)| (id.as_str(), s)));
+        for (name, entry) in entries {
+            if let Some(duration) = &entry.duration {
+                parse_duration(duration)
+                    .with_context(|| format!("Invalid duration for '{name}'"))?;
+            }
+        }
+        Ok(schedule)
+    }
+
+    /// Outcome and duration of the `attempt`th session (from 1) of
+    /// `stage_id`.
+    pub fn plan_for(&self, stage_id: &str, attempt: u32) -> (SimulatedOutcome, Duration) {
+        let entry = self.stages.get(stage_id).unwrap_or(&self.default);
+        let outcome = match entry.attempts {
+            Some(attempts) if attempt > attempts => SimulatedOutcome::Complete,
+            _ => entry.outcome,
+        };
+        let duration = entry
+            .duration
+            .as_deref()
+            .and_then(|d| parse_duration(d).ok())
+            .unwrap_or_else(|| self.default_duration());
+        (outcome, duration)
+    }
+
+    /// Duration of sessions the schedule does not name.
+    pub fn default_duration(&self) -> Duration {
+        self.default
+            .duration
+            .as_deref()
+            .and_then(|d| parse_duration(d).ok())
+            .unwrap_or(DEFAULT_DURATION)
+    }
+
+    /// Stage IDs the schedule names that are not in `known`.
+    pub fn unknown_stages<'a>(&'a self, known: &[String]) -> Vec<&'a str> {
+        self.stages
+            .keys()
+            .filter(|id| !known.contains(id))
+            .map(String::as_str)
+            .collect()
+    }
+}
+
+/// Simulated backend - runs sessions as threads following a schedule
+pub struct MockBackend {
+    work_dir: PathBuf,
+    schedule: SimulationSchedule,
+    /// Liveness flag of every session spawned, by session ID
+    sessions: Mutex<HashMap<String, Arc<AtomicBool>>>,
+    /// Stage sessions spawned so far, by stage ID
+    attempts: Mutex<HashMap<String, u32>>,
+}
+
+impl MockBackend {
+    pub fn new(work_dir: PathBuf, schedule: SimulationSchedule) -> Self {
+        Self {
+            work_dir,
+            schedule,
+            sessions: Mutex::new(HashMap::new()),
+            attempts: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn start(
+        &self,
+        kind: SessionType,
+        stage: &Stage,
+        mut session: Session,
+        worktree: Option<&Path>,
+    ) -> Result<Session> {
+        session.session_type = kind;
+        session.assign_to_stage(stage.id.clone());
+        if let Some(path) = worktree {
+            session.set_worktree_path(path.to_path_buf());
+        }
+        session.try_mark_running()?;
+
+        let alive = Arc::new(AtomicBool::new(true));
+        self.sessions
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(session.id.clone(), Arc::clone(&alive));
+
+        let work_dir = self.work_dir.clone();
+        let agent = session.clone();
+        match kind {
+            SessionType::Stage | SessionType::Knowledge if session.is_pair_reviewer() => {}
+            SessionType::Stage | SessionType::Knowledge => {
+                let attempt = {
+                    let mut attempts = self.attempts.lock().unwrap_or_else(PoisonError::into_inner);
+                    let count = attempts.entry(stage.id.clone()).or_default();
+                    *count += 1;
+                    *count
+                };
+                let (outcome, duration) = self.schedule.plan_for(&stage.id, attempt);
+                thread::spawn(move || run_agent(&work_dir, agent, outcome, duration, &alive));
+            }
+            _ => {
+                let duration = self.schedule.default_duration();
+                thread::spawn(move || {
+                    sleep_while_alive(duration, &alive);
+                    alive.store(false, Ordering::SeqCst);
+                });
+            }
+        }
+        Ok(session)
+    }
+}
+
+impl TerminalBackend for MockBackend {
+    fn spawn_session(
+        &self,
+        stage: &Stage,
+        worktree: &Worktree,
+        session: Session,
+        _signal_path: &Path,
+    ) -> Result<Session> {
+        self.start(SessionType::Stage, stage, session, Some(&worktree.path))
+    }
+
+    fn spawn_merge_session(
+        &self,
+        stage: &Stage,
+        session: Session,
+        _signal_path: &Path,
+        _repo_root: &Path,
+    ) -> Result<Session> {
+        self.start(SessionType::Merge, stage, session, None)
+    }
+
+    fn spawn_base_conflict_session(
+        &self,
+        stage: &Stage,
+        session: Session,
+        _signal_path: &Path,
+        _repo_root: &Path,
+    ) -> Result<Session> {
+        self.start(SessionType::BaseConflict, stage, session, None)
+    }
+
+    fn spawn_knowledge_session(
+        &self,
+        stage: &Stage,
+        session: Session,
+        _signal_path: &Path,
+        _repo_root: &Path,
+    ) -> Result<Session> {
+        self.start(SessionType::Knowledge, stage, session, None)
+    }
+
+    fn kill_session(&self, session: &Session) -> Result<()> {
+        if let Some(alive) = self
+            .sessions
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&session.id)
+        {
+            alive.store(false, Ordering::SeqCst);
+        }
+        Ok(())
+    }
+
+    fn is_session_alive(&self, session: &Session) -> Result<bool> {
+        Ok(self
+            .sessions
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&session.id)
+            .is_some_and(|alive| alive.load(Ordering::SeqCst)))
+    }
+}
+
+/// Body of a simulated stage session.
+fn run_agent(
+    work_dir: &Path,
+    mut session: Session,
+    outcome: SimulatedOutcome,
+    duration: Duration,
+    alive: &AtomicBool,
+) {
+    let stage_id = session.stage_id.clone().unwrap_or_default();
+    let owns = |stage: &Stage| {
+        stage.session.as_deref() == Some(session.id.as_str())
+            && stage.status == StageStatus::Executing
+    };
+
+    // The orchestrator records the session on the stage after spawning it;
+    // touching the stage before that would race its write.
+    let deadline = Instant::now() + ASSIGN_TIMEOUT;
+    while !load_stage(&stage_id, work_dir).is_ok_and(|stage| owns(&stage)) {
+        if !alive.load(Ordering::SeqCst) || Instant::now() > deadline {
+            alive.store(false, Ordering::SeqCst);
+            return;
+        }
+        thread::sleep(TICK);
+    }
+    if !sleep_while_alive(duration, alive) {
+        return;
+    }
+
+    let result = match outcome {
+        SimulatedOutcome::Crash => {
+            alive.store(false, Ordering::SeqCst);
+            return;
+        }
+        SimulatedOutcome::Complete => update_stage(&stage_id, work_dir, |stage| {
+            if !owns(stage) {
+                return Ok(());
+            }
+            stage.try_complete(Some("Simulated session completed".to_string()))
+        }),
+        SimulatedOutcome::Fail => update_stage(&stage_id, work_dir, |stage| {
+            if !owns(stage) {
+                return Ok(());
+            }
+            let reason = "Simulated failure".to_string();
+            stage.try_mark_blocked()?;
+            stage.close_reason = Some(reason.clone());
+            stage.failure_info = Some(FailureInfo {
+                failure_type: FailureType::TestFailure,
+                detected_at: Utc::now(),
+                evidence: vec![reason],
+            });
+            Ok(())
+        }),
+    };
+    if let Err(e) = result {
+        eprintln!(
+            "Warning: simulated session '{}' failed to update stage '{stage_id}': {e:#}",
+            session.id
+        );
+    }
+
+    // Exit the way an agent does after `loom stage complete`, so the monitor
+    // does not report a crash.
+    if session.try_mark_completed().is_ok() {
+        let _ = save_session(&session, work_dir);
+    }
+    alive.store(false, Ordering::SeqCst);
+}
+
+/// Sleep for `duration` unless the session is killed first; returns whether
+/// it is still alive.
+fn sleep_while_alive(duration: Duration, alive: &AtomicBool) -> bool {
+    let end = Instant::now() + duration;
+    loop {
+        if !alive.load(Ordering::SeqCst) {
+            return false;
+        }
+        let now = Instant::now();
+        if now >= end {
+            return true;
+        }
+        thread::sleep(TICK.min(end - now));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::verify::transitions::save_stage;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_schedule_plan_for() {
+        let schedule = SimulationSchedule::parse(
+            r#"
+[default]
+duration = "3s"
+
+[stages.flaky]
+outcome = "crash"
+attempts = 2
+
+[stages.broken]
+outcome = "fail"
+duration = "1m"
+"#,
+        )
+        .unwrap();
+
+        let secs = Duration::from_secs;
+        assert_eq!(
+            schedule.plan_for("other", 1),
+            (SimulatedOutcome::Complete, secs(3))
+        );
+        assert_eq!(
+            schedule.plan_for("flaky", 2),
+            (SimulatedOutcome::Crash, secs(3))
+        );
+        assert_eq!(
+            schedule.plan_for("flaky", 3),
+            (SimulatedOutcome::Complete, secs(3))
+        );
+        assert_eq!(
+            schedule.plan_for("broken", 5),
+            (SimulatedOutcome::Fail, secs(60))
+        );
+        assert_eq!(
+            schedule.unknown_stages(&["flaky".to_string()]),
+            vec!["broken"]
+        );
+        assert_eq!(
+            SimulationSchedule::default().plan_for("any", 1),
+            (SimulatedOutcome::Complete, DEFAULT_DURATION)
+        );
+
+        assert!(SimulationSchedule::parse("[default]\nduration = \"soon\"\n").is_err());
+        assert!(SimulationSchedule::parse("[stages.a]\noutcome = \"explode\"\n").is_err());
+    }
+
+    #[test]
+    fn test_simulated_session_completes_assigned_stage() {
+        let temp = TempDir::new().unwrap();
+        let work_dir = temp.path();
+        std::fs::create_dir_all(work_dir.join("stages")).unwrap();
+
+        let mut stage = Stage::new("Build".to_string(), None);
+        stage.id = "build".to_string();
+        stage.status = StageStatus::Executing;
+        save_stage(&stage, work_dir).unwrap();
+
+        let backend = MockBackend::new(
+            work_dir.to_path_buf(),
+            SimulationSchedule::parse("[default]\nduration = \"1s\"\n").unwrap(),
+        );
+        let session = backend
+            .spawn_knowledge_session(&stage, Session::new(), Path::new("signal.md"), work_dir)
+            .unwrap();
+        assert!(backend.is_session_alive(&session).unwrap());
+
+        stage.assign_session(session.id.clone());
+        save_stage(&stage, work_dir).unwrap();
+
+        let deadline = Instant::now() + Duration::from_secs(10);
+        while backend.is_session_alive(&session).unwrap() && Instant::now() < deadline {
+            thread::sleep(TICK);
+        }
+        assert!(!backend.is_session_alive(&session).unwrap());
+        assert_eq!(
+            load_stage("build", work_dir).unwrap().status,
+            StageStatus::Completed
+        );
+    }
+
+    #[test]
+    fn test_killed_session_leaves_stage_alone() {
+        let temp = TempDir::new().unwrap();
+        let work_dir = temp.path();
+        std::fs::create_dir_all(work_dir.join("stages")).unwrap();
+
+        let mut stage = Stage::new("Build".to_string(), None);
+        stage.id = "build".to_string();
+        stage.status = StageStatus::Executing;
+
+        let backend = MockBackend::new(work_dir.to_path_buf(), SimulationSchedule::default());
+        let session = backend
+            .spawn_knowledge_session(&stage, Session::new(), Path::new("signal.md"), work_dir)
+            .unwrap();
+        stage.assign_session(session.id.clone());
+        save_stage(&stage, work_dir).unwrap();
+
+        backend.kill_session(&session).unwrap();
+        assert!(!backend.is_session_alive(&session).unwrap());
+        thread::sleep(DEFAULT_DURATION + Duration::from_millis(500));
+        assert_eq!(
+            load_stage("build", work_dir).unwrap().status,
+            StageStatus::Executing
+        );
+    }
+}
@@ -1,15 +1,96 @@
 //! Terminal session management
 //!
 //! Spawns and manages Claude Code sessions in native terminal windows
-//! via [`native::NativeBackend`].
+//! via [`native::NativeBackend`], inside a container shown in one via
+//! [`docker::DockerBackend`] for stages that declare `container:`, or in the
+//! background via [`headless::HeadlessBackend`] for stages with
+//! `execution_mode: headless`. `loom run --simulate` replaces all of them
+//! with [`mock::MockBackend`], which runs no agents.
 //!
 //! Supports three session types:
 //! - Stage sessions: run in isolated worktrees for parallel stage execution
 //! - Merge sessions: run in main repository for conflict resolution
 //! - Knowledge sessions: run in main repository for knowledge gathering (no worktree)
 
+pub mod docker;
 pub mod emulator;
+pub mod headless;
+pub mod keep_open;
+pub mod mock;
 pub mod native;
+pub mod overrides;
+
+use anyhow::Result;
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::models::session::Session;
+use crate::models::stage::{ExecutionMode, Stage};
+use crate::models::worktree::Worktree;
 
 // Re-export terminal emulator
 pub use emulator::TerminalEmulator;
+
+/// Spawns agent sessions and manages their lifecycle
+pub trait TerminalBackend {
+    /// Spawn a stage session in its worktree
+    fn spawn_session(
+        &self,
+        stage: &Stage,
+        worktree: &Worktree,
+        session: Session,
+        signal_path: &Path,
+    ) -> Result<Session>;
+
+    /// Spawn a merge conflict resolution session in the main repository
+    fn spawn_merge_session(
+        &self,
+        stage: &Stage,
+        session: Session,
+        signal_path: &Path,
+        repo_root: &Path,
+    ) -> Result<Session>;
+
+    /// Spawn a base-branch conflict resolution session in the main repository
+    fn spawn_base_conflict_session(
+        &self,
+        stage: &Stage,
+        session: Session,
+        signal_path: &Path,
+        repo_root: &Path,
+    ) -> Result<Session>;
+
+    /// Spawn a knowledge session in the main repository
+    fn spawn_knowledge_session(
+        &self,
+        stage: &Stage,
+        session: Session,
+        signal_path: &Path,
+        repo_root: &Path,
+    ) -> Result<Session>;
+
+    /// Terminate a session
+    fn kill_session(&self, session: &Session) -> Result<()>;
+
+    /// Whether the session's agent is still running
+    fn is_session_alive(&self, session: &Session) -> Result<bool>;
+}
+
+/// Backend that spawns sessions for `stage`: docker when the stage declares
+/// a container, headless for `execution_mode: headless`, the native terminal
+/// otherwise.
+pub fn backend_for_stage(
+    native: &Arc<native::NativeBackend>,
+    stage: &Stage,
+) -> Arc<dyn TerminalBackend> {
+    match (&stage.container, stage.execution_mode) {
+        (Some(config), _) => Arc::new(docker::DockerBackend::new(
+            Arc::clone(native),
+            config.clone(),
+        )),
+        (None, Some(ExecutionMode::Headless)) => {
+            Arc::new(headless::HeadlessBackend::new(Arc::clone(native)))
+        }
+        (None, _) => Arc::clone(native) as Arc<dyn TerminalBackend>,
+    }
+}
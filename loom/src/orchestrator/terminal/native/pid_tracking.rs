@@ -384,7 +384,10 @@ fn get_process_cwd_macos(pid: u32) -> Option<PathBuf> {
 ///   `base-conflict-…`), preserved verbatim so hook behavior is unchanged.
 /// * `session_id` - The session identifier (for LOOM_SESSION_ID env var)
 /// * `claude_cmd` - The claude command to execute (e.g., "claude 'prompt here'")
-/// * `working_dir` - The working directory to cd into before running claude
+/// * `working_dir` - The session's root directory, exported as the worktree
+///   boundary and cd'd into before running claude
+/// * `start_dir` - A directory under `working_dir` to cd into instead (the
+///   stage's `working_dir` setting)
 ///
 /// # Returns
 /// The path to the created wrapper script
@@ -395,6 +398,7 @@ pub fn create_wrapper_script(
     session_id: &str,
     claude_cmd: &str,
     working_dir: Option<&Path>,
+    start_dir: Option<&Path>,
 ) -> Result<PathBuf> {
     create_wrappers_dir(work_dir)?;
     create_pid_dir(work_dir)?;
@@ -427,12 +431,19 @@ pub fn create_wrapper_script(
     // macOS where terminals can't reliably set cwd before spawning).
     let (cd_section, worktree_path_export) = match working_dir {
         Some(dir) => {
-            let dir_abs = dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf());
-            let dir_escaped = escape(dir_abs.display().to_string().into());
+            let canonical =
+                |path: &Path| path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+            let dir_escaped = escape(canonical(dir).display().to_string().into());
+            let start_escaped = escape(
+                canonical(start_dir.unwrap_or(dir))
+                    .display()
+                    .to_string()
+                    .into(),
+            );
             (
                 format!(
                     r#"# Change to working directory
-cd {dir_escaped} || {{ echo "Failed to cd to working directory"; exit 1; }}
+cd {start_escaped} || {{ echo "Failed to cd to working directory"; exit 1; }}
 
 "#,
                 ),
@@ -459,6 +470,7 @@ export LOOM_WORKTREE_PATH={dir_escaped}
     let session_id_escaped = escape(session_id.into());
     let work_dir_escaped = escape(work_dir_for_script.display().to_string().into());
     let pid_file_escaped = escape(pid_file_for_script.display().to_string().into());
+    let branch_prefix_escaped = escape(crate::namespace::branch_prefix().into());
 
     let script = format!(
         r#"#!/bin/bash
@@ -469,6 +481,8 @@ export LOOM_WORKTREE_PATH={dir_escaped}
 export LOOM_SESSION_ID={session_id}
 export LOOM_STAGE_ID={stage_id}
 export LOOM_WORK_DIR={work_dir}
+# Stage branch prefix, for hooks that detect the stage from the branch
+export LOOM_BRANCH_PREFIX={branch_prefix}
 # CRITICAL: LOOM_MAIN_AGENT_PID allows hooks to detect subagents
 # Subagents inherit this var but have different $PPID - hooks can compare
 export LOOM_MAIN_AGENT_PID=$$
@@ -498,6 +512,7 @@ exec {claude_cmd}
         stage_id = stage_id_escaped,
         session_id = session_id_escaped,
         work_dir = work_dir_escaped,
+        branch_prefix = branch_prefix_escaped,
         merge_session_export = merge_session_export,
         worktree_path_export = worktree_path_export,
         cd_section = cd_section,
@@ -537,9 +552,10 @@ mod tests {
         let pid_key = "loom-test-stage-session-abc123-1234567890";
         let claude_cmd = "claude 'test prompt'";
 
-        let wrapper_path =
-            create_wrapper_script(work_dir, pid_key, stage_id, session_id, claude_cmd, None)
-                .unwrap();
+        let wrapper_path = create_wrapper_script(
+            work_dir, pid_key, stage_id, session_id, claude_cmd, None, None,
+        )
+        .unwrap();
 
         // Check file exists
         assert!(wrapper_path.exists());
@@ -587,6 +603,7 @@ mod tests {
             session_id,
             claude_cmd,
             Some(working_dir),
+            None,
         )
         .unwrap();
 
@@ -605,6 +622,29 @@ mod tests {
         assert!(content.contains("CLAUDE_CODE_EXPERIMENTAL_AGENT_TEAMS"));
     }
 
+    #[test]
+    fn test_create_wrapper_script_starts_in_stage_working_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        let work_dir = temp_dir.path();
+        let pid_key = "loom-test-stage-session-wd-1234567890";
+
+        let wrapper_path = create_wrapper_script(
+            work_dir,
+            pid_key,
+            "test-stage",
+            "session-wd-1234567890",
+            "claude 'test'",
+            Some(Path::new("/tmp/test-worktree")),
+            Some(Path::new("/tmp/test-worktree/app")),
+        )
+        .unwrap();
+
+        let content = fs::read_to_string(&wrapper_path).unwrap();
+        assert!(content.contains("cd /tmp/test-worktree/app ||"));
+        // The isolation boundary stays at the worktree root
+        assert!(content.contains("export LOOM_WORKTREE_PATH=/tmp/test-worktree\n"));
+    }
+
     #[test]
     fn test_cleanup_stage_files() {
         let temp_dir = TempDir::new().unwrap();
@@ -621,6 +661,7 @@ mod tests {
             session_id,
             "claude 'test'",
             None,
+            None,
         )
         .unwrap();
 
@@ -643,9 +684,10 @@ mod tests {
         let pid_key = "loom-merge-test-stage-session-merge-1234567890";
         let claude_cmd = "claude 'resolve merge conflict'";
 
-        let wrapper_path =
-            create_wrapper_script(work_dir, pid_key, stage_id, session_id, claude_cmd, None)
-                .unwrap();
+        let wrapper_path = create_wrapper_script(
+            work_dir, pid_key, stage_id, session_id, claude_cmd, None, None,
+        )
+        .unwrap();
 
         // Check file exists
         assert!(wrapper_path.exists());
@@ -669,6 +711,7 @@ mod tests {
             regular_session_id,
             claude_cmd,
             None,
+            None,
         )
         .unwrap();
 
@@ -4,10 +4,10 @@
 
 use anyhow::{Context, Result};
 use std::path::Path;
+use std::process::Command;
 use std::thread;
 use std::time::Duration;
 
-use super::super::emulator::TerminalEmulator;
 use super::pid_tracking;
 
 // Terminal spawning timing constants
@@ -36,10 +36,8 @@ fn spawn_reaper_thread(mut child: std::process::Child) {
 /// Spawn a command in a terminal window
 ///
 /// # Arguments
-/// * `terminal` - The terminal emulator to use
-/// * `title` - Window title for the terminal
+/// * `command` - The terminal command opening the window (or tab)
 /// * `workdir` - Working directory for the command
-/// * `cmd` - The command to execute
 /// * `work_dir` - Optional .work directory for PID tracking
 /// * `pid_key` - Optional per-session PID-file key (tracking_key + session.id)
 /// * `session_id` - Optional LOOM_SESSION_ID marker for `/proc`-based discovery
@@ -49,20 +47,16 @@ fn spawn_reaper_thread(mut child: std::process::Child) {
 /// are provided, attempts to resolve the actual Claude PID instead of the
 /// terminal PID.
 pub fn spawn_in_terminal(
-    terminal: &TerminalEmulator,
-    title: &str,
+    mut command: Command,
     workdir: &Path,
-    cmd: &str,
     work_dir: Option<&Path>,
     pid_key: Option<&str>,
     session_id: Option<&str>,
 ) -> Result<u32> {
-    let mut command = terminal.build_command(title, workdir, cmd);
-
     let child = command.spawn().with_context(|| {
         format!(
             "Failed to spawn terminal '{}'. Is it installed?",
-            terminal.binary()
+            command.get_program().to_string_lossy()
         )
     })?;
 
@@ -0,0 +1,314 @@
+//! Session tabs in the terminal window loom was started from
+//!
+//! With `[terminal] tabs = true` in `.work/config.toml`, sessions open as
+//! tabs of the window `loom run` was started in instead of one new window
+//! each. The daemon inherits that window's environment, which names it:
+//! - kitty: `KITTY_LISTEN_ON`, so kitty needs `allow_remote_control` and
+//!   `listen_on` in kitty.conf
+//! - wezterm: `WEZTERM_PANE`, driven through `wezterm cli`
+//! - iTerm2: `TERM_PROGRAM=iTerm.app`, driven through AppleScript
+//!
+//! Tabs are found again by the session's window title: kitty records it as
+//! the `loom_title` window variable, wezterm as the tab title and iTerm2 as
+//! the `user.loom_title` session variable. Elsewhere sessions keep opening
+//! in windows.
+
+use shell_escape::escape;
+use std::borrow::Cow;
+use std::path::Path;
+use std::process::Command;
+
+use super::window_ops::{close_window_by_title_for_terminal, focus_window_by_title_for_terminal};
+use crate::fs::work_dir::read_terminal_config;
+use crate::orchestrator::terminal::emulator::{escape_applescript_string, TerminalEmulator};
+
+/// kitty window variable holding the session's window title.
+const KITTY_TITLE_VAR: &str = "loom_title";
+
+/// Terminal window that hosts session tabs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TabHost {
+    /// kitty, reached over its remote-control socket
+    Kitty { address: String },
+    /// wezterm, via `wezterm cli` in the window of `WEZTERM_PANE`
+    Wezterm,
+    /// iTerm2's frontmost window
+    ITerm2,
+}
+
+impl TabHost {
+    /// Tab host for this process when the config asks for tabs.
+    ///
+    /// Warns and returns `None` when tabs are configured but the process does
+    /// not run inside a terminal that can host them.
+    pub fn configured(work_dir: &Path) -> Option<Self> {
+        if !read_terminal_config(work_dir).unwrap_or_default().tabs {
+            return None;
+        }
+        let host = Self::from_env(|name| std::env::var(name).ok());
+        if host.is_none() {
+            eprintln!(
+                "Warning: [terminal] tabs is set, but loom is not running inside kitty \
+                 (with remote control), wezterm or iTerm2; opening sessions in windows"
+            );
+        }
+        host
+    }
+
+    /// Tab host named by the terminal environment variables.
+    fn from_env(var: impl Fn(&str) -> Option<String>) -> Option<Self> {
+        let set = |name: &str| var(name).filter(|value| !value.is_empty());
+        if let Some(address) = set("KITTY_LISTEN_ON") {
+            return Some(Self::Kitty { address });
+        }
+        if set("WEZTERM_PANE").is_some() {
+            return Some(Self::Wezterm);
+        }
+        if set("TERM_PROGRAM").as_deref() == Some("iTerm.app") {
+            return Some(Self::ITerm2);
+        }
+        None
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::Kitty { .. } => "kitty",
+            Self::Wezterm => "wezterm",
+            Self::ITerm2 => "iTerm2",
+        }
+    }
+
+    /// Build the command that opens a tab titled `title` running `cmd`
+    /// in `workdir`. The tab opens in the background.
+    pub fn build_command(&self, title: &str, workdir: &Path, cmd: &str) -> Command {
+        match self {
+            Self::Kitty { address } => {
+                let mut command = Command::new("kitty");
+                command
+                    .args(["@", "--to", address, "launch", "--type=tab", "--keep-focus"])
+                    .arg("--tab-title")
+                    .arg(title)
+                    .arg("--var")
+                    .arg(format!("{KITTY_TITLE_VAR}={title}"))
+                    .arg("--cwd")
+                    .arg(workdir)
+                    .arg("bash")
+                    .arg("-c")
+                    .arg(cmd);
+                command
+            }
+            Self::Wezterm => {
+                // `spawn` prints the new pane's id, which names the tab to
+                // title; the agent retitles the pane, not the tab
+                let shell_quote = |s: &str| escape(Cow::Owned(s.to_string())).into_owned();
+                let script = format!(
+                    "pane=$(wezterm cli spawn --cwd {} -- bash -c {}) && \
+                     wezterm cli set-tab-title --pane-id \"$pane\" {}",
+                    shell_quote(&workdir.to_string_lossy()),
+                    shell_quote(cmd),
+                    shell_quote(title),
+                );
+                let mut command = Command::new("sh");
+                command.arg("-c").arg(script);
+                command
+            }
+            Self::ITerm2 => {
+                // Same session variables as a new iTerm2 window, so closing
+                // and liveness checks find the tab the same way
+                let escaped_cmd = escape_applescript_string(cmd);
+                let escaped_title = escape_applescript_string(title);
+                let script = format!(
+                    r#"tell application "iTerm"
+    if (count of windows) = 0 then
+        create window with default profile
+    else
+        tell current window to create tab with default profile
+    end if
+    tell current session of current window
+        set variable named "user.loom_title" to "{escaped_title}"
+        set name to "{escaped_title}"
+        write text "{escaped_cmd}"
+    end tell
+end tell"#
+                );
+                let mut command = Command::new("osascript");
+                command.arg("-e").arg(script);
+                command
+            }
+        }
+    }
+
+    /// Close the tab titled `title`. Returns `false` if no such tab exists.
+    pub fn close(&self, title: &str) -> bool {
+        match self {
+            Self::Kitty { address } => kitty_window_id(address, title)
+                .is_some_and(|id| kitty_command(address, "close-window", id)),
+            Self::Wezterm => {
+                wezterm_pane_id(title).is_some_and(|id| wezterm_command("kill-pane", id))
+            }
+            Self::ITerm2 => close_window_by_title_for_terminal(title, &TerminalEmulator::ITerm2),
+        }
+    }
+
+    /// Whether a tab titled `title` exists.
+    pub fn exists(&self, title: &str) -> bool {
+        match self {
+            Self::Kitty { address } => kitty_window_id(address, title).is_some(),
+            Self::Wezterm => wezterm_pane_id(title).is_some(),
+            Self::ITerm2 => super::window_exists_for_terminal(title, &TerminalEmulator::ITerm2),
+        }
+    }
+
+    /// Switch to the tab titled `title`. Returns `false` if no such tab exists.
+    pub fn focus(&self, title: &str) -> bool {
+        match self {
+            Self::Kitty { address } => kitty_window_id(address, title)
+                .is_some_and(|id| kitty_command(address, "focus-window", id)),
+            Self::Wezterm => {
+                wezterm_pane_id(title).is_some_and(|id| wezterm_command("activate-pane", id))
+            }
+            Self::ITerm2 => focus_window_by_title_for_terminal(title, &TerminalEmulator::ITerm2),
+        }
+    }
+}
+
+fn kitty_window_id(address: &str, title: &str) -> Option<u64> {
+    let out = Command::new("kitty")
+        .args(["@", "--to", address, "ls"])
+        .output()
+        .ok()
+        .filter(|out| out.status.success())?;
+    find_kitty_window(&String::from_utf8_lossy(&out.stdout), title)
+}
+
+fn kitty_command(address: &str, action: &str, window_id: u64) -> bool {
+    Command::new("kitty")
+        .args(["@", "--to", address, action, "--match"])
+        .arg(format!("id:{window_id}"))
+        .output()
+        .is_ok_and(|out| out.status.success())
+}
+
+fn wezterm_pane_id(title: &str) -> Option<u64> {
+    let out = Command::new("wezterm")
+        .args(["cli", "list", "--format", "json"])
+        .output()
+        .ok()
+        .filter(|out| out.status.success())?;
+    find_wezterm_pane(&String::from_utf8_lossy(&out.stdout), title)
+}
+
+fn wezterm_command(action: &str, pane_id: u64) -> bool {
+    Command::new("wezterm")
+        .args(["cli", action, "--pane-id"])
+        .arg(pane_id.to_string())
+        .output()
+        .is_ok_and(|out| out.status.success())
+}
+
+/// Id of the window whose `loom_title` variable is `title` in `kitty @ ls`
+/// output (OS windows → tabs → windows).
+fn find_kitty_window(json: &str, title: &str) -> Option<u64> {
+    let os_windows: Vec<serde_json::Value> = serde_json::from_str(json).ok()?;
+    os_windows
+        .iter()
+        .flat_map(|w| w["tabs"].as_array().into_iter().flatten())
+        .flat_map(|tab| tab["windows"].as_array().into_iter().flatten())
+        .find(|window| window["user_vars"][KITTY_TITLE_VAR].as_str() == Some(title))
+        .and_then(|window| window["id"].as_u64())
+}
+
+/// Id of a pane in the tab titled `title` in `wezterm cli list` output.
+fn find_wezterm_pane(json: &str, title: &str) -> Option<u64> {
+    let panes: Vec<serde_json::Value> = serde_json::from_str(json).ok()?;
+    panes
+        .iter()
+        .find(|pane| pane["tab_title"].as_str() == Some(title))
+        .and_then(|pane| pane["pane_id"].as_u64())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_env() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                vars.iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| value.to_string())
+            }
+        };
+        assert_eq!(
+            TabHost::from_env(env(&[("KITTY_LISTEN_ON", "unix:/tmp/kitty")])),
+            Some(TabHost::Kitty {
+                address: "unix:/tmp/kitty".to_string()
+            })
+        );
+        assert_eq!(
+            TabHost::from_env(env(&[("WEZTERM_PANE", "3")])),
+            Some(TabHost::Wezterm)
+        );
+        assert_eq!(
+            TabHost::from_env(env(&[("TERM_PROGRAM", "iTerm.app")])),
+            Some(TabHost::ITerm2)
+        );
+        // Inside kitty without remote control there is nothing to talk to
+        assert_eq!(TabHost::from_env(env(&[("KITTY_WINDOW_ID", "1")])), None);
+        assert_eq!(TabHost::from_env(env(&[("KITTY_LISTEN_ON", "")])), None);
+    }
+
+    #[test]
+    fn test_kitty_build_command_opens_tab() {
+        let host = TabHost::Kitty {
+            address: "unix:/tmp/kitty".to_string(),
+        };
+        let command = host.build_command("loom-api", Path::new("/repo"), "exec /w.sh");
+        let args: Vec<_> = command
+            .get_args()
+            .map(|a| a.to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(command.get_program(), "kitty");
+        assert_eq!(
+            args[..5],
+            ["@", "--to", "unix:/tmp/kitty", "launch", "--type=tab"]
+        );
+        assert!(args.contains(&"loom_title=loom-api".to_string()));
+        assert_eq!(args[args.len() - 3..], ["bash", "-c", "exec /w.sh"]);
+    }
+
+    #[test]
+    fn test_wezterm_build_command_titles_tab() {
+        let command =
+            TabHost::Wezterm.build_command("loom-api", Path::new("/my repo"), "exec /w.sh");
+        let script = command.get_args().nth(1).unwrap().to_string_lossy();
+        assert!(
+            script
+                .starts_with("pane=$(wezterm cli spawn --cwd '/my repo' -- bash -c 'exec /w.sh')"),
+            "{script}"
+        );
+        assert!(script.ends_with("set-tab-title --pane-id \"$pane\" loom-api"));
+    }
+
+    #[test]
+    fn test_find_kitty_window() {
+        let json = r#"[{"id": 1, "tabs": [
+            {"id": 1, "windows": [{"id": 1, "user_vars": {}}]},
+            {"id": 2, "windows": [{"id": 7, "user_vars": {"loom_title": "loom-api"}}]}
+        ]}]"#;
+        assert_eq!(find_kitty_window(json, "loom-api"), Some(7));
+        assert_eq!(find_kitty_window(json, "loom-ap"), None);
+        assert_eq!(find_kitty_window("not json", "loom-api"), None);
+    }
+
+    #[test]
+    fn test_find_wezterm_pane() {
+        let json = r#"[
+            {"window_id": 0, "tab_id": 0, "pane_id": 0, "tab_title": "", "title": "zsh"},
+            {"window_id": 0, "tab_id": 1, "pane_id": 4, "tab_title": "loom-api", "title": "claude"}
+        ]"#;
+        assert_eq!(find_wezterm_pane(json, "loom-api"), Some(4));
+        assert_eq!(find_wezterm_pane(json, "claude"), None);
+    }
+}
@@ -1,9 +1,24 @@
 //! Window operations for terminal management
 //!
 //! Provides functions for closing and focusing terminal windows.
+//!
+//! Closing goes through `close_window_by_title_for_terminal`, which picks the
+//! mechanism the spawning terminal supports:
+//! - macOS: AppleScript per application (Terminal.app, iTerm2, Ghostty, ...)
+//! - kitty (Linux): remote control over the socket loom opens at spawn
+//! - Wayland: compositor IPC (sway, Hyprland), since X11 tools cannot see
+//!   native Wayland windows
+//! - X11: wmctrl / xdotool by exact title
+//!
+//! `focus_window_by_title_for_terminal` raises a window through the same
+//! mechanisms. Sessions opened as tabs (see `super::tabs`) are handled by
+//! their tab host first.
 
 #[cfg(target_os = "macos")]
-use crate::orchestrator::terminal::emulator::{escape_applescript_string, TerminalEmulator};
+use crate::orchestrator::terminal::emulator::escape_applescript_string;
+#[cfg(target_os = "linux")]
+use crate::orchestrator::terminal::emulator::kitty_listen_address;
+use crate::orchestrator::terminal::emulator::TerminalEmulator;
 #[cfg(any(target_os = "linux", target_os = "macos"))]
 use std::process::Command;
 
@@ -63,6 +78,207 @@ pub fn close_window_by_title(title: &str) -> bool {
     false
 }
 
+/// Close a window by title for a specific terminal emulator (Linux).
+///
+/// Tries the terminal's own remote-control interface first, then the Wayland
+/// compositor, then the X11 tools. Terminals without a usable interface
+/// (gnome-terminal, konsole, ...) rely on the compositor or X11 path; when
+/// both fail, `kill_session` falls back to signalling the session process,
+/// which closes the tab without touching a shared terminal server.
+///
+/// Returns `true` if the window was successfully closed, `false` otherwise.
+#[cfg(target_os = "linux")]
+pub fn close_window_by_title_for_terminal(title: &str, terminal: &TerminalEmulator) -> bool {
+    let closed = match terminal {
+        TerminalEmulator::Kitty => close_kitty_window(title),
+        _ => false,
+    };
+
+    closed || close_wayland_window(title) || close_window_by_title(title)
+}
+
+/// Close a kitty window through the remote-control socket it was spawned with.
+///
+/// Each loom window runs in its own kitty instance listening on a socket
+/// derived from the title, so closing every window on that socket only
+/// affects the stage's window.
+#[cfg(target_os = "linux")]
+fn close_kitty_window(title: &str) -> bool {
+    if which::which("kitty").is_err() {
+        return false;
+    }
+
+    Command::new("kitty")
+        .args(["@", "--to", &kitty_listen_address(title)])
+        .args(["close-window", "--match", "all"])
+        .output()
+        .map(|out| out.status.success())
+        .unwrap_or(false)
+}
+
+/// Close a window through the Wayland compositor (sway or Hyprland).
+#[cfg(target_os = "linux")]
+fn close_wayland_window(title: &str) -> bool {
+    if std::env::var_os("WAYLAND_DISPLAY").is_none() {
+        return false;
+    }
+
+    if std::env::var_os("SWAYSOCK").is_some() && which::which("swaymsg").is_ok() {
+        // swaymsg exits non-zero when no window matches the criteria
+        let closed = Command::new("swaymsg")
+            .arg(format!("{} kill", sway_criteria(title)))
+            .output()
+            .map(|out| out.status.success())
+            .unwrap_or(false);
+        if closed {
+            return true;
+        }
+    }
+
+    if std::env::var_os("HYPRLAND_INSTANCE_SIGNATURE").is_some() && which::which("hyprctl").is_ok()
+    {
+        // `hyprctl dispatch` reports "ok" even when nothing matched, so check
+        // the client list first.
+        let listed = Command::new("hyprctl")
+            .args(["clients", "-j"])
+            .output()
+            .ok()
+            .filter(|out| out.status.success())
+            .is_some_and(|out| {
+                hyprland_clients_contain(&String::from_utf8_lossy(&out.stdout), title)
+            });
+        if listed {
+            return Command::new("hyprctl")
+                .args([
+                    "dispatch",
+                    "closewindow",
+                    &format!("title:^({})$", regex_escape(title)),
+                ])
+                .output()
+                .map(|out| out.status.success())
+                .unwrap_or(false);
+        }
+    }
+
+    false
+}
+
+/// Bring a window to the front by title for a specific terminal emulator
+/// (Linux), trying the same interfaces as closing, in the same order.
+///
+/// Returns `true` if a window was focused, `false` otherwise.
+#[cfg(target_os = "linux")]
+pub fn focus_window_by_title_for_terminal(title: &str, terminal: &TerminalEmulator) -> bool {
+    let focused = match terminal {
+        TerminalEmulator::Kitty => {
+            which::which("kitty").is_ok()
+                && Command::new("kitty")
+                    .args(["@", "--to", &kitty_listen_address(title)])
+                    .args(["focus-window", "--match", "all"])
+                    .output()
+                    .is_ok_and(|out| out.status.success())
+        }
+        _ => false,
+    };
+
+    focused || focus_wayland_window(title) || focus_x11_window(title)
+}
+
+/// Focus a window through the Wayland compositor (sway or Hyprland).
+#[cfg(target_os = "linux")]
+fn focus_wayland_window(title: &str) -> bool {
+    if std::env::var_os("WAYLAND_DISPLAY").is_none() {
+        return false;
+    }
+
+    if std::env::var_os("SWAYSOCK").is_some() && which::which("swaymsg").is_ok() {
+        let focused = Command::new("swaymsg")
+            .arg(format!("{} focus", sway_criteria(title)))
+            .output()
+            .is_ok_and(|out| out.status.success());
+        if focused {
+            return true;
+        }
+    }
+
+    if std::env::var_os("HYPRLAND_INSTANCE_SIGNATURE").is_some() && which::which("hyprctl").is_ok()
+    {
+        let listed = Command::new("hyprctl")
+            .args(["clients", "-j"])
+            .output()
+            .ok()
+            .filter(|out| out.status.success())
+            .is_some_and(|out| {
+                hyprland_clients_contain(&String::from_utf8_lossy(&out.stdout), title)
+            });
+        if listed {
+            return Command::new("hyprctl")
+                .args([
+                    "dispatch",
+                    "focuswindow",
+                    &format!("title:^({})$", regex_escape(title)),
+                ])
+                .output()
+                .is_ok_and(|out| out.status.success());
+        }
+    }
+
+    false
+}
+
+/// Focus a window by exact title with wmctrl or xdotool (X11).
+#[cfg(target_os = "linux")]
+fn focus_x11_window(title: &str) -> bool {
+    if which::which("wmctrl").is_ok()
+        && Command::new("wmctrl")
+            .args(["-F", "-a", title])
+            .output()
+            .is_ok_and(|out| out.status.success())
+    {
+        return true;
+    }
+
+    if which::which("xdotool").is_ok() {
+        let anchored = format!("^{}$", regex_escape(title));
+        let window_id = Command::new("xdotool")
+            .args(["search", "--name", &anchored])
+            .output()
+            .ok()
+            .filter(|out| out.status.success())
+            .and_then(|out| {
+                String::from_utf8_lossy(&out.stdout)
+                    .lines()
+                    .map(str::trim)
+                    .find(|id| !id.is_empty())
+                    .map(str::to_string)
+            });
+        if let Some(window_id) = window_id {
+            return Command::new("xdotool")
+                .args(["windowactivate", &window_id])
+                .output()
+                .is_ok_and(|out| out.status.success());
+        }
+    }
+
+    false
+}
+
+/// Sway criteria matching exactly `title`.
+#[cfg(target_os = "linux")]
+fn sway_criteria(title: &str) -> String {
+    format!("[title=\"^{}$\"]", regex_escape(title).replace('"', "\\\""))
+}
+
+/// Whether `hyprctl clients -j` output lists a window titled exactly `title`.
+#[cfg(target_os = "linux")]
+fn hyprland_clients_contain(json: &str, title: &str) -> bool {
+    serde_json::from_str::<Vec<serde_json::Value>>(json).is_ok_and(|clients| {
+        clients
+            .iter()
+            .any(|client| client.get("title").and_then(|t| t.as_str()) == Some(title))
+    })
+}
+
 /// Escape a literal string for use inside an extended regular expression.
 ///
 /// xdotool's `--name` matches with a regex; window titles are
@@ -123,19 +339,30 @@ end tell"#,
     execute_applescript_bool(&script)
 }
 
-/// Close an iTerm2 window by title (macOS)
+/// Close an iTerm2 session by title (macOS)
+///
+/// iTerm2 window names follow the running job, not the title loom sets, so
+/// sessions are matched on the `user.loom_title` variable recorded at spawn
+/// (falling back to the session name) and closed individually.
 #[cfg(target_os = "macos")]
 fn close_iterm2_window(escaped_title: &str) -> bool {
     let script = format!(
         r#"tell application "iTerm2"
     if it is running then
-        set windowList to every window whose name is "{}"
-        if (count of windowList) > 0 then
-            repeat with w in windowList
-                close w
+        set found to false
+        repeat with w in windows
+            repeat with t in tabs of w
+                repeat with s in sessions of t
+                    tell s
+                        if (variable named "user.loom_title") is "{0}" or name is "{0}" then
+                            close
+                            set found to true
+                        end if
+                    end tell
+                end repeat
             end repeat
-            return true
-        end if
+        end repeat
+        return found
     end if
     return false
 end tell"#,
@@ -199,6 +426,63 @@ pub fn close_window_by_title_for_terminal(title: &str, terminal: &TerminalEmulat
     }
 }
 
+/// Bring a window (or iTerm2 session) to the front by title for a specific
+/// terminal emulator (macOS).
+///
+/// Returns `true` if a window was focused, `false` otherwise.
+#[cfg(target_os = "macos")]
+pub fn focus_window_by_title_for_terminal(title: &str, terminal: &TerminalEmulator) -> bool {
+    let escaped_title = escape_applescript_string(title);
+
+    let script = match terminal {
+        TerminalEmulator::ITerm2 => format!(
+            r#"tell application "iTerm2"
+    repeat with w in windows
+        repeat with t in tabs of w
+            repeat with s in sessions of t
+                tell s
+                    if (variable named "user.loom_title") is "{0}" or name is "{0}" then
+                        select w
+                        select t
+                        select s
+                        activate
+                        return true
+                    end if
+                end tell
+            end repeat
+        end repeat
+    end repeat
+    return false
+end tell"#,
+            escaped_title
+        ),
+        _ => {
+            let app_name = match terminal {
+                TerminalEmulator::TerminalApp => "Terminal",
+                TerminalEmulator::Ghostty => "Ghostty",
+                TerminalEmulator::Kitty => "kitty",
+                TerminalEmulator::Alacritty => "Alacritty",
+                TerminalEmulator::Wezterm => "WezTerm",
+                _ => return false,
+            };
+            format!(
+                r#"tell application "{}"
+    set windowList to every window whose name is "{}"
+    if (count of windowList) > 0 then
+        set index of item 1 of windowList to 1
+        activate
+        return true
+    end if
+    return false
+end tell"#,
+                app_name, escaped_title
+            )
+        }
+    };
+
+    execute_applescript_bool(&script)
+}
+
 /// Close a window by its title using AppleScript (macOS).
 ///
 /// This function tries to determine the terminal from LOOM_TERMINAL env var,
@@ -336,13 +620,23 @@ end tell"#,
     execute_applescript_bool(&script)
 }
 
-/// Check if an iTerm2 window exists by title (macOS)
+/// Check if an iTerm2 session exists by title (macOS)
 #[cfg(target_os = "macos")]
 fn iterm2_window_exists(escaped_title: &str) -> bool {
     let script = format!(
         r#"tell application "iTerm2"
-    set windowList to every window whose name is "{}"
-    return (count of windowList) > 0
+    repeat with w in windows
+        repeat with t in tabs of w
+            repeat with s in sessions of t
+                tell s
+                    if (variable named "user.loom_title") is "{0}" or name is "{0}" then
+                        return true
+                    end if
+                end tell
+            end repeat
+        end repeat
+    end repeat
+    return false
 end tell"#,
         escaped_title
     );
@@ -521,6 +815,22 @@ mod tests {
         assert_eq!(wmctrl_list_title("0x05 0"), None);
     }
 
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_sway_criteria_is_anchored() {
+        assert_eq!(sway_criteria("loom-auth"), r#"[title="^loom-auth$"]"#);
+        assert_eq!(sway_criteria("loom-a.b"), r#"[title="^loom-a\.b$"]"#);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_hyprland_clients_contain_exact_title() {
+        let json = r#"[{"title":"loom-auth-tests"},{"title":"editor"}]"#;
+        assert!(!hyprland_clients_contain(json, "loom-auth"));
+        assert!(hyprland_clients_contain(json, "loom-auth-tests"));
+        assert!(!hyprland_clients_contain("not json", "loom-auth"));
+    }
+
     #[cfg(target_os = "linux")]
     #[test]
     fn test_regex_escape_anchors_prevent_prefix_match() {
@@ -0,0 +1,295 @@
+//! Per-emulator spawn configuration from `.work/config.toml`.
+//!
+//! Built-in detection only knows a fixed set of terminals and how to launch
+//! them. `[terminal] emulator` pins the terminal loom uses, skipping
+//! detection, and `[terminal.emulators.<name>]` overrides how a built-in
+//! terminal is launched and closed, or defines a terminal loom does not
+//! know at all:
+//!
+//! ```toml
+//! [terminal]
+//! emulator = "rio"
+//!
+//! [terminal.emulators.rio]
+//! binary = "rio"
+//! title_flag = "--title-placeholder"
+//! working_dir_flag = "--working-dir="
+//! exec_separator = "-e"
+//! close_command = "pkill -f {title}"
+//! ```
+//!
+//! The window is opened as `<binary> <title_flag> <title> <working_dir_flag>
+//! <dir> <exec_separator> bash -c <command>`, leaving out flags that are not
+//! set. A flag ending in `=` is joined with its value. `close_command` runs
+//! under `sh -c` with `{title}` replaced by the shell-quoted window title
+//! (also exported as `LOOM_WINDOW_TITLE`); a zero exit means the window was
+//! closed, otherwise loom falls back to its usual window lookup.
+
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use shell_escape::escape;
+use std::borrow::Cow;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+use super::emulator::TerminalEmulator;
+use super::keep_open::TerminalConfig;
+
+/// One `[terminal.emulators.<name>]` table.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct EmulatorOverride {
+    /// Program to run (default: the built-in terminal's binary)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub binary: Option<String>,
+    /// Flag that sets the window title, e.g. `--title` or `--title=`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title_flag: Option<String>,
+    /// Flag that sets the starting directory
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub working_dir_flag: Option<String>,
+    /// Argument separating the terminal's own flags from the command, e.g.
+    /// `-e` or `--`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exec_separator: Option<String>,
+    /// Shell command closing the window titled `{title}`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub close_command: Option<String>,
+}
+
+impl EmulatorOverride {
+    fn has_spawn_template(&self) -> bool {
+        self.title_flag.is_some()
+            || self.working_dir_flag.is_some()
+            || self.exec_separator.is_some()
+    }
+
+    /// Command opening a window for `cmd`, based on the `base` terminal.
+    ///
+    /// Without any template flag the built-in arguments of `base` are kept,
+    /// only run with `binary` when that is set.
+    pub fn build_command(
+        &self,
+        base: TerminalEmulator,
+        title: &str,
+        workdir: &Path,
+        cmd: &str,
+    ) -> Command {
+        let builtin = (base != TerminalEmulator::Custom && !self.has_spawn_template())
+            .then(|| base.build_command(title, workdir, cmd));
+        let program = match (&self.binary, &builtin) {
+            (Some(binary), _) => binary.clone(),
+            (None, Some(builtin)) => builtin.get_program().to_string_lossy().into_owned(),
+            (None, None) => base.binary().to_string(),
+        };
+        let mut command = Command::new(program);
+        command.current_dir(workdir);
+        if let Some(builtin) = builtin {
+            command.args(builtin.get_args());
+            return command;
+        }
+
+        if let Some(flag) = &self.title_flag {
+            push_flag(&mut command, flag, title);
+        }
+        if let Some(flag) = &self.working_dir_flag {
+            push_flag(&mut command, flag, &workdir.display().to_string());
+        }
+        if let Some(separator) = self.exec_separator.as_deref().filter(|s| !s.is_empty()) {
+            command.arg(separator);
+        }
+        command.arg("bash").arg("-c").arg(cmd);
+        command
+    }
+
+    /// Close the window titled `title` with `close_command`.
+    ///
+    /// Returns `false` when no close command is configured or it failed.
+    pub fn close(&self, title: &str) -> bool {
+        let Some(template) = &self.close_command else {
+            return false;
+        };
+        let quoted = escape(Cow::Borrowed(title));
+        Command::new("sh")
+            .arg("-c")
+            .arg(template.replace("{title}", &quoted))
+            .env("LOOM_WINDOW_TITLE", title)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .is_ok_and(|status| status.success())
+    }
+}
+
+/// Add `flag value` as two arguments, or as one when `flag` ends in `=`.
+fn push_flag(command: &mut Command, flag: &str, value: &str) {
+    if flag.ends_with('=') {
+        command.arg(format!("{flag}{value}"));
+    } else {
+        command.arg(flag).arg(value);
+    }
+}
+
+/// Terminal pinned by `[terminal] emulator`, with its override.
+///
+/// Returns `None` when nothing is pinned and detection should run.
+pub fn pinned_emulator(
+    config: &TerminalConfig,
+) -> Result<Option<(TerminalEmulator, Option<EmulatorOverride>)>> {
+    let Some(name) = config.emulator.as_deref().filter(|n| !n.is_empty()) else {
+        return Ok(None);
+    };
+    let custom = config.emulators.get(name).cloned();
+    if let Some(emulator) = TerminalEmulator::from_name(name) {
+        return Ok(Some((emulator, custom)));
+    }
+    match custom {
+        Some(custom) if custom.binary.is_some() => {
+            Ok(Some((TerminalEmulator::Custom, Some(custom))))
+        }
+        Some(_) => bail!(
+            "[terminal] emulator '{name}' is not a built-in terminal; \
+             set `binary` in [terminal.emulators.{name}]"
+        ),
+        None => bail!(
+            "[terminal] emulator '{name}' is not a built-in terminal and has no \
+             [terminal.emulators.{name}] table"
+        ),
+    }
+}
+
+/// Override configured for a detected built-in terminal, under any of the
+/// names it is known by.
+pub fn override_for(
+    config: &TerminalConfig,
+    emulator: TerminalEmulator,
+) -> Option<EmulatorOverride> {
+    config
+        .emulators
+        .iter()
+        .find(|(name, _)| TerminalEmulator::from_name(name) == Some(emulator))
+        .map(|(_, custom)| custom.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    fn args(command: &Command) -> Vec<String> {
+        command
+            .get_args()
+            .map(|a| a.to_string_lossy().into_owned())
+            .collect()
+    }
+
+    #[test]
+    fn test_custom_spawn_template() {
+        let custom = EmulatorOverride {
+            binary: Some("rio".to_string()),
+            title_flag: Some("--title".to_string()),
+            working_dir_flag: Some("--working-dir=".to_string()),
+            exec_separator: Some("-e".to_string()),
+            close_command: None,
+        };
+        let command = custom.build_command(
+            TerminalEmulator::Custom,
+            "loom-api",
+            Path::new("/tmp/wt"),
+            "exec w.sh",
+        );
+        assert_eq!(command.get_program(), "rio");
+        assert_eq!(
+            args(&command),
+            [
+                "--title",
+                "loom-api",
+                "--working-dir=/tmp/wt",
+                "-e",
+                "bash",
+                "-c",
+                "exec w.sh"
+            ]
+        );
+        assert_eq!(command.get_current_dir(), Some(Path::new("/tmp/wt")));
+    }
+
+    #[test]
+    fn test_binary_only_override_keeps_builtin_args() {
+        let custom = EmulatorOverride {
+            binary: Some("/opt/alacritty/bin/alacritty".to_string()),
+            ..Default::default()
+        };
+        let builtin =
+            TerminalEmulator::Alacritty.build_command("t", Path::new("/tmp"), "exec w.sh");
+        let command = custom.build_command(
+            TerminalEmulator::Alacritty,
+            "t",
+            Path::new("/tmp"),
+            "exec w.sh",
+        );
+        assert_eq!(command.get_program(), "/opt/alacritty/bin/alacritty");
+        assert_eq!(args(&command), args(&builtin));
+    }
+
+    #[test]
+    fn test_pinned_emulator() {
+        let mut config = TerminalConfig::default();
+        assert!(pinned_emulator(&config).unwrap().is_none());
+
+        config.emulator = Some("foot".to_string());
+        assert_eq!(
+            pinned_emulator(&config).unwrap(),
+            Some((TerminalEmulator::Foot, None))
+        );
+
+        config.emulator = Some("rio".to_string());
+        assert!(pinned_emulator(&config).is_err());
+        let rio = EmulatorOverride {
+            binary: Some("rio".to_string()),
+            ..Default::default()
+        };
+        config.emulators = BTreeMap::from([("rio".to_string(), rio.clone())]);
+        assert_eq!(
+            pinned_emulator(&config).unwrap(),
+            Some((TerminalEmulator::Custom, Some(rio)))
+        );
+    }
+
+    #[test]
+    fn test_override_for_detected_terminal() {
+        let kitty = EmulatorOverride {
+            close_command: Some("true".to_string()),
+            ..Default::default()
+        };
+        let config = TerminalConfig {
+            emulators: BTreeMap::from([("kitty".to_string(), kitty.clone())]),
+            ..Default::default()
+        };
+        assert_eq!(
+            override_for(&config, TerminalEmulator::Kitty),
+            Some(kitty.clone())
+        );
+        assert_eq!(override_for(&config, TerminalEmulator::Foot), None);
+        assert!(kitty.close("loom-api"));
+        assert!(!EmulatorOverride::default().close("loom-api"));
+    }
+
+    #[test]
+    fn test_close_command_quotes_title() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let out = temp.path().join("closed");
+        let custom = EmulatorOverride {
+            close_command: Some(format!(
+                "printf '%s|%s' {{title}} \"$LOOM_WINDOW_TITLE\" > {}",
+                out.display()
+            )),
+            ..Default::default()
+        };
+        assert!(custom.close("loom-my stage"));
+        assert_eq!(
+            std::fs::read_to_string(out).unwrap(),
+            "loom-my stage|loom-my stage"
+        );
+    }
+}
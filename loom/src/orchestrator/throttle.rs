@@ -0,0 +1,375 @@
+//! Resource-aware parallelism throttling.
+//!
+//! Parallel agent sessions each run their own builds and test suites, which
+//! can saturate a laptop long before `--max-parallel` is reached. When
+//! thresholds are configured, the daemon samples system load every
+//! `check_interval_secs` and adjusts the number of sessions it may run:
+//!
+//! * Any reading over its threshold lowers the effective limit to one below
+//!   the sessions currently running (never below 1).
+//! * Once every reading is back under [`RESUME_RATIO`] of its threshold, the
+//!   limit rises by one per check until it reaches `--max-parallel` again.
+//!
+//! Running sessions are never stopped; throttling only holds back new starts.
+//! The current state is written to `.work/throttle.json` for `loom status`.
+//!
+//! ```toml
+//! [throttle]
+//! max_load_per_cpu = 1.5     # 1-minute load average divided by CPU count
+//! max_memory_percent = 90    # share of memory in use
+//! max_cpu_percent = 95       # CPU busy time since the previous check
+//! check_interval_secs = 30
+//! ```
+//!
+//! With no threshold set there is no throttling. Memory and CPU readings come
+//! from `/proc` and are only available on Linux; the load average works on
+//! every Unix.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::fs::work_dir::read_throttle_config;
+
+/// File under `.work/` holding the current [`ThrottleState`].
+pub const THROTTLE_STATE_FILE: &str = "throttle.json";
+
+/// Share of each threshold readings must drop below before the limit rises.
+pub const RESUME_RATIO: f64 = 0.9;
+
+/// Persisted `[throttle]` section of `.work/config.toml`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ThrottleConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_load_per_cpu: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_memory_percent: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_cpu_percent: Option<f64>,
+    #[serde(default = "default_check_interval_secs")]
+    pub check_interval_secs: u64,
+}
+
+fn default_check_interval_secs() -> u64 {
+    30
+}
+
+impl Default for ThrottleConfig {
+    fn default() -> Self {
+        Self {
+            max_load_per_cpu: None,
+            max_memory_percent: None,
+            max_cpu_percent: None,
+            check_interval_secs: default_check_interval_secs(),
+        }
+    }
+}
+
+impl ThrottleConfig {
+    fn has_thresholds(&self) -> bool {
+        self.max_load_per_cpu.is_some()
+            || self.max_memory_percent.is_some()
+            || self.max_cpu_percent.is_some()
+    }
+
+    /// Readings over their threshold, described for display, and whether
+    /// every reading is low enough for the limit to rise again.
+    pub fn evaluate(&self, load: &SystemLoad) -> (Vec<String>, bool) {
+        let readings = [
+            ("load", load.load_per_cpu, self.max_load_per_cpu, "/cpu"),
+            ("memory", load.memory_percent, self.max_memory_percent, "%"),
+            ("cpu", load.cpu_percent, self.max_cpu_percent, "%"),
+        ];
+        let mut exceeded = Vec::new();
+        let mut resumable = true;
+        for (name, reading, threshold, unit) in readings {
+            let (Some(reading), Some(threshold)) = (reading, threshold) else {
+                continue;
+            };
+            if reading > threshold {
+                exceeded.push(format!("{name} {reading:.1}{unit} > {threshold:.1}{unit}"));
+            }
+            if reading >= threshold * RESUME_RATIO {
+                resumable = false;
+            }
+        }
+        (exceeded, resumable)
+    }
+}
+
+/// One sample of system resource usage. Readings the platform cannot
+/// provide are `None`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SystemLoad {
+    pub load_per_cpu: Option<f64>,
+    pub memory_percent: Option<f64>,
+    pub cpu_percent: Option<f64>,
+}
+
+/// Cumulative CPU time counters from `/proc/stat`, in clock ticks.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CpuTimes {
+    busy: u64,
+    total: u64,
+}
+
+/// Current throttle state, as shown by `loom status`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ThrottleState {
+    /// `--max-parallel` of the running daemon
+    pub configured_max: usize,
+    /// Sessions the daemon may run right now
+    pub effective_max: usize,
+    /// Readings over their threshold at the last check
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exceeded: Vec<String>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ThrottleState {
+    pub fn is_throttled(&self) -> bool {
+        self.effective_max < self.configured_max
+    }
+}
+
+/// Read the throttle state written by the running daemon.
+pub fn read_throttle_state(work_dir: &Path) -> Option<ThrottleState> {
+    let content = fs::read_to_string(work_dir.join(THROTTLE_STATE_FILE)).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Next effective session limit.
+///
+/// `exceeded` lowers it below the `running` sessions; `resumable` raises it
+/// by one toward `configured`.
+pub fn next_effective_max(
+    current: usize,
+    configured: usize,
+    running: usize,
+    exceeded: bool,
+    resumable: bool,
+) -> usize {
+    if exceeded {
+        current.min(running).saturating_sub(1).max(1)
+    } else if resumable {
+        (current + 1).min(configured)
+    } else {
+        current.min(configured)
+    }
+}
+
+/// Daemon-side throttle: samples load and tracks the effective limit.
+pub struct Throttle {
+    config: ThrottleConfig,
+    state_path: PathBuf,
+    effective_max: Option<usize>,
+    last_check: Option<Instant>,
+    cpu: Option<CpuTimes>,
+}
+
+impl Throttle {
+    /// Load the `[throttle]` config; `None` when no threshold is set.
+    pub fn load(work_dir: &Path) -> Option<Self> {
+        let config = match read_throttle_config(work_dir) {
+            Ok(config) => config,
+            Err(e) => {
+                eprintln!("Warning: ignoring invalid [throttle] config: {e:#}");
+                return None;
+            }
+        };
+        if !config.has_thresholds() {
+            return None;
+        }
+        // Prime the CPU counters so the first check has a baseline.
+        let cpu = read_cpu_times();
+        Some(Self {
+            config,
+            state_path: work_dir.join(THROTTLE_STATE_FILE),
+            effective_max: None,
+            last_check: None,
+            cpu,
+        })
+    }
+
+    /// Session limit for this poll, re-sampling load when a check is due.
+    pub fn effective_max(&mut self, configured: usize, running: usize) -> usize {
+        let current = self.effective_max.unwrap_or(configured).min(configured);
+        let interval = Duration::from_secs(self.config.check_interval_secs);
+        if self
+            .last_check
+            .is_some_and(|last| last.elapsed() < interval)
+        {
+            return current;
+        }
+        self.last_check = Some(Instant::now());
+
+        let load = self.sample();
+        let (exceeded, resumable) = self.config.evaluate(&load);
+        let next = next_effective_max(
+            current,
+            configured,
+            running,
+            !exceeded.is_empty(),
+            resumable,
+        );
+        if self.effective_max != Some(next) {
+            if next < current {
+                println!(
+                    "Throttling parallel sessions to {next}/{configured}: {}",
+                    exceeded.join(", ")
+                );
+            } else if next > current {
+                println!("Raising parallel sessions to {next}/{configured}");
+            }
+            let state = ThrottleState {
+                configured_max: configured,
+                effective_max: next,
+                exceeded,
+                updated_at: Utc::now(),
+            };
+            if let Err(e) = self.write_state(&state) {
+                eprintln!("Warning: failed to record throttle state: {e:#}");
+            }
+        }
+        self.effective_max = Some(next);
+        next
+    }
+
+    fn sample(&mut self) -> SystemLoad {
+        let cpu = read_cpu_times();
+        let cpu_percent = match (self.cpu, cpu) {
+            (Some(prev), Some(now)) if now.total > prev.total => {
+                Some(100.0 * (now.busy - prev.busy) as f64 / (now.total - prev.total) as f64)
+            }
+            _ => None,
+        };
+        self.cpu = cpu;
+        SystemLoad {
+            load_per_cpu: load_per_cpu(),
+            memory_percent: memory_percent(),
+            cpu_percent,
+        }
+    }
+
+    fn write_state(&self, state: &ThrottleState) -> Result<()> {
+        let json = serde_json::to_string_pretty(state)?;
+        fs::write(&self.state_path, json)
+            .with_context(|| format!("Failed to write {}", self.state_path.display()))
+    }
+}
+
+/// 1-minute load average divided by the number of CPUs.
+fn load_per_cpu() -> Option<f64> {
+    let mut load = [0f64; 1];
+    // SAFETY: `load` has room for the one sample requested.
+    let samples = unsafe { libc::getloadavg(load.as_mut_ptr(), 1) };
+    if samples < 1 {
+        return None;
+    }
+    let cpus = std::thread::available_parallelism().ok()?.get();
+    Some(load[0] / cpus as f64)
+}
+
+/// Share of memory in use, from `/proc/meminfo`.
+fn memory_percent() -> Option<f64> {
+    parse_memory_percent(&fs::read_to_string("/proc/meminfo").ok()?)
+}
+
+fn parse_memory_percent(meminfo: &str) -> Option<f64> {
+    let field = |name: &str| {
+        meminfo
+            .lines()
+            .find_map(|line| line.strip_prefix(name))
+            .and_then(|rest| rest.split_whitespace().next())
+            .and_then(|kb| kb.parse::<f64>().ok())
+    };
+    let total = field("MemTotal:")?;
+    let available = field("MemAvailable:")?;
+    (total > 0.0).then(|| 100.0 * (1.0 - available / total))
+}
+
+/// Aggregate CPU counters from `/proc/stat`.
+fn read_cpu_times() -> Option<CpuTimes> {
+    parse_cpu_times(&fs::read_to_string("/proc/stat").ok()?)
+}
+
+fn parse_cpu_times(stat: &str) -> Option<CpuTimes> {
+    let line = stat.lines().find(|line| line.starts_with("cpu "))?;
+    let ticks: Vec<u64> = line
+        .split_whitespace()
+        .skip(1)
+        .filter_map(|value| value.parse().ok())
+        .collect();
+    // user nice system idle iowait irq softirq steal ...
+    let idle = ticks.get(3)? + ticks.get(4).copied().unwrap_or(0);
+    let total: u64 = ticks.iter().take(8).sum();
+    Some(CpuTimes {
+        busy: total - idle,
+        total,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_effective_max() {
+        // Over a threshold: drop below the running sessions, never below 1.
+        assert_eq!(next_effective_max(8, 8, 6, true, false), 5);
+        assert_eq!(next_effective_max(2, 8, 6, true, false), 1);
+        assert_eq!(next_effective_max(1, 8, 1, true, false), 1);
+        // Between the resume ratio and the threshold: hold.
+        assert_eq!(next_effective_max(3, 8, 3, false, false), 3);
+        // Comfortably under: climb back one step at a time.
+        assert_eq!(next_effective_max(3, 8, 3, false, true), 4);
+        assert_eq!(next_effective_max(8, 8, 3, false, true), 8);
+    }
+
+    #[test]
+    fn test_evaluate_thresholds() {
+        let config = ThrottleConfig {
+            max_load_per_cpu: Some(1.5),
+            max_memory_percent: Some(90.0),
+            ..ThrottleConfig::default()
+        };
+        let load = SystemLoad {
+            load_per_cpu: Some(2.0),
+            memory_percent: Some(50.0),
+            cpu_percent: Some(100.0), // No threshold: ignored
+        };
+        let (exceeded, resumable) = config.evaluate(&load);
+        assert_eq!(exceeded, vec!["load 2.0/cpu > 1.5/cpu".to_string()]);
+        assert!(!resumable);
+
+        let calm = SystemLoad {
+            load_per_cpu: Some(1.4), // Under the threshold, over the resume ratio
+            ..load.clone()
+        };
+        assert_eq!(config.evaluate(&calm), (Vec::new(), false));
+        let idle = SystemLoad {
+            load_per_cpu: Some(0.5),
+            ..load
+        };
+        assert_eq!(config.evaluate(&idle), (Vec::new(), true));
+    }
+
+    #[test]
+    fn test_parse_proc_files() {
+        let meminfo =
+            "MemTotal:       16000000 kB\nMemFree:  1000 kB\nMemAvailable:    4000000 kB\n";
+        assert_eq!(parse_memory_percent(meminfo), Some(75.0));
+
+        let stat = "cpu  60 0 20 100 20 0 0 0 0 0\ncpu0 1 2 3 4\n";
+        assert_eq!(
+            parse_cpu_times(stat),
+            Some(CpuTimes {
+                busy: 80,
+                total: 200
+            })
+        );
+    }
+}
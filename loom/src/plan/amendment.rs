@@ -1148,6 +1148,20 @@ mod tests {
             reasoning_effort: None,
             code_review: None,
             ultracode: false,
+            pair: false,
+            worktree_setup: vec![],
+            instructions: None,
+            prompt_prefix: None,
+            container: None,
+            keep_terminal: None,
+            outputs: Vec::new(),
+            params: Vec::new(),
+            requires_outputs: Vec::new(),
+            new_files: Vec::new(),
+            expected_duration: None,
+            acceptance_isolation: None,
+            skip_if_satisfied: false,
+            package: None,
         };
         assert_eq!(current_field_len(&def, AmendmentField::Acceptance), 2);
         assert_eq!(current_field_len(&def, AmendmentField::Wiring), 0);
@@ -0,0 +1,334 @@
+//! Change impact of a modified plan against the current `.work/` state.
+//!
+//! Compares the stage definitions of a plan file with the stage files of the
+//! running plan and reports stages that were added, removed or changed, and
+//! the completed stages whose work the changes would invalidate:
+//!
+//! - a completed stage whose own dependencies, acceptance, setup, files or
+//!   before/after-stage checks changed
+//! - a completed stage downstream of a changed or invalidated stage, since
+//!   it was built on top of work that is going to be redone
+//!
+//! Code review stages loom creates at runtime are not part of the plan and
+//! are ignored.
+
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+
+use crate::models::stage::{Stage, StageStatus, StageType};
+use crate::plan::schema::StageDefinition;
+
+/// A stage definition field whose change is reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ChangedField {
+    Dependencies,
+    Acceptance,
+    Setup,
+    Files,
+    Checks,
+}
+
+impl ChangedField {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Dependencies => "dependencies",
+            Self::Acceptance => "acceptance",
+            Self::Setup => "setup",
+            Self::Files => "files",
+            Self::Checks => "before/after-stage checks",
+        }
+    }
+}
+
+/// A stage present in both the plan and `.work/` whose definition differs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct StageChange {
+    pub stage_id: String,
+    pub fields: Vec<ChangedField>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub added_dependencies: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub removed_dependencies: Vec<String>,
+}
+
+/// Why a completed stage would have to run again.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "cause", rename_all = "kebab-case")]
+pub enum InvalidationCause {
+    /// The stage's own definition changed
+    Changed,
+    /// A dependency (direct or transitive) is new, changed or invalidated
+    Upstream { dependency: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Invalidation {
+    pub stage_id: String,
+    #[serde(flatten)]
+    pub cause: InvalidationCause,
+}
+
+/// Differences between a plan's stage definitions and the `.work/` stages.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct PlanDiff {
+    /// Plan stages without a stage file, in plan order
+    pub added: Vec<String>,
+    /// Stage files the plan no longer defines, by id
+    pub removed: Vec<String>,
+    /// Stages whose definition changed, in plan order
+    pub changed: Vec<StageChange>,
+    /// Completed stages the changes invalidate, in plan order
+    pub invalidated: Vec<Invalidation>,
+}
+
+impl PlanDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Compare `plan` (the stage definitions of a plan file) with `current`
+/// (the stage files in `.work/`).
+pub fn diff_plan(plan: &[StageDefinition], current: &[Stage]) -> PlanDiff {
+    let current: HashMap<&str, &Stage> = current
+        .iter()
+        .filter(|stage| !is_runtime_stage(stage))
+        .map(|stage| (stage.id.as_str(), stage))
+        .collect();
+    let planned: HashSet<&str> = plan.iter().map(|def| def.id.as_str()).collect();
+
+    let mut diff = PlanDiff::default();
+    for def in plan {
+        match current.get(def.id.as_str()) {
+            None => diff.added.push(def.id.clone()),
+            Some(stage) => {
+                if let Some(change) = compare(def, stage) {
+                    diff.changed.push(change);
+                }
+            }
+        }
+    }
+    diff.removed = current
+        .keys()
+        .filter(|id| !planned.contains(*id))
+        .map(|id| id.to_string())
+        .collect();
+    diff.removed.sort();
+
+    // A stage is stale when it is new, changed or an invalidated completed
+    // stage; completed stages downstream of a stale stage are invalidated.
+    let definitions: HashMap<&str, &StageDefinition> =
+        plan.iter().map(|def| (def.id.as_str(), def)).collect();
+    let mut stale: HashMap<&str, bool> = HashMap::new();
+    for id in diff
+        .added
+        .iter()
+        .chain(diff.changed.iter().map(|c| &c.stage_id))
+    {
+        stale.insert(id, true);
+    }
+    for def in plan {
+        let completed = current
+            .get(def.id.as_str())
+            .is_some_and(|stage| stage.status == StageStatus::Completed);
+        if !completed {
+            continue;
+        }
+        let cause = if diff.changed.iter().any(|c| c.stage_id == def.id) {
+            Some(InvalidationCause::Changed)
+        } else {
+            def.dependencies
+                .iter()
+                .find(|dep| is_stale(dep, &definitions, &mut stale, &mut Vec::new()))
+                .map(|dep| InvalidationCause::Upstream {
+                    dependency: dep.clone(),
+                })
+        };
+        if let Some(cause) = cause {
+            diff.invalidated.push(Invalidation {
+                stage_id: def.id.clone(),
+                cause,
+            });
+        }
+    }
+    diff
+}
+
+/// Whether `id` is new, changed, or downstream of such a stage. `path`
+/// guards against dependency cycles in an invalid plan.
+fn is_stale<'a>(
+    id: &'a str,
+    definitions: &HashMap<&'a str, &'a StageDefinition>,
+    stale: &mut HashMap<&'a str, bool>,
+    path: &mut Vec<&'a str>,
+) -> bool {
+    if let Some(&known) = stale.get(id) {
+        return known;
+    }
+    let Some(def) = definitions.get(id) else {
+        return false;
+    };
+    if path.contains(&id) {
+        return false;
+    }
+    path.push(id);
+    let result = def
+        .dependencies
+        .iter()
+        .any(|dep| is_stale(dep, definitions, stale, path));
+    path.pop();
+    stale.insert(id, result);
+    result
+}
+
+fn compare(def: &StageDefinition, stage: &Stage) -> Option<StageChange> {
+    let mut fields = Vec::new();
+    let planned: HashSet<&String> = def.dependencies.iter().collect();
+    let existing: HashSet<&String> = stage.dependencies.iter().collect();
+    let mut added_dependencies: Vec<String> = planned
+        .difference(&existing)
+        .map(|d| d.to_string())
+        .collect();
+    let mut removed_dependencies: Vec<String> = existing
+        .difference(&planned)
+        .map(|d| d.to_string())
+        .collect();
+    added_dependencies.sort();
+    removed_dependencies.sort();
+
+    if planned != existing {
+        fields.push(ChangedField::Dependencies);
+    }
+    if def.acceptance != stage.acceptance {
+        fields.push(ChangedField::Acceptance);
+    }
+    if def.setup != stage.setup {
+        fields.push(ChangedField::Setup);
+    }
+    if def.files != stage.files {
+        fields.push(ChangedField::Files);
+    }
+    if def.before_stage != stage.before_stage || def.after_stage != stage.after_stage {
+        fields.push(ChangedField::Checks);
+    }
+
+    (!fields.is_empty()).then(|| StageChange {
+        stage_id: def.id.clone(),
+        fields,
+        added_dependencies,
+        removed_dependencies,
+    })
+}
+
+/// Stages loom creates while running (code reviews), never part of a plan.
+fn is_runtime_stage(stage: &Stage) -> bool {
+    stage.stage_type == StageType::CodeReview && stage.parent_stage.is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plan::schema::AcceptanceCriterion;
+
+    fn definition(id: &str, deps: &[&str]) -> StageDefinition {
+        let mut def: StageDefinition =
+            serde_yaml::from_str(&format!("id: {id}\nname: {id}\nworking_dir: \".\"")).unwrap();
+        def.dependencies = deps.iter().map(|d| d.to_string()).collect();
+        def.acceptance = vec![AcceptanceCriterion::Simple("cargo test".to_string())];
+        def
+    }
+
+    fn stage(def: &StageDefinition) -> Stage {
+        let mut stage = Stage::new(def.name.clone(), None);
+        stage.id = def.id.clone();
+        stage.dependencies = def.dependencies.clone();
+        stage.acceptance = def.acceptance.clone();
+        stage
+    }
+
+    fn completed(def: &StageDefinition) -> Stage {
+        let mut stage = stage(def);
+        stage.status = StageStatus::Completed;
+        stage
+    }
+
+    #[test]
+    fn test_diff_plan_reports_changes() {
+        let db = definition("db", &[]);
+        let api = definition("api", &["db"]);
+        let web = definition("web", &["api"]);
+        let docs = definition("docs", &[]);
+        let mut review = completed(&definition("api-review", &[]));
+        review.stage_type = StageType::CodeReview;
+        review.parent_stage = Some("api".to_string());
+        let current = vec![
+            completed(&db),
+            completed(&api),
+            completed(&web),
+            completed(&docs),
+            completed(&definition("legacy", &[])),
+            review,
+        ];
+
+        let mut db_changed = db.clone();
+        db_changed.acceptance = vec![AcceptanceCriterion::Simple("cargo test db".to_string())];
+        let mut web_changed = web.clone();
+        web_changed.dependencies.push("cache".to_string());
+        let plan = vec![db_changed, api, definition("cache", &[]), web_changed, docs];
+
+        let diff = diff_plan(&plan, &current);
+        assert_eq!(diff.added, vec!["cache"]);
+        assert_eq!(diff.removed, vec!["legacy"]);
+        assert_eq!(
+            diff.changed,
+            vec![
+                StageChange {
+                    stage_id: "db".to_string(),
+                    fields: vec![ChangedField::Acceptance],
+                    added_dependencies: Vec::new(),
+                    removed_dependencies: Vec::new(),
+                },
+                StageChange {
+                    stage_id: "web".to_string(),
+                    fields: vec![ChangedField::Dependencies],
+                    added_dependencies: vec!["cache".to_string()],
+                    removed_dependencies: Vec::new(),
+                },
+            ]
+        );
+        assert_eq!(
+            diff.invalidated,
+            vec![
+                Invalidation {
+                    stage_id: "db".to_string(),
+                    cause: InvalidationCause::Changed,
+                },
+                Invalidation {
+                    stage_id: "api".to_string(),
+                    cause: InvalidationCause::Upstream {
+                        dependency: "db".to_string()
+                    },
+                },
+                Invalidation {
+                    stage_id: "web".to_string(),
+                    cause: InvalidationCause::Changed,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_plan_unchanged_and_pending_stages() {
+        let db = definition("db", &[]);
+        let mut api = definition("api", &["db"]);
+        let current = vec![completed(&db), stage(&api)];
+        assert!(diff_plan(&[db.clone(), api.clone()], &current).is_empty());
+
+        // A change to a stage that has not run invalidates nothing
+        api.files = vec!["src/api/**".to_string()];
+        let diff = diff_plan(&[db, api], &current);
+        assert_eq!(diff.changed.len(), 1);
+        assert!(diff.invalidated.is_empty());
+    }
+}
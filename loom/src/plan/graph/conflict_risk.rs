@@ -0,0 +1,440 @@
+//! Conflict-risk prediction for stages that may run in parallel.
+//!
+//! Two stages that run at the same time and edit the same files produce a
+//! merge conflict for whichever merges second. Before either has a branch, the
+//! risk is estimated from two signals:
+//!   * overlap of the stages' `files` globs, matched against tracked files
+//!     (and compared literally, for files that do not exist yet)
+//!   * recent git history: how often commits touching one stage's files also
+//!     touched the other's
+//!
+//! The score is `100 * (0.7 * overlap + 0.3 * co-change)`, where `overlap` is
+//! the shared-file count over the smaller stage's file count and `co-change`
+//! the share of recent commits touching either stage that touched both.
+//! Stages without `files` are never scored.
+
+use glob::{MatchOptions, Pattern};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt;
+use std::path::Path;
+
+use crate::git::runner::run_git_checked;
+use crate::models::stage::{Stage, StageStatus};
+
+use super::StageNode;
+
+/// Commits of history inspected for co-changes.
+const RECENT_COMMITS: usize = 200;
+
+/// Weight of the file-overlap signal in the score (co-change gets the rest).
+const OVERLAP_WEIGHT: f64 = 0.7;
+
+/// Scores at or above this are high risk.
+const HIGH_RISK_SCORE: u8 = 50;
+
+/// Scores at or above this are medium risk.
+const MEDIUM_RISK_SCORE: u8 = 20;
+
+/// Coarse conflict-risk level of a stage pair.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RiskLevel {
+    Low,
+    Medium,
+    High,
+}
+
+impl fmt::Display for RiskLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Low => "low",
+            Self::Medium => "medium",
+            Self::High => "high",
+        })
+    }
+}
+
+/// Predicted conflict risk between two stages.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConflictRisk {
+    pub first: String,
+    pub second: String,
+    /// 0–100; see the module docs for the formula
+    pub score: u8,
+    /// Files (or not-yet-existing paths) both stages declare
+    pub shared_files: Vec<String>,
+    /// Recent commits that touched files of both stages
+    pub co_changes: usize,
+}
+
+impl ConflictRisk {
+    pub fn level(&self) -> RiskLevel {
+        if self.score >= HIGH_RISK_SCORE {
+            RiskLevel::High
+        } else if self.score >= MEDIUM_RISK_SCORE {
+            RiskLevel::Medium
+        } else {
+            RiskLevel::Low
+        }
+    }
+}
+
+/// A stage as seen by the analysis.
+#[derive(Debug, Clone, Copy)]
+pub struct RiskCandidate<'a> {
+    pub id: &'a str,
+    pub dependencies: &'a [String],
+    pub files: &'a [String],
+}
+
+impl<'a> From<&'a Stage> for RiskCandidate<'a> {
+    fn from(stage: &'a Stage) -> Self {
+        Self {
+            id: &stage.id,
+            dependencies: &stage.dependencies,
+            files: &stage.files,
+        }
+    }
+}
+
+impl<'a> From<&'a StageNode> for RiskCandidate<'a> {
+    fn from(node: &'a StageNode) -> Self {
+        Self {
+            id: &node.id,
+            dependencies: &node.dependencies,
+            files: &node.files,
+        }
+    }
+}
+
+/// Tracked files and recent commit history of a repository.
+#[derive(Debug, Clone, Default)]
+pub struct RepoHistory {
+    tracked: Vec<String>,
+    /// Files changed by each recent commit, newest first
+    commits: Vec<Vec<String>>,
+}
+
+impl RepoHistory {
+    /// Load from `repo_root`. Git failures yield an empty history, which
+    /// limits scoring to literal pattern overlap.
+    pub fn load(repo_root: &Path) -> Self {
+        let tracked = run_git_checked(&["ls-files"], repo_root)
+            .map(|out| out.lines().map(String::from).collect())
+            .unwrap_or_default();
+        let max_count = format!("--max-count={RECENT_COMMITS}");
+        let commits = run_git_checked(
+            &["log", &max_count, "--name-only", "--format=%x00"],
+            repo_root,
+        )
+        .map(|out| parse_log(&out))
+        .unwrap_or_default();
+        Self { tracked, commits }
+    }
+}
+
+/// Split `git log --name-only --format=%x00` output into per-commit file lists.
+fn parse_log(out: &str) -> Vec<Vec<String>> {
+    out.split('\0')
+        .map(|commit| {
+            commit
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(String::from)
+                .collect::<Vec<_>>()
+        })
+        .filter(|files| !files.is_empty())
+        .collect()
+}
+
+/// Whether `path` matches one of a stage's `files` patterns.
+///
+/// A trailing `/` matches everything under a directory.
+fn matches_any(patterns: &[String], path: &str) -> bool {
+    let options = MatchOptions {
+        require_literal_separator: true,
+        ..MatchOptions::default()
+    };
+    patterns.iter().any(|pattern| {
+        if pattern.ends_with('/') {
+            return path.starts_with(pattern.as_str());
+        }
+        pattern == path || Pattern::new(pattern).is_ok_and(|p| p.matches_with(path, options))
+    })
+}
+
+/// Files a stage's patterns cover: matching tracked files, plus each pattern
+/// that matches nothing yet (it names files the stage will create, and two
+/// stages declaring the same one will collide).
+fn stage_files(patterns: &[String], history: &RepoHistory) -> BTreeSet<String> {
+    let mut files = BTreeSet::new();
+    for pattern in patterns {
+        let single = std::slice::from_ref(pattern);
+        let before = files.len();
+        files.extend(
+            history
+                .tracked
+                .iter()
+                .filter(|path| matches_any(single, path))
+                .cloned(),
+        );
+        if files.len() == before {
+            files.insert(pattern.clone());
+        }
+    }
+    files
+}
+
+/// Score the conflict risk of running `a` and `b` at the same time.
+///
+/// Returns `None` when either stage declares no files.
+pub fn assess_pair(
+    a: &RiskCandidate<'_>,
+    b: &RiskCandidate<'_>,
+    history: &RepoHistory,
+) -> Option<ConflictRisk> {
+    if a.files.is_empty() || b.files.is_empty() {
+        return None;
+    }
+
+    let a_files = stage_files(a.files, history);
+    let b_files = stage_files(b.files, history);
+    let shared_files: Vec<String> = a_files.intersection(&b_files).cloned().collect();
+    let overlap = shared_files.len() as f64 / a_files.len().min(b_files.len()) as f64;
+
+    let mut either = 0usize;
+    let mut co_changes = 0usize;
+    for commit in &history.commits {
+        let touches_a = commit.iter().any(|path| matches_any(a.files, path));
+        let touches_b = commit.iter().any(|path| matches_any(b.files, path));
+        if touches_a || touches_b {
+            either += 1;
+        }
+        if touches_a && touches_b {
+            co_changes += 1;
+        }
+    }
+    let co_change = if either == 0 {
+        0.0
+    } else {
+        co_changes as f64 / either as f64
+    };
+
+    let score = 100.0 * (OVERLAP_WEIGHT * overlap + (1.0 - OVERLAP_WEIGHT) * co_change);
+    Some(ConflictRisk {
+        first: a.id.to_string(),
+        second: b.id.to_string(),
+        score: score.round().clamp(0.0, 100.0) as u8,
+        shared_files,
+        co_changes,
+    })
+}
+
+/// Non-zero risks of every pair of stages that may run in parallel (neither
+/// depends on the other, directly or transitively), highest score first.
+pub fn parallel_risks(
+    candidates: &[RiskCandidate<'_>],
+    history: &RepoHistory,
+) -> Vec<ConflictRisk> {
+    let deps: HashMap<&str, &[String]> = candidates
+        .iter()
+        .map(|candidate| (candidate.id, candidate.dependencies))
+        .collect();
+    let ancestors: HashMap<&str, HashSet<&str>> = candidates
+        .iter()
+        .map(|candidate| (candidate.id, ancestors_of(candidate.id, &deps)))
+        .collect();
+
+    let mut risks = Vec::new();
+    for (i, a) in candidates.iter().enumerate() {
+        for b in &candidates[i + 1..] {
+            if ancestors[a.id].contains(b.id) || ancestors[b.id].contains(a.id) {
+                continue;
+            }
+            if let Some(risk) = assess_pair(a, b, history).filter(|risk| risk.score > 0) {
+                risks.push(risk);
+            }
+        }
+    }
+    risks.sort_by(|x, y| {
+        y.score
+            .cmp(&x.score)
+            .then_with(|| (&x.first, &x.second).cmp(&(&y.first, &y.second)))
+    });
+    risks
+}
+
+/// Medium-or-higher risks between stages that have not finished yet, for
+/// `loom status` and `loom graph`.
+///
+/// The whole graph is scored so ancestry through finished stages still
+/// separates dependent pairs.
+pub fn unfinished_stage_risks(stages: &[Stage], repo_root: &Path) -> Vec<ConflictRisk> {
+    let unfinished: HashSet<&str> = stages
+        .iter()
+        .filter(|stage| !matches!(stage.status, StageStatus::Completed | StageStatus::Skipped))
+        .map(|stage| stage.id.as_str())
+        .collect();
+    if unfinished.len() < 2 {
+        return Vec::new();
+    }
+    let candidates: Vec<RiskCandidate> = stages.iter().map(RiskCandidate::from).collect();
+    parallel_risks(&candidates, &RepoHistory::load(repo_root))
+        .into_iter()
+        .filter(|risk| {
+            risk.level() >= RiskLevel::Medium
+                && unfinished.contains(risk.first.as_str())
+                && unfinished.contains(risk.second.as_str())
+        })
+        .collect()
+}
+
+fn ancestors_of<'a>(id: &'a str, deps: &HashMap<&'a str, &'a [String]>) -> HashSet<&'a str> {
+    let mut seen = HashSet::new();
+    let mut stack: Vec<&str> = deps
+        .get(id)
+        .into_iter()
+        .flat_map(|d| d.iter())
+        .map(String::as_str)
+        .collect();
+    while let Some(dep) = stack.pop() {
+        if seen.insert(dep) {
+            if let Some(next) = deps.get(dep) {
+                stack.extend(next.iter().map(String::as_str));
+            }
+        }
+    }
+    seen
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    fn history() -> RepoHistory {
+        RepoHistory {
+            tracked: strings(&[
+                "src/api/mod.rs",
+                "src/api/routes.rs",
+                "src/db.rs",
+                "docs/a.md",
+            ]),
+            commits: vec![
+                strings(&["src/api/routes.rs", "src/db.rs"]),
+                strings(&["src/db.rs"]),
+                strings(&["docs/a.md"]),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_assess_pair_scores_overlap_and_co_changes() {
+        let api_files = strings(&["src/api/*.rs"]);
+        let db_files = strings(&["src/db.rs", "src/api/routes.rs"]);
+        let docs_files = strings(&["docs/"]);
+        let api = RiskCandidate {
+            id: "api",
+            dependencies: &[],
+            files: &api_files,
+        };
+        let db = RiskCandidate {
+            id: "db",
+            dependencies: &[],
+            files: &db_files,
+        };
+        let docs = RiskCandidate {
+            id: "docs",
+            dependencies: &[],
+            files: &docs_files,
+        };
+
+        let risk = assess_pair(&api, &db, &history()).unwrap();
+        assert_eq!(risk.shared_files, vec!["src/api/routes.rs"]);
+        assert_eq!(risk.co_changes, 1);
+        // Overlap 1 of 2 files, co-change 1 of 2 commits
+        assert_eq!(risk.score, 50);
+        assert_eq!(risk.level(), RiskLevel::High);
+
+        let risk = assess_pair(&api, &docs, &history()).unwrap();
+        assert_eq!(risk.score, 0);
+        assert_eq!(risk.level(), RiskLevel::Low);
+
+        let none = RiskCandidate {
+            id: "none",
+            dependencies: &[],
+            files: &[],
+        };
+        assert!(assess_pair(&api, &none, &history()).is_none());
+    }
+
+    #[test]
+    fn test_parallel_risks_skips_dependent_pairs() {
+        let files = strings(&["src/db.rs"]);
+        let deps = strings(&["a"]);
+        let candidates = [
+            RiskCandidate {
+                id: "a",
+                dependencies: &[],
+                files: &files,
+            },
+            RiskCandidate {
+                id: "b",
+                dependencies: &deps,
+                files: &files,
+            },
+            RiskCandidate {
+                id: "c",
+                dependencies: &[],
+                files: &files,
+            },
+        ];
+        let risks = parallel_risks(&candidates, &history());
+        let pairs: Vec<(&str, &str)> = risks
+            .iter()
+            .map(|r| (r.first.as_str(), r.second.as_str()))
+            .collect();
+        assert_eq!(pairs, vec![("a", "c"), ("b", "c")]);
+        assert_eq!(risks[0].score, 100);
+        assert_eq!(risks[0].level(), RiskLevel::High);
+    }
+
+    #[test]
+    fn test_unfinished_stage_risks_skips_finished_and_dependent_stages() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let stage = |id: &str, deps: &[&str], status: StageStatus| Stage {
+            id: id.to_string(),
+            dependencies: strings(deps),
+            files: strings(&["src/new.rs"]),
+            status,
+            ..Default::default()
+        };
+        let stages = [
+            stage("a", &[], StageStatus::Queued),
+            stage("b", &["a"], StageStatus::Completed),
+            stage("c", &["b"], StageStatus::WaitingForDeps),
+            stage("d", &[], StageStatus::Queued),
+            stage("e", &[], StageStatus::Completed),
+        ];
+        // No repository: only the literal (not yet existing) path overlaps
+        let risks = unfinished_stage_risks(&stages, temp.path());
+        let pairs: Vec<(&str, &str)> = risks
+            .iter()
+            .map(|r| (r.first.as_str(), r.second.as_str()))
+            .collect();
+        assert_eq!(pairs, vec![("a", "d"), ("c", "d")]);
+        assert_eq!(risks[0].shared_files, vec!["src/new.rs"]);
+    }
+
+    #[test]
+    fn test_parse_log() {
+        let out = "\0\nsrc/a.rs\nsrc/b.rs\n\0\nREADME.md\n";
+        assert_eq!(
+            parse_log(out),
+            vec![strings(&["src/a.rs", "src/b.rs"]), strings(&["README.md"])]
+        );
+    }
+}
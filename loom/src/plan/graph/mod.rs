@@ -1,5 +1,6 @@
 //! Execution graph for managing stage dependencies and execution order
 
+pub mod conflict_risk;
 mod cycle;
 pub mod levels;
 mod loader;
@@ -11,7 +12,7 @@ mod tests;
 
 use anyhow::{bail, Result};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::models::stage::StageStatus;
 
@@ -40,21 +41,7 @@ impl ExecutionGraph {
 
         // First pass: create all nodes
         for stage in &stages {
-            let node = StageNode {
-                id: stage.id.clone(),
-                name: stage.name.clone(),
-                dependencies: stage.dependencies.clone(),
-                parallel_group: stage.parallel_group.clone(),
-                status: StageStatus::WaitingForDeps,
-                description: stage.description.clone(),
-                acceptance: stage.acceptance.clone(),
-                setup: stage.setup.clone(),
-                files: stage.files.clone(),
-                auto_merge: stage.auto_merge,
-                outputs: Vec::new(),
-                merged: false,
-            };
-            nodes.insert(stage.id.clone(), node);
+            nodes.insert(stage.id.clone(), StageNode::from_definition(stage));
 
             // Add to parallel group if specified
             if let Some(group) = &stage.parallel_group {
@@ -91,6 +78,42 @@ impl ExecutionGraph {
         Ok(graph)
     }
 
+    /// Add a stage created after the graph was built (a code review, see
+    /// [`crate::orchestrator::code_review`]).
+    ///
+    /// Its dependencies must already be in the graph; since nothing depends
+    /// on a new stage, adding it cannot create a cycle.
+    pub fn add_stage(&mut self, stage: &StageDefinition) -> Result<()> {
+        if self.nodes.contains_key(&stage.id) {
+            bail!("Stage '{}' is already in the execution graph", stage.id);
+        }
+        if let Some(dep) = stage
+            .dependencies
+            .iter()
+            .find(|dep| !self.nodes.contains_key(*dep))
+        {
+            bail!("Stage '{}' depends on unknown stage '{dep}'", stage.id);
+        }
+
+        self.nodes
+            .insert(stage.id.clone(), StageNode::from_definition(stage));
+        self.edges.entry(stage.id.clone()).or_default();
+        for dep in &stage.dependencies {
+            self.edges
+                .entry(dep.clone())
+                .or_default()
+                .push(stage.id.clone());
+        }
+        if let Some(group) = &stage.parallel_group {
+            self.parallel_groups
+                .entry(group.clone())
+                .or_default()
+                .push(stage.id.clone());
+        }
+        let _ = scheduling::update_ready_status(&mut self.nodes);
+        Ok(())
+    }
+
     /// Update which stages are ready (all deps satisfied and merged).
     ///
     /// # Returns
@@ -231,7 +254,7 @@ impl ExecutionGraph {
         // Verify all dependencies are completed
         for dep in &deps {
             if let Some(dep_node) = self.nodes.get(dep) {
-                if dep_node.status != StageStatus::Completed {
+                if dep_node.status != StageStatus::Completed && !dep_node.satisfies_dependents() {
                     bail!(
                         "Cannot mark '{}' as ready: dependency '{}' is {:?}",
                         stage_id,
@@ -261,6 +284,27 @@ impl ExecutionGraph {
         self.nodes.get(stage_id)
     }
 
+    /// IDs of `stage_id` and every stage it transitively depends on.
+    ///
+    /// This is the subgraph that has to run for `stage_id` to complete.
+    pub fn dependency_closure(&self, stage_id: &str) -> Result<HashSet<String>> {
+        if !self.nodes.contains_key(stage_id) {
+            bail!("Stage '{stage_id}' not found in execution graph");
+        }
+
+        let mut closure = HashSet::new();
+        let mut stack = vec![stage_id.to_string()];
+        while let Some(id) = stack.pop() {
+            if !closure.insert(id.clone()) {
+                continue;
+            }
+            if let Some(node) = self.nodes.get(&id) {
+                stack.extend(node.dependencies.iter().cloned());
+            }
+        }
+        Ok(closure)
+    }
+
     /// Get all nodes
     pub fn all_nodes(&self) -> Vec<&StageNode> {
         self.nodes.values().collect()
@@ -1,6 +1,6 @@
 //! Graph node types for the execution graph
 
-use crate::models::stage::{StageOutput, StageStatus};
+use crate::models::stage::{StageOutput, StageStatus, StageType};
 use serde::{Deserialize, Serialize};
 
 /// A node in the execution graph
@@ -11,6 +11,9 @@ pub struct StageNode {
     pub dependencies: Vec<String>,
     pub parallel_group: Option<String>,
     pub status: StageStatus,
+    /// Kind of stage, used for scheduling priority
+    #[serde(default)]
+    pub stage_type: StageType,
     /// Stage description - provides task context for the agent
     #[serde(default)]
     pub description: Option<String>,
@@ -37,3 +40,31 @@ pub struct StageNode {
     #[serde(default)]
     pub merged: bool,
 }
+
+impl StageNode {
+    /// Node for a stage definition, waiting on its dependencies
+    pub fn from_definition(stage: &crate::plan::schema::StageDefinition) -> Self {
+        Self {
+            id: stage.id.clone(),
+            name: stage.name.clone(),
+            dependencies: stage.dependencies.clone(),
+            parallel_group: stage.parallel_group.clone(),
+            status: StageStatus::WaitingForDeps,
+            stage_type: stage.stage_type,
+            description: stage.description.clone(),
+            acceptance: stage.scoped_acceptance(),
+            setup: stage.setup.clone(),
+            files: stage.scoped_files(),
+            auto_merge: stage.auto_merge,
+            outputs: Vec::new(),
+            merged: false,
+        }
+    }
+
+    /// Whether this stage lets its dependents start: completed and merged,
+    /// or skipped because its work was already on the base (such a skip is
+    /// recorded as merged; a user skip never is).
+    pub fn satisfies_dependents(&self) -> bool {
+        self.merged && matches!(self.status, StageStatus::Completed | StageStatus::Skipped)
+    }
+}
@@ -10,7 +10,8 @@ use crate::models::stage::StageStatus;
 ///
 /// A stage transitions from `WaitingForDeps` to `Queued` when:
 /// - It has no dependencies, OR
-/// - All dependencies have BOTH `status == Completed` AND `merged == true`
+/// - All dependencies have BOTH `status == Completed` AND `merged == true`,
+///   or were skipped as already satisfied (see [`StageNode::satisfies_dependents`])
 ///
 /// This ensures dependent stages can use the merge point (main) as their base,
 /// which contains all dependency work.
@@ -30,7 +31,7 @@ pub fn update_ready_status(nodes: &mut HashMap<String, StageNode>) -> Vec<String
     // Collect stages that are completed AND merged - only these satisfy dependencies
     let completed_and_merged: HashSet<_> = nodes
         .values()
-        .filter(|n| n.status == StageStatus::Completed && n.merged)
+        .filter(|n| n.satisfies_dependents())
         .map(|n| n.id.clone())
         .collect();
 
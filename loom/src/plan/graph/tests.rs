@@ -31,6 +31,20 @@ fn make_stage(id: &str, deps: Vec<&str>, group: Option<&str>) -> StageDefinition
         reasoning_effort: None,
         code_review: None,
         ultracode: false,
+        pair: false,
+        worktree_setup: vec![],
+        instructions: None,
+        prompt_prefix: None,
+        container: None,
+        keep_terminal: None,
+        outputs: Vec::new(),
+        params: Vec::new(),
+        requires_outputs: Vec::new(),
+        new_files: Vec::new(),
+        expected_duration: None,
+        acceptance_isolation: None,
+        skip_if_satisfied: false,
+        package: None,
     }
 }
 
@@ -51,6 +65,25 @@ fn test_build_simple_graph() {
     assert_eq!(ready[0].id, "a");
 }
 
+#[test]
+fn test_add_stage() {
+    let mut graph = ExecutionGraph::build(vec![make_stage("a", vec![], None)]).unwrap();
+
+    graph
+        .add_stage(&make_stage("a-review", vec![], None))
+        .unwrap();
+    assert_eq!(
+        graph.get_node("a-review").map(|n| &n.status),
+        Some(&StageStatus::Queued)
+    );
+    assert!(graph
+        .add_stage(&make_stage("a-review", vec![], None))
+        .is_err());
+    assert!(graph
+        .add_stage(&make_stage("b", vec!["missing"], None))
+        .is_err());
+}
+
 #[test]
 fn test_parallel_groups() {
     let stages = vec![
@@ -154,6 +187,22 @@ fn test_skipped_does_not_satisfy_deps() {
     assert!(ready.is_empty());
 }
 
+#[test]
+fn test_satisfied_skip_unblocks_dependents() {
+    let stages = vec![
+        make_stage("a", vec![], None),
+        make_stage("b", vec!["a"], None),
+    ];
+
+    let mut graph = ExecutionGraph::build(stages).unwrap();
+
+    // A skip recorded as merged (already satisfied) satisfies the dependency
+    graph.set_node_merged("a", true);
+    graph.mark_status("a", StageStatus::Skipped).unwrap();
+    assert_eq!(graph.update_ready_status(), vec!["b".to_string()]);
+    assert_eq!(graph.get_node("b").unwrap().status, StageStatus::Queued);
+}
+
 #[test]
 fn test_is_complete_with_skipped() {
     let stages = vec![make_stage("a", vec![], None), make_stage("b", vec![], None)];
@@ -238,3 +287,26 @@ fn test_leaf_stages_all_independent() {
     assert!(leaves.contains(&"b"));
     assert!(leaves.contains(&"c"));
 }
+
+#[test]
+fn test_dependency_closure_includes_transitive_deps_only() {
+    // a -> b -> d, c -> d, e independent
+    let stages = vec![
+        make_stage("a", vec![], None),
+        make_stage("b", vec!["a"], None),
+        make_stage("c", vec![], None),
+        make_stage("d", vec!["b", "c"], None),
+        make_stage("e", vec!["a"], None),
+    ];
+
+    let graph = ExecutionGraph::build(stages).unwrap();
+
+    let closure = graph.dependency_closure("d").unwrap();
+    let expected: HashSet<String> = ["a", "b", "c", "d"].map(String::from).into();
+    assert_eq!(closure, expected);
+
+    let closure = graph.dependency_closure("a").unwrap();
+    assert_eq!(closure, HashSet::from(["a".to_string()]));
+
+    assert!(graph.dependency_closure("missing").is_err());
+}
@@ -6,6 +6,7 @@
 //! - Building execution graphs
 
 pub mod amendment;
+pub mod diff;
 pub mod graph;
 pub mod parser;
 pub mod schema;
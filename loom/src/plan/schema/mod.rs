@@ -1,6 +1,7 @@
 //! Plan YAML schema definitions and validation
 
 mod detect;
+mod package;
 mod types;
 mod validation;
 
@@ -8,14 +9,15 @@ mod validation;
 mod tests;
 
 pub use detect::{detect_stage_type, detect_stage_type_from_id_name};
+pub use package::{has_package_reference, PackageKind, PackageTarget};
 pub use types::{
     AcceptanceCriterion, AdjudicationConfig, ChangeImpactConfig, ChangeImpactPolicy,
-    CodeReviewConfig, DeadCodeCheck, FilesystemConfig, LinuxConfig, LoomConfig, LoomMetadata,
-    NetworkConfig, PermissionMode, RegressionTest, SandboxConfig, StageDefinition,
-    StageSandboxConfig, StageType, SuccessCriteria, TruthCheck, ValidationError, WiringCheck,
-    WiringTest, ALLOWED_REASONING_EFFORTS,
+    CodeReviewConfig, ConflictAvoidance, DeadCodeCheck, EnvIsolation, FilesystemConfig,
+    IsolationMode, LinuxConfig, LoomConfig, LoomMetadata, NetworkConfig, PermissionMode,
+    RegressionTest, SandboxConfig, StageDefinition, StageSandboxConfig, StageType, SuccessCriteria,
+    TruthCheck, ValidationError, WiringCheck, WiringTest, ALLOWED_REASONING_EFFORTS,
 };
 pub use validation::{
-    check_knowledge_recommendations, check_sandbox_recommendations, validate,
+    check_knowledge_recommendations, check_sandbox_recommendations, check_working_dirs, validate,
     validate_structural_preflight,
 };
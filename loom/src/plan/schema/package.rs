@@ -0,0 +1,266 @@
+//! Monorepo package targeting for stages
+//!
+//! A stage that works on one package of a monorepo can name it instead of
+//! spelling out package-scoped commands and globs by hand:
+//!
+//! ```yaml
+//! package:
+//!   kind: cargo          # cargo | pnpm | bazel
+//!   path: crates/auth    # relative to the repository root
+//!   name: loom-auth      # optional: crate / package name or bazel target
+//! ```
+//!
+//! When the stage is created, loom:
+//!
+//! - replaces `{{package}}` in its setup commands, acceptance criteria and
+//!   before/after-stage checks with the tool's selector (`-p loom-auth`,
+//!   `--filter @acme/web`, `//services/auth/...`), and `{{package.name}}` /
+//!   `{{package.path}}` with the name and path
+//! - uses the package's test command as acceptance when the stage has none
+//! - adds `<path>/**` to the stage's `files`, so scope checks and sparse
+//!   worktree checkouts cover the package
+
+use serde::{Deserialize, Serialize};
+
+use super::types::{AcceptanceCriterion, TruthCheck};
+
+const SELECTOR: &str = "{{package}}";
+const NAME: &str = "{{package.name}}";
+const PATH: &str = "{{package.path}}";
+
+/// Build tool of a monorepo package
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PackageKind {
+    /// Cargo workspace member
+    Cargo,
+    /// pnpm workspace package
+    Pnpm,
+    /// Bazel package
+    Bazel,
+}
+
+/// The monorepo package a stage targets
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PackageTarget {
+    pub kind: PackageKind,
+
+    /// Package directory, relative to the repository root
+    pub path: String,
+
+    /// Crate or package name, or bazel target. Defaults to the last
+    /// component of `path` (cargo, pnpm) or `//<path>/...` (bazel).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+impl PackageTarget {
+    /// Path without leading `./` or surrounding slashes
+    pub fn path(&self) -> &str {
+        self.path.trim().trim_start_matches("./").trim_matches('/')
+    }
+
+    /// Crate or package name, or bazel target
+    pub fn name(&self) -> String {
+        if let Some(name) = &self.name {
+            return name.clone();
+        }
+        match self.kind {
+            PackageKind::Bazel => format!("//{}/...", self.path()),
+            PackageKind::Cargo | PackageKind::Pnpm => self
+                .path()
+                .rsplit('/')
+                .next()
+                .unwrap_or_default()
+                .to_string(),
+        }
+    }
+
+    /// Arguments that scope a tool invocation to this package
+    pub fn selector(&self) -> String {
+        match self.kind {
+            PackageKind::Cargo => format!("-p {}", self.name()),
+            PackageKind::Pnpm => format!("--filter {}", self.name()),
+            PackageKind::Bazel => self.name(),
+        }
+    }
+
+    /// Test command used as acceptance when the stage defines none
+    pub fn test_command(&self) -> String {
+        match self.kind {
+            PackageKind::Cargo => format!("cargo test {}", self.selector()),
+            PackageKind::Pnpm => format!("pnpm {} test", self.selector()),
+            PackageKind::Bazel => format!("bazel test {}", self.selector()),
+        }
+    }
+
+    /// Glob covering the package's files
+    pub fn files_glob(&self) -> String {
+        match self.path() {
+            "" => "**".to_string(),
+            path => format!("{path}/**"),
+        }
+    }
+
+    /// Replace the `{{package...}}` references in `text`
+    pub fn render(&self, text: &str) -> String {
+        if !has_package_reference(text) {
+            return text.to_string();
+        }
+        text.replace(NAME, &self.name())
+            .replace(PATH, self.path())
+            .replace(SELECTOR, &self.selector())
+    }
+
+    /// Acceptance criteria scoped to the package: `acceptance` rendered, or
+    /// the package's test command when it is empty.
+    pub fn scope_acceptance(&self, acceptance: &[AcceptanceCriterion]) -> Vec<AcceptanceCriterion> {
+        if acceptance.is_empty() {
+            return vec![AcceptanceCriterion::Simple(self.test_command())];
+        }
+        acceptance
+            .iter()
+            .map(|criterion| match criterion {
+                AcceptanceCriterion::Simple(command) => {
+                    AcceptanceCriterion::Simple(self.render(command))
+                }
+                AcceptanceCriterion::Extended(check) => {
+                    AcceptanceCriterion::Extended(self.render_check(check))
+                }
+            })
+            .collect()
+    }
+
+    /// `checks` with their commands rendered
+    pub fn scope_checks(&self, checks: &[TruthCheck]) -> Vec<TruthCheck> {
+        checks
+            .iter()
+            .map(|check| self.render_check(check))
+            .collect()
+    }
+
+    /// `files` plus the package glob, unless already listed
+    pub fn scope_files(&self, files: &[String]) -> Vec<String> {
+        let mut files = files.to_vec();
+        let glob = self.files_glob();
+        if !files.contains(&glob) {
+            files.push(glob);
+        }
+        files
+    }
+
+    /// Check the path and name are safe to put in globs and shell commands
+    pub fn validate(&self) -> Result<(), String> {
+        let path = self.path.trim();
+        if path.starts_with('/') || path.starts_with('~') {
+            return Err(format!(
+                "package path '{path}' must be relative to the repository root"
+            ));
+        }
+        if path.split('/').any(|c| c == "..") {
+            return Err(format!("package path '{path}' must not contain '..'"));
+        }
+        if self.path().is_empty() && self.name.is_none() {
+            return Err("package path is empty; set name for a package at the root".to_string());
+        }
+        let safe = |value: &str| {
+            value.chars().all(|c| {
+                c.is_ascii_alphanumeric() || matches!(c, '@' | '/' | '.' | '_' | ':' | '+' | '-')
+            })
+        };
+        if !safe(path) {
+            return Err(format!(
+                "package path '{path}' contains unsupported characters"
+            ));
+        }
+        let name = self.name();
+        if name.is_empty() || name.starts_with('-') || !safe(&name) {
+            return Err(format!("package name '{name}' is not a valid package name"));
+        }
+        Ok(())
+    }
+
+    fn render_check(&self, check: &TruthCheck) -> TruthCheck {
+        let mut check = check.clone();
+        check.command = self.render(&check.command);
+        check
+    }
+}
+
+/// Whether `text` uses `{{package}}`, `{{package.name}}` or `{{package.path}}`
+pub fn has_package_reference(text: &str) -> bool {
+    [SELECTOR, NAME, PATH].iter().any(|r| text.contains(r))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn package(kind: PackageKind, path: &str, name: Option<&str>) -> PackageTarget {
+        PackageTarget {
+            kind,
+            path: path.to_string(),
+            name: name.map(String::from),
+        }
+    }
+
+    #[test]
+    fn test_selectors_and_default_acceptance() {
+        let cargo = package(PackageKind::Cargo, "./crates/auth/", None);
+        assert_eq!(cargo.name(), "auth");
+        assert_eq!(cargo.test_command(), "cargo test -p auth");
+        assert_eq!(cargo.files_glob(), "crates/auth/**");
+
+        let pnpm = package(PackageKind::Pnpm, "apps/web", Some("@acme/web"));
+        assert_eq!(pnpm.test_command(), "pnpm --filter @acme/web test");
+
+        let bazel = package(PackageKind::Bazel, "services/auth", None);
+        assert_eq!(bazel.test_command(), "bazel test //services/auth/...");
+
+        assert_eq!(
+            cargo.scope_acceptance(&[]),
+            vec![AcceptanceCriterion::Simple(
+                "cargo test -p auth".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_render_and_scope_files() {
+        let cargo = package(PackageKind::Cargo, "crates/auth", Some("loom-auth"));
+        assert_eq!(
+            cargo.render("cargo clippy {{package}} -- -D warnings && ls {{package.path}}"),
+            "cargo clippy -p loom-auth -- -D warnings && ls crates/auth"
+        );
+        assert_eq!(
+            cargo.render("docker inspect --format '{{.State}}'"),
+            "docker inspect --format '{{.State}}'"
+        );
+
+        let files = vec!["Cargo.toml".to_string(), "crates/auth/**".to_string()];
+        assert_eq!(cargo.scope_files(&files), files);
+        assert_eq!(cargo.scope_files(&[]), vec!["crates/auth/**".to_string()]);
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(package(PackageKind::Cargo, "crates/auth", None)
+            .validate()
+            .is_ok());
+        assert!(package(PackageKind::Bazel, "", Some("//:all"))
+            .validate()
+            .is_ok());
+        assert!(package(PackageKind::Cargo, "../other", None)
+            .validate()
+            .is_err());
+        assert!(package(PackageKind::Cargo, "/abs", None)
+            .validate()
+            .is_err());
+        assert!(package(PackageKind::Cargo, "", None).validate().is_err());
+        assert!(
+            package(PackageKind::Cargo, "crates/auth", Some("x; rm -rf /"))
+                .validate()
+                .is_err()
+        );
+    }
+}
@@ -1,6 +1,6 @@
 //! Acceptance criterion validation tests
 
-use super::make_stage;
+use super::{create_valid_metadata, make_stage};
 use crate::plan::schema::types::{AcceptanceCriterion, LoomConfig, LoomMetadata, SandboxConfig};
 use crate::plan::schema::validation::{validate, validate_acceptance_criterion};
 
@@ -82,6 +82,15 @@ fn test_validate_metadata_with_empty_acceptance() {
             sandbox: SandboxConfig::default(),
             change_impact: None,
             adjudication: None,
+            conflict_avoidance: Default::default(),
+            auto_code_review: false,
+            branch_template: None,
+            push_branches: false,
+            push_remote: None,
+            sparse_checkout: false,
+            sparse_include: Vec::new(),
+            knowledge_first: false,
+            knowledge_refresh_every: None,
             stages: vec![stage],
         },
     };
@@ -110,6 +119,15 @@ fn test_validate_metadata_with_valid_acceptance() {
             sandbox: SandboxConfig::default(),
             change_impact: None,
             adjudication: None,
+            conflict_avoidance: Default::default(),
+            auto_code_review: false,
+            branch_template: None,
+            push_branches: false,
+            push_remote: None,
+            sparse_checkout: false,
+            sparse_include: Vec::new(),
+            knowledge_first: false,
+            knowledge_refresh_every: None,
             stages: vec![stage],
         },
     };
@@ -134,6 +152,15 @@ fn test_validate_metadata_multiple_invalid_acceptance() {
             sandbox: SandboxConfig::default(),
             change_impact: None,
             adjudication: None,
+            conflict_avoidance: Default::default(),
+            auto_code_review: false,
+            branch_template: None,
+            push_branches: false,
+            push_remote: None,
+            sparse_checkout: false,
+            sparse_include: Vec::new(),
+            knowledge_first: false,
+            knowledge_refresh_every: None,
             stages: vec![stage],
         },
     };
@@ -277,3 +304,101 @@ loom:
         AcceptanceCriterion::Simple("echo ok".to_string())
     );
 }
+
+#[test]
+fn test_acceptance_criterion_yaml_flaky_retries() {
+    let yaml = r#"
+- command: "npm run e2e"
+  flaky: true
+- command: "cargo test"
+  retries: 4
+"#;
+    let criteria: Vec<AcceptanceCriterion> = serde_yaml::from_str(yaml).unwrap();
+
+    assert_eq!(
+        criteria[0].retry_count(),
+        crate::models::stage::DEFAULT_FLAKY_RETRIES
+    );
+    assert!(criteria[0].is_flaky_tracked());
+    assert_eq!(criteria[1].retry_count(), 4);
+    assert!(criteria[1].is_flaky_tracked());
+    assert!(!AcceptanceCriterion::Simple("true".to_string()).is_flaky_tracked());
+}
+
+#[test]
+fn test_validate_acceptance_criterion_retries_limit() {
+    let criteria: Vec<AcceptanceCriterion> = serde_yaml::from_str(
+        "- command: \"true\"\n  retries: 10\n- command: \"true\"\n  retries: 11\n",
+    )
+    .unwrap();
+
+    assert!(validate_acceptance_criterion(&criteria[0]).is_ok());
+    let err = validate_acceptance_criterion(&criteria[1]).unwrap_err();
+    assert!(err.contains("retries too high"));
+}
+
+#[test]
+fn test_validate_output_references_require_dependency() {
+    let mut metadata = create_valid_metadata();
+    metadata.loom.stages[1].acceptance = vec![AcceptanceCriterion::Simple(
+        "curl localhost:{{deps.stage-1.port}}".to_string(),
+    )];
+    assert!(validate(&metadata).is_ok());
+
+    metadata.loom.stages[1].setup = vec!["export URL={{deps.stage-3.url}}".to_string()];
+    let errors = validate(&metadata).unwrap_err();
+    assert!(
+        errors.iter().any(|e| e
+            .message
+            .contains("Output reference to 'stage-3' requires 'stage-3' in dependencies")),
+        "{errors:?}"
+    );
+}
+
+#[test]
+fn test_acceptance_criterion_yaml_named_with_category() {
+    let yaml = r#"
+- name: "unit tests"
+  run: "cargo test"
+  category: test
+  timeout: 600
+- "cargo clippy"
+"#;
+    let criteria: Vec<AcceptanceCriterion> = serde_yaml::from_str(yaml).unwrap();
+
+    assert_eq!(criteria[0].command(), "cargo test");
+    assert_eq!(criteria[0].name(), Some("unit tests"));
+    assert_eq!(criteria[0].category(), Some("test"));
+    assert_eq!(
+        criteria[0].timeout(),
+        Some(std::time::Duration::from_secs(600))
+    );
+    assert_eq!(criteria[0].label(), "unit tests");
+    assert_eq!(criteria[1].label(), "cargo clippy");
+    assert!(validate_acceptance_criterion(&criteria[0]).is_ok());
+
+    let invalid: Vec<AcceptanceCriterion> =
+        serde_yaml::from_str("- name: \" \"\n  run: \"true\"\n- run: \"true\"\n  timeout: 0\n")
+            .unwrap();
+    assert!(validate_acceptance_criterion(&invalid[0])
+        .unwrap_err()
+        .contains("name cannot be empty"));
+    assert!(validate_acceptance_criterion(&invalid[1])
+        .unwrap_err()
+        .contains("timeout must be at least 1 second"));
+}
+
+#[test]
+fn test_validate_duplicate_criterion_names() {
+    let mut metadata = create_valid_metadata();
+    metadata.loom.stages[0].acceptance =
+        serde_yaml::from_str("- name: tests\n  run: \"true\"\n- name: tests\n  run: \"false\"\n")
+            .unwrap();
+    let errors = validate(&metadata).unwrap_err();
+    assert!(
+        errors
+            .iter()
+            .any(|e| e.message.contains("duplicate name 'tests'")),
+        "{errors:?}"
+    );
+}
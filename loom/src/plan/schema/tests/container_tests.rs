@@ -0,0 +1,91 @@
+//! Tests for the per-stage `container` configuration (docker backend).
+
+use super::create_valid_metadata;
+use crate::plan::schema::types::{ContainerConfig, ExecutionMode, LoomMetadata};
+use crate::plan::schema::validation::validate;
+
+#[test]
+fn container_parses() {
+    let yaml = r#"
+loom:
+  version: 1
+  stages:
+    - id: s1
+      name: First Stage
+      working_dir: "."
+      artifacts: ["README.md"]
+      container:
+        image: rust:1.79
+        volumes: ["/opt/cache:/cache:ro"]
+        env: ["CARGO_HOME=/cache/cargo", "RUST_LOG"]
+"#;
+    let parsed: LoomMetadata = serde_yaml::from_str(yaml).expect("container should parse");
+    let container = parsed.loom.stages[0].container.as_ref().unwrap();
+    assert_eq!(container.image, "rust:1.79");
+    assert_eq!(container.volumes, vec!["/opt/cache:/cache:ro"]);
+    assert_eq!(container.env.len(), 2);
+}
+
+#[test]
+fn container_rejects_bad_image_volume_and_env() {
+    let mut metadata = create_valid_metadata();
+    metadata.loom.stages[0].container = Some(ContainerConfig {
+        image: "rust 1.79".to_string(),
+        volumes: vec!["/opt/cache".to_string()],
+        env: vec!["BAD-NAME=1".to_string()],
+    });
+
+    let errors = validate(&metadata).unwrap_err();
+    for expected in [
+        "container.image must be a single image reference",
+        "must be in host:container[:ro] form",
+        "must be NAME or NAME=value",
+    ] {
+        assert!(
+            errors.iter().any(|e| e.message.contains(expected)),
+            "missing '{expected}' in {errors:?}"
+        );
+    }
+}
+
+#[test]
+fn valid_container_passes_validation() {
+    let mut metadata = create_valid_metadata();
+    metadata.loom.stages[0].container = Some(ContainerConfig {
+        image: "ghcr.io/acme/toolchain:1.2".to_string(),
+        ..Default::default()
+    });
+
+    assert!(validate(&metadata).is_ok());
+}
+
+#[test]
+fn headless_execution_mode_rejects_container() {
+    let yaml = r#"
+loom:
+  version: 1
+  stages:
+    - id: s1
+      name: First Stage
+      working_dir: "."
+      execution_mode: headless
+"#;
+    let parsed: LoomMetadata = serde_yaml::from_str(yaml).expect("headless should parse");
+    assert_eq!(
+        parsed.loom.stages[0].execution_mode,
+        Some(ExecutionMode::Headless)
+    );
+
+    let mut metadata = create_valid_metadata();
+    metadata.loom.stages[0].execution_mode = Some(ExecutionMode::Headless);
+    assert!(validate(&metadata).is_ok());
+
+    metadata.loom.stages[0].container = Some(ContainerConfig {
+        image: "rust:1.79".to_string(),
+        ..Default::default()
+    });
+    let errors = validate(&metadata).unwrap_err();
+    assert!(errors.iter().any(|e| e
+        .message
+        .contains("headless cannot be combined with container")));
+}
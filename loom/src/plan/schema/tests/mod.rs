@@ -2,12 +2,17 @@
 
 mod acceptance_tests;
 mod auto_merge_tests;
+mod container_tests;
 mod knowledge_recommendations_tests;
+mod output_schema_tests;
+mod params_tests;
+mod prompt_override_tests;
 mod reasoning_effort_tests;
 mod regression_test_tests;
 mod stage_id_tests;
 mod ultracode_tests;
 mod validation_tests;
+mod worktree_setup_tests;
 
 use super::types::{
     AcceptanceCriterion, LoomConfig, LoomMetadata, SandboxConfig, StageDefinition,
@@ -44,6 +49,20 @@ pub(crate) fn make_stage(id: &str, name: &str) -> StageDefinition {
         reasoning_effort: None,
         code_review: None,
         ultracode: false,
+        pair: false,
+        worktree_setup: vec![],
+        instructions: None,
+        prompt_prefix: None,
+        container: None,
+        keep_terminal: None,
+        outputs: Vec::new(),
+        params: Vec::new(),
+        requires_outputs: Vec::new(),
+        new_files: Vec::new(),
+        expected_duration: None,
+        acceptance_isolation: None,
+        skip_if_satisfied: false,
+        package: None,
     }
 }
 
@@ -67,6 +86,15 @@ pub(crate) fn create_valid_metadata() -> LoomMetadata {
             sandbox: SandboxConfig::default(),
             change_impact: None,
             adjudication: None,
+            conflict_avoidance: Default::default(),
+            auto_code_review: false,
+            branch_template: None,
+            push_branches: false,
+            push_remote: None,
+            sparse_checkout: false,
+            sparse_include: Vec::new(),
+            knowledge_first: false,
+            knowledge_refresh_every: None,
             stages: vec![stage1, stage2],
         },
     }
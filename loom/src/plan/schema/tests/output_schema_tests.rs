@@ -0,0 +1,138 @@
+//! Tests for declared stage `outputs` (output schema).
+
+use super::create_valid_metadata;
+use crate::models::stage::OutputType;
+use crate::plan::schema::types::{LoomMetadata, OutputSpec};
+use crate::plan::schema::validation::validate;
+
+#[test]
+fn outputs_parse_with_types() {
+    let yaml = r#"
+loom:
+  version: 1
+  stages:
+    - id: s1
+      name: First Stage
+      working_dir: "."
+      artifacts: ["README.md"]
+      outputs:
+        - key: api_port
+          type: int
+          required: true
+        - key: base_url
+          type: string
+          description: URL the API is served from
+        - key: features
+          type: array
+"#;
+    let parsed: LoomMetadata = serde_yaml::from_str(yaml).expect("outputs should parse");
+    let outputs = &parsed.loom.stages[0].outputs;
+    assert_eq!(outputs.len(), 3);
+    assert_eq!(outputs[0].output_type, Some(OutputType::Int));
+    assert!(outputs[0].required);
+    assert!(!outputs[1].required);
+    assert_eq!(
+        outputs[1].description.as_deref(),
+        Some("URL the API is served from")
+    );
+}
+
+#[test]
+fn outputs_reject_unknown_type() {
+    let yaml = r#"
+loom:
+  version: 1
+  stages:
+    - id: s1
+      name: First Stage
+      working_dir: "."
+      outputs:
+        - key: api_port
+          type: port
+"#;
+    assert!(serde_yaml::from_str::<LoomMetadata>(yaml).is_err());
+}
+
+#[test]
+fn outputs_reject_bad_and_duplicate_keys() {
+    let mut metadata = create_valid_metadata();
+    let spec = |key: &str| OutputSpec {
+        key: key.to_string(),
+        output_type: Some(OutputType::String),
+        required: true,
+        description: None,
+    };
+    metadata.loom.stages[0].outputs = vec![spec("api port"), spec("url"), spec("url")];
+
+    let errors = validate(&metadata).unwrap_err();
+    for expected in [
+        "output key 'api port' must be 1-64",
+        "output key 'url' is declared more than once",
+    ] {
+        assert!(
+            errors.iter().any(|e| e.message.contains(expected)),
+            "missing error '{expected}': {errors:?}"
+        );
+    }
+}
+
+#[test]
+fn requires_outputs_parse_and_validate() {
+    let yaml = r#"
+loom:
+  version: 1
+  stages:
+    - id: stage-x
+      name: Producer
+      working_dir: "."
+      artifacts: ["README.md"]
+      outputs:
+        - key: schema_version
+          type: int
+    - id: stage-y
+      name: Consumer
+      working_dir: "."
+      dependencies: [stage-x]
+      requires_outputs: [stage-x.schema_version]
+"#;
+    let parsed: LoomMetadata = serde_yaml::from_str(yaml).expect("requires_outputs should parse");
+    assert_eq!(
+        parsed.loom.stages[1].requires_outputs,
+        vec!["stage-x.schema_version".to_string()]
+    );
+    let errors = validate(&parsed).err().unwrap_or_default();
+    assert!(
+        !errors
+            .iter()
+            .any(|e| e.message.contains("requires_outputs")),
+        "{errors:?}"
+    );
+}
+
+#[test]
+fn requires_outputs_reject_bad_references() {
+    let mut metadata = create_valid_metadata();
+    metadata.loom.stages[0].outputs = vec![OutputSpec {
+        key: "schema_version".to_string(),
+        output_type: Some(OutputType::Int),
+        required: true,
+        description: None,
+    }];
+    metadata.loom.stages[1].requires_outputs = vec![
+        "schema_version".to_string(),
+        "stage-3.schema_version".to_string(),
+        "stage-1.api_port".to_string(),
+    ];
+
+    let errors = validate(&metadata).unwrap_err();
+    for expected in [
+        "'schema_version' must be <stage-id>.<output-key>",
+        "requires 'stage-3' in dependencies",
+        "'stage-1' declares no output 'api_port'",
+    ] {
+        assert!(
+            errors.iter().any(|e| e.message.contains(expected)),
+            "missing error '{expected}': {errors:?}"
+        );
+    }
+}
@@ -0,0 +1,74 @@
+//! Tests for run-time stage `params`.
+
+use super::create_valid_metadata;
+use crate::models::stage::AcceptanceCriterion;
+use crate::plan::schema::types::{LoomMetadata, StageParam};
+use crate::plan::schema::validation::validate;
+
+#[test]
+fn params_parse_and_validate() {
+    let yaml = r#"
+loom:
+  version: 1
+  stages:
+    - id: deploy
+      name: Deploy
+      working_dir: "."
+      params:
+        - name: target_env
+          choices: [staging, prod]
+          default: staging
+        - name: release_tag
+          description: Tag to deploy
+      acceptance:
+        - "./scripts/smoke.sh {{params.target_env}} {{params.release_tag}}"
+"#;
+    let parsed: LoomMetadata = serde_yaml::from_str(yaml).expect("params should parse");
+    let params = &parsed.loom.stages[0].params;
+    assert_eq!(params.len(), 2);
+    assert_eq!(params[0].choices, vec!["staging", "prod"]);
+    assert_eq!(params[0].default.as_deref(), Some("staging"));
+    assert!(params[1].choices.is_empty());
+
+    let errors = validate(&parsed).err().unwrap_or_default();
+    assert!(
+        !errors.iter().any(|e| e.message.contains("param")),
+        "{errors:?}"
+    );
+}
+
+#[test]
+fn params_reject_bad_declarations_and_references() {
+    let mut metadata = create_valid_metadata();
+    let param = |name: &str, default: Option<&str>| StageParam {
+        name: name.to_string(),
+        description: None,
+        choices: vec!["staging".to_string(), "prod".to_string()],
+        default: default.map(String::from),
+    };
+    let stage = &mut metadata.loom.stages[0];
+    stage.params = vec![
+        param("target_env", Some("dev")),
+        param("target_env", None),
+        param("bad name", None),
+    ];
+    stage.acceptance = vec![AcceptanceCriterion::Simple(
+        "deploy {{params.target_env}} {{params.region}}".to_string(),
+    )];
+
+    let errors = validate(&metadata).unwrap_err();
+    for expected in [
+        "default 'dev' is not one of its choices",
+        "param 'target_env' is declared more than once",
+        "param name 'bad name' must be",
+        "'{{params.region}}' requires 'region' in params",
+    ] {
+        assert!(
+            errors.iter().any(|e| e.message.contains(expected)),
+            "missing error '{expected}': {errors:?}"
+        );
+    }
+    assert!(!errors
+        .iter()
+        .any(|e| e.message.contains("'{{params.target_env}}'")));
+}
@@ -0,0 +1,59 @@
+//! Tests for the per-stage `instructions` and `prompt_prefix` overrides.
+//!
+//! `instructions` is appended to the stage's signal file; `prompt_prefix`
+//! replaces the generic initial prompt given to the agent session.
+
+use super::{create_valid_metadata, make_stage};
+use crate::plan::schema::types::LoomMetadata;
+use crate::plan::schema::validation::validate;
+
+#[test]
+fn prompt_overrides_parse() {
+    let yaml = r#"
+loom:
+  version: 1
+  stages:
+    - id: s1
+      name: First Stage
+      working_dir: "."
+      artifacts: ["README.md"]
+      instructions: |
+        Produce a written plan in PLAN.md before touching code.
+      prompt_prefix: "Plan first, don't edit."
+"#;
+    let parsed: LoomMetadata = serde_yaml::from_str(yaml).expect("overrides should parse");
+    let stage = &parsed.loom.stages[0];
+    assert_eq!(
+        stage.instructions.as_deref(),
+        Some("Produce a written plan in PLAN.md before touching code.\n")
+    );
+    assert_eq!(
+        stage.prompt_prefix.as_deref(),
+        Some("Plan first, don't edit.")
+    );
+}
+
+#[test]
+fn prompt_overrides_default_to_none() {
+    let stage = make_stage("s1", "First Stage");
+    assert!(stage.instructions.is_none());
+    assert!(stage.prompt_prefix.is_none());
+}
+
+#[test]
+fn prompt_overrides_reject_blank_and_oversized_values() {
+    let mut metadata = create_valid_metadata();
+    metadata.loom.stages[0].instructions = Some("   ".to_string());
+    metadata.loom.stages[0].prompt_prefix = Some("x".repeat(2_001));
+
+    let errors = validate(&metadata).unwrap_err();
+    assert!(errors
+        .iter()
+        .any(|e| e.message.contains("instructions cannot be empty")));
+    assert!(
+        errors.iter().any(|e| e
+            .message
+            .contains("prompt_prefix too long (2001 chars, max 2000)")),
+        "got: {errors:?}"
+    );
+}
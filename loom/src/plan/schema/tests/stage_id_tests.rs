@@ -15,6 +15,15 @@ fn test_validate_stage_id_path_traversal() {
             sandbox: SandboxConfig::default(),
             change_impact: None,
             adjudication: None,
+            conflict_avoidance: Default::default(),
+            auto_code_review: false,
+            branch_template: None,
+            push_branches: false,
+            push_remote: None,
+            sparse_checkout: false,
+            sparse_include: Vec::new(),
+            knowledge_first: false,
+            knowledge_refresh_every: None,
             stages: vec![stage],
         },
     };
@@ -38,6 +47,15 @@ fn test_validate_stage_id_with_slashes() {
             sandbox: SandboxConfig::default(),
             change_impact: None,
             adjudication: None,
+            conflict_avoidance: Default::default(),
+            auto_code_review: false,
+            branch_template: None,
+            push_branches: false,
+            push_remote: None,
+            sparse_checkout: false,
+            sparse_include: Vec::new(),
+            knowledge_first: false,
+            knowledge_refresh_every: None,
             stages: vec![stage],
         },
     };
@@ -61,6 +79,15 @@ fn test_validate_stage_id_with_dots() {
             sandbox: SandboxConfig::default(),
             change_impact: None,
             adjudication: None,
+            conflict_avoidance: Default::default(),
+            auto_code_review: false,
+            branch_template: None,
+            push_branches: false,
+            push_remote: None,
+            sparse_checkout: false,
+            sparse_include: Vec::new(),
+            knowledge_first: false,
+            knowledge_refresh_every: None,
             stages: vec![stage],
         },
     };
@@ -84,6 +111,15 @@ fn test_validate_stage_id_reserved_name_dotdot() {
             sandbox: SandboxConfig::default(),
             change_impact: None,
             adjudication: None,
+            conflict_avoidance: Default::default(),
+            auto_code_review: false,
+            branch_template: None,
+            push_branches: false,
+            push_remote: None,
+            sparse_checkout: false,
+            sparse_include: Vec::new(),
+            knowledge_first: false,
+            knowledge_refresh_every: None,
             stages: vec![stage],
         },
     };
@@ -103,6 +139,15 @@ fn test_validate_stage_id_reserved_name_con() {
             sandbox: SandboxConfig::default(),
             change_impact: None,
             adjudication: None,
+            conflict_avoidance: Default::default(),
+            auto_code_review: false,
+            branch_template: None,
+            push_branches: false,
+            push_remote: None,
+            sparse_checkout: false,
+            sparse_include: Vec::new(),
+            knowledge_first: false,
+            knowledge_refresh_every: None,
             stages: vec![stage],
         },
     };
@@ -125,6 +170,15 @@ fn test_validate_dependency_id_path_traversal() {
             sandbox: SandboxConfig::default(),
             change_impact: None,
             adjudication: None,
+            conflict_avoidance: Default::default(),
+            auto_code_review: false,
+            branch_template: None,
+            push_branches: false,
+            push_remote: None,
+            sparse_checkout: false,
+            sparse_include: Vec::new(),
+            knowledge_first: false,
+            knowledge_refresh_every: None,
             stages: vec![stage],
         },
     };
@@ -149,6 +203,15 @@ fn test_validate_stage_id_too_long() {
             sandbox: SandboxConfig::default(),
             change_impact: None,
             adjudication: None,
+            conflict_avoidance: Default::default(),
+            auto_code_review: false,
+            branch_template: None,
+            push_branches: false,
+            push_remote: None,
+            sparse_checkout: false,
+            sparse_include: Vec::new(),
+            knowledge_first: false,
+            knowledge_refresh_every: None,
             stages: vec![stage],
         },
     };
@@ -170,6 +233,15 @@ fn test_validate_stage_id_with_spaces() {
             sandbox: SandboxConfig::default(),
             change_impact: None,
             adjudication: None,
+            conflict_avoidance: Default::default(),
+            auto_code_review: false,
+            branch_template: None,
+            push_branches: false,
+            push_remote: None,
+            sparse_checkout: false,
+            sparse_include: Vec::new(),
+            knowledge_first: false,
+            knowledge_refresh_every: None,
             stages: vec![stage],
         },
     };
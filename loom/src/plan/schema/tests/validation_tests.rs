@@ -6,7 +6,10 @@ use crate::plan::schema::types::{
     AcceptanceCriterion, LoomConfig, LoomMetadata, SandboxConfig, StageDefinition, StageType,
     ValidationError, WiringTest,
 };
-use crate::plan::schema::validation::{validate, validate_structural_preflight};
+use crate::plan::schema::validation::{
+    check_working_dirs, validate, validate_structural_preflight,
+};
+use crate::plan::schema::{PackageKind, PackageTarget};
 
 #[test]
 fn test_validate_valid_metadata() {
@@ -27,6 +30,15 @@ fn test_validate_unsupported_version() {
             sandbox: SandboxConfig::default(),
             change_impact: None,
             adjudication: None,
+            conflict_avoidance: Default::default(),
+            auto_code_review: false,
+            branch_template: None,
+            push_branches: false,
+            push_remote: None,
+            sparse_checkout: false,
+            sparse_include: Vec::new(),
+            knowledge_first: false,
+            knowledge_refresh_every: None,
             stages: vec![stage],
         },
     };
@@ -47,6 +59,15 @@ fn test_validate_empty_stages() {
             sandbox: SandboxConfig::default(),
             change_impact: None,
             adjudication: None,
+            conflict_avoidance: Default::default(),
+            auto_code_review: false,
+            branch_template: None,
+            push_branches: false,
+            push_remote: None,
+            sparse_checkout: false,
+            sparse_include: Vec::new(),
+            knowledge_first: false,
+            knowledge_refresh_every: None,
             stages: vec![],
         },
     };
@@ -70,6 +91,15 @@ fn test_validate_empty_stage_id() {
             sandbox: SandboxConfig::default(),
             change_impact: None,
             adjudication: None,
+            conflict_avoidance: Default::default(),
+            auto_code_review: false,
+            branch_template: None,
+            push_branches: false,
+            push_remote: None,
+            sparse_checkout: false,
+            sparse_include: Vec::new(),
+            knowledge_first: false,
+            knowledge_refresh_every: None,
             stages: vec![stage],
         },
     };
@@ -93,6 +123,15 @@ fn test_validate_empty_stage_name() {
             sandbox: SandboxConfig::default(),
             change_impact: None,
             adjudication: None,
+            conflict_avoidance: Default::default(),
+            auto_code_review: false,
+            branch_template: None,
+            push_branches: false,
+            push_remote: None,
+            sparse_checkout: false,
+            sparse_include: Vec::new(),
+            knowledge_first: false,
+            knowledge_refresh_every: None,
             stages: vec![stage],
         },
     };
@@ -117,6 +156,15 @@ fn test_validate_unknown_dependency() {
             sandbox: SandboxConfig::default(),
             change_impact: None,
             adjudication: None,
+            conflict_avoidance: Default::default(),
+            auto_code_review: false,
+            branch_template: None,
+            push_branches: false,
+            push_remote: None,
+            sparse_checkout: false,
+            sparse_include: Vec::new(),
+            knowledge_first: false,
+            knowledge_refresh_every: None,
             stages: vec![stage],
         },
     };
@@ -142,6 +190,15 @@ fn test_validate_self_dependency() {
             sandbox: SandboxConfig::default(),
             change_impact: None,
             adjudication: None,
+            conflict_avoidance: Default::default(),
+            auto_code_review: false,
+            branch_template: None,
+            push_branches: false,
+            push_remote: None,
+            sparse_checkout: false,
+            sparse_include: Vec::new(),
+            knowledge_first: false,
+            knowledge_refresh_every: None,
             stages: vec![stage],
         },
     };
@@ -167,6 +224,15 @@ fn test_validate_multiple_errors() {
             sandbox: SandboxConfig::default(),
             change_impact: None,
             adjudication: None,
+            conflict_avoidance: Default::default(),
+            auto_code_review: false,
+            branch_template: None,
+            push_branches: false,
+            push_remote: None,
+            sparse_checkout: false,
+            sparse_include: Vec::new(),
+            knowledge_first: false,
+            knowledge_refresh_every: None,
             stages: vec![stage1, stage2],
         },
     };
@@ -236,6 +302,15 @@ fn test_complex_dependency_chain() {
             sandbox: SandboxConfig::default(),
             change_impact: None,
             adjudication: None,
+            conflict_avoidance: Default::default(),
+            auto_code_review: false,
+            branch_template: None,
+            push_branches: false,
+            push_remote: None,
+            sparse_checkout: false,
+            sparse_include: Vec::new(),
+            knowledge_first: false,
+            knowledge_refresh_every: None,
             stages: vec![stage1, stage2, stage3],
         },
     };
@@ -255,6 +330,15 @@ fn test_validate_duplicate_stage_ids() {
             sandbox: SandboxConfig::default(),
             change_impact: None,
             adjudication: None,
+            conflict_avoidance: Default::default(),
+            auto_code_review: false,
+            branch_template: None,
+            push_branches: false,
+            push_remote: None,
+            sparse_checkout: false,
+            sparse_include: Vec::new(),
+            knowledge_first: false,
+            knowledge_refresh_every: None,
             stages: vec![stage1, stage2],
         },
     };
@@ -279,6 +363,15 @@ fn test_validate_working_dir_path_traversal() {
             sandbox: SandboxConfig::default(),
             change_impact: None,
             adjudication: None,
+            conflict_avoidance: Default::default(),
+            auto_code_review: false,
+            branch_template: None,
+            push_branches: false,
+            push_remote: None,
+            sparse_checkout: false,
+            sparse_include: Vec::new(),
+            knowledge_first: false,
+            knowledge_refresh_every: None,
             stages: vec![stage],
         },
     };
@@ -301,6 +394,15 @@ fn test_validate_working_dir_absolute_path() {
             sandbox: SandboxConfig::default(),
             change_impact: None,
             adjudication: None,
+            conflict_avoidance: Default::default(),
+            auto_code_review: false,
+            branch_template: None,
+            push_branches: false,
+            push_remote: None,
+            sparse_checkout: false,
+            sparse_include: Vec::new(),
+            knowledge_first: false,
+            knowledge_refresh_every: None,
             stages: vec![stage],
         },
     };
@@ -326,6 +428,15 @@ fn test_validate_working_dir_valid_subdirectory() {
             sandbox: SandboxConfig::default(),
             change_impact: None,
             adjudication: None,
+            conflict_avoidance: Default::default(),
+            auto_code_review: false,
+            branch_template: None,
+            push_branches: false,
+            push_remote: None,
+            sparse_checkout: false,
+            sparse_include: Vec::new(),
+            knowledge_first: false,
+            knowledge_refresh_every: None,
             stages: vec![stage],
         },
     };
@@ -350,6 +461,15 @@ fn test_integration_verify_requires_goal_backward() {
             sandbox: SandboxConfig::default(),
             change_impact: None,
             adjudication: None,
+            conflict_avoidance: Default::default(),
+            auto_code_review: false,
+            branch_template: None,
+            push_branches: false,
+            push_remote: None,
+            sparse_checkout: false,
+            sparse_include: Vec::new(),
+            knowledge_first: false,
+            knowledge_refresh_every: None,
             stages: vec![stage],
         },
     };
@@ -375,6 +495,15 @@ fn test_integration_verify_with_artifacts_passes() {
             sandbox: SandboxConfig::default(),
             change_impact: None,
             adjudication: None,
+            conflict_avoidance: Default::default(),
+            auto_code_review: false,
+            branch_template: None,
+            push_branches: false,
+            push_remote: None,
+            sparse_checkout: false,
+            sparse_include: Vec::new(),
+            knowledge_first: false,
+            knowledge_refresh_every: None,
             stages: vec![stage],
         },
     };
@@ -400,6 +529,15 @@ fn test_integration_verify_with_wiring_tests_passes() {
             sandbox: SandboxConfig::default(),
             change_impact: None,
             adjudication: None,
+            conflict_avoidance: Default::default(),
+            auto_code_review: false,
+            branch_template: None,
+            push_branches: false,
+            push_remote: None,
+            sparse_checkout: false,
+            sparse_include: Vec::new(),
+            knowledge_first: false,
+            knowledge_refresh_every: None,
             stages: vec![stage],
         },
     };
@@ -424,6 +562,15 @@ fn test_integration_verify_with_wiring_passes() {
             sandbox: SandboxConfig::default(),
             change_impact: None,
             adjudication: None,
+            conflict_avoidance: Default::default(),
+            auto_code_review: false,
+            branch_template: None,
+            push_branches: false,
+            push_remote: None,
+            sparse_checkout: false,
+            sparse_include: Vec::new(),
+            knowledge_first: false,
+            knowledge_refresh_every: None,
             stages: vec![stage],
         },
     };
@@ -444,6 +591,15 @@ fn test_knowledge_stage_exempt_from_goal_backward() {
             sandbox: SandboxConfig::default(),
             change_impact: None,
             adjudication: None,
+            conflict_avoidance: Default::default(),
+            auto_code_review: false,
+            branch_template: None,
+            push_branches: false,
+            push_remote: None,
+            sparse_checkout: false,
+            sparse_include: Vec::new(),
+            knowledge_first: false,
+            knowledge_refresh_every: None,
             stages: vec![stage],
         },
     };
@@ -486,6 +642,36 @@ fn test_preflight_no_warning_when_working_dir_is_dot() {
         .all(|w| !w.contains("redundant working_dir prefix")));
 }
 
+#[test]
+fn test_check_working_dirs() {
+    let dir = tempfile::TempDir::new().unwrap();
+    std::fs::create_dir(dir.path().join("app")).unwrap();
+    std::fs::write(dir.path().join("file.txt"), "").unwrap();
+
+    let mut root = make_stage("root", "Root");
+    root.working_dir = "app".to_string();
+    let mut dependent = make_stage("dependent", "Dependent");
+    dependent.working_dir = "generated".to_string();
+    dependent.dependencies = vec!["root".to_string()];
+    let warnings = check_working_dirs(&[root.clone(), dependent], dir.path()).unwrap();
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("'generated' does not exist yet"));
+
+    let mut missing = make_stage("missing", "Missing");
+    missing.working_dir = "nope".to_string();
+    let mut file = make_stage("file", "File");
+    file.working_dir = "file.txt".to_string();
+    let errors = check_working_dirs(&[root, missing, file], dir.path()).unwrap_err();
+    let messages: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+    assert_eq!(
+        messages,
+        vec![
+            "Stage 'missing': working_dir 'nope' does not exist in the repository",
+            "Stage 'file': working_dir 'file.txt' is not a directory",
+        ]
+    );
+}
+
 #[test]
 fn test_preflight_warns_on_short_wiring_pattern() {
     let mut stage = make_stage("stage-1", "Stage One");
@@ -582,6 +768,15 @@ fn test_knowledge_distill_exempt_from_goal_backward() {
             sandbox: SandboxConfig::default(),
             change_impact: None,
             adjudication: None,
+            conflict_avoidance: Default::default(),
+            auto_code_review: false,
+            branch_template: None,
+            push_branches: false,
+            push_remote: None,
+            sparse_checkout: false,
+            sparse_include: Vec::new(),
+            knowledge_first: false,
+            knowledge_refresh_every: None,
             stages: vec![stage],
         },
     };
@@ -608,6 +803,15 @@ fn test_knowledge_distill_with_acceptance_passes() {
             sandbox: SandboxConfig::default(),
             change_impact: None,
             adjudication: None,
+            conflict_avoidance: Default::default(),
+            auto_code_review: false,
+            branch_template: None,
+            push_branches: false,
+            push_remote: None,
+            sparse_checkout: false,
+            sparse_include: Vec::new(),
+            knowledge_first: false,
+            knowledge_refresh_every: None,
             stages: vec![stage],
         },
     };
@@ -619,3 +823,78 @@ fn test_knowledge_distill_with_acceptance_passes() {
         result.err()
     );
 }
+
+#[test]
+fn test_expected_duration_must_parse() {
+    let mut metadata = create_valid_metadata();
+    metadata.loom.stages[0].expected_duration = Some("1h30m".to_string());
+    assert!(validate(&metadata).is_ok());
+
+    metadata.loom.stages[0].expected_duration = Some("soon".to_string());
+    let errors = validate(&metadata).unwrap_err();
+    assert!(errors
+        .iter()
+        .any(|e| e.message.contains("Invalid expected_duration 'soon'")));
+}
+
+#[test]
+fn test_skip_if_satisfied_needs_checks() {
+    let mut metadata = create_valid_metadata();
+    metadata.loom.stages[0].skip_if_satisfied = true;
+    metadata.loom.stages[0].acceptance.clear();
+    metadata.loom.stages[0].after_stage.clear();
+    let errors = validate(&metadata).unwrap_err();
+    assert!(errors.iter().any(|e| e
+        .message
+        .contains("skip_if_satisfied needs acceptance criteria")));
+
+    metadata.loom.stages[0].acceptance = vec![AcceptanceCriterion::Simple("cargo test".into())];
+    assert!(validate(&metadata).is_ok());
+}
+
+#[test]
+fn test_read_scope_globs_must_be_worktree_relative() {
+    let mut metadata = create_valid_metadata();
+    metadata.loom.stages[0].sandbox.readable_paths = vec!["src/api/**".into()];
+    metadata.loom.stages[0].sandbox.forbidden_paths = vec!["vendor/**".into()];
+    assert!(validate(&metadata).is_ok());
+
+    for bad in ["/etc/**", "../other/**", "src/[api"] {
+        metadata.loom.stages[0].sandbox.forbidden_paths = vec![bad.into()];
+        let errors = validate(&metadata).unwrap_err();
+        assert!(
+            errors
+                .iter()
+                .any(|e| e.message.contains("forbidden_paths glob pattern")),
+            "expected an error for {bad}"
+        );
+    }
+}
+
+#[test]
+fn test_package_supplies_acceptance_and_references_need_a_package() {
+    let mut metadata = create_valid_metadata();
+    metadata.loom.stages[0].acceptance = vec![];
+    metadata.loom.stages[0].package = Some(PackageTarget {
+        kind: PackageKind::Cargo,
+        path: "crates/auth".into(),
+        name: None,
+    });
+    assert!(validate(&metadata).is_ok());
+
+    metadata.loom.stages[0].package = Some(PackageTarget {
+        kind: PackageKind::Pnpm,
+        path: "../web".into(),
+        name: None,
+    });
+    let errors = validate(&metadata).unwrap_err();
+    assert!(errors.iter().any(|e| e.message.contains("Invalid package")));
+
+    metadata.loom.stages[0].package = None;
+    metadata.loom.stages[0].acceptance =
+        vec![AcceptanceCriterion::Simple("cargo test {{package}}".into())];
+    let errors = validate(&metadata).unwrap_err();
+    assert!(errors
+        .iter()
+        .any(|e| e.message.contains("requires a package on the stage")));
+}
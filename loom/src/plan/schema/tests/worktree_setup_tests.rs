@@ -0,0 +1,60 @@
+//! Tests for the per-stage `worktree_setup` command list.
+//!
+//! `worktree_setup` commands are run by the orchestrator (not the agent) in a
+//! stage's worktree before any session is spawned.
+
+use super::{create_valid_metadata, make_stage};
+use crate::plan::schema::types::LoomMetadata;
+use crate::plan::schema::validation::validate;
+
+#[test]
+fn worktree_setup_parses() {
+    let yaml = r#"
+loom:
+  version: 1
+  stages:
+    - id: s1
+      name: First Stage
+      working_dir: "."
+      artifacts: ["README.md"]
+      worktree_setup:
+        - "npm ci"
+        - "cp ../../.env .env"
+"#;
+    let parsed: LoomMetadata = serde_yaml::from_str(yaml).expect("worktree_setup should parse");
+    assert_eq!(
+        parsed.loom.stages[0].worktree_setup,
+        vec!["npm ci".to_string(), "cp ../../.env .env".to_string()]
+    );
+}
+
+#[test]
+fn worktree_setup_defaults_to_empty() {
+    let stage = make_stage("s1", "First Stage");
+    assert!(stage.worktree_setup.is_empty());
+}
+
+#[test]
+fn worktree_setup_rejects_empty_command() {
+    let mut metadata = create_valid_metadata();
+    metadata.loom.stages[0].worktree_setup = vec!["cargo fetch".to_string(), "  ".to_string()];
+
+    let errors = validate(&metadata).unwrap_err();
+    assert!(
+        errors.iter().any(|e| e
+            .message
+            .contains("worktree_setup command #2 cannot be empty")),
+        "expected empty-command error, got: {errors:?}"
+    );
+}
+
+#[test]
+fn worktree_setup_rejects_too_many_commands() {
+    let mut metadata = create_valid_metadata();
+    metadata.loom.stages[0].worktree_setup = (0..21).map(|i| format!("echo {i}")).collect();
+
+    let errors = validate(&metadata).unwrap_err();
+    assert!(errors
+        .iter()
+        .any(|e| e.message.contains("Too many worktree_setup commands")));
+}
@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Deserializer, Serialize};
 
+use super::package::PackageTarget;
+
 /// Claude Code permission mode controlling default tool-approval behavior.
 ///
 /// Re-exported from models::stage for backward compatibility.
@@ -66,6 +68,21 @@ impl Default for SandboxConfig {
 /// The canonical definition is in crate::models::stage::StageSandboxConfig.
 pub use crate::models::stage::StageSandboxConfig;
 
+/// Container configuration for the docker backend
+///
+/// The canonical definition is in crate::models::stage::ContainerConfig.
+pub use crate::models::stage::ContainerConfig;
+
+/// Declared stage output checked at completion
+///
+/// The canonical definition is in crate::models::stage::OutputSpec.
+pub use crate::models::stage::OutputSpec;
+
+/// Run-time parameter a stage asks for
+///
+/// The canonical definition is in crate::models::stage::StageParam.
+pub use crate::models::stage::StageParam;
+
 /// Filesystem access configuration
 ///
 /// Re-exported from models::stage for backward compatibility.
@@ -148,9 +165,55 @@ pub struct LoomConfig {
     /// limits). When omitted, defaults apply via [`AdjudicationConfig::default`].
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub adjudication: Option<AdjudicationConfig>,
+    /// How stages predicted to conflict are scheduled
+    #[serde(default)]
+    pub conflict_avoidance: ConflictAvoidance,
+    /// Create a code-review stage for every completed standard stage and
+    /// merge the stage only once the review approves it
+    #[serde(default)]
+    pub auto_code_review: bool,
+    /// Stage branch name template, e.g. `ai/{plan}/{stage}` (see
+    /// [`crate::namespace`]); unset keeps `loom/[<ns>/]<stage>`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch_template: Option<String>,
+    /// Push each stage branch to a remote when the stage completes, and
+    /// again after retries
+    #[serde(default)]
+    pub push_branches: bool,
+    /// Remote for `push_branches` (default `origin`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub push_remote: Option<String>,
+    /// Check out only the files a stage works on: stage worktrees are
+    /// created with a sparse checkout derived from the stage's `files`
+    #[serde(default)]
+    pub sparse_checkout: bool,
+    /// Extra paths checked out in every sparse worktree (e.g. shared
+    /// crates or config), in addition to top-level files
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sparse_include: Vec<String>,
+    /// Hold back every other stage until the plan's knowledge stages have
+    /// completed
+    #[serde(default)]
+    pub knowledge_first: bool,
+    /// Run a knowledge refresh stage after every N merged standard stages
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub knowledge_refresh_every: Option<u32>,
     pub stages: Vec<StageDefinition>,
 }
 
+/// Scheduling policy for parallel stages predicted to conflict (see
+/// [`crate::plan::graph::conflict_risk`]).
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ConflictAvoidance {
+    /// Report the risk in `loom status` and `loom graph` only
+    #[default]
+    Warn,
+    /// Never run a high-risk pair at the same time: the later stage waits
+    /// until the earlier one finishes
+    Strict,
+}
+
 /// Plan-level adjudication / amendment configuration.
 ///
 /// Caps the number of runtime amendments that may be applied to a single
@@ -232,7 +295,7 @@ pub struct StageDefinition {
     /// Per-stage sandbox configuration (overrides plan-level defaults)
     #[serde(default)]
     pub sandbox: StageSandboxConfig,
-    /// Hint for execution mode (single agent vs team)
+    /// Execution mode (single agent, team, or headless)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub execution_mode: Option<ExecutionMode>,
     /// Whether this stage is a bug fix that requires a regression test
@@ -264,9 +327,98 @@ pub struct StageDefinition {
     /// per-stage opt-in keeps the cost decision explicit.
     #[serde(default)]
     pub ultracode: bool,
+    /// Experimental pair mode: run a read-only reviewer/tester session next
+    /// to the implementer; the stage completes only once the reviewer
+    /// approves the implementer's latest commit.
+    #[serde(default)]
+    pub pair: bool,
+    /// Commands loom itself runs in the stage's worktree before any session is
+    /// spawned (e.g. `npm ci`, `cargo fetch`, `cp ../.env .env`). Unlike `setup`,
+    /// which the agent runs, these are executed by the orchestrator with output
+    /// logged to `.work/logs/worktree-setup/<stage-id>.log`; the first failing
+    /// command marks the stage Blocked. Commands run from the worktree root
+    /// once per worktree: retries and resumes on an existing worktree skip
+    /// them after they have succeeded.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub worktree_setup: Vec<String>,
+    /// Extra instructions for this stage's agent (e.g. "plan first, don't
+    /// edit"), appended to the signal file's dynamic section.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+    /// Initial prompt that replaces the generic "Read the signal file..."
+    /// prompt. The signal file path is appended so the session can still
+    /// find its assignment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prompt_prefix: Option<String>,
+    /// Run the agent session inside this container image instead of on the
+    /// host, for stages that need a pinned toolchain.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub container: Option<ContainerConfig>,
+    /// Keep the session's terminal window open after the agent exits, for
+    /// inspection, or close it (`false`). Unset uses `[terminal] keep_open`
+    /// from `.work/config.toml`; `loom run --keep-terminal` overrides both.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keep_terminal: Option<bool>,
+    /// Outputs this stage must set before it can complete, with their types
+    /// (e.g. `{key: api_port, type: int, required: true}`). Rendered in this
+    /// stage's signal and in the signals of its dependents.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub outputs: Vec<OutputSpec>,
+    /// Values asked for when the plan runs (e.g.
+    /// `{name: target_env, choices: [staging, prod]}`), stored in
+    /// `.work/params.json` and used in commands as `{{params.<name>}}`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub params: Vec<StageParam>,
+    /// Dependency outputs this stage needs, as `<stage-id>.<key>` (e.g.
+    /// `stage-x.schema_version`). The stage does not start until each named
+    /// dependency is merged AND has set the output.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub requires_outputs: Vec<String>,
+    /// Paths or globs the stage creates. Pre-flight does not require `files`
+    /// entries or acceptance scripts under them to exist before the stage runs.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub new_files: Vec<String>,
+    /// How long the stage should take to execute (e.g. `45m`, `1h30m`).
+    /// `loom status` and `loom graph` flag the stage once it runs well past
+    /// this, and the daemon sends a notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_duration: Option<String>,
+    /// Run acceptance criteria in a clean environment or inside the stage's
+    /// sandbox profile, so they cannot pass on variables that only exist on
+    /// the developer's machine. Criteria can override it with `isolation`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub acceptance_isolation: Option<EnvIsolation>,
+    /// Before spawning a session, run the acceptance criteria and
+    /// `after_stage` checks in the stage's fresh worktree (the resolved base).
+    /// If they all pass, the work is already there: the stage is Skipped as
+    /// "already satisfied" and its dependents are unblocked.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub skip_if_satisfied: bool,
+    /// Monorepo package the stage targets (cargo workspace member, pnpm
+    /// package or bazel target). Scopes `{{package}}` in its commands, its
+    /// default acceptance and its `files` (see [`super::package`]).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub package: Option<PackageTarget>,
 }
 
 impl StageDefinition {
+    /// `files`, plus the package glob when the stage targets a package
+    pub fn scoped_files(&self) -> Vec<String> {
+        match &self.package {
+            Some(package) => package.scope_files(&self.files),
+            None => self.files.clone(),
+        }
+    }
+
+    /// `acceptance` with package references resolved, or the package's test
+    /// command when the stage targets a package and defines no criteria
+    pub fn scoped_acceptance(&self) -> Vec<AcceptanceCriterion> {
+        match &self.package {
+            Some(package) => package.scope_acceptance(&self.acceptance),
+            None => self.acceptance.clone(),
+        }
+    }
+
     /// Check if this stage definition has any goal-backward verification checks defined.
     pub fn has_any_goal_checks(&self) -> bool {
         !self.artifacts.is_empty()
@@ -294,6 +446,12 @@ pub use crate::models::stage::TruthCheck;
 /// The canonical definition is in crate::models::stage::AcceptanceCriterion.
 pub use crate::models::stage::AcceptanceCriterion;
 
+/// Environment isolation for acceptance criteria.
+///
+/// Re-exported from models::stage for API convenience.
+/// The canonical definitions are in crate::models::stage.
+pub use crate::models::stage::{EnvIsolation, IsolationMode};
+
 /// Success criteria for wiring tests.
 ///
 /// Re-exported from models::stage for backward compatibility.
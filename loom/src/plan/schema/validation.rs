@@ -1,10 +1,12 @@
 //! Plan YAML schema validation
 
-use crate::validation::validate_id;
+use crate::validation::{parse_duration, validate_id};
+use crate::verify::dependency_outputs::{referenced_params, referenced_stages};
 
+use super::package::has_package_reference;
 use super::types::{
-    FilesystemConfig, LoomMetadata, NetworkConfig, SandboxConfig, StageSandboxConfig,
-    ValidationError,
+    AcceptanceCriterion, ExecutionMode, FilesystemConfig, LoomMetadata, NetworkConfig,
+    SandboxConfig, StageDefinition, StageSandboxConfig, StageType, ValidationError,
 };
 
 /// Validate a single acceptance criterion
@@ -13,6 +15,8 @@ use super::types::{
 /// - Not have an empty or whitespace-only command
 /// - Not contain control characters (except whitespace)
 /// - Have a reasonable command length (max 1024 chars)
+/// - Request at most `MAX_CRITERION_RETRIES` retries
+/// - Not have an empty name or category, or a zero timeout
 pub(crate) fn validate_acceptance_criterion(
     criterion: &super::types::AcceptanceCriterion,
 ) -> Result<(), String> {
@@ -41,9 +45,29 @@ pub(crate) fn validate_acceptance_criterion(
         }
     }
 
+    if criterion.retry_count() > MAX_CRITERION_RETRIES {
+        return Err(format!(
+            "acceptance criterion retries too high ({}, max {MAX_CRITERION_RETRIES})",
+            criterion.retry_count()
+        ));
+    }
+
+    if criterion.name().is_some_and(|n| n.trim().is_empty()) {
+        return Err("acceptance criterion name cannot be empty".to_string());
+    }
+    if criterion.category().is_some_and(|c| c.trim().is_empty()) {
+        return Err("acceptance criterion category cannot be empty".to_string());
+    }
+    if criterion.timeout().is_some_and(|t| t.is_zero()) {
+        return Err("acceptance criterion timeout must be at least 1 second".to_string());
+    }
+
     Ok(())
 }
 
+/// Upper bound on `retries` for a single acceptance criterion
+const MAX_CRITERION_RETRIES: u32 = 10;
+
 /// Validate a glob pattern is syntactically correct
 fn validate_glob_pattern(pattern: &str) -> Result<(), String> {
     // Use the glob crate's Pattern::new() to validate
@@ -165,9 +189,73 @@ fn validate_stage_sandbox_config(
     if let Some(net) = &sandbox.network {
         validate_network_config(net, errors, Some(stage_id));
     }
+
+    // Read scope globs are matched against worktree-relative paths
+    let scope_globs = sandbox
+        .readable_paths
+        .iter()
+        .map(|p| ("readable_paths", p))
+        .chain(
+            sandbox
+                .forbidden_paths
+                .iter()
+                .map(|p| ("forbidden_paths", p)),
+        );
+    for (field, path) in scope_globs {
+        let result = if path.starts_with('/') || path.starts_with('~') {
+            Err("must be relative to the worktree root".to_string())
+        } else if path.split('/').any(|c| c == "..") {
+            Err("must not contain '..'".to_string())
+        } else {
+            validate_glob_pattern(path)
+        };
+        if let Err(e) = result {
+            errors.push(ValidationError {
+                message: format!("Invalid sandbox {field} glob pattern '{path}': {e}"),
+                stage_id: Some(stage_id.to_string()),
+            });
+        }
+    }
 }
 
 /// Validate the loom metadata
+/// The commands and expected output of a stage that may hold references.
+fn command_texts(stage: &StageDefinition) -> Vec<&str> {
+    let mut texts: Vec<&str> = stage.setup.iter().map(String::as_str).collect();
+    let checks = stage
+        .acceptance
+        .iter()
+        .filter_map(|criterion| match criterion {
+            AcceptanceCriterion::Simple(command) => {
+                texts.push(command);
+                None
+            }
+            AcceptanceCriterion::Extended(check) => Some(check),
+        });
+    let checks: Vec<_> = checks
+        .chain(&stage.before_stage)
+        .chain(&stage.after_stage)
+        .collect();
+    for check in checks {
+        texts.push(&check.command);
+        texts.extend(check.stdout_contains.iter().map(String::as_str));
+        texts.extend(check.stdout_not_contains.iter().map(String::as_str));
+    }
+    texts
+}
+
+/// Stages referenced by `{{deps.<stage>.<key>}}` in a stage's commands,
+/// deduplicated.
+fn output_reference_stages(stage: &StageDefinition) -> Vec<&str> {
+    let mut stages = Vec::new();
+    for referenced in command_texts(stage).into_iter().flat_map(referenced_stages) {
+        if !stages.contains(&referenced) {
+            stages.push(referenced);
+        }
+    }
+    stages
+}
+
 pub fn validate(metadata: &LoomMetadata) -> Result<(), Vec<ValidationError>> {
     let mut errors = Vec::new();
 
@@ -185,6 +273,28 @@ pub fn validate(metadata: &LoomMetadata) -> Result<(), Vec<ValidationError>> {
     // Validate plan-level sandbox configuration
     validate_sandbox_config(&metadata.loom.sandbox, &mut errors);
 
+    if let Some(template) = &metadata.loom.branch_template {
+        if let Err(e) = crate::namespace::validate_branch_template(template) {
+            errors.push(ValidationError {
+                message: format!("Invalid branch_template: {e}"),
+                stage_id: None,
+            });
+        }
+    }
+
+    if let Some(remote) = &metadata.loom.push_remote {
+        // Passed to `git push` as an argument, so it must not read as a flag
+        if remote.is_empty()
+            || remote.starts_with('-')
+            || remote.chars().any(|c| c.is_whitespace() || c.is_control())
+        {
+            errors.push(ValidationError {
+                message: format!("Invalid push_remote '{remote}': expected a git remote name"),
+                stage_id: None,
+            });
+        }
+    }
+
     // Check for empty stages
     if metadata.loom.stages.is_empty() {
         errors.push(ValidationError {
@@ -274,6 +384,7 @@ pub fn validate(metadata: &LoomMetadata) -> Result<(), Vec<ValidationError>> {
         }
 
         // Validate acceptance criteria
+        let mut criterion_names = std::collections::HashSet::new();
         for (idx, criterion) in stage.acceptance.iter().enumerate() {
             if let Err(e) = validate_acceptance_criterion(criterion) {
                 errors.push(ValidationError {
@@ -281,6 +392,91 @@ pub fn validate(metadata: &LoomMetadata) -> Result<(), Vec<ValidationError>> {
                     stage_id: Some(stage.id.clone()),
                 });
             }
+            if let Some(name) = criterion.name() {
+                if !criterion_names.insert(name) {
+                    errors.push(ValidationError {
+                        message: format!(
+                            "Invalid acceptance criterion #{}: duplicate name '{name}'",
+                            idx + 1
+                        ),
+                        stage_id: Some(stage.id.clone()),
+                    });
+                }
+            }
+        }
+
+        if let Some(expected) = &stage.expected_duration {
+            if let Err(e) = parse_duration(expected) {
+                errors.push(ValidationError {
+                    message: format!("Invalid expected_duration '{expected}': {e}"),
+                    stage_id: Some(stage.id.clone()),
+                });
+            }
+        }
+
+        if stage.skip_if_satisfied {
+            let problem = if stage.stage_type == StageType::Knowledge {
+                Some("skip_if_satisfied does not apply to knowledge stages")
+            } else if stage.scoped_acceptance().is_empty() && stage.after_stage.is_empty() {
+                Some("skip_if_satisfied needs acceptance criteria or after_stage checks")
+            } else {
+                None
+            };
+            if let Some(message) = problem {
+                errors.push(ValidationError {
+                    message: message.to_string(),
+                    stage_id: Some(stage.id.clone()),
+                });
+            }
+        }
+
+        // Validate dependency output references ({{deps.<stage>.<key>}})
+        for referenced in output_reference_stages(stage) {
+            if !stage.dependencies.iter().any(|dep| dep == referenced) {
+                errors.push(ValidationError {
+                    message: format!(
+                        "Output reference to '{referenced}' requires '{referenced}' in dependencies"
+                    ),
+                    stage_id: Some(stage.id.clone()),
+                });
+            }
+        }
+
+        // Validate required dependency outputs (<stage-id>.<key>)
+        for reference in &stage.requires_outputs {
+            let Some((producer, key)) = reference.split_once('.') else {
+                errors.push(ValidationError {
+                    message: format!(
+                        "requires_outputs entry '{reference}' must be <stage-id>.<output-key>"
+                    ),
+                    stage_id: Some(stage.id.clone()),
+                });
+                continue;
+            };
+            if !stage.dependencies.iter().any(|dep| dep == producer) {
+                errors.push(ValidationError {
+                    message: format!(
+                        "requires_outputs entry '{reference}' requires '{producer}' in dependencies"
+                    ),
+                    stage_id: Some(stage.id.clone()),
+                });
+                continue;
+            }
+            let declared = metadata
+                .loom
+                .stages
+                .iter()
+                .find(|s| s.id == producer)
+                .map(|s| &s.outputs)
+                .filter(|outputs| !outputs.is_empty());
+            if declared.is_some_and(|outputs| !outputs.iter().any(|spec| spec.key == key)) {
+                errors.push(ValidationError {
+                    message: format!(
+                        "requires_outputs entry '{reference}': '{producer}' declares no output '{key}'"
+                    ),
+                    stage_id: Some(stage.id.clone()),
+                });
+            }
         }
 
         // Validate artifacts
@@ -372,6 +568,188 @@ pub fn validate(metadata: &LoomMetadata) -> Result<(), Vec<ValidationError>> {
             }
         }
 
+        // Validate worktree setup commands (run by the orchestrator, not the agent)
+        if stage.worktree_setup.len() > 20 {
+            errors.push(ValidationError {
+                message: format!(
+                    "Too many worktree_setup commands ({}, max 20)",
+                    stage.worktree_setup.len()
+                ),
+                stage_id: Some(stage.id.clone()),
+            });
+        }
+        for (idx, command) in stage.worktree_setup.iter().enumerate() {
+            if command.trim().is_empty() {
+                errors.push(ValidationError {
+                    message: format!("worktree_setup command #{} cannot be empty", idx + 1),
+                    stage_id: Some(stage.id.clone()),
+                });
+            }
+            if command.len() > 500 {
+                errors.push(ValidationError {
+                    message: format!(
+                        "worktree_setup command #{} too long ({} chars, max 500)",
+                        idx + 1,
+                        command.len()
+                    ),
+                    stage_id: Some(stage.id.clone()),
+                });
+            }
+        }
+
+        // Validate prompt customisation (instructions land in the signal,
+        // prompt_prefix on the agent's command line)
+        for (field, value, max) in [
+            ("instructions", &stage.instructions, 10_000),
+            ("prompt_prefix", &stage.prompt_prefix, 2_000),
+        ] {
+            let Some(value) = value else { continue };
+            if value.trim().is_empty() {
+                errors.push(ValidationError {
+                    message: format!("{field} cannot be empty"),
+                    stage_id: Some(stage.id.clone()),
+                });
+            }
+            if value.len() > max {
+                errors.push(ValidationError {
+                    message: format!("{field} too long ({} chars, max {max})", value.len()),
+                    stage_id: Some(stage.id.clone()),
+                });
+            }
+        }
+
+        // Validate container configuration (docker backend)
+        if let Some(container) = &stage.container {
+            let image = container.image.trim();
+            if image.is_empty() || image.contains(char::is_whitespace) {
+                errors.push(ValidationError {
+                    message: format!(
+                        "container.image must be a single image reference, got '{}'",
+                        container.image
+                    ),
+                    stage_id: Some(stage.id.clone()),
+                });
+            }
+            for volume in &container.volumes {
+                if !volume.contains(':') {
+                    errors.push(ValidationError {
+                        message: format!(
+                            "container volume '{volume}' must be in host:container[:ro] form"
+                        ),
+                        stage_id: Some(stage.id.clone()),
+                    });
+                }
+            }
+            for var in &container.env {
+                let name = var.split('=').next().unwrap_or_default();
+                if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+                    errors.push(ValidationError {
+                        message: format!("container env '{var}' must be NAME or NAME=value"),
+                        stage_id: Some(stage.id.clone()),
+                    });
+                }
+            }
+        }
+
+        // Headless sessions run without a terminal; a container still needs one
+        if stage.container.is_some() && stage.execution_mode == Some(ExecutionMode::Headless) {
+            errors.push(ValidationError {
+                message: "execution_mode: headless cannot be combined with container".to_string(),
+                stage_id: Some(stage.id.clone()),
+            });
+        }
+
+        // Validate declared outputs (same key rules as `loom stage output set`)
+        let mut output_keys = std::collections::HashSet::new();
+        for spec in &stage.outputs {
+            let key = spec.key.as_str();
+            if key.is_empty()
+                || key.len() > 64
+                || !key
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+            {
+                errors.push(ValidationError {
+                    message: format!(
+                        "output key '{key}' must be 1-64 alphanumeric, underscore or dash characters"
+                    ),
+                    stage_id: Some(stage.id.clone()),
+                });
+            }
+            if !output_keys.insert(key) {
+                errors.push(ValidationError {
+                    message: format!("output key '{key}' is declared more than once"),
+                    stage_id: Some(stage.id.clone()),
+                });
+            }
+        }
+
+        // Validate run-time parameters and their references ({{params.<name>}})
+        let mut param_names = std::collections::HashSet::new();
+        for param in &stage.params {
+            let name = param.name.as_str();
+            if name.is_empty()
+                || name.len() > 64
+                || !name
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+            {
+                errors.push(ValidationError {
+                    message: format!(
+                        "param name '{name}' must be 1-64 alphanumeric, underscore or dash characters"
+                    ),
+                    stage_id: Some(stage.id.clone()),
+                });
+            }
+            if !param_names.insert(name) {
+                errors.push(ValidationError {
+                    message: format!("param '{name}' is declared more than once"),
+                    stage_id: Some(stage.id.clone()),
+                });
+            }
+            if let Some(default) = &param.default {
+                if !param.accepts(default) {
+                    errors.push(ValidationError {
+                        message: format!(
+                            "param '{name}' default '{default}' is not one of its choices"
+                        ),
+                        stage_id: Some(stage.id.clone()),
+                    });
+                }
+            }
+        }
+        for name in command_texts(stage).into_iter().flat_map(referenced_params) {
+            if !param_names.contains(name) {
+                errors.push(ValidationError {
+                    message: format!(
+                        "Parameter reference '{{{{params.{name}}}}}' requires '{name}' in params"
+                    ),
+                    stage_id: Some(stage.id.clone()),
+                });
+            }
+        }
+
+        // Validate the monorepo package and its references ({{package}})
+        match &stage.package {
+            Some(package) => {
+                if let Err(e) = package.validate() {
+                    errors.push(ValidationError {
+                        message: format!("Invalid package: {e}"),
+                        stage_id: Some(stage.id.clone()),
+                    });
+                }
+            }
+            None => {
+                if command_texts(stage).into_iter().any(has_package_reference) {
+                    errors.push(ValidationError {
+                        message: "Package reference '{{package}}' requires a package on the stage"
+                            .to_string(),
+                        stage_id: Some(stage.id.clone()),
+                    });
+                }
+            }
+        }
+
         // Validate after_stage truth checks
         if stage.after_stage.len() > 20 {
             errors.push(ValidationError {
@@ -410,9 +788,9 @@ pub fn validate(metadata: &LoomMetadata) -> Result<(), Vec<ValidationError>> {
 
         if requires_goal_backward {
             // Standard and IntegrationVerify stages must define either:
-            // 1. Non-empty acceptance criteria, OR
+            // 1. Non-empty acceptance criteria (a package supplies its test command), OR
             // 2. At least one goal-backward check (artifacts, wiring, wiring_tests, dead_code_check)
-            let has_acceptance = !stage.acceptance.is_empty();
+            let has_acceptance = !stage.scoped_acceptance().is_empty();
             let has_goal_checks = stage.has_any_goal_checks();
 
             if !has_acceptance && !has_goal_checks {
@@ -747,6 +1125,58 @@ pub fn validate_structural_preflight(
     warnings
 }
 
+/// Check that every stage's `working_dir` is a directory under `repo_root`.
+///
+/// Sessions start in `worktree/working_dir` and acceptance runs there, so a
+/// missing directory fails the stage after it has been spawned. A stage with
+/// no dependencies starts from the current tree, where nothing can create the
+/// directory first, so a missing `working_dir` is an error; for a dependent
+/// stage an earlier stage may create it, so it is only a warning.
+///
+/// Returns the warnings on success, or every error found.
+pub fn check_working_dirs(
+    stages: &[super::types::StageDefinition],
+    repo_root: &std::path::Path,
+) -> Result<Vec<String>, Vec<ValidationError>> {
+    let mut errors = Vec::new();
+    let mut warnings = Vec::new();
+
+    for stage in stages {
+        if stage.working_dir.is_empty() || stage.working_dir == "." {
+            continue;
+        }
+        let path = repo_root.join(&stage.working_dir);
+        if path.is_dir() {
+            continue;
+        }
+        if path.exists() {
+            errors.push(ValidationError {
+                message: format!("working_dir '{}' is not a directory", stage.working_dir),
+                stage_id: Some(stage.id.clone()),
+            });
+        } else if stage.dependencies.is_empty() {
+            errors.push(ValidationError {
+                message: format!(
+                    "working_dir '{}' does not exist in the repository",
+                    stage.working_dir
+                ),
+                stage_id: Some(stage.id.clone()),
+            });
+        } else {
+            warnings.push(format!(
+                "Stage '{}': working_dir '{}' does not exist yet; a dependency must create it",
+                stage.id, stage.working_dir
+            ));
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(warnings)
+    } else {
+        Err(errors)
+    }
+}
+
 /// Check wiring pattern quality and return a warning if the pattern is weak.
 ///
 /// Returns Some(warning_message) if the pattern is:
@@ -11,8 +11,8 @@
 //!     carries the operator-facing on/off switch (`mode = auto | off`).
 //!   * `preflight()` combines a version probe with an auth-eligibility
 //!     heuristic and yields a `RemoteControlStatus`.
-//!   * `resolve()` is the per-spawn gate: it returns `false` when the mode is
-//!     `off`, when a `.work/remote_control-unsupported` marker exists, or when
+//!   * `resolve()` is the per-spawn gate: it returns `false` in offline mode,
+//!     when the mode is `off`, when a `.work/remote_control-unsupported` marker exists, or when
 //!     the preflight is not satisfied. The marker lets the crash handler
 //!     disable Remote Control mid-run after a fast-fail crash.
 
@@ -246,6 +246,10 @@ fn cached_preflight_enabled(claude_path: &Path) -> bool {
 /// All errors are swallowed (treated as "disabled") so a spawn site can call
 /// this unconditionally.
 pub fn resolve(work_dir: &Path) -> bool {
+    if crate::offline::is_offline(Some(work_dir)) {
+        return false;
+    }
+
     let mode = read_remote_control_config(work_dir)
         .map(|c| c.mode)
         .unwrap_or_default();
@@ -276,6 +280,10 @@ pub fn run_startup_preflight(claude_path: &Path, work_dir: &Path) {
         // Operator explicitly disabled Remote Control; stay quiet.
         return;
     }
+    if crate::offline::is_offline(Some(work_dir)) {
+        eprintln!("\u{26a0} Remote Control disabled: offline mode");
+        return;
+    }
 
     match preflight(claude_path) {
         RemoteControlStatus::Enabled => {}
@@ -49,7 +49,9 @@ pub struct MergedSandboxConfig {
 pub fn default_mode_for(stage_type: StageType) -> PermissionMode {
     match stage_type {
         StageType::Knowledge | StageType::KnowledgeDistill => PermissionMode::Auto,
-        StageType::Standard | StageType::IntegrationVerify => PermissionMode::Auto,
+        StageType::Standard | StageType::IntegrationVerify | StageType::CodeReview => {
+            PermissionMode::Auto
+        }
     }
 }
 
@@ -389,6 +391,8 @@ mod tests {
             network: None,
             linux: None,
             permission_mode: None,
+            readable_paths: Vec::new(),
+            forbidden_paths: Vec::new(),
         };
 
         let merged = merge_config(&plan, &stage, StageType::Standard);
@@ -4,6 +4,7 @@
 //! and generating Claude Code settings files.
 
 mod config;
+mod scope;
 mod settings;
 
 pub use config::{
@@ -11,4 +12,7 @@ pub use config::{
     is_legitimate_work_access, merge_config, validate_config, validate_paths, MergedSandboxConfig,
     PathEscapeAttempt,
 };
+pub use scope::{
+    clear_context_scope, sync_context_scope, write_context_scope, ContextScope, CONTEXT_SCOPE_DIR,
+};
 pub use settings::{apply_default_mode, generate_settings_json, write_settings};
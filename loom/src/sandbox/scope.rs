@@ -0,0 +1,111 @@
+//! Read scope for stage sessions.
+//!
+//! A stage's `sandbox.readable_paths` / `sandbox.forbidden_paths` are recorded
+//! in `.work/context-scope/<stage-id>.json` when the stage is spawned. The
+//! `context-scope-guard.sh` hook reads that file and blocks Read/Glob/Grep
+//! calls outside the scope, so an agent working on one subsystem does not
+//! fill its context with unrelated ones. The hook is the only place the
+//! globs are matched; its behaviour is covered by `hooks/tests/`.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::plan::schema::StageSandboxConfig;
+
+/// Directory under `.work/` holding one scope file per stage.
+pub const CONTEXT_SCOPE_DIR: &str = "context-scope";
+
+/// Paths a stage session may read, relative to the worktree root.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContextScope {
+    pub stage_id: String,
+    /// Globs reads are limited to; empty allows the whole worktree
+    #[serde(default)]
+    pub readable_paths: Vec<String>,
+    /// Globs that may never be read; checked before `readable_paths`
+    #[serde(default)]
+    pub forbidden_paths: Vec<String>,
+}
+
+impl ContextScope {
+    /// The scope for a stage, or `None` when its reads are unrestricted.
+    pub fn for_stage(stage_id: &str, sandbox: &StageSandboxConfig) -> Option<Self> {
+        if sandbox.readable_paths.is_empty() && sandbox.forbidden_paths.is_empty() {
+            return None;
+        }
+        Some(Self {
+            stage_id: stage_id.to_string(),
+            readable_paths: sandbox.readable_paths.clone(),
+            forbidden_paths: sandbox.forbidden_paths.clone(),
+        })
+    }
+}
+
+fn scope_path(work_dir: &Path, stage_id: &str) -> PathBuf {
+    work_dir
+        .join(CONTEXT_SCOPE_DIR)
+        .join(format!("{stage_id}.json"))
+}
+
+/// Record the read scope for a stage's sessions.
+pub fn write_context_scope(work_dir: &Path, scope: &ContextScope) -> Result<()> {
+    let path = scope_path(work_dir, &scope.stage_id);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    let json = serde_json::to_string_pretty(scope).context("Failed to serialize context scope")?;
+    fs::write(&path, json).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// Remove a stage's read scope.
+pub fn clear_context_scope(work_dir: &Path, stage_id: &str) -> Result<()> {
+    let path = scope_path(work_dir, stage_id);
+    if path.exists() {
+        fs::remove_file(&path).with_context(|| format!("Failed to remove {}", path.display()))?;
+    }
+    Ok(())
+}
+
+/// Write or clear a stage's read scope to match its sandbox config.
+pub fn sync_context_scope(
+    work_dir: &Path,
+    stage_id: &str,
+    sandbox: &StageSandboxConfig,
+) -> Result<()> {
+    match ContextScope::for_stage(stage_id, sandbox) {
+        Some(scope) => write_context_scope(work_dir, &scope),
+        None => clear_context_scope(work_dir, stage_id),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn sandbox(readable: &[&str], forbidden: &[&str]) -> StageSandboxConfig {
+        StageSandboxConfig {
+            readable_paths: readable.iter().map(|s| s.to_string()).collect(),
+            forbidden_paths: forbidden.iter().map(|s| s.to_string()).collect(),
+            ..StageSandboxConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_sync_context_scope() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join(CONTEXT_SCOPE_DIR).join("api.json");
+
+        sync_context_scope(temp.path(), "api", &sandbox(&["src/**"], &[])).unwrap();
+        let scope: ContextScope =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(scope.readable_paths, vec!["src/**".to_string()]);
+
+        sync_context_scope(temp.path(), "api", &StageSandboxConfig::default()).unwrap();
+        assert!(!path.exists());
+        assert!(ContextScope::for_stage("api", &StageSandboxConfig::default()).is_none());
+    }
+}
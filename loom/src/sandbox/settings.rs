@@ -1139,6 +1139,7 @@ mod tests {
             StageType::Knowledge,
             StageType::IntegrationVerify,
             StageType::KnowledgeDistill,
+            StageType::CodeReview,
         ] {
             let plan = SandboxConfig::default();
             let stage = StageSandboxConfig::default();
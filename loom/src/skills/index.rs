@@ -3,7 +3,7 @@
 use anyhow::{Context, Result};
 use std::collections::HashMap;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use crate::parser::frontmatter::extract_yaml_frontmatter;
 
@@ -198,6 +198,34 @@ fn extract_description_triggers(description: &str) -> Vec<String> {
     Vec::new()
 }
 
+/// Load the skill index used for signal recommendations from `skills_dir`
+/// (default: `~/.claude/skills/`). Returns `None` when there are no skills.
+pub fn load_skill_index(skills_dir: Option<PathBuf>) -> Option<SkillIndex> {
+    let skills_dir = skills_dir.unwrap_or_else(|| {
+        dirs::home_dir()
+            .map(|h| h.join(".claude").join("skills"))
+            .unwrap_or_else(|| PathBuf::from(".claude/skills"))
+    });
+
+    if !skills_dir.exists() {
+        return None;
+    }
+
+    match SkillIndex::load_from_directory(&skills_dir) {
+        Ok(index) => {
+            if index.is_empty() {
+                None
+            } else {
+                Some(index)
+            }
+        }
+        Err(e) => {
+            eprintln!("Warning: Failed to load skill index: {e}");
+            None
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -23,5 +23,5 @@ mod index;
 mod matcher;
 mod types;
 
-pub use index::SkillIndex;
+pub use index::{load_skill_index, SkillIndex};
 pub use types::{SkillMatch, SkillMetadata};
@@ -142,6 +142,75 @@ pub fn validate_knowledge_content(content: &str) -> Result<()> {
     Ok(())
 }
 
+/// Maximum length for a stage assignee
+pub const MAX_ASSIGNEE_LENGTH: usize = 64;
+
+/// Validates a person's name for `loom stage assign`.
+///
+/// Names are free-form (`alice`, `Bob Smith`, `carol@example.com`) but must
+/// be non-empty, without surrounding whitespace or control characters, and
+/// at most `MAX_ASSIGNEE_LENGTH` characters.
+pub fn validate_assignee(name: &str) -> Result<()> {
+    if name.trim().is_empty() {
+        bail!("Assignee cannot be empty");
+    }
+    if name.trim() != name {
+        bail!("Assignee cannot start or end with whitespace");
+    }
+    if name.chars().count() > MAX_ASSIGNEE_LENGTH {
+        bail!(
+            "Assignee too long: {} characters (max {MAX_ASSIGNEE_LENGTH})",
+            name.chars().count()
+        );
+    }
+    if name.chars().any(char::is_control) {
+        bail!("Assignee cannot contain control characters");
+    }
+    Ok(())
+}
+
+/// Parses a duration such as `4h`, `90m`, `1h30m` or `45s`.
+///
+/// Units are `d`, `h`, `m` and `s`; every number needs one. The total must
+/// be greater than zero.
+pub fn parse_duration(s: &str) -> Result<std::time::Duration> {
+    let mut total: u64 = 0;
+    let mut digits = String::new();
+    for c in s.trim().chars() {
+        if c.is_ascii_digit() {
+            digits.push(c);
+            continue;
+        }
+        let unit = match c {
+            'd' => 86_400,
+            'h' => 3_600,
+            'm' => 60,
+            's' => 1,
+            _ => bail!("Invalid duration '{s}': unknown unit '{c}' (use d, h, m or s)"),
+        };
+        let Ok(n) = digits.parse::<u64>() else {
+            bail!("Invalid duration '{s}': expected a number before '{c}'");
+        };
+        total = n
+            .checked_mul(unit)
+            .and_then(|secs| total.checked_add(secs))
+            .ok_or_else(|| anyhow::anyhow!("Invalid duration '{s}': too large"))?;
+        digits.clear();
+    }
+    if !digits.is_empty() {
+        bail!("Invalid duration '{s}': '{digits}' needs a unit (d, h, m or s)");
+    }
+    if total == 0 {
+        bail!("Invalid duration '{s}': must be greater than zero");
+    }
+    Ok(std::time::Duration::from_secs(total))
+}
+
+/// Clap value parser for duration arguments (see [`parse_duration`]).
+pub fn clap_duration_validator(s: &str) -> Result<std::time::Duration, String> {
+    parse_duration(s).map_err(|e| e.to_string())
+}
+
 /// Clap value parser for validating ID arguments.
 ///
 /// Use this with clap's `value_parser` attribute to validate IDs at parse time.
@@ -163,6 +232,12 @@ pub fn clap_description_validator(s: &str) -> Result<String, String> {
     Ok(s.to_string())
 }
 
+/// Clap value parser for validating assignee arguments.
+pub fn clap_assignee_validator(s: &str) -> Result<String, String> {
+    validate_assignee(s).map_err(|e| e.to_string())?;
+    Ok(s.to_string())
+}
+
 /// Clap value parser for validating knowledge content arguments.
 pub fn clap_knowledge_content_validator(s: &str) -> Result<String, String> {
     validate_knowledge_content(s).map_err(|e| e.to_string())?;
@@ -173,6 +248,32 @@ pub fn clap_knowledge_content_validator(s: &str) -> Result<String, String> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_validate_assignee() {
+        assert!(validate_assignee("alice").is_ok());
+        assert!(validate_assignee("Bob Smith").is_ok());
+        assert!(validate_assignee("carol@example.com").is_ok());
+        assert!(validate_assignee("").is_err());
+        assert!(validate_assignee(" alice").is_err());
+        assert!(validate_assignee("a\tb").is_err());
+        assert!(validate_assignee(&"x".repeat(MAX_ASSIGNEE_LENGTH + 1)).is_err());
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        use std::time::Duration;
+        assert_eq!(parse_duration("4h").unwrap(), Duration::from_secs(4 * 3600));
+        assert_eq!(parse_duration("90m").unwrap(), Duration::from_secs(5400));
+        assert_eq!(parse_duration("1h30m").unwrap(), Duration::from_secs(5400));
+        assert_eq!(parse_duration("1d").unwrap(), Duration::from_secs(86_400));
+        assert_eq!(parse_duration("45s").unwrap(), Duration::from_secs(45));
+        assert!(parse_duration("4").is_err());
+        assert!(parse_duration("h").is_err());
+        assert!(parse_duration("4x").is_err());
+        assert!(parse_duration("0m").is_err());
+        assert!(parse_duration("").is_err());
+    }
+
     #[test]
     fn test_validate_id_valid() {
         assert!(validate_id("runner-001").is_ok());
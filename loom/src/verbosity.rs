@@ -0,0 +1,104 @@
+//! Output levels shared by every command.
+//!
+//! Resolution model:
+//!   * The global `--quiet` / `--verbose` flags export `LOOM_VERBOSITY` for
+//!     the current process, so the daemon and any other `loom` processes it
+//!     spawns inherit the level.
+//!   * `LOOM_VERBOSITY` can also be set directly to `quiet` or `verbose`.
+//!
+//! Levels:
+//!   * quiet — progress and decorative output (headers, ✓ lines, hints) is
+//!     suppressed; results, warnings and errors still print, and the exit
+//!     status tells scripts what happened. Log output is cut to errors.
+//!   * normal — the default.
+//!   * verbose — also logs debug detail: every git command loom runs and
+//!     the scheduler's decisions about which stages start or wait.
+//!
+//! Decorative output goes through [`say!`](crate::say) instead of
+//! `println!`. An explicit `RUST_LOG` still overrides the log filter.
+
+/// Environment variable holding the output level.
+pub const VERBOSITY_ENV: &str = "LOOM_VERBOSITY";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verbosity {
+    Quiet,
+    Normal,
+    Verbose,
+}
+
+impl Verbosity {
+    fn parse(value: &str) -> Self {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "quiet" | "q" | "0" => Self::Quiet,
+            "verbose" | "v" | "debug" | "2" => Self::Verbose,
+            _ => Self::Normal,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Quiet => "quiet",
+            Self::Normal => "normal",
+            Self::Verbose => "verbose",
+        }
+    }
+
+    /// Default `tracing` filter for this level.
+    pub fn log_filter(self) -> &'static str {
+        match self {
+            Self::Quiet => "error",
+            Self::Normal => "warn,loom=info",
+            Self::Verbose => "warn,loom=debug",
+        }
+    }
+}
+
+/// Set the output level for this process and every process it spawns.
+///
+/// Called once from `main` before dispatch, while the process is still
+/// single-threaded.
+pub fn set_for_process(level: Verbosity) {
+    std::env::set_var(VERBOSITY_ENV, level.as_str());
+}
+
+/// The current output level (normal unless `LOOM_VERBOSITY` says otherwise).
+pub fn current() -> Verbosity {
+    std::env::var(VERBOSITY_ENV)
+        .map(|v| Verbosity::parse(&v))
+        .unwrap_or(Verbosity::Normal)
+}
+
+pub fn is_quiet() -> bool {
+    current() == Verbosity::Quiet
+}
+
+pub fn is_verbose() -> bool {
+    current() == Verbosity::Verbose
+}
+
+/// `println!` for progress and decorative output; silent under `--quiet`.
+#[macro_export]
+macro_rules! say {
+    ($($arg:tt)*) => {
+        if !$crate::verbosity::is_quiet() {
+            println!($($arg)*);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_verbosity() {
+        assert_eq!(Verbosity::parse("quiet"), Verbosity::Quiet);
+        assert_eq!(Verbosity::parse(" Verbose "), Verbosity::Verbose);
+        assert_eq!(Verbosity::parse("normal"), Verbosity::Normal);
+        assert_eq!(Verbosity::parse("loud"), Verbosity::Normal);
+        for level in [Verbosity::Quiet, Verbosity::Normal, Verbosity::Verbose] {
+            assert_eq!(Verbosity::parse(level.as_str()), level);
+        }
+    }
+}
@@ -54,6 +54,12 @@ mod tests {
             stderr_empty: None,
             exit_code: Some(1),
             description: Some("Feature test should fail before implementation".to_string()),
+            name: None,
+            category: None,
+            timeout: None,
+            retries: None,
+            flaky: false,
+            isolation: None,
         }];
 
         let working_dir = env::temp_dir();
@@ -74,6 +80,12 @@ mod tests {
             stderr_empty: None,
             exit_code: Some(1),
             description: Some("Feature should not exist yet".to_string()),
+            name: None,
+            category: None,
+            timeout: None,
+            retries: None,
+            flaky: false,
+            isolation: None,
         }];
 
         let working_dir = env::temp_dir();
@@ -95,6 +107,12 @@ mod tests {
             stderr_empty: None,
             exit_code: Some(0),
             description: Some("Feature should work after implementation".to_string()),
+            name: None,
+            category: None,
+            timeout: None,
+            retries: None,
+            flaky: false,
+            isolation: None,
         }];
 
         let working_dir = env::temp_dir();
@@ -115,6 +133,12 @@ mod tests {
             stderr_empty: None,
             exit_code: None,
             description: Some("Feature output check".to_string()),
+            name: None,
+            category: None,
+            timeout: None,
+            retries: None,
+            flaky: false,
+            isolation: None,
         }];
 
         let working_dir = env::temp_dir();
@@ -150,6 +174,12 @@ mod tests {
             stderr_empty: None,
             exit_code: None,
             description: Some("FeatureX should not appear before implementation".to_string()),
+            name: None,
+            category: None,
+            timeout: None,
+            retries: None,
+            flaky: false,
+            isolation: None,
         }];
 
         let working_dir = env::temp_dir();
@@ -170,6 +200,12 @@ mod tests {
                 stderr_empty: None,
                 exit_code: Some(0),
                 description: Some("First post-condition".to_string()),
+                name: None,
+                category: None,
+                timeout: None,
+                retries: None,
+                flaky: false,
+                isolation: None,
             },
             TruthCheck {
                 command: "echo 'integration ok'".to_string(),
@@ -178,6 +214,12 @@ mod tests {
                 stderr_empty: None,
                 exit_code: Some(0),
                 description: Some("Second post-condition".to_string()),
+                name: None,
+                category: None,
+                timeout: None,
+                retries: None,
+                flaky: false,
+                isolation: None,
             },
         ];
 
@@ -5,17 +5,23 @@ use std::time::Duration;
 /// Default timeout for command execution (5 minutes)
 pub const DEFAULT_COMMAND_TIMEOUT: Duration = Duration::from_secs(300);
 
+/// Default delay between attempts of a retried criterion
+pub const DEFAULT_RETRY_DELAY: Duration = Duration::from_secs(2);
+
 /// Configuration for acceptance criteria execution
 #[derive(Debug, Clone)]
 pub struct CriteriaConfig {
     /// Maximum time to wait for a single command to complete
     pub command_timeout: Duration,
+    /// Delay before re-running a failed criterion that allows retries
+    pub retry_delay: Duration,
 }
 
 impl Default for CriteriaConfig {
     fn default() -> Self {
         Self {
             command_timeout: DEFAULT_COMMAND_TIMEOUT,
+            retry_delay: DEFAULT_RETRY_DELAY,
         }
     }
 }
@@ -25,6 +31,7 @@ impl CriteriaConfig {
     pub fn with_timeout(timeout: Duration) -> Self {
         Self {
             command_timeout: timeout,
+            ..Self::default()
         }
     }
 }
@@ -13,6 +13,7 @@ use wait_timeout::ChildExt;
 use std::os::unix::process::CommandExt;
 
 use super::config::DEFAULT_COMMAND_TIMEOUT;
+use super::isolation::Isolation;
 use super::result::CriterionResult;
 
 /// Timeout for collecting output from child process pipes
@@ -43,11 +44,22 @@ pub fn run_single_criterion_with_timeout(
     command: &str,
     working_dir: Option<&Path>,
     timeout: Duration,
+) -> Result<CriterionResult> {
+    run_isolated_criterion_with_timeout(command, working_dir, timeout, None)
+}
+
+/// Run a single acceptance criterion with specified timeout and environment
+/// isolation (`None` inherits loom's environment)
+pub fn run_isolated_criterion_with_timeout(
+    command: &str,
+    working_dir: Option<&Path>,
+    timeout: Duration,
+    isolation: Option<&Isolation>,
 ) -> Result<CriterionResult> {
     let start = Instant::now();
 
     // Spawn the child process using the appropriate shell
-    let mut child = spawn_shell_command(command, working_dir)?;
+    let mut child = spawn_shell_command(command, working_dir, isolation)?;
 
     // IMPORTANT: Start reading output BEFORE waiting for exit.
     // If we wait first, the child may block on write() when the pipe buffer
@@ -138,11 +150,23 @@ pub fn run_single_criterion_with_timeout(
 ///
 /// On Unix the child is placed in its own process group (pgid == child pid) so
 /// that a timeout kill can reach grandchildren (e.g. `cargo test` in `a && b`).
-pub(crate) fn spawn_shell_command(command: &str, working_dir: Option<&Path>) -> Result<Child> {
+/// With `isolation`, the shell starts from a clean environment, possibly
+/// inside the stage's sandbox profile (see [`Isolation`]).
+pub(crate) fn spawn_shell_command(
+    command: &str,
+    working_dir: Option<&Path>,
+    isolation: Option<&Isolation>,
+) -> Result<Child> {
     #[cfg(unix)]
     {
-        let mut cmd = Command::new("sh");
-        cmd.arg("-c").arg(command);
+        let mut cmd = match isolation {
+            Some(isolation) => isolation.shell_command(command, working_dir),
+            None => {
+                let mut cmd = Command::new("sh");
+                cmd.arg("-c").arg(command);
+                cmd
+            }
+        };
         // Place the child in its own process group so kill(-pgid, SIGKILL) on
         // timeout kills the entire subtree including grandchildren.
         // Safety: setpgid(0,0) is async-signal-safe per POSIX.
@@ -166,6 +190,10 @@ pub(crate) fn spawn_shell_command(command: &str, working_dir: Option<&Path>) ->
     {
         let mut cmd = Command::new("cmd");
         cmd.arg("/C").arg(command);
+        if let Some(isolation) = isolation {
+            cmd.env_clear();
+            cmd.envs(isolation.passed_env());
+        }
         cmd.stdin(Stdio::null())
             .stdout(Stdio::piped())
             .stderr(Stdio::piped());
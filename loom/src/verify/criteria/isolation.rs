@@ -0,0 +1,273 @@
+//! Environment isolation for acceptance commands
+//!
+//! By default an acceptance command inherits loom's environment, so a check
+//! can pass only because of a variable, credential or tool configured on the
+//! developer's machine. A stage's `acceptance_isolation` (or a criterion's
+//! `isolation`) tightens that:
+//!
+//! - `clean`: the command starts from an empty environment (`env -i`) with
+//!   only [`BASE_ENV`] and the `allow` list passed through
+//! - `sandbox`: a clean environment inside the stage's sandbox profile.
+//!   On Linux the command runs under `bwrap` with the filesystem read-only
+//!   except the working directory, `allow_write` paths and a private `/tmp`;
+//!   `deny_read` paths are hidden, and the network is cut off unless the
+//!   profile allows domains. Without `bwrap` it falls back to `clean`.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::fs::work_dir::read_plan_sandbox;
+use crate::models::stage::{EnvIsolation, IsolationMode, Stage};
+use crate::plan::schema::AcceptanceCriterion;
+use crate::sandbox::{expand_tilde, merge_config, MergedSandboxConfig};
+
+/// Variables every isolated command keeps, when loom has them set.
+pub const BASE_ENV: &[&str] = &[
+    "PATH", "HOME", "USER", "LOGNAME", "SHELL", "LANG", "LC_ALL", "TERM", "TMPDIR",
+];
+
+/// How one acceptance command is isolated.
+#[derive(Debug, Clone)]
+pub struct Isolation {
+    pub mode: IsolationMode,
+    /// Variables passed through besides [`BASE_ENV`]
+    pub allow: Vec<String>,
+    /// The stage's sandbox profile (`sandbox` mode only)
+    pub sandbox: Option<MergedSandboxConfig>,
+}
+
+impl Isolation {
+    /// Isolation for `criterion` of `stage`, or `None` to inherit loom's
+    /// environment.
+    ///
+    /// The criterion's mode wins over the stage's; their `allow` lists add
+    /// up. The sandbox profile merges the plan's `[plan_sandbox]` (read from
+    /// `.work`) with the stage's own `sandbox` overrides.
+    pub fn resolve(stage: &Stage, criterion: &AcceptanceCriterion) -> Option<Self> {
+        let isolation =
+            resolve_isolation(stage.acceptance_isolation.as_ref(), criterion.isolation())?;
+        let sandbox = (isolation.mode == IsolationMode::Sandbox).then(|| {
+            let plan_sandbox = read_plan_sandbox(Path::new(".work"))
+                .ok()
+                .flatten()
+                .unwrap_or_default();
+            merge_config(&plan_sandbox, &stage.sandbox, stage.stage_type)
+        });
+        Some(Self {
+            mode: isolation.mode,
+            allow: isolation.allow,
+            sandbox,
+        })
+    }
+
+    /// `sh -c <command>` set up for this isolation.
+    pub(super) fn shell_command(&self, command: &str, working_dir: Option<&Path>) -> Command {
+        let mut cmd = match (self.mode, &self.sandbox) {
+            (IsolationMode::Sandbox, Some(sandbox)) => match sandboxed(sandbox, working_dir) {
+                Some(bwrap) => bwrap,
+                None => {
+                    eprintln!(
+                        "warning: bwrap not available; running '{command}' with a clean \
+                         environment only (isolation: sandbox)"
+                    );
+                    Command::new("sh")
+                }
+            },
+            _ => Command::new("sh"),
+        };
+        cmd.arg("-c").arg(command);
+        cmd.env_clear();
+        cmd.envs(self.passed_env());
+        if self.mode == IsolationMode::Sandbox {
+            cmd.env("TMPDIR", "/tmp");
+        }
+        cmd
+    }
+
+    /// Variables from loom's environment the command keeps.
+    pub(super) fn passed_env(&self) -> Vec<(String, String)> {
+        BASE_ENV
+            .iter()
+            .copied()
+            .chain(self.allow.iter().map(String::as_str))
+            .filter_map(|name| {
+                std::env::var(name)
+                    .ok()
+                    .map(|value| (name.to_string(), value))
+            })
+            .collect()
+    }
+}
+
+/// Combine stage- and criterion-level settings; `None` when the result is
+/// to inherit the environment.
+fn resolve_isolation(
+    stage: Option<&EnvIsolation>,
+    criterion: Option<&EnvIsolation>,
+) -> Option<EnvIsolation> {
+    let mode = criterion
+        .or(stage)
+        .map(|isolation| isolation.mode)
+        .unwrap_or_default();
+    if mode == IsolationMode::Inherit {
+        return None;
+    }
+    let mut allow: Vec<String> = Vec::new();
+    for name in stage.into_iter().chain(criterion).flat_map(|i| &i.allow) {
+        if !allow.contains(name) {
+            allow.push(name.clone());
+        }
+    }
+    Some(EnvIsolation { mode, allow })
+}
+
+/// A `bwrap` invocation enforcing `sandbox`, ready for `sh -c ...` to be
+/// appended; `None` when `bwrap` cannot be used here.
+fn sandboxed(sandbox: &MergedSandboxConfig, working_dir: Option<&Path>) -> Option<Command> {
+    if !cfg!(target_os = "linux") {
+        return None;
+    }
+    let bwrap = which::which("bwrap").ok()?;
+    let working_dir = match working_dir {
+        Some(dir) => dir.to_path_buf(),
+        None => std::env::current_dir().ok()?,
+    };
+    let mut cmd = Command::new(bwrap);
+    cmd.args(bwrap_args(sandbox, &working_dir)).arg("sh");
+    Some(cmd)
+}
+
+/// `bwrap` arguments for `sandbox`, up to (not including) the program.
+fn bwrap_args(sandbox: &MergedSandboxConfig, working_dir: &Path) -> Vec<String> {
+    let mut args: Vec<String> = [
+        "--die-with-parent",
+        "--ro-bind",
+        "/",
+        "/",
+        "--dev",
+        "/dev",
+        "--proc",
+        "/proc",
+        "--tmpfs",
+        "/tmp",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect();
+
+    let bind = |args: &mut Vec<String>, flag: &str, path: &Path, target: &Path| {
+        args.push(flag.to_string());
+        args.push(path.display().to_string());
+        args.push(target.display().to_string());
+    };
+    bind(&mut args, "--bind", working_dir, working_dir);
+    for path in sandbox
+        .filesystem
+        .allow_write
+        .iter()
+        .filter_map(|p| static_prefix(p, working_dir))
+        .filter(|p| p.exists())
+    {
+        bind(&mut args, "--bind", &path, &path);
+    }
+    for path in sandbox
+        .filesystem
+        .deny_read
+        .iter()
+        .filter_map(|p| static_prefix(p, working_dir))
+    {
+        if path.is_dir() {
+            args.push("--tmpfs".to_string());
+            args.push(path.display().to_string());
+        } else if path.is_file() {
+            bind(&mut args, "--ro-bind", Path::new("/dev/null"), &path);
+        }
+    }
+
+    let network = &sandbox.network;
+    if network.allowed_domains.is_empty() && network.additional_domains.is_empty() {
+        args.push("--unshare-net".to_string());
+    }
+    args.push("--chdir".to_string());
+    args.push(working_dir.display().to_string());
+    args
+}
+
+/// The literal directory or file a sandbox glob starts with (`~/.ssh/**`
+/// is `$HOME/.ssh`), resolved against `working_dir`. `None` for patterns
+/// that escape upward or start with a wildcard.
+fn static_prefix(pattern: &str, working_dir: &Path) -> Option<PathBuf> {
+    let expanded = expand_tilde(pattern);
+    let literal: Vec<&str> = expanded
+        .split('/')
+        .take_while(|part| !part.contains(['*', '?', '[']))
+        .collect();
+    let literal = literal.join("/");
+    if literal.is_empty() || literal.split('/').any(|part| part == "..") {
+        return None;
+    }
+    let path = Path::new(&literal);
+    Some(if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        working_dir.join(path)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plan::schema::{SandboxConfig, StageSandboxConfig, StageType};
+
+    fn isolation(mode: IsolationMode, allow: &[&str]) -> EnvIsolation {
+        EnvIsolation {
+            mode,
+            allow: allow.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_resolve_isolation() {
+        let stage = isolation(IsolationMode::Clean, &["CARGO_HOME"]);
+        let criterion = isolation(IsolationMode::Sandbox, &["RUSTUP_HOME", "CARGO_HOME"]);
+        assert_eq!(resolve_isolation(None, None), None);
+        assert_eq!(
+            resolve_isolation(Some(&stage), None),
+            Some(isolation(IsolationMode::Clean, &["CARGO_HOME"]))
+        );
+        assert_eq!(
+            resolve_isolation(Some(&stage), Some(&criterion)),
+            Some(isolation(
+                IsolationMode::Sandbox,
+                &["CARGO_HOME", "RUSTUP_HOME"]
+            ))
+        );
+        let inherit = isolation(IsolationMode::Inherit, &[]);
+        assert_eq!(resolve_isolation(Some(&stage), Some(&inherit)), None);
+    }
+
+    #[test]
+    fn test_bwrap_args_follow_sandbox_profile() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let secrets = temp.path().join("secrets");
+        std::fs::create_dir(&secrets).unwrap();
+        let mut plan = SandboxConfig::default();
+        plan.filesystem.deny_read = vec![
+            format!("{}/**", secrets.display()),
+            "../../**".to_string(),
+            "/missing/**".to_string(),
+        ];
+        plan.network.allowed_domains.clear();
+        plan.network.additional_domains.clear();
+        let sandbox = merge_config(&plan, &StageSandboxConfig::default(), StageType::Standard);
+
+        let args = bwrap_args(&sandbox, temp.path()).join(" ");
+        let wd = temp.path().display();
+        assert!(args.starts_with("--die-with-parent --ro-bind / /"));
+        assert!(args.contains(&format!("--bind {wd} {wd}")));
+        assert!(args.contains(&format!("--tmpfs {}", secrets.display())));
+        assert!(!args.contains("missing"));
+        assert!(args.contains("--unshare-net"));
+        assert!(args.ends_with(&format!("--chdir {wd}")));
+    }
+}
@@ -30,6 +30,10 @@
 //! - **Isolated Working Directory**: Commands can be scoped to a specific worktree
 //!   directory, limiting their filesystem context.
 //!
+//! - **Environment Isolation**: `acceptance_isolation` (per stage) or
+//!   `isolation` (per criterion) runs commands in a clean environment or
+//!   inside the stage's sandbox profile (see [`Isolation`]).
+//!
 //! # Timeout Behavior
 //!
 //! When a command exceeds its timeout:
@@ -39,6 +43,7 @@
 
 mod config;
 mod executor;
+mod isolation;
 mod result;
 mod runner;
 
@@ -46,7 +51,10 @@ mod runner;
 mod tests;
 
 // Re-export public types and functions
-pub use config::{CriteriaConfig, DEFAULT_COMMAND_TIMEOUT};
-pub use executor::{run_single_criterion, run_single_criterion_with_timeout};
-pub use result::{AcceptanceResult, CriterionResult};
+pub use config::{CriteriaConfig, DEFAULT_COMMAND_TIMEOUT, DEFAULT_RETRY_DELAY};
+pub use executor::{
+    run_isolated_criterion_with_timeout, run_single_criterion, run_single_criterion_with_timeout,
+};
+pub use isolation::{Isolation, BASE_ENV};
+pub use result::{AcceptanceResult, CategorySummary, CriterionResult};
 pub use runner::{run_acceptance, run_acceptance_with_config};
@@ -1,18 +1,28 @@
 //! Result types for acceptance criteria execution
 
+use serde::Serialize;
 use std::time::Duration;
 
 /// Result of executing a single acceptance criterion (shell command)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct CriterionResult {
+    /// Name given to the criterion in the plan
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Category given to the criterion in the plan
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
     pub command: String,
     pub success: bool,
     pub stdout: String,
     pub stderr: String,
     pub exit_code: Option<i32>,
+    #[serde(rename = "duration_secs", serialize_with = "serialize_secs")]
     pub duration: Duration,
     /// Whether the command was terminated due to timeout
     pub timed_out: bool,
+    /// Number of times the command ran (greater than 1 when retried)
+    pub attempts: u32,
 }
 
 impl CriterionResult {
@@ -27,6 +37,8 @@ impl CriterionResult {
         timed_out: bool,
     ) -> Self {
         Self {
+            name: None,
+            category: None,
             command,
             success,
             stdout,
@@ -34,6 +46,7 @@ impl CriterionResult {
             exit_code,
             duration,
             timed_out,
+            attempts: 1,
         }
     }
 
@@ -42,6 +55,16 @@ impl CriterionResult {
         self.success
     }
 
+    /// How the criterion is reported: `[category] name`, falling back to the
+    /// command when it has no name
+    pub fn label(&self) -> String {
+        let label = self.name.as_deref().unwrap_or(&self.command);
+        match &self.category {
+            Some(category) => format!("[{category}] {label}"),
+            None => label.to_string(),
+        }
+    }
+
     /// Get a summary of the result
     pub fn summary(&self) -> String {
         let status = if self.timed_out {
@@ -54,11 +77,26 @@ impl CriterionResult {
         let duration_ms = self.duration.as_millis();
         format!(
             "{} - {} ({}ms, exit code: {:?})",
-            status, self.command, duration_ms, self.exit_code
+            status,
+            self.label(),
+            duration_ms,
+            self.exit_code
         )
     }
 }
 
+fn serialize_secs<S: serde::Serializer>(duration: &Duration, s: S) -> Result<S::Ok, S::Error> {
+    s.serialize_f64(duration.as_secs_f64())
+}
+
+/// Pass counts for the criteria sharing a category
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CategorySummary {
+    pub category: String,
+    pub passed: usize,
+    pub total: usize,
+}
+
 /// Result of running all acceptance criteria for a stage
 #[derive(Debug)]
 pub enum AcceptanceResult {
@@ -107,4 +145,31 @@ impl AcceptanceResult {
     pub fn failed_count(&self) -> usize {
         self.results().iter().filter(|r| !r.passed()).count()
     }
+
+    /// Pass counts per category, in order of first appearance. Criteria
+    /// without a category are not counted.
+    pub fn category_summaries(&self) -> Vec<CategorySummary> {
+        let mut summaries: Vec<CategorySummary> = Vec::new();
+        for result in self.results() {
+            let Some(category) = &result.category else {
+                continue;
+            };
+            let index = match summaries.iter().position(|s| &s.category == category) {
+                Some(index) => index,
+                None => {
+                    summaries.push(CategorySummary {
+                        category: category.clone(),
+                        passed: 0,
+                        total: 0,
+                    });
+                    summaries.len() - 1
+                }
+            };
+            summaries[index].total += 1;
+            if result.passed() {
+                summaries[index].passed += 1;
+            }
+        }
+        summaries
+    }
 }
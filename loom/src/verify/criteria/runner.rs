@@ -4,8 +4,9 @@ use anyhow::{Context, Result};
 use std::path::{Path, PathBuf};
 
 use super::config::CriteriaConfig;
-use super::executor::run_single_criterion_with_timeout;
-use super::result::AcceptanceResult;
+use super::executor::run_isolated_criterion_with_timeout;
+use super::isolation::Isolation;
+use super::result::{AcceptanceResult, CriterionResult};
 use crate::models::stage::Stage;
 use crate::plan::schema::AcceptanceCriterion;
 use crate::verify::context::CriteriaContext;
@@ -34,6 +35,13 @@ pub fn run_acceptance(stage: &Stage, working_dir: Option<&Path>) -> Result<Accep
 ///
 /// Each command is subject to the timeout specified in `config`. Commands that
 /// exceed the timeout are terminated and marked as failed.
+///
+/// Criteria run in the environment their `isolation` (or the stage's
+/// `acceptance_isolation`) asks for; see [`Isolation`].
+///
+/// Criteria annotated with `retries: N` (or `flaky: true`) are re-run after
+/// `config.retry_delay` until they pass or run out of attempts; the attempt
+/// count is recorded in `CriterionResult::attempts`.
 pub fn run_acceptance_with_config(
     stage: &Stage,
     working_dir: Option<&Path>,
@@ -65,11 +73,12 @@ pub fn run_acceptance_with_config(
         let command_str = criterion.command();
         let expanded_command = context.expand(command_str);
 
-        // Extended criteria use shorter timeout (30s) vs simple (config default 5min)
-        let timeout = if criterion.is_extended() {
-            std::time::Duration::from_secs(30)
-        } else {
-            config.command_timeout
+        // An explicit `timeout` wins; otherwise extended criteria use a
+        // shorter timeout (30s) vs simple (config default 5min)
+        let timeout = match criterion.timeout() {
+            Some(timeout) => timeout,
+            None if criterion.is_extended() => std::time::Duration::from_secs(30),
+            None => config.command_timeout,
         };
 
         // Combine setup commands with criterion if setup is defined
@@ -78,94 +87,42 @@ pub fn run_acceptance_with_config(
             None => expanded_command,
         };
 
-        let result = run_single_criterion_with_timeout(&full_command, working_dir, timeout)
+        let isolation = Isolation::resolve(stage, criterion);
+
+        // Flaky criteria get extra attempts; the last attempt's output is kept
+        let max_attempts = criterion.retry_count() + 1;
+        let mut attempt = 1;
+        let (mut result, criterion_failures) = loop {
+            let result = run_isolated_criterion_with_timeout(
+                &full_command,
+                working_dir,
+                timeout,
+                isolation.as_ref(),
+            )
             .with_context(|| format!("Failed to execute criterion: {command_str}"))?;
-
-        // Check success based on criterion type
-        match criterion {
-            AcceptanceCriterion::Simple(_) => {
-                if !result.success {
-                    let failure_reason = if result.timed_out {
-                        format!(
-                            "Command '{}' timed out after {}s",
-                            command_str,
-                            timeout.as_secs()
-                        )
-                    } else {
-                        format!(
-                            "Command '{}' failed with exit code {:?}",
-                            command_str, result.exit_code
-                        )
-                    };
-                    failures.push(failure_reason);
-                }
-            }
-            AcceptanceCriterion::Extended(truth_check) => {
-                let mut criterion_failures = Vec::new();
-
-                if result.timed_out {
-                    criterion_failures.push(format!(
-                        "Command '{}' timed out after {}s",
-                        command_str,
-                        timeout.as_secs()
-                    ));
-                } else {
-                    // Check exit code
-                    let expected_exit = truth_check.exit_code.unwrap_or(0);
-                    let actual_exit = result.exit_code.unwrap_or(-1);
-                    if actual_exit != expected_exit {
-                        criterion_failures.push(format!(
-                            "Command '{}': expected exit code {}, got {}",
-                            command_str, expected_exit, actual_exit
-                        ));
-                    }
-
-                    // Check stdout_contains
-                    for pattern in &truth_check.stdout_contains {
-                        if !result.stdout.contains(pattern.as_str()) {
-                            criterion_failures.push(format!(
-                                "Command '{}': stdout missing expected pattern '{}'",
-                                command_str, pattern
-                            ));
-                        }
-                    }
-
-                    // Check stdout_not_contains
-                    for pattern in &truth_check.stdout_not_contains {
-                        if result.stdout.contains(pattern.as_str()) {
-                            criterion_failures.push(format!(
-                                "Command '{}': stdout contains forbidden pattern '{}'",
-                                command_str, pattern
-                            ));
-                        }
-                    }
-
-                    // Check stderr_empty
-                    if let Some(true) = truth_check.stderr_empty {
-                        if !result.stderr.is_empty() {
-                            criterion_failures
-                                .push(format!("Command '{}': stderr was not empty", command_str));
-                        }
-                    }
-                }
-
-                // C-10: reflect actual pass/fail in result.success so display is consistent.
-                // Raw exit code is preserved in result.exit_code for diagnostics.
-                // Note: when timed_out, criterion_failures is non-empty so is_empty()==false.
-                let mut result_with_original = result;
-                result_with_original.command = command_str.to_string();
-                result_with_original.success = criterion_failures.is_empty();
-
-                failures.extend(criterion_failures);
-                results.push(result_with_original);
-                continue;
+            let criterion_failures = check_criterion(criterion, &result, timeout);
+            if criterion_failures.is_empty() || attempt >= max_attempts {
+                break (result, criterion_failures);
             }
-        }
+            eprintln!(
+                "warning: criterion '{}' failed (attempt {attempt}/{max_attempts}), retrying",
+                criterion.label()
+            );
+            std::thread::sleep(config.retry_delay);
+            attempt += 1;
+        };
 
-        // Store result with original command for cleaner output
-        let mut result_with_original = result;
-        result_with_original.command = command_str.to_string();
-        results.push(result_with_original);
+        // C-10: reflect actual pass/fail in result.success so display is consistent.
+        // Raw exit code is preserved in result.exit_code for diagnostics.
+        // Store result with original command for cleaner output.
+        result.command = command_str.to_string();
+        result.name = criterion.name().map(String::from);
+        result.category = criterion.category().map(String::from);
+        result.success = criterion_failures.is_empty();
+        result.attempts = attempt;
+
+        failures.extend(criterion_failures);
+        results.push(result);
     }
 
     // Advisory: warn about suspicious stderr patterns in successful commands
@@ -182,10 +139,93 @@ pub fn run_acceptance_with_config(
     }
 }
 
+/// How a criterion is referred to in failure messages: `Command '<cmd>'`,
+/// or `Criterion '<name>' (<cmd>)` when named, prefixed with `[<category>]`.
+fn failure_subject(criterion: &AcceptanceCriterion) -> String {
+    let subject = match criterion.name() {
+        Some(name) => format!("Criterion '{name}' ({})", criterion.command()),
+        None => format!("Command '{}'", criterion.command()),
+    };
+    match criterion.category() {
+        Some(category) => format!("[{category}] {subject}"),
+        None => subject,
+    }
+}
+
+/// Check a criterion's command result, returning one message per failed expectation.
+///
+/// Simple criteria only check for exit code 0; extended criteria also validate
+/// the expected exit code and output patterns. An empty list means the
+/// criterion passed.
+fn check_criterion(
+    criterion: &AcceptanceCriterion,
+    result: &CriterionResult,
+    timeout: std::time::Duration,
+) -> Vec<String> {
+    let mut criterion_failures = Vec::new();
+    let subject = failure_subject(criterion);
+
+    if result.timed_out {
+        criterion_failures.push(format!("{subject} timed out after {}s", timeout.as_secs()));
+        return criterion_failures;
+    }
+
+    match criterion {
+        AcceptanceCriterion::Simple(_) => {
+            if !result.success {
+                criterion_failures.push(format!(
+                    "{subject} failed with exit code {:?}",
+                    result.exit_code
+                ));
+            }
+        }
+        AcceptanceCriterion::Extended(truth_check) => {
+            // Check exit code
+            let expected_exit = truth_check.exit_code.unwrap_or(0);
+            let actual_exit = result.exit_code.unwrap_or(-1);
+            if actual_exit != expected_exit {
+                criterion_failures.push(format!(
+                    "{subject}: expected exit code {}, got {}",
+                    expected_exit, actual_exit
+                ));
+            }
+
+            // Check stdout_contains
+            for pattern in &truth_check.stdout_contains {
+                if !result.stdout.contains(pattern.as_str()) {
+                    criterion_failures.push(format!(
+                        "{subject}: stdout missing expected pattern '{}'",
+                        pattern
+                    ));
+                }
+            }
+
+            // Check stdout_not_contains
+            for pattern in &truth_check.stdout_not_contains {
+                if result.stdout.contains(pattern.as_str()) {
+                    criterion_failures.push(format!(
+                        "{subject}: stdout contains forbidden pattern '{}'",
+                        pattern
+                    ));
+                }
+            }
+
+            // Check stderr_empty
+            if let Some(true) = truth_check.stderr_empty {
+                if !result.stderr.is_empty() {
+                    criterion_failures.push(format!("{subject}: stderr was not empty"));
+                }
+            }
+        }
+    }
+
+    criterion_failures
+}
+
 /// Detect suspicious patterns in stderr that may indicate silent failures.
 /// Only checks results that reported success (exit code 0).
 /// Returns a list of warning messages for each suspicious pattern found.
-fn detect_stderr_warnings(result: &super::result::CriterionResult) -> Vec<String> {
+fn detect_stderr_warnings(result: &CriterionResult) -> Vec<String> {
     if !result.success || result.stderr.is_empty() {
         return Vec::new();
     }
@@ -220,7 +260,6 @@ fn detect_stderr_warnings(result: &super::result::CriterionResult) -> Vec<String
 
 #[cfg(test)]
 mod tests {
-    use super::super::result::CriterionResult;
     use super::*;
     use std::time::Duration;
 
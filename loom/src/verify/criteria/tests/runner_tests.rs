@@ -1,8 +1,35 @@
 //! Tests for acceptance runner
 
-use crate::models::stage::Stage;
+use std::time::Duration;
+
+use crate::models::stage::{EnvIsolation, IsolationMode, Stage, TruthCheck};
 use crate::plan::schema::AcceptanceCriterion;
-use crate::verify::criteria::runner::run_acceptance;
+use crate::verify::criteria::config::CriteriaConfig;
+use crate::verify::criteria::runner::{run_acceptance, run_acceptance_with_config};
+
+fn retried(command: &str, retries: u32) -> AcceptanceCriterion {
+    AcceptanceCriterion::Extended(TruthCheck {
+        command: command.to_string(),
+        stdout_contains: vec![],
+        stdout_not_contains: vec![],
+        stderr_empty: None,
+        exit_code: None,
+        description: None,
+        name: None,
+        category: None,
+        timeout: None,
+        retries: Some(retries),
+        flaky: true,
+        isolation: None,
+    })
+}
+
+fn no_delay() -> CriteriaConfig {
+    CriteriaConfig {
+        retry_delay: Duration::ZERO,
+        ..CriteriaConfig::default()
+    }
+}
 
 #[test]
 fn test_run_acceptance_empty() {
@@ -52,3 +79,112 @@ fn test_run_acceptance_some_fail() {
     assert_eq!(result.failed_count(), 1);
     assert_eq!(result.failures().len(), 1);
 }
+
+#[cfg(unix)]
+#[test]
+fn test_run_acceptance_retries_flaky_until_pass() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let mut stage = Stage::new("test".to_string(), None);
+    // Fails on the first run, passes once the marker exists
+    stage.add_acceptance_criterion(retried("test -f marker || { touch marker; exit 1; }", 2));
+
+    let result = run_acceptance_with_config(&stage, Some(temp.path()), &no_delay()).unwrap();
+
+    assert!(result.all_passed());
+    assert_eq!(result.results()[0].attempts, 2);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_run_acceptance_retries_exhausted() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let mut stage = Stage::new("test".to_string(), None);
+    stage.add_acceptance_criterion(retried("echo run >> runs.log; exit 1", 2));
+
+    let result = run_acceptance_with_config(&stage, Some(temp.path()), &no_delay()).unwrap();
+
+    assert!(!result.all_passed());
+    assert_eq!(result.results()[0].attempts, 3);
+    assert_eq!(
+        result.failures().len(),
+        1,
+        "only the last attempt is reported"
+    );
+    let runs = std::fs::read_to_string(temp.path().join("runs.log")).unwrap();
+    assert_eq!(runs.lines().count(), 3);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_run_acceptance_reports_by_name_and_category() {
+    let mut stage = Stage::new("test".to_string(), None);
+    stage.acceptance = serde_yaml::from_str(
+        r#"
+- name: "unit tests"
+  run: "true"
+  category: test
+- name: "slow suite"
+  run: "sleep 5"
+  category: test
+  timeout: 1
+- run: "false"
+  category: lint
+- "true"
+"#,
+    )
+    .unwrap();
+
+    let result = run_acceptance(&stage, None).unwrap();
+
+    let labels: Vec<_> = result.results().iter().map(|r| r.label()).collect();
+    assert_eq!(
+        labels,
+        [
+            "[test] unit tests",
+            "[test] slow suite",
+            "[lint] false",
+            "true"
+        ]
+    );
+    assert!(result.results()[1].timed_out);
+    assert_eq!(
+        result.failures(),
+        vec![
+            "[test] Criterion 'slow suite' (sleep 5) timed out after 1s".to_string(),
+            "[lint] Command 'false': expected exit code 0, got 1".to_string(),
+        ]
+    );
+    let counts: Vec<_> = result
+        .category_summaries()
+        .into_iter()
+        .map(|s| (s.category, s.passed, s.total))
+        .collect();
+    assert_eq!(
+        counts,
+        [("test".to_string(), 1, 2), ("lint".to_string(), 0, 1)]
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn test_run_acceptance_clean_environment() {
+    std::env::set_var("LOOM_TEST_LEAKED_VAR", "1");
+    std::env::set_var("LOOM_TEST_ALLOWED_VAR", "1");
+    let mut stage = Stage::new("test".to_string(), None);
+    stage.add_acceptance_criterion(AcceptanceCriterion::Simple(
+        "test -n \"$LOOM_TEST_LEAKED_VAR\"".to_string(),
+    ));
+    let result = run_acceptance(&stage, None).unwrap();
+    assert!(result.all_passed());
+
+    stage.acceptance_isolation = Some(EnvIsolation {
+        mode: IsolationMode::Clean,
+        allow: vec!["LOOM_TEST_ALLOWED_VAR".to_string()],
+    });
+    stage.add_acceptance_criterion(AcceptanceCriterion::Simple(
+        "test -n \"$LOOM_TEST_ALLOWED_VAR\" && test -n \"$PATH\"".to_string(),
+    ));
+    let result = run_acceptance(&stage, None).unwrap();
+    let passed: Vec<bool> = result.results().iter().map(|r| r.success).collect();
+    assert_eq!(passed, vec![false, true]);
+}
@@ -0,0 +1,441 @@
+//! Dependency output references in stage commands.
+//!
+//! Acceptance criteria, setup commands and before/after-stage checks can use
+//! the outputs of the stage's dependencies instead of values copied into the
+//! plan by hand:
+//!
+//! ```yaml
+//! dependencies: [api-server]
+//! setup:
+//!   - "export API_PORT={{deps.api-server.port}}"
+//! acceptance:
+//!   - command: "curl -sf http://localhost:{{deps.api-server.port}}/health"
+//!     stdout_contains: ["{{deps.api-server.version}}"]
+//! ```
+//!
+//! `{{deps.<stage>.<key>}}` is replaced by the output's value: strings as
+//! they are, other JSON values in their JSON form. Further `.field` segments
+//! read into object outputs (`{{deps.db.config.host}}`). Only direct
+//! dependencies can be referenced.
+//!
+//! `{{params.<name>}}` is replaced by a run-time parameter the stage
+//! declares in `params`, as collected by `loom run` (see
+//! [`crate::fs::params`]).
+//!
+//! Signals show the resolved commands, keeping references whose output is
+//! not set yet. Verification fails on any reference it cannot resolve, so no
+//! command runs with a hole in it.
+
+use anyhow::{bail, Context, Result};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::fs::params::{load_params, stage_values, ParamValues};
+use crate::models::stage::{AcceptanceCriterion, Stage, StageOutput, TruthCheck};
+use crate::verify::transitions::load_stage;
+
+const OPEN: &str = "{{";
+const CLOSE: &str = "}}";
+const DEPS: &str = "deps.";
+const PARAMS: &str = "params.";
+
+/// A reference found in a command, without its braces and prefix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Reference<'a> {
+    /// `{{deps.<stage>.<key>[.<field>...]}}`
+    Output(&'a str),
+    /// `{{params.<name>}}`
+    Param(&'a str),
+}
+
+impl std::fmt::Display for Reference<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Reference::Output(reference) => write!(f, "{OPEN}{DEPS}{reference}{CLOSE}"),
+            Reference::Param(name) => write!(f, "{OPEN}{PARAMS}{name}{CLOSE}"),
+        }
+    }
+}
+
+/// Outputs of a stage's dependencies, by dependency id, and the values of
+/// the run-time parameters it declares.
+#[derive(Debug, Clone, Default)]
+pub struct DependencyOutputs {
+    outputs: HashMap<String, Vec<StageOutput>>,
+    params: ParamValues,
+}
+
+impl DependencyOutputs {
+    /// Read the outputs of `stage`'s dependencies from their stage files and
+    /// its parameters from `params.json`.
+    pub fn load(stage: &Stage, work_dir: &Path) -> Result<Self> {
+        let mut outputs = Self::default();
+        for dep in &stage.dependencies {
+            let dep_stage = load_stage(dep, work_dir)
+                .with_context(|| format!("Failed to load dependency '{dep}' for its outputs"))?;
+            outputs.insert(dep, dep_stage.outputs);
+        }
+        outputs.params = stage_values(stage, &load_params(work_dir)?);
+        Ok(outputs)
+    }
+
+    pub fn insert(&mut self, stage_id: &str, outputs: Vec<StageOutput>) {
+        self.outputs.insert(stage_id.to_string(), outputs);
+    }
+
+    /// Set the parameter values `{{params.<name>}}` resolves to.
+    pub fn set_params(&mut self, params: ParamValues) {
+        self.params = params;
+    }
+
+    /// Replace every reference in `text`, failing on the first one that
+    /// does not resolve.
+    pub fn render(&self, text: &str) -> Result<String> {
+        let mut rendered = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some((before, reference, after)) = next_reference(rest) {
+            rendered.push_str(before);
+            rendered.push_str(&self.resolve(reference)?);
+            rest = after;
+        }
+        rendered.push_str(rest);
+        Ok(rendered)
+    }
+
+    /// Replace the references in `text` that resolve and keep the others.
+    pub fn render_lenient(&self, text: &str) -> String {
+        let mut rendered = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some((before, reference, after)) = next_reference(rest) {
+            rendered.push_str(before);
+            match self.resolve(reference) {
+                Ok(value) => rendered.push_str(&value),
+                Err(_) => rendered.push_str(&reference.to_string()),
+            }
+            rest = after;
+        }
+        rendered.push_str(rest);
+        rendered
+    }
+
+    /// Resolve the references in `stage`'s setup commands, acceptance
+    /// criteria and before/after-stage checks.
+    pub fn render_stage(&self, stage: &mut Stage) -> Result<()> {
+        for command in &mut stage.setup {
+            *command = self.render(command)?;
+        }
+        for criterion in &mut stage.acceptance {
+            match criterion {
+                AcceptanceCriterion::Simple(command) => *command = self.render(command)?,
+                AcceptanceCriterion::Extended(check) => self.render_check(check)?,
+            }
+        }
+        self.render_checks(&mut stage.before_stage)?;
+        self.render_checks(&mut stage.after_stage)
+    }
+
+    /// Resolve the references in truth checks.
+    pub fn render_checks(&self, checks: &mut [TruthCheck]) -> Result<()> {
+        checks
+            .iter_mut()
+            .try_for_each(|check| self.render_check(check))
+    }
+
+    /// [`render_stage`](Self::render_stage) for signals: unresolved
+    /// references stay in place.
+    pub fn render_stage_lenient(&self, stage: &mut Stage) {
+        let render = |text: &mut String| *text = self.render_lenient(text);
+        stage.setup.iter_mut().for_each(render);
+        for criterion in &mut stage.acceptance {
+            match criterion {
+                AcceptanceCriterion::Simple(command) => render(command),
+                AcceptanceCriterion::Extended(check) => check_texts(check).for_each(render),
+            }
+        }
+        for check in stage.before_stage.iter_mut().chain(&mut stage.after_stage) {
+            check_texts(check).for_each(render);
+        }
+    }
+
+    fn render_check(&self, check: &mut TruthCheck) -> Result<()> {
+        for text in check_texts(check) {
+            *text = self.render(text)?;
+        }
+        Ok(())
+    }
+
+    fn resolve(&self, reference: Reference) -> Result<String> {
+        match reference {
+            Reference::Output(reference) => self.resolve_output(reference),
+            Reference::Param(name) => match self.params.get(name) {
+                Some(value) => Ok(value.clone()),
+                None => bail!(
+                    "Parameter reference '{OPEN}{PARAMS}{name}{CLOSE}': '{name}' is not a parameter of this stage or has no value (declare it in the stage's params, then run 'loom run --param {name}=<value>')"
+                ),
+            },
+        }
+    }
+
+    /// Value for `<stage>.<key>[.<field>...]`.
+    fn resolve_output(&self, reference: &str) -> Result<String> {
+        let mut parts = reference.split('.');
+        let (Some(stage_id), Some(key)) = (parts.next(), parts.next()) else {
+            bail!("Invalid output reference '{OPEN}{DEPS}{reference}{CLOSE}': expected {OPEN}{DEPS}<stage>.<key>{CLOSE}");
+        };
+        let Some(outputs) = self.outputs.get(stage_id) else {
+            bail!("Output reference '{OPEN}{DEPS}{reference}{CLOSE}': '{stage_id}' is not a dependency of this stage");
+        };
+        let Some(output) = outputs.iter().find(|o| o.key == key) else {
+            bail!("Output reference '{OPEN}{DEPS}{reference}{CLOSE}': stage '{stage_id}' has not set output '{key}'");
+        };
+
+        let mut value = &output.value;
+        for field in parts {
+            value = value.get(field).with_context(|| {
+                format!("Output reference '{OPEN}{DEPS}{reference}{CLOSE}': output '{key}' has no field '{field}'")
+            })?;
+        }
+        Ok(match value {
+            Value::String(text) => text.clone(),
+            other => other.to_string(),
+        })
+    }
+}
+
+/// Copy of `stage` with the dependency output references in its commands
+/// resolved, for running them. Fails if any reference does not resolve.
+pub fn resolved_stage(stage: &Stage, work_dir: &Path) -> Result<Stage> {
+    let mut resolved = stage.clone();
+    if has_references(stage) {
+        DependencyOutputs::load(stage, work_dir)?
+            .render_stage(&mut resolved)
+            .with_context(|| format!("Cannot run the commands of stage '{}'", stage.id))?;
+    }
+    Ok(resolved)
+}
+
+/// `checks` (before/after-stage checks of `stage`) with their dependency
+/// output references resolved.
+pub fn resolved_checks(
+    stage: &Stage,
+    checks: &[TruthCheck],
+    work_dir: &Path,
+) -> Result<Vec<TruthCheck>> {
+    let mut resolved = checks.to_vec();
+    if checks.iter().any(check_has_references) {
+        DependencyOutputs::load(stage, work_dir)?
+            .render_checks(&mut resolved)
+            .with_context(|| format!("Cannot run the checks of stage '{}'", stage.id))?;
+    }
+    Ok(resolved)
+}
+
+fn check_has_references(check: &TruthCheck) -> bool {
+    std::iter::once(&check.command)
+        .chain(&check.stdout_contains)
+        .chain(&check.stdout_not_contains)
+        .any(|text| next_reference(text).is_some())
+}
+
+fn has_references(stage: &Stage) -> bool {
+    let mut commands = stage
+        .setup
+        .iter()
+        .chain(stage.acceptance.iter().filter_map(|c| match c {
+            AcceptanceCriterion::Simple(command) => Some(command),
+            AcceptanceCriterion::Extended(_) => None,
+        }));
+    let mut checks = stage
+        .acceptance
+        .iter()
+        .filter_map(|c| match c {
+            AcceptanceCriterion::Simple(_) => None,
+            AcceptanceCriterion::Extended(check) => Some(check),
+        })
+        .chain(&stage.before_stage)
+        .chain(&stage.after_stage);
+    commands.any(|text| next_reference(text).is_some()) || checks.any(check_has_references)
+}
+
+/// The dependency ids `text` references, in order of appearance.
+pub fn referenced_stages(text: &str) -> Vec<&str> {
+    let mut stages = Vec::new();
+    let mut rest = text;
+    while let Some((_, reference, after)) = next_reference(rest) {
+        if let Reference::Output(reference) = reference {
+            stages.push(reference.split('.').next().unwrap_or(reference));
+        }
+        rest = after;
+    }
+    stages
+}
+
+/// The parameter names `text` references, in order of appearance.
+pub fn referenced_params(text: &str) -> Vec<&str> {
+    let mut params = Vec::new();
+    let mut rest = text;
+    while let Some((_, reference, after)) = next_reference(rest) {
+        if let Reference::Param(name) = reference {
+            params.push(name);
+        }
+        rest = after;
+    }
+    params
+}
+
+/// Split `text` around its first `{{deps.…}}` or `{{params.…}}` reference,
+/// returning the text before it, the reference, and the rest.
+fn next_reference(text: &str) -> Option<(&str, Reference<'_>, &str)> {
+    let mut offset = 0;
+    loop {
+        let start = offset + text[offset..].find(OPEN)?;
+        let inner = &text[start + OPEN.len()..];
+        let end = inner.find(CLOSE)?;
+        let body = inner[..end].trim();
+        let reference = match (body.strip_prefix(DEPS), body.strip_prefix(PARAMS)) {
+            (Some(reference), _) => Some(Reference::Output(reference)),
+            (_, Some(name)) => Some(Reference::Param(name)),
+            _ => None,
+        };
+        match reference {
+            Some(reference) => {
+                return Some((&text[..start], reference, &inner[end + CLOSE.len()..]));
+            }
+            // Not ours (e.g. a `docker inspect --format '{{.State}}'` template)
+            None => offset = start + OPEN.len(),
+        }
+    }
+}
+
+/// The fields of a truth check that may hold references.
+fn check_texts(check: &mut TruthCheck) -> impl Iterator<Item = &mut String> {
+    std::iter::once(&mut check.command)
+        .chain(&mut check.stdout_contains)
+        .chain(&mut check.stdout_not_contains)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn outputs() -> DependencyOutputs {
+        let output = |key: &str, value: Value| StageOutput {
+            key: key.to_string(),
+            value,
+            description: String::new(),
+        };
+        let mut outputs = DependencyOutputs::default();
+        outputs.insert(
+            "api",
+            vec![
+                output("port", json!(8080)),
+                output("host", json!("localhost")),
+                output("db", json!({"name": "app"})),
+            ],
+        );
+        outputs
+    }
+
+    #[test]
+    fn test_render_references() {
+        let outputs = outputs();
+        assert_eq!(
+            outputs
+                .render("curl http://{{deps.api.host}}:{{ deps.api.port }}/{{deps.api.db.name}}")
+                .unwrap(),
+            "curl http://localhost:8080/app"
+        );
+        assert_eq!(
+            outputs
+                .render("docker inspect --format '{{.State}}' {{deps.api.host}}")
+                .unwrap(),
+            "docker inspect --format '{{.State}}' localhost"
+        );
+
+        let err = outputs.render("{{deps.api.token}}").unwrap_err();
+        assert!(
+            err.to_string().contains("has not set output 'token'"),
+            "{err}"
+        );
+        let err = outputs.render("{{deps.web.port}}").unwrap_err();
+        assert!(
+            err.to_string().contains("'web' is not a dependency"),
+            "{err}"
+        );
+        assert_eq!(
+            outputs.render_lenient("{{deps.api.port}} {{deps.api.token}}"),
+            "8080 {{deps.api.token}}"
+        );
+    }
+
+    #[test]
+    fn test_render_stage() {
+        let mut stage = Stage::new("Web".to_string(), None);
+        stage.setup = vec!["export PORT={{deps.api.port}}".to_string()];
+        stage.acceptance = vec![
+            AcceptanceCriterion::Simple("test {{deps.api.port}} = 8080".to_string()),
+            AcceptanceCriterion::Extended(TruthCheck {
+                command: "curl {{deps.api.host}}".to_string(),
+                stdout_contains: vec!["{{deps.api.db.name}}".to_string()],
+                stdout_not_contains: Vec::new(),
+                stderr_empty: None,
+                exit_code: None,
+                description: None,
+                name: None,
+                category: None,
+                timeout: None,
+                retries: None,
+                flaky: false,
+                isolation: None,
+            }),
+        ];
+        outputs().render_stage(&mut stage).unwrap();
+
+        assert_eq!(stage.setup, vec!["export PORT=8080"]);
+        assert_eq!(stage.acceptance[0].command(), "test 8080 = 8080");
+        let AcceptanceCriterion::Extended(check) = &stage.acceptance[1] else {
+            panic!("expected an extended criterion");
+        };
+        assert_eq!(check.command, "curl localhost");
+        assert_eq!(check.stdout_contains, vec!["app"]);
+    }
+
+    #[test]
+    fn test_referenced_stages() {
+        assert_eq!(
+            referenced_stages("{{deps.api.port}} {{.State}} {{params.env}} {{deps.db.url}}"),
+            vec!["api", "db"]
+        );
+        assert_eq!(
+            referenced_params("{{deps.api.port}} {{params.env}} {{ params.region }}"),
+            vec!["env", "region"]
+        );
+    }
+
+    #[test]
+    fn test_render_params() {
+        let mut outputs = outputs();
+        outputs.set_params(ParamValues::from([(
+            "target_env".to_string(),
+            "staging".to_string(),
+        )]));
+        assert_eq!(
+            outputs
+                .render("deploy --env {{params.target_env}} --port {{deps.api.port}}")
+                .unwrap(),
+            "deploy --env staging --port 8080"
+        );
+
+        let err = outputs.render("{{params.region}}").unwrap_err();
+        assert!(
+            err.to_string().contains("'region' is not a parameter"),
+            "{err}"
+        );
+        assert_eq!(
+            outputs.render_lenient("{{params.target_env}} {{params.region}}"),
+            "staging {{params.region}}"
+        );
+    }
+}
@@ -147,6 +147,12 @@ mod tests {
             stderr_empty: None,
             exit_code: Some(0),
             description: Some("Test exit code 0".to_string()),
+            name: None,
+            category: None,
+            timeout: None,
+            retries: None,
+            flaky: false,
+            isolation: None,
         }];
 
         let working_dir = env::temp_dir();
@@ -163,6 +169,12 @@ mod tests {
             stderr_empty: None,
             exit_code: Some(0),
             description: Some("Test exit code failure".to_string()),
+            name: None,
+            category: None,
+            timeout: None,
+            retries: None,
+            flaky: false,
+            isolation: None,
         }];
 
         let working_dir = env::temp_dir();
@@ -180,6 +192,12 @@ mod tests {
             stderr_empty: None,
             exit_code: None,
             description: Some("Test stdout contains".to_string()),
+            name: None,
+            category: None,
+            timeout: None,
+            retries: None,
+            flaky: false,
+            isolation: None,
         }];
 
         let working_dir = env::temp_dir();
@@ -199,6 +217,12 @@ mod tests {
             stderr_empty: None,
             exit_code: None,
             description: Some("Test stdout missing pattern".to_string()),
+            name: None,
+            category: None,
+            timeout: None,
+            retries: None,
+            flaky: false,
+            isolation: None,
         }];
 
         let working_dir = env::temp_dir();
@@ -216,6 +240,12 @@ mod tests {
             stderr_empty: None,
             exit_code: None,
             description: Some("Test stdout not contains".to_string()),
+            name: None,
+            category: None,
+            timeout: None,
+            retries: None,
+            flaky: false,
+            isolation: None,
         }];
 
         let working_dir = env::temp_dir();
@@ -235,6 +265,12 @@ mod tests {
             stderr_empty: None,
             exit_code: None,
             description: Some("Test stdout forbidden pattern".to_string()),
+            name: None,
+            category: None,
+            timeout: None,
+            retries: None,
+            flaky: false,
+            isolation: None,
         }];
 
         let working_dir = env::temp_dir();
@@ -252,6 +288,12 @@ mod tests {
             stderr_empty: Some(true),
             exit_code: None,
             description: Some("Test stderr empty".to_string()),
+            name: None,
+            category: None,
+            timeout: None,
+            retries: None,
+            flaky: false,
+            isolation: None,
         }];
 
         let working_dir = env::temp_dir();
@@ -268,6 +310,12 @@ mod tests {
             stderr_empty: Some(true),
             exit_code: None,
             description: Some("Test stderr not empty".to_string()),
+            name: None,
+            category: None,
+            timeout: None,
+            retries: None,
+            flaky: false,
+            isolation: None,
         }];
 
         let working_dir = env::temp_dir();
@@ -285,6 +333,12 @@ mod tests {
             stderr_empty: Some(true),
             exit_code: Some(0),
             description: Some("Test all criteria".to_string()),
+            name: None,
+            category: None,
+            timeout: None,
+            retries: None,
+            flaky: false,
+            isolation: None,
         }];
 
         let working_dir = env::temp_dir();
@@ -2,11 +2,15 @@ pub mod baseline;
 pub mod before_after;
 pub mod context;
 pub mod criteria;
+pub mod dependency_outputs;
 pub mod duplicate_detection;
 pub mod goal_backward;
+pub mod preflight;
+pub mod satisfied;
 pub mod transitions;
 pub mod utils;
 pub mod wiring_detection;
+pub mod worktree_setup;
 
 pub use baseline::{
     baseline_exists, compare_to_baseline, ensure_baseline_captured, load_baseline, save_baseline,
@@ -19,12 +23,18 @@ pub use criteria::{
     run_single_criterion_with_timeout, AcceptanceResult, CriteriaConfig, CriterionResult,
     DEFAULT_COMMAND_TIMEOUT,
 };
+pub use dependency_outputs::DependencyOutputs;
 pub use duplicate_detection::{detect_duplicate_symbols, DuplicateSymbol};
 pub use goal_backward::{
     run_goal_backward_verification, GapType, GoalBackwardResult, VerificationGap,
 };
+pub use preflight::{run_preflight, PreflightIssue, PreflightSeverity};
 pub use transitions::{
     list_all_stages, load_stage, save_stage, serialize_stage_to_markdown, transition_stage,
     trigger_dependents,
 };
 pub use wiring_detection::{detect_unwired_files, UnwiredFile, WiringDetectionResult};
+pub use worktree_setup::{
+    mark_worktree_setup_done, run_worktree_setup, worktree_setup_log_path, worktree_setup_pending,
+    WorktreeSetupFailure,
+};
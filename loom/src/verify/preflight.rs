@@ -0,0 +1,459 @@
+//! Stage pre-flight checks
+//!
+//! Run by the orchestrator in a stage's fresh worktree, after worktree setup
+//! and before a session is spawned. A broken plan entry then blocks the stage
+//! up front with a specific report, instead of the agent discovering it
+//! twenty minutes in:
+//!
+//! - every `setup` command's program is on `PATH` (or exists, for a path)
+//! - every `files` entry matches at least one file
+//! - scripts that acceptance commands run exist
+//! - `context_budget` is a percentage, and the files the stage owns fit in it
+//!
+//! Paths covered by the stage's `new_files` are allowed not to exist yet.
+//! Container stages skip the program check, since their tools live in the
+//! image. An oversized `files` set is only a warning.
+
+use glob::{MatchOptions, Pattern};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use crate::models::constants::{DEFAULT_CONTEXT_BUDGET, DEFAULT_CONTEXT_LIMIT};
+use crate::models::stage::Stage;
+
+/// Rough bytes per token for sizing the files a stage owns.
+const BYTES_PER_TOKEN: u64 = 4;
+
+/// Shell builtins and keywords that are never looked up on `PATH`.
+const SHELL_BUILTINS: &[&str] = &[
+    ".", ":", "[", "[[", "alias", "case", "cd", "command", "do", "done", "echo", "elif", "else",
+    "esac", "eval", "exit", "export", "false", "fi", "for", "if", "printf", "pwd", "read", "set",
+    "source", "test", "then", "true", "type", "unset", "until", "while",
+];
+
+/// Programs whose first non-flag argument is a script to run.
+const INTERPRETERS: &[&str] = &[
+    ".", "bash", "node", "perl", "python", "python3", "ruby", "sh", "source", "zsh",
+];
+
+/// Interpreter flags after which no script path follows (`python -m pytest`).
+const INLINE_FLAGS: &[&str] = &["-c", "-e", "-m", "--eval"];
+
+/// How serious a pre-flight finding is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreflightSeverity {
+    /// The stage cannot run as planned; it is blocked
+    Error,
+    /// Reported, but the stage still starts
+    Warning,
+}
+
+/// One unmet precondition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreflightIssue {
+    pub severity: PreflightSeverity,
+    /// The plan field the finding is about (`setup`, `files`, ...)
+    pub field: &'static str,
+    pub message: String,
+}
+
+impl PreflightIssue {
+    fn error(field: &'static str, message: String) -> Self {
+        Self {
+            severity: PreflightSeverity::Error,
+            field,
+            message,
+        }
+    }
+
+    fn warning(field: &'static str, message: String) -> Self {
+        Self {
+            severity: PreflightSeverity::Warning,
+            field,
+            message,
+        }
+    }
+
+    pub fn is_blocking(&self) -> bool {
+        self.severity == PreflightSeverity::Error
+    }
+
+    /// One-line description suitable for `FailureInfo::evidence`.
+    pub fn describe(&self) -> String {
+        format!("{}: {}", self.field, self.message)
+    }
+}
+
+/// Check a stage's preconditions in its worktree.
+pub fn run_preflight(stage: &Stage, worktree: &Path) -> Vec<PreflightIssue> {
+    let work_dir = match stage.working_dir.as_deref() {
+        Some(wd) if wd != "." && !wd.is_empty() => PathBuf::from(wd),
+        _ => PathBuf::new(),
+    };
+    let mut issues = Vec::new();
+
+    if stage.container.is_none() {
+        for command in &stage.setup {
+            check_setup_command(command, worktree, &work_dir, &stage.new_files, &mut issues);
+        }
+    }
+
+    for pattern in &stage.files {
+        if !covered(&stage.new_files, pattern) && matching_files(worktree, pattern).is_empty() {
+            issues.push(PreflightIssue::error(
+                "files",
+                format!(
+                    "'{pattern}' matches no file in the worktree. Fix the path, \
+                     or list it under new_files if the stage creates it"
+                ),
+            ));
+        }
+    }
+
+    for criterion in &stage.acceptance {
+        let command = criterion.command();
+        for script in referenced_scripts(command) {
+            let rel = normalize(&work_dir.join(&script));
+            if !worktree.join(&rel).exists() && !covered(&stage.new_files, &rel) {
+                issues.push(PreflightIssue::error(
+                    "acceptance",
+                    format!(
+                        "`{command}` runs {}, which does not exist. Fix the path, \
+                         or list it under new_files if the stage creates it",
+                        rel
+                    ),
+                ));
+            }
+        }
+    }
+
+    check_context_budget(stage, worktree, &mut issues);
+    issues
+}
+
+fn check_setup_command(
+    command: &str,
+    worktree: &Path,
+    work_dir: &Path,
+    new_files: &[String],
+    issues: &mut Vec<PreflightIssue>,
+) {
+    for segment in segments(command) {
+        let Some(program) = program(&segment) else {
+            continue;
+        };
+        if SHELL_BUILTINS.contains(&program) || is_dynamic(program) {
+            continue;
+        }
+        if program.contains('/') {
+            let rel = normalize(&work_dir.join(program));
+            let exists = if Path::new(program).is_absolute() {
+                Path::new(program).exists()
+            } else {
+                worktree.join(&rel).exists()
+            };
+            if !exists && !covered(new_files, &rel) {
+                issues.push(PreflightIssue::error(
+                    "setup",
+                    format!("`{command}` runs {program}, which does not exist"),
+                ));
+            }
+        } else if which::which(program).is_err() {
+            issues.push(PreflightIssue::error(
+                "setup",
+                format!("`{command}` needs {program}, which is not on PATH"),
+            ));
+        }
+    }
+}
+
+fn check_context_budget(stage: &Stage, worktree: &Path, issues: &mut Vec<PreflightIssue>) {
+    let budget = match stage.context_budget {
+        Some(budget) if !(1..=100).contains(&budget) => {
+            issues.push(PreflightIssue::error(
+                "context_budget",
+                format!("{budget}% is not a percentage between 1 and 100"),
+            ));
+            return;
+        }
+        Some(budget) => budget as f32,
+        None => DEFAULT_CONTEXT_BUDGET,
+    };
+
+    let mut files: Vec<PathBuf> = stage
+        .files
+        .iter()
+        .flat_map(|pattern| matching_files(worktree, pattern))
+        .collect();
+    files.sort();
+    files.dedup();
+    let bytes: u64 = files
+        .iter()
+        .filter_map(|path| fs::metadata(path).ok())
+        .map(|meta| meta.len())
+        .sum();
+    let tokens = bytes / BYTES_PER_TOKEN;
+    let budget_tokens = (DEFAULT_CONTEXT_LIMIT as f32 * budget / 100.0) as u64;
+    if tokens > budget_tokens {
+        issues.push(PreflightIssue::warning(
+            "context_budget",
+            format!(
+                "the {} files matched by files come to ~{tokens} tokens, more than the \
+                 {budget}% budget (~{budget_tokens} tokens). Narrow files or split the stage",
+                files.len()
+            ),
+        ));
+    }
+}
+
+/// Files in `worktree` matching a `files` entry. A pattern that matches a
+/// directory (`src/api`, `src/`, or the directories `src/**` yields) stands
+/// for every file under it.
+fn matching_files(worktree: &Path, pattern: &str) -> Vec<PathBuf> {
+    let pattern = pattern.strip_suffix('/').unwrap_or(pattern);
+    let full = worktree.join(pattern).to_string_lossy().to_string();
+    let Ok(paths) = glob::glob(&full) else {
+        return Vec::new();
+    };
+    let mut files = Vec::new();
+    for path in paths.flatten() {
+        if path.is_file() {
+            files.push(path);
+        } else if path.is_dir() {
+            let under = path.join("**/*").to_string_lossy().to_string();
+            if let Ok(nested) = glob::glob(&under) {
+                files.extend(nested.flatten().filter(|p| p.is_file()));
+            }
+        }
+    }
+    files.sort();
+    files.dedup();
+    files
+}
+
+/// Whether `path` (a path or a `files` pattern) is covered by `new_files`.
+fn covered(new_files: &[String], path: &str) -> bool {
+    let options = MatchOptions {
+        require_literal_separator: true,
+        ..MatchOptions::default()
+    };
+    new_files.iter().any(|pattern| {
+        if pattern.ends_with('/') {
+            return path.starts_with(pattern.as_str());
+        }
+        pattern == path || Pattern::new(pattern).is_ok_and(|p| p.matches_with(path, options))
+    })
+}
+
+/// Simple commands of a shell command line, split on `&&`, `||`, `;`, `|`
+/// and newlines.
+fn segments(command: &str) -> Vec<String> {
+    command
+        .replace("&&", "\n")
+        .replace("||", "\n")
+        .split(['\n', ';', '|'])
+        .map(|segment| segment.trim().trim_start_matches('(').trim().to_string())
+        .filter(|segment| !segment.is_empty())
+        .collect()
+}
+
+/// Words of a simple command, with surrounding quotes removed.
+fn words(segment: &str) -> Vec<&str> {
+    segment
+        .split_whitespace()
+        .map(|word| word.trim_matches(|c| c == '"' || c == '\''))
+        .collect()
+}
+
+/// The program a simple command runs, after `VAR=value` assignments and
+/// `env` / `exec` / `time` wrappers.
+fn program(segment: &str) -> Option<&str> {
+    words(segment)
+        .into_iter()
+        .find(|word| !(word.contains('=') || matches!(*word, "env" | "exec" | "time")))
+}
+
+/// Scripts a command runs: a path-like program, or the first argument of an
+/// interpreter.
+fn referenced_scripts(command: &str) -> Vec<String> {
+    let mut scripts = Vec::new();
+    for segment in segments(command) {
+        let words = words(&segment);
+        let Some(start) = words
+            .iter()
+            .position(|word| !(word.contains('=') || matches!(*word, "env" | "exec" | "time")))
+        else {
+            continue;
+        };
+        let program = words[start];
+        let script = if INTERPRETERS.contains(&program) {
+            words[start + 1..]
+                .iter()
+                .take_while(|word| !INLINE_FLAGS.contains(word))
+                .find(|word| !word.starts_with('-'))
+                .copied()
+        } else if program.contains('/') && !Path::new(program).is_absolute() {
+            Some(program)
+        } else {
+            None
+        };
+        if let Some(script) = script.filter(|script| !is_dynamic(script)) {
+            scripts.push(script.to_string());
+        }
+    }
+    scripts
+}
+
+/// Words loom cannot resolve statically: expansions, globs, substitutions.
+fn is_dynamic(word: &str) -> bool {
+    word.contains(['$', '`', '*', '?', '{', '~', '<', '>'])
+}
+
+/// Lexically normalize a relative path (`./a/../b` -> `b`) into the
+/// `/`-separated form `files` patterns use.
+fn normalize(path: &Path) -> String {
+    let mut parts: Vec<String> = Vec::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                parts.pop();
+            }
+            other => parts.push(other.as_os_str().to_string_lossy().into_owned()),
+        }
+    }
+    parts.join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::stage::{AcceptanceCriterion, ContainerConfig};
+    use tempfile::TempDir;
+
+    fn stage() -> Stage {
+        Stage::new("api".to_string(), None)
+    }
+
+    fn errors(issues: &[PreflightIssue]) -> Vec<&'static str> {
+        issues
+            .iter()
+            .filter(|issue| issue.is_blocking())
+            .map(|issue| issue.field)
+            .collect()
+    }
+
+    #[test]
+    fn test_referenced_scripts() {
+        assert_eq!(
+            referenced_scripts("./scripts/check.sh && bash tools/lint.sh --fix"),
+            vec!["./scripts/check.sh", "tools/lint.sh"]
+        );
+        assert_eq!(
+            referenced_scripts("CI=1 python3 -u scripts/smoke.py | tee out"),
+            vec!["scripts/smoke.py"]
+        );
+        assert!(referenced_scripts("python -m pytest tests/").is_empty());
+        assert!(referenced_scripts("cargo test && /usr/bin/true").is_empty());
+        assert!(referenced_scripts("sh $SCRIPT").is_empty());
+    }
+
+    #[test]
+    fn test_program_skips_assignments_and_wrappers() {
+        assert_eq!(program("RUST_LOG=debug env cargo build"), Some("cargo"));
+        assert_eq!(program("exec ./run.sh"), Some("./run.sh"));
+        assert_eq!(program("FOO=1"), None);
+    }
+
+    #[test]
+    fn test_clean_stage_passes() {
+        let temp = TempDir::new().unwrap();
+        fs::create_dir_all(temp.path().join("src")).unwrap();
+        fs::create_dir_all(temp.path().join("scripts")).unwrap();
+        fs::write(temp.path().join("src/lib.rs"), "fn main() {}\n").unwrap();
+        fs::write(temp.path().join("scripts/check.sh"), "exit 0\n").unwrap();
+
+        let mut stage = stage();
+        stage.setup = vec!["sh -c true".to_string()];
+        stage.files = vec!["src/**/*.rs".to_string()];
+        stage.acceptance = vec![AcceptanceCriterion::Simple("sh scripts/check.sh".into())];
+        assert!(run_preflight(&stage, temp.path()).is_empty());
+    }
+
+    #[test]
+    fn test_directory_patterns_match_files_under_them() {
+        let temp = TempDir::new().unwrap();
+        fs::create_dir_all(temp.path().join("src/api")).unwrap();
+        fs::create_dir_all(temp.path().join("empty")).unwrap();
+        fs::write(temp.path().join("src/api/a.rs"), "fn a() {}\n").unwrap();
+
+        for pattern in ["src/**", "src/api", "src/api/", "src/**/*.rs"] {
+            assert_eq!(
+                matching_files(temp.path(), pattern),
+                vec![temp.path().join("src/api/a.rs")],
+                "{pattern}"
+            );
+        }
+        assert!(matching_files(temp.path(), "empty").is_empty());
+
+        let mut stage = stage();
+        stage.files = vec!["src/**".to_string(), "src/api".to_string()];
+        assert!(run_preflight(&stage, temp.path()).is_empty());
+    }
+
+    #[test]
+    fn test_reports_each_unmet_precondition() {
+        let temp = TempDir::new().unwrap();
+        let mut stage = stage();
+        stage.setup = vec!["loom-no-such-binary-xyz --version".to_string()];
+        stage.files = vec!["src/missing/*.rs".to_string()];
+        stage.acceptance = vec![AcceptanceCriterion::Simple("./scripts/verify.sh".into())];
+        stage.context_budget = Some(150);
+
+        let issues = run_preflight(&stage, temp.path());
+        assert_eq!(
+            errors(&issues),
+            vec!["setup", "files", "acceptance", "context_budget"]
+        );
+        assert!(issues[0].describe().contains("loom-no-such-binary-xyz"));
+        assert!(issues[2].message.contains("scripts/verify.sh"));
+    }
+
+    #[test]
+    fn test_new_files_and_working_dir() {
+        let temp = TempDir::new().unwrap();
+        let mut stage = stage();
+        stage.working_dir = Some("web".to_string());
+        stage.files = vec!["web/src/new.ts".to_string()];
+        stage.acceptance = vec![AcceptanceCriterion::Simple("bash ./e2e.sh".into())];
+        stage.new_files = vec!["web/src/".to_string(), "web/*.sh".to_string()];
+        assert!(run_preflight(&stage, temp.path()).is_empty());
+    }
+
+    #[test]
+    fn test_container_stage_skips_program_check() {
+        let temp = TempDir::new().unwrap();
+        let mut stage = stage();
+        stage.setup = vec!["loom-no-such-binary-xyz".to_string()];
+        stage.container = Some(ContainerConfig {
+            image: "rust:1".to_string(),
+            volumes: Vec::new(),
+            env: Vec::new(),
+        });
+        assert!(run_preflight(&stage, temp.path()).is_empty());
+    }
+
+    #[test]
+    fn test_oversized_files_warn_only() {
+        let temp = TempDir::new().unwrap();
+        fs::write(temp.path().join("big.txt"), vec![b'x'; 40_000]).unwrap();
+        let mut stage = stage();
+        stage.files = vec!["big.txt".to_string()];
+        stage.context_budget = Some(1);
+
+        let issues = run_preflight(&stage, temp.path());
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].severity, PreflightSeverity::Warning);
+        assert_eq!(issues[0].field, "context_budget");
+    }
+}
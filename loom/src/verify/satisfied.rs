@@ -0,0 +1,83 @@
+//! Already-satisfied check for `skip_if_satisfied` stages
+//!
+//! Runs a stage's acceptance criteria and `after_stage` checks in its fresh
+//! worktree, which holds the resolved base and nothing of the stage's own.
+//! When they all pass, the work the stage would do is already there —
+//! typically because an earlier run of an amended plan finished it.
+
+use anyhow::Result;
+use std::path::Path;
+
+use crate::models::stage::Stage;
+use crate::verify::before_after::run_after_stage_checks;
+use crate::verify::criteria::run_acceptance;
+use crate::verify::dependency_outputs::{resolved_checks, resolved_stage};
+
+/// Whether every acceptance criterion and `after_stage` check of `stage`
+/// passes in `worktree`.
+///
+/// A stage with neither has nothing to prove, so it is never satisfied.
+pub fn is_already_satisfied(stage: &Stage, worktree: &Path, work_dir: &Path) -> Result<bool> {
+    if stage.acceptance.is_empty() && stage.after_stage.is_empty() {
+        return Ok(false);
+    }
+    let check_dir = match &stage.working_dir {
+        Some(wd) if wd != "." && !wd.is_empty() => worktree.join(wd),
+        _ => worktree.to_path_buf(),
+    };
+
+    let resolved = resolved_stage(stage, work_dir)?;
+    if !run_acceptance(&resolved, Some(&check_dir))?.all_passed() {
+        return Ok(false);
+    }
+    let checks = resolved_checks(stage, &stage.after_stage, work_dir)?;
+    Ok(run_after_stage_checks(&checks, &check_dir)?.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::stage::{AcceptanceCriterion, TruthCheck};
+    use tempfile::TempDir;
+
+    fn stage_with(acceptance: &[&str], after_stage: &[&str]) -> Stage {
+        let mut stage = Stage::new("Stage".to_string(), None);
+        stage.acceptance = acceptance
+            .iter()
+            .map(|cmd| AcceptanceCriterion::Simple(cmd.to_string()))
+            .collect();
+        stage.after_stage = after_stage
+            .iter()
+            .map(|cmd| TruthCheck {
+                command: cmd.to_string(),
+                stdout_contains: vec![],
+                stdout_not_contains: vec![],
+                stderr_empty: None,
+                exit_code: Some(0),
+                description: None,
+                name: None,
+                category: None,
+                timeout: None,
+                retries: None,
+                flaky: false,
+                isolation: None,
+            })
+            .collect();
+        stage
+    }
+
+    #[test]
+    fn test_satisfied_only_when_every_check_passes() {
+        let temp = TempDir::new().unwrap();
+        let work_dir = temp.path().join(".work");
+        std::fs::create_dir_all(&work_dir).unwrap();
+        std::fs::write(temp.path().join("done.txt"), "").unwrap();
+
+        let check = |stage: Stage| is_already_satisfied(&stage, temp.path(), &work_dir).unwrap();
+        assert!(check(stage_with(&["test -f done.txt"], &["true"])));
+        assert!(check(stage_with(&[], &["test -f done.txt"])));
+        assert!(!check(stage_with(&["test -f missing.txt"], &[])));
+        assert!(!check(stage_with(&["true"], &["false"])));
+        assert!(!check(stage_with(&[], &[])));
+    }
+}
@@ -165,6 +165,8 @@ pub fn trigger_dependents(
 ///
 /// Knowledge stages are exempt from the git ancestry check because they
 /// have no branch by design — their "merge" is a pure metadata operation.
+/// So are stages skipped as already satisfied: they never made a commit,
+/// their work was on the target branch before they were scheduled.
 ///
 /// The git ancestry check is defense-in-depth against phantom merges: the
 /// `merged` flag can lie (see `PLAN-fix-phantom-merge.md`), so we
@@ -196,6 +198,13 @@ pub fn are_all_dependencies_satisfied(
             )
         })?;
 
+        if dep_stage.status == StageStatus::Skipped {
+            if !dep_stage.merged {
+                return Ok(false);
+            }
+            continue;
+        }
+
         // Knowledge stages are exempt from the git ancestry check
         // (they have no branch by design). Status + merged flag are sufficient.
         if dep_stage.stage_type == StageType::Knowledge {
@@ -0,0 +1,202 @@
+//! Worktree setup commands
+//!
+//! Runs a stage's `worktree_setup` commands (e.g. `npm ci`, `cargo fetch`,
+//! copying `.env`) in its worktree before the orchestrator spawns a session.
+//! Unlike `setup`, which the agent runs inside its own flow, these are executed
+//! by loom itself so a broken environment blocks the stage up front instead of
+//! burning an agent session.
+//!
+//! Output of every command is written to `.work/logs/worktree-setup/<stage-id>.log`
+//! (overwritten on each run) so failures can be diagnosed without re-running them.
+//!
+//! Setup runs once per worktree: a successful run leaves a `<stage-id>.done`
+//! marker next to the log, so retries, resumes and re-spawns on an existing
+//! worktree skip it. A freshly created worktree clears the marker first.
+
+use anyhow::{Context, Result};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use super::criteria::{run_single_criterion_with_timeout, DEFAULT_COMMAND_TIMEOUT};
+use crate::fs::logs::create_log;
+use crate::secrets::RedactingWriter;
+
+/// First failing worktree setup command.
+#[derive(Debug, Clone)]
+pub struct WorktreeSetupFailure {
+    pub command: String,
+    pub exit_code: Option<i32>,
+    pub timed_out: bool,
+    pub log_path: PathBuf,
+}
+
+impl WorktreeSetupFailure {
+    /// One-line description suitable for `FailureInfo::evidence`.
+    pub fn describe(&self) -> String {
+        let reason = if self.timed_out {
+            "timed out".to_string()
+        } else {
+            match self.exit_code {
+                Some(code) => format!("exited with code {code}"),
+                None => "was terminated by a signal".to_string(),
+            }
+        };
+        format!(
+            "Worktree setup command `{}` {reason} (log: {})",
+            self.command,
+            self.log_path.display()
+        )
+    }
+}
+
+/// Path of the worktree setup log for a stage.
+pub fn worktree_setup_log_path(work_dir: &Path, stage_id: &str) -> PathBuf {
+    work_dir
+        .join("logs")
+        .join("worktree-setup")
+        .join(format!("{stage_id}.log"))
+}
+
+/// Path of the marker recording that a stage's worktree setup succeeded.
+pub fn worktree_setup_marker_path(work_dir: &Path, stage_id: &str) -> PathBuf {
+    worktree_setup_log_path(work_dir, stage_id).with_extension("done")
+}
+
+/// Whether worktree setup still has to run for a stage.
+///
+/// Always true when `created` (the worktree was just created), in which case
+/// a marker left by an earlier worktree of the stage is removed. Otherwise
+/// true until setup has succeeded once.
+pub fn worktree_setup_pending(work_dir: &Path, stage_id: &str, created: bool) -> Result<bool> {
+    let marker = worktree_setup_marker_path(work_dir, stage_id);
+    if created {
+        match fs::remove_file(&marker) {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => {
+                return Err(e).with_context(|| {
+                    format!("Failed to remove setup marker: {}", marker.display())
+                })
+            }
+        }
+        return Ok(true);
+    }
+    Ok(!marker.exists())
+}
+
+/// Record that a stage's worktree setup succeeded.
+pub fn mark_worktree_setup_done(work_dir: &Path, stage_id: &str) -> Result<()> {
+    let marker = worktree_setup_marker_path(work_dir, stage_id);
+    if let Some(parent) = marker.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create log directory: {}", parent.display()))?;
+    }
+    fs::write(&marker, "")
+        .with_context(|| format!("Failed to write setup marker: {}", marker.display()))
+}
+
+/// Run worktree setup commands in order from `worktree_path`.
+///
+/// Stops at the first failing command.
+///
+/// # Returns
+///
+/// `Ok(None)` when every command succeeded, `Ok(Some(failure))` for the first
+/// failing command, and `Err` only for infrastructure problems (log file or
+/// process spawn errors).
+pub fn run_worktree_setup(
+    commands: &[String],
+    worktree_path: &Path,
+    log_path: &Path,
+) -> Result<Option<WorktreeSetupFailure>> {
+    if let Some(parent) = log_path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create log directory: {}", parent.display()))?;
+    }
+    let mut log = RedactingWriter(
+        create_log(log_path)
+            .with_context(|| format!("Failed to create setup log: {}", log_path.display()))?,
+    );
+
+    for command in commands {
+        log.write_all(format!("$ {command}\n").as_bytes())?;
+        let result = run_single_criterion_with_timeout(
+            command,
+            Some(worktree_path),
+            DEFAULT_COMMAND_TIMEOUT,
+        )
+        .with_context(|| format!("Failed to run worktree setup command: {command}"))?;
+
+        log.write_all(result.stdout.as_bytes())?;
+        log.write_all(result.stderr.as_bytes())?;
+        writeln!(log, "[{}]\n", result.summary())?;
+
+        if !result.success {
+            return Ok(Some(WorktreeSetupFailure {
+                command: command.clone(),
+                exit_code: result.exit_code,
+                timed_out: result.timed_out,
+                log_path: log_path.to_path_buf(),
+            }));
+        }
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_run_worktree_setup_all_pass_logs_output() {
+        let temp = TempDir::new().unwrap();
+        let log_path = worktree_setup_log_path(temp.path(), "stage-a");
+        let commands = vec!["echo first".to_string(), "touch created.txt".to_string()];
+
+        let failure = run_worktree_setup(&commands, temp.path(), &log_path).unwrap();
+
+        assert!(failure.is_none());
+        assert!(temp.path().join("created.txt").exists());
+        let log = fs::read_to_string(&log_path).unwrap();
+        assert!(log.contains("$ echo first"));
+        assert!(log.contains("first"));
+        assert!(log.contains("$ touch created.txt"));
+    }
+
+    #[test]
+    fn test_run_worktree_setup_stops_at_first_failure() {
+        let temp = TempDir::new().unwrap();
+        let log_path = worktree_setup_log_path(temp.path(), "stage-b");
+        let commands = vec![
+            "echo boom >&2; exit 3".to_string(),
+            "touch never.txt".to_string(),
+        ];
+
+        let failure = run_worktree_setup(&commands, temp.path(), &log_path)
+            .unwrap()
+            .expect("first command should fail");
+
+        assert_eq!(failure.command, "echo boom >&2; exit 3");
+        assert_eq!(failure.exit_code, Some(3));
+        assert!(!temp.path().join("never.txt").exists());
+        assert!(failure.describe().contains("exited with code 3"));
+        assert!(fs::read_to_string(&log_path).unwrap().contains("boom"));
+    }
+
+    #[test]
+    fn test_worktree_setup_pending_until_done_or_recreated() {
+        let temp = TempDir::new().unwrap();
+
+        assert!(worktree_setup_pending(temp.path(), "stage-c", false).unwrap());
+        mark_worktree_setup_done(temp.path(), "stage-c").unwrap();
+        assert!(!worktree_setup_pending(temp.path(), "stage-c", false).unwrap());
+
+        // A recreated worktree needs setup again, and the stale marker goes.
+        assert!(worktree_setup_pending(temp.path(), "stage-c", true).unwrap());
+        assert!(!worktree_setup_marker_path(temp.path(), "stage-c").exists());
+        assert!(worktree_setup_pending(temp.path(), "stage-c", false).unwrap());
+    }
+}
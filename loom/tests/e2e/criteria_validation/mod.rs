@@ -48,6 +48,20 @@ pub(crate) fn create_valid_stage(id: &str, name: &str) -> StageDefinition {
         reasoning_effort: None,
         code_review: None,
         ultracode: false,
+        pair: false,
+        worktree_setup: vec![],
+        instructions: None,
+        prompt_prefix: None,
+        container: None,
+        keep_terminal: None,
+        outputs: Vec::new(),
+        params: Vec::new(),
+        requires_outputs: Vec::new(),
+        new_files: Vec::new(),
+        expected_duration: None,
+        acceptance_isolation: None,
+        skip_if_satisfied: false,
+        package: None,
     }
 }
 
@@ -60,6 +74,15 @@ pub(crate) fn create_metadata(stages: Vec<StageDefinition>) -> LoomMetadata {
             auto_merge: None,
             change_impact: None,
             adjudication: None,
+            conflict_avoidance: Default::default(),
+            auto_code_review: false,
+            branch_template: None,
+            push_branches: false,
+            push_remote: None,
+            sparse_checkout: false,
+            sparse_include: Vec::new(),
+            knowledge_first: false,
+            knowledge_refresh_every: None,
             stages,
         },
     }
@@ -38,6 +38,10 @@ fn test_orchestrator_config_custom_values() {
         max_skill_recommendations: 5,
         sandbox_config: SandboxConfig::default(),
         shutdown_flag: None,
+        until: None,
+        deadline: None,
+        event_sink: None,
+        simulation: None,
     };
 
     assert_eq!(config.max_parallel_sessions, 8);
@@ -2,6 +2,7 @@
 
 use crate::helpers::create_temp_git_repo;
 use loom::models::stage::{Stage, StageStatus};
+use loom::orchestrator::terminal::mock::SimulationSchedule;
 use loom::orchestrator::OrchestratorConfig;
 use loom::plan::graph::ExecutionGraph;
 use loom::plan::schema::SandboxConfig;
@@ -21,7 +22,6 @@ use super::create_stage_def;
 /// This test verifies the manual mode behavior by checking that after calling
 /// `run()`, sessions are tracked, worktrees exist, but no Claude is spawned.
 #[test]
-#[ignore] // Integration test - requires git repo, run with --ignored
 fn test_orchestrator_with_manual_mode() {
     // Create a git repo (required for worktree creation)
     let temp_dir = create_temp_git_repo().expect("Should create git repo");
@@ -63,6 +63,10 @@ fn test_orchestrator_with_manual_mode() {
         max_skill_recommendations: 5,
         sandbox_config: SandboxConfig::default(),
         shutdown_flag: None,
+        until: None,
+        deadline: None,
+        event_sink: None,
+        simulation: Some(SimulationSchedule::default()),
     };
 
     let mut orchestrator =
@@ -1,6 +1,7 @@
 //! Tests for parallel session configuration and behavior
 
 use loom::models::stage::{Stage, StageStatus};
+use loom::orchestrator::terminal::mock::SimulationSchedule;
 use loom::orchestrator::{Orchestrator, OrchestratorConfig};
 use loom::plan::graph::ExecutionGraph;
 use loom::plan::schema::SandboxConfig;
@@ -51,7 +52,6 @@ fn test_max_parallel_sessions_configuration() {
 /// only 2 stages start executing at once. This tests the actual runtime behavior,
 /// not just configuration storage.
 #[test]
-#[ignore] // Integration test - run with --ignored
 fn test_orchestrator_respects_max_parallel_sessions() {
     use std::process::Command;
 
@@ -135,6 +135,10 @@ fn test_orchestrator_respects_max_parallel_sessions() {
         max_skill_recommendations: 5,
         sandbox_config: SandboxConfig::default(),
         shutdown_flag: None,
+        until: None,
+        deadline: None,
+        event_sink: None,
+        simulation: Some(SimulationSchedule::default()),
     };
 
     let mut orchestrator = Orchestrator::new(config, graph).expect("Should create orchestrator");
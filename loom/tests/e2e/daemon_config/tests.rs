@@ -4,6 +4,7 @@
 //! directory configuration, and stage-specific execution.
 
 use loom::models::stage::Stage;
+use loom::orchestrator::terminal::mock::SimulationSchedule;
 use loom::orchestrator::{Orchestrator, OrchestratorConfig};
 use loom::plan::graph::ExecutionGraph;
 use loom::plan::schema::SandboxConfig;
@@ -14,7 +15,6 @@ use tempfile::TempDir;
 use super::create_stage_def;
 
 #[test]
-#[ignore] // Requires a terminal emulator - skipped in CI
 fn test_orchestrator_creation_with_config() {
     let temp_dir = TempDir::new().unwrap();
     let work_dir = temp_dir.path();
@@ -39,6 +39,10 @@ fn test_orchestrator_creation_with_config() {
         max_skill_recommendations: 5,
         sandbox_config: SandboxConfig::default(),
         shutdown_flag: None,
+        until: None,
+        deadline: None,
+        event_sink: None,
+        simulation: Some(SimulationSchedule::default()),
     };
 
     let orchestrator = Orchestrator::new(config.clone(), graph);
@@ -83,6 +87,20 @@ fn test_auto_merge_config_cascade() {
         reasoning_effort: None,
         code_review: None,
         ultracode: false,
+        pair: false,
+        worktree_setup: vec![],
+        instructions: None,
+        prompt_prefix: None,
+        container: None,
+        keep_terminal: None,
+        outputs: Vec::new(),
+        params: Vec::new(),
+        requires_outputs: Vec::new(),
+        new_files: Vec::new(),
+        expected_duration: None,
+        acceptance_isolation: None,
+        skip_if_satisfied: false,
+        package: None,
     };
 
     assert_eq!(stage_with_auto_merge.auto_merge, Some(true));
@@ -115,13 +133,26 @@ fn test_auto_merge_config_cascade() {
         reasoning_effort: None,
         code_review: None,
         ultracode: false,
+        pair: false,
+        worktree_setup: vec![],
+        instructions: None,
+        prompt_prefix: None,
+        container: None,
+        keep_terminal: None,
+        outputs: Vec::new(),
+        params: Vec::new(),
+        requires_outputs: Vec::new(),
+        new_files: Vec::new(),
+        expected_duration: None,
+        acceptance_isolation: None,
+        skip_if_satisfied: false,
+        package: None,
     };
 
     assert_eq!(stage_without_override.auto_merge, None);
 }
 
 #[test]
-#[ignore] // Requires a terminal emulator - skipped in CI
 fn test_orchestrator_creates_successfully() {
     // Test that the orchestrator creates with default config
     let temp_dir = TempDir::new().unwrap();
@@ -134,6 +165,7 @@ fn test_orchestrator_creates_successfully() {
     let config = OrchestratorConfig {
         work_dir: work_dir.to_path_buf(),
         repo_root: work_dir.to_path_buf(),
+        simulation: Some(SimulationSchedule::default()),
         ..Default::default()
     };
 
@@ -185,7 +217,6 @@ fn test_work_dir_and_repo_root_configuration() {
 /// 2. Plan-level `auto_merge` setting
 /// 3. Orchestrator config `auto_merge` setting (lowest priority)
 #[test]
-#[ignore] // Integration test - run with --ignored
 fn test_daemon_respects_auto_merge() {
     use loom::orchestrator::auto_merge::is_auto_merge_enabled;
 
@@ -237,6 +268,7 @@ fn test_daemon_respects_auto_merge() {
         manual_mode: true,
         work_dir: work_dir.to_path_buf(),
         repo_root: temp_dir.path().to_path_buf(),
+        simulation: Some(SimulationSchedule::default()),
         ..Default::default()
     };
 
@@ -283,12 +283,14 @@ fn test_signal_with_dependencies_status() {
             name: "First Dependency".to_string(),
             status: "Verified".to_string(),
             outputs: Vec::new(),
+            expected_outputs: Vec::new(),
         },
         DependencyStatus {
             stage_id: "stage-dep-2".to_string(),
             name: "Second Dependency".to_string(),
             status: "Verified".to_string(),
             outputs: Vec::new(),
+            expected_outputs: Vec::new(),
         },
     ];
 
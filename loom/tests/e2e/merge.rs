@@ -22,6 +22,8 @@ fn create_test_stage(id: &str, auto_merge: Option<bool>) -> Stage {
         worktree: Some(id.to_string()),
         session: None,
         held: false,
+        held_by: None,
+        assignee: None,
         parent_stage: None,
         child_stages: vec![],
         created_at: Utc::now(),
@@ -61,12 +63,28 @@ fn create_test_stage(id: &str, auto_merge: Option<bool>) -> Stage {
         execution_mode: None,
         max_fix_attempts: None,
         review_reason: None,
+        waiting_prompt: None,
+        waiting_since: None,
         bug_fix: None,
         regression_test: None,
         model: None,
         reasoning_effort: None,
         is_possibly_stuck: false,
         ultracode: false,
+        pair: false,
+        pair_review: None,
+        worktree_setup: Vec::new(),
+        instructions: None,
+        prompt_prefix: None,
+        container: None,
+        keep_terminal: None,
+        expected_outputs: Vec::new(),
+        params: Vec::new(),
+        requires_outputs: Vec::new(),
+        new_files: Vec::new(),
+        expected_duration_secs: None,
+        acceptance_isolation: None,
+        skip_if_satisfied: false,
     }
 }
 
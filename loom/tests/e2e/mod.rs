@@ -14,6 +14,7 @@ pub mod merge;
 pub mod parallel;
 pub mod sequential;
 pub mod sessions;
+pub mod simulated_run;
 pub mod uncommitted_changes;
 
 pub use fixtures::*;
@@ -0,0 +1,162 @@
+//! E2E tests for simulated runs (`loom run --simulate`)
+//!
+//! These tests drive the real orchestrator loop with the simulated terminal
+//! backend, so scheduling, merging and failure handling run end to end
+//! without a terminal emulator or agents.
+
+use super::daemon_config::create_stage_def;
+use super::helpers::create_temp_git_repo;
+use loom::git::branch_exists;
+use loom::models::stage::{AcceptanceCriterion, Stage, StageStatus};
+use loom::orchestrator::{Orchestrator, OrchestratorConfig, SimulationSchedule};
+use loom::plan::graph::ExecutionGraph;
+use loom::verify::transitions::{load_stage, save_stage};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Write stage files for `(id, dependencies)` pairs and build their graph.
+fn setup_plan(work_dir: &Path, stages: &[(&str, &[&str])]) -> ExecutionGraph {
+    for dir in ["stages", "sessions", "signals"] {
+        std::fs::create_dir_all(work_dir.join(dir)).unwrap();
+    }
+    let mut defs = Vec::new();
+    for (id, deps) in stages {
+        let deps: Vec<String> = deps.iter().map(|d| d.to_string()).collect();
+        let mut stage = Stage::new(id.to_string(), None);
+        stage.id = id.to_string();
+        stage.status = if deps.is_empty() {
+            StageStatus::Queued
+        } else {
+            StageStatus::WaitingForDeps
+        };
+        stage.dependencies = deps.clone();
+        save_stage(&stage, work_dir).unwrap();
+        defs.push(create_stage_def(id, id, deps));
+    }
+    ExecutionGraph::build(defs).unwrap()
+}
+
+fn simulated_config(repo_root: &Path, schedule: &str) -> OrchestratorConfig {
+    OrchestratorConfig {
+        poll_interval: Duration::from_millis(200),
+        work_dir: repo_root.join(".work"),
+        repo_root: repo_root.to_path_buf(),
+        enable_skill_routing: false,
+        simulation: Some(SimulationSchedule::parse(schedule).unwrap()),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_simulated_run_completes_and_merges_in_dependency_order() {
+    let temp_dir = create_temp_git_repo().unwrap();
+    let repo_root = temp_dir.path();
+    let work_dir = repo_root.join(".work");
+    let graph = setup_plan(&work_dir, &[("build", &[]), ("deploy", &["build"])]);
+
+    let config = simulated_config(repo_root, "[default]\nduration = \"1s\"\n");
+    let mut orchestrator = Orchestrator::new(config, graph).unwrap();
+    let result = orchestrator.run().unwrap();
+
+    assert!(result.is_success(), "failed: {:?}", result.failed_stages);
+    assert_eq!(result.total_sessions_spawned, 2);
+    for id in ["build", "deploy"] {
+        let stage = load_stage(id, &work_dir).unwrap();
+        assert_eq!(stage.status, StageStatus::Completed, "{id}");
+        assert!(stage.merged, "{id} should be merged");
+    }
+    let build = load_stage("build", &work_dir).unwrap();
+    let deploy = load_stage("deploy", &work_dir).unwrap();
+    assert!(build.completed_at <= deploy.started_at);
+}
+
+#[test]
+fn test_simulated_failure_blocks_stage_and_dependents() {
+    let temp_dir = create_temp_git_repo().unwrap();
+    let repo_root = temp_dir.path();
+    let work_dir = repo_root.join(".work");
+    let graph = setup_plan(
+        &work_dir,
+        &[("build", &[]), ("deploy", &["build"]), ("docs", &[])],
+    );
+
+    // A blocked stage keeps the run waiting for intervention, so stop it
+    // once the failure has played out.
+    let shutdown = Arc::new(AtomicBool::new(false));
+    let watcher = {
+        let shutdown = Arc::clone(&shutdown);
+        let work_dir = work_dir.clone();
+        std::thread::spawn(move || {
+            let settled = |id: &str, status| {
+                load_stage(id, &work_dir).is_ok_and(|stage| stage.status == status)
+            };
+            let deadline = Instant::now() + Duration::from_secs(60);
+            while Instant::now() < deadline
+                && !(settled("build", StageStatus::Blocked)
+                    && settled("docs", StageStatus::Completed))
+            {
+                std::thread::sleep(Duration::from_millis(100));
+            }
+            // Let the orchestrator record the outcome before stopping it
+            std::thread::sleep(Duration::from_secs(1));
+            shutdown.store(true, Ordering::SeqCst);
+        })
+    };
+
+    let config = OrchestratorConfig {
+        shutdown_flag: Some(shutdown),
+        ..simulated_config(
+            repo_root,
+            "[default]\nduration = \"1s\"\n\n[stages.build]\noutcome = \"fail\"\n",
+        )
+    };
+    let mut orchestrator = Orchestrator::new(config, graph).unwrap();
+    let result = orchestrator.run().unwrap();
+    watcher.join().unwrap();
+
+    assert!(!result.is_success());
+    assert_eq!(result.failed_stages, vec!["build".to_string()]);
+    let build = load_stage("build", &work_dir).unwrap();
+    assert_eq!(build.status, StageStatus::Blocked);
+    assert_eq!(build.close_reason.as_deref(), Some("Simulated failure"));
+    assert_eq!(
+        load_stage("docs", &work_dir).unwrap().status,
+        StageStatus::Completed
+    );
+    assert_eq!(
+        load_stage("deploy", &work_dir).unwrap().status,
+        StageStatus::WaitingForDeps
+    );
+}
+
+#[test]
+fn test_satisfied_stage_is_skipped_and_unblocks_dependents() {
+    let temp_dir = create_temp_git_repo().unwrap();
+    let repo_root = temp_dir.path();
+    let work_dir = repo_root.join(".work");
+    let graph = setup_plan(&work_dir, &[("build", &[]), ("deploy", &["build"])]);
+
+    // `build`'s acceptance already passes on the base
+    let mut build = load_stage("build", &work_dir).unwrap();
+    build.skip_if_satisfied = true;
+    build.acceptance = vec![AcceptanceCriterion::Simple("true".to_string())];
+    save_stage(&build, &work_dir).unwrap();
+
+    let config = simulated_config(repo_root, "[default]\nduration = \"1s\"\n");
+    let mut orchestrator = Orchestrator::new(config, graph).unwrap();
+    let result = orchestrator.run().unwrap();
+
+    assert!(result.is_success(), "failed: {:?}", result.failed_stages);
+    assert_eq!(result.total_sessions_spawned, 1);
+    let build = load_stage("build", &work_dir).unwrap();
+    assert_eq!(build.status, StageStatus::Skipped);
+    assert_eq!(build.close_reason.as_deref(), Some("already satisfied"));
+    assert!(build.started_at.is_none());
+    assert!(!branch_exists("loom/build", repo_root).unwrap());
+    assert_eq!(
+        load_stage("deploy", &work_dir).unwrap().status,
+        StageStatus::Completed
+    );
+}
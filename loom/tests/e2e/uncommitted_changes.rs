@@ -148,6 +148,10 @@ fn test_loom_run_fails_with_staged_changes() {
         None,  // max_parallel
         false, // watch
         true,  // auto_merge
+        None,  // until
+        false, // allow_dirty
+        None,  // deadline
+        None,  // simulation
     );
 
     // Restore original directory
@@ -195,6 +199,10 @@ fn test_loom_run_fails_with_mixed_changes() {
         None,  // max_parallel
         false, // watch
         true,  // auto_merge
+        None,  // until
+        false, // allow_dirty
+        None,  // deadline
+        None,  // simulation
     );
 
     // Restore original directory
@@ -268,6 +276,10 @@ fn test_loom_run_fails_with_modified_tracked_files() {
         None,  // max_parallel
         false, // watch
         true,  // auto_merge
+        None,  // until
+        false, // allow_dirty
+        None,  // deadline
+        None,  // simulation
     );
 
     // Restore original directory
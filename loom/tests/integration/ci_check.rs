@@ -0,0 +1,80 @@
+//! Integration tests for `loom ci-check`
+
+use std::fs;
+use std::process::Command;
+use tempfile::TempDir;
+
+const LOOM: &str = env!("CARGO_BIN_EXE_loom");
+
+fn plan(acceptance: &str) -> String {
+    format!(
+        r#"# CI Plan
+
+<!-- loom METADATA -->
+
+```yaml
+loom:
+  version: 1
+  stages:
+    - id: stage-one
+      name: "Stage One"
+      working_dir: "."
+      acceptance:
+        - "{acceptance}"
+```
+
+<!-- END loom METADATA -->
+"#
+    )
+}
+
+/// A repository with `doc/plans/PLAN-ci.md`.
+fn repo_with_plan(content: &str) -> TempDir {
+    let temp = TempDir::new().unwrap();
+    fs::create_dir(temp.path().join(".git")).unwrap();
+    let plans = temp.path().join("doc/plans");
+    fs::create_dir_all(&plans).unwrap();
+    fs::write(plans.join("PLAN-ci.md"), content).unwrap();
+    temp
+}
+
+fn run_ci_check(dir: &std::path::Path, github: bool) -> std::process::Output {
+    let mut cmd = Command::new(LOOM);
+    cmd.arg("ci-check").current_dir(dir).env("NO_COLOR", "1");
+    if github {
+        cmd.env("GITHUB_ACTIONS", "true");
+    } else {
+        cmd.env_remove("GITHUB_ACTIONS");
+    }
+    cmd.output().expect("failed to run loom ci-check")
+}
+
+#[test]
+fn test_ci_check_passes_valid_plans() {
+    let repo = repo_with_plan(&plan("true"));
+    let out = run_ci_check(repo.path(), false);
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(out.status.success(), "stdout: {stdout}");
+    assert!(stdout.contains("Checked 1 plan(s): 0 error(s)"), "{stdout}");
+    assert!(!repo.path().join(".work").exists());
+}
+
+#[test]
+fn test_ci_check_annotates_errors() {
+    let repo = repo_with_plan(&plan("if true; then echo"));
+
+    let out = run_ci_check(repo.path(), false);
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(!out.status.success());
+    assert!(
+        stdout.contains("doc/plans/PLAN-ci.md:13: error: [stage-one] acceptance command"),
+        "{stdout}"
+    );
+
+    let out = run_ci_check(repo.path(), true);
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(
+        stdout.contains("::error file=doc/plans/PLAN-ci.md,line=13,title=loom ci-check::"),
+        "{stdout}"
+    );
+}
@@ -166,7 +166,7 @@ fn test_retry_after_manual_conflict_resolution() {
         .output()
         .expect("Failed to checkout main");
 
-    let worktree = create_worktree("stage-c", repo_root, Some("loom/_base/stage-c"))
+    let worktree = create_worktree("stage-c", repo_root, Some("loom/_base/stage-c"), None)
         .expect("Failed to create worktree after conflict resolution");
 
     let content =
@@ -65,7 +65,7 @@ fn test_multiple_deps_all_merged_uses_main() {
     );
 
     // Create worktree from main and verify it has both files
-    let worktree = create_worktree("stage-c", repo_root, Some(result.branch_name()))
+    let worktree = create_worktree("stage-c", repo_root, Some(result.branch_name()), None)
         .expect("Failed to create worktree");
 
     assert!(
@@ -201,7 +201,7 @@ fn test_diamond_pattern_all_merged() {
         "Expected Main for diamond, got {result:?}"
     );
 
-    let worktree = create_worktree("stage-d", repo_root, Some(result.branch_name()))
+    let worktree = create_worktree("stage-d", repo_root, Some(result.branch_name()), None)
         .expect("Failed to create worktree");
 
     assert!(
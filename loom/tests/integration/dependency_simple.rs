@@ -7,7 +7,7 @@ use std::fs;
 use std::process::Command;
 
 use loom::git::create_worktree;
-use loom::git::worktree::{resolve_base_branch, ResolvedBase};
+use loom::git::worktree::{resolve_base_branch, sparse_patterns, ResolvedBase};
 
 use super::helpers::*;
 
@@ -29,7 +29,7 @@ fn test_simple_chain_b_inherits_from_a() {
 
     assert_eq!(result, ResolvedBase::Branch("loom/stage-a".to_string()));
 
-    let worktree = create_worktree("stage-b", repo_root, Some(result.branch_name()))
+    let worktree = create_worktree("stage-b", repo_root, Some(result.branch_name()), None)
         .expect("Failed to create worktree");
 
     assert!(
@@ -113,7 +113,7 @@ fn test_worktree_inherits_full_git_history() {
     let result = resolve_base_branch("stage-b", &["stage-a".to_string()], &graph, repo_root, None)
         .expect("Failed to resolve");
 
-    let worktree = create_worktree("stage-b", repo_root, Some(result.branch_name()))
+    let worktree = create_worktree("stage-b", repo_root, Some(result.branch_name()), None)
         .expect("Failed to create worktree");
 
     assert!(verify_worktree_has_file(&worktree.path, "file1.txt"));
@@ -142,7 +142,7 @@ fn test_dep_already_merged_fallback_to_main() {
 
     assert_eq!(result, ResolvedBase::Main("main".to_string()));
 
-    let worktree = create_worktree("stage-b", repo_root, Some(result.branch_name()))
+    let worktree = create_worktree("stage-b", repo_root, Some(result.branch_name()), None)
         .expect("Failed to create worktree");
 
     assert!(
@@ -150,3 +150,42 @@ fn test_dep_already_merged_fallback_to_main() {
         "stage-b worktree should contain a_file.txt from main (merged from stage-a)"
     );
 }
+
+/// Test 5: A sparse profile checks out only the stage's files
+#[test]
+#[serial]
+fn test_sparse_worktree_checks_out_stage_files_only() {
+    let temp_dir = init_test_repo();
+    let repo_root = temp_dir.path();
+
+    for area in ["auth", "billing"] {
+        let dir = repo_root.join("src").join(area);
+        fs::create_dir_all(&dir).expect("Failed to create source directory");
+        fs::write(dir.join("mod.rs"), "// module\n").expect("Failed to write module");
+    }
+    Command::new("git")
+        .args(["add", "."])
+        .current_dir(repo_root)
+        .output()
+        .expect("Failed to git add");
+    Command::new("git")
+        .args(["commit", "-m", "Add modules"])
+        .current_dir(repo_root)
+        .output()
+        .expect("Failed to git commit");
+
+    let patterns = sparse_patterns(&["src/auth/**".to_string()], None, &[]);
+    let worktree = create_worktree("stage-b", repo_root, Some("main"), Some(&patterns))
+        .expect("Failed to create sparse worktree");
+
+    assert!(verify_worktree_has_file(&worktree.path, "README.md"));
+    assert!(verify_worktree_has_file(&worktree.path, "src/auth/mod.rs"));
+    assert!(
+        !verify_worktree_has_file(&worktree.path, "src/billing/mod.rs"),
+        "files outside the stage's globs should not be checked out"
+    );
+    assert!(
+        repo_root.join("src/billing/mod.rs").exists(),
+        "the main repository keeps its full checkout"
+    );
+}
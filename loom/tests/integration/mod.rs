@@ -3,6 +3,7 @@
 //! These tests verify end-to-end behavior of loom's orchestration features
 //! including dependency inheritance, worktree management, and conflict resolution.
 
+pub mod ci_check;
 pub mod dependency_cleanup;
 pub mod dependency_conflict;
 pub mod dependency_multi;